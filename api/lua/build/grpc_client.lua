@@ -61,6 +61,8 @@ local grpc_client = {}
 ---@class grpc_client.Client
 ---@field conn grpc_client.h2.Conn
 ---@field loop grpc_client.cqueues.Loop
+---Seconds unary requests may take before they fail, or `nil` for the default timeouts.
+---@field deadline number?
 local Client = {}
 
 ---Create a new gRPC client that connects to the socket specified with `sock_args`.
@@ -114,6 +116,11 @@ end
 ---
 ---@param service string The desired service
 ---@param method string The desired method within the service
+---Methods that wait on the user, so they aren't subject to the request deadline.
+local interactive_methods = {
+    ["pinnacle.screenshot.v1.ScreenshotService/SelectRegion"] = true,
+}
+
 local function create_request_headers(service, method)
     local req_headers = headers.new()
     req_headers:append(":method", "POST")
@@ -255,20 +262,46 @@ function Client:unary_request(request_specifier, data)
 
     local body = encode(request_type, data)
 
-    local _, err = stream:write_headers(create_request_headers(service, method), false, 1)
+    local interactive = interactive_methods[service .. "/" .. method]
+
+    local deadline = not interactive and self.deadline and (monotime() + self.deadline)
+
+    ---Caps `timeout` to the time left until the deadline.
+    local function until_deadline(timeout)
+        if not deadline then
+            return timeout
+        end
+        return math.max(math.min(timeout, deadline - monotime()), 0)
+    end
+
+    ---Turns an error into a deadline error if the deadline passed.
+    local function request_error(err)
+        if deadline and monotime() >= deadline then
+            return "error from response: code = DEADLINE_EXCEEDED"
+        end
+        return tostring(err)
+    end
+
+    local _, err =
+        stream:write_headers(create_request_headers(service, method), false, until_deadline(1))
     if err then
-        return nil, tostring(err)
+        return nil, request_error(err)
     end
 
-    local _, err = stream:write_chunk(body, true, 1)
+    local _, err = stream:write_chunk(body, true, until_deadline(1))
     if err then
-        return nil, tostring(err)
+        return nil, request_error(err)
     end
 
-    local headers, err = stream:get_headers_with_retries(0.5, 5)
+    -- With a deadline, keep retrying until it passes instead of a fixed number of times.
+    -- Interactive methods keep retrying until the user is done.
+    local retries = interactive and math.huge
+        or deadline and math.max(math.ceil(self.deadline / 0.5), 1)
+        or 5
+    local headers, err = stream:get_headers_with_retries(until_deadline(0.5), retries)
 
     if err then
-        return nil, tostring(err)
+        return nil, request_error(err)
     end
 
     local grpc_status = headers:get("grpc-status")
@@ -285,13 +318,13 @@ function Client:unary_request(request_specifier, data)
         end
     end
 
-    local response_body, err = stream:get_next_chunk(1)
+    local response_body, err = stream:get_next_chunk(until_deadline(1))
     if err then
-        return nil, tostring(err)
+        return nil, request_error(err)
     end
 
     -- Trailers are not handled properly by lua-http 0.4, but this code will work, eventually
-    local trailers, err = stream:get_headers_with_retries(0.5, 5)
+    local trailers, err = stream:get_headers_with_retries(until_deadline(0.5), 5)
     if trailers then
         for name, value, never_index in trailers:each() do
             print(name, value, never_index)
@@ -476,6 +476,13 @@ Pinnacle.setup(function()
         end)
     end
 
+    -- Ask before letting apps bind global shortcuts
+    if Snowcap then
+        require("pinnacle.portal").on_global_shortcuts_request(function(request)
+            Snowcap.integration.global_shortcuts_prompt(request):show()
+        end)
+    end
+
     -- Enable sloppy focus
     Window.connect_signal({
         pointer_enter = function(window)
@@ -54,6 +54,20 @@ function pinnacle.quit()
     end
 end
 
+---Restarts Pinnacle in place.
+---
+---Pinnacle re-executes itself, so an upgraded binary takes over without ending the session.
+---Clients, including this config, don't survive the restart. Windows that come back
+---afterwards, like ones the config spawns again, are put back on the tags and in the layout
+---mode they had before, matched by app id and title.
+function pinnacle.restart()
+    local _, err = client:pinnacle_v1_PinnacleService_Restart({})
+
+    if err then
+        log.error(err)
+    end
+end
+
 ---Reloads the active config.
 function pinnacle.reload_config()
     local _, err = client:pinnacle_v1_PinnacleService_ReloadConfig({})
@@ -134,6 +148,302 @@ function pinnacle.take_last_error()
     return error and error.error
 end
 
+---Sets how many seconds requests to Pinnacle may take before they fail.
+---
+---Requests that outlive the deadline fail with a `DEADLINE_EXCEEDED` error, which is logged,
+---and the function that made them returns a default value like an empty string or `false`.
+---
+---Pass `nil` to go back to the default, which gives up on requests after a few seconds.
+---
+---This applies to requests made after this is called.
+---
+---#### Example
+---```lua
+---Pinnacle.set_request_deadline(5)
+---```
+---
+---@param seconds number?
+function pinnacle.set_request_deadline(seconds)
+    client.deadline = seconds
+end
+
+---A compositor option that can be changed at runtime.
+---
+---@class pinnacle.Setting
+---A unique, dot-separated name, e.g. `window.grey_out_unresponsive`.
+---@field name string
+---A human-readable description.
+---@field description string
+---The fully-qualified RPC that changes this setting,
+---e.g. `pinnacle.window.v1.WindowService.SetGreyOutUnresponsive`.
+---@field setter string
+---The device this setting applies to, for per-device settings.
+---@field device_sysname string?
+---The current value.
+---@field value boolean | number | string
+---The type of the value.
+---@field type "bool" | "int" | "float" | "choice"
+---For `"choice"` settings, the names of the values the setter accepts.
+---@field choices string[]
+---For numeric settings, the smallest accepted value.
+---@field min number?
+---For numeric settings, the largest accepted value.
+---@field max number?
+
+---Gets all options that can be changed at runtime, along with their current values.
+---
+---This is intended for tools that generate settings UIs.
+---
+---@return pinnacle.Setting[]
+function pinnacle.settings()
+    local response, err = client:pinnacle_v1_PinnacleService_GetSettings({})
+
+    if err then
+        log.error(err)
+        return {}
+    end
+
+    assert(response)
+
+    ---@type pinnacle.Setting[]
+    local settings = {}
+
+    for _, setting in ipairs(response.settings or {}) do
+        local type, value
+        for _, ty in ipairs({ "bool", "int", "float", "choice" }) do
+            if setting[ty] ~= nil then
+                type = ty
+                value = setting[ty]
+            end
+        end
+
+        if type then
+            table.insert(settings, {
+                name = setting.name or "",
+                description = setting.description or "",
+                setter = setting.setter or "",
+                device_sysname = setting.device_sysname,
+                value = value,
+                type = type,
+                choices = setting.choices or {},
+                min = setting.min,
+                max = setting.max,
+            })
+        end
+    end
+
+    return settings
+end
+
+---A histogram of durations, in milliseconds.
+---
+---@class pinnacle.Histogram
+---The buckets, each with an upper bound and the number of observations in it.
+---
+---Counts are not cumulative. The last bucket has no upper bound.
+---@field buckets { bound_ms: number?, count: integer }[]
+---The sum of all observations.
+---@field sum_ms number
+---The number of observations.
+---@field count integer
+
+---Latency and frame timing metrics collected by the compositor.
+---
+---@class pinnacle.Metrics
+---Time from an input event to the presentation of the next frame.
+---@field input_latency pinnacle.Histogram
+---Time from a layout request to the layout being applied.
+---@field layout_time pinnacle.Histogram
+---The number of vblanks that queued frames missed.
+---@field missed_frames integer
+
+---@param histogram pinnacle.v1.Histogram?
+---@return pinnacle.Histogram
+local function histogram_from_api(histogram)
+    histogram = histogram or {}
+
+    local bounds = histogram.bucket_bounds_ms or {}
+    local buckets = {}
+    for i, count in ipairs(histogram.bucket_counts or {}) do
+        table.insert(buckets, { bound_ms = bounds[i], count = count })
+    end
+
+    return {
+        buckets = buckets,
+        sum_ms = histogram.sum_ms or 0,
+        count = histogram.count or 0,
+    }
+end
+
+---Gets latency and frame timing metrics collected since Pinnacle started.
+---
+---This is useful for debugging stutter.
+---
+---#### Example
+---```lua
+---local metrics = Pinnacle.metrics()
+---print(metrics.missed_frames .. " missed frames")
+---```
+---
+---@return pinnacle.Metrics
+function pinnacle.metrics()
+    local response, err = client:pinnacle_v1_PinnacleService_Metrics({})
+
+    if err then
+        log.error(err)
+    end
+
+    response = response or {}
+
+    return {
+        input_latency = histogram_from_api(response.input_latency),
+        layout_time = histogram_from_api(response.layout_time),
+        missed_frames = response.missed_frames or 0,
+    }
+end
+
+---Sets whether metrics are exported in the Prometheus text format on a Unix socket.
+---
+---Every connection to the socket receives the current metrics and is then closed.
+---
+---@param enabled boolean
+---
+---@return string | nil socket_path The path of the socket if exporting is enabled.
+function pinnacle.set_metrics_export(enabled)
+    local response, err = client:pinnacle_v1_PinnacleService_SetMetricsExport({
+        enabled = enabled,
+    })
+
+    if err then
+        log.error(err)
+        return nil
+    end
+
+    return response and response.socket_path
+end
+
+---A named set of runtime options that are applied together.
+---
+---Options that are nil are left alone when the profile is set.
+---
+---@class pinnacle.Profile
+---Whether the session is kept from going idle, as if an app were inhibiting idle.
+---@field inhibit_idle boolean?
+---Whether the inactive effect is turned off.
+---@field disable_inactive_effect boolean?
+---Whether unresponsive windows are greyed out.
+---@field grey_out_unresponsive boolean?
+---Whether fullscreen windows that don't fill their output are letterboxed.
+---@field letterbox_fullscreen boolean?
+
+---Defines a profile that can later be set with `pinnacle.set_profile`.
+---
+---This replaces any profile with the same name. Redefining the active profile
+---takes effect the next time it is set.
+---
+---#### Example
+---```lua
+---pinnacle.define_profile("gaming", {
+---    inhibit_idle = true,
+---    disable_inactive_effect = true,
+---})
+---
+---pinnacle.set_profile("gaming")
+------ ...
+---pinnacle.unset_profile()
+---```
+---
+---@param name string
+---@param profile pinnacle.Profile
+function pinnacle.define_profile(name, profile)
+    local _, err = client:pinnacle_v1_PinnacleService_DefineProfile({
+        name = name,
+        profile = {
+            inhibit_idle = profile.inhibit_idle,
+            disable_inactive_effect = profile.disable_inactive_effect,
+            grey_out_unresponsive = profile.grey_out_unresponsive,
+            letterbox_fullscreen = profile.letterbox_fullscreen,
+        },
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
+---Sets the active profile, applying all of its options at once.
+---
+---The options the profile changes are saved and restored when it is unset
+---or another profile is set.
+---
+---@param name string
+---
+---@return string | nil error An error if no profile called `name` was defined.
+function pinnacle.set_profile(name)
+    local response, err = client:pinnacle_v1_PinnacleService_SetProfile({
+        name = name,
+    })
+
+    if err then
+        log.error(err)
+        return err
+    end
+
+    return response and response.error
+end
+
+---Unsets the active profile, restoring the options it changed.
+function pinnacle.unset_profile()
+    local _, err = client:pinnacle_v1_PinnacleService_SetProfile({})
+
+    if err then
+        log.error(err)
+    end
+end
+
+---Returns the name of the active profile.
+---
+---@return string | nil
+function pinnacle.profile()
+    local response, err = client:pinnacle_v1_PinnacleService_GetProfile({})
+
+    if err then
+        log.error(err)
+        return nil
+    end
+
+    return response and response.name
+end
+
+---Registers a namespace for this process.
+---
+---This lets a separate helper, like a bar or a scratchpad manager, use the API alongside
+---the config. Binds, signal connections, and window rules set up after registering belong
+---to the namespace. They survive config reloads and are removed once this process exits.
+---
+---Returns an error if this process already registered a namespace
+---or another process registered one called `namespace`.
+---
+---#### Example
+---```lua
+---pinnacle.register_namespace("bar")
+---```
+---
+---@param namespace string
+---
+---@return string | nil error
+function pinnacle.register_namespace(namespace)
+    local err = client:pinnacle_v1_PinnacleService_RegisterNamespace({
+        namespace = namespace,
+    }, function(_) end)
+
+    if err then
+        log.error(err)
+    end
+
+    return err
+end
+
 ---Initializes the protobuf backend and connects to Pinnacle's gRPC socket.
 ---
 ---If the Snowcap Lua API is installed and Snowcap is running, this will also setup Snowcap and
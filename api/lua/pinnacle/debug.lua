@@ -95,4 +95,181 @@ function debug.toggle_process_piping()
     })
 end
 
+---Enables or disables input tracing.
+---
+---When on, every input event is logged along with the pointer and keyboard focus,
+---whether either is grabbed, and the client the event was sent to.
+---This is useful to figure out why input is going to the wrong window.
+---
+---Traced events can also be received with `on_input_trace`.
+---
+---@param set boolean
+function debug.trace_input(set)
+    local _, err = client:pinnacle_debug_v1_DebugService_SetInputTracing({
+        set_or_toggle = set and util_v1.SetOrToggle.SET_OR_TOGGLE_SET
+            or util_v1.SetOrToggle.SET_OR_TOGGLE_UNSET,
+    })
+end
+
+---Toggles input tracing.
+function debug.toggle_input_tracing()
+    local _, err = client:pinnacle_debug_v1_DebugService_SetInputTracing({
+        set_or_toggle = util_v1.SetOrToggle.SET_OR_TOGGLE_TOGGLE,
+    })
+end
+
+---Something an input event could be sent to.
+---@class pinnacle.debug.TracedFocus
+---A human-readable description of the target, like "window 3 (Alacritty)".
+---@field description string
+---The window the target belongs to, if any.
+---@field window pinnacle.window.WindowHandle?
+---The pid of the client that owns the target.
+---@field client_pid integer?
+
+---An input event and where it went.
+---@class pinnacle.debug.InputTrace
+---The kind of input event, like "PointerButton".
+---@field event string
+---The pointer focus after the event was handled.
+---@field pointer_focus pinnacle.debug.TracedFocus?
+---The keyboard focus after the event was handled.
+---@field keyboard_focus pinnacle.debug.TracedFocus?
+---Whether the pointer is grabbed, e.g. by a move or popup grab.
+---@field pointer_grabbed boolean
+---Whether the keyboard is grabbed.
+---@field keyboard_grabbed boolean
+---The pid of the client the event was sent to.
+---
+---This is the keyboard focus's client for keyboard events
+---and the pointer focus's client for everything else.
+---@field receiving_client_pid integer?
+
+---@param focus pinnacle.debug.v1.TraceInputResponse.FocusTarget?
+---@return pinnacle.debug.TracedFocus?
+local function traced_focus(focus)
+    if not focus then
+        return nil
+    end
+
+    ---@type pinnacle.debug.TracedFocus
+    return {
+        description = focus.description or "",
+        window = focus.window_id and require("pinnacle.window").handle.new(focus.window_id),
+        client_pid = focus.client_pid,
+    }
+end
+
+---Runs a function for every traced input event.
+---
+---Events are only sent while input tracing is on; see `trace_input`.
+---
+---#### Example
+---```lua
+---Debug.trace_input(true)
+---Debug.on_input_trace(function(trace)
+---    if trace.event == "PointerButton" and trace.pointer_focus then
+---        print("Click went to " .. trace.pointer_focus.description)
+---    end
+---end)
+---```
+---
+---@param on_trace fun(trace: pinnacle.debug.InputTrace)
+function debug.on_input_trace(on_trace)
+    local err = client:pinnacle_debug_v1_DebugService_TraceInput({}, function(response)
+        ---@type pinnacle.debug.InputTrace
+        local trace = {
+            event = response.event or "",
+            pointer_focus = traced_focus(response.pointer_focus),
+            keyboard_focus = traced_focus(response.keyboard_focus),
+            pointer_grabbed = response.pointer_grabbed or false,
+            keyboard_grabbed = response.keyboard_grabbed or false,
+            receiving_client_pid = response.receiving_client_pid,
+        }
+
+        on_trace(trace)
+    end)
+
+    if err then
+        require("pinnacle.log").error(err)
+    end
+end
+
+---@class pinnacle.debug.GpuStats
+---The number of live snapshot textures.
+---@field snapshot_textures integer
+---The total size of live snapshot textures, in bytes.
+---@field snapshot_texture_bytes integer
+---The number of cached cursor buffers.
+---@field cursor_buffers integer
+---The total size of cached cursor buffers, in bytes.
+---@field cursor_buffer_bytes integer
+---The number of snapshots dropped because a layout never came to replace them.
+---@field evicted_snapshots integer
+
+---Gets the amount of textures and buffers the compositor is holding onto.
+---
+---@return pinnacle.debug.GpuStats
+function debug.gpu_stats()
+    local response, err = client:pinnacle_debug_v1_DebugService_GetGpuStats({})
+
+    ---@type pinnacle.debug.GpuStats
+    local stats = {
+        snapshot_textures = response and response.snapshot_textures or 0,
+        snapshot_texture_bytes = response and response.snapshot_texture_bytes or 0,
+        cursor_buffers = response and response.cursor_buffers or 0,
+        cursor_buffer_bytes = response and response.cursor_buffer_bytes or 0,
+        evicted_snapshots = response and response.evicted_snapshots or 0,
+    }
+
+    return stats
+end
+
+---Replaces the filter that decides what Pinnacle logs to stdout.
+---
+---`filter` uses the same directives as the `RUST_LOG` environment variable,
+---so verbosity can be changed without restarting. The log file is unaffected.
+---
+---#### Example
+---```lua
+---local ok, err = Debug.set_log_filter("warn,pinnacle::render=trace")
+---if not ok then
+---    print("Failed to set log filter: " .. err)
+---end
+---```
+---
+---@param filter string
+---
+---@return boolean ok Whether the filter was set.
+---@return string? error Why the filter couldn't be set.
+function debug.set_log_filter(filter)
+    local response, err = client:pinnacle_debug_v1_DebugService_SetLogFilter({
+        filter = filter,
+    })
+
+    if err then
+        require("pinnacle.log").error(err)
+        return false, err
+    end
+
+    if response and response.error then
+        return false, response.error
+    end
+
+    return true, nil
+end
+
+---Runs a function for every line Pinnacle logs to stdout from now on.
+---
+---@param on_line fun(line: string)
+function debug.on_log(on_line)
+    local err = client:pinnacle_debug_v1_DebugService_TailLogs({}, function(response)
+        on_line(response.line or "")
+    end)
+
+    if err then
+        require("pinnacle.log").error(err)
+    end
+end
+
 return debug
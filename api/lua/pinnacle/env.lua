@@ -0,0 +1,57 @@
+-- This Source Code Form is subject to the terms of the Mozilla Public
+-- License, v. 2.0. If a copy of the MPL was not distributed with this
+-- file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+local log = require("pinnacle.log")
+local client = require("pinnacle.grpc.client").client
+
+---Session environment management.
+---
+---Variables set here reach Pinnacle's own environment, newly spawned processes,
+---and the systemd and D-Bus activation environments, so services started before
+---the change also see it when they're next activated.
+---
+---@class pinnacle.env
+local env = {}
+
+---Sets an environment variable for the whole session.
+---
+---Unlike `Process.set_env`, which only affects processes spawned by Pinnacle,
+---this also updates Pinnacle's own environment as well as the systemd and
+---D-Bus activation environments.
+---
+---#### Example
+---```lua
+---require("pinnacle.env").set("QT_QPA_PLATFORMTHEME", "qt6ct")
+---```
+---
+---@param key string The environment variable's key.
+---@param value string The environment variable's value.
+function env.set(key, value)
+    local _, err = client:pinnacle_process_v1_ProcessService_SetGlobalEnv({
+        key = key,
+        value = value,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
+---Unsets an environment variable for the whole session.
+---
+---This removes it from Pinnacle's own environment, newly spawned processes,
+---and the systemd and D-Bus activation environments.
+---
+---@param key string The environment variable's key.
+function env.unset(key)
+    local _, err = client:pinnacle_process_v1_ProcessService_SetGlobalEnv({
+        key = key,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
+return env
@@ -63,6 +63,8 @@ local grpc_client = {}
 ---@class grpc_client.Client
 ---@field conn grpc_client.h2.Conn
 ---@field loop grpc_client.cqueues.Loop
+---Seconds unary requests may take before they fail, or `nil` for the default timeouts.
+---@field deadline number?
 local Client = {}
 
 ---Create a new gRPC client that connects to the socket specified with `sock_args`.
@@ -116,6 +118,11 @@ end
 ---
 ---@param service string The desired service
 ---@param method string The desired method within the service
+---Methods that wait on the user, so they aren't subject to the request deadline.
+local interactive_methods = {
+    ["pinnacle.screenshot.v1.ScreenshotService/SelectRegion"] = true,
+}
+
 local function create_request_headers(service, method)
     local req_headers = headers.new()
     req_headers:append(":method", "POST")
@@ -257,20 +264,46 @@ function Client:unary_request(request_specifier, data)
 
     local body = encode(request_type, data)
 
-    local _, err = stream:write_headers(create_request_headers(service, method), false, 1)
+    local interactive = interactive_methods[service .. "/" .. method]
+
+    local deadline = not interactive and self.deadline and (monotime() + self.deadline)
+
+    ---Caps `timeout` to the time left until the deadline.
+    local function until_deadline(timeout)
+        if not deadline then
+            return timeout
+        end
+        return math.max(math.min(timeout, deadline - monotime()), 0)
+    end
+
+    ---Turns an error into a deadline error if the deadline passed.
+    local function request_error(err)
+        if deadline and monotime() >= deadline then
+            return "error from response: code = DEADLINE_EXCEEDED"
+        end
+        return tostring(err)
+    end
+
+    local _, err =
+        stream:write_headers(create_request_headers(service, method), false, until_deadline(1))
     if err then
-        return nil, tostring(err)
+        return nil, request_error(err)
     end
 
-    local _, err = stream:write_chunk(body, true, 1)
+    local _, err = stream:write_chunk(body, true, until_deadline(1))
     if err then
-        return nil, tostring(err)
+        return nil, request_error(err)
     end
 
-    local headers, err = stream:get_headers_with_retries(0.5, 5)
+    -- With a deadline, keep retrying until it passes instead of a fixed number of times.
+    -- Interactive methods keep retrying until the user is done.
+    local retries = interactive and math.huge
+        or deadline and math.max(math.ceil(self.deadline / 0.5), 1)
+        or 5
+    local headers, err = stream:get_headers_with_retries(until_deadline(0.5), retries)
 
     if err then
-        return nil, tostring(err)
+        return nil, request_error(err)
     end
 
     local grpc_status = headers:get("grpc-status")
@@ -287,13 +320,13 @@ function Client:unary_request(request_specifier, data)
         end
     end
 
-    local response_body, err = stream:get_next_chunk(1)
+    local response_body, err = stream:get_next_chunk(until_deadline(1))
     if err then
-        return nil, tostring(err)
+        return nil, request_error(err)
     end
 
     -- Trailers are not handled properly by lua-http 0.4, but this code will work, eventually
-    local trailers, err = stream:get_headers_with_retries(0.5, 5)
+    local trailers, err = stream:get_headers_with_retries(until_deadline(0.5), 5)
     if trailers then
         for name, value, never_index in trailers:each() do
             print(name, value, never_index)
@@ -518,6 +551,57 @@ local pinnacle_util_v1_Dir = {
     DIR_DOWN = 4,
 }
 
+---@enum pinnacle.output.v1.Transform
+local pinnacle_output_v1_Transform = {
+    TRANSFORM_UNSPECIFIED = 0,
+    TRANSFORM_NORMAL = 1,
+    TRANSFORM_90 = 2,
+    TRANSFORM_180 = 3,
+    TRANSFORM_270 = 4,
+    TRANSFORM_FLIPPED = 5,
+    TRANSFORM_FLIPPED_90 = 6,
+    TRANSFORM_FLIPPED_180 = 7,
+    TRANSFORM_FLIPPED_270 = 8,
+}
+
+---@enum pinnacle.output.v1.ScreenEdge
+local pinnacle_output_v1_ScreenEdge = {
+    SCREEN_EDGE_UNSPECIFIED = 0,
+    SCREEN_EDGE_TOP = 1,
+    SCREEN_EDGE_BOTTOM = 2,
+    SCREEN_EDGE_LEFT = 3,
+    SCREEN_EDGE_RIGHT = 4,
+    SCREEN_EDGE_TOP_LEFT = 5,
+    SCREEN_EDGE_TOP_RIGHT = 6,
+    SCREEN_EDGE_BOTTOM_LEFT = 7,
+    SCREEN_EDGE_BOTTOM_RIGHT = 8,
+}
+
+---@enum pinnacle.output.v1.Vrr
+local pinnacle_output_v1_Vrr = {
+    VRR_UNSPECIFIED = 0,
+    VRR_OFF = 1,
+    VRR_ALWAYS_ON = 2,
+    VRR_ON_DEMAND = 3,
+}
+
+---@enum pinnacle.output.v1.ColorFilter
+local pinnacle_output_v1_ColorFilter = {
+    COLOR_FILTER_UNSPECIFIED = 0,
+    COLOR_FILTER_NONE = 1,
+    COLOR_FILTER_INVERT = 2,
+    COLOR_FILTER_GRAYSCALE = 3,
+    COLOR_FILTER_DEUTERANOPIA_CORRECTION = 4,
+    COLOR_FILTER_PROTANOPIA_CORRECTION = 5,
+}
+
+---@enum pinnacle.output.v1.ClearableProperty
+local pinnacle_output_v1_ClearableProperty = {
+    CLEARABLE_PROPERTY_UNSPECIFIED = 0,
+    CLEARABLE_PROPERTY_LOC = 1,
+    CLEARABLE_PROPERTY_LOGICAL_SIZE = 2,
+}
+
 ---@enum pinnacle.input.v1.Modifier
 local pinnacle_input_v1_Modifier = {
     MODIFIER_UNSPECIFIED = 0,
@@ -536,6 +620,29 @@ local pinnacle_input_v1_Edge = {
     EDGE_RELEASE = 2,
 }
 
+---@enum pinnacle.input.v1.BindConflictPolicy
+local pinnacle_input_v1_BindConflictPolicy = {
+    BIND_CONFLICT_POLICY_UNSPECIFIED = 0,
+    BIND_CONFLICT_POLICY_KEEP = 1,
+    BIND_CONFLICT_POLICY_OVERRIDE = 2,
+    BIND_CONFLICT_POLICY_SHADOW = 3,
+}
+
+---@enum pinnacle.input.v1.PointerCrossing
+local pinnacle_input_v1_PointerCrossing = {
+    POINTER_CROSSING_UNSPECIFIED = 0,
+    POINTER_CROSSING_LOGICAL = 1,
+    POINTER_CROSSING_PHYSICAL = 2,
+}
+
+---@enum pinnacle.input.v1.EdgeAction
+local pinnacle_input_v1_EdgeAction = {
+    EDGE_ACTION_UNSPECIFIED = 0,
+    EDGE_ACTION_NONE = 1,
+    EDGE_ACTION_CALLBACK = 2,
+    EDGE_ACTION_SNAP_WINDOW = 3,
+}
+
 ---@enum pinnacle.input.v1.ClickMethod
 local pinnacle_input_v1_ClickMethod = {
     CLICK_METHOD_UNSPECIFIED = 0,
@@ -593,25 +700,11 @@ local pinnacle_layout_v1_FlexDir = {
     FLEX_DIR_COLUMN = 2,
 }
 
----@enum pinnacle.output.v1.Transform
-local pinnacle_output_v1_Transform = {
-    TRANSFORM_UNSPECIFIED = 0,
-    TRANSFORM_NORMAL = 1,
-    TRANSFORM_90 = 2,
-    TRANSFORM_180 = 3,
-    TRANSFORM_270 = 4,
-    TRANSFORM_FLIPPED = 5,
-    TRANSFORM_FLIPPED_90 = 6,
-    TRANSFORM_FLIPPED_180 = 7,
-    TRANSFORM_FLIPPED_270 = 8,
-}
-
----@enum pinnacle.output.v1.Vrr
-local pinnacle_output_v1_Vrr = {
-    VRR_UNSPECIFIED = 0,
-    VRR_OFF = 1,
-    VRR_ALWAYS_ON = 2,
-    VRR_ON_DEMAND = 3,
+---@enum pinnacle.nightlight.v1.SunEvent
+local pinnacle_nightlight_v1_SunEvent = {
+    SUN_EVENT_UNSPECIFIED = 0,
+    SUN_EVENT_SUNRISE = 1,
+    SUN_EVENT_SUNSET = 2,
 }
 
 ---@enum pinnacle.render.v1.Filter
@@ -621,6 +714,20 @@ local pinnacle_render_v1_Filter = {
     FILTER_NEAREST_NEIGHBOR = 2,
 }
 
+---@enum pinnacle.render.v1.InactiveEffectTarget
+local pinnacle_render_v1_InactiveEffectTarget = {
+    INACTIVE_EFFECT_TARGET_UNSPECIFIED = 0,
+    INACTIVE_EFFECT_TARGET_UNFOCUSED_OUTPUTS = 1,
+    INACTIVE_EFFECT_TARGET_UNFOCUSED_WINDOWS = 2,
+}
+
+---@enum pinnacle.window.v1.FindMode
+local pinnacle_window_v1_FindMode = {
+    FIND_MODE_UNSPECIFIED = 0,
+    FIND_MODE_FUZZY = 1,
+    FIND_MODE_REGEX = 2,
+}
+
 ---@enum pinnacle.window.v1.LayoutMode
 local pinnacle_window_v1_LayoutMode = {
     LAYOUT_MODE_UNSPECIFIED = 0,
@@ -630,6 +737,31 @@ local pinnacle_window_v1_LayoutMode = {
     LAYOUT_MODE_MAXIMIZED = 4,
 }
 
+---@enum pinnacle.window.v1.WindowLayer
+local pinnacle_window_v1_WindowLayer = {
+    WINDOW_LAYER_UNSPECIFIED = 0,
+    WINDOW_LAYER_BELOW = 1,
+    WINDOW_LAYER_NORMAL = 2,
+    WINDOW_LAYER_ABOVE = 3,
+}
+
+---@enum pinnacle.window.v1.ClearableProperty
+local pinnacle_window_v1_ClearableProperty = {
+    CLEARABLE_PROPERTY_UNSPECIFIED = 0,
+    CLEARABLE_PROPERTY_LOC = 1,
+    CLEARABLE_PROPERTY_SIZE = 2,
+}
+
+---@enum pinnacle.window.v1.FocusReason
+local pinnacle_window_v1_FocusReason = {
+    FOCUS_REASON_UNSPECIFIED = 0,
+    FOCUS_REASON_POINTER = 1,
+    FOCUS_REASON_KEYBIND = 2,
+    FOCUS_REASON_ACTIVATION = 3,
+    FOCUS_REASON_MAP = 4,
+    FOCUS_REASON_UNMAP_FALLBACK = 5,
+}
+
 ---@enum pinnacle.window.v1.DecorationMode
 local pinnacle_window_v1_DecorationMode = {
     DECORATION_MODE_UNSPECIFIED = 0,
@@ -637,6 +769,67 @@ local pinnacle_window_v1_DecorationMode = {
     DECORATION_MODE_SERVER_SIDE = 2,
 }
 
+---@enum pinnacle.window.v1.SnapPreset
+local pinnacle_window_v1_SnapPreset = {
+    SNAP_PRESET_UNSPECIFIED = 0,
+    SNAP_PRESET_LEFT_HALF = 1,
+    SNAP_PRESET_RIGHT_HALF = 2,
+    SNAP_PRESET_TOP_HALF = 3,
+    SNAP_PRESET_BOTTOM_HALF = 4,
+    SNAP_PRESET_LEFT_THIRD = 5,
+    SNAP_PRESET_CENTER_THIRD = 6,
+    SNAP_PRESET_RIGHT_THIRD = 7,
+    SNAP_PRESET_LEFT_TWO_THIRDS = 8,
+    SNAP_PRESET_RIGHT_TWO_THIRDS = 9,
+    SNAP_PRESET_TOP_LEFT_QUARTER = 10,
+    SNAP_PRESET_TOP_RIGHT_QUARTER = 11,
+    SNAP_PRESET_BOTTOM_LEFT_QUARTER = 12,
+    SNAP_PRESET_BOTTOM_RIGHT_QUARTER = 13,
+}
+
+---@enum pinnacle.window.v1.MaximizeRequestPolicy
+local pinnacle_window_v1_MaximizeRequestPolicy = {
+    MAXIMIZE_REQUEST_POLICY_UNSPECIFIED = 0,
+    MAXIMIZE_REQUEST_POLICY_HONOR = 1,
+    MAXIMIZE_REQUEST_POLICY_IGNORE = 2,
+    MAXIMIZE_REQUEST_POLICY_FULLSCREEN_WITHIN_TILE = 3,
+    MAXIMIZE_REQUEST_POLICY_FLOAT_AND_MAXIMIZE = 4,
+}
+
+---@enum pinnacle.window.v1.TagInheritance
+local pinnacle_window_v1_TagInheritance = {
+    TAG_INHERITANCE_UNSPECIFIED = 0,
+    TAG_INHERITANCE_ACTIVE_TAGS = 1,
+    TAG_INHERITANCE_FIRST_ACTIVE_TAG = 2,
+    TAG_INHERITANCE_PARENT = 3,
+}
+
+---@enum pinnacle.window.v1.DialogPolicy
+local pinnacle_window_v1_DialogPolicy = {
+    DIALOG_POLICY_UNSPECIFIED = 0,
+    DIALOG_POLICY_INDEPENDENT = 1,
+    DIALOG_POLICY_ATTACH_TO_PARENT = 2,
+}
+
+---@enum pinnacle.window.v1.WindowRuleStrategy
+local pinnacle_window_v1_WindowRuleStrategy = {
+    WINDOW_RULE_STRATEGY_UNSPECIFIED = 0,
+    WINDOW_RULE_STRATEGY_MERGE = 1,
+    WINDOW_RULE_STRATEGY_LAST_MATCH_WINS = 2,
+}
+
+---@enum pinnacle.window.v1.WindowRuleProperty
+local pinnacle_window_v1_WindowRuleProperty = {
+    WINDOW_RULE_PROPERTY_UNSPECIFIED = 0,
+    WINDOW_RULE_PROPERTY_LAYOUT_MODE = 1,
+    WINDOW_RULE_PROPERTY_FLOATING_GEOMETRY = 2,
+    WINDOW_RULE_PROPERTY_DECORATION_MODE = 3,
+    WINDOW_RULE_PROPERTY_TAGS = 4,
+    WINDOW_RULE_PROPERTY_MAXIMIZE_REQUEST_POLICY = 5,
+    WINDOW_RULE_PROPERTY_SIZE_CONSTRAINTS = 6,
+    WINDOW_RULE_PROPERTY_BORDER_STYLE = 7,
+}
+
 ---@enum pinnacle.signal.v1.StreamControl
 local pinnacle_signal_v1_StreamControl = {
     STREAM_CONTROL_UNSPECIFIED = 0,
@@ -644,6 +837,21 @@ local pinnacle_signal_v1_StreamControl = {
     STREAM_CONTROL_DISCONNECT = 2,
 }
 
+---@enum pinnacle.tag.v1.EffectRuleTarget
+local pinnacle_tag_v1_EffectRuleTarget = {
+    EFFECT_RULE_TARGET_UNSPECIFIED = 0,
+    EFFECT_RULE_TARGET_WINDOWS_ON_TAG = 1,
+    EFFECT_RULE_TARGET_OTHER_WINDOWS = 2,
+}
+
+---@enum pinnacle.tag.v1.EmptyOutputPolicy
+local pinnacle_tag_v1_EmptyOutputPolicy = {
+    EMPTY_OUTPUT_POLICY_UNSPECIFIED = 0,
+    EMPTY_OUTPUT_POLICY_BLANK = 1,
+    EMPTY_OUTPUT_POLICY_REACTIVATE_LAST = 2,
+    EMPTY_OUTPUT_POLICY_FALLBACK = 3,
+}
+
 ---@enum pinnacle.v1.Backend
 local pinnacle_v1_Backend = {
     BACKEND_UNSPECIFIED = 0,
@@ -666,6 +874,12 @@ local pinnacle_v1_Backend = {
 ---@field loc pinnacle.util.v1.Point?
 ---@field size pinnacle.util.v1.Size?
 
+---@class pinnacle.util.v1.Color
+---@field red number?
+---@field green number?
+---@field blue number?
+---@field alpha number?
+
 ---@class pinnacle.debug.v1.SetDamageVisualizationRequest
 ---@field set_or_toggle pinnacle.util.v1.SetOrToggle?
 
@@ -678,245 +892,162 @@ local pinnacle_v1_Backend = {
 ---@class pinnacle.debug.v1.SetProcessPipingRequest
 ---@field set_or_toggle pinnacle.util.v1.SetOrToggle?
 
----@class pinnacle.input.v1.Bind
----@field mods pinnacle.input.v1.Modifier[]?
----@field ignore_mods pinnacle.input.v1.Modifier[]?
----@field layer_name string?
----@field properties pinnacle.input.v1.BindProperties?
----@field key pinnacle.input.v1.Keybind?
----@field mouse pinnacle.input.v1.Mousebind?
+---@class pinnacle.debug.v1.SetInputTracingRequest
+---@field set_or_toggle pinnacle.util.v1.SetOrToggle?
 
----@class pinnacle.input.v1.BindRequest
----@field bind pinnacle.input.v1.Bind?
+---@class pinnacle.debug.v1.TraceInputRequest
 
----@class pinnacle.input.v1.BindResponse
----@field bind_id integer?
+---@class pinnacle.debug.v1.TraceInputResponse
+---@field event string?
+---@field pointer_focus pinnacle.debug.v1.TraceInputResponse.FocusTarget?
+---@field keyboard_focus pinnacle.debug.v1.TraceInputResponse.FocusTarget?
+---@field pointer_grabbed boolean?
+---@field keyboard_grabbed boolean?
+---@field receiving_client_pid integer?
 
----@class pinnacle.input.v1.BindProperties
----@field group string?
+---@class pinnacle.debug.v1.TraceInputResponse.FocusTarget
 ---@field description string?
----@field quit boolean?
----@field reload_config boolean?
----@field allow_when_locked boolean?
+---@field window_id integer?
+---@field client_pid integer?
 
----@class pinnacle.input.v1.SetBindPropertiesRequest
----@field bind_id integer?
----@field properties pinnacle.input.v1.BindProperties?
+---@class pinnacle.debug.v1.GetGpuStatsRequest
 
----@class pinnacle.input.v1.Keybind
----@field key_code integer?
----@field xkb_name string?
+---@class pinnacle.debug.v1.GetGpuStatsResponse
+---@field snapshot_textures integer?
+---@field snapshot_texture_bytes integer?
+---@field cursor_buffers integer?
+---@field cursor_buffer_bytes integer?
+---@field evicted_snapshots integer?
 
----@class pinnacle.input.v1.KeybindStreamRequest
----@field bind_id integer?
+---@class pinnacle.debug.v1.SetLogFilterRequest
+---@field filter string?
 
----@class pinnacle.input.v1.KeybindStreamResponse
----@field edge pinnacle.input.v1.Edge?
+---@class pinnacle.debug.v1.SetLogFilterResponse
+---@field error string?
 
----@class pinnacle.input.v1.KeybindOnPressRequest
----@field bind_id integer?
+---@class pinnacle.debug.v1.TailLogsRequest
 
----@class pinnacle.input.v1.Mousebind
----@field button integer?
+---@class pinnacle.debug.v1.TailLogsResponse
+---@field line string?
 
----@class pinnacle.input.v1.MousebindStreamRequest
----@field bind_id integer?
+---@class pinnacle.idle.v1.Shader
+---@field source string?
 
----@class pinnacle.input.v1.MousebindStreamResponse
----@field edge pinnacle.input.v1.Edge?
+---@class pinnacle.idle.v1.Slideshow
+---@field image_paths string[]?
+---@field interval_millis integer?
 
----@class pinnacle.input.v1.MousebindOnPressRequest
----@field bind_id integer?
+---@class pinnacle.idle.v1.Screensaver
+---@field timeout_millis integer?
+---@field shader pinnacle.idle.v1.Shader?
+---@field slideshow pinnacle.idle.v1.Slideshow?
 
----@class pinnacle.input.v1.GetBindInfosRequest
+---@class pinnacle.idle.v1.SetScreensaverRequest
+---@field screensaver pinnacle.idle.v1.Screensaver?
 
----@class pinnacle.input.v1.GetBindInfosResponse
----@field bind_infos pinnacle.input.v1.BindInfo[]?
+---@class pinnacle.idle.v1.SetIdleTimeoutRequest
+---@field timeout_millis integer?
+---@field power_off_outputs boolean?
 
----@class pinnacle.input.v1.BindInfo
----@field bind_id integer?
----@field bind pinnacle.input.v1.Bind?
+---@class pinnacle.output.v1.SetLocRequest
+---@field output_name string?
+---@field x integer?
+---@field y integer?
 
----@class pinnacle.input.v1.GetBindLayerStackRequest
+---@class pinnacle.output.v1.SetModeRequest
+---@field output_name string?
+---@field size pinnacle.util.v1.Size?
+---@field refresh_rate_mhz integer?
+---@field custom boolean?
+---@field revert_after_ms integer?
 
----@class pinnacle.input.v1.GetBindLayerStackResponse
----@field layer_names string[]?
+---@class pinnacle.output.v1.Modeline
+---@field clock number?
+---@field hdisplay integer?
+---@field hsync_start integer?
+---@field hsync_end integer?
+---@field htotal integer?
+---@field vdisplay integer?
+---@field vsync_start integer?
+---@field vsync_end integer?
+---@field vtotal integer?
+---@field hsync boolean?
+---@field vsync boolean?
 
----@class pinnacle.input.v1.EnterBindLayerRequest
----@field layer_name string?
+---@class pinnacle.output.v1.SetModelineRequest
+---@field output_name string?
+---@field modeline pinnacle.output.v1.Modeline?
+---@field revert_after_ms integer?
 
----@class pinnacle.input.v1.SetXkbConfigRequest
----@field rules string?
----@field variant string?
----@field layout string?
----@field model string?
----@field options string?
+---@class pinnacle.output.v1.ConfirmModeChangeRequest
+---@field output_name string?
 
----@class pinnacle.input.v1.SetRepeatRateRequest
----@field rate integer?
----@field delay integer?
+---@class pinnacle.output.v1.ConfirmModeChangeResponse
+---@field confirmed boolean?
 
----@class pinnacle.input.v1.SetXkbKeymapRequest
----@field keymap string?
+---@class pinnacle.output.v1.SetScaleRequest
+---@field output_name string?
+---@field scale number?
+---@field abs_or_rel pinnacle.util.v1.AbsOrRel?
 
----@class pinnacle.input.v1.SwitchXkbLayoutRequest
----@field next google.protobuf.Empty?
----@field prev google.protobuf.Empty?
----@field index integer?
+---@class pinnacle.output.v1.SetTransformRequest
+---@field output_name string?
+---@field transform pinnacle.output.v1.Transform?
 
----@class pinnacle.input.v1.SetXcursorRequest
+---@class pinnacle.output.v1.SetPoweredRequest
+---@field output_name string?
+---@field set_or_toggle pinnacle.util.v1.SetOrToggle?
+
+---@class pinnacle.output.v1.SetVrrRequest
+---@field output_name string?
+---@field vrr pinnacle.output.v1.Vrr?
+
+---@class pinnacle.output.v1.SetVrrResponse
+
+---@class pinnacle.output.v1.SetXcursorRequest
+---@field output_name string?
 ---@field theme string?
 ---@field size integer?
 
----@class pinnacle.input.v1.CalibrationMatrix
----@field matrix number[]?
+---@class pinnacle.output.v1.SetXcursorResponse
 
----@class pinnacle.input.v1.GetDevicesRequest
+---@class pinnacle.output.v1.SetHotplugSettleDelayRequest
+---@field millis integer?
 
----@class pinnacle.input.v1.GetDevicesResponse
----@field device_sysnames string[]?
+---@class pinnacle.output.v1.SetHotplugSettleDelayResponse
 
----@class pinnacle.input.v1.GetDeviceCapabilitiesRequest
----@field device_sysname string?
+---@class pinnacle.output.v1.SetColorFilterRequest
+---@field output_name string?
+---@field filter pinnacle.output.v1.ColorFilter?
 
----@class pinnacle.input.v1.GetDeviceCapabilitiesResponse
----@field keyboard boolean?
----@field pointer boolean?
----@field touch boolean?
----@field tablet_tool boolean?
----@field tablet_pad boolean?
----@field gesture boolean?
----@field switch boolean?
+---@class pinnacle.output.v1.SetColorFilterResponse
 
----@class pinnacle.input.v1.GetDeviceInfoRequest
----@field device_sysname string?
+---@class pinnacle.output.v1.SetAutoRotateRequest
+---@field output_name string?
+---@field auto_rotate boolean?
 
----@class pinnacle.input.v1.GetDeviceInfoResponse
----@field name string?
----@field product_id integer?
----@field vendor_id integer?
+---@class pinnacle.output.v1.SetAutoRotateResponse
 
----@class pinnacle.input.v1.GetDeviceTypeRequest
----@field device_sysname string?
+---@class pinnacle.output.v1.SetHdrRequest
+---@field output_name string?
+---@field hdr boolean?
 
----@class pinnacle.input.v1.GetDeviceTypeResponse
----@field device_type pinnacle.input.v1.DeviceType?
+---@class pinnacle.output.v1.SetHdrResponse
+---@field error string?
 
----@class pinnacle.input.v1.SetDeviceLibinputSettingRequest
----@field device_sysname string?
----@field accel_profile pinnacle.input.v1.AccelProfile?
----@field accel_speed number?
----@field calibration_matrix pinnacle.input.v1.CalibrationMatrix?
----@field click_method pinnacle.input.v1.ClickMethod?
----@field disable_while_typing boolean?
----@field left_handed boolean?
----@field middle_emulation boolean?
----@field rotation_angle integer?
----@field scroll_button integer?
----@field scroll_button_lock boolean?
----@field scroll_method pinnacle.input.v1.ScrollMethod?
----@field natural_scroll boolean?
----@field tap_button_map pinnacle.input.v1.TapButtonMap?
----@field tap_drag boolean?
----@field tap_drag_lock boolean?
----@field tap boolean?
----@field send_events_mode pinnacle.input.v1.SendEventsMode?
+---@class pinnacle.output.v1.SetColorDepthRequest
+---@field output_name string?
+---@field color_depth integer?
 
----@class pinnacle.input.v1.SetDeviceMapTargetRequest
----@field device_sysname string?
----@field region pinnacle.util.v1.Rect?
+---@class pinnacle.output.v1.SetColorDepthResponse
+---@field error string?
+
+---@class pinnacle.output.v1.SetSrgbEmulationRequest
 ---@field output_name string?
+---@field srgb_emulation boolean?
 
----@class pinnacle.layout.v1.Gaps
----@field left number?
----@field right number?
----@field top number?
----@field bottom number?
-
----@class pinnacle.layout.v1.LayoutNode
----@field label string?
----@field traversal_index integer?
----@field traversal_overrides pinnacle.layout.v1.LayoutNode.TraversalOverridesEntry[]?
----@field style pinnacle.layout.v1.NodeStyle?
----@field children pinnacle.layout.v1.LayoutNode[]?
-
----@class pinnacle.layout.v1.LayoutNode.TraversalOverridesEntry
----@field key integer?
----@field value pinnacle.layout.v1.TraversalOverrides?
-
----@class pinnacle.layout.v1.TraversalOverrides
----@field overrides integer[]?
-
----@class pinnacle.layout.v1.NodeStyle
----@field flex_dir pinnacle.layout.v1.FlexDir?
----@field size_proportion number?
----@field gaps pinnacle.layout.v1.Gaps?
-
----@class pinnacle.layout.v1.LayoutRequest
----@field tree_response pinnacle.layout.v1.LayoutRequest.TreeResponse?
----@field force_layout pinnacle.layout.v1.LayoutRequest.ForceLayout?
-
----@class pinnacle.layout.v1.LayoutRequest.TreeResponse
----@field request_id integer?
----@field tree_id integer?
----@field root_node pinnacle.layout.v1.LayoutNode?
----@field output_name string?
-
----@class pinnacle.layout.v1.LayoutRequest.ForceLayout
----@field output_name string?
-
----@class pinnacle.layout.v1.LayoutResponse
----@field request_id integer?
----@field output_name string?
----@field window_count integer?
----@field tag_ids integer[]?
-
----@class pinnacle.output.v1.SetLocRequest
----@field output_name string?
----@field x integer?
----@field y integer?
-
----@class pinnacle.output.v1.SetModeRequest
----@field output_name string?
----@field size pinnacle.util.v1.Size?
----@field refresh_rate_mhz integer?
----@field custom boolean?
-
----@class pinnacle.output.v1.Modeline
----@field clock number?
----@field hdisplay integer?
----@field hsync_start integer?
----@field hsync_end integer?
----@field htotal integer?
----@field vdisplay integer?
----@field vsync_start integer?
----@field vsync_end integer?
----@field vtotal integer?
----@field hsync boolean?
----@field vsync boolean?
-
----@class pinnacle.output.v1.SetModelineRequest
----@field output_name string?
----@field modeline pinnacle.output.v1.Modeline?
-
----@class pinnacle.output.v1.SetScaleRequest
----@field output_name string?
----@field scale number?
----@field abs_or_rel pinnacle.util.v1.AbsOrRel?
-
----@class pinnacle.output.v1.SetTransformRequest
----@field output_name string?
----@field transform pinnacle.output.v1.Transform?
-
----@class pinnacle.output.v1.SetPoweredRequest
----@field output_name string?
----@field set_or_toggle pinnacle.util.v1.SetOrToggle?
-
----@class pinnacle.output.v1.SetVrrRequest
----@field output_name string?
----@field vrr pinnacle.output.v1.Vrr?
-
----@class pinnacle.output.v1.SetVrrResponse
+---@class pinnacle.output.v1.SetSrgbEmulationResponse
+---@field error string?
 
 ---@class pinnacle.output.v1.FocusRequest
 ---@field output_name string?
@@ -931,10 +1062,34 @@ local pinnacle_v1_Backend = {
 ---@class pinnacle.output.v1.GetInfoRequest
 ---@field output_name string?
 
+---@class pinnacle.output.v1.Chromaticity
+---@field x number?
+---@field y number?
+
+---@class pinnacle.output.v1.ColorPrimaries
+---@field red pinnacle.output.v1.Chromaticity?
+---@field green pinnacle.output.v1.Chromaticity?
+---@field blue pinnacle.output.v1.Chromaticity?
+---@field white pinnacle.output.v1.Chromaticity?
+
+---@class pinnacle.output.v1.HdrMetadata
+---@field max_luminance number?
+---@field max_frame_avg_luminance number?
+---@field min_luminance number?
+---@field pq boolean?
+---@field hlg boolean?
+
+---@class pinnacle.output.v1.Edid
+---@field manufacture_year integer?
+---@field manufacture_week integer?
+---@field color_primaries pinnacle.output.v1.ColorPrimaries?
+---@field hdr_metadata pinnacle.output.v1.HdrMetadata?
+
 ---@class pinnacle.output.v1.GetInfoResponse
 ---@field make string?
 ---@field model string?
 ---@field serial string?
+---@field edid pinnacle.output.v1.Edid?
 
 ---@class pinnacle.output.v1.GetLocRequest
 ---@field output_name string?
@@ -1002,12 +1157,45 @@ local pinnacle_v1_Backend = {
 ---@class pinnacle.output.v1.GetPoweredResponse
 ---@field powered boolean?
 
+---@class pinnacle.output.v1.GetHdrRequest
+---@field output_name string?
+
+---@class pinnacle.output.v1.GetHdrResponse
+---@field capable boolean?
+---@field enabled boolean?
+
+---@class pinnacle.output.v1.GetColorDepthRequest
+---@field output_name string?
+
+---@class pinnacle.output.v1.GetColorDepthResponse
+---@field color_depth integer?
+
+---@class pinnacle.output.v1.GetSrgbEmulationRequest
+---@field output_name string?
+
+---@class pinnacle.output.v1.GetSrgbEmulationResponse
+---@field srgb_emulation boolean?
+
+---@class pinnacle.output.v1.GetRenderStatsRequest
+---@field output_name string?
+
+---@class pinnacle.output.v1.GetRenderStatsResponse
+---@field frames_rendered integer?
+---@field frames_skipped_no_damage integer?
+---@field frames_skipped_suspended integer?
+
 ---@class pinnacle.output.v1.GetFocusStackWindowIdsRequest
 ---@field output_name string?
 
 ---@class pinnacle.output.v1.GetFocusStackWindowIdsResponse
 ---@field window_ids integer[]?
 
+---@class pinnacle.output.v1.GetStackingOrderRequest
+---@field output_name string?
+
+---@class pinnacle.output.v1.GetStackingOrderResponse
+---@field window_ids integer[]?
+
 ---@class pinnacle.output.v1.GetOutputsInDirRequest
 ---@field output_name string?
 ---@field dir pinnacle.util.v1.Dir?
@@ -1015,481 +1203,1374 @@ local pinnacle_v1_Backend = {
 ---@class pinnacle.output.v1.GetOutputsInDirResponse
 ---@field output_names string[]?
 
----@class pinnacle.process.v1.SpawnRequest
----@field cmd string[]?
----@field unique boolean?
----@field once boolean?
----@field shell_cmd string[]?
----@field envs pinnacle.process.v1.SpawnRequest.EnvsEntry[]?
----@field pipe_stdin boolean?
----@field pipe_stdout boolean?
----@field pipe_stderr boolean?
+---@class pinnacle.output.v1.WatchPropertiesRequest
+---@field output_name string?
 
----@class pinnacle.process.v1.SpawnRequest.EnvsEntry
----@field key string?
----@field value string?
+---@class pinnacle.output.v1.WatchPropertiesResponse
+---@field loc pinnacle.util.v1.Point?
+---@field logical_size pinnacle.util.v1.Size?
+---@field scale number?
+---@field focused boolean?
+---@field enabled boolean?
+---@field powered boolean?
+---@field cleared pinnacle.output.v1.ClearableProperty[]?
 
----@class pinnacle.process.v1.SpawnData
----@field pid integer?
----@field fd_socket_path string?
----@field has_stdin boolean?
----@field has_stdout boolean?
----@field has_stderr boolean?
+---@class pinnacle.input.v1.Bind
+---@field mods pinnacle.input.v1.Modifier[]?
+---@field ignore_mods pinnacle.input.v1.Modifier[]?
+---@field layer_name string?
+---@field properties pinnacle.input.v1.BindProperties?
+---@field key pinnacle.input.v1.Keybind?
+---@field mouse pinnacle.input.v1.Mousebind?
 
----@class pinnacle.process.v1.SpawnResponse
----@field spawn_data pinnacle.process.v1.SpawnData?
+---@class pinnacle.input.v1.BindRequest
+---@field bind pinnacle.input.v1.Bind?
 
----@class pinnacle.process.v1.WaitOnSpawnRequest
----@field pid integer?
+---@class pinnacle.input.v1.BindResponse
+---@field bind_id integer?
+---@field conflicts pinnacle.input.v1.BindConflict[]?
 
----@class pinnacle.process.v1.WaitOnSpawnResponse
----@field exit_code integer?
----@field exit_msg string?
+---@class pinnacle.input.v1.BindConflict
+---@field bind_id integer?
+---@field group string?
+---@field description string?
 
----@class pinnacle.process.v1.SetEnvRequest
----@field key string?
----@field value string?
+---@class pinnacle.input.v1.BindProperties
+---@field group string?
+---@field description string?
+---@field quit boolean?
+---@field reload_config boolean?
+---@field allow_when_locked boolean?
 
----@class pinnacle.render.v1.SetUpscaleFilterRequest
----@field filter pinnacle.render.v1.Filter?
+---@class pinnacle.input.v1.SetBindPropertiesRequest
+---@field bind_id integer?
+---@field properties pinnacle.input.v1.BindProperties?
 
----@class pinnacle.render.v1.SetDownscaleFilterRequest
----@field filter pinnacle.render.v1.Filter?
+---@class pinnacle.input.v1.Keybind
+---@field key_code integer?
+---@field xkb_name string?
 
----@class pinnacle.window.v1.GetRequest
+---@class pinnacle.input.v1.KeybindStreamRequest
+---@field bind_id integer?
 
----@class pinnacle.window.v1.GetResponse
----@field window_ids integer[]?
+---@class pinnacle.input.v1.KeybindStreamResponse
+---@field edge pinnacle.input.v1.Edge?
 
----@class pinnacle.window.v1.GetAppIdRequest
----@field window_id integer?
+---@class pinnacle.input.v1.KeybindOnPressRequest
+---@field bind_id integer?
 
----@class pinnacle.window.v1.GetAppIdResponse
----@field app_id string?
+---@class pinnacle.input.v1.Mousebind
+---@field button integer?
 
----@class pinnacle.window.v1.GetTitleRequest
----@field window_id integer?
+---@class pinnacle.input.v1.MousebindStreamRequest
+---@field bind_id integer?
 
----@class pinnacle.window.v1.GetTitleResponse
----@field title string?
+---@class pinnacle.input.v1.MousebindStreamResponse
+---@field edge pinnacle.input.v1.Edge?
 
----@class pinnacle.window.v1.GetLocRequest
----@field window_id integer?
+---@class pinnacle.input.v1.MousebindOnPressRequest
+---@field bind_id integer?
 
----@class pinnacle.window.v1.GetLocResponse
----@field loc pinnacle.util.v1.Point?
+---@class pinnacle.input.v1.GetBindInfosRequest
 
----@class pinnacle.window.v1.GetSizeRequest
----@field window_id integer?
+---@class pinnacle.input.v1.GetBindInfosResponse
+---@field bind_infos pinnacle.input.v1.BindInfo[]?
 
----@class pinnacle.window.v1.GetSizeResponse
----@field size pinnacle.util.v1.Size?
+---@class pinnacle.input.v1.BindInfo
+---@field bind_id integer?
+---@field bind pinnacle.input.v1.Bind?
 
----@class pinnacle.window.v1.GetFocusedRequest
----@field window_id integer?
+---@class pinnacle.input.v1.GetBindLayerStackRequest
 
----@class pinnacle.window.v1.GetFocusedResponse
----@field focused boolean?
+---@class pinnacle.input.v1.GetBindLayerStackResponse
+---@field layer_names string[]?
 
----@class pinnacle.window.v1.GetLayoutModeRequest
----@field window_id integer?
+---@class pinnacle.input.v1.EnterBindLayerRequest
+---@field layer_name string?
 
----@class pinnacle.window.v1.GetLayoutModeResponse
----@field layout_mode pinnacle.window.v1.LayoutMode?
+---@class pinnacle.input.v1.SetBindLayerConflictPolicyRequest
+---@field layer_name string?
+---@field policy pinnacle.input.v1.BindConflictPolicy?
 
----@class pinnacle.window.v1.GetTagIdsRequest
----@field window_id integer?
+---@class pinnacle.input.v1.SetXkbConfigRequest
+---@field rules string?
+---@field variant string?
+---@field layout string?
+---@field model string?
+---@field options string?
 
----@class pinnacle.window.v1.GetTagIdsResponse
----@field tag_ids integer[]?
+---@class pinnacle.input.v1.SetRepeatRateRequest
+---@field rate integer?
+---@field delay integer?
 
----@class pinnacle.window.v1.GetWindowsInDirRequest
----@field window_id integer?
----@field dir pinnacle.util.v1.Dir?
+---@class pinnacle.input.v1.SetXkbKeymapRequest
+---@field keymap string?
 
----@class pinnacle.window.v1.GetWindowsInDirResponse
----@field window_ids integer[]?
+---@class pinnacle.input.v1.SwitchXkbLayoutRequest
+---@field next google.protobuf.Empty?
+---@field prev google.protobuf.Empty?
+---@field index integer?
 
----@class pinnacle.window.v1.GetForeignToplevelListIdentifierRequest
----@field window_id integer?
+---@class pinnacle.input.v1.SetStickyKeysRequest
+---@field enabled boolean?
 
----@class pinnacle.window.v1.GetForeignToplevelListIdentifierResponse
----@field identifier string?
+---@class pinnacle.input.v1.SetStickyKeysResponse
 
----@class pinnacle.window.v1.CloseRequest
----@field window_id integer?
+---@class pinnacle.input.v1.SetSlowKeysRequest
+---@field delay_millis integer?
 
----@class pinnacle.window.v1.SetGeometryRequest
----@field window_id integer?
----@field x integer?
----@field y integer?
----@field w integer?
----@field h integer?
+---@class pinnacle.input.v1.SetSlowKeysResponse
 
----@class pinnacle.window.v1.ResizeTileRequest
----@field window_id integer?
----@field left integer?
----@field right integer?
----@field top integer?
----@field bottom integer?
+---@class pinnacle.input.v1.SetHoldToMoveRequest
+---@field touch boolean?
+---@field button integer?
+---@field hold_millis integer?
 
----@class pinnacle.window.v1.SetFullscreenRequest
----@field window_id integer?
----@field set_or_toggle pinnacle.util.v1.SetOrToggle?
+---@class pinnacle.input.v1.SetHoldToMoveResponse
 
----@class pinnacle.window.v1.SetMaximizedRequest
----@field window_id integer?
----@field set_or_toggle pinnacle.util.v1.SetOrToggle?
+---@class pinnacle.input.v1.SetPointerCrossingRequest
+---@field crossing pinnacle.input.v1.PointerCrossing?
 
----@class pinnacle.window.v1.SetFloatingRequest
----@field window_id integer?
----@field set_or_toggle pinnacle.util.v1.SetOrToggle?
+---@class pinnacle.input.v1.SetEdgeActionRequest
+---@field edge pinnacle.output.v1.ScreenEdge?
+---@field action pinnacle.input.v1.EdgeAction?
+---@field output_name string?
 
----@class pinnacle.window.v1.SetFocusedRequest
----@field window_id integer?
----@field set_or_toggle pinnacle.util.v1.SetOrToggle?
+---@class pinnacle.input.v1.SetEdgeActionResponse
+---@field action_id integer?
 
----@class pinnacle.window.v1.SetDecorationModeRequest
----@field window_id integer?
----@field decoration_mode pinnacle.window.v1.DecorationMode?
+---@class pinnacle.input.v1.EdgeActionStreamRequest
+---@field action_id integer?
 
----@class pinnacle.window.v1.MoveToTagRequest
----@field window_id integer?
----@field tag_id integer?
+---@class pinnacle.input.v1.EdgeActionStreamResponse
+---@field output_name string?
 
----@class pinnacle.window.v1.SetTagRequest
----@field window_id integer?
----@field tag_id integer?
----@field set_or_toggle pinnacle.util.v1.SetOrToggle?
+---@class pinnacle.input.v1.RemapMacro
+---@field keys pinnacle.input.v1.Keybind[]?
 
----@class pinnacle.window.v1.SetTagsRequest
----@field window_id integer?
----@field tag_ids integer[]?
+---@class pinnacle.input.v1.RemapRequest
+---@field from pinnacle.input.v1.Keybind?
+---@field key pinnacle.input.v1.Keybind?
+---@field key_macro pinnacle.input.v1.RemapMacro?
+---@field device_sysname string?
 
----@class pinnacle.window.v1.SetTagsResponse
+---@class pinnacle.input.v1.RemapResponse
 
----@class pinnacle.window.v1.VrrDemand
----@field fullscreen boolean?
+---@class pinnacle.input.v1.ClearRemapsRequest
+---@field device_sysname string?
 
----@class pinnacle.window.v1.SetVrrDemandRequest
----@field window_id integer?
----@field vrr_demand pinnacle.window.v1.VrrDemand?
+---@class pinnacle.input.v1.ClearRemapsResponse
 
----@class pinnacle.window.v1.SetVrrDemandResponse
+---@class pinnacle.input.v1.SetXcursorRequest
+---@field theme string?
+---@field size integer?
 
----@class pinnacle.window.v1.MoveToOutputRequest
----@field window_id integer?
----@field output_name string?
+---@class pinnacle.input.v1.CalibrationMatrix
+---@field matrix number[]?
 
----@class pinnacle.window.v1.MoveToOutputResponse
+---@class pinnacle.input.v1.GetDevicesRequest
 
----@class pinnacle.window.v1.RaiseRequest
----@field window_id integer?
+---@class pinnacle.input.v1.GetDevicesResponse
+---@field device_sysnames string[]?
 
----@class pinnacle.window.v1.LowerRequest
----@field window_id integer?
+---@class pinnacle.input.v1.GetDeviceCapabilitiesRequest
+---@field device_sysname string?
 
----@class pinnacle.window.v1.LowerResponse
+---@class pinnacle.input.v1.GetDeviceCapabilitiesResponse
+---@field keyboard boolean?
+---@field pointer boolean?
+---@field touch boolean?
+---@field tablet_tool boolean?
+---@field tablet_pad boolean?
+---@field gesture boolean?
+---@field switch boolean?
 
----@class pinnacle.window.v1.MoveGrabRequest
----@field button integer?
+---@class pinnacle.input.v1.GetDeviceInfoRequest
+---@field device_sysname string?
 
----@class pinnacle.window.v1.ResizeGrabRequest
----@field button integer?
+---@class pinnacle.input.v1.GetDeviceInfoResponse
+---@field name string?
+---@field product_id integer?
+---@field vendor_id integer?
 
----@class pinnacle.window.v1.SwapRequest
----@field window_id integer?
----@field target_id integer?
+---@class pinnacle.input.v1.GetDeviceTypeRequest
+---@field device_sysname string?
 
----@class pinnacle.window.v1.SwapResponse
+---@class pinnacle.input.v1.GetDeviceTypeResponse
+---@field device_type pinnacle.input.v1.DeviceType?
 
----@class pinnacle.window.v1.WindowRuleRequest
----@field finished pinnacle.window.v1.WindowRuleRequest.Finished?
+---@class pinnacle.input.v1.SetDeviceLibinputSettingRequest
+---@field device_sysname string?
+---@field accel_profile pinnacle.input.v1.AccelProfile?
+---@field accel_speed number?
+---@field calibration_matrix pinnacle.input.v1.CalibrationMatrix?
+---@field click_method pinnacle.input.v1.ClickMethod?
+---@field disable_while_typing boolean?
+---@field left_handed boolean?
+---@field middle_emulation boolean?
+---@field rotation_angle integer?
+---@field scroll_button integer?
+---@field scroll_button_lock boolean?
+---@field scroll_method pinnacle.input.v1.ScrollMethod?
+---@field natural_scroll boolean?
+---@field tap_button_map pinnacle.input.v1.TapButtonMap?
+---@field tap_drag boolean?
+---@field tap_drag_lock boolean?
+---@field tap boolean?
+---@field send_events_mode pinnacle.input.v1.SendEventsMode?
 
----@class pinnacle.window.v1.WindowRuleRequest.Finished
----@field request_id integer?
+---@class pinnacle.input.v1.SetDeviceMapTargetRequest
+---@field device_sysname string?
+---@field region pinnacle.util.v1.Rect?
+---@field output_name string?
 
----@class pinnacle.window.v1.WindowRuleResponse
----@field new_window pinnacle.window.v1.WindowRuleResponse.NewWindowRequest?
+---@class pinnacle.layout.v1.Gaps
+---@field left number?
+---@field right number?
+---@field top number?
+---@field bottom number?
 
----@class pinnacle.window.v1.WindowRuleResponse.NewWindowRequest
----@field request_id integer?
----@field window_id integer?
+---@class pinnacle.layout.v1.LayoutNode
+---@field label string?
+---@field traversal_index integer?
+---@field traversal_overrides pinnacle.layout.v1.LayoutNode.TraversalOverridesEntry[]?
+---@field style pinnacle.layout.v1.NodeStyle?
+---@field children pinnacle.layout.v1.LayoutNode[]?
 
----@class pinnacle.signal.v1.OutputConnectRequest
----@field control pinnacle.signal.v1.StreamControl?
+---@class pinnacle.layout.v1.LayoutNode.TraversalOverridesEntry
+---@field key integer?
+---@field value pinnacle.layout.v1.TraversalOverrides?
 
----@class pinnacle.signal.v1.OutputConnectResponse
----@field output_name string?
+---@class pinnacle.layout.v1.TraversalOverrides
+---@field overrides integer[]?
 
----@class pinnacle.signal.v1.OutputDisconnectRequest
----@field control pinnacle.signal.v1.StreamControl?
+---@class pinnacle.layout.v1.NodeStyle
+---@field flex_dir pinnacle.layout.v1.FlexDir?
+---@field size_proportion number?
+---@field gaps pinnacle.layout.v1.Gaps?
 
----@class pinnacle.signal.v1.OutputDisconnectResponse
+---@class pinnacle.layout.v1.LayoutRequest
+---@field tree_response pinnacle.layout.v1.LayoutRequest.TreeResponse?
+---@field force_layout pinnacle.layout.v1.LayoutRequest.ForceLayout?
+
+---@class pinnacle.layout.v1.LayoutRequest.TreeResponse
+---@field request_id integer?
+---@field tree_id integer?
+---@field root_node pinnacle.layout.v1.LayoutNode?
 ---@field output_name string?
 
----@class pinnacle.signal.v1.OutputResizeRequest
----@field control pinnacle.signal.v1.StreamControl?
+---@class pinnacle.layout.v1.LayoutRequest.ForceLayout
+---@field output_name string?
 
----@class pinnacle.signal.v1.OutputResizeResponse
+---@class pinnacle.layout.v1.LayoutResponse
+---@field request_id integer?
 ---@field output_name string?
----@field logical_width integer?
----@field logical_height integer?
+---@field window_count integer?
+---@field tag_ids integer[]?
 
----@class pinnacle.signal.v1.OutputMoveRequest
----@field control pinnacle.signal.v1.StreamControl?
+---@class pinnacle.nightlight.v1.ScheduleTime
+---@field minutes integer?
+---@field sun_event pinnacle.nightlight.v1.SunEvent?
 
----@class pinnacle.signal.v1.OutputMoveResponse
----@field output_name string?
----@field x integer?
----@field y integer?
+---@class pinnacle.nightlight.v1.Location
+---@field latitude number?
+---@field longitude number?
 
----@class pinnacle.signal.v1.OutputPointerEnterRequest
----@field control pinnacle.signal.v1.StreamControl?
+---@class pinnacle.nightlight.v1.Schedule
+---@field start pinnacle.nightlight.v1.ScheduleTime?
+---@field end pinnacle.nightlight.v1.ScheduleTime?
+---@field temperature integer?
+---@field transition_millis integer?
+---@field location pinnacle.nightlight.v1.Location?
 
----@class pinnacle.signal.v1.OutputPointerEnterResponse
----@field output_name string?
+---@class pinnacle.nightlight.v1.SetScheduleRequest
+---@field schedule pinnacle.nightlight.v1.Schedule?
 
----@class pinnacle.signal.v1.OutputPointerLeaveRequest
----@field control pinnacle.signal.v1.StreamControl?
+---@class pinnacle.nightlight.v1.SetScheduleResponse
 
----@class pinnacle.signal.v1.OutputPointerLeaveResponse
----@field output_name string?
+---@class pinnacle.portal.v1.GlobalShortcut
+---@field id string?
+---@field description string?
+---@field preferred_trigger string?
 
----@class pinnacle.signal.v1.OutputFocusedRequest
----@field control pinnacle.signal.v1.StreamControl?
+---@class pinnacle.portal.v1.GlobalShortcutsPermissionRequest
+---@field decision pinnacle.portal.v1.GlobalShortcutsPermissionRequest.Decision?
 
----@class pinnacle.signal.v1.OutputFocusedResponse
----@field output_name string?
+---@class pinnacle.portal.v1.GlobalShortcutsPermissionRequest.Decision
+---@field request_id integer?
+---@field allow boolean?
 
----@class pinnacle.signal.v1.WindowPointerEnterRequest
----@field control pinnacle.signal.v1.StreamControl?
+---@class pinnacle.portal.v1.GlobalShortcutsPermissionResponse
+---@field new_request pinnacle.portal.v1.GlobalShortcutsPermissionResponse.NewRequest?
 
----@class pinnacle.signal.v1.WindowPointerEnterResponse
----@field window_id integer?
+---@class pinnacle.portal.v1.GlobalShortcutsPermissionResponse.NewRequest
+---@field request_id integer?
+---@field app_id string?
+---@field shortcuts pinnacle.portal.v1.GlobalShortcut[]?
 
----@class pinnacle.signal.v1.WindowPointerLeaveRequest
----@field control pinnacle.signal.v1.StreamControl?
+---@class pinnacle.power.v1.GetStatusRequest
 
----@class pinnacle.signal.v1.WindowPointerLeaveResponse
----@field window_id integer?
+---@class pinnacle.power.v1.GetStatusResponse
+---@field on_battery boolean?
+---@field charging boolean?
+---@field percentage number?
+---@field low_battery boolean?
 
----@class pinnacle.signal.v1.WindowFocusedRequest
----@field control pinnacle.signal.v1.StreamControl?
+---@class pinnacle.process.v1.SpawnRequest
+---@field cmd string[]?
+---@field unique boolean?
+---@field once boolean?
+---@field shell_cmd string[]?
+---@field envs pinnacle.process.v1.SpawnRequest.EnvsEntry[]?
+---@field pipe_stdin boolean?
+---@field pipe_stdout boolean?
+---@field pipe_stderr boolean?
 
----@class pinnacle.signal.v1.WindowFocusedResponse
----@field window_id integer?
+---@class pinnacle.process.v1.SpawnRequest.EnvsEntry
+---@field key string?
+---@field value string?
 
----@class pinnacle.signal.v1.WindowTitleChangedRequest
----@field control pinnacle.signal.v1.StreamControl?
+---@class pinnacle.process.v1.SpawnData
+---@field pid integer?
+---@field fd_socket_path string?
+---@field has_stdin boolean?
+---@field has_stdout boolean?
+---@field has_stderr boolean?
 
----@class pinnacle.signal.v1.WindowTitleChangedResponse
----@field window_id integer?
----@field title string?
+---@class pinnacle.process.v1.SpawnResponse
+---@field spawn_data pinnacle.process.v1.SpawnData?
 
----@class pinnacle.signal.v1.WindowLayoutModeChangedRequest
----@field control pinnacle.signal.v1.StreamControl?
+---@class pinnacle.process.v1.WaitOnSpawnRequest
+---@field pid integer?
 
----@class pinnacle.signal.v1.WindowLayoutModeChangedResponse
----@field window_id integer?
----@field layout_mode pinnacle.window.v1.LayoutMode?
+---@class pinnacle.process.v1.WaitOnSpawnResponse
+---@field exit_code integer?
+---@field exit_msg string?
 
----@class pinnacle.signal.v1.WindowCreatedRequest
----@field control pinnacle.signal.v1.StreamControl?
+---@class pinnacle.process.v1.SetEnvRequest
+---@field key string?
+---@field value string?
 
----@class pinnacle.signal.v1.WindowCreatedResponse
----@field window_id integer?
+---@class pinnacle.process.v1.SetGlobalEnvRequest
+---@field key string?
+---@field value string?
 
----@class pinnacle.signal.v1.WindowDestroyedRequest
----@field control pinnacle.signal.v1.StreamControl?
+---@class pinnacle.process.v1.SetGlobalEnvResponse
 
----@class pinnacle.signal.v1.WindowDestroyedResponse
----@field window_id integer?
----@field title string?
+---@class pinnacle.process.v1.RunOrRaiseRequest
 ---@field app_id string?
+---@field cmd string[]?
 
----@class pinnacle.signal.v1.TagActiveRequest
----@field control pinnacle.signal.v1.StreamControl?
+---@class pinnacle.process.v1.RunOrRaiseResponse
+---@field window_id integer?
+---@field spawn_data pinnacle.process.v1.SpawnData?
+---@field error string?
 
----@class pinnacle.signal.v1.TagActiveResponse
----@field tag_id integer?
----@field active boolean?
+---@class pinnacle.render.v1.SetUpscaleFilterRequest
+---@field filter pinnacle.render.v1.Filter?
 
----@class pinnacle.signal.v1.TagCreatedRequest
----@field control pinnacle.signal.v1.StreamControl?
+---@class pinnacle.render.v1.SetDownscaleFilterRequest
+---@field filter pinnacle.render.v1.Filter?
 
----@class pinnacle.signal.v1.TagCreatedResponse
----@field tag_id integer?
+---@class pinnacle.render.v1.SetInactiveEffectRequest
+---@field enabled boolean?
+---@field target pinnacle.render.v1.InactiveEffectTarget?
+---@field dim number?
+---@field desaturate number?
 
----@class pinnacle.signal.v1.TagRemovedRequest
----@field control pinnacle.signal.v1.StreamControl?
+---@class pinnacle.render.v1.SetInactiveEffectResponse
 
----@class pinnacle.signal.v1.TagRemovedResponse
----@field tag_id integer?
+---@class pinnacle.render.v1.SetLetterboxFullscreenRequest
+---@field letterbox boolean?
 
----@class pinnacle.signal.v1.InputDeviceAddedRequest
----@field control pinnacle.signal.v1.StreamControl?
+---@class pinnacle.render.v1.SetLetterboxFullscreenResponse
 
----@class pinnacle.signal.v1.InputDeviceAddedResponse
----@field device_sysname string?
+---@class pinnacle.render.v1.SetCloseSnapshotHoldRequest
+---@field millis integer?
 
----@class pinnacle.tag.v1.GetRequest
+---@class pinnacle.render.v1.SetCloseSnapshotHoldResponse
 
----@class pinnacle.tag.v1.GetResponse
----@field tag_ids integer[]?
+---@class pinnacle.screenshot.v1.SelectRegionRequest
+---@field path string?
 
----@class pinnacle.tag.v1.AddRequest
+---@class pinnacle.screenshot.v1.SelectRegionResponse
 ---@field output_name string?
----@field tag_names string[]?
+---@field region pinnacle.util.v1.Rect?
+---@field image_size pinnacle.util.v1.Size?
+---@field rgba string?
 
----@class pinnacle.tag.v1.AddResponse
----@field tag_ids integer[]?
+---@class pinnacle.window.v1.GetRequest
 
----@class pinnacle.tag.v1.RemoveRequest
----@field tag_ids integer[]?
+---@class pinnacle.window.v1.GetResponse
+---@field window_ids integer[]?
 
----@class pinnacle.tag.v1.MoveToOutputRequest
----@field output_name string?
----@field tag_ids integer[]?
+---@class pinnacle.window.v1.ExistsRequest
+---@field window_id integer?
 
----@class pinnacle.tag.v1.MoveToOutputResponse
----@field error pinnacle.tag.v1.MoveToOutputResponse.Error?
+---@class pinnacle.window.v1.ExistsResponse
+---@field exists boolean?
 
----@class pinnacle.tag.v1.MoveToOutputResponse.Error
----@field output_does_not_exist google.protobuf.Empty?
----@field same_window_on_two_outputs pinnacle.tag.v1.MoveToOutputResponse.Error.SameWindowOnTwoOutputs?
+---@class pinnacle.window.v1.GetAppIdRequest
+---@field window_id integer?
 
----@class pinnacle.tag.v1.MoveToOutputResponse.Error.SameWindowOnTwoOutputs
+---@class pinnacle.window.v1.GetAppIdResponse
+---@field app_id string?
+
+---@class pinnacle.window.v1.GetTitleRequest
+---@field window_id integer?
+
+---@class pinnacle.window.v1.GetTitleResponse
+---@field title string?
+
+---@class pinnacle.window.v1.GetLocRequest
+---@field window_id integer?
+
+---@class pinnacle.window.v1.GetLocResponse
+---@field loc pinnacle.util.v1.Point?
+
+---@class pinnacle.window.v1.GetSizeRequest
+---@field window_id integer?
+
+---@class pinnacle.window.v1.GetSizeResponse
+---@field size pinnacle.util.v1.Size?
+
+---@class pinnacle.window.v1.GetFocusedRequest
+---@field window_id integer?
+
+---@class pinnacle.window.v1.GetFocusedResponse
+---@field focused boolean?
+
+---@class pinnacle.window.v1.GetFocusAgeRequest
+---@field window_id integer?
+
+---@class pinnacle.window.v1.GetFocusAgeResponse
+---@field focus_age integer?
+
+---@class pinnacle.window.v1.GetPreviouslyFocusedRequest
+
+---@class pinnacle.window.v1.GetPreviouslyFocusedResponse
+---@field window_id integer?
+
+---@class pinnacle.window.v1.FindRequest
+---@field query string?
+---@field mode pinnacle.window.v1.FindMode?
+---@field limit integer?
+
+---@class pinnacle.window.v1.FindResponse
 ---@field window_ids integer[]?
+---@field error string?
 
----@class pinnacle.tag.v1.GetActiveRequest
----@field tag_id integer?
+---@class pinnacle.window.v1.GetResponsiveRequest
+---@field window_id integer?
 
----@class pinnacle.tag.v1.GetActiveResponse
----@field active boolean?
+---@class pinnacle.window.v1.GetResponsiveResponse
+---@field responsive boolean?
 
----@class pinnacle.tag.v1.GetNameRequest
----@field tag_id integer?
+---@class pinnacle.window.v1.GetLayoutModeRequest
+---@field window_id integer?
 
----@class pinnacle.tag.v1.GetNameResponse
+---@class pinnacle.window.v1.GetLayoutModeResponse
+---@field layout_mode pinnacle.window.v1.LayoutMode?
+
+---@class pinnacle.window.v1.GetMinimizedRequest
+---@field window_id integer?
+
+---@class pinnacle.window.v1.GetMinimizedResponse
+---@field minimized boolean?
+
+---@class pinnacle.window.v1.GetDemandsAttentionRequest
+---@field window_id integer?
+
+---@class pinnacle.window.v1.GetDemandsAttentionResponse
+---@field demands_attention boolean?
+
+---@class pinnacle.window.v1.IconImage
+---@field size integer?
+---@field scale integer?
+---@field rgba string?
+
+---@class pinnacle.window.v1.GetIconRequest
+---@field window_id integer?
+
+---@class pinnacle.window.v1.GetIconResponse
 ---@field name string?
+---@field images pinnacle.window.v1.IconImage[]?
 
----@class pinnacle.tag.v1.GetOutputNameRequest
----@field tag_id integer?
+---@class pinnacle.window.v1.GetLayerRequest
+---@field window_id integer?
 
----@class pinnacle.tag.v1.GetOutputNameResponse
----@field output_name string?
+---@class pinnacle.window.v1.GetLayerResponse
+---@field layer pinnacle.window.v1.WindowLayer?
 
----@class pinnacle.tag.v1.SetActiveRequest
----@field tag_id integer?
+---@class pinnacle.window.v1.GetParentRequest
+---@field window_id integer?
+
+---@class pinnacle.window.v1.GetParentResponse
+---@field parent_id integer?
+
+---@class pinnacle.window.v1.GetChildrenRequest
+---@field window_id integer?
+
+---@class pinnacle.window.v1.GetChildrenResponse
+---@field child_ids integer[]?
+
+---@class pinnacle.window.v1.GetTagIdsRequest
+---@field window_id integer?
+
+---@class pinnacle.window.v1.GetTagIdsResponse
+---@field tag_ids integer[]?
+
+---@class pinnacle.window.v1.GetWindowsInDirRequest
+---@field window_id integer?
+---@field dir pinnacle.util.v1.Dir?
+
+---@class pinnacle.window.v1.GetWindowsInDirResponse
+---@field window_ids integer[]?
+
+---@class pinnacle.window.v1.GetForeignToplevelListIdentifierRequest
+---@field window_id integer?
+
+---@class pinnacle.window.v1.GetForeignToplevelListIdentifierResponse
+---@field identifier string?
+
+---@class pinnacle.window.v1.WatchPropertiesRequest
+---@field window_id integer?
+
+---@class pinnacle.window.v1.TagIds
+---@field tag_ids integer[]?
+
+---@class pinnacle.window.v1.WatchPropertiesResponse
+---@field app_id string?
+---@field title string?
+---@field loc pinnacle.util.v1.Point?
+---@field size pinnacle.util.v1.Size?
+---@field focused boolean?
+---@field layout_mode pinnacle.window.v1.LayoutMode?
+---@field tag_ids pinnacle.window.v1.TagIds?
+---@field cleared pinnacle.window.v1.ClearableProperty[]?
+
+---@class pinnacle.window.v1.CloseRequest
+---@field window_id integer?
+
+---@class pinnacle.window.v1.SetGeometryRequest
+---@field window_id integer?
+---@field x integer?
+---@field y integer?
+---@field w integer?
+---@field h integer?
+
+---@class pinnacle.window.v1.ResizeTileRequest
+---@field window_id integer?
+---@field left integer?
+---@field right integer?
+---@field top integer?
+---@field bottom integer?
+
+---@class pinnacle.window.v1.SetFullscreenRequest
+---@field window_id integer?
 ---@field set_or_toggle pinnacle.util.v1.SetOrToggle?
 
----@class pinnacle.tag.v1.SwitchToRequest
+---@class pinnacle.window.v1.SetMaximizedRequest
+---@field window_id integer?
+---@field set_or_toggle pinnacle.util.v1.SetOrToggle?
+
+---@class pinnacle.window.v1.SetMinimizedRequest
+---@field window_id integer?
+---@field set_or_toggle pinnacle.util.v1.SetOrToggle?
+
+---@class pinnacle.window.v1.SetDemandsAttentionRequest
+---@field window_id integer?
+---@field demands_attention boolean?
+
+---@class pinnacle.window.v1.SetFloatingRequest
+---@field window_id integer?
+---@field set_or_toggle pinnacle.util.v1.SetOrToggle?
+
+---@class pinnacle.window.v1.SetFocusedRequest
+---@field window_id integer?
+---@field set_or_toggle pinnacle.util.v1.SetOrToggle?
+
+---@class pinnacle.window.v1.SetDecorationModeRequest
+---@field window_id integer?
+---@field decoration_mode pinnacle.window.v1.DecorationMode?
+
+---@class pinnacle.window.v1.MoveToTagRequest
+---@field window_id integer?
 ---@field tag_id integer?
+---@field follow boolean?
 
----@class pinnacle.v1.QuitRequest
+---@class pinnacle.window.v1.SetTagRequest
+---@field window_id integer?
+---@field tag_id integer?
+---@field set_or_toggle pinnacle.util.v1.SetOrToggle?
 
----@class pinnacle.v1.ReloadConfigRequest
+---@class pinnacle.window.v1.SetTagsRequest
+---@field window_id integer?
+---@field tag_ids integer[]?
 
----@class pinnacle.v1.KeepaliveRequest
+---@class pinnacle.window.v1.SetTagsResponse
 
----@class pinnacle.v1.KeepaliveResponse
+---@class pinnacle.window.v1.VrrDemand
+---@field fullscreen boolean?
 
----@class pinnacle.v1.BackendRequest
+---@class pinnacle.window.v1.SetVrrDemandRequest
+---@field window_id integer?
+---@field vrr_demand pinnacle.window.v1.VrrDemand?
 
----@class pinnacle.v1.BackendResponse
----@field backend pinnacle.v1.Backend?
+---@class pinnacle.window.v1.SetVrrDemandResponse
 
----@class pinnacle.v1.SetXwaylandClientSelfScaleRequest
----@field self_scale boolean?
+---@class pinnacle.window.v1.MoveToOutputRequest
+---@field window_id integer?
+---@field output_name string?
 
----@class pinnacle.v1.SetLastErrorRequest
----@field error string?
+---@class pinnacle.window.v1.MoveToOutputResponse
 
----@class pinnacle.v1.TakeLastErrorRequest
+---@class pinnacle.window.v1.RaiseRequest
+---@field window_id integer?
 
----@class pinnacle.v1.TakeLastErrorResponse
----@field error string?
+---@class pinnacle.window.v1.LowerRequest
+---@field window_id integer?
 
-local google = {}
-google.protobuf = {}
-google.protobuf.Empty = {}
-local pinnacle = {}
-pinnacle.util = {}
-pinnacle.util.v1 = {}
-pinnacle.util.v1.Point = {}
-pinnacle.util.v1.Size = {}
-pinnacle.util.v1.Rect = {}
-pinnacle.debug = {}
-pinnacle.debug.v1 = {}
-pinnacle.debug.v1.SetDamageVisualizationRequest = {}
-pinnacle.debug.v1.SetOpaqueRegionVisualizationRequest = {}
-pinnacle.debug.v1.SetCursorPlaneScanoutRequest = {}
-pinnacle.debug.v1.SetProcessPipingRequest = {}
-pinnacle.input = {}
-pinnacle.input.v1 = {}
-pinnacle.input.v1.Bind = {}
-pinnacle.input.v1.BindRequest = {}
-pinnacle.input.v1.BindResponse = {}
-pinnacle.input.v1.BindProperties = {}
-pinnacle.input.v1.SetBindPropertiesRequest = {}
-pinnacle.input.v1.Keybind = {}
-pinnacle.input.v1.KeybindStreamRequest = {}
-pinnacle.input.v1.KeybindStreamResponse = {}
-pinnacle.input.v1.KeybindOnPressRequest = {}
-pinnacle.input.v1.Mousebind = {}
-pinnacle.input.v1.MousebindStreamRequest = {}
-pinnacle.input.v1.MousebindStreamResponse = {}
-pinnacle.input.v1.MousebindOnPressRequest = {}
-pinnacle.input.v1.GetBindInfosRequest = {}
-pinnacle.input.v1.GetBindInfosResponse = {}
-pinnacle.input.v1.BindInfo = {}
-pinnacle.input.v1.GetBindLayerStackRequest = {}
-pinnacle.input.v1.GetBindLayerStackResponse = {}
-pinnacle.input.v1.EnterBindLayerRequest = {}
-pinnacle.input.v1.SetXkbConfigRequest = {}
-pinnacle.input.v1.SetRepeatRateRequest = {}
-pinnacle.input.v1.SetXkbKeymapRequest = {}
-pinnacle.input.v1.SwitchXkbLayoutRequest = {}
-pinnacle.input.v1.SetXcursorRequest = {}
-pinnacle.input.v1.CalibrationMatrix = {}
-pinnacle.input.v1.GetDevicesRequest = {}
-pinnacle.input.v1.GetDevicesResponse = {}
-pinnacle.input.v1.GetDeviceCapabilitiesRequest = {}
-pinnacle.input.v1.GetDeviceCapabilitiesResponse = {}
-pinnacle.input.v1.GetDeviceInfoRequest = {}
-pinnacle.input.v1.GetDeviceInfoResponse = {}
-pinnacle.input.v1.GetDeviceTypeRequest = {}
-pinnacle.input.v1.GetDeviceTypeResponse = {}
-pinnacle.input.v1.SetDeviceLibinputSettingRequest = {}
-pinnacle.input.v1.SetDeviceMapTargetRequest = {}
-pinnacle.layout = {}
-pinnacle.layout.v1 = {}
-pinnacle.layout.v1.Gaps = {}
-pinnacle.layout.v1.LayoutNode = {}
-pinnacle.layout.v1.LayoutNode.TraversalOverridesEntry = {}
-pinnacle.layout.v1.TraversalOverrides = {}
-pinnacle.layout.v1.NodeStyle = {}
-pinnacle.layout.v1.LayoutRequest = {}
-pinnacle.layout.v1.LayoutRequest.TreeResponse = {}
-pinnacle.layout.v1.LayoutRequest.ForceLayout = {}
-pinnacle.layout.v1.LayoutResponse = {}
+---@class pinnacle.window.v1.LowerResponse
+
+---@class pinnacle.window.v1.MoveGrabRequest
+---@field button integer?
+
+---@class pinnacle.window.v1.ResizeGrabRequest
+---@field button integer?
+
+---@class pinnacle.window.v1.FocusInDirectionRequest
+---@field dir pinnacle.util.v1.Dir?
+
+---@class pinnacle.window.v1.FocusInDirectionResponse
+---@field window_id integer?
+
+---@class pinnacle.window.v1.BeginCycleRequest
+
+---@class pinnacle.window.v1.BeginCycleResponse
+
+---@class pinnacle.window.v1.CycleRequest
+---@field backward boolean?
+
+---@class pinnacle.window.v1.CycleResponse
+---@field window_id integer?
+
+---@class pinnacle.window.v1.EndCycleRequest
+
+---@class pinnacle.window.v1.EndCycleResponse
+---@field window_id integer?
+
+---@class pinnacle.window.v1.SwapRequest
+---@field window_id integer?
+---@field target_id integer?
+
+---@class pinnacle.window.v1.SwapResponse
+
+---@class pinnacle.window.v1.SwapInDirectionRequest
+---@field window_id integer?
+---@field dir pinnacle.util.v1.Dir?
+
+---@class pinnacle.window.v1.SwapInDirectionResponse
+---@field target_id integer?
+
+---@class pinnacle.window.v1.SetFloatingVisibilityRequest
+---@field unrestricted pinnacle.window.v1.SetFloatingVisibilityRequest.Unrestricted?
+---@field keep_visible pinnacle.window.v1.SetFloatingVisibilityRequest.KeepVisible?
+---@field clamp_to_outputs pinnacle.window.v1.SetFloatingVisibilityRequest.ClampToOutputs?
+
+---@class pinnacle.window.v1.SetFloatingVisibilityRequest.Unrestricted
+
+---@class pinnacle.window.v1.SetFloatingVisibilityRequest.KeepVisible
+---@field pixels integer?
+
+---@class pinnacle.window.v1.SetFloatingVisibilityRequest.ClampToOutputs
+
+---@class pinnacle.window.v1.SetFloatingVisibilityResponse
+
+---@class pinnacle.window.v1.SetAllowOffscreenRequest
+---@field window_id integer?
+---@field allow_offscreen boolean?
+
+---@class pinnacle.window.v1.SetAllowOffscreenResponse
+
+---@class pinnacle.window.v1.SetInputEnabledRequest
+---@field window_id integer?
+---@field enabled boolean?
+
+---@class pinnacle.window.v1.SetInputEnabledResponse
+
+---@class pinnacle.window.v1.SetHoldToMoveEnabledRequest
+---@field window_id integer?
+---@field enabled boolean?
+
+---@class pinnacle.window.v1.SetHoldToMoveEnabledResponse
+
+---@class pinnacle.window.v1.SetScaleOverrideRequest
+---@field window_id integer?
+---@field scale number?
+
+---@class pinnacle.window.v1.SetScaleOverrideResponse
+
+---@class pinnacle.window.v1.SnapGridCell
+---@field column integer?
+---@field row integer?
+---@field column_span integer?
+---@field row_span integer?
+
+---@class pinnacle.window.v1.SnapToRegionRequest
+---@field window_id integer?
+---@field preset pinnacle.window.v1.SnapPreset?
+---@field grid_cell pinnacle.window.v1.SnapGridCell?
+
+---@class pinnacle.window.v1.SnapToRegionResponse
+
+---@class pinnacle.window.v1.SetSnapGridRequest
+---@field columns integer?
+---@field rows integer?
+
+---@class pinnacle.window.v1.SetSnapGridResponse
+
+---@class pinnacle.window.v1.SetPopupPolicyRequest
+---@field clamp_to_output boolean?
+---@field close_on_tag_switch boolean?
+---@field max_grab_duration_ms integer?
+
+---@class pinnacle.window.v1.SetPopupPolicyResponse
+
+---@class pinnacle.window.v1.SetGreyOutUnresponsiveRequest
+---@field grey_out boolean?
+
+---@class pinnacle.window.v1.SetGreyOutUnresponsiveResponse
+
+---@class pinnacle.window.v1.SetMaximizeRequestPolicyRequest
+---@field window_id integer?
+---@field policy pinnacle.window.v1.MaximizeRequestPolicy?
+
+---@class pinnacle.window.v1.SetMaximizeRequestPolicyResponse
+
+---@class pinnacle.window.v1.SetTagInheritanceRequest
+---@field inheritance pinnacle.window.v1.TagInheritance?
+
+---@class pinnacle.window.v1.SetTagInheritanceResponse
+
+---@class pinnacle.window.v1.SetDialogPolicyRequest
+---@field policy pinnacle.window.v1.DialogPolicy?
+
+---@class pinnacle.window.v1.SetDialogPolicyResponse
+
+---@class pinnacle.window.v1.SetLayerRequest
+---@field window_id integer?
+---@field layer pinnacle.window.v1.WindowLayer?
+
+---@class pinnacle.window.v1.SetLayerResponse
+
+---@class pinnacle.window.v1.SizeConstraints
+---@field min_w integer?
+---@field min_h integer?
+---@field max_w integer?
+---@field max_h integer?
+---@field aspect_ratio number?
+
+---@class pinnacle.window.v1.SetSizeConstraintsRequest
+---@field window_id integer?
+---@field constraints pinnacle.window.v1.SizeConstraints?
+
+---@class pinnacle.window.v1.SetSizeConstraintsResponse
+
+---@class pinnacle.window.v1.BorderStyle
+---@field width integer?
+---@field focused_color pinnacle.util.v1.Color?
+---@field unfocused_color pinnacle.util.v1.Color?
+---@field urgent_color pinnacle.util.v1.Color?
+---@field corner_radius number?
+---@field titlebar_height integer?
+
+---@class pinnacle.window.v1.SetBorderStyleRequest
+---@field window_id integer?
+---@field style pinnacle.window.v1.BorderStyle?
+
+---@class pinnacle.window.v1.SetBorderStyleResponse
+
+---@class pinnacle.window.v1.DeclarativeWindowRule
+---@field name string?
+---@field priority integer?
+---@field app_id string?
+---@field title string?
+---@field layout_mode pinnacle.window.v1.LayoutMode?
+---@field decoration_mode pinnacle.window.v1.DecorationMode?
+---@field floating_x integer?
+---@field floating_y integer?
+---@field floating_w integer?
+---@field floating_h integer?
+---@field tag_ids integer[]?
+---@field focused boolean?
+---@field maximize_request_policy pinnacle.window.v1.MaximizeRequestPolicy?
+---@field size_constraints pinnacle.window.v1.SizeConstraints?
+---@field border_style pinnacle.window.v1.BorderStyle?
+
+---@class pinnacle.window.v1.SetWindowRulesRequest
+---@field rules pinnacle.window.v1.DeclarativeWindowRule[]?
+---@field strategy pinnacle.window.v1.WindowRuleStrategy?
+
+---@class pinnacle.window.v1.SetWindowRulesResponse
+
+---@class pinnacle.window.v1.GetMatchingWindowRulesRequest
+---@field window_id integer?
+
+---@class pinnacle.window.v1.GetMatchingWindowRulesResponse
+---@field rule_names string[]?
+
+---@class pinnacle.window.v1.ApplyRulesToExistingRequest
+
+---@class pinnacle.window.v1.ApplyRulesToExistingResponse
+---@field windows pinnacle.window.v1.ApplyRulesToExistingResponse.WindowChanges[]?
+
+---@class pinnacle.window.v1.ApplyRulesToExistingResponse.WindowChanges
+---@field window_id integer?
+---@field changed pinnacle.window.v1.WindowRuleProperty[]?
+
+---@class pinnacle.window.v1.WindowRuleRequest
+---@field finished pinnacle.window.v1.WindowRuleRequest.Finished?
+
+---@class pinnacle.window.v1.WindowRuleRequest.Finished
+---@field request_id integer?
+
+---@class pinnacle.window.v1.WindowRuleResponse
+---@field new_window pinnacle.window.v1.WindowRuleResponse.NewWindowRequest?
+
+---@class pinnacle.window.v1.WindowRuleResponse.NewWindowRequest
+---@field request_id integer?
+---@field window_id integer?
+
+---@class pinnacle.signal.v1.OutputConnectRequest
+---@field control pinnacle.signal.v1.StreamControl?
+
+---@class pinnacle.signal.v1.OutputConnectResponse
+---@field output_name string?
+
+---@class pinnacle.signal.v1.OutputDisconnectRequest
+---@field control pinnacle.signal.v1.StreamControl?
+
+---@class pinnacle.signal.v1.OutputDisconnectResponse
+---@field output_name string?
+
+---@class pinnacle.signal.v1.OutputResizeRequest
+---@field control pinnacle.signal.v1.StreamControl?
+
+---@class pinnacle.signal.v1.OutputResizeResponse
+---@field output_name string?
+---@field logical_width integer?
+---@field logical_height integer?
+
+---@class pinnacle.signal.v1.OutputMoveRequest
+---@field control pinnacle.signal.v1.StreamControl?
+
+---@class pinnacle.signal.v1.OutputMoveResponse
+---@field output_name string?
+---@field x integer?
+---@field y integer?
+
+---@class pinnacle.signal.v1.OutputPointerEnterRequest
+---@field control pinnacle.signal.v1.StreamControl?
+
+---@class pinnacle.signal.v1.OutputPointerEnterResponse
+---@field output_name string?
+
+---@class pinnacle.signal.v1.OutputPointerLeaveRequest
+---@field control pinnacle.signal.v1.StreamControl?
+
+---@class pinnacle.signal.v1.OutputPointerLeaveResponse
+---@field output_name string?
+
+---@class pinnacle.signal.v1.OutputPointerEdgeRequest
+---@field control pinnacle.signal.v1.StreamControl?
+
+---@class pinnacle.signal.v1.OutputPointerEdgeResponse
+---@field output_name string?
+---@field edge pinnacle.output.v1.ScreenEdge?
+
+---@class pinnacle.signal.v1.OutputFocusedRequest
+---@field control pinnacle.signal.v1.StreamControl?
+
+---@class pinnacle.signal.v1.OutputFocusedResponse
+---@field output_name string?
+
+---@class pinnacle.signal.v1.OutputLayerFocusedRequest
+---@field control pinnacle.signal.v1.StreamControl?
+
+---@class pinnacle.signal.v1.OutputLayerFocusedResponse
+---@field output_name string?
+---@field namespace string?
+
+---@class pinnacle.signal.v1.OutputLayerUnfocusedRequest
+---@field control pinnacle.signal.v1.StreamControl?
+
+---@class pinnacle.signal.v1.OutputLayerUnfocusedResponse
+---@field output_name string?
+---@field namespace string?
+
+---@class pinnacle.signal.v1.OutputOrientationChangedRequest
+---@field control pinnacle.signal.v1.StreamControl?
+
+---@class pinnacle.signal.v1.OutputOrientationChangedResponse
+---@field output_name string?
+---@field transform pinnacle.output.v1.Transform?
+
+---@class pinnacle.signal.v1.OutputFullscreenChangedRequest
+---@field control pinnacle.signal.v1.StreamControl?
+
+---@class pinnacle.signal.v1.OutputFullscreenChangedResponse
+---@field output_name string?
+---@field window_id integer?
+
+---@class pinnacle.signal.v1.OutputStackingChangedRequest
+---@field control pinnacle.signal.v1.StreamControl?
+
+---@class pinnacle.signal.v1.OutputStackingChangedResponse
+---@field output_name string?
+---@field window_ids integer[]?
+
+---@class pinnacle.signal.v1.OutputNoActiveTagsRequest
+---@field control pinnacle.signal.v1.StreamControl?
+
+---@class pinnacle.signal.v1.OutputNoActiveTagsResponse
+---@field output_name string?
+
+---@class pinnacle.signal.v1.WindowPointerEnterRequest
+---@field control pinnacle.signal.v1.StreamControl?
+
+---@class pinnacle.signal.v1.WindowPointerEnterResponse
+---@field window_id integer?
+
+---@class pinnacle.signal.v1.WindowPointerLeaveRequest
+---@field control pinnacle.signal.v1.StreamControl?
+
+---@class pinnacle.signal.v1.WindowPointerLeaveResponse
+---@field window_id integer?
+
+---@class pinnacle.signal.v1.WindowFocusedRequest
+---@field control pinnacle.signal.v1.StreamControl?
+
+---@class pinnacle.signal.v1.WindowFocusedResponse
+---@field window_id integer?
+---@field reason pinnacle.window.v1.FocusReason?
+---@field serial integer?
+
+---@class pinnacle.signal.v1.WindowTitleChangedRequest
+---@field control pinnacle.signal.v1.StreamControl?
+
+---@class pinnacle.signal.v1.WindowTitleChangedResponse
+---@field window_id integer?
+---@field title string?
+
+---@class pinnacle.signal.v1.WindowFocusedTitleChangedRequest
+---@field control pinnacle.signal.v1.StreamControl?
+
+---@class pinnacle.signal.v1.WindowFocusedTitleChangedResponse
+---@field window_id integer?
+---@field title string?
+---@field app_id string?
+
+---@class pinnacle.signal.v1.WindowLayoutModeChangedRequest
+---@field control pinnacle.signal.v1.StreamControl?
+
+---@class pinnacle.signal.v1.WindowLayoutModeChangedResponse
+---@field window_id integer?
+---@field layout_mode pinnacle.window.v1.LayoutMode?
+
+---@class pinnacle.signal.v1.WindowCreatedRequest
+---@field control pinnacle.signal.v1.StreamControl?
+
+---@class pinnacle.signal.v1.WindowCreatedResponse
+---@field window_id integer?
+
+---@class pinnacle.signal.v1.WindowDestroyedRequest
+---@field control pinnacle.signal.v1.StreamControl?
+
+---@class pinnacle.signal.v1.WindowDestroyedResponse
+---@field window_id integer?
+---@field title string?
+---@field app_id string?
+
+---@class pinnacle.signal.v1.WindowUnresponsiveRequest
+---@field control pinnacle.signal.v1.StreamControl?
+
+---@class pinnacle.signal.v1.WindowUnresponsiveResponse
+---@field window_id integer?
+
+---@class pinnacle.signal.v1.WindowMinimizedRequest
+---@field control pinnacle.signal.v1.StreamControl?
+
+---@class pinnacle.signal.v1.WindowMinimizedResponse
+---@field window_id integer?
+---@field minimized boolean?
+
+---@class pinnacle.signal.v1.WindowUrgencyChangedRequest
+---@field control pinnacle.signal.v1.StreamControl?
+
+---@class pinnacle.signal.v1.WindowUrgencyChangedResponse
+---@field window_id integer?
+---@field demands_attention boolean?
+
+---@class pinnacle.signal.v1.WindowPopupGrabBlockedKeybindRequest
+---@field control pinnacle.signal.v1.StreamControl?
+
+---@class pinnacle.signal.v1.WindowPopupGrabBlockedKeybindResponse
+---@field window_id integer?
+
+---@class pinnacle.signal.v1.TagActiveRequest
+---@field control pinnacle.signal.v1.StreamControl?
+
+---@class pinnacle.signal.v1.TagActiveResponse
+---@field tag_id integer?
+---@field active boolean?
+
+---@class pinnacle.signal.v1.TagCreatedRequest
+---@field control pinnacle.signal.v1.StreamControl?
+
+---@class pinnacle.signal.v1.TagCreatedResponse
+---@field tag_id integer?
+
+---@class pinnacle.signal.v1.TagRemovedRequest
+---@field control pinnacle.signal.v1.StreamControl?
+
+---@class pinnacle.signal.v1.TagRemovedResponse
+---@field tag_id integer?
+
+---@class pinnacle.signal.v1.TagUrgencyChangedRequest
+---@field control pinnacle.signal.v1.StreamControl?
+
+---@class pinnacle.signal.v1.TagUrgencyChangedResponse
+---@field tag_id integer?
+---@field urgent boolean?
+
+---@class pinnacle.signal.v1.InputDeviceAddedRequest
+---@field control pinnacle.signal.v1.StreamControl?
+
+---@class pinnacle.signal.v1.InputDeviceAddedResponse
+---@field device_sysname string?
+
+---@class pinnacle.signal.v1.InputAccessibilityChangedRequest
+---@field control pinnacle.signal.v1.StreamControl?
+
+---@class pinnacle.signal.v1.InputAccessibilityChangedResponse
+---@field sticky_keys boolean?
+---@field slow_keys_delay_millis integer?
+
+---@class pinnacle.signal.v1.PowerOnBatteryRequest
+---@field control pinnacle.signal.v1.StreamControl?
+
+---@class pinnacle.signal.v1.PowerOnBatteryResponse
+---@field on_battery boolean?
+
+---@class pinnacle.signal.v1.PowerChargingRequest
+---@field control pinnacle.signal.v1.StreamControl?
+
+---@class pinnacle.signal.v1.PowerChargingResponse
+---@field charging boolean?
+
+---@class pinnacle.signal.v1.PowerLowBatteryRequest
+---@field control pinnacle.signal.v1.StreamControl?
+
+---@class pinnacle.signal.v1.PowerLowBatteryResponse
+---@field percentage number?
+
+---@class pinnacle.signal.v1.IdleStartedRequest
+---@field control pinnacle.signal.v1.StreamControl?
+
+---@class pinnacle.signal.v1.IdleStartedResponse
+
+---@class pinnacle.signal.v1.IdleResumedRequest
+---@field control pinnacle.signal.v1.StreamControl?
+
+---@class pinnacle.signal.v1.IdleResumedResponse
+
+---@class pinnacle.tag.v1.GetRequest
+
+---@class pinnacle.tag.v1.GetResponse
+---@field tag_ids integer[]?
+
+---@class pinnacle.tag.v1.AddRequest
+---@field output_name string?
+---@field tag_names string[]?
+
+---@class pinnacle.tag.v1.AddResponse
+---@field tag_ids integer[]?
+
+---@class pinnacle.tag.v1.RemoveRequest
+---@field tag_ids integer[]?
+
+---@class pinnacle.tag.v1.MoveToOutputRequest
+---@field output_name string?
+---@field tag_ids integer[]?
+
+---@class pinnacle.tag.v1.MoveToOutputResponse
+---@field error pinnacle.tag.v1.MoveToOutputResponse.Error?
+
+---@class pinnacle.tag.v1.MoveToOutputResponse.Error
+---@field output_does_not_exist google.protobuf.Empty?
+---@field same_window_on_two_outputs pinnacle.tag.v1.MoveToOutputResponse.Error.SameWindowOnTwoOutputs?
+
+---@class pinnacle.tag.v1.MoveToOutputResponse.Error.SameWindowOnTwoOutputs
+---@field window_ids integer[]?
+
+---@class pinnacle.tag.v1.MoveToIndexRequest
+---@field tag_id integer?
+---@field index integer?
+
+---@class pinnacle.tag.v1.ExistsRequest
+---@field tag_id integer?
+
+---@class pinnacle.tag.v1.ExistsResponse
+---@field exists boolean?
+
+---@class pinnacle.tag.v1.GetActiveRequest
+---@field tag_id integer?
+
+---@class pinnacle.tag.v1.GetActiveResponse
+---@field active boolean?
+
+---@class pinnacle.tag.v1.GetUrgentRequest
+---@field tag_id integer?
+
+---@class pinnacle.tag.v1.GetUrgentResponse
+---@field urgent boolean?
+
+---@class pinnacle.tag.v1.GetNameRequest
+---@field tag_id integer?
+
+---@class pinnacle.tag.v1.GetNameResponse
+---@field name string?
+
+---@class pinnacle.tag.v1.GetOutputNameRequest
+---@field tag_id integer?
+
+---@class pinnacle.tag.v1.GetOutputNameResponse
+---@field output_name string?
+
+---@class pinnacle.tag.v1.SetActiveRequest
+---@field tag_id integer?
+---@field set_or_toggle pinnacle.util.v1.SetOrToggle?
+
+---@class pinnacle.tag.v1.SwitchToRequest
+---@field tag_id integer?
+
+---@class pinnacle.tag.v1.SwitchToMatchingRequest
+---@field output_name string?
+---@field index integer?
+---@field name string?
+
+---@class pinnacle.tag.v1.SwitchToMatchingResponse
+---@field tag_id integer?
+
+---@class pinnacle.tag.v1.SwitchToPreviousRequest
+---@field output_name string?
+
+---@class pinnacle.tag.v1.SwitchToPreviousResponse
+---@field tag_ids integer[]?
+
+---@class pinnacle.tag.v1.PeekRequest
+---@field tag_id integer?
+---@field millis integer?
+
+---@class pinnacle.tag.v1.AddEffectRuleRequest
+---@field tag_id integer?
+---@field target pinnacle.tag.v1.EffectRuleTarget?
+---@field opacity number?
+---@field dim number?
+---@field desaturate number?
+
+---@class pinnacle.tag.v1.SetEmptyOutputPolicyRequest
+---@field policy pinnacle.tag.v1.EmptyOutputPolicy?
+---@field fallback_tag_name string?
+
+---@class pinnacle.tag.v1.PagerRequest
+
+---@class pinnacle.tag.v1.PagerResponse
+---@field outputs pinnacle.tag.v1.PagerResponse.Output[]?
+
+---@class pinnacle.tag.v1.PagerResponse.Window
+---@field window_id integer?
+---@field title string?
+---@field app_id string?
+---@field geometry pinnacle.util.v1.Rect?
+
+---@class pinnacle.tag.v1.PagerResponse.Tag
+---@field tag_id integer?
+---@field name string?
+---@field active boolean?
+---@field urgent boolean?
+---@field occupied boolean?
+---@field windows pinnacle.tag.v1.PagerResponse.Window[]?
+
+---@class pinnacle.tag.v1.PagerResponse.Output
+---@field output_name string?
+---@field focused boolean?
+---@field tags pinnacle.tag.v1.PagerResponse.Tag[]?
+---@field focused_window_id integer?
+---@field focused_window_title string?
+---@field size pinnacle.util.v1.Size?
+
+---@class pinnacle.v1.QuitRequest
+
+---@class pinnacle.v1.RestartRequest
+
+---@class pinnacle.v1.ReloadConfigRequest
+
+---@class pinnacle.v1.KeepaliveRequest
+
+---@class pinnacle.v1.KeepaliveResponse
+
+---@class pinnacle.v1.BackendRequest
+
+---@class pinnacle.v1.BackendResponse
+---@field backend pinnacle.v1.Backend?
+
+---@class pinnacle.v1.SetXwaylandClientSelfScaleRequest
+---@field self_scale boolean?
+
+---@class pinnacle.v1.SetLastErrorRequest
+---@field error string?
+
+---@class pinnacle.v1.TakeLastErrorRequest
+
+---@class pinnacle.v1.TakeLastErrorResponse
+---@field error string?
+
+---@class pinnacle.v1.Setting
+---@field name string?
+---@field description string?
+---@field setter string?
+---@field device_sysname string?
+---@field bool boolean?
+---@field int integer?
+---@field float number?
+---@field choice string?
+---@field choices string[]?
+---@field min number?
+---@field max number?
+
+---@class pinnacle.v1.GetSettingsRequest
+
+---@class pinnacle.v1.GetSettingsResponse
+---@field settings pinnacle.v1.Setting[]?
+
+---@class pinnacle.v1.Histogram
+---@field bucket_bounds_ms number[]?
+---@field bucket_counts integer[]?
+---@field sum_ms number?
+---@field count integer?
+
+---@class pinnacle.v1.Profile
+---@field inhibit_idle boolean?
+---@field disable_inactive_effect boolean?
+---@field grey_out_unresponsive boolean?
+---@field letterbox_fullscreen boolean?
+
+---@class pinnacle.v1.DefineProfileRequest
+---@field name string?
+---@field profile pinnacle.v1.Profile?
+
+---@class pinnacle.v1.DefineProfileResponse
+
+---@class pinnacle.v1.SetProfileRequest
+---@field name string?
+
+---@class pinnacle.v1.SetProfileResponse
+---@field error string?
+
+---@class pinnacle.v1.GetProfileRequest
+
+---@class pinnacle.v1.GetProfileResponse
+---@field name string?
+
+---@class pinnacle.v1.MetricsRequest
+
+---@class pinnacle.v1.MetricsResponse
+---@field input_latency pinnacle.v1.Histogram?
+---@field layout_time pinnacle.v1.Histogram?
+---@field missed_frames integer?
+
+---@class pinnacle.v1.RegisterNamespaceRequest
+---@field namespace string?
+
+---@class pinnacle.v1.RegisterNamespaceResponse
+
+---@class pinnacle.v1.SetMetricsExportRequest
+---@field enabled boolean?
+
+---@class pinnacle.v1.SetMetricsExportResponse
+---@field socket_path string?
+
+local google = {}
+google.protobuf = {}
+google.protobuf.Empty = {}
+local pinnacle = {}
+pinnacle.util = {}
+pinnacle.util.v1 = {}
+pinnacle.util.v1.Point = {}
+pinnacle.util.v1.Size = {}
+pinnacle.util.v1.Rect = {}
+pinnacle.util.v1.Color = {}
+pinnacle.debug = {}
+pinnacle.debug.v1 = {}
+pinnacle.debug.v1.SetDamageVisualizationRequest = {}
+pinnacle.debug.v1.SetOpaqueRegionVisualizationRequest = {}
+pinnacle.debug.v1.SetCursorPlaneScanoutRequest = {}
+pinnacle.debug.v1.SetProcessPipingRequest = {}
+pinnacle.debug.v1.SetInputTracingRequest = {}
+pinnacle.debug.v1.TraceInputRequest = {}
+pinnacle.debug.v1.TraceInputResponse = {}
+pinnacle.debug.v1.TraceInputResponse.FocusTarget = {}
+pinnacle.debug.v1.GetGpuStatsRequest = {}
+pinnacle.debug.v1.GetGpuStatsResponse = {}
+pinnacle.debug.v1.SetLogFilterRequest = {}
+pinnacle.debug.v1.SetLogFilterResponse = {}
+pinnacle.debug.v1.TailLogsRequest = {}
+pinnacle.debug.v1.TailLogsResponse = {}
+pinnacle.idle = {}
+pinnacle.idle.v1 = {}
+pinnacle.idle.v1.Shader = {}
+pinnacle.idle.v1.Slideshow = {}
+pinnacle.idle.v1.Screensaver = {}
+pinnacle.idle.v1.SetScreensaverRequest = {}
+pinnacle.idle.v1.SetIdleTimeoutRequest = {}
 pinnacle.output = {}
 pinnacle.output.v1 = {}
 pinnacle.output.v1.SetLocRequest = {}
 pinnacle.output.v1.SetModeRequest = {}
 pinnacle.output.v1.Modeline = {}
 pinnacle.output.v1.SetModelineRequest = {}
+pinnacle.output.v1.ConfirmModeChangeRequest = {}
+pinnacle.output.v1.ConfirmModeChangeResponse = {}
 pinnacle.output.v1.SetScaleRequest = {}
 pinnacle.output.v1.SetTransformRequest = {}
 pinnacle.output.v1.SetPoweredRequest = {}
 pinnacle.output.v1.SetVrrRequest = {}
 pinnacle.output.v1.SetVrrResponse = {}
+pinnacle.output.v1.SetXcursorRequest = {}
+pinnacle.output.v1.SetXcursorResponse = {}
+pinnacle.output.v1.SetHotplugSettleDelayRequest = {}
+pinnacle.output.v1.SetHotplugSettleDelayResponse = {}
+pinnacle.output.v1.SetColorFilterRequest = {}
+pinnacle.output.v1.SetColorFilterResponse = {}
+pinnacle.output.v1.SetAutoRotateRequest = {}
+pinnacle.output.v1.SetAutoRotateResponse = {}
+pinnacle.output.v1.SetHdrRequest = {}
+pinnacle.output.v1.SetHdrResponse = {}
+pinnacle.output.v1.SetColorDepthRequest = {}
+pinnacle.output.v1.SetColorDepthResponse = {}
+pinnacle.output.v1.SetSrgbEmulationRequest = {}
+pinnacle.output.v1.SetSrgbEmulationResponse = {}
 pinnacle.output.v1.FocusRequest = {}
 pinnacle.output.v1.FocusResponse = {}
 pinnacle.output.v1.GetRequest = {}
 pinnacle.output.v1.GetResponse = {}
 pinnacle.output.v1.GetInfoRequest = {}
+pinnacle.output.v1.Chromaticity = {}
+pinnacle.output.v1.ColorPrimaries = {}
+pinnacle.output.v1.HdrMetadata = {}
+pinnacle.output.v1.Edid = {}
 pinnacle.output.v1.GetInfoResponse = {}
 pinnacle.output.v1.GetLocRequest = {}
 pinnacle.output.v1.GetLocResponse = {}
@@ -1512,10 +2593,106 @@ pinnacle.output.v1.GetEnabledRequest = {}
 pinnacle.output.v1.GetEnabledResponse = {}
 pinnacle.output.v1.GetPoweredRequest = {}
 pinnacle.output.v1.GetPoweredResponse = {}
+pinnacle.output.v1.GetHdrRequest = {}
+pinnacle.output.v1.GetHdrResponse = {}
+pinnacle.output.v1.GetColorDepthRequest = {}
+pinnacle.output.v1.GetColorDepthResponse = {}
+pinnacle.output.v1.GetSrgbEmulationRequest = {}
+pinnacle.output.v1.GetSrgbEmulationResponse = {}
+pinnacle.output.v1.GetRenderStatsRequest = {}
+pinnacle.output.v1.GetRenderStatsResponse = {}
 pinnacle.output.v1.GetFocusStackWindowIdsRequest = {}
 pinnacle.output.v1.GetFocusStackWindowIdsResponse = {}
+pinnacle.output.v1.GetStackingOrderRequest = {}
+pinnacle.output.v1.GetStackingOrderResponse = {}
 pinnacle.output.v1.GetOutputsInDirRequest = {}
 pinnacle.output.v1.GetOutputsInDirResponse = {}
+pinnacle.output.v1.WatchPropertiesRequest = {}
+pinnacle.output.v1.WatchPropertiesResponse = {}
+pinnacle.input = {}
+pinnacle.input.v1 = {}
+pinnacle.input.v1.Bind = {}
+pinnacle.input.v1.BindRequest = {}
+pinnacle.input.v1.BindResponse = {}
+pinnacle.input.v1.BindConflict = {}
+pinnacle.input.v1.BindProperties = {}
+pinnacle.input.v1.SetBindPropertiesRequest = {}
+pinnacle.input.v1.Keybind = {}
+pinnacle.input.v1.KeybindStreamRequest = {}
+pinnacle.input.v1.KeybindStreamResponse = {}
+pinnacle.input.v1.KeybindOnPressRequest = {}
+pinnacle.input.v1.Mousebind = {}
+pinnacle.input.v1.MousebindStreamRequest = {}
+pinnacle.input.v1.MousebindStreamResponse = {}
+pinnacle.input.v1.MousebindOnPressRequest = {}
+pinnacle.input.v1.GetBindInfosRequest = {}
+pinnacle.input.v1.GetBindInfosResponse = {}
+pinnacle.input.v1.BindInfo = {}
+pinnacle.input.v1.GetBindLayerStackRequest = {}
+pinnacle.input.v1.GetBindLayerStackResponse = {}
+pinnacle.input.v1.EnterBindLayerRequest = {}
+pinnacle.input.v1.SetBindLayerConflictPolicyRequest = {}
+pinnacle.input.v1.SetXkbConfigRequest = {}
+pinnacle.input.v1.SetRepeatRateRequest = {}
+pinnacle.input.v1.SetXkbKeymapRequest = {}
+pinnacle.input.v1.SwitchXkbLayoutRequest = {}
+pinnacle.input.v1.SetStickyKeysRequest = {}
+pinnacle.input.v1.SetStickyKeysResponse = {}
+pinnacle.input.v1.SetSlowKeysRequest = {}
+pinnacle.input.v1.SetSlowKeysResponse = {}
+pinnacle.input.v1.SetHoldToMoveRequest = {}
+pinnacle.input.v1.SetHoldToMoveResponse = {}
+pinnacle.input.v1.SetPointerCrossingRequest = {}
+pinnacle.input.v1.SetEdgeActionRequest = {}
+pinnacle.input.v1.SetEdgeActionResponse = {}
+pinnacle.input.v1.EdgeActionStreamRequest = {}
+pinnacle.input.v1.EdgeActionStreamResponse = {}
+pinnacle.input.v1.RemapMacro = {}
+pinnacle.input.v1.RemapRequest = {}
+pinnacle.input.v1.RemapResponse = {}
+pinnacle.input.v1.ClearRemapsRequest = {}
+pinnacle.input.v1.ClearRemapsResponse = {}
+pinnacle.input.v1.SetXcursorRequest = {}
+pinnacle.input.v1.CalibrationMatrix = {}
+pinnacle.input.v1.GetDevicesRequest = {}
+pinnacle.input.v1.GetDevicesResponse = {}
+pinnacle.input.v1.GetDeviceCapabilitiesRequest = {}
+pinnacle.input.v1.GetDeviceCapabilitiesResponse = {}
+pinnacle.input.v1.GetDeviceInfoRequest = {}
+pinnacle.input.v1.GetDeviceInfoResponse = {}
+pinnacle.input.v1.GetDeviceTypeRequest = {}
+pinnacle.input.v1.GetDeviceTypeResponse = {}
+pinnacle.input.v1.SetDeviceLibinputSettingRequest = {}
+pinnacle.input.v1.SetDeviceMapTargetRequest = {}
+pinnacle.layout = {}
+pinnacle.layout.v1 = {}
+pinnacle.layout.v1.Gaps = {}
+pinnacle.layout.v1.LayoutNode = {}
+pinnacle.layout.v1.LayoutNode.TraversalOverridesEntry = {}
+pinnacle.layout.v1.TraversalOverrides = {}
+pinnacle.layout.v1.NodeStyle = {}
+pinnacle.layout.v1.LayoutRequest = {}
+pinnacle.layout.v1.LayoutRequest.TreeResponse = {}
+pinnacle.layout.v1.LayoutRequest.ForceLayout = {}
+pinnacle.layout.v1.LayoutResponse = {}
+pinnacle.nightlight = {}
+pinnacle.nightlight.v1 = {}
+pinnacle.nightlight.v1.ScheduleTime = {}
+pinnacle.nightlight.v1.Location = {}
+pinnacle.nightlight.v1.Schedule = {}
+pinnacle.nightlight.v1.SetScheduleRequest = {}
+pinnacle.nightlight.v1.SetScheduleResponse = {}
+pinnacle.portal = {}
+pinnacle.portal.v1 = {}
+pinnacle.portal.v1.GlobalShortcut = {}
+pinnacle.portal.v1.GlobalShortcutsPermissionRequest = {}
+pinnacle.portal.v1.GlobalShortcutsPermissionRequest.Decision = {}
+pinnacle.portal.v1.GlobalShortcutsPermissionResponse = {}
+pinnacle.portal.v1.GlobalShortcutsPermissionResponse.NewRequest = {}
+pinnacle.power = {}
+pinnacle.power.v1 = {}
+pinnacle.power.v1.GetStatusRequest = {}
+pinnacle.power.v1.GetStatusResponse = {}
 pinnacle.process = {}
 pinnacle.process.v1 = {}
 pinnacle.process.v1.SpawnRequest = {}
@@ -1525,14 +2702,30 @@ pinnacle.process.v1.SpawnResponse = {}
 pinnacle.process.v1.WaitOnSpawnRequest = {}
 pinnacle.process.v1.WaitOnSpawnResponse = {}
 pinnacle.process.v1.SetEnvRequest = {}
+pinnacle.process.v1.SetGlobalEnvRequest = {}
+pinnacle.process.v1.SetGlobalEnvResponse = {}
+pinnacle.process.v1.RunOrRaiseRequest = {}
+pinnacle.process.v1.RunOrRaiseResponse = {}
 pinnacle.render = {}
 pinnacle.render.v1 = {}
 pinnacle.render.v1.SetUpscaleFilterRequest = {}
 pinnacle.render.v1.SetDownscaleFilterRequest = {}
+pinnacle.render.v1.SetInactiveEffectRequest = {}
+pinnacle.render.v1.SetInactiveEffectResponse = {}
+pinnacle.render.v1.SetLetterboxFullscreenRequest = {}
+pinnacle.render.v1.SetLetterboxFullscreenResponse = {}
+pinnacle.render.v1.SetCloseSnapshotHoldRequest = {}
+pinnacle.render.v1.SetCloseSnapshotHoldResponse = {}
+pinnacle.screenshot = {}
+pinnacle.screenshot.v1 = {}
+pinnacle.screenshot.v1.SelectRegionRequest = {}
+pinnacle.screenshot.v1.SelectRegionResponse = {}
 pinnacle.window = {}
 pinnacle.window.v1 = {}
 pinnacle.window.v1.GetRequest = {}
 pinnacle.window.v1.GetResponse = {}
+pinnacle.window.v1.ExistsRequest = {}
+pinnacle.window.v1.ExistsResponse = {}
 pinnacle.window.v1.GetAppIdRequest = {}
 pinnacle.window.v1.GetAppIdResponse = {}
 pinnacle.window.v1.GetTitleRequest = {}
@@ -1543,19 +2736,45 @@ pinnacle.window.v1.GetSizeRequest = {}
 pinnacle.window.v1.GetSizeResponse = {}
 pinnacle.window.v1.GetFocusedRequest = {}
 pinnacle.window.v1.GetFocusedResponse = {}
+pinnacle.window.v1.GetFocusAgeRequest = {}
+pinnacle.window.v1.GetFocusAgeResponse = {}
+pinnacle.window.v1.GetPreviouslyFocusedRequest = {}
+pinnacle.window.v1.GetPreviouslyFocusedResponse = {}
+pinnacle.window.v1.FindRequest = {}
+pinnacle.window.v1.FindResponse = {}
+pinnacle.window.v1.GetResponsiveRequest = {}
+pinnacle.window.v1.GetResponsiveResponse = {}
 pinnacle.window.v1.GetLayoutModeRequest = {}
 pinnacle.window.v1.GetLayoutModeResponse = {}
+pinnacle.window.v1.GetMinimizedRequest = {}
+pinnacle.window.v1.GetMinimizedResponse = {}
+pinnacle.window.v1.GetDemandsAttentionRequest = {}
+pinnacle.window.v1.GetDemandsAttentionResponse = {}
+pinnacle.window.v1.IconImage = {}
+pinnacle.window.v1.GetIconRequest = {}
+pinnacle.window.v1.GetIconResponse = {}
+pinnacle.window.v1.GetLayerRequest = {}
+pinnacle.window.v1.GetLayerResponse = {}
+pinnacle.window.v1.GetParentRequest = {}
+pinnacle.window.v1.GetParentResponse = {}
+pinnacle.window.v1.GetChildrenRequest = {}
+pinnacle.window.v1.GetChildrenResponse = {}
 pinnacle.window.v1.GetTagIdsRequest = {}
 pinnacle.window.v1.GetTagIdsResponse = {}
 pinnacle.window.v1.GetWindowsInDirRequest = {}
 pinnacle.window.v1.GetWindowsInDirResponse = {}
 pinnacle.window.v1.GetForeignToplevelListIdentifierRequest = {}
 pinnacle.window.v1.GetForeignToplevelListIdentifierResponse = {}
+pinnacle.window.v1.WatchPropertiesRequest = {}
+pinnacle.window.v1.TagIds = {}
+pinnacle.window.v1.WatchPropertiesResponse = {}
 pinnacle.window.v1.CloseRequest = {}
 pinnacle.window.v1.SetGeometryRequest = {}
 pinnacle.window.v1.ResizeTileRequest = {}
 pinnacle.window.v1.SetFullscreenRequest = {}
 pinnacle.window.v1.SetMaximizedRequest = {}
+pinnacle.window.v1.SetMinimizedRequest = {}
+pinnacle.window.v1.SetDemandsAttentionRequest = {}
 pinnacle.window.v1.SetFloatingRequest = {}
 pinnacle.window.v1.SetFocusedRequest = {}
 pinnacle.window.v1.SetDecorationModeRequest = {}
@@ -1573,8 +2792,62 @@ pinnacle.window.v1.LowerRequest = {}
 pinnacle.window.v1.LowerResponse = {}
 pinnacle.window.v1.MoveGrabRequest = {}
 pinnacle.window.v1.ResizeGrabRequest = {}
+pinnacle.window.v1.FocusInDirectionRequest = {}
+pinnacle.window.v1.FocusInDirectionResponse = {}
+pinnacle.window.v1.BeginCycleRequest = {}
+pinnacle.window.v1.BeginCycleResponse = {}
+pinnacle.window.v1.CycleRequest = {}
+pinnacle.window.v1.CycleResponse = {}
+pinnacle.window.v1.EndCycleRequest = {}
+pinnacle.window.v1.EndCycleResponse = {}
 pinnacle.window.v1.SwapRequest = {}
 pinnacle.window.v1.SwapResponse = {}
+pinnacle.window.v1.SwapInDirectionRequest = {}
+pinnacle.window.v1.SwapInDirectionResponse = {}
+pinnacle.window.v1.SetFloatingVisibilityRequest = {}
+pinnacle.window.v1.SetFloatingVisibilityRequest.Unrestricted = {}
+pinnacle.window.v1.SetFloatingVisibilityRequest.KeepVisible = {}
+pinnacle.window.v1.SetFloatingVisibilityRequest.ClampToOutputs = {}
+pinnacle.window.v1.SetFloatingVisibilityResponse = {}
+pinnacle.window.v1.SetAllowOffscreenRequest = {}
+pinnacle.window.v1.SetAllowOffscreenResponse = {}
+pinnacle.window.v1.SetInputEnabledRequest = {}
+pinnacle.window.v1.SetInputEnabledResponse = {}
+pinnacle.window.v1.SetHoldToMoveEnabledRequest = {}
+pinnacle.window.v1.SetHoldToMoveEnabledResponse = {}
+pinnacle.window.v1.SetScaleOverrideRequest = {}
+pinnacle.window.v1.SetScaleOverrideResponse = {}
+pinnacle.window.v1.SnapGridCell = {}
+pinnacle.window.v1.SnapToRegionRequest = {}
+pinnacle.window.v1.SnapToRegionResponse = {}
+pinnacle.window.v1.SetSnapGridRequest = {}
+pinnacle.window.v1.SetSnapGridResponse = {}
+pinnacle.window.v1.SetPopupPolicyRequest = {}
+pinnacle.window.v1.SetPopupPolicyResponse = {}
+pinnacle.window.v1.SetGreyOutUnresponsiveRequest = {}
+pinnacle.window.v1.SetGreyOutUnresponsiveResponse = {}
+pinnacle.window.v1.SetMaximizeRequestPolicyRequest = {}
+pinnacle.window.v1.SetMaximizeRequestPolicyResponse = {}
+pinnacle.window.v1.SetTagInheritanceRequest = {}
+pinnacle.window.v1.SetTagInheritanceResponse = {}
+pinnacle.window.v1.SetDialogPolicyRequest = {}
+pinnacle.window.v1.SetDialogPolicyResponse = {}
+pinnacle.window.v1.SetLayerRequest = {}
+pinnacle.window.v1.SetLayerResponse = {}
+pinnacle.window.v1.SizeConstraints = {}
+pinnacle.window.v1.SetSizeConstraintsRequest = {}
+pinnacle.window.v1.SetSizeConstraintsResponse = {}
+pinnacle.window.v1.BorderStyle = {}
+pinnacle.window.v1.SetBorderStyleRequest = {}
+pinnacle.window.v1.SetBorderStyleResponse = {}
+pinnacle.window.v1.DeclarativeWindowRule = {}
+pinnacle.window.v1.SetWindowRulesRequest = {}
+pinnacle.window.v1.SetWindowRulesResponse = {}
+pinnacle.window.v1.GetMatchingWindowRulesRequest = {}
+pinnacle.window.v1.GetMatchingWindowRulesResponse = {}
+pinnacle.window.v1.ApplyRulesToExistingRequest = {}
+pinnacle.window.v1.ApplyRulesToExistingResponse = {}
+pinnacle.window.v1.ApplyRulesToExistingResponse.WindowChanges = {}
 pinnacle.window.v1.WindowRuleRequest = {}
 pinnacle.window.v1.WindowRuleRequest.Finished = {}
 pinnacle.window.v1.WindowRuleResponse = {}
@@ -1593,8 +2866,22 @@ pinnacle.signal.v1.OutputPointerEnterRequest = {}
 pinnacle.signal.v1.OutputPointerEnterResponse = {}
 pinnacle.signal.v1.OutputPointerLeaveRequest = {}
 pinnacle.signal.v1.OutputPointerLeaveResponse = {}
+pinnacle.signal.v1.OutputPointerEdgeRequest = {}
+pinnacle.signal.v1.OutputPointerEdgeResponse = {}
 pinnacle.signal.v1.OutputFocusedRequest = {}
 pinnacle.signal.v1.OutputFocusedResponse = {}
+pinnacle.signal.v1.OutputLayerFocusedRequest = {}
+pinnacle.signal.v1.OutputLayerFocusedResponse = {}
+pinnacle.signal.v1.OutputLayerUnfocusedRequest = {}
+pinnacle.signal.v1.OutputLayerUnfocusedResponse = {}
+pinnacle.signal.v1.OutputOrientationChangedRequest = {}
+pinnacle.signal.v1.OutputOrientationChangedResponse = {}
+pinnacle.signal.v1.OutputFullscreenChangedRequest = {}
+pinnacle.signal.v1.OutputFullscreenChangedResponse = {}
+pinnacle.signal.v1.OutputStackingChangedRequest = {}
+pinnacle.signal.v1.OutputStackingChangedResponse = {}
+pinnacle.signal.v1.OutputNoActiveTagsRequest = {}
+pinnacle.signal.v1.OutputNoActiveTagsResponse = {}
 pinnacle.signal.v1.WindowPointerEnterRequest = {}
 pinnacle.signal.v1.WindowPointerEnterResponse = {}
 pinnacle.signal.v1.WindowPointerLeaveRequest = {}
@@ -1603,20 +2890,44 @@ pinnacle.signal.v1.WindowFocusedRequest = {}
 pinnacle.signal.v1.WindowFocusedResponse = {}
 pinnacle.signal.v1.WindowTitleChangedRequest = {}
 pinnacle.signal.v1.WindowTitleChangedResponse = {}
+pinnacle.signal.v1.WindowFocusedTitleChangedRequest = {}
+pinnacle.signal.v1.WindowFocusedTitleChangedResponse = {}
 pinnacle.signal.v1.WindowLayoutModeChangedRequest = {}
 pinnacle.signal.v1.WindowLayoutModeChangedResponse = {}
 pinnacle.signal.v1.WindowCreatedRequest = {}
 pinnacle.signal.v1.WindowCreatedResponse = {}
 pinnacle.signal.v1.WindowDestroyedRequest = {}
 pinnacle.signal.v1.WindowDestroyedResponse = {}
+pinnacle.signal.v1.WindowUnresponsiveRequest = {}
+pinnacle.signal.v1.WindowUnresponsiveResponse = {}
+pinnacle.signal.v1.WindowMinimizedRequest = {}
+pinnacle.signal.v1.WindowMinimizedResponse = {}
+pinnacle.signal.v1.WindowUrgencyChangedRequest = {}
+pinnacle.signal.v1.WindowUrgencyChangedResponse = {}
+pinnacle.signal.v1.WindowPopupGrabBlockedKeybindRequest = {}
+pinnacle.signal.v1.WindowPopupGrabBlockedKeybindResponse = {}
 pinnacle.signal.v1.TagActiveRequest = {}
 pinnacle.signal.v1.TagActiveResponse = {}
 pinnacle.signal.v1.TagCreatedRequest = {}
 pinnacle.signal.v1.TagCreatedResponse = {}
 pinnacle.signal.v1.TagRemovedRequest = {}
 pinnacle.signal.v1.TagRemovedResponse = {}
+pinnacle.signal.v1.TagUrgencyChangedRequest = {}
+pinnacle.signal.v1.TagUrgencyChangedResponse = {}
 pinnacle.signal.v1.InputDeviceAddedRequest = {}
 pinnacle.signal.v1.InputDeviceAddedResponse = {}
+pinnacle.signal.v1.InputAccessibilityChangedRequest = {}
+pinnacle.signal.v1.InputAccessibilityChangedResponse = {}
+pinnacle.signal.v1.PowerOnBatteryRequest = {}
+pinnacle.signal.v1.PowerOnBatteryResponse = {}
+pinnacle.signal.v1.PowerChargingRequest = {}
+pinnacle.signal.v1.PowerChargingResponse = {}
+pinnacle.signal.v1.PowerLowBatteryRequest = {}
+pinnacle.signal.v1.PowerLowBatteryResponse = {}
+pinnacle.signal.v1.IdleStartedRequest = {}
+pinnacle.signal.v1.IdleStartedResponse = {}
+pinnacle.signal.v1.IdleResumedRequest = {}
+pinnacle.signal.v1.IdleResumedResponse = {}
 pinnacle.tag = {}
 pinnacle.tag.v1 = {}
 pinnacle.tag.v1.GetRequest = {}
@@ -1628,16 +2939,34 @@ pinnacle.tag.v1.MoveToOutputRequest = {}
 pinnacle.tag.v1.MoveToOutputResponse = {}
 pinnacle.tag.v1.MoveToOutputResponse.Error = {}
 pinnacle.tag.v1.MoveToOutputResponse.Error.SameWindowOnTwoOutputs = {}
+pinnacle.tag.v1.MoveToIndexRequest = {}
+pinnacle.tag.v1.ExistsRequest = {}
+pinnacle.tag.v1.ExistsResponse = {}
 pinnacle.tag.v1.GetActiveRequest = {}
 pinnacle.tag.v1.GetActiveResponse = {}
+pinnacle.tag.v1.GetUrgentRequest = {}
+pinnacle.tag.v1.GetUrgentResponse = {}
 pinnacle.tag.v1.GetNameRequest = {}
 pinnacle.tag.v1.GetNameResponse = {}
 pinnacle.tag.v1.GetOutputNameRequest = {}
 pinnacle.tag.v1.GetOutputNameResponse = {}
 pinnacle.tag.v1.SetActiveRequest = {}
 pinnacle.tag.v1.SwitchToRequest = {}
+pinnacle.tag.v1.SwitchToMatchingRequest = {}
+pinnacle.tag.v1.SwitchToMatchingResponse = {}
+pinnacle.tag.v1.SwitchToPreviousRequest = {}
+pinnacle.tag.v1.SwitchToPreviousResponse = {}
+pinnacle.tag.v1.PeekRequest = {}
+pinnacle.tag.v1.AddEffectRuleRequest = {}
+pinnacle.tag.v1.SetEmptyOutputPolicyRequest = {}
+pinnacle.tag.v1.PagerRequest = {}
+pinnacle.tag.v1.PagerResponse = {}
+pinnacle.tag.v1.PagerResponse.Window = {}
+pinnacle.tag.v1.PagerResponse.Tag = {}
+pinnacle.tag.v1.PagerResponse.Output = {}
 pinnacle.v1 = {}
 pinnacle.v1.QuitRequest = {}
+pinnacle.v1.RestartRequest = {}
 pinnacle.v1.ReloadConfigRequest = {}
 pinnacle.v1.KeepaliveRequest = {}
 pinnacle.v1.KeepaliveResponse = {}
@@ -1647,11 +2976,36 @@ pinnacle.v1.SetXwaylandClientSelfScaleRequest = {}
 pinnacle.v1.SetLastErrorRequest = {}
 pinnacle.v1.TakeLastErrorRequest = {}
 pinnacle.v1.TakeLastErrorResponse = {}
+pinnacle.v1.Setting = {}
+pinnacle.v1.GetSettingsRequest = {}
+pinnacle.v1.GetSettingsResponse = {}
+pinnacle.v1.Histogram = {}
+pinnacle.v1.Profile = {}
+pinnacle.v1.DefineProfileRequest = {}
+pinnacle.v1.DefineProfileResponse = {}
+pinnacle.v1.SetProfileRequest = {}
+pinnacle.v1.SetProfileResponse = {}
+pinnacle.v1.GetProfileRequest = {}
+pinnacle.v1.GetProfileResponse = {}
+pinnacle.v1.MetricsRequest = {}
+pinnacle.v1.MetricsResponse = {}
+pinnacle.v1.RegisterNamespaceRequest = {}
+pinnacle.v1.RegisterNamespaceResponse = {}
+pinnacle.v1.SetMetricsExportRequest = {}
+pinnacle.v1.SetMetricsExportResponse = {}
 pinnacle.util.v1.SetOrToggle = pinnacle_util_v1_SetOrToggle
 pinnacle.util.v1.AbsOrRel = pinnacle_util_v1_AbsOrRel
 pinnacle.util.v1.Dir = pinnacle_util_v1_Dir
+pinnacle.output.v1.Transform = pinnacle_output_v1_Transform
+pinnacle.output.v1.ScreenEdge = pinnacle_output_v1_ScreenEdge
+pinnacle.output.v1.Vrr = pinnacle_output_v1_Vrr
+pinnacle.output.v1.ColorFilter = pinnacle_output_v1_ColorFilter
+pinnacle.output.v1.ClearableProperty = pinnacle_output_v1_ClearableProperty
 pinnacle.input.v1.Modifier = pinnacle_input_v1_Modifier
 pinnacle.input.v1.Edge = pinnacle_input_v1_Edge
+pinnacle.input.v1.BindConflictPolicy = pinnacle_input_v1_BindConflictPolicy
+pinnacle.input.v1.PointerCrossing = pinnacle_input_v1_PointerCrossing
+pinnacle.input.v1.EdgeAction = pinnacle_input_v1_EdgeAction
 pinnacle.input.v1.ClickMethod = pinnacle_input_v1_ClickMethod
 pinnacle.input.v1.AccelProfile = pinnacle_input_v1_AccelProfile
 pinnacle.input.v1.ScrollMethod = pinnacle_input_v1_ScrollMethod
@@ -1659,1400 +3013,3170 @@ pinnacle.input.v1.TapButtonMap = pinnacle_input_v1_TapButtonMap
 pinnacle.input.v1.SendEventsMode = pinnacle_input_v1_SendEventsMode
 pinnacle.input.v1.DeviceType = pinnacle_input_v1_DeviceType
 pinnacle.layout.v1.FlexDir = pinnacle_layout_v1_FlexDir
-pinnacle.output.v1.Transform = pinnacle_output_v1_Transform
-pinnacle.output.v1.Vrr = pinnacle_output_v1_Vrr
+pinnacle.nightlight.v1.SunEvent = pinnacle_nightlight_v1_SunEvent
 pinnacle.render.v1.Filter = pinnacle_render_v1_Filter
+pinnacle.render.v1.InactiveEffectTarget = pinnacle_render_v1_InactiveEffectTarget
+pinnacle.window.v1.FindMode = pinnacle_window_v1_FindMode
 pinnacle.window.v1.LayoutMode = pinnacle_window_v1_LayoutMode
+pinnacle.window.v1.WindowLayer = pinnacle_window_v1_WindowLayer
+pinnacle.window.v1.ClearableProperty = pinnacle_window_v1_ClearableProperty
+pinnacle.window.v1.FocusReason = pinnacle_window_v1_FocusReason
 pinnacle.window.v1.DecorationMode = pinnacle_window_v1_DecorationMode
+pinnacle.window.v1.SnapPreset = pinnacle_window_v1_SnapPreset
+pinnacle.window.v1.MaximizeRequestPolicy = pinnacle_window_v1_MaximizeRequestPolicy
+pinnacle.window.v1.TagInheritance = pinnacle_window_v1_TagInheritance
+pinnacle.window.v1.DialogPolicy = pinnacle_window_v1_DialogPolicy
+pinnacle.window.v1.WindowRuleStrategy = pinnacle_window_v1_WindowRuleStrategy
+pinnacle.window.v1.WindowRuleProperty = pinnacle_window_v1_WindowRuleProperty
 pinnacle.signal.v1.StreamControl = pinnacle_signal_v1_StreamControl
+pinnacle.tag.v1.EffectRuleTarget = pinnacle_tag_v1_EffectRuleTarget
+pinnacle.tag.v1.EmptyOutputPolicy = pinnacle_tag_v1_EmptyOutputPolicy
 pinnacle.v1.Backend = pinnacle_v1_Backend
 
-pinnacle.debug.v1.DebugService = {}
-pinnacle.debug.v1.DebugService.SetDamageVisualization = {}
-pinnacle.debug.v1.DebugService.SetDamageVisualization.service = "pinnacle.debug.v1.DebugService"
-pinnacle.debug.v1.DebugService.SetDamageVisualization.method = "SetDamageVisualization"
-pinnacle.debug.v1.DebugService.SetDamageVisualization.request = ".pinnacle.debug.v1.SetDamageVisualizationRequest"
-pinnacle.debug.v1.DebugService.SetDamageVisualization.response = ".google.protobuf.Empty"
+pinnacle.debug.v1.DebugService = {}
+pinnacle.debug.v1.DebugService.SetDamageVisualization = {}
+pinnacle.debug.v1.DebugService.SetDamageVisualization.service = "pinnacle.debug.v1.DebugService"
+pinnacle.debug.v1.DebugService.SetDamageVisualization.method = "SetDamageVisualization"
+pinnacle.debug.v1.DebugService.SetDamageVisualization.request = ".pinnacle.debug.v1.SetDamageVisualizationRequest"
+pinnacle.debug.v1.DebugService.SetDamageVisualization.response = ".google.protobuf.Empty"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.debug.v1.SetDamageVisualizationRequest
+---
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_debug_v1_DebugService_SetDamageVisualization(data)
+    return self:unary_request(pinnacle.debug.v1.DebugService.SetDamageVisualization, data)
+end
+pinnacle.debug.v1.DebugService.SetOpaqueRegionVisualization = {}
+pinnacle.debug.v1.DebugService.SetOpaqueRegionVisualization.service = "pinnacle.debug.v1.DebugService"
+pinnacle.debug.v1.DebugService.SetOpaqueRegionVisualization.method = "SetOpaqueRegionVisualization"
+pinnacle.debug.v1.DebugService.SetOpaqueRegionVisualization.request = ".pinnacle.debug.v1.SetOpaqueRegionVisualizationRequest"
+pinnacle.debug.v1.DebugService.SetOpaqueRegionVisualization.response = ".google.protobuf.Empty"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.debug.v1.SetOpaqueRegionVisualizationRequest
+---
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_debug_v1_DebugService_SetOpaqueRegionVisualization(data)
+    return self:unary_request(pinnacle.debug.v1.DebugService.SetOpaqueRegionVisualization, data)
+end
+pinnacle.debug.v1.DebugService.SetCursorPlaneScanout = {}
+pinnacle.debug.v1.DebugService.SetCursorPlaneScanout.service = "pinnacle.debug.v1.DebugService"
+pinnacle.debug.v1.DebugService.SetCursorPlaneScanout.method = "SetCursorPlaneScanout"
+pinnacle.debug.v1.DebugService.SetCursorPlaneScanout.request = ".pinnacle.debug.v1.SetCursorPlaneScanoutRequest"
+pinnacle.debug.v1.DebugService.SetCursorPlaneScanout.response = ".google.protobuf.Empty"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.debug.v1.SetCursorPlaneScanoutRequest
+---
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_debug_v1_DebugService_SetCursorPlaneScanout(data)
+    return self:unary_request(pinnacle.debug.v1.DebugService.SetCursorPlaneScanout, data)
+end
+pinnacle.debug.v1.DebugService.SetProcessPiping = {}
+pinnacle.debug.v1.DebugService.SetProcessPiping.service = "pinnacle.debug.v1.DebugService"
+pinnacle.debug.v1.DebugService.SetProcessPiping.method = "SetProcessPiping"
+pinnacle.debug.v1.DebugService.SetProcessPiping.request = ".pinnacle.debug.v1.SetProcessPipingRequest"
+pinnacle.debug.v1.DebugService.SetProcessPiping.response = ".google.protobuf.Empty"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.debug.v1.SetProcessPipingRequest
+---
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_debug_v1_DebugService_SetProcessPiping(data)
+    return self:unary_request(pinnacle.debug.v1.DebugService.SetProcessPiping, data)
+end
+pinnacle.debug.v1.DebugService.SetInputTracing = {}
+pinnacle.debug.v1.DebugService.SetInputTracing.service = "pinnacle.debug.v1.DebugService"
+pinnacle.debug.v1.DebugService.SetInputTracing.method = "SetInputTracing"
+pinnacle.debug.v1.DebugService.SetInputTracing.request = ".pinnacle.debug.v1.SetInputTracingRequest"
+pinnacle.debug.v1.DebugService.SetInputTracing.response = ".google.protobuf.Empty"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.debug.v1.SetInputTracingRequest
+---
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_debug_v1_DebugService_SetInputTracing(data)
+    return self:unary_request(pinnacle.debug.v1.DebugService.SetInputTracing, data)
+end
+pinnacle.debug.v1.DebugService.TraceInput = {}
+pinnacle.debug.v1.DebugService.TraceInput.service = "pinnacle.debug.v1.DebugService"
+pinnacle.debug.v1.DebugService.TraceInput.method = "TraceInput"
+pinnacle.debug.v1.DebugService.TraceInput.request = ".pinnacle.debug.v1.TraceInputRequest"
+pinnacle.debug.v1.DebugService.TraceInput.response = ".pinnacle.debug.v1.TraceInputResponse"
+
+---Performs a server-streaming request.
+---
+---`callback` will be called with every streamed response.
+---
+---@nodiscard
+---
+---@param data pinnacle.debug.v1.TraceInputRequest
+---@param callback fun(response: pinnacle.debug.v1.TraceInputResponse)
+---@param done? fun()
+---
+---@return string | nil An error string, if any
+function Client:pinnacle_debug_v1_DebugService_TraceInput(data, callback, done)
+    return self:server_streaming_request(pinnacle.debug.v1.DebugService.TraceInput, data, callback, done)
+end
+pinnacle.debug.v1.DebugService.GetGpuStats = {}
+pinnacle.debug.v1.DebugService.GetGpuStats.service = "pinnacle.debug.v1.DebugService"
+pinnacle.debug.v1.DebugService.GetGpuStats.method = "GetGpuStats"
+pinnacle.debug.v1.DebugService.GetGpuStats.request = ".pinnacle.debug.v1.GetGpuStatsRequest"
+pinnacle.debug.v1.DebugService.GetGpuStats.response = ".pinnacle.debug.v1.GetGpuStatsResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.debug.v1.GetGpuStatsRequest
+---
+---@return pinnacle.debug.v1.GetGpuStatsResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_debug_v1_DebugService_GetGpuStats(data)
+    return self:unary_request(pinnacle.debug.v1.DebugService.GetGpuStats, data)
+end
+pinnacle.debug.v1.DebugService.SetLogFilter = {}
+pinnacle.debug.v1.DebugService.SetLogFilter.service = "pinnacle.debug.v1.DebugService"
+pinnacle.debug.v1.DebugService.SetLogFilter.method = "SetLogFilter"
+pinnacle.debug.v1.DebugService.SetLogFilter.request = ".pinnacle.debug.v1.SetLogFilterRequest"
+pinnacle.debug.v1.DebugService.SetLogFilter.response = ".pinnacle.debug.v1.SetLogFilterResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.debug.v1.SetLogFilterRequest
+---
+---@return pinnacle.debug.v1.SetLogFilterResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_debug_v1_DebugService_SetLogFilter(data)
+    return self:unary_request(pinnacle.debug.v1.DebugService.SetLogFilter, data)
+end
+pinnacle.debug.v1.DebugService.TailLogs = {}
+pinnacle.debug.v1.DebugService.TailLogs.service = "pinnacle.debug.v1.DebugService"
+pinnacle.debug.v1.DebugService.TailLogs.method = "TailLogs"
+pinnacle.debug.v1.DebugService.TailLogs.request = ".pinnacle.debug.v1.TailLogsRequest"
+pinnacle.debug.v1.DebugService.TailLogs.response = ".pinnacle.debug.v1.TailLogsResponse"
+
+---Performs a server-streaming request.
+---
+---`callback` will be called with every streamed response.
+---
+---@nodiscard
+---
+---@param data pinnacle.debug.v1.TailLogsRequest
+---@param callback fun(response: pinnacle.debug.v1.TailLogsResponse)
+---@param done? fun()
+---
+---@return string | nil An error string, if any
+function Client:pinnacle_debug_v1_DebugService_TailLogs(data, callback, done)
+    return self:server_streaming_request(pinnacle.debug.v1.DebugService.TailLogs, data, callback, done)
+end
+pinnacle.idle.v1.IdleService = {}
+pinnacle.idle.v1.IdleService.SetScreensaver = {}
+pinnacle.idle.v1.IdleService.SetScreensaver.service = "pinnacle.idle.v1.IdleService"
+pinnacle.idle.v1.IdleService.SetScreensaver.method = "SetScreensaver"
+pinnacle.idle.v1.IdleService.SetScreensaver.request = ".pinnacle.idle.v1.SetScreensaverRequest"
+pinnacle.idle.v1.IdleService.SetScreensaver.response = ".google.protobuf.Empty"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.idle.v1.SetScreensaverRequest
+---
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_idle_v1_IdleService_SetScreensaver(data)
+    return self:unary_request(pinnacle.idle.v1.IdleService.SetScreensaver, data)
+end
+pinnacle.idle.v1.IdleService.SetIdleTimeout = {}
+pinnacle.idle.v1.IdleService.SetIdleTimeout.service = "pinnacle.idle.v1.IdleService"
+pinnacle.idle.v1.IdleService.SetIdleTimeout.method = "SetIdleTimeout"
+pinnacle.idle.v1.IdleService.SetIdleTimeout.request = ".pinnacle.idle.v1.SetIdleTimeoutRequest"
+pinnacle.idle.v1.IdleService.SetIdleTimeout.response = ".google.protobuf.Empty"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.idle.v1.SetIdleTimeoutRequest
+---
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_idle_v1_IdleService_SetIdleTimeout(data)
+    return self:unary_request(pinnacle.idle.v1.IdleService.SetIdleTimeout, data)
+end
+pinnacle.output.v1.OutputService = {}
+pinnacle.output.v1.OutputService.Get = {}
+pinnacle.output.v1.OutputService.Get.service = "pinnacle.output.v1.OutputService"
+pinnacle.output.v1.OutputService.Get.method = "Get"
+pinnacle.output.v1.OutputService.Get.request = ".pinnacle.output.v1.GetRequest"
+pinnacle.output.v1.OutputService.Get.response = ".pinnacle.output.v1.GetResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.output.v1.GetRequest
+---
+---@return pinnacle.output.v1.GetResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_output_v1_OutputService_Get(data)
+    return self:unary_request(pinnacle.output.v1.OutputService.Get, data)
+end
+pinnacle.output.v1.OutputService.SetLoc = {}
+pinnacle.output.v1.OutputService.SetLoc.service = "pinnacle.output.v1.OutputService"
+pinnacle.output.v1.OutputService.SetLoc.method = "SetLoc"
+pinnacle.output.v1.OutputService.SetLoc.request = ".pinnacle.output.v1.SetLocRequest"
+pinnacle.output.v1.OutputService.SetLoc.response = ".google.protobuf.Empty"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.output.v1.SetLocRequest
+---
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_output_v1_OutputService_SetLoc(data)
+    return self:unary_request(pinnacle.output.v1.OutputService.SetLoc, data)
+end
+pinnacle.output.v1.OutputService.SetMode = {}
+pinnacle.output.v1.OutputService.SetMode.service = "pinnacle.output.v1.OutputService"
+pinnacle.output.v1.OutputService.SetMode.method = "SetMode"
+pinnacle.output.v1.OutputService.SetMode.request = ".pinnacle.output.v1.SetModeRequest"
+pinnacle.output.v1.OutputService.SetMode.response = ".google.protobuf.Empty"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.output.v1.SetModeRequest
+---
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_output_v1_OutputService_SetMode(data)
+    return self:unary_request(pinnacle.output.v1.OutputService.SetMode, data)
+end
+pinnacle.output.v1.OutputService.SetModeline = {}
+pinnacle.output.v1.OutputService.SetModeline.service = "pinnacle.output.v1.OutputService"
+pinnacle.output.v1.OutputService.SetModeline.method = "SetModeline"
+pinnacle.output.v1.OutputService.SetModeline.request = ".pinnacle.output.v1.SetModelineRequest"
+pinnacle.output.v1.OutputService.SetModeline.response = ".google.protobuf.Empty"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.output.v1.SetModelineRequest
+---
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_output_v1_OutputService_SetModeline(data)
+    return self:unary_request(pinnacle.output.v1.OutputService.SetModeline, data)
+end
+pinnacle.output.v1.OutputService.ConfirmModeChange = {}
+pinnacle.output.v1.OutputService.ConfirmModeChange.service = "pinnacle.output.v1.OutputService"
+pinnacle.output.v1.OutputService.ConfirmModeChange.method = "ConfirmModeChange"
+pinnacle.output.v1.OutputService.ConfirmModeChange.request = ".pinnacle.output.v1.ConfirmModeChangeRequest"
+pinnacle.output.v1.OutputService.ConfirmModeChange.response = ".pinnacle.output.v1.ConfirmModeChangeResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.output.v1.ConfirmModeChangeRequest
+---
+---@return pinnacle.output.v1.ConfirmModeChangeResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_output_v1_OutputService_ConfirmModeChange(data)
+    return self:unary_request(pinnacle.output.v1.OutputService.ConfirmModeChange, data)
+end
+pinnacle.output.v1.OutputService.SetScale = {}
+pinnacle.output.v1.OutputService.SetScale.service = "pinnacle.output.v1.OutputService"
+pinnacle.output.v1.OutputService.SetScale.method = "SetScale"
+pinnacle.output.v1.OutputService.SetScale.request = ".pinnacle.output.v1.SetScaleRequest"
+pinnacle.output.v1.OutputService.SetScale.response = ".google.protobuf.Empty"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.output.v1.SetScaleRequest
+---
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_output_v1_OutputService_SetScale(data)
+    return self:unary_request(pinnacle.output.v1.OutputService.SetScale, data)
+end
+pinnacle.output.v1.OutputService.SetTransform = {}
+pinnacle.output.v1.OutputService.SetTransform.service = "pinnacle.output.v1.OutputService"
+pinnacle.output.v1.OutputService.SetTransform.method = "SetTransform"
+pinnacle.output.v1.OutputService.SetTransform.request = ".pinnacle.output.v1.SetTransformRequest"
+pinnacle.output.v1.OutputService.SetTransform.response = ".google.protobuf.Empty"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.output.v1.SetTransformRequest
+---
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_output_v1_OutputService_SetTransform(data)
+    return self:unary_request(pinnacle.output.v1.OutputService.SetTransform, data)
+end
+pinnacle.output.v1.OutputService.SetPowered = {}
+pinnacle.output.v1.OutputService.SetPowered.service = "pinnacle.output.v1.OutputService"
+pinnacle.output.v1.OutputService.SetPowered.method = "SetPowered"
+pinnacle.output.v1.OutputService.SetPowered.request = ".pinnacle.output.v1.SetPoweredRequest"
+pinnacle.output.v1.OutputService.SetPowered.response = ".google.protobuf.Empty"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.output.v1.SetPoweredRequest
+---
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_output_v1_OutputService_SetPowered(data)
+    return self:unary_request(pinnacle.output.v1.OutputService.SetPowered, data)
+end
+pinnacle.output.v1.OutputService.SetVrr = {}
+pinnacle.output.v1.OutputService.SetVrr.service = "pinnacle.output.v1.OutputService"
+pinnacle.output.v1.OutputService.SetVrr.method = "SetVrr"
+pinnacle.output.v1.OutputService.SetVrr.request = ".pinnacle.output.v1.SetVrrRequest"
+pinnacle.output.v1.OutputService.SetVrr.response = ".pinnacle.output.v1.SetVrrResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.output.v1.SetVrrRequest
+---
+---@return pinnacle.output.v1.SetVrrResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_output_v1_OutputService_SetVrr(data)
+    return self:unary_request(pinnacle.output.v1.OutputService.SetVrr, data)
+end
+pinnacle.output.v1.OutputService.SetXcursor = {}
+pinnacle.output.v1.OutputService.SetXcursor.service = "pinnacle.output.v1.OutputService"
+pinnacle.output.v1.OutputService.SetXcursor.method = "SetXcursor"
+pinnacle.output.v1.OutputService.SetXcursor.request = ".pinnacle.output.v1.SetXcursorRequest"
+pinnacle.output.v1.OutputService.SetXcursor.response = ".pinnacle.output.v1.SetXcursorResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.output.v1.SetXcursorRequest
+---
+---@return pinnacle.output.v1.SetXcursorResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_output_v1_OutputService_SetXcursor(data)
+    return self:unary_request(pinnacle.output.v1.OutputService.SetXcursor, data)
+end
+pinnacle.output.v1.OutputService.SetHotplugSettleDelay = {}
+pinnacle.output.v1.OutputService.SetHotplugSettleDelay.service = "pinnacle.output.v1.OutputService"
+pinnacle.output.v1.OutputService.SetHotplugSettleDelay.method = "SetHotplugSettleDelay"
+pinnacle.output.v1.OutputService.SetHotplugSettleDelay.request = ".pinnacle.output.v1.SetHotplugSettleDelayRequest"
+pinnacle.output.v1.OutputService.SetHotplugSettleDelay.response = ".pinnacle.output.v1.SetHotplugSettleDelayResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.output.v1.SetHotplugSettleDelayRequest
+---
+---@return pinnacle.output.v1.SetHotplugSettleDelayResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_output_v1_OutputService_SetHotplugSettleDelay(data)
+    return self:unary_request(pinnacle.output.v1.OutputService.SetHotplugSettleDelay, data)
+end
+pinnacle.output.v1.OutputService.SetColorFilter = {}
+pinnacle.output.v1.OutputService.SetColorFilter.service = "pinnacle.output.v1.OutputService"
+pinnacle.output.v1.OutputService.SetColorFilter.method = "SetColorFilter"
+pinnacle.output.v1.OutputService.SetColorFilter.request = ".pinnacle.output.v1.SetColorFilterRequest"
+pinnacle.output.v1.OutputService.SetColorFilter.response = ".pinnacle.output.v1.SetColorFilterResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.output.v1.SetColorFilterRequest
+---
+---@return pinnacle.output.v1.SetColorFilterResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_output_v1_OutputService_SetColorFilter(data)
+    return self:unary_request(pinnacle.output.v1.OutputService.SetColorFilter, data)
+end
+pinnacle.output.v1.OutputService.SetAutoRotate = {}
+pinnacle.output.v1.OutputService.SetAutoRotate.service = "pinnacle.output.v1.OutputService"
+pinnacle.output.v1.OutputService.SetAutoRotate.method = "SetAutoRotate"
+pinnacle.output.v1.OutputService.SetAutoRotate.request = ".pinnacle.output.v1.SetAutoRotateRequest"
+pinnacle.output.v1.OutputService.SetAutoRotate.response = ".pinnacle.output.v1.SetAutoRotateResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.output.v1.SetAutoRotateRequest
+---
+---@return pinnacle.output.v1.SetAutoRotateResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_output_v1_OutputService_SetAutoRotate(data)
+    return self:unary_request(pinnacle.output.v1.OutputService.SetAutoRotate, data)
+end
+pinnacle.output.v1.OutputService.SetHdr = {}
+pinnacle.output.v1.OutputService.SetHdr.service = "pinnacle.output.v1.OutputService"
+pinnacle.output.v1.OutputService.SetHdr.method = "SetHdr"
+pinnacle.output.v1.OutputService.SetHdr.request = ".pinnacle.output.v1.SetHdrRequest"
+pinnacle.output.v1.OutputService.SetHdr.response = ".pinnacle.output.v1.SetHdrResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.output.v1.SetHdrRequest
+---
+---@return pinnacle.output.v1.SetHdrResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_output_v1_OutputService_SetHdr(data)
+    return self:unary_request(pinnacle.output.v1.OutputService.SetHdr, data)
+end
+pinnacle.output.v1.OutputService.SetColorDepth = {}
+pinnacle.output.v1.OutputService.SetColorDepth.service = "pinnacle.output.v1.OutputService"
+pinnacle.output.v1.OutputService.SetColorDepth.method = "SetColorDepth"
+pinnacle.output.v1.OutputService.SetColorDepth.request = ".pinnacle.output.v1.SetColorDepthRequest"
+pinnacle.output.v1.OutputService.SetColorDepth.response = ".pinnacle.output.v1.SetColorDepthResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.output.v1.SetColorDepthRequest
+---
+---@return pinnacle.output.v1.SetColorDepthResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_output_v1_OutputService_SetColorDepth(data)
+    return self:unary_request(pinnacle.output.v1.OutputService.SetColorDepth, data)
+end
+pinnacle.output.v1.OutputService.SetSrgbEmulation = {}
+pinnacle.output.v1.OutputService.SetSrgbEmulation.service = "pinnacle.output.v1.OutputService"
+pinnacle.output.v1.OutputService.SetSrgbEmulation.method = "SetSrgbEmulation"
+pinnacle.output.v1.OutputService.SetSrgbEmulation.request = ".pinnacle.output.v1.SetSrgbEmulationRequest"
+pinnacle.output.v1.OutputService.SetSrgbEmulation.response = ".pinnacle.output.v1.SetSrgbEmulationResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.output.v1.SetSrgbEmulationRequest
+---
+---@return pinnacle.output.v1.SetSrgbEmulationResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_output_v1_OutputService_SetSrgbEmulation(data)
+    return self:unary_request(pinnacle.output.v1.OutputService.SetSrgbEmulation, data)
+end
+pinnacle.output.v1.OutputService.Focus = {}
+pinnacle.output.v1.OutputService.Focus.service = "pinnacle.output.v1.OutputService"
+pinnacle.output.v1.OutputService.Focus.method = "Focus"
+pinnacle.output.v1.OutputService.Focus.request = ".pinnacle.output.v1.FocusRequest"
+pinnacle.output.v1.OutputService.Focus.response = ".pinnacle.output.v1.FocusResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.output.v1.FocusRequest
+---
+---@return pinnacle.output.v1.FocusResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_output_v1_OutputService_Focus(data)
+    return self:unary_request(pinnacle.output.v1.OutputService.Focus, data)
+end
+pinnacle.output.v1.OutputService.GetInfo = {}
+pinnacle.output.v1.OutputService.GetInfo.service = "pinnacle.output.v1.OutputService"
+pinnacle.output.v1.OutputService.GetInfo.method = "GetInfo"
+pinnacle.output.v1.OutputService.GetInfo.request = ".pinnacle.output.v1.GetInfoRequest"
+pinnacle.output.v1.OutputService.GetInfo.response = ".pinnacle.output.v1.GetInfoResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.output.v1.GetInfoRequest
+---
+---@return pinnacle.output.v1.GetInfoResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_output_v1_OutputService_GetInfo(data)
+    return self:unary_request(pinnacle.output.v1.OutputService.GetInfo, data)
+end
+pinnacle.output.v1.OutputService.GetLoc = {}
+pinnacle.output.v1.OutputService.GetLoc.service = "pinnacle.output.v1.OutputService"
+pinnacle.output.v1.OutputService.GetLoc.method = "GetLoc"
+pinnacle.output.v1.OutputService.GetLoc.request = ".pinnacle.output.v1.GetLocRequest"
+pinnacle.output.v1.OutputService.GetLoc.response = ".pinnacle.output.v1.GetLocResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.output.v1.GetLocRequest
+---
+---@return pinnacle.output.v1.GetLocResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_output_v1_OutputService_GetLoc(data)
+    return self:unary_request(pinnacle.output.v1.OutputService.GetLoc, data)
+end
+pinnacle.output.v1.OutputService.GetLogicalSize = {}
+pinnacle.output.v1.OutputService.GetLogicalSize.service = "pinnacle.output.v1.OutputService"
+pinnacle.output.v1.OutputService.GetLogicalSize.method = "GetLogicalSize"
+pinnacle.output.v1.OutputService.GetLogicalSize.request = ".pinnacle.output.v1.GetLogicalSizeRequest"
+pinnacle.output.v1.OutputService.GetLogicalSize.response = ".pinnacle.output.v1.GetLogicalSizeResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.output.v1.GetLogicalSizeRequest
+---
+---@return pinnacle.output.v1.GetLogicalSizeResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_output_v1_OutputService_GetLogicalSize(data)
+    return self:unary_request(pinnacle.output.v1.OutputService.GetLogicalSize, data)
+end
+pinnacle.output.v1.OutputService.GetPhysicalSize = {}
+pinnacle.output.v1.OutputService.GetPhysicalSize.service = "pinnacle.output.v1.OutputService"
+pinnacle.output.v1.OutputService.GetPhysicalSize.method = "GetPhysicalSize"
+pinnacle.output.v1.OutputService.GetPhysicalSize.request = ".pinnacle.output.v1.GetPhysicalSizeRequest"
+pinnacle.output.v1.OutputService.GetPhysicalSize.response = ".pinnacle.output.v1.GetPhysicalSizeResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.output.v1.GetPhysicalSizeRequest
+---
+---@return pinnacle.output.v1.GetPhysicalSizeResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_output_v1_OutputService_GetPhysicalSize(data)
+    return self:unary_request(pinnacle.output.v1.OutputService.GetPhysicalSize, data)
+end
+pinnacle.output.v1.OutputService.GetModes = {}
+pinnacle.output.v1.OutputService.GetModes.service = "pinnacle.output.v1.OutputService"
+pinnacle.output.v1.OutputService.GetModes.method = "GetModes"
+pinnacle.output.v1.OutputService.GetModes.request = ".pinnacle.output.v1.GetModesRequest"
+pinnacle.output.v1.OutputService.GetModes.response = ".pinnacle.output.v1.GetModesResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.output.v1.GetModesRequest
+---
+---@return pinnacle.output.v1.GetModesResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_output_v1_OutputService_GetModes(data)
+    return self:unary_request(pinnacle.output.v1.OutputService.GetModes, data)
+end
+pinnacle.output.v1.OutputService.GetFocused = {}
+pinnacle.output.v1.OutputService.GetFocused.service = "pinnacle.output.v1.OutputService"
+pinnacle.output.v1.OutputService.GetFocused.method = "GetFocused"
+pinnacle.output.v1.OutputService.GetFocused.request = ".pinnacle.output.v1.GetFocusedRequest"
+pinnacle.output.v1.OutputService.GetFocused.response = ".pinnacle.output.v1.GetFocusedResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.output.v1.GetFocusedRequest
+---
+---@return pinnacle.output.v1.GetFocusedResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_output_v1_OutputService_GetFocused(data)
+    return self:unary_request(pinnacle.output.v1.OutputService.GetFocused, data)
+end
+pinnacle.output.v1.OutputService.GetTagIds = {}
+pinnacle.output.v1.OutputService.GetTagIds.service = "pinnacle.output.v1.OutputService"
+pinnacle.output.v1.OutputService.GetTagIds.method = "GetTagIds"
+pinnacle.output.v1.OutputService.GetTagIds.request = ".pinnacle.output.v1.GetTagIdsRequest"
+pinnacle.output.v1.OutputService.GetTagIds.response = ".pinnacle.output.v1.GetTagIdsResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.output.v1.GetTagIdsRequest
+---
+---@return pinnacle.output.v1.GetTagIdsResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_output_v1_OutputService_GetTagIds(data)
+    return self:unary_request(pinnacle.output.v1.OutputService.GetTagIds, data)
+end
+pinnacle.output.v1.OutputService.GetScale = {}
+pinnacle.output.v1.OutputService.GetScale.service = "pinnacle.output.v1.OutputService"
+pinnacle.output.v1.OutputService.GetScale.method = "GetScale"
+pinnacle.output.v1.OutputService.GetScale.request = ".pinnacle.output.v1.GetScaleRequest"
+pinnacle.output.v1.OutputService.GetScale.response = ".pinnacle.output.v1.GetScaleResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.output.v1.GetScaleRequest
+---
+---@return pinnacle.output.v1.GetScaleResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_output_v1_OutputService_GetScale(data)
+    return self:unary_request(pinnacle.output.v1.OutputService.GetScale, data)
+end
+pinnacle.output.v1.OutputService.GetTransform = {}
+pinnacle.output.v1.OutputService.GetTransform.service = "pinnacle.output.v1.OutputService"
+pinnacle.output.v1.OutputService.GetTransform.method = "GetTransform"
+pinnacle.output.v1.OutputService.GetTransform.request = ".pinnacle.output.v1.GetTransformRequest"
+pinnacle.output.v1.OutputService.GetTransform.response = ".pinnacle.output.v1.GetTransformResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.output.v1.GetTransformRequest
+---
+---@return pinnacle.output.v1.GetTransformResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_output_v1_OutputService_GetTransform(data)
+    return self:unary_request(pinnacle.output.v1.OutputService.GetTransform, data)
+end
+pinnacle.output.v1.OutputService.GetEnabled = {}
+pinnacle.output.v1.OutputService.GetEnabled.service = "pinnacle.output.v1.OutputService"
+pinnacle.output.v1.OutputService.GetEnabled.method = "GetEnabled"
+pinnacle.output.v1.OutputService.GetEnabled.request = ".pinnacle.output.v1.GetEnabledRequest"
+pinnacle.output.v1.OutputService.GetEnabled.response = ".pinnacle.output.v1.GetEnabledResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.output.v1.GetEnabledRequest
+---
+---@return pinnacle.output.v1.GetEnabledResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_output_v1_OutputService_GetEnabled(data)
+    return self:unary_request(pinnacle.output.v1.OutputService.GetEnabled, data)
+end
+pinnacle.output.v1.OutputService.GetPowered = {}
+pinnacle.output.v1.OutputService.GetPowered.service = "pinnacle.output.v1.OutputService"
+pinnacle.output.v1.OutputService.GetPowered.method = "GetPowered"
+pinnacle.output.v1.OutputService.GetPowered.request = ".pinnacle.output.v1.GetPoweredRequest"
+pinnacle.output.v1.OutputService.GetPowered.response = ".pinnacle.output.v1.GetPoweredResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.output.v1.GetPoweredRequest
+---
+---@return pinnacle.output.v1.GetPoweredResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_output_v1_OutputService_GetPowered(data)
+    return self:unary_request(pinnacle.output.v1.OutputService.GetPowered, data)
+end
+pinnacle.output.v1.OutputService.GetHdr = {}
+pinnacle.output.v1.OutputService.GetHdr.service = "pinnacle.output.v1.OutputService"
+pinnacle.output.v1.OutputService.GetHdr.method = "GetHdr"
+pinnacle.output.v1.OutputService.GetHdr.request = ".pinnacle.output.v1.GetHdrRequest"
+pinnacle.output.v1.OutputService.GetHdr.response = ".pinnacle.output.v1.GetHdrResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.output.v1.GetHdrRequest
+---
+---@return pinnacle.output.v1.GetHdrResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_output_v1_OutputService_GetHdr(data)
+    return self:unary_request(pinnacle.output.v1.OutputService.GetHdr, data)
+end
+pinnacle.output.v1.OutputService.GetColorDepth = {}
+pinnacle.output.v1.OutputService.GetColorDepth.service = "pinnacle.output.v1.OutputService"
+pinnacle.output.v1.OutputService.GetColorDepth.method = "GetColorDepth"
+pinnacle.output.v1.OutputService.GetColorDepth.request = ".pinnacle.output.v1.GetColorDepthRequest"
+pinnacle.output.v1.OutputService.GetColorDepth.response = ".pinnacle.output.v1.GetColorDepthResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.output.v1.GetColorDepthRequest
+---
+---@return pinnacle.output.v1.GetColorDepthResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_output_v1_OutputService_GetColorDepth(data)
+    return self:unary_request(pinnacle.output.v1.OutputService.GetColorDepth, data)
+end
+pinnacle.output.v1.OutputService.GetSrgbEmulation = {}
+pinnacle.output.v1.OutputService.GetSrgbEmulation.service = "pinnacle.output.v1.OutputService"
+pinnacle.output.v1.OutputService.GetSrgbEmulation.method = "GetSrgbEmulation"
+pinnacle.output.v1.OutputService.GetSrgbEmulation.request = ".pinnacle.output.v1.GetSrgbEmulationRequest"
+pinnacle.output.v1.OutputService.GetSrgbEmulation.response = ".pinnacle.output.v1.GetSrgbEmulationResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.output.v1.GetSrgbEmulationRequest
+---
+---@return pinnacle.output.v1.GetSrgbEmulationResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_output_v1_OutputService_GetSrgbEmulation(data)
+    return self:unary_request(pinnacle.output.v1.OutputService.GetSrgbEmulation, data)
+end
+pinnacle.output.v1.OutputService.GetRenderStats = {}
+pinnacle.output.v1.OutputService.GetRenderStats.service = "pinnacle.output.v1.OutputService"
+pinnacle.output.v1.OutputService.GetRenderStats.method = "GetRenderStats"
+pinnacle.output.v1.OutputService.GetRenderStats.request = ".pinnacle.output.v1.GetRenderStatsRequest"
+pinnacle.output.v1.OutputService.GetRenderStats.response = ".pinnacle.output.v1.GetRenderStatsResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.output.v1.GetRenderStatsRequest
+---
+---@return pinnacle.output.v1.GetRenderStatsResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_output_v1_OutputService_GetRenderStats(data)
+    return self:unary_request(pinnacle.output.v1.OutputService.GetRenderStats, data)
+end
+pinnacle.output.v1.OutputService.GetFocusStackWindowIds = {}
+pinnacle.output.v1.OutputService.GetFocusStackWindowIds.service = "pinnacle.output.v1.OutputService"
+pinnacle.output.v1.OutputService.GetFocusStackWindowIds.method = "GetFocusStackWindowIds"
+pinnacle.output.v1.OutputService.GetFocusStackWindowIds.request = ".pinnacle.output.v1.GetFocusStackWindowIdsRequest"
+pinnacle.output.v1.OutputService.GetFocusStackWindowIds.response = ".pinnacle.output.v1.GetFocusStackWindowIdsResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.output.v1.GetFocusStackWindowIdsRequest
+---
+---@return pinnacle.output.v1.GetFocusStackWindowIdsResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_output_v1_OutputService_GetFocusStackWindowIds(data)
+    return self:unary_request(pinnacle.output.v1.OutputService.GetFocusStackWindowIds, data)
+end
+pinnacle.output.v1.OutputService.GetStackingOrder = {}
+pinnacle.output.v1.OutputService.GetStackingOrder.service = "pinnacle.output.v1.OutputService"
+pinnacle.output.v1.OutputService.GetStackingOrder.method = "GetStackingOrder"
+pinnacle.output.v1.OutputService.GetStackingOrder.request = ".pinnacle.output.v1.GetStackingOrderRequest"
+pinnacle.output.v1.OutputService.GetStackingOrder.response = ".pinnacle.output.v1.GetStackingOrderResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.output.v1.GetStackingOrderRequest
+---
+---@return pinnacle.output.v1.GetStackingOrderResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_output_v1_OutputService_GetStackingOrder(data)
+    return self:unary_request(pinnacle.output.v1.OutputService.GetStackingOrder, data)
+end
+pinnacle.output.v1.OutputService.GetOutputsInDir = {}
+pinnacle.output.v1.OutputService.GetOutputsInDir.service = "pinnacle.output.v1.OutputService"
+pinnacle.output.v1.OutputService.GetOutputsInDir.method = "GetOutputsInDir"
+pinnacle.output.v1.OutputService.GetOutputsInDir.request = ".pinnacle.output.v1.GetOutputsInDirRequest"
+pinnacle.output.v1.OutputService.GetOutputsInDir.response = ".pinnacle.output.v1.GetOutputsInDirResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.output.v1.GetOutputsInDirRequest
+---
+---@return pinnacle.output.v1.GetOutputsInDirResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_output_v1_OutputService_GetOutputsInDir(data)
+    return self:unary_request(pinnacle.output.v1.OutputService.GetOutputsInDir, data)
+end
+pinnacle.output.v1.OutputService.WatchProperties = {}
+pinnacle.output.v1.OutputService.WatchProperties.service = "pinnacle.output.v1.OutputService"
+pinnacle.output.v1.OutputService.WatchProperties.method = "WatchProperties"
+pinnacle.output.v1.OutputService.WatchProperties.request = ".pinnacle.output.v1.WatchPropertiesRequest"
+pinnacle.output.v1.OutputService.WatchProperties.response = ".pinnacle.output.v1.WatchPropertiesResponse"
+
+---Performs a server-streaming request.
+---
+---`callback` will be called with every streamed response.
+---
+---@nodiscard
+---
+---@param data pinnacle.output.v1.WatchPropertiesRequest
+---@param callback fun(response: pinnacle.output.v1.WatchPropertiesResponse)
+---@param done? fun()
+---
+---@return string | nil An error string, if any
+function Client:pinnacle_output_v1_OutputService_WatchProperties(data, callback, done)
+    return self:server_streaming_request(pinnacle.output.v1.OutputService.WatchProperties, data, callback, done)
+end
+pinnacle.input.v1.InputService = {}
+pinnacle.input.v1.InputService.Bind = {}
+pinnacle.input.v1.InputService.Bind.service = "pinnacle.input.v1.InputService"
+pinnacle.input.v1.InputService.Bind.method = "Bind"
+pinnacle.input.v1.InputService.Bind.request = ".pinnacle.input.v1.BindRequest"
+pinnacle.input.v1.InputService.Bind.response = ".pinnacle.input.v1.BindResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.input.v1.BindRequest
+---
+---@return pinnacle.input.v1.BindResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_input_v1_InputService_Bind(data)
+    return self:unary_request(pinnacle.input.v1.InputService.Bind, data)
+end
+pinnacle.input.v1.InputService.GetBindInfos = {}
+pinnacle.input.v1.InputService.GetBindInfos.service = "pinnacle.input.v1.InputService"
+pinnacle.input.v1.InputService.GetBindInfos.method = "GetBindInfos"
+pinnacle.input.v1.InputService.GetBindInfos.request = ".pinnacle.input.v1.GetBindInfosRequest"
+pinnacle.input.v1.InputService.GetBindInfos.response = ".pinnacle.input.v1.GetBindInfosResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.input.v1.GetBindInfosRequest
+---
+---@return pinnacle.input.v1.GetBindInfosResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_input_v1_InputService_GetBindInfos(data)
+    return self:unary_request(pinnacle.input.v1.InputService.GetBindInfos, data)
+end
+pinnacle.input.v1.InputService.SetBindProperties = {}
+pinnacle.input.v1.InputService.SetBindProperties.service = "pinnacle.input.v1.InputService"
+pinnacle.input.v1.InputService.SetBindProperties.method = "SetBindProperties"
+pinnacle.input.v1.InputService.SetBindProperties.request = ".pinnacle.input.v1.SetBindPropertiesRequest"
+pinnacle.input.v1.InputService.SetBindProperties.response = ".google.protobuf.Empty"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.input.v1.SetBindPropertiesRequest
+---
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_input_v1_InputService_SetBindProperties(data)
+    return self:unary_request(pinnacle.input.v1.InputService.SetBindProperties, data)
+end
+pinnacle.input.v1.InputService.GetBindLayerStack = {}
+pinnacle.input.v1.InputService.GetBindLayerStack.service = "pinnacle.input.v1.InputService"
+pinnacle.input.v1.InputService.GetBindLayerStack.method = "GetBindLayerStack"
+pinnacle.input.v1.InputService.GetBindLayerStack.request = ".pinnacle.input.v1.GetBindLayerStackRequest"
+pinnacle.input.v1.InputService.GetBindLayerStack.response = ".pinnacle.input.v1.GetBindLayerStackResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.input.v1.GetBindLayerStackRequest
+---
+---@return pinnacle.input.v1.GetBindLayerStackResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_input_v1_InputService_GetBindLayerStack(data)
+    return self:unary_request(pinnacle.input.v1.InputService.GetBindLayerStack, data)
+end
+pinnacle.input.v1.InputService.EnterBindLayer = {}
+pinnacle.input.v1.InputService.EnterBindLayer.service = "pinnacle.input.v1.InputService"
+pinnacle.input.v1.InputService.EnterBindLayer.method = "EnterBindLayer"
+pinnacle.input.v1.InputService.EnterBindLayer.request = ".pinnacle.input.v1.EnterBindLayerRequest"
+pinnacle.input.v1.InputService.EnterBindLayer.response = ".google.protobuf.Empty"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.input.v1.EnterBindLayerRequest
+---
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_input_v1_InputService_EnterBindLayer(data)
+    return self:unary_request(pinnacle.input.v1.InputService.EnterBindLayer, data)
+end
+pinnacle.input.v1.InputService.SetBindLayerConflictPolicy = {}
+pinnacle.input.v1.InputService.SetBindLayerConflictPolicy.service = "pinnacle.input.v1.InputService"
+pinnacle.input.v1.InputService.SetBindLayerConflictPolicy.method = "SetBindLayerConflictPolicy"
+pinnacle.input.v1.InputService.SetBindLayerConflictPolicy.request = ".pinnacle.input.v1.SetBindLayerConflictPolicyRequest"
+pinnacle.input.v1.InputService.SetBindLayerConflictPolicy.response = ".google.protobuf.Empty"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.input.v1.SetBindLayerConflictPolicyRequest
+---
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_input_v1_InputService_SetBindLayerConflictPolicy(data)
+    return self:unary_request(pinnacle.input.v1.InputService.SetBindLayerConflictPolicy, data)
+end
+pinnacle.input.v1.InputService.KeybindStream = {}
+pinnacle.input.v1.InputService.KeybindStream.service = "pinnacle.input.v1.InputService"
+pinnacle.input.v1.InputService.KeybindStream.method = "KeybindStream"
+pinnacle.input.v1.InputService.KeybindStream.request = ".pinnacle.input.v1.KeybindStreamRequest"
+pinnacle.input.v1.InputService.KeybindStream.response = ".pinnacle.input.v1.KeybindStreamResponse"
+
+---Performs a server-streaming request.
+---
+---`callback` will be called with every streamed response.
+---
+---@nodiscard
+---
+---@param data pinnacle.input.v1.KeybindStreamRequest
+---@param callback fun(response: pinnacle.input.v1.KeybindStreamResponse)
+---@param done? fun()
+---
+---@return string | nil An error string, if any
+function Client:pinnacle_input_v1_InputService_KeybindStream(data, callback, done)
+    return self:server_streaming_request(pinnacle.input.v1.InputService.KeybindStream, data, callback, done)
+end
+pinnacle.input.v1.InputService.MousebindStream = {}
+pinnacle.input.v1.InputService.MousebindStream.service = "pinnacle.input.v1.InputService"
+pinnacle.input.v1.InputService.MousebindStream.method = "MousebindStream"
+pinnacle.input.v1.InputService.MousebindStream.request = ".pinnacle.input.v1.MousebindStreamRequest"
+pinnacle.input.v1.InputService.MousebindStream.response = ".pinnacle.input.v1.MousebindStreamResponse"
+
+---Performs a server-streaming request.
+---
+---`callback` will be called with every streamed response.
+---
+---@nodiscard
+---
+---@param data pinnacle.input.v1.MousebindStreamRequest
+---@param callback fun(response: pinnacle.input.v1.MousebindStreamResponse)
+---@param done? fun()
+---
+---@return string | nil An error string, if any
+function Client:pinnacle_input_v1_InputService_MousebindStream(data, callback, done)
+    return self:server_streaming_request(pinnacle.input.v1.InputService.MousebindStream, data, callback, done)
+end
+pinnacle.input.v1.InputService.KeybindOnPress = {}
+pinnacle.input.v1.InputService.KeybindOnPress.service = "pinnacle.input.v1.InputService"
+pinnacle.input.v1.InputService.KeybindOnPress.method = "KeybindOnPress"
+pinnacle.input.v1.InputService.KeybindOnPress.request = ".pinnacle.input.v1.KeybindOnPressRequest"
+pinnacle.input.v1.InputService.KeybindOnPress.response = ".google.protobuf.Empty"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.input.v1.KeybindOnPressRequest
+---
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_input_v1_InputService_KeybindOnPress(data)
+    return self:unary_request(pinnacle.input.v1.InputService.KeybindOnPress, data)
+end
+pinnacle.input.v1.InputService.MousebindOnPress = {}
+pinnacle.input.v1.InputService.MousebindOnPress.service = "pinnacle.input.v1.InputService"
+pinnacle.input.v1.InputService.MousebindOnPress.method = "MousebindOnPress"
+pinnacle.input.v1.InputService.MousebindOnPress.request = ".pinnacle.input.v1.MousebindOnPressRequest"
+pinnacle.input.v1.InputService.MousebindOnPress.response = ".google.protobuf.Empty"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.input.v1.MousebindOnPressRequest
+---
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_input_v1_InputService_MousebindOnPress(data)
+    return self:unary_request(pinnacle.input.v1.InputService.MousebindOnPress, data)
+end
+pinnacle.input.v1.InputService.SetXkbConfig = {}
+pinnacle.input.v1.InputService.SetXkbConfig.service = "pinnacle.input.v1.InputService"
+pinnacle.input.v1.InputService.SetXkbConfig.method = "SetXkbConfig"
+pinnacle.input.v1.InputService.SetXkbConfig.request = ".pinnacle.input.v1.SetXkbConfigRequest"
+pinnacle.input.v1.InputService.SetXkbConfig.response = ".google.protobuf.Empty"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.input.v1.SetXkbConfigRequest
+---
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_input_v1_InputService_SetXkbConfig(data)
+    return self:unary_request(pinnacle.input.v1.InputService.SetXkbConfig, data)
+end
+pinnacle.input.v1.InputService.SetRepeatRate = {}
+pinnacle.input.v1.InputService.SetRepeatRate.service = "pinnacle.input.v1.InputService"
+pinnacle.input.v1.InputService.SetRepeatRate.method = "SetRepeatRate"
+pinnacle.input.v1.InputService.SetRepeatRate.request = ".pinnacle.input.v1.SetRepeatRateRequest"
+pinnacle.input.v1.InputService.SetRepeatRate.response = ".google.protobuf.Empty"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.input.v1.SetRepeatRateRequest
+---
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_input_v1_InputService_SetRepeatRate(data)
+    return self:unary_request(pinnacle.input.v1.InputService.SetRepeatRate, data)
+end
+pinnacle.input.v1.InputService.SetXkbKeymap = {}
+pinnacle.input.v1.InputService.SetXkbKeymap.service = "pinnacle.input.v1.InputService"
+pinnacle.input.v1.InputService.SetXkbKeymap.method = "SetXkbKeymap"
+pinnacle.input.v1.InputService.SetXkbKeymap.request = ".pinnacle.input.v1.SetXkbKeymapRequest"
+pinnacle.input.v1.InputService.SetXkbKeymap.response = ".google.protobuf.Empty"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.input.v1.SetXkbKeymapRequest
+---
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_input_v1_InputService_SetXkbKeymap(data)
+    return self:unary_request(pinnacle.input.v1.InputService.SetXkbKeymap, data)
+end
+pinnacle.input.v1.InputService.SwitchXkbLayout = {}
+pinnacle.input.v1.InputService.SwitchXkbLayout.service = "pinnacle.input.v1.InputService"
+pinnacle.input.v1.InputService.SwitchXkbLayout.method = "SwitchXkbLayout"
+pinnacle.input.v1.InputService.SwitchXkbLayout.request = ".pinnacle.input.v1.SwitchXkbLayoutRequest"
+pinnacle.input.v1.InputService.SwitchXkbLayout.response = ".google.protobuf.Empty"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.input.v1.SwitchXkbLayoutRequest
+---
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_input_v1_InputService_SwitchXkbLayout(data)
+    return self:unary_request(pinnacle.input.v1.InputService.SwitchXkbLayout, data)
+end
+pinnacle.input.v1.InputService.SetStickyKeys = {}
+pinnacle.input.v1.InputService.SetStickyKeys.service = "pinnacle.input.v1.InputService"
+pinnacle.input.v1.InputService.SetStickyKeys.method = "SetStickyKeys"
+pinnacle.input.v1.InputService.SetStickyKeys.request = ".pinnacle.input.v1.SetStickyKeysRequest"
+pinnacle.input.v1.InputService.SetStickyKeys.response = ".pinnacle.input.v1.SetStickyKeysResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.input.v1.SetStickyKeysRequest
+---
+---@return pinnacle.input.v1.SetStickyKeysResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_input_v1_InputService_SetStickyKeys(data)
+    return self:unary_request(pinnacle.input.v1.InputService.SetStickyKeys, data)
+end
+pinnacle.input.v1.InputService.SetSlowKeys = {}
+pinnacle.input.v1.InputService.SetSlowKeys.service = "pinnacle.input.v1.InputService"
+pinnacle.input.v1.InputService.SetSlowKeys.method = "SetSlowKeys"
+pinnacle.input.v1.InputService.SetSlowKeys.request = ".pinnacle.input.v1.SetSlowKeysRequest"
+pinnacle.input.v1.InputService.SetSlowKeys.response = ".pinnacle.input.v1.SetSlowKeysResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.input.v1.SetSlowKeysRequest
+---
+---@return pinnacle.input.v1.SetSlowKeysResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_input_v1_InputService_SetSlowKeys(data)
+    return self:unary_request(pinnacle.input.v1.InputService.SetSlowKeys, data)
+end
+pinnacle.input.v1.InputService.SetHoldToMove = {}
+pinnacle.input.v1.InputService.SetHoldToMove.service = "pinnacle.input.v1.InputService"
+pinnacle.input.v1.InputService.SetHoldToMove.method = "SetHoldToMove"
+pinnacle.input.v1.InputService.SetHoldToMove.request = ".pinnacle.input.v1.SetHoldToMoveRequest"
+pinnacle.input.v1.InputService.SetHoldToMove.response = ".pinnacle.input.v1.SetHoldToMoveResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.input.v1.SetHoldToMoveRequest
+---
+---@return pinnacle.input.v1.SetHoldToMoveResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_input_v1_InputService_SetHoldToMove(data)
+    return self:unary_request(pinnacle.input.v1.InputService.SetHoldToMove, data)
+end
+pinnacle.input.v1.InputService.SetPointerCrossing = {}
+pinnacle.input.v1.InputService.SetPointerCrossing.service = "pinnacle.input.v1.InputService"
+pinnacle.input.v1.InputService.SetPointerCrossing.method = "SetPointerCrossing"
+pinnacle.input.v1.InputService.SetPointerCrossing.request = ".pinnacle.input.v1.SetPointerCrossingRequest"
+pinnacle.input.v1.InputService.SetPointerCrossing.response = ".google.protobuf.Empty"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.input.v1.SetPointerCrossingRequest
+---
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_input_v1_InputService_SetPointerCrossing(data)
+    return self:unary_request(pinnacle.input.v1.InputService.SetPointerCrossing, data)
+end
+pinnacle.input.v1.InputService.SetEdgeAction = {}
+pinnacle.input.v1.InputService.SetEdgeAction.service = "pinnacle.input.v1.InputService"
+pinnacle.input.v1.InputService.SetEdgeAction.method = "SetEdgeAction"
+pinnacle.input.v1.InputService.SetEdgeAction.request = ".pinnacle.input.v1.SetEdgeActionRequest"
+pinnacle.input.v1.InputService.SetEdgeAction.response = ".pinnacle.input.v1.SetEdgeActionResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.input.v1.SetEdgeActionRequest
+---
+---@return pinnacle.input.v1.SetEdgeActionResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_input_v1_InputService_SetEdgeAction(data)
+    return self:unary_request(pinnacle.input.v1.InputService.SetEdgeAction, data)
+end
+pinnacle.input.v1.InputService.EdgeActionStream = {}
+pinnacle.input.v1.InputService.EdgeActionStream.service = "pinnacle.input.v1.InputService"
+pinnacle.input.v1.InputService.EdgeActionStream.method = "EdgeActionStream"
+pinnacle.input.v1.InputService.EdgeActionStream.request = ".pinnacle.input.v1.EdgeActionStreamRequest"
+pinnacle.input.v1.InputService.EdgeActionStream.response = ".pinnacle.input.v1.EdgeActionStreamResponse"
+
+---Performs a server-streaming request.
+---
+---`callback` will be called with every streamed response.
+---
+---@nodiscard
+---
+---@param data pinnacle.input.v1.EdgeActionStreamRequest
+---@param callback fun(response: pinnacle.input.v1.EdgeActionStreamResponse)
+---@param done? fun()
+---
+---@return string | nil An error string, if any
+function Client:pinnacle_input_v1_InputService_EdgeActionStream(data, callback, done)
+    return self:server_streaming_request(pinnacle.input.v1.InputService.EdgeActionStream, data, callback, done)
+end
+pinnacle.input.v1.InputService.Remap = {}
+pinnacle.input.v1.InputService.Remap.service = "pinnacle.input.v1.InputService"
+pinnacle.input.v1.InputService.Remap.method = "Remap"
+pinnacle.input.v1.InputService.Remap.request = ".pinnacle.input.v1.RemapRequest"
+pinnacle.input.v1.InputService.Remap.response = ".pinnacle.input.v1.RemapResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.input.v1.RemapRequest
+---
+---@return pinnacle.input.v1.RemapResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_input_v1_InputService_Remap(data)
+    return self:unary_request(pinnacle.input.v1.InputService.Remap, data)
+end
+pinnacle.input.v1.InputService.ClearRemaps = {}
+pinnacle.input.v1.InputService.ClearRemaps.service = "pinnacle.input.v1.InputService"
+pinnacle.input.v1.InputService.ClearRemaps.method = "ClearRemaps"
+pinnacle.input.v1.InputService.ClearRemaps.request = ".pinnacle.input.v1.ClearRemapsRequest"
+pinnacle.input.v1.InputService.ClearRemaps.response = ".pinnacle.input.v1.ClearRemapsResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.input.v1.ClearRemapsRequest
+---
+---@return pinnacle.input.v1.ClearRemapsResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_input_v1_InputService_ClearRemaps(data)
+    return self:unary_request(pinnacle.input.v1.InputService.ClearRemaps, data)
+end
+pinnacle.input.v1.InputService.SetXcursor = {}
+pinnacle.input.v1.InputService.SetXcursor.service = "pinnacle.input.v1.InputService"
+pinnacle.input.v1.InputService.SetXcursor.method = "SetXcursor"
+pinnacle.input.v1.InputService.SetXcursor.request = ".pinnacle.input.v1.SetXcursorRequest"
+pinnacle.input.v1.InputService.SetXcursor.response = ".google.protobuf.Empty"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.input.v1.SetXcursorRequest
+---
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_input_v1_InputService_SetXcursor(data)
+    return self:unary_request(pinnacle.input.v1.InputService.SetXcursor, data)
+end
+pinnacle.input.v1.InputService.GetDevices = {}
+pinnacle.input.v1.InputService.GetDevices.service = "pinnacle.input.v1.InputService"
+pinnacle.input.v1.InputService.GetDevices.method = "GetDevices"
+pinnacle.input.v1.InputService.GetDevices.request = ".pinnacle.input.v1.GetDevicesRequest"
+pinnacle.input.v1.InputService.GetDevices.response = ".pinnacle.input.v1.GetDevicesResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.input.v1.GetDevicesRequest
+---
+---@return pinnacle.input.v1.GetDevicesResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_input_v1_InputService_GetDevices(data)
+    return self:unary_request(pinnacle.input.v1.InputService.GetDevices, data)
+end
+pinnacle.input.v1.InputService.GetDeviceCapabilities = {}
+pinnacle.input.v1.InputService.GetDeviceCapabilities.service = "pinnacle.input.v1.InputService"
+pinnacle.input.v1.InputService.GetDeviceCapabilities.method = "GetDeviceCapabilities"
+pinnacle.input.v1.InputService.GetDeviceCapabilities.request = ".pinnacle.input.v1.GetDeviceCapabilitiesRequest"
+pinnacle.input.v1.InputService.GetDeviceCapabilities.response = ".pinnacle.input.v1.GetDeviceCapabilitiesResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.input.v1.GetDeviceCapabilitiesRequest
+---
+---@return pinnacle.input.v1.GetDeviceCapabilitiesResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_input_v1_InputService_GetDeviceCapabilities(data)
+    return self:unary_request(pinnacle.input.v1.InputService.GetDeviceCapabilities, data)
+end
+pinnacle.input.v1.InputService.GetDeviceInfo = {}
+pinnacle.input.v1.InputService.GetDeviceInfo.service = "pinnacle.input.v1.InputService"
+pinnacle.input.v1.InputService.GetDeviceInfo.method = "GetDeviceInfo"
+pinnacle.input.v1.InputService.GetDeviceInfo.request = ".pinnacle.input.v1.GetDeviceInfoRequest"
+pinnacle.input.v1.InputService.GetDeviceInfo.response = ".pinnacle.input.v1.GetDeviceInfoResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.input.v1.GetDeviceInfoRequest
+---
+---@return pinnacle.input.v1.GetDeviceInfoResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_input_v1_InputService_GetDeviceInfo(data)
+    return self:unary_request(pinnacle.input.v1.InputService.GetDeviceInfo, data)
+end
+pinnacle.input.v1.InputService.GetDeviceType = {}
+pinnacle.input.v1.InputService.GetDeviceType.service = "pinnacle.input.v1.InputService"
+pinnacle.input.v1.InputService.GetDeviceType.method = "GetDeviceType"
+pinnacle.input.v1.InputService.GetDeviceType.request = ".pinnacle.input.v1.GetDeviceTypeRequest"
+pinnacle.input.v1.InputService.GetDeviceType.response = ".pinnacle.input.v1.GetDeviceTypeResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.input.v1.GetDeviceTypeRequest
+---
+---@return pinnacle.input.v1.GetDeviceTypeResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_input_v1_InputService_GetDeviceType(data)
+    return self:unary_request(pinnacle.input.v1.InputService.GetDeviceType, data)
+end
+pinnacle.input.v1.InputService.SetDeviceLibinputSetting = {}
+pinnacle.input.v1.InputService.SetDeviceLibinputSetting.service = "pinnacle.input.v1.InputService"
+pinnacle.input.v1.InputService.SetDeviceLibinputSetting.method = "SetDeviceLibinputSetting"
+pinnacle.input.v1.InputService.SetDeviceLibinputSetting.request = ".pinnacle.input.v1.SetDeviceLibinputSettingRequest"
+pinnacle.input.v1.InputService.SetDeviceLibinputSetting.response = ".google.protobuf.Empty"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.input.v1.SetDeviceLibinputSettingRequest
+---
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_input_v1_InputService_SetDeviceLibinputSetting(data)
+    return self:unary_request(pinnacle.input.v1.InputService.SetDeviceLibinputSetting, data)
+end
+pinnacle.input.v1.InputService.SetDeviceMapTarget = {}
+pinnacle.input.v1.InputService.SetDeviceMapTarget.service = "pinnacle.input.v1.InputService"
+pinnacle.input.v1.InputService.SetDeviceMapTarget.method = "SetDeviceMapTarget"
+pinnacle.input.v1.InputService.SetDeviceMapTarget.request = ".pinnacle.input.v1.SetDeviceMapTargetRequest"
+pinnacle.input.v1.InputService.SetDeviceMapTarget.response = ".google.protobuf.Empty"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.input.v1.SetDeviceMapTargetRequest
+---
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_input_v1_InputService_SetDeviceMapTarget(data)
+    return self:unary_request(pinnacle.input.v1.InputService.SetDeviceMapTarget, data)
+end
+pinnacle.layout.v1.LayoutService = {}
+pinnacle.layout.v1.LayoutService.Layout = {}
+pinnacle.layout.v1.LayoutService.Layout.service = "pinnacle.layout.v1.LayoutService"
+pinnacle.layout.v1.LayoutService.Layout.method = "Layout"
+pinnacle.layout.v1.LayoutService.Layout.request = ".pinnacle.layout.v1.LayoutRequest"
+pinnacle.layout.v1.LayoutService.Layout.response = ".pinnacle.layout.v1.LayoutResponse"
+
+---Performs a bidirectional-streaming request.
+---
+---`callback` will be called with every streamed response.
+---
+---The raw client-to-server stream is returned to allow you to send encoded messages.
+---
+---@nodiscard
+---
+---@param callback fun(response: pinnacle.layout.v1.LayoutResponse, stream: grpc_client.h2.Stream)
+---@param done? fun()
+---
+---@return grpc_client.h2.Stream | nil
+---@return string | nil An error string, if any
+function Client:pinnacle_layout_v1_LayoutService_Layout(callback, done)
+    return self:bidirectional_streaming_request(pinnacle.layout.v1.LayoutService.Layout, callback, done)
+end
+pinnacle.layout.v1.LayoutService.RegisterGenerator = {}
+pinnacle.layout.v1.LayoutService.RegisterGenerator.service = "pinnacle.layout.v1.LayoutService"
+pinnacle.layout.v1.LayoutService.RegisterGenerator.method = "RegisterGenerator"
+pinnacle.layout.v1.LayoutService.RegisterGenerator.request = ".pinnacle.layout.v1.LayoutRequest"
+pinnacle.layout.v1.LayoutService.RegisterGenerator.response = ".pinnacle.layout.v1.LayoutResponse"
+
+---Performs a bidirectional-streaming request.
+---
+---`callback` will be called with every streamed response.
+---
+---The raw client-to-server stream is returned to allow you to send encoded messages.
+---
+---@nodiscard
+---
+---@param callback fun(response: pinnacle.layout.v1.LayoutResponse, stream: grpc_client.h2.Stream)
+---@param done? fun()
+---
+---@return grpc_client.h2.Stream | nil
+---@return string | nil An error string, if any
+function Client:pinnacle_layout_v1_LayoutService_RegisterGenerator(callback, done)
+    return self:bidirectional_streaming_request(pinnacle.layout.v1.LayoutService.RegisterGenerator, callback, done)
+end
+pinnacle.nightlight.v1.NightlightService = {}
+pinnacle.nightlight.v1.NightlightService.SetSchedule = {}
+pinnacle.nightlight.v1.NightlightService.SetSchedule.service = "pinnacle.nightlight.v1.NightlightService"
+pinnacle.nightlight.v1.NightlightService.SetSchedule.method = "SetSchedule"
+pinnacle.nightlight.v1.NightlightService.SetSchedule.request = ".pinnacle.nightlight.v1.SetScheduleRequest"
+pinnacle.nightlight.v1.NightlightService.SetSchedule.response = ".pinnacle.nightlight.v1.SetScheduleResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.nightlight.v1.SetScheduleRequest
+---
+---@return pinnacle.nightlight.v1.SetScheduleResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_nightlight_v1_NightlightService_SetSchedule(data)
+    return self:unary_request(pinnacle.nightlight.v1.NightlightService.SetSchedule, data)
+end
+pinnacle.portal.v1.PortalService = {}
+pinnacle.portal.v1.PortalService.GlobalShortcutsPermission = {}
+pinnacle.portal.v1.PortalService.GlobalShortcutsPermission.service = "pinnacle.portal.v1.PortalService"
+pinnacle.portal.v1.PortalService.GlobalShortcutsPermission.method = "GlobalShortcutsPermission"
+pinnacle.portal.v1.PortalService.GlobalShortcutsPermission.request = ".pinnacle.portal.v1.GlobalShortcutsPermissionRequest"
+pinnacle.portal.v1.PortalService.GlobalShortcutsPermission.response = ".pinnacle.portal.v1.GlobalShortcutsPermissionResponse"
+
+---Performs a bidirectional-streaming request.
+---
+---`callback` will be called with every streamed response.
+---
+---The raw client-to-server stream is returned to allow you to send encoded messages.
+---
+---@nodiscard
+---
+---@param callback fun(response: pinnacle.portal.v1.GlobalShortcutsPermissionResponse, stream: grpc_client.h2.Stream)
+---@param done? fun()
+---
+---@return grpc_client.h2.Stream | nil
+---@return string | nil An error string, if any
+function Client:pinnacle_portal_v1_PortalService_GlobalShortcutsPermission(callback, done)
+    return self:bidirectional_streaming_request(pinnacle.portal.v1.PortalService.GlobalShortcutsPermission, callback, done)
+end
+pinnacle.power.v1.PowerService = {}
+pinnacle.power.v1.PowerService.GetStatus = {}
+pinnacle.power.v1.PowerService.GetStatus.service = "pinnacle.power.v1.PowerService"
+pinnacle.power.v1.PowerService.GetStatus.method = "GetStatus"
+pinnacle.power.v1.PowerService.GetStatus.request = ".pinnacle.power.v1.GetStatusRequest"
+pinnacle.power.v1.PowerService.GetStatus.response = ".pinnacle.power.v1.GetStatusResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.power.v1.GetStatusRequest
+---
+---@return pinnacle.power.v1.GetStatusResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_power_v1_PowerService_GetStatus(data)
+    return self:unary_request(pinnacle.power.v1.PowerService.GetStatus, data)
+end
+pinnacle.process.v1.ProcessService = {}
+pinnacle.process.v1.ProcessService.Spawn = {}
+pinnacle.process.v1.ProcessService.Spawn.service = "pinnacle.process.v1.ProcessService"
+pinnacle.process.v1.ProcessService.Spawn.method = "Spawn"
+pinnacle.process.v1.ProcessService.Spawn.request = ".pinnacle.process.v1.SpawnRequest"
+pinnacle.process.v1.ProcessService.Spawn.response = ".pinnacle.process.v1.SpawnResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.process.v1.SpawnRequest
+---
+---@return pinnacle.process.v1.SpawnResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_process_v1_ProcessService_Spawn(data)
+    return self:unary_request(pinnacle.process.v1.ProcessService.Spawn, data)
+end
+pinnacle.process.v1.ProcessService.WaitOnSpawn = {}
+pinnacle.process.v1.ProcessService.WaitOnSpawn.service = "pinnacle.process.v1.ProcessService"
+pinnacle.process.v1.ProcessService.WaitOnSpawn.method = "WaitOnSpawn"
+pinnacle.process.v1.ProcessService.WaitOnSpawn.request = ".pinnacle.process.v1.WaitOnSpawnRequest"
+pinnacle.process.v1.ProcessService.WaitOnSpawn.response = ".pinnacle.process.v1.WaitOnSpawnResponse"
+
+---Performs a server-streaming request.
+---
+---`callback` will be called with every streamed response.
+---
+---@nodiscard
+---
+---@param data pinnacle.process.v1.WaitOnSpawnRequest
+---@param callback fun(response: pinnacle.process.v1.WaitOnSpawnResponse)
+---@param done? fun()
+---
+---@return string | nil An error string, if any
+function Client:pinnacle_process_v1_ProcessService_WaitOnSpawn(data, callback, done)
+    return self:server_streaming_request(pinnacle.process.v1.ProcessService.WaitOnSpawn, data, callback, done)
+end
+pinnacle.process.v1.ProcessService.SetEnv = {}
+pinnacle.process.v1.ProcessService.SetEnv.service = "pinnacle.process.v1.ProcessService"
+pinnacle.process.v1.ProcessService.SetEnv.method = "SetEnv"
+pinnacle.process.v1.ProcessService.SetEnv.request = ".pinnacle.process.v1.SetEnvRequest"
+pinnacle.process.v1.ProcessService.SetEnv.response = ".google.protobuf.Empty"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.debug.v1.SetDamageVisualizationRequest
+---@param data pinnacle.process.v1.SetEnvRequest
 ---
 ---@return google.protobuf.Empty | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_debug_v1_DebugService_SetDamageVisualization(data)
-    return self:unary_request(pinnacle.debug.v1.DebugService.SetDamageVisualization, data)
+function Client:pinnacle_process_v1_ProcessService_SetEnv(data)
+    return self:unary_request(pinnacle.process.v1.ProcessService.SetEnv, data)
 end
-pinnacle.debug.v1.DebugService.SetOpaqueRegionVisualization = {}
-pinnacle.debug.v1.DebugService.SetOpaqueRegionVisualization.service = "pinnacle.debug.v1.DebugService"
-pinnacle.debug.v1.DebugService.SetOpaqueRegionVisualization.method = "SetOpaqueRegionVisualization"
-pinnacle.debug.v1.DebugService.SetOpaqueRegionVisualization.request = ".pinnacle.debug.v1.SetOpaqueRegionVisualizationRequest"
-pinnacle.debug.v1.DebugService.SetOpaqueRegionVisualization.response = ".google.protobuf.Empty"
+pinnacle.process.v1.ProcessService.RunOrRaise = {}
+pinnacle.process.v1.ProcessService.RunOrRaise.service = "pinnacle.process.v1.ProcessService"
+pinnacle.process.v1.ProcessService.RunOrRaise.method = "RunOrRaise"
+pinnacle.process.v1.ProcessService.RunOrRaise.request = ".pinnacle.process.v1.RunOrRaiseRequest"
+pinnacle.process.v1.ProcessService.RunOrRaise.response = ".pinnacle.process.v1.RunOrRaiseResponse"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.debug.v1.SetOpaqueRegionVisualizationRequest
+---@param data pinnacle.process.v1.RunOrRaiseRequest
 ---
----@return google.protobuf.Empty | nil response
+---@return pinnacle.process.v1.RunOrRaiseResponse | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_debug_v1_DebugService_SetOpaqueRegionVisualization(data)
-    return self:unary_request(pinnacle.debug.v1.DebugService.SetOpaqueRegionVisualization, data)
+function Client:pinnacle_process_v1_ProcessService_RunOrRaise(data)
+    return self:unary_request(pinnacle.process.v1.ProcessService.RunOrRaise, data)
 end
-pinnacle.debug.v1.DebugService.SetCursorPlaneScanout = {}
-pinnacle.debug.v1.DebugService.SetCursorPlaneScanout.service = "pinnacle.debug.v1.DebugService"
-pinnacle.debug.v1.DebugService.SetCursorPlaneScanout.method = "SetCursorPlaneScanout"
-pinnacle.debug.v1.DebugService.SetCursorPlaneScanout.request = ".pinnacle.debug.v1.SetCursorPlaneScanoutRequest"
-pinnacle.debug.v1.DebugService.SetCursorPlaneScanout.response = ".google.protobuf.Empty"
+pinnacle.process.v1.ProcessService.SetGlobalEnv = {}
+pinnacle.process.v1.ProcessService.SetGlobalEnv.service = "pinnacle.process.v1.ProcessService"
+pinnacle.process.v1.ProcessService.SetGlobalEnv.method = "SetGlobalEnv"
+pinnacle.process.v1.ProcessService.SetGlobalEnv.request = ".pinnacle.process.v1.SetGlobalEnvRequest"
+pinnacle.process.v1.ProcessService.SetGlobalEnv.response = ".pinnacle.process.v1.SetGlobalEnvResponse"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.debug.v1.SetCursorPlaneScanoutRequest
+---@param data pinnacle.process.v1.SetGlobalEnvRequest
+---
+---@return pinnacle.process.v1.SetGlobalEnvResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_process_v1_ProcessService_SetGlobalEnv(data)
+    return self:unary_request(pinnacle.process.v1.ProcessService.SetGlobalEnv, data)
+end
+pinnacle.render.v1.RenderService = {}
+pinnacle.render.v1.RenderService.SetUpscaleFilter = {}
+pinnacle.render.v1.RenderService.SetUpscaleFilter.service = "pinnacle.render.v1.RenderService"
+pinnacle.render.v1.RenderService.SetUpscaleFilter.method = "SetUpscaleFilter"
+pinnacle.render.v1.RenderService.SetUpscaleFilter.request = ".pinnacle.render.v1.SetUpscaleFilterRequest"
+pinnacle.render.v1.RenderService.SetUpscaleFilter.response = ".google.protobuf.Empty"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.render.v1.SetUpscaleFilterRequest
 ---
 ---@return google.protobuf.Empty | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_debug_v1_DebugService_SetCursorPlaneScanout(data)
-    return self:unary_request(pinnacle.debug.v1.DebugService.SetCursorPlaneScanout, data)
+function Client:pinnacle_render_v1_RenderService_SetUpscaleFilter(data)
+    return self:unary_request(pinnacle.render.v1.RenderService.SetUpscaleFilter, data)
 end
-pinnacle.debug.v1.DebugService.SetProcessPiping = {}
-pinnacle.debug.v1.DebugService.SetProcessPiping.service = "pinnacle.debug.v1.DebugService"
-pinnacle.debug.v1.DebugService.SetProcessPiping.method = "SetProcessPiping"
-pinnacle.debug.v1.DebugService.SetProcessPiping.request = ".pinnacle.debug.v1.SetProcessPipingRequest"
-pinnacle.debug.v1.DebugService.SetProcessPiping.response = ".google.protobuf.Empty"
+pinnacle.render.v1.RenderService.SetDownscaleFilter = {}
+pinnacle.render.v1.RenderService.SetDownscaleFilter.service = "pinnacle.render.v1.RenderService"
+pinnacle.render.v1.RenderService.SetDownscaleFilter.method = "SetDownscaleFilter"
+pinnacle.render.v1.RenderService.SetDownscaleFilter.request = ".pinnacle.render.v1.SetDownscaleFilterRequest"
+pinnacle.render.v1.RenderService.SetDownscaleFilter.response = ".google.protobuf.Empty"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.debug.v1.SetProcessPipingRequest
+---@param data pinnacle.render.v1.SetDownscaleFilterRequest
 ---
 ---@return google.protobuf.Empty | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_debug_v1_DebugService_SetProcessPiping(data)
-    return self:unary_request(pinnacle.debug.v1.DebugService.SetProcessPiping, data)
+function Client:pinnacle_render_v1_RenderService_SetDownscaleFilter(data)
+    return self:unary_request(pinnacle.render.v1.RenderService.SetDownscaleFilter, data)
 end
-pinnacle.input.v1.InputService = {}
-pinnacle.input.v1.InputService.Bind = {}
-pinnacle.input.v1.InputService.Bind.service = "pinnacle.input.v1.InputService"
-pinnacle.input.v1.InputService.Bind.method = "Bind"
-pinnacle.input.v1.InputService.Bind.request = ".pinnacle.input.v1.BindRequest"
-pinnacle.input.v1.InputService.Bind.response = ".pinnacle.input.v1.BindResponse"
+pinnacle.render.v1.RenderService.SetInactiveEffect = {}
+pinnacle.render.v1.RenderService.SetInactiveEffect.service = "pinnacle.render.v1.RenderService"
+pinnacle.render.v1.RenderService.SetInactiveEffect.method = "SetInactiveEffect"
+pinnacle.render.v1.RenderService.SetInactiveEffect.request = ".pinnacle.render.v1.SetInactiveEffectRequest"
+pinnacle.render.v1.RenderService.SetInactiveEffect.response = ".pinnacle.render.v1.SetInactiveEffectResponse"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.input.v1.BindRequest
+---@param data pinnacle.render.v1.SetInactiveEffectRequest
 ---
----@return pinnacle.input.v1.BindResponse | nil response
+---@return pinnacle.render.v1.SetInactiveEffectResponse | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_input_v1_InputService_Bind(data)
-    return self:unary_request(pinnacle.input.v1.InputService.Bind, data)
+function Client:pinnacle_render_v1_RenderService_SetInactiveEffect(data)
+    return self:unary_request(pinnacle.render.v1.RenderService.SetInactiveEffect, data)
 end
-pinnacle.input.v1.InputService.GetBindInfos = {}
-pinnacle.input.v1.InputService.GetBindInfos.service = "pinnacle.input.v1.InputService"
-pinnacle.input.v1.InputService.GetBindInfos.method = "GetBindInfos"
-pinnacle.input.v1.InputService.GetBindInfos.request = ".pinnacle.input.v1.GetBindInfosRequest"
-pinnacle.input.v1.InputService.GetBindInfos.response = ".pinnacle.input.v1.GetBindInfosResponse"
+pinnacle.render.v1.RenderService.SetLetterboxFullscreen = {}
+pinnacle.render.v1.RenderService.SetLetterboxFullscreen.service = "pinnacle.render.v1.RenderService"
+pinnacle.render.v1.RenderService.SetLetterboxFullscreen.method = "SetLetterboxFullscreen"
+pinnacle.render.v1.RenderService.SetLetterboxFullscreen.request = ".pinnacle.render.v1.SetLetterboxFullscreenRequest"
+pinnacle.render.v1.RenderService.SetLetterboxFullscreen.response = ".pinnacle.render.v1.SetLetterboxFullscreenResponse"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.input.v1.GetBindInfosRequest
+---@param data pinnacle.render.v1.SetLetterboxFullscreenRequest
 ---
----@return pinnacle.input.v1.GetBindInfosResponse | nil response
+---@return pinnacle.render.v1.SetLetterboxFullscreenResponse | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_input_v1_InputService_GetBindInfos(data)
-    return self:unary_request(pinnacle.input.v1.InputService.GetBindInfos, data)
+function Client:pinnacle_render_v1_RenderService_SetLetterboxFullscreen(data)
+    return self:unary_request(pinnacle.render.v1.RenderService.SetLetterboxFullscreen, data)
 end
-pinnacle.input.v1.InputService.SetBindProperties = {}
-pinnacle.input.v1.InputService.SetBindProperties.service = "pinnacle.input.v1.InputService"
-pinnacle.input.v1.InputService.SetBindProperties.method = "SetBindProperties"
-pinnacle.input.v1.InputService.SetBindProperties.request = ".pinnacle.input.v1.SetBindPropertiesRequest"
-pinnacle.input.v1.InputService.SetBindProperties.response = ".google.protobuf.Empty"
+pinnacle.render.v1.RenderService.SetCloseSnapshotHold = {}
+pinnacle.render.v1.RenderService.SetCloseSnapshotHold.service = "pinnacle.render.v1.RenderService"
+pinnacle.render.v1.RenderService.SetCloseSnapshotHold.method = "SetCloseSnapshotHold"
+pinnacle.render.v1.RenderService.SetCloseSnapshotHold.request = ".pinnacle.render.v1.SetCloseSnapshotHoldRequest"
+pinnacle.render.v1.RenderService.SetCloseSnapshotHold.response = ".pinnacle.render.v1.SetCloseSnapshotHoldResponse"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.input.v1.SetBindPropertiesRequest
+---@param data pinnacle.render.v1.SetCloseSnapshotHoldRequest
 ---
----@return google.protobuf.Empty | nil response
+---@return pinnacle.render.v1.SetCloseSnapshotHoldResponse | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_input_v1_InputService_SetBindProperties(data)
-    return self:unary_request(pinnacle.input.v1.InputService.SetBindProperties, data)
+function Client:pinnacle_render_v1_RenderService_SetCloseSnapshotHold(data)
+    return self:unary_request(pinnacle.render.v1.RenderService.SetCloseSnapshotHold, data)
 end
-pinnacle.input.v1.InputService.GetBindLayerStack = {}
-pinnacle.input.v1.InputService.GetBindLayerStack.service = "pinnacle.input.v1.InputService"
-pinnacle.input.v1.InputService.GetBindLayerStack.method = "GetBindLayerStack"
-pinnacle.input.v1.InputService.GetBindLayerStack.request = ".pinnacle.input.v1.GetBindLayerStackRequest"
-pinnacle.input.v1.InputService.GetBindLayerStack.response = ".pinnacle.input.v1.GetBindLayerStackResponse"
+pinnacle.screenshot.v1.ScreenshotService = {}
+pinnacle.screenshot.v1.ScreenshotService.SelectRegion = {}
+pinnacle.screenshot.v1.ScreenshotService.SelectRegion.service = "pinnacle.screenshot.v1.ScreenshotService"
+pinnacle.screenshot.v1.ScreenshotService.SelectRegion.method = "SelectRegion"
+pinnacle.screenshot.v1.ScreenshotService.SelectRegion.request = ".pinnacle.screenshot.v1.SelectRegionRequest"
+pinnacle.screenshot.v1.ScreenshotService.SelectRegion.response = ".pinnacle.screenshot.v1.SelectRegionResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.screenshot.v1.SelectRegionRequest
+---
+---@return pinnacle.screenshot.v1.SelectRegionResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_screenshot_v1_ScreenshotService_SelectRegion(data)
+    return self:unary_request(pinnacle.screenshot.v1.ScreenshotService.SelectRegion, data)
+end
+pinnacle.window.v1.WindowService = {}
+pinnacle.window.v1.WindowService.Get = {}
+pinnacle.window.v1.WindowService.Get.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.Get.method = "Get"
+pinnacle.window.v1.WindowService.Get.request = ".pinnacle.window.v1.GetRequest"
+pinnacle.window.v1.WindowService.Get.response = ".pinnacle.window.v1.GetResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.window.v1.GetRequest
+---
+---@return pinnacle.window.v1.GetResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_window_v1_WindowService_Get(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.Get, data)
+end
+pinnacle.window.v1.WindowService.Exists = {}
+pinnacle.window.v1.WindowService.Exists.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.Exists.method = "Exists"
+pinnacle.window.v1.WindowService.Exists.request = ".pinnacle.window.v1.ExistsRequest"
+pinnacle.window.v1.WindowService.Exists.response = ".pinnacle.window.v1.ExistsResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.window.v1.ExistsRequest
+---
+---@return pinnacle.window.v1.ExistsResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_window_v1_WindowService_Exists(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.Exists, data)
+end
+pinnacle.window.v1.WindowService.GetAppId = {}
+pinnacle.window.v1.WindowService.GetAppId.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.GetAppId.method = "GetAppId"
+pinnacle.window.v1.WindowService.GetAppId.request = ".pinnacle.window.v1.GetAppIdRequest"
+pinnacle.window.v1.WindowService.GetAppId.response = ".pinnacle.window.v1.GetAppIdResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.window.v1.GetAppIdRequest
+---
+---@return pinnacle.window.v1.GetAppIdResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_window_v1_WindowService_GetAppId(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.GetAppId, data)
+end
+pinnacle.window.v1.WindowService.GetTitle = {}
+pinnacle.window.v1.WindowService.GetTitle.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.GetTitle.method = "GetTitle"
+pinnacle.window.v1.WindowService.GetTitle.request = ".pinnacle.window.v1.GetTitleRequest"
+pinnacle.window.v1.WindowService.GetTitle.response = ".pinnacle.window.v1.GetTitleResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.window.v1.GetTitleRequest
+---
+---@return pinnacle.window.v1.GetTitleResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_window_v1_WindowService_GetTitle(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.GetTitle, data)
+end
+pinnacle.window.v1.WindowService.GetLoc = {}
+pinnacle.window.v1.WindowService.GetLoc.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.GetLoc.method = "GetLoc"
+pinnacle.window.v1.WindowService.GetLoc.request = ".pinnacle.window.v1.GetLocRequest"
+pinnacle.window.v1.WindowService.GetLoc.response = ".pinnacle.window.v1.GetLocResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.window.v1.GetLocRequest
+---
+---@return pinnacle.window.v1.GetLocResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_window_v1_WindowService_GetLoc(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.GetLoc, data)
+end
+pinnacle.window.v1.WindowService.GetSize = {}
+pinnacle.window.v1.WindowService.GetSize.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.GetSize.method = "GetSize"
+pinnacle.window.v1.WindowService.GetSize.request = ".pinnacle.window.v1.GetSizeRequest"
+pinnacle.window.v1.WindowService.GetSize.response = ".pinnacle.window.v1.GetSizeResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.window.v1.GetSizeRequest
+---
+---@return pinnacle.window.v1.GetSizeResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_window_v1_WindowService_GetSize(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.GetSize, data)
+end
+pinnacle.window.v1.WindowService.GetFocused = {}
+pinnacle.window.v1.WindowService.GetFocused.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.GetFocused.method = "GetFocused"
+pinnacle.window.v1.WindowService.GetFocused.request = ".pinnacle.window.v1.GetFocusedRequest"
+pinnacle.window.v1.WindowService.GetFocused.response = ".pinnacle.window.v1.GetFocusedResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.window.v1.GetFocusedRequest
+---
+---@return pinnacle.window.v1.GetFocusedResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_window_v1_WindowService_GetFocused(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.GetFocused, data)
+end
+pinnacle.window.v1.WindowService.GetFocusAge = {}
+pinnacle.window.v1.WindowService.GetFocusAge.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.GetFocusAge.method = "GetFocusAge"
+pinnacle.window.v1.WindowService.GetFocusAge.request = ".pinnacle.window.v1.GetFocusAgeRequest"
+pinnacle.window.v1.WindowService.GetFocusAge.response = ".pinnacle.window.v1.GetFocusAgeResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.window.v1.GetFocusAgeRequest
+---
+---@return pinnacle.window.v1.GetFocusAgeResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_window_v1_WindowService_GetFocusAge(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.GetFocusAge, data)
+end
+pinnacle.window.v1.WindowService.GetPreviouslyFocused = {}
+pinnacle.window.v1.WindowService.GetPreviouslyFocused.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.GetPreviouslyFocused.method = "GetPreviouslyFocused"
+pinnacle.window.v1.WindowService.GetPreviouslyFocused.request = ".pinnacle.window.v1.GetPreviouslyFocusedRequest"
+pinnacle.window.v1.WindowService.GetPreviouslyFocused.response = ".pinnacle.window.v1.GetPreviouslyFocusedResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.window.v1.GetPreviouslyFocusedRequest
+---
+---@return pinnacle.window.v1.GetPreviouslyFocusedResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_window_v1_WindowService_GetPreviouslyFocused(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.GetPreviouslyFocused, data)
+end
+pinnacle.window.v1.WindowService.Find = {}
+pinnacle.window.v1.WindowService.Find.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.Find.method = "Find"
+pinnacle.window.v1.WindowService.Find.request = ".pinnacle.window.v1.FindRequest"
+pinnacle.window.v1.WindowService.Find.response = ".pinnacle.window.v1.FindResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.window.v1.FindRequest
+---
+---@return pinnacle.window.v1.FindResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_window_v1_WindowService_Find(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.Find, data)
+end
+pinnacle.window.v1.WindowService.GetResponsive = {}
+pinnacle.window.v1.WindowService.GetResponsive.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.GetResponsive.method = "GetResponsive"
+pinnacle.window.v1.WindowService.GetResponsive.request = ".pinnacle.window.v1.GetResponsiveRequest"
+pinnacle.window.v1.WindowService.GetResponsive.response = ".pinnacle.window.v1.GetResponsiveResponse"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.input.v1.GetBindLayerStackRequest
+---@param data pinnacle.window.v1.GetResponsiveRequest
 ---
----@return pinnacle.input.v1.GetBindLayerStackResponse | nil response
+---@return pinnacle.window.v1.GetResponsiveResponse | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_input_v1_InputService_GetBindLayerStack(data)
-    return self:unary_request(pinnacle.input.v1.InputService.GetBindLayerStack, data)
+function Client:pinnacle_window_v1_WindowService_GetResponsive(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.GetResponsive, data)
 end
-pinnacle.input.v1.InputService.EnterBindLayer = {}
-pinnacle.input.v1.InputService.EnterBindLayer.service = "pinnacle.input.v1.InputService"
-pinnacle.input.v1.InputService.EnterBindLayer.method = "EnterBindLayer"
-pinnacle.input.v1.InputService.EnterBindLayer.request = ".pinnacle.input.v1.EnterBindLayerRequest"
-pinnacle.input.v1.InputService.EnterBindLayer.response = ".google.protobuf.Empty"
+pinnacle.window.v1.WindowService.GetLayoutMode = {}
+pinnacle.window.v1.WindowService.GetLayoutMode.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.GetLayoutMode.method = "GetLayoutMode"
+pinnacle.window.v1.WindowService.GetLayoutMode.request = ".pinnacle.window.v1.GetLayoutModeRequest"
+pinnacle.window.v1.WindowService.GetLayoutMode.response = ".pinnacle.window.v1.GetLayoutModeResponse"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.input.v1.EnterBindLayerRequest
+---@param data pinnacle.window.v1.GetLayoutModeRequest
 ---
----@return google.protobuf.Empty | nil response
+---@return pinnacle.window.v1.GetLayoutModeResponse | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_input_v1_InputService_EnterBindLayer(data)
-    return self:unary_request(pinnacle.input.v1.InputService.EnterBindLayer, data)
+function Client:pinnacle_window_v1_WindowService_GetLayoutMode(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.GetLayoutMode, data)
 end
-pinnacle.input.v1.InputService.KeybindStream = {}
-pinnacle.input.v1.InputService.KeybindStream.service = "pinnacle.input.v1.InputService"
-pinnacle.input.v1.InputService.KeybindStream.method = "KeybindStream"
-pinnacle.input.v1.InputService.KeybindStream.request = ".pinnacle.input.v1.KeybindStreamRequest"
-pinnacle.input.v1.InputService.KeybindStream.response = ".pinnacle.input.v1.KeybindStreamResponse"
+pinnacle.window.v1.WindowService.GetMinimized = {}
+pinnacle.window.v1.WindowService.GetMinimized.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.GetMinimized.method = "GetMinimized"
+pinnacle.window.v1.WindowService.GetMinimized.request = ".pinnacle.window.v1.GetMinimizedRequest"
+pinnacle.window.v1.WindowService.GetMinimized.response = ".pinnacle.window.v1.GetMinimizedResponse"
 
----Performs a server-streaming request.
----
----`callback` will be called with every streamed response.
+---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.input.v1.KeybindStreamRequest
----@param callback fun(response: pinnacle.input.v1.KeybindStreamResponse)
----@param done? fun()
+---@param data pinnacle.window.v1.GetMinimizedRequest
 ---
----@return string | nil An error string, if any
-function Client:pinnacle_input_v1_InputService_KeybindStream(data, callback, done)
-    return self:server_streaming_request(pinnacle.input.v1.InputService.KeybindStream, data, callback, done)
+---@return pinnacle.window.v1.GetMinimizedResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_window_v1_WindowService_GetMinimized(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.GetMinimized, data)
 end
-pinnacle.input.v1.InputService.MousebindStream = {}
-pinnacle.input.v1.InputService.MousebindStream.service = "pinnacle.input.v1.InputService"
-pinnacle.input.v1.InputService.MousebindStream.method = "MousebindStream"
-pinnacle.input.v1.InputService.MousebindStream.request = ".pinnacle.input.v1.MousebindStreamRequest"
-pinnacle.input.v1.InputService.MousebindStream.response = ".pinnacle.input.v1.MousebindStreamResponse"
+pinnacle.window.v1.WindowService.GetDemandsAttention = {}
+pinnacle.window.v1.WindowService.GetDemandsAttention.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.GetDemandsAttention.method = "GetDemandsAttention"
+pinnacle.window.v1.WindowService.GetDemandsAttention.request = ".pinnacle.window.v1.GetDemandsAttentionRequest"
+pinnacle.window.v1.WindowService.GetDemandsAttention.response = ".pinnacle.window.v1.GetDemandsAttentionResponse"
 
----Performs a server-streaming request.
----
----`callback` will be called with every streamed response.
+---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.input.v1.MousebindStreamRequest
----@param callback fun(response: pinnacle.input.v1.MousebindStreamResponse)
----@param done? fun()
+---@param data pinnacle.window.v1.GetDemandsAttentionRequest
 ---
----@return string | nil An error string, if any
-function Client:pinnacle_input_v1_InputService_MousebindStream(data, callback, done)
-    return self:server_streaming_request(pinnacle.input.v1.InputService.MousebindStream, data, callback, done)
+---@return pinnacle.window.v1.GetDemandsAttentionResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_window_v1_WindowService_GetDemandsAttention(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.GetDemandsAttention, data)
 end
-pinnacle.input.v1.InputService.KeybindOnPress = {}
-pinnacle.input.v1.InputService.KeybindOnPress.service = "pinnacle.input.v1.InputService"
-pinnacle.input.v1.InputService.KeybindOnPress.method = "KeybindOnPress"
-pinnacle.input.v1.InputService.KeybindOnPress.request = ".pinnacle.input.v1.KeybindOnPressRequest"
-pinnacle.input.v1.InputService.KeybindOnPress.response = ".google.protobuf.Empty"
+pinnacle.window.v1.WindowService.GetIcon = {}
+pinnacle.window.v1.WindowService.GetIcon.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.GetIcon.method = "GetIcon"
+pinnacle.window.v1.WindowService.GetIcon.request = ".pinnacle.window.v1.GetIconRequest"
+pinnacle.window.v1.WindowService.GetIcon.response = ".pinnacle.window.v1.GetIconResponse"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.input.v1.KeybindOnPressRequest
+---@param data pinnacle.window.v1.GetIconRequest
 ---
----@return google.protobuf.Empty | nil response
+---@return pinnacle.window.v1.GetIconResponse | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_input_v1_InputService_KeybindOnPress(data)
-    return self:unary_request(pinnacle.input.v1.InputService.KeybindOnPress, data)
+function Client:pinnacle_window_v1_WindowService_GetIcon(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.GetIcon, data)
 end
-pinnacle.input.v1.InputService.MousebindOnPress = {}
-pinnacle.input.v1.InputService.MousebindOnPress.service = "pinnacle.input.v1.InputService"
-pinnacle.input.v1.InputService.MousebindOnPress.method = "MousebindOnPress"
-pinnacle.input.v1.InputService.MousebindOnPress.request = ".pinnacle.input.v1.MousebindOnPressRequest"
-pinnacle.input.v1.InputService.MousebindOnPress.response = ".google.protobuf.Empty"
+pinnacle.window.v1.WindowService.GetLayer = {}
+pinnacle.window.v1.WindowService.GetLayer.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.GetLayer.method = "GetLayer"
+pinnacle.window.v1.WindowService.GetLayer.request = ".pinnacle.window.v1.GetLayerRequest"
+pinnacle.window.v1.WindowService.GetLayer.response = ".pinnacle.window.v1.GetLayerResponse"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.input.v1.MousebindOnPressRequest
+---@param data pinnacle.window.v1.GetLayerRequest
 ---
----@return google.protobuf.Empty | nil response
+---@return pinnacle.window.v1.GetLayerResponse | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_input_v1_InputService_MousebindOnPress(data)
-    return self:unary_request(pinnacle.input.v1.InputService.MousebindOnPress, data)
+function Client:pinnacle_window_v1_WindowService_GetLayer(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.GetLayer, data)
 end
-pinnacle.input.v1.InputService.SetXkbConfig = {}
-pinnacle.input.v1.InputService.SetXkbConfig.service = "pinnacle.input.v1.InputService"
-pinnacle.input.v1.InputService.SetXkbConfig.method = "SetXkbConfig"
-pinnacle.input.v1.InputService.SetXkbConfig.request = ".pinnacle.input.v1.SetXkbConfigRequest"
-pinnacle.input.v1.InputService.SetXkbConfig.response = ".google.protobuf.Empty"
+pinnacle.window.v1.WindowService.GetTagIds = {}
+pinnacle.window.v1.WindowService.GetTagIds.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.GetTagIds.method = "GetTagIds"
+pinnacle.window.v1.WindowService.GetTagIds.request = ".pinnacle.window.v1.GetTagIdsRequest"
+pinnacle.window.v1.WindowService.GetTagIds.response = ".pinnacle.window.v1.GetTagIdsResponse"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.input.v1.SetXkbConfigRequest
+---@param data pinnacle.window.v1.GetTagIdsRequest
 ---
----@return google.protobuf.Empty | nil response
+---@return pinnacle.window.v1.GetTagIdsResponse | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_input_v1_InputService_SetXkbConfig(data)
-    return self:unary_request(pinnacle.input.v1.InputService.SetXkbConfig, data)
+function Client:pinnacle_window_v1_WindowService_GetTagIds(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.GetTagIds, data)
 end
-pinnacle.input.v1.InputService.SetRepeatRate = {}
-pinnacle.input.v1.InputService.SetRepeatRate.service = "pinnacle.input.v1.InputService"
-pinnacle.input.v1.InputService.SetRepeatRate.method = "SetRepeatRate"
-pinnacle.input.v1.InputService.SetRepeatRate.request = ".pinnacle.input.v1.SetRepeatRateRequest"
-pinnacle.input.v1.InputService.SetRepeatRate.response = ".google.protobuf.Empty"
+pinnacle.window.v1.WindowService.GetParent = {}
+pinnacle.window.v1.WindowService.GetParent.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.GetParent.method = "GetParent"
+pinnacle.window.v1.WindowService.GetParent.request = ".pinnacle.window.v1.GetParentRequest"
+pinnacle.window.v1.WindowService.GetParent.response = ".pinnacle.window.v1.GetParentResponse"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.input.v1.SetRepeatRateRequest
+---@param data pinnacle.window.v1.GetParentRequest
 ---
----@return google.protobuf.Empty | nil response
+---@return pinnacle.window.v1.GetParentResponse | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_input_v1_InputService_SetRepeatRate(data)
-    return self:unary_request(pinnacle.input.v1.InputService.SetRepeatRate, data)
+function Client:pinnacle_window_v1_WindowService_GetParent(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.GetParent, data)
 end
-pinnacle.input.v1.InputService.SetXkbKeymap = {}
-pinnacle.input.v1.InputService.SetXkbKeymap.service = "pinnacle.input.v1.InputService"
-pinnacle.input.v1.InputService.SetXkbKeymap.method = "SetXkbKeymap"
-pinnacle.input.v1.InputService.SetXkbKeymap.request = ".pinnacle.input.v1.SetXkbKeymapRequest"
-pinnacle.input.v1.InputService.SetXkbKeymap.response = ".google.protobuf.Empty"
+pinnacle.window.v1.WindowService.GetChildren = {}
+pinnacle.window.v1.WindowService.GetChildren.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.GetChildren.method = "GetChildren"
+pinnacle.window.v1.WindowService.GetChildren.request = ".pinnacle.window.v1.GetChildrenRequest"
+pinnacle.window.v1.WindowService.GetChildren.response = ".pinnacle.window.v1.GetChildrenResponse"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.input.v1.SetXkbKeymapRequest
+---@param data pinnacle.window.v1.GetChildrenRequest
 ---
----@return google.protobuf.Empty | nil response
+---@return pinnacle.window.v1.GetChildrenResponse | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_input_v1_InputService_SetXkbKeymap(data)
-    return self:unary_request(pinnacle.input.v1.InputService.SetXkbKeymap, data)
+function Client:pinnacle_window_v1_WindowService_GetChildren(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.GetChildren, data)
 end
-pinnacle.input.v1.InputService.SwitchXkbLayout = {}
-pinnacle.input.v1.InputService.SwitchXkbLayout.service = "pinnacle.input.v1.InputService"
-pinnacle.input.v1.InputService.SwitchXkbLayout.method = "SwitchXkbLayout"
-pinnacle.input.v1.InputService.SwitchXkbLayout.request = ".pinnacle.input.v1.SwitchXkbLayoutRequest"
-pinnacle.input.v1.InputService.SwitchXkbLayout.response = ".google.protobuf.Empty"
+pinnacle.window.v1.WindowService.GetWindowsInDir = {}
+pinnacle.window.v1.WindowService.GetWindowsInDir.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.GetWindowsInDir.method = "GetWindowsInDir"
+pinnacle.window.v1.WindowService.GetWindowsInDir.request = ".pinnacle.window.v1.GetWindowsInDirRequest"
+pinnacle.window.v1.WindowService.GetWindowsInDir.response = ".pinnacle.window.v1.GetWindowsInDirResponse"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.input.v1.SwitchXkbLayoutRequest
+---@param data pinnacle.window.v1.GetWindowsInDirRequest
 ---
----@return google.protobuf.Empty | nil response
+---@return pinnacle.window.v1.GetWindowsInDirResponse | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_input_v1_InputService_SwitchXkbLayout(data)
-    return self:unary_request(pinnacle.input.v1.InputService.SwitchXkbLayout, data)
+function Client:pinnacle_window_v1_WindowService_GetWindowsInDir(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.GetWindowsInDir, data)
 end
-pinnacle.input.v1.InputService.SetXcursor = {}
-pinnacle.input.v1.InputService.SetXcursor.service = "pinnacle.input.v1.InputService"
-pinnacle.input.v1.InputService.SetXcursor.method = "SetXcursor"
-pinnacle.input.v1.InputService.SetXcursor.request = ".pinnacle.input.v1.SetXcursorRequest"
-pinnacle.input.v1.InputService.SetXcursor.response = ".google.protobuf.Empty"
+pinnacle.window.v1.WindowService.GetForeignToplevelListIdentifier = {}
+pinnacle.window.v1.WindowService.GetForeignToplevelListIdentifier.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.GetForeignToplevelListIdentifier.method = "GetForeignToplevelListIdentifier"
+pinnacle.window.v1.WindowService.GetForeignToplevelListIdentifier.request = ".pinnacle.window.v1.GetForeignToplevelListIdentifierRequest"
+pinnacle.window.v1.WindowService.GetForeignToplevelListIdentifier.response = ".pinnacle.window.v1.GetForeignToplevelListIdentifierResponse"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.input.v1.SetXcursorRequest
+---@param data pinnacle.window.v1.GetForeignToplevelListIdentifierRequest
 ---
----@return google.protobuf.Empty | nil response
+---@return pinnacle.window.v1.GetForeignToplevelListIdentifierResponse | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_input_v1_InputService_SetXcursor(data)
-    return self:unary_request(pinnacle.input.v1.InputService.SetXcursor, data)
+function Client:pinnacle_window_v1_WindowService_GetForeignToplevelListIdentifier(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.GetForeignToplevelListIdentifier, data)
 end
-pinnacle.input.v1.InputService.GetDevices = {}
-pinnacle.input.v1.InputService.GetDevices.service = "pinnacle.input.v1.InputService"
-pinnacle.input.v1.InputService.GetDevices.method = "GetDevices"
-pinnacle.input.v1.InputService.GetDevices.request = ".pinnacle.input.v1.GetDevicesRequest"
-pinnacle.input.v1.InputService.GetDevices.response = ".pinnacle.input.v1.GetDevicesResponse"
+pinnacle.window.v1.WindowService.WatchProperties = {}
+pinnacle.window.v1.WindowService.WatchProperties.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.WatchProperties.method = "WatchProperties"
+pinnacle.window.v1.WindowService.WatchProperties.request = ".pinnacle.window.v1.WatchPropertiesRequest"
+pinnacle.window.v1.WindowService.WatchProperties.response = ".pinnacle.window.v1.WatchPropertiesResponse"
 
----Performs a unary request.
+---Performs a server-streaming request.
+---
+---`callback` will be called with every streamed response.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.input.v1.GetDevicesRequest
+---@param data pinnacle.window.v1.WatchPropertiesRequest
+---@param callback fun(response: pinnacle.window.v1.WatchPropertiesResponse)
+---@param done? fun()
 ---
----@return pinnacle.input.v1.GetDevicesResponse | nil response
----@return string | nil error An error string, if any
-function Client:pinnacle_input_v1_InputService_GetDevices(data)
-    return self:unary_request(pinnacle.input.v1.InputService.GetDevices, data)
+---@return string | nil An error string, if any
+function Client:pinnacle_window_v1_WindowService_WatchProperties(data, callback, done)
+    return self:server_streaming_request(pinnacle.window.v1.WindowService.WatchProperties, data, callback, done)
 end
-pinnacle.input.v1.InputService.GetDeviceCapabilities = {}
-pinnacle.input.v1.InputService.GetDeviceCapabilities.service = "pinnacle.input.v1.InputService"
-pinnacle.input.v1.InputService.GetDeviceCapabilities.method = "GetDeviceCapabilities"
-pinnacle.input.v1.InputService.GetDeviceCapabilities.request = ".pinnacle.input.v1.GetDeviceCapabilitiesRequest"
-pinnacle.input.v1.InputService.GetDeviceCapabilities.response = ".pinnacle.input.v1.GetDeviceCapabilitiesResponse"
+pinnacle.window.v1.WindowService.Close = {}
+pinnacle.window.v1.WindowService.Close.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.Close.method = "Close"
+pinnacle.window.v1.WindowService.Close.request = ".pinnacle.window.v1.CloseRequest"
+pinnacle.window.v1.WindowService.Close.response = ".google.protobuf.Empty"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.input.v1.GetDeviceCapabilitiesRequest
+---@param data pinnacle.window.v1.CloseRequest
 ---
----@return pinnacle.input.v1.GetDeviceCapabilitiesResponse | nil response
+---@return google.protobuf.Empty | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_input_v1_InputService_GetDeviceCapabilities(data)
-    return self:unary_request(pinnacle.input.v1.InputService.GetDeviceCapabilities, data)
+function Client:pinnacle_window_v1_WindowService_Close(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.Close, data)
 end
-pinnacle.input.v1.InputService.GetDeviceInfo = {}
-pinnacle.input.v1.InputService.GetDeviceInfo.service = "pinnacle.input.v1.InputService"
-pinnacle.input.v1.InputService.GetDeviceInfo.method = "GetDeviceInfo"
-pinnacle.input.v1.InputService.GetDeviceInfo.request = ".pinnacle.input.v1.GetDeviceInfoRequest"
-pinnacle.input.v1.InputService.GetDeviceInfo.response = ".pinnacle.input.v1.GetDeviceInfoResponse"
+pinnacle.window.v1.WindowService.SetGeometry = {}
+pinnacle.window.v1.WindowService.SetGeometry.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.SetGeometry.method = "SetGeometry"
+pinnacle.window.v1.WindowService.SetGeometry.request = ".pinnacle.window.v1.SetGeometryRequest"
+pinnacle.window.v1.WindowService.SetGeometry.response = ".google.protobuf.Empty"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.input.v1.GetDeviceInfoRequest
+---@param data pinnacle.window.v1.SetGeometryRequest
 ---
----@return pinnacle.input.v1.GetDeviceInfoResponse | nil response
+---@return google.protobuf.Empty | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_input_v1_InputService_GetDeviceInfo(data)
-    return self:unary_request(pinnacle.input.v1.InputService.GetDeviceInfo, data)
+function Client:pinnacle_window_v1_WindowService_SetGeometry(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.SetGeometry, data)
 end
-pinnacle.input.v1.InputService.GetDeviceType = {}
-pinnacle.input.v1.InputService.GetDeviceType.service = "pinnacle.input.v1.InputService"
-pinnacle.input.v1.InputService.GetDeviceType.method = "GetDeviceType"
-pinnacle.input.v1.InputService.GetDeviceType.request = ".pinnacle.input.v1.GetDeviceTypeRequest"
-pinnacle.input.v1.InputService.GetDeviceType.response = ".pinnacle.input.v1.GetDeviceTypeResponse"
+pinnacle.window.v1.WindowService.ResizeTile = {}
+pinnacle.window.v1.WindowService.ResizeTile.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.ResizeTile.method = "ResizeTile"
+pinnacle.window.v1.WindowService.ResizeTile.request = ".pinnacle.window.v1.ResizeTileRequest"
+pinnacle.window.v1.WindowService.ResizeTile.response = ".google.protobuf.Empty"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.input.v1.GetDeviceTypeRequest
+---@param data pinnacle.window.v1.ResizeTileRequest
 ---
----@return pinnacle.input.v1.GetDeviceTypeResponse | nil response
+---@return google.protobuf.Empty | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_input_v1_InputService_GetDeviceType(data)
-    return self:unary_request(pinnacle.input.v1.InputService.GetDeviceType, data)
+function Client:pinnacle_window_v1_WindowService_ResizeTile(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.ResizeTile, data)
 end
-pinnacle.input.v1.InputService.SetDeviceLibinputSetting = {}
-pinnacle.input.v1.InputService.SetDeviceLibinputSetting.service = "pinnacle.input.v1.InputService"
-pinnacle.input.v1.InputService.SetDeviceLibinputSetting.method = "SetDeviceLibinputSetting"
-pinnacle.input.v1.InputService.SetDeviceLibinputSetting.request = ".pinnacle.input.v1.SetDeviceLibinputSettingRequest"
-pinnacle.input.v1.InputService.SetDeviceLibinputSetting.response = ".google.protobuf.Empty"
+pinnacle.window.v1.WindowService.SetFullscreen = {}
+pinnacle.window.v1.WindowService.SetFullscreen.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.SetFullscreen.method = "SetFullscreen"
+pinnacle.window.v1.WindowService.SetFullscreen.request = ".pinnacle.window.v1.SetFullscreenRequest"
+pinnacle.window.v1.WindowService.SetFullscreen.response = ".google.protobuf.Empty"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.input.v1.SetDeviceLibinputSettingRequest
+---@param data pinnacle.window.v1.SetFullscreenRequest
 ---
 ---@return google.protobuf.Empty | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_input_v1_InputService_SetDeviceLibinputSetting(data)
-    return self:unary_request(pinnacle.input.v1.InputService.SetDeviceLibinputSetting, data)
+function Client:pinnacle_window_v1_WindowService_SetFullscreen(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.SetFullscreen, data)
 end
-pinnacle.input.v1.InputService.SetDeviceMapTarget = {}
-pinnacle.input.v1.InputService.SetDeviceMapTarget.service = "pinnacle.input.v1.InputService"
-pinnacle.input.v1.InputService.SetDeviceMapTarget.method = "SetDeviceMapTarget"
-pinnacle.input.v1.InputService.SetDeviceMapTarget.request = ".pinnacle.input.v1.SetDeviceMapTargetRequest"
-pinnacle.input.v1.InputService.SetDeviceMapTarget.response = ".google.protobuf.Empty"
+pinnacle.window.v1.WindowService.SetMaximized = {}
+pinnacle.window.v1.WindowService.SetMaximized.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.SetMaximized.method = "SetMaximized"
+pinnacle.window.v1.WindowService.SetMaximized.request = ".pinnacle.window.v1.SetMaximizedRequest"
+pinnacle.window.v1.WindowService.SetMaximized.response = ".google.protobuf.Empty"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.input.v1.SetDeviceMapTargetRequest
+---@param data pinnacle.window.v1.SetMaximizedRequest
 ---
 ---@return google.protobuf.Empty | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_input_v1_InputService_SetDeviceMapTarget(data)
-    return self:unary_request(pinnacle.input.v1.InputService.SetDeviceMapTarget, data)
+function Client:pinnacle_window_v1_WindowService_SetMaximized(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.SetMaximized, data)
 end
-pinnacle.layout.v1.LayoutService = {}
-pinnacle.layout.v1.LayoutService.Layout = {}
-pinnacle.layout.v1.LayoutService.Layout.service = "pinnacle.layout.v1.LayoutService"
-pinnacle.layout.v1.LayoutService.Layout.method = "Layout"
-pinnacle.layout.v1.LayoutService.Layout.request = ".pinnacle.layout.v1.LayoutRequest"
-pinnacle.layout.v1.LayoutService.Layout.response = ".pinnacle.layout.v1.LayoutResponse"
+pinnacle.window.v1.WindowService.SetMinimized = {}
+pinnacle.window.v1.WindowService.SetMinimized.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.SetMinimized.method = "SetMinimized"
+pinnacle.window.v1.WindowService.SetMinimized.request = ".pinnacle.window.v1.SetMinimizedRequest"
+pinnacle.window.v1.WindowService.SetMinimized.response = ".google.protobuf.Empty"
 
----Performs a bidirectional-streaming request.
+---Performs a unary request.
 ---
----`callback` will be called with every streamed response.
+---@nodiscard
 ---
----The raw client-to-server stream is returned to allow you to send encoded messages.
+---@param data pinnacle.window.v1.SetMinimizedRequest
+---
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_window_v1_WindowService_SetMinimized(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.SetMinimized, data)
+end
+pinnacle.window.v1.WindowService.SetDemandsAttention = {}
+pinnacle.window.v1.WindowService.SetDemandsAttention.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.SetDemandsAttention.method = "SetDemandsAttention"
+pinnacle.window.v1.WindowService.SetDemandsAttention.request = ".pinnacle.window.v1.SetDemandsAttentionRequest"
+pinnacle.window.v1.WindowService.SetDemandsAttention.response = ".google.protobuf.Empty"
+
+---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param callback fun(response: pinnacle.layout.v1.LayoutResponse, stream: grpc_client.h2.Stream)
----@param done? fun()
+---@param data pinnacle.window.v1.SetDemandsAttentionRequest
 ---
----@return grpc_client.h2.Stream | nil
----@return string | nil An error string, if any
-function Client:pinnacle_layout_v1_LayoutService_Layout(callback, done)
-    return self:bidirectional_streaming_request(pinnacle.layout.v1.LayoutService.Layout, callback, done)
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_window_v1_WindowService_SetDemandsAttention(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.SetDemandsAttention, data)
 end
-pinnacle.output.v1.OutputService = {}
-pinnacle.output.v1.OutputService.Get = {}
-pinnacle.output.v1.OutputService.Get.service = "pinnacle.output.v1.OutputService"
-pinnacle.output.v1.OutputService.Get.method = "Get"
-pinnacle.output.v1.OutputService.Get.request = ".pinnacle.output.v1.GetRequest"
-pinnacle.output.v1.OutputService.Get.response = ".pinnacle.output.v1.GetResponse"
+pinnacle.window.v1.WindowService.SetFloating = {}
+pinnacle.window.v1.WindowService.SetFloating.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.SetFloating.method = "SetFloating"
+pinnacle.window.v1.WindowService.SetFloating.request = ".pinnacle.window.v1.SetFloatingRequest"
+pinnacle.window.v1.WindowService.SetFloating.response = ".google.protobuf.Empty"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.output.v1.GetRequest
+---@param data pinnacle.window.v1.SetFloatingRequest
 ---
----@return pinnacle.output.v1.GetResponse | nil response
+---@return google.protobuf.Empty | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_output_v1_OutputService_Get(data)
-    return self:unary_request(pinnacle.output.v1.OutputService.Get, data)
+function Client:pinnacle_window_v1_WindowService_SetFloating(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.SetFloating, data)
 end
-pinnacle.output.v1.OutputService.SetLoc = {}
-pinnacle.output.v1.OutputService.SetLoc.service = "pinnacle.output.v1.OutputService"
-pinnacle.output.v1.OutputService.SetLoc.method = "SetLoc"
-pinnacle.output.v1.OutputService.SetLoc.request = ".pinnacle.output.v1.SetLocRequest"
-pinnacle.output.v1.OutputService.SetLoc.response = ".google.protobuf.Empty"
+pinnacle.window.v1.WindowService.SetFocused = {}
+pinnacle.window.v1.WindowService.SetFocused.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.SetFocused.method = "SetFocused"
+pinnacle.window.v1.WindowService.SetFocused.request = ".pinnacle.window.v1.SetFocusedRequest"
+pinnacle.window.v1.WindowService.SetFocused.response = ".google.protobuf.Empty"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.output.v1.SetLocRequest
+---@param data pinnacle.window.v1.SetFocusedRequest
 ---
 ---@return google.protobuf.Empty | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_output_v1_OutputService_SetLoc(data)
-    return self:unary_request(pinnacle.output.v1.OutputService.SetLoc, data)
+function Client:pinnacle_window_v1_WindowService_SetFocused(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.SetFocused, data)
 end
-pinnacle.output.v1.OutputService.SetMode = {}
-pinnacle.output.v1.OutputService.SetMode.service = "pinnacle.output.v1.OutputService"
-pinnacle.output.v1.OutputService.SetMode.method = "SetMode"
-pinnacle.output.v1.OutputService.SetMode.request = ".pinnacle.output.v1.SetModeRequest"
-pinnacle.output.v1.OutputService.SetMode.response = ".google.protobuf.Empty"
+pinnacle.window.v1.WindowService.FocusInDirection = {}
+pinnacle.window.v1.WindowService.FocusInDirection.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.FocusInDirection.method = "FocusInDirection"
+pinnacle.window.v1.WindowService.FocusInDirection.request = ".pinnacle.window.v1.FocusInDirectionRequest"
+pinnacle.window.v1.WindowService.FocusInDirection.response = ".pinnacle.window.v1.FocusInDirectionResponse"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.output.v1.SetModeRequest
+---@param data pinnacle.window.v1.FocusInDirectionRequest
 ---
----@return google.protobuf.Empty | nil response
+---@return pinnacle.window.v1.FocusInDirectionResponse | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_output_v1_OutputService_SetMode(data)
-    return self:unary_request(pinnacle.output.v1.OutputService.SetMode, data)
+function Client:pinnacle_window_v1_WindowService_FocusInDirection(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.FocusInDirection, data)
 end
-pinnacle.output.v1.OutputService.SetModeline = {}
-pinnacle.output.v1.OutputService.SetModeline.service = "pinnacle.output.v1.OutputService"
-pinnacle.output.v1.OutputService.SetModeline.method = "SetModeline"
-pinnacle.output.v1.OutputService.SetModeline.request = ".pinnacle.output.v1.SetModelineRequest"
-pinnacle.output.v1.OutputService.SetModeline.response = ".google.protobuf.Empty"
+pinnacle.window.v1.WindowService.BeginCycle = {}
+pinnacle.window.v1.WindowService.BeginCycle.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.BeginCycle.method = "BeginCycle"
+pinnacle.window.v1.WindowService.BeginCycle.request = ".pinnacle.window.v1.BeginCycleRequest"
+pinnacle.window.v1.WindowService.BeginCycle.response = ".pinnacle.window.v1.BeginCycleResponse"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.output.v1.SetModelineRequest
+---@param data pinnacle.window.v1.BeginCycleRequest
 ---
----@return google.protobuf.Empty | nil response
+---@return pinnacle.window.v1.BeginCycleResponse | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_output_v1_OutputService_SetModeline(data)
-    return self:unary_request(pinnacle.output.v1.OutputService.SetModeline, data)
+function Client:pinnacle_window_v1_WindowService_BeginCycle(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.BeginCycle, data)
 end
-pinnacle.output.v1.OutputService.SetScale = {}
-pinnacle.output.v1.OutputService.SetScale.service = "pinnacle.output.v1.OutputService"
-pinnacle.output.v1.OutputService.SetScale.method = "SetScale"
-pinnacle.output.v1.OutputService.SetScale.request = ".pinnacle.output.v1.SetScaleRequest"
-pinnacle.output.v1.OutputService.SetScale.response = ".google.protobuf.Empty"
+pinnacle.window.v1.WindowService.Cycle = {}
+pinnacle.window.v1.WindowService.Cycle.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.Cycle.method = "Cycle"
+pinnacle.window.v1.WindowService.Cycle.request = ".pinnacle.window.v1.CycleRequest"
+pinnacle.window.v1.WindowService.Cycle.response = ".pinnacle.window.v1.CycleResponse"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.output.v1.SetScaleRequest
+---@param data pinnacle.window.v1.CycleRequest
 ---
----@return google.protobuf.Empty | nil response
+---@return pinnacle.window.v1.CycleResponse | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_output_v1_OutputService_SetScale(data)
-    return self:unary_request(pinnacle.output.v1.OutputService.SetScale, data)
+function Client:pinnacle_window_v1_WindowService_Cycle(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.Cycle, data)
 end
-pinnacle.output.v1.OutputService.SetTransform = {}
-pinnacle.output.v1.OutputService.SetTransform.service = "pinnacle.output.v1.OutputService"
-pinnacle.output.v1.OutputService.SetTransform.method = "SetTransform"
-pinnacle.output.v1.OutputService.SetTransform.request = ".pinnacle.output.v1.SetTransformRequest"
-pinnacle.output.v1.OutputService.SetTransform.response = ".google.protobuf.Empty"
+pinnacle.window.v1.WindowService.EndCycle = {}
+pinnacle.window.v1.WindowService.EndCycle.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.EndCycle.method = "EndCycle"
+pinnacle.window.v1.WindowService.EndCycle.request = ".pinnacle.window.v1.EndCycleRequest"
+pinnacle.window.v1.WindowService.EndCycle.response = ".pinnacle.window.v1.EndCycleResponse"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.output.v1.SetTransformRequest
+---@param data pinnacle.window.v1.EndCycleRequest
 ---
----@return google.protobuf.Empty | nil response
+---@return pinnacle.window.v1.EndCycleResponse | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_output_v1_OutputService_SetTransform(data)
-    return self:unary_request(pinnacle.output.v1.OutputService.SetTransform, data)
+function Client:pinnacle_window_v1_WindowService_EndCycle(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.EndCycle, data)
 end
-pinnacle.output.v1.OutputService.SetPowered = {}
-pinnacle.output.v1.OutputService.SetPowered.service = "pinnacle.output.v1.OutputService"
-pinnacle.output.v1.OutputService.SetPowered.method = "SetPowered"
-pinnacle.output.v1.OutputService.SetPowered.request = ".pinnacle.output.v1.SetPoweredRequest"
-pinnacle.output.v1.OutputService.SetPowered.response = ".google.protobuf.Empty"
+pinnacle.window.v1.WindowService.SetDecorationMode = {}
+pinnacle.window.v1.WindowService.SetDecorationMode.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.SetDecorationMode.method = "SetDecorationMode"
+pinnacle.window.v1.WindowService.SetDecorationMode.request = ".pinnacle.window.v1.SetDecorationModeRequest"
+pinnacle.window.v1.WindowService.SetDecorationMode.response = ".google.protobuf.Empty"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.output.v1.SetPoweredRequest
+---@param data pinnacle.window.v1.SetDecorationModeRequest
 ---
 ---@return google.protobuf.Empty | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_output_v1_OutputService_SetPowered(data)
-    return self:unary_request(pinnacle.output.v1.OutputService.SetPowered, data)
+function Client:pinnacle_window_v1_WindowService_SetDecorationMode(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.SetDecorationMode, data)
 end
-pinnacle.output.v1.OutputService.SetVrr = {}
-pinnacle.output.v1.OutputService.SetVrr.service = "pinnacle.output.v1.OutputService"
-pinnacle.output.v1.OutputService.SetVrr.method = "SetVrr"
-pinnacle.output.v1.OutputService.SetVrr.request = ".pinnacle.output.v1.SetVrrRequest"
-pinnacle.output.v1.OutputService.SetVrr.response = ".pinnacle.output.v1.SetVrrResponse"
+pinnacle.window.v1.WindowService.MoveToTag = {}
+pinnacle.window.v1.WindowService.MoveToTag.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.MoveToTag.method = "MoveToTag"
+pinnacle.window.v1.WindowService.MoveToTag.request = ".pinnacle.window.v1.MoveToTagRequest"
+pinnacle.window.v1.WindowService.MoveToTag.response = ".google.protobuf.Empty"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.output.v1.SetVrrRequest
+---@param data pinnacle.window.v1.MoveToTagRequest
 ---
----@return pinnacle.output.v1.SetVrrResponse | nil response
+---@return google.protobuf.Empty | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_output_v1_OutputService_SetVrr(data)
-    return self:unary_request(pinnacle.output.v1.OutputService.SetVrr, data)
+function Client:pinnacle_window_v1_WindowService_MoveToTag(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.MoveToTag, data)
 end
-pinnacle.output.v1.OutputService.Focus = {}
-pinnacle.output.v1.OutputService.Focus.service = "pinnacle.output.v1.OutputService"
-pinnacle.output.v1.OutputService.Focus.method = "Focus"
-pinnacle.output.v1.OutputService.Focus.request = ".pinnacle.output.v1.FocusRequest"
-pinnacle.output.v1.OutputService.Focus.response = ".pinnacle.output.v1.FocusResponse"
+pinnacle.window.v1.WindowService.SetTag = {}
+pinnacle.window.v1.WindowService.SetTag.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.SetTag.method = "SetTag"
+pinnacle.window.v1.WindowService.SetTag.request = ".pinnacle.window.v1.SetTagRequest"
+pinnacle.window.v1.WindowService.SetTag.response = ".google.protobuf.Empty"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.output.v1.FocusRequest
+---@param data pinnacle.window.v1.SetTagRequest
 ---
----@return pinnacle.output.v1.FocusResponse | nil response
+---@return google.protobuf.Empty | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_output_v1_OutputService_Focus(data)
-    return self:unary_request(pinnacle.output.v1.OutputService.Focus, data)
+function Client:pinnacle_window_v1_WindowService_SetTag(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.SetTag, data)
 end
-pinnacle.output.v1.OutputService.GetInfo = {}
-pinnacle.output.v1.OutputService.GetInfo.service = "pinnacle.output.v1.OutputService"
-pinnacle.output.v1.OutputService.GetInfo.method = "GetInfo"
-pinnacle.output.v1.OutputService.GetInfo.request = ".pinnacle.output.v1.GetInfoRequest"
-pinnacle.output.v1.OutputService.GetInfo.response = ".pinnacle.output.v1.GetInfoResponse"
+pinnacle.window.v1.WindowService.SetTags = {}
+pinnacle.window.v1.WindowService.SetTags.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.SetTags.method = "SetTags"
+pinnacle.window.v1.WindowService.SetTags.request = ".pinnacle.window.v1.SetTagsRequest"
+pinnacle.window.v1.WindowService.SetTags.response = ".pinnacle.window.v1.SetTagsResponse"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.output.v1.GetInfoRequest
+---@param data pinnacle.window.v1.SetTagsRequest
 ---
----@return pinnacle.output.v1.GetInfoResponse | nil response
+---@return pinnacle.window.v1.SetTagsResponse | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_output_v1_OutputService_GetInfo(data)
-    return self:unary_request(pinnacle.output.v1.OutputService.GetInfo, data)
+function Client:pinnacle_window_v1_WindowService_SetTags(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.SetTags, data)
 end
-pinnacle.output.v1.OutputService.GetLoc = {}
-pinnacle.output.v1.OutputService.GetLoc.service = "pinnacle.output.v1.OutputService"
-pinnacle.output.v1.OutputService.GetLoc.method = "GetLoc"
-pinnacle.output.v1.OutputService.GetLoc.request = ".pinnacle.output.v1.GetLocRequest"
-pinnacle.output.v1.OutputService.GetLoc.response = ".pinnacle.output.v1.GetLocResponse"
+pinnacle.window.v1.WindowService.SetVrrDemand = {}
+pinnacle.window.v1.WindowService.SetVrrDemand.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.SetVrrDemand.method = "SetVrrDemand"
+pinnacle.window.v1.WindowService.SetVrrDemand.request = ".pinnacle.window.v1.SetVrrDemandRequest"
+pinnacle.window.v1.WindowService.SetVrrDemand.response = ".pinnacle.window.v1.SetVrrDemandResponse"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.output.v1.GetLocRequest
+---@param data pinnacle.window.v1.SetVrrDemandRequest
 ---
----@return pinnacle.output.v1.GetLocResponse | nil response
+---@return pinnacle.window.v1.SetVrrDemandResponse | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_output_v1_OutputService_GetLoc(data)
-    return self:unary_request(pinnacle.output.v1.OutputService.GetLoc, data)
+function Client:pinnacle_window_v1_WindowService_SetVrrDemand(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.SetVrrDemand, data)
 end
-pinnacle.output.v1.OutputService.GetLogicalSize = {}
-pinnacle.output.v1.OutputService.GetLogicalSize.service = "pinnacle.output.v1.OutputService"
-pinnacle.output.v1.OutputService.GetLogicalSize.method = "GetLogicalSize"
-pinnacle.output.v1.OutputService.GetLogicalSize.request = ".pinnacle.output.v1.GetLogicalSizeRequest"
-pinnacle.output.v1.OutputService.GetLogicalSize.response = ".pinnacle.output.v1.GetLogicalSizeResponse"
+pinnacle.window.v1.WindowService.MoveToOutput = {}
+pinnacle.window.v1.WindowService.MoveToOutput.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.MoveToOutput.method = "MoveToOutput"
+pinnacle.window.v1.WindowService.MoveToOutput.request = ".pinnacle.window.v1.MoveToOutputRequest"
+pinnacle.window.v1.WindowService.MoveToOutput.response = ".pinnacle.window.v1.MoveToOutputResponse"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.output.v1.GetLogicalSizeRequest
+---@param data pinnacle.window.v1.MoveToOutputRequest
 ---
----@return pinnacle.output.v1.GetLogicalSizeResponse | nil response
+---@return pinnacle.window.v1.MoveToOutputResponse | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_output_v1_OutputService_GetLogicalSize(data)
-    return self:unary_request(pinnacle.output.v1.OutputService.GetLogicalSize, data)
+function Client:pinnacle_window_v1_WindowService_MoveToOutput(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.MoveToOutput, data)
 end
-pinnacle.output.v1.OutputService.GetPhysicalSize = {}
-pinnacle.output.v1.OutputService.GetPhysicalSize.service = "pinnacle.output.v1.OutputService"
-pinnacle.output.v1.OutputService.GetPhysicalSize.method = "GetPhysicalSize"
-pinnacle.output.v1.OutputService.GetPhysicalSize.request = ".pinnacle.output.v1.GetPhysicalSizeRequest"
-pinnacle.output.v1.OutputService.GetPhysicalSize.response = ".pinnacle.output.v1.GetPhysicalSizeResponse"
+pinnacle.window.v1.WindowService.Raise = {}
+pinnacle.window.v1.WindowService.Raise.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.Raise.method = "Raise"
+pinnacle.window.v1.WindowService.Raise.request = ".pinnacle.window.v1.RaiseRequest"
+pinnacle.window.v1.WindowService.Raise.response = ".google.protobuf.Empty"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.output.v1.GetPhysicalSizeRequest
+---@param data pinnacle.window.v1.RaiseRequest
 ---
----@return pinnacle.output.v1.GetPhysicalSizeResponse | nil response
+---@return google.protobuf.Empty | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_output_v1_OutputService_GetPhysicalSize(data)
-    return self:unary_request(pinnacle.output.v1.OutputService.GetPhysicalSize, data)
+function Client:pinnacle_window_v1_WindowService_Raise(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.Raise, data)
 end
-pinnacle.output.v1.OutputService.GetModes = {}
-pinnacle.output.v1.OutputService.GetModes.service = "pinnacle.output.v1.OutputService"
-pinnacle.output.v1.OutputService.GetModes.method = "GetModes"
-pinnacle.output.v1.OutputService.GetModes.request = ".pinnacle.output.v1.GetModesRequest"
-pinnacle.output.v1.OutputService.GetModes.response = ".pinnacle.output.v1.GetModesResponse"
+pinnacle.window.v1.WindowService.Lower = {}
+pinnacle.window.v1.WindowService.Lower.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.Lower.method = "Lower"
+pinnacle.window.v1.WindowService.Lower.request = ".pinnacle.window.v1.LowerRequest"
+pinnacle.window.v1.WindowService.Lower.response = ".pinnacle.window.v1.LowerResponse"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.output.v1.GetModesRequest
+---@param data pinnacle.window.v1.LowerRequest
 ---
----@return pinnacle.output.v1.GetModesResponse | nil response
+---@return pinnacle.window.v1.LowerResponse | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_output_v1_OutputService_GetModes(data)
-    return self:unary_request(pinnacle.output.v1.OutputService.GetModes, data)
+function Client:pinnacle_window_v1_WindowService_Lower(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.Lower, data)
 end
-pinnacle.output.v1.OutputService.GetFocused = {}
-pinnacle.output.v1.OutputService.GetFocused.service = "pinnacle.output.v1.OutputService"
-pinnacle.output.v1.OutputService.GetFocused.method = "GetFocused"
-pinnacle.output.v1.OutputService.GetFocused.request = ".pinnacle.output.v1.GetFocusedRequest"
-pinnacle.output.v1.OutputService.GetFocused.response = ".pinnacle.output.v1.GetFocusedResponse"
+pinnacle.window.v1.WindowService.SetLayer = {}
+pinnacle.window.v1.WindowService.SetLayer.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.SetLayer.method = "SetLayer"
+pinnacle.window.v1.WindowService.SetLayer.request = ".pinnacle.window.v1.SetLayerRequest"
+pinnacle.window.v1.WindowService.SetLayer.response = ".pinnacle.window.v1.SetLayerResponse"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.output.v1.GetFocusedRequest
+---@param data pinnacle.window.v1.SetLayerRequest
 ---
----@return pinnacle.output.v1.GetFocusedResponse | nil response
+---@return pinnacle.window.v1.SetLayerResponse | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_output_v1_OutputService_GetFocused(data)
-    return self:unary_request(pinnacle.output.v1.OutputService.GetFocused, data)
+function Client:pinnacle_window_v1_WindowService_SetLayer(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.SetLayer, data)
 end
-pinnacle.output.v1.OutputService.GetTagIds = {}
-pinnacle.output.v1.OutputService.GetTagIds.service = "pinnacle.output.v1.OutputService"
-pinnacle.output.v1.OutputService.GetTagIds.method = "GetTagIds"
-pinnacle.output.v1.OutputService.GetTagIds.request = ".pinnacle.output.v1.GetTagIdsRequest"
-pinnacle.output.v1.OutputService.GetTagIds.response = ".pinnacle.output.v1.GetTagIdsResponse"
+pinnacle.window.v1.WindowService.MoveGrab = {}
+pinnacle.window.v1.WindowService.MoveGrab.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.MoveGrab.method = "MoveGrab"
+pinnacle.window.v1.WindowService.MoveGrab.request = ".pinnacle.window.v1.MoveGrabRequest"
+pinnacle.window.v1.WindowService.MoveGrab.response = ".google.protobuf.Empty"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.output.v1.GetTagIdsRequest
+---@param data pinnacle.window.v1.MoveGrabRequest
 ---
----@return pinnacle.output.v1.GetTagIdsResponse | nil response
+---@return google.protobuf.Empty | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_output_v1_OutputService_GetTagIds(data)
-    return self:unary_request(pinnacle.output.v1.OutputService.GetTagIds, data)
+function Client:pinnacle_window_v1_WindowService_MoveGrab(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.MoveGrab, data)
 end
-pinnacle.output.v1.OutputService.GetScale = {}
-pinnacle.output.v1.OutputService.GetScale.service = "pinnacle.output.v1.OutputService"
-pinnacle.output.v1.OutputService.GetScale.method = "GetScale"
-pinnacle.output.v1.OutputService.GetScale.request = ".pinnacle.output.v1.GetScaleRequest"
-pinnacle.output.v1.OutputService.GetScale.response = ".pinnacle.output.v1.GetScaleResponse"
+pinnacle.window.v1.WindowService.ResizeGrab = {}
+pinnacle.window.v1.WindowService.ResizeGrab.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.ResizeGrab.method = "ResizeGrab"
+pinnacle.window.v1.WindowService.ResizeGrab.request = ".pinnacle.window.v1.ResizeGrabRequest"
+pinnacle.window.v1.WindowService.ResizeGrab.response = ".google.protobuf.Empty"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.output.v1.GetScaleRequest
+---@param data pinnacle.window.v1.ResizeGrabRequest
 ---
----@return pinnacle.output.v1.GetScaleResponse | nil response
+---@return google.protobuf.Empty | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_output_v1_OutputService_GetScale(data)
-    return self:unary_request(pinnacle.output.v1.OutputService.GetScale, data)
+function Client:pinnacle_window_v1_WindowService_ResizeGrab(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.ResizeGrab, data)
 end
-pinnacle.output.v1.OutputService.GetTransform = {}
-pinnacle.output.v1.OutputService.GetTransform.service = "pinnacle.output.v1.OutputService"
-pinnacle.output.v1.OutputService.GetTransform.method = "GetTransform"
-pinnacle.output.v1.OutputService.GetTransform.request = ".pinnacle.output.v1.GetTransformRequest"
-pinnacle.output.v1.OutputService.GetTransform.response = ".pinnacle.output.v1.GetTransformResponse"
+pinnacle.window.v1.WindowService.Swap = {}
+pinnacle.window.v1.WindowService.Swap.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.Swap.method = "Swap"
+pinnacle.window.v1.WindowService.Swap.request = ".pinnacle.window.v1.SwapRequest"
+pinnacle.window.v1.WindowService.Swap.response = ".pinnacle.window.v1.SwapResponse"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.output.v1.GetTransformRequest
+---@param data pinnacle.window.v1.SwapRequest
 ---
----@return pinnacle.output.v1.GetTransformResponse | nil response
+---@return pinnacle.window.v1.SwapResponse | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_output_v1_OutputService_GetTransform(data)
-    return self:unary_request(pinnacle.output.v1.OutputService.GetTransform, data)
+function Client:pinnacle_window_v1_WindowService_Swap(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.Swap, data)
 end
-pinnacle.output.v1.OutputService.GetEnabled = {}
-pinnacle.output.v1.OutputService.GetEnabled.service = "pinnacle.output.v1.OutputService"
-pinnacle.output.v1.OutputService.GetEnabled.method = "GetEnabled"
-pinnacle.output.v1.OutputService.GetEnabled.request = ".pinnacle.output.v1.GetEnabledRequest"
-pinnacle.output.v1.OutputService.GetEnabled.response = ".pinnacle.output.v1.GetEnabledResponse"
+pinnacle.window.v1.WindowService.SwapInDirection = {}
+pinnacle.window.v1.WindowService.SwapInDirection.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.SwapInDirection.method = "SwapInDirection"
+pinnacle.window.v1.WindowService.SwapInDirection.request = ".pinnacle.window.v1.SwapInDirectionRequest"
+pinnacle.window.v1.WindowService.SwapInDirection.response = ".pinnacle.window.v1.SwapInDirectionResponse"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.output.v1.GetEnabledRequest
+---@param data pinnacle.window.v1.SwapInDirectionRequest
 ---
----@return pinnacle.output.v1.GetEnabledResponse | nil response
+---@return pinnacle.window.v1.SwapInDirectionResponse | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_output_v1_OutputService_GetEnabled(data)
-    return self:unary_request(pinnacle.output.v1.OutputService.GetEnabled, data)
+function Client:pinnacle_window_v1_WindowService_SwapInDirection(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.SwapInDirection, data)
 end
-pinnacle.output.v1.OutputService.GetPowered = {}
-pinnacle.output.v1.OutputService.GetPowered.service = "pinnacle.output.v1.OutputService"
-pinnacle.output.v1.OutputService.GetPowered.method = "GetPowered"
-pinnacle.output.v1.OutputService.GetPowered.request = ".pinnacle.output.v1.GetPoweredRequest"
-pinnacle.output.v1.OutputService.GetPowered.response = ".pinnacle.output.v1.GetPoweredResponse"
+pinnacle.window.v1.WindowService.SetFloatingVisibility = {}
+pinnacle.window.v1.WindowService.SetFloatingVisibility.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.SetFloatingVisibility.method = "SetFloatingVisibility"
+pinnacle.window.v1.WindowService.SetFloatingVisibility.request = ".pinnacle.window.v1.SetFloatingVisibilityRequest"
+pinnacle.window.v1.WindowService.SetFloatingVisibility.response = ".pinnacle.window.v1.SetFloatingVisibilityResponse"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.output.v1.GetPoweredRequest
+---@param data pinnacle.window.v1.SetFloatingVisibilityRequest
 ---
----@return pinnacle.output.v1.GetPoweredResponse | nil response
+---@return pinnacle.window.v1.SetFloatingVisibilityResponse | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_output_v1_OutputService_GetPowered(data)
-    return self:unary_request(pinnacle.output.v1.OutputService.GetPowered, data)
+function Client:pinnacle_window_v1_WindowService_SetFloatingVisibility(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.SetFloatingVisibility, data)
 end
-pinnacle.output.v1.OutputService.GetFocusStackWindowIds = {}
-pinnacle.output.v1.OutputService.GetFocusStackWindowIds.service = "pinnacle.output.v1.OutputService"
-pinnacle.output.v1.OutputService.GetFocusStackWindowIds.method = "GetFocusStackWindowIds"
-pinnacle.output.v1.OutputService.GetFocusStackWindowIds.request = ".pinnacle.output.v1.GetFocusStackWindowIdsRequest"
-pinnacle.output.v1.OutputService.GetFocusStackWindowIds.response = ".pinnacle.output.v1.GetFocusStackWindowIdsResponse"
+pinnacle.window.v1.WindowService.SetAllowOffscreen = {}
+pinnacle.window.v1.WindowService.SetAllowOffscreen.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.SetAllowOffscreen.method = "SetAllowOffscreen"
+pinnacle.window.v1.WindowService.SetAllowOffscreen.request = ".pinnacle.window.v1.SetAllowOffscreenRequest"
+pinnacle.window.v1.WindowService.SetAllowOffscreen.response = ".pinnacle.window.v1.SetAllowOffscreenResponse"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.output.v1.GetFocusStackWindowIdsRequest
+---@param data pinnacle.window.v1.SetAllowOffscreenRequest
 ---
----@return pinnacle.output.v1.GetFocusStackWindowIdsResponse | nil response
+---@return pinnacle.window.v1.SetAllowOffscreenResponse | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_output_v1_OutputService_GetFocusStackWindowIds(data)
-    return self:unary_request(pinnacle.output.v1.OutputService.GetFocusStackWindowIds, data)
+function Client:pinnacle_window_v1_WindowService_SetAllowOffscreen(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.SetAllowOffscreen, data)
 end
-pinnacle.output.v1.OutputService.GetOutputsInDir = {}
-pinnacle.output.v1.OutputService.GetOutputsInDir.service = "pinnacle.output.v1.OutputService"
-pinnacle.output.v1.OutputService.GetOutputsInDir.method = "GetOutputsInDir"
-pinnacle.output.v1.OutputService.GetOutputsInDir.request = ".pinnacle.output.v1.GetOutputsInDirRequest"
-pinnacle.output.v1.OutputService.GetOutputsInDir.response = ".pinnacle.output.v1.GetOutputsInDirResponse"
+pinnacle.window.v1.WindowService.SetInputEnabled = {}
+pinnacle.window.v1.WindowService.SetInputEnabled.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.SetInputEnabled.method = "SetInputEnabled"
+pinnacle.window.v1.WindowService.SetInputEnabled.request = ".pinnacle.window.v1.SetInputEnabledRequest"
+pinnacle.window.v1.WindowService.SetInputEnabled.response = ".pinnacle.window.v1.SetInputEnabledResponse"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.output.v1.GetOutputsInDirRequest
+---@param data pinnacle.window.v1.SetInputEnabledRequest
 ---
----@return pinnacle.output.v1.GetOutputsInDirResponse | nil response
+---@return pinnacle.window.v1.SetInputEnabledResponse | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_output_v1_OutputService_GetOutputsInDir(data)
-    return self:unary_request(pinnacle.output.v1.OutputService.GetOutputsInDir, data)
+function Client:pinnacle_window_v1_WindowService_SetInputEnabled(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.SetInputEnabled, data)
 end
-pinnacle.process.v1.ProcessService = {}
-pinnacle.process.v1.ProcessService.Spawn = {}
-pinnacle.process.v1.ProcessService.Spawn.service = "pinnacle.process.v1.ProcessService"
-pinnacle.process.v1.ProcessService.Spawn.method = "Spawn"
-pinnacle.process.v1.ProcessService.Spawn.request = ".pinnacle.process.v1.SpawnRequest"
-pinnacle.process.v1.ProcessService.Spawn.response = ".pinnacle.process.v1.SpawnResponse"
+pinnacle.window.v1.WindowService.SetHoldToMoveEnabled = {}
+pinnacle.window.v1.WindowService.SetHoldToMoveEnabled.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.SetHoldToMoveEnabled.method = "SetHoldToMoveEnabled"
+pinnacle.window.v1.WindowService.SetHoldToMoveEnabled.request = ".pinnacle.window.v1.SetHoldToMoveEnabledRequest"
+pinnacle.window.v1.WindowService.SetHoldToMoveEnabled.response = ".pinnacle.window.v1.SetHoldToMoveEnabledResponse"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.process.v1.SpawnRequest
+---@param data pinnacle.window.v1.SetHoldToMoveEnabledRequest
 ---
----@return pinnacle.process.v1.SpawnResponse | nil response
+---@return pinnacle.window.v1.SetHoldToMoveEnabledResponse | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_process_v1_ProcessService_Spawn(data)
-    return self:unary_request(pinnacle.process.v1.ProcessService.Spawn, data)
+function Client:pinnacle_window_v1_WindowService_SetHoldToMoveEnabled(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.SetHoldToMoveEnabled, data)
 end
-pinnacle.process.v1.ProcessService.WaitOnSpawn = {}
-pinnacle.process.v1.ProcessService.WaitOnSpawn.service = "pinnacle.process.v1.ProcessService"
-pinnacle.process.v1.ProcessService.WaitOnSpawn.method = "WaitOnSpawn"
-pinnacle.process.v1.ProcessService.WaitOnSpawn.request = ".pinnacle.process.v1.WaitOnSpawnRequest"
-pinnacle.process.v1.ProcessService.WaitOnSpawn.response = ".pinnacle.process.v1.WaitOnSpawnResponse"
+pinnacle.window.v1.WindowService.SetScaleOverride = {}
+pinnacle.window.v1.WindowService.SetScaleOverride.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.SetScaleOverride.method = "SetScaleOverride"
+pinnacle.window.v1.WindowService.SetScaleOverride.request = ".pinnacle.window.v1.SetScaleOverrideRequest"
+pinnacle.window.v1.WindowService.SetScaleOverride.response = ".pinnacle.window.v1.SetScaleOverrideResponse"
 
----Performs a server-streaming request.
----
----`callback` will be called with every streamed response.
+---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.process.v1.WaitOnSpawnRequest
----@param callback fun(response: pinnacle.process.v1.WaitOnSpawnResponse)
----@param done? fun()
+---@param data pinnacle.window.v1.SetScaleOverrideRequest
 ---
----@return string | nil An error string, if any
-function Client:pinnacle_process_v1_ProcessService_WaitOnSpawn(data, callback, done)
-    return self:server_streaming_request(pinnacle.process.v1.ProcessService.WaitOnSpawn, data, callback, done)
+---@return pinnacle.window.v1.SetScaleOverrideResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_window_v1_WindowService_SetScaleOverride(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.SetScaleOverride, data)
 end
-pinnacle.process.v1.ProcessService.SetEnv = {}
-pinnacle.process.v1.ProcessService.SetEnv.service = "pinnacle.process.v1.ProcessService"
-pinnacle.process.v1.ProcessService.SetEnv.method = "SetEnv"
-pinnacle.process.v1.ProcessService.SetEnv.request = ".pinnacle.process.v1.SetEnvRequest"
-pinnacle.process.v1.ProcessService.SetEnv.response = ".google.protobuf.Empty"
+pinnacle.window.v1.WindowService.SnapToRegion = {}
+pinnacle.window.v1.WindowService.SnapToRegion.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.SnapToRegion.method = "SnapToRegion"
+pinnacle.window.v1.WindowService.SnapToRegion.request = ".pinnacle.window.v1.SnapToRegionRequest"
+pinnacle.window.v1.WindowService.SnapToRegion.response = ".pinnacle.window.v1.SnapToRegionResponse"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.process.v1.SetEnvRequest
+---@param data pinnacle.window.v1.SnapToRegionRequest
 ---
----@return google.protobuf.Empty | nil response
+---@return pinnacle.window.v1.SnapToRegionResponse | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_process_v1_ProcessService_SetEnv(data)
-    return self:unary_request(pinnacle.process.v1.ProcessService.SetEnv, data)
+function Client:pinnacle_window_v1_WindowService_SnapToRegion(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.SnapToRegion, data)
 end
-pinnacle.render.v1.RenderService = {}
-pinnacle.render.v1.RenderService.SetUpscaleFilter = {}
-pinnacle.render.v1.RenderService.SetUpscaleFilter.service = "pinnacle.render.v1.RenderService"
-pinnacle.render.v1.RenderService.SetUpscaleFilter.method = "SetUpscaleFilter"
-pinnacle.render.v1.RenderService.SetUpscaleFilter.request = ".pinnacle.render.v1.SetUpscaleFilterRequest"
-pinnacle.render.v1.RenderService.SetUpscaleFilter.response = ".google.protobuf.Empty"
+pinnacle.window.v1.WindowService.SetSnapGrid = {}
+pinnacle.window.v1.WindowService.SetSnapGrid.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.SetSnapGrid.method = "SetSnapGrid"
+pinnacle.window.v1.WindowService.SetSnapGrid.request = ".pinnacle.window.v1.SetSnapGridRequest"
+pinnacle.window.v1.WindowService.SetSnapGrid.response = ".pinnacle.window.v1.SetSnapGridResponse"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.render.v1.SetUpscaleFilterRequest
+---@param data pinnacle.window.v1.SetSnapGridRequest
 ---
----@return google.protobuf.Empty | nil response
+---@return pinnacle.window.v1.SetSnapGridResponse | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_render_v1_RenderService_SetUpscaleFilter(data)
-    return self:unary_request(pinnacle.render.v1.RenderService.SetUpscaleFilter, data)
+function Client:pinnacle_window_v1_WindowService_SetSnapGrid(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.SetSnapGrid, data)
 end
-pinnacle.render.v1.RenderService.SetDownscaleFilter = {}
-pinnacle.render.v1.RenderService.SetDownscaleFilter.service = "pinnacle.render.v1.RenderService"
-pinnacle.render.v1.RenderService.SetDownscaleFilter.method = "SetDownscaleFilter"
-pinnacle.render.v1.RenderService.SetDownscaleFilter.request = ".pinnacle.render.v1.SetDownscaleFilterRequest"
-pinnacle.render.v1.RenderService.SetDownscaleFilter.response = ".google.protobuf.Empty"
+pinnacle.window.v1.WindowService.SetPopupPolicy = {}
+pinnacle.window.v1.WindowService.SetPopupPolicy.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.SetPopupPolicy.method = "SetPopupPolicy"
+pinnacle.window.v1.WindowService.SetPopupPolicy.request = ".pinnacle.window.v1.SetPopupPolicyRequest"
+pinnacle.window.v1.WindowService.SetPopupPolicy.response = ".pinnacle.window.v1.SetPopupPolicyResponse"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.render.v1.SetDownscaleFilterRequest
+---@param data pinnacle.window.v1.SetPopupPolicyRequest
 ---
----@return google.protobuf.Empty | nil response
+---@return pinnacle.window.v1.SetPopupPolicyResponse | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_render_v1_RenderService_SetDownscaleFilter(data)
-    return self:unary_request(pinnacle.render.v1.RenderService.SetDownscaleFilter, data)
+function Client:pinnacle_window_v1_WindowService_SetPopupPolicy(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.SetPopupPolicy, data)
 end
-pinnacle.window.v1.WindowService = {}
-pinnacle.window.v1.WindowService.Get = {}
-pinnacle.window.v1.WindowService.Get.service = "pinnacle.window.v1.WindowService"
-pinnacle.window.v1.WindowService.Get.method = "Get"
-pinnacle.window.v1.WindowService.Get.request = ".pinnacle.window.v1.GetRequest"
-pinnacle.window.v1.WindowService.Get.response = ".pinnacle.window.v1.GetResponse"
+pinnacle.window.v1.WindowService.SetGreyOutUnresponsive = {}
+pinnacle.window.v1.WindowService.SetGreyOutUnresponsive.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.SetGreyOutUnresponsive.method = "SetGreyOutUnresponsive"
+pinnacle.window.v1.WindowService.SetGreyOutUnresponsive.request = ".pinnacle.window.v1.SetGreyOutUnresponsiveRequest"
+pinnacle.window.v1.WindowService.SetGreyOutUnresponsive.response = ".pinnacle.window.v1.SetGreyOutUnresponsiveResponse"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.window.v1.GetRequest
+---@param data pinnacle.window.v1.SetGreyOutUnresponsiveRequest
 ---
----@return pinnacle.window.v1.GetResponse | nil response
+---@return pinnacle.window.v1.SetGreyOutUnresponsiveResponse | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_window_v1_WindowService_Get(data)
-    return self:unary_request(pinnacle.window.v1.WindowService.Get, data)
+function Client:pinnacle_window_v1_WindowService_SetGreyOutUnresponsive(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.SetGreyOutUnresponsive, data)
 end
-pinnacle.window.v1.WindowService.GetAppId = {}
-pinnacle.window.v1.WindowService.GetAppId.service = "pinnacle.window.v1.WindowService"
-pinnacle.window.v1.WindowService.GetAppId.method = "GetAppId"
-pinnacle.window.v1.WindowService.GetAppId.request = ".pinnacle.window.v1.GetAppIdRequest"
-pinnacle.window.v1.WindowService.GetAppId.response = ".pinnacle.window.v1.GetAppIdResponse"
+pinnacle.window.v1.WindowService.SetMaximizeRequestPolicy = {}
+pinnacle.window.v1.WindowService.SetMaximizeRequestPolicy.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.SetMaximizeRequestPolicy.method = "SetMaximizeRequestPolicy"
+pinnacle.window.v1.WindowService.SetMaximizeRequestPolicy.request = ".pinnacle.window.v1.SetMaximizeRequestPolicyRequest"
+pinnacle.window.v1.WindowService.SetMaximizeRequestPolicy.response = ".pinnacle.window.v1.SetMaximizeRequestPolicyResponse"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.window.v1.GetAppIdRequest
+---@param data pinnacle.window.v1.SetMaximizeRequestPolicyRequest
 ---
----@return pinnacle.window.v1.GetAppIdResponse | nil response
+---@return pinnacle.window.v1.SetMaximizeRequestPolicyResponse | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_window_v1_WindowService_GetAppId(data)
-    return self:unary_request(pinnacle.window.v1.WindowService.GetAppId, data)
+function Client:pinnacle_window_v1_WindowService_SetMaximizeRequestPolicy(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.SetMaximizeRequestPolicy, data)
 end
-pinnacle.window.v1.WindowService.GetTitle = {}
-pinnacle.window.v1.WindowService.GetTitle.service = "pinnacle.window.v1.WindowService"
-pinnacle.window.v1.WindowService.GetTitle.method = "GetTitle"
-pinnacle.window.v1.WindowService.GetTitle.request = ".pinnacle.window.v1.GetTitleRequest"
-pinnacle.window.v1.WindowService.GetTitle.response = ".pinnacle.window.v1.GetTitleResponse"
+pinnacle.window.v1.WindowService.SetTagInheritance = {}
+pinnacle.window.v1.WindowService.SetTagInheritance.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.SetTagInheritance.method = "SetTagInheritance"
+pinnacle.window.v1.WindowService.SetTagInheritance.request = ".pinnacle.window.v1.SetTagInheritanceRequest"
+pinnacle.window.v1.WindowService.SetTagInheritance.response = ".pinnacle.window.v1.SetTagInheritanceResponse"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.window.v1.GetTitleRequest
+---@param data pinnacle.window.v1.SetTagInheritanceRequest
 ---
----@return pinnacle.window.v1.GetTitleResponse | nil response
+---@return pinnacle.window.v1.SetTagInheritanceResponse | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_window_v1_WindowService_GetTitle(data)
-    return self:unary_request(pinnacle.window.v1.WindowService.GetTitle, data)
+function Client:pinnacle_window_v1_WindowService_SetTagInheritance(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.SetTagInheritance, data)
 end
-pinnacle.window.v1.WindowService.GetLoc = {}
-pinnacle.window.v1.WindowService.GetLoc.service = "pinnacle.window.v1.WindowService"
-pinnacle.window.v1.WindowService.GetLoc.method = "GetLoc"
-pinnacle.window.v1.WindowService.GetLoc.request = ".pinnacle.window.v1.GetLocRequest"
-pinnacle.window.v1.WindowService.GetLoc.response = ".pinnacle.window.v1.GetLocResponse"
+pinnacle.window.v1.WindowService.SetDialogPolicy = {}
+pinnacle.window.v1.WindowService.SetDialogPolicy.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.SetDialogPolicy.method = "SetDialogPolicy"
+pinnacle.window.v1.WindowService.SetDialogPolicy.request = ".pinnacle.window.v1.SetDialogPolicyRequest"
+pinnacle.window.v1.WindowService.SetDialogPolicy.response = ".pinnacle.window.v1.SetDialogPolicyResponse"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.window.v1.GetLocRequest
+---@param data pinnacle.window.v1.SetDialogPolicyRequest
 ---
----@return pinnacle.window.v1.GetLocResponse | nil response
+---@return pinnacle.window.v1.SetDialogPolicyResponse | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_window_v1_WindowService_GetLoc(data)
-    return self:unary_request(pinnacle.window.v1.WindowService.GetLoc, data)
+function Client:pinnacle_window_v1_WindowService_SetDialogPolicy(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.SetDialogPolicy, data)
 end
-pinnacle.window.v1.WindowService.GetSize = {}
-pinnacle.window.v1.WindowService.GetSize.service = "pinnacle.window.v1.WindowService"
-pinnacle.window.v1.WindowService.GetSize.method = "GetSize"
-pinnacle.window.v1.WindowService.GetSize.request = ".pinnacle.window.v1.GetSizeRequest"
-pinnacle.window.v1.WindowService.GetSize.response = ".pinnacle.window.v1.GetSizeResponse"
+pinnacle.window.v1.WindowService.SetSizeConstraints = {}
+pinnacle.window.v1.WindowService.SetSizeConstraints.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.SetSizeConstraints.method = "SetSizeConstraints"
+pinnacle.window.v1.WindowService.SetSizeConstraints.request = ".pinnacle.window.v1.SetSizeConstraintsRequest"
+pinnacle.window.v1.WindowService.SetSizeConstraints.response = ".pinnacle.window.v1.SetSizeConstraintsResponse"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.window.v1.GetSizeRequest
+---@param data pinnacle.window.v1.SetSizeConstraintsRequest
 ---
----@return pinnacle.window.v1.GetSizeResponse | nil response
+---@return pinnacle.window.v1.SetSizeConstraintsResponse | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_window_v1_WindowService_GetSize(data)
-    return self:unary_request(pinnacle.window.v1.WindowService.GetSize, data)
+function Client:pinnacle_window_v1_WindowService_SetSizeConstraints(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.SetSizeConstraints, data)
 end
-pinnacle.window.v1.WindowService.GetFocused = {}
-pinnacle.window.v1.WindowService.GetFocused.service = "pinnacle.window.v1.WindowService"
-pinnacle.window.v1.WindowService.GetFocused.method = "GetFocused"
-pinnacle.window.v1.WindowService.GetFocused.request = ".pinnacle.window.v1.GetFocusedRequest"
-pinnacle.window.v1.WindowService.GetFocused.response = ".pinnacle.window.v1.GetFocusedResponse"
+pinnacle.window.v1.WindowService.SetBorderStyle = {}
+pinnacle.window.v1.WindowService.SetBorderStyle.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.SetBorderStyle.method = "SetBorderStyle"
+pinnacle.window.v1.WindowService.SetBorderStyle.request = ".pinnacle.window.v1.SetBorderStyleRequest"
+pinnacle.window.v1.WindowService.SetBorderStyle.response = ".pinnacle.window.v1.SetBorderStyleResponse"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.window.v1.GetFocusedRequest
+---@param data pinnacle.window.v1.SetBorderStyleRequest
 ---
----@return pinnacle.window.v1.GetFocusedResponse | nil response
+---@return pinnacle.window.v1.SetBorderStyleResponse | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_window_v1_WindowService_GetFocused(data)
-    return self:unary_request(pinnacle.window.v1.WindowService.GetFocused, data)
+function Client:pinnacle_window_v1_WindowService_SetBorderStyle(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.SetBorderStyle, data)
 end
-pinnacle.window.v1.WindowService.GetLayoutMode = {}
-pinnacle.window.v1.WindowService.GetLayoutMode.service = "pinnacle.window.v1.WindowService"
-pinnacle.window.v1.WindowService.GetLayoutMode.method = "GetLayoutMode"
-pinnacle.window.v1.WindowService.GetLayoutMode.request = ".pinnacle.window.v1.GetLayoutModeRequest"
-pinnacle.window.v1.WindowService.GetLayoutMode.response = ".pinnacle.window.v1.GetLayoutModeResponse"
+pinnacle.window.v1.WindowService.WindowRule = {}
+pinnacle.window.v1.WindowService.WindowRule.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.WindowRule.method = "WindowRule"
+pinnacle.window.v1.WindowService.WindowRule.request = ".pinnacle.window.v1.WindowRuleRequest"
+pinnacle.window.v1.WindowService.WindowRule.response = ".pinnacle.window.v1.WindowRuleResponse"
 
----Performs a unary request.
+---Performs a bidirectional-streaming request.
+---
+---`callback` will be called with every streamed response.
+---
+---The raw client-to-server stream is returned to allow you to send encoded messages.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.window.v1.GetLayoutModeRequest
+---@param callback fun(response: pinnacle.window.v1.WindowRuleResponse, stream: grpc_client.h2.Stream)
+---@param done? fun()
 ---
----@return pinnacle.window.v1.GetLayoutModeResponse | nil response
----@return string | nil error An error string, if any
-function Client:pinnacle_window_v1_WindowService_GetLayoutMode(data)
-    return self:unary_request(pinnacle.window.v1.WindowService.GetLayoutMode, data)
+---@return grpc_client.h2.Stream | nil
+---@return string | nil An error string, if any
+function Client:pinnacle_window_v1_WindowService_WindowRule(callback, done)
+    return self:bidirectional_streaming_request(pinnacle.window.v1.WindowService.WindowRule, callback, done)
 end
-pinnacle.window.v1.WindowService.GetTagIds = {}
-pinnacle.window.v1.WindowService.GetTagIds.service = "pinnacle.window.v1.WindowService"
-pinnacle.window.v1.WindowService.GetTagIds.method = "GetTagIds"
-pinnacle.window.v1.WindowService.GetTagIds.request = ".pinnacle.window.v1.GetTagIdsRequest"
-pinnacle.window.v1.WindowService.GetTagIds.response = ".pinnacle.window.v1.GetTagIdsResponse"
+pinnacle.window.v1.WindowService.SetWindowRules = {}
+pinnacle.window.v1.WindowService.SetWindowRules.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.SetWindowRules.method = "SetWindowRules"
+pinnacle.window.v1.WindowService.SetWindowRules.request = ".pinnacle.window.v1.SetWindowRulesRequest"
+pinnacle.window.v1.WindowService.SetWindowRules.response = ".pinnacle.window.v1.SetWindowRulesResponse"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.window.v1.GetTagIdsRequest
+---@param data pinnacle.window.v1.SetWindowRulesRequest
 ---
----@return pinnacle.window.v1.GetTagIdsResponse | nil response
+---@return pinnacle.window.v1.SetWindowRulesResponse | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_window_v1_WindowService_GetTagIds(data)
-    return self:unary_request(pinnacle.window.v1.WindowService.GetTagIds, data)
+function Client:pinnacle_window_v1_WindowService_SetWindowRules(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.SetWindowRules, data)
 end
-pinnacle.window.v1.WindowService.GetWindowsInDir = {}
-pinnacle.window.v1.WindowService.GetWindowsInDir.service = "pinnacle.window.v1.WindowService"
-pinnacle.window.v1.WindowService.GetWindowsInDir.method = "GetWindowsInDir"
-pinnacle.window.v1.WindowService.GetWindowsInDir.request = ".pinnacle.window.v1.GetWindowsInDirRequest"
-pinnacle.window.v1.WindowService.GetWindowsInDir.response = ".pinnacle.window.v1.GetWindowsInDirResponse"
+pinnacle.window.v1.WindowService.GetMatchingWindowRules = {}
+pinnacle.window.v1.WindowService.GetMatchingWindowRules.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.GetMatchingWindowRules.method = "GetMatchingWindowRules"
+pinnacle.window.v1.WindowService.GetMatchingWindowRules.request = ".pinnacle.window.v1.GetMatchingWindowRulesRequest"
+pinnacle.window.v1.WindowService.GetMatchingWindowRules.response = ".pinnacle.window.v1.GetMatchingWindowRulesResponse"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.window.v1.GetWindowsInDirRequest
+---@param data pinnacle.window.v1.GetMatchingWindowRulesRequest
 ---
----@return pinnacle.window.v1.GetWindowsInDirResponse | nil response
+---@return pinnacle.window.v1.GetMatchingWindowRulesResponse | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_window_v1_WindowService_GetWindowsInDir(data)
-    return self:unary_request(pinnacle.window.v1.WindowService.GetWindowsInDir, data)
+function Client:pinnacle_window_v1_WindowService_GetMatchingWindowRules(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.GetMatchingWindowRules, data)
 end
-pinnacle.window.v1.WindowService.GetForeignToplevelListIdentifier = {}
-pinnacle.window.v1.WindowService.GetForeignToplevelListIdentifier.service = "pinnacle.window.v1.WindowService"
-pinnacle.window.v1.WindowService.GetForeignToplevelListIdentifier.method = "GetForeignToplevelListIdentifier"
-pinnacle.window.v1.WindowService.GetForeignToplevelListIdentifier.request = ".pinnacle.window.v1.GetForeignToplevelListIdentifierRequest"
-pinnacle.window.v1.WindowService.GetForeignToplevelListIdentifier.response = ".pinnacle.window.v1.GetForeignToplevelListIdentifierResponse"
+pinnacle.window.v1.WindowService.ApplyRulesToExisting = {}
+pinnacle.window.v1.WindowService.ApplyRulesToExisting.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.ApplyRulesToExisting.method = "ApplyRulesToExisting"
+pinnacle.window.v1.WindowService.ApplyRulesToExisting.request = ".pinnacle.window.v1.ApplyRulesToExistingRequest"
+pinnacle.window.v1.WindowService.ApplyRulesToExisting.response = ".pinnacle.window.v1.ApplyRulesToExistingResponse"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.window.v1.GetForeignToplevelListIdentifierRequest
+---@param data pinnacle.window.v1.ApplyRulesToExistingRequest
 ---
----@return pinnacle.window.v1.GetForeignToplevelListIdentifierResponse | nil response
+---@return pinnacle.window.v1.ApplyRulesToExistingResponse | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_window_v1_WindowService_GetForeignToplevelListIdentifier(data)
-    return self:unary_request(pinnacle.window.v1.WindowService.GetForeignToplevelListIdentifier, data)
+function Client:pinnacle_window_v1_WindowService_ApplyRulesToExisting(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.ApplyRulesToExisting, data)
 end
-pinnacle.window.v1.WindowService.Close = {}
-pinnacle.window.v1.WindowService.Close.service = "pinnacle.window.v1.WindowService"
-pinnacle.window.v1.WindowService.Close.method = "Close"
-pinnacle.window.v1.WindowService.Close.request = ".pinnacle.window.v1.CloseRequest"
-pinnacle.window.v1.WindowService.Close.response = ".google.protobuf.Empty"
+pinnacle.signal.v1.SignalService = {}
+pinnacle.signal.v1.SignalService.OutputConnect = {}
+pinnacle.signal.v1.SignalService.OutputConnect.service = "pinnacle.signal.v1.SignalService"
+pinnacle.signal.v1.SignalService.OutputConnect.method = "OutputConnect"
+pinnacle.signal.v1.SignalService.OutputConnect.request = ".pinnacle.signal.v1.OutputConnectRequest"
+pinnacle.signal.v1.SignalService.OutputConnect.response = ".pinnacle.signal.v1.OutputConnectResponse"
 
----Performs a unary request.
+---Performs a bidirectional-streaming request.
+---
+---`callback` will be called with every streamed response.
+---
+---The raw client-to-server stream is returned to allow you to send encoded messages.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.window.v1.CloseRequest
+---@param callback fun(response: pinnacle.signal.v1.OutputConnectResponse, stream: grpc_client.h2.Stream)
+---@param done? fun()
 ---
----@return google.protobuf.Empty | nil response
----@return string | nil error An error string, if any
-function Client:pinnacle_window_v1_WindowService_Close(data)
-    return self:unary_request(pinnacle.window.v1.WindowService.Close, data)
+---@return grpc_client.h2.Stream | nil
+---@return string | nil An error string, if any
+function Client:pinnacle_signal_v1_SignalService_OutputConnect(callback, done)
+    return self:bidirectional_streaming_request(pinnacle.signal.v1.SignalService.OutputConnect, callback, done)
 end
-pinnacle.window.v1.WindowService.SetGeometry = {}
-pinnacle.window.v1.WindowService.SetGeometry.service = "pinnacle.window.v1.WindowService"
-pinnacle.window.v1.WindowService.SetGeometry.method = "SetGeometry"
-pinnacle.window.v1.WindowService.SetGeometry.request = ".pinnacle.window.v1.SetGeometryRequest"
-pinnacle.window.v1.WindowService.SetGeometry.response = ".google.protobuf.Empty"
+pinnacle.signal.v1.SignalService.OutputDisconnect = {}
+pinnacle.signal.v1.SignalService.OutputDisconnect.service = "pinnacle.signal.v1.SignalService"
+pinnacle.signal.v1.SignalService.OutputDisconnect.method = "OutputDisconnect"
+pinnacle.signal.v1.SignalService.OutputDisconnect.request = ".pinnacle.signal.v1.OutputDisconnectRequest"
+pinnacle.signal.v1.SignalService.OutputDisconnect.response = ".pinnacle.signal.v1.OutputDisconnectResponse"
 
----Performs a unary request.
+---Performs a bidirectional-streaming request.
+---
+---`callback` will be called with every streamed response.
+---
+---The raw client-to-server stream is returned to allow you to send encoded messages.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.window.v1.SetGeometryRequest
+---@param callback fun(response: pinnacle.signal.v1.OutputDisconnectResponse, stream: grpc_client.h2.Stream)
+---@param done? fun()
 ---
----@return google.protobuf.Empty | nil response
----@return string | nil error An error string, if any
-function Client:pinnacle_window_v1_WindowService_SetGeometry(data)
-    return self:unary_request(pinnacle.window.v1.WindowService.SetGeometry, data)
+---@return grpc_client.h2.Stream | nil
+---@return string | nil An error string, if any
+function Client:pinnacle_signal_v1_SignalService_OutputDisconnect(callback, done)
+    return self:bidirectional_streaming_request(pinnacle.signal.v1.SignalService.OutputDisconnect, callback, done)
 end
-pinnacle.window.v1.WindowService.ResizeTile = {}
-pinnacle.window.v1.WindowService.ResizeTile.service = "pinnacle.window.v1.WindowService"
-pinnacle.window.v1.WindowService.ResizeTile.method = "ResizeTile"
-pinnacle.window.v1.WindowService.ResizeTile.request = ".pinnacle.window.v1.ResizeTileRequest"
-pinnacle.window.v1.WindowService.ResizeTile.response = ".google.protobuf.Empty"
+pinnacle.signal.v1.SignalService.OutputResize = {}
+pinnacle.signal.v1.SignalService.OutputResize.service = "pinnacle.signal.v1.SignalService"
+pinnacle.signal.v1.SignalService.OutputResize.method = "OutputResize"
+pinnacle.signal.v1.SignalService.OutputResize.request = ".pinnacle.signal.v1.OutputResizeRequest"
+pinnacle.signal.v1.SignalService.OutputResize.response = ".pinnacle.signal.v1.OutputResizeResponse"
 
----Performs a unary request.
+---Performs a bidirectional-streaming request.
+---
+---`callback` will be called with every streamed response.
+---
+---The raw client-to-server stream is returned to allow you to send encoded messages.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.window.v1.ResizeTileRequest
+---@param callback fun(response: pinnacle.signal.v1.OutputResizeResponse, stream: grpc_client.h2.Stream)
+---@param done? fun()
 ---
----@return google.protobuf.Empty | nil response
----@return string | nil error An error string, if any
-function Client:pinnacle_window_v1_WindowService_ResizeTile(data)
-    return self:unary_request(pinnacle.window.v1.WindowService.ResizeTile, data)
+---@return grpc_client.h2.Stream | nil
+---@return string | nil An error string, if any
+function Client:pinnacle_signal_v1_SignalService_OutputResize(callback, done)
+    return self:bidirectional_streaming_request(pinnacle.signal.v1.SignalService.OutputResize, callback, done)
 end
-pinnacle.window.v1.WindowService.SetFullscreen = {}
-pinnacle.window.v1.WindowService.SetFullscreen.service = "pinnacle.window.v1.WindowService"
-pinnacle.window.v1.WindowService.SetFullscreen.method = "SetFullscreen"
-pinnacle.window.v1.WindowService.SetFullscreen.request = ".pinnacle.window.v1.SetFullscreenRequest"
-pinnacle.window.v1.WindowService.SetFullscreen.response = ".google.protobuf.Empty"
+pinnacle.signal.v1.SignalService.OutputMove = {}
+pinnacle.signal.v1.SignalService.OutputMove.service = "pinnacle.signal.v1.SignalService"
+pinnacle.signal.v1.SignalService.OutputMove.method = "OutputMove"
+pinnacle.signal.v1.SignalService.OutputMove.request = ".pinnacle.signal.v1.OutputMoveRequest"
+pinnacle.signal.v1.SignalService.OutputMove.response = ".pinnacle.signal.v1.OutputMoveResponse"
 
----Performs a unary request.
+---Performs a bidirectional-streaming request.
+---
+---`callback` will be called with every streamed response.
+---
+---The raw client-to-server stream is returned to allow you to send encoded messages.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.window.v1.SetFullscreenRequest
+---@param callback fun(response: pinnacle.signal.v1.OutputMoveResponse, stream: grpc_client.h2.Stream)
+---@param done? fun()
 ---
----@return google.protobuf.Empty | nil response
----@return string | nil error An error string, if any
-function Client:pinnacle_window_v1_WindowService_SetFullscreen(data)
-    return self:unary_request(pinnacle.window.v1.WindowService.SetFullscreen, data)
+---@return grpc_client.h2.Stream | nil
+---@return string | nil An error string, if any
+function Client:pinnacle_signal_v1_SignalService_OutputMove(callback, done)
+    return self:bidirectional_streaming_request(pinnacle.signal.v1.SignalService.OutputMove, callback, done)
 end
-pinnacle.window.v1.WindowService.SetMaximized = {}
-pinnacle.window.v1.WindowService.SetMaximized.service = "pinnacle.window.v1.WindowService"
-pinnacle.window.v1.WindowService.SetMaximized.method = "SetMaximized"
-pinnacle.window.v1.WindowService.SetMaximized.request = ".pinnacle.window.v1.SetMaximizedRequest"
-pinnacle.window.v1.WindowService.SetMaximized.response = ".google.protobuf.Empty"
+pinnacle.signal.v1.SignalService.OutputPointerEnter = {}
+pinnacle.signal.v1.SignalService.OutputPointerEnter.service = "pinnacle.signal.v1.SignalService"
+pinnacle.signal.v1.SignalService.OutputPointerEnter.method = "OutputPointerEnter"
+pinnacle.signal.v1.SignalService.OutputPointerEnter.request = ".pinnacle.signal.v1.OutputPointerEnterRequest"
+pinnacle.signal.v1.SignalService.OutputPointerEnter.response = ".pinnacle.signal.v1.OutputPointerEnterResponse"
 
----Performs a unary request.
+---Performs a bidirectional-streaming request.
+---
+---`callback` will be called with every streamed response.
+---
+---The raw client-to-server stream is returned to allow you to send encoded messages.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.window.v1.SetMaximizedRequest
+---@param callback fun(response: pinnacle.signal.v1.OutputPointerEnterResponse, stream: grpc_client.h2.Stream)
+---@param done? fun()
 ---
----@return google.protobuf.Empty | nil response
----@return string | nil error An error string, if any
-function Client:pinnacle_window_v1_WindowService_SetMaximized(data)
-    return self:unary_request(pinnacle.window.v1.WindowService.SetMaximized, data)
+---@return grpc_client.h2.Stream | nil
+---@return string | nil An error string, if any
+function Client:pinnacle_signal_v1_SignalService_OutputPointerEnter(callback, done)
+    return self:bidirectional_streaming_request(pinnacle.signal.v1.SignalService.OutputPointerEnter, callback, done)
 end
-pinnacle.window.v1.WindowService.SetFloating = {}
-pinnacle.window.v1.WindowService.SetFloating.service = "pinnacle.window.v1.WindowService"
-pinnacle.window.v1.WindowService.SetFloating.method = "SetFloating"
-pinnacle.window.v1.WindowService.SetFloating.request = ".pinnacle.window.v1.SetFloatingRequest"
-pinnacle.window.v1.WindowService.SetFloating.response = ".google.protobuf.Empty"
+pinnacle.signal.v1.SignalService.OutputPointerLeave = {}
+pinnacle.signal.v1.SignalService.OutputPointerLeave.service = "pinnacle.signal.v1.SignalService"
+pinnacle.signal.v1.SignalService.OutputPointerLeave.method = "OutputPointerLeave"
+pinnacle.signal.v1.SignalService.OutputPointerLeave.request = ".pinnacle.signal.v1.OutputPointerLeaveRequest"
+pinnacle.signal.v1.SignalService.OutputPointerLeave.response = ".pinnacle.signal.v1.OutputPointerLeaveResponse"
 
----Performs a unary request.
+---Performs a bidirectional-streaming request.
+---
+---`callback` will be called with every streamed response.
+---
+---The raw client-to-server stream is returned to allow you to send encoded messages.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.window.v1.SetFloatingRequest
+---@param callback fun(response: pinnacle.signal.v1.OutputPointerLeaveResponse, stream: grpc_client.h2.Stream)
+---@param done? fun()
 ---
----@return google.protobuf.Empty | nil response
----@return string | nil error An error string, if any
-function Client:pinnacle_window_v1_WindowService_SetFloating(data)
-    return self:unary_request(pinnacle.window.v1.WindowService.SetFloating, data)
+---@return grpc_client.h2.Stream | nil
+---@return string | nil An error string, if any
+function Client:pinnacle_signal_v1_SignalService_OutputPointerLeave(callback, done)
+    return self:bidirectional_streaming_request(pinnacle.signal.v1.SignalService.OutputPointerLeave, callback, done)
 end
-pinnacle.window.v1.WindowService.SetFocused = {}
-pinnacle.window.v1.WindowService.SetFocused.service = "pinnacle.window.v1.WindowService"
-pinnacle.window.v1.WindowService.SetFocused.method = "SetFocused"
-pinnacle.window.v1.WindowService.SetFocused.request = ".pinnacle.window.v1.SetFocusedRequest"
-pinnacle.window.v1.WindowService.SetFocused.response = ".google.protobuf.Empty"
+pinnacle.signal.v1.SignalService.OutputPointerEdge = {}
+pinnacle.signal.v1.SignalService.OutputPointerEdge.service = "pinnacle.signal.v1.SignalService"
+pinnacle.signal.v1.SignalService.OutputPointerEdge.method = "OutputPointerEdge"
+pinnacle.signal.v1.SignalService.OutputPointerEdge.request = ".pinnacle.signal.v1.OutputPointerEdgeRequest"
+pinnacle.signal.v1.SignalService.OutputPointerEdge.response = ".pinnacle.signal.v1.OutputPointerEdgeResponse"
 
----Performs a unary request.
+---Performs a bidirectional-streaming request.
+---
+---`callback` will be called with every streamed response.
+---
+---The raw client-to-server stream is returned to allow you to send encoded messages.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.window.v1.SetFocusedRequest
+---@param callback fun(response: pinnacle.signal.v1.OutputPointerEdgeResponse, stream: grpc_client.h2.Stream)
+---@param done? fun()
 ---
----@return google.protobuf.Empty | nil response
----@return string | nil error An error string, if any
-function Client:pinnacle_window_v1_WindowService_SetFocused(data)
-    return self:unary_request(pinnacle.window.v1.WindowService.SetFocused, data)
+---@return grpc_client.h2.Stream | nil
+---@return string | nil An error string, if any
+function Client:pinnacle_signal_v1_SignalService_OutputPointerEdge(callback, done)
+    return self:bidirectional_streaming_request(pinnacle.signal.v1.SignalService.OutputPointerEdge, callback, done)
 end
-pinnacle.window.v1.WindowService.SetDecorationMode = {}
-pinnacle.window.v1.WindowService.SetDecorationMode.service = "pinnacle.window.v1.WindowService"
-pinnacle.window.v1.WindowService.SetDecorationMode.method = "SetDecorationMode"
-pinnacle.window.v1.WindowService.SetDecorationMode.request = ".pinnacle.window.v1.SetDecorationModeRequest"
-pinnacle.window.v1.WindowService.SetDecorationMode.response = ".google.protobuf.Empty"
+pinnacle.signal.v1.SignalService.OutputFocused = {}
+pinnacle.signal.v1.SignalService.OutputFocused.service = "pinnacle.signal.v1.SignalService"
+pinnacle.signal.v1.SignalService.OutputFocused.method = "OutputFocused"
+pinnacle.signal.v1.SignalService.OutputFocused.request = ".pinnacle.signal.v1.OutputFocusedRequest"
+pinnacle.signal.v1.SignalService.OutputFocused.response = ".pinnacle.signal.v1.OutputFocusedResponse"
 
----Performs a unary request.
+---Performs a bidirectional-streaming request.
+---
+---`callback` will be called with every streamed response.
+---
+---The raw client-to-server stream is returned to allow you to send encoded messages.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.window.v1.SetDecorationModeRequest
+---@param callback fun(response: pinnacle.signal.v1.OutputFocusedResponse, stream: grpc_client.h2.Stream)
+---@param done? fun()
 ---
----@return google.protobuf.Empty | nil response
----@return string | nil error An error string, if any
-function Client:pinnacle_window_v1_WindowService_SetDecorationMode(data)
-    return self:unary_request(pinnacle.window.v1.WindowService.SetDecorationMode, data)
+---@return grpc_client.h2.Stream | nil
+---@return string | nil An error string, if any
+function Client:pinnacle_signal_v1_SignalService_OutputFocused(callback, done)
+    return self:bidirectional_streaming_request(pinnacle.signal.v1.SignalService.OutputFocused, callback, done)
 end
-pinnacle.window.v1.WindowService.MoveToTag = {}
-pinnacle.window.v1.WindowService.MoveToTag.service = "pinnacle.window.v1.WindowService"
-pinnacle.window.v1.WindowService.MoveToTag.method = "MoveToTag"
-pinnacle.window.v1.WindowService.MoveToTag.request = ".pinnacle.window.v1.MoveToTagRequest"
-pinnacle.window.v1.WindowService.MoveToTag.response = ".google.protobuf.Empty"
+pinnacle.signal.v1.SignalService.OutputLayerFocused = {}
+pinnacle.signal.v1.SignalService.OutputLayerFocused.service = "pinnacle.signal.v1.SignalService"
+pinnacle.signal.v1.SignalService.OutputLayerFocused.method = "OutputLayerFocused"
+pinnacle.signal.v1.SignalService.OutputLayerFocused.request = ".pinnacle.signal.v1.OutputLayerFocusedRequest"
+pinnacle.signal.v1.SignalService.OutputLayerFocused.response = ".pinnacle.signal.v1.OutputLayerFocusedResponse"
 
----Performs a unary request.
+---Performs a bidirectional-streaming request.
+---
+---`callback` will be called with every streamed response.
+---
+---The raw client-to-server stream is returned to allow you to send encoded messages.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.window.v1.MoveToTagRequest
+---@param callback fun(response: pinnacle.signal.v1.OutputLayerFocusedResponse, stream: grpc_client.h2.Stream)
+---@param done? fun()
 ---
----@return google.protobuf.Empty | nil response
----@return string | nil error An error string, if any
-function Client:pinnacle_window_v1_WindowService_MoveToTag(data)
-    return self:unary_request(pinnacle.window.v1.WindowService.MoveToTag, data)
+---@return grpc_client.h2.Stream | nil
+---@return string | nil An error string, if any
+function Client:pinnacle_signal_v1_SignalService_OutputLayerFocused(callback, done)
+    return self:bidirectional_streaming_request(pinnacle.signal.v1.SignalService.OutputLayerFocused, callback, done)
 end
-pinnacle.window.v1.WindowService.SetTag = {}
-pinnacle.window.v1.WindowService.SetTag.service = "pinnacle.window.v1.WindowService"
-pinnacle.window.v1.WindowService.SetTag.method = "SetTag"
-pinnacle.window.v1.WindowService.SetTag.request = ".pinnacle.window.v1.SetTagRequest"
-pinnacle.window.v1.WindowService.SetTag.response = ".google.protobuf.Empty"
+pinnacle.signal.v1.SignalService.OutputLayerUnfocused = {}
+pinnacle.signal.v1.SignalService.OutputLayerUnfocused.service = "pinnacle.signal.v1.SignalService"
+pinnacle.signal.v1.SignalService.OutputLayerUnfocused.method = "OutputLayerUnfocused"
+pinnacle.signal.v1.SignalService.OutputLayerUnfocused.request = ".pinnacle.signal.v1.OutputLayerUnfocusedRequest"
+pinnacle.signal.v1.SignalService.OutputLayerUnfocused.response = ".pinnacle.signal.v1.OutputLayerUnfocusedResponse"
 
----Performs a unary request.
+---Performs a bidirectional-streaming request.
+---
+---`callback` will be called with every streamed response.
+---
+---The raw client-to-server stream is returned to allow you to send encoded messages.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.window.v1.SetTagRequest
+---@param callback fun(response: pinnacle.signal.v1.OutputLayerUnfocusedResponse, stream: grpc_client.h2.Stream)
+---@param done? fun()
 ---
----@return google.protobuf.Empty | nil response
----@return string | nil error An error string, if any
-function Client:pinnacle_window_v1_WindowService_SetTag(data)
-    return self:unary_request(pinnacle.window.v1.WindowService.SetTag, data)
+---@return grpc_client.h2.Stream | nil
+---@return string | nil An error string, if any
+function Client:pinnacle_signal_v1_SignalService_OutputLayerUnfocused(callback, done)
+    return self:bidirectional_streaming_request(pinnacle.signal.v1.SignalService.OutputLayerUnfocused, callback, done)
 end
-pinnacle.window.v1.WindowService.SetTags = {}
-pinnacle.window.v1.WindowService.SetTags.service = "pinnacle.window.v1.WindowService"
-pinnacle.window.v1.WindowService.SetTags.method = "SetTags"
-pinnacle.window.v1.WindowService.SetTags.request = ".pinnacle.window.v1.SetTagsRequest"
-pinnacle.window.v1.WindowService.SetTags.response = ".pinnacle.window.v1.SetTagsResponse"
+pinnacle.signal.v1.SignalService.OutputOrientationChanged = {}
+pinnacle.signal.v1.SignalService.OutputOrientationChanged.service = "pinnacle.signal.v1.SignalService"
+pinnacle.signal.v1.SignalService.OutputOrientationChanged.method = "OutputOrientationChanged"
+pinnacle.signal.v1.SignalService.OutputOrientationChanged.request = ".pinnacle.signal.v1.OutputOrientationChangedRequest"
+pinnacle.signal.v1.SignalService.OutputOrientationChanged.response = ".pinnacle.signal.v1.OutputOrientationChangedResponse"
 
----Performs a unary request.
+---Performs a bidirectional-streaming request.
+---
+---`callback` will be called with every streamed response.
+---
+---The raw client-to-server stream is returned to allow you to send encoded messages.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.window.v1.SetTagsRequest
+---@param callback fun(response: pinnacle.signal.v1.OutputOrientationChangedResponse, stream: grpc_client.h2.Stream)
+---@param done? fun()
 ---
----@return pinnacle.window.v1.SetTagsResponse | nil response
----@return string | nil error An error string, if any
-function Client:pinnacle_window_v1_WindowService_SetTags(data)
-    return self:unary_request(pinnacle.window.v1.WindowService.SetTags, data)
+---@return grpc_client.h2.Stream | nil
+---@return string | nil An error string, if any
+function Client:pinnacle_signal_v1_SignalService_OutputOrientationChanged(callback, done)
+    return self:bidirectional_streaming_request(pinnacle.signal.v1.SignalService.OutputOrientationChanged, callback, done)
 end
-pinnacle.window.v1.WindowService.SetVrrDemand = {}
-pinnacle.window.v1.WindowService.SetVrrDemand.service = "pinnacle.window.v1.WindowService"
-pinnacle.window.v1.WindowService.SetVrrDemand.method = "SetVrrDemand"
-pinnacle.window.v1.WindowService.SetVrrDemand.request = ".pinnacle.window.v1.SetVrrDemandRequest"
-pinnacle.window.v1.WindowService.SetVrrDemand.response = ".pinnacle.window.v1.SetVrrDemandResponse"
+pinnacle.signal.v1.SignalService.OutputFullscreenChanged = {}
+pinnacle.signal.v1.SignalService.OutputFullscreenChanged.service = "pinnacle.signal.v1.SignalService"
+pinnacle.signal.v1.SignalService.OutputFullscreenChanged.method = "OutputFullscreenChanged"
+pinnacle.signal.v1.SignalService.OutputFullscreenChanged.request = ".pinnacle.signal.v1.OutputFullscreenChangedRequest"
+pinnacle.signal.v1.SignalService.OutputFullscreenChanged.response = ".pinnacle.signal.v1.OutputFullscreenChangedResponse"
 
----Performs a unary request.
+---Performs a bidirectional-streaming request.
+---
+---`callback` will be called with every streamed response.
+---
+---The raw client-to-server stream is returned to allow you to send encoded messages.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.window.v1.SetVrrDemandRequest
+---@param callback fun(response: pinnacle.signal.v1.OutputFullscreenChangedResponse, stream: grpc_client.h2.Stream)
+---@param done? fun()
 ---
----@return pinnacle.window.v1.SetVrrDemandResponse | nil response
----@return string | nil error An error string, if any
-function Client:pinnacle_window_v1_WindowService_SetVrrDemand(data)
-    return self:unary_request(pinnacle.window.v1.WindowService.SetVrrDemand, data)
+---@return grpc_client.h2.Stream | nil
+---@return string | nil An error string, if any
+function Client:pinnacle_signal_v1_SignalService_OutputFullscreenChanged(callback, done)
+    return self:bidirectional_streaming_request(pinnacle.signal.v1.SignalService.OutputFullscreenChanged, callback, done)
 end
-pinnacle.window.v1.WindowService.MoveToOutput = {}
-pinnacle.window.v1.WindowService.MoveToOutput.service = "pinnacle.window.v1.WindowService"
-pinnacle.window.v1.WindowService.MoveToOutput.method = "MoveToOutput"
-pinnacle.window.v1.WindowService.MoveToOutput.request = ".pinnacle.window.v1.MoveToOutputRequest"
-pinnacle.window.v1.WindowService.MoveToOutput.response = ".pinnacle.window.v1.MoveToOutputResponse"
+pinnacle.signal.v1.SignalService.OutputStackingChanged = {}
+pinnacle.signal.v1.SignalService.OutputStackingChanged.service = "pinnacle.signal.v1.SignalService"
+pinnacle.signal.v1.SignalService.OutputStackingChanged.method = "OutputStackingChanged"
+pinnacle.signal.v1.SignalService.OutputStackingChanged.request = ".pinnacle.signal.v1.OutputStackingChangedRequest"
+pinnacle.signal.v1.SignalService.OutputStackingChanged.response = ".pinnacle.signal.v1.OutputStackingChangedResponse"
 
----Performs a unary request.
+---Performs a bidirectional-streaming request.
+---
+---`callback` will be called with every streamed response.
+---
+---The raw client-to-server stream is returned to allow you to send encoded messages.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.window.v1.MoveToOutputRequest
+---@param callback fun(response: pinnacle.signal.v1.OutputStackingChangedResponse, stream: grpc_client.h2.Stream)
+---@param done? fun()
 ---
----@return pinnacle.window.v1.MoveToOutputResponse | nil response
----@return string | nil error An error string, if any
-function Client:pinnacle_window_v1_WindowService_MoveToOutput(data)
-    return self:unary_request(pinnacle.window.v1.WindowService.MoveToOutput, data)
+---@return grpc_client.h2.Stream | nil
+---@return string | nil An error string, if any
+function Client:pinnacle_signal_v1_SignalService_OutputStackingChanged(callback, done)
+    return self:bidirectional_streaming_request(pinnacle.signal.v1.SignalService.OutputStackingChanged, callback, done)
 end
-pinnacle.window.v1.WindowService.Raise = {}
-pinnacle.window.v1.WindowService.Raise.service = "pinnacle.window.v1.WindowService"
-pinnacle.window.v1.WindowService.Raise.method = "Raise"
-pinnacle.window.v1.WindowService.Raise.request = ".pinnacle.window.v1.RaiseRequest"
-pinnacle.window.v1.WindowService.Raise.response = ".google.protobuf.Empty"
+pinnacle.signal.v1.SignalService.OutputNoActiveTags = {}
+pinnacle.signal.v1.SignalService.OutputNoActiveTags.service = "pinnacle.signal.v1.SignalService"
+pinnacle.signal.v1.SignalService.OutputNoActiveTags.method = "OutputNoActiveTags"
+pinnacle.signal.v1.SignalService.OutputNoActiveTags.request = ".pinnacle.signal.v1.OutputNoActiveTagsRequest"
+pinnacle.signal.v1.SignalService.OutputNoActiveTags.response = ".pinnacle.signal.v1.OutputNoActiveTagsResponse"
 
----Performs a unary request.
+---Performs a bidirectional-streaming request.
+---
+---`callback` will be called with every streamed response.
+---
+---The raw client-to-server stream is returned to allow you to send encoded messages.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.window.v1.RaiseRequest
+---@param callback fun(response: pinnacle.signal.v1.OutputNoActiveTagsResponse, stream: grpc_client.h2.Stream)
+---@param done? fun()
 ---
----@return google.protobuf.Empty | nil response
----@return string | nil error An error string, if any
-function Client:pinnacle_window_v1_WindowService_Raise(data)
-    return self:unary_request(pinnacle.window.v1.WindowService.Raise, data)
+---@return grpc_client.h2.Stream | nil
+---@return string | nil An error string, if any
+function Client:pinnacle_signal_v1_SignalService_OutputNoActiveTags(callback, done)
+    return self:bidirectional_streaming_request(pinnacle.signal.v1.SignalService.OutputNoActiveTags, callback, done)
 end
-pinnacle.window.v1.WindowService.Lower = {}
-pinnacle.window.v1.WindowService.Lower.service = "pinnacle.window.v1.WindowService"
-pinnacle.window.v1.WindowService.Lower.method = "Lower"
-pinnacle.window.v1.WindowService.Lower.request = ".pinnacle.window.v1.LowerRequest"
-pinnacle.window.v1.WindowService.Lower.response = ".pinnacle.window.v1.LowerResponse"
+pinnacle.signal.v1.SignalService.WindowPointerEnter = {}
+pinnacle.signal.v1.SignalService.WindowPointerEnter.service = "pinnacle.signal.v1.SignalService"
+pinnacle.signal.v1.SignalService.WindowPointerEnter.method = "WindowPointerEnter"
+pinnacle.signal.v1.SignalService.WindowPointerEnter.request = ".pinnacle.signal.v1.WindowPointerEnterRequest"
+pinnacle.signal.v1.SignalService.WindowPointerEnter.response = ".pinnacle.signal.v1.WindowPointerEnterResponse"
 
----Performs a unary request.
+---Performs a bidirectional-streaming request.
+---
+---`callback` will be called with every streamed response.
+---
+---The raw client-to-server stream is returned to allow you to send encoded messages.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.window.v1.LowerRequest
+---@param callback fun(response: pinnacle.signal.v1.WindowPointerEnterResponse, stream: grpc_client.h2.Stream)
+---@param done? fun()
 ---
----@return pinnacle.window.v1.LowerResponse | nil response
----@return string | nil error An error string, if any
-function Client:pinnacle_window_v1_WindowService_Lower(data)
-    return self:unary_request(pinnacle.window.v1.WindowService.Lower, data)
+---@return grpc_client.h2.Stream | nil
+---@return string | nil An error string, if any
+function Client:pinnacle_signal_v1_SignalService_WindowPointerEnter(callback, done)
+    return self:bidirectional_streaming_request(pinnacle.signal.v1.SignalService.WindowPointerEnter, callback, done)
 end
-pinnacle.window.v1.WindowService.MoveGrab = {}
-pinnacle.window.v1.WindowService.MoveGrab.service = "pinnacle.window.v1.WindowService"
-pinnacle.window.v1.WindowService.MoveGrab.method = "MoveGrab"
-pinnacle.window.v1.WindowService.MoveGrab.request = ".pinnacle.window.v1.MoveGrabRequest"
-pinnacle.window.v1.WindowService.MoveGrab.response = ".google.protobuf.Empty"
+pinnacle.signal.v1.SignalService.WindowPointerLeave = {}
+pinnacle.signal.v1.SignalService.WindowPointerLeave.service = "pinnacle.signal.v1.SignalService"
+pinnacle.signal.v1.SignalService.WindowPointerLeave.method = "WindowPointerLeave"
+pinnacle.signal.v1.SignalService.WindowPointerLeave.request = ".pinnacle.signal.v1.WindowPointerLeaveRequest"
+pinnacle.signal.v1.SignalService.WindowPointerLeave.response = ".pinnacle.signal.v1.WindowPointerLeaveResponse"
 
----Performs a unary request.
+---Performs a bidirectional-streaming request.
+---
+---`callback` will be called with every streamed response.
+---
+---The raw client-to-server stream is returned to allow you to send encoded messages.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.window.v1.MoveGrabRequest
+---@param callback fun(response: pinnacle.signal.v1.WindowPointerLeaveResponse, stream: grpc_client.h2.Stream)
+---@param done? fun()
 ---
----@return google.protobuf.Empty | nil response
----@return string | nil error An error string, if any
-function Client:pinnacle_window_v1_WindowService_MoveGrab(data)
-    return self:unary_request(pinnacle.window.v1.WindowService.MoveGrab, data)
+---@return grpc_client.h2.Stream | nil
+---@return string | nil An error string, if any
+function Client:pinnacle_signal_v1_SignalService_WindowPointerLeave(callback, done)
+    return self:bidirectional_streaming_request(pinnacle.signal.v1.SignalService.WindowPointerLeave, callback, done)
 end
-pinnacle.window.v1.WindowService.ResizeGrab = {}
-pinnacle.window.v1.WindowService.ResizeGrab.service = "pinnacle.window.v1.WindowService"
-pinnacle.window.v1.WindowService.ResizeGrab.method = "ResizeGrab"
-pinnacle.window.v1.WindowService.ResizeGrab.request = ".pinnacle.window.v1.ResizeGrabRequest"
-pinnacle.window.v1.WindowService.ResizeGrab.response = ".google.protobuf.Empty"
+pinnacle.signal.v1.SignalService.WindowFocused = {}
+pinnacle.signal.v1.SignalService.WindowFocused.service = "pinnacle.signal.v1.SignalService"
+pinnacle.signal.v1.SignalService.WindowFocused.method = "WindowFocused"
+pinnacle.signal.v1.SignalService.WindowFocused.request = ".pinnacle.signal.v1.WindowFocusedRequest"
+pinnacle.signal.v1.SignalService.WindowFocused.response = ".pinnacle.signal.v1.WindowFocusedResponse"
 
----Performs a unary request.
+---Performs a bidirectional-streaming request.
+---
+---`callback` will be called with every streamed response.
+---
+---The raw client-to-server stream is returned to allow you to send encoded messages.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.window.v1.ResizeGrabRequest
+---@param callback fun(response: pinnacle.signal.v1.WindowFocusedResponse, stream: grpc_client.h2.Stream)
+---@param done? fun()
 ---
----@return google.protobuf.Empty | nil response
----@return string | nil error An error string, if any
-function Client:pinnacle_window_v1_WindowService_ResizeGrab(data)
-    return self:unary_request(pinnacle.window.v1.WindowService.ResizeGrab, data)
+---@return grpc_client.h2.Stream | nil
+---@return string | nil An error string, if any
+function Client:pinnacle_signal_v1_SignalService_WindowFocused(callback, done)
+    return self:bidirectional_streaming_request(pinnacle.signal.v1.SignalService.WindowFocused, callback, done)
 end
-pinnacle.window.v1.WindowService.Swap = {}
-pinnacle.window.v1.WindowService.Swap.service = "pinnacle.window.v1.WindowService"
-pinnacle.window.v1.WindowService.Swap.method = "Swap"
-pinnacle.window.v1.WindowService.Swap.request = ".pinnacle.window.v1.SwapRequest"
-pinnacle.window.v1.WindowService.Swap.response = ".pinnacle.window.v1.SwapResponse"
+pinnacle.signal.v1.SignalService.WindowTitleChanged = {}
+pinnacle.signal.v1.SignalService.WindowTitleChanged.service = "pinnacle.signal.v1.SignalService"
+pinnacle.signal.v1.SignalService.WindowTitleChanged.method = "WindowTitleChanged"
+pinnacle.signal.v1.SignalService.WindowTitleChanged.request = ".pinnacle.signal.v1.WindowTitleChangedRequest"
+pinnacle.signal.v1.SignalService.WindowTitleChanged.response = ".pinnacle.signal.v1.WindowTitleChangedResponse"
 
----Performs a unary request.
+---Performs a bidirectional-streaming request.
+---
+---`callback` will be called with every streamed response.
+---
+---The raw client-to-server stream is returned to allow you to send encoded messages.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.window.v1.SwapRequest
+---@param callback fun(response: pinnacle.signal.v1.WindowTitleChangedResponse, stream: grpc_client.h2.Stream)
+---@param done? fun()
 ---
----@return pinnacle.window.v1.SwapResponse | nil response
----@return string | nil error An error string, if any
-function Client:pinnacle_window_v1_WindowService_Swap(data)
-    return self:unary_request(pinnacle.window.v1.WindowService.Swap, data)
+---@return grpc_client.h2.Stream | nil
+---@return string | nil An error string, if any
+function Client:pinnacle_signal_v1_SignalService_WindowTitleChanged(callback, done)
+    return self:bidirectional_streaming_request(pinnacle.signal.v1.SignalService.WindowTitleChanged, callback, done)
 end
-pinnacle.window.v1.WindowService.WindowRule = {}
-pinnacle.window.v1.WindowService.WindowRule.service = "pinnacle.window.v1.WindowService"
-pinnacle.window.v1.WindowService.WindowRule.method = "WindowRule"
-pinnacle.window.v1.WindowService.WindowRule.request = ".pinnacle.window.v1.WindowRuleRequest"
-pinnacle.window.v1.WindowService.WindowRule.response = ".pinnacle.window.v1.WindowRuleResponse"
+pinnacle.signal.v1.SignalService.WindowFocusedTitleChanged = {}
+pinnacle.signal.v1.SignalService.WindowFocusedTitleChanged.service = "pinnacle.signal.v1.SignalService"
+pinnacle.signal.v1.SignalService.WindowFocusedTitleChanged.method = "WindowFocusedTitleChanged"
+pinnacle.signal.v1.SignalService.WindowFocusedTitleChanged.request = ".pinnacle.signal.v1.WindowFocusedTitleChangedRequest"
+pinnacle.signal.v1.SignalService.WindowFocusedTitleChanged.response = ".pinnacle.signal.v1.WindowFocusedTitleChangedResponse"
 
 ---Performs a bidirectional-streaming request.
 ---
@@ -3062,20 +6186,19 @@ pinnacle.window.v1.WindowService.WindowRule.response = ".pinnacle.window.v1.Wind
 ---
 ---@nodiscard
 ---
----@param callback fun(response: pinnacle.window.v1.WindowRuleResponse, stream: grpc_client.h2.Stream)
+---@param callback fun(response: pinnacle.signal.v1.WindowFocusedTitleChangedResponse, stream: grpc_client.h2.Stream)
 ---@param done? fun()
 ---
 ---@return grpc_client.h2.Stream | nil
 ---@return string | nil An error string, if any
-function Client:pinnacle_window_v1_WindowService_WindowRule(callback, done)
-    return self:bidirectional_streaming_request(pinnacle.window.v1.WindowService.WindowRule, callback, done)
+function Client:pinnacle_signal_v1_SignalService_WindowFocusedTitleChanged(callback, done)
+    return self:bidirectional_streaming_request(pinnacle.signal.v1.SignalService.WindowFocusedTitleChanged, callback, done)
 end
-pinnacle.signal.v1.SignalService = {}
-pinnacle.signal.v1.SignalService.OutputConnect = {}
-pinnacle.signal.v1.SignalService.OutputConnect.service = "pinnacle.signal.v1.SignalService"
-pinnacle.signal.v1.SignalService.OutputConnect.method = "OutputConnect"
-pinnacle.signal.v1.SignalService.OutputConnect.request = ".pinnacle.signal.v1.OutputConnectRequest"
-pinnacle.signal.v1.SignalService.OutputConnect.response = ".pinnacle.signal.v1.OutputConnectResponse"
+pinnacle.signal.v1.SignalService.WindowLayoutModeChanged = {}
+pinnacle.signal.v1.SignalService.WindowLayoutModeChanged.service = "pinnacle.signal.v1.SignalService"
+pinnacle.signal.v1.SignalService.WindowLayoutModeChanged.method = "WindowLayoutModeChanged"
+pinnacle.signal.v1.SignalService.WindowLayoutModeChanged.request = ".pinnacle.signal.v1.WindowLayoutModeChangedRequest"
+pinnacle.signal.v1.SignalService.WindowLayoutModeChanged.response = ".pinnacle.signal.v1.WindowLayoutModeChangedResponse"
 
 ---Performs a bidirectional-streaming request.
 ---
@@ -3085,19 +6208,19 @@ pinnacle.signal.v1.SignalService.OutputConnect.response = ".pinnacle.signal.v1.O
 ---
 ---@nodiscard
 ---
----@param callback fun(response: pinnacle.signal.v1.OutputConnectResponse, stream: grpc_client.h2.Stream)
+---@param callback fun(response: pinnacle.signal.v1.WindowLayoutModeChangedResponse, stream: grpc_client.h2.Stream)
 ---@param done? fun()
 ---
 ---@return grpc_client.h2.Stream | nil
 ---@return string | nil An error string, if any
-function Client:pinnacle_signal_v1_SignalService_OutputConnect(callback, done)
-    return self:bidirectional_streaming_request(pinnacle.signal.v1.SignalService.OutputConnect, callback, done)
+function Client:pinnacle_signal_v1_SignalService_WindowLayoutModeChanged(callback, done)
+    return self:bidirectional_streaming_request(pinnacle.signal.v1.SignalService.WindowLayoutModeChanged, callback, done)
 end
-pinnacle.signal.v1.SignalService.OutputDisconnect = {}
-pinnacle.signal.v1.SignalService.OutputDisconnect.service = "pinnacle.signal.v1.SignalService"
-pinnacle.signal.v1.SignalService.OutputDisconnect.method = "OutputDisconnect"
-pinnacle.signal.v1.SignalService.OutputDisconnect.request = ".pinnacle.signal.v1.OutputDisconnectRequest"
-pinnacle.signal.v1.SignalService.OutputDisconnect.response = ".pinnacle.signal.v1.OutputDisconnectResponse"
+pinnacle.signal.v1.SignalService.WindowCreated = {}
+pinnacle.signal.v1.SignalService.WindowCreated.service = "pinnacle.signal.v1.SignalService"
+pinnacle.signal.v1.SignalService.WindowCreated.method = "WindowCreated"
+pinnacle.signal.v1.SignalService.WindowCreated.request = ".pinnacle.signal.v1.WindowCreatedRequest"
+pinnacle.signal.v1.SignalService.WindowCreated.response = ".pinnacle.signal.v1.WindowCreatedResponse"
 
 ---Performs a bidirectional-streaming request.
 ---
@@ -3107,19 +6230,19 @@ pinnacle.signal.v1.SignalService.OutputDisconnect.response = ".pinnacle.signal.v
 ---
 ---@nodiscard
 ---
----@param callback fun(response: pinnacle.signal.v1.OutputDisconnectResponse, stream: grpc_client.h2.Stream)
+---@param callback fun(response: pinnacle.signal.v1.WindowCreatedResponse, stream: grpc_client.h2.Stream)
 ---@param done? fun()
 ---
 ---@return grpc_client.h2.Stream | nil
 ---@return string | nil An error string, if any
-function Client:pinnacle_signal_v1_SignalService_OutputDisconnect(callback, done)
-    return self:bidirectional_streaming_request(pinnacle.signal.v1.SignalService.OutputDisconnect, callback, done)
+function Client:pinnacle_signal_v1_SignalService_WindowCreated(callback, done)
+    return self:bidirectional_streaming_request(pinnacle.signal.v1.SignalService.WindowCreated, callback, done)
 end
-pinnacle.signal.v1.SignalService.OutputResize = {}
-pinnacle.signal.v1.SignalService.OutputResize.service = "pinnacle.signal.v1.SignalService"
-pinnacle.signal.v1.SignalService.OutputResize.method = "OutputResize"
-pinnacle.signal.v1.SignalService.OutputResize.request = ".pinnacle.signal.v1.OutputResizeRequest"
-pinnacle.signal.v1.SignalService.OutputResize.response = ".pinnacle.signal.v1.OutputResizeResponse"
+pinnacle.signal.v1.SignalService.WindowDestroyed = {}
+pinnacle.signal.v1.SignalService.WindowDestroyed.service = "pinnacle.signal.v1.SignalService"
+pinnacle.signal.v1.SignalService.WindowDestroyed.method = "WindowDestroyed"
+pinnacle.signal.v1.SignalService.WindowDestroyed.request = ".pinnacle.signal.v1.WindowDestroyedRequest"
+pinnacle.signal.v1.SignalService.WindowDestroyed.response = ".pinnacle.signal.v1.WindowDestroyedResponse"
 
 ---Performs a bidirectional-streaming request.
 ---
@@ -3129,19 +6252,19 @@ pinnacle.signal.v1.SignalService.OutputResize.response = ".pinnacle.signal.v1.Ou
 ---
 ---@nodiscard
 ---
----@param callback fun(response: pinnacle.signal.v1.OutputResizeResponse, stream: grpc_client.h2.Stream)
+---@param callback fun(response: pinnacle.signal.v1.WindowDestroyedResponse, stream: grpc_client.h2.Stream)
 ---@param done? fun()
 ---
 ---@return grpc_client.h2.Stream | nil
 ---@return string | nil An error string, if any
-function Client:pinnacle_signal_v1_SignalService_OutputResize(callback, done)
-    return self:bidirectional_streaming_request(pinnacle.signal.v1.SignalService.OutputResize, callback, done)
+function Client:pinnacle_signal_v1_SignalService_WindowDestroyed(callback, done)
+    return self:bidirectional_streaming_request(pinnacle.signal.v1.SignalService.WindowDestroyed, callback, done)
 end
-pinnacle.signal.v1.SignalService.OutputMove = {}
-pinnacle.signal.v1.SignalService.OutputMove.service = "pinnacle.signal.v1.SignalService"
-pinnacle.signal.v1.SignalService.OutputMove.method = "OutputMove"
-pinnacle.signal.v1.SignalService.OutputMove.request = ".pinnacle.signal.v1.OutputMoveRequest"
-pinnacle.signal.v1.SignalService.OutputMove.response = ".pinnacle.signal.v1.OutputMoveResponse"
+pinnacle.signal.v1.SignalService.WindowUnresponsive = {}
+pinnacle.signal.v1.SignalService.WindowUnresponsive.service = "pinnacle.signal.v1.SignalService"
+pinnacle.signal.v1.SignalService.WindowUnresponsive.method = "WindowUnresponsive"
+pinnacle.signal.v1.SignalService.WindowUnresponsive.request = ".pinnacle.signal.v1.WindowUnresponsiveRequest"
+pinnacle.signal.v1.SignalService.WindowUnresponsive.response = ".pinnacle.signal.v1.WindowUnresponsiveResponse"
 
 ---Performs a bidirectional-streaming request.
 ---
@@ -3151,19 +6274,19 @@ pinnacle.signal.v1.SignalService.OutputMove.response = ".pinnacle.signal.v1.Outp
 ---
 ---@nodiscard
 ---
----@param callback fun(response: pinnacle.signal.v1.OutputMoveResponse, stream: grpc_client.h2.Stream)
+---@param callback fun(response: pinnacle.signal.v1.WindowUnresponsiveResponse, stream: grpc_client.h2.Stream)
 ---@param done? fun()
 ---
 ---@return grpc_client.h2.Stream | nil
 ---@return string | nil An error string, if any
-function Client:pinnacle_signal_v1_SignalService_OutputMove(callback, done)
-    return self:bidirectional_streaming_request(pinnacle.signal.v1.SignalService.OutputMove, callback, done)
+function Client:pinnacle_signal_v1_SignalService_WindowUnresponsive(callback, done)
+    return self:bidirectional_streaming_request(pinnacle.signal.v1.SignalService.WindowUnresponsive, callback, done)
 end
-pinnacle.signal.v1.SignalService.OutputPointerEnter = {}
-pinnacle.signal.v1.SignalService.OutputPointerEnter.service = "pinnacle.signal.v1.SignalService"
-pinnacle.signal.v1.SignalService.OutputPointerEnter.method = "OutputPointerEnter"
-pinnacle.signal.v1.SignalService.OutputPointerEnter.request = ".pinnacle.signal.v1.OutputPointerEnterRequest"
-pinnacle.signal.v1.SignalService.OutputPointerEnter.response = ".pinnacle.signal.v1.OutputPointerEnterResponse"
+pinnacle.signal.v1.SignalService.WindowMinimized = {}
+pinnacle.signal.v1.SignalService.WindowMinimized.service = "pinnacle.signal.v1.SignalService"
+pinnacle.signal.v1.SignalService.WindowMinimized.method = "WindowMinimized"
+pinnacle.signal.v1.SignalService.WindowMinimized.request = ".pinnacle.signal.v1.WindowMinimizedRequest"
+pinnacle.signal.v1.SignalService.WindowMinimized.response = ".pinnacle.signal.v1.WindowMinimizedResponse"
 
 ---Performs a bidirectional-streaming request.
 ---
@@ -3173,19 +6296,19 @@ pinnacle.signal.v1.SignalService.OutputPointerEnter.response = ".pinnacle.signal
 ---
 ---@nodiscard
 ---
----@param callback fun(response: pinnacle.signal.v1.OutputPointerEnterResponse, stream: grpc_client.h2.Stream)
+---@param callback fun(response: pinnacle.signal.v1.WindowMinimizedResponse, stream: grpc_client.h2.Stream)
 ---@param done? fun()
 ---
 ---@return grpc_client.h2.Stream | nil
 ---@return string | nil An error string, if any
-function Client:pinnacle_signal_v1_SignalService_OutputPointerEnter(callback, done)
-    return self:bidirectional_streaming_request(pinnacle.signal.v1.SignalService.OutputPointerEnter, callback, done)
+function Client:pinnacle_signal_v1_SignalService_WindowMinimized(callback, done)
+    return self:bidirectional_streaming_request(pinnacle.signal.v1.SignalService.WindowMinimized, callback, done)
 end
-pinnacle.signal.v1.SignalService.OutputPointerLeave = {}
-pinnacle.signal.v1.SignalService.OutputPointerLeave.service = "pinnacle.signal.v1.SignalService"
-pinnacle.signal.v1.SignalService.OutputPointerLeave.method = "OutputPointerLeave"
-pinnacle.signal.v1.SignalService.OutputPointerLeave.request = ".pinnacle.signal.v1.OutputPointerLeaveRequest"
-pinnacle.signal.v1.SignalService.OutputPointerLeave.response = ".pinnacle.signal.v1.OutputPointerLeaveResponse"
+pinnacle.signal.v1.SignalService.WindowUrgencyChanged = {}
+pinnacle.signal.v1.SignalService.WindowUrgencyChanged.service = "pinnacle.signal.v1.SignalService"
+pinnacle.signal.v1.SignalService.WindowUrgencyChanged.method = "WindowUrgencyChanged"
+pinnacle.signal.v1.SignalService.WindowUrgencyChanged.request = ".pinnacle.signal.v1.WindowUrgencyChangedRequest"
+pinnacle.signal.v1.SignalService.WindowUrgencyChanged.response = ".pinnacle.signal.v1.WindowUrgencyChangedResponse"
 
 ---Performs a bidirectional-streaming request.
 ---
@@ -3195,19 +6318,19 @@ pinnacle.signal.v1.SignalService.OutputPointerLeave.response = ".pinnacle.signal
 ---
 ---@nodiscard
 ---
----@param callback fun(response: pinnacle.signal.v1.OutputPointerLeaveResponse, stream: grpc_client.h2.Stream)
+---@param callback fun(response: pinnacle.signal.v1.WindowUrgencyChangedResponse, stream: grpc_client.h2.Stream)
 ---@param done? fun()
 ---
 ---@return grpc_client.h2.Stream | nil
 ---@return string | nil An error string, if any
-function Client:pinnacle_signal_v1_SignalService_OutputPointerLeave(callback, done)
-    return self:bidirectional_streaming_request(pinnacle.signal.v1.SignalService.OutputPointerLeave, callback, done)
+function Client:pinnacle_signal_v1_SignalService_WindowUrgencyChanged(callback, done)
+    return self:bidirectional_streaming_request(pinnacle.signal.v1.SignalService.WindowUrgencyChanged, callback, done)
 end
-pinnacle.signal.v1.SignalService.OutputFocused = {}
-pinnacle.signal.v1.SignalService.OutputFocused.service = "pinnacle.signal.v1.SignalService"
-pinnacle.signal.v1.SignalService.OutputFocused.method = "OutputFocused"
-pinnacle.signal.v1.SignalService.OutputFocused.request = ".pinnacle.signal.v1.OutputFocusedRequest"
-pinnacle.signal.v1.SignalService.OutputFocused.response = ".pinnacle.signal.v1.OutputFocusedResponse"
+pinnacle.signal.v1.SignalService.WindowPopupGrabBlockedKeybind = {}
+pinnacle.signal.v1.SignalService.WindowPopupGrabBlockedKeybind.service = "pinnacle.signal.v1.SignalService"
+pinnacle.signal.v1.SignalService.WindowPopupGrabBlockedKeybind.method = "WindowPopupGrabBlockedKeybind"
+pinnacle.signal.v1.SignalService.WindowPopupGrabBlockedKeybind.request = ".pinnacle.signal.v1.WindowPopupGrabBlockedKeybindRequest"
+pinnacle.signal.v1.SignalService.WindowPopupGrabBlockedKeybind.response = ".pinnacle.signal.v1.WindowPopupGrabBlockedKeybindResponse"
 
 ---Performs a bidirectional-streaming request.
 ---
@@ -3217,19 +6340,19 @@ pinnacle.signal.v1.SignalService.OutputFocused.response = ".pinnacle.signal.v1.O
 ---
 ---@nodiscard
 ---
----@param callback fun(response: pinnacle.signal.v1.OutputFocusedResponse, stream: grpc_client.h2.Stream)
+---@param callback fun(response: pinnacle.signal.v1.WindowPopupGrabBlockedKeybindResponse, stream: grpc_client.h2.Stream)
 ---@param done? fun()
 ---
 ---@return grpc_client.h2.Stream | nil
 ---@return string | nil An error string, if any
-function Client:pinnacle_signal_v1_SignalService_OutputFocused(callback, done)
-    return self:bidirectional_streaming_request(pinnacle.signal.v1.SignalService.OutputFocused, callback, done)
+function Client:pinnacle_signal_v1_SignalService_WindowPopupGrabBlockedKeybind(callback, done)
+    return self:bidirectional_streaming_request(pinnacle.signal.v1.SignalService.WindowPopupGrabBlockedKeybind, callback, done)
 end
-pinnacle.signal.v1.SignalService.WindowPointerEnter = {}
-pinnacle.signal.v1.SignalService.WindowPointerEnter.service = "pinnacle.signal.v1.SignalService"
-pinnacle.signal.v1.SignalService.WindowPointerEnter.method = "WindowPointerEnter"
-pinnacle.signal.v1.SignalService.WindowPointerEnter.request = ".pinnacle.signal.v1.WindowPointerEnterRequest"
-pinnacle.signal.v1.SignalService.WindowPointerEnter.response = ".pinnacle.signal.v1.WindowPointerEnterResponse"
+pinnacle.signal.v1.SignalService.TagActive = {}
+pinnacle.signal.v1.SignalService.TagActive.service = "pinnacle.signal.v1.SignalService"
+pinnacle.signal.v1.SignalService.TagActive.method = "TagActive"
+pinnacle.signal.v1.SignalService.TagActive.request = ".pinnacle.signal.v1.TagActiveRequest"
+pinnacle.signal.v1.SignalService.TagActive.response = ".pinnacle.signal.v1.TagActiveResponse"
 
 ---Performs a bidirectional-streaming request.
 ---
@@ -3239,19 +6362,19 @@ pinnacle.signal.v1.SignalService.WindowPointerEnter.response = ".pinnacle.signal
 ---
 ---@nodiscard
 ---
----@param callback fun(response: pinnacle.signal.v1.WindowPointerEnterResponse, stream: grpc_client.h2.Stream)
+---@param callback fun(response: pinnacle.signal.v1.TagActiveResponse, stream: grpc_client.h2.Stream)
 ---@param done? fun()
 ---
 ---@return grpc_client.h2.Stream | nil
 ---@return string | nil An error string, if any
-function Client:pinnacle_signal_v1_SignalService_WindowPointerEnter(callback, done)
-    return self:bidirectional_streaming_request(pinnacle.signal.v1.SignalService.WindowPointerEnter, callback, done)
+function Client:pinnacle_signal_v1_SignalService_TagActive(callback, done)
+    return self:bidirectional_streaming_request(pinnacle.signal.v1.SignalService.TagActive, callback, done)
 end
-pinnacle.signal.v1.SignalService.WindowPointerLeave = {}
-pinnacle.signal.v1.SignalService.WindowPointerLeave.service = "pinnacle.signal.v1.SignalService"
-pinnacle.signal.v1.SignalService.WindowPointerLeave.method = "WindowPointerLeave"
-pinnacle.signal.v1.SignalService.WindowPointerLeave.request = ".pinnacle.signal.v1.WindowPointerLeaveRequest"
-pinnacle.signal.v1.SignalService.WindowPointerLeave.response = ".pinnacle.signal.v1.WindowPointerLeaveResponse"
+pinnacle.signal.v1.SignalService.TagCreated = {}
+pinnacle.signal.v1.SignalService.TagCreated.service = "pinnacle.signal.v1.SignalService"
+pinnacle.signal.v1.SignalService.TagCreated.method = "TagCreated"
+pinnacle.signal.v1.SignalService.TagCreated.request = ".pinnacle.signal.v1.TagCreatedRequest"
+pinnacle.signal.v1.SignalService.TagCreated.response = ".pinnacle.signal.v1.TagCreatedResponse"
 
 ---Performs a bidirectional-streaming request.
 ---
@@ -3261,19 +6384,19 @@ pinnacle.signal.v1.SignalService.WindowPointerLeave.response = ".pinnacle.signal
 ---
 ---@nodiscard
 ---
----@param callback fun(response: pinnacle.signal.v1.WindowPointerLeaveResponse, stream: grpc_client.h2.Stream)
+---@param callback fun(response: pinnacle.signal.v1.TagCreatedResponse, stream: grpc_client.h2.Stream)
 ---@param done? fun()
 ---
 ---@return grpc_client.h2.Stream | nil
 ---@return string | nil An error string, if any
-function Client:pinnacle_signal_v1_SignalService_WindowPointerLeave(callback, done)
-    return self:bidirectional_streaming_request(pinnacle.signal.v1.SignalService.WindowPointerLeave, callback, done)
+function Client:pinnacle_signal_v1_SignalService_TagCreated(callback, done)
+    return self:bidirectional_streaming_request(pinnacle.signal.v1.SignalService.TagCreated, callback, done)
 end
-pinnacle.signal.v1.SignalService.WindowFocused = {}
-pinnacle.signal.v1.SignalService.WindowFocused.service = "pinnacle.signal.v1.SignalService"
-pinnacle.signal.v1.SignalService.WindowFocused.method = "WindowFocused"
-pinnacle.signal.v1.SignalService.WindowFocused.request = ".pinnacle.signal.v1.WindowFocusedRequest"
-pinnacle.signal.v1.SignalService.WindowFocused.response = ".pinnacle.signal.v1.WindowFocusedResponse"
+pinnacle.signal.v1.SignalService.TagRemoved = {}
+pinnacle.signal.v1.SignalService.TagRemoved.service = "pinnacle.signal.v1.SignalService"
+pinnacle.signal.v1.SignalService.TagRemoved.method = "TagRemoved"
+pinnacle.signal.v1.SignalService.TagRemoved.request = ".pinnacle.signal.v1.TagRemovedRequest"
+pinnacle.signal.v1.SignalService.TagRemoved.response = ".pinnacle.signal.v1.TagRemovedResponse"
 
 ---Performs a bidirectional-streaming request.
 ---
@@ -3283,19 +6406,19 @@ pinnacle.signal.v1.SignalService.WindowFocused.response = ".pinnacle.signal.v1.W
 ---
 ---@nodiscard
 ---
----@param callback fun(response: pinnacle.signal.v1.WindowFocusedResponse, stream: grpc_client.h2.Stream)
+---@param callback fun(response: pinnacle.signal.v1.TagRemovedResponse, stream: grpc_client.h2.Stream)
 ---@param done? fun()
 ---
 ---@return grpc_client.h2.Stream | nil
 ---@return string | nil An error string, if any
-function Client:pinnacle_signal_v1_SignalService_WindowFocused(callback, done)
-    return self:bidirectional_streaming_request(pinnacle.signal.v1.SignalService.WindowFocused, callback, done)
+function Client:pinnacle_signal_v1_SignalService_TagRemoved(callback, done)
+    return self:bidirectional_streaming_request(pinnacle.signal.v1.SignalService.TagRemoved, callback, done)
 end
-pinnacle.signal.v1.SignalService.WindowTitleChanged = {}
-pinnacle.signal.v1.SignalService.WindowTitleChanged.service = "pinnacle.signal.v1.SignalService"
-pinnacle.signal.v1.SignalService.WindowTitleChanged.method = "WindowTitleChanged"
-pinnacle.signal.v1.SignalService.WindowTitleChanged.request = ".pinnacle.signal.v1.WindowTitleChangedRequest"
-pinnacle.signal.v1.SignalService.WindowTitleChanged.response = ".pinnacle.signal.v1.WindowTitleChangedResponse"
+pinnacle.signal.v1.SignalService.TagUrgencyChanged = {}
+pinnacle.signal.v1.SignalService.TagUrgencyChanged.service = "pinnacle.signal.v1.SignalService"
+pinnacle.signal.v1.SignalService.TagUrgencyChanged.method = "TagUrgencyChanged"
+pinnacle.signal.v1.SignalService.TagUrgencyChanged.request = ".pinnacle.signal.v1.TagUrgencyChangedRequest"
+pinnacle.signal.v1.SignalService.TagUrgencyChanged.response = ".pinnacle.signal.v1.TagUrgencyChangedResponse"
 
 ---Performs a bidirectional-streaming request.
 ---
@@ -3305,19 +6428,19 @@ pinnacle.signal.v1.SignalService.WindowTitleChanged.response = ".pinnacle.signal
 ---
 ---@nodiscard
 ---
----@param callback fun(response: pinnacle.signal.v1.WindowTitleChangedResponse, stream: grpc_client.h2.Stream)
+---@param callback fun(response: pinnacle.signal.v1.TagUrgencyChangedResponse, stream: grpc_client.h2.Stream)
 ---@param done? fun()
 ---
 ---@return grpc_client.h2.Stream | nil
 ---@return string | nil An error string, if any
-function Client:pinnacle_signal_v1_SignalService_WindowTitleChanged(callback, done)
-    return self:bidirectional_streaming_request(pinnacle.signal.v1.SignalService.WindowTitleChanged, callback, done)
+function Client:pinnacle_signal_v1_SignalService_TagUrgencyChanged(callback, done)
+    return self:bidirectional_streaming_request(pinnacle.signal.v1.SignalService.TagUrgencyChanged, callback, done)
 end
-pinnacle.signal.v1.SignalService.WindowLayoutModeChanged = {}
-pinnacle.signal.v1.SignalService.WindowLayoutModeChanged.service = "pinnacle.signal.v1.SignalService"
-pinnacle.signal.v1.SignalService.WindowLayoutModeChanged.method = "WindowLayoutModeChanged"
-pinnacle.signal.v1.SignalService.WindowLayoutModeChanged.request = ".pinnacle.signal.v1.WindowLayoutModeChangedRequest"
-pinnacle.signal.v1.SignalService.WindowLayoutModeChanged.response = ".pinnacle.signal.v1.WindowLayoutModeChangedResponse"
+pinnacle.signal.v1.SignalService.InputDeviceAdded = {}
+pinnacle.signal.v1.SignalService.InputDeviceAdded.service = "pinnacle.signal.v1.SignalService"
+pinnacle.signal.v1.SignalService.InputDeviceAdded.method = "InputDeviceAdded"
+pinnacle.signal.v1.SignalService.InputDeviceAdded.request = ".pinnacle.signal.v1.InputDeviceAddedRequest"
+pinnacle.signal.v1.SignalService.InputDeviceAdded.response = ".pinnacle.signal.v1.InputDeviceAddedResponse"
 
 ---Performs a bidirectional-streaming request.
 ---
@@ -3327,19 +6450,19 @@ pinnacle.signal.v1.SignalService.WindowLayoutModeChanged.response = ".pinnacle.s
 ---
 ---@nodiscard
 ---
----@param callback fun(response: pinnacle.signal.v1.WindowLayoutModeChangedResponse, stream: grpc_client.h2.Stream)
+---@param callback fun(response: pinnacle.signal.v1.InputDeviceAddedResponse, stream: grpc_client.h2.Stream)
 ---@param done? fun()
 ---
 ---@return grpc_client.h2.Stream | nil
 ---@return string | nil An error string, if any
-function Client:pinnacle_signal_v1_SignalService_WindowLayoutModeChanged(callback, done)
-    return self:bidirectional_streaming_request(pinnacle.signal.v1.SignalService.WindowLayoutModeChanged, callback, done)
+function Client:pinnacle_signal_v1_SignalService_InputDeviceAdded(callback, done)
+    return self:bidirectional_streaming_request(pinnacle.signal.v1.SignalService.InputDeviceAdded, callback, done)
 end
-pinnacle.signal.v1.SignalService.WindowCreated = {}
-pinnacle.signal.v1.SignalService.WindowCreated.service = "pinnacle.signal.v1.SignalService"
-pinnacle.signal.v1.SignalService.WindowCreated.method = "WindowCreated"
-pinnacle.signal.v1.SignalService.WindowCreated.request = ".pinnacle.signal.v1.WindowCreatedRequest"
-pinnacle.signal.v1.SignalService.WindowCreated.response = ".pinnacle.signal.v1.WindowCreatedResponse"
+pinnacle.signal.v1.SignalService.InputAccessibilityChanged = {}
+pinnacle.signal.v1.SignalService.InputAccessibilityChanged.service = "pinnacle.signal.v1.SignalService"
+pinnacle.signal.v1.SignalService.InputAccessibilityChanged.method = "InputAccessibilityChanged"
+pinnacle.signal.v1.SignalService.InputAccessibilityChanged.request = ".pinnacle.signal.v1.InputAccessibilityChangedRequest"
+pinnacle.signal.v1.SignalService.InputAccessibilityChanged.response = ".pinnacle.signal.v1.InputAccessibilityChangedResponse"
 
 ---Performs a bidirectional-streaming request.
 ---
@@ -3349,19 +6472,19 @@ pinnacle.signal.v1.SignalService.WindowCreated.response = ".pinnacle.signal.v1.W
 ---
 ---@nodiscard
 ---
----@param callback fun(response: pinnacle.signal.v1.WindowCreatedResponse, stream: grpc_client.h2.Stream)
+---@param callback fun(response: pinnacle.signal.v1.InputAccessibilityChangedResponse, stream: grpc_client.h2.Stream)
 ---@param done? fun()
 ---
 ---@return grpc_client.h2.Stream | nil
 ---@return string | nil An error string, if any
-function Client:pinnacle_signal_v1_SignalService_WindowCreated(callback, done)
-    return self:bidirectional_streaming_request(pinnacle.signal.v1.SignalService.WindowCreated, callback, done)
+function Client:pinnacle_signal_v1_SignalService_InputAccessibilityChanged(callback, done)
+    return self:bidirectional_streaming_request(pinnacle.signal.v1.SignalService.InputAccessibilityChanged, callback, done)
 end
-pinnacle.signal.v1.SignalService.WindowDestroyed = {}
-pinnacle.signal.v1.SignalService.WindowDestroyed.service = "pinnacle.signal.v1.SignalService"
-pinnacle.signal.v1.SignalService.WindowDestroyed.method = "WindowDestroyed"
-pinnacle.signal.v1.SignalService.WindowDestroyed.request = ".pinnacle.signal.v1.WindowDestroyedRequest"
-pinnacle.signal.v1.SignalService.WindowDestroyed.response = ".pinnacle.signal.v1.WindowDestroyedResponse"
+pinnacle.signal.v1.SignalService.PowerOnBattery = {}
+pinnacle.signal.v1.SignalService.PowerOnBattery.service = "pinnacle.signal.v1.SignalService"
+pinnacle.signal.v1.SignalService.PowerOnBattery.method = "PowerOnBattery"
+pinnacle.signal.v1.SignalService.PowerOnBattery.request = ".pinnacle.signal.v1.PowerOnBatteryRequest"
+pinnacle.signal.v1.SignalService.PowerOnBattery.response = ".pinnacle.signal.v1.PowerOnBatteryResponse"
 
 ---Performs a bidirectional-streaming request.
 ---
@@ -3371,19 +6494,19 @@ pinnacle.signal.v1.SignalService.WindowDestroyed.response = ".pinnacle.signal.v1
 ---
 ---@nodiscard
 ---
----@param callback fun(response: pinnacle.signal.v1.WindowDestroyedResponse, stream: grpc_client.h2.Stream)
+---@param callback fun(response: pinnacle.signal.v1.PowerOnBatteryResponse, stream: grpc_client.h2.Stream)
 ---@param done? fun()
 ---
 ---@return grpc_client.h2.Stream | nil
 ---@return string | nil An error string, if any
-function Client:pinnacle_signal_v1_SignalService_WindowDestroyed(callback, done)
-    return self:bidirectional_streaming_request(pinnacle.signal.v1.SignalService.WindowDestroyed, callback, done)
+function Client:pinnacle_signal_v1_SignalService_PowerOnBattery(callback, done)
+    return self:bidirectional_streaming_request(pinnacle.signal.v1.SignalService.PowerOnBattery, callback, done)
 end
-pinnacle.signal.v1.SignalService.TagActive = {}
-pinnacle.signal.v1.SignalService.TagActive.service = "pinnacle.signal.v1.SignalService"
-pinnacle.signal.v1.SignalService.TagActive.method = "TagActive"
-pinnacle.signal.v1.SignalService.TagActive.request = ".pinnacle.signal.v1.TagActiveRequest"
-pinnacle.signal.v1.SignalService.TagActive.response = ".pinnacle.signal.v1.TagActiveResponse"
+pinnacle.signal.v1.SignalService.PowerCharging = {}
+pinnacle.signal.v1.SignalService.PowerCharging.service = "pinnacle.signal.v1.SignalService"
+pinnacle.signal.v1.SignalService.PowerCharging.method = "PowerCharging"
+pinnacle.signal.v1.SignalService.PowerCharging.request = ".pinnacle.signal.v1.PowerChargingRequest"
+pinnacle.signal.v1.SignalService.PowerCharging.response = ".pinnacle.signal.v1.PowerChargingResponse"
 
 ---Performs a bidirectional-streaming request.
 ---
@@ -3393,19 +6516,19 @@ pinnacle.signal.v1.SignalService.TagActive.response = ".pinnacle.signal.v1.TagAc
 ---
 ---@nodiscard
 ---
----@param callback fun(response: pinnacle.signal.v1.TagActiveResponse, stream: grpc_client.h2.Stream)
+---@param callback fun(response: pinnacle.signal.v1.PowerChargingResponse, stream: grpc_client.h2.Stream)
 ---@param done? fun()
 ---
 ---@return grpc_client.h2.Stream | nil
 ---@return string | nil An error string, if any
-function Client:pinnacle_signal_v1_SignalService_TagActive(callback, done)
-    return self:bidirectional_streaming_request(pinnacle.signal.v1.SignalService.TagActive, callback, done)
+function Client:pinnacle_signal_v1_SignalService_PowerCharging(callback, done)
+    return self:bidirectional_streaming_request(pinnacle.signal.v1.SignalService.PowerCharging, callback, done)
 end
-pinnacle.signal.v1.SignalService.TagCreated = {}
-pinnacle.signal.v1.SignalService.TagCreated.service = "pinnacle.signal.v1.SignalService"
-pinnacle.signal.v1.SignalService.TagCreated.method = "TagCreated"
-pinnacle.signal.v1.SignalService.TagCreated.request = ".pinnacle.signal.v1.TagCreatedRequest"
-pinnacle.signal.v1.SignalService.TagCreated.response = ".pinnacle.signal.v1.TagCreatedResponse"
+pinnacle.signal.v1.SignalService.PowerLowBattery = {}
+pinnacle.signal.v1.SignalService.PowerLowBattery.service = "pinnacle.signal.v1.SignalService"
+pinnacle.signal.v1.SignalService.PowerLowBattery.method = "PowerLowBattery"
+pinnacle.signal.v1.SignalService.PowerLowBattery.request = ".pinnacle.signal.v1.PowerLowBatteryRequest"
+pinnacle.signal.v1.SignalService.PowerLowBattery.response = ".pinnacle.signal.v1.PowerLowBatteryResponse"
 
 ---Performs a bidirectional-streaming request.
 ---
@@ -3415,19 +6538,19 @@ pinnacle.signal.v1.SignalService.TagCreated.response = ".pinnacle.signal.v1.TagC
 ---
 ---@nodiscard
 ---
----@param callback fun(response: pinnacle.signal.v1.TagCreatedResponse, stream: grpc_client.h2.Stream)
+---@param callback fun(response: pinnacle.signal.v1.PowerLowBatteryResponse, stream: grpc_client.h2.Stream)
 ---@param done? fun()
 ---
 ---@return grpc_client.h2.Stream | nil
 ---@return string | nil An error string, if any
-function Client:pinnacle_signal_v1_SignalService_TagCreated(callback, done)
-    return self:bidirectional_streaming_request(pinnacle.signal.v1.SignalService.TagCreated, callback, done)
+function Client:pinnacle_signal_v1_SignalService_PowerLowBattery(callback, done)
+    return self:bidirectional_streaming_request(pinnacle.signal.v1.SignalService.PowerLowBattery, callback, done)
 end
-pinnacle.signal.v1.SignalService.TagRemoved = {}
-pinnacle.signal.v1.SignalService.TagRemoved.service = "pinnacle.signal.v1.SignalService"
-pinnacle.signal.v1.SignalService.TagRemoved.method = "TagRemoved"
-pinnacle.signal.v1.SignalService.TagRemoved.request = ".pinnacle.signal.v1.TagRemovedRequest"
-pinnacle.signal.v1.SignalService.TagRemoved.response = ".pinnacle.signal.v1.TagRemovedResponse"
+pinnacle.signal.v1.SignalService.IdleStarted = {}
+pinnacle.signal.v1.SignalService.IdleStarted.service = "pinnacle.signal.v1.SignalService"
+pinnacle.signal.v1.SignalService.IdleStarted.method = "IdleStarted"
+pinnacle.signal.v1.SignalService.IdleStarted.request = ".pinnacle.signal.v1.IdleStartedRequest"
+pinnacle.signal.v1.SignalService.IdleStarted.response = ".pinnacle.signal.v1.IdleStartedResponse"
 
 ---Performs a bidirectional-streaming request.
 ---
@@ -3437,19 +6560,19 @@ pinnacle.signal.v1.SignalService.TagRemoved.response = ".pinnacle.signal.v1.TagR
 ---
 ---@nodiscard
 ---
----@param callback fun(response: pinnacle.signal.v1.TagRemovedResponse, stream: grpc_client.h2.Stream)
+---@param callback fun(response: pinnacle.signal.v1.IdleStartedResponse, stream: grpc_client.h2.Stream)
 ---@param done? fun()
 ---
 ---@return grpc_client.h2.Stream | nil
 ---@return string | nil An error string, if any
-function Client:pinnacle_signal_v1_SignalService_TagRemoved(callback, done)
-    return self:bidirectional_streaming_request(pinnacle.signal.v1.SignalService.TagRemoved, callback, done)
+function Client:pinnacle_signal_v1_SignalService_IdleStarted(callback, done)
+    return self:bidirectional_streaming_request(pinnacle.signal.v1.SignalService.IdleStarted, callback, done)
 end
-pinnacle.signal.v1.SignalService.InputDeviceAdded = {}
-pinnacle.signal.v1.SignalService.InputDeviceAdded.service = "pinnacle.signal.v1.SignalService"
-pinnacle.signal.v1.SignalService.InputDeviceAdded.method = "InputDeviceAdded"
-pinnacle.signal.v1.SignalService.InputDeviceAdded.request = ".pinnacle.signal.v1.InputDeviceAddedRequest"
-pinnacle.signal.v1.SignalService.InputDeviceAdded.response = ".pinnacle.signal.v1.InputDeviceAddedResponse"
+pinnacle.signal.v1.SignalService.IdleResumed = {}
+pinnacle.signal.v1.SignalService.IdleResumed.service = "pinnacle.signal.v1.SignalService"
+pinnacle.signal.v1.SignalService.IdleResumed.method = "IdleResumed"
+pinnacle.signal.v1.SignalService.IdleResumed.request = ".pinnacle.signal.v1.IdleResumedRequest"
+pinnacle.signal.v1.SignalService.IdleResumed.response = ".pinnacle.signal.v1.IdleResumedResponse"
 
 ---Performs a bidirectional-streaming request.
 ---
@@ -3459,13 +6582,13 @@ pinnacle.signal.v1.SignalService.InputDeviceAdded.response = ".pinnacle.signal.v
 ---
 ---@nodiscard
 ---
----@param callback fun(response: pinnacle.signal.v1.InputDeviceAddedResponse, stream: grpc_client.h2.Stream)
+---@param callback fun(response: pinnacle.signal.v1.IdleResumedResponse, stream: grpc_client.h2.Stream)
 ---@param done? fun()
 ---
 ---@return grpc_client.h2.Stream | nil
 ---@return string | nil An error string, if any
-function Client:pinnacle_signal_v1_SignalService_InputDeviceAdded(callback, done)
-    return self:bidirectional_streaming_request(pinnacle.signal.v1.SignalService.InputDeviceAdded, callback, done)
+function Client:pinnacle_signal_v1_SignalService_IdleResumed(callback, done)
+    return self:bidirectional_streaming_request(pinnacle.signal.v1.SignalService.IdleResumed, callback, done)
 end
 pinnacle.tag.v1.TagService = {}
 pinnacle.tag.v1.TagService.Get = {}
@@ -3485,6 +6608,23 @@ pinnacle.tag.v1.TagService.Get.response = ".pinnacle.tag.v1.GetResponse"
 function Client:pinnacle_tag_v1_TagService_Get(data)
     return self:unary_request(pinnacle.tag.v1.TagService.Get, data)
 end
+pinnacle.tag.v1.TagService.Exists = {}
+pinnacle.tag.v1.TagService.Exists.service = "pinnacle.tag.v1.TagService"
+pinnacle.tag.v1.TagService.Exists.method = "Exists"
+pinnacle.tag.v1.TagService.Exists.request = ".pinnacle.tag.v1.ExistsRequest"
+pinnacle.tag.v1.TagService.Exists.response = ".pinnacle.tag.v1.ExistsResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.tag.v1.ExistsRequest
+---
+---@return pinnacle.tag.v1.ExistsResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_tag_v1_TagService_Exists(data)
+    return self:unary_request(pinnacle.tag.v1.TagService.Exists, data)
+end
 pinnacle.tag.v1.TagService.GetActive = {}
 pinnacle.tag.v1.TagService.GetActive.service = "pinnacle.tag.v1.TagService"
 pinnacle.tag.v1.TagService.GetActive.method = "GetActive"
@@ -3502,6 +6642,23 @@ pinnacle.tag.v1.TagService.GetActive.response = ".pinnacle.tag.v1.GetActiveRespo
 function Client:pinnacle_tag_v1_TagService_GetActive(data)
     return self:unary_request(pinnacle.tag.v1.TagService.GetActive, data)
 end
+pinnacle.tag.v1.TagService.GetUrgent = {}
+pinnacle.tag.v1.TagService.GetUrgent.service = "pinnacle.tag.v1.TagService"
+pinnacle.tag.v1.TagService.GetUrgent.method = "GetUrgent"
+pinnacle.tag.v1.TagService.GetUrgent.request = ".pinnacle.tag.v1.GetUrgentRequest"
+pinnacle.tag.v1.TagService.GetUrgent.response = ".pinnacle.tag.v1.GetUrgentResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.tag.v1.GetUrgentRequest
+---
+---@return pinnacle.tag.v1.GetUrgentResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_tag_v1_TagService_GetUrgent(data)
+    return self:unary_request(pinnacle.tag.v1.TagService.GetUrgent, data)
+end
 pinnacle.tag.v1.TagService.GetName = {}
 pinnacle.tag.v1.TagService.GetName.service = "pinnacle.tag.v1.TagService"
 pinnacle.tag.v1.TagService.GetName.method = "GetName"
@@ -3604,6 +6761,40 @@ pinnacle.tag.v1.TagService.SwitchTo.response = ".google.protobuf.Empty"
 function Client:pinnacle_tag_v1_TagService_SwitchTo(data)
     return self:unary_request(pinnacle.tag.v1.TagService.SwitchTo, data)
 end
+pinnacle.tag.v1.TagService.SwitchToMatching = {}
+pinnacle.tag.v1.TagService.SwitchToMatching.service = "pinnacle.tag.v1.TagService"
+pinnacle.tag.v1.TagService.SwitchToMatching.method = "SwitchToMatching"
+pinnacle.tag.v1.TagService.SwitchToMatching.request = ".pinnacle.tag.v1.SwitchToMatchingRequest"
+pinnacle.tag.v1.TagService.SwitchToMatching.response = ".pinnacle.tag.v1.SwitchToMatchingResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.tag.v1.SwitchToMatchingRequest
+---
+---@return pinnacle.tag.v1.SwitchToMatchingResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_tag_v1_TagService_SwitchToMatching(data)
+    return self:unary_request(pinnacle.tag.v1.TagService.SwitchToMatching, data)
+end
+pinnacle.tag.v1.TagService.SwitchToPrevious = {}
+pinnacle.tag.v1.TagService.SwitchToPrevious.service = "pinnacle.tag.v1.TagService"
+pinnacle.tag.v1.TagService.SwitchToPrevious.method = "SwitchToPrevious"
+pinnacle.tag.v1.TagService.SwitchToPrevious.request = ".pinnacle.tag.v1.SwitchToPreviousRequest"
+pinnacle.tag.v1.TagService.SwitchToPrevious.response = ".pinnacle.tag.v1.SwitchToPreviousResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.tag.v1.SwitchToPreviousRequest
+---
+---@return pinnacle.tag.v1.SwitchToPreviousResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_tag_v1_TagService_SwitchToPrevious(data)
+    return self:unary_request(pinnacle.tag.v1.TagService.SwitchToPrevious, data)
+end
 pinnacle.tag.v1.TagService.MoveToOutput = {}
 pinnacle.tag.v1.TagService.MoveToOutput.service = "pinnacle.tag.v1.TagService"
 pinnacle.tag.v1.TagService.MoveToOutput.method = "MoveToOutput"
@@ -3621,6 +6812,94 @@ pinnacle.tag.v1.TagService.MoveToOutput.response = ".pinnacle.tag.v1.MoveToOutpu
 function Client:pinnacle_tag_v1_TagService_MoveToOutput(data)
     return self:unary_request(pinnacle.tag.v1.TagService.MoveToOutput, data)
 end
+pinnacle.tag.v1.TagService.MoveToIndex = {}
+pinnacle.tag.v1.TagService.MoveToIndex.service = "pinnacle.tag.v1.TagService"
+pinnacle.tag.v1.TagService.MoveToIndex.method = "MoveToIndex"
+pinnacle.tag.v1.TagService.MoveToIndex.request = ".pinnacle.tag.v1.MoveToIndexRequest"
+pinnacle.tag.v1.TagService.MoveToIndex.response = ".google.protobuf.Empty"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.tag.v1.MoveToIndexRequest
+---
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_tag_v1_TagService_MoveToIndex(data)
+    return self:unary_request(pinnacle.tag.v1.TagService.MoveToIndex, data)
+end
+pinnacle.tag.v1.TagService.Peek = {}
+pinnacle.tag.v1.TagService.Peek.service = "pinnacle.tag.v1.TagService"
+pinnacle.tag.v1.TagService.Peek.method = "Peek"
+pinnacle.tag.v1.TagService.Peek.request = ".pinnacle.tag.v1.PeekRequest"
+pinnacle.tag.v1.TagService.Peek.response = ".google.protobuf.Empty"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.tag.v1.PeekRequest
+---
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_tag_v1_TagService_Peek(data)
+    return self:unary_request(pinnacle.tag.v1.TagService.Peek, data)
+end
+pinnacle.tag.v1.TagService.AddEffectRule = {}
+pinnacle.tag.v1.TagService.AddEffectRule.service = "pinnacle.tag.v1.TagService"
+pinnacle.tag.v1.TagService.AddEffectRule.method = "AddEffectRule"
+pinnacle.tag.v1.TagService.AddEffectRule.request = ".pinnacle.tag.v1.AddEffectRuleRequest"
+pinnacle.tag.v1.TagService.AddEffectRule.response = ".google.protobuf.Empty"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.tag.v1.AddEffectRuleRequest
+---
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_tag_v1_TagService_AddEffectRule(data)
+    return self:unary_request(pinnacle.tag.v1.TagService.AddEffectRule, data)
+end
+pinnacle.tag.v1.TagService.SetEmptyOutputPolicy = {}
+pinnacle.tag.v1.TagService.SetEmptyOutputPolicy.service = "pinnacle.tag.v1.TagService"
+pinnacle.tag.v1.TagService.SetEmptyOutputPolicy.method = "SetEmptyOutputPolicy"
+pinnacle.tag.v1.TagService.SetEmptyOutputPolicy.request = ".pinnacle.tag.v1.SetEmptyOutputPolicyRequest"
+pinnacle.tag.v1.TagService.SetEmptyOutputPolicy.response = ".google.protobuf.Empty"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.tag.v1.SetEmptyOutputPolicyRequest
+---
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_tag_v1_TagService_SetEmptyOutputPolicy(data)
+    return self:unary_request(pinnacle.tag.v1.TagService.SetEmptyOutputPolicy, data)
+end
+pinnacle.tag.v1.TagService.Pager = {}
+pinnacle.tag.v1.TagService.Pager.service = "pinnacle.tag.v1.TagService"
+pinnacle.tag.v1.TagService.Pager.method = "Pager"
+pinnacle.tag.v1.TagService.Pager.request = ".pinnacle.tag.v1.PagerRequest"
+pinnacle.tag.v1.TagService.Pager.response = ".pinnacle.tag.v1.PagerResponse"
+
+---Performs a server-streaming request.
+---
+---`callback` will be called with every streamed response.
+---
+---@nodiscard
+---
+---@param data pinnacle.tag.v1.PagerRequest
+---@param callback fun(response: pinnacle.tag.v1.PagerResponse)
+---@param done? fun()
+---
+---@return string | nil An error string, if any
+function Client:pinnacle_tag_v1_TagService_Pager(data, callback, done)
+    return self:server_streaming_request(pinnacle.tag.v1.TagService.Pager, data, callback, done)
+end
 pinnacle.v1.PinnacleService = {}
 pinnacle.v1.PinnacleService.Quit = {}
 pinnacle.v1.PinnacleService.Quit.service = "pinnacle.v1.PinnacleService"
@@ -3639,6 +6918,23 @@ pinnacle.v1.PinnacleService.Quit.response = ".google.protobuf.Empty"
 function Client:pinnacle_v1_PinnacleService_Quit(data)
     return self:unary_request(pinnacle.v1.PinnacleService.Quit, data)
 end
+pinnacle.v1.PinnacleService.Restart = {}
+pinnacle.v1.PinnacleService.Restart.service = "pinnacle.v1.PinnacleService"
+pinnacle.v1.PinnacleService.Restart.method = "Restart"
+pinnacle.v1.PinnacleService.Restart.request = ".pinnacle.v1.RestartRequest"
+pinnacle.v1.PinnacleService.Restart.response = ".google.protobuf.Empty"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.v1.RestartRequest
+---
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_v1_PinnacleService_Restart(data)
+    return self:unary_request(pinnacle.v1.PinnacleService.Restart, data)
+end
 pinnacle.v1.PinnacleService.ReloadConfig = {}
 pinnacle.v1.PinnacleService.ReloadConfig.service = "pinnacle.v1.PinnacleService"
 pinnacle.v1.PinnacleService.ReloadConfig.method = "ReloadConfig"
@@ -3746,6 +7042,128 @@ pinnacle.v1.PinnacleService.TakeLastError.response = ".pinnacle.v1.TakeLastError
 function Client:pinnacle_v1_PinnacleService_TakeLastError(data)
     return self:unary_request(pinnacle.v1.PinnacleService.TakeLastError, data)
 end
+pinnacle.v1.PinnacleService.GetSettings = {}
+pinnacle.v1.PinnacleService.GetSettings.service = "pinnacle.v1.PinnacleService"
+pinnacle.v1.PinnacleService.GetSettings.method = "GetSettings"
+pinnacle.v1.PinnacleService.GetSettings.request = ".pinnacle.v1.GetSettingsRequest"
+pinnacle.v1.PinnacleService.GetSettings.response = ".pinnacle.v1.GetSettingsResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.v1.GetSettingsRequest
+---
+---@return pinnacle.v1.GetSettingsResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_v1_PinnacleService_GetSettings(data)
+    return self:unary_request(pinnacle.v1.PinnacleService.GetSettings, data)
+end
+pinnacle.v1.PinnacleService.Metrics = {}
+pinnacle.v1.PinnacleService.Metrics.service = "pinnacle.v1.PinnacleService"
+pinnacle.v1.PinnacleService.Metrics.method = "Metrics"
+pinnacle.v1.PinnacleService.Metrics.request = ".pinnacle.v1.MetricsRequest"
+pinnacle.v1.PinnacleService.Metrics.response = ".pinnacle.v1.MetricsResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.v1.MetricsRequest
+---
+---@return pinnacle.v1.MetricsResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_v1_PinnacleService_Metrics(data)
+    return self:unary_request(pinnacle.v1.PinnacleService.Metrics, data)
+end
+pinnacle.v1.PinnacleService.SetMetricsExport = {}
+pinnacle.v1.PinnacleService.SetMetricsExport.service = "pinnacle.v1.PinnacleService"
+pinnacle.v1.PinnacleService.SetMetricsExport.method = "SetMetricsExport"
+pinnacle.v1.PinnacleService.SetMetricsExport.request = ".pinnacle.v1.SetMetricsExportRequest"
+pinnacle.v1.PinnacleService.SetMetricsExport.response = ".pinnacle.v1.SetMetricsExportResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.v1.SetMetricsExportRequest
+---
+---@return pinnacle.v1.SetMetricsExportResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_v1_PinnacleService_SetMetricsExport(data)
+    return self:unary_request(pinnacle.v1.PinnacleService.SetMetricsExport, data)
+end
+pinnacle.v1.PinnacleService.DefineProfile = {}
+pinnacle.v1.PinnacleService.DefineProfile.service = "pinnacle.v1.PinnacleService"
+pinnacle.v1.PinnacleService.DefineProfile.method = "DefineProfile"
+pinnacle.v1.PinnacleService.DefineProfile.request = ".pinnacle.v1.DefineProfileRequest"
+pinnacle.v1.PinnacleService.DefineProfile.response = ".pinnacle.v1.DefineProfileResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.v1.DefineProfileRequest
+---
+---@return pinnacle.v1.DefineProfileResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_v1_PinnacleService_DefineProfile(data)
+    return self:unary_request(pinnacle.v1.PinnacleService.DefineProfile, data)
+end
+pinnacle.v1.PinnacleService.SetProfile = {}
+pinnacle.v1.PinnacleService.SetProfile.service = "pinnacle.v1.PinnacleService"
+pinnacle.v1.PinnacleService.SetProfile.method = "SetProfile"
+pinnacle.v1.PinnacleService.SetProfile.request = ".pinnacle.v1.SetProfileRequest"
+pinnacle.v1.PinnacleService.SetProfile.response = ".pinnacle.v1.SetProfileResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.v1.SetProfileRequest
+---
+---@return pinnacle.v1.SetProfileResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_v1_PinnacleService_SetProfile(data)
+    return self:unary_request(pinnacle.v1.PinnacleService.SetProfile, data)
+end
+pinnacle.v1.PinnacleService.GetProfile = {}
+pinnacle.v1.PinnacleService.GetProfile.service = "pinnacle.v1.PinnacleService"
+pinnacle.v1.PinnacleService.GetProfile.method = "GetProfile"
+pinnacle.v1.PinnacleService.GetProfile.request = ".pinnacle.v1.GetProfileRequest"
+pinnacle.v1.PinnacleService.GetProfile.response = ".pinnacle.v1.GetProfileResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.v1.GetProfileRequest
+---
+---@return pinnacle.v1.GetProfileResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_v1_PinnacleService_GetProfile(data)
+    return self:unary_request(pinnacle.v1.PinnacleService.GetProfile, data)
+end
+pinnacle.v1.PinnacleService.RegisterNamespace = {}
+pinnacle.v1.PinnacleService.RegisterNamespace.service = "pinnacle.v1.PinnacleService"
+pinnacle.v1.PinnacleService.RegisterNamespace.method = "RegisterNamespace"
+pinnacle.v1.PinnacleService.RegisterNamespace.request = ".pinnacle.v1.RegisterNamespaceRequest"
+pinnacle.v1.PinnacleService.RegisterNamespace.response = ".pinnacle.v1.RegisterNamespaceResponse"
+
+---Performs a server-streaming request.
+---
+---`callback` will be called with every streamed response.
+---
+---@nodiscard
+---
+---@param data pinnacle.v1.RegisterNamespaceRequest
+---@param callback fun(response: pinnacle.v1.RegisterNamespaceResponse)
+---@param done? fun()
+---
+---@return string | nil An error string, if any
+function Client:pinnacle_v1_PinnacleService_RegisterNamespace(data, callback, done)
+    return self:server_streaming_request(pinnacle.v1.PinnacleService.RegisterNamespace, data, callback, done)
+end
 return {
     google = google,
     pinnacle = pinnacle,
@@ -22,6 +22,11 @@ function protobuf.build_protos()
         "pinnacle/signal/" .. version .. "/signal.proto",
         "pinnacle/layout/" .. version .. "/layout.proto",
         "pinnacle/render/" .. version .. "/render.proto",
+        "pinnacle/screenshot/" .. version .. "/screenshot.proto",
+        "pinnacle/portal/" .. version .. "/portal.proto",
+        "pinnacle/idle/" .. version .. "/idle.proto",
+        "pinnacle/nightlight/" .. version .. "/nightlight.proto",
+        "pinnacle/power/" .. version .. "/power.proto",
         "pinnacle/util/" .. version .. "/util.proto",
         "pinnacle/debug/" .. version .. "/debug.proto",
         "google/protobuf/empty.proto",
@@ -0,0 +1,182 @@
+-- This Source Code Form is subject to the terms of the Mozilla Public
+-- License, v. 2.0. If a copy of the MPL was not distributed with this
+-- file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+local log = require("pinnacle.log")
+local client = require("pinnacle.grpc.client").client
+
+---Idle behavior and the screensaver.
+---
+---@class pinnacle.idle
+local idle = {}
+
+---A screensaver shown on all outputs after a period without input.
+---
+---Exactly one of `shader` or `slideshow` must be set.
+---
+---@class pinnacle.idle.Screensaver
+---How long there must be no input before the screensaver starts, in milliseconds.
+---@field timeout integer
+---A GLSL ES 1.00 fragment shader drawn over each output.
+---
+---The shader gets the output's size in pixels as `uniform vec2 size`,
+---the seconds since the screensaver started as `uniform float time`,
+---and the position on the output from 0 to 1 as `varying vec2 v_coords`.
+---@field shader string?
+---PNG images shown one after another, scaled to fit each output.
+---@field slideshow pinnacle.idle.Slideshow?
+
+---@class pinnacle.idle.Slideshow
+---Paths to the images. `~` is expanded to the home directory.
+---@field images string[]
+---How long each image is shown, in milliseconds.
+---@field interval integer
+
+---Sets the screensaver, or disables it with `nil`.
+---
+---The screensaver starts once there has been no input for its timeout
+---unless an app is inhibiting idle, and any input stops it.
+---
+---#### Example
+---```lua
+---Idle.set_screensaver({
+---    timeout = 300000,
+---    shader = [[
+---        #version 100
+---        precision mediump float;
+---
+---        uniform vec2 size;
+---        uniform float time;
+---        uniform float alpha;
+---        varying vec2 v_coords;
+---
+---        void main() {
+---            vec3 color = 0.5 + 0.5 * cos(time + v_coords.xyx + vec3(0.0, 2.0, 4.0));
+---            gl_FragColor = vec4(color, 1.0) * alpha;
+---        }
+---    ]],
+---})
+---
+---Idle.set_screensaver({
+---    timeout = 300000,
+---    slideshow = {
+---        images = { "~/Pictures/mountains.png", "~/Pictures/lake.png" },
+---        interval = 30000,
+---    },
+---})
+---
+----- Disable the screensaver
+---Idle.set_screensaver(nil)
+---```
+---
+---@param screensaver pinnacle.idle.Screensaver?
+function idle.set_screensaver(screensaver)
+    ---@type pinnacle.idle.v1.Screensaver?
+    local proto_screensaver = nil
+
+    if screensaver then
+        proto_screensaver = {
+            timeout_millis = screensaver.timeout,
+        }
+
+        if screensaver.shader then
+            proto_screensaver.shader = { source = screensaver.shader }
+        elseif screensaver.slideshow then
+            proto_screensaver.slideshow = {
+                image_paths = screensaver.slideshow.images,
+                interval_millis = screensaver.slideshow.interval,
+            }
+        end
+    end
+
+    local _, err = client:pinnacle_idle_v1_IdleService_SetScreensaver({
+        screensaver = proto_screensaver,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
+---What happens when there has been no input for a while.
+---
+---@class pinnacle.idle.IdleTimeout
+---How long there must be no input before the session is idle, in milliseconds.
+---@field timeout integer
+---Whether to power off all outputs while the session is idle.
+---
+---The outputs are powered back on at the next input.
+---@field power_off_outputs boolean?
+
+---Sets how long there must be no input before the session is idle,
+---or disables idle tracking with `nil`.
+---
+---The session doesn't become idle while an app is inhibiting idle.
+---Use `connect_signal` to run something when the session becomes idle
+---and when it resumes.
+---
+---#### Example
+---```lua
+----- Turn off the screens after 10 minutes
+---Idle.set_idle_timeout({
+---    timeout = 600000,
+---    power_off_outputs = true,
+---})
+---```
+---
+---@param idle_timeout pinnacle.idle.IdleTimeout?
+function idle.set_idle_timeout(idle_timeout)
+    local _, err = client:pinnacle_idle_v1_IdleService_SetIdleTimeout({
+        timeout_millis = idle_timeout and idle_timeout.timeout,
+        power_off_outputs = idle_timeout and idle_timeout.power_off_outputs or false,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
+---@class pinnacle.idle.IdleSignal Signals related to the session being idle.
+---@field idle fun()? There was no input for the idle timeout and the session became idle.
+---@field resume fun()? There was input while the session was idle.
+
+local signal_name_to_SignalName = {
+    idle = "IdleStarted",
+    resume = "IdleResumed",
+}
+
+---Connects to an idle signal.
+---
+---`signals` is a table containing the signal(s) you want to connect to along with
+---a corresponding callback that will be called when the signal is signalled.
+---
+---This function returns a table of signal handles with each handle stored at the same key used
+---to connect to the signal. See `SignalHandles` for more information.
+---
+---# Example
+---```lua
+---Idle.connect_signal({
+---    idle = function()
+---        Process.spawn("swaylock")
+---    end
+---})
+---```
+---@param signals pinnacle.idle.IdleSignal The signal you want to connect to
+---
+---@return pinnacle.signal.SignalHandles signal_handles Handles to every signal you connected to wrapped in a table, with keys being the same as the connected signal.
+---
+---@see pinnacle.signal.SignalHandles.disconnect_all - To disconnect from these signals
+function idle.connect_signal(signals)
+    ---@diagnostic disable-next-line: invisible
+    local handles = require("pinnacle.signal").handles.new()
+
+    for signal, callback in pairs(signals) do
+        local handle =
+            require("pinnacle.signal").add_callback(signal_name_to_SignalName[signal], callback)
+        handles[signal] = handle
+    end
+
+    return handles
+end
+
+return idle
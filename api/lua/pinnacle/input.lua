@@ -134,6 +134,33 @@ input.mouse_button_values = mouse_button_values
 ---Allows this bind to trigger when the session is locked.
 ---@field allow_when_locked boolean?
 
+---An existing bind that conflicted with a newly added bind.
+---
+---Binds conflict when they are on the same layer, trigger on the same key or button,
+---and have modifiers that can be held at the same time.
+---@class pinnacle.input.BindConflict
+---The id of the existing bind.
+---@field bind_id integer
+---The existing bind's group.
+---@field group string
+---The existing bind's description.
+---@field description string
+
+---@param response pinnacle.input.v1.BindResponse
+---@return pinnacle.input.BindConflict[]
+local function conflicts_from_response(response)
+    ---@type pinnacle.input.BindConflict[]
+    local conflicts = {}
+    for _, conflict in ipairs(response.conflicts or {}) do
+        table.insert(conflicts, {
+            bind_id = conflict.bind_id or 0,
+            group = conflict.group or "",
+            description = conflict.description or "",
+        })
+    end
+    return conflicts
+end
+
 ---A keybind.
 ---@class pinnacle.input.Keybind : pinnacle.input.Bind
 ---The key that will trigger this bind.
@@ -144,6 +171,7 @@ input.mouse_button_values = mouse_button_values
 ---@field on_release fun()?
 
 ---@param kb pinnacle.input.Keybind
+---@return pinnacle.input.BindConflict[]?
 local function keybind_inner(kb)
     local key_code = nil
     local xkb_name = nil
@@ -191,6 +219,7 @@ local function keybind_inner(kb)
     assert(response)
 
     local bind_id = response.bind_id or 0
+    local conflicts = conflicts_from_response(response)
 
     local err = client:pinnacle_input_v1_InputService_KeybindStream({
         bind_id = bind_id,
@@ -222,6 +251,8 @@ local function keybind_inner(kb)
         log.error(err)
         return
     end
+
+    return conflicts
 end
 
 ---Sets a keybind.
@@ -268,7 +299,9 @@ end
 ---@param on_press fun() The function to run when the bind is triggered
 ---@param bind_info { group: string?, description: string? }? An optional group and description that is displayed in the bind overlay.
 ---
----@overload fun(keybind: pinnacle.input.Keybind)
+---@return pinnacle.input.BindConflict[]? conflicts Existing binds that conflict with this one, or nil if the bind couldn't be set.
+---
+---@overload fun(keybind: pinnacle.input.Keybind): pinnacle.input.BindConflict[]?
 function input.keybind(mods, key, on_press, bind_info)
     ---@type pinnacle.input.Keybind
     local kb
@@ -285,7 +318,7 @@ function input.keybind(mods, key, on_press, bind_info)
         }
     end
 
-    keybind_inner(kb)
+    return keybind_inner(kb)
 end
 
 ---A mousebind.
@@ -298,6 +331,7 @@ end
 ---@field on_release fun()?
 
 ---@param mb pinnacle.input.Mousebind
+---@return pinnacle.input.BindConflict[]?
 local function mousebind_inner(mb)
     local modifs = {}
     local ignore_modifs = {}
@@ -335,6 +369,7 @@ local function mousebind_inner(mb)
     assert(response)
 
     local bind_id = response.bind_id or 0
+    local conflicts = conflicts_from_response(response)
 
     local err = client:pinnacle_input_v1_InputService_MousebindStream({
         bind_id = bind_id,
@@ -366,6 +401,8 @@ local function mousebind_inner(mb)
         log.error(err)
         return
     end
+
+    return conflicts
 end
 
 ---Sets a mousebind.
@@ -404,7 +441,9 @@ end
 ---@param on_press fun() The function to run when the bind is triggered
 ---@param bind_info { group: string?, description: string? }? An optional group and description that will be displayed in the bind overlay.
 ---
----@overload fun(mousebind: pinnacle.input.Mousebind)
+---@return pinnacle.input.BindConflict[]? conflicts Existing binds that conflict with this one, or nil if the bind couldn't be set.
+---
+---@overload fun(mousebind: pinnacle.input.Mousebind): pinnacle.input.BindConflict[]?
 function input.mousebind(mods, button, on_press, bind_info)
     ---@type pinnacle.input.Mousebind
     local mb
@@ -421,7 +460,7 @@ function input.mousebind(mods, button, on_press, bind_info)
         }
     end
 
-    mousebind_inner(mb)
+    return mousebind_inner(mb)
 end
 
 ---Enters the bind layer `layer`, or the default layer if `layer` is nil.
@@ -433,6 +472,45 @@ function input.enter_bind_layer(layer)
     })
 end
 
+local conflict_policy_values = {
+    keep = input_v1.BindConflictPolicy.BIND_CONFLICT_POLICY_KEEP,
+    override = input_v1.BindConflictPolicy.BIND_CONFLICT_POLICY_OVERRIDE,
+    shadow = input_v1.BindConflictPolicy.BIND_CONFLICT_POLICY_SHADOW,
+}
+
+---What happens to existing binds when a conflicting bind is added.
+---
+---- `"keep"`: Keep existing binds. All conflicting binds will trigger. This is the default.
+---- `"override"`: Remove existing binds.
+---- `"shadow"`: Disable existing binds until the new bind is removed.
+---@alias pinnacle.input.ConflictPolicy
+---| "keep"
+---| "override"
+---| "shadow"
+
+---Sets what happens to existing binds on the bind layer `layer` when a conflicting bind is added.
+---
+---This only affects binds added after this is called.
+---
+---#### Example
+---```lua
+--- -- Binds on the "resize" layer replace earlier binds with the same key
+---Input.set_bind_layer_conflict_policy("resize", "override")
+---```
+---
+---@param layer string? The bind layer, or nil for the default layer.
+---@param policy pinnacle.input.ConflictPolicy
+function input.set_bind_layer_conflict_policy(layer, policy)
+    local _, err = client:pinnacle_input_v1_InputService_SetBindLayerConflictPolicy({
+        layer_name = layer,
+        policy = conflict_policy_values[policy],
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
 ---Bind information.
 ---
 ---Mainly used for the bind overlay.
@@ -692,11 +770,241 @@ function input.set_xcursor_size(size)
     end
 end
 
+---Enables or disables sticky keys.
+---
+---With sticky keys, tapping a modifier keeps it pressed until the next
+---non-modifier key is released, so key combinations can be typed one key at a time.
+---Tapping a latched modifier again releases it.
+---
+---@param enabled boolean
+function input.set_sticky_keys(enabled)
+    local _, err = client:pinnacle_input_v1_InputService_SetStickyKeys({
+        enabled = enabled,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
+---Enables slow keys with the given delay, or disables it with `nil`.
+---
+---With slow keys, keys must be held for `delay` milliseconds before they are accepted.
+---Keys released earlier are ignored.
+---
+---#### Example
+---```lua
+---Input.set_slow_keys(300)
+---```
+---
+---@param delay integer? The delay in milliseconds.
+function input.set_slow_keys(delay)
+    local _, err = client:pinnacle_input_v1_InputService_SetSlowKeys({
+        delay_millis = delay,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
+---Options for moving windows by holding them.
+---@class pinnacle.input.HoldToMoveOpts
+---Whether holding a touch on a window moves it.
+---@field touch boolean?
+---The mouse button that moves a window when held on it.
+---@field button pinnacle.input.MouseButton?
+---How long a window must be held before it starts moving, in milliseconds. Defaults to 500.
+---@field hold_time integer?
+
+---Sets up moving windows by holding them, without needing a modifier key.
+---
+---The window must be held without moving much before it starts moving.
+---Windows that use long presses themselves can opt out with
+---`WindowHandle:set_hold_to_move_enabled`.
+---
+---#### Example
+---```lua
+---Input.set_hold_to_move({ touch = true, button = "btn_left" })
+---```
+---
+---@param opts pinnacle.input.HoldToMoveOpts
+function input.set_hold_to_move(opts)
+    local _, err = client:pinnacle_input_v1_InputService_SetHoldToMove({
+        touch = opts.touch or false,
+        button = opts.button and mouse_button_values[opts.button],
+        hold_millis = opts.hold_time,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
+---Where the pointer ends up when it crosses from one output to another.
+---@enum (key) pinnacle.input.PointerCrossing
+local pointer_crossing_values = {
+    ---The pointer keeps its position in the global space.
+    logical = input_v1.PointerCrossing.POINTER_CROSSING_LOGICAL,
+    ---The pointer keeps its relative position along the edge it crossed.
+    ---This lines the pointer up between outputs that are physically the same size
+    ---but have different scales.
+    physical = input_v1.PointerCrossing.POINTER_CROSSING_PHYSICAL,
+}
+
+---Sets where the pointer ends up when it crosses from one output to another.
+---
+---Either way, the pointer slides along the edge of an output instead of jumping
+---onto a neighboring output that doesn't line up with it.
+---
+---#### Example
+---```lua
+---Input.set_pointer_crossing("physical")
+---```
+---
+---@param crossing pinnacle.input.PointerCrossing
+function input.set_pointer_crossing(crossing)
+    local _, err = client:pinnacle_input_v1_InputService_SetPointerCrossing({
+        crossing = pointer_crossing_values[crossing],
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
+---What happens when the pointer hits an edge or corner of an output.
+---
+---Either the name of a built-in action or a function that is called with the output
+---whose edge was hit.
+---@alias pinnacle.input.EdgeAction
+---| "none" Nothing happens. Set for a single output, this disables the action set for all outputs.
+---| "snap_window" Floating windows dropped at the edge snap to the half or quarter of the output next to it.
+---| fun(output: pinnacle.output.OutputHandle)
+
+---Sets what happens when the pointer hits an edge or corner of an output.
+---
+---Edges only count where the pointer can't move onto another output, and actions
+---run once when the pointer first hits them. If `output` is given, the action only
+---applies to that output and takes precedence over actions for all outputs.
+---
+---#### Example
+---```lua
+---Input.set_edge_action("top_right", function(output)
+---    print("Hit the top right corner of " .. output.name)
+---end)
+---Input.set_edge_action("left", "snap_window")
+---```
+---
+---@param edge pinnacle.output.ScreenEdge
+---@param action pinnacle.input.EdgeAction
+---@param output pinnacle.output.OutputHandle?
+function input.set_edge_action(edge, action, output)
+    local output_mod = require("pinnacle.output")
+
+    local api_action
+    if type(action) == "function" then
+        api_action = input_v1.EdgeAction.EDGE_ACTION_CALLBACK
+    elseif action == "snap_window" then
+        api_action = input_v1.EdgeAction.EDGE_ACTION_SNAP_WINDOW
+    else
+        api_action = input_v1.EdgeAction.EDGE_ACTION_NONE
+    end
+
+    local response, err = client:pinnacle_input_v1_InputService_SetEdgeAction({
+        edge = output_mod.screen_edge[edge],
+        action = api_action,
+        output_name = output and output.name,
+    })
+
+    if err then
+        log.error(err)
+        return
+    end
+
+    assert(response)
+
+    if type(action) ~= "function" or not response.action_id then
+        return
+    end
+
+    local err = client:pinnacle_input_v1_InputService_EdgeActionStream({
+        action_id = response.action_id,
+    }, function(response)
+        local success, error = pcall(action, output_mod.handle.new(response.output_name))
+        if not success then
+            log.error("While handling edge action: " .. tostring(error))
+        end
+    end)
+
+    if err then
+        log.error(err)
+    end
+end
+
+---@param key pinnacle.input.Key | string
+---@return pinnacle.input.v1.Keybind
+local function key_to_api(key)
+    if type(key) == "number" then
+        return { key_code = key }
+    else
+        return { xkb_name = key }
+    end
+end
+
+---Remaps a key to another key or to a macro.
+---
+---If `to` is an array of keys, pressing `from` presses and releases each key in order.
+---
+---Remaps apply before binds and clients see the key, so binds should use the key
+---that is remapped to.
+---
+---#### Example
+---```lua
+---Input.remap(Input.key.Caps_Lock, Input.key.Escape)
+---Input.remap(Input.key.F13, { "h", "i" })
+---```
+---
+---@param from pinnacle.input.Key | string The key to remap.
+---@param to pinnacle.input.Key | string | (pinnacle.input.Key | string)[] The key or macro to remap to.
+function input.remap(from, to)
+    local request = {
+        from = key_to_api(from),
+    }
+
+    if type(to) == "table" then
+        local keys = {}
+        for _, key in ipairs(to) do
+            table.insert(keys, key_to_api(key))
+        end
+        request.key_macro = { keys = keys }
+    else
+        request.key = key_to_api(to)
+    end
+
+    local _, err = client:pinnacle_input_v1_InputService_Remap(request)
+
+    if err then
+        log.error(err)
+    end
+end
+
+---Removes all remaps, including ones scoped to devices.
+function input.clear_remaps()
+    local _, err = client:pinnacle_input_v1_InputService_ClearRemaps({})
+
+    if err then
+        log.error(err)
+    end
+end
+
 ---@class pinnacle.input.InputSignal Signals related to input events.
 ---@field device_added fun(device: pinnacle.input.libinput.DeviceHandle)? A new input device was connected.
+---@field accessibility_changed fun(sticky_keys: boolean, slow_keys_delay: integer?)? Sticky keys or slow keys were enabled, disabled, or changed.
 
 local signal_name_to_SignalName = {
     device_added = "InputDeviceAdded",
+    accessibility_changed = "InputAccessibilityChanged",
 }
 
 ---Connects to an input signal.
@@ -252,6 +252,57 @@ function DeviceHandle:map_to_output(output)
     })
 end
 
+---@param key pinnacle.input.Key | string
+---@return pinnacle.input.v1.Keybind
+local function key_to_api(key)
+    if type(key) == "number" then
+        return { key_code = key }
+    else
+        return { xkb_name = key }
+    end
+end
+
+---Remaps a key on this device to another key or to a macro.
+---
+---If `to` is an array of keys, pressing `from` presses and releases each key in order.
+---Remaps scoped to a device take priority over ones set with `Input.remap`.
+---
+---@param from pinnacle.input.Key | string The key to remap.
+---@param to pinnacle.input.Key | string | (pinnacle.input.Key | string)[] The key or macro to remap to.
+function DeviceHandle:remap(from, to)
+    local request = {
+        from = key_to_api(from),
+        device_sysname = self.sysname,
+    }
+
+    if type(to) == "table" then
+        local keys = {}
+        for _, key in ipairs(to) do
+            table.insert(keys, key_to_api(key))
+        end
+        request.key_macro = { keys = keys }
+    else
+        request.key = key_to_api(to)
+    end
+
+    local _, err = client:pinnacle_input_v1_InputService_Remap(request)
+
+    if err then
+        log.error(err)
+    end
+end
+
+---Removes all remaps scoped to this device.
+function DeviceHandle:clear_remaps()
+    local _, err = client:pinnacle_input_v1_InputService_ClearRemaps({
+        device_sysname = self.sysname,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
 ---Maps the absolute input from this device to the corresponding region
 ---in the global space.
 ---
@@ -846,43 +846,12 @@ end
 ---should have the same identifier to allow Pinnacle to remember tile sizing.
 ---@field tree_id integer
 
----Begins managing layout requests from the compositor.
----
----You must call this function to get windows to tile.
----The provided function will be run with the arguments of the layout request.
----It must return a `LayoutResponse` containing a `LayoutNode` that represents
----the root of a layout tree, along with an identifier.
+---@param rpc fun(self: grpc_client.Client, callback: fun(response: table, stream: grpc_client.h2.Stream)): grpc_client.h2.Stream | nil, string | nil
+---@param on_layout fun(args: pinnacle.layout.LayoutArgs): pinnacle.layout.LayoutResponse
 ---
----#### Example
----
----```lua
----local layout_requester = Layout.manage(function(args)
----    local first_tag = args.tags[1]
----    if not first_tag then
----        ---@type pinnacle.layout.LayoutResponse
----        return {
----            root_node = {},
----            tree_id = 0,
----        }
----    end
----    layout_cycler.current_tag = first_tag
----    local root_node = layout_cycler:layout(args.window_count)
----    local tree_id = layout_cycler:current_tree_id()
----
----    ---@type pinnacle.layout.LayoutResponse
----    return {
----        root_node = root_node,
----        tree_id = tree_id,
----    }
----end)
----```
----
----@param on_layout fun(args: pinnacle.layout.LayoutArgs): pinnacle.layout.LayoutResponse A function that receives layout arguments and builds and returns a layout response.
----
----@return pinnacle.layout.LayoutRequester # A requester that allows you to force the compositor to request a layout.
----@nodiscard
-function layout.manage(on_layout)
-    local stream, err = client:pinnacle_layout_v1_LayoutService_Layout(function(response, stream)
+---@return pinnacle.layout.LayoutRequester
+local function handle_layouts(rpc, on_layout)
+    local stream, err = rpc(client, function(response, stream)
         ---@type pinnacle.layout.LayoutArgs
         local args = {
             output = require("pinnacle.output").handle.new(response.output_name),
@@ -892,7 +861,7 @@ function layout.manage(on_layout)
 
         local success, ret = pcall(on_layout, args)
         if not success then
-            log.error("In Layout:manage: " .. tostring(ret))
+            log.error("In layout generator: " .. tostring(ret))
             ret = {
                 root_node = {},
                 tree_id = 0,
@@ -939,4 +908,58 @@ function layout.manage(on_layout)
     return requester
 end
 
+---Begins managing layout requests from the compositor.
+---
+---You must call this function to get windows to tile.
+---The provided function will be run with the arguments of the layout request.
+---It must return a `LayoutResponse` containing a `LayoutNode` that represents
+---the root of a layout tree, along with an identifier.
+---
+---#### Example
+---
+---```lua
+---local layout_requester = Layout.manage(function(args)
+---    local first_tag = args.tags[1]
+---    if not first_tag then
+---        ---@type pinnacle.layout.LayoutResponse
+---        return {
+---            root_node = {},
+---            tree_id = 0,
+---        }
+---    end
+---    layout_cycler.current_tag = first_tag
+---    local root_node = layout_cycler:layout(args.window_count)
+---    local tree_id = layout_cycler:current_tree_id()
+---
+---    ---@type pinnacle.layout.LayoutResponse
+---    return {
+---        root_node = root_node,
+---        tree_id = tree_id,
+---    }
+---end)
+---```
+---
+---@param on_layout fun(args: pinnacle.layout.LayoutArgs): pinnacle.layout.LayoutResponse A function that receives layout arguments and builds and returns a layout response.
+---
+---@return pinnacle.layout.LayoutRequester # A requester that allows you to force the compositor to request a layout.
+---@nodiscard
+function layout.manage(on_layout)
+    return handle_layouts(client.pinnacle_layout_v1_LayoutService_Layout, on_layout)
+end
+
+---Registers this process as an external layout generator.
+---
+---This works like `Layout.manage`, but can be called from any process connected to Pinnacle,
+---not just the config. While this process is connected, its layouts take priority over the
+---config's. Once it disconnects, Pinnacle falls back to the previously registered generator,
+---then to the config, then to a built-in layout.
+---
+---@param on_layout fun(args: pinnacle.layout.LayoutArgs): pinnacle.layout.LayoutResponse A function that receives layout arguments and builds and returns a layout response.
+---
+---@return pinnacle.layout.LayoutRequester # A requester that allows you to force the compositor to request a layout.
+---@nodiscard
+function layout.register_generator(on_layout)
+    return handle_layouts(client.pinnacle_layout_v1_LayoutService_RegisterGenerator, on_layout)
+end
+
 return layout
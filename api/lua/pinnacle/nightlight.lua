@@ -0,0 +1,101 @@
+-- This Source Code Form is subject to the terms of the Mozilla Public
+-- License, v. 2.0. If a copy of the MPL was not distributed with this
+-- file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+local log = require("pinnacle.log")
+local client = require("pinnacle.grpc.client").client
+local nightlight_v1 = require("pinnacle.grpc.defs").pinnacle.nightlight.v1
+
+---The night light.
+---
+---The night light tints outputs to a warmer color temperature at night.
+---It fades in and out instead of switching at once.
+---
+---@class pinnacle.nightlight
+local nightlight = {}
+
+---When the night light turns on or off.
+---
+---Either a local time of day as `"HH:MM"`, or `"sunrise"` or `"sunset"` at the schedule's location.
+---@alias pinnacle.nightlight.ScheduleTime string
+
+---@class pinnacle.nightlight.ScheduleOptions
+---How long fading between temperatures takes, in milliseconds. Defaults to 30 minutes.
+---@field transition integer?
+---Where sunrise and sunset are calculated for, in degrees.
+---
+---Without a location, sunrise is at 6:00 and sunset is at 18:00.
+---@field location { latitude: number, longitude: number }?
+
+---@param time pinnacle.nightlight.ScheduleTime
+---
+---@return pinnacle.nightlight.v1.ScheduleTime?
+local function schedule_time(time)
+    if time == "sunrise" then
+        return { sun_event = nightlight_v1.SunEvent.SUN_EVENT_SUNRISE }
+    elseif time == "sunset" then
+        return { sun_event = nightlight_v1.SunEvent.SUN_EVENT_SUNSET }
+    end
+
+    local hour, minute = time:match("^(%d%d?):(%d%d)$")
+    if not hour then
+        return nil
+    end
+
+    return { minutes = tonumber(hour) * 60 + tonumber(minute) }
+end
+
+---Turns the night light on between `start` and `stop`, tinting outputs to `temperature` Kelvin.
+---
+---`temperature` must be between 1000 and 6500, the temperature of outputs without the night light.
+---
+---#### Example
+---```lua
+---Nightlight.schedule("20:00", "07:00", 4000)
+---
+---Nightlight.schedule("sunset", "sunrise", 3500, {
+---    transition = 3600000,
+---    location = { latitude = 52.52, longitude = 13.40 },
+---})
+---```
+---
+---@param start pinnacle.nightlight.ScheduleTime When the night light turns on.
+---@param stop pinnacle.nightlight.ScheduleTime When the night light turns off.
+---@param temperature integer The color temperature while the night light is on.
+---@param opts pinnacle.nightlight.ScheduleOptions?
+function nightlight.schedule(start, stop, temperature, opts)
+    opts = opts or {}
+
+    local start_time = schedule_time(start)
+    local stop_time = schedule_time(stop)
+
+    if not start_time or not stop_time then
+        log.error("Night light times must be \"HH:MM\", \"sunrise\", or \"sunset\"")
+        return
+    end
+
+    local _, err = client:pinnacle_nightlight_v1_NightlightService_SetSchedule({
+        schedule = {
+            start = start_time,
+            ["end"] = stop_time,
+            temperature = temperature,
+            transition_millis = opts.transition or 1800000,
+            location = opts.location,
+        },
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
+---Turns the night light off.
+function nightlight.disable()
+    local _, err = client:pinnacle_nightlight_v1_NightlightService_SetSchedule({})
+
+    if err then
+        log.error(err)
+    end
+end
+
+return nightlight
@@ -137,6 +137,41 @@ function output.for_each_output(for_each)
     })
 end
 
+---Sets how long output hotplug events must settle before outputs are reconfigured.
+---
+---Docks and KVM switches can connect and disconnect monitors many times in quick
+---succession. Pinnacle waits until no hotplug events have happened for `millis`
+---milliseconds before connecting or disconnecting outputs, so only the final state is applied.
+---
+---The default is 200ms. A delay of 0 handles every hotplug event immediately.
+---
+---@param millis integer The settle delay in milliseconds.
+function output.set_hotplug_settle_delay(millis)
+    local _, err = client:pinnacle_output_v1_OutputService_SetHotplugSettleDelay({
+        millis = millis,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
+---An edge or corner of an output.
+---@enum (key) pinnacle.output.ScreenEdge
+local screen_edge_name_to_code = {
+    top = output_v1.ScreenEdge.SCREEN_EDGE_TOP,
+    bottom = output_v1.ScreenEdge.SCREEN_EDGE_BOTTOM,
+    left = output_v1.ScreenEdge.SCREEN_EDGE_LEFT,
+    right = output_v1.ScreenEdge.SCREEN_EDGE_RIGHT,
+    top_left = output_v1.ScreenEdge.SCREEN_EDGE_TOP_LEFT,
+    top_right = output_v1.ScreenEdge.SCREEN_EDGE_TOP_RIGHT,
+    bottom_left = output_v1.ScreenEdge.SCREEN_EDGE_BOTTOM_LEFT,
+    bottom_right = output_v1.ScreenEdge.SCREEN_EDGE_BOTTOM_RIGHT,
+}
+require("pinnacle.util").make_bijective(screen_edge_name_to_code)
+
+output.screen_edge = screen_edge_name_to_code
+
 local signal_name_to_SignalName = {
     connect = "OutputConnect",
     disconnect = "OutputDisconnect",
@@ -144,7 +179,14 @@ local signal_name_to_SignalName = {
     move = "OutputMove",
     pointer_enter = "OutputPointerEnter",
     pointer_leave = "OutputPointerLeave",
+    pointer_edge = "OutputPointerEdge",
     focused = "OutputFocused",
+    layer_focused = "OutputLayerFocused",
+    layer_unfocused = "OutputLayerUnfocused",
+    orientation_changed = "OutputOrientationChanged",
+    fullscreen_changed = "OutputFullscreenChanged",
+    stacking_changed = "OutputStackingChanged",
+    no_active_tags = "OutputNoActiveTags",
 }
 
 ---@class pinnacle.output.OutputSignal Signals related to output events.
@@ -154,7 +196,14 @@ local signal_name_to_SignalName = {
 ---@field move fun(output: pinnacle.output.OutputHandle, x: integer, y: integer)? An output moved.
 ---@field pointer_enter fun(output: pinnacle.output.OutputHandle)? The pointer entered an output.
 ---@field pointer_leave fun(output: pinnacle.output.OutputHandle)? The pointer left an output.
+---@field pointer_edge fun(output: pinnacle.output.OutputHandle, edge: pinnacle.output.ScreenEdge)? The pointer hit an edge or corner of an output that it can't move past.
 ---@field focused fun(output: pinnacle.output.OutputHandle)? An output was focused.
+---@field layer_focused fun(output: pinnacle.output.OutputHandle, namespace: string)? A layer surface on an output got keyboard focus.
+---@field layer_unfocused fun(output: pinnacle.output.OutputHandle, namespace: string)? A layer surface on an output lost keyboard focus.
+---@field orientation_changed fun(output: pinnacle.output.OutputHandle, transform: pinnacle.output.Transform)? An output with auto-rotate enabled was rotated to match the device's orientation.
+---@field fullscreen_changed fun(output: pinnacle.output.OutputHandle, window: pinnacle.window.WindowHandle?)? The topmost window on an output became fullscreen, or stopped being fullscreen or topmost, in which case `window` is `nil`. Useful for hiding bars.
+---@field stacking_changed fun(output: pinnacle.output.OutputHandle, windows: pinnacle.window.WindowHandle[])? Windows shown on an output were raised, lowered, mapped, or unmapped. `windows` are ordered from bottom to top.
+---@field no_active_tags fun(output: pinnacle.output.OutputHandle)? An output was left with no active tags. This is signaled before the empty output policy is applied.
 
 ---Connects to an output signal.
 ---
@@ -352,6 +401,14 @@ function OutputHandle:set_loc_adj_to(other, alignment)
     self:set_loc(x, y)
 end
 
+---Options for changing an output's mode.
+---
+---@class pinnacle.output.ModeChangeOpts
+---If set, the previous mode is restored after this many milliseconds
+---unless `OutputHandle:confirm_mode_change` is called first.
+---This keeps a mode the monitor can't show from leaving you with a blank screen.
+---@field revert_after_ms integer?
+
 ---Sets this output's mode.
 ---
 ---If `refresh_rate_mhz` is provided, Pinnacle will attempt to use the mode with that refresh rate.
@@ -370,12 +427,14 @@ end
 ---@param width integer The mode's width.
 ---@param height integer The mode's height.
 ---@param refresh_rate_mhz integer? The mode's refresh rate in millihertz, or `nil` to auto-select.
-function OutputHandle:set_mode(width, height, refresh_rate_mhz)
+---@param opts pinnacle.output.ModeChangeOpts?
+function OutputHandle:set_mode(width, height, refresh_rate_mhz, opts)
     local _, err = client:pinnacle_output_v1_OutputService_SetMode({
         output_name = self.name,
         size = { width = width, height = height },
         refresh_rate_mhz = refresh_rate_mhz,
         custom = false,
+        revert_after_ms = opts and opts.revert_after_ms,
     })
 
     if err then
@@ -398,12 +457,14 @@ end
 ---@param width integer A custom width.
 ---@param height integer A custom height.
 ---@param refresh_rate_mhz integer? A custom refresh rate in millihertz, or `nil` to default to 60Hz.
-function OutputHandle:set_custom_mode(width, height, refresh_rate_mhz)
+---@param opts pinnacle.output.ModeChangeOpts?
+function OutputHandle:set_custom_mode(width, height, refresh_rate_mhz, opts)
     local _, err = client:pinnacle_output_v1_OutputService_SetMode({
         output_name = self.name,
         size = { width = width, height = height },
         refresh_rate_mhz = refresh_rate_mhz,
         custom = true,
+        revert_after_ms = opts and opts.revert_after_ms,
     })
 
     if err then
@@ -435,10 +496,14 @@ end
 ---)
 ---```
 ---
+---Unsupported modelines can leave the output blank, so consider passing
+---`opts.revert_after_ms` and confirming the change with `OutputHandle:confirm_mode_change`.
+---
 ---@param modeline string|pinnacle.output.Modeline A modeline table, or a modeline string to feed it into `parse_modeline`.
+---@param opts pinnacle.output.ModeChangeOpts?
 ---
 ---@see pinnacle.util.output.parse_modeline
-function OutputHandle:set_modeline(modeline)
+function OutputHandle:set_modeline(modeline, opts)
     if type(modeline) == "string" then
         local ml, err = require("pinnacle.util").output.parse_modeline(modeline)
         if ml then
@@ -465,6 +530,7 @@ function OutputHandle:set_modeline(modeline)
             hsync = modeline.hsync,
             vsync = modeline.vsync,
         },
+        revert_after_ms = opts and opts.revert_after_ms,
     }
 
     local _, err = client:pinnacle_output_v1_OutputService_SetModeline(request)
@@ -474,6 +540,30 @@ function OutputHandle:set_modeline(modeline)
     end
 end
 
+---Keeps a mode set with `revert_after_ms` from being reverted.
+---
+---#### Example
+---```lua
+---local output = Output.get_focused()
+---output:set_modeline(modeline, { revert_after_ms = 10000 })
+----- Later, once the user says it looks fine
+---output:confirm_mode_change()
+---```
+---
+---@return boolean confirmed Whether there was a mode change waiting to be confirmed.
+function OutputHandle:confirm_mode_change()
+    local response, err = client:pinnacle_output_v1_OutputService_ConfirmModeChange({
+        output_name = self.name,
+    })
+
+    if err then
+        log.error(err)
+        return false
+    end
+
+    return response and response.confirmed or false
+end
+
 ---Sets this output's scaling factor.
 ---
 ---@param scale number The new scale.
@@ -528,6 +618,8 @@ local transform_name_to_code = {
 }
 require("pinnacle.util").make_bijective(transform_name_to_code)
 
+output.transform = transform_name_to_code
+
 ---Sets this output's transform.
 ---
 ---@param transform pinnacle.output.Transform The new transform.
@@ -542,6 +634,124 @@ function OutputHandle:set_transform(transform)
     end
 end
 
+---Sets whether this output is rotated to match the device's orientation.
+---
+---The orientation is read from the accelerometer through iio-sensor-proxy,
+---so this only has an effect on convertibles and tablets.
+---Connect to the `orientation_changed` signal to be notified of rotations.
+---
+---@param auto_rotate boolean
+function OutputHandle:set_auto_rotate(auto_rotate)
+    local _, err = client:pinnacle_output_v1_OutputService_SetAutoRotate({
+        output_name = self.name,
+        auto_rotate = auto_rotate,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
+---Sets the number of bits per color channel this output is rendered and scanned out with.
+---
+---10 bits reduce banding in gradients. If the monitor or GPU can't do 10 bits,
+---the output falls back to 8; check `color_depth` for the depth actually used.
+---
+---Outputs on the same GPU share a color depth, so this changes it for all of them.
+---They are briefly disconnected while the change is applied.
+---
+---#### Example
+---```lua
+---local err = Output.get_focused():set_color_depth(10)
+---if err then
+---    print("Failed to set color depth: " .. err)
+---end
+---```
+---
+---@param color_depth 8 | 10
+---
+---@return string | nil error An error if the color depth couldn't be changed.
+function OutputHandle:set_color_depth(color_depth)
+    local response, err = client:pinnacle_output_v1_OutputService_SetColorDepth({
+        output_name = self.name,
+        color_depth = color_depth,
+    })
+
+    if err then
+        log.error(err)
+        return err
+    end
+
+    return response and response.error
+end
+
+---Sets whether sRGB content on this output is converted to the monitor's primaries.
+---
+---Wide-gamut monitors show sRGB content oversaturated. With sRGB emulation, colors
+---are mapped to the primaries the monitor's EDID advertises so they look as intended.
+---
+---Fails if the monitor doesn't advertise its primaries. Has no effect while the output
+---is in HDR mode, which already maps colors to the monitor's gamut.
+---
+---#### Example
+---```lua
+---local err = Output.get_focused():set_srgb_emulation(true)
+---if err then
+---    print("Failed to enable sRGB emulation: " .. err)
+---end
+---```
+---
+---@param srgb_emulation boolean
+---
+---@return string | nil error An error if sRGB emulation couldn't be enabled.
+function OutputHandle:set_srgb_emulation(srgb_emulation)
+    local response, err = client:pinnacle_output_v1_OutputService_SetSrgbEmulation({
+        output_name = self.name,
+        srgb_emulation = srgb_emulation,
+    })
+
+    if err then
+        log.error(err)
+        return err
+    end
+
+    return response and response.error
+end
+
+---Sets whether this output is in HDR mode.
+---
+---In HDR mode, the monitor is switched to the BT.2020 colorspace and all content
+---is encoded with the PQ transfer function. Content keeps looking the same,
+---with white at the reference luminance for SDR content.
+---
+---#### Example
+---```lua
+---local output = Output.get_focused()
+---if output:hdr_capable() then
+---    local err = output:set_hdr(true)
+---    if err then
+---        print("Failed to enable HDR: " .. err)
+---    end
+---end
+---```
+---
+---@param hdr boolean
+---
+---@return string | nil error An error if the output doesn't support HDR or couldn't be switched.
+function OutputHandle:set_hdr(hdr)
+    local response, err = client:pinnacle_output_v1_OutputService_SetHdr({
+        output_name = self.name,
+        hdr = hdr,
+    })
+
+    if err then
+        log.error(err)
+        return err
+    end
+
+    return response and response.error
+end
+
 local set_or_toggle = {
     SET = require("pinnacle.grpc.defs").pinnacle.util.v1.SetOrToggle.SET_OR_TOGGLE_SET,
     [true] = require("pinnacle.grpc.defs").pinnacle.util.v1.SetOrToggle.SET_OR_TOGGLE_SET,
@@ -603,6 +813,84 @@ function OutputHandle:set_vrr(vrr)
     end
 end
 
+---A color filter applied to everything on an output.
+---@enum (key) pinnacle.output.ColorFilter
+local color_filter_to_value = {
+    ---No filter.
+    none = output_v1.ColorFilter.COLOR_FILTER_NONE,
+    ---Inverts colors.
+    invert = output_v1.ColorFilter.COLOR_FILTER_INVERT,
+    ---Removes all color.
+    grayscale = output_v1.ColorFilter.COLOR_FILTER_GRAYSCALE,
+    ---Corrects colors for deuteranopia (green-weak red-green color blindness).
+    deuteranopia_correction = output_v1.ColorFilter.COLOR_FILTER_DEUTERANOPIA_CORRECTION,
+    ---Corrects colors for protanopia (red-weak red-green color blindness).
+    protanopia_correction = output_v1.ColorFilter.COLOR_FILTER_PROTANOPIA_CORRECTION,
+}
+
+---Sets the color filter applied to everything on this output.
+---
+---#### Example
+---```lua
+---Output.get_by_name("eDP-1"):set_color_filter("grayscale")
+---```
+---
+---@param filter pinnacle.output.ColorFilter
+function OutputHandle:set_color_filter(filter)
+    local _, err = client:pinnacle_output_v1_OutputService_SetColorFilter({
+        output_name = self.name,
+        filter = color_filter_to_value[filter],
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
+---Sets the xcursor theme used on this output.
+---
+---This overrides the theme set with `input.set_xcursor_theme`.
+---Pass an empty string to use that theme again.
+---
+---@param theme string The name of the xcursor theme.
+function OutputHandle:set_xcursor_theme(theme)
+    local _, err = client:pinnacle_output_v1_OutputService_SetXcursor({
+        output_name = self.name,
+        theme = theme,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
+---Sets the xcursor size used on this output.
+---
+---This overrides the size set with `input.set_xcursor_size`.
+---Pass 0 to use that size again.
+---
+---@param size integer The size of the cursor.
+function OutputHandle:set_xcursor_size(size)
+    local _, err = client:pinnacle_output_v1_OutputService_SetXcursor({
+        output_name = self.name,
+        size = size,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
+---Sets what happens when the pointer hits an edge or corner of this output.
+---
+---This takes precedence over the action set for all outputs with `Input.set_edge_action`.
+---
+---@param edge pinnacle.output.ScreenEdge
+---@param action pinnacle.input.EdgeAction
+function OutputHandle:set_edge_action(edge, action)
+    require("pinnacle.input").set_edge_action(edge, action, self)
+end
+
 ---Focuses this output.
 function OutputHandle:focus()
     local _, err = client:pinnacle_output_v1_OutputService_Focus({
@@ -653,6 +941,83 @@ function OutputHandle:serial()
     return response and response.serial or ""
 end
 
+---Chromaticity coordinates in the CIE 1931 color space.
+---@class pinnacle.output.Chromaticity
+---@field x number
+---@field y number
+
+---The color primaries and white point a monitor advertises.
+---@class pinnacle.output.ColorPrimaries
+---@field red pinnacle.output.Chromaticity
+---@field green pinnacle.output.Chromaticity
+---@field blue pinnacle.output.Chromaticity
+---The default white point, if advertised.
+---@field white pinnacle.output.Chromaticity?
+
+---HDR static metadata a monitor advertises. Luminances are in cd/m².
+---@class pinnacle.output.HdrMetadata
+---@field max_luminance number?
+---@field max_frame_avg_luminance number?
+---@field min_luminance number?
+---Whether the SMPTE ST 2084 (PQ) transfer function is supported.
+---@field pq boolean
+---Whether the hybrid log-gamma transfer function is supported.
+---@field hlg boolean
+
+---Properties parsed from a monitor's EDID.
+---@class pinnacle.output.Edid
+---@field manufacture_year integer?
+---@field manufacture_week integer?
+---The monitor's color primaries, if advertised.
+---@field color_primaries pinnacle.output.ColorPrimaries?
+---The monitor's HDR metadata, or `nil` if it doesn't support HDR.
+---@field hdr_metadata pinnacle.output.HdrMetadata?
+
+---Gets the properties parsed from this output's EDID.
+---
+---#### Example
+---```lua
+---local edid = Output.get_focused():edid()
+---if edid and edid.hdr_metadata then
+---    print("HDR up to " .. tostring(edid.hdr_metadata.max_luminance) .. " nits")
+---end
+---```
+---
+---@return pinnacle.output.Edid | nil # The EDID properties, or `nil` if the output has no EDID.
+function OutputHandle:edid()
+    local response, err =
+        client:pinnacle_output_v1_OutputService_GetInfo({ output_name = self.name })
+
+    if err then
+        log.error(err)
+        return nil
+    end
+
+    local edid = response and response.edid
+    if not edid then
+        return nil
+    end
+
+    local hdr_metadata = edid.hdr_metadata
+    if hdr_metadata then
+        hdr_metadata = {
+            max_luminance = hdr_metadata.max_luminance,
+            max_frame_avg_luminance = hdr_metadata.max_frame_avg_luminance,
+            min_luminance = hdr_metadata.min_luminance,
+            pq = hdr_metadata.pq or false,
+            hlg = hdr_metadata.hlg or false,
+        }
+    end
+
+    ---@type pinnacle.output.Edid
+    return {
+        manufacture_year = edid.manufacture_year,
+        manufacture_week = edid.manufacture_week,
+        color_primaries = edid.color_primaries --[[@as pinnacle.output.ColorPrimaries?]],
+        hdr_metadata = hdr_metadata,
+    }
+end
+
 ---Gets this output's location in the global space.
 ---
 ---@return { x: integer, y: integer }? # The output's location, or `nil` if it is not enabled or doesn't exist.
@@ -888,6 +1253,123 @@ function OutputHandle:powered()
     return response and response.powered or false
 end
 
+---Gets the number of bits per color channel this output is scanned out with.
+---
+---@return integer
+function OutputHandle:color_depth()
+    local response, err =
+        client:pinnacle_output_v1_OutputService_GetColorDepth({ output_name = self.name })
+
+    return response and response.color_depth or 8
+end
+
+---Gets whether sRGB emulation is enabled on this output.
+---
+---@return boolean
+function OutputHandle:srgb_emulation()
+    local response, err =
+        client:pinnacle_output_v1_OutputService_GetSrgbEmulation({ output_name = self.name })
+
+    return response and response.srgb_emulation or false
+end
+
+---Gets whether this output's monitor and connector support HDR.
+---
+---@return boolean
+function OutputHandle:hdr_capable()
+    local response, err =
+        client:pinnacle_output_v1_OutputService_GetHdr({ output_name = self.name })
+
+    return response and response.capable or false
+end
+
+---Gets whether this output is in HDR mode.
+---
+---@return boolean
+function OutputHandle:hdr_enabled()
+    local response, err =
+        client:pinnacle_output_v1_OutputService_GetHdr({ output_name = self.name })
+
+    return response and response.enabled or false
+end
+
+---Rendering statistics for an output.
+---@class pinnacle.output.RenderStats
+---The number of frames that were rendered.
+---@field frames_rendered integer
+---The number of renders skipped because nothing changed.
+---@field frames_skipped_no_damage integer
+---The number of renders skipped because the output was powered off or blanked while locked.
+---@field frames_skipped_suspended integer
+
+---Gets rendering statistics for this output.
+---
+---These can be used to see how often rendering is skipped.
+---
+---@return pinnacle.output.RenderStats
+function OutputHandle:render_stats()
+    local response, err =
+        client:pinnacle_output_v1_OutputService_GetRenderStats({ output_name = self.name })
+
+    ---@type pinnacle.output.RenderStats
+    local stats = {
+        frames_rendered = response and response.frames_rendered or 0,
+        frames_skipped_no_damage = response and response.frames_skipped_no_damage or 0,
+        frames_skipped_suspended = response and response.frames_skipped_suspended or 0,
+    }
+
+    return stats
+end
+
+---Changes to an output's properties. Only the properties that changed are set.
+---@class pinnacle.output.PropertiesChange
+---@field loc { x: integer, y: integer }?
+---@field logical_size { width: integer, height: integer }?
+---@field scale number?
+---@field focused boolean?
+---@field enabled boolean?
+---@field powered boolean?
+---@field cleared { loc: true?, logical_size: true? } Properties that were set and now aren't, because the output was unmapped.
+
+---Watches this output's properties, calling `on_change` when they change.
+---
+---`on_change` is first called with every property set, then with only the properties
+---that changed.
+---
+---Watching ends when the output is disconnected.
+---
+---@param on_change fun(change: pinnacle.output.PropertiesChange)
+function OutputHandle:watch(on_change)
+    local err = client:pinnacle_output_v1_OutputService_WatchProperties({
+        output_name = self.name,
+    }, function(response)
+        ---@type pinnacle.output.PropertiesChange
+        local change = {
+            loc = response.loc,
+            logical_size = response.logical_size,
+            scale = response.scale,
+            focused = response.focused,
+            enabled = response.enabled,
+            powered = response.powered,
+            cleared = {},
+        }
+
+        for _, property in ipairs(response.cleared or {}) do
+            if property == output_v1.ClearableProperty.CLEARABLE_PROPERTY_LOC then
+                change.cleared.loc = true
+            elseif property == output_v1.ClearableProperty.CLEARABLE_PROPERTY_LOGICAL_SIZE then
+                change.cleared.logical_size = true
+            end
+        end
+
+        on_change(change)
+    end)
+
+    if err then
+        log.error(err)
+    end
+end
+
 ---Gets this output's keyboard focus stack.
 ---
 ---This includes *all* windows on the output, even those on inactive tags.
@@ -940,6 +1422,26 @@ function OutputHandle:keyboard_focus_stack_visible()
     return keyboard_focus_stack_visible
 end
 
+---Gets the windows shown on this output, from bottom to top.
+---
+---Windows on inactive tags aren't shown, so they aren't included.
+---The last window is the one visually on top. Windows can be reordered with
+---`WindowHandle:raise` and `WindowHandle:lower`.
+---
+---@return pinnacle.window.WindowHandle[]
+function OutputHandle:stacking_order()
+    local response, err =
+        client:pinnacle_output_v1_OutputService_GetStackingOrder({ output_name = self.name })
+
+    if err then
+        log.error(err)
+    end
+
+    local window_ids = response and response.window_ids or {}
+
+    return require("pinnacle.window").handle.new_from_table(window_ids)
+end
+
 ---Gets all outputs in the provided direction, sorted closest to farthest.
 ---
 ---@param direction "left" | "right" | "up" | "down"
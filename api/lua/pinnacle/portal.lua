@@ -0,0 +1,127 @@
+-- This Source Code Form is subject to the terms of the Mozilla Public
+-- License, v. 2.0. If a copy of the MPL was not distributed with this
+-- file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+local log = require("pinnacle.log")
+local client = require("pinnacle.grpc.client").client
+
+---xdg-desktop-portal integration.
+---
+---Pinnacle implements the GlobalShortcuts portal, which lets apps bind shortcuts
+---that work while they aren't focused. Apps can only bind shortcuts after the
+---config allows them to with `Portal.on_global_shortcuts_request`.
+---
+---@class pinnacle.portal
+local portal = {}
+
+---A shortcut an app wants to bind.
+---
+---@class pinnacle.portal.GlobalShortcut
+---@field id string The app-defined id of the shortcut.
+---@field description string A user-readable description of what the shortcut does.
+---@field preferred_trigger string? The trigger the app would like, e.g. "CTRL+SHIFT+a".
+
+---A request from an app to bind global shortcuts.
+---
+---The request must be answered with `allow` or `deny`.
+---
+---@class pinnacle.portal.GlobalShortcutsRequest
+---The app id of the app binding the shortcuts. This is empty for apps that aren't sandboxed.
+---@field app_id string
+---The shortcuts the app wants to bind.
+---@field shortcuts pinnacle.portal.GlobalShortcut[]
+---@field private request_id integer
+---@field private stream grpc_client.h2.Stream
+---@field private answered boolean
+local GlobalShortcutsRequest = {}
+
+---@param allow boolean
+function GlobalShortcutsRequest:decide(allow)
+    if self.answered then
+        return
+    end
+    self.answered = true
+
+    local chunk = require("pinnacle.grpc.protobuf").encode(
+        "pinnacle.portal.v1.GlobalShortcutsPermissionRequest",
+        {
+            decision = {
+                request_id = self.request_id,
+                allow = allow,
+            },
+        }
+    )
+
+    local success, err = pcall(self.stream.write_chunk, self.stream, chunk)
+
+    if not success then
+        print("error sending to stream:", err)
+    end
+end
+
+---Allows the app to bind the shortcuts.
+---
+---Sandboxed apps that are allowed once won't be asked about again until Pinnacle restarts.
+function GlobalShortcutsRequest:allow()
+    self:decide(true)
+end
+
+---Denies the app from binding the shortcuts.
+function GlobalShortcutsRequest:deny()
+    self:decide(false)
+end
+
+---Runs a function whenever an app wants to bind global shortcuts.
+---
+---If this is never called, all apps are denied.
+---
+---#### Example
+---
+---```lua
+---Portal.on_global_shortcuts_request(function(request)
+---    if request.app_id == "com.obsproject.Studio" then
+---        request:allow()
+---    else
+---        request:deny()
+---    end
+---end)
+---```
+---
+---@param on_request fun(request: pinnacle.portal.GlobalShortcutsRequest)
+function portal.on_global_shortcuts_request(on_request)
+    local _stream, err = client:pinnacle_portal_v1_PortalService_GlobalShortcutsPermission(
+        function(response, stream)
+            local new_request = response.new_request
+            if not new_request then
+                return
+            end
+
+            ---@type pinnacle.portal.GlobalShortcut[]
+            local shortcuts = {}
+            for _, shortcut in ipairs(new_request.shortcuts or {}) do
+                table.insert(shortcuts, {
+                    id = shortcut.id or "",
+                    description = shortcut.description or "",
+                    preferred_trigger = shortcut.preferred_trigger,
+                })
+            end
+
+            local request = setmetatable({
+                app_id = new_request.app_id or "",
+                shortcuts = shortcuts,
+                request_id = new_request.request_id,
+                stream = stream,
+                answered = false,
+            }, { __index = GlobalShortcutsRequest })
+
+            on_request(request)
+        end
+    )
+
+    if err then
+        log.error("failed to start bidir stream")
+        os.exit(1)
+    end
+end
+
+return portal
@@ -0,0 +1,94 @@
+-- This Source Code Form is subject to the terms of the Mozilla Public
+-- License, v. 2.0. If a copy of the MPL was not distributed with this
+-- file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+local log = require("pinnacle.log")
+local client = require("pinnacle.grpc.client").client
+
+---Battery and power supply status.
+---
+---The status comes from UPower. Without UPower, the system is reported as
+---running on external power with no battery.
+---
+---@class pinnacle.power
+local power = {}
+
+---@class pinnacle.power.PowerStatus
+---Whether the system is running on battery power.
+---@field on_battery boolean
+---Whether the battery is charging.
+---@field charging boolean
+---The battery's charge from 0 to 100, or nil if there is no battery.
+---@field percentage number?
+---Whether the battery is low.
+---@field low_battery boolean
+
+---Gets the battery and power supply status of the system.
+---
+---Use `Power.connect_signal` to be notified when it changes.
+---
+---@return pinnacle.power.PowerStatus
+function power.status()
+    local response, err = client:pinnacle_power_v1_PowerService_GetStatus({})
+
+    if err then
+        log.error(err)
+    end
+
+    ---@type pinnacle.power.PowerStatus
+    local status = {
+        on_battery = response and response.on_battery or false,
+        charging = response and response.charging or false,
+        percentage = response and response.percentage,
+        low_battery = response and response.low_battery or false,
+    }
+
+    return status
+end
+
+---@class pinnacle.power.PowerSignal Signals related to the battery and power supply.
+---@field on_battery fun(on_battery: boolean)? The system switched between battery and external power.
+---@field charging fun(charging: boolean)? The battery started or stopped charging.
+---@field low_battery fun(percentage: number)? The battery became low.
+
+local signal_name_to_SignalName = {
+    on_battery = "PowerOnBattery",
+    charging = "PowerCharging",
+    low_battery = "PowerLowBattery",
+}
+
+---Connects to a power signal.
+---
+---`signals` is a table containing the signal(s) you want to connect to along with
+---a corresponding callback that will be called when the signal is signalled.
+---
+---This function returns a table of signal handles with each handle stored at the same key used
+---to connect to the signal. See `SignalHandles` for more information.
+---
+---# Example
+---```lua
+---Power.connect_signal({
+---    on_battery = function(on_battery)
+---        print(on_battery and "Unplugged" or "Plugged in")
+---    end
+---})
+---```
+---@param signals pinnacle.power.PowerSignal The signal you want to connect to
+---
+---@return pinnacle.signal.SignalHandles signal_handles Handles to every signal you connected to wrapped in a table, with keys being the same as the connected signal.
+---
+---@see pinnacle.signal.SignalHandles.disconnect_all - To disconnect from these signals
+function power.connect_signal(signals)
+    ---@diagnostic disable-next-line: invisible
+    local handles = require("pinnacle.signal").handles.new()
+
+    for signal, callback in pairs(signals) do
+        local handle =
+            require("pinnacle.signal").add_callback(signal_name_to_SignalName[signal], callback)
+        handles[signal] = handle
+    end
+
+    return handles
+end
+
+return power
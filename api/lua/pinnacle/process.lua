@@ -64,6 +64,44 @@ function child_module.new_child(child)
     return child
 end
 
+---Receives the standard IO of a process Pinnacle spawned.
+---
+---@param data pinnacle.process.v1.SpawnData
+---
+---@return pinnacle.process.Child
+local function child_from_spawn_data(data)
+    local fd_socket = require("cqueues.socket").connect({
+        path = data.fd_socket_path,
+    })
+
+    local stdin, stdout, stderr
+
+    if data.has_stdin then
+        local _, sock_stdin, err = fd_socket:recvfd()
+        stdin = sock_stdin
+    end
+    if data.has_stdout then
+        local _, sock_stdout, err = fd_socket:recvfd()
+        stdout = sock_stdout
+    end
+    if data.has_stderr then
+        local _, sock_stderr, err = fd_socket:recvfd()
+        stderr = sock_stderr
+    end
+
+    fd_socket:close()
+
+    ---@type pinnacle.process.Child
+    local child = {
+        pid = data.pid,
+        stdin = stdin,
+        stdout = stdout,
+        stderr = stderr,
+    }
+
+    return child_module.new_child(child)
+end
+
 ---A command representing a to-be-spawned process.
 ---@class pinnacle.process.Command
 ---@field private cmd string | string[]
@@ -124,45 +162,11 @@ function Command:spawn()
 
     ---@cast response pinnacle.process.v1.SpawnResponse
 
-    if not response then
+    if not response or not response.spawn_data then
         return nil
     end
 
-    local data = response.spawn_data
-    if not data then
-        return nil
-    end
-
-    local fd_socket = require("cqueues.socket").connect({
-        path = data.fd_socket_path,
-    })
-
-    local stdin, stdout, stderr
-
-    if data.has_stdin then
-        local _, sock_stdin, err = fd_socket:recvfd()
-        stdin = sock_stdin
-    end
-    if data.has_stdout then
-        local _, sock_stdout, err = fd_socket:recvfd()
-        stdout = sock_stdout
-    end
-    if data.has_stderr then
-        local _, sock_stderr, err = fd_socket:recvfd()
-        stderr = sock_stderr
-    end
-
-    fd_socket:close()
-
-    ---@type pinnacle.process.Child
-    local child = {
-        pid = data.pid,
-        stdin = stdin,
-        stdout = stdout,
-        stderr = stderr,
-    }
-
-    return child_module.new_child(child)
+    return child_from_spawn_data(response.spawn_data)
 end
 
 ---Waits for this child process to exit.
@@ -322,6 +326,191 @@ function process.spawn_unique(...)
         :spawn()
 end
 
+---Focuses a window whose app id matches the regex `app_id`, or spawns `cmd` if there is none.
+---
+---If several windows match, the most recently focused one is used. Its tag and output
+---are switched to if needed. Unlike assembling this from separate calls, this happens
+---in one step in Pinnacle, so pressing a keybind twice quickly won't spawn two processes.
+---
+---#### Example
+---```lua
+---Process.run_or_raise("^firefox$", "firefox")
+---```
+---
+---@param app_id string A regex matched against window app ids.
+---@param cmd string | string[] The command to spawn if no window matches.
+---
+---@return pinnacle.window.WindowHandle? window The window that was focused, if one matched.
+---@return pinnacle.process.Child? child The spawned process, if no window matched.
+---@return string? error Why `app_id` couldn't be matched if it's an invalid regex.
+function process.run_or_raise(app_id, cmd)
+    local response, err = client:pinnacle_process_v1_ProcessService_RunOrRaise({
+        app_id = app_id,
+        cmd = type(cmd) == "string" and { cmd } or cmd,
+    })
+
+    if err then
+        log.error(err)
+        return nil, nil, err
+    end
+
+    if not response then
+        return nil, nil, nil
+    end
+
+    if response.error then
+        return nil, nil, response.error
+    end
+
+    if response.window_id then
+        return require("pinnacle.window").handle.new(response.window_id), nil, nil
+    end
+
+    if response.spawn_data then
+        return nil, child_from_spawn_data(response.spawn_data), nil
+    end
+
+    return nil, nil, nil
+end
+
+---Creates a `Command` from the given options.
+---
+---A `Command` represents a to-be-spawned process.
+---
+---@param cmd pinnacle.process.CommandOpts Options for the command.
+---
+---@return pinnacle.process.Command # An object that allows you to spawn this command.
+---@nodiscard
+function process.command(cmd)
+    setmetatable(cmd, { __index = Command })
+    return cmd --[[@as pinnacle.process.Command]]
+end
+
+---Adds an environment variable that all newly spawned processes will inherit.
+---
+---@param key string The environment variable's key.
+---@param value string The environment variable's value.
+function process.set_env(key, value)
+    local _, err = client:pinnacle_process_v1_ProcessService_SetEnv({
+        key = key,
+        value = value,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
+return process
+        .command({
+            cmd = cmd,
+        })
+        :spawn()
+end
+
+---Spawns a process if it hasn't been spawned before,
+---returning a `Child` with the process's standard IO if successful.
+---
+---Receives the arguments of the command to be spawned, either as varargs or as a table.
+---
+---For more control over the spawn, use `Process.command` instead.
+---
+---@param ... string The arguments of the command.
+---
+---@overload fun(cmd: string[]): pinnacle.process.Child?
+---
+---@return pinnacle.process.Child? # A child with the process's standard IO, or `nil` if the process failed to spawn or doesn't exist.
+---
+---@see pinnacle.process.Process.command A way to spawn processes with more control.
+function process.spawn_once(...)
+    local cmd = { ... }
+    if cmd[1] and type(cmd[1]) == "table" then
+        cmd = cmd[1]
+    end
+
+    return process
+        .command({
+            cmd = cmd,
+            once = true,
+        })
+        :spawn()
+end
+
+---Spawns a process if it isn't already running,
+---returning a `Child` with the process's standard IO if successful.
+---
+---Receives the arguments of the command to be spawned, either as varargs or as a table.
+---
+---For more control over the spawn, use `Process.command` instead.
+---
+---@param ... string The arguments of the command.
+---
+---@overload fun(cmd: string[]): pinnacle.process.Child?
+---
+---@return pinnacle.process.Child? # A child with the process's standard IO, or `nil` if the process failed to spawn or doesn't exist.
+---
+---@see pinnacle.process.Process.command A way to spawn processes with more control.
+function process.spawn_unique(...)
+    local cmd = { ... }
+    if cmd[1] and type(cmd[1]) == "table" then
+        cmd = cmd[1]
+    end
+
+    return process
+        .command({
+            cmd = cmd,
+            unique = true,
+        })
+        :spawn()
+end
+
+---Focuses a window whose app id matches the regex `app_id`, or spawns `cmd` if there is none.
+---
+---If several windows match, the most recently focused one is used. Its tag and output
+---are switched to if needed. Unlike assembling this from separate calls, this happens
+---in one step in Pinnacle, so pressing a keybind twice quickly won't spawn two processes.
+---
+---#### Example
+---```lua
+---Process.run_or_raise("^firefox$", "firefox")
+---```
+---
+---@param app_id string A regex matched against window app ids.
+---@param cmd string | string[] The command to spawn if no window matches.
+---
+---@return pinnacle.window.WindowHandle? window The window that was focused, if one matched.
+---@return pinnacle.process.Child? child The spawned process, if no window matched.
+---@return string? error Why `app_id` couldn't be matched if it's an invalid regex.
+function process.run_or_raise(app_id, cmd)
+    local response, err = client:pinnacle_process_v1_ProcessService_RunOrRaise({
+        app_id = app_id,
+        cmd = type(cmd) == "string" and { cmd } or cmd,
+    })
+
+    if err then
+        log.error(err)
+        return nil, nil, err
+    end
+
+    if not response then
+        return nil, nil, nil
+    end
+
+    if response.error then
+        return nil, nil, response.error
+    end
+
+    if response.window_id then
+        return require("pinnacle.window").handle.new(response.window_id), nil, nil
+    end
+
+    if response.spawn_data then
+        return nil, child_from_spawn_data(response.spawn_data), nil
+    end
+
+    return nil, nil, nil
+end
+
 ---Creates a `Command` from the given options.
 ---
 ---A `Command` represents a to-be-spawned process.
@@ -350,4 +539,30 @@ function process.set_env(key, value)
     end
 end
 
+---Sets an environment variable everywhere it matters.
+---
+---Unlike `Process.set_env`, this updates Pinnacle's own environment as well as the
+---systemd and D-Bus activation environments, so services and D-Bus-activated
+---programs started after this call also see the new value.
+---
+---Passing `nil` as the value unsets the variable.
+---
+---#### Example
+---```lua
+---Process.set_global_env("QT_QPA_PLATFORMTHEME", "qt6ct")
+---```
+---
+---@param key string The environment variable's key.
+---@param value string? The environment variable's value, or `nil` to unset it.
+function process.set_global_env(key, value)
+    local _, err = client:pinnacle_process_v1_ProcessService_SetGlobalEnv({
+        key = key,
+        value = value,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
 return process
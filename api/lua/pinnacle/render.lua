@@ -46,4 +46,100 @@ function render.set_downscale_filter(filter)
     end
 end
 
+---What the inactive effect applies to.
+---@enum (key) pinnacle.render.InactiveEffectTarget
+local inactive_effect_target_to_value = {
+    ---Windows on outputs that don't have focus.
+    unfocused_outputs = render_v1.InactiveEffectTarget.INACTIVE_EFFECT_TARGET_UNFOCUSED_OUTPUTS,
+    ---Every window except the focused one.
+    unfocused_windows = render_v1.InactiveEffectTarget.INACTIVE_EFFECT_TARGET_UNFOCUSED_WINDOWS,
+}
+
+---@class pinnacle.render.InactiveEffect
+---What the effect applies to.
+---@field target pinnacle.render.InactiveEffectTarget
+---How much to darken windows, from 0.0 to 1.0.
+---@field dim number?
+---How much to desaturate windows, from 0.0 to 1.0.
+---@field desaturate number?
+
+---Dims and desaturates inactive windows.
+---
+---#### Example
+---```lua
+----- Gray out windows on outputs that aren't focused
+---Render.set_inactive_effect({ target = "unfocused_outputs", dim = 0.2, desaturate = 1.0 })
+---```
+---
+---@param effect pinnacle.render.InactiveEffect
+function render.set_inactive_effect(effect)
+    local _, err = client:pinnacle_render_v1_RenderService_SetInactiveEffect({
+        enabled = true,
+        target = inactive_effect_target_to_value[effect.target],
+        dim = effect.dim or 0.0,
+        desaturate = effect.desaturate or 0.0,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
+---Disables the inactive effect set with `set_inactive_effect`.
+function render.disable_inactive_effect()
+    local _, err = client:pinnacle_render_v1_RenderService_SetInactiveEffect({
+        enabled = false,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
+---Sets whether fullscreen windows that don't fill their output are letterboxed.
+---
+---Letterboxed windows are centered on their output with black bars around them
+---instead of leaving the desktop visible, which is useful for games running at a
+---different resolution or aspect ratio than the output.
+---
+---#### Example
+---```lua
+---Render.set_letterbox_fullscreen(true)
+---```
+---
+---@param letterbox boolean
+function render.set_letterbox_fullscreen(letterbox)
+    local _, err = client:pinnacle_render_v1_RenderService_SetLetterboxFullscreen({
+        letterbox = letterbox,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
+---Sets how long closed tiled windows are held in place while the layout fills their space.
+---
+---When a tiled window closes, Pinnacle keeps drawing what it last looked like until
+---the windows taking over its space have resized, so the space never flashes empty.
+---`millis` caps how long that takes, for example when no layout ever comes.
+---
+---The default is 2000ms. A hold of 0 removes closed windows immediately.
+---
+---#### Example
+---```lua
+---Render.set_close_snapshot_hold(500)
+---```
+---
+---@param millis integer The hold in milliseconds.
+function render.set_close_snapshot_hold(millis)
+    local _, err = client:pinnacle_render_v1_RenderService_SetCloseSnapshotHold({
+        millis = millis,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
 return render
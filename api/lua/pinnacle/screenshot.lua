@@ -0,0 +1,86 @@
+-- This Source Code Form is subject to the terms of the Mozilla Public
+-- License, v. 2.0. If a copy of the MPL was not distributed with this
+-- file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+local log = require("pinnacle.log")
+local client = require("pinnacle.grpc.client").client
+
+---Screenshots.
+---
+---@class pinnacle.screenshot
+local screenshot = {}
+
+---A captured region of an output.
+---
+---@class pinnacle.screenshot.Screenshot
+---@field output pinnacle.output.OutputHandle The output the region was captured from.
+---@field region { x: integer, y: integer, width: integer, height: integer } The captured region in the global space.
+---@field size { width: integer, height: integer } The size of the captured image in pixels.
+---@field rgba string The captured pixels as 8-bit RGBA, row by row.
+
+---Lets the user select a region of the screen, then captures it.
+---
+---This dims every output and lets the user drag out a rectangle with the left mouse
+---button. Pressing any other button or Escape cancels the selection.
+---The region is clamped to the output the drag started on.
+---
+---If `save_to` is provided, the region is also saved there as a PNG.
+---
+---This blocks until the selection is finished. The request deadline doesn't apply,
+---as the user may take a while.
+---
+---Selecting a region fails while the session is locked.
+---
+---#### Example
+---```lua
+---local shot, err = Screenshot.select_region("~/screenshot.png")
+---if shot then
+---    print("Captured " .. shot.region.width .. "x" .. shot.region.height)
+---elseif err then
+---    print("Failed to take a screenshot: " .. err)
+---end
+---```
+---
+---@param save_to string? A path to save the captured region to as a PNG.
+---
+---@return pinnacle.screenshot.Screenshot? screenshot The captured region, or `nil` if the selection was cancelled or failed.
+---@return string? error Why the selection couldn't be started, captured, or saved.
+function screenshot.select_region(save_to)
+    local response, err = client:pinnacle_screenshot_v1_ScreenshotService_SelectRegion({
+        path = save_to,
+    })
+
+    if err then
+        log.error(err)
+        return nil, err
+    end
+
+    ---@cast response pinnacle.screenshot.v1.SelectRegionResponse
+
+    if not response.output_name then
+        return nil
+    end
+
+    local region = response.region or {}
+    local loc = region.loc or {}
+    local size = region.size or {}
+    local image_size = response.image_size or {}
+
+    ---@type pinnacle.screenshot.Screenshot
+    return {
+        output = require("pinnacle.output").handle.new(response.output_name),
+        region = {
+            x = loc.x or 0,
+            y = loc.y or 0,
+            width = size.width or 0,
+            height = size.height or 0,
+        },
+        size = {
+            width = image_size.width or 0,
+            height = image_size.height or 0,
+        },
+        rgba = response.rgba or "",
+    }
+end
+
+return screenshot
@@ -61,6 +61,14 @@ local signals = {
         ---@type fun(response: table)
         on_response = nil,
     },
+    OutputPointerEdge = {
+        ---@type grpc_client.h2.Stream?
+        sender = nil,
+        ---@type { callback_id: integer, callback: fun(output: pinnacle.output.OutputHandle, edge: pinnacle.output.ScreenEdge) }[]
+        callbacks = {},
+        ---@type fun(response: table)
+        on_response = nil,
+    },
     OutputFocused = {
         ---@type grpc_client.h2.Stream?
         sender = nil,
@@ -69,6 +77,54 @@ local signals = {
         ---@type fun(response: table)
         on_response = nil,
     },
+    OutputLayerFocused = {
+        ---@type grpc_client.h2.Stream?
+        sender = nil,
+        ---@type { callback_id: integer, callback: fun(output: pinnacle.output.OutputHandle, namespace: string) }[]
+        callbacks = {},
+        ---@type fun(response: table)
+        on_response = nil,
+    },
+    OutputLayerUnfocused = {
+        ---@type grpc_client.h2.Stream?
+        sender = nil,
+        ---@type { callback_id: integer, callback: fun(output: pinnacle.output.OutputHandle, namespace: string) }[]
+        callbacks = {},
+        ---@type fun(response: table)
+        on_response = nil,
+    },
+    OutputOrientationChanged = {
+        ---@type grpc_client.h2.Stream?
+        sender = nil,
+        ---@type { callback_id: integer, callback: fun(output: pinnacle.output.OutputHandle, transform: pinnacle.output.Transform) }[]
+        callbacks = {},
+        ---@type fun(response: table)
+        on_response = nil,
+    },
+    OutputFullscreenChanged = {
+        ---@type grpc_client.h2.Stream?
+        sender = nil,
+        ---@type { callback_id: integer, callback: fun(output: pinnacle.output.OutputHandle, window: pinnacle.window.WindowHandle?) }[]
+        callbacks = {},
+        ---@type fun(response: table)
+        on_response = nil,
+    },
+    OutputStackingChanged = {
+        ---@type grpc_client.h2.Stream?
+        sender = nil,
+        ---@type { callback_id: integer, callback: fun(output: pinnacle.output.OutputHandle, windows: pinnacle.window.WindowHandle[]) }[]
+        callbacks = {},
+        ---@type fun(response: table)
+        on_response = nil,
+    },
+    OutputNoActiveTags = {
+        ---@type grpc_client.h2.Stream?
+        sender = nil,
+        ---@type { callback_id: integer, callback: fun(output: pinnacle.output.OutputHandle) }[]
+        callbacks = {},
+        ---@type fun(response: table)
+        on_response = nil,
+    },
     WindowPointerEnter = {
         ---@type grpc_client.h2.Stream?
         sender = nil,
@@ -88,7 +144,7 @@ local signals = {
     WindowFocused = {
         ---@type grpc_client.h2.Stream?
         sender = nil,
-        ---@type { callback_id: integer, callback: fun(window: pinnacle.window.WindowHandle) }[]
+        ---@type { callback_id: integer, callback: fun(window: pinnacle.window.WindowHandle, reason: pinnacle.window.FocusReason, serial: integer) }[]
         callbacks = {},
         ---@type fun(response: table)
         on_response = nil,
@@ -101,6 +157,14 @@ local signals = {
         ---@type fun(response: table)
         on_response = nil,
     },
+    WindowFocusedTitleChanged = {
+        ---@type grpc_client.h2.Stream?
+        sender = nil,
+        ---@type { callback_id: integer, callback: fun(window: pinnacle.window.WindowHandle | nil, title: string, app_id: string) }[]
+        callbacks = {},
+        ---@type fun(response: table)
+        on_response = nil,
+    },
     WindowLayoutModeChanged = {
         ---@type grpc_client.h2.Stream?
         sender = nil,
@@ -125,6 +189,38 @@ local signals = {
         ---@type fun(response: table)
         on_response = nil,
     },
+    WindowUnresponsive = {
+        ---@type grpc_client.h2.Stream?
+        sender = nil,
+        ---@type { callback_id: integer, callback: fun(window: pinnacle.window.WindowHandle) }[]
+        callbacks = {},
+        ---@type fun(response: table)
+        on_response = nil,
+    },
+    WindowMinimized = {
+        ---@type grpc_client.h2.Stream?
+        sender = nil,
+        ---@type { callback_id: integer, callback: fun(window: pinnacle.window.WindowHandle, minimized: boolean) }[]
+        callbacks = {},
+        ---@type fun(response: table)
+        on_response = nil,
+    },
+    WindowUrgencyChanged = {
+        ---@type grpc_client.h2.Stream?
+        sender = nil,
+        ---@type { callback_id: integer, callback: fun(window: pinnacle.window.WindowHandle, demands_attention: boolean) }[]
+        callbacks = {},
+        ---@type fun(response: table)
+        on_response = nil,
+    },
+    WindowPopupGrabBlockedKeybind = {
+        ---@type grpc_client.h2.Stream?
+        sender = nil,
+        ---@type { callback_id: integer, callback: fun(window: pinnacle.window.WindowHandle) }[]
+        callbacks = {},
+        ---@type fun(response: table)
+        on_response = nil,
+    },
     TagActive = {
         ---@type grpc_client.h2.Stream?
         sender = nil,
@@ -149,6 +245,14 @@ local signals = {
         ---@type fun(response: table)
         on_response = nil,
     },
+    TagUrgencyChanged = {
+        ---@type grpc_client.h2.Stream?
+        sender = nil,
+        ---@type { callback_id: integer, callback: fun(tag: pinnacle.tag.TagHandle, urgent: boolean) }[]
+        callbacks = {},
+        ---@type fun(response: table)
+        on_response = nil,
+    },
     InputDeviceAdded = {
         ---@type grpc_client.h2.Stream?
         sender = nil,
@@ -157,6 +261,54 @@ local signals = {
         ---@type fun(response: table)
         on_response = nil,
     },
+    InputAccessibilityChanged = {
+        ---@type grpc_client.h2.Stream?
+        sender = nil,
+        ---@type { callback_id: integer, callback: fun(sticky_keys: boolean, slow_keys_delay: integer?) }[]
+        callbacks = {},
+        ---@type fun(response: table)
+        on_response = nil,
+    },
+    PowerOnBattery = {
+        ---@type grpc_client.h2.Stream?
+        sender = nil,
+        ---@type { callback_id: integer, callback: fun(on_battery: boolean) }[]
+        callbacks = {},
+        ---@type fun(response: table)
+        on_response = nil,
+    },
+    PowerCharging = {
+        ---@type grpc_client.h2.Stream?
+        sender = nil,
+        ---@type { callback_id: integer, callback: fun(charging: boolean) }[]
+        callbacks = {},
+        ---@type fun(response: table)
+        on_response = nil,
+    },
+    PowerLowBattery = {
+        ---@type grpc_client.h2.Stream?
+        sender = nil,
+        ---@type { callback_id: integer, callback: fun(percentage: number) }[]
+        callbacks = {},
+        ---@type fun(response: table)
+        on_response = nil,
+    },
+    IdleStarted = {
+        ---@type grpc_client.h2.Stream?
+        sender = nil,
+        ---@type { callback_id: integer, callback: fun() }[]
+        callbacks = {},
+        ---@type fun(response: table)
+        on_response = nil,
+    },
+    IdleResumed = {
+        ---@type grpc_client.h2.Stream?
+        sender = nil,
+        ---@type { callback_id: integer, callback: fun() }[]
+        callbacks = {},
+        ---@type fun(response: table)
+        on_response = nil,
+    },
 }
 
 ---Call a signal callback in protected mode
@@ -259,6 +411,22 @@ signals.OutputPointerLeave.on_response = function(response)
     end
 end
 
+signals.OutputPointerEdge.on_response = function(response)
+    local output = require("pinnacle.output")
+    ---@diagnostic disable-next-line: invisible
+    local handle = output.handle.new(response.output_name)
+    local edge = output.screen_edge[response.edge]
+    local callbacks = require("pinnacle.util").deep_copy(signals.OutputPointerEdge.callbacks)
+
+    if edge == nil then
+        return
+    end
+
+    for _, callback in ipairs(callbacks) do
+        protected_callback("OutputPointerEdge", callback.callback, handle, edge)
+    end
+end
+
 signals.OutputFocused.on_response = function(response)
     ---@diagnostic disable-next-line: invisible
     local handle = require("pinnacle.output").handle.new(response.output_name)
@@ -269,6 +437,76 @@ signals.OutputFocused.on_response = function(response)
     end
 end
 
+signals.OutputLayerFocused.on_response = function(response)
+    ---@diagnostic disable-next-line: invisible
+    local handle = require("pinnacle.output").handle.new(response.output_name)
+    local callbacks = require("pinnacle.util").deep_copy(signals.OutputLayerFocused.callbacks)
+
+    for _, callback in ipairs(callbacks) do
+        protected_callback("OutputLayerFocused", callback.callback, handle, response.namespace)
+    end
+end
+
+signals.OutputLayerUnfocused.on_response = function(response)
+    ---@diagnostic disable-next-line: invisible
+    local handle = require("pinnacle.output").handle.new(response.output_name)
+    local callbacks = require("pinnacle.util").deep_copy(signals.OutputLayerUnfocused.callbacks)
+
+    for _, callback in ipairs(callbacks) do
+        protected_callback("OutputLayerUnfocused", callback.callback, handle, response.namespace)
+    end
+end
+
+signals.OutputOrientationChanged.on_response = function(response)
+    local output = require("pinnacle.output")
+    ---@diagnostic disable-next-line: invisible
+    local handle = output.handle.new(response.output_name)
+    local transform = output.transform[response.transform]
+    local callbacks = require("pinnacle.util").deep_copy(signals.OutputOrientationChanged.callbacks)
+
+    if transform == nil then
+        return
+    end
+
+    for _, callback in ipairs(callbacks) do
+        protected_callback("OutputOrientationChanged", callback.callback, handle, transform)
+    end
+end
+
+signals.OutputFullscreenChanged.on_response = function(response)
+    ---@diagnostic disable-next-line: invisible
+    local handle = require("pinnacle.output").handle.new(response.output_name)
+    local window = response.window_id
+        and require("pinnacle.window").handle.new(response.window_id)
+    local callbacks = require("pinnacle.util").deep_copy(signals.OutputFullscreenChanged.callbacks)
+
+    for _, callback in ipairs(callbacks) do
+        protected_callback("OutputFullscreenChanged", callback.callback, handle, window)
+    end
+end
+
+signals.OutputStackingChanged.on_response = function(response)
+    ---@diagnostic disable-next-line: invisible
+    local handle = require("pinnacle.output").handle.new(response.output_name)
+    ---@diagnostic disable-next-line: invisible
+    local windows = require("pinnacle.window").handle.new_from_table(response.window_ids or {})
+    local callbacks = require("pinnacle.util").deep_copy(signals.OutputStackingChanged.callbacks)
+
+    for _, callback in ipairs(callbacks) do
+        protected_callback("OutputStackingChanged", callback.callback, handle, windows)
+    end
+end
+
+signals.OutputNoActiveTags.on_response = function(response)
+    ---@diagnostic disable-next-line: invisible
+    local handle = require("pinnacle.output").handle.new(response.output_name)
+    local callbacks = require("pinnacle.util").deep_copy(signals.OutputNoActiveTags.callbacks)
+
+    for _, callback in ipairs(callbacks) do
+        protected_callback("OutputNoActiveTags", callback.callback, handle)
+    end
+end
+
 signals.WindowPointerEnter.on_response = function(response)
     ---@diagnostic disable-next-line: invisible
     local window_handle = require("pinnacle.window").handle.new(response.window_id)
@@ -293,9 +531,11 @@ signals.WindowFocused.on_response = function(response)
     ---@diagnostic disable-next-line: invisible
     local window_handle = require("pinnacle.window").handle.new(response.window_id)
     local callbacks = require("pinnacle.util").deep_copy(signals.WindowFocused.callbacks)
+    local reason = window.focus_reason[response.reason or 0] or "unmap_fallback"
+    local serial = response.serial or 0
 
     for _, callback in ipairs(callbacks) do
-        protected_callback("WindowFocused", callback.callback, window_handle)
+        protected_callback("WindowFocused", callback.callback, window_handle, reason, serial)
     end
 end
 
@@ -310,6 +550,25 @@ signals.WindowTitleChanged.on_response = function(response)
     end
 end
 
+signals.WindowFocusedTitleChanged.on_response = function(response)
+    local window_handle = nil
+    if response.window_id then
+        ---@diagnostic disable-next-line: invisible
+        window_handle = require("pinnacle.window").handle.new(response.window_id)
+    end
+    local callbacks =
+        require("pinnacle.util").deep_copy(signals.WindowFocusedTitleChanged.callbacks)
+    local title = response.title or ""
+    local app_id = response.app_id or ""
+
+    for _, callback in ipairs(callbacks) do
+        local success, err = pcall(callback.callback, window_handle, title, app_id)
+        if not success then
+            log.error("While handling 'WindowFocusedTitleChanged': " .. tostring(err))
+        end
+    end
+end
+
 signals.WindowLayoutModeChanged.on_response = function(response)
     ---@diagnostic disable-next-line: invisible
     local window_handle = require("pinnacle.window").handle.new(response.window_id)
@@ -354,6 +613,54 @@ signals.WindowDestroyed.on_response = function(response)
     end
 end
 
+signals.WindowUnresponsive.on_response = function(response)
+    ---@diagnostic disable-next-line: invisible
+    local window_handle = require("pinnacle.window").handle.new(response.window_id)
+    local callbacks = require("pinnacle.util").deep_copy(signals.WindowUnresponsive.callbacks)
+
+    for _, callback in ipairs(callbacks) do
+        protected_callback("WindowUnresponsive", callback.callback, window_handle)
+    end
+end
+
+signals.WindowMinimized.on_response = function(response)
+    ---@diagnostic disable-next-line: invisible
+    local window_handle = require("pinnacle.window").handle.new(response.window_id)
+    local callbacks = require("pinnacle.util").deep_copy(signals.WindowMinimized.callbacks)
+    local minimized = response.minimized or false
+
+    for _, callback in ipairs(callbacks) do
+        protected_callback("WindowMinimized", callback.callback, window_handle, minimized)
+    end
+end
+
+signals.WindowUrgencyChanged.on_response = function(response)
+    ---@diagnostic disable-next-line: invisible
+    local window_handle = require("pinnacle.window").handle.new(response.window_id)
+    local callbacks = require("pinnacle.util").deep_copy(signals.WindowUrgencyChanged.callbacks)
+    local demands_attention = response.demands_attention or false
+
+    for _, callback in ipairs(callbacks) do
+        protected_callback(
+            "WindowUrgencyChanged",
+            callback.callback,
+            window_handle,
+            demands_attention
+        )
+    end
+end
+
+signals.WindowPopupGrabBlockedKeybind.on_response = function(response)
+    ---@diagnostic disable-next-line: invisible
+    local window_handle = require("pinnacle.window").handle.new(response.window_id)
+    local callbacks =
+        require("pinnacle.util").deep_copy(signals.WindowPopupGrabBlockedKeybind.callbacks)
+
+    for _, callback in ipairs(callbacks) do
+        protected_callback("WindowPopupGrabBlockedKeybind", callback.callback, window_handle)
+    end
+end
+
 signals.TagActive.on_response = function(response)
     ---@diagnostic disable-next-line: invisible
     local tag_handle = require("pinnacle.tag").handle.new(response.tag_id)
@@ -384,6 +691,16 @@ signals.TagRemoved.on_response = function(response)
     end
 end
 
+signals.TagUrgencyChanged.on_response = function(response)
+    ---@diagnostic disable-next-line: invisible
+    local tag_handle = require("pinnacle.tag").handle.new(response.tag_id)
+    local callbacks = require("pinnacle.util").deep_copy(signals.TagUrgencyChanged.callbacks)
+
+    for _, callback in ipairs(callbacks) do
+        protected_callback("TagUrgencyChanged", callback.callback, tag_handle, response.urgent or false)
+    end
+end
+
 signals.InputDeviceAdded.on_response = function(response)
     ---@diagnostic disable-next-line: invisible
     local device_handle = require("pinnacle.input.libinput").new_device(response.device_sysname)
@@ -394,6 +711,60 @@ signals.InputDeviceAdded.on_response = function(response)
     end
 end
 
+signals.InputAccessibilityChanged.on_response = function(response)
+    local callbacks =
+        require("pinnacle.util").deep_copy(signals.InputAccessibilityChanged.callbacks)
+
+    for _, callback in ipairs(callbacks) do
+        protected_callback(
+            "InputAccessibilityChanged",
+            callback.callback,
+            response.sticky_keys or false,
+            response.slow_keys_delay_millis
+        )
+    end
+end
+
+signals.PowerOnBattery.on_response = function(response)
+    local callbacks = require("pinnacle.util").deep_copy(signals.PowerOnBattery.callbacks)
+
+    for _, callback in ipairs(callbacks) do
+        protected_callback("PowerOnBattery", callback.callback, response.on_battery or false)
+    end
+end
+
+signals.PowerCharging.on_response = function(response)
+    local callbacks = require("pinnacle.util").deep_copy(signals.PowerCharging.callbacks)
+
+    for _, callback in ipairs(callbacks) do
+        protected_callback("PowerCharging", callback.callback, response.charging or false)
+    end
+end
+
+signals.PowerLowBattery.on_response = function(response)
+    local callbacks = require("pinnacle.util").deep_copy(signals.PowerLowBattery.callbacks)
+
+    for _, callback in ipairs(callbacks) do
+        protected_callback("PowerLowBattery", callback.callback, response.percentage or 0)
+    end
+end
+
+signals.IdleStarted.on_response = function(_)
+    local callbacks = require("pinnacle.util").deep_copy(signals.IdleStarted.callbacks)
+
+    for _, callback in ipairs(callbacks) do
+        protected_callback("IdleStarted", callback.callback)
+    end
+end
+
+signals.IdleResumed.on_response = function(_)
+    local callbacks = require("pinnacle.util").deep_copy(signals.IdleResumed.callbacks)
+
+    for _, callback in ipairs(callbacks) do
+        protected_callback("IdleResumed", callback.callback)
+    end
+end
+
 -----------------------------------------------------------------------------
 
 ---@class pinnacle.signal.SignalHandleModule
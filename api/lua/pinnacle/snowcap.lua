@@ -49,6 +49,29 @@ local snowcap = {
 local QuitPrompt = {}
 setmetatable(QuitPrompt, { __index = require("snowcap.widget.base").Base })
 
+---A prompt asking whether an app may bind global shortcuts.
+---
+---When opened, pressing ENTER will allow the app to bind its shortcuts
+---and pressing ESCAPE will deny it.
+---@class pinnacle.snowcap.integration.GlobalShortcutsPrompt : snowcap.widget.Program
+---The radius of the prompt's corners.
+---@field border_radius number
+---The thickness of the prompt border.
+---@field border_thickness number
+---The color of the prompt background.
+---@field background_color snowcap.widget.Color
+---The color of the prompt border.
+---@field border_color snowcap.widget.Color
+---The font of the prompt.
+---@field font snowcap.widget.Font
+---The width of the prompt.
+---@field width integer
+---The height of the prompt.
+---@field height integer
+---@field private request pinnacle.portal.GlobalShortcutsRequest
+local GlobalShortcutsPrompt = {}
+setmetatable(GlobalShortcutsPrompt, { __index = require("snowcap.widget.base").Base })
+
 ---An overlay that shows various input binds.
 ---@class pinnacle.snowcap.integration.BindOverlay : snowcap.widget.Program
 ---The radius of the overlay's corners.
@@ -89,6 +112,154 @@ setmetatable(BindOverlay, { __index = require("snowcap.widget.base").Base })
 local FocusBorder = {}
 setmetatable(FocusBorder, { __index = require("snowcap.widget.base").Base })
 
+---A module that can be placed in a bar.
+---
+---- `"tag_pager"`: The tags on the bar's output. Clicking a tag switches to it.
+---- `"focused_title"`: The title of the focused window on the bar's output.
+---- `"battery"`: The battery's charge. This is empty on systems without a battery.
+---- `{ clock = format }`: The current time, formatted with an `os.date` format string like `"%H:%M"`.
+---- `{ text = text }`: Some fixed text.
+---@alias pinnacle.snowcap.integration.BarModule
+---| "tag_pager"
+---| "focused_title"
+---| "battery"
+---| { clock: string }
+---| { text: string }
+
+---The colors, font, and size of a bar.
+---@class pinnacle.snowcap.integration.BarTheme
+---The height of the bar.
+---@field height integer
+---The space between modules.
+---@field spacing number
+---The font of all text in the bar.
+---@field font snowcap.widget.Font
+---The size of all text in the bar, in pixels.
+---@field text_size number
+---The color of the bar background.
+---@field background_color snowcap.widget.Color
+---The color of text.
+---@field text_color snowcap.widget.Color
+---The color of tags without windows.
+---@field empty_tag_color snowcap.widget.Color
+---The background color of active tags.
+---@field active_tag_color snowcap.widget.Color
+---The background color of tags with a window that demands attention.
+---@field urgent_tag_color snowcap.widget.Color
+
+---A bar along the top or bottom of an output, made of modules.
+---
+---Modules are placed in three slots: the left slot, the centered slot, and the right slot.
+---The bar is shown on the focused output.
+---
+---With `auto_hide`, the bar stays hidden and doesn't reserve space until the pointer
+---hits its edge of the output, and hides again once the pointer leaves it.
+---
+---A system tray module will be added once Pinnacle has a tray.
+---@class pinnacle.snowcap.integration.Bar : snowcap.widget.Program
+---The modules in the left slot.
+---@field left pinnacle.snowcap.integration.BarModule[]
+---The modules in the center slot.
+---@field center pinnacle.snowcap.integration.BarModule[]
+---The modules in the right slot.
+---@field right pinnacle.snowcap.integration.BarModule[]
+---Whether the bar is at the bottom of the output instead of the top.
+---@field bottom boolean
+---Whether the bar hides until the pointer hits its edge of the output.
+---@field auto_hide boolean
+---The bar's theme.
+---@field theme pinnacle.snowcap.integration.BarTheme
+---@field private output_name string?
+---@field private revealed boolean
+---@field private pager pinnacle.tag.v1.PagerResponse.Output?
+---@field private power pinnacle.power.PowerStatus?
+---@field private clocks table<string, string>
+---@field private bindings pinnacle.snowcap.integration.Binding[]
+local Bar = {}
+setmetatable(Bar, { __index = require("snowcap.widget.base").Base })
+
+---A button in a confirmation dialog or power menu.
+---@class pinnacle.snowcap.integration.MenuButton
+---The button's label.
+---@field label string
+---What happens when the button is pressed.
+---@field on_press fun()
+
+---The colors, font, and size of a confirmation dialog or power menu.
+---@class pinnacle.snowcap.integration.MenuTheme
+---The radius of the menu's corners.
+---@field border_radius number
+---The thickness of the menu border.
+---@field border_thickness number
+---The color of the menu background.
+---@field background_color snowcap.widget.Color
+---The color of the menu border.
+---@field border_color snowcap.widget.Color
+---The font of the menu.
+---@field font snowcap.widget.Font
+---The size of the menu's text, in pixels.
+---@field text_size number
+---The color of text.
+---@field text_color snowcap.widget.Color
+---The background color of buttons.
+---@field button_color snowcap.widget.Color
+---The background color of the selected button.
+---@field selected_button_color snowcap.widget.Color
+
+---A row of buttons with an optional title and message, shared by
+---confirmation dialogs and power menus.
+---@class pinnacle.snowcap.integration.ButtonMenu : snowcap.widget.Program
+---@field title string
+---@field message string?
+---@field buttons pinnacle.snowcap.integration.MenuButton[]
+---@field selected integer
+---@field theme pinnacle.snowcap.integration.MenuTheme
+local ButtonMenu = {}
+setmetatable(ButtonMenu, { __index = require("snowcap.widget.base").Base })
+
+---A dialog asking to confirm an action.
+---
+---When opened, the cancel button is selected so an accidental ENTER doesn't confirm.
+---@class pinnacle.snowcap.integration.ConfirmDialog
+---The title of the dialog.
+---@field title string
+---Text shown below the title.
+---@field message string?
+---The confirm button.
+---@field confirm pinnacle.snowcap.integration.MenuButton
+---The label of the cancel button.
+---@field cancel_label string
+---The dialog's theme.
+---@field theme pinnacle.snowcap.integration.MenuTheme
+local ConfirmDialog = {}
+
+---A menu to log out, suspend, reboot, or shut down.
+---@class pinnacle.snowcap.integration.PowerMenu
+---The title of the menu.
+---@field title string
+---The menu's buttons, from left to right.
+---@field buttons pinnacle.snowcap.integration.MenuButton[]
+---Whether pressing a button asks for confirmation first.
+---@field confirm boolean
+---The menu's theme.
+---@field theme pinnacle.snowcap.integration.MenuTheme
+local PowerMenu = {}
+
+---A widget that bindings can send messages to.
+---@alias pinnacle.snowcap.integration.BindTarget
+---| snowcap.layer.LayerHandle
+---| snowcap.decoration.DecorationHandle
+---| snowcap.popup.PopupHandle
+
+---A running binding that keeps a Snowcap widget up to date with compositor state.
+---
+---The binding keeps updating its widget until it is unbound, which is usually
+---done when the widget closes.
+---@class pinnacle.snowcap.integration.Binding
+---@field private unbound boolean
+---@field private signals pinnacle.signal.SignalHandles?
+local Binding = {}
+
 function QuitPrompt:view()
     local Widget = require("snowcap.widget")
 
@@ -163,6 +334,124 @@ function QuitPrompt:show()
     end)
 end
 
+function GlobalShortcutsPrompt:view()
+    local Widget = require("snowcap.widget")
+
+    local title_font = require("pinnacle.util").deep_copy(self.font)
+    title_font.weight = Widget.font.weight.BOLD
+
+    local app = self.request.app_id
+    if app == "" then
+        app = "An app"
+    end
+
+    local shortcuts = {}
+    for _, shortcut in ipairs(self.request.shortcuts) do
+        local text = shortcut.description
+        if shortcut.preferred_trigger then
+            text = text .. " (" .. shortcut.preferred_trigger .. ")"
+        end
+        table.insert(
+            shortcuts,
+            Widget.text({
+                text = text,
+                style = {
+                    font = self.font,
+                    pixels = 14.0,
+                },
+            })
+        )
+    end
+
+    local prompt = Widget.container({
+        width = Widget.length.Fixed(self.width),
+        height = Widget.length.Fixed(self.height),
+        padding = {
+            top = 16.0,
+            right = 16.0,
+            bottom = 16.0,
+            left = 16.0,
+        },
+        valign = Widget.alignment.CENTER,
+        halign = Widget.alignment.CENTER,
+        style = {
+            background = Widget.background.Color(self.background_color),
+            border = {
+                width = self.border_thickness,
+                color = self.border_color,
+                radius = {
+                    top_left = self.border_radius,
+                    top_right = self.border_radius,
+                    bottom_left = self.border_radius,
+                    bottom_right = self.border_radius,
+                },
+            },
+        },
+        child = Widget.column({
+            children = {
+                Widget.text({
+                    text = app .. " wants to bind global shortcuts",
+                    style = {
+                        font = title_font,
+                        pixels = 20.0,
+                    },
+                }),
+                Widget.text({ text = "", style = { pixels = 8.0 } }),
+                Widget.scrollable({
+                    child = Widget.column({
+                        children = shortcuts,
+                    }),
+                    width = Widget.length.Fill,
+                    height = Widget.length.Fill,
+                }),
+                Widget.text({ text = "", style = { pixels = 8.0 } }),
+                Widget.text({
+                    text = "ENTER: Allow. ESCAPE: Deny.",
+                    style = {
+                        font = self.font,
+                        pixels = 14.0,
+                    },
+                }),
+            },
+        }),
+    })
+
+    return prompt
+end
+
+function GlobalShortcutsPrompt:update(_) end
+
+---Shows this prompt.
+---
+---The request is denied if the prompt can't be shown.
+function GlobalShortcutsPrompt:show()
+    local Layer = require("snowcap.layer")
+    local prompt = Layer.new_widget({
+        program = self,
+        anchor = nil,
+        keyboard_interactivity = Layer.keyboard_interactivity.EXCLUSIVE,
+        exclusive_zone = "respect",
+        layer = Layer.zlayer.OVERLAY,
+    })
+
+    if not prompt then
+        self.request:deny()
+        return
+    end
+
+    local keys = require("snowcap.input.keys")
+
+    prompt:on_key_press(function(_, key)
+        if key == keys.Return then
+            self.request:allow()
+            prompt:close()
+        elseif key == keys.Escape then
+            self.request:deny()
+            prompt:close()
+        end
+    end)
+end
+
 function BindOverlay:view()
     ---@param mods pinnacle.input.Mod[]
     ---@return string?
@@ -924,6 +1213,817 @@ function FocusBorder:decorate()
     return border
 end
 
+---@param module pinnacle.snowcap.integration.BarModule
+---
+---@return snowcap.widget.WidgetDef?
+function Bar:module_view(module)
+    local Widget = require("snowcap.widget")
+
+    local function text(string, color)
+        return Widget.text({
+            text = string,
+            style = {
+                font = self.theme.font,
+                pixels = self.theme.text_size,
+                color = color,
+            },
+        })
+    end
+
+    if module == "tag_pager" then
+        if not self.pager then
+            return nil
+        end
+
+        local tags = {}
+        for _, tag in ipairs(self.pager.tags or {}) do
+            local background = nil
+            if tag.urgent then
+                background = Widget.background.Color(self.theme.urgent_tag_color)
+            elseif tag.active then
+                background = Widget.background.Color(self.theme.active_tag_color)
+            end
+
+            local color = (tag.occupied or tag.active) and self.theme.text_color
+                or self.theme.empty_tag_color
+
+            table.insert(
+                tags,
+                Widget.button({
+                    height = Widget.length.Fill,
+                    padding = {
+                        top = 0,
+                        right = 6,
+                        bottom = 0,
+                        left = 6,
+                    },
+                    style = {
+                        active = { background = background },
+                        hovered = { background = background },
+                        pressed = { background = background },
+                    },
+                    on_press = { switch_to_tag = tag.tag_id },
+                    child = text(tag.name or "", color),
+                })
+            )
+        end
+
+        return Widget.row({
+            item_alignment = Widget.alignment.CENTER,
+            height = Widget.length.Fill,
+            children = tags,
+        })
+    elseif module == "focused_title" then
+        local title = self.pager and self.pager.focused_window_title
+        return title and text(title, self.theme.text_color)
+    elseif module == "battery" then
+        local percentage = self.power and self.power.percentage
+        if not percentage then
+            return nil
+        end
+
+        local label = string.format("%.0f%%", percentage)
+        if self.power.charging then
+            label = label .. " (charging)"
+        end
+        return text(label, self.theme.text_color)
+    elseif type(module) == "table" and module.clock then
+        local time = self.clocks[module.clock]
+        return time and text(time, self.theme.text_color)
+    elseif type(module) == "table" and module.text then
+        return text(module.text, self.theme.text_color)
+    end
+
+    return nil
+end
+
+---@param modules pinnacle.snowcap.integration.BarModule[]
+---@param halign snowcap.widget.Alignment
+---@param width snowcap.widget.Length
+---
+---@return snowcap.widget.WidgetDef
+function Bar:slot_view(modules, halign, width)
+    local Widget = require("snowcap.widget")
+
+    local children = {}
+    for _, module in ipairs(modules) do
+        local child = self:module_view(module)
+        if child then
+            table.insert(children, child)
+        end
+    end
+
+    return Widget.container({
+        width = width,
+        height = Widget.length.Fill,
+        halign = halign,
+        valign = Widget.alignment.CENTER,
+        child = Widget.row({
+            spacing = self.theme.spacing,
+            item_alignment = Widget.alignment.CENTER,
+            height = Widget.length.Fill,
+            children = children,
+        }),
+    })
+end
+
+function Bar:view()
+    local Widget = require("snowcap.widget")
+
+    if self.auto_hide and not self.revealed then
+        -- Keep a sliver of the surface around so the layer stays mapped
+        return Widget.container({
+            width = Widget.length.Fill,
+            height = Widget.length.Fixed(1),
+            child = Widget.row({ children = {} }),
+        })
+    end
+
+    local bar = Widget.container({
+        width = Widget.length.Fill,
+        height = Widget.length.Fixed(self.theme.height),
+        padding = {
+            top = 0,
+            right = 8,
+            bottom = 0,
+            left = 8,
+        },
+        style = {
+            background = Widget.background.Color(self.theme.background_color),
+            text_color = self.theme.text_color,
+        },
+        child = Widget.row({
+            spacing = self.theme.spacing,
+            width = Widget.length.Fill,
+            height = Widget.length.Fill,
+            children = {
+                self:slot_view(self.left, Widget.alignment.START, Widget.length.Fill),
+                self:slot_view(self.center, Widget.alignment.CENTER, Widget.length.Shrink),
+                self:slot_view(self.right, Widget.alignment.END, Widget.length.Fill),
+            },
+        }),
+    })
+
+    if self.auto_hide then
+        return Widget.mouse_area({
+            child = bar,
+            on_exit = { hide = true },
+        })
+    end
+
+    return bar
+end
+
+function Bar:update(msg)
+    if msg.pager then
+        self.pager = nil
+        for _, output in ipairs(msg.pager.outputs or {}) do
+            if output.output_name == self.output_name then
+                self.pager = output
+            end
+        end
+    end
+    if msg.power then
+        self.power = msg.power
+    end
+    if msg.clock then
+        self.clocks[msg.clock.format] = msg.clock.time
+    end
+    if msg.switch_to_tag then
+        require("pinnacle.tag").handle.new(msg.switch_to_tag):switch_to()
+    end
+    if msg.reveal then
+        self.revealed = true
+    end
+    if msg.hide then
+        self.revealed = false
+    end
+end
+
+function Bar:event(event)
+    if event.closing then
+        for _, binding in ipairs(self.bindings) do
+            binding:unbind()
+        end
+        self.bindings = {}
+    end
+end
+
+---Shows this bar on the focused output.
+---
+---@return snowcap.layer.LayerHandle|nil
+function Bar:show()
+    local Layer = require("snowcap.layer")
+
+    local focused = require("pinnacle.output").get_focused()
+    self.output_name = focused and focused.name
+
+    local bar = Layer.new_widget({
+        program = self,
+        anchor = self.bottom and Layer.anchor.BOTTOM or Layer.anchor.TOP,
+        keyboard_interactivity = Layer.keyboard_interactivity.NONE,
+        exclusive_zone = (self.theme.height > 0 and not self.auto_hide) and self.theme.height
+            or "respect",
+        layer = Layer.zlayer.TOP,
+    })
+
+    if not bar then
+        return nil
+    end
+
+    local needs_pager = false
+    local needs_power = false
+    ---@type table<string, boolean>
+    local clock_formats = {}
+    for _, slot in ipairs({ self.left, self.center, self.right }) do
+        for _, module in ipairs(slot) do
+            if module == "tag_pager" or module == "focused_title" then
+                needs_pager = true
+            elseif module == "battery" then
+                needs_power = true
+            elseif type(module) == "table" and module.clock then
+                clock_formats[module.clock] = true
+            end
+        end
+    end
+
+    if needs_pager then
+        table.insert(
+            self.bindings,
+            integration.bind_pager(bar, function(pager)
+                return { pager = pager }
+            end)
+        )
+    end
+
+    if needs_power then
+        table.insert(
+            self.bindings,
+            integration.bind_power(bar, function(status)
+                return { power = status }
+            end)
+        )
+    end
+
+    if self.auto_hide and focused then
+        local bar_edges = self.bottom and { "bottom", "bottom_left", "bottom_right" }
+            or { "top", "top_left", "top_right" }
+
+        table.insert(
+            self.bindings,
+            integration.bind_screen_edge(bar, focused, bar_edges, function(_)
+                return { reveal = true }
+            end)
+        )
+    end
+
+    for format, _ in pairs(clock_formats) do
+        table.insert(
+            self.bindings,
+            integration.bind_clock(bar, format, function(time)
+                return { clock = { format = format, time = time } }
+            end)
+        )
+    end
+
+    return bar
+end
+
+---Stops this binding from updating its widget.
+function Binding:unbind()
+    self.unbound = true
+    if self.signals then
+        self.signals:disconnect_all()
+    end
+end
+
+---@return pinnacle.snowcap.integration.Binding
+local function new_binding()
+    ---@type pinnacle.snowcap.integration.Binding
+    local binding = {
+        unbound = false,
+    }
+
+    setmetatable(binding, { __index = Binding })
+
+    return binding
+end
+
+---Streams the pager state, sending whatever `message` returns to `target`.
+---
+---@param target pinnacle.snowcap.integration.BindTarget
+---@param message fun(pager: pinnacle.tag.v1.PagerResponse): any
+---
+---@return pinnacle.snowcap.integration.Binding
+local function bind_pager_with(target, message)
+    local client = require("pinnacle.grpc.client").client
+    local binding = new_binding()
+
+    local err = client:pinnacle_tag_v1_TagService_Pager({}, function(response)
+        if binding.unbound then
+            return
+        end
+
+        local msg = message(response)
+        if msg ~= nil then
+            target:send_message(msg)
+        end
+    end)
+
+    if err then
+        require("pinnacle.log").error(err)
+    end
+
+    return binding
+end
+
+---Returns whether two optional rectangles are the same.
+---
+---@param a pinnacle.util.v1.Rect?
+---@param b pinnacle.util.v1.Rect?
+---
+---@return boolean
+local function rects_equal(a, b)
+    if not a or not b then
+        return a == b
+    end
+
+    local a_loc, b_loc = a.loc or {}, b.loc or {}
+    local a_size, b_size = a.size or {}, b.size or {}
+
+    return a_loc.x == b_loc.x
+        and a_loc.y == b_loc.y
+        and a_size.width == b_size.width
+        and a_size.height == b_size.height
+end
+
+---Returns whether two lists of pager windows are the same.
+---
+---@param a pinnacle.tag.v1.PagerResponse.Window[]?
+---@param b pinnacle.tag.v1.PagerResponse.Window[]?
+---
+---@return boolean
+local function pager_windows_equal(a, b)
+    a, b = a or {}, b or {}
+    if #a ~= #b then
+        return false
+    end
+
+    for i, window in ipairs(a) do
+        local other = b[i]
+        if
+            window.window_id ~= other.window_id
+            or window.title ~= other.title
+            or window.app_id ~= other.app_id
+            or not rects_equal(window.geometry, other.geometry)
+        then
+            return false
+        end
+    end
+
+    return true
+end
+
+---Returns whether two lists of pager tags are the same.
+---
+---@param a pinnacle.tag.v1.PagerResponse.Tag[]?
+---@param b pinnacle.tag.v1.PagerResponse.Tag[]
+---
+---@return boolean
+local function pager_tags_equal(a, b)
+    if not a or #a ~= #b then
+        return false
+    end
+
+    for i, tag in ipairs(a) do
+        local other = b[i]
+        if
+            tag.tag_id ~= other.tag_id
+            or tag.name ~= other.name
+            or tag.active ~= other.active
+            or tag.urgent ~= other.urgent
+            or tag.occupied ~= other.occupied
+            or not pager_windows_equal(tag.windows, other.windows)
+        then
+            return false
+        end
+    end
+
+    return true
+end
+
+---Sends the pager state of all outputs to `target` every time it changes.
+---
+---Bindings keep a Snowcap widget up to date with compositor state. The current state
+---is sent right away, and the binding keeps running until it is unbound.
+---
+---@param target pinnacle.snowcap.integration.BindTarget
+---@param message fun(pager: pinnacle.tag.v1.PagerResponse): any The message to send.
+---
+---@return pinnacle.snowcap.integration.Binding
+function integration.bind_pager(target, message)
+    return bind_pager_with(target, message)
+end
+
+---Sends the tags on `output` to `target` every time they change.
+---
+---The tags are empty when the output is disconnected.
+---
+---Example:
+---
+---```lua
+---local binding = require("pinnacle.snowcap").integration.bind_tag_pager(
+---    layer,
+---    require("pinnacle.output").get_focused(),
+---    function(tags)
+---        return { tags = tags }
+---    end
+---)
+---
+----- Later, when the layer closes
+---binding:unbind()
+---```
+---
+---@param target pinnacle.snowcap.integration.BindTarget
+---@param output pinnacle.output.OutputHandle
+---@param message fun(tags: pinnacle.tag.v1.PagerResponse.Tag[]): any The message to send.
+---
+---@return pinnacle.snowcap.integration.Binding
+function integration.bind_tag_pager(target, output, message)
+    ---@type pinnacle.tag.v1.PagerResponse.Tag[]?
+    local last = nil
+
+    return bind_pager_with(target, function(pager)
+        local tags = {}
+        for _, pager_output in ipairs(pager.outputs or {}) do
+            if pager_output.output_name == output.name then
+                tags = pager_output.tags or {}
+            end
+        end
+
+        if pager_tags_equal(last, tags) then
+            return nil
+        end
+        last = tags
+
+        return message(tags)
+    end)
+end
+
+---Sends the title of the focused window on the focused output to `target`
+---every time it changes.
+---
+---@param target pinnacle.snowcap.integration.BindTarget
+---@param message fun(title: string?): any The message to send.
+---
+---@return pinnacle.snowcap.integration.Binding
+function integration.bind_focused_title(target, message)
+    local sent = false
+    ---@type string?
+    local last = nil
+
+    return bind_pager_with(target, function(pager)
+        ---@type string?
+        local title = nil
+        for _, pager_output in ipairs(pager.outputs or {}) do
+            if pager_output.focused then
+                title = pager_output.focused_window_title
+            end
+        end
+
+        if sent and title == last then
+            return nil
+        end
+        sent = true
+        last = title
+
+        return message(title)
+    end)
+end
+
+---Sends the power status to `target` when the power source changes,
+---and every 30 seconds for the battery's charge.
+---
+---@param target pinnacle.snowcap.integration.BindTarget
+---@param message fun(status: pinnacle.power.PowerStatus): any The message to send.
+---
+---@return pinnacle.snowcap.integration.Binding
+function integration.bind_power(target, message)
+    local client = require("pinnacle.grpc.client").client
+    local cqueues = require("cqueues")
+    local binding = new_binding()
+
+    local function send_status()
+        target:send_message(message(require("pinnacle.power").status()))
+    end
+
+    client.loop:wrap(function()
+        while not binding.unbound do
+            send_status()
+            cqueues.sleep(30)
+        end
+    end)
+
+    -- Power source changes are sent right away instead of on the next poll
+    binding.signals = require("pinnacle.power").connect_signal({
+        on_battery = send_status,
+        charging = send_status,
+    })
+
+    return binding
+end
+
+---Sends the current time, formatted with an `os.date` format string like `"%H:%M"`,
+---to `target` every time it changes.
+---
+---@param target pinnacle.snowcap.integration.BindTarget
+---@param format string
+---@param message fun(time: string): any The message to send.
+---
+---@return pinnacle.snowcap.integration.Binding
+function integration.bind_clock(target, format, message)
+    local client = require("pinnacle.grpc.client").client
+    local cqueues = require("cqueues")
+    local binding = new_binding()
+
+    local shows_seconds = false
+    for _, specifier in ipairs({ "%%S", "%%s", "%%T", "%%r", "%%c" }) do
+        shows_seconds = shows_seconds or format:find(specifier) ~= nil
+    end
+
+    client.loop:wrap(function()
+        while not binding.unbound do
+            target:send_message(message(os.date(format) --[[@as string]]))
+            -- Clocks that don't show seconds update at the start of every minute
+            cqueues.sleep(shows_seconds and 1 or (60 - os.time() % 60))
+        end
+    end)
+
+    return binding
+end
+
+---Sends the edge the pointer hit to `target` every time it hits one of `edges` on `output`.
+---
+---Nothing is sent right away, as the pointer isn't touching an edge until it moves there.
+---
+---@param target pinnacle.snowcap.integration.BindTarget
+---@param output pinnacle.output.OutputHandle
+---@param edges pinnacle.output.ScreenEdge[]
+---@param message fun(edge: pinnacle.output.ScreenEdge): any The message to send.
+---
+---@return pinnacle.snowcap.integration.Binding
+function integration.bind_screen_edge(target, output, edges, message)
+    local binding = new_binding()
+
+    binding.signals = require("pinnacle.output").connect_signal({
+        pointer_edge = function(edge_output, edge)
+            if edge_output.name ~= output.name then
+                return
+            end
+            for _, bound_edge in ipairs(edges) do
+                if edge == bound_edge then
+                    target:send_message(message(edge))
+                end
+            end
+        end,
+    })
+
+    return binding
+end
+
+function ButtonMenu:view()
+    local Widget = require("snowcap.widget")
+
+    local function text(string, pixels, font)
+        return Widget.text({
+            text = string,
+            style = {
+                font = font,
+                pixels = pixels,
+                color = self.theme.text_color,
+            },
+        })
+    end
+
+    local buttons = {}
+    for i, button in ipairs(self.buttons) do
+        local style = {
+            background = Widget.background.Color(
+                i == self.selected and self.theme.selected_button_color or self.theme.button_color
+            ),
+            border = {
+                radius = {
+                    top_left = 6.0,
+                    top_right = 6.0,
+                    bottom_left = 6.0,
+                    bottom_right = 6.0,
+                },
+            },
+        }
+
+        table.insert(
+            buttons,
+            Widget.button({
+                padding = {
+                    top = 8.0,
+                    right = 8.0,
+                    bottom = 8.0,
+                    left = 8.0,
+                },
+                style = {
+                    active = style,
+                    hovered = style,
+                    pressed = style,
+                },
+                on_press = { press = i },
+                child = text(button.label, self.theme.text_size, self.theme.font),
+            })
+        )
+    end
+
+    local title_font = require("pinnacle.util").deep_copy(self.theme.font)
+    title_font.weight = Widget.font.weight.BOLD
+
+    local children = {
+        text(self.title, self.theme.text_size + 6.0, title_font),
+    }
+    if self.message then
+        table.insert(children, text(self.message, self.theme.text_size, self.theme.font))
+    end
+    table.insert(children, Widget.row({ spacing = 8.0, children = buttons }))
+
+    return Widget.container({
+        padding = {
+            top = 16.0,
+            right = 16.0,
+            bottom = 16.0,
+            left = 16.0,
+        },
+        style = {
+            background = Widget.background.Color(self.theme.background_color),
+            border = {
+                width = self.theme.border_thickness,
+                color = self.theme.border_color,
+                radius = {
+                    top_left = self.theme.border_radius,
+                    top_right = self.theme.border_radius,
+                    bottom_left = self.theme.border_radius,
+                    bottom_right = self.theme.border_radius,
+                },
+            },
+        },
+        child = Widget.column({
+            spacing = 12.0,
+            item_alignment = Widget.alignment.CENTER,
+            children = children,
+        }),
+    })
+end
+
+---@param index integer
+function ButtonMenu:press(index)
+    local button = self.buttons[index]
+    if button then
+        button.on_press()
+    end
+    self:emit(require("snowcap.widget.signal").request_close)
+end
+
+function ButtonMenu:update(msg)
+    local count = math.max(#self.buttons, 1)
+
+    if msg.select_next then
+        self.selected = self.selected % count + 1
+    end
+    if msg.select_previous then
+        self.selected = (self.selected - 2) % count + 1
+    end
+    if msg.activate then
+        self:press(self.selected)
+    end
+    if msg.press then
+        self:press(msg.press)
+    end
+    if msg.close then
+        self:emit(require("snowcap.widget.signal").request_close)
+    end
+end
+
+---Shows this menu and binds its keys.
+---
+---Left, Right, Tab, and Shift+Tab change the selected button, ENTER presses it,
+---and ESCAPE closes the menu.
+---
+---@return snowcap.layer.LayerHandle|nil
+function ButtonMenu:show()
+    local Layer = require("snowcap.layer")
+    local keys = require("snowcap.input.keys")
+
+    local menu = Layer.new_widget({
+        program = self,
+        anchor = nil,
+        keyboard_interactivity = Layer.keyboard_interactivity.EXCLUSIVE,
+        exclusive_zone = "respect",
+        layer = Layer.zlayer.OVERLAY,
+    })
+
+    if not menu then
+        return nil
+    end
+
+    menu:on_key_press(function(mods, key)
+        if key == keys.Left or key == keys.Up or key == keys.ISO_Left_Tab then
+            menu:send_message({ select_previous = true })
+        elseif key == keys.Tab and mods.shift then
+            menu:send_message({ select_previous = true })
+        elseif key == keys.Right or key == keys.Down or key == keys.Tab then
+            menu:send_message({ select_next = true })
+        elseif key == keys.Return or key == keys.KP_Enter or key == keys.space then
+            menu:send_message({ activate = true })
+        elseif key == keys.Escape then
+            menu:send_message({ close = true })
+        end
+    end)
+
+    return menu
+end
+
+---@param title string
+---@param message string?
+---@param buttons pinnacle.snowcap.integration.MenuButton[]
+---@param selected integer
+---@param theme pinnacle.snowcap.integration.MenuTheme
+---
+---@return pinnacle.snowcap.integration.ButtonMenu
+local function button_menu(title, message, buttons, selected, theme)
+    local base = require("snowcap.widget.base").Base.new()
+    setmetatable(base, { __index = ButtonMenu })
+
+    base.title = title
+    base.message = message
+    base.buttons = buttons
+    base.selected = selected
+    base.theme = theme
+
+    ---@cast base pinnacle.snowcap.integration.ButtonMenu
+
+    return base
+end
+
+---Shows this dialog.
+---
+---@return snowcap.layer.LayerHandle|nil
+function ConfirmDialog:show()
+    return button_menu(self.title, self.message, {
+        self.confirm,
+        { label = self.cancel_label, on_press = function() end },
+    }, 2, self.theme):show()
+end
+
+---Shows this menu.
+---
+---@return snowcap.layer.LayerHandle|nil
+function PowerMenu:show()
+    local buttons = self.buttons
+
+    if self.confirm then
+        buttons = {}
+        for _, button in ipairs(self.buttons) do
+            table.insert(buttons, {
+                label = button.label,
+                on_press = function()
+                    local dialog = integration.confirm_dialog(button.label .. "?", button.on_press)
+                    dialog.confirm.label = button.label
+                    dialog.theme = self.theme
+                    dialog:show()
+                end,
+            })
+        end
+    end
+
+    return button_menu(self.title, nil, buttons, 1, self.theme):show()
+end
+
+---@return pinnacle.snowcap.integration.MenuTheme
+local function default_menu_theme()
+    local Widget = require("snowcap.widget")
+
+    ---@type pinnacle.snowcap.integration.MenuTheme
+    return {
+        border_radius = 12.0,
+        border_thickness = 6.0,
+        background_color = Widget.color.from_rgba(0.15, 0.03, 0.1, 0.65),
+        border_color = Widget.color.from_rgba(0.8, 0.2, 0.4),
+        font = {
+            family = Widget.font.family.Name("Ubuntu"),
+        },
+        text_size = 14.0,
+        text_color = Widget.color.from_rgba(0.9, 0.9, 0.9),
+        button_color = Widget.color.from_rgba(0.3, 0.1, 0.2),
+        selected_button_color = Widget.color.from_rgba(0.8, 0.2, 0.4),
+    }
+end
+
 ---Creates the default quit prompt.
 ---
 ---Some of its characteristics can be changed by altering its fields.
@@ -957,6 +2057,41 @@ function integration.quit_prompt()
     return base
 end
 
+---Creates a prompt asking whether an app may bind global shortcuts.
+---
+---Some of its characteristics can be changed by altering its fields.
+---
+---@param request pinnacle.portal.GlobalShortcutsRequest
+---
+---@return pinnacle.snowcap.integration.GlobalShortcutsPrompt
+function integration.global_shortcuts_prompt(request)
+    local Widget = require("snowcap.widget")
+
+    local base = require("snowcap.widget.base").Base.new()
+    setmetatable(base, { __index = GlobalShortcutsPrompt })
+
+    local prompt = {
+        border_radius = 12.0,
+        border_thickness = 6.0,
+        background_color = Widget.color.from_rgba(0.15, 0.03, 0.1, 0.65),
+        border_color = Widget.color.from_rgba(0.8, 0.2, 0.4),
+        font = {
+            family = Widget.font.family.Name("Ubuntu"),
+        },
+        width = 500,
+        height = 300,
+        request = request,
+    }
+
+    for k, v in pairs(prompt) do
+        base[k] = v
+    end
+
+    ---@cast base pinnacle.snowcap.integration.GlobalShortcutsPrompt
+
+    return base
+end
+
 ---Creates the default bind overlay.
 ---
 ---Some of its characteristics can be changed by altering its fields.
@@ -1054,4 +2189,128 @@ function integration.focus_border_with_titlebar(window)
     return base
 end
 
+---Creates a dialog titled `title` that runs `on_confirm` when confirmed.
+---
+---Some of its characteristics can be changed by altering its fields.
+---
+---Example:
+---
+---```lua
+---require("pinnacle.snowcap").integration
+---    .confirm_dialog("Quit Pinnacle?", function()
+---        require("pinnacle").quit()
+---    end)
+---    :show()
+---```
+---
+---@param title string
+---@param on_confirm fun()
+---
+---@return pinnacle.snowcap.integration.ConfirmDialog
+function integration.confirm_dialog(title, on_confirm)
+    ---@type pinnacle.snowcap.integration.ConfirmDialog
+    local dialog = {
+        title = title,
+        message = nil,
+        confirm = { label = "Confirm", on_press = on_confirm },
+        cancel_label = "Cancel",
+        theme = default_menu_theme(),
+    }
+
+    setmetatable(dialog, { __index = ConfirmDialog })
+
+    return dialog
+end
+
+---Creates a power menu with buttons to log out, suspend, reboot, and shut down.
+---
+---Suspend, reboot, and shut down use `systemctl`.
+---Some of its characteristics can be changed by altering its fields.
+---
+---@return pinnacle.snowcap.integration.PowerMenu
+function integration.power_menu()
+    local function systemctl(verb)
+        return function()
+            require("pinnacle.process").spawn("systemctl", verb)
+        end
+    end
+
+    ---@type pinnacle.snowcap.integration.PowerMenu
+    local menu = {
+        title = "Power",
+        buttons = {
+            {
+                label = "Log out",
+                on_press = function()
+                    require("pinnacle").quit()
+                end,
+            },
+            { label = "Suspend", on_press = systemctl("suspend") },
+            { label = "Reboot", on_press = systemctl("reboot") },
+            { label = "Shut down", on_press = systemctl("poweroff") },
+        },
+        confirm = true,
+        theme = default_menu_theme(),
+    }
+
+    setmetatable(menu, { __index = PowerMenu })
+
+    return menu
+end
+
+---Creates a bar with the default theme.
+---
+---Modules are added by inserting them into the `left`, `center`, and `right` fields.
+---
+---Example:
+---
+---```lua
+---local bar = require("pinnacle.snowcap").integration.bar()
+---bar.left = { "tag_pager" }
+---bar.center = { "focused_title" }
+---bar.right = { "battery", { clock = "%a %d %b %H:%M" } }
+---bar:show()
+---```
+---
+---@return pinnacle.snowcap.integration.Bar
+function integration.bar()
+    local Widget = require("snowcap.widget")
+
+    local base = require("snowcap.widget.base").Base.new()
+    setmetatable(base, { __index = Bar })
+
+    ---@type pinnacle.snowcap.integration.Bar
+    local bar = {
+        left = {},
+        center = {},
+        right = {},
+        bottom = false,
+        auto_hide = false,
+        theme = {
+            height = 24,
+            spacing = 12.0,
+            font = {
+                family = Widget.font.family.Name("Ubuntu"),
+            },
+            text_size = 14.0,
+            background_color = Widget.color.from_rgba(0.1, 0.1, 0.15, 0.9),
+            text_color = Widget.color.from_rgba(0.9, 0.9, 0.9),
+            empty_tag_color = Widget.color.from_rgba(0.5, 0.5, 0.5),
+            active_tag_color = Widget.color.from_rgba(0.4, 0.15, 0.7),
+            urgent_tag_color = Widget.color.from_rgba(0.8, 0.2, 0.4),
+        },
+        clocks = {},
+        revealed = false,
+        bindings = {},
+    }
+
+    for k, v in pairs(bar) do
+        base[k] = v
+    end
+
+    ---@cast base pinnacle.snowcap.integration.Bar
+
+    return base
+end
+
 return snowcap
@@ -4,6 +4,7 @@
 
 local log = require("pinnacle.log")
 local client = require("pinnacle.grpc.client").client
+local tag_v1 = require("pinnacle.grpc.defs").pinnacle.tag.v1
 
 local set_or_toggle = {
     SET = require("pinnacle.grpc.defs").pinnacle.util.v1.SetOrToggle.SET_OR_TOGGLE_SET,
@@ -99,7 +100,7 @@ function tag.get(name, output)
 
     local handles = tag.get_all()
 
-    ---@type (fun(): { output: pinnacle.output.OutputHandle, name: string })[]
+    ---@type (fun(): { output: pinnacle.output.OutputHandle?, name: string })[]
     local requests = {}
 
     for i, handle in ipairs(handles) do
@@ -114,7 +115,7 @@ function tag.get(name, output)
     local props = require("pinnacle.util").batch(requests)
 
     for i, prop in ipairs(props) do
-        if prop.output.name == output.name and prop.name == name then
+        if prop.output and prop.output.name == output.name and prop.name == name then
             return handles[i]
         end
     end
@@ -256,16 +257,213 @@ function tag.move_to_output(output, tags)
     return true, nil
 end
 
+---Switches the given output back to the tags that were active before its last tag change.
+---
+---If `output` is not specified, this uses the focused output.
+---Calling this repeatedly flips between the last two sets of tags, like `Mod+Tab`
+---in awesome. Each output remembers its own history.
+---
+---Returns the tags that were switched to, which are empty if there was nothing to go back to.
+---
+---#### Example
+---```lua
+---Input.keybind({ "super" }, "Tab", function()
+---    Tag.switch_to_previous()
+---end)
+---```
+---
+---@param output pinnacle.output.OutputHandle? The output to switch tags on, or `nil` for the focused output.
+---
+---@return pinnacle.tag.TagHandle[]
+function tag.switch_to_previous(output)
+    local response, err = client:pinnacle_tag_v1_TagService_SwitchToPrevious({
+        output_name = output and output.name,
+    })
+
+    if err then
+        log.error(err)
+        return {}
+    end
+
+    return tag_handle.new_from_table(response and response.tag_ids or {})
+end
+
+---@param data pinnacle.tag.v1.SwitchToMatchingRequest
+---@return pinnacle.tag.TagHandle | nil
+local function switch_to_matching(data)
+    local response, err = client:pinnacle_tag_v1_TagService_SwitchToMatching(data)
+
+    if err then
+        log.error(err)
+        return nil
+    end
+
+    if not (response and response.tag_id) then
+        return nil
+    end
+
+    return tag_handle.new(response.tag_id)
+end
+
+---Switches to the tag at position `index` on the given output.
+---
+---If `output` is not specified, this uses the focused output.
+---Indices start at 1 and count tags in the order they were added.
+---
+---Returns the tag that was switched to, or `nil` if there is no such tag.
+---
+---#### Example
+---```lua
+--- -- Switch the focused output to its third tag
+---Tag.switch_to_index(3)
+---
+--- -- Switch "DP-1" to its first tag
+---Tag.switch_to_index(1, Output.get_by_name("DP-1"))
+---```
+---
+---@param index integer The position of the tag on the output.
+---@param output pinnacle.output.OutputHandle? The output to switch tags on, or `nil` for the focused output.
+---
+---@return pinnacle.tag.TagHandle | nil
+function tag.switch_to_index(index, output)
+    if index < 1 then
+        return nil
+    end
+
+    return switch_to_matching({
+        output_name = output and output.name,
+        index = index - 1,
+    })
+end
+
+---Switches to the first tag with the given name on the given output.
+---
+---If `output` is not specified, this uses the focused output.
+---
+---Returns the tag that was switched to, or `nil` if there is no such tag.
+---
+---#### Example
+---```lua
+---if not Tag.switch_to_name("Browser") then
+---    print("no tag named Browser")
+---end
+---```
+---
+---@param name string The name of the tag.
+---@param output pinnacle.output.OutputHandle? The output to switch tags on, or `nil` for the focused output.
+---
+---@return pinnacle.tag.TagHandle | nil
+function tag.switch_to_name(name, output)
+    return switch_to_matching({
+        output_name = output and output.name,
+        name = name,
+    })
+end
+
+---@enum (key) pinnacle.tag.EffectTarget
+local effect_target_to_value = {
+    ---Windows on the tag.
+    windows_on_tag = tag_v1.EffectRuleTarget.EFFECT_RULE_TARGET_WINDOWS_ON_TAG,
+    ---Windows on the tag's output that aren't on the tag.
+    other_windows = tag_v1.EffectRuleTarget.EFFECT_RULE_TARGET_OTHER_WINDOWS,
+}
+
+---An effect applied to windows while a tag is active.
+---
+---@class pinnacle.tag.EffectRule
+---The tag whose active state the rule is keyed on.
+---@field tag pinnacle.tag.TagHandle
+---What the effect applies to.
+---@field target pinnacle.tag.EffectTarget
+---The opacity of windows, from 0.0 to 1.0. Defaults to 1.0.
+---@field opacity number?
+---How much to darken windows, from 0.0 to 1.0.
+---@field dim number?
+---How much to desaturate windows, from 0.0 to 1.0.
+---@field desaturate number?
+
+---Adds an effect applied to windows while a tag is active.
+---
+---The compositor keeps effects in sync with the tag's active state itself,
+---so windows change as soon as the tag is toggled.
+---When multiple rules apply to a window, their effects stack.
+---
+---Effect rules are cleared when the config reloads.
+---
+---#### Example
+---```lua
+--- -- While "video" is active, make other windows on its output translucent
+---Tag.add_effect_rule({
+---    tag = Tag.get("video"),
+---    target = "other_windows",
+---    opacity = 0.8,
+---})
+---```
+---
+---@param rule pinnacle.tag.EffectRule
+function tag.add_effect_rule(rule)
+    local _, err = client:pinnacle_tag_v1_TagService_AddEffectRule({
+        tag_id = rule.tag.id,
+        target = effect_target_to_value[rule.target],
+        opacity = rule.opacity or 1.0,
+        dim = rule.dim or 0.0,
+        desaturate = rule.desaturate or 0.0,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
+---What happens when an output is left with no active tags.
+---@enum (key) pinnacle.tag.EmptyOutputPolicy
+local empty_output_policy_to_value = {
+    ---Nothing is shown but the background.
+    blank = tag_v1.EmptyOutputPolicy.EMPTY_OUTPUT_POLICY_BLANK,
+    ---The most recently active tags are activated again, or the first tag if there are none.
+    reactivate_last = tag_v1.EmptyOutputPolicy.EMPTY_OUTPUT_POLICY_REACTIVATE_LAST,
+    ---The tag named `fallback_tag_name` is activated. Outputs without such a tag are left blank.
+    fallback = tag_v1.EmptyOutputPolicy.EMPTY_OUTPUT_POLICY_FALLBACK,
+}
+
+---Sets what happens when an output is left with no active tags.
+---
+---By default outputs are left blank, which can strand you on an output with nothing
+---to focus. The policy applies as soon as an output has no active tags, so when
+---switching tags by hand, activate the new ones before deactivating the old ones.
+---
+---Outputs left with no active tags are also signaled with the output `no_active_tags` signal.
+---
+---#### Example
+---```lua
+---Tag.set_empty_output_policy("fallback", "1")
+---```
+---
+---@param policy pinnacle.tag.EmptyOutputPolicy
+---@param fallback_tag_name string? The name of the tag to activate with the `"fallback"` policy.
+function tag.set_empty_output_policy(policy, fallback_tag_name)
+    local _, err = client:pinnacle_tag_v1_TagService_SetEmptyOutputPolicy({
+        policy = empty_output_policy_to_value[policy],
+        fallback_tag_name = fallback_tag_name,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
 local signal_name_to_SignalName = {
     active = "TagActive",
     created = "TagCreated",
     removed = "TagRemoved",
+    urgency_changed = "TagUrgencyChanged",
 }
 
 ---@class pinnacle.tag.TagSignal Signals related to tag events.
 ---@field active fun(tag: pinnacle.tag.TagHandle, active: boolean)? A tag was set to active or not active.
 ---@field created fun(tag: pinnacle.tag.TagHandle)? A tag was created.
 ---@field removed fun(tag: pinnacle.tag.TagHandle)? A tag was removed.
+---@field urgency_changed fun(tag: pinnacle.tag.TagHandle, urgent: boolean)? A window on an inactive tag requested attention, making the tag urgent, or an urgent tag was activated.
 
 ---@class pinnacle.tag.MoveToOutputError
 ---`true` if the output does not exist.
@@ -343,6 +541,59 @@ function TagHandle:switch_to()
     end
 end
 
+---Shows snapshots of this tag's windows over its output for `millis` milliseconds
+---without switching to it.
+---
+---The active tags, layout, and focus stay the same, so this is useful to
+---glance at what's on another tag. Peeking at a different tag on the same output
+---before the time is up replaces the current peek.
+---
+---#### Example
+---```lua
+---Tag.get("chat"):peek(2000)
+---```
+---
+---@param millis integer How long to show the peek for, in milliseconds.
+function TagHandle:peek(millis)
+    local _, err = client:pinnacle_tag_v1_TagService_Peek({
+        tag_id = self.id,
+        millis = millis,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
+---Moves this tag to position `index` among the tags on its output.
+---
+---Tags keep this order everywhere they're listed, like in `OutputHandle:tags` and
+---the pager, so bars show them in the order the config chooses.
+---Positions past the last tag move this tag to the end.
+---
+---#### Example
+---```lua
+----- Make "chat" the first tag
+---Tag.get("chat"):move_to_index(1)
+---```
+---
+---@param index integer The position to move this tag to, starting at 1.
+function TagHandle:move_to_index(index)
+    if index < 1 then
+        log.error("Tag index must be at least 1")
+        return
+    end
+
+    local _, err = client:pinnacle_tag_v1_TagService_MoveToIndex({
+        tag_id = self.id,
+        index = index - 1,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
 ---Sets whether or not this tag is active.
 ---
 ---#### Example
@@ -401,8 +652,29 @@ function TagHandle:move_to_output(output)
     return tag.move_to_output(output, { self })
 end
 
+---Returns whether this tag still exists.
+---
+---Handles can outlive their tags, for example when the tag is removed or its
+---output is disconnected. Methods on such handles do nothing. Getters whose
+---values could be mistaken for a real one return `nil`; the rest document
+---what they return instead.
+---
+---@return boolean
+function TagHandle:exists()
+    local response, err = client:pinnacle_tag_v1_TagService_Exists({ tag_id = self.id })
+
+    if err then
+        log.error(err)
+    end
+
+    return response and response.exists or false
+end
+
 ---Gets whether or not this tag is active.
 ---
+---Returns `false` if this tag doesn't exist, as a removed tag displays nothing.
+---Use `TagHandle:exists` to tell the two apart.
+---
 ---@return boolean
 function TagHandle:active()
     local response, err = client:pinnacle_tag_v1_TagService_GetActive({ tag_id = self.id })
@@ -410,9 +682,21 @@ function TagHandle:active()
     return response and response.active or false
 end
 
+---Gets whether a window on this tag requested attention while the tag was inactive.
+---
+---Urgency is cleared when the tag is activated.
+---Returns `false` if this tag doesn't exist, as no window can request attention on it.
+---
+---@return boolean
+function TagHandle:urgent()
+    local response, err = client:pinnacle_tag_v1_TagService_GetUrgent({ tag_id = self.id })
+
+    return response and response.urgent or false
+end
+
 ---Gets this tag's name.
 ---
----@return string?
+---@return string | nil name The name, or `nil` if this tag doesn't exist
 function TagHandle:name()
     local response, err = client:pinnacle_tag_v1_TagService_GetName({ tag_id = self.id })
 
@@ -420,12 +704,12 @@ function TagHandle:name()
         log.error(err)
     end
 
-    return response and response.name or ""
+    return response and response.name
 end
 
 ---Gets the output this tag is on.
 ---
----@return pinnacle.output.OutputHandle
+---@return pinnacle.output.OutputHandle | nil output The output, or `nil` if this tag doesn't exist
 function TagHandle:output()
     local response, err = client:pinnacle_tag_v1_TagService_GetOutputName({ tag_id = self.id })
 
@@ -433,12 +717,17 @@ function TagHandle:output()
         log.error(err)
     end
 
-    local output_name = response and response.output_name or ""
-    return require("pinnacle.output").handle.new(output_name)
+    if not response or not response.output_name then
+        return nil
+    end
+
+    return require("pinnacle.output").handle.new(response.output_name)
 end
 
 ---Gets the windows that have this tag.
 ---
+---Returns an empty table if this tag doesn't exist, as no window can have it.
+---
 ---@return pinnacle.window.WindowHandle[]
 function TagHandle:windows()
     local windows = require("pinnacle.window").get_all()
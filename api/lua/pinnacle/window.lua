@@ -90,6 +90,197 @@ function window.get_focused()
     return nil
 end
 
+---Gets the most recently focused window that isn't currently focused.
+---
+---This can be used to toggle focus between the last two windows.
+---
+---#### Example
+---```lua
+---local previous = Window.get_previously_focused()
+---if previous then
+---    previous:set_focused(true)
+---end
+---```
+---
+---@return pinnacle.window.WindowHandle | nil window A handle to the previously focused window
+function window.get_previously_focused()
+    local response, err = client:pinnacle_window_v1_WindowService_GetPreviouslyFocused({})
+
+    if err or not response or not response.window_id then
+        return nil
+    end
+
+    return window_handle.new(response.window_id)
+end
+
+---Focuses the window nearest to the focused window in the given direction.
+---
+---Only windows on the focused output are considered.
+---
+---#### Example
+---```lua
+---Input.keybind({ "super" }, "h", function()
+---    Window.focus_in_direction("left")
+---end)
+---```
+---
+---@param direction "left" | "right" | "up" | "down"
+---
+---@return pinnacle.window.WindowHandle | nil window The newly focused window, or `nil` if nothing is focused or there's no window in that direction
+function window.focus_in_direction(direction)
+    local response, err = client:pinnacle_window_v1_WindowService_FocusInDirection({
+        dir = util_v1.Dir["DIR_" .. direction:upper()],
+    })
+
+    if err then
+        log.error(err)
+        return nil
+    end
+
+    if not response or not response.window_id then
+        return nil
+    end
+
+    return window_handle.new(response.window_id)
+end
+
+---Starts cycling through shown windows, most recently focused first, like alt-tab.
+---
+---The cycle starts at the focused window. `Window.cycle_next` and `Window.cycle_prev`
+---raise the window cycled to without focusing it, and `Window.end_cycle` focuses it.
+---This restarts the cycle if one is in progress.
+---
+---#### Example
+---```lua
+---Window.begin_cycle()
+---Window.cycle_next()
+---Window.cycle_next()
+----- Focuses the third most recently focused window
+---Window.end_cycle()
+---```
+function window.begin_cycle()
+    local _, err = client:pinnacle_window_v1_WindowService_BeginCycle({})
+
+    if err then
+        log.error(err)
+    end
+end
+
+---@param backward boolean
+---
+---@return pinnacle.window.WindowHandle | nil
+local function cycle(backward)
+    local response, err = client:pinnacle_window_v1_WindowService_Cycle({
+        backward = backward,
+    })
+
+    if err then
+        log.error(err)
+        return nil
+    end
+
+    if not response or not response.window_id then
+        return nil
+    end
+
+    return window_handle.new(response.window_id)
+end
+
+---Cycles to the next less recently focused window and raises it.
+---
+---Wraps around to the focused window after the least recently focused one.
+---Starts a cycle if there isn't one.
+---
+---@return pinnacle.window.WindowHandle | nil window The window cycled to
+function window.cycle_next()
+    return cycle(false)
+end
+
+---Cycles to the next more recently focused window and raises it.
+---
+---Wraps around to the least recently focused window after the focused one.
+---Starts a cycle if there isn't one.
+---
+---@return pinnacle.window.WindowHandle | nil window The window cycled to
+function window.cycle_prev()
+    return cycle(true)
+end
+
+---Ends the current cycle, focusing the window cycled to.
+---
+---@return pinnacle.window.WindowHandle | nil window The newly focused window, or `nil` if there was no cycle
+function window.end_cycle()
+    local response, err = client:pinnacle_window_v1_WindowService_EndCycle({})
+
+    if err then
+        log.error(err)
+        return nil
+    end
+
+    if not response or not response.window_id then
+        return nil
+    end
+
+    return window_handle.new(response.window_id)
+end
+
+---Options for `Window.find`.
+---
+---@class pinnacle.window.FindOpts
+---How the query is matched. Defaults to `"fuzzy"`.
+---
+---With `"fuzzy"`, the query's characters must appear in order, ignoring case, and
+---consecutive characters and characters at the start of words rank higher.
+---With `"regex"`, the query is a regular expression and earlier matches rank higher.
+---@field mode ("fuzzy" | "regex")?
+---The maximum number of windows to return.
+---@field limit integer?
+
+---Finds windows whose title or app id match `query`, best match first.
+---
+---Matching is done by Pinnacle, so this is much cheaper than getting every window's
+---title and app id.
+---
+---#### Example
+---```lua
+----- Jump to the best match for "ffx", e.g. Firefox
+---local windows = Window.find("ffx", { limit = 1 })
+---if windows[1] then
+---    windows[1]:set_focused(true)
+---end
+---```
+---
+---@param query string
+---@param opts pinnacle.window.FindOpts?
+---
+---@return pinnacle.window.WindowHandle[] windows The matching windows.
+---@return string? error Why the query couldn't be matched if it's an invalid regex.
+function window.find(query, opts)
+    opts = opts or {}
+
+    local mode = window_v1.FindMode.FIND_MODE_FUZZY
+    if opts.mode == "regex" then
+        mode = window_v1.FindMode.FIND_MODE_REGEX
+    end
+
+    local response, err = client:pinnacle_window_v1_WindowService_Find({
+        query = query,
+        mode = mode,
+        limit = opts.limit,
+    })
+
+    if err then
+        log.error(err)
+        return {}, err
+    end
+
+    if response and response.error then
+        return {}, response.error
+    end
+
+    return window_handle.new_from_table(response and response.window_ids or {}), nil
+end
+
 ---Begins moving this window using the specified mouse button.
 ---
 ---The button must be pressed at the time this method is called.
@@ -134,6 +325,386 @@ function window.begin_resize(button)
     end
 end
 
+---How much of floating windows must remain on-screen.
+---
+---Either a string or a table of the form `{ keep_visible = <pixels> }`.
+---@alias pinnacle.window.FloatingVisibility
+---| "unrestricted" Floating windows can be placed anywhere, including fully offscreen.
+---| "clamp_to_outputs" Floating windows must stay fully inside an output if they fit.
+---| { keep_visible: integer } At least this many pixels of floating windows must remain on an output along each axis.
+
+---Sets how much of floating windows must remain on-screen.
+---
+---This is applied when floating windows are moved with the mouse or through
+---`WindowHandle:set_geometry`. Windows can opt out with `WindowHandle:set_allow_offscreen`.
+---
+---#### Example
+---```lua
+----- Always keep at least 64 pixels of floating windows visible
+---Window.set_floating_visibility({ keep_visible = 64 })
+---```
+---
+---@param visibility pinnacle.window.FloatingVisibility
+function window.set_floating_visibility(visibility)
+    ---@type pinnacle.window.v1.SetFloatingVisibilityRequest
+    local request = {}
+
+    if visibility == "unrestricted" then
+        request.unrestricted = {}
+    elseif visibility == "clamp_to_outputs" then
+        request.clamp_to_outputs = {}
+    elseif type(visibility) == "table" and visibility.keep_visible then
+        request.keep_visible = { pixels = visibility.keep_visible }
+    else
+        log.error("invalid floating visibility")
+        return
+    end
+
+    local _, err = client:pinnacle_window_v1_WindowService_SetFloatingVisibility(request)
+
+    if err then
+        log.error(err)
+    end
+end
+
+---Sets whether windows that stop responding are greyed out.
+---
+---Windows are unresponsive when they take too long to respond to the compositor,
+---usually because the app froze. They are no longer greyed out once they catch up.
+---
+---#### Example
+---```lua
+---Window.set_grey_out_unresponsive(true)
+---```
+---
+---@param grey_out boolean
+function window.set_grey_out_unresponsive(grey_out)
+    local _, err = client:pinnacle_window_v1_WindowService_SetGreyOutUnresponsive({
+        grey_out = grey_out,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
+---What Pinnacle does when a window asks to be maximized.
+---
+---- `"honor"`: Maximize the window.
+---- `"ignore"`: Leave the window as is.
+---- `"fullscreen_within_tile"`: Tell the window it's fullscreen but leave it in its tile.
+---- `"float_and_maximize"`: Float the window and maximize it, so it's floating once unmaximized.
+---@alias pinnacle.window.MaximizeRequestPolicy
+---| "honor"
+---| "ignore"
+---| "fullscreen_within_tile"
+---| "float_and_maximize"
+
+---@type table<pinnacle.window.MaximizeRequestPolicy, pinnacle.window.v1.MaximizeRequestPolicy>
+local maximize_request_policy = {
+    honor = window_v1.MaximizeRequestPolicy.MAXIMIZE_REQUEST_POLICY_HONOR,
+    ignore = window_v1.MaximizeRequestPolicy.MAXIMIZE_REQUEST_POLICY_IGNORE,
+    fullscreen_within_tile = window_v1.MaximizeRequestPolicy.MAXIMIZE_REQUEST_POLICY_FULLSCREEN_WITHIN_TILE,
+    float_and_maximize = window_v1.MaximizeRequestPolicy.MAXIMIZE_REQUEST_POLICY_FLOAT_AND_MAXIMIZE,
+}
+
+---Sets how Pinnacle handles windows asking to be maximized.
+---
+---Window rules and `WindowHandle:set_maximize_request_policy` override this
+---for specific windows.
+---
+---#### Example
+---```lua
+----- Keep apps that maximize themselves on startup tiled
+---Window.set_maximize_request_policy("ignore")
+---```
+---
+---@param policy pinnacle.window.MaximizeRequestPolicy
+function window.set_maximize_request_policy(policy)
+    local _, err = client:pinnacle_window_v1_WindowService_SetMaximizeRequestPolicy({
+        policy = maximize_request_policy[policy],
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
+---Which tags a new window is put on.
+---
+---Window rules that set tags always override this.
+---@alias pinnacle.window.TagInheritance
+---| "active_tags" All active tags on the focused output.
+---| "first_active_tag" Only the first active tag on the focused output.
+---| "parent" The tags of the window's parent, or the active tags on the focused output without one.
+
+---@type table<pinnacle.window.TagInheritance, pinnacle.window.v1.TagInheritance>
+local tag_inheritance = {
+    active_tags = window_v1.TagInheritance.TAG_INHERITANCE_ACTIVE_TAGS,
+    first_active_tag = window_v1.TagInheritance.TAG_INHERITANCE_FIRST_ACTIVE_TAG,
+    parent = window_v1.TagInheritance.TAG_INHERITANCE_PARENT,
+}
+
+---Sets which tags new windows are put on.
+---
+---By default, new windows are put on every active tag of the focused output,
+---which puts them on several tags when more than one is active.
+---
+---#### Example
+---```lua
+----- Put dialogs on the same tags as the window that opened them
+---Window.set_tag_inheritance("parent")
+---```
+---
+---@param inheritance pinnacle.window.TagInheritance
+function window.set_tag_inheritance(inheritance)
+    local _, err = client:pinnacle_window_v1_WindowService_SetTagInheritance({
+        inheritance = tag_inheritance[inheritance],
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
+---How dialogs, windows with a parent, are placed.
+---@alias pinnacle.window.DialogPolicy
+---| "independent" Dialogs are put on tags like any other window.
+---| "attach_to_parent" Dialogs are put on their parent's tags and centered over it.
+
+---@type table<pinnacle.window.DialogPolicy, pinnacle.window.v1.DialogPolicy>
+local dialog_policy = {
+    independent = window_v1.DialogPolicy.DIALOG_POLICY_INDEPENDENT,
+    attach_to_parent = window_v1.DialogPolicy.DIALOG_POLICY_ATTACH_TO_PARENT,
+}
+
+---Sets how dialogs, windows with a parent, are placed.
+---
+---`"attach_to_parent"` takes precedence over `Window.set_tag_inheritance`,
+---but window rules that set tags still override it.
+---
+---#### Example
+---```lua
+----- Keep file pickers with the window that opened them
+---Window.set_dialog_policy("attach_to_parent")
+---```
+---
+---@param policy pinnacle.window.DialogPolicy
+function window.set_dialog_policy(policy)
+    local _, err = client:pinnacle_window_v1_WindowService_SetDialogPolicy({
+        policy = dialog_policy[policy],
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
+---The stacking layer of a window.
+---
+---Windows are always stacked above windows in lower layers.
+---@alias pinnacle.window.WindowLayer
+---| "below" Below all normal windows.
+---| "normal" The layer windows start out in.
+---| "above" Above all normal windows.
+
+---@type table<pinnacle.window.WindowLayer, pinnacle.window.v1.WindowLayer>
+local window_layer = {
+    below = window_v1.WindowLayer.WINDOW_LAYER_BELOW,
+    normal = window_v1.WindowLayer.WINDOW_LAYER_NORMAL,
+    above = window_v1.WindowLayer.WINDOW_LAYER_ABOVE,
+}
+
+---A region of an output that a window can be snapped to with `WindowHandle:snap_to_region`.
+---
+---Either one of the predefined regions or a block of cells in the grid set with
+---`Window.set_snap_grid`. Columns and rows start at 0 from the top left.
+---@alias pinnacle.window.Region
+---| "left_half"
+---| "right_half"
+---| "top_half"
+---| "bottom_half"
+---| "left_third"
+---| "center_third"
+---| "right_third"
+---| "left_two_thirds"
+---| "right_two_thirds"
+---| "top_left_quarter"
+---| "top_right_quarter"
+---| "bottom_left_quarter"
+---| "bottom_right_quarter"
+---| { column: integer, row: integer, column_span: integer?, row_span: integer? }
+
+---@type table<string, pinnacle.window.v1.SnapPreset>
+local snap_preset = {
+    left_half = window_v1.SnapPreset.SNAP_PRESET_LEFT_HALF,
+    right_half = window_v1.SnapPreset.SNAP_PRESET_RIGHT_HALF,
+    top_half = window_v1.SnapPreset.SNAP_PRESET_TOP_HALF,
+    bottom_half = window_v1.SnapPreset.SNAP_PRESET_BOTTOM_HALF,
+    left_third = window_v1.SnapPreset.SNAP_PRESET_LEFT_THIRD,
+    center_third = window_v1.SnapPreset.SNAP_PRESET_CENTER_THIRD,
+    right_third = window_v1.SnapPreset.SNAP_PRESET_RIGHT_THIRD,
+    left_two_thirds = window_v1.SnapPreset.SNAP_PRESET_LEFT_TWO_THIRDS,
+    right_two_thirds = window_v1.SnapPreset.SNAP_PRESET_RIGHT_TWO_THIRDS,
+    top_left_quarter = window_v1.SnapPreset.SNAP_PRESET_TOP_LEFT_QUARTER,
+    top_right_quarter = window_v1.SnapPreset.SNAP_PRESET_TOP_RIGHT_QUARTER,
+    bottom_left_quarter = window_v1.SnapPreset.SNAP_PRESET_BOTTOM_LEFT_QUARTER,
+    bottom_right_quarter = window_v1.SnapPreset.SNAP_PRESET_BOTTOM_RIGHT_QUARTER,
+}
+
+---Sets the grid used by grid cell regions in `WindowHandle:snap_to_region`.
+---
+---The grid is laid over the part of an output not taken up by layer surfaces
+---like bars. It defaults to 3 columns and 2 rows.
+---
+---#### Example
+---```lua
+----- A 4x4 grid for an ultrawide monitor
+---Window.set_snap_grid(4, 4)
+---```
+---
+---@param columns integer
+---@param rows integer
+function window.set_snap_grid(columns, rows)
+    local _, err = client:pinnacle_window_v1_WindowService_SetSnapGrid({
+        columns = columns,
+        rows = rows,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
+---How popups like menus and tooltips are constrained.
+---
+---Everything is off by default.
+---
+---@class pinnacle.window.PopupPolicy
+---Move popups fully onto their output, even if the client didn't allow it.
+---@field clamp_to_output boolean?
+---Close the popups of windows hidden by a tag switch.
+---@field close_on_tag_switch boolean?
+---Close popups that hold a grab for longer than this many milliseconds.
+---
+---Popups with a grab, like most menus, get all keyboard input. Keybinds still run,
+---but ones that move keyboard focus won't take effect until the popup closes.
+---@field max_grab_duration integer?
+
+---Sets how popups like menus and tooltips are constrained.
+---
+---#### Example
+---```lua
+---Window.set_popup_policy({
+---    close_on_tag_switch = true,
+---    max_grab_duration = 30000,
+---})
+---```
+---
+---@param policy pinnacle.window.PopupPolicy
+function window.set_popup_policy(policy)
+    local _, err = client:pinnacle_window_v1_WindowService_SetPopupPolicy({
+        clamp_to_output = policy.clamp_to_output or false,
+        close_on_tag_switch = policy.close_on_tag_switch or false,
+        max_grab_duration_ms = policy.max_grab_duration,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
+---A color with channels ranging from 0.0 to 1.0.
+---
+---@class pinnacle.window.Color
+---@field red number
+---@field green number
+---@field blue number
+---Defaults to 1.0.
+---@field alpha number?
+
+---A border and titlebar drawn around windows with server-side decorations.
+---
+---Windows that draw their own decorations and fullscreen windows don't get a border.
+---
+---@class pinnacle.window.BorderStyle
+---The width of the border in logical pixels. Defaults to 2.
+---@field width integer?
+---The color of the focused window's border.
+---@field focused_color pinnacle.window.Color?
+---The color of other windows' borders.
+---@field unfocused_color pinnacle.window.Color?
+---The color of windows that want attention until they're focused.
+---@field urgent_color pinnacle.window.Color?
+---The radius of the border's outer corners in logical pixels. Defaults to 0.
+---@field corner_radius number?
+---The height of a bar above the window in logical pixels, drawn in the border's color.
+---Defaults to 0, which draws no titlebar.
+---@field titlebar_height integer?
+
+---@param color pinnacle.window.Color?
+---@param default pinnacle.window.Color
+---
+---@return pinnacle.util.v1.Color
+local function color_to_api(color, default)
+    color = color or default
+    return {
+        red = color.red,
+        green = color.green,
+        blue = color.blue,
+        alpha = color.alpha or 1.0,
+    }
+end
+
+local default_border_colors = {
+    focused = { red = 0.4, green = 0.6, blue = 1.0 },
+    unfocused = { red = 0.3, green = 0.3, blue = 0.3 },
+    urgent = { red = 0.9, green = 0.3, blue = 0.3 },
+}
+
+---@param style pinnacle.window.BorderStyle?
+---
+---@return pinnacle.window.v1.BorderStyle?
+local function border_style_to_api(style)
+    if not style then
+        return nil
+    end
+
+    return {
+        width = style.width or 2,
+        focused_color = color_to_api(style.focused_color, default_border_colors.focused),
+        unfocused_color = color_to_api(style.unfocused_color, default_border_colors.unfocused),
+        urgent_color = color_to_api(style.urgent_color, default_border_colors.urgent),
+        corner_radius = style.corner_radius or 0,
+        titlebar_height = style.titlebar_height or 0,
+    }
+end
+
+---Sets the border drawn around windows with server-side decorations,
+---or draws none if `style` is nil.
+---
+---Window rules and `WindowHandle:set_border_style` override this for specific windows.
+---
+---#### Example
+---```lua
+---Window.set_border_style({
+---    width = 3,
+---    focused_color = { red = 1.0, green = 0.6, blue = 0.2 },
+---    corner_radius = 8,
+---})
+---```
+---
+---@param style pinnacle.window.BorderStyle?
+function window.set_border_style(style)
+    local _, err = client:pinnacle_window_v1_WindowService_SetBorderStyle({
+        style = border_style_to_api(style),
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
 ---A window's current layout mode.
 ---@alias pinnacle.window.LayoutMode
 ---| "tiled" The window is tiled.
@@ -154,24 +725,54 @@ require("pinnacle.util").make_bijective(layout_mode)
 
 window.layout_mode = layout_mode
 
+---Why a window got keyboard focus.
+---@alias pinnacle.window.FocusReason
+---| "pointer" The window was clicked.
+---| "keybind" The window was focused through the API, usually from a keybind.
+---| "activation" The window or another client requested that it be activated.
+---| "map" The window was focused when it mapped.
+---| "unmap_fallback" The previously focused window closed or was hidden.
+
+local focus_reason = {
+    pointer = window_v1.FocusReason.FOCUS_REASON_POINTER,
+    keybind = window_v1.FocusReason.FOCUS_REASON_KEYBIND,
+    activation = window_v1.FocusReason.FOCUS_REASON_ACTIVATION,
+    map = window_v1.FocusReason.FOCUS_REASON_MAP,
+    unmap_fallback = window_v1.FocusReason.FOCUS_REASON_UNMAP_FALLBACK,
+}
+
+require("pinnacle.util").make_bijective(focus_reason)
+
+window.focus_reason = focus_reason
+
 local signal_name_to_SignalName = {
     pointer_enter = "WindowPointerEnter",
     pointer_leave = "WindowPointerLeave",
     focused = "WindowFocused",
     title_changed = "WindowTitleChanged",
+    focused_title_changed = "WindowFocusedTitleChanged",
     layout_mode_changed = "WindowLayoutModeChanged",
     created = "WindowCreated",
     destroyed = "WindowDestroyed",
+    unresponsive = "WindowUnresponsive",
+    minimized = "WindowMinimized",
+    urgency_changed = "WindowUrgencyChanged",
+    popup_grab_blocked_keybind = "WindowPopupGrabBlockedKeybind",
 }
 
 ---@class pinnacle.window.WindowSignal Signals related to compositor events.
 ---@field pointer_enter fun(window: pinnacle.window.WindowHandle)? The pointer entered a window.
 ---@field pointer_leave fun(window: pinnacle.window.WindowHandle)? The pointer left a window.
----@field focused fun(window: pinnacle.window.WindowHandle)? The window got keyboard focus.
+---@field focused fun(window: pinnacle.window.WindowHandle, reason: pinnacle.window.FocusReason, serial: integer)? The window got keyboard focus. `serial` increases with every focus change.
 ---@field title_changed fun(window: pinnacle.window.WindowHandle, title: string)? A window's title changed.
+---@field focused_title_changed fun(window: pinnacle.window.WindowHandle | nil, title: string, app_id: string)? The focused window or its title or app id changed. `window` is nil when no window is focused.
 ---@field layout_mode_changed fun(window: pinnacle.window.WindowHandle, layout_mode: pinnacle.window.LayoutMode)? A window's layout mode changed.
 ---@field created fun(window: pinnacle.window.WindowHandle)? A window was created.
 ---@field destroyed fun(window: pinnacle.window.WindowHandle, title: string, app_id: string)? A window was closed.
+---@field unresponsive fun(window: pinnacle.window.WindowHandle)? A window stopped responding to the compositor.
+---@field minimized fun(window: pinnacle.window.WindowHandle, minimized: boolean)? A window was minimized or unminimized.
+---@field urgency_changed fun(window: pinnacle.window.WindowHandle, demands_attention: boolean)? A window started or stopped demanding attention.
+---@field popup_grab_blocked_keybind fun(window: pinnacle.window.WindowHandle)? A keybind was pressed while one of a window's popups held the keyboard grab. Keybinds that move keyboard focus won't take effect until the popup closes.
 
 ---Connects to a window signal.
 ---
@@ -259,6 +860,206 @@ function window.add_window_rule(rule)
     end
 end
 
+---Limits on a window's size, enforced when it's configured or resized.
+---
+---These apply on top of the minimum and maximum sizes the window asks for.
+---They're ignored while the window is fullscreen.
+---
+---@class pinnacle.window.SizeConstraints
+---The smallest size the window can be.
+---@field min_size { width: integer, height: integer }?
+---The largest size the window can be.
+---@field max_size { width: integer, height: integer }?
+---Fixes the window's size, overriding `min_size` and `max_size`.
+---@field fixed_size { width: integer, height: integer }?
+---The window's width divided by its height.
+---
+---The window is shrunk to keep this ratio, even if that makes it smaller than `min_size`.
+---@field aspect_ratio number?
+
+---@param constraints pinnacle.window.SizeConstraints?
+---
+---@return pinnacle.window.v1.SizeConstraints?
+local function size_constraints_to_api(constraints)
+    if not constraints then
+        return nil
+    end
+
+    local min_size = constraints.fixed_size or constraints.min_size
+    local max_size = constraints.fixed_size or constraints.max_size
+
+    return {
+        min_w = min_size and min_size.width,
+        min_h = min_size and min_size.height,
+        max_w = max_size and max_size.width,
+        max_h = max_size and max_size.height,
+        aspect_ratio = constraints.aspect_ratio,
+    }
+end
+
+---A window rule that Pinnacle matches and applies itself.
+---
+---Unlike rules added with `Window.add_window_rule`, these are plain data,
+---so they can be replaced at any time with `Window.set_window_rules`.
+---Declarative rules are applied before rules added with `Window.add_window_rule`.
+---
+---@class pinnacle.window.WindowRule
+---@field name string A name for this rule, reported by `WindowHandle:matching_window_rules`.
+---@field priority integer? Rules with a higher priority are applied later. Defaults to 0.
+---@field app_id string? Match windows whose app id is exactly this.
+---@field title string? Match windows whose title contains this.
+---@field layout_mode pinnacle.window.LayoutMode? The layout mode to open matching windows in.
+---@field decoration_mode ("client_side" | "server_side")? The decoration mode of matching windows.
+---@field floating_loc { x: integer, y: integer }? Where matching windows float.
+---@field floating_size { width: integer, height: integer }? The size of matching windows when floating.
+---@field tags pinnacle.tag.TagHandle[]? The tags matching windows open on.
+---@field focused boolean? Whether matching windows should be focused when they open.
+---@field maximize_request_policy pinnacle.window.MaximizeRequestPolicy? How matching windows asking to be maximized are handled.
+---@field size_constraints pinnacle.window.SizeConstraints? Limits on the size of matching windows.
+---@field border_style pinnacle.window.BorderStyle? The border drawn around matching windows. Use a `width` and `titlebar_height` of 0 to draw none.
+
+---Replaces the set of declarative window rules.
+---
+---This can be called again at any time to update the rules; new windows
+---will use the latest set. Use `Window.apply_rules_to_existing` to apply them
+---to windows that are already open.
+---
+---`strategy` decides how multiple matching rules combine:
+---- `"merge"` (the default) applies every matching rule in priority order,
+---  with later rules overriding properties set by earlier ones.
+---- `"last_match_wins"` applies only the matching rule with the highest priority.
+---
+---#### Example
+---```lua
+---Window.set_window_rules({
+---    { name = "float dialogs", title = "Open File", layout_mode = "floating" },
+---    { name = "tile firefox", priority = 10, app_id = "firefox", layout_mode = "tiled" },
+---})
+---```
+---
+---@param rules pinnacle.window.WindowRule[]
+---@param strategy ("merge" | "last_match_wins")?
+function window.set_window_rules(rules, strategy)
+    ---@type pinnacle.window.v1.DeclarativeWindowRule[]
+    local rule_msgs = {}
+
+    for _, rule in ipairs(rules) do
+        ---@type integer[]
+        local tag_ids = {}
+        for _, tg in ipairs(rule.tags or {}) do
+            table.insert(tag_ids, tg.id)
+        end
+
+        local decoration_mode = nil
+        if rule.decoration_mode == "client_side" then
+            decoration_mode = window_v1.DecorationMode.DECORATION_MODE_CLIENT_SIDE
+        elseif rule.decoration_mode == "server_side" then
+            decoration_mode = window_v1.DecorationMode.DECORATION_MODE_SERVER_SIDE
+        end
+
+        table.insert(rule_msgs, {
+            name = rule.name,
+            priority = rule.priority,
+            app_id = rule.app_id,
+            title = rule.title,
+            layout_mode = rule.layout_mode and layout_mode[rule.layout_mode],
+            decoration_mode = decoration_mode,
+            floating_x = rule.floating_loc and rule.floating_loc.x,
+            floating_y = rule.floating_loc and rule.floating_loc.y,
+            floating_w = rule.floating_size and rule.floating_size.width,
+            floating_h = rule.floating_size and rule.floating_size.height,
+            tag_ids = tag_ids,
+            focused = rule.focused,
+            maximize_request_policy = rule.maximize_request_policy
+                and maximize_request_policy[rule.maximize_request_policy],
+            size_constraints = size_constraints_to_api(rule.size_constraints),
+            border_style = border_style_to_api(rule.border_style),
+        })
+    end
+
+    local _, err = client:pinnacle_window_v1_WindowService_SetWindowRules({
+        rules = rule_msgs,
+        strategy = strategy == "last_match_wins"
+                and window_v1.WindowRuleStrategy.WINDOW_RULE_STRATEGY_LAST_MATCH_WINS
+            or window_v1.WindowRuleStrategy.WINDOW_RULE_STRATEGY_MERGE,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
+---A window property that a window rule can set.
+---@alias pinnacle.window.WindowRuleProperty
+---| "layout_mode"
+---| "floating_geometry" `floating_loc` or `floating_size`.
+---| "decoration_mode"
+---| "tags"
+---| "maximize_request_policy"
+---| "size_constraints"
+---| "border_style"
+
+---@type table<pinnacle.window.v1.WindowRuleProperty, pinnacle.window.WindowRuleProperty>
+local window_rule_property_names = {
+    [window_v1.WindowRuleProperty.WINDOW_RULE_PROPERTY_LAYOUT_MODE] = "layout_mode",
+    [window_v1.WindowRuleProperty.WINDOW_RULE_PROPERTY_FLOATING_GEOMETRY] = "floating_geometry",
+    [window_v1.WindowRuleProperty.WINDOW_RULE_PROPERTY_DECORATION_MODE] = "decoration_mode",
+    [window_v1.WindowRuleProperty.WINDOW_RULE_PROPERTY_TAGS] = "tags",
+    [window_v1.WindowRuleProperty.WINDOW_RULE_PROPERTY_MAXIMIZE_REQUEST_POLICY] = "maximize_request_policy",
+    [window_v1.WindowRuleProperty.WINDOW_RULE_PROPERTY_SIZE_CONSTRAINTS] = "size_constraints",
+    [window_v1.WindowRuleProperty.WINDOW_RULE_PROPERTY_BORDER_STYLE] = "border_style",
+}
+
+---What changed about a window when `Window.apply_rules_to_existing` applied rules to it.
+---@class pinnacle.window.WindowRuleChanges
+---The window that changed.
+---@field window pinnacle.window.WindowHandle
+---The properties of the window that changed.
+---@field changed pinnacle.window.WindowRuleProperty[]
+
+---Applies the current declarative window rules to windows that are already open.
+---
+---Rules normally only apply to windows when they open, so call this after
+---`Window.set_window_rules` to have existing windows follow the new rules too.
+---`focused` is skipped as it only makes sense when a window opens, and rules added
+---with `Window.add_window_rule` aren't run again.
+---
+---#### Example
+---```lua
+---for _, changes in ipairs(Window.apply_rules_to_existing()) do
+---    print(changes.window:app_id() .. " changed " .. table.concat(changes.changed, ", "))
+---end
+---```
+---
+---@return pinnacle.window.WindowRuleChanges[] changes The windows that changed along with what changed about them
+function window.apply_rules_to_existing()
+    local response, err = client:pinnacle_window_v1_WindowService_ApplyRulesToExisting({})
+
+    if err then
+        log.error(err)
+        return {}
+    end
+
+    assert(response)
+
+    ---@type pinnacle.window.WindowRuleChanges[]
+    local ret = {}
+
+    for _, changes in ipairs(response.windows or {}) do
+        local changed = {}
+        for _, property in ipairs(changes.changed or {}) do
+            table.insert(changed, window_rule_property_names[property])
+        end
+
+        table.insert(ret, {
+            window = window_handle.new(changes.window_id),
+            changed = changed,
+        })
+    end
+
+    return ret
+end
+
 ------------------------------------------------------------------------
 
 ---Sends a close request to this window.
@@ -401,6 +1202,60 @@ function WindowHandle:toggle_maximized()
     end
 end
 
+---Sets this window to minimized or not.
+---
+---Minimized windows keep their tags but aren't laid out, drawn, or focused.
+---Activating a minimized window, like from a taskbar, unminimizes it.
+---
+---@param minimized boolean
+function WindowHandle:set_minimized(minimized)
+    local _, err = client:pinnacle_window_v1_WindowService_SetMinimized({
+        window_id = self.id,
+        set_or_toggle = set_or_toggle[minimized],
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
+---Minimizes this window.
+---
+---This is a shorthand for `WindowHandle:set_minimized(true)`.
+function WindowHandle:minimize()
+    self:set_minimized(true)
+end
+
+---Toggles this window to and from minimized.
+---
+function WindowHandle:toggle_minimized()
+    local _, err = client:pinnacle_window_v1_WindowService_SetMinimized({
+        window_id = self.id,
+        set_or_toggle = set_or_toggle.TOGGLE,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
+---Marks this window as demanding attention, or clears it.
+---
+---Marking a window also marks its inactive tags as urgent. A focused window
+---stops demanding attention right away.
+---
+---@param demands_attention boolean
+function WindowHandle:set_demands_attention(demands_attention)
+    local _, err = client:pinnacle_window_v1_WindowService_SetDemandsAttention({
+        window_id = self.id,
+        demands_attention = demands_attention,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
 ---Sets this window to floating or not.
 ---
 ---@param floating boolean
@@ -455,17 +1310,85 @@ function WindowHandle:toggle_focused()
     end
 end
 
----Sets this window's decoration mode.
+---Sets this window's decoration mode.
+---
+---If not set, the client is allowed to choose its decoration mode, defaulting to client-side if it doesn't.
+---
+---@param mode "client_side" | "server_side" `"client_side"` to enable CSD, or `"server_side"` to enable CSD.
+function WindowHandle:set_decoration_mode(mode)
+    local _, err = client:pinnacle_window_v1_WindowService_SetDecorationMode({
+        window_id = self.id,
+        decoration_mode = mode == "client_side"
+                and defs.pinnacle.window.v1.DecorationMode.DECORATION_MODE_CLIENT_SIDE
+            or defs.pinnacle.window.v1.DecorationMode.DECORATION_MODE_SERVER_SIDE,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
+---Sets how Pinnacle handles this window asking to be maximized,
+---overriding `Window.set_maximize_request_policy`.
+---
+---@param policy pinnacle.window.MaximizeRequestPolicy
+function WindowHandle:set_maximize_request_policy(policy)
+    local _, err = client:pinnacle_window_v1_WindowService_SetMaximizeRequestPolicy({
+        window_id = self.id,
+        policy = maximize_request_policy[policy],
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
+---Sets limits on this window's size.
+---
+---This is mostly useful in window rules, for example to keep a picture-in-picture
+---player at 16:9 or to give a dropdown terminal a fixed size.
+---
+---#### Example
+---```lua
+---Window.add_window_rule(function(window)
+---    if window:title() == "Picture-in-Picture" then
+---        window:set_size_constraints({
+---            max_size = { width = 960, height = 540 },
+---            aspect_ratio = 16 / 9,
+---        })
+---    end
+---end)
+---```
+---
+---@param constraints pinnacle.window.SizeConstraints
+function WindowHandle:set_size_constraints(constraints)
+    local _, err = client:pinnacle_window_v1_WindowService_SetSizeConstraints({
+        window_id = self.id,
+        constraints = size_constraints_to_api(constraints),
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
+---Sets the border drawn around this window, overriding `Window.set_border_style`,
+---or draws none if `style` is nil.
 ---
----If not set, the client is allowed to choose its decoration mode, defaulting to client-side if it doesn't.
+---#### Example
+---```lua
+---Window.add_window_rule(function(window)
+---    if window:app_id() == "mpv" then
+---        window:set_border_style(nil)
+---    end
+---end)
+---```
 ---
----@param mode "client_side" | "server_side" `"client_side"` to enable CSD, or `"server_side"` to enable CSD.
-function WindowHandle:set_decoration_mode(mode)
-    local _, err = client:pinnacle_window_v1_WindowService_SetDecorationMode({
+---@param style pinnacle.window.BorderStyle?
+function WindowHandle:set_border_style(style)
+    local _, err = client:pinnacle_window_v1_WindowService_SetBorderStyle({
         window_id = self.id,
-        decoration_mode = mode == "client_side"
-                and defs.pinnacle.window.v1.DecorationMode.DECORATION_MODE_CLIENT_SIDE
-            or defs.pinnacle.window.v1.DecorationMode.DECORATION_MODE_SERVER_SIDE,
+        style = border_style_to_api(style),
     })
 
     if err then
@@ -503,6 +1426,24 @@ function WindowHandle:move_to_tag(tag)
     end
 end
 
+---Moves this window to the specified tag, then switches to that tag and focuses this window.
+---
+---Unlike calling `WindowHandle:move_to_tag` then `TagHandle:switch_to`,
+---this happens all at once, so the window doesn't flicker out of view in between.
+---
+---@param tag pinnacle.tag.TagHandle The tag to move this window to
+function WindowHandle:move_to_tag_and_follow(tag)
+    local _, err = client:pinnacle_window_v1_WindowService_MoveToTag({
+        window_id = self.id,
+        tag_id = tag.id,
+        follow = true,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
 ---Adds or removes the given tag to or from this window.
 ---
 ---@param tag pinnacle.tag.TagHandle The tag to set or unset
@@ -594,6 +1535,137 @@ function WindowHandle:set_vrr_demand(vrr_demand)
     end
 end
 
+---Sets whether this window is exempt from the floating visibility policy.
+---
+---This allows the window to be deliberately placed offscreen.
+---
+---@param allow_offscreen boolean
+---
+---@see pinnacle.window.set_floating_visibility
+function WindowHandle:set_allow_offscreen(allow_offscreen)
+    local _, err = client:pinnacle_window_v1_WindowService_SetAllowOffscreen({
+        window_id = self.id,
+        allow_offscreen = allow_offscreen,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
+---Sets whether this window receives pointer and keyboard input.
+---
+---A window with input disabled is still drawn, but clicks go through it to whatever
+---is below and it can't get keyboard focus. This is useful for overlays like
+---reference images or cheat sheets.
+---
+---@param enabled boolean
+function WindowHandle:set_input_enabled(enabled)
+    local _, err = client:pinnacle_window_v1_WindowService_SetInputEnabled({
+        window_id = self.id,
+        enabled = enabled,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
+---Sets whether holding this window moves it.
+---
+---This is useful for apps that use long presses themselves.
+---See `Input.set_hold_to_move`.
+---
+---@param enabled boolean
+function WindowHandle:set_hold_to_move_enabled(enabled)
+    local _, err = client:pinnacle_window_v1_WindowService_SetHoldToMoveEnabled({
+        window_id = self.id,
+        enabled = enabled,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
+---Floats this window and moves and resizes it to fill `region` of its output.
+---
+---The region doesn't include space taken up by layer surfaces like bars.
+---
+---#### Example
+---```lua
+---Window.get_focused():snap_to_region("left_half")
+---
+----- The second and third columns of the top row of the snap grid
+---Window.get_focused():snap_to_region({ column = 1, row = 0, column_span = 2 })
+---```
+---
+---@param region pinnacle.window.Region
+function WindowHandle:snap_to_region(region)
+    ---@type pinnacle.window.v1.SnapToRegionRequest
+    local request = { window_id = self.id }
+
+    if type(region) == "table" then
+        request.grid_cell = {
+            column = region.column,
+            row = region.row,
+            column_span = region.column_span or 1,
+            row_span = region.row_span or 1,
+        }
+    else
+        request.preset = snap_preset[region]
+    end
+
+    local _, err = client:pinnacle_window_v1_WindowService_SnapToRegion(request)
+
+    if err then
+        log.error(err)
+    end
+end
+
+---Asks this window to render at a different scale than its output's.
+---
+---This only changes the preferred scale sent to the window; Pinnacle doesn't
+---rescale the window itself. The window keeps its logical size, so a window that
+---follows the preferred scale gets its buffers scaled to fit. For example, a window
+---that misbehaves at a fractional scale can be asked to render at an integer one.
+---Windows that ignore the preferred scale aren't affected.
+---
+---When `scale` is nil, this window goes back to its output's scale.
+---
+---This has no effect on Xwayland windows.
+---
+---@param scale number? The scale to render at. Must be at least 0.25.
+function WindowHandle:set_scale_override(scale)
+    local _, err = client:pinnacle_window_v1_WindowService_SetScaleOverride({
+        window_id = self.id,
+        scale = scale,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
+---Gets the names of the declarative window rules that would apply to this window
+---if it opened now, in the order they would apply.
+---
+---This is useful for debugging rules set with `Window.set_window_rules`.
+---
+---@return string[]
+function WindowHandle:matching_window_rules()
+    local response, err = client:pinnacle_window_v1_WindowService_GetMatchingWindowRules({
+        window_id = self.id,
+    })
+
+    if err then
+        log.error(err)
+        return {}
+    end
+
+    return response and response.rule_names or {}
+end
+
 ---Raises a window.
 ---
 ---This will bring the window to the front.
@@ -616,8 +1688,53 @@ function WindowHandle:lower()
     end
 end
 
+---Moves this window to another stacking layer and raises it within that layer.
+---
+---`WindowHandle:raise` and `WindowHandle:lower` only move windows within their layer.
+---
+---#### Example
+---```lua
+----- Keep a picture-in-picture window above everything else
+---local focused = Window.get_focused()
+---if focused then
+---    focused:set_layer("above")
+---end
+---```
+---
+---@param layer pinnacle.window.WindowLayer
+function WindowHandle:set_layer(layer)
+    local _, err = client:pinnacle_window_v1_WindowService_SetLayer({
+        window_id = self.id,
+        layer = window_layer[layer],
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
+---Gets this window's stacking layer.
+---
+---@return pinnacle.window.WindowLayer | nil layer The layer, or `nil` if this window doesn't exist
+function WindowHandle:layer()
+    local response, err =
+        client:pinnacle_window_v1_WindowService_GetLayer({ window_id = self.id })
+
+    local layer = response and response.layer
+
+    for name, value in pairs(window_layer) do
+        if value == layer then
+            return name
+        end
+    end
+
+    return nil
+end
+
 ---Returns whether or not this window is on an active tag.
 ---
+---Returns `false` if this window doesn't exist, as it has no tags.
+---
 ---@return boolean
 function WindowHandle:is_on_active_tag()
     local tags = self:tags() or {}
@@ -644,6 +1761,8 @@ end
 
 ---Gets this window's location.
 ---
+---Returns `nil` if this window doesn't exist or isn't mapped.
+---
 ---@return { x: integer, y: integer }?
 function WindowHandle:loc()
     local loc, err = client:pinnacle_window_v1_WindowService_GetLoc({ window_id = self.id })
@@ -651,7 +1770,9 @@ function WindowHandle:loc()
     return loc and loc.loc
 end
 
----Gets this window's location.
+---Gets this window's size.
+---
+---Returns `nil` if this window doesn't exist or isn't mapped.
 ---
 ---@return { width: integer, height: integer }?
 function WindowHandle:size()
@@ -660,8 +1781,28 @@ function WindowHandle:size()
     return loc and loc.size
 end
 
+---Returns whether this window still exists.
+---
+---Handles outlive the windows they refer to once those windows close.
+---Methods on such handles do nothing. Getters whose values could be mistaken
+---for a real one return `nil`; the rest document what they return instead.
+---
+---@return boolean
+function WindowHandle:exists()
+    local response, err = client:pinnacle_window_v1_WindowService_Exists({ window_id = self.id })
+
+    if err then
+        log.error(err)
+    end
+
+    return response and response.exists or false
+end
+
 ---Gets this window's class.
 ---
+---If it doesn't have one or doesn't exist, this returns an empty string,
+---the same as a window that never set one. Use `WindowHandle:exists` to tell them apart.
+---
 ---@return string
 function WindowHandle:app_id()
     local response, err = client:pinnacle_window_v1_WindowService_GetAppId({ window_id = self.id })
@@ -671,6 +1812,9 @@ end
 
 ---Gets this window's title.
 ---
+---If it doesn't have one or doesn't exist, this returns an empty string,
+---the same as a window that never set one. Use `WindowHandle:exists` to tell them apart.
+---
 ---@return string
 function WindowHandle:title()
     local response, err = client:pinnacle_window_v1_WindowService_GetTitle({ window_id = self.id })
@@ -680,6 +1824,8 @@ end
 
 ---Gets whether or not this window is focused.
 ---
+---Returns `false` if this window doesn't exist, as it can't hold focus.
+---
 ---@return boolean
 function WindowHandle:focused()
     local response, err =
@@ -688,6 +1834,39 @@ function WindowHandle:focused()
     return response and response.focused or false
 end
 
+---Gets how many windows were focused more recently than this one.
+---
+---The most recently focused window has a focus age of 0. Sorting windows by
+---their focus age gives the most-recently-used order, like for an alt-tab switcher.
+---
+---@return integer | nil focus_age The focus age, or `nil` if this window was never focused or doesn't exist.
+function WindowHandle:focus_age()
+    local response, err =
+        client:pinnacle_window_v1_WindowService_GetFocusAge({ window_id = self.id })
+
+    return response and response.focus_age
+end
+
+---Gets whether or not this window is responding to the compositor.
+---
+---Windows that take too long to respond, usually because the app froze,
+---are unresponsive until they catch up.
+---
+---Returns `true` if this window doesn't exist, so closed windows aren't
+---mistaken for frozen ones.
+---
+---@return boolean
+function WindowHandle:is_responsive()
+    local response, err =
+        client:pinnacle_window_v1_WindowService_GetResponsive({ window_id = self.id })
+
+    if not response then
+        return true
+    end
+
+    return response.responsive or false
+end
+
 ---Gets this window's output.
 ---
 ---This is currently implemented as the output of the first tag on this window.
@@ -703,6 +1882,8 @@ end
 
 ---Gets whether or not this window is floating.
 ---
+---Returns `false` if this window doesn't exist.
+---
 ---@return boolean
 function WindowHandle:floating()
     local response, err =
@@ -715,6 +1896,8 @@ end
 
 ---Gets whether this window is tiled.
 ---
+---Returns `false` if this window doesn't exist.
+---
 ---@return boolean
 function WindowHandle:tiled()
     local response, err =
@@ -740,6 +1923,8 @@ end
 
 ---Gets whether this window is fullscreen.
 ---
+---Returns `false` if this window doesn't exist.
+---
 ---@return boolean
 function WindowHandle:fullscreen()
     local response, err =
@@ -750,6 +1935,8 @@ end
 
 ---Gets whether this window is maximized.
 ---
+---Returns `false` if this window doesn't exist.
+---
 ---@return boolean
 function WindowHandle:maximized()
     local response, err =
@@ -758,8 +1945,80 @@ function WindowHandle:maximized()
     return response and response.layout_mode == layout_mode_def.LAYOUT_MODE_MAXIMIZED or false
 end
 
+---Gets whether this window is minimized.
+---
+---Returns `false` if this window doesn't exist.
+---
+---@return boolean
+function WindowHandle:minimized()
+    local response, err =
+        client:pinnacle_window_v1_WindowService_GetMinimized({ window_id = self.id })
+
+    return response and response.minimized or false
+end
+
+---Gets whether this window demands attention.
+---
+---Windows demand attention through xdg-activation or X11 urgency hints
+---and stop once they're focused.
+---
+---Returns `false` if this window doesn't exist, as it can no longer be focused.
+---
+---@return boolean
+function WindowHandle:demands_attention()
+    local response, err =
+        client:pinnacle_window_v1_WindowService_GetDemandsAttention({ window_id = self.id })
+
+    return response and response.demands_attention or false
+end
+
+---The icon of a window.
+---
+---@class pinnacle.window.WindowIcon
+---The name of the icon in the icon theme.
+---@field name string?
+---Images of the icon, used if there's no name or it isn't in the icon theme.
+---
+---Each image is `size` by `size` pixels of non-premultiplied RGBA8, row by row, in `rgba`.
+---@field images { size: integer, scale: integer, rgba: string }[]
+
+---Gets this window's icon.
+---
+---This is the icon the window set through xdg-toplevel-icon or, if it didn't,
+---the icon named in the desktop entry for its app id.
+---
+---@return pinnacle.window.WindowIcon | nil icon The icon, or `nil` if the window has none or doesn't exist.
+function WindowHandle:icon()
+    local response, err = client:pinnacle_window_v1_WindowService_GetIcon({ window_id = self.id })
+
+    if not response then
+        return nil
+    end
+
+    local images = {}
+    for _, image in ipairs(response.images or {}) do
+        table.insert(images, {
+            size = image.size or 0,
+            scale = image.scale or 0,
+            rgba = image.rgba or "",
+        })
+    end
+
+    if not response.name and #images == 0 then
+        return nil
+    end
+
+    ---@type pinnacle.window.WindowIcon
+    return {
+        name = response.name,
+        images = images,
+    }
+end
+
 ---Gets all tags on this window.
 ---
+---Returns an empty table if this window doesn't exist.
+---
 ---@return pinnacle.tag.TagHandle[]
 function WindowHandle:tags()
     local response, err = client:pinnacle_window_v1_WindowService_GetTagIds({ window_id = self.id })
@@ -771,6 +2030,35 @@ function WindowHandle:tags()
     return handles
 end
 
+---Gets this window's parent.
+---
+---This is the window a dialog was opened for, set through xdg-toplevel's parent
+---or X11's transient-for hint.
+---
+---@return pinnacle.window.WindowHandle | nil parent The parent, or `nil` if this window has none or doesn't exist
+function WindowHandle:parent()
+    local response, err =
+        client:pinnacle_window_v1_WindowService_GetParent({ window_id = self.id })
+
+    if err or not response or not response.parent_id then
+        return nil
+    end
+
+    return window_handle.new(response.parent_id)
+end
+
+---Gets the windows whose parent is this window.
+---
+---Returns an empty table if this window doesn't exist.
+---
+---@return pinnacle.window.WindowHandle[]
+function WindowHandle:children()
+    local response, err =
+        client:pinnacle_window_v1_WindowService_GetChildren({ window_id = self.id })
+
+    return window_handle.new_from_table(response and response.child_ids or {})
+end
+
 ---Gets all windows in the provided direction, sorted closest to farthest.
 ---
 ---@param direction "left" | "right" | "up" | "down"
@@ -811,6 +2099,68 @@ function WindowHandle:foreign_toplevel_list_identifier()
     return identifier and identifier.identifier
 end
 
+---Changes to a window's properties. Only the properties that changed are set.
+---@class pinnacle.window.PropertiesChange
+---@field app_id string?
+---@field title string?
+---@field loc { x: integer, y: integer }?
+---@field size { width: integer, height: integer }?
+---@field focused boolean?
+---@field layout_mode pinnacle.window.LayoutMode?
+---@field tags pinnacle.tag.TagHandle[]?
+---@field cleared { loc: true?, size: true? } Properties that were set and now aren't, because the window was unmapped.
+
+---Watches this window's properties, calling `on_change` when they change.
+---
+---`on_change` is first called with every property set, then with only the properties
+---that changed. Use this instead of polling properties to keep something like a bar
+---up to date.
+---
+---Watching ends when the window closes.
+---
+---#### Example
+---```lua
+---window:watch(function(change)
+---    if change.title then
+---        print("New title: " .. change.title)
+---    end
+---end)
+---```
+---
+---@param on_change fun(change: pinnacle.window.PropertiesChange)
+function WindowHandle:watch(on_change)
+    local err = client:pinnacle_window_v1_WindowService_WatchProperties({
+        window_id = self.id,
+    }, function(response)
+        ---@type pinnacle.window.PropertiesChange
+        local change = {
+            app_id = response.app_id,
+            title = response.title,
+            loc = response.loc,
+            size = response.size,
+            focused = response.focused,
+            layout_mode = response.layout_mode and layout_mode[response.layout_mode],
+            tags = response.tag_ids
+                and require("pinnacle.tag").handle.new_from_table(response.tag_ids.tag_ids or {}),
+            cleared = {},
+        }
+
+        for _, property in ipairs(response.cleared or {}) do
+            if property == window_v1.ClearableProperty.CLEARABLE_PROPERTY_LOC then
+                change.cleared.loc = true
+            elseif property == window_v1.ClearableProperty.CLEARABLE_PROPERTY_SIZE then
+                change.cleared.size = true
+            end
+        end
+
+        on_change(change)
+    end)
+
+    if err then
+        log.error(err)
+    end
+end
+
 ---Swap position with another window.
 ---
 ---@param target pinnacle.window.WindowHandle
@@ -828,6 +2178,42 @@ function WindowHandle:swap(target)
     end
 end
 
+---Swaps position with the window nearest to this one in the given direction
+---on the same output.
+---
+---Like `WindowHandle:swap`, this doesn't run the layout again.
+---
+---#### Example
+---```lua
+---Input.keybind({ "super", "shift" }, "h", function()
+---    local focused = Window.get_focused()
+---    if focused then
+---        focused:swap_in_direction("left")
+---    end
+---end)
+---```
+---
+---@param direction "left" | "right" | "up" | "down"
+---
+---@return pinnacle.window.WindowHandle | nil window The window swapped with, or `nil` if there's no window in that direction
+function WindowHandle:swap_in_direction(direction)
+    local response, err = client:pinnacle_window_v1_WindowService_SwapInDirection({
+        window_id = self.id,
+        dir = util_v1.Dir["DIR_" .. direction:upper()],
+    })
+
+    if err then
+        log.error(err)
+        return nil
+    end
+
+    if not response or not response.target_id then
+        return nil
+    end
+
+    return window_handle.new(response.target_id)
+end
+
 ---Convert a WindowHandle to a string
 ---
 ---@param win pinnacle.window.WindowHandle
@@ -470,6 +470,12 @@ async fn config() {
         output.focus();
     })));
 
+    // Ask before letting apps bind global shortcuts
+    #[cfg(feature = "snowcap")]
+    pinnacle_api::portal::on_global_shortcuts_request(|request| {
+        pinnacle_api::snowcap::GlobalShortcutsPrompt::new(request).show();
+    });
+
     #[cfg(feature = "snowcap")]
     if let Some(error) = pinnacle_api::pinnacle::take_last_error() {
         // Show previous crash messages
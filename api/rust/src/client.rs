@@ -1,34 +1,56 @@
 use pinnacle_api_defs::pinnacle::{
     debug::v1::debug_service_client::DebugServiceClient,
+    idle::v1::idle_service_client::IdleServiceClient,
     input::v1::input_service_client::InputServiceClient,
     layout::v1::layout_service_client::LayoutServiceClient,
+    nightlight::v1::nightlight_service_client::NightlightServiceClient,
     output::v1::output_service_client::OutputServiceClient,
+    portal::v1::portal_service_client::PortalServiceClient,
+    power::v1::power_service_client::PowerServiceClient,
     process::v1::process_service_client::ProcessServiceClient,
     render::v1::render_service_client::RenderServiceClient,
+    screenshot::v1::screenshot_service_client::ScreenshotServiceClient,
     signal::v1::signal_service_client::SignalServiceClient,
     tag::v1::tag_service_client::TagServiceClient,
     v1::pinnacle_service_client::PinnacleServiceClient,
     window::v1::window_service_client::WindowServiceClient,
 };
-use tokio::sync::{MappedMutexGuard, Mutex, MutexGuard, RwLock, RwLockReadGuard};
-use tonic::transport::Channel;
+use std::{
+    sync::atomic::{AtomicU64, Ordering},
+    task::{Context, Poll},
+    time::Duration,
+};
+
+use futures::future::BoxFuture;
+use tokio::sync::{MappedMutexGuard, Mutex, MutexGuard, Notify, RwLock, RwLockReadGuard};
+use tonic::{Status, body::Body, codegen::http, transport::Channel};
+use tower::Service;
 
 use crate::{BlockOnTokio, signal::SignalState};
 
 static CLIENT: RwLock<Option<Client>> = RwLock::const_new(None);
 static SIGNAL_STATE: Mutex<Option<SignalState>> = Mutex::const_new(None);
 
+/// The request deadline in milliseconds, or 0 for no deadline.
+static REQUEST_DEADLINE_MILLIS: AtomicU64 = AtomicU64::new(0);
+static REQUEST_CANCELLATION: Notify = Notify::const_new();
+
 pub struct Client {
-    pinnacle: PinnacleServiceClient<Channel>,
-    window: WindowServiceClient<Channel>,
-    tag: TagServiceClient<Channel>,
-    output: OutputServiceClient<Channel>,
-    input: InputServiceClient<Channel>,
-    process: ProcessServiceClient<Channel>,
-    layout: LayoutServiceClient<Channel>,
-    render: RenderServiceClient<Channel>,
-    signal: SignalServiceClient<Channel>,
-    debug: DebugServiceClient<Channel>,
+    pinnacle: PinnacleServiceClient<DeadlineChannel>,
+    window: WindowServiceClient<DeadlineChannel>,
+    tag: TagServiceClient<DeadlineChannel>,
+    output: OutputServiceClient<DeadlineChannel>,
+    input: InputServiceClient<DeadlineChannel>,
+    process: ProcessServiceClient<DeadlineChannel>,
+    layout: LayoutServiceClient<DeadlineChannel>,
+    render: RenderServiceClient<DeadlineChannel>,
+    screenshot: ScreenshotServiceClient<DeadlineChannel>,
+    portal: PortalServiceClient<DeadlineChannel>,
+    idle: IdleServiceClient<DeadlineChannel>,
+    nightlight: NightlightServiceClient<DeadlineChannel>,
+    power: PowerServiceClient<DeadlineChannel>,
+    signal: SignalServiceClient<DeadlineChannel>,
+    debug: DebugServiceClient<DeadlineChannel>,
 }
 
 impl Client {
@@ -48,39 +70,59 @@ impl Client {
         })
     }
 
-    pub fn pinnacle() -> PinnacleServiceClient<Channel> {
+    pub fn pinnacle() -> PinnacleServiceClient<DeadlineChannel> {
         Self::get().pinnacle.clone()
     }
 
-    pub fn window() -> WindowServiceClient<Channel> {
+    pub fn window() -> WindowServiceClient<DeadlineChannel> {
         Self::get().window.clone()
     }
 
-    pub fn tag() -> TagServiceClient<Channel> {
+    pub fn tag() -> TagServiceClient<DeadlineChannel> {
         Self::get().tag.clone()
     }
 
-    pub fn output() -> OutputServiceClient<Channel> {
+    pub fn output() -> OutputServiceClient<DeadlineChannel> {
         Self::get().output.clone()
     }
 
-    pub fn input() -> InputServiceClient<Channel> {
+    pub fn input() -> InputServiceClient<DeadlineChannel> {
         Self::get().input.clone()
     }
 
-    pub fn process() -> ProcessServiceClient<Channel> {
+    pub fn process() -> ProcessServiceClient<DeadlineChannel> {
         Self::get().process.clone()
     }
 
-    pub fn layout() -> LayoutServiceClient<Channel> {
+    pub fn layout() -> LayoutServiceClient<DeadlineChannel> {
         Self::get().layout.clone()
     }
 
-    pub fn render() -> RenderServiceClient<Channel> {
+    pub fn render() -> RenderServiceClient<DeadlineChannel> {
         Self::get().render.clone()
     }
 
-    pub fn signal() -> SignalServiceClient<Channel> {
+    pub fn screenshot() -> ScreenshotServiceClient<DeadlineChannel> {
+        Self::get().screenshot.clone()
+    }
+
+    pub fn portal() -> PortalServiceClient<DeadlineChannel> {
+        Self::get().portal.clone()
+    }
+
+    pub fn idle() -> IdleServiceClient<DeadlineChannel> {
+        Self::get().idle.clone()
+    }
+
+    pub fn nightlight() -> NightlightServiceClient<DeadlineChannel> {
+        Self::get().nightlight.clone()
+    }
+
+    pub fn power() -> PowerServiceClient<DeadlineChannel> {
+        Self::get().power.clone()
+    }
+
+    pub fn signal() -> SignalServiceClient<DeadlineChannel> {
         Self::get().signal.clone()
     }
 
@@ -92,11 +134,22 @@ impl Client {
         })
     }
 
-    pub fn debug() -> DebugServiceClient<Channel> {
+    pub fn debug() -> DebugServiceClient<DeadlineChannel> {
         Self::get().debug.clone()
     }
 
+    pub fn set_request_deadline(deadline: Option<Duration>) {
+        let millis = deadline.map_or(0, |deadline| deadline.as_millis().max(1) as u64);
+        REQUEST_DEADLINE_MILLIS.store(millis, Ordering::Relaxed);
+    }
+
+    pub fn cancel_requests() {
+        REQUEST_CANCELLATION.notify_waiters();
+    }
+
     fn new(channel: Channel) -> Self {
+        let channel = DeadlineChannel { inner: channel };
+
         Self {
             pinnacle: PinnacleServiceClient::new(channel.clone()),
             window: WindowServiceClient::new(channel.clone()),
@@ -106,8 +159,62 @@ impl Client {
             process: ProcessServiceClient::new(channel.clone()),
             layout: LayoutServiceClient::new(channel.clone()),
             render: RenderServiceClient::new(channel.clone()),
+            screenshot: ScreenshotServiceClient::new(channel.clone()),
+            portal: PortalServiceClient::new(channel.clone()),
+            idle: IdleServiceClient::new(channel.clone()),
+            nightlight: NightlightServiceClient::new(channel.clone()),
+            power: PowerServiceClient::new(channel.clone()),
             signal: SignalServiceClient::new(channel.clone()),
             debug: DebugServiceClient::new(channel.clone()),
         }
     }
 }
+
+/// RPCs that wait on the user, so they aren't subject to the request deadline.
+const INTERACTIVE_RPCS: &[&str] = &["/pinnacle.screenshot.v1.ScreenshotService/SelectRegion"];
+
+/// A [`Channel`] that fails requests that outlive the request deadline
+/// or that get cancelled.
+#[derive(Debug, Clone)]
+pub struct DeadlineChannel {
+    inner: Channel,
+}
+
+impl Service<http::Request<Body>> for DeadlineChannel {
+    type Response = http::Response<Body>;
+    type Error = Box<dyn std::error::Error + Send + Sync>;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx).map_err(Into::into)
+    }
+
+    fn call(&mut self, request: http::Request<Body>) -> Self::Future {
+        let interactive = INTERACTIVE_RPCS.contains(&request.uri().path());
+        let response = self.inner.call(request);
+        // Created here so that cancelling catches requests that haven't been polled yet
+        let cancelled = REQUEST_CANCELLATION.notified();
+        let deadline = match REQUEST_DEADLINE_MILLIS.load(Ordering::Relaxed) {
+            _ if interactive => None,
+            0 => None,
+            millis => Some(Duration::from_millis(millis)),
+        };
+
+        Box::pin(async move {
+            let timeout = async {
+                match deadline {
+                    Some(deadline) => tokio::time::sleep(deadline).await,
+                    None => std::future::pending().await,
+                }
+            };
+
+            tokio::select! {
+                response = response => response.map_err(Into::into),
+                () = cancelled => Err(Status::cancelled("the request was cancelled").into()),
+                () = timeout => {
+                    Err(Status::deadline_exceeded("Pinnacle did not respond in time").into())
+                }
+            }
+        })
+    }
+}
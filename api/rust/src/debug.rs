@@ -2,15 +2,18 @@
 //!
 //! WARNING: This module is not governed by the API stability guarantees.
 
+use futures::{Stream, StreamExt};
 use pinnacle_api_defs::pinnacle::{
     debug::v1::{
-        SetCursorPlaneScanoutRequest, SetDamageVisualizationRequest,
-        SetOpaqueRegionVisualizationRequest, SetProcessPipingRequest,
+        GetGpuStatsRequest, SetCursorPlaneScanoutRequest, SetDamageVisualizationRequest,
+        SetInputTracingRequest, SetLogFilterRequest, SetOpaqueRegionVisualizationRequest,
+        SetProcessPipingRequest, TailLogsRequest, TraceInputRequest, TraceInputResponse,
+        trace_input_response,
     },
     util::v1::SetOrToggle,
 };
 
-use crate::{BlockOnTokio, client::Client};
+use crate::{BlockOnTokio, ResponseExt, client::Client, window::WindowHandle};
 
 /// Sets damage visualization.
 ///
@@ -26,7 +29,7 @@ pub fn set_damage_visualization(set: bool) {
             .into(),
         })
         .block_on_tokio()
-        .unwrap();
+        .log_err();
 }
 
 /// Toggles damage visualization.
@@ -39,7 +42,7 @@ pub fn toggle_damage_visualization() {
             set_or_toggle: SetOrToggle::Toggle.into(),
         })
         .block_on_tokio()
-        .unwrap();
+        .log_err();
 }
 
 /// Sets opaque region visualization.
@@ -57,7 +60,7 @@ pub fn set_opaque_region_visualization(set: bool) {
             .into(),
         })
         .block_on_tokio()
-        .unwrap();
+        .log_err();
 }
 
 /// Toggles opaque region visualization.
@@ -71,7 +74,7 @@ pub fn toggle_opaque_region_visualization() {
             set_or_toggle: SetOrToggle::Toggle.into(),
         })
         .block_on_tokio()
-        .unwrap();
+        .log_err();
 }
 
 /// Enables or disables cursor plane scanout.
@@ -85,7 +88,7 @@ pub fn set_cursor_plane_scanout(set: bool) {
             .into(),
         })
         .block_on_tokio()
-        .unwrap();
+        .log_err();
 }
 
 /// Toggles cursor plane scanout.
@@ -95,7 +98,7 @@ pub fn toggle_cursor_plane_scanout() {
             set_or_toggle: SetOrToggle::Toggle.into(),
         })
         .block_on_tokio()
-        .unwrap();
+        .log_err();
 }
 
 /// Enables or disables process spawning setting up pipes to expose fds to the config.
@@ -109,7 +112,7 @@ pub fn set_process_piping(set: bool) {
             .into(),
         })
         .block_on_tokio()
-        .unwrap();
+        .log_err();
 }
 
 /// Toggles process spawning setting up pipes to expose fds to the config.
@@ -119,5 +122,217 @@ pub fn toggle_process_piping() {
             set_or_toggle: SetOrToggle::Toggle.into(),
         })
         .block_on_tokio()
-        .unwrap();
+        .log_err();
+}
+
+/// Textures and buffers held by the compositor.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+pub struct GpuStats {
+    /// The number of live snapshot textures.
+    pub snapshot_textures: u64,
+    /// The total size of live snapshot textures, in bytes.
+    pub snapshot_texture_bytes: u64,
+    /// The number of cached cursor buffers.
+    pub cursor_buffers: u64,
+    /// The total size of cached cursor buffers, in bytes.
+    pub cursor_buffer_bytes: u64,
+    /// The number of snapshots dropped because a layout never came to replace them.
+    pub evicted_snapshots: u64,
+}
+
+/// Gets the amount of textures and buffers the compositor is holding onto.
+pub fn gpu_stats() -> GpuStats {
+    let stats = Client::debug()
+        .get_gpu_stats(GetGpuStatsRequest {})
+        .block_on_tokio()
+        .into_inner_or_default();
+
+    GpuStats {
+        snapshot_textures: stats.snapshot_textures,
+        snapshot_texture_bytes: stats.snapshot_texture_bytes,
+        cursor_buffers: stats.cursor_buffers,
+        cursor_buffer_bytes: stats.cursor_buffer_bytes,
+        evicted_snapshots: stats.evicted_snapshots,
+    }
+}
+
+/// Enables or disables input tracing.
+///
+/// When on, every input event is logged along with the pointer and keyboard focus,
+/// whether either is grabbed, and the client the event was sent to.
+/// This is useful to figure out why input is going to the wrong window.
+///
+/// Traced events can also be received with [`input_trace_stream`].
+pub fn trace_input(set: bool) {
+    Client::debug()
+        .set_input_tracing(SetInputTracingRequest {
+            set_or_toggle: match set {
+                true => SetOrToggle::Set,
+                false => SetOrToggle::Unset,
+            }
+            .into(),
+        })
+        .block_on_tokio()
+        .log_err();
+}
+
+/// Toggles input tracing.
+///
+/// See [`trace_input`] for more information.
+pub fn toggle_input_tracing() {
+    Client::debug()
+        .set_input_tracing(SetInputTracingRequest {
+            set_or_toggle: SetOrToggle::Toggle.into(),
+        })
+        .block_on_tokio()
+        .log_err();
+}
+
+/// Something an input event could be sent to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TracedFocus {
+    /// A human-readable description of the target, like `window 3 (Alacritty)`.
+    pub description: String,
+    /// The window the target belongs to, if any.
+    pub window: Option<WindowHandle>,
+    /// The pid of the client that owns the target.
+    pub client_pid: Option<i32>,
+}
+
+/// An input event and where it went.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InputTrace {
+    /// The kind of input event, like `PointerButton`.
+    pub event: String,
+    /// The pointer focus after the event was handled.
+    pub pointer_focus: Option<TracedFocus>,
+    /// The keyboard focus after the event was handled.
+    pub keyboard_focus: Option<TracedFocus>,
+    /// Whether the pointer is grabbed, e.g. by a move or popup grab.
+    pub pointer_grabbed: bool,
+    /// Whether the keyboard is grabbed.
+    pub keyboard_grabbed: bool,
+    /// The pid of the client the event was sent to.
+    ///
+    /// This is the keyboard focus's client for keyboard events
+    /// and the pointer focus's client for everything else.
+    pub receiving_client_pid: Option<i32>,
+}
+
+impl From<trace_input_response::FocusTarget> for TracedFocus {
+    fn from(focus: trace_input_response::FocusTarget) -> Self {
+        Self {
+            description: focus.description,
+            window: focus.window_id.map(WindowHandle::from_id),
+            client_pid: focus.client_pid,
+        }
+    }
+}
+
+impl From<TraceInputResponse> for InputTrace {
+    fn from(response: TraceInputResponse) -> Self {
+        Self {
+            event: response.event,
+            pointer_focus: response.pointer_focus.map(TracedFocus::from),
+            keyboard_focus: response.keyboard_focus.map(TracedFocus::from),
+            pointer_grabbed: response.pointer_grabbed,
+            keyboard_grabbed: response.keyboard_grabbed,
+            receiving_client_pid: response.receiving_client_pid,
+        }
+    }
+}
+
+/// Returns a stream of traced input events.
+///
+/// Events are only sent while input tracing is on; see [`trace_input`].
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::debug;
+/// # use futures::StreamExt;
+/// # async {
+/// debug::trace_input(true);
+///
+/// let mut traces = debug::input_trace_stream();
+/// while let Some(trace) = traces.next().await {
+///     if trace.event == "PointerButton" {
+///         println!("Click went to {:?}", trace.pointer_focus);
+///     }
+/// }
+/// # };
+/// ```
+pub fn input_trace_stream() -> impl Stream<Item = InputTrace> + Send + Unpin + 'static {
+    input_trace_stream_async().block_on_tokio()
+}
+
+/// Async impl for [`input_trace_stream`].
+pub async fn input_trace_stream_async() -> impl Stream<Item = InputTrace> + Send + Unpin + 'static {
+    Client::debug()
+        .trace_input(TraceInputRequest {})
+        .await
+        .unwrap()
+        .into_inner()
+        .filter_map(|response| async move { response.ok().map(InputTrace::from) })
+        .boxed()
+}
+
+/// Replaces the filter that decides what Pinnacle logs to stdout.
+///
+/// `filter` uses the same directives as the `RUST_LOG` environment variable,
+/// so verbosity can be changed without restarting. The log file is unaffected.
+///
+/// Returns why the filter couldn't be set if it was invalid.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::debug;
+/// if let Err(err) = debug::set_log_filter("warn,pinnacle::render=trace") {
+///     eprintln!("Failed to set log filter: {err}");
+/// }
+/// ```
+pub fn set_log_filter(filter: impl ToString) -> Result<(), String> {
+    let error = Client::debug()
+        .set_log_filter(SetLogFilterRequest {
+            filter: filter.to_string(),
+        })
+        .block_on_tokio()
+        .into_result()
+        .map_err(|err| err.to_string())?
+        .error;
+
+    match error {
+        Some(error) => Err(error),
+        None => Ok(()),
+    }
+}
+
+/// Returns a stream of lines Pinnacle logs to stdout from now on.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::debug;
+/// # use futures::StreamExt;
+/// # async {
+/// let mut lines = debug::log_stream();
+/// while let Some(line) = lines.next().await {
+///     println!("{line}");
+/// }
+/// # };
+/// ```
+pub fn log_stream() -> impl Stream<Item = String> + Send + Unpin + 'static {
+    log_stream_async().block_on_tokio()
+}
+
+/// Async impl for [`log_stream`].
+pub async fn log_stream_async() -> impl Stream<Item = String> + Send + Unpin + 'static {
+    Client::debug()
+        .tail_logs(TailLogsRequest {})
+        .await
+        .unwrap()
+        .into_inner()
+        .filter_map(|response| async move { response.ok().map(|response| response.line) })
+        .boxed()
 }
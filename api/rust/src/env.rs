@@ -0,0 +1,49 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Session environment management.
+//!
+//! Variables set here reach Pinnacle's own environment, newly spawned processes,
+//! and the systemd and D-Bus activation environments, so services started before
+//! the change also see it when they're next activated.
+
+use pinnacle_api_defs::pinnacle::process::v1::SetGlobalEnvRequest;
+
+use crate::{BlockOnTokio, ResponseExt, client::Client};
+
+/// Sets an environment variable for the whole session.
+///
+/// Unlike [`process::set_env`][crate::process::set_env], which only affects
+/// processes spawned by Pinnacle, this also updates Pinnacle's own environment
+/// as well as the systemd and D-Bus activation environments.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::env;
+/// env::set("QT_QPA_PLATFORMTHEME", "qt6ct");
+/// ```
+pub fn set(key: impl ToString, value: impl ToString) {
+    Client::process()
+        .set_global_env(SetGlobalEnvRequest {
+            key: key.to_string(),
+            value: Some(value.to_string()),
+        })
+        .block_on_tokio()
+        .log_err();
+}
+
+/// Unsets an environment variable for the whole session.
+///
+/// This removes it from Pinnacle's own environment, newly spawned processes,
+/// and the systemd and D-Bus activation environments.
+pub fn unset(key: impl ToString) {
+    Client::process()
+        .set_global_env(SetGlobalEnvRequest {
+            key: key.to_string(),
+            value: None,
+        })
+        .block_on_tokio()
+        .log_err();
+}
@@ -0,0 +1,183 @@
+//! Idle behavior and the screensaver.
+
+use std::{path::PathBuf, time::Duration};
+
+use pinnacle_api_defs::pinnacle::idle::v1::{
+    self, SetIdleTimeoutRequest, SetScreensaverRequest, screensaver,
+};
+
+use crate::{
+    BlockOnTokio, ResponseExt,
+    client::Client,
+    signal::{IdleSignal, SignalHandle},
+};
+
+/// A screensaver shown on all outputs after a period without input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Screensaver {
+    /// How long there must be no input before the screensaver starts.
+    pub timeout: Duration,
+    /// What the screensaver shows.
+    pub source: ScreensaverSource,
+}
+
+/// What a screensaver shows.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ScreensaverSource {
+    /// A GLSL ES 1.00 fragment shader drawn over each output.
+    ///
+    /// The shader gets the output's size in pixels as `uniform vec2 size`,
+    /// the seconds since the screensaver started as `uniform float time`,
+    /// and the position on the output from 0 to 1 as `varying vec2 v_coords`.
+    Shader(String),
+    /// PNG images shown one after another, each for `interval`.
+    ///
+    /// Images are scaled to fit each output.
+    Slideshow {
+        /// Paths to the images. `~` is expanded to the home directory.
+        images: Vec<PathBuf>,
+        /// How long each image is shown.
+        interval: Duration,
+    },
+}
+
+/// Sets the screensaver, or disables it with `None`.
+///
+/// The screensaver starts once there has been no input for its timeout
+/// unless an app is inhibiting idle, and any input stops it.
+///
+/// # Panics
+///
+/// Panics if the shader fails to compile, if the timeout or slideshow interval
+/// is zero, or if the slideshow has no images.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::idle::{self, Screensaver, ScreensaverSource};
+/// # use std::time::Duration;
+/// idle::set_screensaver(Some(Screensaver {
+///     timeout: Duration::from_secs(300),
+///     source: ScreensaverSource::Shader(
+///         r#"
+///         #version 100
+///         precision mediump float;
+///
+///         uniform vec2 size;
+///         uniform float time;
+///         uniform float alpha;
+///         varying vec2 v_coords;
+///
+///         void main() {
+///             vec3 color = 0.5 + 0.5 * cos(time + v_coords.xyx + vec3(0.0, 2.0, 4.0));
+///             gl_FragColor = vec4(color, 1.0) * alpha;
+///         }
+///         "#
+///         .into(),
+///     ),
+/// }));
+///
+/// idle::set_screensaver(Some(Screensaver {
+///     timeout: Duration::from_secs(300),
+///     source: ScreensaverSource::Slideshow {
+///         images: vec!["~/Pictures/mountains.png".into(), "~/Pictures/lake.png".into()],
+///         interval: Duration::from_secs(30),
+///     },
+/// }));
+///
+/// // Disable the screensaver
+/// idle::set_screensaver(None);
+/// ```
+pub fn set_screensaver(screensaver: Option<Screensaver>) {
+    let screensaver = screensaver.map(|screensaver| v1::Screensaver {
+        timeout_millis: screensaver.timeout.as_millis() as u32,
+        source: Some(match screensaver.source {
+            ScreensaverSource::Shader(source) => screensaver::Source::Shader(v1::Shader { source }),
+            ScreensaverSource::Slideshow { images, interval } => {
+                screensaver::Source::Slideshow(v1::Slideshow {
+                    image_paths: images
+                        .into_iter()
+                        .map(|path| path.to_string_lossy().into_owned())
+                        .collect(),
+                    interval_millis: interval.as_millis() as u32,
+                })
+            }
+        }),
+    });
+
+    Client::idle()
+        .set_screensaver(SetScreensaverRequest { screensaver })
+        .block_on_tokio()
+        .log_err();
+}
+
+/// What happens when there has been no input for a while.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IdleTimeout {
+    /// How long there must be no input before the session is idle.
+    pub timeout: Duration,
+    /// Whether to power off all outputs while the session is idle.
+    ///
+    /// The outputs are powered back on at the next input.
+    pub power_off_outputs: bool,
+}
+
+/// Sets how long there must be no input before the session is idle,
+/// or disables idle tracking with `None`.
+///
+/// The session doesn't become idle while an app is inhibiting idle.
+/// Connect to [`IdleSignal`]s to run something when the session becomes idle
+/// and when it resumes.
+///
+/// # Panics
+///
+/// Panics if the timeout is zero.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::idle::{self, IdleTimeout};
+/// # use std::time::Duration;
+/// // Turn off the screens after 10 minutes
+/// idle::set_idle_timeout(Some(IdleTimeout {
+///     timeout: Duration::from_secs(600),
+///     power_off_outputs: true,
+/// }));
+/// ```
+pub fn set_idle_timeout(idle_timeout: Option<IdleTimeout>) {
+    let (timeout_millis, power_off_outputs) = match idle_timeout {
+        Some(idle_timeout) => (
+            Some(idle_timeout.timeout.as_millis() as u32),
+            idle_timeout.power_off_outputs,
+        ),
+        None => (None, false),
+    };
+
+    Client::idle()
+        .set_idle_timeout(SetIdleTimeoutRequest {
+            timeout_millis,
+            power_off_outputs,
+        })
+        .block_on_tokio()
+        .log_err();
+}
+
+/// Connects to an [`IdleSignal`].
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::idle;
+/// # use pinnacle_api::signal::IdleSignal;
+/// idle::connect_signal(IdleSignal::Idle(Box::new(|| {
+///     pinnacle_api::process::Command::new("swaylock").spawn();
+/// })));
+/// ```
+pub fn connect_signal(signal: IdleSignal) -> SignalHandle {
+    let mut signal_state = Client::signal_state();
+
+    match signal {
+        IdleSignal::Idle(f) => signal_state.idle_started.add_callback(f),
+        IdleSignal::Resume(f) => signal_state.idle_resumed.add_callback(f),
+    }
+}
@@ -6,23 +6,32 @@
 //!
 //! This module provides ways to manage bindings, input devices, and other input settings.
 
+use std::time::Duration;
+
 use num_enum::{FromPrimitive, IntoPrimitive};
-use pinnacle_api_defs::pinnacle::input::{
-    self,
-    v1::{
-        BindProperties, BindRequest, EnterBindLayerRequest, GetBindInfosRequest,
-        KeybindOnPressRequest, KeybindStreamRequest, MousebindOnPressRequest,
-        MousebindStreamRequest, SetBindPropertiesRequest, SetRepeatRateRequest, SetXcursorRequest,
-        SetXkbConfigRequest, SetXkbKeymapRequest, SwitchXkbLayoutRequest,
-        switch_xkb_layout_request,
+use pinnacle_api_defs::pinnacle::{
+    input::{
+        self,
+        v1::{
+            BindProperties, BindRequest, ClearRemapsRequest, EdgeActionStreamRequest,
+            EnterBindLayerRequest, GetBindInfosRequest, KeybindOnPressRequest,
+            KeybindStreamRequest, MousebindOnPressRequest, MousebindStreamRequest, RemapMacro,
+            RemapRequest, SetBindLayerConflictPolicyRequest, SetBindPropertiesRequest,
+            SetEdgeActionRequest, SetHoldToMoveRequest, SetPointerCrossingRequest,
+            SetRepeatRateRequest, SetSlowKeysRequest, SetStickyKeysRequest, SetXcursorRequest,
+            SetXkbConfigRequest, SetXkbKeymapRequest, SwitchXkbLayoutRequest, remap_request,
+            switch_xkb_layout_request,
+        },
     },
+    output,
 };
 use tokio::sync::mpsc::{UnboundedSender, unbounded_channel};
 use tokio_stream::StreamExt;
 
 use crate::{
-    BlockOnTokio,
+    BlockOnTokio, ResponseExt,
     client::Client,
+    output::{OutputHandle, ScreenEdge},
     signal::{InputSignal, SignalHandle},
 };
 
@@ -183,13 +192,72 @@ impl BindLayer {
                 layer_name: self.name.clone(),
             })
             .block_on_tokio()
-            .unwrap();
+            .log_err();
     }
 
     /// Returns this bind layer's name, or `None` if this is the default bind layer.
     pub fn name(&self) -> Option<String> {
         self.name.clone()
     }
+
+    /// Sets what happens to existing binds on this layer when a conflicting bind is added.
+    ///
+    /// This only affects binds added after this is called.
+    pub fn set_conflict_policy(&self, policy: ConflictPolicy) {
+        Client::input()
+            .set_bind_layer_conflict_policy(SetBindLayerConflictPolicyRequest {
+                layer_name: self.name.clone(),
+                policy: input::v1::BindConflictPolicy::from(policy).into(),
+            })
+            .block_on_tokio()
+            .log_err();
+    }
+}
+
+/// What happens to existing binds when a conflicting bind is added.
+///
+/// Binds conflict when they are on the same layer, trigger on the same key or button,
+/// and have modifiers that can be held at the same time.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ConflictPolicy {
+    /// Keep existing binds. All conflicting binds will trigger.
+    #[default]
+    Keep,
+    /// Remove existing binds.
+    Override,
+    /// Disable existing binds until the new bind is removed.
+    Shadow,
+}
+
+impl From<ConflictPolicy> for input::v1::BindConflictPolicy {
+    fn from(value: ConflictPolicy) -> Self {
+        match value {
+            ConflictPolicy::Keep => input::v1::BindConflictPolicy::Keep,
+            ConflictPolicy::Override => input::v1::BindConflictPolicy::Override,
+            ConflictPolicy::Shadow => input::v1::BindConflictPolicy::Shadow,
+        }
+    }
+}
+
+/// An existing bind that conflicted with a newly added bind.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BindConflict {
+    /// The id of the existing bind.
+    pub bind_id: u32,
+    /// The existing bind's group.
+    pub group: String,
+    /// The existing bind's description.
+    pub description: String,
+}
+
+impl From<input::v1::BindConflict> for BindConflict {
+    fn from(value: input::v1::BindConflict) -> Self {
+        Self {
+            bind_id: value.bind_id,
+            group: value.group,
+            description: value.description,
+        }
+    }
 }
 
 /// Functionality common to all bind types.
@@ -204,6 +272,8 @@ pub trait Bind {
     fn set_as_reload_config(&mut self) -> &mut Self;
     /// Allows this bind to trigger when the session is locked.
     fn allow_when_locked(&mut self) -> &mut Self;
+    /// Returns the existing binds that conflicted with this bind when it was added.
+    fn conflicts(&self) -> &[BindConflict];
 }
 
 macro_rules! bind_impl {
@@ -219,7 +289,7 @@ macro_rules! bind_impl {
                         }),
                     })
                     .block_on_tokio()
-                    .unwrap();
+                    .log_err();
                 self
             }
 
@@ -233,7 +303,7 @@ macro_rules! bind_impl {
                         }),
                     })
                     .block_on_tokio()
-                    .unwrap();
+                    .log_err();
                 self
             }
 
@@ -247,7 +317,7 @@ macro_rules! bind_impl {
                         }),
                     })
                     .block_on_tokio()
-                    .unwrap();
+                    .log_err();
                 self
             }
 
@@ -261,7 +331,7 @@ macro_rules! bind_impl {
                         }),
                     })
                     .block_on_tokio()
-                    .unwrap();
+                    .log_err();
                 self
             }
 
@@ -275,9 +345,13 @@ macro_rules! bind_impl {
                         }),
                     })
                     .block_on_tokio()
-                    .unwrap();
+                    .log_err();
                 self
             }
+
+            fn conflicts(&self) -> &[BindConflict] {
+                &self.conflicts
+            }
         }
     };
 }
@@ -293,6 +367,7 @@ type KeybindCallback = (Box<dyn FnMut() + Send + 'static>, Edge);
 pub struct Keybind {
     bind_id: u32,
     callback_sender: Option<UnboundedSender<KeybindCallback>>,
+    conflicts: Vec<BindConflict>,
 }
 
 bind_impl!(Keybind);
@@ -315,7 +390,7 @@ impl Keybind {
                 bind_id: self.bind_id,
             })
             .block_on_tokio()
-            .unwrap();
+            .log_err();
 
         self
     }
@@ -335,7 +410,7 @@ async fn new_keybind(mods: Mod, key: impl ToKeysym, layer: &BindLayer) -> Keybin
     let ignore_mods = mods.api_ignore_mods();
     let mods = mods.api_mods();
 
-    let bind_id = Client::input()
+    let response = Client::input()
         .bind(BindRequest {
             bind: Some(input::v1::Bind {
                 mods: mods.into_iter().map(|m| m.into()).collect(),
@@ -349,13 +424,12 @@ async fn new_keybind(mods: Mod, key: impl ToKeysym, layer: &BindLayer) -> Keybin
             }),
         })
         .await
-        .unwrap()
-        .into_inner()
-        .bind_id;
+        .into_inner_or_default();
 
     Keybind {
-        bind_id,
+        bind_id: response.bind_id,
         callback_sender: None,
+        conflicts: response.conflicts.into_iter().map(Into::into).collect(),
     }
 }
 
@@ -413,6 +487,7 @@ type MousebindCallback = (Box<dyn FnMut() + Send + 'static>, Edge);
 pub struct Mousebind {
     bind_id: u32,
     callback_sender: Option<UnboundedSender<MousebindCallback>>,
+    conflicts: Vec<BindConflict>,
 }
 
 bind_impl!(Mousebind);
@@ -435,7 +510,7 @@ impl Mousebind {
                 bind_id: self.bind_id,
             })
             .block_on_tokio()
-            .unwrap();
+            .log_err();
 
         self
     }
@@ -455,7 +530,7 @@ async fn new_mousebind(mods: Mod, button: MouseButton, layer: &BindLayer) -> Mou
     let ignore_mods = mods.api_ignore_mods();
     let mods = mods.api_mods();
 
-    let bind_id = Client::input()
+    let response = Client::input()
         .bind(BindRequest {
             bind: Some(input::v1::Bind {
                 mods: mods.into_iter().map(|m| m.into()).collect(),
@@ -468,13 +543,12 @@ async fn new_mousebind(mods: Mod, button: MouseButton, layer: &BindLayer) -> Mou
             }),
         })
         .await
-        .unwrap()
-        .into_inner()
-        .bind_id;
+        .into_inner_or_default();
 
     Mousebind {
-        bind_id,
+        bind_id: response.bind_id,
         callback_sender: None,
+        conflicts: response.conflicts.into_iter().map(Into::into).collect(),
     }
 }
 
@@ -603,7 +677,7 @@ pub fn set_xkb_config(xkb_config: XkbConfig) {
             options: xkb_config.options,
         })
         .block_on_tokio()
-        .unwrap();
+        .log_err();
 }
 
 /// Sets the XKB keymap.
@@ -626,7 +700,7 @@ pub fn set_xkb_keymap(keymap: impl ToString) {
             keymap: keymap.to_string(),
         })
         .block_on_tokio()
-        .unwrap();
+        .log_err();
 }
 
 /// Cycles the current XKB layout forward.
@@ -636,7 +710,7 @@ pub fn cycle_xkb_layout_forward() {
             action: Some(switch_xkb_layout_request::Action::Next(())),
         })
         .block_on_tokio()
-        .unwrap();
+        .log_err();
 }
 
 /// Cycles the current XKB layout backward.
@@ -646,7 +720,7 @@ pub fn cycle_xkb_layout_backward() {
             action: Some(switch_xkb_layout_request::Action::Prev(())),
         })
         .block_on_tokio()
-        .unwrap();
+        .log_err();
 }
 
 /// Switches the current XKB layout to the one at the provided `index`.
@@ -658,7 +732,7 @@ pub fn switch_xkb_layout(index: u32) {
             action: Some(switch_xkb_layout_request::Action::Index(index)),
         })
         .block_on_tokio()
-        .unwrap();
+        .log_err();
 }
 
 /// Bind information.
@@ -723,7 +797,7 @@ pub fn set_repeat_rate(rate: i32, delay: i32) {
             delay: Some(delay),
         })
         .block_on_tokio()
-        .unwrap();
+        .log_err();
 }
 
 /// Sets the xcursor theme.
@@ -744,7 +818,7 @@ pub fn set_xcursor_theme(theme: impl ToString) {
             size: None,
         })
         .block_on_tokio()
-        .unwrap();
+        .log_err();
 }
 
 /// Sets the xcursor size.
@@ -765,7 +839,267 @@ pub fn set_xcursor_size(size: u32) {
             size: Some(size),
         })
         .block_on_tokio()
-        .unwrap();
+        .log_err();
+}
+
+/// Enables or disables sticky keys.
+///
+/// With sticky keys, tapping a modifier keeps it pressed until the next
+/// non-modifier key is released, so key combinations can be typed one key at a time.
+/// Tapping a latched modifier again releases it.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::input;
+/// input::set_sticky_keys(true);
+/// ```
+pub fn set_sticky_keys(enabled: bool) {
+    Client::input()
+        .set_sticky_keys(SetStickyKeysRequest { enabled })
+        .block_on_tokio()
+        .log_err();
+}
+
+/// Enables slow keys with the given delay, or disables it with `None`.
+///
+/// With slow keys, keys must be held for `delay` before they are accepted.
+/// Keys released earlier are ignored.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::input;
+/// # use std::time::Duration;
+/// input::set_slow_keys(Some(Duration::from_millis(300)));
+/// ```
+pub fn set_slow_keys(delay: Option<Duration>) {
+    Client::input()
+        .set_slow_keys(SetSlowKeysRequest {
+            delay_millis: delay.map(|delay| delay.as_millis() as u32),
+        })
+        .block_on_tokio()
+        .log_err();
+}
+
+/// Sets up moving windows by holding them, without needing a modifier key.
+///
+/// If `touch` is true, holding a touch on a window moves it. If `button` is set, holding that
+/// button on a window moves it. The window must be held for `hold_time`, or 500 milliseconds
+/// if `None`, without moving much before it starts moving.
+///
+/// Windows that use long presses themselves can opt out with
+/// [`WindowHandle::set_hold_to_move_enabled`][crate::window::WindowHandle::set_hold_to_move_enabled].
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::input;
+/// # use pinnacle_api::input::MouseButton;
+/// input::set_hold_to_move(true, Some(MouseButton::Left), None);
+/// ```
+pub fn set_hold_to_move(touch: bool, button: Option<MouseButton>, hold_time: Option<Duration>) {
+    Client::input()
+        .set_hold_to_move(SetHoldToMoveRequest {
+            touch,
+            button: button.map(u32::from),
+            hold_millis: hold_time.map(|hold_time| hold_time.as_millis() as u32),
+        })
+        .block_on_tokio()
+        .log_err();
+}
+
+/// Where the pointer ends up when it crosses from one output to another.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum PointerCrossing {
+    /// The pointer keeps its position in the global space.
+    #[default]
+    Logical,
+    /// The pointer keeps its relative position along the edge it crossed.
+    ///
+    /// This lines the pointer up between outputs that are physically the same size
+    /// but have different scales, like a 1x output next to a 1.5x one.
+    Physical,
+}
+
+/// Sets where the pointer ends up when it crosses from one output to another.
+///
+/// Either way, the pointer slides along the edge of an output instead of jumping
+/// onto a neighboring output that doesn't line up with it.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::input::{self, PointerCrossing};
+/// input::set_pointer_crossing(PointerCrossing::Physical);
+/// ```
+pub fn set_pointer_crossing(crossing: PointerCrossing) {
+    let crossing = match crossing {
+        PointerCrossing::Logical => input::v1::PointerCrossing::Logical,
+        PointerCrossing::Physical => input::v1::PointerCrossing::Physical,
+    };
+
+    Client::input()
+        .set_pointer_crossing(SetPointerCrossingRequest {
+            crossing: crossing.into(),
+        })
+        .block_on_tokio()
+        .log_err();
+}
+
+/// What happens when the pointer hits an edge or corner of an output.
+pub enum EdgeAction {
+    /// Nothing happens.
+    ///
+    /// Set on a single output, this disables the action set for all outputs.
+    None,
+    /// Runs a closure with the output whose edge was hit.
+    Callback(Box<dyn FnMut(&OutputHandle) + Send + 'static>),
+    /// Floating windows dropped at the edge snap to the half or quarter
+    /// of the output next to it.
+    SnapWindow,
+}
+
+impl std::fmt::Debug for EdgeAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::None => write!(f, "None"),
+            Self::Callback(_) => write!(f, "Callback"),
+            Self::SnapWindow => write!(f, "SnapWindow"),
+        }
+    }
+}
+
+/// Sets what happens when the pointer hits `edge` of any output.
+///
+/// Edges only count where the pointer can't move onto another output, and actions
+/// run once when the pointer first hits them. An action set on a single output with
+/// [`OutputHandle::set_edge_action`] takes precedence.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::input::{self, EdgeAction};
+/// # use pinnacle_api::output::ScreenEdge;
+/// input::set_edge_action(
+///     ScreenEdge::TopRight,
+///     EdgeAction::Callback(Box::new(|output| {
+///         println!("Hit the top right corner of {}", output.name());
+///     })),
+/// );
+/// input::set_edge_action(ScreenEdge::Left, EdgeAction::SnapWindow);
+/// ```
+pub fn set_edge_action(edge: ScreenEdge, action: EdgeAction) {
+    send_edge_action(None, edge, action);
+}
+
+pub(crate) fn send_edge_action(output_name: Option<String>, edge: ScreenEdge, action: EdgeAction) {
+    let (api_action, callback) = match action {
+        EdgeAction::None => (input::v1::EdgeAction::None, None),
+        EdgeAction::Callback(callback) => (input::v1::EdgeAction::Callback, Some(callback)),
+        EdgeAction::SnapWindow => (input::v1::EdgeAction::SnapWindow, None),
+    };
+
+    let response = Client::input()
+        .set_edge_action(SetEdgeActionRequest {
+            edge: output::v1::ScreenEdge::from(edge).into(),
+            action: api_action.into(),
+            output_name,
+        })
+        .block_on_tokio()
+        .into_inner_or_default();
+
+    if let (Some(action_id), Some(callback)) = (response.action_id, callback) {
+        new_edge_action_stream(action_id, callback).block_on_tokio();
+    }
+}
+
+async fn new_edge_action_stream(
+    action_id: u32,
+    mut callback: Box<dyn FnMut(&OutputHandle) + Send + 'static>,
+) {
+    let mut from_server = Client::input()
+        .edge_action_stream(EdgeActionStreamRequest { action_id })
+        .await
+        .unwrap()
+        .into_inner();
+
+    tokio::spawn(async move {
+        while let Some(Ok(response)) = from_server.next().await {
+            callback(&OutputHandle {
+                name: response.output_name,
+            });
+        }
+    });
+}
+
+/// Remaps a key to another key.
+///
+/// Remaps apply before binds and clients see the key, so binds should use the key
+/// that is remapped to.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::input;
+/// # use pinnacle_api::input::Keysym;
+/// input::remap(Keysym::Caps_Lock, Keysym::Escape);
+/// ```
+pub fn remap(from: impl ToKeysym, to: impl ToKeysym) {
+    send_remap(from, remap_request::To::Key(api_key(to)), None);
+}
+
+/// Remaps a key to a macro.
+///
+/// Pressing `from` presses and releases each key in `keys` in order.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::input;
+/// # use pinnacle_api::input::Keysym;
+/// input::remap_to_macro(Keysym::F13, ['h', 'i']);
+/// ```
+pub fn remap_to_macro<K: ToKeysym>(from: impl ToKeysym, keys: impl IntoIterator<Item = K>) {
+    send_remap(from, remap_macro(keys), None);
+}
+
+/// Removes all remaps, including ones scoped to devices.
+pub fn clear_remaps() {
+    Client::input()
+        .clear_remaps(ClearRemapsRequest {
+            device_sysname: None,
+        })
+        .block_on_tokio()
+        .log_err();
+}
+
+pub(crate) fn send_remap(
+    from: impl ToKeysym,
+    to: remap_request::To,
+    device_sysname: Option<String>,
+) {
+    Client::input()
+        .remap(RemapRequest {
+            from: Some(api_key(from)),
+            to: Some(to),
+            device_sysname,
+        })
+        .block_on_tokio()
+        .log_err();
+}
+
+pub(crate) fn remap_macro<K: ToKeysym>(keys: impl IntoIterator<Item = K>) -> remap_request::To {
+    remap_request::To::KeyMacro(RemapMacro {
+        keys: keys.into_iter().map(api_key).collect(),
+    })
+}
+
+pub(crate) fn api_key(key: impl ToKeysym) -> input::v1::Keybind {
+    input::v1::Keybind {
+        key_code: Some(key.to_keysym().raw()),
+        xkb_name: None,
+    }
 }
 
 /// A trait that designates anything that can be converted into a [`Keysym`].
@@ -809,8 +1143,7 @@ pub fn bind_infos() -> impl Iterator<Item = BindInfo> {
     let infos = Client::input()
         .get_bind_infos(GetBindInfosRequest {})
         .block_on_tokio()
-        .unwrap()
-        .into_inner()
+        .into_inner_or_default()
         .bind_infos;
 
     infos.into_iter().filter_map(|info| {
@@ -905,5 +1238,8 @@ pub fn connect_signal(signal: InputSignal) -> SignalHandle {
 
     match signal {
         InputSignal::DeviceAdded(f) => signal_state.input_device_added.add_callback(f),
+        InputSignal::AccessibilityChanged(f) => {
+            signal_state.input_accessibility_changed.add_callback(f)
+        }
     }
 }
@@ -7,13 +7,21 @@
 use pinnacle_api_defs::pinnacle::input::{
     self,
     v1::{
-        GetDeviceCapabilitiesRequest, GetDeviceInfoRequest, GetDeviceTypeRequest,
-        GetDevicesRequest, SetDeviceLibinputSettingRequest, SetDeviceMapTargetRequest,
-        set_device_libinput_setting_request::Setting, set_device_map_target_request::Target,
+        ClearRemapsRequest, GetDeviceCapabilitiesRequest, GetDeviceInfoRequest,
+        GetDeviceTypeRequest, GetDevicesRequest, SetDeviceLibinputSettingRequest,
+        SetDeviceMapTargetRequest, remap_request, set_device_libinput_setting_request::Setting,
+        set_device_map_target_request::Target,
     },
 };
 
-use crate::{BlockOnTokio, client::Client, output::OutputHandle, signal::InputSignal, util::Rect};
+use crate::{
+    BlockOnTokio, ResponseExt,
+    client::Client,
+    input::{ToKeysym, api_key, remap_macro, send_remap},
+    output::OutputHandle,
+    signal::InputSignal,
+    util::Rect,
+};
 
 /// A pointer acceleration profile.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -271,8 +279,7 @@ impl DeviceHandle {
                 device_sysname: self.sysname.clone(),
             })
             .await
-            .unwrap()
-            .into_inner();
+            .into_inner_or_default();
 
         let mut capability = Capability::default();
 
@@ -313,8 +320,7 @@ impl DeviceHandle {
                 device_sysname: self.sysname.clone(),
             })
             .await
-            .unwrap()
-            .into_inner()
+            .into_inner_or_default()
             .name
     }
 
@@ -330,8 +336,7 @@ impl DeviceHandle {
                 device_sysname: self.sysname.clone(),
             })
             .await
-            .unwrap()
-            .into_inner()
+            .into_inner_or_default()
             .product_id
     }
 
@@ -347,8 +352,7 @@ impl DeviceHandle {
                 device_sysname: self.sysname.clone(),
             })
             .await
-            .unwrap()
-            .into_inner()
+            .into_inner_or_default()
             .vendor_id
     }
 
@@ -364,8 +368,7 @@ impl DeviceHandle {
                 device_sysname: self.sysname.clone(),
             })
             .await
-            .unwrap()
-            .into_inner()
+            .into_inner_or_default()
             .device_type()
             .into()
     }
@@ -386,7 +389,41 @@ impl DeviceHandle {
                 target: Some(Target::OutputName(output.name())),
             })
             .block_on_tokio()
-            .unwrap();
+            .log_err();
+    }
+
+    /// Remaps a key on this device to another key.
+    ///
+    /// Remaps scoped to a device take priority over ones set with [`input::remap`].
+    ///
+    /// [`input::remap`]: crate::input::remap
+    pub fn remap(&self, from: impl ToKeysym, to: impl ToKeysym) {
+        send_remap(
+            from,
+            remap_request::To::Key(api_key(to)),
+            Some(self.sysname.clone()),
+        );
+    }
+
+    /// Remaps a key on this device to a macro.
+    ///
+    /// Pressing `from` presses and releases each key in `keys` in order.
+    pub fn remap_to_macro<K: ToKeysym>(
+        &self,
+        from: impl ToKeysym,
+        keys: impl IntoIterator<Item = K>,
+    ) {
+        send_remap(from, remap_macro(keys), Some(self.sysname.clone()));
+    }
+
+    /// Removes all remaps scoped to this device.
+    pub fn clear_remaps(&self) {
+        Client::input()
+            .clear_remaps(ClearRemapsRequest {
+                device_sysname: Some(self.sysname.clone()),
+            })
+            .block_on_tokio()
+            .log_err();
     }
 
     /// Maps the absolute input from this device to the corresponding region
@@ -407,7 +444,7 @@ impl DeviceHandle {
                 target: Some(Target::Region(region.into())),
             })
             .block_on_tokio()
-            .unwrap();
+            .log_err();
     }
 
     /// Sets this device's acceleration profile.
@@ -420,7 +457,7 @@ impl DeviceHandle {
                 )),
             })
             .block_on_tokio()
-            .unwrap();
+            .log_err();
     }
 
     /// Sets this device's acceleration speed.
@@ -431,7 +468,7 @@ impl DeviceHandle {
                 setting: Some(Setting::AccelSpeed(accel_speed)),
             })
             .block_on_tokio()
-            .unwrap();
+            .log_err();
     }
 
     /// Sets this device's calibration matrix.
@@ -444,7 +481,7 @@ impl DeviceHandle {
                 })),
             })
             .block_on_tokio()
-            .unwrap();
+            .log_err();
     }
 
     /// Sets this device's click method.
@@ -457,7 +494,7 @@ impl DeviceHandle {
                 )),
             })
             .block_on_tokio()
-            .unwrap();
+            .log_err();
     }
 
     /// Sets whether or not this device is disabled while typing.
@@ -468,7 +505,7 @@ impl DeviceHandle {
                 setting: Some(Setting::DisableWhileTyping(disable_while_typing)),
             })
             .block_on_tokio()
-            .unwrap();
+            .log_err();
     }
 
     /// Sets this device to left-handed or not.
@@ -479,7 +516,7 @@ impl DeviceHandle {
                 setting: Some(Setting::LeftHanded(left_handed)),
             })
             .block_on_tokio()
-            .unwrap();
+            .log_err();
     }
 
     /// Sets whether or not middle emulation is enabled.
@@ -490,7 +527,7 @@ impl DeviceHandle {
                 setting: Some(Setting::MiddleEmulation(middle_emulation)),
             })
             .block_on_tokio()
-            .unwrap();
+            .log_err();
     }
 
     /// Sets this device's rotation angle.
@@ -501,7 +538,7 @@ impl DeviceHandle {
                 setting: Some(Setting::RotationAngle(rotation_angle)),
             })
             .block_on_tokio()
-            .unwrap();
+            .log_err();
     }
 
     /// Sets this device's scroll button.
@@ -512,7 +549,7 @@ impl DeviceHandle {
                 setting: Some(Setting::ScrollButton(scroll_button)),
             })
             .block_on_tokio()
-            .unwrap();
+            .log_err();
     }
 
     /// Sets whether or not the scroll button locks on this device.
@@ -523,7 +560,7 @@ impl DeviceHandle {
                 setting: Some(Setting::ScrollButtonLock(scroll_button_lock)),
             })
             .block_on_tokio()
-            .unwrap();
+            .log_err();
     }
 
     /// Sets this device's scroll method.
@@ -536,7 +573,7 @@ impl DeviceHandle {
                 )),
             })
             .block_on_tokio()
-            .unwrap();
+            .log_err();
     }
 
     /// Enables or disables natural scroll on this device.
@@ -547,7 +584,7 @@ impl DeviceHandle {
                 setting: Some(Setting::NaturalScroll(natural_scroll)),
             })
             .block_on_tokio()
-            .unwrap();
+            .log_err();
     }
 
     /// Sets this device's tap button map.
@@ -560,7 +597,7 @@ impl DeviceHandle {
                 )),
             })
             .block_on_tokio()
-            .unwrap();
+            .log_err();
     }
 
     /// Enables or disables tap dragging on this device.
@@ -571,7 +608,7 @@ impl DeviceHandle {
                 setting: Some(Setting::TapDrag(tap_drag)),
             })
             .block_on_tokio()
-            .unwrap();
+            .log_err();
     }
 
     /// Sets whether or not tap dragging locks on this device.
@@ -582,7 +619,7 @@ impl DeviceHandle {
                 setting: Some(Setting::TapDragLock(tap_drag_lock)),
             })
             .block_on_tokio()
-            .unwrap();
+            .log_err();
     }
 
     /// Enables or disables tap-to-click on this device.
@@ -593,7 +630,7 @@ impl DeviceHandle {
                 setting: Some(Setting::Tap(tap)),
             })
             .block_on_tokio()
-            .unwrap();
+            .log_err();
     }
 
     /// Sets this device's send events mode.
@@ -606,7 +643,7 @@ impl DeviceHandle {
                 )),
             })
             .block_on_tokio()
-            .unwrap();
+            .log_err();
     }
 }
 
@@ -615,8 +652,7 @@ pub fn get_devices() -> impl Iterator<Item = DeviceHandle> {
     Client::input()
         .get_devices(GetDevicesRequest {})
         .block_on_tokio()
-        .unwrap()
-        .into_inner()
+        .into_inner_or_default()
         .device_sysnames
         .into_iter()
         .map(|sysname| DeviceHandle { sysname })
@@ -18,7 +18,13 @@ use pinnacle_api_defs::pinnacle::layout::{
 use tokio::sync::mpsc::{UnboundedSender, unbounded_channel};
 use tokio_stream::StreamExt;
 
-use crate::{BlockOnTokio, client::Client, output::OutputHandle, tag::TagHandle};
+use crate::{
+    BlockOnTokio,
+    client::Client,
+    output::OutputHandle,
+    signal::{catch_callback_panic, report_callback_panic},
+    tag::TagHandle,
+};
 
 /// A response to a layout request containing a layout tree.
 pub struct LayoutResponse {
@@ -40,17 +46,43 @@ pub struct LayoutResponse {
 /// This returns a [`LayoutRequester`] that allows you to force the compositor to emit a
 /// layout request.
 ///
+/// If `on_layout` panics, that layout request is skipped and the panic is reported through
+/// [`ConfigSignal::CallbackPanicked`][crate::signal::ConfigSignal::CallbackPanicked].
+///
 /// See the module level documentation for more information on how to generate layouts.
 pub fn manage(
+    on_layout: impl FnMut(LayoutArgs) -> LayoutResponse + Send + 'static,
+) -> LayoutRequester {
+    handle_layouts(false, on_layout)
+}
+
+/// Registers this process as an external layout generator.
+///
+/// This works like [`manage`], but can be called from any process connected to Pinnacle,
+/// not just the config. While this process is connected, its layouts take priority over the
+/// config's. Once it disconnects, Pinnacle falls back to the previously registered generator,
+/// then to the config, then to a built-in layout.
+pub fn register_generator(
+    on_layout: impl FnMut(LayoutArgs) -> LayoutResponse + Send + 'static,
+) -> LayoutRequester {
+    handle_layouts(true, on_layout)
+}
+
+fn handle_layouts(
+    external: bool,
     mut on_layout: impl FnMut(LayoutArgs) -> LayoutResponse + Send + 'static,
 ) -> LayoutRequester {
     let (from_client, to_server) = unbounded_channel::<LayoutRequest>();
     let to_server_stream = tokio_stream::wrappers::UnboundedReceiverStream::new(to_server);
-    let mut from_server = Client::layout()
-        .layout(to_server_stream)
-        .block_on_tokio()
-        .unwrap()
-        .into_inner();
+    let mut from_server = if external {
+        Client::layout()
+            .register_generator(to_server_stream)
+            .block_on_tokio()
+    } else {
+        Client::layout().layout(to_server_stream).block_on_tokio()
+    }
+    .unwrap()
+    .into_inner();
 
     let from_client_clone = from_client.clone();
 
@@ -71,7 +103,14 @@ pub fn manage(
                     .map(|id| TagHandle { id })
                     .collect(),
             };
-            let tree_response = on_layout(args);
+            // Skip this layout if the generator panics so later requests still get laid out
+            let tree_response = match catch_callback_panic(|| on_layout(args)) {
+                Ok(tree_response) => tree_response,
+                Err(payload) => {
+                    report_callback_panic("Layout", payload);
+                    continue;
+                }
+            };
             from_client
                 .send(LayoutRequest {
                     request: Some(layout_request::Request::TreeResponse(
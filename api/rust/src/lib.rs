@@ -67,6 +67,8 @@
 //!
 //! Take a look at the default config or browse the docs to see what you can do.
 
+use std::time::Duration;
+
 use client::Client;
 use futures::{Future, StreamExt};
 use hyper_util::rt::TokioIo;
@@ -74,19 +76,27 @@ use tonic::transport::{Endpoint, Uri};
 use tower::service_fn;
 
 pub mod debug;
+pub mod env;
 pub mod experimental;
+pub mod idle;
 pub mod input;
 pub mod layout;
+pub mod nightlight;
 pub mod output;
+pub mod pager;
 pub mod pinnacle;
+pub mod portal;
+pub mod power;
 pub mod process;
 pub mod render;
+pub mod screenshot;
 pub mod signal;
 #[cfg(feature = "snowcap")]
 pub mod snowcap;
 pub mod tag;
 pub mod util;
 pub mod window;
+pub mod workspaces;
 
 mod client;
 
@@ -122,6 +132,38 @@ pub async fn connect() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+/// Sets how long requests to Pinnacle may take before they fail.
+///
+/// Requests that outlive the deadline fail with a `DEADLINE_EXCEEDED` status instead of
+/// blocking forever, which would otherwise freeze your config if Pinnacle stops responding.
+/// Functions that return a `Result`, like the `try_*` getters on
+/// [`OutputHandle`][output::OutputHandle] and [`TagHandle`][tag::TagHandle], return a
+/// [`RequestError`] for which [`RequestError::is_deadline_exceeded`] is `true`.
+/// Functions that don't return a `Result` log the error and return a default value instead,
+/// like an empty string or `false`.
+///
+/// Pass `None` to wait indefinitely. This is the default.
+///
+/// This applies to requests made after this is called.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use std::time::Duration;
+/// pinnacle_api::set_request_deadline(Some(Duration::from_secs(5)));
+/// ```
+pub fn set_request_deadline(deadline: Option<Duration>) {
+    Client::set_request_deadline(deadline);
+}
+
+/// Cancels all requests to Pinnacle that are currently in flight.
+///
+/// Cancelled requests fail with a `CANCELLED` status; see [`RequestError::is_cancelled`].
+/// Functions that don't return a `Result` log the error and return a default value instead.
+pub fn cancel_requests() {
+    Client::cancel_requests();
+}
+
 /// Blocks until Pinnacle exits.
 ///
 /// This function is called by the [`main`] and [`config`] macros.
@@ -154,6 +196,110 @@ impl<F: Future> BlockOnTokio for F {
     }
 }
 
+/// A request to Pinnacle that failed.
+///
+/// This happens when a request outlives the [request deadline][set_request_deadline],
+/// is [cancelled][cancel_requests], Pinnacle can't be reached, or Pinnacle couldn't
+/// carry the request out.
+#[derive(Debug, Clone)]
+pub struct RequestError(tonic::Status);
+
+impl RequestError {
+    /// Returns whether the request failed because it outlived the request deadline.
+    pub fn is_deadline_exceeded(&self) -> bool {
+        self.0.code() == tonic::Code::DeadlineExceeded
+    }
+
+    /// Returns whether the request failed because it was cancelled with [`cancel_requests`].
+    pub fn is_cancelled(&self) -> bool {
+        self.0.code() == tonic::Code::Cancelled
+    }
+
+    /// Returns the error message sent with the failed request.
+    pub fn message(&self) -> &str {
+        self.0.message()
+    }
+}
+
+impl std::fmt::Display for RequestError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "request to Pinnacle failed ({}): {}",
+            self.0.code(),
+            self.0.message()
+        )
+    }
+}
+
+impl std::error::Error for RequestError {}
+
+impl PartialEq for RequestError {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.code() == other.0.code() && self.0.message() == other.0.message()
+    }
+}
+
+impl From<tonic::Status> for RequestError {
+    fn from(status: tonic::Status) -> Self {
+        Self(status)
+    }
+}
+
+/// Handles failed requests.
+trait ResponseExt {
+    type Output;
+
+    /// Returns the response's message, or a [`RequestError`] if the request failed.
+    fn into_result(self) -> Result<Self::Output, RequestError>;
+
+    /// Returns the response's message, or logs the error and returns
+    /// the message's default if the request failed.
+    fn into_inner_or_default(self) -> Self::Output;
+
+    /// Logs the error if the request failed.
+    fn log_err(self);
+}
+
+impl<T: Default> ResponseExt for Result<tonic::Response<T>, tonic::Status> {
+    type Output = T;
+
+    fn into_result(self) -> Result<Self::Output, RequestError> {
+        self.map(tonic::Response::into_inner)
+            .map_err(RequestError::from)
+    }
+
+    fn into_inner_or_default(self) -> Self::Output {
+        self.into_result().unwrap_or_log()
+    }
+
+    fn log_err(self) {
+        if let Err(err) = self.into_result() {
+            log_failed_request(&err);
+        }
+    }
+}
+
+/// Handles errors from `try_*` functions in the functions that don't return a `Result`.
+trait RequestResultExt {
+    type Output;
+
+    /// Returns the value, or logs the error and returns the default if the request failed.
+    fn unwrap_or_log(self) -> Self::Output;
+}
+
+impl<T: Default> RequestResultExt for Result<T, RequestError> {
+    type Output = T;
+
+    fn unwrap_or_log(self) -> Self::Output {
+        self.inspect_err(log_failed_request).unwrap_or_default()
+    }
+}
+
+fn log_failed_request(err: &RequestError) {
+    eprintln!("{err}");
+}
+
 /// Defines the config's main entry point.
 ///
 /// This macro creates a `main` function annotated with
@@ -201,6 +347,13 @@ macro_rules! config {
         let hook = ::std::panic::take_hook();
         ::std::panic::set_hook(::std::boxed::Box::new(move |info| {
             hook(info);
+
+            // Panics in signal callbacks and layout generators are reported
+            // through `ConfigSignal::CallbackPanicked` instead of ending the config
+            if $crate::signal::is_isolating_panics() {
+                return;
+            }
+
             let backtrace = ::std::backtrace::Backtrace::force_capture();
             let error_msg = format!("{info}\n{backtrace}");
             let _ = send.send(error_msg);
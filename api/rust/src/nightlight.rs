@@ -0,0 +1,162 @@
+//! The night light.
+//!
+//! The night light tints outputs to a warmer color temperature at night.
+//! It fades in and out instead of switching at once.
+
+use std::time::Duration;
+
+use pinnacle_api_defs::pinnacle::nightlight::v1::{
+    self, SetScheduleRequest, SunEvent, schedule_time,
+};
+
+use crate::{BlockOnTokio, ResponseExt, client::Client};
+
+/// When the night light turns on or off.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScheduleTime {
+    /// A local time of day.
+    At {
+        /// The hour, from 0 to 23.
+        hour: u32,
+        /// The minute, from 0 to 59.
+        minute: u32,
+    },
+    /// Sunrise at the schedule's [`Location`].
+    Sunrise,
+    /// Sunset at the schedule's [`Location`].
+    Sunset,
+}
+
+/// A place on Earth, in degrees.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Location {
+    /// The latitude, from -90 (south) to 90 (north).
+    pub latitude: f64,
+    /// The longitude, from -180 (west) to 180 (east).
+    pub longitude: f64,
+}
+
+/// When and how warm the night light is.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Schedule {
+    /// When the night light turns on.
+    pub start: ScheduleTime,
+    /// When the night light turns off.
+    pub end: ScheduleTime,
+    /// The color temperature while the night light is on, in Kelvin.
+    ///
+    /// This must be between 1000 and 6500, the temperature of outputs without the night light.
+    pub temperature: u32,
+    /// How long fading between temperatures takes.
+    pub transition: Duration,
+    /// Where sunrise and sunset are calculated for.
+    ///
+    /// Without a location, sunrise is at 6:00 and sunset is at 18:00.
+    pub location: Option<Location>,
+}
+
+impl Schedule {
+    /// Creates a schedule that fades over 30 minutes and has no location.
+    pub fn new(start: ScheduleTime, end: ScheduleTime, temperature: u32) -> Self {
+        Self {
+            start,
+            end,
+            temperature,
+            transition: Duration::from_secs(30 * 60),
+            location: None,
+        }
+    }
+
+    /// Sets how long fading between temperatures takes.
+    pub fn transition(self, transition: Duration) -> Self {
+        Self { transition, ..self }
+    }
+
+    /// Sets where sunrise and sunset are calculated for.
+    pub fn location(self, latitude: f64, longitude: f64) -> Self {
+        Self {
+            location: Some(Location {
+                latitude,
+                longitude,
+            }),
+            ..self
+        }
+    }
+}
+
+/// Turns the night light on between `start` and `end`, tinting outputs to `temperature` Kelvin.
+///
+/// Use [`set_schedule`] to change the transition or to use sunrise and sunset at a location.
+///
+/// # Panics
+///
+/// Panics if `temperature` isn't between 1000 and 6500 or a time is out of range.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::nightlight::{self, ScheduleTime};
+/// nightlight::schedule(
+///     ScheduleTime::At { hour: 20, minute: 0 },
+///     ScheduleTime::At { hour: 7, minute: 0 },
+///     4000,
+/// );
+/// ```
+pub fn schedule(start: ScheduleTime, end: ScheduleTime, temperature: u32) {
+    set_schedule(Some(Schedule::new(start, end, temperature)));
+}
+
+/// Sets the night light's schedule, or disables it with `None`.
+///
+/// # Panics
+///
+/// Panics if the temperature isn't between 1000 and 6500 or a time or the location
+/// is out of range.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::nightlight::{self, Schedule, ScheduleTime};
+/// # use std::time::Duration;
+/// nightlight::set_schedule(Some(
+///     Schedule::new(ScheduleTime::Sunset, ScheduleTime::Sunrise, 3500)
+///         .location(52.52, 13.40)
+///         .transition(Duration::from_secs(60 * 60)),
+/// ));
+///
+/// // Turn the night light off
+/// nightlight::set_schedule(None);
+/// ```
+pub fn set_schedule(schedule: Option<Schedule>) {
+    let schedule = schedule.map(|schedule| v1::Schedule {
+        start: Some(schedule.start.into()),
+        end: Some(schedule.end.into()),
+        temperature: schedule.temperature,
+        transition_millis: schedule
+            .transition
+            .as_millis()
+            .try_into()
+            .unwrap_or(u32::MAX),
+        location: schedule.location.map(|location| v1::Location {
+            latitude: location.latitude,
+            longitude: location.longitude,
+        }),
+    });
+
+    Client::nightlight()
+        .set_schedule(SetScheduleRequest { schedule })
+        .block_on_tokio()
+        .log_err();
+}
+
+impl From<ScheduleTime> for v1::ScheduleTime {
+    fn from(value: ScheduleTime) -> Self {
+        let time = match value {
+            ScheduleTime::At { hour, minute } => schedule_time::Time::Minutes(hour * 60 + minute),
+            ScheduleTime::Sunrise => schedule_time::Time::SunEvent(SunEvent::Sunrise.into()),
+            ScheduleTime::Sunset => schedule_time::Time::SunEvent(SunEvent::Sunset.into()),
+        };
+
+        v1::ScheduleTime { time: Some(time) }
+    }
+}
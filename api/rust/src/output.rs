@@ -8,30 +8,35 @@
 //!
 //! Outputs are uniquely identified by their name, a.k.a. the name of the connector they're plugged in to.
 
-use std::str::FromStr;
+use std::{str::FromStr, time::Duration};
 
-use futures::FutureExt;
+use futures::{FutureExt, StreamExt};
 use pinnacle_api_defs::pinnacle::{
     output::{
         self,
         v1::{
-            FocusRequest, GetEnabledRequest, GetFocusStackWindowIdsRequest, GetFocusedRequest,
-            GetInfoRequest, GetLocRequest, GetLogicalSizeRequest, GetModesRequest,
-            GetOutputsInDirRequest, GetPhysicalSizeRequest, GetPoweredRequest, GetRequest,
-            GetScaleRequest, GetTagIdsRequest, GetTransformRequest, SetLocRequest, SetModeRequest,
-            SetModelineRequest, SetPoweredRequest, SetScaleRequest, SetTransformRequest,
-            SetVrrRequest,
+            ConfirmModeChangeRequest, FocusRequest, GetColorDepthRequest, GetEnabledRequest,
+            GetFocusStackWindowIdsRequest, GetFocusedRequest, GetHdrRequest, GetInfoRequest,
+            GetLocRequest, GetLogicalSizeRequest, GetModesRequest, GetOutputsInDirRequest,
+            GetPhysicalSizeRequest, GetPoweredRequest, GetRenderStatsRequest, GetRequest,
+            GetScaleRequest, GetSrgbEmulationRequest, GetStackingOrderRequest, GetTagIdsRequest,
+            GetTransformRequest, SetAutoRotateRequest, SetColorDepthRequest, SetColorFilterRequest,
+            SetHdrRequest, SetHotplugSettleDelayRequest, SetLocRequest, SetModeRequest,
+            SetModelineRequest, SetPoweredRequest, SetScaleRequest, SetSrgbEmulationRequest,
+            SetTransformRequest, SetVrrRequest, SetXcursorRequest, WatchPropertiesRequest,
         },
     },
     util::v1::{AbsOrRel, SetOrToggle},
 };
 
 use crate::{
-    BlockOnTokio,
+    BlockOnTokio, RequestError, RequestResultExt, ResponseExt,
     client::Client,
+    input::EdgeAction,
+    log_failed_request,
     signal::{OutputSignal, SignalHandle},
     tag::TagHandle,
-    util::{Batch, Direction, Point, Size},
+    util::{Batch, Direction, Point, Size, WatchHandle},
     window::WindowHandle,
 };
 
@@ -51,14 +56,27 @@ pub fn get_all() -> impl Iterator<Item = OutputHandle> {
 
 /// Async impl for [`get_all`].
 pub async fn get_all_async() -> impl Iterator<Item = OutputHandle> {
-    Client::output()
+    try_get_all_async()
+        .await
+        .inspect_err(log_failed_request)
+        .into_iter()
+        .flatten()
+}
+
+/// Like [`get_all`], but returns an error if the request to Pinnacle fails.
+pub fn try_get_all() -> Result<impl Iterator<Item = OutputHandle>, RequestError> {
+    try_get_all_async().block_on_tokio()
+}
+
+/// Async impl for [`try_get_all`].
+pub async fn try_get_all_async() -> Result<impl Iterator<Item = OutputHandle>, RequestError> {
+    Ok(Client::output()
         .get(GetRequest {})
         .await
-        .unwrap()
-        .into_inner()
+        .into_result()?
         .output_names
         .into_iter()
-        .map(|name| OutputHandle { name })
+        .map(|name| OutputHandle { name }))
 }
 
 /// Gets handles to all currently plugged-in *and enabled* outputs.
@@ -141,6 +159,30 @@ pub fn for_each_output(mut for_each: impl FnMut(&OutputHandle) + Send + 'static)
         .add_callback(Box::new(for_each));
 }
 
+/// Sets how long output hotplug events must settle before outputs are reconfigured.
+///
+/// Docks and KVM switches can connect and disconnect monitors many times in quick
+/// succession. Pinnacle waits until no hotplug events have happened for `delay`
+/// before connecting or disconnecting outputs, so only the final state is applied.
+///
+/// The default is 200ms. A delay of zero handles every hotplug event immediately.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::output;
+/// # use std::time::Duration;
+/// output::set_hotplug_settle_delay(Duration::from_millis(500));
+/// ```
+pub fn set_hotplug_settle_delay(delay: Duration) {
+    Client::output()
+        .set_hotplug_settle_delay(SetHotplugSettleDelayRequest {
+            millis: delay.as_millis().try_into().unwrap_or(u32::MAX),
+        })
+        .block_on_tokio()
+        .log_err();
+}
+
 /// Connects to an [`OutputSignal`].
 ///
 /// # Examples
@@ -162,7 +204,18 @@ pub fn connect_signal(signal: OutputSignal) -> SignalHandle {
         OutputSignal::Move(f) => signal_state.output_move.add_callback(f),
         OutputSignal::PointerEnter(f) => signal_state.output_pointer_enter.add_callback(f),
         OutputSignal::PointerLeave(f) => signal_state.output_pointer_leave.add_callback(f),
+        OutputSignal::PointerEdge(f) => signal_state.output_pointer_edge.add_callback(f),
         OutputSignal::Focused(f) => signal_state.output_focused.add_callback(f),
+        OutputSignal::LayerFocused(f) => signal_state.output_layer_focused.add_callback(f),
+        OutputSignal::LayerUnfocused(f) => signal_state.output_layer_unfocused.add_callback(f),
+        OutputSignal::OrientationChanged(f) => {
+            signal_state.output_orientation_changed.add_callback(f)
+        }
+        OutputSignal::FullscreenChanged(f) => {
+            signal_state.output_fullscreen_changed.add_callback(f)
+        }
+        OutputSignal::StackingChanged(f) => signal_state.output_stacking_changed.add_callback(f),
+        OutputSignal::NoActiveTags(f) => signal_state.output_no_active_tags.add_callback(f),
     }
 }
 
@@ -261,6 +314,60 @@ impl From<Transform> for output::v1::Transform {
     }
 }
 
+/// An edge or corner of an output.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ScreenEdge {
+    /// The top edge.
+    Top,
+    /// The bottom edge.
+    Bottom,
+    /// The left edge.
+    Left,
+    /// The right edge.
+    Right,
+    /// The top-left corner.
+    TopLeft,
+    /// The top-right corner.
+    TopRight,
+    /// The bottom-left corner.
+    BottomLeft,
+    /// The bottom-right corner.
+    BottomRight,
+}
+
+impl TryFrom<output::v1::ScreenEdge> for ScreenEdge {
+    type Error = ();
+
+    fn try_from(value: output::v1::ScreenEdge) -> Result<Self, Self::Error> {
+        match value {
+            output::v1::ScreenEdge::Unspecified => Err(()),
+            output::v1::ScreenEdge::Top => Ok(ScreenEdge::Top),
+            output::v1::ScreenEdge::Bottom => Ok(ScreenEdge::Bottom),
+            output::v1::ScreenEdge::Left => Ok(ScreenEdge::Left),
+            output::v1::ScreenEdge::Right => Ok(ScreenEdge::Right),
+            output::v1::ScreenEdge::TopLeft => Ok(ScreenEdge::TopLeft),
+            output::v1::ScreenEdge::TopRight => Ok(ScreenEdge::TopRight),
+            output::v1::ScreenEdge::BottomLeft => Ok(ScreenEdge::BottomLeft),
+            output::v1::ScreenEdge::BottomRight => Ok(ScreenEdge::BottomRight),
+        }
+    }
+}
+
+impl From<ScreenEdge> for output::v1::ScreenEdge {
+    fn from(value: ScreenEdge) -> Self {
+        match value {
+            ScreenEdge::Top => output::v1::ScreenEdge::Top,
+            ScreenEdge::Bottom => output::v1::ScreenEdge::Bottom,
+            ScreenEdge::Left => output::v1::ScreenEdge::Left,
+            ScreenEdge::Right => output::v1::ScreenEdge::Right,
+            ScreenEdge::TopLeft => output::v1::ScreenEdge::TopLeft,
+            ScreenEdge::TopRight => output::v1::ScreenEdge::TopRight,
+            ScreenEdge::BottomLeft => output::v1::ScreenEdge::BottomLeft,
+            ScreenEdge::BottomRight => output::v1::ScreenEdge::BottomRight,
+        }
+    }
+}
+
 /// The state of variable refresh rate on an output.
 #[doc(alias = "AdaptiveSync")]
 #[doc(alias = "VariableRefreshRate")]
@@ -276,6 +383,22 @@ pub enum Vrr {
     OnDemand,
 }
 
+/// A color filter applied to everything on an output.
+#[derive(Default, Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ColorFilter {
+    /// No filter.
+    #[default]
+    None,
+    /// Inverts colors.
+    Invert,
+    /// Removes all color.
+    Grayscale,
+    /// Corrects colors for deuteranopia (green-weak red-green color blindness).
+    DeuteranopiaCorrection,
+    /// Corrects colors for protanopia (red-weak red-green color blindness).
+    ProtanopiaCorrection,
+}
+
 impl OutputHandle {
     /// Creates an output handle from a name.
     pub fn from_name(name: impl ToString) -> Self {
@@ -326,7 +449,7 @@ impl OutputHandle {
                 y,
             })
             .block_on_tokio()
-            .unwrap();
+            .log_err();
     }
 
     /// Sets this output adjacent to another one.
@@ -467,9 +590,10 @@ impl OutputHandle {
                 size: Some(pinnacle_api_defs::pinnacle::util::v1::Size { width, height }),
                 refresh_rate_mhz: refresh_rate_mhz.into(),
                 custom: false,
+                revert_after_ms: None,
             })
             .block_on_tokio()
-            .unwrap();
+            .log_err();
     }
 
     /// Sets this output's mode to a custom one.
@@ -502,9 +626,10 @@ impl OutputHandle {
                 size: Some(pinnacle_api_defs::pinnacle::util::v1::Size { width, height }),
                 refresh_rate_mhz: refresh_rate_mhz.into(),
                 custom: true,
+                revert_after_ms: None,
             })
             .block_on_tokio()
-            .unwrap();
+            .log_err();
     }
 
     /// Sets a custom modeline for this output.
@@ -529,9 +654,113 @@ impl OutputHandle {
             .set_modeline(SetModelineRequest {
                 output_name: self.name(),
                 modeline: Some(modeline.into()),
+                revert_after_ms: None,
             })
             .block_on_tokio()
-            .unwrap();
+            .log_err();
+    }
+
+    /// Sets this output's mode like [`set_mode`][Self::set_mode], then reverts it after
+    /// `revert_after` unless [`confirm_mode_change`][Self::confirm_mode_change] is called first.
+    ///
+    /// This keeps a mode the monitor can't show from leaving you with a blank screen.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pinnacle_api::output;
+    /// # use std::time::Duration;
+    /// # || {
+    /// let output = output::get_focused()?;
+    /// output.test_mode(2560, 1440, 144000, Duration::from_secs(10));
+    /// // Later, once the user says it looks fine
+    /// output.confirm_mode_change();
+    /// # Some(())
+    /// # };
+    /// ```
+    pub fn test_mode(
+        &self,
+        width: u32,
+        height: u32,
+        refresh_rate_mhz: impl Into<Option<u32>>,
+        revert_after: Duration,
+    ) {
+        Client::output()
+            .set_mode(SetModeRequest {
+                output_name: self.name(),
+                size: Some(pinnacle_api_defs::pinnacle::util::v1::Size { width, height }),
+                refresh_rate_mhz: refresh_rate_mhz.into(),
+                custom: false,
+                revert_after_ms: Some(revert_after.as_millis() as u32),
+            })
+            .block_on_tokio()
+            .log_err();
+    }
+
+    /// Sets this output's mode to a custom one like [`set_custom_mode`][Self::set_custom_mode],
+    /// then reverts it after `revert_after` unless
+    /// [`confirm_mode_change`][Self::confirm_mode_change] is called first.
+    pub fn test_custom_mode(
+        &self,
+        width: u32,
+        height: u32,
+        refresh_rate_mhz: impl Into<Option<u32>>,
+        revert_after: Duration,
+    ) {
+        Client::output()
+            .set_mode(SetModeRequest {
+                output_name: self.name(),
+                size: Some(pinnacle_api_defs::pinnacle::util::v1::Size { width, height }),
+                refresh_rate_mhz: refresh_rate_mhz.into(),
+                custom: true,
+                revert_after_ms: Some(revert_after.as_millis() as u32),
+            })
+            .block_on_tokio()
+            .log_err();
+    }
+
+    /// Sets a custom modeline for this output like [`set_modeline`][Self::set_modeline],
+    /// then reverts it after `revert_after` unless
+    /// [`confirm_mode_change`][Self::confirm_mode_change] is called first.
+    pub fn test_modeline(&self, modeline: Modeline, revert_after: Duration) {
+        Client::output()
+            .set_modeline(SetModelineRequest {
+                output_name: self.name(),
+                modeline: Some(modeline.into()),
+                revert_after_ms: Some(revert_after.as_millis() as u32),
+            })
+            .block_on_tokio()
+            .log_err();
+    }
+
+    /// Keeps a mode set with [`test_mode`][Self::test_mode],
+    /// [`test_custom_mode`][Self::test_custom_mode], or [`test_modeline`][Self::test_modeline]
+    /// from being reverted.
+    ///
+    /// Returns whether there was a mode change waiting to be confirmed.
+    pub fn confirm_mode_change(&self) -> bool {
+        self.confirm_mode_change_async().block_on_tokio()
+    }
+
+    /// Async impl for [`Self::confirm_mode_change`].
+    pub async fn confirm_mode_change_async(&self) -> bool {
+        self.try_confirm_mode_change_async().await.unwrap_or_log()
+    }
+
+    /// Like [`Self::confirm_mode_change`], but returns an error if the request to Pinnacle fails.
+    pub fn try_confirm_mode_change(&self) -> Result<bool, RequestError> {
+        self.try_confirm_mode_change_async().block_on_tokio()
+    }
+
+    /// Async impl for [`Self::try_confirm_mode_change`].
+    pub async fn try_confirm_mode_change_async(&self) -> Result<bool, RequestError> {
+        Ok(Client::output()
+            .confirm_mode_change(ConfirmModeChangeRequest {
+                output_name: self.name(),
+            })
+            .await
+            .into_result()?
+            .confirmed)
     }
 
     /// Sets this output's scaling factor.
@@ -543,7 +772,7 @@ impl OutputHandle {
                 abs_or_rel: AbsOrRel::Absolute.into(),
             })
             .block_on_tokio()
-            .unwrap();
+            .log_err();
     }
 
     /// Changes this output's scaling factor by a relative amount.
@@ -566,7 +795,7 @@ impl OutputHandle {
                 abs_or_rel: AbsOrRel::Relative.into(),
             })
             .block_on_tokio()
-            .unwrap();
+            .log_err();
     }
 
     /// Sets this output's [`Transform`].
@@ -589,7 +818,145 @@ impl OutputHandle {
                 transform: output::v1::Transform::from(transform).into(),
             })
             .block_on_tokio()
-            .unwrap();
+            .log_err();
+    }
+
+    /// Sets whether this output is rotated to match the device's orientation.
+    ///
+    /// The orientation is read from the accelerometer through iio-sensor-proxy,
+    /// so this only has an effect on convertibles and tablets.
+    /// Connect to [`OutputSignal::OrientationChanged`] to be notified of rotations.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pinnacle_api::output;
+    /// # || {
+    /// output::get_by_name("eDP-1")?.set_auto_rotate(true);
+    /// # Some(())
+    /// # };
+    /// ```
+    pub fn set_auto_rotate(&self, auto_rotate: bool) {
+        Client::output()
+            .set_auto_rotate(SetAutoRotateRequest {
+                output_name: self.name(),
+                auto_rotate,
+            })
+            .block_on_tokio()
+            .log_err();
+    }
+
+    /// Sets whether this output is in HDR mode.
+    ///
+    /// In HDR mode, the monitor is switched to the BT.2020 colorspace and all content
+    /// is encoded with the PQ transfer function. Content keeps looking the same,
+    /// with white at the reference luminance for SDR content.
+    ///
+    /// Returns an error if the output doesn't [support HDR][Self::hdr_capable]
+    /// or couldn't be switched.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pinnacle_api::output;
+    /// # || {
+    /// let output = output::get_focused()?;
+    /// if output.hdr_capable() {
+    ///     if let Err(err) = output.set_hdr(true) {
+    ///         eprintln!("Failed to enable HDR: {err}");
+    ///     }
+    /// }
+    /// # Some(())
+    /// # };
+    /// ```
+    pub fn set_hdr(&self, hdr: bool) -> Result<(), String> {
+        let error = Client::output()
+            .set_hdr(SetHdrRequest {
+                output_name: self.name(),
+                hdr,
+            })
+            .block_on_tokio()
+            .into_result()
+            .map_err(|err| err.to_string())?
+            .error;
+
+        match error {
+            Some(error) => Err(error),
+            None => Ok(()),
+        }
+    }
+
+    /// Sets the number of bits per color channel this output is rendered and scanned out with.
+    ///
+    /// `color_depth` can be 8 or 10. 10 bits reduce banding in gradients. If the monitor or GPU
+    /// can't do 10 bits, the output falls back to 8; check [`Self::color_depth`] for the depth
+    /// actually used.
+    ///
+    /// Outputs on the same GPU share a color depth, so this changes it for all of them.
+    /// They are briefly disconnected while the change is applied.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pinnacle_api::output;
+    /// # || {
+    /// if let Err(err) = output::get_focused()?.set_color_depth(10) {
+    ///     eprintln!("Failed to set color depth: {err}");
+    /// }
+    /// # Some(())
+    /// # };
+    /// ```
+    pub fn set_color_depth(&self, color_depth: u32) -> Result<(), String> {
+        let error = Client::output()
+            .set_color_depth(SetColorDepthRequest {
+                output_name: self.name(),
+                color_depth,
+            })
+            .block_on_tokio()
+            .into_result()
+            .map_err(|err| err.to_string())?
+            .error;
+
+        match error {
+            Some(error) => Err(error),
+            None => Ok(()),
+        }
+    }
+
+    /// Sets whether sRGB content on this output is converted to the monitor's primaries.
+    ///
+    /// Wide-gamut monitors show sRGB content oversaturated. With sRGB emulation, colors
+    /// are mapped to the primaries the monitor's EDID advertises so they look as intended.
+    ///
+    /// Fails if the monitor doesn't advertise its primaries. Has no effect while the output
+    /// is in HDR mode, which already maps colors to the monitor's gamut.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pinnacle_api::output;
+    /// # || {
+    /// if let Err(err) = output::get_focused()?.set_srgb_emulation(true) {
+    ///     eprintln!("Failed to enable sRGB emulation: {err}");
+    /// }
+    /// # Some(())
+    /// # };
+    /// ```
+    pub fn set_srgb_emulation(&self, srgb_emulation: bool) -> Result<(), String> {
+        let error = Client::output()
+            .set_srgb_emulation(SetSrgbEmulationRequest {
+                output_name: self.name(),
+                srgb_emulation,
+            })
+            .block_on_tokio()
+            .into_result()
+            .map_err(|err| err.to_string())?
+            .error;
+
+        match error {
+            Some(error) => Err(error),
+            None => Ok(()),
+        }
     }
 
     /// Powers on or off this output.
@@ -607,7 +974,7 @@ impl OutputHandle {
                 .into(),
             })
             .block_on_tokio()
-            .unwrap();
+            .log_err();
     }
 
     /// Toggles the power on this output.
@@ -621,7 +988,7 @@ impl OutputHandle {
                 set_or_toggle: SetOrToggle::Toggle.into(),
             })
             .block_on_tokio()
-            .unwrap();
+            .log_err();
     }
 
     /// Sets the variable refresh rate state of this output.
@@ -640,7 +1007,103 @@ impl OutputHandle {
                 } as i32,
             })
             .block_on_tokio()
-            .unwrap();
+            .log_err();
+    }
+
+    /// Sets the color filter applied to everything on this output.
+    ///
+    /// See [`ColorFilter`] for possible filters.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pinnacle_api::output;
+    /// # use pinnacle_api::output::ColorFilter;
+    /// # || {
+    /// output::get_by_name("eDP-1")?.set_color_filter(ColorFilter::Grayscale);
+    /// # Some(())
+    /// # };
+    /// ```
+    pub fn set_color_filter(&self, filter: ColorFilter) {
+        Client::output()
+            .set_color_filter(SetColorFilterRequest {
+                output_name: self.name(),
+                filter: match filter {
+                    ColorFilter::None => output::v1::ColorFilter::None,
+                    ColorFilter::Invert => output::v1::ColorFilter::Invert,
+                    ColorFilter::Grayscale => output::v1::ColorFilter::Grayscale,
+                    ColorFilter::DeuteranopiaCorrection => {
+                        output::v1::ColorFilter::DeuteranopiaCorrection
+                    }
+                    ColorFilter::ProtanopiaCorrection => {
+                        output::v1::ColorFilter::ProtanopiaCorrection
+                    }
+                } as i32,
+            })
+            .block_on_tokio()
+            .log_err();
+    }
+
+    /// Sets the xcursor theme used on this output.
+    ///
+    /// This overrides the theme set with [`input::set_xcursor_theme`][crate::input::set_xcursor_theme].
+    /// Pass an empty string to use that theme again.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pinnacle_api::output;
+    /// # || {
+    /// output::get_by_name("HDMI-A-1")?.set_xcursor_theme("Adwaita");
+    /// # Some(())
+    /// # };
+    /// ```
+    pub fn set_xcursor_theme(&self, theme: impl ToString) {
+        Client::output()
+            .set_xcursor(SetXcursorRequest {
+                output_name: self.name(),
+                theme: Some(theme.to_string()),
+                size: None,
+            })
+            .block_on_tokio()
+            .log_err();
+    }
+
+    /// Sets the xcursor size used on this output.
+    ///
+    /// This overrides the size set with [`input::set_xcursor_size`][crate::input::set_xcursor_size].
+    /// Pass 0 to use that size again.
+    ///
+    /// The cursor is still scaled by the output's scale.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pinnacle_api::output;
+    /// # || {
+    /// output::get_by_name("HDMI-A-1")?.set_xcursor_size(48);
+    /// # Some(())
+    /// # };
+    /// ```
+    pub fn set_xcursor_size(&self, size: u32) {
+        Client::output()
+            .set_xcursor(SetXcursorRequest {
+                output_name: self.name(),
+                theme: None,
+                size: Some(size),
+            })
+            .block_on_tokio()
+            .log_err();
+    }
+
+    /// Sets what happens when the pointer hits `edge` of this output.
+    ///
+    /// This takes precedence over the action set for all outputs with
+    /// [`input::set_edge_action`].
+    ///
+    /// [`input::set_edge_action`]: crate::input::set_edge_action
+    pub fn set_edge_action(&self, edge: ScreenEdge, action: EdgeAction) {
+        crate::input::send_edge_action(Some(self.name()), edge, action);
     }
 
     /// Focuses this output.
@@ -650,7 +1113,7 @@ impl OutputHandle {
                 output_name: self.name(),
             })
             .block_on_tokio()
-            .unwrap();
+            .log_err();
     }
 
     /// Gets this output's make.
@@ -660,14 +1123,23 @@ impl OutputHandle {
 
     /// Async impl for [`Self::make`].
     pub async fn make_async(&self) -> String {
-        Client::output()
+        self.try_make_async().await.unwrap_or_log()
+    }
+
+    /// Like [`Self::make`], but returns an error if the request to Pinnacle fails.
+    pub fn try_make(&self) -> Result<String, RequestError> {
+        self.try_make_async().block_on_tokio()
+    }
+
+    /// Async impl for [`Self::try_make`].
+    pub async fn try_make_async(&self) -> Result<String, RequestError> {
+        Ok(Client::output()
             .get_info(GetInfoRequest {
                 output_name: self.name(),
             })
             .await
-            .unwrap()
-            .into_inner()
-            .make
+            .into_result()?
+            .make)
     }
 
     /// Gets this output's model.
@@ -677,14 +1149,23 @@ impl OutputHandle {
 
     /// Async impl for [`Self::model`].
     pub async fn model_async(&self) -> String {
-        Client::output()
+        self.try_model_async().await.unwrap_or_log()
+    }
+
+    /// Like [`Self::model`], but returns an error if the request to Pinnacle fails.
+    pub fn try_model(&self) -> Result<String, RequestError> {
+        self.try_model_async().block_on_tokio()
+    }
+
+    /// Async impl for [`Self::try_model`].
+    pub async fn try_model_async(&self) -> Result<String, RequestError> {
+        Ok(Client::output()
             .get_info(GetInfoRequest {
                 output_name: self.name(),
             })
             .await
-            .unwrap()
-            .into_inner()
-            .model
+            .into_result()?
+            .model)
     }
 
     /// Gets this output's serial.
@@ -694,14 +1175,65 @@ impl OutputHandle {
 
     /// Async impl for [`Self::serial`].
     pub async fn serial_async(&self) -> String {
-        Client::output()
+        self.try_serial_async().await.unwrap_or_log()
+    }
+
+    /// Like [`Self::serial`], but returns an error if the request to Pinnacle fails.
+    pub fn try_serial(&self) -> Result<String, RequestError> {
+        self.try_serial_async().block_on_tokio()
+    }
+
+    /// Async impl for [`Self::try_serial`].
+    pub async fn try_serial_async(&self) -> Result<String, RequestError> {
+        Ok(Client::output()
             .get_info(GetInfoRequest {
                 output_name: self.name(),
             })
             .await
-            .unwrap()
-            .into_inner()
-            .serial
+            .into_result()?
+            .serial)
+    }
+
+    /// Gets the properties parsed from this output's EDID.
+    ///
+    /// Returns `None` if the output has no EDID, like outputs of the nested backend.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pinnacle_api::output;
+    /// # || {
+    /// let output = output::get_focused()?;
+    /// if let Some(hdr) = output.edid()?.hdr_metadata {
+    ///     println!("{} supports HDR up to {:?} nits", output.name(), hdr.max_luminance);
+    /// }
+    /// # Some(())
+    /// # };
+    /// ```
+    pub fn edid(&self) -> Option<Edid> {
+        self.edid_async().block_on_tokio()
+    }
+
+    /// Async impl for [`Self::edid`].
+    pub async fn edid_async(&self) -> Option<Edid> {
+        self.try_edid_async().await.unwrap_or_log()
+    }
+
+    /// Like [`Self::edid`], but returns an error if the request to Pinnacle fails.
+    pub fn try_edid(&self) -> Result<Option<Edid>, RequestError> {
+        self.try_edid_async().block_on_tokio()
+    }
+
+    /// Async impl for [`Self::try_edid`].
+    pub async fn try_edid_async(&self) -> Result<Option<Edid>, RequestError> {
+        Ok(Client::output()
+            .get_info(GetInfoRequest {
+                output_name: self.name(),
+            })
+            .await
+            .into_result()?
+            .edid
+            .map(From::from))
     }
 
     /// Gets this output's location in the global space.
@@ -713,15 +1245,24 @@ impl OutputHandle {
 
     /// Async impl for [`Self::loc`].
     pub async fn loc_async(&self) -> Option<Point> {
-        Client::output()
+        self.try_loc_async().await.unwrap_or_log()
+    }
+
+    /// Like [`Self::loc`], but returns an error if the request to Pinnacle fails.
+    pub fn try_loc(&self) -> Result<Option<Point>, RequestError> {
+        self.try_loc_async().block_on_tokio()
+    }
+
+    /// Async impl for [`Self::try_loc`].
+    pub async fn try_loc_async(&self) -> Result<Option<Point>, RequestError> {
+        Ok(Client::output()
             .get_loc(GetLocRequest {
                 output_name: self.name(),
             })
             .await
-            .unwrap()
-            .into_inner()
+            .into_result()?
             .loc
-            .map(|loc| Point { x: loc.x, y: loc.y })
+            .map(|loc| Point { x: loc.x, y: loc.y }))
     }
 
     /// Gets this output's logical size in logical pixels.
@@ -738,18 +1279,27 @@ impl OutputHandle {
 
     /// Async impl for [`Self::logical_size`].
     pub async fn logical_size_async(&self) -> Option<Size> {
-        Client::output()
+        self.try_logical_size_async().await.unwrap_or_log()
+    }
+
+    /// Like [`Self::logical_size`], but returns an error if the request to Pinnacle fails.
+    pub fn try_logical_size(&self) -> Result<Option<Size>, RequestError> {
+        self.try_logical_size_async().block_on_tokio()
+    }
+
+    /// Async impl for [`Self::try_logical_size`].
+    pub async fn try_logical_size_async(&self) -> Result<Option<Size>, RequestError> {
+        Ok(Client::output()
             .get_logical_size(GetLogicalSizeRequest {
                 output_name: self.name(),
             })
             .await
-            .unwrap()
-            .into_inner()
+            .into_result()?
             .logical_size
             .map(|size| Size {
                 w: size.width,
                 h: size.height,
-            })
+            }))
     }
 
     /// Gets this output's current mode.
@@ -761,13 +1311,22 @@ impl OutputHandle {
 
     /// Async impl for [`Self::current_mode`].
     pub async fn current_mode_async(&self) -> Option<Mode> {
-        Client::output()
+        self.try_current_mode_async().await.unwrap_or_log()
+    }
+
+    /// Like [`Self::current_mode`], but returns an error if the request to Pinnacle fails.
+    pub fn try_current_mode(&self) -> Result<Option<Mode>, RequestError> {
+        self.try_current_mode_async().block_on_tokio()
+    }
+
+    /// Async impl for [`Self::try_current_mode`].
+    pub async fn try_current_mode_async(&self) -> Result<Option<Mode>, RequestError> {
+        Ok(Client::output()
             .get_modes(GetModesRequest {
                 output_name: self.name(),
             })
             .await
-            .unwrap()
-            .into_inner()
+            .into_result()?
             .current_mode
             .map(|mode| Mode {
                 size: Size {
@@ -775,7 +1334,7 @@ impl OutputHandle {
                     h: mode.size.expect("mode should have a size").height,
                 },
                 refresh_rate_mhz: mode.refresh_rate_mhz,
-            })
+            }))
     }
 
     /// Gets this output's preferred mode.
@@ -787,13 +1346,22 @@ impl OutputHandle {
 
     /// Async impl for [`Self::preferred_mode`].
     pub async fn preferred_mode_async(&self) -> Option<Mode> {
-        Client::output()
+        self.try_preferred_mode_async().await.unwrap_or_log()
+    }
+
+    /// Like [`Self::preferred_mode`], but returns an error if the request to Pinnacle fails.
+    pub fn try_preferred_mode(&self) -> Result<Option<Mode>, RequestError> {
+        self.try_preferred_mode_async().block_on_tokio()
+    }
+
+    /// Async impl for [`Self::try_preferred_mode`].
+    pub async fn try_preferred_mode_async(&self) -> Result<Option<Mode>, RequestError> {
+        Ok(Client::output()
             .get_modes(GetModesRequest {
                 output_name: self.name(),
             })
             .await
-            .unwrap()
-            .into_inner()
+            .into_result()?
             .preferred_mode
             .map(|mode| Mode {
                 size: Size {
@@ -801,7 +1369,7 @@ impl OutputHandle {
                     h: mode.size.expect("mode should have a size").height,
                 },
                 refresh_rate_mhz: mode.refresh_rate_mhz,
-            })
+            }))
     }
 
     /// Gets all modes currently known to this output.
@@ -811,13 +1379,28 @@ impl OutputHandle {
 
     /// Async impl for [`Self::modes`].
     pub async fn modes_async(&self) -> impl Iterator<Item = Mode> + use<> {
-        Client::output()
+        self.try_modes_async()
+            .await
+            .inspect_err(log_failed_request)
+            .into_iter()
+            .flatten()
+    }
+
+    /// Like [`Self::modes`], but returns an error if the request to Pinnacle fails.
+    pub fn try_modes(&self) -> Result<impl Iterator<Item = Mode> + use<>, RequestError> {
+        self.try_modes_async().block_on_tokio()
+    }
+
+    /// Async impl for [`Self::try_modes`].
+    pub async fn try_modes_async(
+        &self,
+    ) -> Result<impl Iterator<Item = Mode> + use<>, RequestError> {
+        Ok(Client::output()
             .get_modes(GetModesRequest {
                 output_name: self.name(),
             })
             .await
-            .unwrap()
-            .into_inner()
+            .into_result()?
             .modes
             .into_iter()
             .map(|mode| Mode {
@@ -826,7 +1409,7 @@ impl OutputHandle {
                     h: mode.size.expect("mode should have a size").height,
                 },
                 refresh_rate_mhz: mode.refresh_rate_mhz,
-            })
+            }))
     }
 
     /// Gets this output's physical size in millimeters.
@@ -838,19 +1421,28 @@ impl OutputHandle {
 
     /// Async impl for [`Self::physical_size`].
     pub async fn physical_size_async(&self) -> Size {
-        Client::output()
+        self.try_physical_size_async().await.unwrap_or_log()
+    }
+
+    /// Like [`Self::physical_size`], but returns an error if the request to Pinnacle fails.
+    pub fn try_physical_size(&self) -> Result<Size, RequestError> {
+        self.try_physical_size_async().block_on_tokio()
+    }
+
+    /// Async impl for [`Self::try_physical_size`].
+    pub async fn try_physical_size_async(&self) -> Result<Size, RequestError> {
+        Ok(Client::output()
             .get_physical_size(GetPhysicalSizeRequest {
                 output_name: self.name(),
             })
             .await
-            .unwrap()
-            .into_inner()
+            .into_result()?
             .physical_size
             .map(|size| Size {
                 w: size.width,
                 h: size.height,
             })
-            .unwrap_or_default()
+            .unwrap_or_default())
     }
 
     /// Gets whether or not this output is focused.
@@ -862,14 +1454,23 @@ impl OutputHandle {
 
     /// Async impl for [`Self::focused`].
     pub async fn focused_async(&self) -> bool {
-        Client::output()
+        self.try_focused_async().await.unwrap_or_log()
+    }
+
+    /// Like [`Self::focused`], but returns an error if the request to Pinnacle fails.
+    pub fn try_focused(&self) -> Result<bool, RequestError> {
+        self.try_focused_async().block_on_tokio()
+    }
+
+    /// Async impl for [`Self::try_focused`].
+    pub async fn try_focused_async(&self) -> Result<bool, RequestError> {
+        Ok(Client::output()
             .get_focused(GetFocusedRequest {
                 output_name: self.name(),
             })
             .await
-            .unwrap()
-            .into_inner()
-            .focused
+            .into_result()?
+            .focused)
     }
 
     /// Gets handles to all tags on this output.
@@ -879,16 +1480,31 @@ impl OutputHandle {
 
     /// Async impl for [`Self::tags`].
     pub async fn tags_async(&self) -> impl Iterator<Item = TagHandle> + use<> {
-        Client::output()
+        self.try_tags_async()
+            .await
+            .inspect_err(log_failed_request)
+            .into_iter()
+            .flatten()
+    }
+
+    /// Like [`Self::tags`], but returns an error if the request to Pinnacle fails.
+    pub fn try_tags(&self) -> Result<impl Iterator<Item = TagHandle> + use<>, RequestError> {
+        self.try_tags_async().block_on_tokio()
+    }
+
+    /// Async impl for [`Self::try_tags`].
+    pub async fn try_tags_async(
+        &self,
+    ) -> Result<impl Iterator<Item = TagHandle> + use<>, RequestError> {
+        Ok(Client::output()
             .get_tag_ids(GetTagIdsRequest {
                 output_name: self.name(),
             })
             .await
-            .unwrap()
-            .into_inner()
+            .into_result()?
             .tag_ids
             .into_iter()
-            .map(|id| TagHandle { id })
+            .map(|id| TagHandle { id }))
     }
 
     /// Gets handles to all active tags on this output.
@@ -922,14 +1538,23 @@ impl OutputHandle {
 
     /// Async impl for [`Self::scale`].
     pub async fn scale_async(&self) -> f32 {
-        Client::output()
+        self.try_scale_async().await.unwrap_or_log()
+    }
+
+    /// Like [`Self::scale`], but returns an error if the request to Pinnacle fails.
+    pub fn try_scale(&self) -> Result<f32, RequestError> {
+        self.try_scale_async().block_on_tokio()
+    }
+
+    /// Async impl for [`Self::try_scale`].
+    pub async fn try_scale_async(&self) -> Result<f32, RequestError> {
+        Ok(Client::output()
             .get_scale(GetScaleRequest {
                 output_name: self.name(),
             })
             .await
-            .unwrap()
-            .into_inner()
-            .scale
+            .into_result()?
+            .scale)
     }
 
     /// Gets this output's current transform.
@@ -939,16 +1564,25 @@ impl OutputHandle {
 
     /// Async impl for [`Self::transform`].
     pub async fn transform_async(&self) -> Transform {
-        Client::output()
+        self.try_transform_async().await.unwrap_or_log()
+    }
+
+    /// Like [`Self::transform`], but returns an error if the request to Pinnacle fails.
+    pub fn try_transform(&self) -> Result<Transform, RequestError> {
+        self.try_transform_async().block_on_tokio()
+    }
+
+    /// Async impl for [`Self::try_transform`].
+    pub async fn try_transform_async(&self) -> Result<Transform, RequestError> {
+        Ok(Client::output()
             .get_transform(GetTransformRequest {
                 output_name: self.name(),
             })
             .await
-            .unwrap()
-            .into_inner()
+            .into_result()?
             .transform()
             .try_into()
-            .unwrap_or_default()
+            .unwrap_or_default())
     }
 
     /// Gets this window's keyboard focus stack.
@@ -966,16 +1600,33 @@ impl OutputHandle {
 
     /// Async impl for [`Self::keyboard_focus_stack`].
     pub async fn keyboard_focus_stack_async(&self) -> impl Iterator<Item = WindowHandle> + use<> {
-        Client::output()
+        self.try_keyboard_focus_stack_async()
+            .await
+            .inspect_err(log_failed_request)
+            .into_iter()
+            .flatten()
+    }
+
+    /// Like [`Self::keyboard_focus_stack`], but returns an error if the request to Pinnacle fails.
+    pub fn try_keyboard_focus_stack(
+        &self,
+    ) -> Result<impl Iterator<Item = WindowHandle> + use<>, RequestError> {
+        self.try_keyboard_focus_stack_async().block_on_tokio()
+    }
+
+    /// Async impl for [`Self::try_keyboard_focus_stack`].
+    pub async fn try_keyboard_focus_stack_async(
+        &self,
+    ) -> Result<impl Iterator<Item = WindowHandle> + use<>, RequestError> {
+        Ok(Client::output()
             .get_focus_stack_window_ids(GetFocusStackWindowIdsRequest {
                 output_name: self.name(),
             })
             .await
-            .unwrap()
-            .into_inner()
+            .into_result()?
             .window_ids
             .into_iter()
-            .map(|id| WindowHandle { id })
+            .map(|id| WindowHandle { id }))
     }
 
     /// Gets this window's keyboard focus stack with only windows on active tags.
@@ -999,6 +1650,46 @@ impl OutputHandle {
             .batch_filter(|win| win.is_on_active_tag_async().boxed(), |is_on| is_on)
     }
 
+    /// Gets the windows shown on this output, from bottom to top.
+    ///
+    /// Windows on inactive tags aren't shown, so they aren't included.
+    /// The last window is the one visually on top. Windows can be reordered with
+    /// [`WindowHandle::raise`] and [`WindowHandle::lower`].
+    pub fn stacking_order(&self) -> impl Iterator<Item = WindowHandle> + use<> {
+        self.stacking_order_async().block_on_tokio()
+    }
+
+    /// Async impl for [`Self::stacking_order`].
+    pub async fn stacking_order_async(&self) -> impl Iterator<Item = WindowHandle> + use<> {
+        self.try_stacking_order_async()
+            .await
+            .inspect_err(log_failed_request)
+            .into_iter()
+            .flatten()
+    }
+
+    /// Like [`Self::stacking_order`], but returns an error if the request to Pinnacle fails.
+    pub fn try_stacking_order(
+        &self,
+    ) -> Result<impl Iterator<Item = WindowHandle> + use<>, RequestError> {
+        self.try_stacking_order_async().block_on_tokio()
+    }
+
+    /// Async impl for [`Self::try_stacking_order`].
+    pub async fn try_stacking_order_async(
+        &self,
+    ) -> Result<impl Iterator<Item = WindowHandle> + use<>, RequestError> {
+        Ok(Client::output()
+            .get_stacking_order(GetStackingOrderRequest {
+                output_name: self.name(),
+            })
+            .await
+            .into_result()?
+            .window_ids
+            .into_iter()
+            .map(|id| WindowHandle { id }))
+    }
+
     /// Gets whether this output is enabled.
     pub fn enabled(&self) -> bool {
         self.enabled_async().block_on_tokio()
@@ -1006,14 +1697,23 @@ impl OutputHandle {
 
     /// Async impl for [`Self::enabled`].
     pub async fn enabled_async(&self) -> bool {
-        Client::output()
+        self.try_enabled_async().await.unwrap_or_log()
+    }
+
+    /// Like [`Self::enabled`], but returns an error if the request to Pinnacle fails.
+    pub fn try_enabled(&self) -> Result<bool, RequestError> {
+        self.try_enabled_async().block_on_tokio()
+    }
+
+    /// Async impl for [`Self::try_enabled`].
+    pub async fn try_enabled_async(&self) -> Result<bool, RequestError> {
+        Ok(Client::output()
             .get_enabled(GetEnabledRequest {
                 output_name: self.name(),
             })
             .await
-            .unwrap()
-            .into_inner()
-            .enabled
+            .into_result()?
+            .enabled)
     }
 
     /// Gets whether or not this output is powered.
@@ -1025,14 +1725,216 @@ impl OutputHandle {
 
     /// Async impl for [`Self::powered`].
     pub async fn powered_async(&self) -> bool {
-        Client::output()
+        self.try_powered_async().await.unwrap_or_log()
+    }
+
+    /// Like [`Self::powered`], but returns an error if the request to Pinnacle fails.
+    pub fn try_powered(&self) -> Result<bool, RequestError> {
+        self.try_powered_async().block_on_tokio()
+    }
+
+    /// Async impl for [`Self::try_powered`].
+    pub async fn try_powered_async(&self) -> Result<bool, RequestError> {
+        Ok(Client::output()
             .get_powered(GetPoweredRequest {
                 output_name: self.name(),
             })
             .await
-            .unwrap()
-            .into_inner()
-            .powered
+            .into_result()?
+            .powered)
+    }
+
+    /// Gets the number of bits per color channel this output is scanned out with.
+    pub fn color_depth(&self) -> u32 {
+        self.color_depth_async().block_on_tokio()
+    }
+
+    /// Async impl for [`Self::color_depth`].
+    pub async fn color_depth_async(&self) -> u32 {
+        self.try_color_depth_async().await.unwrap_or_log()
+    }
+
+    /// Like [`Self::color_depth`], but returns an error if the request to Pinnacle fails.
+    pub fn try_color_depth(&self) -> Result<u32, RequestError> {
+        self.try_color_depth_async().block_on_tokio()
+    }
+
+    /// Async impl for [`Self::try_color_depth`].
+    pub async fn try_color_depth_async(&self) -> Result<u32, RequestError> {
+        Ok(Client::output()
+            .get_color_depth(GetColorDepthRequest {
+                output_name: self.name(),
+            })
+            .await
+            .into_result()?
+            .color_depth)
+    }
+
+    /// Returns whether sRGB emulation is enabled on this output.
+    pub fn srgb_emulation(&self) -> bool {
+        self.srgb_emulation_async().block_on_tokio()
+    }
+
+    /// Async impl for [`Self::srgb_emulation`].
+    pub async fn srgb_emulation_async(&self) -> bool {
+        self.try_srgb_emulation_async().await.unwrap_or_log()
+    }
+
+    /// Like [`Self::srgb_emulation`], but returns an error if the request to Pinnacle fails.
+    pub fn try_srgb_emulation(&self) -> Result<bool, RequestError> {
+        self.try_srgb_emulation_async().block_on_tokio()
+    }
+
+    /// Async impl for [`Self::try_srgb_emulation`].
+    pub async fn try_srgb_emulation_async(&self) -> Result<bool, RequestError> {
+        Ok(Client::output()
+            .get_srgb_emulation(GetSrgbEmulationRequest {
+                output_name: self.name(),
+            })
+            .await
+            .into_result()?
+            .srgb_emulation)
+    }
+
+    /// Returns whether this output's monitor and connector support HDR.
+    pub fn hdr_capable(&self) -> bool {
+        self.hdr_capable_async().block_on_tokio()
+    }
+
+    /// Async impl for [`Self::hdr_capable`].
+    pub async fn hdr_capable_async(&self) -> bool {
+        self.try_hdr_capable_async().await.unwrap_or_log()
+    }
+
+    /// Like [`Self::hdr_capable`], but returns an error if the request to Pinnacle fails.
+    pub fn try_hdr_capable(&self) -> Result<bool, RequestError> {
+        self.try_hdr_capable_async().block_on_tokio()
+    }
+
+    /// Async impl for [`Self::try_hdr_capable`].
+    pub async fn try_hdr_capable_async(&self) -> Result<bool, RequestError> {
+        Ok(Client::output()
+            .get_hdr(GetHdrRequest {
+                output_name: self.name(),
+            })
+            .await
+            .into_result()?
+            .capable)
+    }
+
+    /// Returns whether this output is in HDR mode.
+    pub fn hdr_enabled(&self) -> bool {
+        self.hdr_enabled_async().block_on_tokio()
+    }
+
+    /// Async impl for [`Self::hdr_enabled`].
+    pub async fn hdr_enabled_async(&self) -> bool {
+        self.try_hdr_enabled_async().await.unwrap_or_log()
+    }
+
+    /// Like [`Self::hdr_enabled`], but returns an error if the request to Pinnacle fails.
+    pub fn try_hdr_enabled(&self) -> Result<bool, RequestError> {
+        self.try_hdr_enabled_async().block_on_tokio()
+    }
+
+    /// Async impl for [`Self::try_hdr_enabled`].
+    pub async fn try_hdr_enabled_async(&self) -> Result<bool, RequestError> {
+        Ok(Client::output()
+            .get_hdr(GetHdrRequest {
+                output_name: self.name(),
+            })
+            .await
+            .into_result()?
+            .enabled)
+    }
+
+    /// Gets rendering statistics for this output.
+    ///
+    /// These can be used to see how often rendering is skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pinnacle_api::output;
+    /// # || {
+    /// let stats = output::get_focused()?.render_stats();
+    /// println!("{} frames rendered", stats.frames_rendered);
+    /// # Some(())
+    /// # };
+    /// ```
+    pub fn render_stats(&self) -> RenderStats {
+        self.render_stats_async().block_on_tokio()
+    }
+
+    /// Async impl for [`Self::render_stats`].
+    pub async fn render_stats_async(&self) -> RenderStats {
+        self.try_render_stats_async().await.unwrap_or_log()
+    }
+
+    /// Like [`Self::render_stats`], but returns an error if the request to Pinnacle fails.
+    pub fn try_render_stats(&self) -> Result<RenderStats, RequestError> {
+        self.try_render_stats_async().block_on_tokio()
+    }
+
+    /// Async impl for [`Self::try_render_stats`].
+    pub async fn try_render_stats_async(&self) -> Result<RenderStats, RequestError> {
+        let stats = Client::output()
+            .get_render_stats(GetRenderStatsRequest {
+                output_name: self.name(),
+            })
+            .await
+            .into_result()?;
+
+        Ok(RenderStats {
+            frames_rendered: stats.frames_rendered,
+            frames_skipped_no_damage: stats.frames_skipped_no_damage,
+            frames_skipped_suspended: stats.frames_skipped_suspended,
+        })
+    }
+
+    /// Watches this output's properties, calling `on_change` when they change.
+    ///
+    /// `on_change` is first called with every property set, then with only the properties
+    /// that changed.
+    ///
+    /// Watching ends when the output is disconnected or the returned [`WatchHandle`]
+    /// is stopped.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pinnacle_api::output;
+    /// # || {
+    /// let watch = output::get_focused()?.watch(|change| {
+    ///     if let Some(Some(size)) = change.logical_size {
+    ///         println!("New size: {}x{}", size.w, size.h);
+    ///     }
+    /// });
+    /// # Some(())
+    /// # };
+    /// ```
+    pub fn watch(
+        &self,
+        mut on_change: impl FnMut(OutputPropertiesChange) + Send + 'static,
+    ) -> WatchHandle {
+        let output_name = self.name();
+
+        let join_handle = tokio::spawn(async move {
+            let Ok(response) = Client::output()
+                .watch_properties(WatchPropertiesRequest { output_name })
+                .await
+            else {
+                return;
+            };
+
+            let mut changes = response.into_inner();
+
+            while let Some(Ok(change)) = changes.next().await {
+                on_change(change.into());
+            }
+        });
+
+        WatchHandle::new(join_handle)
     }
 
     /// Gets all outputs in the provided direction, sorted closest to farthest.
@@ -1045,6 +1947,26 @@ impl OutputHandle {
         &self,
         direction: Direction,
     ) -> impl Iterator<Item = OutputHandle> + use<> {
+        self.try_in_direction_async(direction)
+            .await
+            .inspect_err(log_failed_request)
+            .into_iter()
+            .flatten()
+    }
+
+    /// Like [`Self::in_direction`], but returns an error if the request to Pinnacle fails.
+    pub fn try_in_direction(
+        &self,
+        direction: Direction,
+    ) -> Result<impl Iterator<Item = OutputHandle> + use<>, RequestError> {
+        self.try_in_direction_async(direction).block_on_tokio()
+    }
+
+    /// Async impl for [`Self::try_in_direction`].
+    pub async fn try_in_direction_async(
+        &self,
+        direction: Direction,
+    ) -> Result<impl Iterator<Item = OutputHandle> + use<>, RequestError> {
         let output_name = self.name();
 
         let mut request = GetOutputsInDirRequest {
@@ -1062,13 +1984,12 @@ impl OutputHandle {
         let response = Client::output()
             .get_outputs_in_dir(request)
             .await
-            .unwrap()
-            .into_inner();
+            .into_result()?;
 
-        response
+        Ok(response
             .output_names
             .into_iter()
-            .map(OutputHandle::from_name)
+            .map(OutputHandle::from_name))
     }
 
     /// Returns this output's unique name (the name of its connector).
@@ -1088,6 +2009,144 @@ pub struct Mode {
     pub refresh_rate_mhz: u32,
 }
 
+/// Chromaticity coordinates in the CIE 1931 color space.
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub struct Chromaticity {
+    /// The x coordinate.
+    pub x: f32,
+    /// The y coordinate.
+    pub y: f32,
+}
+
+/// The color primaries and white point a monitor advertises.
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub struct ColorPrimaries {
+    /// The red primary.
+    pub red: Chromaticity,
+    /// The green primary.
+    pub green: Chromaticity,
+    /// The blue primary.
+    pub blue: Chromaticity,
+    /// The default white point, if advertised.
+    pub white: Option<Chromaticity>,
+}
+
+/// HDR static metadata a monitor advertises.
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub struct HdrMetadata {
+    /// The desired maximum content luminance in cd/m², if advertised.
+    pub max_luminance: Option<f32>,
+    /// The desired maximum frame-average content luminance in cd/m², if advertised.
+    pub max_frame_avg_luminance: Option<f32>,
+    /// The desired minimum content luminance in cd/m², if advertised.
+    pub min_luminance: Option<f32>,
+    /// Whether the SMPTE ST 2084 (PQ) transfer function is supported.
+    pub pq: bool,
+    /// Whether the hybrid log-gamma transfer function is supported.
+    pub hlg: bool,
+}
+
+/// Properties parsed from a monitor's EDID, as returned by [`OutputHandle::edid`].
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub struct Edid {
+    /// The year the monitor was manufactured.
+    pub manufacture_year: Option<u32>,
+    /// The week of the year the monitor was manufactured.
+    pub manufacture_week: Option<u32>,
+    /// The monitor's color primaries, if advertised.
+    pub color_primaries: Option<ColorPrimaries>,
+    /// The monitor's HDR metadata, or `None` if it doesn't support HDR.
+    pub hdr_metadata: Option<HdrMetadata>,
+}
+
+impl From<output::v1::Chromaticity> for Chromaticity {
+    fn from(chromaticity: output::v1::Chromaticity) -> Self {
+        Self {
+            x: chromaticity.x,
+            y: chromaticity.y,
+        }
+    }
+}
+
+impl From<output::v1::Edid> for Edid {
+    fn from(edid: output::v1::Edid) -> Self {
+        Self {
+            manufacture_year: edid.manufacture_year,
+            manufacture_week: edid.manufacture_week,
+            color_primaries: edid.color_primaries.map(|primaries| ColorPrimaries {
+                red: primaries.red.unwrap_or_default().into(),
+                green: primaries.green.unwrap_or_default().into(),
+                blue: primaries.blue.unwrap_or_default().into(),
+                white: primaries.white.map(From::from),
+            }),
+            hdr_metadata: edid.hdr_metadata.map(|metadata| HdrMetadata {
+                max_luminance: metadata.max_luminance,
+                max_frame_avg_luminance: metadata.max_frame_avg_luminance,
+                min_luminance: metadata.min_luminance,
+                pq: metadata.pq,
+                hlg: metadata.hlg,
+            }),
+        }
+    }
+}
+
+/// Changes to an output's properties, as passed to [`OutputHandle::watch`].
+///
+/// Only the properties that changed are `Some`.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct OutputPropertiesChange {
+    /// The output's new location, or `Some(None)` if the output was unmapped.
+    pub loc: Option<Option<Point>>,
+    /// The output's new logical size, or `Some(None)` if the output was unmapped.
+    pub logical_size: Option<Option<Size>>,
+    /// The output's new scale.
+    pub scale: Option<f32>,
+    /// Whether the output is now focused.
+    pub focused: Option<bool>,
+    /// Whether the output is now enabled.
+    pub enabled: Option<bool>,
+    /// Whether the output is now powered.
+    pub powered: Option<bool>,
+}
+
+impl From<output::v1::WatchPropertiesResponse> for OutputPropertiesChange {
+    fn from(response: output::v1::WatchPropertiesResponse) -> Self {
+        let cleared = |property: output::v1::ClearableProperty| {
+            response
+                .cleared
+                .contains(&(property as i32))
+                .then_some(None)
+        };
+
+        Self {
+            loc: response
+                .loc
+                .map(|loc| Some(loc.into()))
+                .or_else(|| cleared(output::v1::ClearableProperty::Loc)),
+            logical_size: response
+                .logical_size
+                .map(|size| Some(size.into()))
+                .or_else(|| cleared(output::v1::ClearableProperty::LogicalSize)),
+            scale: response.scale,
+            focused: response.focused,
+            enabled: response.enabled,
+            powered: response.powered,
+        }
+    }
+}
+
+/// Rendering statistics for an output.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+pub struct RenderStats {
+    /// The number of frames that were rendered.
+    pub frames_rendered: u64,
+    /// The number of renders skipped because nothing changed.
+    pub frames_skipped_no_damage: u64,
+    /// The number of renders skipped because the output was powered off
+    /// or blanked while the session is locked.
+    pub frames_skipped_suspended: u64,
+}
+
 /// A custom modeline.
 #[allow(missing_docs)]
 #[derive(Copy, Clone, Debug, PartialEq, Default)]
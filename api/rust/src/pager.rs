@@ -0,0 +1,164 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Pager state for bars.
+//!
+//! This module provides a single stream of the state a typical tag pager needs:
+//! the tags on every output, which of them are active, urgent, or occupied,
+//! the windows on each tag and where they are, and the title of each output's
+//! focused window.
+//!
+//! A new state is sent whenever any part of it changes, so bars don't need to
+//! connect to many signals and query properties themselves.
+
+use futures::{Stream, StreamExt};
+use pinnacle_api_defs::pinnacle::tag::v1::{PagerRequest, PagerResponse, pager_response};
+
+use crate::{
+    BlockOnTokio,
+    client::Client,
+    output::OutputHandle,
+    tag::TagHandle,
+    util::{Rect, Size},
+    window::WindowHandle,
+};
+
+/// The pager state of all outputs.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Pager {
+    /// The pager state of each output.
+    pub outputs: Vec<PagerOutput>,
+}
+
+/// The pager state of an output.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PagerOutput {
+    /// The output.
+    pub output: OutputHandle,
+    /// Whether this output is focused.
+    pub focused: bool,
+    /// The tags on this output, in order.
+    pub tags: Vec<PagerTag>,
+    /// The window that has or would have focus on this output.
+    pub focused_window: Option<WindowHandle>,
+    /// The title of [`Self::focused_window`].
+    pub focused_window_title: Option<String>,
+    /// The logical size of this output.
+    pub size: Size,
+}
+
+/// The pager state of a tag.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PagerTag {
+    /// The tag.
+    pub tag: TagHandle,
+    /// The tag's name.
+    pub name: String,
+    /// Whether the tag is active.
+    pub active: bool,
+    /// Whether a window on this tag demands attention.
+    pub urgent: bool,
+    /// Whether this tag has at least one window on it.
+    pub occupied: bool,
+    /// The windows on this tag, from bottom to top.
+    pub windows: Vec<PagerWindow>,
+}
+
+/// The pager state of a window.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PagerWindow {
+    /// The window.
+    pub window: WindowHandle,
+    /// The window's title.
+    pub title: String,
+    /// The window's app id.
+    pub app_id: String,
+    /// Where the window is relative to its output.
+    ///
+    /// For windows on inactive tags, this is where the window was last shown.
+    /// This is `None` for windows that haven't been shown yet.
+    pub geometry: Option<Rect>,
+}
+
+impl From<PagerResponse> for Pager {
+    fn from(response: PagerResponse) -> Self {
+        Self {
+            outputs: response.outputs.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<pager_response::Output> for PagerOutput {
+    fn from(output: pager_response::Output) -> Self {
+        Self {
+            output: OutputHandle {
+                name: output.output_name,
+            },
+            focused: output.focused,
+            tags: output.tags.into_iter().map(Into::into).collect(),
+            focused_window: output.focused_window_id.map(|id| WindowHandle { id }),
+            focused_window_title: output.focused_window_title,
+            size: output.size.map(From::from).unwrap_or_default(),
+        }
+    }
+}
+
+impl From<pager_response::Tag> for PagerTag {
+    fn from(tag: pager_response::Tag) -> Self {
+        Self {
+            tag: TagHandle { id: tag.tag_id },
+            name: tag.name,
+            active: tag.active,
+            urgent: tag.urgent,
+            occupied: tag.occupied,
+            windows: tag.windows.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<pager_response::Window> for PagerWindow {
+    fn from(window: pager_response::Window) -> Self {
+        Self {
+            window: WindowHandle {
+                id: window.window_id,
+            },
+            title: window.title,
+            app_id: window.app_id,
+            geometry: window.geometry.map(From::from),
+        }
+    }
+}
+
+/// Streams the pager state of all outputs.
+///
+/// The current state is sent immediately, then again every time it changes.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::pager;
+/// # use futures::StreamExt;
+/// # async {
+/// let mut pager = pager::stream();
+/// while let Some(pager) = pager.next().await {
+///     for output in pager.outputs {
+///         println!("{}: {:?}", output.output.name(), output.focused_window_title);
+///     }
+/// }
+/// # };
+/// ```
+pub fn stream() -> impl Stream<Item = Pager> + Send + Unpin + 'static {
+    stream_async().block_on_tokio()
+}
+
+/// Async impl for [`stream`].
+pub async fn stream_async() -> impl Stream<Item = Pager> + Send + Unpin + 'static {
+    Client::tag()
+        .pager(PagerRequest {})
+        .await
+        .unwrap()
+        .into_inner()
+        .filter_map(|response| async move { response.ok().map(Pager::from) })
+        .boxed()
+}
@@ -6,16 +6,24 @@
 //!
 //! This module provides general compositor actions like quitting and reloading the config.
 
+use std::{path::PathBuf, time::Duration};
+
 use pinnacle_api_defs::pinnacle::{
     self,
     v1::{
-        BackendRequest, KeepaliveRequest, KeepaliveResponse, QuitRequest, ReloadConfigRequest,
-        SetLastErrorRequest, SetXwaylandClientSelfScaleRequest, TakeLastErrorRequest,
+        BackendRequest, DefineProfileRequest, GetProfileRequest, GetSettingsRequest,
+        KeepaliveRequest, KeepaliveResponse, MetricsRequest, QuitRequest, RegisterNamespaceRequest,
+        ReloadConfigRequest, RestartRequest, SetLastErrorRequest, SetMetricsExportRequest,
+        SetProfileRequest, SetXwaylandClientSelfScaleRequest, TakeLastErrorRequest, setting,
     },
 };
 use tonic::Streaming;
 
-use crate::{BlockOnTokio, client::Client};
+use crate::{
+    BlockOnTokio, ResponseExt,
+    client::Client,
+    signal::{ConfigSignal, SignalHandle},
+};
 
 /// A backend that Pinnacle runs with.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -33,6 +41,19 @@ pub fn quit() {
     let _ = Client::pinnacle().quit(QuitRequest {}).block_on_tokio();
 }
 
+/// Restarts Pinnacle in place.
+///
+/// Pinnacle re-executes itself, so an upgraded binary takes over without ending the session.
+/// Clients, including this config, don't survive the restart. Windows that come back
+/// afterwards, like ones the config spawns again, are put back on the tags and in the layout
+/// mode they had before, matched by app id and title.
+pub fn restart() {
+    // Ignore errors here, the config is meant to be killed
+    let _ = Client::pinnacle()
+        .restart(RestartRequest {})
+        .block_on_tokio();
+}
+
 /// Reloads the currently active config.
 pub fn reload_config() {
     // Ignore errors here, the config is meant to be killed
@@ -46,8 +67,7 @@ pub fn backend() -> Backend {
     let backend = Client::pinnacle()
         .backend(BackendRequest {})
         .block_on_tokio()
-        .unwrap()
-        .into_inner()
+        .into_inner_or_default()
         .backend();
 
     match backend {
@@ -73,7 +93,7 @@ pub fn set_xwayland_self_scaling(should_self_scale: bool) {
             self_scale: should_self_scale,
         })
         .block_on_tokio()
-        .unwrap();
+        .log_err();
 }
 
 /// Sets an error message that is held by the compositor until it is retrieved.
@@ -83,7 +103,7 @@ pub fn set_last_error(error: impl std::fmt::Display) {
             error: error.to_string(),
         })
         .block_on_tokio()
-        .unwrap();
+        .log_err();
 }
 
 /// Gets and consumes the last error message set, possibly by a previously
@@ -92,11 +112,321 @@ pub fn take_last_error() -> Option<String> {
     Client::pinnacle()
         .take_last_error(TakeLastErrorRequest {})
         .block_on_tokio()
-        .unwrap()
-        .into_inner()
+        .into_inner_or_default()
         .error
 }
 
+/// A compositor option that can be changed at runtime.
+///
+/// Returned by [`settings`] for tools that generate settings UIs.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Setting {
+    /// A unique, dot-separated name, e.g. `window.grey_out_unresponsive`.
+    pub name: String,
+    /// A human-readable description.
+    pub description: String,
+    /// The fully-qualified RPC that changes this setting,
+    /// e.g. `pinnacle.window.v1.WindowService.SetGreyOutUnresponsive`.
+    pub setter: String,
+    /// The device this setting applies to, for per-device settings.
+    pub device_sysname: Option<String>,
+    /// The current value.
+    pub value: SettingValue,
+    /// For [`SettingValue::Choice`] settings, the names of the values the setter accepts.
+    pub choices: Vec<String>,
+    /// For numeric settings, the smallest accepted value.
+    pub min: Option<f64>,
+    /// For numeric settings, the largest accepted value.
+    pub max: Option<f64>,
+}
+
+/// The value of a [`Setting`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum SettingValue {
+    Bool(bool),
+    Int(i64),
+    Float(f64),
+    /// One of the setting's [`choices`][Setting::choices].
+    Choice(String),
+}
+
+/// Gets all options that can be changed at runtime, along with their current values.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::pinnacle;
+/// for setting in pinnacle::settings() {
+///     println!("{}: {:?}", setting.name, setting.value);
+/// }
+/// ```
+pub fn settings() -> Vec<Setting> {
+    Client::pinnacle()
+        .get_settings(GetSettingsRequest {})
+        .block_on_tokio()
+        .into_inner_or_default()
+        .settings
+        .into_iter()
+        .filter_map(|setting| {
+            let value = match setting.value? {
+                setting::Value::Bool(value) => SettingValue::Bool(value),
+                setting::Value::Int(value) => SettingValue::Int(value),
+                setting::Value::Float(value) => SettingValue::Float(value),
+                setting::Value::Choice(value) => SettingValue::Choice(value),
+            };
+
+            Some(Setting {
+                name: setting.name,
+                description: setting.description,
+                setter: setting.setter,
+                device_sysname: setting.device_sysname,
+                value,
+                choices: setting.choices,
+                min: setting.min,
+                max: setting.max,
+            })
+        })
+        .collect()
+}
+
+/// A histogram of durations.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Histogram {
+    /// The buckets as pairs of their upper bound and the number of observations in them.
+    ///
+    /// Counts are not cumulative. The last bucket has no upper bound.
+    pub buckets: Vec<(Option<Duration>, u64)>,
+    /// The sum of all observations.
+    pub sum: Duration,
+    /// The number of observations.
+    pub count: u64,
+}
+
+impl From<pinnacle::v1::Histogram> for Histogram {
+    fn from(histogram: pinnacle::v1::Histogram) -> Self {
+        let bounds = histogram
+            .bucket_bounds_ms
+            .into_iter()
+            .map(|ms| Some(Duration::from_secs_f64(ms / 1000.0)))
+            .chain(std::iter::once(None));
+
+        Self {
+            buckets: bounds.zip(histogram.bucket_counts).collect(),
+            sum: Duration::from_secs_f64(histogram.sum_ms.max(0.0) / 1000.0),
+            count: histogram.count,
+        }
+    }
+}
+
+/// Latency and frame timing metrics collected by the compositor.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Metrics {
+    /// Time from an input event to the presentation of the next frame.
+    pub input_latency: Histogram,
+    /// Time from a layout request to the layout being applied.
+    pub layout_time: Histogram,
+    /// The number of vblanks that queued frames missed.
+    pub missed_frames: u64,
+}
+
+/// Gets latency and frame timing metrics collected since Pinnacle started.
+///
+/// This is useful for debugging stutter.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::pinnacle;
+/// let metrics = pinnacle::metrics();
+/// println!("{} missed frames", metrics.missed_frames);
+/// ```
+pub fn metrics() -> Metrics {
+    metrics_async().block_on_tokio()
+}
+
+/// Async impl for [`metrics`].
+pub async fn metrics_async() -> Metrics {
+    let response = Client::pinnacle()
+        .metrics(MetricsRequest {})
+        .await
+        .into_inner_or_default();
+
+    Metrics {
+        input_latency: response.input_latency.unwrap_or_default().into(),
+        layout_time: response.layout_time.unwrap_or_default().into(),
+        missed_frames: response.missed_frames,
+    }
+}
+
+/// Sets whether metrics are exported in the Prometheus text format on a Unix socket.
+///
+/// Every connection to the socket receives the current [`metrics`] and is then closed.
+/// Returns the path of the socket if exporting is enabled.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::pinnacle;
+/// if let Some(socket) = pinnacle::set_metrics_export(true) {
+///     println!("Scrape metrics with `socat - UNIX-CONNECT:{}`", socket.display());
+/// }
+/// ```
+pub fn set_metrics_export(enabled: bool) -> Option<PathBuf> {
+    Client::pinnacle()
+        .set_metrics_export(SetMetricsExportRequest { enabled })
+        .block_on_tokio()
+        .into_inner_or_default()
+        .socket_path
+        .map(PathBuf::from)
+}
+
+/// A named set of runtime options that are applied together.
+///
+/// Options that are `None` are left alone when the profile is set.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Profile {
+    /// Whether the session is kept from going idle, as if an app were inhibiting idle.
+    pub inhibit_idle: Option<bool>,
+    /// Whether the [inactive effect][crate::render::set_inactive_effect] is turned off.
+    pub disable_inactive_effect: bool,
+    /// Whether unresponsive windows are greyed out.
+    pub grey_out_unresponsive: Option<bool>,
+    /// Whether fullscreen windows that don't fill their output are letterboxed.
+    pub letterbox_fullscreen: Option<bool>,
+}
+
+/// Defines a profile that can later be set with [`set_profile`].
+///
+/// This replaces any profile with the same name. Redefining the active profile
+/// takes effect the next time it is set.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::pinnacle::{self, Profile};
+/// pinnacle::define_profile(
+///     "gaming",
+///     Profile {
+///         inhibit_idle: Some(true),
+///         disable_inactive_effect: true,
+///         ..Default::default()
+///     },
+/// );
+///
+/// pinnacle::set_profile("gaming").unwrap();
+/// // ...
+/// pinnacle::unset_profile();
+/// ```
+pub fn define_profile(name: impl ToString, profile: Profile) {
+    let Profile {
+        inhibit_idle,
+        disable_inactive_effect,
+        grey_out_unresponsive,
+        letterbox_fullscreen,
+    } = profile;
+
+    Client::pinnacle()
+        .define_profile(DefineProfileRequest {
+            name: name.to_string(),
+            profile: Some(pinnacle::v1::Profile {
+                inhibit_idle,
+                disable_inactive_effect,
+                grey_out_unresponsive,
+                letterbox_fullscreen,
+            }),
+        })
+        .block_on_tokio()
+        .log_err();
+}
+
+/// Sets the active profile, applying all of its options at once.
+///
+/// The options the profile changes are saved and restored when it is
+/// [unset][unset_profile] or another profile is set.
+///
+/// Returns an error if no profile called `name` was [defined][define_profile].
+pub fn set_profile(name: impl ToString) -> Result<(), String> {
+    let error = Client::pinnacle()
+        .set_profile(SetProfileRequest {
+            name: Some(name.to_string()),
+        })
+        .block_on_tokio()
+        .into_result()
+        .map_err(|err| err.to_string())?
+        .error;
+
+    match error {
+        Some(error) => Err(error),
+        None => Ok(()),
+    }
+}
+
+/// Unsets the active profile, restoring the options it changed.
+pub fn unset_profile() {
+    Client::pinnacle()
+        .set_profile(SetProfileRequest { name: None })
+        .block_on_tokio()
+        .log_err();
+}
+
+/// Returns the name of the active profile.
+pub fn profile() -> Option<String> {
+    Client::pinnacle()
+        .get_profile(GetProfileRequest {})
+        .block_on_tokio()
+        .into_inner_or_default()
+        .name
+}
+
+/// Registers a namespace for this process.
+///
+/// This lets a separate helper, like a bar or a scratchpad manager, use the API alongside
+/// the config. Binds, signal connections, and window rules set up after registering belong
+/// to the namespace. They survive config reloads and are removed once this process exits.
+///
+/// Returns an error if this process already registered a namespace
+/// or another process registered one called `namespace`.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::pinnacle;
+/// pinnacle::register_namespace("bar").unwrap();
+/// ```
+pub fn register_namespace(namespace: impl ToString) -> Result<(), String> {
+    let mut stream = Client::pinnacle()
+        .register_namespace(RegisterNamespaceRequest {
+            namespace: namespace.to_string(),
+        })
+        .block_on_tokio()
+        .map_err(|status| status.message().to_string())?
+        .into_inner();
+
+    // The namespace is revoked when the stream is dropped
+    tokio::spawn(async move { while let Ok(Some(_)) = stream.message().await {} });
+
+    Ok(())
+}
+
+/// Connects to a [`ConfigSignal`].
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::pinnacle;
+/// # use pinnacle_api::signal::ConfigSignal;
+/// pinnacle::connect_signal(ConfigSignal::CallbackPanicked(Box::new(|signal, message| {
+///     pinnacle::set_last_error(format!("{signal} callback panicked: {message}"));
+/// })));
+/// ```
+pub fn connect_signal(signal: ConfigSignal) -> SignalHandle {
+    let mut signal_state = Client::signal_state();
+
+    match signal {
+        ConfigSignal::CallbackPanicked(f) => signal_state.config_callback_panicked.add_callback(f),
+    }
+}
+
 pub(crate) async fn keepalive() -> (
     tokio::sync::mpsc::Sender<KeepaliveRequest>,
     Streaming<KeepaliveResponse>,
@@ -0,0 +1,147 @@
+//! xdg-desktop-portal integration.
+//!
+//! Pinnacle implements the GlobalShortcuts portal, which lets apps bind shortcuts
+//! that work while they aren't focused. Apps can only bind shortcuts after the
+//! config allows them to with [`on_global_shortcuts_request`].
+
+use pinnacle_api_defs::pinnacle::portal::v1::{
+    GlobalShortcutsPermissionRequest, global_shortcuts_permission_request,
+    global_shortcuts_permission_response,
+};
+use tokio::sync::mpsc::{UnboundedSender, unbounded_channel};
+use tokio_stream::StreamExt;
+
+use crate::{BlockOnTokio, client::Client};
+
+/// A shortcut an app wants to bind.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct GlobalShortcut {
+    /// The app-defined id of the shortcut.
+    pub id: String,
+    /// A user-readable description of what the shortcut does.
+    pub description: String,
+    /// The trigger the app would like, e.g. `"CTRL+SHIFT+a"`.
+    pub preferred_trigger: Option<String>,
+}
+
+/// A request from an app to bind global shortcuts.
+///
+/// The request must be answered with [`allow`][Self::allow] or [`deny`][Self::deny].
+/// Dropping it without answering denies it.
+#[derive(Debug)]
+pub struct GlobalShortcutsRequest {
+    request_id: u32,
+    app_id: String,
+    shortcuts: Vec<GlobalShortcut>,
+    sender: Option<UnboundedSender<GlobalShortcutsPermissionRequest>>,
+}
+
+impl GlobalShortcutsRequest {
+    /// Returns the app id of the app binding the shortcuts.
+    ///
+    /// This is empty for apps that aren't sandboxed.
+    pub fn app_id(&self) -> &str {
+        &self.app_id
+    }
+
+    /// Returns the shortcuts the app wants to bind.
+    pub fn shortcuts(&self) -> &[GlobalShortcut] {
+        &self.shortcuts
+    }
+
+    /// Allows the app to bind the shortcuts.
+    ///
+    /// Sandboxed apps that are allowed once won't be asked about again
+    /// until Pinnacle restarts.
+    pub fn allow(mut self) {
+        self.decide(true);
+    }
+
+    /// Denies the app from binding the shortcuts.
+    pub fn deny(mut self) {
+        self.decide(false);
+    }
+
+    fn decide(&mut self, allow: bool) {
+        let Some(sender) = self.sender.take() else {
+            return;
+        };
+
+        let _ = sender.send(GlobalShortcutsPermissionRequest {
+            request: Some(global_shortcuts_permission_request::Request::Decision(
+                global_shortcuts_permission_request::Decision {
+                    request_id: self.request_id,
+                    allow,
+                },
+            )),
+        });
+    }
+}
+
+impl Drop for GlobalShortcutsRequest {
+    fn drop(&mut self) {
+        self.decide(false);
+    }
+}
+
+/// Runs a closure whenever an app wants to bind global shortcuts.
+///
+/// If this is never called, all apps are denied.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::portal;
+/// portal::on_global_shortcuts_request(|request| {
+///     if request.app_id() == "com.obsproject.Studio" {
+///         request.allow();
+///     } else {
+///         request.deny();
+///     }
+/// });
+/// ```
+pub fn on_global_shortcuts_request(
+    mut on_request: impl FnMut(GlobalShortcutsRequest) + Send + 'static,
+) {
+    let (client_outgoing, client_outgoing_to_server) = unbounded_channel();
+    let client_outgoing_to_server =
+        tokio_stream::wrappers::UnboundedReceiverStream::new(client_outgoing_to_server);
+    let mut client_incoming = Client::portal()
+        .global_shortcuts_permission(client_outgoing_to_server)
+        .block_on_tokio()
+        .unwrap()
+        .into_inner();
+
+    let fut = async move {
+        while let Some(Ok(response)) = client_incoming.next().await {
+            let Some(response) = response.response else {
+                continue;
+            };
+
+            match response {
+                global_shortcuts_permission_response::Response::NewRequest(new_request) => {
+                    if client_outgoing.is_closed() {
+                        break;
+                    }
+
+                    on_request(GlobalShortcutsRequest {
+                        request_id: new_request.request_id,
+                        app_id: new_request.app_id,
+                        shortcuts: new_request
+                            .shortcuts
+                            .into_iter()
+                            .map(|shortcut| GlobalShortcut {
+                                id: shortcut.id,
+                                description: shortcut.description,
+                                preferred_trigger: shortcut.preferred_trigger,
+                            })
+                            .collect(),
+                        sender: Some(client_outgoing.clone()),
+                    });
+                }
+            }
+        }
+    };
+
+    tokio::spawn(fut);
+}
@@ -0,0 +1,70 @@
+//! Battery and power supply status.
+//!
+//! The status comes from UPower. Without UPower, the system is reported as
+//! running on external power with no battery.
+
+use pinnacle_api_defs::pinnacle::power::v1::GetStatusRequest;
+
+use crate::{
+    BlockOnTokio, ResponseExt,
+    client::Client,
+    signal::{PowerSignal, SignalHandle},
+};
+
+/// The battery and power supply status of the system.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct PowerStatus {
+    /// Whether the system is running on battery power.
+    pub on_battery: bool,
+    /// Whether the battery is charging.
+    pub charging: bool,
+    /// The battery's charge from 0 to 100, or `None` if there is no battery.
+    pub percentage: Option<f64>,
+    /// Whether the battery is low.
+    pub low_battery: bool,
+}
+
+/// Gets the battery and power supply status of the system.
+///
+/// Use [`connect_signal`] to be notified when it changes.
+pub fn status() -> PowerStatus {
+    status_async().block_on_tokio()
+}
+
+/// Async impl for [`status`].
+pub async fn status_async() -> PowerStatus {
+    let response = Client::power()
+        .get_status(GetStatusRequest {})
+        .await
+        .into_inner_or_default();
+
+    PowerStatus {
+        on_battery: response.on_battery,
+        charging: response.charging,
+        percentage: response.percentage,
+        low_battery: response.low_battery,
+    }
+}
+
+/// Connects to a [`PowerSignal`].
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::power;
+/// # use pinnacle_api::signal::PowerSignal;
+/// power::connect_signal(PowerSignal::OnBattery(Box::new(|on_battery| {
+///     if on_battery {
+///         println!("Unplugged");
+///     }
+/// })));
+/// ```
+pub fn connect_signal(signal: PowerSignal) -> SignalHandle {
+    let mut signal_state = Client::signal_state();
+
+    match signal {
+        PowerSignal::OnBattery(f) => signal_state.power_on_battery.add_callback(f),
+        PowerSignal::Charging(f) => signal_state.power_charging.add_callback(f),
+        PowerSignal::LowBattery(f) => signal_state.power_low_battery.add_callback(f),
+    }
+}
@@ -12,10 +12,12 @@ use std::{
 };
 
 use passfd::FdPassingExt;
-use pinnacle_api_defs::pinnacle::process::v1::{SetEnvRequest, SpawnRequest, WaitOnSpawnRequest};
+use pinnacle_api_defs::pinnacle::process::v1::{
+    RunOrRaiseRequest, SetEnvRequest, SpawnData, SpawnRequest, WaitOnSpawnRequest,
+};
 use tokio_stream::StreamExt;
 
-use crate::{BlockOnTokio, client::Client};
+use crate::{BlockOnTokio, ResponseExt, client::Client, window::WindowHandle};
 
 /// Adds an environment variable that all newly spawned [`Command`]s will inherit.
 pub fn set_env(key: impl ToString, value: impl ToString) {
@@ -25,7 +27,57 @@ pub fn set_env(key: impl ToString, value: impl ToString) {
             value: value.to_string(),
         })
         .block_on_tokio()
-        .unwrap();
+        .log_err();
+}
+
+/// What [`run_or_raise`] did.
+#[derive(Debug)]
+pub enum RunOrRaise {
+    /// A matching window was focused.
+    Raised(WindowHandle),
+    /// No window matched, so the command was spawned.
+    Spawned(Child),
+    /// Nothing happened, either because a matching window is still opening
+    /// or because the command failed to spawn.
+    Nothing,
+}
+
+/// Focuses a window whose app id matches the regex `app_id`, or spawns `cmd` if there is none.
+///
+/// If several windows match, the most recently focused one is used. Its tag and output
+/// are switched to if needed. Unlike assembling this from separate calls, this happens
+/// in one step in Pinnacle, so pressing a keybind twice quickly won't spawn two processes.
+///
+/// Returns why `app_id` couldn't be matched if it's an invalid regex.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::process;
+/// process::run_or_raise("^firefox$", ["firefox"]).unwrap();
+/// ```
+pub fn run_or_raise(
+    app_id: impl ToString,
+    cmd: impl IntoIterator<Item = impl ToString>,
+) -> Result<RunOrRaise, String> {
+    let response = Client::process()
+        .run_or_raise(RunOrRaiseRequest {
+            app_id: app_id.to_string(),
+            cmd: cmd.into_iter().map(|arg| arg.to_string()).collect(),
+        })
+        .block_on_tokio()
+        .into_result()
+        .map_err(|err| err.to_string())?;
+
+    if let Some(error) = response.error {
+        return Err(error);
+    }
+
+    Ok(match (response.window_id, response.spawn_data) {
+        (Some(id), _) => RunOrRaise::Raised(WindowHandle::from_id(id)),
+        (None, Some(data)) => RunOrRaise::Spawned(Child::from_spawn_data(data)),
+        (None, None) => RunOrRaise::Nothing,
+    })
 }
 
 /// A process builder that allows you to spawn programs.
@@ -68,6 +120,57 @@ pub struct ExitInfo {
 }
 
 impl Child {
+    /// Receives the standard io of a process Pinnacle spawned.
+    fn from_spawn_data(data: SpawnData) -> Self {
+        let pid = data.pid;
+        let fd_socket_path = data.fd_socket_path;
+
+        let mut stdin = None;
+        let mut stdout = None;
+        let mut stderr = None;
+
+        let stream = std::os::unix::net::UnixStream::connect(fd_socket_path)
+            .expect("this should be set up by the compositor");
+
+        if data.has_stdin {
+            let fd = stream.recv_fd().unwrap();
+            // SAFETY: Fds are dup'd in over the socket
+            let child_stdin =
+                tokio::process::ChildStdin::from_std(std::process::ChildStdin::from(unsafe {
+                    OwnedFd::from_raw_fd(fd)
+                }))
+                .unwrap();
+            stdin = Some(child_stdin);
+        }
+
+        if data.has_stdout {
+            let fd = stream.recv_fd().unwrap();
+            let child_stdout =
+                tokio::process::ChildStdout::from_std(std::process::ChildStdout::from(unsafe {
+                    OwnedFd::from_raw_fd(fd)
+                }))
+                .unwrap();
+            stdout = Some(child_stdout);
+        }
+
+        if data.has_stderr {
+            let fd = stream.recv_fd().unwrap();
+            let child_stderr =
+                tokio::process::ChildStderr::from_std(std::process::ChildStderr::from(unsafe {
+                    OwnedFd::from_raw_fd(fd)
+                }))
+                .unwrap();
+            stderr = Some(child_stderr);
+        }
+
+        Child {
+            pid,
+            stdin,
+            stdout,
+            stderr,
+        }
+    }
+
     /// Waits for this process to exit, blocking the current thread.
     pub fn wait(self) -> ExitInfo {
         self.wait_async().block_on_tokio()
@@ -233,56 +336,9 @@ impl Command {
                 pipe_stderr: self.pipe_stderr,
             })
             .block_on_tokio()
-            .unwrap()
-            .into_inner()
+            .into_inner_or_default()
             .spawn_data?;
 
-        let pid = data.pid;
-        let fd_socket_path = data.fd_socket_path;
-
-        let mut stdin = None;
-        let mut stdout = None;
-        let mut stderr = None;
-
-        let stream = std::os::unix::net::UnixStream::connect(fd_socket_path)
-            .expect("this should be set up by the compositor");
-
-        if data.has_stdin {
-            let fd = stream.recv_fd().unwrap();
-            // SAFETY: Fds are dup'd in over the socket
-            let child_stdin =
-                tokio::process::ChildStdin::from_std(std::process::ChildStdin::from(unsafe {
-                    OwnedFd::from_raw_fd(fd)
-                }))
-                .unwrap();
-            stdin = Some(child_stdin);
-        }
-
-        if data.has_stdout {
-            let fd = stream.recv_fd().unwrap();
-            let child_stdout =
-                tokio::process::ChildStdout::from_std(std::process::ChildStdout::from(unsafe {
-                    OwnedFd::from_raw_fd(fd)
-                }))
-                .unwrap();
-            stdout = Some(child_stdout);
-        }
-
-        if data.has_stderr {
-            let fd = stream.recv_fd().unwrap();
-            let child_stderr =
-                tokio::process::ChildStderr::from_std(std::process::ChildStderr::from(unsafe {
-                    OwnedFd::from_raw_fd(fd)
-                }))
-                .unwrap();
-            stderr = Some(child_stderr);
-        }
-
-        Some(Child {
-            pid,
-            stdin,
-            stdout,
-            stderr,
-        })
+        Some(Child::from_spawn_data(data))
     }
 }
@@ -1,11 +1,16 @@
 //! Rendering management.
 
+use std::time::Duration;
+
 use pinnacle_api_defs::pinnacle::render::{
     self,
-    v1::{SetDownscaleFilterRequest, SetUpscaleFilterRequest},
+    v1::{
+        SetCloseSnapshotHoldRequest, SetDownscaleFilterRequest, SetInactiveEffectRequest,
+        SetLetterboxFullscreenRequest, SetUpscaleFilterRequest,
+    },
 };
 
-use crate::{BlockOnTokio, client::Client};
+use crate::{BlockOnTokio, ResponseExt, client::Client};
 
 /// What filter to use when scaling.
 pub enum ScalingFilter {
@@ -43,7 +48,7 @@ pub fn set_upscale_filter(filter: ScalingFilter) {
             filter: render::v1::Filter::from(filter).into(),
         })
         .block_on_tokio()
-        .unwrap();
+        .log_err();
 }
 
 /// Sets the downscaling filter that will be used for rendering.
@@ -61,5 +66,106 @@ pub fn set_downscale_filter(filter: ScalingFilter) {
             filter: render::v1::Filter::from(filter).into(),
         })
         .block_on_tokio()
-        .unwrap();
+        .log_err();
+}
+
+/// What the inactive effect applies to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InactiveEffectTarget {
+    /// Apply to windows on outputs that don't have focus.
+    UnfocusedOutputs,
+    /// Apply to every window except the focused one.
+    UnfocusedWindows,
+}
+
+impl From<InactiveEffectTarget> for render::v1::InactiveEffectTarget {
+    fn from(value: InactiveEffectTarget) -> Self {
+        match value {
+            InactiveEffectTarget::UnfocusedOutputs => {
+                render::v1::InactiveEffectTarget::UnfocusedOutputs
+            }
+            InactiveEffectTarget::UnfocusedWindows => {
+                render::v1::InactiveEffectTarget::UnfocusedWindows
+            }
+        }
+    }
+}
+
+/// Dims and desaturates inactive windows.
+///
+/// `dim` and `desaturate` range from 0.0 (no effect) to 1.0 (fully black and
+/// fully grayscale, respectively).
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::render;
+/// # use pinnacle_api::render::InactiveEffectTarget;
+/// // Gray out windows on outputs that aren't focused
+/// render::set_inactive_effect(InactiveEffectTarget::UnfocusedOutputs, 0.2, 1.0);
+/// ```
+pub fn set_inactive_effect(target: InactiveEffectTarget, dim: f32, desaturate: f32) {
+    Client::render()
+        .set_inactive_effect(SetInactiveEffectRequest {
+            enabled: true,
+            target: render::v1::InactiveEffectTarget::from(target).into(),
+            dim,
+            desaturate,
+        })
+        .block_on_tokio()
+        .log_err();
+}
+
+/// Disables the inactive effect set with [`set_inactive_effect`].
+pub fn disable_inactive_effect() {
+    Client::render()
+        .set_inactive_effect(SetInactiveEffectRequest {
+            enabled: false,
+            ..Default::default()
+        })
+        .block_on_tokio()
+        .log_err();
+}
+
+/// Sets whether fullscreen windows that don't fill their output are letterboxed.
+///
+/// Letterboxed windows are centered on their output with black bars around them
+/// instead of leaving the desktop visible, which is useful for games running at a
+/// different resolution or aspect ratio than the output.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::render;
+/// render::set_letterbox_fullscreen(true);
+/// ```
+pub fn set_letterbox_fullscreen(letterbox: bool) {
+    Client::render()
+        .set_letterbox_fullscreen(SetLetterboxFullscreenRequest { letterbox })
+        .block_on_tokio()
+        .log_err();
+}
+
+/// Sets how long closed tiled windows are held in place while the layout fills their space.
+///
+/// When a tiled window closes, Pinnacle keeps drawing what it last looked like until
+/// the windows taking over its space have resized, so the space never flashes empty.
+/// `hold` caps how long that takes, for example when no layout ever comes.
+///
+/// The default is 2 seconds. A hold of zero removes closed windows immediately.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::render;
+/// # use std::time::Duration;
+/// render::set_close_snapshot_hold(Duration::from_millis(500));
+/// ```
+pub fn set_close_snapshot_hold(hold: Duration) {
+    Client::render()
+        .set_close_snapshot_hold(SetCloseSnapshotHoldRequest {
+            millis: hold.as_millis().try_into().unwrap_or(u32::MAX),
+        })
+        .block_on_tokio()
+        .log_err();
 }
@@ -0,0 +1,78 @@
+//! Screenshots.
+
+use std::path::Path;
+
+use pinnacle_api_defs::pinnacle::screenshot::v1::SelectRegionRequest;
+
+use crate::{
+    BlockOnTokio, RequestError, ResponseExt,
+    client::Client,
+    output::OutputHandle,
+    util::{Rect, Size},
+};
+
+/// A captured region of an output.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Screenshot {
+    /// The output the region was captured from.
+    pub output: OutputHandle,
+    /// The captured region in the global space.
+    pub region: Rect,
+    /// The size of the captured image in pixels.
+    ///
+    /// This is larger than the region's size on outputs with a scale above 1.
+    pub size: Size,
+    /// The captured pixels as 8-bit RGBA, row by row.
+    pub rgba: Vec<u8>,
+}
+
+/// Lets the user select a region of the screen, then captures it.
+///
+/// This dims every output and lets the user drag out a rectangle with the left mouse
+/// button. Pressing any other button or Escape cancels the selection.
+/// The region is clamped to the output the drag started on.
+///
+/// If `save_to` is provided, the region is also saved there as a PNG.
+///
+/// This blocks until the selection is finished, so you may want to call it
+/// from another thread. The [request deadline][crate::set_request_deadline]
+/// doesn't apply, as the user may take a while.
+///
+/// Returns `Ok(None)` if the selection was cancelled, or an error if the selection
+/// couldn't be started, captured, or saved, like while the session is locked.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::screenshot;
+/// match screenshot::select_region(Some("~/screenshot.png")) {
+///     Ok(Some(screenshot)) => {
+///         println!("Captured {:?} on {}", screenshot.region, screenshot.output.name());
+///     }
+///     Ok(None) => println!("Cancelled"),
+///     Err(err) => eprintln!("Failed to take a screenshot: {err}"),
+/// }
+/// ```
+pub fn select_region(
+    save_to: Option<impl AsRef<Path>>,
+) -> Result<Option<Screenshot>, RequestError> {
+    let path = save_to.map(|path| path.as_ref().to_string_lossy().into_owned());
+
+    let response = Client::screenshot()
+        .select_region(SelectRegionRequest { path })
+        .block_on_tokio()
+        .into_result()?;
+
+    let (Some(name), Some(region), Some(size)) =
+        (response.output_name, response.region, response.image_size)
+    else {
+        return Ok(None);
+    };
+
+    Ok(Some(Screenshot {
+        output: OutputHandle { name },
+        region: region.into(),
+        size: size.into(),
+        rgba: response.rgba,
+    }))
+}
@@ -5,15 +5,23 @@
 //!
 //! Some of the other modules have a `connect_signal` method that will allow you to pass in
 //! callbacks to run on each signal. Use them to connect to the signals defined here.
+//!
+//! A callback that panics doesn't affect other callbacks and stays connected.
+//! Connect to [`ConfigSignal::CallbackPanicked`] with
+//! [`pinnacle::connect_signal`][crate::pinnacle::connect_signal] to be notified when this happens.
 
 #![allow(clippy::type_complexity)]
 
 use std::{
-    collections::{BTreeMap, btree_map},
+    any::Any,
+    cell::Cell,
+    collections::BTreeMap,
+    panic::{self, AssertUnwindSafe},
     sync::{
-        Arc,
+        Arc, Mutex, PoisonError,
         atomic::{AtomicU32, Ordering},
     },
+    time::Duration,
 };
 
 use futures::{FutureExt, pin_mut};
@@ -28,9 +36,9 @@ use tonic::Streaming;
 use crate::{
     BlockOnTokio,
     input::libinput::DeviceHandle,
-    output::OutputHandle,
+    output::{OutputHandle, ScreenEdge, Transform},
     tag::TagHandle,
-    window::{LayoutMode, WindowHandle},
+    window::{FocusChange, LayoutMode, WindowHandle},
 };
 
 pub(crate) trait Signal {
@@ -95,6 +103,7 @@ macro_rules! signals {
                     self.reset();
 
                     let channels = connect_signal::<_, _, <$name as Signal>::Callback, _, _>(
+                        stringify!($name),
                         self.callback_count.clone(),
                         |out| {
                             $crate::client::Client::signal().$req(out)
@@ -133,12 +142,10 @@ signals! {
             enum_name = Connect,
             callback_type = SingleOutputFn,
             client_request = output_connect,
-            on_response = |response, callbacks| {
+            on_response = |response, callback| {
                 let handle = OutputHandle { name: response.output_name };
 
-                for callback in callbacks {
-                    callback(&handle);
-                }
+                callback(&handle);
             },
         }
         /// An output was connected.
@@ -148,12 +155,10 @@ signals! {
             enum_name = Disconnect,
             callback_type = SingleOutputFn,
             client_request = output_disconnect,
-            on_response = |response, callbacks| {
+            on_response = |response, callback| {
                 let handle = OutputHandle { name: response.output_name };
 
-                for callback in callbacks {
-                    callback(&handle);
-                }
+                callback(&handle);
             },
         }
         /// An output's logical size changed.
@@ -163,12 +168,10 @@ signals! {
             enum_name = Resize,
             callback_type = Box<dyn FnMut(&OutputHandle, u32, u32) + Send + 'static>,
             client_request = output_resize,
-            on_response = |response, callbacks| {
+            on_response = |response, callback| {
                 let handle = OutputHandle { name: response.output_name };
 
-                for callback in callbacks {
-                    callback(&handle, response.logical_width, response.logical_height)
-                }
+                callback(&handle, response.logical_width, response.logical_height)
             },
         }
         /// An output's location in the global space changed.
@@ -178,12 +181,10 @@ signals! {
             enum_name = Move,
             callback_type = Box<dyn FnMut(&OutputHandle, i32, i32) + Send + 'static>,
             client_request = output_move,
-            on_response = |response, callbacks| {
+            on_response = |response, callback| {
                 let handle = OutputHandle { name: response.output_name };
 
-                for callback in callbacks {
-                    callback(&handle, response.x, response.y)
-                }
+                callback(&handle, response.x, response.y)
             },
         }
         /// The pointer entered an output.
@@ -193,12 +194,10 @@ signals! {
             enum_name = PointerEnter,
             callback_type = SingleOutputFn,
             client_request = output_pointer_enter,
-            on_response = |response, callbacks| {
+            on_response = |response, callback| {
                 let handle = OutputHandle { name: response.output_name };
 
-                for callback in callbacks {
-                    callback(&handle);
-                }
+                callback(&handle);
             },
         }
         /// The pointer left an output.
@@ -208,11 +207,27 @@ signals! {
             enum_name = PointerLeave,
             callback_type = SingleOutputFn,
             client_request = output_pointer_leave,
-            on_response = |response, callbacks| {
+            on_response = |response, callback| {
+                let handle = OutputHandle { name: response.output_name };
+
+                callback(&handle);
+            },
+        }
+        /// The pointer hit an edge or corner of an output that it can't move past.
+        ///
+        /// This fires once when the pointer reaches the edge, not continuously while
+        /// it's held there. Edges shared with another output don't count.
+        ///
+        /// Callbacks receive the output and the edge the pointer hit.
+        OutputPointerEdge = {
+            enum_name = PointerEdge,
+            callback_type = Box<dyn FnMut(&OutputHandle, ScreenEdge) + Send + 'static>,
+            client_request = output_pointer_edge,
+            on_response = |response, callback| {
                 let handle = OutputHandle { name: response.output_name };
 
-                for callback in callbacks {
-                    callback(&handle);
+                if let Ok(edge) = response.edge().try_into() {
+                    callback(&handle, edge);
                 }
             },
         }
@@ -223,14 +238,103 @@ signals! {
             enum_name = Focused,
             callback_type = SingleOutputFn,
             client_request = output_focused,
-            on_response = |response, callbacks| {
+            on_response = |response, callback| {
+                let handle = OutputHandle { name: response.output_name };
+
+                callback(&handle);
+            },
+        }
+        /// A layer surface on an output got keyboard focus.
+        ///
+        /// Callbacks receive the output the layer surface is on and its namespace.
+        OutputLayerFocused = {
+            enum_name = LayerFocused,
+            callback_type = Box<dyn FnMut(&OutputHandle, &str) + Send + 'static>,
+            client_request = output_layer_focused,
+            on_response = |response, callback| {
+                let handle = OutputHandle { name: response.output_name };
+
+                callback(&handle, &response.namespace);
+            },
+        }
+        /// A layer surface on an output lost keyboard focus.
+        ///
+        /// Callbacks receive the output the layer surface is on and its namespace.
+        OutputLayerUnfocused = {
+            enum_name = LayerUnfocused,
+            callback_type = Box<dyn FnMut(&OutputHandle, &str) + Send + 'static>,
+            client_request = output_layer_unfocused,
+            on_response = |response, callback| {
+                let handle = OutputHandle { name: response.output_name };
+
+                callback(&handle, &response.namespace);
+            },
+        }
+        /// An output with auto-rotate enabled was rotated to match the device's orientation.
+        ///
+        /// Callbacks receive the output and its new transform.
+        OutputOrientationChanged = {
+            enum_name = OrientationChanged,
+            callback_type = Box<dyn FnMut(&OutputHandle, Transform) + Send + 'static>,
+            client_request = output_orientation_changed,
+            on_response = |response, callback| {
                 let handle = OutputHandle { name: response.output_name };
 
-                for callback in callbacks {
-                    callback(&handle);
+                if let Ok(transform) = response.transform().try_into() {
+                    callback(&handle, transform);
                 }
             },
         }
+        /// The topmost window on an output became fullscreen, or stopped being
+        /// fullscreen or topmost.
+        ///
+        /// Callbacks receive the output and its fullscreen window, or `None` if the
+        /// topmost window isn't fullscreen anymore. This is useful for hiding bars.
+        OutputFullscreenChanged = {
+            enum_name = FullscreenChanged,
+            callback_type = Box<dyn FnMut(&OutputHandle, Option<&WindowHandle>) + Send + 'static>,
+            client_request = output_fullscreen_changed,
+            on_response = |response, callback| {
+                let handle = OutputHandle { name: response.output_name };
+                let window = response.window_id.map(|id| WindowHandle { id });
+
+                callback(&handle, window.as_ref());
+            },
+        }
+        /// Windows shown on an output were raised, lowered, mapped, or unmapped.
+        ///
+        /// Callbacks receive the output and its windows from bottom to top,
+        /// like [`OutputHandle::stacking_order`].
+        OutputStackingChanged = {
+            enum_name = StackingChanged,
+            callback_type = Box<dyn FnMut(&OutputHandle, &[WindowHandle]) + Send + 'static>,
+            client_request = output_stacking_changed,
+            on_response = |response, callback| {
+                let handle = OutputHandle { name: response.output_name };
+                let windows = response
+                    .window_ids
+                    .into_iter()
+                    .map(|id| WindowHandle { id })
+                    .collect::<Vec<_>>();
+
+                callback(&handle, &windows);
+            },
+        }
+        /// An output was left with no active tags.
+        ///
+        /// This is signaled before the [`EmptyOutputPolicy`] is applied.
+        ///
+        /// [`EmptyOutputPolicy`]: crate::tag::EmptyOutputPolicy
+        OutputNoActiveTags = {
+            enum_name = NoActiveTags,
+            callback_type = SingleOutputFn,
+            client_request = output_no_active_tags,
+            on_response = |response, callback| {
+                let handle = OutputHandle { name: response.output_name };
+
+                callback(&handle);
+            },
+        }
     }
     /// Signals relating to window events.
     WindowSignal => {
@@ -241,12 +345,10 @@ signals! {
             enum_name = PointerEnter,
             callback_type = SingleWindowFn,
             client_request = window_pointer_enter,
-            on_response = |response, callbacks| {
+            on_response = |response, callback| {
                 let handle = WindowHandle { id: response.window_id };
 
-                for callback in callbacks {
-                    callback(&handle);
-                }
+                callback(&handle);
             },
         }
         /// The pointer left a window.
@@ -256,12 +358,10 @@ signals! {
             enum_name = PointerLeave,
             callback_type = SingleWindowFn,
             client_request = window_pointer_leave,
-            on_response = |response, callbacks| {
+            on_response = |response, callback| {
                 let handle = WindowHandle { id: response.window_id };
 
-                for callback in callbacks {
-                    callback(&handle);
-                }
+                callback(&handle);
             },
         }
         /// The window got keyboard focus.
@@ -271,11 +371,24 @@ signals! {
             enum_name = Focused,
             callback_type = SingleWindowFn,
             client_request = window_focused,
-            on_response = |response, callbacks| {
+            on_response = |response, callback| {
+                let handle = WindowHandle { id: response.window_id };
+
+                callback(&handle);
+            },
+        }
+        /// A window got keyboard focus.
+        ///
+        /// Callbacks receive the newly focused window and why and when it got focus.
+        WindowFocusChanged = {
+            enum_name = FocusChanged,
+            callback_type = Box<dyn FnMut(&WindowHandle, FocusChange) + Send + 'static>,
+            client_request = window_focused,
+            on_response = |response, callback| {
                 let handle = WindowHandle { id: response.window_id };
 
-                for callback in callbacks {
-                    callback(&handle);
+                if let Ok(reason) = response.reason().try_into() {
+                    callback(&handle, FocusChange { reason, serial: response.serial });
                 }
             },
         }
@@ -286,13 +399,25 @@ signals! {
             enum_name = TitleChanged,
             callback_type = Box<dyn FnMut(&WindowHandle, &str) + Send + 'static>,
             client_request = window_title_changed,
-            on_response = |response, callbacks| {
+            on_response = |response, callback| {
                 let handle = WindowHandle { id: response.window_id };
                 let title = response.title;
 
-                for callback in callbacks {
-                    callback(&handle, &title);
-                }
+                callback(&handle, &title);
+            },
+        }
+        /// The focused window changed, or the focused window's title or app id changed.
+        ///
+        /// Callbacks receive the focused window, if any, along with its title and app id.
+        /// When no window is focused, the title and app id are empty.
+        WindowFocusedTitleChanged = {
+            enum_name = FocusedTitleChanged,
+            callback_type = Box<dyn FnMut(Option<&WindowHandle>, &str, &str) + Send + 'static>,
+            client_request = window_focused_title_changed,
+            on_response = |response, callback| {
+                let handle = response.window_id.map(|id| WindowHandle { id });
+
+                callback(handle.as_ref(), &response.title, &response.app_id);
             },
         }
 
@@ -303,13 +428,11 @@ signals! {
             enum_name = LayoutModeChanged,
             callback_type = Box<dyn FnMut(&WindowHandle, LayoutMode) + Send + 'static>,
             client_request = window_layout_mode_changed,
-            on_response = |response, callbacks| {
+            on_response = |response, callback| {
                 let handle = WindowHandle { id: response.window_id };
 
                 if let Ok(layout_mode) = response.layout_mode().try_into() {
-                    for callback in callbacks {
-                        callback(&handle, layout_mode);
-                    }
+                    callback(&handle, layout_mode);
                 }
             },
         }
@@ -322,11 +445,9 @@ signals! {
             enum_name = Created,
             callback_type = SingleWindowFn,
             client_request = window_created,
-            on_response = |response, callbacks| {
+            on_response = |response, callback| {
                 let handle = WindowHandle { id: response.window_id };
-                for callback in callbacks {
-                    callback(&handle);
-                }
+                callback(&handle);
             },
         }
 
@@ -339,14 +460,74 @@ signals! {
             enum_name = Destroyed,
             callback_type = Box<dyn FnMut(&WindowHandle, &str, &str) + Send + 'static>,
             client_request = window_destroyed,
-            on_response = |response, callbacks| {
+            on_response = |response, callback| {
                 let handle = WindowHandle { id: response.window_id };
                 let title = response.title;
                 let app_id = response.app_id;
 
-                for callback in callbacks {
-                    callback(&handle, &title, &app_id);
-                }
+                callback(&handle, &title, &app_id);
+            },
+        }
+
+        /// A window stopped responding to the compositor.
+        ///
+        /// Callbacks receive the unresponsive window.
+        WindowUnresponsive = {
+            enum_name = Unresponsive,
+            callback_type = SingleWindowFn,
+            client_request = window_unresponsive,
+            on_response = |response, callback| {
+                let handle = WindowHandle { id: response.window_id };
+
+                callback(&handle);
+            },
+        }
+
+        /// A window was minimized or unminimized.
+        ///
+        /// Callbacks receive the window and whether it's now minimized.
+        WindowMinimized = {
+            enum_name = Minimized,
+            callback_type = Box<dyn FnMut(&WindowHandle, bool) + Send + 'static>,
+            client_request = window_minimized,
+            on_response = |response, callback| {
+                let handle = WindowHandle { id: response.window_id };
+
+                callback(&handle, response.minimized);
+            },
+        }
+
+        /// A window started or stopped demanding attention.
+        ///
+        /// Windows demand attention through xdg-activation or X11 urgency hints,
+        /// and stop once they're focused.
+        ///
+        /// Callbacks receive the window and whether it now demands attention.
+        WindowUrgencyChanged = {
+            enum_name = UrgencyChanged,
+            callback_type = Box<dyn FnMut(&WindowHandle, bool) + Send + 'static>,
+            client_request = window_urgency_changed,
+            on_response = |response, callback| {
+                let handle = WindowHandle { id: response.window_id };
+
+                callback(&handle, response.demands_attention);
+            },
+        }
+
+        /// A keybind was pressed while one of a window's popups held the keyboard grab.
+        ///
+        /// Keybinds still run, but ones that move keyboard focus won't take effect
+        /// until the popup closes.
+        ///
+        /// Callbacks receive the window the popup belongs to.
+        WindowPopupGrabBlockedKeybind = {
+            enum_name = PopupGrabBlockedKeybind,
+            callback_type = SingleWindowFn,
+            client_request = window_popup_grab_blocked_keybind,
+            on_response = |response, callback| {
+                let handle = WindowHandle { id: response.window_id };
+
+                callback(&handle);
             },
         }
     }
@@ -357,12 +538,10 @@ signals! {
             enum_name = Active,
             callback_type = Box<dyn FnMut(&TagHandle, bool) + Send + 'static>,
             client_request = tag_active,
-            on_response = |response, callbacks| {
+            on_response = |response, callback| {
                 let handle = TagHandle { id: response.tag_id };
 
-                for callback in callbacks {
-                    callback(&handle, response.active);
-                }
+                callback(&handle, response.active);
             },
         }
         /// A tag was created.
@@ -370,12 +549,10 @@ signals! {
             enum_name = Created,
             callback_type = Box<dyn FnMut(&TagHandle) + Send + 'static>,
             client_request = tag_created,
-            on_response = |response, callbacks| {
+            on_response = |response, callback| {
                 let handle = TagHandle { id: response.tag_id };
 
-                for callback in callbacks {
-                    callback(&handle);
-                }
+                callback(&handle);
             },
         }
         /// A tag was removed
@@ -383,12 +560,22 @@ signals! {
             enum_name = Removed,
             callback_type = Box<dyn FnMut(&TagHandle) + Send + 'static>,
             client_request = tag_removed,
-            on_response = |response, callbacks| {
+            on_response = |response, callback| {
                 let handle = TagHandle { id: response.tag_id };
 
-                for callback in callbacks {
-                    callback(&handle);
-                }
+                callback(&handle);
+            },
+        }
+        /// A window on an inactive tag requested attention, making the tag urgent,
+        /// or an urgent tag was activated.
+        TagUrgencyChanged = {
+            enum_name = UrgencyChanged,
+            callback_type = Box<dyn FnMut(&TagHandle, bool) + Send + 'static>,
+            client_request = tag_urgency_changed,
+            on_response = |response, callback| {
+                let handle = TagHandle { id: response.tag_id };
+
+                callback(&handle, response.urgent);
             },
         }
     }
@@ -399,17 +586,101 @@ signals! {
             enum_name = DeviceAdded,
             callback_type = Box<dyn FnMut(&DeviceHandle) + Send + 'static>,
             client_request = input_device_added,
-            on_response = |response, callbacks| {
+            on_response = |response, callback| {
                 let handle = DeviceHandle { sysname: response.device_sysname };
 
-                for callback in callbacks {
-                    callback(&handle);
-                }
+                callback(&handle);
+            },
+        }
+        /// Sticky keys or slow keys were enabled, disabled, or changed.
+        ///
+        /// Callbacks receive whether sticky keys is enabled and the slow keys delay,
+        /// if enabled.
+        InputAccessibilityChanged = {
+            enum_name = AccessibilityChanged,
+            callback_type = Box<dyn FnMut(bool, Option<Duration>) + Send + 'static>,
+            client_request = input_accessibility_changed,
+            on_response = |response, callback| {
+                let slow_keys = response
+                    .slow_keys_delay_millis
+                    .map(|millis| Duration::from_millis(millis.into()));
+
+                callback(response.sticky_keys, slow_keys);
+            },
+        }
+    }
+    /// Signals relating to the battery and power supply.
+    PowerSignal => {
+        /// The system switched between battery and external power.
+        ///
+        /// Callbacks receive whether the system is now running on battery power.
+        PowerOnBattery = {
+            enum_name = OnBattery,
+            callback_type = Box<dyn FnMut(bool) + Send + 'static>,
+            client_request = power_on_battery,
+            on_response = |response, callback| {
+                callback(response.on_battery);
+            },
+        }
+        /// The battery started or stopped charging.
+        ///
+        /// Callbacks receive whether the battery is now charging.
+        PowerCharging = {
+            enum_name = Charging,
+            callback_type = Box<dyn FnMut(bool) + Send + 'static>,
+            client_request = power_charging,
+            on_response = |response, callback| {
+                callback(response.charging);
+            },
+        }
+        /// The battery became low.
+        ///
+        /// Callbacks receive the battery's charge from 0 to 100.
+        PowerLowBattery = {
+            enum_name = LowBattery,
+            callback_type = Box<dyn FnMut(f64) + Send + 'static>,
+            client_request = power_low_battery,
+            on_response = |response, callback| {
+                callback(response.percentage);
+            },
+        }
+    }
+    /// Signals relating to the session being idle.
+    IdleSignal => {
+        /// There was no input for the idle timeout and the session became idle.
+        IdleStarted = {
+            enum_name = Idle,
+            callback_type = Box<dyn FnMut() + Send + 'static>,
+            client_request = idle_started,
+            on_response = |_response, callback| {
+                callback();
+            },
+        }
+        /// There was input while the session was idle.
+        IdleResumed = {
+            enum_name = Resume,
+            callback_type = Box<dyn FnMut() + Send + 'static>,
+            client_request = idle_resumed,
+            on_response = |_response, callback| {
+                callback();
             },
         }
     }
 }
 
+/// Signals relating to the config itself.
+///
+/// Unlike other signals, these come from the config and not the compositor.
+pub enum ConfigSignal {
+    /// A signal callback or layout generator panicked.
+    ///
+    /// Callbacks receive the name of the signal, or `"Layout"` for layout generators,
+    /// and the panic message.
+    ///
+    /// Panicking callbacks stay connected and run again on the next signal.
+    CallbackPanicked(Box<dyn FnMut(&str, &str) + Send + 'static>),
+}
+
 pub(crate) type SingleOutputFn = Box<dyn FnMut(&OutputHandle) + Send + 'static>;
 pub(crate) type SingleWindowFn = Box<dyn FnMut(&WindowHandle) + Send + 'static>;
 
@@ -420,21 +691,45 @@ pub(crate) struct SignalState {
     pub(crate) output_move: SignalData<OutputMove>,
     pub(crate) output_pointer_enter: SignalData<OutputPointerEnter>,
     pub(crate) output_pointer_leave: SignalData<OutputPointerLeave>,
+    pub(crate) output_pointer_edge: SignalData<OutputPointerEdge>,
     pub(crate) output_focused: SignalData<OutputFocused>,
+    pub(crate) output_layer_focused: SignalData<OutputLayerFocused>,
+    pub(crate) output_layer_unfocused: SignalData<OutputLayerUnfocused>,
+    pub(crate) output_orientation_changed: SignalData<OutputOrientationChanged>,
+    pub(crate) output_fullscreen_changed: SignalData<OutputFullscreenChanged>,
+    pub(crate) output_stacking_changed: SignalData<OutputStackingChanged>,
+    pub(crate) output_no_active_tags: SignalData<OutputNoActiveTags>,
 
     pub(crate) window_pointer_enter: SignalData<WindowPointerEnter>,
     pub(crate) window_pointer_leave: SignalData<WindowPointerLeave>,
     pub(crate) window_focused: SignalData<WindowFocused>,
+    pub(crate) window_focus_changed: SignalData<WindowFocusChanged>,
     pub(crate) window_title_changed: SignalData<WindowTitleChanged>,
+    pub(crate) window_focused_title_changed: SignalData<WindowFocusedTitleChanged>,
     pub(crate) window_layout_mode_changed: SignalData<WindowLayoutModeChanged>,
     pub(crate) window_created: SignalData<WindowCreated>,
     pub(crate) window_destroyed: SignalData<WindowDestroyed>,
+    pub(crate) window_unresponsive: SignalData<WindowUnresponsive>,
+    pub(crate) window_minimized: SignalData<WindowMinimized>,
+    pub(crate) window_urgency_changed: SignalData<WindowUrgencyChanged>,
+    pub(crate) window_popup_grab_blocked_keybind: SignalData<WindowPopupGrabBlockedKeybind>,
 
     pub(crate) tag_active: SignalData<TagActive>,
     pub(crate) tag_created: SignalData<TagCreated>,
     pub(crate) tag_removed: SignalData<TagRemoved>,
+    pub(crate) tag_urgency_changed: SignalData<TagUrgencyChanged>,
 
     pub(crate) input_device_added: SignalData<InputDeviceAdded>,
+    pub(crate) input_accessibility_changed: SignalData<InputAccessibilityChanged>,
+
+    pub(crate) power_on_battery: SignalData<PowerOnBattery>,
+    pub(crate) power_charging: SignalData<PowerCharging>,
+    pub(crate) power_low_battery: SignalData<PowerLowBattery>,
+
+    pub(crate) idle_started: SignalData<IdleStarted>,
+    pub(crate) idle_resumed: SignalData<IdleResumed>,
+
+    pub(crate) config_callback_panicked: CallbackPanickedData,
 }
 
 impl std::fmt::Debug for SignalState {
@@ -452,21 +747,45 @@ impl SignalState {
             output_move: SignalData::new(),
             output_pointer_enter: SignalData::new(),
             output_pointer_leave: SignalData::new(),
+            output_pointer_edge: SignalData::new(),
             output_focused: SignalData::new(),
+            output_layer_focused: SignalData::new(),
+            output_layer_unfocused: SignalData::new(),
+            output_orientation_changed: SignalData::new(),
+            output_fullscreen_changed: SignalData::new(),
+            output_stacking_changed: SignalData::new(),
+            output_no_active_tags: SignalData::new(),
 
             window_pointer_enter: SignalData::new(),
             window_pointer_leave: SignalData::new(),
             window_focused: SignalData::new(),
+            window_focus_changed: SignalData::new(),
             window_title_changed: SignalData::new(),
+            window_focused_title_changed: SignalData::new(),
             window_layout_mode_changed: SignalData::new(),
             window_created: SignalData::new(),
             window_destroyed: SignalData::new(),
+            window_unresponsive: SignalData::new(),
+            window_minimized: SignalData::new(),
+            window_urgency_changed: SignalData::new(),
+            window_popup_grab_blocked_keybind: SignalData::new(),
 
             tag_active: SignalData::new(),
             tag_created: SignalData::new(),
             tag_removed: SignalData::new(),
+            tag_urgency_changed: SignalData::new(),
 
             input_device_added: SignalData::new(),
+            input_accessibility_changed: SignalData::new(),
+
+            power_on_battery: SignalData::new(),
+            power_charging: SignalData::new(),
+            power_low_battery: SignalData::new(),
+
+            idle_started: SignalData::new(),
+            idle_resumed: SignalData::new(),
+
+            config_callback_panicked: CallbackPanickedData::default(),
         }
     }
 
@@ -477,21 +796,45 @@ impl SignalState {
         self.output_move.reset();
         self.output_pointer_enter.reset();
         self.output_pointer_leave.reset();
+        self.output_pointer_edge.reset();
         self.output_focused.reset();
+        self.output_layer_focused.reset();
+        self.output_layer_unfocused.reset();
+        self.output_orientation_changed.reset();
+        self.output_fullscreen_changed.reset();
+        self.output_stacking_changed.reset();
+        self.output_no_active_tags.reset();
 
         self.window_pointer_enter.reset();
         self.window_pointer_leave.reset();
         self.window_focused.reset();
+        self.window_focus_changed.reset();
         self.window_title_changed.reset();
+        self.window_focused_title_changed.reset();
         self.window_layout_mode_changed.reset();
         self.window_created.reset();
         self.window_destroyed.reset();
+        self.window_unresponsive.reset();
+        self.window_minimized.reset();
+        self.window_urgency_changed.reset();
+        self.window_popup_grab_blocked_keybind.reset();
 
         self.tag_active.reset();
         self.tag_created.reset();
         self.tag_removed.reset();
+        self.tag_urgency_changed.reset();
 
         self.input_device_added.reset();
+        self.input_accessibility_changed.reset();
+
+        self.power_on_battery.reset();
+        self.power_charging.reset();
+        self.power_low_battery.reset();
+
+        self.idle_started.reset();
+        self.idle_resumed.reset();
+
+        self.config_callback_panicked.reset();
     }
 }
 
@@ -518,6 +861,119 @@ impl<S: Signal> SignalData<S> {
     }
 }
 
+type CallbackPanickedFn = Box<dyn FnMut(&str, &str) + Send + 'static>;
+type SharedCallbackPanickedFn = Arc<Mutex<CallbackPanickedFn>>;
+
+/// Callbacks for [`ConfigSignal::CallbackPanicked`].
+///
+/// These live outside of `SignalState` because panics are reported
+/// from the tasks running other signals' callbacks.
+///
+/// Each callback has its own lock so the list can be cloned and released before
+/// they're called, letting callbacks connect to or disconnect from this signal.
+static CALLBACK_PANICKED_CALLBACKS: Mutex<BTreeMap<SignalConnId, SharedCallbackPanickedFn>> =
+    Mutex::new(BTreeMap::new());
+
+/// Connection data for [`ConfigSignal::CallbackPanicked`].
+#[derive(Default)]
+pub(crate) struct CallbackPanickedData {
+    remove_callback_sender: Option<UnboundedSender<SignalConnId>>,
+    current_id: SignalConnId,
+}
+
+impl CallbackPanickedData {
+    pub(crate) fn add_callback(&mut self, callback: CallbackPanickedFn) -> SignalHandle {
+        let remove_callback_sender = self
+            .remove_callback_sender
+            .get_or_insert_with(|| {
+                let (remove_callback_sender, mut remove_callback_recv) =
+                    unbounded_channel::<SignalConnId>();
+
+                tokio::spawn(async move {
+                    while let Some(id) = remove_callback_recv.recv().await {
+                        CALLBACK_PANICKED_CALLBACKS
+                            .lock()
+                            .unwrap_or_else(PoisonError::into_inner)
+                            .remove(&id);
+                    }
+                });
+
+                remove_callback_sender
+            })
+            .clone();
+
+        CALLBACK_PANICKED_CALLBACKS
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(self.current_id, Arc::new(Mutex::new(callback)));
+
+        let handle = SignalHandle::new(self.current_id, remove_callback_sender);
+
+        self.current_id.0 += 1;
+
+        handle
+    }
+
+    fn reset(&mut self) {
+        self.remove_callback_sender.take();
+        self.current_id = SignalConnId::default();
+        CALLBACK_PANICKED_CALLBACKS
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clear();
+    }
+}
+
+thread_local! {
+    static ISOLATING_PANICS: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Runs `f`, catching any panic so it doesn't take down the config.
+///
+/// While `f` runs, [`is_isolating_panics`] returns `true` on this thread.
+pub(crate) fn catch_callback_panic<R>(f: impl FnOnce() -> R) -> Result<R, Box<dyn Any + Send>> {
+    let was_isolating = ISOLATING_PANICS.replace(true);
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    ISOLATING_PANICS.set(was_isolating);
+    result
+}
+
+/// Returns whether a panic on the current thread will be caught and reported
+/// through [`ConfigSignal::CallbackPanicked`] instead of unwinding further.
+///
+/// The panic hook installed by [`config`][crate::config] uses this to keep
+/// panics in signal callbacks and layout generators from ending the config.
+#[doc(hidden)]
+pub fn is_isolating_panics() -> bool {
+    ISOLATING_PANICS.get()
+}
+
+/// Reports a panic in a signal callback or layout generator
+/// to [`ConfigSignal::CallbackPanicked`] callbacks.
+///
+/// The panic message was already printed by the panic hook.
+pub(crate) fn report_callback_panic(signal_name: &str, payload: Box<dyn Any + Send>) {
+    let message = payload
+        .downcast_ref::<&str>()
+        .copied()
+        .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+        .unwrap_or("Box<dyn Any>");
+
+    let callbacks = CALLBACK_PANICKED_CALLBACKS
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .values()
+        .cloned()
+        .collect::<Vec<_>>();
+
+    for callback in callbacks {
+        let mut callback = callback.lock().unwrap_or_else(PoisonError::into_inner);
+
+        // Don't report panics from these callbacks to avoid looping forever
+        let _ = catch_callback_panic(|| callback(signal_name, message));
+    }
+}
+
 struct ConnectSignalChannels<F> {
     callback_sender: UnboundedSender<(SignalConnId, F)>,
     dc_pinger: oneshot::Sender<()>,
@@ -525,16 +981,17 @@ struct ConnectSignalChannels<F> {
 }
 
 fn connect_signal<Req, Resp, F, T, O>(
+    signal_name: &'static str,
     callback_count: Arc<AtomicU32>,
     to_in_stream: T,
     mut on_response: O,
 ) -> ConnectSignalChannels<F>
 where
     Req: SignalRequest + Send + 'static,
-    Resp: Send + 'static,
+    Resp: Clone + Send + 'static,
     F: Send + 'static,
     T: FnOnce(UnboundedReceiverStream<Req>) -> Streaming<Resp>,
-    O: FnMut(Resp, btree_map::ValuesMut<'_, SignalConnId, F>) + Send + 'static,
+    O: FnMut(Resp, &mut F) + Send + 'static,
 {
     let (control_sender, recv) = unbounded_channel::<Req>();
     let out_stream = UnboundedReceiverStream::new(recv);
@@ -571,7 +1028,17 @@ where
 
                     match response {
                         Ok(response) => {
-                            on_response(response, callbacks.values_mut());
+                            // Run each callback on its own so one panicking doesn't
+                            // stop the rest or kill the signal.
+                            for callback in callbacks.values_mut() {
+                                let response = response.clone();
+                                let result =
+                                    catch_callback_panic(|| on_response(response, callback));
+
+                                if let Err(payload) = result {
+                                    report_callback_panic(signal_name, payload);
+                                }
+                            }
 
                             control_sender
                                 .send(Req::from_control(StreamControl::Ready))
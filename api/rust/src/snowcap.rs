@@ -4,22 +4,37 @@
 //! Snowcap is a really-early-in-development widget system, designed for Pinnacle.
 //! This module contains preliminary widgets made with the system.
 
-use std::sync::{Arc, OnceLock};
+pub mod binding;
+pub mod pager;
 
+use std::{
+    collections::HashMap,
+    num::NonZeroU32,
+    sync::{Arc, Mutex, OnceLock},
+};
+
+use binding::Binding;
 use indexmap::IndexMap;
 use snowcap_api::{
     decoration::{DecorationHandle, NewDecorationError},
-    layer::{ExclusiveZone, KeyboardInteractivity, ZLayer},
+    input::Modifiers,
+    layer::{Anchor, ExclusiveZone, KeyboardInteractivity, LayerHandle, NewLayerError, ZLayer},
+    popup::{self, Gravity, PopupHandle, Position},
+    signal::Signaler,
+    surface::{SurfaceEvent, SurfaceHandle},
     widget::{
         Alignment, Background, Border, Color, Length, Padding, Program, Radius, WidgetDef,
+        base::WidgetBase,
         button::{self, Button, Styles},
         column::Column,
         container::Container,
         font::{Family, Font, Weight},
         image::{Handle, Image},
         input_region::InputRegion,
+        mouse_area::MouseArea,
         row::Row,
         scrollable::Scrollable,
+        signal::RequestClose,
         text::{self, Text},
     },
 };
@@ -27,7 +42,12 @@ use xkbcommon::xkb::Keysym;
 
 use crate::{
     input::{BindInfoKind, Mod},
+    output::{OutputHandle, ScreenEdge},
+    pager::{Pager, PagerOutput},
+    portal::{GlobalShortcut, GlobalShortcutsRequest},
+    power::PowerStatus,
     signal::SignalHandle,
+    tag::TagHandle,
     window::WindowHandle,
 };
 
@@ -571,6 +591,145 @@ impl ConfigCrashedMessage {
     }
 }
 
+/// A prompt asking whether an app may bind global shortcuts.
+///
+/// When opened, pressing ENTER will allow the app to bind its shortcuts
+/// and pressing ESCAPE will deny it.
+#[derive(Debug)]
+pub struct GlobalShortcutsPrompt {
+    /// The radius of the prompt's corners.
+    pub border_radius: f32,
+    /// The thickness of the prompt border.
+    pub border_thickness: f32,
+    /// The color of the prompt background.
+    pub background_color: Color,
+    /// The color of the prompt border.
+    pub border_color: Color,
+    /// The font of the prompt.
+    pub font: Font,
+    /// The width of the prompt.
+    pub width: u32,
+    /// The height of the prompt.
+    pub height: u32,
+    app_id: String,
+    shortcuts: Vec<GlobalShortcut>,
+    request: Option<GlobalShortcutsRequest>,
+}
+
+impl Program for GlobalShortcutsPrompt {
+    type Message = ();
+
+    fn update(&mut self, _msg: Self::Message) {}
+
+    fn view(&self) -> Option<WidgetDef<Self::Message>> {
+        let app = match self.app_id.as_str() {
+            "" => "An app",
+            app_id => app_id,
+        };
+
+        let shortcuts = self.shortcuts.iter().map(|shortcut| {
+            let trigger = shortcut
+                .preferred_trigger
+                .as_ref()
+                .map(|trigger| format!(" ({trigger})"))
+                .unwrap_or_default();
+
+            Text::new(format!("{}{trigger}", shortcut.description))
+                .style(text::Style::new().font(self.font.clone()).pixels(14.0))
+                .into()
+        });
+
+        let widget = Container::new(Column::new_with_children([
+            Text::new(format!("{app} wants to bind global shortcuts"))
+                .style(
+                    text::Style::new()
+                        .font(self.font.clone().weight(Weight::Bold))
+                        .pixels(20.0),
+                )
+                .into(),
+            Text::new("").style(text::Style::new().pixels(8.0)).into(), // Spacing
+            Scrollable::new(Column::new_with_children(shortcuts))
+                .width(Length::Fill)
+                .height(Length::Fill)
+                .into(),
+            Text::new("").style(text::Style::new().pixels(8.0)).into(), // Spacing
+            Text::new("ENTER: Allow. ESCAPE: Deny.")
+                .style(text::Style::new().font(self.font.clone()).pixels(14.0))
+                .into(),
+        ]))
+        .width(Length::Fixed(self.width as f32))
+        .height(Length::Fixed(self.height as f32))
+        .padding(Padding {
+            top: 16.0,
+            right: 16.0,
+            bottom: 16.0,
+            left: 16.0,
+        })
+        .vertical_alignment(Alignment::Center)
+        .horizontal_alignment(Alignment::Center)
+        .style(snowcap_api::widget::container::Style {
+            text_color: None,
+            background: Some(Background::Color(self.background_color)),
+            border: Some(snowcap_api::widget::Border {
+                color: Some(self.border_color),
+                width: Some(self.border_thickness),
+                radius: Some(self.border_radius.into()),
+            }),
+        });
+
+        Some(widget.into())
+    }
+}
+
+impl GlobalShortcutsPrompt {
+    /// Creates a prompt for the given request with sane defaults.
+    pub fn new(request: GlobalShortcutsRequest) -> Self {
+        GlobalShortcutsPrompt {
+            border_radius: 12.0,
+            border_thickness: 6.0,
+            background_color: [0.15, 0.03, 0.1, 0.65].into(),
+            border_color: [0.8, 0.2, 0.4].into(),
+            font: Font::new_with_family(Family::Name("Ubuntu".into())),
+            width: 500,
+            height: 300,
+            app_id: request.app_id().to_string(),
+            shortcuts: request.shortcuts().to_vec(),
+            request: Some(request),
+        }
+    }
+
+    /// Shows this prompt.
+    ///
+    /// The request is denied if the prompt can't be shown.
+    pub fn show(mut self) {
+        let mut request = self.request.take();
+
+        let Ok(layer) = snowcap_api::layer::new_widget(
+            self,
+            None,
+            KeyboardInteractivity::Exclusive,
+            ExclusiveZone::Respect,
+            ZLayer::Overlay,
+        ) else {
+            return;
+        };
+
+        layer.on_key_press(move |handle, key, _mods| {
+            if key == Keysym::Return {
+                if let Some(request) = request.take() {
+                    request.allow();
+                }
+                handle.close();
+            } else if key == Keysym::Escape {
+                if let Some(request) = request.take() {
+                    request.deny();
+                }
+                handle.close();
+            }
+        });
+    }
+}
+
 /// A border that shows window focus, with an optional titlebar.
 #[derive(Debug, Clone)]
 pub struct FocusBorder {
@@ -996,3 +1155,1308 @@ impl Program for FocusBorder {
         Some(row.into())
     }
 }
+
+/// A module that can be placed in a [`Bar`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum BarModule {
+    /// The tags on the bar's output.
+    ///
+    /// Clicking a tag switches to it. With [`Bar::tag_previews`], hovering a tag
+    /// shows a miniature of its windows.
+    TagPager,
+    /// The title of the focused window on the bar's output.
+    FocusedTitle,
+    /// The current time, formatted with a `date(1)` format string like `"%H:%M"`.
+    Clock(String),
+    /// The battery's charge. This is empty on systems without a battery.
+    Battery,
+    /// Some fixed text.
+    Text(String),
+}
+
+/// The colors, font, and size of a [`Bar`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct BarTheme {
+    /// The height of the bar.
+    pub height: u32,
+    /// The space between modules.
+    pub spacing: f32,
+    /// The font of all text in the bar.
+    pub font: Font,
+    /// The size of all text in the bar, in pixels.
+    pub text_size: f32,
+    /// The color of the bar background.
+    pub background_color: Color,
+    /// The color of text.
+    pub text_color: Color,
+    /// The color of tags without windows.
+    pub empty_tag_color: Color,
+    /// The background color of active tags.
+    pub active_tag_color: Color,
+    /// The background color of tags with a window that demands attention.
+    pub urgent_tag_color: Color,
+}
+
+impl Default for BarTheme {
+    fn default() -> Self {
+        Self {
+            height: 24,
+            spacing: 12.0,
+            font: Font::new_with_family(Family::Name("Ubuntu".into())),
+            text_size: 14.0,
+            background_color: [0.1, 0.1, 0.15, 0.9].into(),
+            text_color: [0.9, 0.9, 0.9].into(),
+            empty_tag_color: [0.5, 0.5, 0.5].into(),
+            active_tag_color: [0.4, 0.15, 0.7].into(),
+            urgent_tag_color: [0.8, 0.2, 0.4].into(),
+        }
+    }
+}
+
+/// A bar along the top or bottom of an output, made of [`BarModule`]s.
+///
+/// Modules are placed in three slots: the left slot, the centered slot, and the right slot.
+/// The bar is shown on the focused output.
+///
+/// With [`auto_hide`][Bar::auto_hide], the bar stays hidden and doesn't reserve space
+/// until the pointer hits its edge of the output, and hides again once the pointer leaves it.
+///
+/// A system tray module will be added once Pinnacle has a tray.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::snowcap::{Bar, BarModule};
+/// Bar::new()
+///     .left(BarModule::TagPager)
+///     .center(BarModule::FocusedTitle)
+///     .right(BarModule::Battery)
+///     .right(BarModule::Clock("%a %d %b %H:%M".into()))
+///     .tag_previews(true)
+///     .show()
+///     .unwrap();
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Bar {
+    /// The modules in the left slot.
+    pub left: Vec<BarModule>,
+    /// The modules in the center slot.
+    pub center: Vec<BarModule>,
+    /// The modules in the right slot.
+    pub right: Vec<BarModule>,
+    /// Whether the bar is at the bottom of the output instead of the top.
+    pub bottom: bool,
+    /// Whether the bar hides until the pointer hits its edge of the output.
+    pub auto_hide: bool,
+    /// Whether hovering a tag in the [`BarModule::TagPager`] shows a miniature
+    /// of its windows.
+    pub tag_previews: bool,
+    /// The bar's theme.
+    pub theme: BarTheme,
+
+    output: Option<OutputHandle>,
+    surface: Option<SurfaceHandle<BarMessage>>,
+    revealed: bool,
+    pager: Option<PagerOutput>,
+    preview: Option<(TagHandle, PopupHandle<pager::Pager>)>,
+    power: Option<PowerStatus>,
+    clocks: HashMap<String, String>,
+    tasks: Arc<Mutex<BarTasks>>,
+}
+
+/// Things that feed a [`Bar`] that need to stop when it closes.
+#[derive(Debug, Default)]
+struct BarTasks {
+    bindings: Vec<Binding>,
+}
+
+/// A message that changes a [`Bar`].
+#[derive(Debug, Clone)]
+pub enum BarMessage {
+    /// The pager state changed.
+    Pager(Pager),
+    /// The battery status changed.
+    Power(PowerStatus),
+    /// The clock with the given format ticked.
+    Clock {
+        /// The format of the clock.
+        format: String,
+        /// The formatted time.
+        time: String,
+    },
+    /// Switch to a tag.
+    SwitchToTag(TagHandle),
+    /// Show a preview of a tag's windows, or hide it if `None`.
+    PreviewTag(Option<TagHandle>),
+    /// Show an auto-hiding bar.
+    Reveal,
+    /// Hide an auto-hiding bar.
+    Hide,
+}
+
+impl Bar {
+    /// Creates an empty bar with the default theme.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a module to the end of the left slot.
+    pub fn left(mut self, module: BarModule) -> Self {
+        self.left.push(module);
+        self
+    }
+
+    /// Adds a module to the end of the center slot.
+    pub fn center(mut self, module: BarModule) -> Self {
+        self.center.push(module);
+        self
+    }
+
+    /// Adds a module to the end of the right slot.
+    pub fn right(mut self, module: BarModule) -> Self {
+        self.right.push(module);
+        self
+    }
+
+    /// Sets whether this bar hides until the pointer hits its edge of the output.
+    pub fn auto_hide(mut self, auto_hide: bool) -> Self {
+        self.auto_hide = auto_hide;
+        self
+    }
+
+    /// Sets whether hovering a tag in the [`BarModule::TagPager`] shows a miniature
+    /// of its windows.
+    pub fn tag_previews(mut self, tag_previews: bool) -> Self {
+        self.tag_previews = tag_previews;
+        self
+    }
+
+    /// Sets this bar's theme.
+    pub fn theme(mut self, theme: BarTheme) -> Self {
+        self.theme = theme;
+        self
+    }
+
+    /// Shows this bar on the focused output.
+    pub fn show(mut self) -> Result<LayerHandle<BarMessage>, NewLayerError> {
+        self.output = crate::output::get_focused();
+
+        let modules = self
+            .left
+            .iter()
+            .chain(&self.center)
+            .chain(&self.right)
+            .cloned()
+            .collect::<Vec<_>>();
+        let tasks = self.tasks.clone();
+        let output = self.output.clone();
+        let (auto_hide, bottom) = (self.auto_hide, self.bottom);
+        let anchor = if bottom { Anchor::Bottom } else { Anchor::Top };
+        let exclusive_zone = match NonZeroU32::new(self.theme.height) {
+            Some(height) if !auto_hide => ExclusiveZone::Exclusive(height),
+            _ => ExclusiveZone::Respect,
+        };
+
+        let bar = snowcap_api::layer::new_widget(
+            self,
+            Some(anchor),
+            KeyboardInteractivity::None,
+            exclusive_zone,
+            ZLayer::Top,
+        )?;
+
+        let mut tasks = tasks.lock().unwrap();
+
+        if modules
+            .iter()
+            .any(|module| matches!(module, BarModule::TagPager | BarModule::FocusedTitle))
+        {
+            tasks
+                .bindings
+                .push(binding::bind_pager(&bar, BarMessage::Pager));
+        }
+
+        if modules.contains(&BarModule::Battery) {
+            tasks
+                .bindings
+                .push(binding::bind_power(&bar, BarMessage::Power));
+        }
+
+        if let Some(output) = output.filter(|_| auto_hide) {
+            let bar_edges = if bottom {
+                [
+                    ScreenEdge::Bottom,
+                    ScreenEdge::BottomLeft,
+                    ScreenEdge::BottomRight,
+                ]
+            } else {
+                [ScreenEdge::Top, ScreenEdge::TopLeft, ScreenEdge::TopRight]
+            };
+
+            tasks
+                .bindings
+                .push(binding::bind_screen_edge(&bar, &output, bar_edges, |_| {
+                    BarMessage::Reveal
+                }));
+        }
+
+        for module in modules {
+            let BarModule::Clock(format) = module else {
+                continue;
+            };
+
+            let clock = binding::bind_clock(&bar, format.clone(), move |time| BarMessage::Clock {
+                format: format.clone(),
+                time,
+            });
+            tasks.bindings.push(clock);
+        }
+
+        drop(tasks);
+
+        Ok(bar)
+    }
+
+    fn module_view(&self, module: &BarModule) -> Option<WidgetDef<BarMessage>> {
+        let text = |text: &str, color: Color| {
+            Text::new(text).style(
+                text::Style::new()
+                    .font(self.theme.font.clone())
+                    .pixels(self.theme.text_size)
+                    .color(color),
+            )
+        };
+
+        match module {
+            BarModule::TagPager => {
+                let pager = self.pager.as_ref()?;
+
+                let tags = pager.tags.iter().map(|tag| -> WidgetDef<BarMessage> {
+                    let background = if tag.urgent {
+                        Some(self.theme.urgent_tag_color)
+                    } else if tag.active {
+                        Some(self.theme.active_tag_color)
+                    } else {
+                        None
+                    };
+                    let color = if tag.occupied || tag.active {
+                        self.theme.text_color
+                    } else {
+                        self.theme.empty_tag_color
+                    };
+
+                    let style = background
+                        .map(|color| button::Style::new().background(Background::Color(color)))
+                        .unwrap_or_default();
+
+                    let button = Button::new(text(&tag.name, color))
+                        .height(Length::Fill)
+                        .padding(Padding {
+                            top: 0.0,
+                            right: 6.0,
+                            bottom: 0.0,
+                            left: 6.0,
+                        })
+                        .style(Styles {
+                            active: Some(style.clone()),
+                            hovered: Some(style.clone()),
+                            pressed: Some(style),
+                            disabled: None,
+                        })
+                        .on_press(BarMessage::SwitchToTag(tag.tag.clone()));
+
+                    if !self.tag_previews {
+                        return button.into();
+                    }
+
+                    // Previews are placed below (or above) the tag's container
+                    let button = Container::new(button)
+                        .id(tag_preview_anchor(&tag.tag))
+                        .height(Length::Fill);
+
+                    MouseArea::new(button)
+                        .on_enter(BarMessage::PreviewTag(Some(tag.tag.clone())))
+                        .on_exit(BarMessage::PreviewTag(None))
+                        .into()
+                });
+
+                Some(
+                    Row::new_with_children(tags)
+                        .item_alignment(Alignment::Center)
+                        .height(Length::Fill)
+                        .into(),
+                )
+            }
+            BarModule::FocusedTitle => {
+                let title = self.pager.as_ref()?.focused_window_title.as_deref()?;
+                Some(text(title, self.theme.text_color).into())
+            }
+            BarModule::Clock(format) => {
+                let time = self.clocks.get(format)?;
+                Some(text(time, self.theme.text_color).into())
+            }
+            BarModule::Battery => {
+                let percentage = self.power?.percentage?;
+                let label = if self.power.is_some_and(|power| power.charging) {
+                    format!("{percentage:.0}% (charging)")
+                } else {
+                    format!("{percentage:.0}%")
+                };
+                Some(text(&label, self.theme.text_color).into())
+            }
+            BarModule::Text(string) => Some(text(string, self.theme.text_color).into()),
+        }
+    }
+
+    /// Returns the pager widget shown in tag previews.
+    fn preview_pager(&self) -> Option<pager::Pager> {
+        let pager = pager::Pager::new(self.pager.as_ref()?)
+            .tag_width(200.0)
+            .window_thumbnails(true);
+
+        Some(pager)
+    }
+
+    /// Shows a preview of `tag` below the bar, or above it if the bar is at the bottom.
+    fn open_preview(&mut self, tag: TagHandle) {
+        self.close_preview();
+
+        let (Some(surface), Some(pager)) = (self.surface.as_ref(), self.preview_pager()) else {
+            return;
+        };
+
+        let (anchor, gravity) = if self.bottom {
+            (popup::Anchor::Top, Gravity::Top)
+        } else {
+            (popup::Anchor::Bottom, Gravity::Bottom)
+        };
+
+        let preview = TagPreview {
+            tag: tag.clone(),
+            pager,
+        };
+
+        let handle = popup::new_widget(
+            preview,
+            surface,
+            Position::at_widget(tag_preview_anchor(&tag)),
+            Some(anchor),
+            Some(gravity),
+            None,
+            None,
+            true,
+            false,
+        );
+
+        // Previews are best-effort, a bar is still usable without them
+        self.preview = handle.ok().map(|handle| (tag, handle));
+    }
+
+    fn close_preview(&mut self) {
+        if let Some((_, handle)) = self.preview.take() {
+            handle.close();
+        }
+    }
+
+    fn slot_view(&self, modules: &[BarModule], alignment: Alignment) -> Container<BarMessage> {
+        let row =
+            Row::new_with_children(modules.iter().filter_map(|module| self.module_view(module)))
+                .spacing(self.theme.spacing)
+                .item_alignment(Alignment::Center)
+                .height(Length::Fill);
+
+        Container::new(row)
+            .height(Length::Fill)
+            .horizontal_alignment(alignment)
+            .vertical_alignment(Alignment::Center)
+    }
+}
+
+impl Program for Bar {
+    type Message = BarMessage;
+
+    fn update(&mut self, msg: Self::Message) {
+        match msg {
+            BarMessage::Pager(pager) => {
+                self.pager = pager
+                    .outputs
+                    .into_iter()
+                    .find(|output| Some(&output.output) == self.output.as_ref());
+
+                if let (Some((_, handle)), Some(pager)) = (&self.preview, self.preview_pager()) {
+                    handle.send_message(pager);
+                }
+            }
+            BarMessage::Power(power) => {
+                self.power = Some(power);
+            }
+            BarMessage::Clock { format, time } => {
+                self.clocks.insert(format, time);
+            }
+            BarMessage::SwitchToTag(tag) => {
+                tag.switch_to();
+            }
+            BarMessage::PreviewTag(Some(tag)) => {
+                if self.preview.as_ref().map(|(preview, _)| preview) != Some(&tag) {
+                    self.open_preview(tag);
+                }
+            }
+            BarMessage::PreviewTag(None) => {
+                self.close_preview();
+            }
+            BarMessage::Reveal => {
+                self.revealed = true;
+            }
+            BarMessage::Hide => {
+                self.revealed = false;
+                self.close_preview();
+            }
+        }
+    }
+
+    fn view(&self) -> Option<WidgetDef<Self::Message>> {
+        if self.auto_hide && !self.revealed {
+            // Keep a sliver of the surface around so the layer stays mapped
+            let sliver = Container::new(Row::new())
+                .width(Length::Fill)
+                .height(Length::Fixed(1.0));
+            return Some(sliver.into());
+        }
+
+        let widget = Container::new(
+            Row::new_with_children([
+                self.slot_view(&self.left, Alignment::Start)
+                    .width(Length::Fill)
+                    .into(),
+                self.slot_view(&self.center, Alignment::Center)
+                    .width(Length::Shrink)
+                    .into(),
+                self.slot_view(&self.right, Alignment::End)
+                    .width(Length::Fill)
+                    .into(),
+            ])
+            .spacing(self.theme.spacing)
+            .width(Length::Fill)
+            .height(Length::Fill),
+        )
+        .width(Length::Fill)
+        .height(Length::Fixed(self.theme.height as f32))
+        .padding(Padding {
+            top: 0.0,
+            right: 8.0,
+            bottom: 0.0,
+            left: 8.0,
+        })
+        .style(
+            snowcap_api::widget::container::Style::new()
+                .background(Background::Color(self.theme.background_color))
+                .text_color(self.theme.text_color),
+        );
+
+        if self.auto_hide {
+            return Some(MouseArea::new(widget).on_exit(BarMessage::Hide).into());
+        }
+
+        Some(widget.into())
+    }
+
+    fn event(&mut self, event: SurfaceEvent<Self::Message>) {
+        match event {
+            SurfaceEvent::Created { surface } => {
+                self.surface = Some(surface);
+            }
+            SurfaceEvent::Closing => {
+                self.close_preview();
+
+                let mut tasks = self.tasks.lock().unwrap();
+                for binding in tasks.bindings.drain(..) {
+                    binding.unbind();
+                }
+            }
+            _ => (),
+        }
+    }
+}
+
+/// The id of the container a tag's preview is placed at.
+fn tag_preview_anchor(tag: &TagHandle) -> String {
+    format!("pinnacle-bar-tag-{}", tag.id)
+}
+
+/// A popup showing a miniature of a tag's windows, kept up to date by its [`Bar`].
+struct TagPreview {
+    tag: TagHandle,
+    pager: pager::Pager,
+}
+
+impl Program for TagPreview {
+    type Message = pager::Pager;
+
+    fn update(&mut self, msg: Self::Message) {
+        self.pager = msg;
+    }
+
+    fn view(&self) -> Option<WidgetDef<Self::Message>> {
+        let tag = self.pager.tags.iter().find(|tag| tag.tag == self.tag)?;
+        Some(self.pager.tag_view(tag))
+    }
+}
+
+/// A button in a [`ConfirmDialog`] or [`PowerMenu`].
+#[derive(Clone)]
+pub struct MenuButton {
+    /// The button's label.
+    pub label: String,
+    /// What happens when the button is pressed.
+    pub on_press: Arc<dyn Fn() + Send + Sync>,
+}
+
+impl std::fmt::Debug for MenuButton {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MenuButton")
+            .field("label", &self.label)
+            .finish_non_exhaustive()
+    }
+}
+
+impl MenuButton {
+    /// Creates a button that runs `on_press` when pressed.
+    pub fn new(label: impl ToString, on_press: impl Fn() + Send + Sync + 'static) -> Self {
+        Self {
+            label: label.to_string(),
+            on_press: Arc::new(on_press),
+        }
+    }
+}
+
+/// The colors, font, and size of a [`ConfirmDialog`] or [`PowerMenu`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct MenuTheme {
+    /// The radius of the menu's corners.
+    pub border_radius: f32,
+    /// The thickness of the menu border.
+    pub border_thickness: f32,
+    /// The color of the menu background.
+    pub background_color: Color,
+    /// The color of the menu border.
+    pub border_color: Color,
+    /// The font of the menu.
+    pub font: Font,
+    /// The size of the menu's text, in pixels.
+    pub text_size: f32,
+    /// The color of text.
+    pub text_color: Color,
+    /// The background color of buttons.
+    pub button_color: Color,
+    /// The background color of the selected button.
+    pub selected_button_color: Color,
+}
+
+impl Default for MenuTheme {
+    fn default() -> Self {
+        Self {
+            border_radius: 12.0,
+            border_thickness: 6.0,
+            background_color: [0.15, 0.03, 0.1, 0.65].into(),
+            border_color: [0.8, 0.2, 0.4].into(),
+            font: Font::new_with_family(Family::Name("Ubuntu".into())),
+            text_size: 14.0,
+            text_color: [0.9, 0.9, 0.9].into(),
+            button_color: [0.3, 0.1, 0.2].into(),
+            selected_button_color: [0.8, 0.2, 0.4].into(),
+        }
+    }
+}
+
+/// A message that changes a [`ConfirmDialog`] or [`PowerMenu`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MenuMessage {
+    /// Select the next button.
+    SelectNext,
+    /// Select the previous button.
+    SelectPrevious,
+    /// Press the selected button.
+    Activate,
+    /// Press the button at the given index.
+    Press(usize),
+    /// Close the menu without pressing anything.
+    Close,
+}
+
+/// A row of buttons with an optional title and message, shared by
+/// [`ConfirmDialog`] and [`PowerMenu`].
+struct ButtonMenu {
+    title: String,
+    message: Option<String>,
+    buttons: Vec<MenuButton>,
+    selected: usize,
+    theme: MenuTheme,
+    base: WidgetBase,
+}
+
+impl ButtonMenu {
+    /// Shows this menu and binds its keys.
+    ///
+    /// Left, Right, Tab, and Shift+Tab change the selected button, ENTER presses it,
+    /// and ESCAPE closes the menu.
+    fn show(self) -> Result<LayerHandle<MenuMessage>, NewLayerError> {
+        let menu = snowcap_api::layer::new_widget(
+            self,
+            None,
+            KeyboardInteractivity::Exclusive,
+            ExclusiveZone::Respect,
+            ZLayer::Overlay,
+        )?;
+
+        menu.on_key_press(|handle, key, mods| {
+            if let Some(msg) = Self::key_message(key, mods) {
+                handle.send_message(msg);
+            }
+        });
+
+        Ok(menu)
+    }
+
+    /// Returns the message a key press sends to the menu, if any.
+    fn key_message(key: Keysym, mods: Modifiers) -> Option<MenuMessage> {
+        let msg = match key {
+            Keysym::Left | Keysym::Up | Keysym::ISO_Left_Tab => MenuMessage::SelectPrevious,
+            Keysym::Tab if mods.shift => MenuMessage::SelectPrevious,
+            Keysym::Right | Keysym::Down | Keysym::Tab => MenuMessage::SelectNext,
+            Keysym::Return | Keysym::KP_Enter | Keysym::space => MenuMessage::Activate,
+            Keysym::Escape => MenuMessage::Close,
+            _ => return None,
+        };
+        Some(msg)
+    }
+
+    fn press(&self, index: usize) {
+        if let Some(button) = self.buttons.get(index) {
+            (button.on_press)();
+        }
+        self.base.signaler().emit(RequestClose);
+    }
+}
+
+impl Program for ButtonMenu {
+    type Message = MenuMessage;
+
+    fn update(&mut self, msg: Self::Message) {
+        let count = self.buttons.len().max(1);
+
+        match msg {
+            MenuMessage::SelectNext => self.selected = (self.selected + 1) % count,
+            MenuMessage::SelectPrevious => self.selected = (self.selected + count - 1) % count,
+            MenuMessage::Activate => self.press(self.selected),
+            MenuMessage::Press(index) => self.press(index),
+            MenuMessage::Close => self.base.signaler().emit(RequestClose),
+        }
+    }
+
+    fn view(&self) -> Option<WidgetDef<Self::Message>> {
+        let text = |string: &str, pixels: f32, font: Font| {
+            Text::new(string).style(
+                text::Style::new()
+                    .font(font)
+                    .pixels(pixels)
+                    .color(self.theme.text_color),
+            )
+        };
+
+        let buttons =
+            self.buttons
+                .iter()
+                .enumerate()
+                .map(|(i, button)| -> WidgetDef<MenuMessage> {
+                    let color = if i == self.selected {
+                        self.theme.selected_button_color
+                    } else {
+                        self.theme.button_color
+                    };
+                    let style = button::Style::new()
+                        .background(Background::Color(color))
+                        .border(Border {
+                            color: None,
+                            width: None,
+                            radius: Some(Radius::from(6.0)),
+                        });
+
+                    Button::new(text(
+                        &button.label,
+                        self.theme.text_size,
+                        self.theme.font.clone(),
+                    ))
+                    .padding(Padding::from(8.0))
+                    .style(Styles {
+                        active: Some(style.clone()),
+                        hovered: Some(style.clone()),
+                        pressed: Some(style),
+                        disabled: None,
+                    })
+                    .on_press(MenuMessage::Press(i))
+                    .into()
+                });
+
+        let mut column = Column::new()
+            .push(text(
+                &self.title,
+                self.theme.text_size + 6.0,
+                self.theme.font.clone().weight(Weight::Bold),
+            ))
+            .spacing(12.0)
+            .item_alignment(Alignment::Center);
+
+        if let Some(message) = self.message.as_deref() {
+            column = column.push(text(message, self.theme.text_size, self.theme.font.clone()));
+        }
+
+        column = column.push(Row::new_with_children(buttons).spacing(8.0));
+
+        let widget = Container::new(column).padding(Padding::from(16.0)).style(
+            snowcap_api::widget::container::Style {
+                text_color: None,
+                background: Some(Background::Color(self.theme.background_color)),
+                border: Some(snowcap_api::widget::Border {
+                    color: Some(self.theme.border_color),
+                    width: Some(self.theme.border_thickness),
+                    radius: Some(self.theme.border_radius.into()),
+                }),
+            },
+        );
+
+        Some(widget.into())
+    }
+
+    fn signaler(&self) -> Option<Signaler> {
+        Some(self.base.signaler())
+    }
+}
+
+/// A dialog asking to confirm an action.
+///
+/// When opened, the cancel button is selected so an accidental ENTER doesn't confirm.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::snowcap::ConfirmDialog;
+/// ConfirmDialog::new("Quit Pinnacle?", pinnacle_api::pinnacle::quit)
+///     .show()
+///     .unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct ConfirmDialog {
+    /// The title of the dialog.
+    pub title: String,
+    /// Text shown below the title.
+    pub message: Option<String>,
+    /// The confirm button.
+    pub confirm: MenuButton,
+    /// The label of the cancel button.
+    pub cancel_label: String,
+    /// The dialog's theme.
+    pub theme: MenuTheme,
+}
+
+impl ConfirmDialog {
+    /// Creates a dialog titled `title` that runs `on_confirm` when confirmed.
+    pub fn new(title: impl ToString, on_confirm: impl Fn() + Send + Sync + 'static) -> Self {
+        Self {
+            title: title.to_string(),
+            message: None,
+            confirm: MenuButton::new("Confirm", on_confirm),
+            cancel_label: "Cancel".into(),
+            theme: MenuTheme::default(),
+        }
+    }
+
+    /// Sets the text shown below the title.
+    pub fn message(mut self, message: impl ToString) -> Self {
+        self.message = Some(message.to_string());
+        self
+    }
+
+    /// Sets the labels of the confirm and cancel buttons.
+    pub fn labels(mut self, confirm: impl ToString, cancel: impl ToString) -> Self {
+        self.confirm.label = confirm.to_string();
+        self.cancel_label = cancel.to_string();
+        self
+    }
+
+    /// Sets this dialog's theme.
+    pub fn theme(mut self, theme: MenuTheme) -> Self {
+        self.theme = theme;
+        self
+    }
+
+    /// Shows this dialog.
+    pub fn show(self) -> Result<LayerHandle<MenuMessage>, NewLayerError> {
+        self.menu().show()
+    }
+
+    fn menu(self) -> ButtonMenu {
+        ButtonMenu {
+            title: self.title,
+            message: self.message,
+            buttons: vec![self.confirm, MenuButton::new(self.cancel_label, || ())],
+            selected: 1,
+            theme: self.theme,
+            base: WidgetBase::new("ConfirmDialog"),
+        }
+    }
+}
+
+/// A menu to log out, suspend, reboot, or shut down.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::snowcap::{MenuButton, PowerMenu};
+/// let mut menu = PowerMenu::new();
+/// menu.buttons.insert(
+///     0,
+///     MenuButton::new("Lock", || {
+///         pinnacle_api::process::Command::new("swaylock").spawn();
+///     }),
+/// );
+/// menu.show().unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct PowerMenu {
+    /// The title of the menu.
+    pub title: String,
+    /// The menu's buttons, from left to right.
+    pub buttons: Vec<MenuButton>,
+    /// Whether pressing a button asks for confirmation first.
+    pub confirm: bool,
+    /// The menu's theme.
+    pub theme: MenuTheme,
+}
+
+impl Default for PowerMenu {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PowerMenu {
+    /// Creates a power menu with buttons to log out, suspend, reboot, and shut down.
+    ///
+    /// Suspend, reboot, and shut down use `systemctl`.
+    pub fn new() -> Self {
+        let systemctl = |verb: &'static str| {
+            move || {
+                crate::process::Command::new("systemctl").arg(verb).spawn();
+            }
+        };
+
+        Self {
+            title: "Power".into(),
+            buttons: vec![
+                MenuButton::new("Log out", crate::pinnacle::quit),
+                MenuButton::new("Suspend", systemctl("suspend")),
+                MenuButton::new("Reboot", systemctl("reboot")),
+                MenuButton::new("Shut down", systemctl("poweroff")),
+            ],
+            confirm: true,
+            theme: MenuTheme::default(),
+        }
+    }
+
+    /// Shows this menu.
+    pub fn show(self) -> Result<LayerHandle<MenuMessage>, NewLayerError> {
+        self.menu().show()
+    }
+
+    fn menu(self) -> ButtonMenu {
+        let buttons = if self.confirm {
+            self.buttons
+                .into_iter()
+                .map(|button| {
+                    let theme = self.theme.clone();
+                    let label = button.label.clone();
+                    MenuButton::new(label, move || {
+                        let dialog = ConfirmDialog {
+                            title: format!("{}?", button.label),
+                            message: None,
+                            confirm: button.clone(),
+                            cancel_label: "Cancel".into(),
+                            theme: theme.clone(),
+                        };
+                        let _ = dialog.show();
+                    })
+                })
+                .collect()
+        } else {
+            self.buttons
+        };
+
+        ButtonMenu {
+            title: self.title,
+            message: None,
+            buttons,
+            selected: 0,
+            theme: self.theme,
+            base: WidgetBase::new("PowerMenu"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use snowcap_api::widget::Widget;
+
+    use crate::{pager::PagerTag, util::Size};
+
+    use super::*;
+
+    /// Returns all text in `widget`, in order.
+    fn texts<Msg>(widget: &WidgetDef<Msg>) -> Vec<String> {
+        match &widget.widget {
+            Widget::Text(text) => vec![text.text.clone()],
+            Widget::Column(column) => column.children.iter().flat_map(texts).collect(),
+            Widget::Row(row) => row.children.iter().flat_map(texts).collect(),
+            Widget::Stack(stack) => stack.children.iter().flat_map(texts).collect(),
+            Widget::Scrollable(scrollable) => texts(&scrollable.child),
+            Widget::Container(container) => texts(&container.child),
+            Widget::Button(button) => texts(&button.child),
+            Widget::InputRegion(input_region) => texts(&input_region.child),
+            Widget::MouseArea(mouse_area) => texts(&mouse_area.child),
+            Widget::Pin(pin) => texts(&pin.child),
+            Widget::Image(_) | Widget::TextInput(_) => Vec::new(),
+        }
+    }
+
+    /// Returns the ids of all containers in `widget`, in order.
+    fn container_ids<Msg>(widget: &WidgetDef<Msg>) -> Vec<String> {
+        match &widget.widget {
+            Widget::Container(container) => container
+                .id
+                .iter()
+                .cloned()
+                .chain(container_ids(&container.child))
+                .collect(),
+            Widget::Column(column) => column.children.iter().flat_map(container_ids).collect(),
+            Widget::Row(row) => row.children.iter().flat_map(container_ids).collect(),
+            Widget::Stack(stack) => stack.children.iter().flat_map(container_ids).collect(),
+            Widget::Scrollable(scrollable) => container_ids(&scrollable.child),
+            Widget::Button(button) => container_ids(&button.child),
+            Widget::InputRegion(input_region) => container_ids(&input_region.child),
+            Widget::MouseArea(mouse_area) => container_ids(&mouse_area.child),
+            Widget::Pin(pin) => container_ids(&pin.child),
+            Widget::Text(_) | Widget::Image(_) | Widget::TextInput(_) => Vec::new(),
+        }
+    }
+
+    /// Returns the text in each of a bar's slots.
+    fn bar_slots(bar: &Bar) -> [Vec<String>; 3] {
+        let view = bar.view().unwrap();
+        let Widget::Container(container) = view.widget else {
+            panic!("bar isn't in a container");
+        };
+        let Widget::Row(row) = container.child.widget else {
+            panic!("bar slots aren't in a row");
+        };
+
+        let [left, center, right] = row.children.as_slice() else {
+            panic!("bar doesn't have three slots");
+        };
+
+        [texts(left), texts(center), texts(right)]
+    }
+
+    fn pager_output(name: &str, tags: &[(&str, bool)], title: Option<&str>) -> PagerOutput {
+        PagerOutput {
+            output: OutputHandle {
+                name: name.to_string(),
+            },
+            focused: false,
+            tags: tags
+                .iter()
+                .enumerate()
+                .map(|(id, (name, active))| PagerTag {
+                    tag: TagHandle { id: id as u32 },
+                    name: name.to_string(),
+                    active: *active,
+                    urgent: false,
+                    occupied: false,
+                    windows: Vec::new(),
+                })
+                .collect(),
+            focused_window: None,
+            focused_window_title: title.map(ToString::to_string),
+            size: Size { w: 1920, h: 1080 },
+        }
+    }
+
+    #[test]
+    fn bar_places_modules_in_their_slots() {
+        let bar = Bar::new()
+            .left(BarModule::Text("a".into()))
+            .left(BarModule::Text("b".into()))
+            .center(BarModule::Text("c".into()))
+            .right(BarModule::Text("d".into()));
+
+        assert_eq!(
+            bar_slots(&bar),
+            [
+                vec!["a".to_string(), "b".to_string()],
+                vec!["c".to_string()],
+                vec!["d".to_string()]
+            ]
+        );
+    }
+
+    #[test]
+    fn bar_modules_are_empty_until_they_have_state() {
+        let mut bar = Bar::new()
+            .left(BarModule::TagPager)
+            .center(BarModule::FocusedTitle)
+            .right(BarModule::Clock("%H:%M".into()))
+            .right(BarModule::Battery);
+
+        assert_eq!(
+            bar_slots(&bar),
+            [Vec::<String>::new(), Vec::new(), Vec::new()]
+        );
+
+        bar.update(BarMessage::Clock {
+            format: "%H:%M".into(),
+            time: "12:34".into(),
+        });
+        bar.update(BarMessage::Power(PowerStatus {
+            on_battery: false,
+            charging: true,
+            percentage: Some(50.0),
+            low_battery: false,
+        }));
+
+        assert_eq!(
+            bar_slots(&bar)[2],
+            ["12:34".to_string(), "50% (charging)".to_string()]
+        );
+    }
+
+    #[test]
+    fn bar_shows_the_pager_of_its_output() {
+        let mut bar = Bar::new()
+            .left(BarModule::TagPager)
+            .center(BarModule::FocusedTitle);
+        bar.output = Some(OutputHandle {
+            name: "DP-1".into(),
+        });
+
+        bar.update(BarMessage::Pager(Pager {
+            outputs: vec![
+                pager_output("HDMI-A-1", &[("9", true)], Some("other")),
+                pager_output("DP-1", &[("1", true), ("2", false)], Some("terminal")),
+            ],
+        }));
+
+        let [left, center, right] = bar_slots(&bar);
+        assert_eq!(left, ["1", "2"]);
+        assert_eq!(center, ["terminal"]);
+        assert!(right.is_empty());
+    }
+
+    #[test]
+    fn auto_hiding_bar_shows_modules_only_while_revealed() {
+        let mut bar = Bar::new().left(BarModule::Text("a".into())).auto_hide(true);
+
+        assert!(texts(&bar.view().unwrap()).is_empty());
+
+        bar.update(BarMessage::Reveal);
+        let Widget::MouseArea(mouse_area) = bar.view().unwrap().widget else {
+            panic!("revealed bar isn't in a mouse area");
+        };
+        assert_eq!(texts(&mouse_area.child), ["a"]);
+
+        bar.update(BarMessage::Hide);
+        assert!(texts(&bar.view().unwrap()).is_empty());
+    }
+
+    #[test]
+    fn bar_tag_previews_are_anchored_at_each_tag() {
+        let mut bar = Bar::new().left(BarModule::TagPager);
+        bar.output = Some(OutputHandle {
+            name: "DP-1".into(),
+        });
+        bar.update(BarMessage::Pager(Pager {
+            outputs: vec![pager_output("DP-1", &[("1", true), ("2", false)], None)],
+        }));
+
+        let tag_anchors = |bar: &Bar| {
+            container_ids(&bar.view().unwrap())
+                .into_iter()
+                .filter(|id| id.starts_with("pinnacle-bar-tag-"))
+                .collect::<Vec<_>>()
+        };
+
+        assert!(tag_anchors(&bar).is_empty());
+
+        let mut bar = bar.tag_previews(true);
+        assert_eq!(
+            tag_anchors(&bar),
+            ["pinnacle-bar-tag-0", "pinnacle-bar-tag-1"]
+        );
+
+        let preview = bar.preview_pager().unwrap();
+        assert_eq!(
+            preview.tags.iter().map(|tag| &tag.name).collect::<Vec<_>>(),
+            ["1", "2"]
+        );
+        assert!(preview.window_thumbnails);
+
+        // Previews open from the bar's surface, so there are none before it's created
+        bar.update(BarMessage::PreviewTag(Some(TagHandle { id: 0 })));
+        assert!(bar.preview.is_none());
+    }
+
+    /// Returns a button that counts its presses.
+    fn counting_button(label: &str) -> (MenuButton, Arc<AtomicUsize>) {
+        let presses = Arc::new(AtomicUsize::new(0));
+        let button = MenuButton::new(label, {
+            let presses = presses.clone();
+            move || {
+                presses.fetch_add(1, Ordering::Relaxed);
+            }
+        });
+        (button, presses)
+    }
+
+    #[test]
+    fn menu_keys_map_to_messages() {
+        let shift = Modifiers {
+            shift: true,
+            ..Default::default()
+        };
+
+        let cases = [
+            (
+                Keysym::Right,
+                Modifiers::default(),
+                Some(MenuMessage::SelectNext),
+            ),
+            (
+                Keysym::Tab,
+                Modifiers::default(),
+                Some(MenuMessage::SelectNext),
+            ),
+            (
+                Keysym::Left,
+                Modifiers::default(),
+                Some(MenuMessage::SelectPrevious),
+            ),
+            (Keysym::Tab, shift, Some(MenuMessage::SelectPrevious)),
+            (
+                Keysym::ISO_Left_Tab,
+                shift,
+                Some(MenuMessage::SelectPrevious),
+            ),
+            (
+                Keysym::Return,
+                Modifiers::default(),
+                Some(MenuMessage::Activate),
+            ),
+            (
+                Keysym::space,
+                Modifiers::default(),
+                Some(MenuMessage::Activate),
+            ),
+            (
+                Keysym::Escape,
+                Modifiers::default(),
+                Some(MenuMessage::Close),
+            ),
+            (Keysym::a, Modifiers::default(), None),
+        ];
+
+        for (key, mods, msg) in cases {
+            assert_eq!(ButtonMenu::key_message(key, mods), msg, "{key:?}");
+        }
+    }
+
+    #[test]
+    fn confirm_dialog_starts_on_cancel() {
+        let (confirm, presses) = counting_button("unused");
+        let mut dialog = ConfirmDialog {
+            confirm,
+            ..ConfirmDialog::new("Quit?", || ())
+        }
+        .message("Unsaved work will be lost")
+        .labels("Quit", "Stay")
+        .menu();
+
+        assert_eq!(
+            texts(&dialog.view().unwrap()),
+            ["Quit?", "Unsaved work will be lost", "Quit", "Stay"]
+        );
+
+        dialog.update(MenuMessage::Activate);
+        assert_eq!(presses.load(Ordering::Relaxed), 0);
+    }
+
+    #[test]
+    fn confirm_dialog_navigates_to_confirm() {
+        let (confirm, presses) = counting_button("Quit");
+        let mut dialog = ConfirmDialog {
+            confirm,
+            ..ConfirmDialog::new("Quit?", || ())
+        }
+        .menu();
+
+        // Selection wraps around
+        dialog.update(MenuMessage::SelectNext);
+        assert_eq!(dialog.selected, 0);
+        dialog.update(MenuMessage::SelectPrevious);
+        assert_eq!(dialog.selected, 1);
+        dialog.update(MenuMessage::SelectPrevious);
+        assert_eq!(dialog.selected, 0);
+
+        dialog.update(MenuMessage::Activate);
+        assert_eq!(presses.load(Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    fn power_menu_presses_buttons_directly_without_confirmation() {
+        let (lock, lock_presses) = counting_button("Lock");
+        let (suspend, suspend_presses) = counting_button("Suspend");
+
+        let mut menu = PowerMenu {
+            buttons: vec![lock, suspend],
+            confirm: false,
+            ..PowerMenu::new()
+        }
+        .menu();
+
+        assert_eq!(texts(&menu.view().unwrap()), ["Power", "Lock", "Suspend"]);
+        assert_eq!(menu.selected, 0);
+
+        menu.update(MenuMessage::Press(1));
+        assert_eq!(lock_presses.load(Ordering::Relaxed), 0);
+        assert_eq!(suspend_presses.load(Ordering::Relaxed), 1);
+
+        menu.update(MenuMessage::Activate);
+        assert_eq!(lock_presses.load(Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    fn power_menu_asks_for_confirmation_before_pressing() {
+        let (lock, presses) = counting_button("Lock");
+        let on_press = lock.on_press.clone();
+
+        let menu = PowerMenu {
+            buttons: vec![lock],
+            ..PowerMenu::new()
+        }
+        .menu();
+
+        // Pressing would open a dialog, so only check that the button was wrapped
+        assert_eq!(texts(&menu.view().unwrap()), ["Power", "Lock"]);
+        assert!(!Arc::ptr_eq(&menu.buttons[0].on_press, &on_press));
+        assert_eq!(presses.load(Ordering::Relaxed), 0);
+    }
+}
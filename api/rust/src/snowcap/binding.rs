@@ -0,0 +1,311 @@
+//! Bindings that keep Snowcap widgets up to date with compositor state.
+//!
+//! A binding connects to the signals and streams a piece of state needs, then sends
+//! a message to a widget every time that state changes. The current state is sent
+//! right away, so programs don't need to query it themselves.
+//!
+//! Bindings keep running until they are [unbound][Binding::unbind], which is usually
+//! done when the widget closes.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! # use pinnacle_api::snowcap::binding;
+//! # use snowcap_api::widget::{Program, WidgetDef, text::Text};
+//! # use snowcap_api::layer::{ExclusiveZone, KeyboardInteractivity, ZLayer};
+//! #[derive(Default)]
+//! struct TitleBar {
+//!     title: Option<String>,
+//! }
+//!
+//! #[derive(Clone)]
+//! enum Message {
+//!     Title(Option<String>),
+//! }
+//!
+//! impl Program for TitleBar {
+//!     type Message = Message;
+//!
+//!     fn update(&mut self, msg: Message) {
+//!         let Message::Title(title) = msg;
+//!         self.title = title;
+//!     }
+//!
+//!     fn view(&self) -> Option<WidgetDef<Message>> {
+//!         Some(Text::new(self.title.clone().unwrap_or_default()).into())
+//!     }
+//! }
+//!
+//! let layer = snowcap_api::layer::new_widget(
+//!     TitleBar::default(),
+//!     None,
+//!     KeyboardInteractivity::None,
+//!     ExclusiveZone::Respect,
+//!     ZLayer::Top,
+//! )
+//! .unwrap();
+//!
+//! let binding = binding::bind_focused_title(&layer, Message::Title);
+//! ```
+
+use std::{sync::Arc, time::Duration};
+
+use futures::StreamExt;
+use snowcap_api::{decoration::DecorationHandle, layer::LayerHandle, popup::PopupHandle};
+
+use crate::{
+    output::{OutputHandle, ScreenEdge},
+    pager::{Pager, PagerTag},
+    power::PowerStatus,
+    signal::{OutputSignal, PowerSignal, SignalHandle},
+};
+
+/// A widget that bindings can send messages to.
+pub trait BindTarget<Msg>: Clone + Send + 'static {
+    /// Sends a message to this widget.
+    fn send(&self, message: Msg);
+}
+
+impl<Msg: Send + 'static> BindTarget<Msg> for LayerHandle<Msg> {
+    fn send(&self, message: Msg) {
+        self.send_message(message);
+    }
+}
+
+impl<Msg: Send + 'static> BindTarget<Msg> for DecorationHandle<Msg> {
+    fn send(&self, message: Msg) {
+        self.send_message(message);
+    }
+}
+
+impl<Msg: Send + 'static> BindTarget<Msg> for PopupHandle<Msg> {
+    fn send(&self, message: Msg) {
+        self.send_message(message);
+    }
+}
+
+/// A running binding.
+///
+/// The binding keeps updating its widget until [`Binding::unbind`] is called.
+#[derive(Debug, Default)]
+#[must_use]
+pub struct Binding {
+    join_handles: Vec<tokio::task::JoinHandle<()>>,
+    signals: Vec<SignalHandle>,
+}
+
+impl Binding {
+    /// Stops this binding from updating its widget.
+    pub fn unbind(self) {
+        for join_handle in self.join_handles {
+            join_handle.abort();
+        }
+        for signal in self.signals {
+            signal.disconnect();
+        }
+    }
+}
+
+/// Sends the [`Pager`] state of all outputs to `target` every time it changes.
+pub fn bind_pager<Msg: 'static>(
+    target: &impl BindTarget<Msg>,
+    message: impl Fn(Pager) -> Msg + Send + 'static,
+) -> Binding {
+    bind_pager_with(target, move |pager| Some(message(pager.clone())))
+}
+
+/// Sends the tags on `output` to `target` every time they change.
+///
+/// The tags are empty when the output is disconnected.
+pub fn bind_tag_pager<Msg: 'static>(
+    target: &impl BindTarget<Msg>,
+    output: &OutputHandle,
+    message: impl Fn(Vec<PagerTag>) -> Msg + Send + 'static,
+) -> Binding {
+    let output = output.clone();
+    let mut last = None;
+
+    bind_pager_with(target, move |pager| {
+        let tags = pager
+            .outputs
+            .iter()
+            .find(|pager_output| pager_output.output == output)
+            .map(|pager_output| pager_output.tags.clone())
+            .unwrap_or_default();
+
+        changed(&mut last, tags).map(&message)
+    })
+}
+
+/// Sends the title of the focused window on the focused output to `target`
+/// every time it changes.
+pub fn bind_focused_title<Msg: 'static>(
+    target: &impl BindTarget<Msg>,
+    message: impl Fn(Option<String>) -> Msg + Send + 'static,
+) -> Binding {
+    let mut last = None;
+
+    bind_pager_with(target, move |pager| {
+        let title = pager
+            .outputs
+            .iter()
+            .find(|pager_output| pager_output.focused)
+            .and_then(|pager_output| pager_output.focused_window_title.clone());
+
+        changed(&mut last, title).map(&message)
+    })
+}
+
+/// Sends the [`PowerStatus`] to `target` when the power source changes,
+/// and every 30 seconds for the battery's charge.
+pub fn bind_power<Msg: 'static>(
+    target: &impl BindTarget<Msg>,
+    message: impl Fn(PowerStatus) -> Msg + Send + Sync + 'static,
+) -> Binding {
+    let message = Arc::new(message);
+
+    let poll = tokio::spawn({
+        let target = target.clone();
+        let message = message.clone();
+        async move {
+            loop {
+                target.send(message(crate::power::status_async().await));
+                tokio::time::sleep(Duration::from_secs(30)).await;
+            }
+        }
+    });
+
+    let send_status = {
+        let target = target.clone();
+        move || target.send(message(crate::power::status()))
+    };
+
+    // Power source changes are sent right away instead of on the next poll
+    Binding {
+        join_handles: vec![poll],
+        signals: vec![
+            crate::power::connect_signal(PowerSignal::OnBattery(Box::new({
+                let send_status = send_status.clone();
+                move |_| send_status()
+            }))),
+            crate::power::connect_signal(PowerSignal::Charging(Box::new(move |_| send_status()))),
+        ],
+    }
+}
+
+/// Sends the current time, formatted with a `date(1)` format string like `"%H:%M"`,
+/// to `target` every time it changes.
+pub fn bind_clock<Msg: 'static>(
+    target: &impl BindTarget<Msg>,
+    format: impl ToString,
+    message: impl Fn(String) -> Msg + Send + 'static,
+) -> Binding {
+    let target = target.clone();
+    let format = format.to_string();
+
+    let join_handle = tokio::spawn(async move {
+        loop {
+            if let Some(time) = format_time(&format).await {
+                target.send(message(time));
+            }
+            tokio::time::sleep(clock_tick(&format)).await;
+        }
+    });
+
+    Binding {
+        join_handles: vec![join_handle],
+        signals: Vec::new(),
+    }
+}
+
+/// Sends the edge the pointer hit to `target` every time it hits one of `edges` on `output`.
+///
+/// Nothing is sent right away, as the pointer isn't touching an edge until it moves there.
+pub fn bind_screen_edge<Msg: 'static>(
+    target: &impl BindTarget<Msg>,
+    output: &OutputHandle,
+    edges: impl IntoIterator<Item = ScreenEdge>,
+    message: impl Fn(ScreenEdge) -> Msg + Send + 'static,
+) -> Binding {
+    let target = target.clone();
+    let output = output.clone();
+    let edges = edges.into_iter().collect::<Vec<_>>();
+
+    Binding {
+        join_handles: Vec::new(),
+        signals: vec![crate::output::connect_signal(OutputSignal::PointerEdge(
+            Box::new(move |edge_output, edge| {
+                if *edge_output == output && edges.contains(&edge) {
+                    target.send(message(edge));
+                }
+            }),
+        ))],
+    }
+}
+
+/// Streams the pager state, sending whatever `message` returns to `target`.
+fn bind_pager_with<Msg: 'static>(
+    target: &impl BindTarget<Msg>,
+    mut message: impl FnMut(&Pager) -> Option<Msg> + Send + 'static,
+) -> Binding {
+    let target = target.clone();
+
+    let join_handle = tokio::spawn(async move {
+        let mut pager = crate::pager::stream_async().await;
+        while let Some(pager) = pager.next().await {
+            if let Some(message) = message(&pager) {
+                target.send(message);
+            }
+        }
+    });
+
+    Binding {
+        join_handles: vec![join_handle],
+        signals: Vec::new(),
+    }
+}
+
+/// Returns `value` if it differs from the last value, remembering it.
+fn changed<T: Clone + PartialEq>(last: &mut Option<T>, value: T) -> Option<T> {
+    if last.as_ref() == Some(&value) {
+        return None;
+    }
+
+    *last = Some(value.clone());
+    Some(value)
+}
+
+/// Formats the current local time with `date`.
+async fn format_time(format: &str) -> Option<String> {
+    let output = tokio::process::Command::new("date")
+        .arg(format!("+{format}"))
+        .output()
+        .await
+        .ok()?;
+
+    Some(
+        String::from_utf8_lossy(&output.stdout)
+            .trim_end()
+            .to_string(),
+    )
+}
+
+/// Returns how long until a clock with `format` next needs to update.
+///
+/// Clocks that don't show seconds update at the start of every minute.
+fn clock_tick(format: &str) -> Duration {
+    const SECONDS_SPECIFIERS: [&str; 5] = ["%S", "%s", "%T", "%r", "%c"];
+
+    if SECONDS_SPECIFIERS
+        .iter()
+        .any(|specifier| format.contains(specifier))
+    {
+        return Duration::from_secs(1);
+    }
+
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default();
+
+    Duration::from_secs(60 - now.as_secs() % 60)
+}
@@ -0,0 +1,364 @@
+//! A pager widget that shows a miniature of each tag.
+//!
+//! Each tag is drawn as a scaled-down copy of its output, with its windows where
+//! they really are. Windows can be drawn as plain rectangles or as live thumbnails.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! # use pinnacle_api::pager::PagerOutput;
+//! # use pinnacle_api::snowcap::pager::Pager;
+//! # use pinnacle_api::tag::TagHandle;
+//! # use snowcap_api::widget::WidgetDef;
+//! # fn view(output: &PagerOutput) -> WidgetDef<TagHandle> {
+//! Pager::new(output)
+//!     .tag_width(160.0)
+//!     .window_thumbnails(true)
+//!     .view(|tag| tag)
+//! # }
+//! ```
+
+use snowcap_api::widget::{
+    Alignment, Background, Border, Color, Length, WidgetDef,
+    column::Column,
+    container::{self, Container},
+    font::{Family, Font},
+    image::{Handle, Image, ThumbnailSource},
+    mouse_area::MouseArea,
+    pin::Pin,
+    row::Row,
+    stack::Stack,
+    text::{self, Text},
+};
+
+use crate::{
+    pager::{PagerOutput, PagerTag, PagerWindow},
+    tag::TagHandle,
+    util::Size,
+};
+
+/// The colors and font of a [`Pager`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct PagerTheme {
+    /// The space between tags.
+    pub spacing: f32,
+    /// The font of tag names.
+    pub font: Font,
+    /// The size of tag names, in pixels.
+    pub text_size: f32,
+    /// The color of tag names.
+    pub text_color: Color,
+    /// The background color of tags.
+    pub tag_color: Color,
+    /// The border color of active tags.
+    pub active_tag_color: Color,
+    /// The border color of tags with a window that demands attention.
+    pub urgent_tag_color: Color,
+    /// The color of windows drawn without thumbnails.
+    pub window_color: Color,
+    /// The border color of windows.
+    pub window_border_color: Color,
+}
+
+impl Default for PagerTheme {
+    fn default() -> Self {
+        Self {
+            spacing: 8.0,
+            font: Font::new_with_family(Family::Name("Ubuntu".into())),
+            text_size: 12.0,
+            text_color: [0.9, 0.9, 0.9].into(),
+            tag_color: [0.1, 0.1, 0.15, 0.9].into(),
+            active_tag_color: [0.4, 0.15, 0.7].into(),
+            urgent_tag_color: [0.8, 0.2, 0.4].into(),
+            window_color: [0.3, 0.3, 0.4].into(),
+            window_border_color: [0.6, 0.6, 0.7].into(),
+        }
+    }
+}
+
+/// A pager that shows a miniature of each tag on an output.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Pager {
+    /// The tags to show.
+    pub tags: Vec<PagerTag>,
+    /// The logical size of the tags' output.
+    pub output_size: Size,
+    /// The width of each tag's miniature. The height follows the output's aspect ratio.
+    pub tag_width: f32,
+    /// Whether windows are drawn as live thumbnails instead of rectangles.
+    pub window_thumbnails: bool,
+    /// The pager's theme.
+    pub theme: PagerTheme,
+}
+
+impl Pager {
+    /// Creates a pager for the tags on an output.
+    pub fn new(output: &PagerOutput) -> Self {
+        Self {
+            tags: output.tags.clone(),
+            output_size: output.size,
+            tag_width: 120.0,
+            window_thumbnails: false,
+            theme: PagerTheme::default(),
+        }
+    }
+
+    /// Sets the width of each tag's miniature.
+    pub fn tag_width(mut self, tag_width: f32) -> Self {
+        self.tag_width = tag_width;
+        self
+    }
+
+    /// Sets whether windows are drawn as live thumbnails instead of rectangles.
+    pub fn window_thumbnails(mut self, window_thumbnails: bool) -> Self {
+        self.window_thumbnails = window_thumbnails;
+        self
+    }
+
+    /// Sets this pager's theme.
+    pub fn theme(mut self, theme: PagerTheme) -> Self {
+        self.theme = theme;
+        self
+    }
+
+    /// Creates a row of every tag's miniature with its name below.
+    ///
+    /// Clicking a tag sends the message `on_press` returns.
+    pub fn view<Msg: Clone>(&self, on_press: impl Fn(TagHandle) -> Msg) -> WidgetDef<Msg> {
+        let tags = self.tags.iter().map(|tag| -> WidgetDef<Msg> {
+            let name = Text::new(&tag.name).style(
+                text::Style::new()
+                    .font(self.theme.font.clone())
+                    .pixels(self.theme.text_size)
+                    .color(self.theme.text_color),
+            );
+
+            let column = Column::new_with_children([self.tag_view(tag), name.into()])
+                .spacing(2.0)
+                .item_alignment(Alignment::Center);
+
+            MouseArea::new(column)
+                .on_press(on_press(tag.tag.clone()))
+                .into()
+        });
+
+        Row::new_with_children(tags)
+            .spacing(self.theme.spacing)
+            .into()
+    }
+
+    /// Creates the miniature of a single tag.
+    pub fn tag_view<Msg>(&self, tag: &PagerTag) -> WidgetDef<Msg> {
+        let scale = self.scale();
+
+        let windows = tag
+            .windows
+            .iter()
+            .filter_map(|window| self.window_view(window, scale));
+
+        let border_color = if tag.urgent {
+            Some(self.theme.urgent_tag_color)
+        } else if tag.active {
+            Some(self.theme.active_tag_color)
+        } else {
+            None
+        };
+
+        Container::new(
+            Stack::new_with_children(windows)
+                .width(Length::Fill)
+                .height(Length::Fill),
+        )
+        .width(Length::Fixed(self.tag_width))
+        .height(Length::Fixed(self.output_size.h as f32 * scale))
+        .clip(true)
+        .style(container::Style {
+            text_color: None,
+            background: Some(Background::Color(self.theme.tag_color)),
+            border: border_color.map(|color| Border {
+                color: Some(color),
+                width: Some(2.0),
+                radius: None,
+            }),
+        })
+        .into()
+    }
+
+    /// Creates a window's rectangle, or `None` if it hasn't been shown yet.
+    fn window_view<Msg>(&self, window: &PagerWindow, scale: f32) -> Option<WidgetDef<Msg>> {
+        let geometry = window.geometry?;
+
+        let width = geometry.size.w as f32 * scale;
+        let height = geometry.size.h as f32 * scale;
+
+        let child: WidgetDef<Msg> = if self.window_thumbnails {
+            Image::new(Handle::Thumbnail {
+                source: ThumbnailSource::Window(window.window.id()),
+                max_width: width.ceil() as u32,
+                max_height: height.ceil() as u32,
+            })
+            .width(Length::Fixed(width))
+            .height(Length::Fixed(height))
+            .into()
+        } else {
+            Container::new(Row::new())
+                .width(Length::Fixed(width))
+                .height(Length::Fixed(height))
+                .style(container::Style {
+                    text_color: None,
+                    background: Some(Background::Color(self.theme.window_color)),
+                    border: Some(Border {
+                        color: Some(self.theme.window_border_color),
+                        width: Some(1.0),
+                        radius: None,
+                    }),
+                })
+                .into()
+        };
+
+        Some(
+            Pin::new(child)
+                .position(geometry.loc.x as f32 * scale, geometry.loc.y as f32 * scale)
+                .into(),
+        )
+    }
+
+    /// Returns how much the output is scaled down in each miniature.
+    fn scale(&self) -> f32 {
+        if self.output_size.w == 0 {
+            return 0.0;
+        }
+
+        self.tag_width / self.output_size.w as f32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use snowcap_api::widget::Widget;
+
+    use crate::{
+        output::OutputHandle,
+        util::{Point, Rect},
+        window::WindowHandle,
+    };
+
+    use super::*;
+
+    fn pager_window(id: u32, geometry: Option<Rect>) -> PagerWindow {
+        PagerWindow {
+            window: WindowHandle { id },
+            title: String::new(),
+            app_id: String::new(),
+            geometry,
+        }
+    }
+
+    fn pager_tag(windows: Vec<PagerWindow>) -> PagerTag {
+        PagerTag {
+            tag: TagHandle { id: 1 },
+            name: "1".into(),
+            active: true,
+            urgent: false,
+            occupied: !windows.is_empty(),
+            windows,
+        }
+    }
+
+    fn pager(tag: PagerTag) -> Pager {
+        Pager::new(&PagerOutput {
+            output: OutputHandle {
+                name: "DP-1".into(),
+            },
+            focused: true,
+            tags: vec![tag],
+            focused_window: None,
+            focused_window_title: None,
+            size: Size { w: 1920, h: 1080 },
+        })
+        .tag_width(192.0)
+    }
+
+    const GEOMETRY: Rect = Rect {
+        loc: Point { x: 100, y: 200 },
+        size: Size { w: 960, h: 540 },
+    };
+
+    /// Returns the container of a tag's miniature.
+    fn tag_container(pager: &Pager) -> Container<()> {
+        let Widget::Container(container) = pager.tag_view(&pager.tags[0]).widget else {
+            panic!("tag isn't in a container");
+        };
+        *container
+    }
+
+    /// Returns the windows pinned in a tag's miniature.
+    fn pinned_windows(pager: &Pager) -> Vec<Pin<()>> {
+        let Widget::Stack(stack) = tag_container(pager).child.widget else {
+            panic!("windows aren't in a stack");
+        };
+
+        stack
+            .children
+            .into_iter()
+            .map(|child| match child.widget {
+                Widget::Pin(pin) => *pin,
+                _ => panic!("window isn't pinned"),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn windows_are_scaled_to_tag_width() {
+        let pager = pager(pager_tag(vec![pager_window(7, Some(GEOMETRY))]));
+
+        assert_eq!(tag_container(&pager).height, Some(Length::Fixed(108.0)));
+
+        let [pin] = pinned_windows(&pager).try_into().unwrap();
+        assert_eq!((pin.x, pin.y), (10.0, 20.0));
+
+        let Widget::Container(window) = pin.child.widget else {
+            panic!("window isn't a rectangle");
+        };
+        assert_eq!(window.width, Some(Length::Fixed(96.0)));
+        assert_eq!(window.height, Some(Length::Fixed(54.0)));
+    }
+
+    #[test]
+    fn window_thumbnails_show_live_windows() {
+        let pager = pager(pager_tag(vec![pager_window(7, Some(GEOMETRY))])).window_thumbnails(true);
+
+        let [pin] = pinned_windows(&pager).try_into().unwrap();
+        let Widget::Image(image) = pin.child.widget else {
+            panic!("window isn't a thumbnail");
+        };
+        assert_eq!(
+            image.handle,
+            Handle::Thumbnail {
+                source: ThumbnailSource::Window(7),
+                max_width: 96,
+                max_height: 54,
+            }
+        );
+    }
+
+    #[test]
+    fn windows_that_were_never_shown_are_skipped() {
+        let pager = pager(pager_tag(vec![
+            pager_window(7, None),
+            pager_window(8, Some(GEOMETRY)),
+        ]));
+
+        assert_eq!(pinned_windows(&pager).len(), 1);
+    }
+
+    #[test]
+    fn urgent_tag_border_takes_precedence() {
+        let mut tag = pager_tag(Vec::new());
+        tag.urgent = true;
+        let pager = pager(tag);
+
+        let border = tag_container(&pager).style.unwrap().border.unwrap();
+        assert_eq!(border.color, Some(pager.theme.urgent_tag_color));
+    }
+}
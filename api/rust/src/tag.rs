@@ -23,19 +23,27 @@
 //! different "workspaces". As you can see, this system is much more powerful than workspaces
 //! alone.
 
+use std::time::Duration;
+
 use futures::FutureExt;
 use pinnacle_api_defs::pinnacle::{
-    tag::v1::{
-        AddRequest, GetActiveRequest, GetNameRequest, GetOutputNameRequest, GetRequest,
-        MoveToOutputRequest, RemoveRequest, SetActiveRequest, SwitchToRequest,
-        move_to_output_response::error::Kind,
+    tag::{
+        self,
+        v1::{
+            AddEffectRuleRequest, AddRequest, ExistsRequest, GetActiveRequest, GetNameRequest,
+            GetOutputNameRequest, GetRequest, GetUrgentRequest, MoveToIndexRequest,
+            MoveToOutputRequest, PeekRequest, RemoveRequest, SetActiveRequest,
+            SetEmptyOutputPolicyRequest, SwitchToMatchingRequest, SwitchToPreviousRequest,
+            SwitchToRequest, move_to_output_response::error::Kind, switch_to_matching_request,
+        },
     },
     util::v1::SetOrToggle,
 };
 
 use crate::{
-    BlockOnTokio,
+    BlockOnTokio, RequestError, RequestResultExt, ResponseExt,
     client::Client,
+    log_failed_request,
     output::OutputHandle,
     signal::{SignalHandle, TagSignal},
     util::Batch,
@@ -71,8 +79,7 @@ where
             tag_names,
         })
         .block_on_tokio()
-        .unwrap()
-        .into_inner()
+        .into_inner_or_default()
         .tag_ids
         .into_iter()
         .map(|id| TagHandle { id })
@@ -85,23 +92,36 @@ where
 /// ```no_run
 /// # use pinnacle_api::tag;
 /// for tag in tag::get_all() {
-///     println!("{}", tag.name());
+///     println!("{:?}", tag.name());
 /// }
 /// ```
 pub fn get_all() -> impl Iterator<Item = TagHandle> {
     get_all_async().block_on_tokio()
 }
 
-/// Async impl for [`get_all_async`].
+/// Async impl for [`get_all`].
 pub async fn get_all_async() -> impl Iterator<Item = TagHandle> {
-    Client::tag()
+    try_get_all_async()
+        .await
+        .inspect_err(log_failed_request)
+        .into_iter()
+        .flatten()
+}
+
+/// Like [`get_all`], but returns an error if the request to Pinnacle fails.
+pub fn try_get_all() -> Result<impl Iterator<Item = TagHandle>, RequestError> {
+    try_get_all_async().block_on_tokio()
+}
+
+/// Async impl for [`try_get_all`].
+pub async fn try_get_all_async() -> Result<impl Iterator<Item = TagHandle>, RequestError> {
+    Ok(Client::tag()
         .get(GetRequest {})
         .await
-        .unwrap()
-        .into_inner()
+        .into_result()?
         .tag_ids
         .into_iter()
-        .map(|id| TagHandle { id })
+        .map(|id| TagHandle { id }))
 }
 
 /// Gets a handle to the first tag with the given `name` on the focused output.
@@ -155,7 +175,7 @@ pub async fn get_on_output_async(name: impl ToString, output: &OutputHandle) ->
     let output = output.clone();
     get_all_async().await.batch_find(
         |tag| async { (tag.name_async().await, tag.output_async().await) }.boxed(),
-        |(n, op)| *n == name && *op == output,
+        |(n, op)| n.as_ref() == Some(&name) && op.as_ref() == Some(&output),
     )
 }
 
@@ -179,7 +199,7 @@ pub fn remove(tags: impl IntoIterator<Item = TagHandle>) {
     Client::tag()
         .remove(RemoveRequest { tag_ids })
         .block_on_tokio()
-        .unwrap();
+        .log_err();
 }
 
 /// Error that happens when moving tags to a different output.
@@ -191,6 +211,9 @@ pub enum MoveToOutputError {
     /// Moving the Tag to another output would result in having the same window in multiple tags.
     /// It contains a list of windows that would be on multiple outputs.
     SameWindowOnTwoOutputs(Vec<WindowHandle>),
+
+    /// The request to Pinnacle failed.
+    RequestFailed(RequestError),
 }
 
 /// Moves existing tags to the specified output.
@@ -220,8 +243,8 @@ where
             tag_ids,
         })
         .block_on_tokio()
-        .unwrap()
-        .into_inner()
+        .into_result()
+        .map_err(MoveToOutputError::RequestFailed)?
         .error
         .and_then(|error| error.kind);
 
@@ -240,6 +263,218 @@ where
     }
 }
 
+/// Switches to the tag at `index` on `output`, or the focused output if `None`.
+///
+/// `index` is zero-based and counts tags in the order they were added.
+/// This is useful for scripts and keybinds that don't keep track of tag handles.
+///
+/// Returns a handle to the tag that was switched to, or `None` if there is no such tag.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::output;
+/// # use pinnacle_api::tag;
+/// // Switch the focused output to its third tag
+/// tag::switch_to_index(None, 2);
+///
+/// // Switch "DP-1" to its first tag
+/// # || {
+/// tag::switch_to_index(Some(&output::get_by_name("DP-1")?), 0);
+/// # Some(())
+/// # };
+/// ```
+pub fn switch_to_index(output: Option<&OutputHandle>, index: u32) -> Option<TagHandle> {
+    switch_to_matching(output, switch_to_matching_request::Tag::Index(index))
+}
+
+/// Switches to the first tag named `name` on `output`, or the focused output if `None`.
+///
+/// Returns a handle to the tag that was switched to, or `None` if there is no such tag.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::tag;
+/// if tag::switch_to_name(None, "Browser").is_none() {
+///     println!("no tag named Browser");
+/// }
+/// ```
+pub fn switch_to_name(output: Option<&OutputHandle>, name: impl ToString) -> Option<TagHandle> {
+    switch_to_matching(
+        output,
+        switch_to_matching_request::Tag::Name(name.to_string()),
+    )
+}
+
+/// Switches `output`, or the focused output if `None`, back to the tags that were
+/// active before its last tag change.
+///
+/// Calling this repeatedly flips between the last two sets of tags, like `Mod+Tab`
+/// in awesome. Each output remembers its own history.
+///
+/// Returns handles to the tags that were switched to, which are empty if there
+/// was nothing to go back to.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::tag;
+/// tag::switch_to_previous(None);
+/// ```
+pub fn switch_to_previous(output: Option<&OutputHandle>) -> Vec<TagHandle> {
+    Client::tag()
+        .switch_to_previous(SwitchToPreviousRequest {
+            output_name: output.map(|op| op.name()),
+        })
+        .block_on_tokio()
+        .into_inner_or_default()
+        .tag_ids
+        .into_iter()
+        .map(|id| TagHandle { id })
+        .collect()
+}
+
+fn switch_to_matching(
+    output: Option<&OutputHandle>,
+    tag: switch_to_matching_request::Tag,
+) -> Option<TagHandle> {
+    Client::tag()
+        .switch_to_matching(SwitchToMatchingRequest {
+            output_name: output.map(|op| op.name()),
+            tag: Some(tag),
+        })
+        .block_on_tokio()
+        .into_inner_or_default()
+        .tag_id
+        .map(|id| TagHandle { id })
+}
+
+/// What an effect rule applies to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EffectTarget {
+    /// Apply to windows on the tag.
+    WindowsOnTag,
+    /// Apply to windows on the tag's output that aren't on the tag.
+    OtherWindows,
+}
+
+impl From<EffectTarget> for tag::v1::EffectRuleTarget {
+    fn from(value: EffectTarget) -> Self {
+        match value {
+            EffectTarget::WindowsOnTag => tag::v1::EffectRuleTarget::WindowsOnTag,
+            EffectTarget::OtherWindows => tag::v1::EffectRuleTarget::OtherWindows,
+        }
+    }
+}
+
+/// Opacity, dimming, and desaturation applied to windows by an effect rule.
+///
+/// All fields range from 0.0 to 1.0. The default leaves windows unchanged.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WindowEffect {
+    /// The opacity of windows.
+    pub opacity: f32,
+    /// How much to darken windows.
+    pub dim: f32,
+    /// How much to desaturate windows.
+    pub desaturate: f32,
+}
+
+impl Default for WindowEffect {
+    fn default() -> Self {
+        Self {
+            opacity: 1.0,
+            dim: 0.0,
+            desaturate: 0.0,
+        }
+    }
+}
+
+/// Adds an effect applied to windows while `tag` is active.
+///
+/// The compositor keeps effects in sync with the tag's active state itself,
+/// so windows change as soon as the tag is toggled.
+/// When multiple rules apply to a window, their effects stack.
+///
+/// Effect rules are cleared when the config reloads.
+/// This does nothing if `tag` doesn't exist.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::tag::{self, EffectTarget, WindowEffect};
+/// # || {
+/// // While "video" is active, make other windows on its output translucent
+/// tag::add_effect_rule(
+///     &tag::get("video")?,
+///     EffectTarget::OtherWindows,
+///     WindowEffect {
+///         opacity: 0.8,
+///         ..Default::default()
+///     },
+/// );
+/// # Some(())
+/// # };
+/// ```
+pub fn add_effect_rule(tag: &TagHandle, target: EffectTarget, effect: WindowEffect) {
+    Client::tag()
+        .add_effect_rule(AddEffectRuleRequest {
+            tag_id: tag.id,
+            target: tag::v1::EffectRuleTarget::from(target).into(),
+            opacity: effect.opacity,
+            dim: effect.dim,
+            desaturate: effect.desaturate,
+        })
+        .block_on_tokio()
+        .log_err();
+}
+
+/// What happens when an output is left with no active tags.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub enum EmptyOutputPolicy {
+    /// Nothing is shown but the background.
+    #[default]
+    Blank,
+    /// The most recently active tags are activated again, or the first tag
+    /// if there are none.
+    ReactivateLast,
+    /// The tag with this name is activated. Outputs without such a tag are left blank.
+    Fallback(String),
+}
+
+/// Sets what happens when an output is left with no active tags.
+///
+/// By default outputs are left blank, which can strand you on an output with nothing
+/// to focus. The policy applies as soon as an output has no active tags, so when
+/// switching tags by hand, activate the new ones before deactivating the old ones.
+///
+/// Outputs left with no active tags are also signaled with [`OutputSignal::NoActiveTags`].
+///
+/// [`OutputSignal::NoActiveTags`]: crate::signal::OutputSignal::NoActiveTags
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::tag::{self, EmptyOutputPolicy};
+/// tag::set_empty_output_policy(EmptyOutputPolicy::Fallback("1".into()));
+/// ```
+pub fn set_empty_output_policy(policy: EmptyOutputPolicy) {
+    let (policy, fallback_tag_name) = match policy {
+        EmptyOutputPolicy::Blank => (tag::v1::EmptyOutputPolicy::Blank, None),
+        EmptyOutputPolicy::ReactivateLast => (tag::v1::EmptyOutputPolicy::ReactivateLast, None),
+        EmptyOutputPolicy::Fallback(name) => (tag::v1::EmptyOutputPolicy::Fallback, Some(name)),
+    };
+
+    Client::tag()
+        .set_empty_output_policy(SetEmptyOutputPolicyRequest {
+            policy: policy.into(),
+            fallback_tag_name,
+        })
+        .block_on_tokio()
+        .log_err();
+}
+
 /// Connects to a [`TagSignal`].
 ///
 /// # Examples
@@ -258,6 +493,7 @@ pub fn connect_signal(signal: TagSignal) -> SignalHandle {
         TagSignal::Active(f) => signal_state.tag_active.add_callback(f),
         TagSignal::Created(f) => signal_state.tag_created.add_callback(f),
         TagSignal::Removed(f) => signal_state.tag_removed.add_callback(f),
+        TagSignal::UrgencyChanged(f) => signal_state.tag_urgency_changed.add_callback(f),
     }
 }
 
@@ -299,7 +535,64 @@ impl TagHandle {
         Client::tag()
             .switch_to(SwitchToRequest { tag_id })
             .block_on_tokio()
-            .unwrap();
+            .log_err();
+    }
+
+    /// Shows snapshots of this tag's windows over its output for `duration`
+    /// without switching to it.
+    ///
+    /// The active tags, layout, and focus stay the same, so this is useful to
+    /// glance at what's on another tag. Peeking at a different tag on the same output
+    /// before `duration` has passed replaces the current peek.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pinnacle_api::tag;
+    /// # use std::time::Duration;
+    /// # || {
+    /// tag::get("chat")?.peek(Duration::from_secs(2));
+    /// # Some(())
+    /// # };
+    /// ```
+    pub fn peek(&self, duration: Duration) {
+        let tag_id = self.id;
+
+        Client::tag()
+            .peek(PeekRequest {
+                tag_id,
+                millis: duration.as_millis().try_into().unwrap_or(u32::MAX),
+            })
+            .block_on_tokio()
+            .log_err();
+    }
+
+    /// Moves this tag to position `index` among the tags on its output.
+    ///
+    /// Tags keep this order everywhere they're listed, like in [`OutputHandle::tags`] and
+    /// the pager, so bars show them in the order the config chooses.
+    /// Positions past the last tag move this tag to the end.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pinnacle_api::tag;
+    /// # || {
+    /// // Make "chat" the first tag
+    /// tag::get("chat")?.move_to_index(0);
+    /// # Some(())
+    /// # };
+    /// ```
+    pub fn move_to_index(&self, index: usize) {
+        let tag_id = self.id;
+
+        Client::tag()
+            .move_to_index(MoveToIndexRequest {
+                tag_id,
+                index: index.try_into().unwrap_or(u32::MAX),
+            })
+            .block_on_tokio()
+            .log_err();
     }
 
     /// Sets this tag to active or not.
@@ -337,7 +630,7 @@ impl TagHandle {
                 .into(),
             })
             .block_on_tokio()
-            .unwrap();
+            .log_err();
     }
 
     /// Toggles this tag between active and inactive.
@@ -372,7 +665,7 @@ impl TagHandle {
                 set_or_toggle: SetOrToggle::Toggle.into(),
             })
             .block_on_tokio()
-            .unwrap();
+            .log_err();
     }
 
     /// Moves this tag to the specified output.
@@ -407,62 +700,158 @@ impl TagHandle {
                 tag_ids: vec![tag_id],
             })
             .block_on_tokio()
-            .unwrap();
+            .log_err();
+    }
+
+    /// Returns whether this tag still exists.
+    ///
+    /// Handles can outlive their tags, for example when the tag is removed or its
+    /// output is disconnected. Methods on such handles do nothing. Getters whose
+    /// values could be mistaken for a real one return `None`; the rest document
+    /// what they return instead.
+    pub fn exists(&self) -> bool {
+        self.exists_async().block_on_tokio()
+    }
+
+    /// Async impl for [`Self::exists`].
+    pub async fn exists_async(&self) -> bool {
+        self.try_exists_async().await.unwrap_or_log()
+    }
+
+    /// Like [`Self::exists`], but returns an error if the request to Pinnacle fails.
+    pub fn try_exists(&self) -> Result<bool, RequestError> {
+        self.try_exists_async().block_on_tokio()
+    }
+
+    /// Async impl for [`Self::try_exists`].
+    pub async fn try_exists_async(&self) -> Result<bool, RequestError> {
+        let tag_id = self.id;
+
+        Ok(Client::tag()
+            .exists(ExistsRequest { tag_id })
+            .await
+            .into_result()?
+            .exists)
     }
 
     /// Gets whether or not this tag is active.
+    ///
+    /// Returns `false` if this tag doesn't exist, as a removed tag displays nothing.
+    /// Use [`Self::exists`] to tell the two apart.
     pub fn active(&self) -> bool {
         self.active_async().block_on_tokio()
     }
 
     /// Async impl for [`Self::active`].
     pub async fn active_async(&self) -> bool {
+        self.try_active_async().await.unwrap_or_log()
+    }
+
+    /// Like [`Self::active`], but returns an error if the request to Pinnacle fails.
+    pub fn try_active(&self) -> Result<bool, RequestError> {
+        self.try_active_async().block_on_tokio()
+    }
+
+    /// Async impl for [`Self::try_active`].
+    pub async fn try_active_async(&self) -> Result<bool, RequestError> {
         let tag_id = self.id;
 
-        Client::tag()
+        Ok(Client::tag()
             .get_active(GetActiveRequest { tag_id })
             .await
-            .unwrap()
-            .into_inner()
-            .active
+            .into_result()?
+            .active)
+    }
+
+    /// Gets whether a window on this tag requested attention while the tag was inactive.
+    ///
+    /// Urgency is cleared when the tag is activated.
+    /// Returns `false` if this tag doesn't exist, as no window can request attention on it.
+    pub fn urgent(&self) -> bool {
+        self.urgent_async().block_on_tokio()
+    }
+
+    /// Async impl for [`Self::urgent`].
+    pub async fn urgent_async(&self) -> bool {
+        self.try_urgent_async().await.unwrap_or_log()
+    }
+
+    /// Like [`Self::urgent`], but returns an error if the request to Pinnacle fails.
+    pub fn try_urgent(&self) -> Result<bool, RequestError> {
+        self.try_urgent_async().block_on_tokio()
+    }
+
+    /// Async impl for [`Self::try_urgent`].
+    pub async fn try_urgent_async(&self) -> Result<bool, RequestError> {
+        let tag_id = self.id;
+
+        Ok(Client::tag()
+            .get_urgent(GetUrgentRequest { tag_id })
+            .await
+            .into_result()?
+            .urgent)
     }
 
     /// Gets this tag's name.
-    pub fn name(&self) -> String {
+    ///
+    /// Returns `None` if this tag doesn't exist.
+    pub fn name(&self) -> Option<String> {
         self.name_async().block_on_tokio()
     }
 
     /// Async impl for [`Self::name`].
-    pub async fn name_async(&self) -> String {
+    pub async fn name_async(&self) -> Option<String> {
+        self.try_name_async().await.unwrap_or_log()
+    }
+
+    /// Like [`Self::name`], but returns an error if the request to Pinnacle fails.
+    pub fn try_name(&self) -> Result<Option<String>, RequestError> {
+        self.try_name_async().block_on_tokio()
+    }
+
+    /// Async impl for [`Self::try_name`].
+    pub async fn try_name_async(&self) -> Result<Option<String>, RequestError> {
         let tag_id = self.id;
 
-        Client::tag()
+        Ok(Client::tag()
             .get_name(GetNameRequest { tag_id })
             .await
-            .unwrap()
-            .into_inner()
-            .name
+            .into_result()?
+            .name)
     }
 
     /// Gets a handle to the output this tag is on.
-    pub fn output(&self) -> OutputHandle {
+    ///
+    /// Returns `None` if this tag doesn't exist.
+    pub fn output(&self) -> Option<OutputHandle> {
         self.output_async().block_on_tokio()
     }
 
     /// Async impl for [`Self::output`].
-    pub async fn output_async(&self) -> OutputHandle {
+    pub async fn output_async(&self) -> Option<OutputHandle> {
+        self.try_output_async().await.unwrap_or_log()
+    }
+
+    /// Like [`Self::output`], but returns an error if the request to Pinnacle fails.
+    pub fn try_output(&self) -> Result<Option<OutputHandle>, RequestError> {
+        self.try_output_async().block_on_tokio()
+    }
+
+    /// Async impl for [`Self::try_output`].
+    pub async fn try_output_async(&self) -> Result<Option<OutputHandle>, RequestError> {
         let tag_id = self.id;
 
-        let name = Client::tag()
+        let output_name = Client::tag()
             .get_output_name(GetOutputNameRequest { tag_id })
             .await
-            .unwrap()
-            .into_inner()
+            .into_result()?
             .output_name;
-        OutputHandle { name }
+        Ok(output_name.map(|name| OutputHandle { name }))
     }
 
     /// Gets all windows with this tag.
+    ///
+    /// Yields nothing if this tag doesn't exist, as no window can have it.
     pub fn windows(&self) -> impl Iterator<Item = WindowHandle> + use<> {
         self.windows_async().block_on_tokio()
     }
@@ -7,6 +7,7 @@
 use std::pin::Pin;
 
 use futures::{Future, StreamExt, stream::FuturesOrdered};
+use tokio::task::JoinHandle;
 
 use crate::BlockOnTokio;
 pub use crate::batch_boxed;
@@ -234,6 +235,15 @@ impl From<Point> for pinnacle_api_defs::pinnacle::util::v1::Point {
     }
 }
 
+impl From<pinnacle_api_defs::pinnacle::util::v1::Point> for Point {
+    fn from(value: pinnacle_api_defs::pinnacle::util::v1::Point) -> Self {
+        Self {
+            x: value.x,
+            y: value.y,
+        }
+    }
+}
+
 /// A size with a width and height.
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Default, Debug)]
 pub struct Size {
@@ -252,6 +262,15 @@ impl From<Size> for pinnacle_api_defs::pinnacle::util::v1::Size {
     }
 }
 
+impl From<pinnacle_api_defs::pinnacle::util::v1::Size> for Size {
+    fn from(value: pinnacle_api_defs::pinnacle::util::v1::Size) -> Self {
+        Self {
+            w: value.width,
+            h: value.height,
+        }
+    }
+}
+
 /// A rectangle with a location and size.
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Default, Debug)]
 pub struct Rect {
@@ -269,3 +288,92 @@ impl From<Rect> for pinnacle_api_defs::pinnacle::util::v1::Rect {
         }
     }
 }
+
+impl From<pinnacle_api_defs::pinnacle::util::v1::Rect> for Rect {
+    fn from(value: pinnacle_api_defs::pinnacle::util::v1::Rect) -> Self {
+        Self {
+            loc: value.loc.map(From::from).unwrap_or_default(),
+            size: value.size.map(From::from).unwrap_or_default(),
+        }
+    }
+}
+
+/// A color.
+///
+/// All channels range from 0.0 to 1.0.
+#[derive(Copy, Clone, PartialEq, Default, Debug)]
+pub struct Color {
+    /// The red channel.
+    pub red: f32,
+    /// The green channel.
+    pub green: f32,
+    /// The blue channel.
+    pub blue: f32,
+    /// The alpha channel.
+    pub alpha: f32,
+}
+
+impl Color {
+    /// Creates an opaque `Color` from red, green, and blue channels.
+    pub fn rgb(red: f32, green: f32, blue: f32) -> Self {
+        Self::rgba(red, green, blue, 1.0)
+    }
+
+    /// Creates a `Color` from red, green, blue, and alpha channels.
+    pub fn rgba(red: f32, green: f32, blue: f32, alpha: f32) -> Self {
+        Self {
+            red: red.clamp(0.0, 1.0),
+            green: green.clamp(0.0, 1.0),
+            blue: blue.clamp(0.0, 1.0),
+            alpha: alpha.clamp(0.0, 1.0),
+        }
+    }
+}
+
+impl From<[f32; 4]> for Color {
+    fn from([red, green, blue, alpha]: [f32; 4]) -> Self {
+        Self::rgba(red, green, blue, alpha)
+    }
+}
+
+impl From<[f32; 3]> for Color {
+    fn from([red, green, blue]: [f32; 3]) -> Self {
+        Self::rgb(red, green, blue)
+    }
+}
+
+impl From<Color> for pinnacle_api_defs::pinnacle::util::v1::Color {
+    fn from(value: Color) -> Self {
+        Self {
+            red: value.red,
+            green: value.green,
+            blue: value.blue,
+            alpha: value.alpha,
+        }
+    }
+}
+
+/// A handle to a running property watch.
+///
+/// Dropping this does not stop the watch; use [`WatchHandle::stop`] for that.
+#[derive(Debug)]
+pub struct WatchHandle {
+    join_handle: JoinHandle<()>,
+}
+
+impl WatchHandle {
+    pub(crate) fn new(join_handle: JoinHandle<()>) -> Self {
+        Self { join_handle }
+    }
+
+    /// Stops watching.
+    pub fn stop(&self) {
+        self.join_handle.abort();
+    }
+
+    /// Returns whether the watch has ended, either because it was stopped
+    /// or because what it watched no longer exists.
+    pub fn is_finished(&self) -> bool {
+        self.join_handle.is_finished()
+    }
+}
@@ -10,7 +10,7 @@
 //! [`WindowHandle`]s allow you to do things like resize and move windows, toggle them between
 //! floating and tiled, close them, and more.
 
-use std::borrow::Borrow;
+use std::{borrow::Borrow, time::Duration};
 
 use futures::FutureExt;
 use pinnacle_api_defs::pinnacle::{
@@ -18,13 +18,26 @@ use pinnacle_api_defs::pinnacle::{
     window::{
         self,
         v1::{
-            GetAppIdRequest, GetFocusedRequest, GetForeignToplevelListIdentifierRequest,
-            GetLayoutModeRequest, GetLocRequest, GetSizeRequest, GetTagIdsRequest, GetTitleRequest,
+            ApplyRulesToExistingRequest, BeginCycleRequest, CycleRequest, DeclarativeWindowRule,
+            EndCycleRequest, ExistsRequest, FindRequest, FocusInDirectionRequest, GetAppIdRequest,
+            GetChildrenRequest, GetDemandsAttentionRequest, GetFocusAgeRequest, GetFocusedRequest,
+            GetForeignToplevelListIdentifierRequest, GetIconRequest, GetLayerRequest,
+            GetLayoutModeRequest, GetLocRequest, GetMatchingWindowRulesRequest,
+            GetMinimizedRequest, GetParentRequest, GetPreviouslyFocusedRequest,
+            GetResponsiveRequest, GetSizeRequest, GetTagIdsRequest, GetTitleRequest,
             GetWindowsInDirRequest, LowerRequest, MoveGrabRequest, MoveToOutputRequest,
             MoveToTagRequest, RaiseRequest, ResizeGrabRequest, ResizeTileRequest,
-            SetDecorationModeRequest, SetFloatingRequest, SetFocusedRequest, SetFullscreenRequest,
-            SetGeometryRequest, SetMaximizedRequest, SetTagRequest, SetTagsRequest,
-            SetVrrDemandRequest, SwapRequest,
+            SetAllowOffscreenRequest, SetBorderStyleRequest, SetDecorationModeRequest,
+            SetDemandsAttentionRequest, SetDialogPolicyRequest, SetFloatingRequest,
+            SetFloatingVisibilityRequest, SetFocusedRequest, SetFullscreenRequest,
+            SetGeometryRequest, SetGreyOutUnresponsiveRequest, SetHoldToMoveEnabledRequest,
+            SetInputEnabledRequest, SetLayerRequest, SetMaximizeRequestPolicyRequest,
+            SetMaximizedRequest, SetMinimizedRequest, SetPopupPolicyRequest,
+            SetScaleOverrideRequest, SetSizeConstraintsRequest, SetSnapGridRequest,
+            SetTagInheritanceRequest, SetTagRequest, SetTagsRequest, SetVrrDemandRequest,
+            SetWindowRulesRequest, SnapGridCell, SnapPreset, SnapToRegionRequest,
+            SwapInDirectionRequest, SwapRequest, WatchPropertiesRequest,
+            set_floating_visibility_request, snap_to_region_request,
         },
     },
 };
@@ -32,13 +45,13 @@ use tokio::sync::mpsc::unbounded_channel;
 use tokio_stream::StreamExt;
 
 use crate::{
-    BlockOnTokio,
+    BlockOnTokio, ResponseExt,
     client::Client,
     input::MouseButton,
     output::OutputHandle,
     signal::{SignalHandle, WindowSignal},
     tag::TagHandle,
-    util::{Batch, Direction, Point, Size},
+    util::{Batch, Color, Direction, Point, Size, WatchHandle},
 };
 
 /// Gets handles to all windows.
@@ -60,8 +73,7 @@ pub async fn get_all_async() -> impl Iterator<Item = WindowHandle> {
     let window_ids = Client::window()
         .get(pinnacle_api_defs::pinnacle::window::v1::GetRequest {})
         .await
-        .unwrap()
-        .into_inner()
+        .into_inner_or_default()
         .window_ids;
 
     window_ids.into_iter().map(|id| WindowHandle { id })
@@ -88,6 +100,196 @@ pub async fn get_focused_async() -> Option<WindowHandle> {
     windows.batch_find(|win| win.focused_async().boxed(), |focused| *focused)
 }
 
+/// Gets a handle to the most recently focused window that isn't currently focused.
+///
+/// This can be used to toggle focus between the last two windows.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::window;
+/// if let Some(previous) = window::get_previously_focused() {
+///     previous.set_focused(true);
+/// }
+/// ```
+pub fn get_previously_focused() -> Option<WindowHandle> {
+    get_previously_focused_async().block_on_tokio()
+}
+
+/// Async impl for [`get_previously_focused`].
+pub async fn get_previously_focused_async() -> Option<WindowHandle> {
+    Client::window()
+        .get_previously_focused(GetPreviouslyFocusedRequest {})
+        .await
+        .into_inner_or_default()
+        .window_id
+        .map(|id| WindowHandle { id })
+}
+
+/// Focuses the window nearest to the focused window in the given direction.
+///
+/// Only windows on the focused output are considered. Returns the newly focused
+/// window, or `None` if nothing is focused or there's no window in that direction.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::window;
+/// # use pinnacle_api::util::Direction;
+/// window::focus_in_direction(Direction::Left);
+/// ```
+pub fn focus_in_direction(direction: Direction) -> Option<WindowHandle> {
+    let mut request = FocusInDirectionRequest {
+        dir: Default::default(),
+    };
+
+    request.set_dir(match direction {
+        Direction::Left => pinnacle_api_defs::pinnacle::util::v1::Dir::Left,
+        Direction::Right => pinnacle_api_defs::pinnacle::util::v1::Dir::Right,
+        Direction::Up => pinnacle_api_defs::pinnacle::util::v1::Dir::Up,
+        Direction::Down => pinnacle_api_defs::pinnacle::util::v1::Dir::Down,
+    });
+
+    Client::window()
+        .focus_in_direction(request)
+        .block_on_tokio()
+        .into_inner_or_default()
+        .window_id
+        .map(WindowHandle::from_id)
+}
+
+/// Starts cycling through shown windows, most recently focused first, like alt-tab.
+///
+/// The cycle starts at the focused window. [`cycle_next`] and [`cycle_prev`] raise the
+/// window cycled to without focusing it, and [`end_cycle`] focuses it.
+/// This restarts the cycle if one is in progress.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::window;
+/// window::begin_cycle();
+/// window::cycle_next();
+/// window::cycle_next();
+/// // Focuses the third most recently focused window
+/// window::end_cycle();
+/// ```
+pub fn begin_cycle() {
+    Client::window()
+        .begin_cycle(BeginCycleRequest {})
+        .block_on_tokio()
+        .log_err();
+}
+
+/// Cycles to the next less recently focused window and raises it.
+///
+/// Wraps around to the focused window after the least recently focused one.
+/// Starts a cycle if there isn't one. Returns the window cycled to.
+pub fn cycle_next() -> Option<WindowHandle> {
+    cycle(false)
+}
+
+/// Cycles to the next more recently focused window and raises it.
+///
+/// Wraps around to the least recently focused window after the focused one.
+/// Starts a cycle if there isn't one. Returns the window cycled to.
+pub fn cycle_prev() -> Option<WindowHandle> {
+    cycle(true)
+}
+
+fn cycle(backward: bool) -> Option<WindowHandle> {
+    Client::window()
+        .cycle(CycleRequest { backward })
+        .block_on_tokio()
+        .into_inner_or_default()
+        .window_id
+        .map(WindowHandle::from_id)
+}
+
+/// Ends the current cycle, focusing the window cycled to.
+///
+/// Returns the focused window, or `None` if there was no cycle.
+pub fn end_cycle() -> Option<WindowHandle> {
+    Client::window()
+        .end_cycle(EndCycleRequest {})
+        .block_on_tokio()
+        .into_inner_or_default()
+        .window_id
+        .map(WindowHandle::from_id)
+}
+
+/// How [`find`] matches windows.
+#[derive(Default, Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum FindMode {
+    /// The query's characters must appear in order, ignoring case.
+    ///
+    /// Consecutive characters and characters at the start of words rank higher.
+    #[default]
+    Fuzzy,
+    /// The query is a regular expression.
+    ///
+    /// Earlier matches rank higher.
+    Regex,
+}
+
+/// Finds windows whose title or app id match `query`, best match first.
+///
+/// Matching is done by Pinnacle, so this is much cheaper than getting every window's
+/// title and app id. If `limit` is set, at most that many windows are returned.
+///
+/// Returns why the query couldn't be matched if it's an invalid regex.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::window::{self, FindMode};
+/// // Jump to the best match for "ffx", e.g. Firefox
+/// if let Some(win) = window::find("ffx", FindMode::Fuzzy, Some(1))
+///     .ok()
+///     .and_then(|wins| wins.into_iter().next())
+/// {
+///     win.set_focused(true);
+/// }
+/// ```
+pub fn find(
+    query: impl ToString,
+    mode: FindMode,
+    limit: Option<u32>,
+) -> Result<Vec<WindowHandle>, String> {
+    find_async(query, mode, limit).block_on_tokio()
+}
+
+/// Async impl for [`find`].
+pub async fn find_async(
+    query: impl ToString,
+    mode: FindMode,
+    limit: Option<u32>,
+) -> Result<Vec<WindowHandle>, String> {
+    let mode = match mode {
+        FindMode::Fuzzy => window::v1::FindMode::Fuzzy,
+        FindMode::Regex => window::v1::FindMode::Regex,
+    };
+
+    let response = Client::window()
+        .find(FindRequest {
+            query: query.to_string(),
+            mode: mode.into(),
+            limit,
+        })
+        .await
+        .into_result()
+        .map_err(|err| err.to_string())?;
+
+    match response.error {
+        Some(error) => Err(error),
+        None => Ok(response
+            .window_ids
+            .into_iter()
+            .map(|id| WindowHandle { id })
+            .collect()),
+    }
+}
+
 /// Begins an interactive window move.
 ///
 /// This will start moving the window under the pointer until `button` is released.
@@ -112,7 +314,7 @@ pub fn begin_move(button: MouseButton) {
             button: button.into(),
         })
         .block_on_tokio()
-        .unwrap();
+        .log_err();
 }
 
 /// Begins an interactive window resize.
@@ -139,7 +341,217 @@ pub fn begin_resize(button: MouseButton) {
             button: button.into(),
         })
         .block_on_tokio()
-        .unwrap();
+        .log_err();
+}
+
+/// Sets how much of floating windows must remain on-screen.
+///
+/// This is applied when floating windows are moved with the mouse
+/// or through [`WindowHandle::set_geometry`]. Windows can opt out with
+/// [`WindowHandle::set_allow_offscreen`].
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::window;
+/// # use pinnacle_api::window::FloatingVisibility;
+/// // Always keep at least 64 pixels of floating windows visible
+/// window::set_floating_visibility(FloatingVisibility::KeepVisible(64));
+/// ```
+pub fn set_floating_visibility(visibility: FloatingVisibility) {
+    let policy = match visibility {
+        FloatingVisibility::Unrestricted => set_floating_visibility_request::Policy::Unrestricted(
+            set_floating_visibility_request::Unrestricted {},
+        ),
+        FloatingVisibility::KeepVisible(pixels) => {
+            set_floating_visibility_request::Policy::KeepVisible(
+                set_floating_visibility_request::KeepVisible { pixels },
+            )
+        }
+        FloatingVisibility::ClampToOutputs => {
+            set_floating_visibility_request::Policy::ClampToOutputs(
+                set_floating_visibility_request::ClampToOutputs {},
+            )
+        }
+    };
+
+    Client::window()
+        .set_floating_visibility(SetFloatingVisibilityRequest {
+            policy: Some(policy),
+        })
+        .block_on_tokio()
+        .log_err();
+}
+
+/// Sets whether windows that stop responding are greyed out.
+///
+/// Windows are unresponsive when they take too long to respond to the compositor,
+/// usually because the app froze. They are no longer greyed out once they catch up.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::window;
+/// window::set_grey_out_unresponsive(true);
+/// ```
+pub fn set_grey_out_unresponsive(grey_out: bool) {
+    Client::window()
+        .set_grey_out_unresponsive(SetGreyOutUnresponsiveRequest { grey_out })
+        .block_on_tokio()
+        .log_err();
+}
+
+/// Sets how Pinnacle handles windows asking to be maximized.
+///
+/// Window rules and [`WindowHandle::set_maximize_request_policy`] override this
+/// for specific windows.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::window::{self, MaximizeRequestPolicy};
+/// // Keep apps that maximize themselves on startup tiled
+/// window::set_maximize_request_policy(MaximizeRequestPolicy::Ignore);
+/// ```
+pub fn set_maximize_request_policy(policy: MaximizeRequestPolicy) {
+    Client::window()
+        .set_maximize_request_policy(SetMaximizeRequestPolicyRequest {
+            window_id: None,
+            policy: window::v1::MaximizeRequestPolicy::from(policy).into(),
+        })
+        .block_on_tokio()
+        .log_err();
+}
+
+/// Sets which tags new windows are put on.
+///
+/// By default, new windows are put on every active tag of the focused output,
+/// which puts them on several tags when more than one is active.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::window::{self, TagInheritance};
+/// // Put dialogs on the same tags as the window that opened them
+/// window::set_tag_inheritance(TagInheritance::Parent);
+/// ```
+pub fn set_tag_inheritance(inheritance: TagInheritance) {
+    Client::window()
+        .set_tag_inheritance(SetTagInheritanceRequest {
+            inheritance: window::v1::TagInheritance::from(inheritance).into(),
+        })
+        .block_on_tokio()
+        .log_err();
+}
+
+/// Sets how dialogs, windows with a parent, are placed.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::window::{self, DialogPolicy};
+/// // Keep file pickers with the window that opened them
+/// window::set_dialog_policy(DialogPolicy::AttachToParent);
+/// ```
+pub fn set_dialog_policy(policy: DialogPolicy) {
+    Client::window()
+        .set_dialog_policy(SetDialogPolicyRequest {
+            policy: window::v1::DialogPolicy::from(policy).into(),
+        })
+        .block_on_tokio()
+        .log_err();
+}
+
+/// Sets the border drawn around windows with server-side decorations,
+/// or draws none if `style` is `None`.
+///
+/// Window rules and [`WindowHandle::set_border_style`] override this for specific windows.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::window::{self, BorderStyle};
+/// # use pinnacle_api::util::Color;
+/// window::set_border_style(BorderStyle {
+///     width: 3,
+///     focused_color: Color::rgb(1.0, 0.6, 0.2),
+///     corner_radius: 8.0,
+///     ..Default::default()
+/// });
+/// ```
+pub fn set_border_style(style: impl Into<Option<BorderStyle>>) {
+    Client::window()
+        .set_border_style(SetBorderStyleRequest {
+            window_id: None,
+            style: style.into().map(From::from),
+        })
+        .block_on_tokio()
+        .log_err();
+}
+
+/// Sets the grid used by [`Region::GridCell`].
+///
+/// The grid is laid over the part of an output not taken up by layer surfaces
+/// like bars. It defaults to 3 columns and 2 rows.
+///
+/// # Panics
+///
+/// Panics if `columns` or `rows` is 0.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::window;
+/// // A 4x4 grid for an ultrawide monitor
+/// window::set_snap_grid(4, 4);
+/// ```
+pub fn set_snap_grid(columns: u32, rows: u32) {
+    Client::window()
+        .set_snap_grid(SetSnapGridRequest { columns, rows })
+        .block_on_tokio()
+        .log_err();
+}
+
+/// How popups like menus and tooltips are constrained.
+///
+/// Everything is off by default.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PopupPolicy {
+    /// Moves popups fully onto their output, even if the client didn't allow it.
+    pub clamp_to_output: bool,
+    /// Closes the popups of windows hidden by a tag switch.
+    pub close_on_tag_switch: bool,
+    /// Closes popups that hold a grab for longer than this.
+    ///
+    /// Popups with a grab, like most menus, get all keyboard input. Keybinds still run,
+    /// but ones that move keyboard focus won't take effect until the popup closes.
+    pub max_grab_duration: Option<Duration>,
+}
+
+/// Sets how popups like menus and tooltips are constrained.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::window;
+/// # use std::time::Duration;
+/// window::set_popup_policy(window::PopupPolicy {
+///     close_on_tag_switch: true,
+///     max_grab_duration: Some(Duration::from_secs(30)),
+///     ..Default::default()
+/// });
+/// ```
+pub fn set_popup_policy(policy: PopupPolicy) {
+    Client::window()
+        .set_popup_policy(SetPopupPolicyRequest {
+            clamp_to_output: policy.clamp_to_output,
+            close_on_tag_switch: policy.close_on_tag_switch,
+            max_grab_duration_ms: policy
+                .max_grab_duration
+                .map(|duration| duration.as_millis().try_into().unwrap_or(u32::MAX)),
+        })
+        .block_on_tokio()
+        .log_err();
 }
 
 /// Connects to a [`WindowSignal`].
@@ -160,12 +572,22 @@ pub fn connect_signal(signal: WindowSignal) -> SignalHandle {
         WindowSignal::PointerEnter(f) => signal_state.window_pointer_enter.add_callback(f),
         WindowSignal::PointerLeave(f) => signal_state.window_pointer_leave.add_callback(f),
         WindowSignal::Focused(f) => signal_state.window_focused.add_callback(f),
+        WindowSignal::FocusChanged(f) => signal_state.window_focus_changed.add_callback(f),
         WindowSignal::TitleChanged(f) => signal_state.window_title_changed.add_callback(f),
+        WindowSignal::FocusedTitleChanged(f) => {
+            signal_state.window_focused_title_changed.add_callback(f)
+        }
         WindowSignal::LayoutModeChanged(f) => {
             signal_state.window_layout_mode_changed.add_callback(f)
         }
         WindowSignal::Created(f) => signal_state.window_created.add_callback(f),
         WindowSignal::Destroyed(f) => signal_state.window_destroyed.add_callback(f),
+        WindowSignal::Unresponsive(f) => signal_state.window_unresponsive.add_callback(f),
+        WindowSignal::Minimized(f) => signal_state.window_minimized.add_callback(f),
+        WindowSignal::UrgencyChanged(f) => signal_state.window_urgency_changed.add_callback(f),
+        WindowSignal::PopupGrabBlockedKeybind(f) => signal_state
+            .window_popup_grab_blocked_keybind
+            .add_callback(f),
     }
 }
 
@@ -205,6 +627,105 @@ impl TryFrom<window::v1::LayoutMode> for LayoutMode {
     }
 }
 
+/// Why a window got keyboard focus.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum FocusReason {
+    /// The window was clicked.
+    Pointer,
+    /// The window was focused through the API, usually from a keybind.
+    Keybind,
+    /// The window or another client requested that it be activated.
+    Activation,
+    /// The window was focused when it mapped.
+    Map,
+    /// The previously focused window closed or was hidden and focus fell back
+    /// to this window.
+    UnmapFallback,
+}
+
+impl TryFrom<window::v1::FocusReason> for FocusReason {
+    type Error = ();
+
+    fn try_from(value: window::v1::FocusReason) -> Result<Self, Self::Error> {
+        match value {
+            window::v1::FocusReason::Unspecified => Err(()),
+            window::v1::FocusReason::Pointer => Ok(FocusReason::Pointer),
+            window::v1::FocusReason::Keybind => Ok(FocusReason::Keybind),
+            window::v1::FocusReason::Activation => Ok(FocusReason::Activation),
+            window::v1::FocusReason::Map => Ok(FocusReason::Map),
+            window::v1::FocusReason::UnmapFallback => Ok(FocusReason::UnmapFallback),
+        }
+    }
+}
+
+/// A keyboard focus change, as passed to [`WindowSignal::FocusChanged`] callbacks.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub struct FocusChange {
+    /// Why the window got focus.
+    pub reason: FocusReason,
+    /// A serial that increases with every focus change.
+    ///
+    /// This can be used to ignore focus changes that happened before one a config caused.
+    pub serial: u64,
+}
+
+/// Changes to a window's properties, as passed to [`WindowHandle::watch`].
+///
+/// Only the properties that changed are `Some`.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct WindowPropertiesChange {
+    /// The window's new app id.
+    pub app_id: Option<String>,
+    /// The window's new title.
+    pub title: Option<String>,
+    /// The window's new location, or `Some(None)` if the window was unmapped.
+    pub loc: Option<Option<Point>>,
+    /// The window's new size, or `Some(None)` if the window was unmapped.
+    pub size: Option<Option<Size>>,
+    /// Whether the window now has keyboard focus.
+    pub focused: Option<bool>,
+    /// The window's new layout mode.
+    pub layout_mode: Option<LayoutMode>,
+    /// The window's new tags.
+    pub tags: Option<Vec<TagHandle>>,
+}
+
+impl From<window::v1::WatchPropertiesResponse> for WindowPropertiesChange {
+    fn from(response: window::v1::WatchPropertiesResponse) -> Self {
+        let cleared = |property: window::v1::ClearableProperty| {
+            response
+                .cleared
+                .contains(&(property as i32))
+                .then_some(None)
+        };
+
+        Self {
+            loc: response
+                .loc
+                .map(|loc| Some(loc.into()))
+                .or_else(|| cleared(window::v1::ClearableProperty::Loc)),
+            size: response
+                .size
+                .map(|size| Some(size.into()))
+                .or_else(|| cleared(window::v1::ClearableProperty::Size)),
+            app_id: response.app_id,
+            title: response.title,
+            focused: response.focused,
+            layout_mode: response
+                .layout_mode
+                .and_then(|mode| window::v1::LayoutMode::try_from(mode).ok())
+                .and_then(|mode| mode.try_into().ok()),
+            tags: response.tag_ids.map(|tag_ids| {
+                tag_ids
+                    .tag_ids
+                    .into_iter()
+                    .map(|id| TagHandle { id })
+                    .collect()
+            }),
+        }
+    }
+}
+
 /// A mode for window decorations (titlebar, shadows, etc).
 #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
 pub enum DecorationMode {
@@ -214,6 +735,305 @@ pub enum DecorationMode {
     ServerSide,
 }
 
+/// How much of floating windows must remain on-screen.
+#[derive(Default, Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum FloatingVisibility {
+    /// Floating windows can be placed anywhere, including fully offscreen.
+    #[default]
+    Unrestricted,
+    /// At least this many pixels of floating windows must remain
+    /// on an output along each axis.
+    KeepVisible(u32),
+    /// Floating windows must stay fully inside an output if they fit.
+    ClampToOutputs,
+}
+
+/// What Pinnacle does when a window asks to be maximized.
+#[derive(Default, Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum MaximizeRequestPolicy {
+    /// Maximize the window.
+    #[default]
+    Honor,
+    /// Leave the window as is.
+    Ignore,
+    /// Tell the window it's fullscreen but leave it in its tile.
+    FullscreenWithinTile,
+    /// Float the window and maximize it, so it's floating once unmaximized.
+    FloatAndMaximize,
+}
+
+impl From<MaximizeRequestPolicy> for window::v1::MaximizeRequestPolicy {
+    fn from(policy: MaximizeRequestPolicy) -> Self {
+        match policy {
+            MaximizeRequestPolicy::Honor => Self::Honor,
+            MaximizeRequestPolicy::Ignore => Self::Ignore,
+            MaximizeRequestPolicy::FullscreenWithinTile => Self::FullscreenWithinTile,
+            MaximizeRequestPolicy::FloatAndMaximize => Self::FloatAndMaximize,
+        }
+    }
+}
+
+/// Which tags a new window is put on.
+///
+/// Window rules that set tags always override this.
+#[derive(Default, Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum TagInheritance {
+    /// All active tags on the focused output.
+    #[default]
+    ActiveTags,
+    /// Only the first active tag on the focused output.
+    FirstActiveTag,
+    /// The tags of the window's parent, like the app that opened a dialog,
+    /// or the active tags on the focused output if it doesn't have one.
+    Parent,
+}
+
+impl From<TagInheritance> for window::v1::TagInheritance {
+    fn from(inheritance: TagInheritance) -> Self {
+        match inheritance {
+            TagInheritance::ActiveTags => Self::ActiveTags,
+            TagInheritance::FirstActiveTag => Self::FirstActiveTag,
+            TagInheritance::Parent => Self::Parent,
+        }
+    }
+}
+
+/// How dialogs, windows with a parent, are placed.
+#[derive(Default, Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum DialogPolicy {
+    /// Dialogs are put on tags like any other window.
+    #[default]
+    Independent,
+    /// Dialogs are put on their parent's tags and centered over it,
+    /// even if another output is focused.
+    ///
+    /// This takes precedence over [`TagInheritance`], but window rules that
+    /// set tags still override it.
+    AttachToParent,
+}
+
+impl From<DialogPolicy> for window::v1::DialogPolicy {
+    fn from(policy: DialogPolicy) -> Self {
+        match policy {
+            DialogPolicy::Independent => Self::Independent,
+            DialogPolicy::AttachToParent => Self::AttachToParent,
+        }
+    }
+}
+
+/// The stacking layer of a window.
+///
+/// Windows are always stacked above windows in lower layers, so raising a
+/// normal window never puts it above a window in [`WindowLayer::Above`].
+#[derive(Default, Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum WindowLayer {
+    /// Below all normal windows.
+    Below,
+    /// The layer windows start out in.
+    #[default]
+    Normal,
+    /// Above all normal windows.
+    Above,
+}
+
+impl From<WindowLayer> for window::v1::WindowLayer {
+    fn from(layer: WindowLayer) -> Self {
+        match layer {
+            WindowLayer::Below => Self::Below,
+            WindowLayer::Normal => Self::Normal,
+            WindowLayer::Above => Self::Above,
+        }
+    }
+}
+
+impl TryFrom<window::v1::WindowLayer> for WindowLayer {
+    type Error = ();
+
+    fn try_from(value: window::v1::WindowLayer) -> Result<Self, Self::Error> {
+        match value {
+            window::v1::WindowLayer::Unspecified => Err(()),
+            window::v1::WindowLayer::Below => Ok(WindowLayer::Below),
+            window::v1::WindowLayer::Normal => Ok(WindowLayer::Normal),
+            window::v1::WindowLayer::Above => Ok(WindowLayer::Above),
+        }
+    }
+}
+
+/// A region of an output that a window can be snapped to with
+/// [`WindowHandle::snap_to_region`].
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum Region {
+    /// The left half of the output.
+    LeftHalf,
+    /// The right half of the output.
+    RightHalf,
+    /// The top half of the output.
+    TopHalf,
+    /// The bottom half of the output.
+    BottomHalf,
+    /// The left third of the output.
+    LeftThird,
+    /// The middle third of the output.
+    CenterThird,
+    /// The right third of the output.
+    RightThird,
+    /// The left two thirds of the output.
+    LeftTwoThirds,
+    /// The right two thirds of the output.
+    RightTwoThirds,
+    /// The top left quarter of the output.
+    TopLeftQuarter,
+    /// The top right quarter of the output.
+    TopRightQuarter,
+    /// The bottom left quarter of the output.
+    BottomLeftQuarter,
+    /// The bottom right quarter of the output.
+    BottomRightQuarter,
+    /// A block of cells in the grid set with [`set_snap_grid`].
+    ///
+    /// Columns and rows start at 0 from the top left.
+    GridCell {
+        /// The leftmost column of the block.
+        column: u32,
+        /// The topmost row of the block.
+        row: u32,
+        /// How many columns the block spans.
+        column_span: u32,
+        /// How many rows the block spans.
+        row_span: u32,
+    },
+}
+
+impl From<Region> for snap_to_region_request::Region {
+    fn from(region: Region) -> Self {
+        let preset = match region {
+            Region::LeftHalf => SnapPreset::LeftHalf,
+            Region::RightHalf => SnapPreset::RightHalf,
+            Region::TopHalf => SnapPreset::TopHalf,
+            Region::BottomHalf => SnapPreset::BottomHalf,
+            Region::LeftThird => SnapPreset::LeftThird,
+            Region::CenterThird => SnapPreset::CenterThird,
+            Region::RightThird => SnapPreset::RightThird,
+            Region::LeftTwoThirds => SnapPreset::LeftTwoThirds,
+            Region::RightTwoThirds => SnapPreset::RightTwoThirds,
+            Region::TopLeftQuarter => SnapPreset::TopLeftQuarter,
+            Region::TopRightQuarter => SnapPreset::TopRightQuarter,
+            Region::BottomLeftQuarter => SnapPreset::BottomLeftQuarter,
+            Region::BottomRightQuarter => SnapPreset::BottomRightQuarter,
+            Region::GridCell {
+                column,
+                row,
+                column_span,
+                row_span,
+            } => {
+                return Self::GridCell(SnapGridCell {
+                    column,
+                    row,
+                    column_span,
+                    row_span,
+                });
+            }
+        };
+
+        Self::Preset(preset.into())
+    }
+}
+
+/// Limits on a window's size, enforced when it's configured or resized.
+///
+/// These apply on top of the minimum and maximum sizes the window asks for.
+/// They're ignored while the window is fullscreen.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct SizeConstraints {
+    /// The smallest size the window can be.
+    pub min_size: Option<Size>,
+    /// The largest size the window can be.
+    pub max_size: Option<Size>,
+    /// The window's width divided by its height.
+    ///
+    /// The window is shrunk to keep this ratio, even if that makes it
+    /// smaller than `min_size`.
+    pub aspect_ratio: Option<f64>,
+}
+
+impl SizeConstraints {
+    /// Creates constraints that fix a window's size.
+    pub fn fixed(size: Size) -> Self {
+        Self {
+            min_size: Some(size),
+            max_size: Some(size),
+            aspect_ratio: None,
+        }
+    }
+
+    /// Creates constraints that keep a window's aspect ratio at `width:height`.
+    pub fn keep_aspect_ratio(width: u32, height: u32) -> Self {
+        Self {
+            aspect_ratio: Some(width as f64 / height as f64),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<SizeConstraints> for window::v1::SizeConstraints {
+    fn from(constraints: SizeConstraints) -> Self {
+        Self {
+            min_w: constraints.min_size.map(|size| size.w),
+            min_h: constraints.min_size.map(|size| size.h),
+            max_w: constraints.max_size.map(|size| size.w),
+            max_h: constraints.max_size.map(|size| size.h),
+            aspect_ratio: constraints.aspect_ratio,
+        }
+    }
+}
+
+/// A border and titlebar drawn around windows with server-side decorations.
+///
+/// Windows that draw their own decorations and fullscreen windows don't get a border.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BorderStyle {
+    /// The width of the border in logical pixels.
+    pub width: u32,
+    /// The color of the focused window's border.
+    pub focused_color: Color,
+    /// The color of other windows' borders.
+    pub unfocused_color: Color,
+    /// The color of windows that want attention until they're focused.
+    pub urgent_color: Color,
+    /// The radius of the border's outer corners in logical pixels.
+    pub corner_radius: f32,
+    /// The height of a bar above the window in logical pixels, or 0 for no titlebar.
+    ///
+    /// The titlebar is drawn in the border's color.
+    pub titlebar_height: u32,
+}
+
+impl Default for BorderStyle {
+    fn default() -> Self {
+        Self {
+            width: 2,
+            focused_color: Color::rgb(0.4, 0.6, 1.0),
+            unfocused_color: Color::rgb(0.3, 0.3, 0.3),
+            urgent_color: Color::rgb(0.9, 0.3, 0.3),
+            corner_radius: 0.0,
+            titlebar_height: 0,
+        }
+    }
+}
+
+impl From<BorderStyle> for window::v1::BorderStyle {
+    fn from(style: BorderStyle) -> Self {
+        Self {
+            width: style.width,
+            focused_color: Some(style.focused_color.into()),
+            unfocused_color: Some(style.unfocused_color.into()),
+            urgent_color: Some(style.urgent_color.into()),
+            corner_radius: style.corner_radius,
+            titlebar_height: style.titlebar_height,
+        }
+    }
+}
+
 /// A demand for variable refresh rate on an output.
 #[derive(Default, Debug, Clone, Copy, Hash, PartialEq, Eq)]
 #[non_exhaustive]
@@ -235,6 +1055,26 @@ impl VrrDemand {
     }
 }
 
+/// The icon of a window.
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub struct WindowIcon {
+    /// The name of the icon in the icon theme.
+    pub name: Option<String>,
+    /// Images of the icon, used if there's no name or it isn't in the icon theme.
+    pub images: Vec<IconImage>,
+}
+
+/// An image of a [`WindowIcon`].
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub struct IconImage {
+    /// The width and height of the image.
+    pub size: u32,
+    /// The scale the image is meant for.
+    pub scale: u32,
+    /// Non-premultiplied RGBA8 pixels, row by row.
+    pub rgba: Vec<u8>,
+}
+
 impl WindowHandle {
     /// Sends a close request to this window.
     ///
@@ -244,7 +1084,7 @@ impl WindowHandle {
         Client::window()
             .close(pinnacle_api_defs::pinnacle::window::v1::CloseRequest { window_id })
             .block_on_tokio()
-            .unwrap();
+            .log_err();
     }
 
     /// Sets this window's location and/or size.
@@ -267,7 +1107,7 @@ impl WindowHandle {
                 h: h.into(),
             })
             .block_on_tokio()
-            .unwrap();
+            .log_err();
     }
 
     /// If this window is tiled, resizes its tile by shifting the left, right,
@@ -306,7 +1146,7 @@ impl WindowHandle {
                 bottom,
             })
             .block_on_tokio()
-            .unwrap();
+            .log_err();
     }
 
     /// Sets this window to fullscreen or not.
@@ -322,7 +1162,7 @@ impl WindowHandle {
                 .into(),
             })
             .block_on_tokio()
-            .unwrap();
+            .log_err();
     }
 
     /// Toggles this window between fullscreen and not.
@@ -334,7 +1174,7 @@ impl WindowHandle {
                 set_or_toggle: SetOrToggle::Toggle.into(),
             })
             .block_on_tokio()
-            .unwrap();
+            .log_err();
     }
 
     /// Sets this window to maximized or not.
@@ -350,7 +1190,7 @@ impl WindowHandle {
                 .into(),
             })
             .block_on_tokio()
-            .unwrap();
+            .log_err();
     }
 
     /// Toggles this window between maximized and not.
@@ -362,7 +1202,60 @@ impl WindowHandle {
                 set_or_toggle: SetOrToggle::Toggle.into(),
             })
             .block_on_tokio()
-            .unwrap();
+            .log_err();
+    }
+
+    /// Sets this window to minimized or not.
+    ///
+    /// Minimized windows keep their tags but aren't laid out, drawn, or focused.
+    /// Activating a minimized window, like from a taskbar, unminimizes it.
+    pub fn set_minimized(&self, set: bool) {
+        let window_id = self.id;
+        Client::window()
+            .set_minimized(SetMinimizedRequest {
+                window_id,
+                set_or_toggle: match set {
+                    true => SetOrToggle::Set,
+                    false => SetOrToggle::Unset,
+                }
+                .into(),
+            })
+            .block_on_tokio()
+            .log_err();
+    }
+
+    /// Minimizes this window.
+    ///
+    /// This is a shorthand for [`WindowHandle::set_minimized(true)`][Self::set_minimized].
+    pub fn minimize(&self) {
+        self.set_minimized(true);
+    }
+
+    /// Toggles this window between minimized and not.
+    pub fn toggle_minimized(&self) {
+        let window_id = self.id;
+        Client::window()
+            .set_minimized(SetMinimizedRequest {
+                window_id,
+                set_or_toggle: SetOrToggle::Toggle.into(),
+            })
+            .block_on_tokio()
+            .log_err();
+    }
+
+    /// Marks this window as demanding attention, or clears it.
+    ///
+    /// Marking a window also marks its inactive tags as urgent. A focused window
+    /// stops demanding attention right away.
+    pub fn set_demands_attention(&self, demands_attention: bool) {
+        let window_id = self.id;
+        Client::window()
+            .set_demands_attention(SetDemandsAttentionRequest {
+                window_id,
+                demands_attention,
+            })
+            .block_on_tokio()
+            .log_err();
     }
 
     /// Sets this window to floating or not.
@@ -380,7 +1273,7 @@ impl WindowHandle {
                 .into(),
             })
             .block_on_tokio()
-            .unwrap();
+            .log_err();
     }
 
     /// Toggles this window to and from floating.
@@ -394,7 +1287,7 @@ impl WindowHandle {
                 set_or_toggle: SetOrToggle::Toggle.into(),
             })
             .block_on_tokio()
-            .unwrap();
+            .log_err();
     }
 
     /// Focuses or unfocuses this window.
@@ -410,7 +1303,7 @@ impl WindowHandle {
                 .into(),
             })
             .block_on_tokio()
-            .unwrap();
+            .log_err();
     }
 
     /// Toggles this window between focused and unfocused.
@@ -422,7 +1315,7 @@ impl WindowHandle {
                 set_or_toggle: SetOrToggle::Toggle.into(),
             })
             .block_on_tokio()
-            .unwrap();
+            .log_err();
     }
 
     /// Sets this window's decoration mode.
@@ -437,7 +1330,42 @@ impl WindowHandle {
                 .into(),
             })
             .block_on_tokio()
-            .unwrap();
+            .log_err();
+    }
+
+    /// Sets how Pinnacle handles this window asking to be maximized,
+    /// overriding [`set_maximize_request_policy`].
+    pub fn set_maximize_request_policy(&self, policy: MaximizeRequestPolicy) {
+        Client::window()
+            .set_maximize_request_policy(SetMaximizeRequestPolicyRequest {
+                window_id: Some(self.id),
+                policy: window::v1::MaximizeRequestPolicy::from(policy).into(),
+            })
+            .block_on_tokio()
+            .log_err();
+    }
+
+    /// Sets the border drawn around this window, overriding [`set_border_style`],
+    /// or draws none if `style` is `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pinnacle_api::window;
+    /// window::add_window_rule(|window| {
+    ///     if window.app_id() == "mpv" {
+    ///         window.set_border_style(None);
+    ///     }
+    /// });
+    /// ```
+    pub fn set_border_style(&self, style: impl Into<Option<BorderStyle>>) {
+        Client::window()
+            .set_border_style(SetBorderStyleRequest {
+                window_id: Some(self.id),
+                style: style.into().map(From::from),
+            })
+            .block_on_tokio()
+            .log_err();
     }
 
     /// Moves this window to the specified output.
@@ -465,7 +1393,7 @@ impl WindowHandle {
                 output_name,
             })
             .block_on_tokio()
-            .unwrap();
+            .log_err();
     }
 
     /// Moves this window to the given `tag`.
@@ -488,9 +1416,42 @@ impl WindowHandle {
         let window_id = self.id;
         let tag_id = tag.id;
         Client::window()
-            .move_to_tag(MoveToTagRequest { window_id, tag_id })
+            .move_to_tag(MoveToTagRequest {
+                window_id,
+                tag_id,
+                follow: false,
+            })
             .block_on_tokio()
-            .unwrap();
+            .log_err();
+    }
+
+    /// Moves this window to the given `tag`, then switches to that tag and focuses this window.
+    ///
+    /// Unlike calling [`WindowHandle::move_to_tag`] then [`TagHandle::switch_to`],
+    /// this happens all at once, so the window doesn't flicker out of view in between.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pinnacle_api::window;
+    /// # use pinnacle_api::tag;
+    /// # || {
+    /// // Move the focused window to tag "Code" and go with it
+    /// window::get_focused()?.move_to_tag_and_follow(&tag::get("Code")?);
+    /// # Some(())
+    /// # };
+    /// ```
+    pub fn move_to_tag_and_follow(&self, tag: &TagHandle) {
+        let window_id = self.id;
+        let tag_id = tag.id;
+        Client::window()
+            .move_to_tag(MoveToTagRequest {
+                window_id,
+                tag_id,
+                follow: true,
+            })
+            .block_on_tokio()
+            .log_err();
     }
 
     /// Sets or unsets a tag on this window.
@@ -523,7 +1484,7 @@ impl WindowHandle {
                 .into(),
             })
             .block_on_tokio()
-            .unwrap();
+            .log_err();
     }
 
     /// Toggles a tag on this window.
@@ -542,75 +1503,265 @@ impl WindowHandle {
     /// # Some(())
     /// # };
     /// ```
-    pub fn toggle_tag(&self, tag: &TagHandle) {
+    pub fn toggle_tag(&self, tag: &TagHandle) {
+        let window_id = self.id;
+        let tag_id = tag.id;
+        Client::window()
+            .set_tag(SetTagRequest {
+                window_id,
+                tag_id,
+                set_or_toggle: SetOrToggle::Toggle.into(),
+            })
+            .block_on_tokio()
+            .log_err();
+    }
+
+    /// Sets the exact provided tags on this window.
+    ///
+    /// Passing in an empty collection will not change the window's tags.
+    ///
+    /// For ergonomics, this accepts iterators of both `TagHandle` and `&TagHandle`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pinnacle_api::window;
+    /// # use pinnacle_api::tag;
+    /// # || {
+    /// let focused = window::get_focused()?;
+    /// let tag1 = tag::get("1")?;
+    /// let tag3 = tag::get("3")?;
+    ///
+    /// // Set `focused`'s tags to "1" and "3", removing all others
+    /// focused.set_tags([tag1, tag3]);
+    /// # Some(())
+    /// # };
+    /// ```
+    pub fn set_tags<T: Borrow<TagHandle>>(&self, tags: impl IntoIterator<Item = T>) {
+        let window_id = self.id;
+        let tag_ids = tags
+            .into_iter()
+            .map(|tag| {
+                let tag: &TagHandle = tag.borrow();
+                tag.id
+            })
+            .collect::<Vec<_>>();
+
+        Client::window()
+            .set_tags(SetTagsRequest { window_id, tag_ids })
+            .block_on_tokio()
+            .log_err();
+    }
+
+    /// Sets this window's [`VrrDemand`].
+    ///
+    /// When set to `None`, this window has no vrr demand.
+    ///
+    /// This works in conjunction with an output with
+    /// [`Vrr::OnDemand`](crate::output::Vrr::OnDemand).
+    pub fn set_vrr_demand(&self, vrr_demand: impl Into<Option<VrrDemand>>) {
+        let window_id = self.id;
+        let vrr_demand: Option<_> = vrr_demand.into();
+
+        Client::window()
+            .set_vrr_demand(SetVrrDemandRequest {
+                window_id,
+                vrr_demand: vrr_demand.map(|vrr_demand| window::v1::VrrDemand {
+                    fullscreen: vrr_demand.fullscreen,
+                }),
+            })
+            .block_on_tokio()
+            .log_err();
+    }
+
+    /// Sets whether this window is exempt from the [`FloatingVisibility`] policy.
+    ///
+    /// This allows the window to be deliberately placed offscreen.
+    pub fn set_allow_offscreen(&self, allow_offscreen: bool) {
+        let window_id = self.id;
+
+        Client::window()
+            .set_allow_offscreen(SetAllowOffscreenRequest {
+                window_id,
+                allow_offscreen,
+            })
+            .block_on_tokio()
+            .log_err();
+    }
+
+    /// Sets whether this window receives pointer and keyboard input.
+    ///
+    /// A window with input disabled is still drawn, but clicks go through it to whatever
+    /// is below and it can't get keyboard focus. This is useful for overlays like
+    /// reference images or cheat sheets.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pinnacle_api::window;
+    /// # || {
+    /// window::get_focused()?.set_input_enabled(false);
+    /// # Some(())
+    /// # };
+    /// ```
+    pub fn set_input_enabled(&self, enabled: bool) {
+        let window_id = self.id;
+
+        Client::window()
+            .set_input_enabled(SetInputEnabledRequest { window_id, enabled })
+            .block_on_tokio()
+            .log_err();
+    }
+
+    /// Sets whether holding this window moves it.
+    ///
+    /// This is useful for apps that use long presses themselves.
+    /// See [`input::set_hold_to_move`][crate::input::set_hold_to_move].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pinnacle_api::window;
+    /// # || {
+    /// window::get_focused()?.set_hold_to_move_enabled(false);
+    /// # Some(())
+    /// # };
+    /// ```
+    pub fn set_hold_to_move_enabled(&self, enabled: bool) {
+        let window_id = self.id;
+
+        Client::window()
+            .set_hold_to_move_enabled(SetHoldToMoveEnabledRequest { window_id, enabled })
+            .block_on_tokio()
+            .log_err();
+    }
+
+    /// Sets limits on this window's size.
+    ///
+    /// This is mostly useful in window rules, for example to keep a picture-in-picture
+    /// player at 16:9 or to give a dropdown terminal a fixed size.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the aspect ratio isn't positive.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pinnacle_api::window::{self, SizeConstraints};
+    /// # use pinnacle_api::util::Size;
+    /// window::add_window_rule(|window| {
+    ///     if window.title() == "Picture-in-Picture" {
+    ///         window.set_size_constraints(SizeConstraints {
+    ///             max_size: Some(Size { w: 960, h: 540 }),
+    ///             ..SizeConstraints::keep_aspect_ratio(16, 9)
+    ///         });
+    ///     }
+    /// });
+    /// ```
+    pub fn set_size_constraints(&self, constraints: SizeConstraints) {
+        let window_id = self.id;
+
+        Client::window()
+            .set_size_constraints(SetSizeConstraintsRequest {
+                window_id,
+                constraints: Some(constraints.into()),
+            })
+            .block_on_tokio()
+            .log_err();
+    }
+
+    /// Floats this window and moves and resizes it to fill `region` of its output.
+    ///
+    /// The region doesn't include space taken up by layer surfaces like bars.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `region` is a [`Region::GridCell`] outside the grid set with
+    /// [`set_snap_grid`] or spans no cells.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pinnacle_api::window::{self, Region};
+    /// # || {
+    /// window::get_focused()?.snap_to_region(Region::LeftHalf);
+    /// window::get_focused()?.snap_to_region(Region::GridCell {
+    ///     column: 1,
+    ///     row: 0,
+    ///     column_span: 2,
+    ///     row_span: 1,
+    /// });
+    /// # Some(())
+    /// # };
+    /// ```
+    pub fn snap_to_region(&self, region: Region) {
         let window_id = self.id;
-        let tag_id = tag.id;
+
         Client::window()
-            .set_tag(SetTagRequest {
+            .snap_to_region(SnapToRegionRequest {
                 window_id,
-                tag_id,
-                set_or_toggle: SetOrToggle::Toggle.into(),
+                region: Some(region.into()),
             })
             .block_on_tokio()
-            .unwrap();
+            .log_err();
     }
 
-    /// Sets the exact provided tags on this window.
+    /// Asks this window to render at a different scale than its output's.
     ///
-    /// Passing in an empty collection will not change the window's tags.
+    /// This only changes the preferred scale sent to the window; Pinnacle doesn't
+    /// rescale the window itself. The window keeps its logical size, so a window that
+    /// follows the preferred scale gets its buffers scaled to fit. For example, a window
+    /// that misbehaves at a fractional scale can be asked to render at an integer one.
+    /// Windows that ignore the preferred scale aren't affected.
     ///
-    /// For ergonomics, this accepts iterators of both `TagHandle` and `&TagHandle`.
+    /// When set to `None`, this window goes back to its output's scale.
+    ///
+    /// This has no effect on Xwayland windows.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `scale` is less than 0.25.
     ///
     /// # Examples
     ///
     /// ```no_run
     /// # use pinnacle_api::window;
-    /// # use pinnacle_api::tag;
     /// # || {
-    /// let focused = window::get_focused()?;
-    /// let tag1 = tag::get("1")?;
-    /// let tag3 = tag::get("3")?;
-    ///
-    /// // Set `focused`'s tags to "1" and "3", removing all others
-    /// focused.set_tags([tag1, tag3]);
+    /// window::get_focused()?.set_scale_override(1.0);
+    /// window::get_focused()?.set_scale_override(None);
     /// # Some(())
     /// # };
     /// ```
-    pub fn set_tags<T: Borrow<TagHandle>>(&self, tags: impl IntoIterator<Item = T>) {
+    pub fn set_scale_override(&self, scale: impl Into<Option<f32>>) {
         let window_id = self.id;
-        let tag_ids = tags
-            .into_iter()
-            .map(|tag| {
-                let tag: &TagHandle = tag.borrow();
-                tag.id
-            })
-            .collect::<Vec<_>>();
 
         Client::window()
-            .set_tags(SetTagsRequest { window_id, tag_ids })
+            .set_scale_override(SetScaleOverrideRequest {
+                window_id,
+                scale: scale.into(),
+            })
             .block_on_tokio()
-            .unwrap();
+            .log_err();
     }
 
-    /// Sets this window's [`VrrDemand`].
-    ///
-    /// When set to `None`, this window has no vrr demand.
+    /// Gets the names of the [`WindowRule`]s that would apply to this window
+    /// if it opened now, in the order they would apply.
     ///
-    /// This works in conjunction with an output with
-    /// [`Vrr::OnDemand`](crate::output::Vrr::OnDemand).
-    pub fn set_vrr_demand(&self, vrr_demand: impl Into<Option<VrrDemand>>) {
+    /// This is useful for debugging rules set with [`set_window_rules`].
+    pub fn matching_window_rules(&self) -> Vec<String> {
+        self.matching_window_rules_async().block_on_tokio()
+    }
+
+    /// Async impl for [`Self::matching_window_rules`].
+    pub async fn matching_window_rules_async(&self) -> Vec<String> {
         let window_id = self.id;
-        let vrr_demand: Option<_> = vrr_demand.into();
 
         Client::window()
-            .set_vrr_demand(SetVrrDemandRequest {
-                window_id,
-                vrr_demand: vrr_demand.map(|vrr_demand| window::v1::VrrDemand {
-                    fullscreen: vrr_demand.fullscreen,
-                }),
-            })
-            .block_on_tokio()
-            .unwrap();
+            .get_matching_window_rules(GetMatchingWindowRulesRequest { window_id })
+            .await
+            .into_inner_or_default()
+            .rule_names
     }
 
     /// Raises this window to the front.
@@ -619,7 +1770,7 @@ impl WindowHandle {
         Client::window()
             .raise(RaiseRequest { window_id })
             .block_on_tokio()
-            .unwrap();
+            .log_err();
     }
 
     /// Lowers this window to the back.
@@ -628,10 +1779,55 @@ impl WindowHandle {
         Client::window()
             .lower(LowerRequest { window_id })
             .block_on_tokio()
-            .unwrap();
+            .log_err();
+    }
+
+    /// Moves this window to another stacking [`WindowLayer`] and raises it within that layer.
+    ///
+    /// [`raise`][Self::raise] and [`lower`][Self::lower] only move windows within their layer.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pinnacle_api::window::{self, WindowLayer};
+    /// // Keep a picture-in-picture window above everything else
+    /// if let Some(window) = window::get_focused() {
+    ///     window.set_layer(WindowLayer::Above);
+    /// }
+    /// ```
+    pub fn set_layer(&self, layer: WindowLayer) {
+        let window_id = self.id;
+        Client::window()
+            .set_layer(SetLayerRequest {
+                window_id,
+                layer: window::v1::WindowLayer::from(layer).into(),
+            })
+            .block_on_tokio()
+            .log_err();
+    }
+
+    /// Gets this window's stacking [`WindowLayer`].
+    ///
+    /// Returns `None` if this window doesn't exist.
+    pub fn layer(&self) -> Option<WindowLayer> {
+        self.layer_async().block_on_tokio()
+    }
+
+    /// Async impl for [`Self::layer`].
+    pub async fn layer_async(&self) -> Option<WindowLayer> {
+        let window_id = self.id;
+        Client::window()
+            .get_layer(GetLayerRequest { window_id })
+            .await
+            .into_inner_or_default()
+            .layer()
+            .try_into()
+            .ok()
     }
 
     /// Gets this window's current location in the global space.
+    ///
+    /// Returns `None` if this window doesn't exist or isn't mapped.
     pub fn loc(&self) -> Option<Point> {
         self.loc_async().block_on_tokio()
     }
@@ -642,13 +1838,14 @@ impl WindowHandle {
         Client::window()
             .get_loc(GetLocRequest { window_id })
             .await
-            .unwrap()
-            .into_inner()
+            .into_inner_or_default()
             .loc
             .map(|loc| Point { x: loc.x, y: loc.y })
     }
 
     /// Gets this window's current size.
+    ///
+    /// Returns `None` if this window doesn't exist or isn't mapped.
     pub fn size(&self) -> Option<Size> {
         self.size_async().block_on_tokio()
     }
@@ -659,8 +1856,7 @@ impl WindowHandle {
         Client::window()
             .get_size(GetSizeRequest { window_id })
             .await
-            .unwrap()
-            .into_inner()
+            .into_inner_or_default()
             .size
             .map(|size| Size {
                 w: size.width,
@@ -668,9 +1864,29 @@ impl WindowHandle {
             })
     }
 
+    /// Returns whether this window still exists.
+    ///
+    /// Handles outlive the windows they refer to once those windows close.
+    /// Methods on such handles do nothing. Getters whose values could be mistaken
+    /// for a real one return `None`; the rest document what they return instead.
+    pub fn exists(&self) -> bool {
+        self.exists_async().block_on_tokio()
+    }
+
+    /// Async impl for [`Self::exists`].
+    pub async fn exists_async(&self) -> bool {
+        let window_id = self.id;
+        Client::window()
+            .exists(ExistsRequest { window_id })
+            .await
+            .into_inner_or_default()
+            .exists
+    }
+
     /// Gets this window's app id (class if it's an xwayland window).
     ///
-    /// If it doesn't have one, this returns an empty string.
+    /// If it doesn't have one or doesn't exist, this returns an empty string,
+    /// the same as a window that never set one. Use [`Self::exists`] to tell them apart.
     pub fn app_id(&self) -> String {
         self.app_id_async().block_on_tokio()
     }
@@ -681,14 +1897,14 @@ impl WindowHandle {
         Client::window()
             .get_app_id(GetAppIdRequest { window_id })
             .await
-            .unwrap()
-            .into_inner()
+            .into_inner_or_default()
             .app_id
     }
 
     /// Gets this window's title.
     ///
-    /// If it doesn't have one, this returns an empty string.
+    /// If it doesn't have one or doesn't exist, this returns an empty string,
+    /// the same as a window that never set one. Use [`Self::exists`] to tell them apart.
     pub fn title(&self) -> String {
         self.title_async().block_on_tokio()
     }
@@ -699,8 +1915,7 @@ impl WindowHandle {
         Client::window()
             .get_title(GetTitleRequest { window_id })
             .await
-            .unwrap()
-            .into_inner()
+            .into_inner_or_default()
             .title
     }
 
@@ -716,10 +1931,12 @@ impl WindowHandle {
 
     /// Async impl for [`Self::output`].
     pub async fn output_async(&self) -> Option<OutputHandle> {
-        Some(self.tags_async().await.next()?.output())
+        self.tags_async().await.next()?.output_async().await
     }
 
     /// Gets whether or not this window has keyboard focus.
+    ///
+    /// Returns `false` if this window doesn't exist, as it can't hold focus.
     pub fn focused(&self) -> bool {
         self.focused_async().block_on_tokio()
     }
@@ -730,47 +1947,92 @@ impl WindowHandle {
         Client::window()
             .get_focused(GetFocusedRequest { window_id })
             .await
-            .unwrap()
-            .into_inner()
+            .into_inner_or_default()
             .focused
     }
 
+    /// Gets how many windows were focused more recently than this one.
+    ///
+    /// The most recently focused window has a focus age of 0. Sorting windows by
+    /// their focus age gives the most-recently-used order, like for an alt-tab switcher.
+    ///
+    /// Returns `None` if this window was never focused or doesn't exist.
+    pub fn focus_age(&self) -> Option<u32> {
+        self.focus_age_async().block_on_tokio()
+    }
+
+    /// Async impl for [`Self::focus_age`].
+    pub async fn focus_age_async(&self) -> Option<u32> {
+        let window_id = self.id;
+        Client::window()
+            .get_focus_age(GetFocusAgeRequest { window_id })
+            .await
+            .into_inner_or_default()
+            .focus_age
+    }
+
+    /// Gets whether or not this window is responding to the compositor.
+    ///
+    /// Windows that take too long to respond, usually because the app froze,
+    /// are unresponsive until they catch up.
+    ///
+    /// Returns `true` if this window doesn't exist, so closed windows aren't
+    /// mistaken for frozen ones.
+    pub fn is_responsive(&self) -> bool {
+        self.is_responsive_async().block_on_tokio()
+    }
+
+    /// Async impl for [`Self::is_responsive`].
+    pub async fn is_responsive_async(&self) -> bool {
+        let window_id = self.id;
+        Client::window()
+            .get_responsive(GetResponsiveRequest { window_id })
+            .await
+            .into_inner_or_default()
+            .responsive
+    }
+
     /// Gets this window's current [`LayoutMode`].
-    pub fn layout_mode(&self) -> LayoutMode {
+    ///
+    /// Returns `None` if this window doesn't exist.
+    pub fn layout_mode(&self) -> Option<LayoutMode> {
         self.layout_mode_async().block_on_tokio()
     }
 
     /// Async impl for [`Self::layout_mode`].
-    pub async fn layout_mode_async(&self) -> LayoutMode {
+    pub async fn layout_mode_async(&self) -> Option<LayoutMode> {
         let window_id = self.id;
         Client::window()
             .get_layout_mode(GetLayoutModeRequest { window_id })
             .await
-            .unwrap()
-            .into_inner()
+            .into_inner_or_default()
             .layout_mode()
             .try_into()
-            .unwrap_or(LayoutMode::Tiled)
+            .ok()
     }
 
     /// Gets whether or not this window is floating.
+    ///
+    /// Returns `false` if this window doesn't exist.
     pub fn floating(&self) -> bool {
         self.floating_async().block_on_tokio()
     }
 
     /// Async impl for [`Self::floating`].
     pub async fn floating_async(&self) -> bool {
-        self.layout_mode_async().await == LayoutMode::Floating
+        self.layout_mode_async().await == Some(LayoutMode::Floating)
     }
 
     /// Gets whether or not this window is tiled.
+    ///
+    /// Returns `false` if this window doesn't exist.
     pub fn tiled(&self) -> bool {
         self.tiled_async().block_on_tokio()
     }
 
     /// Async impl for [`Self::tiled`].
     pub async fn tiled_async(&self) -> bool {
-        self.layout_mode_async().await == LayoutMode::Tiled
+        self.layout_mode_async().await == Some(LayoutMode::Tiled)
     }
 
     // /// Gets whether or not this window is spilled from the layout.
@@ -788,26 +2050,103 @@ impl WindowHandle {
     // }
 
     /// Gets whether or not this window is fullscreen.
+    ///
+    /// Returns `false` if this window doesn't exist.
     pub fn fullscreen(&self) -> bool {
         self.fullscreen_async().block_on_tokio()
     }
 
     /// Async impl for [`Self::fullscreen`].
     pub async fn fullscreen_async(&self) -> bool {
-        self.layout_mode_async().await == LayoutMode::Fullscreen
+        self.layout_mode_async().await == Some(LayoutMode::Fullscreen)
     }
 
     /// Gets whether or not this window is maximized.
+    ///
+    /// Returns `false` if this window doesn't exist.
     pub fn maximized(&self) -> bool {
         self.maximized_async().block_on_tokio()
     }
 
     /// Async impl for [`Self::maximized`].
     pub async fn maximized_async(&self) -> bool {
-        self.layout_mode_async().await == LayoutMode::Maximized
+        self.layout_mode_async().await == Some(LayoutMode::Maximized)
+    }
+
+    /// Gets whether or not this window is minimized.
+    ///
+    /// Returns `false` if this window doesn't exist.
+    pub fn minimized(&self) -> bool {
+        self.minimized_async().block_on_tokio()
+    }
+
+    /// Async impl for [`Self::minimized`].
+    pub async fn minimized_async(&self) -> bool {
+        let window_id = self.id;
+        Client::window()
+            .get_minimized(GetMinimizedRequest { window_id })
+            .await
+            .into_inner_or_default()
+            .minimized
+    }
+
+    /// Gets whether or not this window demands attention.
+    ///
+    /// Windows demand attention through xdg-activation or X11 urgency hints
+    /// and stop once they're focused.
+    ///
+    /// Returns `false` if this window doesn't exist, as it can no longer be focused.
+    pub fn demands_attention(&self) -> bool {
+        self.demands_attention_async().block_on_tokio()
+    }
+
+    /// Async impl for [`Self::demands_attention`].
+    pub async fn demands_attention_async(&self) -> bool {
+        let window_id = self.id;
+        Client::window()
+            .get_demands_attention(GetDemandsAttentionRequest { window_id })
+            .await
+            .into_inner_or_default()
+            .demands_attention
+    }
+
+    /// Gets this window's icon.
+    ///
+    /// This is the icon the window set through xdg-toplevel-icon or, if it didn't,
+    /// the icon named in the desktop entry for its app id.
+    ///
+    /// Returns `None` if the window has no icon or doesn't exist.
+    pub fn icon(&self) -> Option<WindowIcon> {
+        self.icon_async().block_on_tokio()
+    }
+
+    /// Async impl for [`Self::icon`].
+    pub async fn icon_async(&self) -> Option<WindowIcon> {
+        let window_id = self.id;
+        let response = Client::window()
+            .get_icon(GetIconRequest { window_id })
+            .await
+            .into_inner_or_default();
+
+        let images = response
+            .images
+            .into_iter()
+            .map(|image| IconImage {
+                size: image.size,
+                scale: image.scale,
+                rgba: image.rgba,
+            })
+            .collect::<Vec<_>>();
+
+        (response.name.is_some() || !images.is_empty()).then_some(WindowIcon {
+            name: response.name,
+            images,
+        })
     }
 
     /// Gets handles to all tags on this window.
+    ///
+    /// Yields nothing if this window doesn't exist.
     pub fn tags(&self) -> impl Iterator<Item = TagHandle> + use<> {
         self.tags_async().block_on_tokio()
     }
@@ -818,14 +2157,55 @@ impl WindowHandle {
         Client::window()
             .get_tag_ids(GetTagIdsRequest { window_id })
             .await
-            .unwrap()
-            .into_inner()
+            .into_inner_or_default()
             .tag_ids
             .into_iter()
             .map(|id| TagHandle { id })
     }
 
+    /// Gets this window's parent.
+    ///
+    /// This is the window a dialog was opened for, set through xdg-toplevel's parent
+    /// or X11's transient-for hint.
+    ///
+    /// Returns `None` if this window has no parent or doesn't exist.
+    pub fn parent(&self) -> Option<WindowHandle> {
+        self.parent_async().block_on_tokio()
+    }
+
+    /// Async impl for [`Self::parent`].
+    pub async fn parent_async(&self) -> Option<WindowHandle> {
+        let window_id = self.id;
+        Client::window()
+            .get_parent(GetParentRequest { window_id })
+            .await
+            .into_inner_or_default()
+            .parent_id
+            .map(|id| WindowHandle { id })
+    }
+
+    /// Gets the windows whose [parent][Self::parent] is this window.
+    ///
+    /// Yields nothing if this window doesn't exist.
+    pub fn children(&self) -> impl Iterator<Item = WindowHandle> + use<> {
+        self.children_async().block_on_tokio()
+    }
+
+    /// Async impl for [`Self::children`].
+    pub async fn children_async(&self) -> impl Iterator<Item = WindowHandle> + use<> {
+        let window_id = self.id;
+        Client::window()
+            .get_children(GetChildrenRequest { window_id })
+            .await
+            .into_inner_or_default()
+            .child_ids
+            .into_iter()
+            .map(|id| WindowHandle { id })
+    }
+
     /// Gets whether or not this window has an active tag.
+    ///
+    /// Returns `false` if this window doesn't exist, as it has no tags.
     pub fn is_on_active_tag(&self) -> bool {
         self.is_on_active_tag_async().block_on_tokio()
     }
@@ -839,6 +2219,8 @@ impl WindowHandle {
     }
 
     /// Gets all windows in the provided direction, sorted closest to farthest.
+    ///
+    /// Yields nothing if this window doesn't exist.
     pub fn in_direction(&self, direction: Direction) -> impl Iterator<Item = WindowHandle> + use<> {
         self.in_direction_async(direction).block_on_tokio()
     }
@@ -865,13 +2247,14 @@ impl WindowHandle {
         let response = Client::window()
             .get_windows_in_dir(request)
             .await
-            .unwrap()
-            .into_inner();
+            .into_inner_or_default();
 
         response.window_ids.into_iter().map(WindowHandle::from_id)
     }
 
     /// Gets this window's ext-foreign-toplevel-list handle identifier.
+    ///
+    /// Returns `None` if this window doesn't exist.
     pub fn foreign_toplevel_list_identifier(&self) -> Option<String> {
         self.foreign_toplevel_list_identifier_async()
             .block_on_tokio()
@@ -886,11 +2269,55 @@ impl WindowHandle {
                 window_id,
             })
             .await
-            .unwrap()
-            .into_inner()
+            .into_inner_or_default()
             .identifier
     }
 
+    /// Watches this window's properties, calling `on_change` when they change.
+    ///
+    /// `on_change` is first called with every property set, then with only the properties
+    /// that changed. Use this instead of polling properties to keep something like a bar
+    /// up to date.
+    ///
+    /// Watching ends when the window closes or the returned [`WatchHandle`] is stopped.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pinnacle_api::window;
+    /// # || {
+    /// let watch = window::get_focused()?.watch(|change| {
+    ///     if let Some(title) = change.title {
+    ///         println!("New title: {title}");
+    ///     }
+    /// });
+    /// # Some(())
+    /// # };
+    /// ```
+    pub fn watch(
+        &self,
+        mut on_change: impl FnMut(WindowPropertiesChange) + Send + 'static,
+    ) -> WatchHandle {
+        let window_id = self.id;
+
+        let join_handle = tokio::spawn(async move {
+            let Ok(response) = Client::window()
+                .watch_properties(WatchPropertiesRequest { window_id })
+                .await
+            else {
+                return;
+            };
+
+            let mut changes = response.into_inner();
+
+            while let Some(Ok(change)) = changes.next().await {
+                on_change(change.into());
+            }
+        });
+
+        WatchHandle::new(join_handle)
+    }
+
     /// Swap position with another window.
     pub fn swap(&self, target: &WindowHandle) {
         self.swap_async(target).block_on_tokio()
@@ -903,7 +2330,48 @@ impl WindowHandle {
             target_id: target.id,
         };
 
-        Client::window().swap(request).await.unwrap();
+        Client::window().swap(request).await.log_err();
+    }
+
+    /// Swaps position with the window nearest to this one in the given direction
+    /// on the same output.
+    ///
+    /// Like [`swap`][Self::swap], this doesn't run the layout again.
+    /// Returns the window swapped with, or `None` if there's no window in that direction.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pinnacle_api::window;
+    /// # use pinnacle_api::util::Direction;
+    /// if let Some(focused) = window::get_focused() {
+    ///     focused.swap_in_direction(Direction::Left);
+    /// }
+    /// ```
+    pub fn swap_in_direction(&self, direction: Direction) -> Option<WindowHandle> {
+        self.swap_in_direction_async(direction).block_on_tokio()
+    }
+
+    /// Async impl for [`Self::swap_in_direction`].
+    pub async fn swap_in_direction_async(&self, direction: Direction) -> Option<WindowHandle> {
+        let mut request = SwapInDirectionRequest {
+            window_id: self.id,
+            dir: Default::default(),
+        };
+
+        request.set_dir(match direction {
+            Direction::Left => pinnacle_api_defs::pinnacle::util::v1::Dir::Left,
+            Direction::Right => pinnacle_api_defs::pinnacle::util::v1::Dir::Right,
+            Direction::Up => pinnacle_api_defs::pinnacle::util::v1::Dir::Up,
+            Direction::Down => pinnacle_api_defs::pinnacle::util::v1::Dir::Down,
+        });
+
+        Client::window()
+            .swap_in_direction(request)
+            .await
+            .into_inner_or_default()
+            .target_id
+            .map(WindowHandle::from_id)
     }
 
     /// Gets this window's raw compositor id.
@@ -989,3 +2457,217 @@ pub fn add_window_rule(mut for_all: impl FnMut(WindowHandle) + Send + 'static) {
 
     tokio::spawn(fut);
 }
+
+/// A window rule that Pinnacle matches and applies itself.
+///
+/// Unlike rules added with [`add_window_rule`], these are plain data, so they can be
+/// replaced at any time with [`set_window_rules`] and inspected with
+/// [`WindowHandle::matching_window_rules`].
+///
+/// Declarative rules are applied before any rules added with [`add_window_rule`],
+/// which can still override them.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct WindowRule {
+    /// A name for this rule, reported by [`WindowHandle::matching_window_rules`].
+    pub name: String,
+    /// Rules with a higher priority are applied later.
+    pub priority: i32,
+    /// Match windows whose app id is exactly this.
+    pub app_id: Option<String>,
+    /// Match windows whose title contains this.
+    pub title: Option<String>,
+    /// The layout mode to open matching windows in.
+    pub layout_mode: Option<LayoutMode>,
+    /// The decoration mode of matching windows.
+    pub decoration_mode: Option<DecorationMode>,
+    /// Where matching windows float.
+    pub floating_loc: Option<Point>,
+    /// The size of matching windows when floating.
+    pub floating_size: Option<Size>,
+    /// The tags matching windows open on. An empty list leaves tags alone.
+    pub tags: Vec<TagHandle>,
+    /// Whether matching windows should be focused when they open.
+    pub focused: Option<bool>,
+    /// How matching windows asking to be maximized are handled.
+    pub maximize_request_policy: Option<MaximizeRequestPolicy>,
+    /// Limits on the size of matching windows.
+    pub size_constraints: SizeConstraints,
+    /// The border drawn around matching windows.
+    ///
+    /// Use a style with a `width` and `titlebar_height` of 0 to draw no border.
+    pub border_style: Option<BorderStyle>,
+}
+
+/// How multiple matching [`WindowRule`]s are combined.
+#[derive(Debug, Clone, Copy, Default, Hash, PartialEq, Eq)]
+pub enum WindowRuleStrategy {
+    /// Apply every matching rule in priority order, with later rules
+    /// overriding properties set by earlier ones.
+    #[default]
+    Merge,
+    /// Apply only the matching rule with the highest priority.
+    LastMatchWins,
+}
+
+/// Replaces the set of declarative [`WindowRule`]s.
+///
+/// This can be called again at any time to update the rules; new windows
+/// will use the latest set. Use [`apply_rules_to_existing`] to apply them
+/// to windows that are already open.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::window;
+/// # use pinnacle_api::window::{LayoutMode, WindowRule, WindowRuleStrategy};
+/// window::set_window_rules(
+///     [
+///         WindowRule {
+///             name: "float dialogs".into(),
+///             title: Some("Open File".into()),
+///             layout_mode: Some(LayoutMode::Floating),
+///             ..Default::default()
+///         },
+///         WindowRule {
+///             name: "tile firefox".into(),
+///             priority: 10,
+///             app_id: Some("firefox".into()),
+///             layout_mode: Some(LayoutMode::Tiled),
+///             ..Default::default()
+///         },
+///     ],
+///     WindowRuleStrategy::Merge,
+/// );
+/// ```
+pub fn set_window_rules(rules: impl IntoIterator<Item = WindowRule>, strategy: WindowRuleStrategy) {
+    let rules = rules
+        .into_iter()
+        .map(|rule| DeclarativeWindowRule {
+            name: rule.name,
+            priority: rule.priority,
+            app_id: rule.app_id,
+            title: rule.title,
+            layout_mode: match rule.layout_mode {
+                None => window::v1::LayoutMode::Unspecified,
+                Some(LayoutMode::Tiled) => window::v1::LayoutMode::Tiled,
+                Some(LayoutMode::Floating) => window::v1::LayoutMode::Floating,
+                Some(LayoutMode::Fullscreen) => window::v1::LayoutMode::Fullscreen,
+                Some(LayoutMode::Maximized) => window::v1::LayoutMode::Maximized,
+            }
+            .into(),
+            decoration_mode: match rule.decoration_mode {
+                None => window::v1::DecorationMode::Unspecified,
+                Some(DecorationMode::ClientSide) => window::v1::DecorationMode::ClientSide,
+                Some(DecorationMode::ServerSide) => window::v1::DecorationMode::ServerSide,
+            }
+            .into(),
+            floating_x: rule.floating_loc.map(|loc| loc.x),
+            floating_y: rule.floating_loc.map(|loc| loc.y),
+            floating_w: rule.floating_size.map(|size| size.w),
+            floating_h: rule.floating_size.map(|size| size.h),
+            tag_ids: rule.tags.into_iter().map(|tag| tag.id).collect(),
+            focused: rule.focused,
+            maximize_request_policy: rule
+                .maximize_request_policy
+                .map_or(window::v1::MaximizeRequestPolicy::Unspecified, From::from)
+                .into(),
+            size_constraints: Some(rule.size_constraints.into()),
+            border_style: rule.border_style.map(From::from),
+        })
+        .collect();
+
+    Client::window()
+        .set_window_rules(SetWindowRulesRequest {
+            rules,
+            strategy: match strategy {
+                WindowRuleStrategy::Merge => window::v1::WindowRuleStrategy::Merge,
+                WindowRuleStrategy::LastMatchWins => window::v1::WindowRuleStrategy::LastMatchWins,
+            }
+            .into(),
+        })
+        .block_on_tokio()
+        .log_err();
+}
+
+/// A window property that a [`WindowRule`] can set.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum WindowRuleProperty {
+    /// [`WindowRule::layout_mode`].
+    LayoutMode,
+    /// [`WindowRule::floating_loc`] or [`WindowRule::floating_size`].
+    FloatingGeometry,
+    /// [`WindowRule::decoration_mode`].
+    DecorationMode,
+    /// [`WindowRule::tags`].
+    Tags,
+    /// [`WindowRule::maximize_request_policy`].
+    MaximizeRequestPolicy,
+    /// [`WindowRule::size_constraints`].
+    SizeConstraints,
+    /// [`WindowRule::border_style`].
+    BorderStyle,
+}
+
+/// What changed about a window when [`apply_rules_to_existing`] applied rules to it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WindowRuleChanges {
+    /// The window that changed.
+    pub window: WindowHandle,
+    /// The properties of the window that changed.
+    pub changed: Vec<WindowRuleProperty>,
+}
+
+/// Applies the current declarative [`WindowRule`]s to windows that are already open.
+///
+/// Rules normally only apply to windows when they open, so call this after
+/// [`set_window_rules`] to have existing windows follow the new rules too.
+/// [`WindowRule::focused`] is skipped as it only makes sense when a window opens,
+/// and rules added with [`add_window_rule`] aren't run again.
+///
+/// Returns the windows that changed along with what changed about them.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::window;
+/// for changes in window::apply_rules_to_existing() {
+///     println!("{:?} changed {:?}", changes.window.app_id(), changes.changed);
+/// }
+/// ```
+pub fn apply_rules_to_existing() -> Vec<WindowRuleChanges> {
+    Client::window()
+        .apply_rules_to_existing(ApplyRulesToExistingRequest {})
+        .block_on_tokio()
+        .into_inner_or_default()
+        .windows
+        .into_iter()
+        .map(|changes| WindowRuleChanges {
+            window: WindowHandle::from_id(changes.window_id),
+            changed: changes
+                .changed()
+                .filter_map(|property| match property {
+                    window::v1::WindowRuleProperty::Unspecified => None,
+                    window::v1::WindowRuleProperty::LayoutMode => {
+                        Some(WindowRuleProperty::LayoutMode)
+                    }
+                    window::v1::WindowRuleProperty::FloatingGeometry => {
+                        Some(WindowRuleProperty::FloatingGeometry)
+                    }
+                    window::v1::WindowRuleProperty::DecorationMode => {
+                        Some(WindowRuleProperty::DecorationMode)
+                    }
+                    window::v1::WindowRuleProperty::Tags => Some(WindowRuleProperty::Tags),
+                    window::v1::WindowRuleProperty::MaximizeRequestPolicy => {
+                        Some(WindowRuleProperty::MaximizeRequestPolicy)
+                    }
+                    window::v1::WindowRuleProperty::SizeConstraints => {
+                        Some(WindowRuleProperty::SizeConstraints)
+                    }
+                    window::v1::WindowRuleProperty::BorderStyle => {
+                        Some(WindowRuleProperty::BorderStyle)
+                    }
+                })
+                .collect(),
+        })
+        .collect()
+}
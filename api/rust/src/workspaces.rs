@@ -0,0 +1,159 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Numbered workspaces.
+//!
+//! This module emulates sway and i3 style workspaces on top of the [tag system][crate::tag].
+//! Every output gets tags named `1` through `n`, exactly one of which is active at a time,
+//! and windows live on a single workspace.
+//!
+//! Workspaces belong to outputs, so workspace 2 on one monitor is different from
+//! workspace 2 on another. Every function here acts on the focused output.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! # use pinnacle_api::input::{self, Mod};
+//! # use pinnacle_api::window;
+//! # use pinnacle_api::workspaces;
+//! workspaces::setup(10);
+//!
+//! for n in 1..=9 {
+//!     let key = char::from_digit(n, 10).unwrap();
+//!
+//!     input::keybind(Mod::SUPER, key).on_press(move || {
+//!         workspaces::switch_to_or_back(n);
+//!     });
+//!
+//!     input::keybind(Mod::SUPER | Mod::SHIFT, key).on_press(move || {
+//!         if let Some(window) = window::get_focused() {
+//!             workspaces::move_window_to(&window, n);
+//!         }
+//!     });
+//! }
+//!
+//! input::keybind(Mod::SUPER, pinnacle_api::Keysym::Tab).on_press(|| {
+//!     workspaces::back_and_forth();
+//! });
+//! ```
+
+use crate::{
+    output::{self, OutputHandle},
+    tag::{self, TagHandle},
+    window::WindowHandle,
+};
+
+/// Adds workspaces `1` through `count` to every output, now and as outputs are connected.
+///
+/// The first workspace of each output starts out active.
+pub fn setup(count: u32) {
+    output::for_each_output(move |output| {
+        let mut workspaces = tag::add(output, (1..=count).map(|number| number.to_string()));
+        if let Some(first) = workspaces.next() {
+            first.set_active(true);
+        }
+    });
+}
+
+/// Switches to workspace `number`.
+///
+/// Returns a handle to the workspace's tag, or `None` if there is no such workspace.
+pub fn switch_to(number: u32) -> Option<TagHandle> {
+    tag::switch_to_name(None, number)
+}
+
+/// Switches to workspace `number`, or back to the previous workspace if `number` is
+/// already shown, like `workspace_auto_back_and_forth` in sway and i3.
+///
+/// Returns handles to the tags that were switched to.
+pub fn switch_to_or_back(number: u32) -> Vec<TagHandle> {
+    if current() == Some(number) {
+        back_and_forth()
+    } else {
+        switch_to(number).into_iter().collect()
+    }
+}
+
+/// Switches back to the previously shown workspace.
+///
+/// Returns handles to the tags that were switched to, which are empty if there
+/// was nothing to go back to.
+pub fn back_and_forth() -> Vec<TagHandle> {
+    tag::switch_to_previous(None)
+}
+
+/// Switches to the next workspace, wrapping around to the first.
+///
+/// Returns a handle to the workspace's tag, or `None` if there are no workspaces.
+pub fn next() -> Option<TagHandle> {
+    cycle(1)
+}
+
+/// Switches to the previous workspace, wrapping around to the last.
+///
+/// Returns a handle to the workspace's tag, or `None` if there are no workspaces.
+pub fn prev() -> Option<TagHandle> {
+    cycle(-1)
+}
+
+/// Moves `window` to workspace `number` on the focused output.
+pub fn move_window_to(window: &WindowHandle, number: u32) {
+    if let Some(workspace) = tag::get(number) {
+        window.move_to_tag(&workspace);
+    }
+}
+
+/// Moves `window` to workspace `number` on the focused output and switches to it.
+pub fn move_window_to_and_follow(window: &WindowHandle, number: u32) {
+    if let Some(workspace) = tag::get(number) {
+        window.move_to_tag_and_follow(&workspace);
+    }
+}
+
+/// Returns the number of the shown workspace.
+///
+/// Returns `None` if no workspace is shown, or if several tags are active,
+/// which can happen when tags are changed outside of this module.
+pub fn current() -> Option<u32> {
+    let output = output::get_focused()?;
+
+    let mut active = output.active_tags();
+    let workspace = number(&active.next()?)?;
+
+    active.next().is_none().then_some(workspace)
+}
+
+/// Returns the number of a workspace's tag, or `None` if the tag isn't a workspace.
+fn number(tag: &TagHandle) -> Option<u32> {
+    tag.name()?.parse().ok()
+}
+
+/// Returns the workspaces on `output`, sorted by number.
+fn workspaces(output: &OutputHandle) -> Vec<(u32, TagHandle)> {
+    let mut workspaces = output
+        .tags()
+        .filter_map(|tag| Some((number(&tag)?, tag)))
+        .collect::<Vec<_>>();
+    workspaces.sort_by_key(|(number, _)| *number);
+    workspaces
+}
+
+fn cycle(offset: isize) -> Option<TagHandle> {
+    let output = output::get_focused()?;
+    let workspaces = workspaces(&output);
+
+    let index = match current() {
+        Some(current) => {
+            let index = workspaces
+                .iter()
+                .position(|(number, _)| *number == current)?;
+            (index as isize + offset).rem_euclid(workspaces.len() as isize) as usize
+        }
+        None => 0,
+    };
+
+    let (_, workspace) = workspaces.into_iter().nth(index)?;
+    workspace.switch_to();
+    Some(workspace)
+}
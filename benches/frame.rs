@@ -0,0 +1,282 @@
+//! Benchmarks for layout and rendering under synthetic loads.
+//!
+//! These run on the dummy backend, so they need the `testing` feature:
+//! `cargo bench --no-default-features --features testing`, or `just bench`.
+//!
+//! Pass a substring to only run the benchmarks whose names contain it.
+//! If `PINNACLE_BENCH_FRAME_BUDGET_US` is set, the run fails when the median
+//! full frame time for any window count exceeds that many microseconds.
+
+#[allow(dead_code)]
+#[path = "../tests/integration/common/mod.rs"]
+mod common;
+
+use std::{
+    collections::HashMap,
+    time::{Duration, Instant},
+};
+
+use pinnacle::{
+    layout::tree::{LayoutNode, LayoutTree},
+    state::WithState,
+    tag::Tag,
+};
+use pinnacle_api::layout::{LayoutGenerator as _, generators::MasterStack};
+use smithay::{
+    backend::renderer::{
+        damage::OutputDamageTracker,
+        element::surface::WaylandSurfaceRenderElement,
+        test::{DummyFramebuffer, DummyRenderer},
+    },
+    output::Output,
+    utils::{Rectangle, Scale},
+};
+
+use crate::common::fixture::Fixture;
+
+const WINDOW_COUNTS: [u8; 3] = [1, 10, 50];
+const WARMUP_ITERATIONS: usize = 10;
+const SAMPLES: usize = 100;
+const FRAME_BUDGET_VAR: &str = "PINNACLE_BENCH_FRAME_BUDGET_US";
+
+#[derive(Debug, Clone, Copy)]
+struct Summary {
+    median: Duration,
+    p95: Duration,
+    min: Duration,
+}
+
+struct Bencher {
+    filter: Option<String>,
+}
+
+impl Bencher {
+    fn from_args() -> Self {
+        // `cargo bench` passes `--bench`; anything else is a name filter
+        let filter = std::env::args().skip(1).find(|arg| !arg.starts_with('-'));
+        Self { filter }
+    }
+
+    fn is_enabled(&self, name: &str) -> bool {
+        self.filter
+            .as_ref()
+            .is_none_or(|filter| name.contains(filter.as_str()))
+    }
+
+    fn bench(&self, name: &str, mut routine: impl FnMut()) -> Option<Summary> {
+        if !self.is_enabled(name) {
+            return None;
+        }
+
+        for _ in 0..WARMUP_ITERATIONS {
+            routine();
+        }
+
+        let mut samples = (0..SAMPLES)
+            .map(|_| {
+                let start = Instant::now();
+                routine();
+                start.elapsed()
+            })
+            .collect::<Vec<_>>();
+        samples.sort();
+
+        let summary = Summary {
+            median: samples[SAMPLES / 2],
+            p95: samples[SAMPLES * 95 / 100],
+            min: samples[0],
+        };
+
+        println!(
+            "{name:<36} median {:>12}  p95 {:>12}  min {:>12}",
+            format!("{:?}", summary.median),
+            format!("{:?}", summary.p95),
+            format!("{:?}", summary.min),
+        );
+
+        Some(summary)
+    }
+}
+
+fn set_up(window_count: u8) -> (Fixture, Output) {
+    let mut fixture = Fixture::new();
+
+    let output = fixture.add_output(Rectangle::new((0, 0).into(), (1920, 1080).into()));
+    output.with_state_mut(|state| {
+        let tag = Tag::new("1".to_string());
+        tag.set_active(true);
+        state.add_tags([tag]);
+    });
+    fixture.pinnacle().focus_output(&output);
+
+    fixture
+        .runtime_handle()
+        .block_on(pinnacle_api::connect())
+        .unwrap();
+
+    fixture.spawn_blocking(|| {
+        pinnacle_api::layout::manage(|args| pinnacle_api::layout::LayoutResponse {
+            root_node: MasterStack::default().layout(args.window_count),
+            tree_id: 0,
+        });
+    });
+
+    let client_id = fixture.add_client();
+    fixture.spawn_windows(window_count, client_id);
+
+    (fixture, output)
+}
+
+/// Builds a layout tree with one master tile and a column of the rest.
+fn master_stack_node(window_count: u32) -> LayoutNode {
+    let node = |traversal_index, style, children| LayoutNode {
+        label: None,
+        traversal_index,
+        traversal_overrides: HashMap::new(),
+        style,
+        children,
+    };
+
+    let tile_style = taffy::Style {
+        flex_grow: 1.0,
+        flex_basis: taffy::Dimension::percent(1.0),
+        ..Default::default()
+    };
+
+    let mut children = vec![node(0, tile_style.clone(), Vec::new())];
+
+    if window_count > 1 {
+        let stack = (0..window_count - 1)
+            .map(|i| node(i, tile_style.clone(), Vec::new()))
+            .collect();
+
+        let stack_style = taffy::Style {
+            flex_direction: taffy::FlexDirection::Column,
+            ..tile_style.clone()
+        };
+
+        children.push(node(1, stack_style, stack));
+    }
+
+    let root_style = taffy::Style {
+        flex_direction: taffy::FlexDirection::Row,
+        ..Default::default()
+    };
+
+    node(0, root_style, children)
+}
+
+/// Creates render elements for all mapped windows, from top to bottom.
+fn window_elements(
+    fixture: &mut Fixture,
+    renderer: &mut DummyRenderer,
+) -> Vec<WaylandSurfaceRenderElement<DummyRenderer>> {
+    let space = &fixture.pinnacle().space;
+
+    space
+        .elements()
+        .rev()
+        .flat_map(|win| {
+            let loc = space.element_location(win).unwrap_or_default();
+            let elements = win.render_elements(renderer, loc, Scale::from(1.0), 1.0, true);
+            elements
+                .popup_elements
+                .into_iter()
+                .chain(elements.surface_elements)
+        })
+        .collect()
+}
+
+fn main() {
+    let bencher = Bencher::from_args();
+    let mut frame_summaries = Vec::new();
+
+    for window_count in WINDOW_COUNTS {
+        let node = master_stack_node(window_count.into());
+
+        bencher.bench(&format!("layout/compute/{window_count}"), || {
+            let mut tree = LayoutTree::new(node.clone());
+            std::hint::black_box(tree.compute_geos(1920, 1080));
+        });
+
+        let needs_fixture = [
+            "layout/apply",
+            "render/elements",
+            "render/elements_uncached",
+            "frame/full",
+        ]
+        .into_iter()
+        .any(|name| bencher.is_enabled(&format!("{name}/{window_count}")));
+
+        if !needs_fixture {
+            continue;
+        }
+
+        let (mut fixture, output) = set_up(window_count);
+        let mut renderer = DummyRenderer;
+
+        bencher.bench(&format!("layout/apply/{window_count}"), || {
+            fixture
+                .state()
+                .apply_layout_tree(0, node.clone(), 0, output.name())
+                .unwrap();
+            fixture.flush();
+        });
+
+        bencher.bench(&format!("render/elements/{window_count}"), || {
+            std::hint::black_box(window_elements(&mut fixture, &mut renderer));
+        });
+
+        bencher.bench(&format!("render/elements_uncached/{window_count}"), || {
+            for win in fixture.pinnacle().windows.iter() {
+                win.with_state_mut(|state| state.render_plan_cache.invalidate());
+            }
+            std::hint::black_box(window_elements(&mut fixture, &mut renderer));
+        });
+
+        let mut damage_tracker = OutputDamageTracker::from_output(&output);
+
+        let summary = bencher.bench(&format!("frame/full/{window_count}"), || {
+            fixture.state().on_event_loop_cycle_completion();
+
+            let elements = window_elements(&mut fixture, &mut renderer);
+            damage_tracker
+                .render_output(
+                    &mut renderer,
+                    &mut DummyFramebuffer,
+                    0,
+                    &elements,
+                    [0.0, 0.0, 0.0, 1.0],
+                )
+                .unwrap();
+        });
+
+        if let Some(summary) = summary {
+            frame_summaries.push((window_count, summary));
+        }
+    }
+
+    let Some(budget) = std::env::var(FRAME_BUDGET_VAR)
+        .ok()
+        .and_then(|budget| budget.parse().ok())
+        .map(Duration::from_micros)
+    else {
+        return;
+    };
+
+    let over_budget = frame_summaries
+        .iter()
+        .filter(|(_, summary)| summary.median > budget)
+        .collect::<Vec<_>>();
+
+    for (window_count, summary) in over_budget.iter() {
+        eprintln!(
+            "frame/full/{window_count}: median {:?} is over the budget of {budget:?}",
+            summary.median
+        );
+    }
+
+    if !over_budget.is_empty() {
+        std::process::exit(1);
+    }
+}
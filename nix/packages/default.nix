@@ -151,6 +151,8 @@ rustPlatform.buildRustPackage (finalAttrs: {
     mkdir -p $out/share/xdg-desktop-portal
     install -m644 ./resources/pinnacle-portals.conf $out/share/xdg-desktop-portal/pinnacle-portals.conf
     install -m644 ./resources/pinnacle-portals.conf $out/share/xdg-desktop-portal/pinnacle-uwsm-portals.conf
+    mkdir -p $out/share/xdg-desktop-portal/portals
+    install -m644 ./resources/pinnacle.portal $out/share/xdg-desktop-portal/portals/pinnacle.portal
   '';
 
   runtimeDependencies = [
@@ -68,18 +68,37 @@ pub mod pinnacle {
                 OutputMoveRequest,
                 OutputPointerEnterRequest,
                 OutputPointerLeaveRequest,
+                OutputPointerEdgeRequest,
                 OutputFocusedRequest,
+                OutputLayerFocusedRequest,
+                OutputLayerUnfocusedRequest,
+                OutputOrientationChangedRequest,
+                OutputFullscreenChangedRequest,
+                OutputStackingChangedRequest,
+                OutputNoActiveTagsRequest,
                 WindowPointerEnterRequest,
                 WindowPointerLeaveRequest,
                 WindowFocusedRequest,
                 WindowTitleChangedRequest,
+                WindowFocusedTitleChangedRequest,
                 WindowLayoutModeChangedRequest,
                 WindowCreatedRequest,
                 WindowDestroyedRequest,
+                WindowUnresponsiveRequest,
+                WindowMinimizedRequest,
+                WindowUrgencyChangedRequest,
+                WindowPopupGrabBlockedKeybindRequest,
                 TagActiveRequest,
                 TagCreatedRequest,
                 TagRemovedRequest,
-                InputDeviceAddedRequest
+                TagUrgencyChangedRequest,
+                InputDeviceAddedRequest,
+                InputAccessibilityChangedRequest,
+                PowerOnBatteryRequest,
+                PowerChargingRequest,
+                PowerLowBatteryRequest,
+                IdleStartedRequest,
+                IdleResumedRequest
             );
         }
     }
@@ -96,6 +115,36 @@ pub mod pinnacle {
         }
     }
 
+    pub mod screenshot {
+        pub mod v1 {
+            tonic::include_proto!("pinnacle.screenshot.v1");
+        }
+    }
+
+    pub mod portal {
+        pub mod v1 {
+            tonic::include_proto!("pinnacle.portal.v1");
+        }
+    }
+
+    pub mod idle {
+        pub mod v1 {
+            tonic::include_proto!("pinnacle.idle.v1");
+        }
+    }
+
+    pub mod nightlight {
+        pub mod v1 {
+            tonic::include_proto!("pinnacle.nightlight.v1");
+        }
+    }
+
+    pub mod power {
+        pub mod v1 {
+            tonic::include_proto!("pinnacle.power.v1");
+        }
+    }
+
     pub mod util {
         pub mod v1 {
             tonic::include_proto!("pinnacle.util.v1");
@@ -804,6 +804,8 @@ local snowcap_popup_v1_PopupEvent_Focus = {
 ---@field scrollable_style snowcap.widget.v1.Scrollable.Style?
 ---@field container_style snowcap.widget.v1.Container.Style?
 ---@field button_style snowcap.widget.v1.Button.Style?
+---@field spacing number?
+---@field radius snowcap.widget.v1.Radius?
 
 ---@class snowcap.widget.v1.Palette
 ---@field background snowcap.widget.v1.Color?
@@ -825,6 +827,8 @@ local snowcap_popup_v1_PopupEvent_Focus = {
 ---@field input_region snowcap.widget.v1.InputRegion?
 ---@field mouse_area snowcap.widget.v1.MouseArea?
 ---@field text_input snowcap.widget.v1.TextInput?
+---@field stack snowcap.widget.v1.Stack?
+---@field pin snowcap.widget.v1.Pin?
 
 ---@class snowcap.widget.v1.Text
 ---@field text string?
@@ -909,6 +913,18 @@ local snowcap_popup_v1_PopupEvent_Focus = {
 ---@field border snowcap.widget.v1.Border?
 ---@field background snowcap.widget.v1.Background?
 
+---@class snowcap.widget.v1.Stack
+---@field width snowcap.widget.v1.Length?
+---@field height snowcap.widget.v1.Length?
+---@field children snowcap.widget.v1.WidgetDef[]?
+
+---@class snowcap.widget.v1.Pin
+---@field x number?
+---@field y number?
+---@field width snowcap.widget.v1.Length?
+---@field height snowcap.widget.v1.Length?
+---@field child snowcap.widget.v1.WidgetDef?
+
 ---@class snowcap.widget.v1.InputRegion
 ---@field add boolean?
 ---@field width snowcap.widget.v1.Length?
@@ -942,6 +958,7 @@ local snowcap_popup_v1_PopupEvent_Focus = {
 ---@field path string?
 ---@field bytes string?
 ---@field rgba snowcap.widget.v1.Image.Rgba?
+---@field thumbnail snowcap.widget.v1.Image.Thumbnail?
 ---@field width snowcap.widget.v1.Length?
 ---@field height snowcap.widget.v1.Length?
 ---@field expand boolean?
@@ -956,6 +973,13 @@ local snowcap_popup_v1_PopupEvent_Focus = {
 ---@field height integer?
 ---@field rgba string?
 
+---@class snowcap.widget.v1.Image.Thumbnail
+---@field output_name string?
+---@field tag_id integer?
+---@field window_id integer?
+---@field max_width integer?
+---@field max_height integer?
+
 ---@class snowcap.widget.v1.MouseArea
 ---@field child snowcap.widget.v1.WidgetDef?
 ---@field on_press boolean?
@@ -1298,12 +1322,19 @@ local snowcap_popup_v1_PopupEvent_Focus = {
 ---@class snowcap.layer.v0alpha1.CloseRequest
 ---@field layer_id integer?
 
+---@class snowcap.layer.v1.Margin
+---@field top integer?
+---@field right integer?
+---@field bottom integer?
+---@field left integer?
+
 ---@class snowcap.layer.v1.NewLayerRequest
 ---@field widget_def snowcap.widget.v1.WidgetDef?
 ---@field anchor snowcap.layer.v1.Anchor?
 ---@field keyboard_interactivity snowcap.layer.v1.KeyboardInteractivity?
 ---@field exclusive_zone integer?
 ---@field layer snowcap.layer.v1.Layer?
+---@field margin snowcap.layer.v1.Margin?
 
 ---@class snowcap.layer.v1.NewLayerResponse
 ---@field layer_id integer?
@@ -1324,6 +1355,7 @@ local snowcap_popup_v1_PopupEvent_Focus = {
 ---@field keyboard_interactivity snowcap.layer.v1.KeyboardInteractivity?
 ---@field exclusive_zone integer?
 ---@field layer snowcap.layer.v1.Layer?
+---@field margin snowcap.layer.v1.Margin?
 
 ---@class snowcap.layer.v1.UpdateLayerResponse
 
@@ -1451,6 +1483,8 @@ snowcap.widget.v1.Scrollable.Direction = {}
 snowcap.widget.v1.Scrollable.Scrollbar = {}
 snowcap.widget.v1.Container = {}
 snowcap.widget.v1.Container.Style = {}
+snowcap.widget.v1.Stack = {}
+snowcap.widget.v1.Pin = {}
 snowcap.widget.v1.InputRegion = {}
 snowcap.widget.v1.Button = {}
 snowcap.widget.v1.Button.Style = {}
@@ -1458,6 +1492,7 @@ snowcap.widget.v1.Button.Style.Inner = {}
 snowcap.widget.v1.Button.Event = {}
 snowcap.widget.v1.Image = {}
 snowcap.widget.v1.Image.Rgba = {}
+snowcap.widget.v1.Image.Thumbnail = {}
 snowcap.widget.v1.MouseArea = {}
 snowcap.widget.v1.MouseArea.Event = {}
 snowcap.widget.v1.MouseArea.ScrollEvent = {}
@@ -1531,6 +1566,7 @@ snowcap.layer.v0alpha1.NewLayerRequest = {}
 snowcap.layer.v0alpha1.NewLayerResponse = {}
 snowcap.layer.v0alpha1.CloseRequest = {}
 snowcap.layer.v1 = {}
+snowcap.layer.v1.Margin = {}
 snowcap.layer.v1.NewLayerRequest = {}
 snowcap.layer.v1.NewLayerResponse = {}
 snowcap.layer.v1.CloseRequest = {}
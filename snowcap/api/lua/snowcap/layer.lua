@@ -90,12 +90,22 @@ local function exclusive_zone_to_api(zone)
     return -1
 end
 
+---The space between a layer surface and the edges of the output it's anchored to.
+---
+---Margins only apply to edges the layer surface is anchored to.
+---@class snowcap.layer.Margin
+---@field top integer?
+---@field right integer?
+---@field bottom integer?
+---@field left integer?
+
 ---@class snowcap.layer.LayerArgs
 ---@field program snowcap.widget.Program
 ---@field anchor snowcap.layer.Anchor?
 ---@field keyboard_interactivity snowcap.layer.KeyboardInteractivity
 ---@field exclusive_zone snowcap.layer.ExclusiveZone
 ---@field layer snowcap.layer.ZLayer
+---@field margin snowcap.layer.Margin?
 
 ---@param args snowcap.layer.LayerArgs
 ---@return snowcap.layer.LayerHandle|nil handle A handle to the layer surface, or nil if an error occurred.
@@ -113,6 +123,7 @@ function layer.new_widget(args)
         exclusive_zone = exclusive_zone_to_api(args.exclusive_zone),
         anchor = args.anchor,
         keyboard_interactivity = args.keyboard_interactivity,
+        margin = args.margin,
         widget_def = widget.widget_def_into_api(widget_def),
     }
 
@@ -297,6 +308,7 @@ end
 ---@field keyboard_interactivity? snowcap.layer.KeyboardInteractivity
 ---@field exclusive_zone? snowcap.layer.ExclusiveZone
 ---@field layer? snowcap.layer.ZLayer
+---@field margin? snowcap.layer.Margin
 
 ---Update this layer's attributes.
 ---@param args snowcap.layer.LayerUpdateArgs
@@ -310,6 +322,7 @@ function LayerHandle:update(args)
         keyboard_interactivity = args.keyboard_interactivity,
         exclusive_zone = exclusive_zone,
         layer = args.layer,
+        margin = args.margin,
     })
 
     if err then
@@ -20,11 +20,21 @@
 ---@field warning snowcap.widget.Color
 ---@field danger snowcap.widget.Color
 
+---A theme applied to a widget and all of its descendants.
+---
+---Nested themes only override the fields they set, and styles set directly
+---on a widget take precedence over its theme.
 ---@class snowcap.widget.Theme
+---The palette used for the default widget styles.
 ---@field palette snowcap.widget.Palette?
 ---@field text_style snowcap.widget.text.Style?
 ---@field scrollable_style snowcap.widget.scrollable.Style?
 ---@field container_style snowcap.widget.container.Style?
+---@field button_style snowcap.widget.button.Styles?
+---The default spacing between the children of columns and rows.
+---@field spacing number?
+---The default corner radius of containers and buttons.
+---@field radius snowcap.widget.Radius?
 
 ---@class snowcap.widget.WidgetDef
 ---@field theme snowcap.widget.Theme?
@@ -38,6 +48,8 @@
 ---@field input_region snowcap.widget.InputRegion?
 ---@field mouse_area snowcap.widget.MouseArea?
 ---@field text_input snowcap.widget.TextInput?
+---@field stack snowcap.widget.Stack?
+---@field pin snowcap.widget.Pin?
 
 ---@class snowcap.widget.Border
 ---@field color snowcap.widget.Color?
@@ -175,6 +187,34 @@ local content_fit = {
 ---@field path string?
 ---@field bytes string?
 ---@field rgba { width: integer, height: integer, rgba: string }?
+---A live, downscaled image of an output, tag, or window.
+---
+---The image fits within `max_width` by `max_height` pixels and keeps the aspect ratio
+---of its output or window. Tags that aren't active are drawn as an overview of their windows.
+---@field thumbnail snowcap.widget.image.Thumbnail?
+
+---@class snowcap.widget.image.Thumbnail
+---@field output_name string? The name of the output to show
+---@field tag_id integer? The id of the tag to show
+---@field window_id integer? The id of the window to show
+---@field max_width integer
+---@field max_height integer
+
+---Draws its children on top of each other, the last child on top.
+---@class snowcap.widget.Stack
+---@field width snowcap.widget.Length?
+---@field height snowcap.widget.Length?
+---@field children snowcap.widget.WidgetDef[]
+
+---Positions its child at an offset from its top-left corner.
+---
+---Pins are usually placed in a `Stack` to lay widgets out at absolute positions.
+---@class snowcap.widget.Pin
+---@field x number?
+---@field y number?
+---@field width snowcap.widget.Length?
+---@field height snowcap.widget.Length?
+---@field child snowcap.widget.WidgetDef
 
 ---@class snowcap.widget.InputRegion
 ---@field add boolean
@@ -783,6 +823,7 @@ local function image_into_api(def)
         path = def.handle.path,
         bytes = def.handle.bytes,
         rgba = def.handle.rgba,
+        thumbnail = def.handle.thumbnail,
         width = def.width --[[@as snowcap.widget.v1.Length]],
         height = def.height --[[@as snowcap.widget.v1.Length]],
         expand = def.expand,
@@ -806,6 +847,35 @@ local function input_region_into_api(def)
     }
 end
 
+---@param def snowcap.widget.Stack
+---@return snowcap.widget.v1.Stack
+local function stack_into_api(def)
+    local children = {}
+    for _, child in ipairs(def.children) do
+        table.insert(children, widget.widget_def_into_api(child))
+    end
+
+    ---@type snowcap.widget.v1.Stack
+    return {
+        width = def.width --[[@as snowcap.widget.v1.Length]],
+        height = def.height --[[@as snowcap.widget.v1.Length]],
+        children = children,
+    }
+end
+
+---@param def snowcap.widget.Pin
+---@return snowcap.widget.v1.Pin
+local function pin_into_api(def)
+    ---@type snowcap.widget.v1.Pin
+    return {
+        x = def.x,
+        y = def.y,
+        width = def.width --[[@as snowcap.widget.v1.Length]],
+        height = def.height --[[@as snowcap.widget.v1.Length]],
+        child = widget.widget_def_into_api(def.child),
+    }
+end
+
 ---@param def snowcap.widget.MouseArea
 ---@return snowcap.widget.v1.MouseArea
 local function mouse_area_into_api(def)
@@ -884,6 +954,12 @@ function widget.widget_def_into_api(def)
     if def.text_input then
         def.text_input = text_input_into_api(def.text_input)
     end
+    if def.stack then
+        def.stack = stack_into_api(def.stack)
+    end
+    if def.pin then
+        def.pin = pin_into_api(def.pin)
+    end
 
     return def --[[@as snowcap.widget.v1.WidgetDef]]
 end
@@ -968,6 +1044,26 @@ function widget.input_region(input_region)
     }
 end
 
+---@param stack snowcap.widget.Stack
+---
+---@return snowcap.widget.WidgetDef
+function widget.stack(stack)
+    ---@type snowcap.widget.WidgetDef
+    return {
+        stack = stack,
+    }
+end
+
+---@param pin snowcap.widget.Pin
+---
+---@return snowcap.widget.WidgetDef
+function widget.pin(pin)
+    ---@type snowcap.widget.WidgetDef
+    return {
+        pin = pin,
+    }
+end
+
 ---Create a new MouseArea widget.
 ---@param mouse_area snowcap.widget.MouseArea
 ---
@@ -1045,6 +1141,12 @@ function widget._traverse_widget_tree(wgt, callbacks, with_widget)
         widget._traverse_widget_tree(wgt.input_region.child, callbacks, with_widget)
     elseif wgt.mouse_area then
         widget._traverse_widget_tree(wgt.mouse_area.child, callbacks, with_widget)
+    elseif wgt.stack then
+        for _, w in ipairs(wgt.stack.children or {}) do
+            widget._traverse_widget_tree(w, callbacks, with_widget)
+        end
+    elseif wgt.pin then
+        widget._traverse_widget_tree(wgt.pin.child, callbacks, with_widget)
     end
 end
 
@@ -17,7 +17,7 @@ pub mod signal;
 pub mod surface;
 pub mod widget;
 
-pub use surface::{decoration, layer, popup};
+pub use surface::{decoration, layer, multi, popup};
 
 use client::Client;
 use hyper_util::rt::TokioIo;
@@ -8,6 +8,7 @@ use crate::{
 
 pub mod decoration;
 pub mod layer;
+pub mod multi;
 pub mod popup;
 
 /// Events emitted by the surface to notify [`Program`] of state changes.
@@ -113,6 +114,26 @@ impl<Msg> From<PopupHandle<Msg>> for SurfaceHandle<Msg> {
 }
 
 impl<Msg> SurfaceHandle<Msg> {
+    /// Closes this surface.
+    pub fn close(&self) {
+        match &self.0 {
+            Inner::Layer(layer_handle) => layer_handle.close(),
+            Inner::Decoration(decoration_handle) => decoration_handle.close(),
+            Inner::Popup(popup_handle) => popup_handle.close(),
+        }
+    }
+
+    /// Sends a message to this surface's [`Program`].
+    ///
+    /// [`Program`]: crate::widget::Program
+    pub fn send_message(&self, message: Msg) {
+        match &self.0 {
+            Inner::Layer(layer_handle) => layer_handle.send_message(message),
+            Inner::Decoration(decoration_handle) => decoration_handle.send_message(message),
+            Inner::Popup(popup_handle) => popup_handle.send_message(message),
+        }
+    }
+
     /// Forces this surface to redraw.
     pub fn force_redraw(&self) {
         match &self.0 {
@@ -122,6 +122,32 @@ impl From<ZLayer> for layer::v1::Layer {
     }
 }
 
+/// The space between a layer surface and the edges of the output it's anchored to.
+///
+/// Margins only apply to edges the layer surface is anchored to.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Margin {
+    /// The margin from the top edge.
+    pub top: i32,
+    /// The margin from the right edge.
+    pub right: i32,
+    /// The margin from the bottom edge.
+    pub bottom: i32,
+    /// The margin from the left edge.
+    pub left: i32,
+}
+
+impl From<Margin> for layer::v1::Margin {
+    fn from(value: Margin) -> Self {
+        Self {
+            top: value.top,
+            right: value.right,
+            bottom: value.bottom,
+            left: value.left,
+        }
+    }
+}
+
 /// The error type for layer event conversion.
 #[allow(missing_docs)]
 #[derive(Debug)]
@@ -164,12 +190,42 @@ pub enum UpdateLayerError {
 }
 
 /// Create a new widget.
+///
+/// `layer` determines what the widget is drawn above and below. Use [`ZLayer::Overlay`]
+/// for widgets that should show above fullscreen windows, like OSDs.
+/// `exclusive_zone` lets anchored widgets like bars reserve space on the output.
+///
+/// The widget has no margin. Use [`LayerHandle::set_margin`] to offset it from the edges
+/// it's anchored to.
 pub fn new_widget<Msg, P>(
+    program: P,
+    anchor: Option<Anchor>,
+    keyboard_interactivity: KeyboardInteractivity,
+    exclusive_zone: ExclusiveZone,
+    layer: ZLayer,
+) -> Result<LayerHandle<Msg>, NewLayerError>
+where
+    Msg: Clone + Send + 'static,
+    P: Program<Message = Msg> + Send + 'static,
+{
+    new_widget_with_margin(
+        program,
+        anchor,
+        keyboard_interactivity,
+        exclusive_zone,
+        layer,
+        Margin::default(),
+    )
+}
+
+/// Create a new widget that starts out with `margin`.
+pub(crate) fn new_widget_with_margin<Msg, P>(
     mut program: P,
     anchor: Option<Anchor>,
     keyboard_interactivity: KeyboardInteractivity,
     exclusive_zone: ExclusiveZone,
     layer: ZLayer,
+    margin: Margin,
 ) -> Result<LayerHandle<Msg>, NewLayerError>
 where
     Msg: Clone + Send + 'static,
@@ -193,6 +249,7 @@ where
                 as i32,
             exclusive_zone: exclusive_zone.into(),
             layer: layer::v1::Layer::from(layer) as i32,
+            margin: Some(margin.into()),
         })
         .block_on_tokio()?;
 
@@ -332,6 +389,7 @@ where
                     keyboard_interactivity: None,
                     exclusive_zone: None,
                     layer: None,
+                    margin: None,
                 })
                 .await
                 .unwrap();
@@ -366,12 +424,25 @@ impl<Msg> std::fmt::Debug for LayerHandle<Msg> {
 
 impl<Msg> LayerHandle<Msg> {
     /// Update this layer's attributes.
+    ///
+    /// Use [`Self::set_margin`] to update the margin.
     pub fn update(
         &self,
         anchor: Option<Option<Anchor>>,
         keyboard_interactivity: Option<KeyboardInteractivity>,
         exclusive_zone: Option<ExclusiveZone>,
         layer: Option<ZLayer>,
+    ) -> Result<(), UpdateLayerError> {
+        self.update_with_margin(anchor, keyboard_interactivity, exclusive_zone, layer, None)
+    }
+
+    fn update_with_margin(
+        &self,
+        anchor: Option<Option<Anchor>>,
+        keyboard_interactivity: Option<KeyboardInteractivity>,
+        exclusive_zone: Option<ExclusiveZone>,
+        layer: Option<ZLayer>,
+        margin: Option<Margin>,
     ) -> Result<(), UpdateLayerError> {
         let anchor = if let Some(anchor) = anchor {
             anchor
@@ -390,6 +461,8 @@ impl<Msg> LayerHandle<Msg> {
 
         let layer = layer.map(layer::v1::Layer::from).map(i32::from);
 
+        let margin = margin.map(layer::v1::Margin::from);
+
         Client::layer()
             .update_layer(UpdateLayerRequest {
                 layer_id: self.id.to_inner(),
@@ -398,6 +471,7 @@ impl<Msg> LayerHandle<Msg> {
                 keyboard_interactivity,
                 exclusive_zone,
                 layer,
+                margin,
             })
             .block_on_tokio()?;
 
@@ -430,6 +504,13 @@ impl<Msg> LayerHandle<Msg> {
         self.update(None, None, None, Some(layer))
     }
 
+    /// Update this layer's margin.
+    ///
+    /// Margins only apply to edges the layer is anchored to.
+    pub fn set_margin(&self, margin: Margin) -> Result<(), UpdateLayerError> {
+        self.update_with_margin(None, None, None, None, Some(margin))
+    }
+
     /// Close this layer widget.
     pub fn close(&self) {
         if let Err(status) = Client::layer()
@@ -521,3 +602,36 @@ impl<Msg> AsParent for LayerHandle<Msg> {
         popup::Parent(popup::ParentInner::Layer(self.id))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn margin_converts_each_edge() {
+        let margin = Margin {
+            top: 1,
+            right: 2,
+            bottom: 3,
+            left: 4,
+        };
+
+        assert_eq!(
+            layer::v1::Margin::from(margin),
+            layer::v1::Margin {
+                top: 1,
+                right: 2,
+                bottom: 3,
+                left: 4,
+            }
+        );
+    }
+
+    #[test]
+    fn default_margin_is_zero() {
+        assert_eq!(
+            layer::v1::Margin::from(Margin::default()),
+            layer::v1::Margin::default()
+        );
+    }
+}
@@ -0,0 +1,524 @@
+//! Widget programs that own multiple surfaces.
+//!
+//! A [`MultiProgram`] is like a [`Program`], except its state is shared between
+//! several surfaces, called windows. Each window is identified by a
+//! [`MultiProgram::Window`] key and is drawn by [`MultiProgram::view`].
+//!
+//! Windows can be opened and closed from [`MultiProgram::update`], so a bar can open
+//! a calendar popup when clicked, for example. A message from any window updates the
+//! shared state, after which every open window is redrawn.
+
+use std::{
+    collections::HashMap,
+    hash::Hash,
+    sync::{Arc, Mutex},
+};
+
+use tracing::error;
+
+use crate::{
+    layer::{self, ExclusiveZone, KeyboardInteractivity, Margin, NewLayerError, ZLayer},
+    popup::{self, ConstraintsAdjust, Gravity, NewPopupError, Offset, Position},
+    surface::{SurfaceEvent, SurfaceHandle},
+    widget::{Program, WidgetDef},
+};
+
+/// A widget program whose state is shared between several windows.
+pub trait MultiProgram {
+    /// The type of messages that this program receives.
+    type Message;
+    /// The key that identifies each of this program's windows.
+    type Window;
+
+    /// Updates this program with a message received by any of its windows.
+    ///
+    /// Windows can be opened and closed through `windows`.
+    fn update(&mut self, msg: Self::Message, windows: &mut Windows<Self::Window>);
+
+    /// Creates the widget definition of `window`.
+    fn view(&self, window: &Self::Window) -> Option<WidgetDef<Self::Message>>;
+
+    /// Called to notify this program about the state changes of `window`.
+    ///
+    /// Windows can be opened and closed through `windows`.
+    fn event(
+        &mut self,
+        window: &Self::Window,
+        event: SurfaceEvent<Self::Message>,
+        windows: &mut Windows<Self::Window>,
+    ) {
+        let _ = (window, event, windows);
+    }
+}
+
+/// How to open a window of a [`MultiProgram`].
+#[derive(Debug, Clone)]
+pub enum WindowSettings<W> {
+    /// A layer surface.
+    ///
+    /// See [`layer::new_widget`] and [`layer::LayerHandle::set_margin`] for what the fields do.
+    Layer {
+        /// The edges the layer surface is anchored to.
+        anchor: Option<layer::Anchor>,
+        /// Whether the layer surface can get keyboard focus.
+        keyboard_interactivity: KeyboardInteractivity,
+        /// Whether the layer surface reserves space on the output.
+        exclusive_zone: ExclusiveZone,
+        /// The layer the surface is drawn on.
+        layer: ZLayer,
+        /// The space between the layer surface and the edges it's anchored to.
+        margin: Margin,
+    },
+    /// A popup attached to another window of the same program.
+    ///
+    /// See [`popup::new_widget`] for what the fields do.
+    Popup {
+        /// The window this popup is attached to.
+        parent: W,
+        /// Where the popup is positioned on its parent.
+        position: Position,
+        /// The edge of the position rectangle the popup is anchored to.
+        anchor: Option<popup::Anchor>,
+        /// The direction the popup extends in.
+        gravity: Option<Gravity>,
+        /// The offset from the anchor point.
+        offset: Option<Offset>,
+        /// How the popup is adjusted if it doesn't fit.
+        constraints_adjust: Option<ConstraintsAdjust>,
+        /// Whether the popup doesn't grab the keyboard and pointer.
+        no_grab: bool,
+        /// Whether the popup doesn't replace other popups of its parent.
+        no_replace: bool,
+    },
+}
+
+/// The error type for opening a window of a [`MultiProgram`].
+#[derive(thiserror::Error, Debug)]
+pub enum OpenWindowError {
+    /// The layer surface couldn't be created.
+    #[error(transparent)]
+    Layer(#[from] NewLayerError),
+    /// The popup couldn't be created.
+    #[error(transparent)]
+    Popup(#[from] NewPopupError),
+    /// The parent of a popup isn't open.
+    #[error("the parent window is not open")]
+    ParentNotOpen,
+}
+
+/// The windows of a [`MultiProgram`].
+///
+/// Windows opened or closed here are opened or closed once the program is done
+/// updating.
+#[derive(Debug)]
+pub struct Windows<W> {
+    open: Vec<W>,
+    commands: Vec<Command<W>>,
+}
+
+#[derive(Debug)]
+enum Command<W> {
+    Open(W, WindowSettings<W>),
+    Close(W),
+}
+
+impl<W: PartialEq> Windows<W> {
+    /// Opens a window.
+    ///
+    /// Opening a window that is already open does nothing.
+    pub fn open(&mut self, window: W, settings: WindowSettings<W>) {
+        self.commands.push(Command::Open(window, settings));
+    }
+
+    /// Closes a window.
+    pub fn close(&mut self, window: W) {
+        self.commands.push(Command::Close(window));
+    }
+
+    /// Returns whether a window was open when the program started updating.
+    pub fn is_open(&self, window: &W) -> bool {
+        self.open.contains(window)
+    }
+}
+
+/// A handle to a [`MultiProgram`].
+pub struct MultiProgramHandle<P: MultiProgram> {
+    shared: Arc<Shared<P>>,
+}
+
+impl<P: MultiProgram> Clone for MultiProgramHandle<P> {
+    fn clone(&self) -> Self {
+        Self {
+            shared: self.shared.clone(),
+        }
+    }
+}
+
+impl<P: MultiProgram> std::fmt::Debug for MultiProgramHandle<P> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MultiProgramHandle").finish_non_exhaustive()
+    }
+}
+
+impl<P> MultiProgramHandle<P>
+where
+    P: MultiProgram + Send + 'static,
+    P::Message: Clone + Send + 'static,
+    P::Window: Clone + Eq + Hash + Send + 'static,
+{
+    /// Opens a window of this program.
+    ///
+    /// Opening a window that is already open does nothing.
+    /// From [`MultiProgram::update`], use [`Windows::open`] instead.
+    pub fn open(
+        &self,
+        window: P::Window,
+        settings: WindowSettings<P::Window>,
+    ) -> Result<(), OpenWindowError> {
+        self.shared.open(window, settings)
+    }
+
+    /// Closes a window of this program.
+    pub fn close(&self, window: &P::Window) {
+        let handle = self.shared.handles.lock().unwrap().get(window).cloned();
+        if let Some(handle) = handle {
+            handle.close();
+        }
+    }
+
+    /// Closes all windows of this program.
+    pub fn close_all(&self) {
+        let handles = self.shared.handles();
+        for handle in handles {
+            handle.close();
+        }
+    }
+
+    /// Returns whether a window of this program is open.
+    pub fn is_open(&self, window: &P::Window) -> bool {
+        self.shared.handles.lock().unwrap().contains_key(window)
+    }
+
+    /// Sends a message to this program.
+    ///
+    /// The message is dropped if no window is open.
+    pub fn send_message(&self, message: P::Message) {
+        if let Some(handle) = self.shared.handles().into_iter().next() {
+            handle.send_message(message);
+        }
+    }
+}
+
+/// Creates a new [`MultiProgram`] and opens its first window.
+///
+/// The first window can't be a popup, as it has no window to be attached to.
+pub fn new_program<P>(
+    program: P,
+    window: P::Window,
+    settings: WindowSettings<P::Window>,
+) -> Result<MultiProgramHandle<P>, OpenWindowError>
+where
+    P: MultiProgram + Send + 'static,
+    P::Message: Clone + Send + 'static,
+    P::Window: Clone + Eq + Hash + Send + 'static,
+{
+    let shared = Arc::new(Shared {
+        program: Mutex::new(program),
+        handles: Mutex::new(HashMap::new()),
+    });
+
+    shared.open(window, settings)?;
+
+    Ok(MultiProgramHandle { shared })
+}
+
+/// The state shared between the windows of a [`MultiProgram`].
+struct Shared<P: MultiProgram> {
+    program: Mutex<P>,
+    handles: Mutex<HashMap<P::Window, SurfaceHandle<P::Message>>>,
+}
+
+impl<P> Shared<P>
+where
+    P: MultiProgram + Send + 'static,
+    P::Message: Clone + Send + 'static,
+    P::Window: Clone + Eq + Hash + Send + 'static,
+{
+    fn handles(&self) -> Vec<SurfaceHandle<P::Message>> {
+        self.handles.lock().unwrap().values().cloned().collect()
+    }
+
+    fn windows(&self) -> Windows<P::Window> {
+        Windows {
+            open: self.handles.lock().unwrap().keys().cloned().collect(),
+            commands: Vec::new(),
+        }
+    }
+
+    fn open(
+        self: &Arc<Self>,
+        window: P::Window,
+        settings: WindowSettings<P::Window>,
+    ) -> Result<(), OpenWindowError> {
+        if self.handles.lock().unwrap().contains_key(&window) {
+            return Ok(());
+        }
+
+        let program = WindowProgram {
+            window,
+            shared: self.clone(),
+        };
+
+        // The window's handle is stored when the program gets `SurfaceEvent::Created`
+        match settings {
+            WindowSettings::Layer {
+                anchor,
+                keyboard_interactivity,
+                exclusive_zone,
+                layer,
+                margin,
+            } => {
+                layer::new_widget_with_margin(
+                    program,
+                    anchor,
+                    keyboard_interactivity,
+                    exclusive_zone,
+                    layer,
+                    margin,
+                )?;
+            }
+            WindowSettings::Popup {
+                parent,
+                position,
+                anchor,
+                gravity,
+                offset,
+                constraints_adjust,
+                no_grab,
+                no_replace,
+            } => {
+                let parent = self.handles.lock().unwrap().get(&parent).cloned();
+                let Some(parent) = parent else {
+                    return Err(OpenWindowError::ParentNotOpen);
+                };
+
+                popup::new_widget(
+                    program,
+                    &parent,
+                    position,
+                    anchor,
+                    gravity,
+                    offset,
+                    constraints_adjust,
+                    no_grab,
+                    no_replace,
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Opens and closes the windows requested by the program, then redraws
+    /// every window other than `current`.
+    fn apply(self: &Arc<Self>, windows: Windows<P::Window>, current: &P::Window) {
+        for command in windows.commands {
+            match command {
+                Command::Open(window, settings) => {
+                    if let Err(err) = self.open(window, settings) {
+                        error!("Failed to open window: {err}");
+                    }
+                }
+                Command::Close(window) => {
+                    let handle = self.handles.lock().unwrap().get(&window).cloned();
+                    if let Some(handle) = handle {
+                        handle.close();
+                    }
+                }
+            }
+        }
+
+        let others = self
+            .handles
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|(window, _)| *window != current)
+            .map(|(_, handle)| handle.clone())
+            .collect::<Vec<_>>();
+
+        for handle in others {
+            handle.force_redraw();
+        }
+    }
+}
+
+/// One window of a [`MultiProgram`], drawn from the shared state.
+struct WindowProgram<P: MultiProgram> {
+    window: P::Window,
+    shared: Arc<Shared<P>>,
+}
+
+impl<P> Program for WindowProgram<P>
+where
+    P: MultiProgram + Send + 'static,
+    P::Message: Clone + Send + 'static,
+    P::Window: Clone + Eq + Hash + Send + 'static,
+{
+    type Message = P::Message;
+
+    fn update(&mut self, msg: Self::Message) {
+        let mut windows = self.shared.windows();
+        self.shared
+            .program
+            .lock()
+            .unwrap()
+            .update(msg, &mut windows);
+        self.shared.apply(windows, &self.window);
+    }
+
+    fn view(&self) -> Option<WidgetDef<Self::Message>> {
+        self.shared.program.lock().unwrap().view(&self.window)
+    }
+
+    fn event(&mut self, event: SurfaceEvent<Self::Message>) {
+        match &event {
+            SurfaceEvent::Created { surface } => {
+                self.shared
+                    .handles
+                    .lock()
+                    .unwrap()
+                    .insert(self.window.clone(), surface.clone());
+            }
+            SurfaceEvent::Closing => {
+                self.shared.handles.lock().unwrap().remove(&self.window);
+            }
+            _ => (),
+        }
+
+        let mut windows = self.shared.windows();
+        self.shared
+            .program
+            .lock()
+            .unwrap()
+            .event(&self.window, event, &mut windows);
+        self.shared.apply(windows, &self.window);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::widget::{Widget, text::Text};
+
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+    enum Window {
+        Bar,
+        Calendar,
+    }
+
+    #[derive(Debug, Clone)]
+    enum Message {
+        Increment,
+        OpenCalendar,
+        CloseCalendar,
+    }
+
+    #[derive(Default)]
+    struct Counter {
+        count: u32,
+    }
+
+    impl MultiProgram for Counter {
+        type Message = Message;
+        type Window = Window;
+
+        fn update(&mut self, msg: Message, windows: &mut Windows<Window>) {
+            match msg {
+                Message::Increment => self.count += 1,
+                Message::OpenCalendar => windows.open(Window::Calendar, calendar_settings()),
+                Message::CloseCalendar => windows.close(Window::Calendar),
+            }
+        }
+
+        fn view(&self, window: &Window) -> Option<WidgetDef<Message>> {
+            Some(Text::new(format!("{window:?} {}", self.count)).into())
+        }
+    }
+
+    fn calendar_settings() -> WindowSettings<Window> {
+        WindowSettings::Popup {
+            parent: Window::Bar,
+            position: Position::AtCursor,
+            anchor: None,
+            gravity: None,
+            offset: None,
+            constraints_adjust: None,
+            no_grab: false,
+            no_replace: false,
+        }
+    }
+
+    fn shared() -> Arc<Shared<Counter>> {
+        Arc::new(Shared {
+            program: Mutex::new(Counter::default()),
+            handles: Mutex::new(HashMap::new()),
+        })
+    }
+
+    fn window_program(shared: &Arc<Shared<Counter>>, window: Window) -> WindowProgram<Counter> {
+        WindowProgram {
+            window,
+            shared: shared.clone(),
+        }
+    }
+
+    fn text(program: &WindowProgram<Counter>) -> String {
+        match program.view().unwrap().widget {
+            Widget::Text(text) => text.text,
+            _ => panic!("expected text"),
+        }
+    }
+
+    #[test]
+    fn windows_share_program_state() {
+        let shared = shared();
+        let mut bar = window_program(&shared, Window::Bar);
+        let calendar = window_program(&shared, Window::Calendar);
+
+        bar.update(Message::Increment);
+        bar.update(Message::Increment);
+
+        assert_eq!(text(&bar), "Bar 2");
+        assert_eq!(text(&calendar), "Calendar 2");
+    }
+
+    #[test]
+    fn windows_are_opened_and_closed_after_update() {
+        let mut program = Counter::default();
+        let mut windows = shared().windows();
+
+        program.update(Message::OpenCalendar, &mut windows);
+        program.update(Message::CloseCalendar, &mut windows);
+
+        assert!(!windows.is_open(&Window::Calendar));
+        assert!(matches!(
+            windows.commands.as_slice(),
+            [
+                Command::Open(Window::Calendar, WindowSettings::Popup { .. }),
+                Command::Close(Window::Calendar),
+            ]
+        ));
+    }
+
+    #[test]
+    fn popup_without_open_parent_fails() {
+        let shared = shared();
+
+        let result = shared.open(Window::Calendar, calendar_settings());
+
+        assert!(matches!(result, Err(OpenWindowError::ParentNotOpen)));
+        assert!(shared.handles.lock().unwrap().is_empty());
+    }
+}
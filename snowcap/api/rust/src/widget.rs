@@ -12,9 +12,11 @@ pub mod input_region;
 pub mod message;
 pub mod mouse_area;
 pub mod operation;
+pub mod pin;
 pub mod row;
 pub mod scrollable;
 pub mod signal;
+pub mod stack;
 pub mod text;
 pub mod text_input;
 pub mod utils;
@@ -29,9 +31,11 @@ use column::Column;
 use container::Container;
 use image::Image;
 use mouse_area::MouseArea;
+use pin::Pin;
 use row::Row;
 use scrollable::Scrollable;
 use snowcap_api_defs::snowcap::widget;
+use stack::Stack;
 use text::Text;
 use text_input::TextInput;
 
@@ -87,14 +91,24 @@ impl From<Palette> for widget::v1::Palette {
     }
 }
 
+/// A theme applied to a widget and all of its descendants.
+///
+/// Nested themes only override the fields they set, and styles set directly
+/// on a widget take precedence over its theme.
 #[derive(Debug, Clone, PartialEq, Default)]
 pub struct Theme {
+    /// The palette used for the default widget styles.
     pub palette: Option<Palette>,
 
     pub text_style: Option<text::Style>,
     pub scrollable_style: Option<scrollable::Style>,
     pub container_style: Option<container::Style>,
     pub button_style: Option<button::Styles>,
+
+    /// The default spacing between the children of columns and rows.
+    pub spacing: Option<f32>,
+    /// The default corner radius of containers and buttons.
+    pub radius: Option<Radius>,
 }
 
 impl From<Theme> for widget::v1::Theme {
@@ -105,6 +119,8 @@ impl From<Theme> for widget::v1::Theme {
             scrollable_style: value.scrollable_style.map(From::from),
             container_style: value.container_style.map(From::from),
             button_style: value.button_style.map(From::from),
+            spacing: value.spacing,
+            radius: value.radius.map(From::from),
         }
     }
 }
@@ -153,6 +169,17 @@ where
 }
 
 impl<Msg> WidgetDef<Msg> {
+    /// Sets the theme of this widget and its descendants.
+    ///
+    /// Setting a theme on the root widget of a program's view applies it to the
+    /// whole program.
+    pub fn theme(self, theme: Theme) -> Self {
+        Self {
+            theme: Some(theme),
+            ..self
+        }
+    }
+
     pub(crate) fn collect_messages(
         &self,
         callbacks: &mut HashMap<WidgetId, WidgetMessage<Msg>>,
@@ -188,6 +215,14 @@ impl<Msg> WidgetDef<Msg> {
                 mouse_area.child.collect_messages(callbacks, with_widget);
             }
             Widget::TextInput(_) => (),
+            Widget::Stack(stack) => {
+                for widget in stack.children.iter() {
+                    widget.collect_messages(callbacks, with_widget);
+                }
+            }
+            Widget::Pin(pin) => {
+                pin.child.collect_messages(callbacks, with_widget);
+            }
         }
     }
 }
@@ -244,6 +279,8 @@ pub enum Widget<Msg> {
     InputRegion(Box<InputRegion<Msg>>),
     MouseArea(Box<MouseArea<Msg>>),
     TextInput(Box<TextInput<Msg>>),
+    Stack(Stack<Msg>),
+    Pin(Box<Pin<Msg>>),
 }
 
 impl<Msg, T: Into<Widget<Msg>>> From<T> for WidgetDef<Msg> {
@@ -281,6 +318,8 @@ impl<Msg> From<Widget<Msg>> for widget::v1::widget_def::Widget {
             Widget::TextInput(text_input) => {
                 widget::v1::widget_def::Widget::TextInput(Box::new((*text_input).into()))
             }
+            Widget::Stack(stack) => widget::v1::widget_def::Widget::Stack(stack.into()),
+            Widget::Pin(pin) => widget::v1::widget_def::Widget::Pin(Box::new((*pin).into())),
         }
     }
 }
@@ -390,6 +429,10 @@ impl From<Alignment> for widget::v1::Alignment {
     }
 }
 
+/// The length of a widget.
+///
+/// Fixed lengths are in logical pixels. Widgets are rendered at the scale of the output
+/// they're on, so they keep the same size across outputs with different scales.
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
 pub enum Length {
     #[default]
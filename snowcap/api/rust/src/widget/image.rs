@@ -117,6 +117,25 @@ impl From<Image> for widget::v1::Image {
                     height,
                     rgba: bytes,
                 }),
+                Handle::Thumbnail {
+                    source,
+                    max_width,
+                    max_height,
+                } => widget::v1::image::Handle::Thumbnail(widget::v1::image::Thumbnail {
+                    source: Some(match source {
+                        ThumbnailSource::Output(name) => {
+                            widget::v1::image::thumbnail::Source::OutputName(name)
+                        }
+                        ThumbnailSource::Tag(tag_id) => {
+                            widget::v1::image::thumbnail::Source::TagId(tag_id)
+                        }
+                        ThumbnailSource::Window(window_id) => {
+                            widget::v1::image::thumbnail::Source::WindowId(window_id)
+                        }
+                    }),
+                    max_width,
+                    max_height,
+                }),
             }),
         }
     }
@@ -131,6 +150,30 @@ pub enum Handle {
         height: u32,
         bytes: Vec<u8>,
     },
+    /// A live, downscaled image of an output, tag, or window.
+    ///
+    /// The image fits within `max_width` by `max_height` pixels and keeps the aspect ratio
+    /// of its output or window. Tags that aren't active are drawn as an overview of their
+    /// windows.
+    Thumbnail {
+        source: ThumbnailSource,
+        max_width: u32,
+        max_height: u32,
+    },
+}
+
+/// What a [`Handle::Thumbnail`] shows.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ThumbnailSource {
+    /// An output, by name.
+    Output(String),
+    /// A tag, by its id.
+    Tag(u32),
+    /// A window, by its id.
+    ///
+    /// Window thumbnails need a compositor that supports version 2 of the
+    /// thumbnail protocol.
+    Window(u32),
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
@@ -0,0 +1,72 @@
+use snowcap_api_defs::snowcap::widget;
+
+use crate::widget::{Length, Widget, WidgetDef};
+
+/// Positions its child at an offset from its top-left corner.
+///
+/// Pins are usually placed in a [`Stack`][super::stack::Stack] to lay widgets out
+/// at absolute positions.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Pin<Msg> {
+    pub x: f32,
+    pub y: f32,
+    pub width: Option<Length>,
+    pub height: Option<Length>,
+    pub child: WidgetDef<Msg>,
+}
+
+impl<Msg> Pin<Msg> {
+    pub fn new(child: impl Into<WidgetDef<Msg>>) -> Self {
+        Self {
+            x: 0.0,
+            y: 0.0,
+            width: None,
+            height: None,
+            child: child.into(),
+        }
+    }
+
+    pub fn x(self, x: f32) -> Self {
+        Self { x, ..self }
+    }
+
+    pub fn y(self, y: f32) -> Self {
+        Self { y, ..self }
+    }
+
+    pub fn position(self, x: f32, y: f32) -> Self {
+        Self { x, y, ..self }
+    }
+
+    pub fn width(self, width: Length) -> Self {
+        Self {
+            width: Some(width),
+            ..self
+        }
+    }
+
+    pub fn height(self, height: Length) -> Self {
+        Self {
+            height: Some(height),
+            ..self
+        }
+    }
+}
+
+impl<Msg> From<Pin<Msg>> for widget::v1::Pin {
+    fn from(value: Pin<Msg>) -> Self {
+        Self {
+            x: Some(value.x),
+            y: Some(value.y),
+            width: value.width.map(From::from),
+            height: value.height.map(From::from),
+            child: Some(Box::new(value.child.into())),
+        }
+    }
+}
+
+impl<Msg> From<Pin<Msg>> for Widget<Msg> {
+    fn from(value: Pin<Msg>) -> Self {
+        Self::Pin(Box::new(value))
+    }
+}
@@ -0,0 +1,63 @@
+use snowcap_api_defs::snowcap::widget;
+
+use super::{Length, WidgetDef};
+
+/// Draws its children on top of each other, the last child on top.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Stack<Msg> {
+    pub width: Option<Length>,
+    pub height: Option<Length>,
+    pub children: Vec<WidgetDef<Msg>>,
+}
+
+impl<Msg> Default for Stack<Msg> {
+    fn default() -> Self {
+        Self {
+            width: Default::default(),
+            height: Default::default(),
+            children: Default::default(),
+        }
+    }
+}
+
+impl<Msg> Stack<Msg> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn new_with_children(children: impl IntoIterator<Item = WidgetDef<Msg>>) -> Self {
+        Self {
+            children: children.into_iter().collect(),
+            ..Default::default()
+        }
+    }
+
+    pub fn width(self, width: Length) -> Self {
+        Self {
+            width: Some(width),
+            ..self
+        }
+    }
+
+    pub fn height(self, height: Length) -> Self {
+        Self {
+            height: Some(height),
+            ..self
+        }
+    }
+
+    pub fn push(mut self, child: impl Into<WidgetDef<Msg>>) -> Self {
+        self.children.push(child.into());
+        self
+    }
+}
+
+impl<Msg> From<Stack<Msg>> for widget::v1::Stack {
+    fn from(value: Stack<Msg>) -> Self {
+        widget::v1::Stack {
+            width: value.width.map(From::from),
+            height: value.height.map(From::from),
+            children: value.children.into_iter().map(From::from).collect(),
+        }
+    }
+}
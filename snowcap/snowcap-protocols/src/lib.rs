@@ -35,3 +35,33 @@ pub mod snowcap_decoration_v1 {
         wayland_scanner::generate_server_code!("./protocol/snowcap-decoration-v1.xml");
     }
 }
+
+pub mod snowcap_thumbnail_v1 {
+    pub mod client {
+        use wayland_client;
+        use wayland_client::protocol::*;
+
+        pub mod __interfaces {
+            use wayland_client::protocol::__interfaces::*;
+
+            wayland_scanner::generate_interfaces!("./protocol/snowcap-thumbnail-v1.xml");
+        }
+        use self::__interfaces::*;
+
+        wayland_scanner::generate_client_code!("./protocol/snowcap-thumbnail-v1.xml");
+    }
+
+    pub mod server {
+        use wayland_server;
+        use wayland_server::protocol::*;
+
+        pub mod __interfaces {
+            use wayland_server::protocol::__interfaces::*;
+
+            wayland_scanner::generate_interfaces!("./protocol/snowcap-thumbnail-v1.xml");
+        }
+        use self::__interfaces::*;
+
+        wayland_scanner::generate_server_code!("./protocol/snowcap-thumbnail-v1.xml");
+    }
+}
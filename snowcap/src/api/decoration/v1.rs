@@ -8,7 +8,7 @@ use tonic::{Request, Response, Status};
 use tracing::warn;
 
 use crate::{
-    api::{run_unary, widget::v1::widget_def_to_fn},
+    api::run_unary,
     decoration::{DecorationId, SnowcapDecoration},
     util::convert::TryFromApi,
 };
@@ -148,7 +148,7 @@ impl decoration_service_server::DecorationService for super::DecorationService {
             };
 
             deco.update_properties(
-                widget_def.and_then(widget_def_to_fn),
+                widget_def,
                 bounds.map(|bounds| crate::decoration::Bounds {
                     left: bounds.left,
                     right: bounds.right,
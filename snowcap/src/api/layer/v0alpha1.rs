@@ -15,6 +15,7 @@ use crate::api::run_unary_no_response;
 use crate::api::widget::v0alpha1::widget_def_to_fn;
 use crate::layer::ExclusiveZone;
 use crate::layer::LayerId;
+use crate::layer::Margin;
 use crate::layer::SnowcapLayer;
 
 #[tonic::async_trait]
@@ -91,6 +92,7 @@ impl layer_service_server::LayerService for super::LayerService {
                 anchor,
                 exclusive_zone,
                 keyboard_interactivity,
+                Margin::default(),
                 f,
             );
 
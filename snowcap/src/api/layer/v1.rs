@@ -13,11 +13,8 @@ use snowcap_api_defs::snowcap::layer::{
 use tonic::{Request, Response, Status};
 
 use crate::{
-    api::{
-        ResponseStream, run_server_streaming_mapped, run_unary, run_unary_no_response,
-        widget::v1::widget_def_to_fn,
-    },
-    layer::{ExclusiveZone, LayerEvent, LayerId, SnowcapLayer},
+    api::{ResponseStream, run_server_streaming_mapped, run_unary, run_unary_no_response},
+    layer::{ExclusiveZone, LayerEvent, LayerId, Margin, SnowcapLayer},
     util::convert::TryFromApi,
 };
 
@@ -35,6 +32,7 @@ impl layer_service_server::LayerService for super::LayerService {
         let exclusive_zone = request.exclusive_zone;
         let keyboard_interactivity = request.keyboard_interactivity();
         let layer = request.layer();
+        let margin = request.margin.map(Margin::from).unwrap_or_default();
 
         let Some(widget_def) = request.widget_def else {
             return Err(Status::invalid_argument("no widget def"));
@@ -88,6 +86,7 @@ impl layer_service_server::LayerService for super::LayerService {
                 anchor,
                 exclusive_zone,
                 keyboard_interactivity,
+                margin,
                 f,
             );
 
@@ -202,6 +201,8 @@ impl layer_service_server::LayerService for super::LayerService {
             layer::v1::Layer::Overlay => Some(wlr_layer::Layer::Overlay),
         };
 
+        let margin = request.margin.map(Margin::from);
+
         let widget_def = request.widget_def;
 
         run_unary(&self.sender, move |state| {
@@ -214,7 +215,8 @@ impl layer_service_server::LayerService for super::LayerService {
                 anchor,
                 exclusive_zone,
                 keyboard_interactivity,
-                widget_def.and_then(widget_def_to_fn),
+                margin,
+                widget_def,
             );
 
             Ok(UpdateLayerResponse {})
@@ -285,3 +287,39 @@ impl From<LayerEvent> for snowcap_api_defs::snowcap::layer::v1::LayerEvent {
         }
     }
 }
+
+impl From<layer::v1::Margin> for Margin {
+    fn from(value: layer::v1::Margin) -> Self {
+        Self {
+            top: value.top,
+            right: value.right,
+            bottom: value.bottom,
+            left: value.left,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn margin_converts_each_edge() {
+        let margin = Margin::from(layer::v1::Margin {
+            top: 1,
+            right: 2,
+            bottom: 3,
+            left: 4,
+        });
+
+        assert_eq!(
+            margin,
+            Margin {
+                top: 1,
+                right: 2,
+                bottom: 3,
+                left: 4,
+            }
+        );
+    }
+}
@@ -8,10 +8,7 @@ use snowcap_api_defs::snowcap::popup::v1::{
 use tonic::{Request, Response, Status};
 
 use crate::{
-    api::{
-        ResponseStream, run_server_streaming_mapped, run_unary, run_unary_no_response,
-        widget::v1::widget_def_to_fn,
-    },
+    api::{ResponseStream, run_server_streaming_mapped, run_unary, run_unary_no_response},
     decoration::DecorationId,
     layer::LayerId,
     popup::{self, PopupEvent, PopupId, SnowcapPopup},
@@ -260,7 +257,7 @@ impl popup_service_server::PopupService for super::PopupService {
                 gravity,
                 offset,
                 constraints_adjust,
-                widget_def.and_then(widget_def_to_fn),
+                widget_def,
             );
 
             Ok(UpdatePopupResponse {})
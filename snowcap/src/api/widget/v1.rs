@@ -1,6 +1,8 @@
+mod theme;
+
 use anyhow::Context;
 use iced::widget::{
-    Column, Container, Row, Scrollable, button, image::FilterMethod, scrollable::Scrollbar,
+    Column, Container, Row, Scrollable, Stack, button, image::FilterMethod, scrollable::Scrollbar,
 };
 use snowcap_api_defs::snowcap::widget::{
     self,
@@ -9,6 +11,7 @@ use snowcap_api_defs::snowcap::widget::{
         widget_def, widget_event, widget_service_server,
     },
 };
+use theme::InheritedTheme;
 use tonic::{Request, Response, Status};
 
 use crate::{
@@ -16,6 +19,7 @@ use crate::{
     decoration::DecorationId,
     layer::LayerId,
     popup::PopupId,
+    thumbnail::{ThumbnailImage, ThumbnailSource},
     util::convert::{FromApi, TryFromApi},
     widget::{MouseAreaEvent, TextInputEvent, ViewFn, WidgetEvent, WidgetId},
 };
@@ -77,6 +81,12 @@ impl widget_service_server::WidgetService for super::WidgetService {
 }
 
 pub fn widget_def_to_fn(def: WidgetDef) -> Option<ViewFn> {
+    themed_widget_def_to_fn(def, &InheritedTheme::default())
+}
+
+/// Converts a widget definition, inheriting unset styles from `theme`.
+fn themed_widget_def_to_fn(def: WidgetDef, theme: &InheritedTheme) -> Option<ViewFn> {
+    let theme = theme.merge(def.theme);
     let def = def.widget?;
     match def {
         widget_def::Widget::Text(text_def) => {
@@ -94,6 +104,8 @@ pub fn widget_def_to_fn(def: WidgetDef) -> Option<ViewFn> {
                 wrapping: _,
             } = text_def;
 
+            let style = theme.text_style(style);
+
             let f: ViewFn = Box::new(move || {
                 let mut text = iced::widget::Text::new(text.clone());
                 if let Some(pixels) = style.as_ref().and_then(|style| style.pixels) {
@@ -171,8 +183,9 @@ pub fn widget_def_to_fn(def: WidgetDef) -> Option<ViewFn> {
         }) => {
             let children_widget_fns = children
                 .into_iter()
-                .flat_map(widget_def_to_fn)
+                .flat_map(|child| themed_widget_def_to_fn(child, &theme))
                 .collect::<Vec<_>>();
+            let spacing = spacing.or(theme.spacing);
 
             let f: ViewFn = Box::new(move || {
                 let mut column = Column::new();
@@ -228,8 +241,9 @@ pub fn widget_def_to_fn(def: WidgetDef) -> Option<ViewFn> {
         }) => {
             let children_widget_fns = children
                 .into_iter()
-                .flat_map(widget_def_to_fn)
+                .flat_map(|child| themed_widget_def_to_fn(child, &theme))
                 .collect::<Vec<_>>();
+            let spacing = spacing.or(theme.spacing);
 
             let f: ViewFn = Box::new(move || {
                 let mut row = Row::new();
@@ -291,7 +305,9 @@ pub fn widget_def_to_fn(def: WidgetDef) -> Option<ViewFn> {
                 style,
             } = *scrollable_def;
 
-            let child_widget_fn = child.and_then(|def| widget_def_to_fn(*def));
+            let style = theme.scrollable_style(style);
+            let iced_theme = theme.iced_theme.clone();
+            let child_widget_fn = child.and_then(|def| themed_widget_def_to_fn(*def, &theme));
 
             let f: ViewFn = Box::new(move || {
                 let mut scrollable = Scrollable::new(
@@ -312,7 +328,9 @@ pub fn widget_def_to_fn(def: WidgetDef) -> Option<ViewFn> {
                         .direction(iced::widget::scrollable::Direction::from_api(direction));
                 }
                 let style = style.clone();
+                let iced_theme = iced_theme.clone();
                 scrollable = scrollable.style(move |theme, status| {
+                    let theme = iced_theme.as_ref().unwrap_or(theme);
                     let mut s = iced::widget::scrollable::default(theme, status);
                     if let Some(container_style) =
                         style.as_ref().and_then(|s| s.container_style.clone())
@@ -418,7 +436,10 @@ pub fn widget_def_to_fn(def: WidgetDef) -> Option<ViewFn> {
                 id,
             } = *container_def;
 
-            let child_widget_fn = child.and_then(|def| widget_def_to_fn(*def));
+            let style = theme.container_style(style);
+            let iced_theme = theme.iced_theme.clone();
+            let theme = theme.with_text_color(style.as_ref().and_then(|s| s.text_color));
+            let child_widget_fn = child.and_then(|def| themed_widget_def_to_fn(*def, &theme));
 
             let f: ViewFn = Box::new(move || {
                 let mut container = Container::new(
@@ -467,8 +488,10 @@ pub fn widget_def_to_fn(def: WidgetDef) -> Option<ViewFn> {
                 let background_color_clone = style.as_ref().and_then(|s| s.background_color);
                 let border_color_clone = style.as_ref().and_then(|s| s.border);
                 let background_clone = style.as_ref().and_then(|s| s.background.clone());
+                let iced_theme = iced_theme.clone();
 
                 let style = move |theme: &iced::Theme| {
+                    let theme = iced_theme.as_ref().unwrap_or(theme);
                     let mut style =
                         <iced::Theme as iced::widget::container::Catalog>::default()(theme);
 
@@ -526,7 +549,9 @@ pub fn widget_def_to_fn(def: WidgetDef) -> Option<ViewFn> {
                 widget_id,
             } = *button;
 
-            let child_widget_fn = child.and_then(|def| widget_def_to_fn(*def));
+            let style = theme.button_style(style);
+            let iced_theme = theme.iced_theme.clone();
+            let child_widget_fn = child.and_then(|def| themed_widget_def_to_fn(*def, &theme));
 
             let f: ViewFn = Box::new(move || {
                 let mut button = iced::widget::Button::new(
@@ -557,7 +582,9 @@ pub fn widget_def_to_fn(def: WidgetDef) -> Option<ViewFn> {
 
                 let style = {
                     let style = style.clone();
+                    let iced_theme = iced_theme.clone();
                     move |theme: &iced::Theme, status| {
+                        let theme = iced_theme.as_ref().unwrap_or(theme);
                         let mut s = <iced::Theme as button::Catalog>::default()(theme, status);
                         let style = style.clone();
 
@@ -623,6 +650,28 @@ pub fn widget_def_to_fn(def: WidgetDef) -> Option<ViewFn> {
 
             let handle = handle?;
 
+            let thumbnail = match &handle {
+                widget::v1::image::Handle::Thumbnail(widget::v1::image::Thumbnail {
+                    source,
+                    max_width,
+                    max_height,
+                }) => {
+                    let source = match source.clone()? {
+                        widget::v1::image::thumbnail::Source::OutputName(name) => {
+                            ThumbnailSource::Output(name)
+                        }
+                        widget::v1::image::thumbnail::Source::TagId(tag_id) => {
+                            ThumbnailSource::Tag(tag_id)
+                        }
+                        widget::v1::image::thumbnail::Source::WindowId(window_id) => {
+                            ThumbnailSource::Window(window_id)
+                        }
+                    };
+                    Some(ThumbnailImage::request(source, *max_width, *max_height))
+                }
+                _ => None,
+            };
+
             let f: ViewFn = Box::new(move || {
                 // FIXME: don't clone the entire image
                 let mut image = match handle.clone() {
@@ -639,6 +688,15 @@ pub fn widget_def_to_fn(def: WidgetDef) -> Option<ViewFn> {
                     }) => iced::widget::Image::new(iced::widget::image::Handle::from_rgba(
                         width, height, rgba,
                     )),
+                    // Until the first capture, show a transparent pixel
+                    widget::v1::image::Handle::Thumbnail(_) => iced::widget::Image::new(
+                        thumbnail
+                            .as_ref()
+                            .and_then(|thumbnail| thumbnail.handle())
+                            .unwrap_or_else(|| {
+                                iced::widget::image::Handle::from_rgba(1, 1, vec![0; 4])
+                            }),
+                    ),
                 };
 
                 if let Some(true) = nearest_neighbor {
@@ -681,6 +739,68 @@ pub fn widget_def_to_fn(def: WidgetDef) -> Option<ViewFn> {
 
             Some(f)
         }
+        widget_def::Widget::Stack(widget::v1::Stack {
+            width,
+            height,
+            children,
+        }) => {
+            let children_widget_fns = children
+                .into_iter()
+                .flat_map(|child| themed_widget_def_to_fn(child, &theme))
+                .collect::<Vec<_>>();
+
+            let f: ViewFn = Box::new(move || {
+                let mut stack = Stack::new();
+
+                if let Some(width) = width {
+                    stack = stack.width(iced::Length::from_api(width));
+                }
+                if let Some(height) = height {
+                    stack = stack.height(iced::Length::from_api(height));
+                }
+
+                for child in children_widget_fns.iter() {
+                    stack = stack.push(child());
+                }
+
+                stack.into()
+            });
+
+            Some(f)
+        }
+        widget_def::Widget::Pin(pin) => {
+            let widget::v1::Pin {
+                x,
+                y,
+                width,
+                height,
+                child,
+            } = *pin;
+
+            let child_widget_fn = child.and_then(|def| themed_widget_def_to_fn(*def, &theme));
+
+            let f: ViewFn = Box::new(move || {
+                let mut pin = iced::widget::pin(
+                    child_widget_fn
+                        .as_ref()
+                        .map(|child| child())
+                        .unwrap_or_else(|| iced::widget::Text::new("NULL").into()),
+                )
+                .x(x.unwrap_or_default())
+                .y(y.unwrap_or_default());
+
+                if let Some(width) = width {
+                    pin = pin.width(iced::Length::from_api(width));
+                }
+                if let Some(height) = height {
+                    pin = pin.height(iced::Length::from_api(height));
+                }
+
+                pin.into()
+            });
+
+            Some(f)
+        }
         widget_def::Widget::InputRegion(input_region) => {
             let widget::v1::InputRegion {
                 add,
@@ -689,7 +809,7 @@ pub fn widget_def_to_fn(def: WidgetDef) -> Option<ViewFn> {
                 child,
             } = *input_region;
 
-            let child_widget_fn = child.and_then(|def| widget_def_to_fn(*def));
+            let child_widget_fn = child.and_then(|def| themed_widget_def_to_fn(*def, &theme));
 
             let f: ViewFn = Box::new(move || {
                 let mut input_region = crate::widget::input_region::InputRegion::new(
@@ -730,7 +850,7 @@ pub fn widget_def_to_fn(def: WidgetDef) -> Option<ViewFn> {
                 widget_id,
             } = *mouse_area;
 
-            let child_widget_fn = child.and_then(|def| widget_def_to_fn(*def));
+            let child_widget_fn = child.and_then(|def| themed_widget_def_to_fn(*def, &theme));
 
             let f: ViewFn = Box::new(move || {
                 let mut mouse_area = iced::widget::MouseArea::new(
@@ -867,6 +987,9 @@ pub fn widget_def_to_fn(def: WidgetDef) -> Option<ViewFn> {
                 widget_id,
             } = *text_input;
 
+            let font = font.or_else(|| theme.text_style.as_ref().and_then(|s| s.font.clone()));
+            let iced_theme = theme.iced_theme.clone();
+
             let f: ViewFn = Box::new(move || {
                 let mut text_input = iced::widget::TextInput::new(&placeholder, &value);
 
@@ -942,8 +1065,10 @@ pub fn widget_def_to_fn(def: WidgetDef) -> Option<ViewFn> {
                     use crate::widget::text_input::{Style, Styles};
 
                     let style = Styles::from_api(style);
+                    let iced_theme = iced_theme.clone();
                     let style = move |theme: &iced::Theme, status| {
                         use iced::widget::text_input;
+                        let theme = iced_theme.as_ref().unwrap_or(theme);
                         let s = <iced::Theme as text_input::Catalog>::default()(theme, status);
 
                         let crate::widget::text_input::Styles {
@@ -989,6 +1114,10 @@ pub fn widget_def_to_fn(def: WidgetDef) -> Option<ViewFn> {
                     };
 
                     text_input = text_input.style(style);
+                } else if let Some(iced_theme) = iced_theme.clone() {
+                    text_input = text_input.style(move |_, status| {
+                        iced::widget::text_input::default(&iced_theme, status)
+                    });
                 }
 
                 text_input.into()
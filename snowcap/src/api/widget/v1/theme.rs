@@ -0,0 +1,357 @@
+//! Theme inheritance for widget definitions.
+//!
+//! A [`Theme`] set on a widget definition applies to that widget and all of its
+//! descendants. Nested themes only override the fields they set.
+
+use snowcap_api_defs::snowcap::widget::v1::{
+    Border, Color, Palette, Radius, Theme, button, container, scrollable, text,
+};
+
+use crate::util::convert::FromApi;
+
+/// The theme a widget inherits from its ancestors.
+#[derive(Debug, Clone, Default)]
+pub struct InheritedTheme {
+    /// The iced theme built from the closest palette, used as the base for
+    /// widget styles.
+    pub iced_theme: Option<iced::Theme>,
+    /// The default text color.
+    pub text_color: Option<Color>,
+    pub text_style: Option<text::Style>,
+    pub scrollable_style: Option<scrollable::Style>,
+    pub container_style: Option<container::Style>,
+    pub button_style: Option<button::Style>,
+    /// The default spacing of columns and rows.
+    pub spacing: Option<f32>,
+    /// The default corner radius of containers and buttons.
+    pub radius: Option<Radius>,
+}
+
+impl InheritedTheme {
+    /// Returns this theme with the fields set in `theme` overridden.
+    pub fn merge(&self, theme: Option<Theme>) -> Self {
+        let Some(Theme {
+            palette,
+            text_style,
+            scrollable_style,
+            container_style,
+            button_style,
+            spacing,
+            radius,
+        }) = theme
+        else {
+            return self.clone();
+        };
+
+        let text_color = text_style
+            .as_ref()
+            .and_then(|style| style.color)
+            .or(palette.and_then(|palette| palette.text))
+            .or(self.text_color);
+
+        Self {
+            iced_theme: palette
+                .map(iced_theme_from_palette)
+                .or_else(|| self.iced_theme.clone()),
+            text_color,
+            text_style: merge_text_style(self.text_style.as_ref(), text_style),
+            scrollable_style: scrollable_style.or_else(|| self.scrollable_style.clone()),
+            container_style: merge_container_style(self.container_style.as_ref(), container_style),
+            button_style: merge_button_style(self.button_style.as_ref(), button_style),
+            spacing: spacing.or(self.spacing),
+            radius: radius.or(self.radius),
+        }
+    }
+
+    /// Returns this theme with the default text color replaced.
+    ///
+    /// This is used for the children of containers that set their own text color.
+    pub fn with_text_color(&self, text_color: Option<Color>) -> Self {
+        match text_color {
+            Some(text_color) => Self {
+                text_color: Some(text_color),
+                ..self.clone()
+            },
+            None => self.clone(),
+        }
+    }
+
+    /// Fills in the unset fields of a text style.
+    pub fn text_style(&self, style: Option<text::Style>) -> Option<text::Style> {
+        let mut style = merge_text_style(self.text_style.as_ref(), style);
+
+        if let Some(text_color) = self.text_color {
+            let style = style.get_or_insert_default();
+            style.color = style.color.or(Some(text_color));
+        }
+
+        style
+    }
+
+    /// Fills in the unset fields of a container style.
+    pub fn container_style(&self, style: Option<container::Style>) -> Option<container::Style> {
+        let mut style = merge_container_style(self.container_style.as_ref(), style);
+
+        if let Some(radius) = self.radius {
+            let border = style.get_or_insert_default().border.get_or_insert_default();
+            border.radius = border.radius.or(Some(radius));
+        }
+
+        style
+    }
+
+    /// Fills in the unset fields of a scrollable style.
+    pub fn scrollable_style(&self, style: Option<scrollable::Style>) -> Option<scrollable::Style> {
+        style.or_else(|| self.scrollable_style.clone())
+    }
+
+    /// Fills in the unset fields of a button style.
+    pub fn button_style(&self, style: Option<button::Style>) -> Option<button::Style> {
+        let mut style = merge_button_style(self.button_style.as_ref(), style);
+
+        if let Some(radius) = self.radius {
+            let style = style.get_or_insert_default();
+            for inner in [
+                &mut style.active,
+                &mut style.hovered,
+                &mut style.pressed,
+                &mut style.disabled,
+            ] {
+                let border = inner.get_or_insert_default().border.get_or_insert_default();
+                border.radius = border.radius.or(Some(radius));
+            }
+        }
+
+        style
+    }
+}
+
+fn iced_theme_from_palette(palette: Palette) -> iced::Theme {
+    let Palette {
+        background,
+        text,
+        primary,
+        success,
+        warning,
+        danger,
+    } = palette;
+
+    let base = iced::Theme::CatppuccinFrappe.palette();
+    let color = |color: Option<Color>, default| color.map(iced::Color::from_api).unwrap_or(default);
+
+    iced::Theme::custom(
+        String::from("snowcap"),
+        iced::theme::Palette {
+            background: color(background, base.background),
+            text: color(text, base.text),
+            primary: color(primary, base.primary),
+            success: color(success, base.success),
+            warning: color(warning, base.warning),
+            danger: color(danger, base.danger),
+        },
+    )
+}
+
+fn merge_text_style(base: Option<&text::Style>, style: Option<text::Style>) -> Option<text::Style> {
+    match (base, style) {
+        (Some(base), Some(mut style)) => {
+            style.color = style.color.or(base.color);
+            style.pixels = style.pixels.or(base.pixels);
+            style.font = style.font.or_else(|| base.font.clone());
+            Some(style)
+        }
+        (base, style) => style.or_else(|| base.cloned()),
+    }
+}
+
+fn merge_border(base: Option<Border>, border: Option<Border>) -> Option<Border> {
+    match (base, border) {
+        (Some(base), Some(border)) => Some(Border {
+            color: border.color.or(base.color),
+            width: border.width.or(base.width),
+            radius: border.radius.or(base.radius),
+        }),
+        (base, border) => border.or(base),
+    }
+}
+
+fn merge_container_style(
+    base: Option<&container::Style>,
+    style: Option<container::Style>,
+) -> Option<container::Style> {
+    match (base, style) {
+        (Some(base), Some(mut style)) => {
+            style.text_color = style.text_color.or(base.text_color);
+            style.border = merge_border(base.border, style.border);
+            style.background = style.background.or_else(|| base.background.clone());
+            Some(style)
+        }
+        (base, style) => style.or_else(|| base.cloned()),
+    }
+}
+
+fn merge_button_inner(
+    base: Option<&button::style::Inner>,
+    inner: Option<button::style::Inner>,
+) -> Option<button::style::Inner> {
+    match (base, inner) {
+        (Some(base), Some(mut inner)) => {
+            inner.text_color = inner.text_color.or(base.text_color);
+            inner.border = merge_border(base.border, inner.border);
+            inner.background = inner.background.or_else(|| base.background.clone());
+            Some(inner)
+        }
+        (base, inner) => inner.or_else(|| base.cloned()),
+    }
+}
+
+fn merge_button_style(
+    base: Option<&button::Style>,
+    style: Option<button::Style>,
+) -> Option<button::Style> {
+    match (base, style) {
+        (Some(base), Some(style)) => Some(button::Style {
+            active: merge_button_inner(base.active.as_ref(), style.active),
+            hovered: merge_button_inner(base.hovered.as_ref(), style.hovered),
+            pressed: merge_button_inner(base.pressed.as_ref(), style.pressed),
+            disabled: merge_button_inner(base.disabled.as_ref(), style.disabled),
+        }),
+        (base, style) => style.or_else(|| base.cloned()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RED: Color = Color {
+        red: 1.0,
+        green: 0.0,
+        blue: 0.0,
+        alpha: 1.0,
+    };
+
+    const BLUE: Color = Color {
+        red: 0.0,
+        green: 0.0,
+        blue: 1.0,
+        alpha: 1.0,
+    };
+
+    const RADIUS: Radius = Radius {
+        top_left: 4.0,
+        top_right: 4.0,
+        bottom_right: 4.0,
+        bottom_left: 4.0,
+    };
+
+    fn text_theme(color: Color, pixels: f32) -> Theme {
+        Theme {
+            text_style: Some(text::Style {
+                color: Some(color),
+                pixels: Some(pixels),
+                font: None,
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn theme_reaches_nested_children() {
+        let root = InheritedTheme::default().merge(Some(Theme {
+            spacing: Some(8.0),
+            radius: Some(RADIUS),
+            ..text_theme(RED, 14.0)
+        }));
+        let grandchild = root.merge(None).merge(None);
+
+        let style = grandchild.text_style(None).unwrap();
+        assert_eq!(style.color, Some(RED));
+        assert_eq!(style.pixels, Some(14.0));
+        assert_eq!(grandchild.spacing, Some(8.0));
+
+        let border = grandchild.container_style(None).unwrap().border.unwrap();
+        assert_eq!(border.radius, Some(RADIUS));
+    }
+
+    #[test]
+    fn widget_style_overrides_theme() {
+        let theme = InheritedTheme::default().merge(Some(text_theme(RED, 14.0)));
+
+        let style = theme
+            .text_style(Some(text::Style {
+                color: Some(BLUE),
+                pixels: None,
+                font: None,
+            }))
+            .unwrap();
+
+        assert_eq!(style.color, Some(BLUE));
+        assert_eq!(style.pixels, Some(14.0));
+    }
+
+    #[test]
+    fn widget_radius_overrides_theme() {
+        let theme = InheritedTheme::default().merge(Some(Theme {
+            radius: Some(RADIUS),
+            ..Default::default()
+        }));
+        let radius = Radius {
+            top_left: 0.0,
+            top_right: 0.0,
+            bottom_right: 0.0,
+            bottom_left: 0.0,
+        };
+
+        let style = theme
+            .container_style(Some(container::Style {
+                border: Some(Border {
+                    color: None,
+                    width: None,
+                    radius: Some(radius),
+                }),
+                ..Default::default()
+            }))
+            .unwrap();
+
+        assert_eq!(style.border.unwrap().radius, Some(radius));
+    }
+
+    #[test]
+    fn nested_theme_overrides_only_its_fields() {
+        let theme = InheritedTheme::default()
+            .merge(Some(Theme {
+                spacing: Some(8.0),
+                ..text_theme(RED, 14.0)
+            }))
+            .merge(Some(Theme {
+                text_style: Some(text::Style {
+                    color: Some(BLUE),
+                    pixels: None,
+                    font: None,
+                }),
+                ..Default::default()
+            }));
+
+        let style = theme.text_style(None).unwrap();
+        assert_eq!(style.color, Some(BLUE));
+        assert_eq!(style.pixels, Some(14.0));
+        assert_eq!(theme.spacing, Some(8.0));
+    }
+
+    #[test]
+    fn container_text_color_reaches_children() {
+        let theme = InheritedTheme::default()
+            .merge(Some(Theme {
+                palette: Some(Palette {
+                    text: Some(RED),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }))
+            .with_text_color(Some(BLUE));
+
+        let style = theme.merge(None).text_style(None).unwrap();
+        assert_eq!(style.color, Some(BLUE));
+    }
+}
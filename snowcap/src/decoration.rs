@@ -2,6 +2,7 @@ use iced_runtime::core::widget;
 use smithay_client_toolkit::reexports::{
     protocols::ext::foreign_toplevel_list::v1::client::ext_foreign_toplevel_handle_v1::ExtForeignToplevelHandleV1,
 };
+use snowcap_api_defs::snowcap::widget::v1::WidgetDef;
 use snowcap_protocols::snowcap_decoration_v1::client::snowcap_decoration_surface_v1::SnowcapDecorationSurfaceV1;
 
 use crate::{popup::ParentId, state::State, surface::SnowcapSurface, widget::ViewFn};
@@ -159,14 +160,14 @@ impl SnowcapDecoration {
 
     pub fn update_properties(
         &mut self,
-        widgets: Option<ViewFn>,
+        widget_def: Option<WidgetDef>,
         bounds: Option<Bounds>,
         extents: Option<Bounds>,
         z_index: Option<i32>,
     ) {
-        if let Some(widgets) = widgets {
-            self.surface.view_changed(widgets);
-        }
+        let properties_changed = bounds.is_some() || extents.is_some() || z_index.is_some();
+        let view_changed =
+            widget_def.is_some_and(|widget_def| self.surface.widget_def_changed(widget_def));
 
         if let Some(bounds) = bounds {
             self.pending_bounds = Some(bounds);
@@ -180,7 +181,9 @@ impl SnowcapDecoration {
             self.pending_z_index = Some(z_index);
         }
 
-        self.surface.request_frame();
+        if properties_changed || view_changed {
+            self.surface.request_frame();
+        }
     }
 
     pub fn draw_if_scheduled(&mut self) {
@@ -7,7 +7,7 @@ pub mod pointer;
 use smithay_client_toolkit::{
     compositor::CompositorHandler,
     delegate_compositor, delegate_layer, delegate_output, delegate_registry, delegate_seat,
-    delegate_xdg_popup, delegate_xdg_shell,
+    delegate_shm, delegate_xdg_popup, delegate_xdg_shell,
     output::{OutputHandler, OutputState},
     reexports::{
         client::{
@@ -38,6 +38,7 @@ use smithay_client_toolkit::{
             window::WindowHandler,
         },
     },
+    shm::{Shm, ShmHandler},
 };
 
 use crate::{layer::InitialConfigureState, state::State};
@@ -150,6 +151,9 @@ impl OutputHandler for State {
             .filter(|layer| layer.wl_output.as_ref() == Some(&output))
         {
             layer.output_size_changed(iced::Size::new(size.0 as u32, size.1 as u32));
+            layer
+                .surface
+                .fallback_scale_changed(output_info.scale_factor);
             layer.surface.request_frame();
         }
 
@@ -159,6 +163,9 @@ impl OutputHandler for State {
             .filter(|p| p.wl_output.as_ref() == Some(&output))
         {
             popup.output_size_changed(iced::Size::new(size.0 as u32, size.1 as u32));
+            popup
+                .surface
+                .fallback_scale_changed(output_info.scale_factor);
             popup.surface.request_frame();
         }
     }
@@ -228,9 +235,15 @@ impl CompositorHandler for State {
         &mut self,
         _conn: &Connection,
         _qh: &QueueHandle<Self>,
-        _surface: &WlSurface,
-        _new_factor: i32,
+        surface: &WlSurface,
+        new_factor: i32,
     ) {
+        let Some(surface) = self.snowcap_surface_for(surface) else {
+            return;
+        };
+
+        surface.fallback_scale_changed(new_factor);
+        surface.request_frame();
     }
 
     fn transform_changed(
@@ -302,6 +315,9 @@ impl CompositorHandler for State {
             .find(|layer| layer.layer.wl_surface() == surface)
         {
             layer.wl_output = Some(output.clone());
+            layer
+                .surface
+                .fallback_scale_changed(output_info.scale_factor);
 
             if let InitialConfigureState::PreConfigure(pending) = &mut layer.initial_configure {
                 *pending = Some(size);
@@ -322,6 +338,9 @@ impl CompositorHandler for State {
             .find(|p| p.popup.wl_surface() == surface)
         {
             popup.wl_output = Some(output.clone());
+            popup
+                .surface
+                .fallback_scale_changed(output_info.scale_factor);
 
             popup.output_size_changed(size);
 
@@ -342,6 +361,13 @@ impl CompositorHandler for State {
 }
 delegate_compositor!(State);
 
+impl ShmHandler for State {
+    fn shm_state(&mut self) -> &mut Shm {
+        &mut self.shm
+    }
+}
+delegate_shm!(State);
+
 delegate_noop!(State: WpFractionalScaleManagerV1);
 delegate_noop!(State: WpViewporter);
 delegate_noop!(State: WpViewport);
@@ -356,25 +382,7 @@ impl Dispatch<WpFractionalScaleV1, WlSurface> for State {
         _conn: &Connection,
         _qhandle: &QueueHandle<Self>,
     ) {
-        let surface = if let Some(layer) = state
-            .layers
-            .iter_mut()
-            .find(|layer| layer.layer.wl_surface() == surface)
-        {
-            &mut layer.surface
-        } else if let Some(deco) = state
-            .decorations
-            .iter_mut()
-            .find(|deco| &deco.surface.wl_surface == surface)
-        {
-            &mut deco.surface
-        } else if let Some(popup) = state
-            .popups
-            .iter_mut()
-            .find(|popup| &popup.surface.wl_surface == surface)
-        {
-            &mut popup.surface
-        } else {
+        let Some(surface) = state.snowcap_surface_for(surface) else {
             return;
         };
 
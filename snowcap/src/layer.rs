@@ -8,7 +8,7 @@ use smithay_client_toolkit::{
         wlr_layer::{self, Anchor, LayerSurface},
     },
 };
-use snowcap_api_defs::snowcap::input::v0alpha1::PointerButtonResponse;
+use snowcap_api_defs::snowcap::{input::v0alpha1::PointerButtonResponse, widget::v1::WidgetDef};
 use tokio::sync::mpsc::UnboundedSender;
 use tonic::Status;
 
@@ -106,7 +106,17 @@ pub enum ExclusiveZone {
     Ignore,
 }
 
+/// The space between a layer surface and the edges of the output it's anchored to.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Margin {
+    pub top: i32,
+    pub right: i32,
+    pub bottom: i32,
+    pub left: i32,
+}
+
 impl SnowcapLayer {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         state: &mut State,
         // COMPAT: 0.1
@@ -115,6 +125,7 @@ impl SnowcapLayer {
         anchor: Anchor,
         exclusive_zone: ExclusiveZone,
         keyboard_interactivity: wlr_layer::KeyboardInteractivity,
+        margin: Margin,
         widgets: ViewFn,
     ) -> Self {
         let surface = SnowcapSurface::new(state, widgets, false);
@@ -135,6 +146,7 @@ impl SnowcapLayer {
             ExclusiveZone::Respect => 0,
             ExclusiveZone::Ignore => -1,
         });
+        layer.set_margin(margin.top, margin.right, margin.bottom, margin.left);
 
         layer.commit();
 
@@ -169,11 +181,16 @@ impl SnowcapLayer {
         anchor: Option<Anchor>,
         exclusive_zone: Option<ExclusiveZone>,
         keyboard_interactivity: Option<wlr_layer::KeyboardInteractivity>,
-        widgets: Option<ViewFn>,
+        margin: Option<Margin>,
+        widget_def: Option<WidgetDef>,
     ) {
-        if let Some(widgets) = widgets {
-            self.surface.view_changed(widgets);
-        }
+        let properties_changed = layer.is_some()
+            || anchor.is_some()
+            || exclusive_zone.is_some()
+            || keyboard_interactivity.is_some()
+            || margin.is_some();
+        let view_changed =
+            widget_def.is_some_and(|widget_def| self.surface.widget_def_changed(widget_def));
 
         if let Some(layer) = layer {
             self.layer.set_layer(layer);
@@ -196,7 +213,14 @@ impl SnowcapLayer {
                 .set_keyboard_interactivity(keyboard_interactivity);
         }
 
-        self.surface.request_frame();
+        if let Some(margin) = margin {
+            self.layer
+                .set_margin(margin.top, margin.right, margin.bottom, margin.left);
+        }
+
+        if properties_changed || view_changed {
+            self.surface.request_frame();
+        }
     }
 
     pub fn draw_if_scheduled(&mut self) {
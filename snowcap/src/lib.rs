@@ -10,6 +10,7 @@ pub mod runtime;
 pub mod server;
 pub mod state;
 pub mod surface;
+pub mod thumbnail;
 pub mod util;
 pub mod wgpu;
 pub mod widget;
@@ -9,6 +9,7 @@ use smithay_client_toolkit::{
     },
     shell::xdg::{XdgPositioner, popup::Popup},
 };
+use snowcap_api_defs::snowcap::widget::v1::WidgetDef;
 use tokio::sync::mpsc::UnboundedSender;
 
 use crate::{
@@ -499,7 +500,7 @@ impl SnowcapPopup {
         gravity: Option<xdg_positioner::Gravity>,
         offset: Option<Offset>,
         constraints_adjustment: Option<ConstraintAdjustment>,
-        widgets: Option<ViewFn>,
+        widget_def: Option<WidgetDef>,
     ) {
         let mut update_pos = false;
 
@@ -534,16 +535,17 @@ impl SnowcapPopup {
             update_pos = true;
         }
 
-        if let Some(widgets) = widgets {
-            self.surface.view_changed(widgets);
-        }
+        let view_changed =
+            widget_def.is_some_and(|widget_def| self.surface.widget_def_changed(widget_def));
 
         if update_pos {
             self.send_reposition();
         }
 
-        self.recompute_size = true;
-        self.surface.request_frame();
+        if update_pos || view_changed {
+            self.recompute_size = true;
+            self.surface.request_frame();
+        }
     }
 
     pub fn draw_if_scheduled(&mut self) {
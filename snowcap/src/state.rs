@@ -5,7 +5,10 @@ use smithay_client_toolkit::{
     compositor::CompositorState,
     output::OutputState,
     reexports::{
-        calloop::{self, Dispatcher, LoopHandle, LoopSignal},
+        calloop::{
+            self, Dispatcher, LoopHandle, LoopSignal,
+            timer::{TimeoutAction, Timer},
+        },
         calloop_wayland_source::WaylandSource,
         client::{
             Connection, QueueHandle,
@@ -30,8 +33,12 @@ use smithay_client_toolkit::{
     registry::RegistryState,
     seat::{SeatState, keyboard::Modifiers, pointer::cursor_shape::CursorShapeManager},
     shell::{WaylandSurface, wlr_layer::LayerShell, xdg::XdgShell},
+    shm::Shm,
+};
+use snowcap_protocols::{
+    snowcap_decoration_v1::client::snowcap_decoration_manager_v1::SnowcapDecorationManagerV1,
+    snowcap_thumbnail_v1::client::snowcap_thumbnail_manager_v1::SnowcapThumbnailManagerV1,
 };
-use snowcap_protocols::snowcap_decoration_v1::client::snowcap_decoration_manager_v1::SnowcapDecorationManagerV1;
 use xkbcommon::xkb::Keysym;
 
 use crate::{
@@ -42,6 +49,7 @@ use crate::{
     runtime::{CalloopSenderSink, CurrentTokioExecutor},
     server::GrpcServerState,
     surface::{self, CalloopNotifier},
+    thumbnail::{self, Thumbnail},
     widget::SnowcapMessage,
 };
 
@@ -57,10 +65,13 @@ pub struct State {
     pub seat_state: SeatState,
     pub output_state: OutputState,
     pub compositor_state: CompositorState,
+    pub shm: Shm,
     pub layer_shell_state: LayerShell,
-    pub fractional_scale_manager: WpFractionalScaleManagerV1,
+    pub fractional_scale_manager: Option<WpFractionalScaleManagerV1>,
     pub viewporter: WpViewporter,
     pub snowcap_decoration_manager: SnowcapDecorationManagerV1,
+    /// The thumbnail manager, if the compositor supports thumbnails.
+    pub snowcap_thumbnail_manager: Option<SnowcapThumbnailManagerV1>,
     pub foreign_toplevel_list: ExtForeignToplevelListV1,
     pub xdg_shell: XdgShell,
     pub cursor_shape_manager: CursorShapeManager,
@@ -76,6 +87,7 @@ pub struct State {
     pub layers: Vec<SnowcapLayer>,
     pub decorations: Vec<SnowcapDecoration>,
     pub popups: Vec<SnowcapPopup>,
+    pub thumbnails: Vec<Thumbnail>,
 
     pub seat: Option<WlSeat>,
     // TODO: per wl_keyboard
@@ -112,11 +124,14 @@ impl State {
         let registry_state = RegistryState::new(&globals);
         let output_state = OutputState::new(&globals, &queue_handle);
         let compositor_state = CompositorState::bind(&globals, &queue_handle).unwrap();
-        let fractional_scale_manager: WpFractionalScaleManagerV1 =
-            globals.bind(&queue_handle, 1..=1, ()).unwrap();
+        let shm = Shm::bind(&globals, &queue_handle).unwrap();
+        let fractional_scale_manager: Option<WpFractionalScaleManagerV1> =
+            globals.bind(&queue_handle, 1..=1, ()).ok();
         let viewporter: WpViewporter = globals.bind(&queue_handle, 1..=1, ()).unwrap();
         let snowcap_decoration_manager: SnowcapDecorationManagerV1 =
             globals.bind(&queue_handle, 1..=1, ()).unwrap();
+        let snowcap_thumbnail_manager: Option<SnowcapThumbnailManagerV1> =
+            globals.bind(&queue_handle, 1..=2, ()).ok();
         let foreign_toplevel_list: ExtForeignToplevelListV1 =
             globals.bind(&queue_handle, 1..=1, ()).unwrap();
         let cursor_shape_manager = CursorShapeManager::bind(&globals, &queue_handle).unwrap();
@@ -163,6 +178,13 @@ impl State {
             })
             .unwrap();
 
+        loop_handle
+            .insert_source(Timer::immediate(), |_, _, state| {
+                state.refresh_thumbnails();
+                TimeoutAction::ToDuration(thumbnail::REFRESH_INTERVAL)
+            })
+            .unwrap();
+
         let notifier = CalloopNotifier::new(request_redraw_ping, invalidate_layout_ping);
         let shell = iced_graphics::Shell::new(notifier);
 
@@ -272,10 +294,12 @@ impl State {
             seat_state,
             output_state,
             compositor_state,
+            shm,
             layer_shell_state,
             fractional_scale_manager,
             viewporter,
             snowcap_decoration_manager,
+            snowcap_thumbnail_manager,
             foreign_toplevel_list,
             xdg_shell,
             cursor_shape_manager,
@@ -288,6 +312,7 @@ impl State {
             layers: Vec::new(),
             decorations: Vec::new(),
             popups: Vec::new(),
+            thumbnails: Vec::new(),
             seat,
             keyboard_focus: None,
             keyboard_modifiers: smithay_client_toolkit::seat::keyboard::Modifiers::default(),
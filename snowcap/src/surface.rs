@@ -18,9 +18,11 @@ use smithay_client_toolkit::{
         },
     },
 };
+use snowcap_api_defs::snowcap::widget::v1::WidgetDef;
 use tokio::sync::mpsc::UnboundedSender;
 
 use crate::{
+    api::widget::v1::widget_def_to_fn,
     clipboard::WaylandClipboard,
     compositor::{Renderer, Surface},
     state::State,
@@ -28,6 +30,114 @@ use crate::{
     widget::{SnowcapMessage, SnowcapWidgetProgram, ViewFn, WidgetEvent, WidgetId},
 };
 
+/// The scale a surface is rendered at.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct SurfaceScale {
+    scale: f32,
+    pending: Option<f32>,
+    /// Whether the compositor has sent a fractional scale for this surface.
+    ///
+    /// Until then, the integer scale of the output is used.
+    has_preferred_scale: bool,
+}
+
+impl Default for SurfaceScale {
+    fn default() -> Self {
+        Self {
+            scale: 1.0,
+            pending: None,
+            has_preferred_scale: false,
+        }
+    }
+}
+
+impl SurfaceScale {
+    fn get(&self) -> f32 {
+        self.scale
+    }
+
+    fn preferred_scale_changed(&mut self, new_scale: f32) {
+        self.has_preferred_scale = true;
+        self.pending = Some(new_scale);
+    }
+
+    fn fallback_scale_changed(&mut self, new_scale: i32) {
+        if !self.has_preferred_scale && new_scale > 0 {
+            self.pending = Some(new_scale as f32);
+        }
+    }
+
+    /// Applies the pending scale.
+    ///
+    /// Returns whether the scale changed, in which case the surface needs to be re-rendered.
+    fn apply_pending(&mut self) -> bool {
+        let Some(scale) = self.pending.take() else {
+            return false;
+        };
+
+        // HACK: With exact fractional scaling, there's a small seam between
+        // adjacent widgets with fractional scales like 1.125.
+        // Rounding up to the nearest 0.25 seems to work around that issue.
+        let scale = (scale * 4.0).ceil() / 4.0;
+        if scale == self.scale {
+            return false;
+        }
+
+        self.scale = scale;
+        true
+    }
+}
+
+/// The view of a surface and the view it's waiting to apply.
+#[derive(Default)]
+struct PendingView {
+    /// The widget definition of the latest view.
+    widget_def: Option<WidgetDef>,
+    /// A new view that will be applied when the next frame is drawn.
+    pending: Option<ViewFn>,
+    /// Whether the client was sent widget events and a new view is expected.
+    waiting: bool,
+}
+
+impl PendingView {
+    fn set(&mut self, view: ViewFn) {
+        self.pending = Some(view);
+    }
+
+    /// Sets the pending view from a widget definition.
+    ///
+    /// Returns whether the definition differs from the current one.
+    fn widget_def_changed(&mut self, widget_def: WidgetDef) -> bool {
+        if self.widget_def.as_ref() == Some(&widget_def) {
+            // The client may be answering a view request, so don't keep waiting on it.
+            self.waiting = false;
+            return false;
+        }
+
+        let Some(view) = widget_def_to_fn(widget_def.clone()) else {
+            return false;
+        };
+
+        self.widget_def = Some(widget_def);
+        self.set(view);
+        true
+    }
+
+    /// Takes the pending view if a frame is about to be drawn.
+    ///
+    /// New views are only applied when a frame is about to be drawn, so views sent
+    /// in quick succession cause at most one re-layout per frame.
+    fn take_for_frame(&mut self, frame_scheduled: bool) -> Option<ViewFn> {
+        if !frame_scheduled {
+            return None;
+        }
+
+        let view = self.pending.take()?;
+        self.waiting = false;
+        Some(view)
+    }
+}
+
 pub struct SnowcapSurface {
     // This is an option so we can drop it first
     surface: Option<<crate::compositor::Compositor as iced_graphics::Compositor>::Surface>,
@@ -39,17 +149,15 @@ pub struct SnowcapSurface {
     loop_handle: LoopHandle<'static, State>,
 
     /// The scale of the output this layer is on.
-    output_scale: f32,
-    pending_output_scale: Option<f32>,
+    output_scale: SurfaceScale,
     bounds: iced::Size<u32>,
     pending_bounds: Option<iced::Size<u32>>,
 
     renderer: Renderer,
 
     redraw_scheduled: bool,
-    pending_view: Option<ViewFn>,
+    view: PendingView,
     view_requested: bool,
-    waiting_view: bool,
     layout_invalidated: bool,
     pub widgets: SnowcapWidgetProgram,
     clipboard: WaylandClipboard,
@@ -61,11 +169,35 @@ pub struct SnowcapSurface {
     pub window_id: iced::window::Id,
 
     viewport: WpViewport,
-    fractional_scale: WpFractionalScaleV1,
+    fractional_scale: Option<WpFractionalScaleV1>,
 
     pub widget_event_sender: Option<UnboundedSender<Vec<(WidgetId, WidgetEvent)>>>,
 }
 
+impl State {
+    /// Returns the layer, decoration, or popup surface with the given [`WlSurface`].
+    pub fn snowcap_surface_for(&mut self, surface: &WlSurface) -> Option<&mut SnowcapSurface> {
+        if let Some(layer) = self
+            .layers
+            .iter_mut()
+            .find(|layer| &layer.surface.wl_surface == surface)
+        {
+            Some(&mut layer.surface)
+        } else if let Some(deco) = self
+            .decorations
+            .iter_mut()
+            .find(|deco| &deco.surface.wl_surface == surface)
+        {
+            Some(&mut deco.surface)
+        } else {
+            self.popups
+                .iter_mut()
+                .find(|popup| &popup.surface.wl_surface == surface)
+                .map(|popup| &mut popup.surface)
+        }
+    }
+}
+
 impl Drop for SnowcapSurface {
     fn drop(&mut self) {
         // SAFETY: This needs to be dropped first, it implicitly borrows the wl_surface
@@ -73,7 +205,9 @@ impl Drop for SnowcapSurface {
         // SAFETY: If a toplevel surface was set, let's drop it early.
         self.toplevel_wl_surface.take();
 
-        self.fractional_scale.destroy();
+        if let Some(fractional_scale) = self.fractional_scale.take() {
+            fractional_scale.destroy();
+        }
         self.wl_surface.destroy();
         self.viewport.destroy();
     }
@@ -85,11 +219,9 @@ impl SnowcapSurface {
         let viewport = state
             .viewporter
             .get_viewport(&wl_surface, &state.queue_handle, ());
-        let fractional_scale = state.fractional_scale_manager.get_fractional_scale(
-            &wl_surface,
-            &state.queue_handle,
-            wl_surface.clone(),
-        );
+        let fractional_scale = state.fractional_scale_manager.as_ref().map(|manager| {
+            manager.get_fractional_scale(&wl_surface, &state.queue_handle, wl_surface.clone())
+        });
         let compositor_state = state.compositor_state.clone();
 
         let window_handle = WindowHandle::new(&wl_surface);
@@ -133,13 +265,11 @@ impl SnowcapSurface {
             compositor_state,
             queue_handle: state.queue_handle.clone(),
             loop_handle: state.loop_handle.clone(),
-            output_scale: 1.0,
-            pending_output_scale: None,
+            output_scale: SurfaceScale::default(),
             bounds: iced::Size::default(),
             pending_bounds: None,
             view_requested: false,
-            waiting_view: false,
-            pending_view: None,
+            view: PendingView::default(),
             layout_invalidated: false,
             widgets,
             renderer,
@@ -155,8 +285,16 @@ impl SnowcapSurface {
         }
     }
 
+    /// Sets the fractional scale the compositor prefers for this surface.
     pub fn scale_changed(&mut self, new_scale: f32) {
-        self.pending_output_scale = Some(new_scale);
+        self.output_scale.preferred_scale_changed(new_scale);
+    }
+
+    /// Sets the integer scale of the output this surface is on.
+    ///
+    /// This is ignored once the compositor has sent a fractional scale.
+    pub fn fallback_scale_changed(&mut self, new_scale: i32) {
+        self.output_scale.fallback_scale_changed(new_scale);
     }
 
     pub fn bounds_changed(&mut self, new_bounds: iced::Size<u32>) {
@@ -164,7 +302,16 @@ impl SnowcapSurface {
     }
 
     pub fn view_changed(&mut self, new_view: ViewFn) {
-        self.pending_view = Some(new_view);
+        self.view.set(new_view);
+    }
+
+    /// Sets the view of this surface from a widget definition.
+    ///
+    /// Returns whether the definition differs from the current one. An identical
+    /// definition is ignored, so clients resending the same view every second don't
+    /// cause a re-layout and redraw.
+    pub fn widget_def_changed(&mut self, widget_def: WidgetDef) -> bool {
+        self.view.widget_def_changed(widget_def)
     }
 
     pub fn invalidate_layout(&mut self) {
@@ -210,7 +357,7 @@ impl SnowcapSurface {
                 iced_wgpu::window::compositor::present(
                     renderer,
                     surface,
-                    &self.widgets.viewport(self.output_scale),
+                    &self.widgets.viewport(self.output_scale.get()),
                     iced::Color::TRANSPARENT,
                     || {},
                 )
@@ -220,7 +367,7 @@ impl SnowcapSurface {
                 iced_tiny_skia::window::compositor::present(
                     renderer,
                     surface,
-                    &self.widgets.viewport(self.output_scale),
+                    &self.widgets.viewport(self.output_scale.get()),
                     iced::Color::TRANSPARENT,
                     || {},
                 )
@@ -239,13 +386,7 @@ impl SnowcapSurface {
         let _span = tracy_client::span!("SnowcapSurface::update");
 
         let mut needs_rebuild = mem::take(&mut self.layout_invalidated);
-        if let Some(scale) = self.pending_output_scale.take()
-            && scale != self.output_scale
-        {
-            // HACK: With exact fractional scaling, there's a small seam between
-            // adjacent widgets with fractional scales like 1.125.
-            // Rounding up to the nearest 0.25 seems to work around that issue.
-            self.output_scale = (scale * 4.0).ceil() / 4.0;
+        if self.output_scale.apply_pending() {
             needs_rebuild = true;
         }
         if let Some(bounds) = self.pending_bounds.take()
@@ -254,9 +395,11 @@ impl SnowcapSurface {
             self.bounds = bounds;
             needs_rebuild = true;
         }
-        if self.pending_view.is_some() {
+        let view = self
+            .view
+            .take_for_frame(self.redraw_scheduled || needs_rebuild);
+        if view.is_some() {
             needs_rebuild = true;
-            self.waiting_view = false;
         }
 
         let mut update_status = UpdateStatus::default();
@@ -265,7 +408,7 @@ impl SnowcapSurface {
             let old_size = self.widgets.size();
 
             self.widgets
-                .rebuild_ui(self.bounds, &mut self.renderer, self.pending_view.take())
+                .rebuild_ui(self.bounds, &mut self.renderer, view)
                 .update(&self.queue_handle, &self.compositor_state, &self.wl_surface);
 
             if self.widgets.size() != old_size {
@@ -280,7 +423,10 @@ impl SnowcapSurface {
 
             self.viewport.set_destination(width, height);
 
-            let buffer_size = self.widgets.viewport(self.output_scale).physical_size();
+            let buffer_size = self
+                .widgets
+                .viewport(self.output_scale.get())
+                .physical_size();
 
             compositor.configure_surface(
                 self.surface.as_mut().unwrap(),
@@ -299,7 +445,7 @@ impl SnowcapSurface {
 
         let mut messages = Vec::new();
 
-        if self.waiting_view {
+        if self.view.waiting {
             return update_status;
         }
 
@@ -372,7 +518,7 @@ impl SnowcapSurface {
                 .collect();
 
             self.view_requested = false;
-            self.waiting_view = true;
+            self.view.waiting = true;
             let _ = sender.send(widget_events);
         }
 
@@ -522,3 +668,106 @@ impl HasDisplayHandle for DisplayHandle {
         Ok(unsafe { raw_window_handle::DisplayHandle::borrow_raw(self.display) })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use snowcap_api_defs::snowcap::widget::v1::{Text, widget_def};
+
+    use super::*;
+
+    fn text_def(text: &str) -> WidgetDef {
+        WidgetDef {
+            theme: None,
+            widget: Some(widget_def::Widget::Text(Text {
+                text: text.to_string(),
+                ..Default::default()
+            })),
+        }
+    }
+
+    #[test]
+    fn fallback_scale_change_rerenders() {
+        let mut scale = SurfaceScale::default();
+
+        scale.fallback_scale_changed(2);
+        assert!(scale.apply_pending());
+        assert_eq!(scale.get(), 2.0);
+
+        scale.fallback_scale_changed(2);
+        assert!(!scale.apply_pending());
+        assert!(!scale.apply_pending());
+    }
+
+    #[test]
+    fn invalid_fallback_scale_is_ignored() {
+        let mut scale = SurfaceScale::default();
+
+        scale.fallback_scale_changed(0);
+        assert!(!scale.apply_pending());
+        assert_eq!(scale.get(), 1.0);
+    }
+
+    #[test]
+    fn preferred_scale_overrides_fallback() {
+        let mut scale = SurfaceScale::default();
+
+        scale.preferred_scale_changed(1.5);
+        assert!(scale.apply_pending());
+        assert_eq!(scale.get(), 1.5);
+
+        scale.fallback_scale_changed(2);
+        assert!(!scale.apply_pending());
+        assert_eq!(scale.get(), 1.5);
+    }
+
+    #[test]
+    fn fractional_scale_is_rounded_up_to_a_quarter() {
+        let mut scale = SurfaceScale::default();
+
+        scale.preferred_scale_changed(1.125);
+        assert!(scale.apply_pending());
+        assert_eq!(scale.get(), 1.25);
+
+        scale.preferred_scale_changed(1.2);
+        assert!(!scale.apply_pending());
+    }
+
+    #[test]
+    fn rapid_views_are_applied_once_per_frame() {
+        let mut view = PendingView::default();
+
+        assert!(view.widget_def_changed(text_def("1")));
+        assert!(view.widget_def_changed(text_def("2")));
+        assert!(view.widget_def_changed(text_def("3")));
+        assert!(view.take_for_frame(false).is_none());
+
+        assert!(view.take_for_frame(true).is_some());
+        assert!(view.take_for_frame(true).is_none());
+        assert_eq!(view.widget_def, Some(text_def("3")));
+    }
+
+    #[test]
+    fn unchanged_view_is_skipped() {
+        let mut view = PendingView::default();
+
+        assert!(view.widget_def_changed(text_def("1")));
+        assert!(view.take_for_frame(true).is_some());
+
+        view.waiting = true;
+        assert!(!view.widget_def_changed(text_def("1")));
+        assert!(!view.waiting);
+        assert!(view.take_for_frame(true).is_none());
+    }
+
+    #[test]
+    fn applying_a_view_stops_waiting() {
+        let mut view = PendingView::default();
+        view.waiting = true;
+
+        assert!(view.widget_def_changed(text_def("1")));
+        assert!(view.waiting);
+
+        assert!(view.take_for_frame(true).is_some());
+        assert!(!view.waiting);
+    }
+}
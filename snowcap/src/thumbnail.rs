@@ -0,0 +1,319 @@
+//! Live thumbnails of outputs, tags, and windows.
+//!
+//! Thumbnail widgets request a [`ThumbnailImage`] when they are created. Snowcap picks the
+//! request up, captures the source through the snowcap-thumbnail protocol, and keeps the image
+//! up to date for as long as a widget holds on to it.
+
+use std::{
+    sync::{Arc, Mutex, Weak},
+    time::Duration,
+};
+
+use smithay_client_toolkit::{
+    reexports::client::{Dispatch, Proxy, protocol::wl_shm},
+    shm::slot::{Buffer, SlotPool},
+};
+use snowcap_protocols::snowcap_thumbnail_v1::client::{
+    snowcap_thumbnail_manager_v1::SnowcapThumbnailManagerV1,
+    snowcap_thumbnail_v1::{self, SnowcapThumbnailV1},
+};
+use tracing::warn;
+
+use crate::state::State;
+
+/// How often thumbnails are recaptured.
+pub const REFRESH_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Thumbnail images requested by widgets that have yet to be picked up.
+static REQUESTED: Mutex<Vec<Weak<ThumbnailImage>>> = Mutex::new(Vec::new());
+
+/// What a thumbnail captures.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ThumbnailSource {
+    /// An output, by name.
+    Output(String),
+    /// A tag, by its id.
+    Tag(u32),
+    /// A window, by its id.
+    Window(u32),
+}
+
+/// The latest capture of a thumbnail.
+#[derive(Debug)]
+pub struct ThumbnailImage {
+    source: ThumbnailSource,
+    max_width: u32,
+    max_height: u32,
+    handle: Mutex<Option<iced::widget::image::Handle>>,
+}
+
+impl ThumbnailImage {
+    /// Requests a thumbnail that fits within `max_width` by `max_height` pixels.
+    ///
+    /// The thumbnail is captured until the returned image is dropped.
+    pub fn request(source: ThumbnailSource, max_width: u32, max_height: u32) -> Arc<Self> {
+        let image = Arc::new(Self {
+            source,
+            max_width,
+            max_height,
+            handle: Mutex::new(None),
+        });
+
+        REQUESTED.lock().unwrap().push(Arc::downgrade(&image));
+
+        image
+    }
+
+    /// Returns the latest capture, or `None` if the thumbnail hasn't been captured yet.
+    pub fn handle(&self) -> Option<iced::widget::image::Handle> {
+        self.handle.lock().unwrap().clone()
+    }
+}
+
+#[derive(Debug)]
+pub struct Thumbnail {
+    image: Weak<ThumbnailImage>,
+    thumbnail: Option<SnowcapThumbnailV1>,
+    /// The size buffers must be.
+    buffer_size: Option<(u32, u32)>,
+    pool: Option<SlotPool>,
+    buffer: Option<Buffer>,
+    capturing: bool,
+}
+
+impl Thumbnail {
+    fn new(image: Weak<ThumbnailImage>) -> Self {
+        Self {
+            image,
+            thumbnail: None,
+            buffer_size: None,
+            pool: None,
+            buffer: None,
+            capturing: false,
+        }
+    }
+
+    fn reset(&mut self) {
+        if let Some(thumbnail) = self.thumbnail.take() {
+            thumbnail.destroy();
+        }
+        self.buffer_size = None;
+        self.buffer = None;
+        self.capturing = false;
+    }
+}
+
+impl State {
+    /// Picks up newly requested thumbnails, drops unused ones, and recaptures the rest.
+    pub fn refresh_thumbnails(&mut self) {
+        let requested = std::mem::take(&mut *REQUESTED.lock().unwrap());
+        self.thumbnails
+            .extend(requested.into_iter().map(Thumbnail::new));
+
+        self.thumbnails.retain_mut(|thumbnail| {
+            let in_use = thumbnail.image.strong_count() > 0;
+            if !in_use {
+                thumbnail.reset();
+            }
+            in_use
+        });
+
+        let Some(manager) = self.snowcap_thumbnail_manager.as_ref() else {
+            return;
+        };
+
+        for thumbnail in self.thumbnails.iter_mut() {
+            let Some(image) = thumbnail.image.upgrade() else {
+                continue;
+            };
+
+            if thumbnail.thumbnail.is_none() {
+                thumbnail.thumbnail = match &image.source {
+                    ThumbnailSource::Output(name) => self
+                        .output_state
+                        .outputs()
+                        .find(|output| {
+                            self.output_state
+                                .info(output)
+                                .is_some_and(|info| info.name.as_ref() == Some(name))
+                        })
+                        .map(|output| {
+                            manager.get_output_thumbnail(
+                                &output,
+                                image.max_width,
+                                image.max_height,
+                                &self.queue_handle,
+                                (),
+                            )
+                        }),
+                    ThumbnailSource::Tag(tag_id) => Some(manager.get_tag_thumbnail(
+                        *tag_id,
+                        image.max_width,
+                        image.max_height,
+                        &self.queue_handle,
+                        (),
+                    )),
+                    // Window thumbnails were added in version 2
+                    ThumbnailSource::Window(window_id) => (manager.version() >= 2).then(|| {
+                        manager.get_window_thumbnail(
+                            *window_id,
+                            image.max_width,
+                            image.max_height,
+                            &self.queue_handle,
+                            (),
+                        )
+                    }),
+                };
+            }
+
+            let (Some(proxy), Some((width, height))) =
+                (thumbnail.thumbnail.as_ref(), thumbnail.buffer_size)
+            else {
+                continue;
+            };
+
+            if thumbnail.capturing {
+                continue;
+            }
+
+            if thumbnail.buffer.is_none() {
+                let stride = width as i32 * 4;
+                let len = stride as usize * height as usize;
+
+                let pool = match thumbnail.pool.as_mut() {
+                    Some(pool) => pool,
+                    None => match SlotPool::new(len, &self.shm) {
+                        Ok(pool) => thumbnail.pool.insert(pool),
+                        Err(err) => {
+                            warn!("failed to create thumbnail pool: {err}");
+                            continue;
+                        }
+                    },
+                };
+
+                match pool.create_buffer(
+                    width as i32,
+                    height as i32,
+                    stride,
+                    wl_shm::Format::Argb8888,
+                ) {
+                    Ok((buffer, _)) => thumbnail.buffer = Some(buffer),
+                    Err(err) => {
+                        warn!("failed to create thumbnail buffer: {err}");
+                        continue;
+                    }
+                }
+            }
+
+            if let Some(buffer) = thumbnail.buffer.as_ref() {
+                proxy.capture(buffer.wl_buffer());
+                thumbnail.capturing = true;
+            }
+        }
+    }
+
+    /// Copies a finished capture into its image and redraws all surfaces.
+    fn thumbnail_ready(&mut self, proxy: &SnowcapThumbnailV1) {
+        let Some(thumbnail) = self
+            .thumbnails
+            .iter_mut()
+            .find(|thumbnail| thumbnail.thumbnail.as_ref() == Some(proxy))
+        else {
+            return;
+        };
+
+        thumbnail.capturing = false;
+
+        let (Some(image), Some((width, height)), Some(buffer), Some(pool)) = (
+            thumbnail.image.upgrade(),
+            thumbnail.buffer_size,
+            thumbnail.buffer.as_ref(),
+            thumbnail.pool.as_mut(),
+        ) else {
+            return;
+        };
+
+        let Some(canvas) = pool.canvas(buffer) else {
+            return;
+        };
+
+        // Argb8888 is stored as little-endian BGRA.
+        let rgba = canvas
+            .chunks_exact(4)
+            .flat_map(|pixel| [pixel[2], pixel[1], pixel[0], pixel[3]])
+            .collect::<Vec<_>>();
+
+        *image.handle.lock().unwrap() =
+            Some(iced::widget::image::Handle::from_rgba(width, height, rgba));
+
+        for layer in self.layers.iter_mut() {
+            layer.surface.invalidate_layout();
+            layer.schedule_redraw();
+        }
+        for deco in self.decorations.iter_mut() {
+            deco.surface.invalidate_layout();
+            deco.schedule_redraw();
+        }
+        for popup in self.popups.iter_mut() {
+            popup.surface.invalidate_layout();
+            popup.schedule_redraw();
+        }
+    }
+}
+
+impl Dispatch<SnowcapThumbnailManagerV1, ()> for State {
+    fn event(
+        _state: &mut Self,
+        _proxy: &SnowcapThumbnailManagerV1,
+        _event: <SnowcapThumbnailManagerV1 as Proxy>::Event,
+        _data: &(),
+        _conn: &smithay_client_toolkit::reexports::client::Connection,
+        _qhandle: &smithay_client_toolkit::reexports::client::QueueHandle<Self>,
+    ) {
+    }
+}
+
+impl Dispatch<SnowcapThumbnailV1, ()> for State {
+    fn event(
+        state: &mut Self,
+        proxy: &SnowcapThumbnailV1,
+        event: <SnowcapThumbnailV1 as Proxy>::Event,
+        _data: &(),
+        _conn: &smithay_client_toolkit::reexports::client::Connection,
+        _qhandle: &smithay_client_toolkit::reexports::client::QueueHandle<Self>,
+    ) {
+        match event {
+            snowcap_thumbnail_v1::Event::BufferSize { width, height } => {
+                if let Some(thumbnail) = state
+                    .thumbnails
+                    .iter_mut()
+                    .find(|thumbnail| thumbnail.thumbnail.as_ref() == Some(proxy))
+                {
+                    thumbnail.buffer_size = Some((width, height));
+                    thumbnail.buffer = None;
+                }
+            }
+            snowcap_thumbnail_v1::Event::Ready => state.thumbnail_ready(proxy),
+            snowcap_thumbnail_v1::Event::Failed => {
+                if let Some(thumbnail) = state
+                    .thumbnails
+                    .iter_mut()
+                    .find(|thumbnail| thumbnail.thumbnail.as_ref() == Some(proxy))
+                {
+                    thumbnail.capturing = false;
+                }
+            }
+            snowcap_thumbnail_v1::Event::Stopped => {
+                // The thumbnail is recreated on the next refresh in case its source comes back.
+                if let Some(thumbnail) = state
+                    .thumbnails
+                    .iter_mut()
+                    .find(|thumbnail| thumbnail.thumbnail.as_ref() == Some(proxy))
+                {
+                    thumbnail.reset();
+                }
+            }
+            _ => (),
+        }
+    }
+}
@@ -1,10 +1,16 @@
 pub mod debug;
+pub mod idle;
 pub mod input;
 pub mod layout;
+pub mod namespace;
+pub mod nightlight;
 pub mod output;
 pub mod pinnacle;
+pub mod portal;
+pub mod power;
 pub mod process;
 pub mod render;
+pub mod screenshot;
 pub mod signal;
 pub mod tag;
 pub mod window;
@@ -27,6 +33,19 @@ pub type StateFnSender = calloop::channel::Sender<Box<dyn FnOnce(&mut State) + S
 pub type TonicResult<T> = Result<Response<T>, Status>;
 pub type Sender<T> = async_channel::Sender<T>;
 
+/// Returns `new` if it differs from `old`.
+///
+/// Used to only send the properties that changed to watching clients.
+/// Properties that went from set to unset are reported with [`cleared_property`].
+fn changed_property<T: PartialEq + Clone>(old: &Option<T>, new: &Option<T>) -> Option<T> {
+    (old != new).then(|| new.clone()).flatten()
+}
+
+/// Returns `property` if it was set in `old` and isn't in `new`.
+fn cleared_property<T, P>(old: &Option<T>, new: &Option<T>, property: P) -> Option<P> {
+    (old.is_some() && new.is_none()).then_some(property)
+}
+
 async fn run_unary_no_response<F>(
     fn_sender: &StateFnSender,
     with_state: F,
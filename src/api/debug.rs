@@ -1,3 +1,9 @@
+use pinnacle_api_defs::pinnacle::debug::v1::{TraceInputResponse, trace_input_response};
+use tokio::sync::mpsc::UnboundedSender;
+use tonic::Status;
+
+use crate::input::trace::{InputTrace, TracedFocus};
+
 use super::StateFnSender;
 
 mod v1;
@@ -11,3 +17,43 @@ impl DebugService {
         Self { sender }
     }
 }
+
+/// Clients streaming traced input events.
+#[derive(Debug, Default)]
+pub struct InputTraceState {
+    subscribers: Vec<UnboundedSender<Result<TraceInputResponse, Status>>>,
+}
+
+impl InputTraceState {
+    pub fn subscribe(&mut self, sender: UnboundedSender<Result<TraceInputResponse, Status>>) {
+        self.subscribers.push(sender);
+    }
+
+    /// Sends a traced input event to streaming clients.
+    pub fn send(&mut self, trace: &InputTrace) {
+        self.subscribers.retain(|sender| !sender.is_closed());
+
+        if self.subscribers.is_empty() {
+            return;
+        }
+
+        let focus_target = |focus: &TracedFocus| trace_input_response::FocusTarget {
+            description: focus.description.clone(),
+            window_id: focus.window_id,
+            client_pid: focus.client_pid,
+        };
+
+        let response = TraceInputResponse {
+            event: trace.event.to_string(),
+            pointer_focus: trace.pointer_focus.as_ref().map(focus_target),
+            keyboard_focus: trace.keyboard_focus.as_ref().map(focus_target),
+            pointer_grabbed: trace.pointer_grabbed,
+            keyboard_grabbed: trace.keyboard_grabbed,
+            receiving_client_pid: trace.receiving_client_pid,
+        };
+
+        for sender in self.subscribers.iter() {
+            let _ = sender.send(Ok(response.clone()));
+        }
+    }
+}
@@ -2,18 +2,27 @@ use pinnacle_api_defs::pinnacle::{
     debug::{
         self,
         v1::{
-            SetCursorPlaneScanoutRequest, SetDamageVisualizationRequest,
-            SetOpaqueRegionVisualizationRequest, SetProcessPipingRequest,
+            GetGpuStatsRequest, GetGpuStatsResponse, SetCursorPlaneScanoutRequest,
+            SetDamageVisualizationRequest, SetInputTracingRequest, SetLogFilterRequest,
+            SetLogFilterResponse, SetOpaqueRegionVisualizationRequest, SetProcessPipingRequest,
+            TailLogsRequest, TailLogsResponse, TraceInputRequest, TraceInputResponse,
         },
     },
     util::v1::SetOrToggle,
 };
-use tonic::{Request, Status};
+use tokio_stream::{StreamExt, wrappers::UnboundedReceiverStream};
+use tonic::{Request, Response, Status};
 
-use crate::api::{TonicResult, run_unary_no_response};
+use crate::{
+    api::{ResponseStream, TonicResult, run_server_streaming, run_unary, run_unary_no_response},
+    logging,
+};
 
 #[tonic::async_trait]
 impl debug::v1::debug_service_server::DebugService for super::DebugService {
+    type TraceInputStream = ResponseStream<TraceInputResponse>;
+    type TailLogsStream = ResponseStream<TailLogsResponse>;
+
     async fn set_damage_visualization(
         &self,
         request: Request<SetDamageVisualizationRequest>,
@@ -123,4 +132,77 @@ impl debug::v1::debug_service_server::DebugService for super::DebugService {
         })
         .await
     }
+
+    async fn set_input_tracing(&self, request: Request<SetInputTracingRequest>) -> TonicResult<()> {
+        let request = request.into_inner();
+        let set_or_toggle = request.set_or_toggle();
+
+        let set = match set_or_toggle {
+            SetOrToggle::Set => Some(true),
+            SetOrToggle::Unset => Some(false),
+            SetOrToggle::Toggle => None,
+            SetOrToggle::Unspecified => {
+                return Err(Status::invalid_argument("no set or toggle specified"));
+            }
+        };
+
+        run_unary_no_response(&self.sender, move |state| {
+            state.pinnacle.config.debug.trace_input =
+                set.unwrap_or(!state.pinnacle.config.debug.trace_input);
+            tracing::debug!("Input tracing: {}", state.pinnacle.config.debug.trace_input);
+        })
+        .await
+    }
+
+    async fn trace_input(
+        &self,
+        _request: Request<TraceInputRequest>,
+    ) -> TonicResult<Self::TraceInputStream> {
+        run_server_streaming(&self.sender, move |state, sender| {
+            state.pinnacle.input_trace_state.subscribe(sender);
+            Ok(())
+        })
+        .await
+    }
+
+    async fn get_gpu_stats(
+        &self,
+        _request: Request<GetGpuStatsRequest>,
+    ) -> TonicResult<GetGpuStatsResponse> {
+        run_unary(&self.sender, move |state| {
+            let stats = state.pinnacle.gpu_accounting.stats();
+
+            Ok(GetGpuStatsResponse {
+                snapshot_textures: stats.snapshot_textures,
+                snapshot_texture_bytes: stats.snapshot_texture_bytes,
+                cursor_buffers: stats.cursor_buffers,
+                cursor_buffer_bytes: stats.cursor_buffer_bytes,
+                evicted_snapshots: stats.evicted_snapshots,
+            })
+        })
+        .await
+    }
+
+    async fn set_log_filter(
+        &self,
+        request: Request<SetLogFilterRequest>,
+    ) -> TonicResult<SetLogFilterResponse> {
+        let filter = request.into_inner().filter;
+
+        let error = logging::set_filter(&filter)
+            .err()
+            .map(|err| format!("{err:#}"));
+
+        Ok(Response::new(SetLogFilterResponse { error }))
+    }
+
+    async fn tail_logs(
+        &self,
+        _request: Request<TailLogsRequest>,
+    ) -> TonicResult<Self::TailLogsStream> {
+        let lines = UnboundedReceiverStream::new(logging::subscribe())
+            .map(|line| Ok::<_, Status>(TailLogsResponse { line }));
+
+        Ok(Response::new(Box::pin(lines)))
+    }
 }
@@ -0,0 +1,13 @@
+mod v1;
+
+use super::StateFnSender;
+
+pub struct IdleService {
+    sender: StateFnSender,
+}
+
+impl IdleService {
+    pub fn new(sender: StateFnSender) -> Self {
+        Self { sender }
+    }
+}
@@ -0,0 +1,104 @@
+use std::{path::PathBuf, time::Duration};
+
+use pinnacle_api_defs::pinnacle::idle::{
+    self,
+    v1::{SetIdleTimeoutRequest, SetScreensaverRequest, screensaver},
+};
+use tonic::{Request, Status};
+
+use crate::{
+    api::{TonicResult, run_unary, run_unary_no_response},
+    idle::{IdleTimeout, Screensaver, ScreensaverSource},
+    render::screensaver::screensaver_program,
+};
+
+#[tonic::async_trait]
+impl idle::v1::idle_service_server::IdleService for super::IdleService {
+    async fn set_screensaver(&self, request: Request<SetScreensaverRequest>) -> TonicResult<()> {
+        let request = request.into_inner();
+
+        let screensaver = request
+            .screensaver
+            .map(|screensaver| {
+                let timeout = Duration::from_millis(screensaver.timeout_millis.into());
+                if timeout.is_zero() {
+                    return Err(Status::invalid_argument("timeout must be greater than 0"));
+                }
+
+                let source = match screensaver.source {
+                    Some(screensaver::Source::Shader(shader)) => {
+                        ScreensaverSource::Shader(shader.source)
+                    }
+                    Some(screensaver::Source::Slideshow(slideshow)) => {
+                        if slideshow.image_paths.is_empty() {
+                            return Err(Status::invalid_argument("slideshow has no images"));
+                        }
+
+                        let interval = Duration::from_millis(slideshow.interval_millis.into());
+                        if interval.is_zero() {
+                            return Err(Status::invalid_argument(
+                                "slideshow interval must be greater than 0",
+                            ));
+                        }
+
+                        let images = slideshow
+                            .image_paths
+                            .iter()
+                            .map(|path| PathBuf::from(shellexpand::tilde(path).into_owned()))
+                            .collect();
+
+                        ScreensaverSource::Slideshow { images, interval }
+                    }
+                    None => return Err(Status::invalid_argument("no screensaver source")),
+                };
+
+                Ok(Screensaver { timeout, source })
+            })
+            .transpose()?;
+
+        run_unary(&self.sender, move |state| {
+            // Compile the shader now so errors make it back to the config
+            if let Some(Screensaver {
+                source: ScreensaverSource::Shader(source),
+                ..
+            }) = &screensaver
+                && let Some(Err(err)) = state
+                    .backend
+                    .with_renderer(|renderer| screensaver_program(renderer, source))
+            {
+                return Err(Status::invalid_argument(format!(
+                    "failed to compile shader: {err}"
+                )));
+            }
+
+            state.set_screensaver(screensaver);
+
+            Ok(())
+        })
+        .await
+    }
+
+    async fn set_idle_timeout(&self, request: Request<SetIdleTimeoutRequest>) -> TonicResult<()> {
+        let request = request.into_inner();
+
+        let idle_timeout = request
+            .timeout_millis
+            .map(|timeout_millis| {
+                let timeout = Duration::from_millis(timeout_millis.into());
+                if timeout.is_zero() {
+                    return Err(Status::invalid_argument("timeout must be greater than 0"));
+                }
+
+                Ok(IdleTimeout {
+                    timeout,
+                    power_off_outputs: request.power_off_outputs,
+                })
+            })
+            .transpose()?;
+
+        run_unary_no_response(&self.sender, move |state| {
+            state.set_idle_timeout(idle_timeout);
+        })
+        .await
+    }
+}
@@ -1,17 +1,29 @@
-use pinnacle_api_defs::pinnacle::input::{
-    self,
-    v1::{
-        AccelProfile, BindInfo, BindRequest, BindResponse, ClickMethod, EnterBindLayerRequest,
-        GetBindInfosRequest, GetBindInfosResponse, GetBindLayerStackRequest,
-        GetBindLayerStackResponse, GetDeviceCapabilitiesRequest, GetDeviceCapabilitiesResponse,
-        GetDeviceInfoRequest, GetDeviceInfoResponse, GetDeviceTypeRequest, GetDeviceTypeResponse,
-        GetDevicesRequest, GetDevicesResponse, KeybindOnPressRequest, KeybindStreamRequest,
-        KeybindStreamResponse, MousebindOnPressRequest, MousebindStreamRequest,
-        MousebindStreamResponse, ScrollMethod, SendEventsMode, SetBindPropertiesRequest,
-        SetDeviceLibinputSettingRequest, SetDeviceMapTargetRequest, SetRepeatRateRequest,
-        SetXcursorRequest, SetXkbConfigRequest, SetXkbKeymapRequest, SwitchXkbLayoutRequest,
-        TapButtonMap, set_device_map_target_request::Target, switch_xkb_layout_request::Action,
+use std::time::Duration;
+
+use pinnacle_api_defs::pinnacle::{
+    input::{
+        self,
+        v1::{
+            AccelProfile, BindConflict, BindConflictPolicy, BindInfo, BindRequest, BindResponse,
+            ClearRemapsRequest, ClearRemapsResponse, ClickMethod, EdgeAction,
+            EdgeActionStreamRequest, EdgeActionStreamResponse, EnterBindLayerRequest,
+            GetBindInfosRequest, GetBindInfosResponse, GetBindLayerStackRequest,
+            GetBindLayerStackResponse, GetDeviceCapabilitiesRequest, GetDeviceCapabilitiesResponse,
+            GetDeviceInfoRequest, GetDeviceInfoResponse, GetDeviceTypeRequest,
+            GetDeviceTypeResponse, GetDevicesRequest, GetDevicesResponse, KeybindOnPressRequest,
+            KeybindStreamRequest, KeybindStreamResponse, MousebindOnPressRequest,
+            MousebindStreamRequest, MousebindStreamResponse, RemapRequest, RemapResponse,
+            ScrollMethod, SendEventsMode, SetBindLayerConflictPolicyRequest,
+            SetBindPropertiesRequest, SetDeviceLibinputSettingRequest, SetDeviceMapTargetRequest,
+            SetEdgeActionRequest, SetEdgeActionResponse, SetHoldToMoveRequest,
+            SetHoldToMoveResponse, SetPointerCrossingRequest, SetRepeatRateRequest,
+            SetSlowKeysRequest, SetSlowKeysResponse, SetStickyKeysRequest, SetStickyKeysResponse,
+            SetXcursorRequest, SetXkbConfigRequest, SetXkbKeymapRequest, SwitchXkbLayoutRequest,
+            TapButtonMap, remap_request, set_device_map_target_request::Target,
+            switch_xkb_layout_request::Action,
+        },
     },
+    output,
 };
 use smithay::reexports::input as libinput;
 use smithay::{
@@ -23,10 +35,17 @@ use tonic::{Request, Status};
 use tracing::{error, warn};
 
 use crate::{
-    api::{ResponseStream, TonicResult, run_server_streaming, run_unary, run_unary_no_response},
+    api::{
+        ResponseStream, TonicResult, namespace::client_pid, run_server_streaming, run_unary,
+        run_unary_no_response,
+    },
+    focus::pointer::ScreenEdge,
     input::{
-        bind::{Edge, ModMask},
+        PointerCrossing,
+        bind::{ConflictPolicy, Edge, ModMask},
+        hold_to_move::HoldToMoveConfig,
         libinput::device_type,
+        remap::{Remap, RemapTarget},
     },
     output::OutputName,
 };
@@ -37,8 +56,10 @@ use super::InputService;
 impl input::v1::input_service_server::InputService for InputService {
     type KeybindStreamStream = ResponseStream<KeybindStreamResponse>;
     type MousebindStreamStream = ResponseStream<MousebindStreamResponse>;
+    type EdgeActionStreamStream = ResponseStream<EdgeActionStreamResponse>;
 
     async fn bind(&self, request: Request<BindRequest>) -> TonicResult<BindResponse> {
+        let pid = client_pid(&request);
         let request = request.into_inner();
 
         let Some(bind) = request.bind else {
@@ -101,33 +122,18 @@ impl input::v1::input_service_server::InputService for InputService {
         };
 
         run_unary(&self.sender, move |state| {
-            let bind_id = match bind {
-                input::v1::bind::Bind::Key(keybind) => {
-                    let mut keysym = None;
-                    if let Some(xkb_name) = keybind.xkb_name {
-                        keysym = Some(if xkb_name.chars().count() == 1 {
-                            let Some(ch) = xkb_name.chars().next() else {
-                                unreachable!()
-                            };
-                            let keysym = xkbcommon::xkb::Keysym::from_char(ch);
-                            keysym
-                        } else {
-                            let keysym = xkbcommon::xkb::keysym_from_name(
-                                &xkb_name,
-                                xkbcommon::xkb::KEYSYM_NO_FLAGS,
-                            );
-                            keysym
-                        })
-                    }
-                    if let Some(key_code) = keybind.key_code {
-                        keysym = Some(xkbcommon::xkb::Keysym::new(key_code));
-                    }
+            let namespace = state.pinnacle.namespace_state.namespace_of(pid);
+            let bind_state = &mut state.pinnacle.input_state.bind_state;
+            let conflict_policy = bind_state.conflict_policy(&layer);
+            let layer_for_log = layer.clone();
 
-                    let Some(keysym) = keysym else {
+            let (bind_id, conflicts) = match bind {
+                input::v1::bind::Bind::Key(keybind) => {
+                    let Some(keysym) = keysym_from_api(&keybind) else {
                         return Err(Status::invalid_argument("no key was specified"));
                     };
 
-                    let bind_id = state.pinnacle.input_state.bind_state.keybinds.add_keybind(
+                    bind_state.keybinds.add_keybind(
                         keysym,
                         mods,
                         layer,
@@ -136,33 +142,49 @@ impl input::v1::input_service_server::InputService for InputService {
                         quit,
                         reload_config,
                         allow_when_locked,
-                    );
-
-                    bind_id
+                        conflict_policy,
+                        namespace,
+                    )
                 }
                 input::v1::bind::Bind::Mouse(mousebind) => {
                     let button = mousebind.button;
-                    let bind_id = state
-                        .pinnacle
-                        .input_state
-                        .bind_state
-                        .mousebinds
-                        .add_mousebind(
-                            button,
-                            mods,
-                            layer,
-                            group,
-                            desc,
-                            quit,
-                            reload_config,
-                            allow_when_locked,
-                        );
-
-                    bind_id
+                    bind_state.mousebinds.add_mousebind(
+                        button,
+                        mods,
+                        layer,
+                        group,
+                        desc,
+                        quit,
+                        reload_config,
+                        allow_when_locked,
+                        conflict_policy,
+                        namespace,
+                    )
                 }
             };
 
-            Ok(BindResponse { bind_id })
+            if conflict_policy == ConflictPolicy::Keep && !conflicts.is_empty() {
+                let ids = conflicts
+                    .iter()
+                    .map(|conflict| conflict.id)
+                    .collect::<Vec<_>>();
+                warn!(
+                    "Bind {bind_id} on layer {:?} conflicts with existing binds {ids:?}; \
+                    all of them will trigger",
+                    layer_for_log.as_deref().unwrap_or("default"),
+                );
+            }
+
+            let conflicts = conflicts
+                .into_iter()
+                .map(|conflict| BindConflict {
+                    bind_id: conflict.id,
+                    group: conflict.group,
+                    description: conflict.desc,
+                })
+                .collect();
+
+            Ok(BindResponse { bind_id, conflicts })
         })
         .await
     }
@@ -423,6 +445,32 @@ impl input::v1::input_service_server::InputService for InputService {
         .await
     }
 
+    async fn set_bind_layer_conflict_policy(
+        &self,
+        request: Request<SetBindLayerConflictPolicyRequest>,
+    ) -> TonicResult<()> {
+        let request = request.into_inner();
+
+        let policy = match request.policy() {
+            BindConflictPolicy::Unspecified => {
+                return Err(Status::invalid_argument("unspecified conflict policy"));
+            }
+            BindConflictPolicy::Keep => ConflictPolicy::Keep,
+            BindConflictPolicy::Override => ConflictPolicy::Override,
+            BindConflictPolicy::Shadow => ConflictPolicy::Shadow,
+        };
+
+        run_unary_no_response(&self.sender, move |state| {
+            state
+                .pinnacle
+                .input_state
+                .bind_state
+                .conflict_policies
+                .insert(request.layer_name, policy);
+        })
+        .await
+    }
+
     async fn keybind_stream(
         &self,
         request: Request<KeybindStreamRequest>,
@@ -797,6 +845,210 @@ impl input::v1::input_service_server::InputService for InputService {
         .await
     }
 
+    async fn set_sticky_keys(
+        &self,
+        request: Request<SetStickyKeysRequest>,
+    ) -> TonicResult<SetStickyKeysResponse> {
+        let enabled = request.into_inner().enabled;
+
+        run_unary(&self.sender, move |state| {
+            state.set_sticky_keys(enabled);
+            Ok(SetStickyKeysResponse {})
+        })
+        .await
+    }
+
+    async fn set_slow_keys(
+        &self,
+        request: Request<SetSlowKeysRequest>,
+    ) -> TonicResult<SetSlowKeysResponse> {
+        let delay = request
+            .into_inner()
+            .delay_millis
+            .map(|millis| Duration::from_millis(millis.into()));
+
+        run_unary(&self.sender, move |state| {
+            state.set_slow_keys(delay);
+            Ok(SetSlowKeysResponse {})
+        })
+        .await
+    }
+
+    async fn set_hold_to_move(
+        &self,
+        request: Request<SetHoldToMoveRequest>,
+    ) -> TonicResult<SetHoldToMoveResponse> {
+        let request = request.into_inner();
+
+        let mut config = HoldToMoveConfig {
+            touch: request.touch,
+            button: request.button,
+            ..Default::default()
+        };
+        if let Some(millis) = request.hold_millis {
+            config.hold_time = Duration::from_millis(millis.into());
+        }
+
+        run_unary(&self.sender, move |state| {
+            state.pinnacle.input_state.hold_to_move.config = config;
+            Ok(SetHoldToMoveResponse {})
+        })
+        .await
+    }
+
+    async fn set_pointer_crossing(
+        &self,
+        request: Request<SetPointerCrossingRequest>,
+    ) -> TonicResult<()> {
+        let crossing = match request.into_inner().crossing() {
+            input::v1::PointerCrossing::Unspecified => {
+                return Err(Status::invalid_argument("unspecified pointer crossing"));
+            }
+            input::v1::PointerCrossing::Logical => PointerCrossing::Logical,
+            input::v1::PointerCrossing::Physical => PointerCrossing::Physical,
+        };
+
+        run_unary_no_response(&self.sender, move |state| {
+            state.pinnacle.input_state.pointer_crossing = crossing;
+        })
+        .await
+    }
+
+    async fn set_edge_action(
+        &self,
+        request: Request<SetEdgeActionRequest>,
+    ) -> TonicResult<SetEdgeActionResponse> {
+        let request = request.into_inner();
+
+        let edge = match request.edge() {
+            output::v1::ScreenEdge::Unspecified => {
+                return Err(Status::invalid_argument("no edge was specified"));
+            }
+            output::v1::ScreenEdge::Top => ScreenEdge::Top,
+            output::v1::ScreenEdge::Bottom => ScreenEdge::Bottom,
+            output::v1::ScreenEdge::Left => ScreenEdge::Left,
+            output::v1::ScreenEdge::Right => ScreenEdge::Right,
+            output::v1::ScreenEdge::TopLeft => ScreenEdge::TopLeft,
+            output::v1::ScreenEdge::TopRight => ScreenEdge::TopRight,
+            output::v1::ScreenEdge::BottomLeft => ScreenEdge::BottomLeft,
+            output::v1::ScreenEdge::BottomRight => ScreenEdge::BottomRight,
+        };
+        let action = request.action();
+        let output_name = request.output_name;
+
+        run_unary(&self.sender, move |state| {
+            let edge_actions = &mut state.pinnacle.input_state.edge_actions;
+
+            let action_id = match action {
+                EdgeAction::Unspecified => {
+                    return Err(Status::invalid_argument("no edge action was specified"));
+                }
+                EdgeAction::None => {
+                    edge_actions.set_none(edge, output_name);
+                    None
+                }
+                EdgeAction::Callback => Some(edge_actions.set_callback(edge, output_name)),
+                EdgeAction::SnapWindow => {
+                    edge_actions.set_snap_window(edge, output_name);
+                    None
+                }
+            };
+
+            Ok(SetEdgeActionResponse { action_id })
+        })
+        .await
+    }
+
+    async fn edge_action_stream(
+        &self,
+        request: Request<EdgeActionStreamRequest>,
+    ) -> TonicResult<Self::EdgeActionStreamStream> {
+        let action_id = request.into_inner().action_id;
+
+        run_server_streaming(&self.sender, move |state, sender| {
+            let Some(mut recv) = state
+                .pinnacle
+                .input_state
+                .edge_actions
+                .take_callback_recv(action_id)
+            else {
+                return Err(Status::not_found(format!(
+                    "edge action {action_id} was not found or already has a stream set up"
+                )));
+            };
+
+            tokio::spawn(async move {
+                while let Some(output_name) = recv.recv().await {
+                    if sender
+                        .send(Ok(EdgeActionStreamResponse { output_name }))
+                        .is_err()
+                    {
+                        break;
+                    }
+                    tokio::task::yield_now().await;
+                }
+            });
+
+            Ok(())
+        })
+        .await
+    }
+
+    async fn remap(&self, request: Request<RemapRequest>) -> TonicResult<RemapResponse> {
+        let request = request.into_inner();
+
+        let from = request
+            .from
+            .as_ref()
+            .and_then(keysym_from_api)
+            .ok_or_else(|| Status::invalid_argument("no key to remap was specified"))?;
+
+        let to = match request.to {
+            Some(remap_request::To::Key(key)) => RemapTarget::Key(
+                keysym_from_api(&key)
+                    .ok_or_else(|| Status::invalid_argument("no key to remap to was specified"))?,
+            ),
+            Some(remap_request::To::KeyMacro(key_macro)) => RemapTarget::Macro(
+                key_macro
+                    .keys
+                    .iter()
+                    .map(keysym_from_api)
+                    .collect::<Option<Vec<_>>>()
+                    .ok_or_else(|| Status::invalid_argument("a macro key was not specified"))?,
+            ),
+            None => return Err(Status::invalid_argument("no remap target was specified")),
+        };
+
+        let device_sysname = request.device_sysname;
+
+        run_unary(&self.sender, move |state| {
+            state.pinnacle.input_state.remap_state.add(Remap {
+                from,
+                to,
+                device_sysname,
+            });
+            Ok(RemapResponse {})
+        })
+        .await
+    }
+
+    async fn clear_remaps(
+        &self,
+        request: Request<ClearRemapsRequest>,
+    ) -> TonicResult<ClearRemapsResponse> {
+        let device_sysname = request.into_inner().device_sysname;
+
+        run_unary(&self.sender, move |state| {
+            state
+                .pinnacle
+                .input_state
+                .remap_state
+                .clear(device_sysname.as_deref());
+            Ok(ClearRemapsResponse {})
+        })
+        .await
+    }
+
     async fn set_xcursor(&self, request: Request<SetXcursorRequest>) -> TonicResult<()> {
         let request = request.into_inner();
 
@@ -812,6 +1064,8 @@ impl input::v1::input_service_server::InputService for InputService {
                 state.pinnacle.cursor_state.set_size(size);
             }
 
+            state.pinnacle.update_xwayland_cursor();
+
             if let Some(output) = state.pinnacle.focused_output().cloned() {
                 state.schedule_render(&output)
             }
@@ -990,3 +1244,19 @@ impl input::v1::input_service_server::InputService for InputService {
         .await
     }
 }
+
+/// Converts an API key into a keysym.
+///
+/// If both the key code and the name are specified, the key code is used.
+fn keysym_from_api(key: &input::v1::Keybind) -> Option<xkbcommon::xkb::Keysym> {
+    if let Some(key_code) = key.key_code {
+        return Some(xkbcommon::xkb::Keysym::new(key_code));
+    }
+
+    let xkb_name = key.xkb_name.as_ref()?;
+    let mut chars = xkb_name.chars();
+    Some(match (chars.next(), chars.next()) {
+        (Some(ch), None) => xkbcommon::xkb::Keysym::from_char(ch),
+        _ => xkbcommon::xkb::keysym_from_name(xkb_name, xkbcommon::xkb::KEYSYM_NO_FLAGS),
+    })
+}
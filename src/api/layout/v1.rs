@@ -2,18 +2,20 @@ use pinnacle_api_defs::pinnacle::layout::{
     self,
     v1::{LayoutRequest, LayoutResponse},
 };
-use tokio::sync::mpsc::unbounded_channel;
-use tonic::{Request, Streaming};
+use tokio::sync::mpsc::{UnboundedSender, unbounded_channel};
+use tonic::{Request, Status, Streaming};
 
 use crate::{
-    api::{ResponseStream, TonicResult, run_bidirectional_streaming},
+    api::{ResponseStream, Sender, TonicResult, run_bidirectional_streaming},
     layout::LayoutInfo,
     output::OutputName,
+    state::State,
 };
 
 #[tonic::async_trait]
 impl layout::v1::layout_service_server::LayoutService for super::LayoutService {
     type LayoutStream = ResponseStream<LayoutResponse>;
+    type RegisterGeneratorStream = ResponseStream<LayoutResponse>;
 
     async fn layout(
         &self,
@@ -24,62 +26,9 @@ impl layout::v1::layout_service_server::LayoutService for super::LayoutService {
         run_bidirectional_streaming(
             self.sender.clone(),
             in_stream,
-            |state, request| {
-                let Some(request) = request.request else {
-                    return;
-                };
-
-                match request {
-                    layout::v1::layout_request::Request::TreeResponse(tree_response) => {
-                        let root_node = tree_response.root_node.unwrap(); // TODO: unwrap
-                        let tree_id = tree_response.tree_id;
-
-                        let root_node = match crate::layout::tree::LayoutNode::try_from(root_node) {
-                            Ok(root_node) => root_node,
-                            Err(()) => {
-                                tracing::debug!("failed to create layout tree");
-                                return;
-                            }
-                        };
-
-                        if let Err(err) = state.apply_layout_tree(
-                            tree_id,
-                            root_node,
-                            tree_response.request_id,
-                            tree_response.output_name,
-                        ) {
-                            tracing::debug!("{err}")
-                        }
-                    }
-                    layout::v1::layout_request::Request::ForceLayout(force_layout) => {
-                        let output_name = force_layout.output_name;
-                        if let Some(output) = OutputName(output_name)
-                            .output(&state.pinnacle)
-                            .or_else(|| state.pinnacle.focused_output().cloned())
-                        {
-                            state.pinnacle.request_layout(&output);
-                        }
-                    }
-                }
-            },
+            handle_layout_request,
             |state, sender, _join_handle| {
-                let (send, mut recv) = unbounded_channel::<LayoutInfo>();
-                tokio::spawn(async move {
-                    while let Some(info) = recv.recv().await {
-                        if sender
-                            .send(Ok(LayoutResponse {
-                                request_id: info.request_id.to_inner(),
-                                output_name: info.output_name.0,
-                                window_count: info.window_count,
-                                tag_ids: info.tag_ids.into_iter().map(|id| id.to_inner()).collect(),
-                            }))
-                            .await
-                            .is_err()
-                        {
-                            break;
-                        }
-                    }
-                });
+                let send = forward_layout_requests(sender);
                 state
                     .pinnacle
                     .layout_state
@@ -88,6 +37,115 @@ impl layout::v1::layout_service_server::LayoutService for super::LayoutService {
             },
         )
     }
+
+    async fn register_generator(
+        &self,
+        request: Request<Streaming<LayoutRequest>>,
+    ) -> TonicResult<Self::RegisterGeneratorStream> {
+        let in_stream = request.into_inner();
+        let fn_sender = self.sender.clone();
+
+        run_bidirectional_streaming(
+            self.sender.clone(),
+            in_stream,
+            handle_layout_request,
+            move |state, sender, join_handle| {
+                let send = forward_layout_requests(sender);
+                state.pinnacle.layout_state.generators.push(send.clone());
+                request_layout_on_all_outputs(state);
+
+                // Hand layouts back to whoever was generating them before once the
+                // generator disconnects.
+                tokio::spawn(async move {
+                    let _ = join_handle.await;
+                    let _ = fn_sender.send(Box::new(move |state| {
+                        state
+                            .pinnacle
+                            .layout_state
+                            .generators
+                            .retain(|generator| !generator.same_channel(&send));
+                        request_layout_on_all_outputs(state);
+                    }));
+                });
+            },
+        )
+    }
+}
+
+fn handle_layout_request(state: &mut State, request: LayoutRequest) {
+    let Some(request) = request.request else {
+        return;
+    };
+
+    match request {
+        layout::v1::layout_request::Request::TreeResponse(tree_response) => {
+            // Generators registered by other processes can send anything
+            let Some(root_node) = tree_response.root_node else {
+                tracing::warn!(
+                    "Ignoring layout response for output `{}` without a root node",
+                    tree_response.output_name
+                );
+                return;
+            };
+            let tree_id = tree_response.tree_id;
+
+            let root_node = match crate::layout::tree::LayoutNode::try_from(root_node) {
+                Ok(root_node) => root_node,
+                Err(()) => {
+                    tracing::debug!("failed to create layout tree");
+                    return;
+                }
+            };
+
+            if let Err(err) = state.apply_layout_tree(
+                tree_id,
+                root_node,
+                tree_response.request_id,
+                tree_response.output_name,
+            ) {
+                tracing::debug!("{err}")
+            }
+        }
+        layout::v1::layout_request::Request::ForceLayout(force_layout) => {
+            let output_name = force_layout.output_name;
+            if let Some(output) = OutputName(output_name)
+                .output(&state.pinnacle)
+                .or_else(|| state.pinnacle.focused_output().cloned())
+            {
+                state.pinnacle.request_layout(&output);
+            }
+        }
+    }
+}
+
+/// Spawns a task that forwards layout requests to a layout client.
+fn forward_layout_requests(
+    sender: Sender<Result<LayoutResponse, Status>>,
+) -> UnboundedSender<LayoutInfo> {
+    let (send, mut recv) = unbounded_channel::<LayoutInfo>();
+    tokio::spawn(async move {
+        while let Some(info) = recv.recv().await {
+            if sender
+                .send(Ok(LayoutResponse {
+                    request_id: info.request_id.to_inner(),
+                    output_name: info.output_name.0,
+                    window_count: info.window_count,
+                    tag_ids: info.tag_ids.into_iter().map(|id| id.to_inner()).collect(),
+                }))
+                .await
+                .is_err()
+            {
+                break;
+            }
+        }
+    });
+    send
+}
+
+fn request_layout_on_all_outputs(state: &mut State) {
+    for output in state.pinnacle.space.outputs().cloned().collect::<Vec<_>>() {
+        state.pinnacle.request_layout(&output);
+    }
 }
 
 impl TryFrom<layout::v1::LayoutNode> for crate::layout::tree::LayoutNode {
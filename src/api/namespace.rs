@@ -0,0 +1,79 @@
+//! Namespaces for API clients.
+//!
+//! Helper processes like bars can use the API alongside the config. A client that registers
+//! a namespace owns the binds, signal subscriptions, and window rules it sets up afterwards.
+//! These survive config reloads and are removed as soon as the client disconnects,
+//! so a crashing helper doesn't leave anything behind.
+//!
+//! Clients are told apart by the process id of their connection.
+
+use std::collections::HashMap;
+
+use tonic::{Request, transport::server::UdsConnectInfo};
+use tracing::info;
+
+use crate::state::State;
+
+#[derive(Debug, Default)]
+pub struct NamespaceState {
+    /// Namespaces by the process id of the client that registered them.
+    namespaces: HashMap<i32, String>,
+}
+
+impl NamespaceState {
+    /// Registers `namespace` for the client with the given process id.
+    pub fn register(&mut self, pid: i32, namespace: String) -> Result<(), String> {
+        if let Some(existing) = self.namespaces.get(&pid) {
+            return Err(format!("client already registered namespace {existing}"));
+        }
+
+        if self
+            .namespaces
+            .values()
+            .any(|existing| *existing == namespace)
+        {
+            return Err(format!("namespace {namespace} is already registered"));
+        }
+
+        self.namespaces.insert(pid, namespace);
+
+        Ok(())
+    }
+
+    /// Returns the namespace of the client with the given process id, if it registered one.
+    pub fn namespace_of(&self, pid: Option<i32>) -> Option<String> {
+        self.namespaces.get(&pid?).cloned()
+    }
+}
+
+/// Returns the process id of the client that sent `request`.
+pub fn client_pid<T>(request: &Request<T>) -> Option<i32> {
+    request
+        .extensions()
+        .get::<UdsConnectInfo>()?
+        .peer_cred?
+        .pid()
+}
+
+impl State {
+    /// Removes everything the client that registered `namespace` set up.
+    ///
+    /// Signal subscriptions end along with the client's streams, so they aren't touched here.
+    pub fn revoke_namespace(&mut self, namespace: &str) {
+        info!("Revoking namespace {namespace}");
+
+        self.pinnacle
+            .namespace_state
+            .namespaces
+            .retain(|_, existing| existing != namespace);
+
+        self.pinnacle
+            .input_state
+            .bind_state
+            .clear_namespace(Some(namespace));
+
+        // Windows may be waiting on rules from this client
+        self.pinnacle.window_rule_state.remove_namespace(namespace);
+        self.pinnacle.apply_finished_window_rules();
+    }
+}
@@ -0,0 +1,13 @@
+mod v1;
+
+use super::StateFnSender;
+
+pub struct NightlightService {
+    sender: StateFnSender,
+}
+
+impl NightlightService {
+    pub fn new(sender: StateFnSender) -> Self {
+        Self { sender }
+    }
+}
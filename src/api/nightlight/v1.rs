@@ -0,0 +1,83 @@
+use std::time::Duration;
+
+use pinnacle_api_defs::pinnacle::nightlight::{
+    self,
+    v1::{SetScheduleRequest, SetScheduleResponse, SunEvent, schedule_time},
+};
+use tonic::{Request, Status};
+
+use crate::{
+    api::{TonicResult, run_unary},
+    nightlight::{Location, NEUTRAL_TEMPERATURE, NightlightSchedule, ScheduleTime},
+};
+
+#[tonic::async_trait]
+impl nightlight::v1::nightlight_service_server::NightlightService for super::NightlightService {
+    async fn set_schedule(
+        &self,
+        request: Request<SetScheduleRequest>,
+    ) -> TonicResult<SetScheduleResponse> {
+        let request = request.into_inner();
+
+        let schedule = request
+            .schedule
+            .map(|schedule| {
+                if !(1000..=NEUTRAL_TEMPERATURE).contains(&schedule.temperature) {
+                    return Err(Status::invalid_argument(format!(
+                        "temperature must be between 1000 and {NEUTRAL_TEMPERATURE}"
+                    )));
+                }
+
+                let location = schedule
+                    .location
+                    .map(|location| {
+                        if !(-90.0..=90.0).contains(&location.latitude)
+                            || !(-180.0..=180.0).contains(&location.longitude)
+                        {
+                            return Err(Status::invalid_argument("location is out of range"));
+                        }
+
+                        Ok(Location {
+                            latitude: location.latitude,
+                            longitude: location.longitude,
+                        })
+                    })
+                    .transpose()?;
+
+                Ok(NightlightSchedule {
+                    start: parse_schedule_time(schedule.start.and_then(|start| start.time))?,
+                    end: parse_schedule_time(schedule.end.and_then(|end| end.time))?,
+                    temperature: schedule.temperature,
+                    transition: Duration::from_millis(schedule.transition_millis.into()),
+                    location,
+                })
+            })
+            .transpose()?;
+
+        run_unary(&self.sender, move |state| {
+            state.set_nightlight_schedule(schedule);
+
+            Ok(SetScheduleResponse {})
+        })
+        .await
+    }
+}
+
+fn parse_schedule_time(time: Option<schedule_time::Time>) -> Result<ScheduleTime, Status> {
+    match time {
+        Some(schedule_time::Time::Minutes(minutes)) => {
+            if minutes >= 24 * 60 {
+                return Err(Status::invalid_argument("time of day is past midnight"));
+            }
+            Ok(ScheduleTime::Clock(minutes))
+        }
+        Some(schedule_time::Time::SunEvent(sun_event)) => match SunEvent::try_from(sun_event) {
+            Ok(SunEvent::Sunrise) => Ok(ScheduleTime::Sunrise),
+            Ok(SunEvent::Sunset) => Ok(ScheduleTime::Sunset),
+            Ok(SunEvent::Unspecified) | Err(_) => {
+                Err(Status::invalid_argument("sun event was unspecified"))
+            }
+        },
+        None => Err(Status::invalid_argument("schedule time was unspecified")),
+    }
+}
@@ -1,4 +1,17 @@
-use super::StateFnSender;
+use pinnacle_api_defs::pinnacle::{
+    output::v1::{ClearableProperty, WatchPropertiesResponse},
+    util,
+};
+use smithay::output::Output;
+use tokio::sync::mpsc::UnboundedSender;
+use tonic::Status;
+
+use crate::{
+    output::OutputName,
+    state::{Pinnacle, WithState},
+};
+
+use super::{StateFnSender, changed_property, cleared_property};
 
 mod v1;
 
@@ -11,3 +24,110 @@ impl OutputService {
         Self { sender }
     }
 }
+
+/// Clients watching output properties.
+#[derive(Debug, Default)]
+pub struct OutputWatchState {
+    watchers: Vec<OutputWatcher>,
+}
+
+#[derive(Debug)]
+struct OutputWatcher {
+    output_name: OutputName,
+    sender: UnboundedSender<Result<WatchPropertiesResponse, Status>>,
+    last_sent: Option<WatchPropertiesResponse>,
+}
+
+impl OutputWatchState {
+    pub fn subscribe(
+        &mut self,
+        output_name: OutputName,
+        sender: UnboundedSender<Result<WatchPropertiesResponse, Status>>,
+    ) {
+        self.watchers.push(OutputWatcher {
+            output_name,
+            sender,
+            last_sent: None,
+        });
+    }
+}
+
+impl Pinnacle {
+    fn output_properties(&self, output: &Output) -> WatchPropertiesResponse {
+        let geo = self.space.output_geometry(output);
+
+        WatchPropertiesResponse {
+            loc: geo.map(|geo| util::v1::Point {
+                x: geo.loc.x,
+                y: geo.loc.y,
+            }),
+            logical_size: geo.map(|geo| util::v1::Size {
+                width: geo.size.w.try_into().unwrap_or_default(),
+                height: geo.size.h.try_into().unwrap_or_default(),
+            }),
+            scale: Some(output.current_scale().fractional_scale() as f32),
+            focused: Some(self.focused_output() == Some(output)),
+            enabled: Some(output.with_state(|state| state.enabled_global_id.is_some())),
+            powered: Some(output.with_state(|state| state.powered)),
+            cleared: Vec::new(),
+        }
+    }
+
+    /// Sends changed output properties to watching clients.
+    ///
+    /// Watchers of outputs that were disconnected are dropped, which ends their streams.
+    pub fn refresh_output_watchers(&mut self) {
+        let _span = tracy_client::span!("Pinnacle::refresh_output_watchers");
+
+        let mut watchers = std::mem::take(&mut self.output_watch_state.watchers);
+
+        watchers.retain_mut(|watcher| {
+            if watcher.sender.is_closed() {
+                return false;
+            }
+
+            let Some(output) = watcher.output_name.output(self) else {
+                return false;
+            };
+
+            let properties = self.output_properties(&output);
+
+            let response = match watcher.last_sent.as_ref() {
+                Some(last_sent) => WatchPropertiesResponse {
+                    loc: changed_property(&last_sent.loc, &properties.loc),
+                    logical_size: changed_property(
+                        &last_sent.logical_size,
+                        &properties.logical_size,
+                    ),
+                    scale: changed_property(&last_sent.scale, &properties.scale),
+                    focused: changed_property(&last_sent.focused, &properties.focused),
+                    enabled: changed_property(&last_sent.enabled, &properties.enabled),
+                    powered: changed_property(&last_sent.powered, &properties.powered),
+                    cleared: [
+                        cleared_property(&last_sent.loc, &properties.loc, ClearableProperty::Loc),
+                        cleared_property(
+                            &last_sent.logical_size,
+                            &properties.logical_size,
+                            ClearableProperty::LogicalSize,
+                        ),
+                    ]
+                    .into_iter()
+                    .flatten()
+                    .map(i32::from)
+                    .collect(),
+                },
+                None => properties.clone(),
+            };
+
+            if response != WatchPropertiesResponse::default() {
+                let _ = watcher.sender.send(Ok(response));
+            }
+
+            watcher.last_sent = Some(properties);
+
+            true
+        });
+
+        self.output_watch_state.watchers = watchers;
+    }
+}
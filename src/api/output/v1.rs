@@ -2,16 +2,24 @@ use pinnacle_api_defs::pinnacle::{
     output::{
         self,
         v1::{
-            FocusRequest, FocusResponse, GetEnabledRequest, GetEnabledResponse,
-            GetFocusStackWindowIdsRequest, GetFocusStackWindowIdsResponse, GetFocusedRequest,
-            GetFocusedResponse, GetInfoRequest, GetInfoResponse, GetLocRequest, GetLocResponse,
+            Chromaticity, ColorPrimaries, ConfirmModeChangeRequest, ConfirmModeChangeResponse,
+            Edid, FocusRequest, FocusResponse, GetColorDepthRequest, GetColorDepthResponse,
+            GetEnabledRequest, GetEnabledResponse, GetFocusStackWindowIdsRequest,
+            GetFocusStackWindowIdsResponse, GetFocusedRequest, GetFocusedResponse, GetHdrRequest,
+            GetHdrResponse, GetInfoRequest, GetInfoResponse, GetLocRequest, GetLocResponse,
             GetLogicalSizeRequest, GetLogicalSizeResponse, GetModesRequest, GetModesResponse,
             GetOutputsInDirRequest, GetOutputsInDirResponse, GetPhysicalSizeRequest,
-            GetPhysicalSizeResponse, GetPoweredRequest, GetPoweredResponse, GetRequest,
-            GetResponse, GetScaleRequest, GetScaleResponse, GetTagIdsRequest, GetTagIdsResponse,
-            GetTransformRequest, GetTransformResponse, SetLocRequest, SetModeRequest,
-            SetModelineRequest, SetPoweredRequest, SetScaleRequest, SetTransformRequest,
-            SetVrrRequest, SetVrrResponse,
+            GetPhysicalSizeResponse, GetPoweredRequest, GetPoweredResponse, GetRenderStatsRequest,
+            GetRenderStatsResponse, GetRequest, GetResponse, GetScaleRequest, GetScaleResponse,
+            GetSrgbEmulationRequest, GetSrgbEmulationResponse, GetStackingOrderRequest,
+            GetStackingOrderResponse, GetTagIdsRequest, GetTagIdsResponse, GetTransformRequest,
+            GetTransformResponse, HdrMetadata, SetAutoRotateRequest, SetAutoRotateResponse,
+            SetColorDepthRequest, SetColorDepthResponse, SetColorFilterRequest,
+            SetColorFilterResponse, SetHdrRequest, SetHdrResponse, SetHotplugSettleDelayRequest,
+            SetHotplugSettleDelayResponse, SetLocRequest, SetModeRequest, SetModelineRequest,
+            SetPoweredRequest, SetScaleRequest, SetSrgbEmulationRequest, SetSrgbEmulationResponse,
+            SetTransformRequest, SetVrrRequest, SetVrrResponse, SetXcursorRequest,
+            SetXcursorResponse, WatchPropertiesRequest, WatchPropertiesResponse,
         },
     },
     util::{
@@ -20,20 +28,24 @@ use pinnacle_api_defs::pinnacle::{
     },
 };
 use smithay::output::Scale;
+use std::time::Duration;
 use tonic::{Request, Status};
 use tracing::debug;
 
 use crate::{
-    api::{TonicResult, run_unary, run_unary_no_response},
-    backend::udev::drm_mode_from_modeinfo,
+    api::{ResponseStream, TonicResult, run_server_streaming, run_unary, run_unary_no_response},
+    backend::{BackendData, udev::drm_mode_from_modeinfo},
     config::ConnectorSavedState,
-    output::{OutputMode, OutputName},
+    output::{ColorDepth, OutputMode, OutputName, edid::EdidInfo},
+    render::util::effect::ColorFilter,
     state::{State, WithState},
     util::rect::Direction,
 };
 
 #[tonic::async_trait]
 impl output::v1::output_service_server::OutputService for super::OutputService {
+    type WatchPropertiesStream = ResponseStream<WatchPropertiesResponse>;
+
     async fn get(&self, _request: Request<GetRequest>) -> TonicResult<GetResponse> {
         run_unary(&self.sender, move |state| {
             let output_names = state
@@ -130,19 +142,10 @@ impl output::v1::output_service_server::OutputService for super::OutputService {
                 return Ok(());
             };
 
-            state.pinnacle.change_output_state(
-                &mut state.backend,
-                &output,
-                Some(OutputMode::Smithay(mode)),
-                None,
-                None,
-                None,
-            );
-            state.pinnacle.request_layout(&output);
-            state
-                .pinnacle
-                .output_management_manager_state
-                .update::<State>();
+            let revert_after = request
+                .revert_after_ms
+                .map(|ms| Duration::from_millis(ms as u64));
+            state.set_output_mode(&output, OutputMode::Smithay(mode), revert_after);
 
             Ok(())
         })
@@ -152,6 +155,9 @@ impl output::v1::output_service_server::OutputService for super::OutputService {
     async fn set_modeline(&self, request: Request<SetModelineRequest>) -> TonicResult<()> {
         let request = request.into_inner();
         let output_name = OutputName(request.output_name);
+        let revert_after = request
+            .revert_after_ms
+            .map(|ms| Duration::from_millis(ms as u64));
 
         let Some(output::v1::Modeline {
             clock,
@@ -189,19 +195,23 @@ impl output::v1::output_service_server::OutputService for super::OutputService {
                 vsync,
             );
 
-            state.pinnacle.change_output_state(
-                &mut state.backend,
-                &output,
-                Some(OutputMode::Drm(mode)),
-                None,
-                None,
-                None,
-            );
-            state.pinnacle.request_layout(&output);
-            state
-                .pinnacle
-                .output_management_manager_state
-                .update::<State>();
+            state.set_output_mode(&output, OutputMode::Drm(mode), revert_after);
+        })
+        .await
+    }
+
+    async fn confirm_mode_change(
+        &self,
+        request: Request<ConfirmModeChangeRequest>,
+    ) -> TonicResult<ConfirmModeChangeResponse> {
+        let output_name = OutputName(request.into_inner().output_name);
+
+        run_unary(&self.sender, move |state| {
+            let confirmed = output_name
+                .output(&state.pinnacle)
+                .is_some_and(|output| state.confirm_mode_change(&output));
+
+            Ok(ConfirmModeChangeResponse { confirmed })
         })
         .await
     }
@@ -354,6 +364,197 @@ impl output::v1::output_service_server::OutputService for super::OutputService {
         .await
     }
 
+    async fn set_xcursor(
+        &self,
+        request: Request<SetXcursorRequest>,
+    ) -> TonicResult<SetXcursorResponse> {
+        let request = request.into_inner();
+        let output_name = OutputName(request.output_name);
+        let theme = request.theme;
+        let size = request.size;
+
+        run_unary(&self.sender, move |state| {
+            let Some(output) = output_name.output(&state.pinnacle) else {
+                return Ok(SetXcursorResponse {});
+            };
+
+            let mut cursor_override = state.pinnacle.cursor_state.output_override(&output);
+
+            if let Some(theme) = theme {
+                cursor_override.theme = (!theme.is_empty()).then_some(theme);
+            }
+
+            if let Some(size) = size {
+                cursor_override.size = (size != 0).then_some(size);
+            }
+
+            state
+                .pinnacle
+                .cursor_state
+                .set_output_override(&output, cursor_override);
+
+            state.schedule_render(&output);
+
+            Ok(SetXcursorResponse {})
+        })
+        .await
+    }
+
+    async fn set_color_filter(
+        &self,
+        request: Request<SetColorFilterRequest>,
+    ) -> TonicResult<SetColorFilterResponse> {
+        let request = request.into_inner();
+
+        let filter = match request.filter() {
+            output::v1::ColorFilter::Unspecified => {
+                return Err(Status::invalid_argument("unspecified filter"));
+            }
+            output::v1::ColorFilter::None => None,
+            output::v1::ColorFilter::Invert => Some(ColorFilter::Invert),
+            output::v1::ColorFilter::Grayscale => Some(ColorFilter::Grayscale),
+            output::v1::ColorFilter::DeuteranopiaCorrection => {
+                Some(ColorFilter::DeuteranopiaCorrection)
+            }
+            output::v1::ColorFilter::ProtanopiaCorrection => {
+                Some(ColorFilter::ProtanopiaCorrection)
+            }
+        };
+
+        let output_name = OutputName(request.output_name);
+
+        run_unary(&self.sender, move |state| {
+            let Some(output) = output_name.output(&state.pinnacle) else {
+                return Ok(SetColorFilterResponse {});
+            };
+
+            output.with_state_mut(|state| state.color_filter = filter);
+
+            state.backend.reset_buffers(&output);
+            state.schedule_render(&output);
+
+            Ok(SetColorFilterResponse {})
+        })
+        .await
+    }
+
+    async fn set_auto_rotate(
+        &self,
+        request: Request<SetAutoRotateRequest>,
+    ) -> TonicResult<SetAutoRotateResponse> {
+        let request = request.into_inner();
+
+        let output_name = OutputName(request.output_name);
+        let auto_rotate = request.auto_rotate;
+
+        run_unary(&self.sender, move |state| {
+            let Some(output) = output_name.output(&state.pinnacle) else {
+                return Ok(SetAutoRotateResponse {});
+            };
+
+            output.with_state_mut(|state| state.auto_rotate = auto_rotate);
+
+            if auto_rotate {
+                state.pinnacle.start_auto_rotate();
+                state.auto_rotate_output(&output);
+            }
+
+            Ok(SetAutoRotateResponse {})
+        })
+        .await
+    }
+
+    async fn set_hdr(&self, request: Request<SetHdrRequest>) -> TonicResult<SetHdrResponse> {
+        let request = request.into_inner();
+
+        let output_name = OutputName(request.output_name);
+        let hdr = request.hdr;
+
+        run_unary(&self.sender, move |state| {
+            let Some(output) = output_name.output(&state.pinnacle) else {
+                return Ok(SetHdrResponse {
+                    error: Some(format!("no output named {}", output_name.0)),
+                });
+            };
+
+            let error = state.set_output_hdr(&output, hdr).err();
+
+            Ok(SetHdrResponse { error })
+        })
+        .await
+    }
+
+    async fn set_color_depth(
+        &self,
+        request: Request<SetColorDepthRequest>,
+    ) -> TonicResult<SetColorDepthResponse> {
+        let request = request.into_inner();
+
+        let output_name = OutputName(request.output_name);
+        let bits = request.color_depth;
+
+        run_unary(&self.sender, move |state| {
+            let Some(color_depth) = ColorDepth::from_bits(bits) else {
+                return Ok(SetColorDepthResponse {
+                    error: Some(format!("unsupported color depth of {bits} bits")),
+                });
+            };
+
+            let Some(output) = output_name.output(&state.pinnacle) else {
+                return Ok(SetColorDepthResponse {
+                    error: Some(format!("no output named {}", output_name.0)),
+                });
+            };
+
+            let error = state
+                .backend
+                .set_output_color_depth(&mut state.pinnacle, &output, color_depth)
+                .map_err(|err| format!("{err:#}"))
+                .err();
+
+            Ok(SetColorDepthResponse { error })
+        })
+        .await
+    }
+
+    async fn set_srgb_emulation(
+        &self,
+        request: Request<SetSrgbEmulationRequest>,
+    ) -> TonicResult<SetSrgbEmulationResponse> {
+        let request = request.into_inner();
+
+        let output_name = OutputName(request.output_name);
+        let srgb_emulation = request.srgb_emulation;
+
+        run_unary(&self.sender, move |state| {
+            let Some(output) = output_name.output(&state.pinnacle) else {
+                return Ok(SetSrgbEmulationResponse {
+                    error: Some(format!("no output named {}", output_name.0)),
+                });
+            };
+
+            let error = state
+                .set_output_srgb_emulation(&output, srgb_emulation)
+                .err();
+
+            Ok(SetSrgbEmulationResponse { error })
+        })
+        .await
+    }
+
+    async fn set_hotplug_settle_delay(
+        &self,
+        request: Request<SetHotplugSettleDelayRequest>,
+    ) -> TonicResult<SetHotplugSettleDelayResponse> {
+        let delay = Duration::from_millis(request.into_inner().millis.into());
+
+        run_unary(&self.sender, move |state| {
+            state.pinnacle.config.hotplug_settle_delay = delay;
+            Ok(SetHotplugSettleDelayResponse {})
+        })
+        .await
+    }
+
     async fn focus(&self, request: Request<FocusRequest>) -> TonicResult<FocusResponse> {
         let request = request.into_inner();
 
@@ -389,11 +590,15 @@ impl output::v1::output_service_server::OutputService for super::OutputService {
                 .as_ref()
                 .map(|op| op.physical_properties().serial_number)
                 .unwrap_or_default();
+            let edid = output
+                .and_then(|op| op.with_state(|state| state.edid))
+                .map(edid_to_api);
 
             Ok(GetInfoResponse {
                 make,
                 model,
                 serial,
+                edid,
             })
         })
         .await
@@ -632,6 +837,75 @@ impl output::v1::output_service_server::OutputService for super::OutputService {
         .await
     }
 
+    async fn get_hdr(&self, request: Request<GetHdrRequest>) -> TonicResult<GetHdrResponse> {
+        let output_name = OutputName(request.into_inner().output_name);
+
+        run_unary(&self.sender, move |state| {
+            let (capable, enabled) = output_name
+                .output(&state.pinnacle)
+                .map(|output| output.with_state(|state| (state.hdr_capable, state.hdr)))
+                .unwrap_or_default();
+
+            Ok(GetHdrResponse { capable, enabled })
+        })
+        .await
+    }
+
+    async fn get_color_depth(
+        &self,
+        request: Request<GetColorDepthRequest>,
+    ) -> TonicResult<GetColorDepthResponse> {
+        let output_name = OutputName(request.into_inner().output_name);
+
+        run_unary(&self.sender, move |state| {
+            let color_depth = output_name
+                .output(&state.pinnacle)
+                .map(|output| output.with_state(|state| state.color_depth))
+                .unwrap_or_default()
+                .bits();
+
+            Ok(GetColorDepthResponse { color_depth })
+        })
+        .await
+    }
+
+    async fn get_srgb_emulation(
+        &self,
+        request: Request<GetSrgbEmulationRequest>,
+    ) -> TonicResult<GetSrgbEmulationResponse> {
+        let output_name = OutputName(request.into_inner().output_name);
+
+        run_unary(&self.sender, move |state| {
+            let srgb_emulation = output_name
+                .output(&state.pinnacle)
+                .is_some_and(|output| output.with_state(|state| state.srgb_emulation));
+
+            Ok(GetSrgbEmulationResponse { srgb_emulation })
+        })
+        .await
+    }
+
+    async fn get_render_stats(
+        &self,
+        request: Request<GetRenderStatsRequest>,
+    ) -> TonicResult<GetRenderStatsResponse> {
+        let output_name = OutputName(request.into_inner().output_name);
+
+        run_unary(&self.sender, move |state| {
+            let stats = output_name
+                .output(&state.pinnacle)
+                .map(|output| output.with_state(|state| state.render_stats))
+                .unwrap_or_default();
+
+            Ok(GetRenderStatsResponse {
+                frames_rendered: stats.frames_rendered,
+                frames_skipped_no_damage: stats.frames_skipped_no_damage,
+                frames_skipped_suspended: stats.frames_skipped_suspended,
+            })
+        })
+        .await
+    }
+
     async fn get_focus_stack_window_ids(
         &self,
         request: Request<GetFocusStackWindowIdsRequest>,
@@ -659,6 +933,30 @@ impl output::v1::output_service_server::OutputService for super::OutputService {
         .await
     }
 
+    async fn get_stacking_order(
+        &self,
+        request: Request<GetStackingOrderRequest>,
+    ) -> TonicResult<GetStackingOrderResponse> {
+        let output_name = OutputName(request.into_inner().output_name);
+
+        run_unary(&self.sender, move |state| {
+            let window_ids = output_name
+                .output(&state.pinnacle)
+                .map(|output| {
+                    state
+                        .pinnacle
+                        .stacking_order(&output)
+                        .iter()
+                        .map(|win| win.with_state(|state| state.id.0))
+                        .collect()
+                })
+                .unwrap_or_default();
+
+            Ok(GetStackingOrderResponse { window_ids })
+        })
+        .await
+    }
+
     async fn get_outputs_in_dir(
         &self,
         request: Request<GetOutputsInDirRequest>,
@@ -704,4 +1002,49 @@ impl output::v1::output_service_server::OutputService for super::OutputService {
         })
         .await
     }
+
+    async fn watch_properties(
+        &self,
+        request: Request<WatchPropertiesRequest>,
+    ) -> TonicResult<Self::WatchPropertiesStream> {
+        let output_name = OutputName(request.into_inner().output_name);
+
+        run_server_streaming(&self.sender, move |state, sender| {
+            if output_name.output(&state.pinnacle).is_none() {
+                return Err(Status::not_found("output doesn't exist"));
+            }
+
+            state
+                .pinnacle
+                .output_watch_state
+                .subscribe(output_name, sender);
+            Ok(())
+        })
+        .await
+    }
+}
+
+fn edid_to_api(edid: EdidInfo) -> Edid {
+    let chromaticity = |chromaticity: crate::output::edid::Chromaticity| Chromaticity {
+        x: chromaticity.x,
+        y: chromaticity.y,
+    };
+
+    Edid {
+        manufacture_year: edid.manufacture_year,
+        manufacture_week: edid.manufacture_week,
+        color_primaries: edid.color_primaries.map(|primaries| ColorPrimaries {
+            red: Some(chromaticity(primaries.red)),
+            green: Some(chromaticity(primaries.green)),
+            blue: Some(chromaticity(primaries.blue)),
+            white: primaries.white.map(chromaticity),
+        }),
+        hdr_metadata: edid.hdr_metadata.map(|metadata| HdrMetadata {
+            max_luminance: metadata.max_luminance,
+            max_frame_avg_luminance: metadata.max_frame_avg_luminance,
+            min_luminance: metadata.min_luminance,
+            pq: metadata.pq,
+            hlg: metadata.hlg,
+        }),
+    }
 }
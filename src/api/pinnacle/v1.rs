@@ -1,21 +1,36 @@
 use pinnacle_api_defs::pinnacle::{
-    self,
+    self, input, tag,
     v1::{
-        self, BackendRequest, BackendResponse, KeepaliveRequest, KeepaliveResponse, QuitRequest,
-        ReloadConfigRequest, SetLastErrorRequest, SetXwaylandClientSelfScaleRequest,
-        TakeLastErrorRequest, TakeLastErrorResponse,
+        self, BackendRequest, BackendResponse, DefineProfileRequest, DefineProfileResponse,
+        GetProfileRequest, GetProfileResponse, GetSettingsRequest, GetSettingsResponse, Histogram,
+        KeepaliveRequest, KeepaliveResponse, MetricsRequest, MetricsResponse, QuitRequest,
+        RegisterNamespaceRequest, RegisterNamespaceResponse, ReloadConfigRequest, RestartRequest,
+        SetLastErrorRequest, SetMetricsExportRequest, SetMetricsExportResponse, SetProfileRequest,
+        SetProfileResponse, SetXwaylandClientSelfScaleRequest, Setting, TakeLastErrorRequest,
+        TakeLastErrorResponse, setting,
     },
+    window,
 };
-use tonic::{Request, Streaming};
+use tonic::{Request, Status, Streaming};
 use tracing::{info, trace};
 
-use crate::api::{
-    ResponseStream, TonicResult, run_bidirectional_streaming, run_unary, run_unary_no_response,
+use crate::{
+    api::{
+        ResponseStream, TonicResult, namespace::client_pid, run_bidirectional_streaming,
+        run_server_streaming, run_unary, run_unary_no_response,
+    },
+    input::PointerCrossing,
+    metrics::{self, MetricsExport},
+    profile::Profile,
+    state::State,
+    tag::EmptyOutputPolicy,
+    window::window_state::{DialogPolicy, MaximizeRequestPolicy, TagInheritance},
 };
 
 #[tonic::async_trait]
 impl v1::pinnacle_service_server::PinnacleService for super::PinnacleService {
     type KeepaliveStream = ResponseStream<KeepaliveResponse>;
+    type RegisterNamespaceStream = ResponseStream<RegisterNamespaceResponse>;
 
     async fn quit(&self, _request: Request<QuitRequest>) -> TonicResult<()> {
         trace!("PinnacleService.quit");
@@ -26,6 +41,13 @@ impl v1::pinnacle_service_server::PinnacleService for super::PinnacleService {
         .await
     }
 
+    async fn restart(&self, _request: Request<RestartRequest>) -> TonicResult<()> {
+        run_unary_no_response(&self.sender, |state| {
+            state.pinnacle.restart();
+        })
+        .await
+    }
+
     async fn reload_config(&self, _request: Request<ReloadConfigRequest>) -> TonicResult<()> {
         run_unary_no_response(&self.sender, |state| {
             info!("Reloading config");
@@ -108,4 +130,463 @@ impl v1::pinnacle_service_server::PinnacleService for super::PinnacleService {
         })
         .await
     }
+
+    async fn get_settings(
+        &self,
+        _request: Request<GetSettingsRequest>,
+    ) -> TonicResult<GetSettingsResponse> {
+        run_unary(&self.sender, move |state| {
+            Ok(GetSettingsResponse {
+                settings: settings(state),
+            })
+        })
+        .await
+    }
+
+    async fn metrics(&self, _request: Request<MetricsRequest>) -> TonicResult<MetricsResponse> {
+        run_unary(&self.sender, move |state| {
+            let metrics = &state.pinnacle.metrics;
+
+            Ok(MetricsResponse {
+                input_latency: Some(histogram(&metrics.input_latency)),
+                layout_time: Some(histogram(&metrics.layout_time)),
+                missed_frames: metrics.missed_frames,
+            })
+        })
+        .await
+    }
+
+    async fn set_metrics_export(
+        &self,
+        request: Request<SetMetricsExportRequest>,
+    ) -> TonicResult<SetMetricsExportResponse> {
+        let enabled = request.into_inner().enabled;
+        let sender = self.sender.clone();
+
+        run_unary(&self.sender, move |state| {
+            let config = &mut state.pinnacle.config;
+
+            if !enabled {
+                config.metrics_export = None;
+            } else if config.metrics_export.is_none() {
+                let socket_dir = config
+                    .socket_dir()
+                    .ok_or_else(|| Status::failed_precondition("no socket directory"))?;
+                let export = MetricsExport::start(socket_dir, sender).map_err(|err| {
+                    Status::internal(format!("failed to start metrics export: {err}"))
+                })?;
+                config.metrics_export = Some(export);
+            }
+
+            Ok(SetMetricsExportResponse {
+                socket_path: config
+                    .metrics_export
+                    .as_ref()
+                    .map(|export| export.socket_path().to_string_lossy().into_owned()),
+            })
+        })
+        .await
+    }
+
+    async fn define_profile(
+        &self,
+        request: Request<DefineProfileRequest>,
+    ) -> TonicResult<DefineProfileResponse> {
+        let request = request.into_inner();
+        let name = request.name;
+        let profile = request.profile.unwrap_or_default();
+
+        let profile = Profile {
+            inhibit_idle: profile.inhibit_idle,
+            disable_inactive_effect: profile.disable_inactive_effect,
+            grey_out_unresponsive: profile.grey_out_unresponsive,
+            letterbox_fullscreen: profile.letterbox_fullscreen,
+        };
+
+        run_unary(&self.sender, move |state| {
+            state
+                .pinnacle
+                .config
+                .profiles
+                .profiles
+                .insert(name, profile);
+            Ok(DefineProfileResponse {})
+        })
+        .await
+    }
+
+    async fn set_profile(
+        &self,
+        request: Request<SetProfileRequest>,
+    ) -> TonicResult<SetProfileResponse> {
+        let name = request.into_inner().name;
+
+        run_unary(&self.sender, move |state| {
+            let error = match name {
+                Some(name) => state.set_profile(&name).err(),
+                None => {
+                    state.unset_profile();
+                    None
+                }
+            };
+
+            Ok(SetProfileResponse { error })
+        })
+        .await
+    }
+
+    async fn get_profile(
+        &self,
+        _request: Request<GetProfileRequest>,
+    ) -> TonicResult<GetProfileResponse> {
+        run_unary(&self.sender, move |state| {
+            Ok(GetProfileResponse {
+                name: state.pinnacle.config.profiles.active().map(String::from),
+            })
+        })
+        .await
+    }
+
+    async fn register_namespace(
+        &self,
+        request: Request<RegisterNamespaceRequest>,
+    ) -> TonicResult<Self::RegisterNamespaceStream> {
+        let Some(pid) = client_pid(&request) else {
+            return Err(Status::failed_precondition(
+                "could not determine the client's process id",
+            ));
+        };
+
+        let namespace = request.into_inner().namespace;
+        if namespace.is_empty() {
+            return Err(Status::invalid_argument("namespace is empty"));
+        }
+
+        let fn_sender = self.sender.clone();
+
+        run_server_streaming(&self.sender, move |state, sender| {
+            state
+                .pinnacle
+                .namespace_state
+                .register(pid, namespace.clone())
+                .map_err(Status::already_exists)?;
+
+            info!("Registered namespace {namespace}");
+
+            tokio::spawn(async move {
+                sender.closed().await;
+                let _ = fn_sender.send(Box::new(move |state| {
+                    state.revoke_namespace(&namespace);
+                }));
+            });
+
+            Ok(())
+        })
+        .await
+    }
+}
+
+fn histogram(histogram: &metrics::Histogram) -> Histogram {
+    Histogram {
+        bucket_bounds_ms: metrics::BUCKET_BOUNDS_MS.to_vec(),
+        bucket_counts: histogram.counts.to_vec(),
+        sum_ms: histogram.sum.as_secs_f64() * 1000.0,
+        count: histogram.count,
+    }
+}
+
+fn setting(name: &str, description: &str, setter: &str, value: setting::Value) -> Setting {
+    Setting {
+        name: name.to_string(),
+        description: description.to_string(),
+        setter: setter.to_string(),
+        device_sysname: None,
+        value: Some(value),
+        choices: Vec::new(),
+        min: None,
+        max: None,
+    }
+}
+
+/// Returns all runtime-settable options along with their current values.
+fn settings(state: &State) -> Vec<Setting> {
+    let config = &state.pinnacle.config;
+
+    let maximize_request_policy = match config.maximize_request_policy {
+        MaximizeRequestPolicy::Honor => window::v1::MaximizeRequestPolicy::Honor,
+        MaximizeRequestPolicy::Ignore => window::v1::MaximizeRequestPolicy::Ignore,
+        MaximizeRequestPolicy::FullscreenWithinTile => {
+            window::v1::MaximizeRequestPolicy::FullscreenWithinTile
+        }
+        MaximizeRequestPolicy::FloatAndMaximize => {
+            window::v1::MaximizeRequestPolicy::FloatAndMaximize
+        }
+    };
+
+    let tag_inheritance = match config.tag_inheritance {
+        TagInheritance::ActiveTags => window::v1::TagInheritance::ActiveTags,
+        TagInheritance::FirstActiveTag => window::v1::TagInheritance::FirstActiveTag,
+        TagInheritance::Parent => window::v1::TagInheritance::Parent,
+    };
+
+    let dialog_policy = match config.dialog_policy {
+        DialogPolicy::Independent => window::v1::DialogPolicy::Independent,
+        DialogPolicy::AttachToParent => window::v1::DialogPolicy::AttachToParent,
+    };
+
+    let empty_output_policy = match config.empty_output_policy {
+        EmptyOutputPolicy::Blank => tag::v1::EmptyOutputPolicy::Blank,
+        EmptyOutputPolicy::ReactivateLast => tag::v1::EmptyOutputPolicy::ReactivateLast,
+        EmptyOutputPolicy::Fallback(_) => tag::v1::EmptyOutputPolicy::Fallback,
+    };
+
+    let mut settings = vec![
+        setting(
+            "window.grey_out_unresponsive",
+            "Grey out windows that stop responding",
+            "pinnacle.window.v1.WindowService.SetGreyOutUnresponsive",
+            setting::Value::Bool(config.grey_out_unresponsive),
+        ),
+        Setting {
+            choices: [
+                window::v1::MaximizeRequestPolicy::Honor,
+                window::v1::MaximizeRequestPolicy::Ignore,
+                window::v1::MaximizeRequestPolicy::FullscreenWithinTile,
+                window::v1::MaximizeRequestPolicy::FloatAndMaximize,
+            ]
+            .iter()
+            .map(|policy| policy.as_str_name().to_string())
+            .collect(),
+            ..setting(
+                "window.maximize_request_policy",
+                "How client maximize requests are handled",
+                "pinnacle.window.v1.WindowService.SetMaximizeRequestPolicy",
+                setting::Value::Choice(maximize_request_policy.as_str_name().to_string()),
+            )
+        },
+        Setting {
+            choices: [
+                window::v1::TagInheritance::ActiveTags,
+                window::v1::TagInheritance::FirstActiveTag,
+                window::v1::TagInheritance::Parent,
+            ]
+            .iter()
+            .map(|inheritance| inheritance.as_str_name().to_string())
+            .collect(),
+            ..setting(
+                "window.tag_inheritance",
+                "Which tags new windows are put on",
+                "pinnacle.window.v1.WindowService.SetTagInheritance",
+                setting::Value::Choice(tag_inheritance.as_str_name().to_string()),
+            )
+        },
+        Setting {
+            choices: [
+                window::v1::DialogPolicy::Independent,
+                window::v1::DialogPolicy::AttachToParent,
+            ]
+            .iter()
+            .map(|policy| policy.as_str_name().to_string())
+            .collect(),
+            ..setting(
+                "window.dialog_policy",
+                "How dialogs are placed relative to their parent",
+                "pinnacle.window.v1.WindowService.SetDialogPolicy",
+                setting::Value::Choice(dialog_policy.as_str_name().to_string()),
+            )
+        },
+        Setting {
+            choices: [
+                tag::v1::EmptyOutputPolicy::Blank,
+                tag::v1::EmptyOutputPolicy::ReactivateLast,
+                tag::v1::EmptyOutputPolicy::Fallback,
+            ]
+            .iter()
+            .map(|policy| policy.as_str_name().to_string())
+            .collect(),
+            ..setting(
+                "tag.empty_output_policy",
+                "What happens when an output is left with no active tags",
+                "pinnacle.tag.v1.TagService.SetEmptyOutputPolicy",
+                setting::Value::Choice(empty_output_policy.as_str_name().to_string()),
+            )
+        },
+        setting(
+            "render.letterbox_fullscreen",
+            "Center fullscreen windows that don't fill their output with bars around them",
+            "pinnacle.render.v1.RenderService.SetLetterboxFullscreen",
+            setting::Value::Bool(config.letterbox_fullscreen),
+        ),
+        Setting {
+            min: Some(0.0),
+            ..setting(
+                "render.close_snapshot_hold",
+                "How long closed tiled windows stay in place while the layout fills their \
+                    space, in milliseconds",
+                "pinnacle.render.v1.RenderService.SetCloseSnapshotHold",
+                setting::Value::Int(config.close_snapshot_hold.as_millis() as i64),
+            )
+        },
+        Setting {
+            min: Some(0.0),
+            ..setting(
+                "output.hotplug_settle_delay",
+                "How long connector changes must stop for before outputs are reconfigured, \
+                    in milliseconds",
+                "pinnacle.output.v1.OutputService.SetHotplugSettleDelay",
+                setting::Value::Int(config.hotplug_settle_delay.as_millis() as i64),
+            )
+        },
+        setting(
+            "debug.visualize_damage",
+            "Draw damaged regions",
+            "pinnacle.debug.v1.DebugService.SetDamageVisualization",
+            setting::Value::Bool(config.debug.visualize_damage),
+        ),
+        setting(
+            "debug.visualize_opaque_regions",
+            "Draw opaque regions",
+            "pinnacle.debug.v1.DebugService.SetOpaqueRegionVisualization",
+            setting::Value::Bool(config.debug.visualize_opaque_regions),
+        ),
+        setting(
+            "debug.cursor_plane_scanout",
+            "Scan the cursor out on a hardware cursor plane",
+            "pinnacle.debug.v1.DebugService.SetCursorPlaneScanout",
+            setting::Value::Bool(!config.debug.disable_cursor_plane_scanout),
+        ),
+        setting(
+            "debug.process_piping",
+            "Pipe the output of spawned processes",
+            "pinnacle.debug.v1.DebugService.SetProcessPiping",
+            setting::Value::Bool(!config.debug.disable_process_piping),
+        ),
+        setting(
+            "debug.trace_input",
+            "Log input events and where they went",
+            "pinnacle.debug.v1.DebugService.SetInputTracing",
+            setting::Value::Bool(config.debug.trace_input),
+        ),
+    ];
+
+    if let Some(keyboard) = state.pinnacle.seat.get_keyboard() {
+        settings.push(Setting {
+            min: Some(0.0),
+            ..setting(
+                "input.repeat_rate",
+                "How many times per second held keys repeat",
+                "pinnacle.input.v1.InputService.SetRepeatRate",
+                setting::Value::Int(keyboard.repeat_rate() as i64),
+            )
+        });
+        settings.push(Setting {
+            min: Some(0.0),
+            ..setting(
+                "input.repeat_delay",
+                "How long keys must be held before they repeat, in milliseconds",
+                "pinnacle.input.v1.InputService.SetRepeatRate",
+                setting::Value::Int(keyboard.repeat_delay() as i64),
+            )
+        });
+    }
+
+    let pointer_crossing = match state.pinnacle.input_state.pointer_crossing {
+        PointerCrossing::Logical => input::v1::PointerCrossing::Logical,
+        PointerCrossing::Physical => input::v1::PointerCrossing::Physical,
+    };
+
+    settings.push(Setting {
+        choices: [
+            input::v1::PointerCrossing::Logical,
+            input::v1::PointerCrossing::Physical,
+        ]
+        .iter()
+        .map(|crossing| crossing.as_str_name().to_string())
+        .collect(),
+        ..setting(
+            "input.pointer_crossing",
+            "Where the pointer ends up when it crosses between outputs",
+            "pinnacle.input.v1.InputService.SetPointerCrossing",
+            setting::Value::Choice(pointer_crossing.as_str_name().to_string()),
+        )
+    });
+
+    for device in state.pinnacle.input_state.libinput_state.devices.keys() {
+        let mut device_settings = Vec::new();
+        let setter = "pinnacle.input.v1.InputService.SetDeviceLibinputSetting";
+
+        if device.config_tap_finger_count() > 0 {
+            device_settings.extend([
+                setting(
+                    "input.libinput.tap",
+                    "Tap to click",
+                    setter,
+                    setting::Value::Bool(device.config_tap_enabled()),
+                ),
+                setting(
+                    "input.libinput.tap_drag",
+                    "Double-tap to drag",
+                    setter,
+                    setting::Value::Bool(device.config_tap_drag_enabled()),
+                ),
+                setting(
+                    "input.libinput.tap_drag_lock",
+                    "Keep tap-drags going when the finger is briefly lifted",
+                    setter,
+                    setting::Value::Bool(device.config_tap_drag_lock_enabled()),
+                ),
+            ]);
+        }
+        if device.config_accel_is_available() {
+            device_settings.push(Setting {
+                min: Some(-1.0),
+                max: Some(1.0),
+                ..setting(
+                    "input.libinput.accel_speed",
+                    "Pointer acceleration speed",
+                    setter,
+                    setting::Value::Float(device.config_accel_speed()),
+                )
+            });
+        }
+        if device.config_scroll_has_natural_scroll() {
+            device_settings.push(setting(
+                "input.libinput.natural_scroll",
+                "Reverse the scroll direction",
+                setter,
+                setting::Value::Bool(device.config_scroll_natural_scroll_enabled()),
+            ));
+        }
+        if device.config_left_handed_is_available() {
+            device_settings.push(setting(
+                "input.libinput.left_handed",
+                "Swap the left and right buttons",
+                setter,
+                setting::Value::Bool(device.config_left_handed()),
+            ));
+        }
+        if device.config_dwt_is_available() {
+            device_settings.push(setting(
+                "input.libinput.disable_while_typing",
+                "Disable the device while typing",
+                setter,
+                setting::Value::Bool(device.config_dwt_enabled()),
+            ));
+        }
+        if device.config_middle_emulation_is_available() {
+            device_settings.push(setting(
+                "input.libinput.middle_emulation",
+                "Press the left and right buttons together for a middle click",
+                setter,
+                setting::Value::Bool(device.config_middle_emulation_enabled()),
+            ));
+        }
+
+        settings.extend(device_settings.into_iter().map(|setting| Setting {
+            device_sysname: Some(device.sysname().to_string()),
+            ..setting
+        }));
+    }
+
+    settings
 }
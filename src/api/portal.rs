@@ -0,0 +1,13 @@
+mod v1;
+
+use super::StateFnSender;
+
+pub struct PortalService {
+    sender: StateFnSender,
+}
+
+impl PortalService {
+    pub fn new(sender: StateFnSender) -> Self {
+        Self { sender }
+    }
+}
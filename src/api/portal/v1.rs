@@ -0,0 +1,69 @@
+use pinnacle_api_defs::pinnacle::portal::{
+    self,
+    v1::{GlobalShortcutsPermissionRequest, GlobalShortcutsPermissionResponse},
+};
+use tonic::{Request, Streaming};
+
+use crate::api::{ResponseStream, TonicResult, run_bidirectional_streaming_mapped};
+
+#[tonic::async_trait]
+impl portal::v1::portal_service_server::PortalService for super::PortalService {
+    type GlobalShortcutsPermissionStream = ResponseStream<GlobalShortcutsPermissionResponse>;
+
+    async fn global_shortcuts_permission(
+        &self,
+        request: Request<Streaming<GlobalShortcutsPermissionRequest>>,
+    ) -> TonicResult<Self::GlobalShortcutsPermissionStream> {
+        let in_stream = request.into_inner();
+
+        run_bidirectional_streaming_mapped(
+            self.sender.clone(),
+            in_stream,
+            |state, request| {
+                let Some(request) = request.request else {
+                    return;
+                };
+
+                match request {
+                    portal::v1::global_shortcuts_permission_request::Request::Decision(
+                        decision,
+                    ) => {
+                        state
+                            .pinnacle
+                            .global_shortcuts_state
+                            .respond(decision.request_id, decision.allow);
+                    }
+                }
+            },
+            |state, sender, _join_handle| {
+                state
+                    .pinnacle
+                    .global_shortcuts_state
+                    .new_permission_sender(sender);
+            },
+            |request| {
+                let shortcuts = request
+                    .shortcuts
+                    .into_iter()
+                    .map(|shortcut| portal::v1::GlobalShortcut {
+                        id: shortcut.id,
+                        description: shortcut.description,
+                        preferred_trigger: shortcut.preferred_trigger,
+                    })
+                    .collect();
+
+                Ok(GlobalShortcutsPermissionResponse {
+                    response: Some(
+                        portal::v1::global_shortcuts_permission_response::Response::NewRequest(
+                            portal::v1::global_shortcuts_permission_response::NewRequest {
+                                request_id: request.request_id,
+                                app_id: request.app_id,
+                                shortcuts,
+                            },
+                        ),
+                    ),
+                })
+            },
+        )
+    }
+}
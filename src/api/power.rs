@@ -0,0 +1,13 @@
+mod v1;
+
+use super::StateFnSender;
+
+pub struct PowerService {
+    sender: StateFnSender,
+}
+
+impl PowerService {
+    pub fn new(sender: StateFnSender) -> Self {
+        Self { sender }
+    }
+}
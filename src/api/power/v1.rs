@@ -0,0 +1,27 @@
+use pinnacle_api_defs::pinnacle::power::{
+    self,
+    v1::{GetStatusRequest, GetStatusResponse},
+};
+use tonic::Request;
+
+use crate::api::{TonicResult, run_unary};
+
+#[tonic::async_trait]
+impl power::v1::power_service_server::PowerService for super::PowerService {
+    async fn get_status(
+        &self,
+        _request: Request<GetStatusRequest>,
+    ) -> TonicResult<GetStatusResponse> {
+        run_unary(&self.sender, move |state| {
+            let status = state.pinnacle.power_state.status;
+
+            Ok(GetStatusResponse {
+                on_battery: status.on_battery,
+                charging: status.charging,
+                percentage: status.percentage,
+                low_battery: status.low_battery,
+            })
+        })
+        .await
+    }
+}
@@ -1,12 +1,18 @@
 use pinnacle_api_defs::pinnacle::process::{
     self,
-    v1::{SetEnvRequest, SpawnRequest, SpawnResponse, WaitOnSpawnRequest, WaitOnSpawnResponse},
+    v1::{
+        RunOrRaiseRequest, RunOrRaiseResponse, SetEnvRequest, SetGlobalEnvRequest,
+        SetGlobalEnvResponse, SpawnRequest, SpawnResponse, WaitOnSpawnRequest, WaitOnSpawnResponse,
+    },
 };
-use tonic::Request;
+use regex::Regex;
+use tonic::{Request, Response, Status};
 
 use crate::{
     api::{ResponseStream, TonicResult, run_server_streaming, run_unary, run_unary_no_response},
+    focus::FocusReason,
     process::PipeProcesses,
+    state::WithState,
 };
 
 #[tonic::async_trait]
@@ -99,4 +105,124 @@ impl process::v1::process_service_server::ProcessService for super::ProcessServi
         })
         .await
     }
+
+    async fn run_or_raise(
+        &self,
+        request: Request<RunOrRaiseRequest>,
+    ) -> TonicResult<RunOrRaiseResponse> {
+        let RunOrRaiseRequest { app_id, cmd } = request.into_inner();
+
+        let app_id = match Regex::new(&app_id) {
+            Ok(app_id) => app_id,
+            Err(err) => {
+                return Ok(Response::new(RunOrRaiseResponse {
+                    window_id: None,
+                    spawn_data: None,
+                    error: Some(err.to_string()),
+                }));
+            }
+        };
+
+        run_unary(&self.sender, move |state| {
+            let matches = |win: &&crate::window::WindowElement| {
+                win.class().is_some_and(|class| app_id.is_match(&class))
+            };
+
+            // Prefer the most recently focused window
+            let window = state
+                .pinnacle
+                .keyboard_focus_stack
+                .history()
+                .chain(state.pinnacle.windows.iter())
+                .find(&matches)
+                .cloned();
+
+            if let Some(window) = window {
+                let window_id = window.with_state(|state| state.id.0);
+                state.activate_window(window, FocusReason::Keybind);
+
+                return Ok(RunOrRaiseResponse {
+                    window_id: Some(window_id),
+                    spawn_data: None,
+                    error: None,
+                });
+            }
+
+            // Don't spawn a second instance while the first one is still mapping
+            let pending = state
+                .pinnacle
+                .unmapped_windows
+                .iter()
+                .map(|unmapped| &unmapped.window)
+                .any(|win| matches(&win));
+
+            let spawn_data = if pending {
+                None
+            } else {
+                state.pinnacle.process_state.spawn(
+                    &cmd,
+                    &[],
+                    false,
+                    false,
+                    state.pinnacle.config.process_envs.clone(),
+                    &state.pinnacle.xdg_base_dirs,
+                    PipeProcesses {
+                        stdin: false,
+                        stdout: false,
+                        stderr: false,
+                    },
+                )
+            };
+
+            Ok(RunOrRaiseResponse {
+                window_id: None,
+                spawn_data: spawn_data.map(|data| process::v1::SpawnData {
+                    pid: data.pid,
+                    fd_socket_path: data.fd_socket_path,
+                    has_stdin: data.has_stdin,
+                    has_stdout: data.has_stdout,
+                    has_stderr: data.has_stderr,
+                }),
+                error: None,
+            })
+        })
+        .await
+    }
+
+    async fn set_global_env(
+        &self,
+        request: Request<SetGlobalEnvRequest>,
+    ) -> TonicResult<SetGlobalEnvResponse> {
+        let SetGlobalEnvRequest { key, value } = request.into_inner();
+
+        if key.is_empty() || key.contains(['=', '\0']) {
+            return Err(Status::invalid_argument(format!(
+                "invalid environment variable name `{key}`"
+            )));
+        }
+
+        if value.as_ref().is_some_and(|value| value.contains('\0')) {
+            return Err(Status::invalid_argument(
+                "environment variable value contains NUL",
+            ));
+        }
+
+        run_unary(&self.sender, move |state| {
+            // SAFETY: All set_vars and remove_vars occur on the event loop thread
+            unsafe {
+                match value.as_ref() {
+                    Some(value) => std::env::set_var(&key, value),
+                    None => std::env::remove_var(&key),
+                }
+            }
+
+            // Don't let a value from `SetEnv` shadow the new one
+            state.pinnacle.config.process_envs.remove(&key);
+
+            crate::session::update_activation_environment(&key, value.as_deref());
+
+            Ok(SetGlobalEnvResponse {})
+        })
+        .await
+    }
 }
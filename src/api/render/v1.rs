@@ -1,13 +1,20 @@
+use std::time::Duration;
+
 use pinnacle_api_defs::pinnacle::render::{
     self,
-    v1::{Filter, SetDownscaleFilterRequest, SetUpscaleFilterRequest},
+    v1::{
+        Filter, InactiveEffectTarget, SetCloseSnapshotHoldRequest, SetCloseSnapshotHoldResponse,
+        SetDownscaleFilterRequest, SetInactiveEffectRequest, SetInactiveEffectResponse,
+        SetLetterboxFullscreenRequest, SetLetterboxFullscreenResponse, SetUpscaleFilterRequest,
+    },
 };
 use smithay::backend::renderer::TextureFilter;
 use tonic::{Request, Status};
 
 use crate::{
-    api::{TonicResult, run_unary_no_response},
+    api::{TonicResult, run_unary, run_unary_no_response},
     backend::BackendData,
+    render::util::effect::{self, InactiveEffect},
 };
 
 #[tonic::async_trait]
@@ -61,4 +68,68 @@ impl render::v1::render_service_server::RenderService for super::RenderService {
         })
         .await
     }
+    async fn set_inactive_effect(
+        &self,
+        request: Request<SetInactiveEffectRequest>,
+    ) -> TonicResult<SetInactiveEffectResponse> {
+        let request = request.into_inner();
+
+        let effect = if request.enabled {
+            let target = match request.target() {
+                InactiveEffectTarget::Unspecified => {
+                    return Err(Status::invalid_argument("unspecified target"));
+                }
+                InactiveEffectTarget::UnfocusedOutputs => {
+                    effect::InactiveEffectTarget::UnfocusedOutputs
+                }
+                InactiveEffectTarget::UnfocusedWindows => {
+                    effect::InactiveEffectTarget::UnfocusedWindows
+                }
+            };
+
+            Some(InactiveEffect {
+                target,
+                dim: request.dim.clamp(0.0, 1.0),
+                desaturate: request.desaturate.clamp(0.0, 1.0),
+            })
+        } else {
+            None
+        };
+
+        run_unary(&self.sender, move |state| {
+            state.pinnacle.config.inactive_effect = effect;
+            Ok(SetInactiveEffectResponse {})
+        })
+        .await
+    }
+    async fn set_letterbox_fullscreen(
+        &self,
+        request: Request<SetLetterboxFullscreenRequest>,
+    ) -> TonicResult<SetLetterboxFullscreenResponse> {
+        let letterbox = request.into_inner().letterbox;
+
+        run_unary(&self.sender, move |state| {
+            state.pinnacle.config.letterbox_fullscreen = letterbox;
+
+            for window in state.pinnacle.windows.clone() {
+                state.pinnacle.update_letterbox(&window);
+            }
+
+            Ok(SetLetterboxFullscreenResponse {})
+        })
+        .await
+    }
+
+    async fn set_close_snapshot_hold(
+        &self,
+        request: Request<SetCloseSnapshotHoldRequest>,
+    ) -> TonicResult<SetCloseSnapshotHoldResponse> {
+        let hold = Duration::from_millis(request.into_inner().millis.into());
+
+        run_unary(&self.sender, move |state| {
+            state.pinnacle.config.close_snapshot_hold = hold;
+            Ok(SetCloseSnapshotHoldResponse {})
+        })
+        .await
+    }
 }
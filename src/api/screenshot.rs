@@ -0,0 +1,13 @@
+mod v1;
+
+use super::StateFnSender;
+
+pub struct ScreenshotService {
+    sender: StateFnSender,
+}
+
+impl ScreenshotService {
+    pub fn new(sender: StateFnSender) -> Self {
+        Self { sender }
+    }
+}
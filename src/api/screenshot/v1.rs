@@ -0,0 +1,70 @@
+use std::path::PathBuf;
+
+use pinnacle_api_defs::pinnacle::{
+    screenshot::{
+        self,
+        v1::{SelectRegionRequest, SelectRegionResponse},
+    },
+    util,
+};
+use tonic::{Request, Status};
+
+use crate::api::{TonicResult, run_unary};
+
+#[tonic::async_trait]
+impl screenshot::v1::screenshot_service_server::ScreenshotService for super::ScreenshotService {
+    async fn select_region(
+        &self,
+        request: Request<SelectRegionRequest>,
+    ) -> TonicResult<SelectRegionResponse> {
+        let request = request.into_inner();
+
+        let path = request
+            .path
+            .map(|path| shellexpand::tilde(&path).into_owned())
+            .map(PathBuf::from);
+
+        let selection = run_unary(&self.sender, move |state| Ok(state.start_region_select()))
+            .await?
+            .into_inner();
+
+        let screenshot = match selection.await {
+            Ok(Ok(Some(screenshot))) => screenshot,
+            Ok(Err(err)) => return Err(Status::internal(format!("{err:#}"))),
+            Ok(Ok(None)) | Err(_) => {
+                return Ok(tonic::Response::new(SelectRegionResponse::default()));
+            }
+        };
+
+        let screenshot = match path {
+            Some(path) => {
+                tokio::task::spawn_blocking(move || screenshot.save_png(&path).map(|()| screenshot))
+                    .await
+                    .map_err(|err| Status::internal(err.to_string()))?
+                    .map_err(|err| Status::internal(format!("{err:#}")))?
+            }
+            None => screenshot,
+        };
+
+        let region = screenshot.region;
+
+        Ok(tonic::Response::new(SelectRegionResponse {
+            output_name: Some(screenshot.output_name),
+            region: Some(util::v1::Rect {
+                loc: Some(util::v1::Point {
+                    x: region.loc.x,
+                    y: region.loc.y,
+                }),
+                size: Some(util::v1::Size {
+                    width: region.size.w as u32,
+                    height: region.size.h as u32,
+                }),
+            }),
+            image_size: Some(util::v1::Size {
+                width: screenshot.size.w as u32,
+                height: screenshot.size.h as u32,
+            }),
+            rgba: screenshot.rgba,
+        }))
+    }
+}
@@ -1,41 +1,53 @@
 use std::{
-    collections::{HashMap, VecDeque},
+    collections::{HashMap, HashSet, VecDeque},
     sync::atomic::{AtomicU32, Ordering},
 };
 
 use pinnacle_api_defs::pinnacle::{
+    output,
     signal::{
         self,
         v1::{
+            IdleResumedRequest, IdleResumedResponse, IdleStartedRequest, IdleStartedResponse,
+            InputAccessibilityChangedRequest, InputAccessibilityChangedResponse,
             InputDeviceAddedRequest, InputDeviceAddedResponse, OutputConnectRequest,
             OutputConnectResponse, OutputDisconnectRequest, OutputDisconnectResponse,
-            OutputFocusedRequest, OutputFocusedResponse, OutputMoveRequest, OutputMoveResponse,
+            OutputFocusedRequest, OutputFocusedResponse, OutputLayerFocusedRequest,
+            OutputLayerFocusedResponse, OutputLayerUnfocusedRequest, OutputLayerUnfocusedResponse,
+            OutputMoveRequest, OutputMoveResponse, OutputOrientationChangedRequest,
+            OutputOrientationChangedResponse, OutputPointerEdgeRequest, OutputPointerEdgeResponse,
             OutputPointerEnterRequest, OutputPointerEnterResponse, OutputPointerLeaveRequest,
-            OutputPointerLeaveResponse, OutputResizeRequest, OutputResizeResponse, SignalRequest,
+            OutputPointerLeaveResponse, OutputResizeRequest, OutputResizeResponse,
+            PowerChargingRequest, PowerChargingResponse, PowerLowBatteryRequest,
+            PowerLowBatteryResponse, PowerOnBatteryRequest, PowerOnBatteryResponse, SignalRequest,
             StreamControl, TagActiveRequest, TagActiveResponse, TagCreatedRequest,
             TagCreatedResponse, TagRemovedRequest, TagRemovedResponse, WindowCreatedRequest,
             WindowCreatedResponse, WindowDestroyedRequest, WindowDestroyedResponse,
             WindowFocusedRequest, WindowFocusedResponse, WindowLayoutModeChangedRequest,
             WindowLayoutModeChangedResponse, WindowPointerEnterRequest, WindowPointerEnterResponse,
-            WindowPointerLeaveRequest, WindowPointerLeaveResponse, WindowTitleChangedRequest,
-            WindowTitleChangedResponse,
+            WindowPointerLeaveRequest, WindowPointerLeaveResponse,
+            WindowPopupGrabBlockedKeybindRequest, WindowPopupGrabBlockedKeybindResponse,
+            WindowTitleChangedRequest, WindowTitleChangedResponse, WindowUnresponsiveRequest,
+            WindowUnresponsiveResponse,
         },
     },
     window,
 };
 
-use smithay::output::Output;
+use smithay::{desktop::LayerSurface, output::Output, utils::Transform};
 use tonic::{Request, Response, Status, Streaming};
 use tracing::warn;
 
 use crate::{
     api::Sender,
+    focus::{FocusReason, pointer::ScreenEdge},
+    input::accessibility::AccessibilityState,
     state::{State, WithState},
     tag::Tag,
     window::{WindowElement, window_state::LayoutModeKind},
 };
 
-use super::{ResponseStream, StateFnSender, run_bidirectional_streaming};
+use super::{ResponseStream, StateFnSender, namespace::client_pid, run_bidirectional_streaming};
 
 #[derive(Debug, Default)]
 pub struct SignalState {
@@ -46,24 +58,47 @@ pub struct SignalState {
     pub output_move: OutputMove,
     pub output_pointer_enter: OutputPointerEnter,
     pub output_pointer_leave: OutputPointerLeave,
+    pub output_pointer_edge: OutputPointerEdge,
     pub output_focused: OutputFocused,
+    pub layer_focused: LayerFocused,
+    pub layer_unfocused: LayerUnfocused,
+    pub output_orientation_changed: OutputOrientationChanged,
+    pub output_fullscreen_changed: OutputFullscreenChanged,
+    pub output_stacking_changed: OutputStackingChanged,
+    pub output_no_active_tags: OutputNoActiveTags,
 
     // Window
     pub window_pointer_enter: WindowPointerEnter,
     pub window_pointer_leave: WindowPointerLeave,
     pub window_focused: WindowFocused,
     pub window_title_changed: WindowTitleChanged,
+    pub window_focused_title_changed: WindowFocusedTitleChanged,
     pub window_layout_changed: WindowLayoutChanged,
     pub window_created: WindowCreated,
     pub window_destroyed: WindowDestroyed,
+    pub window_unresponsive: WindowUnresponsive,
+    pub window_minimized: WindowMinimized,
+    pub window_urgency_changed: WindowUrgencyChanged,
+    pub window_popup_grab_blocked_keybind: WindowPopupGrabBlockedKeybind,
 
     // Tag
     pub tag_active: TagActive,
     pub tag_created: TagCreated,
     pub tag_removed: TagRemoved,
+    pub tag_urgency_changed: TagUrgencyChanged,
 
     // Input
     pub input_device_added: InputDeviceAdded,
+    pub input_accessibility_changed: InputAccessibilityChanged,
+
+    // Power
+    pub power_on_battery: PowerOnBattery,
+    pub power_charging: PowerCharging,
+    pub power_low_battery: PowerLowBattery,
+
+    // Idle
+    pub idle_started: IdleStarted,
+    pub idle_resumed: IdleResumed,
 }
 
 impl SignalState {
@@ -74,21 +109,42 @@ impl SignalState {
         self.output_move.clear();
         self.output_pointer_enter.clear();
         self.output_pointer_leave.clear();
+        self.output_pointer_edge.clear();
         self.output_focused.clear();
+        self.layer_focused.clear();
+        self.layer_unfocused.clear();
+        self.output_orientation_changed.clear();
+        self.output_fullscreen_changed.clear();
+        self.output_stacking_changed.clear();
+        self.output_no_active_tags.clear();
 
         self.window_pointer_enter.clear();
         self.window_pointer_leave.clear();
         self.window_focused.clear();
         self.window_title_changed.clear();
+        self.window_focused_title_changed.clear();
         self.window_layout_changed.clear();
         self.window_created.clear();
         self.window_destroyed.clear();
+        self.window_unresponsive.clear();
+        self.window_minimized.clear();
+        self.window_urgency_changed.clear();
+        self.window_popup_grab_blocked_keybind.clear();
 
         self.tag_active.clear();
         self.tag_created.clear();
         self.tag_removed.clear();
+        self.tag_urgency_changed.clear();
 
         self.input_device_added.clear();
+        self.input_accessibility_changed.clear();
+
+        self.power_on_battery.clear();
+        self.power_charging.clear();
+        self.power_low_battery.clear();
+
+        self.idle_started.clear();
+        self.idle_resumed.clear();
     }
 }
 
@@ -102,6 +158,8 @@ struct SignalInstance<T> {
     sender: Sender<Result<T, Status>>,
     ready: bool,
     buffer: VecDeque<T>,
+    /// The namespace of the client that connected, or `None` for the config.
+    namespace: Option<String>,
 }
 
 pub trait Signal {
@@ -128,7 +186,7 @@ impl Signal for OutputConnect {
     }
 
     fn clear(&mut self) {
-        self.v1.instances.clear();
+        self.v1.clear();
     }
 }
 
@@ -149,7 +207,7 @@ impl Signal for OutputDisconnect {
     }
 
     fn clear(&mut self) {
-        self.v1.instances.clear();
+        self.v1.clear();
     }
 }
 
@@ -174,7 +232,7 @@ impl Signal for OutputResize {
     }
 
     fn clear(&mut self) {
-        self.v1.instances.clear();
+        self.v1.clear();
     }
 }
 
@@ -197,7 +255,7 @@ impl Signal for OutputMove {
     }
 
     fn clear(&mut self) {
-        self.v1.instances.clear();
+        self.v1.clear();
     }
 }
 
@@ -218,7 +276,7 @@ impl Signal for OutputPointerEnter {
     }
 
     fn clear(&mut self) {
-        self.v1.instances.clear();
+        self.v1.clear();
     }
 }
 
@@ -239,7 +297,40 @@ impl Signal for OutputPointerLeave {
     }
 
     fn clear(&mut self) {
-        self.v1.instances.clear();
+        self.v1.clear();
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct OutputPointerEdge {
+    v1: SignalData<OutputPointerEdgeResponse>,
+}
+
+impl Signal for OutputPointerEdge {
+    type Args<'a> = (&'a Output, ScreenEdge);
+
+    fn signal(&mut self, (output, edge): Self::Args<'_>) {
+        let edge = match edge {
+            ScreenEdge::Top => output::v1::ScreenEdge::Top,
+            ScreenEdge::Bottom => output::v1::ScreenEdge::Bottom,
+            ScreenEdge::Left => output::v1::ScreenEdge::Left,
+            ScreenEdge::Right => output::v1::ScreenEdge::Right,
+            ScreenEdge::TopLeft => output::v1::ScreenEdge::TopLeft,
+            ScreenEdge::TopRight => output::v1::ScreenEdge::TopRight,
+            ScreenEdge::BottomLeft => output::v1::ScreenEdge::BottomLeft,
+            ScreenEdge::BottomRight => output::v1::ScreenEdge::BottomRight,
+        };
+
+        self.v1.signal(|buf| {
+            buf.push_back(OutputPointerEdgeResponse {
+                output_name: output.name(),
+                edge: edge.into(),
+            });
+        });
+    }
+
+    fn clear(&mut self) {
+        self.v1.clear();
     }
 }
 
@@ -260,7 +351,189 @@ impl Signal for OutputFocused {
     }
 
     fn clear(&mut self) {
-        self.v1.instances.clear();
+        self.v1.clear();
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct LayerFocused {
+    v1: SignalData<OutputLayerFocusedResponse>,
+}
+
+impl Signal for LayerFocused {
+    type Args<'a> = (&'a Output, &'a LayerSurface);
+
+    fn signal(&mut self, (output, layer): Self::Args<'_>) {
+        self.v1.signal(|buf| {
+            buf.push_back(OutputLayerFocusedResponse {
+                output_name: output.name(),
+                namespace: layer.namespace().to_string(),
+            });
+        });
+    }
+
+    fn clear(&mut self) {
+        self.v1.clear();
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct LayerUnfocused {
+    v1: SignalData<OutputLayerUnfocusedResponse>,
+}
+
+impl Signal for LayerUnfocused {
+    type Args<'a> = (&'a Output, &'a LayerSurface);
+
+    fn signal(&mut self, (output, layer): Self::Args<'_>) {
+        self.v1.signal(|buf| {
+            buf.push_back(OutputLayerUnfocusedResponse {
+                output_name: output.name(),
+                namespace: layer.namespace().to_string(),
+            });
+        });
+    }
+
+    fn clear(&mut self) {
+        self.v1.clear();
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct OutputFullscreenChanged {
+    v1: SignalData<signal::v1::OutputFullscreenChangedResponse>,
+    /// The last fullscreen window sent for each output.
+    last: HashMap<String, u32>,
+}
+
+impl Signal for OutputFullscreenChanged {
+    type Args<'a> = (&'a Output, Option<&'a WindowElement>);
+
+    fn signal(&mut self, (output, window): Self::Args<'_>) {
+        let output_name = output.name();
+        let window_id = window.map(|window| window.with_state(|state| state.id.0));
+
+        if self.last.get(&output_name).copied() == window_id {
+            return;
+        }
+
+        match window_id {
+            Some(window_id) => self.last.insert(output_name.clone(), window_id),
+            None => self.last.remove(&output_name),
+        };
+
+        self.v1.signal(|buf| {
+            buf.push_back(signal::v1::OutputFullscreenChangedResponse {
+                output_name: output_name.clone(),
+                window_id,
+            });
+        });
+    }
+
+    fn clear(&mut self) {
+        self.v1.clear();
+        self.last.clear();
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct OutputStackingChanged {
+    v1: SignalData<signal::v1::OutputStackingChangedResponse>,
+    /// The last stacking order sent for each output.
+    last: HashMap<String, Vec<u32>>,
+}
+
+impl Signal for OutputStackingChanged {
+    type Args<'a> = (&'a Output, Vec<u32>);
+
+    fn signal(&mut self, (output, window_ids): Self::Args<'_>) {
+        let output_name = output.name();
+
+        if self.last.get(&output_name) == Some(&window_ids) {
+            return;
+        }
+
+        self.last.insert(output_name.clone(), window_ids.clone());
+
+        self.v1.signal(|buf| {
+            buf.push_back(signal::v1::OutputStackingChangedResponse {
+                output_name: output_name.clone(),
+                window_ids: window_ids.clone(),
+            });
+        });
+    }
+
+    fn clear(&mut self) {
+        self.v1.clear();
+        self.last.clear();
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct OutputNoActiveTags {
+    v1: SignalData<signal::v1::OutputNoActiveTagsResponse>,
+    /// Outputs that had no active tags when last signaled.
+    empty: HashSet<String>,
+}
+
+impl Signal for OutputNoActiveTags {
+    /// The output and whether it has no active tags.
+    type Args<'a> = (&'a Output, bool);
+
+    fn signal(&mut self, (output, no_active_tags): Self::Args<'_>) {
+        let output_name = output.name();
+
+        if !no_active_tags {
+            self.empty.remove(&output_name);
+            return;
+        }
+
+        if !self.empty.insert(output_name.clone()) {
+            return;
+        }
+
+        self.v1.signal(|buf| {
+            buf.push_back(signal::v1::OutputNoActiveTagsResponse {
+                output_name: output_name.clone(),
+            });
+        });
+    }
+
+    fn clear(&mut self) {
+        self.v1.clear();
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct OutputOrientationChanged {
+    v1: SignalData<OutputOrientationChangedResponse>,
+}
+
+impl Signal for OutputOrientationChanged {
+    type Args<'a> = (&'a Output, Transform);
+
+    fn signal(&mut self, (output, transform): Self::Args<'_>) {
+        let transform = match transform {
+            Transform::Normal => output::v1::Transform::Normal,
+            Transform::_90 => output::v1::Transform::Transform90,
+            Transform::_180 => output::v1::Transform::Transform180,
+            Transform::_270 => output::v1::Transform::Transform270,
+            Transform::Flipped => output::v1::Transform::Flipped,
+            Transform::Flipped90 => output::v1::Transform::Flipped90,
+            Transform::Flipped180 => output::v1::Transform::Flipped180,
+            Transform::Flipped270 => output::v1::Transform::Flipped270,
+        };
+
+        self.v1.signal(|buf| {
+            buf.push_back(OutputOrientationChangedResponse {
+                output_name: output.name(),
+                transform: transform.into(),
+            });
+        });
+    }
+
+    fn clear(&mut self) {
+        self.v1.clear();
     }
 }
 
@@ -281,7 +554,7 @@ impl Signal for WindowPointerEnter {
     }
 
     fn clear(&mut self) {
-        self.v1.instances.clear();
+        self.v1.clear();
     }
 }
 
@@ -302,7 +575,7 @@ impl Signal for WindowPointerLeave {
     }
 
     fn clear(&mut self) {
-        self.v1.instances.clear();
+        self.v1.clear();
     }
 }
 
@@ -312,18 +585,27 @@ pub struct WindowFocused {
 }
 
 impl Signal for WindowFocused {
-    type Args<'a> = &'a WindowElement;
+    type Args<'a> = (&'a WindowElement, FocusReason, u64);
 
-    fn signal(&mut self, window: Self::Args<'_>) {
+    fn signal(&mut self, (window, reason, serial): Self::Args<'_>) {
         self.v1.signal(|buf| {
             buf.push_back(signal::v1::WindowFocusedResponse {
                 window_id: window.with_state(|state| state.id.0),
+                reason: match reason {
+                    FocusReason::Pointer => window::v1::FocusReason::Pointer,
+                    FocusReason::Keybind => window::v1::FocusReason::Keybind,
+                    FocusReason::Activation => window::v1::FocusReason::Activation,
+                    FocusReason::Map => window::v1::FocusReason::Map,
+                    FocusReason::UnmapFallback => window::v1::FocusReason::UnmapFallback,
+                }
+                .into(),
+                serial,
             });
         });
     }
 
     fn clear(&mut self) {
-        self.v1.instances.clear();
+        self.v1.clear();
     }
 }
 
@@ -345,7 +627,40 @@ impl Signal for WindowTitleChanged {
     }
 
     fn clear(&mut self) {
-        self.v1.instances.clear();
+        self.v1.clear();
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct WindowFocusedTitleChanged {
+    v1: SignalData<signal::v1::WindowFocusedTitleChangedResponse>,
+    last: Option<signal::v1::WindowFocusedTitleChangedResponse>,
+}
+
+impl Signal for WindowFocusedTitleChanged {
+    type Args<'a> = Option<&'a WindowElement>;
+
+    fn signal(&mut self, window: Self::Args<'_>) {
+        let response = signal::v1::WindowFocusedTitleChangedResponse {
+            window_id: window.map(|window| window.with_state(|state| state.id.0)),
+            title: window.and_then(|window| window.title()).unwrap_or_default(),
+            app_id: window.and_then(|window| window.class()).unwrap_or_default(),
+        };
+
+        if self.last.as_ref() == Some(&response) {
+            return;
+        }
+
+        self.last = Some(response.clone());
+
+        self.v1.signal(|buf| {
+            buf.push_back(response);
+        });
+    }
+
+    fn clear(&mut self) {
+        self.v1.clear();
+        self.last = None;
     }
 }
 
@@ -375,7 +690,7 @@ impl Signal for WindowLayoutChanged {
     }
 
     fn clear(&mut self) {
-        self.v1.instances.clear();
+        self.v1.clear();
     }
 }
 
@@ -396,7 +711,7 @@ impl Signal for WindowCreated {
     }
 
     fn clear(&mut self) {
-        self.v1.instances.clear();
+        self.v1.clear();
     }
 }
 
@@ -419,7 +734,93 @@ impl Signal for WindowDestroyed {
     }
 
     fn clear(&mut self) {
-        self.v1.instances.clear();
+        self.v1.clear();
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct WindowUnresponsive {
+    v1: SignalData<signal::v1::WindowUnresponsiveResponse>,
+}
+
+impl Signal for WindowUnresponsive {
+    type Args<'a> = &'a WindowElement;
+
+    fn signal(&mut self, window: Self::Args<'_>) {
+        self.v1.signal(|buf| {
+            buf.push_back(signal::v1::WindowUnresponsiveResponse {
+                window_id: window.with_state(|state| state.id.0),
+            });
+        });
+    }
+
+    fn clear(&mut self) {
+        self.v1.clear();
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct WindowMinimized {
+    v1: SignalData<signal::v1::WindowMinimizedResponse>,
+}
+
+impl Signal for WindowMinimized {
+    type Args<'a> = (&'a WindowElement, bool);
+
+    fn signal(&mut self, (window, minimized): Self::Args<'_>) {
+        self.v1.signal(|buf| {
+            buf.push_back(signal::v1::WindowMinimizedResponse {
+                window_id: window.with_state(|state| state.id.0),
+                minimized,
+            });
+        });
+    }
+
+    fn clear(&mut self) {
+        self.v1.clear();
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct WindowUrgencyChanged {
+    v1: SignalData<signal::v1::WindowUrgencyChangedResponse>,
+}
+
+impl Signal for WindowUrgencyChanged {
+    type Args<'a> = (&'a WindowElement, bool);
+
+    fn signal(&mut self, (window, demands_attention): Self::Args<'_>) {
+        self.v1.signal(|buf| {
+            buf.push_back(signal::v1::WindowUrgencyChangedResponse {
+                window_id: window.with_state(|state| state.id.0),
+                demands_attention,
+            });
+        });
+    }
+
+    fn clear(&mut self) {
+        self.v1.clear();
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct WindowPopupGrabBlockedKeybind {
+    v1: SignalData<signal::v1::WindowPopupGrabBlockedKeybindResponse>,
+}
+
+impl Signal for WindowPopupGrabBlockedKeybind {
+    type Args<'a> = &'a WindowElement;
+
+    fn signal(&mut self, window: Self::Args<'_>) {
+        self.v1.signal(|buf| {
+            buf.push_back(signal::v1::WindowPopupGrabBlockedKeybindResponse {
+                window_id: window.with_state(|state| state.id.0),
+            });
+        });
+    }
+
+    fn clear(&mut self) {
+        self.v1.clear();
     }
 }
 
@@ -441,7 +842,7 @@ impl Signal for TagActive {
     }
 
     fn clear(&mut self) {
-        self.v1.instances.clear();
+        self.v1.clear();
     }
 }
 
@@ -462,7 +863,7 @@ impl Signal for TagCreated {
     }
 
     fn clear(&mut self) {
-        self.v1.instances.clear();
+        self.v1.clear();
     }
 }
 
@@ -483,7 +884,29 @@ impl Signal for TagRemoved {
     }
 
     fn clear(&mut self) {
-        self.v1.instances.clear();
+        self.v1.clear();
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct TagUrgencyChanged {
+    v1: SignalData<signal::v1::TagUrgencyChangedResponse>,
+}
+
+impl Signal for TagUrgencyChanged {
+    type Args<'a> = &'a Tag;
+
+    fn signal(&mut self, tag: Self::Args<'_>) {
+        self.v1.signal(|buf| {
+            buf.push_back(signal::v1::TagUrgencyChangedResponse {
+                tag_id: tag.id().to_inner(),
+                urgent: tag.urgent(),
+            });
+        });
+    }
+
+    fn clear(&mut self) {
+        self.v1.clear();
     }
 }
 
@@ -504,7 +927,127 @@ impl Signal for InputDeviceAdded {
     }
 
     fn clear(&mut self) {
-        self.v1.instances.clear();
+        self.v1.clear();
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct InputAccessibilityChanged {
+    v1: SignalData<signal::v1::InputAccessibilityChangedResponse>,
+}
+
+impl Signal for InputAccessibilityChanged {
+    type Args<'a> = &'a AccessibilityState;
+
+    fn signal(&mut self, accessibility: Self::Args<'_>) {
+        self.v1.signal(|buf| {
+            buf.push_back(signal::v1::InputAccessibilityChangedResponse {
+                sticky_keys: accessibility.sticky_keys,
+                slow_keys_delay_millis: accessibility
+                    .slow_keys
+                    .map(|delay| delay.as_millis() as u32),
+            });
+        });
+    }
+
+    fn clear(&mut self) {
+        self.v1.clear();
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct PowerOnBattery {
+    v1: SignalData<PowerOnBatteryResponse>,
+}
+
+impl Signal for PowerOnBattery {
+    type Args<'a> = bool;
+
+    fn signal(&mut self, on_battery: Self::Args<'_>) {
+        self.v1.signal(|buf| {
+            buf.push_back(PowerOnBatteryResponse { on_battery });
+        });
+    }
+
+    fn clear(&mut self) {
+        self.v1.clear();
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct PowerCharging {
+    v1: SignalData<PowerChargingResponse>,
+}
+
+impl Signal for PowerCharging {
+    type Args<'a> = bool;
+
+    fn signal(&mut self, charging: Self::Args<'_>) {
+        self.v1.signal(|buf| {
+            buf.push_back(PowerChargingResponse { charging });
+        });
+    }
+
+    fn clear(&mut self) {
+        self.v1.clear();
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct PowerLowBattery {
+    v1: SignalData<PowerLowBatteryResponse>,
+}
+
+impl Signal for PowerLowBattery {
+    /// Args: the battery percentage
+    type Args<'a> = f64;
+
+    fn signal(&mut self, percentage: Self::Args<'_>) {
+        self.v1.signal(|buf| {
+            buf.push_back(PowerLowBatteryResponse { percentage });
+        });
+    }
+
+    fn clear(&mut self) {
+        self.v1.clear();
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct IdleStarted {
+    v1: SignalData<IdleStartedResponse>,
+}
+
+impl Signal for IdleStarted {
+    type Args<'a> = ();
+
+    fn signal(&mut self, _args: Self::Args<'_>) {
+        self.v1.signal(|buf| {
+            buf.push_back(IdleStartedResponse {});
+        });
+    }
+
+    fn clear(&mut self) {
+        self.v1.clear();
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct IdleResumed {
+    v1: SignalData<IdleResumedResponse>,
+}
+
+impl Signal for IdleResumed {
+    type Args<'a> = ();
+
+    fn signal(&mut self, _args: Self::Args<'_>) {
+        self.v1.signal(|buf| {
+            buf.push_back(IdleResumedResponse {});
+        });
+    }
+
+    fn clear(&mut self) {
+        self.v1.clear();
     }
 }
 
@@ -536,17 +1079,32 @@ impl<T> SignalData<T> {
         })
     }
 
-    fn connect(&mut self, id: ClientSignalId, sender: Sender<Result<T, Status>>) {
+    fn connect(
+        &mut self,
+        id: ClientSignalId,
+        sender: Sender<Result<T, Status>>,
+        namespace: Option<String>,
+    ) {
         self.instances.insert(
             id,
             SignalInstance {
                 sender,
                 ready: true,
                 buffer: Default::default(),
+                namespace,
             },
         );
     }
 
+    /// Disconnects all instances connected by the config.
+    ///
+    /// Instances of namespaced clients stay connected, as those clients
+    /// aren't restarted with the config.
+    fn clear(&mut self) {
+        self.instances
+            .retain(|_, instance| instance.namespace.is_some());
+    }
+
     fn disconnect(&mut self, id: ClientSignalId) {
         self.instances.remove(&id);
     }
@@ -572,7 +1130,7 @@ impl<T> SignalData<T> {
 
 fn start_signal_stream<I, O, F>(
     sender: StateFnSender,
-    in_stream: Streaming<I>,
+    request: Request<Streaming<I>>,
     signal_data_selector: F,
 ) -> Result<Response<ResponseStream<O>>, Status>
 where
@@ -584,9 +1142,11 @@ where
 
     let client_signal_id = CLIENT_SIGNAL_ID_COUNTER.fetch_add(1, Ordering::Relaxed);
 
+    let pid = client_pid(&request);
+
     run_bidirectional_streaming(
         sender,
-        in_stream,
+        request.into_inner(),
         move |state, request| {
             let signal = signal_data_selector(state);
             match request.control() {
@@ -596,8 +1156,9 @@ where
             }
         },
         move |state, sender, _join_handle| {
+            let namespace = state.pinnacle.namespace_state.namespace_of(pid);
             let signal = signal_data_selector_clone(state);
-            signal.connect(client_signal_id, sender);
+            signal.connect(client_signal_id, sender, namespace);
         },
     )
 }
@@ -620,29 +1181,51 @@ impl signal::v1::signal_service_server::SignalService for SignalService {
     type OutputMoveStream = ResponseStream<OutputMoveResponse>;
     type OutputPointerEnterStream = ResponseStream<OutputPointerEnterResponse>;
     type OutputPointerLeaveStream = ResponseStream<OutputPointerLeaveResponse>;
+    type OutputPointerEdgeStream = ResponseStream<OutputPointerEdgeResponse>;
     type OutputFocusedStream = ResponseStream<OutputFocusedResponse>;
+    type OutputLayerFocusedStream = ResponseStream<OutputLayerFocusedResponse>;
+    type OutputLayerUnfocusedStream = ResponseStream<OutputLayerUnfocusedResponse>;
+    type OutputOrientationChangedStream = ResponseStream<OutputOrientationChangedResponse>;
+    type OutputFullscreenChangedStream =
+        ResponseStream<signal::v1::OutputFullscreenChangedResponse>;
+    type OutputStackingChangedStream = ResponseStream<signal::v1::OutputStackingChangedResponse>;
+    type OutputNoActiveTagsStream = ResponseStream<signal::v1::OutputNoActiveTagsResponse>;
 
     type WindowPointerEnterStream = ResponseStream<WindowPointerEnterResponse>;
     type WindowPointerLeaveStream = ResponseStream<WindowPointerLeaveResponse>;
     type WindowFocusedStream = ResponseStream<WindowFocusedResponse>;
     type WindowTitleChangedStream = ResponseStream<WindowTitleChangedResponse>;
+    type WindowFocusedTitleChangedStream =
+        ResponseStream<signal::v1::WindowFocusedTitleChangedResponse>;
     type WindowLayoutModeChangedStream = ResponseStream<WindowLayoutModeChangedResponse>;
     type WindowCreatedStream = ResponseStream<WindowCreatedResponse>;
     type WindowDestroyedStream = ResponseStream<WindowDestroyedResponse>;
+    type WindowUnresponsiveStream = ResponseStream<WindowUnresponsiveResponse>;
+    type WindowMinimizedStream = ResponseStream<signal::v1::WindowMinimizedResponse>;
+    type WindowUrgencyChangedStream = ResponseStream<signal::v1::WindowUrgencyChangedResponse>;
+    type WindowPopupGrabBlockedKeybindStream =
+        ResponseStream<WindowPopupGrabBlockedKeybindResponse>;
 
     type TagActiveStream = ResponseStream<TagActiveResponse>;
     type TagCreatedStream = ResponseStream<TagCreatedResponse>;
     type TagRemovedStream = ResponseStream<TagRemovedResponse>;
+    type TagUrgencyChangedStream = ResponseStream<signal::v1::TagUrgencyChangedResponse>;
 
     type InputDeviceAddedStream = ResponseStream<InputDeviceAddedResponse>;
+    type InputAccessibilityChangedStream = ResponseStream<InputAccessibilityChangedResponse>;
+
+    type PowerOnBatteryStream = ResponseStream<PowerOnBatteryResponse>;
+    type PowerChargingStream = ResponseStream<PowerChargingResponse>;
+    type PowerLowBatteryStream = ResponseStream<PowerLowBatteryResponse>;
+
+    type IdleStartedStream = ResponseStream<IdleStartedResponse>;
+    type IdleResumedStream = ResponseStream<IdleResumedResponse>;
 
     async fn output_connect(
         &self,
         request: Request<Streaming<OutputConnectRequest>>,
     ) -> Result<Response<Self::OutputConnectStream>, Status> {
-        let in_stream = request.into_inner();
-
-        start_signal_stream(self.sender.clone(), in_stream, |state| {
+        start_signal_stream(self.sender.clone(), request, |state| {
             &mut state.pinnacle.signal_state.output_connect.v1
         })
     }
@@ -651,9 +1234,7 @@ impl signal::v1::signal_service_server::SignalService for SignalService {
         &self,
         request: Request<Streaming<OutputDisconnectRequest>>,
     ) -> Result<Response<Self::OutputDisconnectStream>, Status> {
-        let in_stream = request.into_inner();
-
-        start_signal_stream(self.sender.clone(), in_stream, |state| {
+        start_signal_stream(self.sender.clone(), request, |state| {
             &mut state.pinnacle.signal_state.output_disconnect.v1
         })
     }
@@ -662,9 +1243,7 @@ impl signal::v1::signal_service_server::SignalService for SignalService {
         &self,
         request: Request<Streaming<OutputResizeRequest>>,
     ) -> Result<Response<Self::OutputResizeStream>, Status> {
-        let in_stream = request.into_inner();
-
-        start_signal_stream(self.sender.clone(), in_stream, |state| {
+        start_signal_stream(self.sender.clone(), request, |state| {
             &mut state.pinnacle.signal_state.output_resize.v1
         })
     }
@@ -673,9 +1252,7 @@ impl signal::v1::signal_service_server::SignalService for SignalService {
         &self,
         request: Request<Streaming<OutputMoveRequest>>,
     ) -> Result<Response<Self::OutputMoveStream>, Status> {
-        let in_stream = request.into_inner();
-
-        start_signal_stream(self.sender.clone(), in_stream, |state| {
+        start_signal_stream(self.sender.clone(), request, |state| {
             &mut state.pinnacle.signal_state.output_move.v1
         })
     }
@@ -684,9 +1261,7 @@ impl signal::v1::signal_service_server::SignalService for SignalService {
         &self,
         request: Request<Streaming<OutputPointerEnterRequest>>,
     ) -> Result<Response<Self::OutputPointerEnterStream>, Status> {
-        let in_stream = request.into_inner();
-
-        start_signal_stream(self.sender.clone(), in_stream, |state| {
+        start_signal_stream(self.sender.clone(), request, |state| {
             &mut state.pinnacle.signal_state.output_pointer_enter.v1
         })
     }
@@ -695,31 +1270,88 @@ impl signal::v1::signal_service_server::SignalService for SignalService {
         &self,
         request: Request<Streaming<OutputPointerLeaveRequest>>,
     ) -> Result<Response<Self::OutputPointerLeaveStream>, Status> {
-        let in_stream = request.into_inner();
-
-        start_signal_stream(self.sender.clone(), in_stream, |state| {
+        start_signal_stream(self.sender.clone(), request, |state| {
             &mut state.pinnacle.signal_state.output_pointer_leave.v1
         })
     }
 
+    async fn output_pointer_edge(
+        &self,
+        request: Request<Streaming<OutputPointerEdgeRequest>>,
+    ) -> Result<Response<Self::OutputPointerEdgeStream>, Status> {
+        start_signal_stream(self.sender.clone(), request, |state| {
+            &mut state.pinnacle.signal_state.output_pointer_edge.v1
+        })
+    }
+
     async fn output_focused(
         &self,
         request: Request<Streaming<OutputFocusedRequest>>,
     ) -> Result<Response<Self::OutputFocusedStream>, Status> {
-        let in_stream = request.into_inner();
-
-        start_signal_stream(self.sender.clone(), in_stream, |state| {
+        start_signal_stream(self.sender.clone(), request, |state| {
             &mut state.pinnacle.signal_state.output_focused.v1
         })
     }
 
+    async fn output_layer_focused(
+        &self,
+        request: Request<Streaming<OutputLayerFocusedRequest>>,
+    ) -> Result<Response<Self::OutputLayerFocusedStream>, Status> {
+        start_signal_stream(self.sender.clone(), request, |state| {
+            &mut state.pinnacle.signal_state.layer_focused.v1
+        })
+    }
+
+    async fn output_layer_unfocused(
+        &self,
+        request: Request<Streaming<OutputLayerUnfocusedRequest>>,
+    ) -> Result<Response<Self::OutputLayerUnfocusedStream>, Status> {
+        start_signal_stream(self.sender.clone(), request, |state| {
+            &mut state.pinnacle.signal_state.layer_unfocused.v1
+        })
+    }
+
+    async fn output_orientation_changed(
+        &self,
+        request: Request<Streaming<OutputOrientationChangedRequest>>,
+    ) -> Result<Response<Self::OutputOrientationChangedStream>, Status> {
+        start_signal_stream(self.sender.clone(), request, |state| {
+            &mut state.pinnacle.signal_state.output_orientation_changed.v1
+        })
+    }
+
+    async fn output_fullscreen_changed(
+        &self,
+        request: Request<Streaming<signal::v1::OutputFullscreenChangedRequest>>,
+    ) -> Result<Response<Self::OutputFullscreenChangedStream>, Status> {
+        start_signal_stream(self.sender.clone(), request, |state| {
+            &mut state.pinnacle.signal_state.output_fullscreen_changed.v1
+        })
+    }
+
+    async fn output_stacking_changed(
+        &self,
+        request: Request<Streaming<signal::v1::OutputStackingChangedRequest>>,
+    ) -> Result<Response<Self::OutputStackingChangedStream>, Status> {
+        start_signal_stream(self.sender.clone(), request, |state| {
+            &mut state.pinnacle.signal_state.output_stacking_changed.v1
+        })
+    }
+
+    async fn output_no_active_tags(
+        &self,
+        request: Request<Streaming<signal::v1::OutputNoActiveTagsRequest>>,
+    ) -> Result<Response<Self::OutputNoActiveTagsStream>, Status> {
+        start_signal_stream(self.sender.clone(), request, |state| {
+            &mut state.pinnacle.signal_state.output_no_active_tags.v1
+        })
+    }
+
     async fn window_pointer_enter(
         &self,
         request: Request<Streaming<WindowPointerEnterRequest>>,
     ) -> Result<Response<Self::WindowPointerEnterStream>, Status> {
-        let in_stream = request.into_inner();
-
-        start_signal_stream(self.sender.clone(), in_stream, |state| {
+        start_signal_stream(self.sender.clone(), request, |state| {
             &mut state.pinnacle.signal_state.window_pointer_enter.v1
         })
     }
@@ -728,9 +1360,7 @@ impl signal::v1::signal_service_server::SignalService for SignalService {
         &self,
         request: Request<Streaming<WindowPointerLeaveRequest>>,
     ) -> Result<Response<Self::WindowPointerLeaveStream>, Status> {
-        let in_stream = request.into_inner();
-
-        start_signal_stream(self.sender.clone(), in_stream, |state| {
+        start_signal_stream(self.sender.clone(), request, |state| {
             &mut state.pinnacle.signal_state.window_pointer_leave.v1
         })
     }
@@ -739,9 +1369,7 @@ impl signal::v1::signal_service_server::SignalService for SignalService {
         &self,
         request: Request<Streaming<WindowFocusedRequest>>,
     ) -> Result<Response<Self::WindowFocusedStream>, Status> {
-        let in_stream = request.into_inner();
-
-        start_signal_stream(self.sender.clone(), in_stream, |state| {
+        start_signal_stream(self.sender.clone(), request, |state| {
             &mut state.pinnacle.signal_state.window_focused.v1
         })
     }
@@ -750,20 +1378,25 @@ impl signal::v1::signal_service_server::SignalService for SignalService {
         &self,
         request: Request<Streaming<WindowTitleChangedRequest>>,
     ) -> Result<Response<Self::WindowTitleChangedStream>, Status> {
-        let in_stream = request.into_inner();
-
-        start_signal_stream(self.sender.clone(), in_stream, |state| {
+        start_signal_stream(self.sender.clone(), request, |state| {
             &mut state.pinnacle.signal_state.window_title_changed.v1
         })
     }
 
+    async fn window_focused_title_changed(
+        &self,
+        request: Request<Streaming<signal::v1::WindowFocusedTitleChangedRequest>>,
+    ) -> Result<Response<Self::WindowFocusedTitleChangedStream>, Status> {
+        start_signal_stream(self.sender.clone(), request, |state| {
+            &mut state.pinnacle.signal_state.window_focused_title_changed.v1
+        })
+    }
+
     async fn window_layout_mode_changed(
         &self,
         request: Request<Streaming<WindowLayoutModeChangedRequest>>,
     ) -> Result<Response<Self::WindowLayoutModeChangedStream>, Status> {
-        let in_stream = request.into_inner();
-
-        start_signal_stream(self.sender.clone(), in_stream, |state| {
+        start_signal_stream(self.sender.clone(), request, |state| {
             &mut state.pinnacle.signal_state.window_layout_changed.v1
         })
     }
@@ -772,9 +1405,7 @@ impl signal::v1::signal_service_server::SignalService for SignalService {
         &self,
         request: Request<Streaming<WindowCreatedRequest>>,
     ) -> Result<Response<Self::WindowCreatedStream>, Status> {
-        let in_stream = request.into_inner();
-
-        start_signal_stream(self.sender.clone(), in_stream, |state| {
+        start_signal_stream(self.sender.clone(), request, |state| {
             &mut state.pinnacle.signal_state.window_created.v1
         })
     }
@@ -783,20 +1414,56 @@ impl signal::v1::signal_service_server::SignalService for SignalService {
         &self,
         request: Request<Streaming<WindowDestroyedRequest>>,
     ) -> Result<Response<Self::WindowDestroyedStream>, Status> {
-        let in_stream = request.into_inner();
-
-        start_signal_stream(self.sender.clone(), in_stream, |state| {
+        start_signal_stream(self.sender.clone(), request, |state| {
             &mut state.pinnacle.signal_state.window_destroyed.v1
         })
     }
 
+    async fn window_unresponsive(
+        &self,
+        request: Request<Streaming<WindowUnresponsiveRequest>>,
+    ) -> Result<Response<Self::WindowUnresponsiveStream>, Status> {
+        start_signal_stream(self.sender.clone(), request, |state| {
+            &mut state.pinnacle.signal_state.window_unresponsive.v1
+        })
+    }
+
+    async fn window_minimized(
+        &self,
+        request: Request<Streaming<signal::v1::WindowMinimizedRequest>>,
+    ) -> Result<Response<Self::WindowMinimizedStream>, Status> {
+        start_signal_stream(self.sender.clone(), request, |state| {
+            &mut state.pinnacle.signal_state.window_minimized.v1
+        })
+    }
+
+    async fn window_urgency_changed(
+        &self,
+        request: Request<Streaming<signal::v1::WindowUrgencyChangedRequest>>,
+    ) -> Result<Response<Self::WindowUrgencyChangedStream>, Status> {
+        start_signal_stream(self.sender.clone(), request, |state| {
+            &mut state.pinnacle.signal_state.window_urgency_changed.v1
+        })
+    }
+
+    async fn window_popup_grab_blocked_keybind(
+        &self,
+        request: Request<Streaming<WindowPopupGrabBlockedKeybindRequest>>,
+    ) -> Result<Response<Self::WindowPopupGrabBlockedKeybindStream>, Status> {
+        start_signal_stream(self.sender.clone(), request, |state| {
+            &mut state
+                .pinnacle
+                .signal_state
+                .window_popup_grab_blocked_keybind
+                .v1
+        })
+    }
+
     async fn tag_active(
         &self,
         request: Request<Streaming<TagActiveRequest>>,
     ) -> Result<Response<Self::TagActiveStream>, Status> {
-        let in_stream = request.into_inner();
-
-        start_signal_stream(self.sender.clone(), in_stream, |state| {
+        start_signal_stream(self.sender.clone(), request, |state| {
             &mut state.pinnacle.signal_state.tag_active.v1
         })
     }
@@ -805,9 +1472,7 @@ impl signal::v1::signal_service_server::SignalService for SignalService {
         &self,
         request: Request<Streaming<TagCreatedRequest>>,
     ) -> Result<Response<Self::TagCreatedStream>, Status> {
-        let in_stream = request.into_inner();
-
-        start_signal_stream(self.sender.clone(), in_stream, |state| {
+        start_signal_stream(self.sender.clone(), request, |state| {
             &mut state.pinnacle.signal_state.tag_created.v1
         })
     }
@@ -816,21 +1481,80 @@ impl signal::v1::signal_service_server::SignalService for SignalService {
         &self,
         request: Request<Streaming<TagRemovedRequest>>,
     ) -> Result<Response<Self::TagRemovedStream>, Status> {
-        let in_stream = request.into_inner();
-
-        start_signal_stream(self.sender.clone(), in_stream, |state| {
+        start_signal_stream(self.sender.clone(), request, |state| {
             &mut state.pinnacle.signal_state.tag_removed.v1
         })
     }
 
+    async fn tag_urgency_changed(
+        &self,
+        request: Request<Streaming<signal::v1::TagUrgencyChangedRequest>>,
+    ) -> Result<Response<Self::TagUrgencyChangedStream>, Status> {
+        start_signal_stream(self.sender.clone(), request, |state| {
+            &mut state.pinnacle.signal_state.tag_urgency_changed.v1
+        })
+    }
+
     async fn input_device_added(
         &self,
         request: Request<Streaming<InputDeviceAddedRequest>>,
     ) -> Result<Response<Self::InputDeviceAddedStream>, Status> {
-        let in_stream = request.into_inner();
-
-        start_signal_stream(self.sender.clone(), in_stream, |state| {
+        start_signal_stream(self.sender.clone(), request, |state| {
             &mut state.pinnacle.signal_state.input_device_added.v1
         })
     }
+
+    async fn input_accessibility_changed(
+        &self,
+        request: Request<Streaming<InputAccessibilityChangedRequest>>,
+    ) -> Result<Response<Self::InputAccessibilityChangedStream>, Status> {
+        start_signal_stream(self.sender.clone(), request, |state| {
+            &mut state.pinnacle.signal_state.input_accessibility_changed.v1
+        })
+    }
+
+    async fn power_on_battery(
+        &self,
+        request: Request<Streaming<PowerOnBatteryRequest>>,
+    ) -> Result<Response<Self::PowerOnBatteryStream>, Status> {
+        start_signal_stream(self.sender.clone(), request, |state| {
+            &mut state.pinnacle.signal_state.power_on_battery.v1
+        })
+    }
+
+    async fn power_charging(
+        &self,
+        request: Request<Streaming<PowerChargingRequest>>,
+    ) -> Result<Response<Self::PowerChargingStream>, Status> {
+        start_signal_stream(self.sender.clone(), request, |state| {
+            &mut state.pinnacle.signal_state.power_charging.v1
+        })
+    }
+
+    async fn power_low_battery(
+        &self,
+        request: Request<Streaming<PowerLowBatteryRequest>>,
+    ) -> Result<Response<Self::PowerLowBatteryStream>, Status> {
+        start_signal_stream(self.sender.clone(), request, |state| {
+            &mut state.pinnacle.signal_state.power_low_battery.v1
+        })
+    }
+
+    async fn idle_started(
+        &self,
+        request: Request<Streaming<IdleStartedRequest>>,
+    ) -> Result<Response<Self::IdleStartedStream>, Status> {
+        start_signal_stream(self.sender.clone(), request, |state| {
+            &mut state.pinnacle.signal_state.idle_started.v1
+        })
+    }
+
+    async fn idle_resumed(
+        &self,
+        request: Request<Streaming<IdleResumedRequest>>,
+    ) -> Result<Response<Self::IdleResumedStream>, Status> {
+        start_signal_stream(self.sender.clone(), request, |state| {
+            &mut state.pinnacle.signal_state.idle_resumed.v1
+        })
+    }
 }
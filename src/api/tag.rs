@@ -1,15 +1,28 @@
 pub mod v1;
 
-use std::mem;
+use std::{
+    collections::{HashMap, HashSet},
+    mem,
+};
 
 use indexmap::IndexSet;
+use pinnacle_api_defs::pinnacle::{
+    tag::v1::{PagerResponse, pager_response},
+    util,
+};
+use smithay::{
+    output::Output,
+    utils::{Logical, Rectangle},
+};
+use tokio::sync::mpsc::UnboundedSender;
+use tonic::Status;
 use tracing::warn;
 
 use crate::{
     output::OutputName,
-    state::{State, WithState},
-    tag::Tag,
-    window::{UnmappedState, window_state::WindowId},
+    state::{Pinnacle, State, WithState},
+    tag::{EmptyOutputPolicy, Tag},
+    window::{UnmappedState, WindowElement, ZIndexElement, window_state::WindowId},
 };
 
 use super::{StateFnSender, signal::Signal};
@@ -24,17 +37,24 @@ impl TagService {
     }
 }
 
+/// How many previously active sets of tags are remembered per output.
+const TAG_HISTORY_LEN: usize = 16;
+
 pub fn set_active(state: &mut State, tag: &Tag, set: Option<bool>) {
     let Some(output) = tag.output(&state.pinnacle) else {
         return;
     };
 
     let active = set.unwrap_or(!tag.active());
+    let previous = active_tags(&output);
 
     if tag.set_active(active) {
-        state.pinnacle.signal_state.tag_active.signal(tag);
+        signal_active(&mut state.pinnacle, tag);
     }
 
+    push_tag_history(&output, previous);
+
+    state.pinnacle.close_hidden_popups();
     state.pinnacle.update_xwayland_stacking_order();
 
     state.pinnacle.request_layout(&output);
@@ -47,22 +67,183 @@ pub fn switch_to(state: &mut State, tag: &Tag) {
         return;
     };
 
+    switch_to_tags(state, &output, std::slice::from_ref(tag));
+}
+
+/// Switches `output` back to the tags that were active before the last tag change.
+///
+/// Tags that were removed since are skipped. Returns the tags that were switched to,
+/// which are empty if there was nothing to go back to.
+pub fn switch_to_previous(state: &mut State, output: &Output) -> Vec<Tag> {
+    let previous = output.with_state_mut(|op_state| {
+        while let Some(tags) = op_state.tag_history.pop_back() {
+            let tags = tags
+                .into_iter()
+                .filter(|tag| !tag.defunct() && op_state.tags.contains(tag))
+                .collect::<Vec<_>>();
+
+            if !tags.is_empty() {
+                return tags;
+            }
+        }
+
+        Vec::new()
+    });
+
+    if !previous.is_empty() {
+        switch_to_tags(state, output, &previous);
+    }
+
+    previous
+}
+
+/// Activates exactly `tags` on `output`, remembering the previously active tags.
+fn switch_to_tags(state: &mut State, output: &Output, tags: &[Tag]) {
+    let previous = active_tags(output);
+
     output.with_state(|op_state| {
         for op_tag in op_state.tags.iter() {
             if op_tag.set_active(false) {
-                state.pinnacle.signal_state.tag_active.signal(op_tag);
+                signal_active(&mut state.pinnacle, op_tag);
             }
         }
-        if tag.set_active(true) {
-            state.pinnacle.signal_state.tag_active.signal(tag);
+        for tag in tags {
+            if tag.set_active(true) {
+                signal_active(&mut state.pinnacle, tag);
+            }
         }
     });
 
+    push_tag_history(output, previous);
+
+    state.pinnacle.close_hidden_popups();
     state.pinnacle.update_xwayland_stacking_order();
 
-    state.pinnacle.request_layout(&output);
+    state.pinnacle.request_layout(output);
 
-    state.schedule_render(&output);
+    state.schedule_render(output);
+}
+
+impl State {
+    /// Signals outputs that were left with no active tags and applies the
+    /// [`EmptyOutputPolicy`] to them.
+    ///
+    /// Outputs without tags, like ones waiting for the config to add them, are skipped.
+    pub fn refresh_empty_outputs(&mut self) {
+        let _span = tracy_client::span!("State::refresh_empty_outputs");
+
+        for output in self.pinnacle.outputs.clone() {
+            let live_tags = output.with_state(|op_state| {
+                op_state
+                    .tags
+                    .iter()
+                    .filter(|tag| !tag.defunct())
+                    .cloned()
+                    .collect::<Vec<_>>()
+            });
+
+            let no_active_tags = !live_tags.is_empty() && live_tags.iter().all(|tag| !tag.active());
+
+            self.pinnacle
+                .signal_state
+                .output_no_active_tags
+                .signal((&output, no_active_tags));
+
+            if !no_active_tags {
+                continue;
+            }
+
+            match self.pinnacle.config.empty_output_policy.clone() {
+                EmptyOutputPolicy::Blank => (),
+                EmptyOutputPolicy::ReactivateLast => {
+                    if switch_to_previous(self, &output).is_empty() {
+                        switch_to_tags(self, &output, &live_tags[..1]);
+                    }
+                }
+                EmptyOutputPolicy::Fallback(name) => {
+                    if let Some(tag) = live_tags.iter().find(|tag| tag.name() == name) {
+                        switch_to_tags(self, &output, std::slice::from_ref(tag));
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Signals that `tag` was activated or deactivated, clearing its urgency if activated.
+fn signal_active(pinnacle: &mut Pinnacle, tag: &Tag) {
+    pinnacle.signal_state.tag_active.signal(tag);
+
+    if tag.active() && tag.set_urgent(false) {
+        pinnacle.signal_state.tag_urgency_changed.signal(tag);
+    }
+}
+
+/// Marks `window` and its inactive tags as urgent because it requested attention.
+///
+/// The window stays urgent until it's focused or [`clear_urgent`] is called.
+pub fn mark_urgent(pinnacle: &mut Pinnacle, window: &WindowElement) {
+    pinnacle.pager_state.mark_dirty();
+
+    let (was_urgent, tags) = window.with_state_mut(|state| {
+        let was_urgent = std::mem::replace(&mut state.urgent, true);
+        (was_urgent, state.tags.clone())
+    });
+
+    if !was_urgent {
+        pinnacle
+            .signal_state
+            .window_urgency_changed
+            .signal((window, true));
+    }
+
+    for tag in tags.iter().filter(|tag| !tag.active()) {
+        if tag.set_urgent(true) {
+            pinnacle.signal_state.tag_urgency_changed.signal(tag);
+        }
+    }
+}
+
+/// Clears the urgency of `window`.
+///
+/// Tags it made urgent stay urgent until they're activated.
+pub fn clear_urgent(pinnacle: &mut Pinnacle, window: &WindowElement) {
+    pinnacle.pager_state.mark_dirty();
+
+    let was_urgent = window.with_state_mut(|state| std::mem::take(&mut state.urgent));
+
+    if was_urgent {
+        pinnacle
+            .signal_state
+            .window_urgency_changed
+            .signal((window, false));
+    }
+}
+
+fn active_tags(output: &Output) -> Vec<Tag> {
+    output.with_state(|op_state| {
+        op_state
+            .tags
+            .iter()
+            .filter(|tag| tag.active())
+            .cloned()
+            .collect()
+    })
+}
+
+/// Remembers `previous` as the tags that were active on `output`,
+/// unless they're still the active ones.
+fn push_tag_history(output: &Output, previous: Vec<Tag>) {
+    if active_tags(output) == previous {
+        return;
+    }
+
+    output.with_state_mut(|op_state| {
+        op_state.tag_history.push_back(previous);
+        if op_state.tag_history.len() > TAG_HISTORY_LEN {
+            op_state.tag_history.pop_front();
+        }
+    });
 }
 
 pub fn add(
@@ -150,6 +331,31 @@ pub fn remove(state: &mut State, tags_to_remove: Vec<Tag>) {
     }
 }
 
+/// Moves `tag` to position `index` among the tags on its output.
+///
+/// Indices past the last tag move it to the end.
+pub fn move_to_index(state: &mut State, tag: &Tag, index: usize) {
+    let Some(output) = tag.output(&state.pinnacle) else {
+        return;
+    };
+
+    output.with_state_mut(|op_state| {
+        let Some(from) = op_state.tags.get_index_of(tag) else {
+            return;
+        };
+
+        // Defunct tags stay at the end so new tags replace them in order
+        let live_tags = op_state
+            .tags
+            .iter()
+            .filter(|op_tag| !op_tag.defunct())
+            .count();
+        let to = index.min(live_tags.saturating_sub(1));
+
+        op_state.tags.move_index(from, to);
+    });
+}
+
 #[derive(Debug, PartialEq, Clone)]
 pub enum TagMoveToOutputError {
     /// Its impossible to move tags to an output that does not exist. Create it first
@@ -244,3 +450,186 @@ where
     state.pinnacle.update_xwayland_stacking_order();
     Ok(())
 }
+
+/// Clients streaming pager state.
+#[derive(Debug, Default)]
+pub struct PagerState {
+    subscribers: Vec<UnboundedSender<Result<PagerResponse, Status>>>,
+    last_sent: Option<PagerResponse>,
+    /// Whether something shown by the pager may have changed since it was last sent.
+    dirty: bool,
+    /// Where each window was relative to its output when it was last mapped,
+    /// so windows on inactive tags can be shown where they were.
+    window_geometries: HashMap<WindowId, Rectangle<i32, Logical>>,
+}
+
+impl PagerState {
+    pub fn subscribe(&mut self, sender: UnboundedSender<Result<PagerResponse, Status>>) {
+        if let Some(last_sent) = self.last_sent.clone() {
+            let _ = sender.send(Ok(last_sent));
+        }
+        self.subscribers.push(sender);
+        self.dirty = true;
+    }
+
+    /// Marks the pager to be recomputed on the next refresh.
+    pub fn mark_dirty(&mut self) {
+        self.dirty = true;
+    }
+}
+
+impl Pinnacle {
+    fn pager_response(&self) -> PagerResponse {
+        let focused_output = self.focused_output();
+
+        let z_indices = self
+            .z_index_stack
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, elem)| match elem {
+                ZIndexElement::Window(win) => Some((win, idx)),
+                ZIndexElement::Unmapping(_) => None,
+            })
+            .collect::<HashMap<_, _>>();
+
+        let mut windows_by_tag = HashMap::<Tag, Vec<&WindowElement>>::new();
+        for win in self.windows.iter() {
+            for tag in win.with_state(|state| state.tags.clone()) {
+                windows_by_tag.entry(tag).or_default().push(win);
+            }
+        }
+
+        // Windows that aren't mapped come first
+        for windows in windows_by_tag.values_mut() {
+            windows.sort_by_key(|win| z_indices.get(win).copied());
+        }
+
+        let tag_windows = |tag: &Tag| {
+            windows_by_tag
+                .get(tag)
+                .into_iter()
+                .flatten()
+                .map(|win| {
+                    let id = win.with_state(|state| state.id);
+                    let geometry = self.pager_state.window_geometries.get(&id);
+
+                    pager_response::Window {
+                        window_id: id.0,
+                        title: win.title().unwrap_or_default(),
+                        app_id: win.class().unwrap_or_default(),
+                        geometry: geometry.map(|geo| util::v1::Rect {
+                            loc: Some(util::v1::Point {
+                                x: geo.loc.x,
+                                y: geo.loc.y,
+                            }),
+                            size: Some(util::v1::Size {
+                                width: geo.size.w as u32,
+                                height: geo.size.h as u32,
+                            }),
+                        }),
+                    }
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let outputs = self
+            .outputs
+            .iter()
+            .map(|output| {
+                let tags = output.with_state(|state| {
+                    state
+                        .tags
+                        .iter()
+                        .filter(|tag| !tag.defunct())
+                        .map(|tag| pager_response::Tag {
+                            tag_id: tag.id().to_inner(),
+                            name: tag.name(),
+                            active: tag.active(),
+                            urgent: tag.urgent(),
+                            occupied: windows_by_tag.contains_key(tag),
+                            windows: tag_windows(tag),
+                        })
+                        .collect()
+                });
+
+                let focused_window = self.focus_stack_for_output(output).last();
+
+                pager_response::Output {
+                    output_name: output.name(),
+                    focused: Some(output) == focused_output,
+                    tags,
+                    focused_window_id: focused_window.map(|win| win.with_state(|state| state.id.0)),
+                    focused_window_title: focused_window.and_then(|win| win.title()),
+                    size: self
+                        .space
+                        .output_geometry(output)
+                        .map(|geo| util::v1::Size {
+                            width: geo.size.w as u32,
+                            height: geo.size.h as u32,
+                        }),
+                }
+            })
+            .collect();
+
+        PagerResponse { outputs }
+    }
+
+    /// Remembers where mapped windows are relative to their output
+    /// and forgets windows that were closed.
+    fn update_pager_window_geometries(&mut self) {
+        let geometries = self
+            .windows
+            .iter()
+            .filter_map(|win| {
+                let geo = self.space.element_geometry(win)?;
+                let output_geo = self.space.output_geometry(&win.output(self)?)?;
+                let id = win.with_state(|state| state.id);
+                Some((id, Rectangle::new(geo.loc - output_geo.loc, geo.size)))
+            })
+            .collect::<Vec<_>>();
+
+        let window_ids = self
+            .windows
+            .iter()
+            .map(|win| win.with_state(|state| state.id))
+            .collect::<HashSet<_>>();
+
+        let window_geometries = &mut self.pager_state.window_geometries;
+        window_geometries.extend(geometries);
+        window_geometries.retain(|id, _| window_ids.contains(id));
+    }
+
+    /// Sends the current pager state to streaming clients if it changed.
+    ///
+    /// The state is only recomputed after [`PagerState::mark_dirty`].
+    pub fn refresh_pager(&mut self) {
+        let _span = tracy_client::span!("Pinnacle::refresh_pager");
+
+        self.pager_state
+            .subscribers
+            .retain(|sender| !sender.is_closed());
+
+        if self.pager_state.subscribers.is_empty() {
+            self.pager_state.last_sent = None;
+            return;
+        }
+
+        if !mem::take(&mut self.pager_state.dirty) {
+            return;
+        }
+
+        self.update_pager_window_geometries();
+
+        let response = self.pager_response();
+
+        if self.pager_state.last_sent.as_ref() == Some(&response) {
+            return;
+        }
+
+        for sender in self.pager_state.subscribers.iter() {
+            let _ = sender.send(Ok(response.clone()));
+        }
+
+        self.pager_state.last_sent = Some(response);
+    }
+}
@@ -1,23 +1,32 @@
+use std::time::Duration;
+
 use pinnacle_api_defs::pinnacle::{
     tag::v1::{
-        self, AddRequest, AddResponse, GetActiveRequest, GetActiveResponse, GetNameRequest,
-        GetNameResponse, GetOutputNameRequest, GetOutputNameResponse, GetRequest, GetResponse,
-        MoveToOutputRequest, MoveToOutputResponse, RemoveRequest, SetActiveRequest,
-        SwitchToRequest,
+        self, AddEffectRuleRequest, AddRequest, AddResponse, EffectRuleTarget, ExistsRequest,
+        ExistsResponse, GetActiveRequest, GetActiveResponse, GetNameRequest, GetNameResponse,
+        GetOutputNameRequest, GetOutputNameResponse, GetRequest, GetResponse, GetUrgentRequest,
+        GetUrgentResponse, MoveToIndexRequest, MoveToOutputRequest, MoveToOutputResponse,
+        PagerRequest, PagerResponse, PeekRequest, RemoveRequest, SetActiveRequest,
+        SetEmptyOutputPolicyRequest, SwitchToMatchingRequest, SwitchToMatchingResponse,
+        SwitchToPreviousRequest, SwitchToPreviousResponse, SwitchToRequest,
+        switch_to_matching_request,
     },
     util::v1::SetOrToggle,
 };
 use tonic::{Request, Status};
 
 use crate::{
-    api::{TonicResult, run_unary, run_unary_no_response},
+    api::{ResponseStream, TonicResult, run_server_streaming, run_unary, run_unary_no_response},
     output::OutputName,
+    render::util::effect::{TagEffectRule, TagEffectTarget, WindowEffect},
     state::WithState,
-    tag::TagId,
+    tag::{EmptyOutputPolicy, TagId},
 };
 
 #[tonic::async_trait]
 impl v1::tag_service_server::TagService for super::TagService {
+    type PagerStream = ResponseStream<PagerResponse>;
+
     async fn get(&self, _request: Request<GetRequest>) -> TonicResult<GetResponse> {
         run_unary(&self.sender, move |state| {
             let tags = state.pinnacle.outputs.iter().flat_map(|op| {
@@ -38,6 +47,16 @@ impl v1::tag_service_server::TagService for super::TagService {
         .await
     }
 
+    async fn exists(&self, request: Request<ExistsRequest>) -> TonicResult<ExistsResponse> {
+        let tag_id = TagId::new(request.into_inner().tag_id);
+        run_unary(&self.sender, move |state| {
+            let exists = tag_id.tag(&state.pinnacle).is_some();
+
+            Ok(ExistsResponse { exists })
+        })
+        .await
+    }
+
     async fn get_active(
         &self,
         request: Request<GetActiveRequest>,
@@ -54,14 +73,27 @@ impl v1::tag_service_server::TagService for super::TagService {
         .await
     }
 
-    async fn get_name(&self, request: Request<GetNameRequest>) -> TonicResult<GetNameResponse> {
+    async fn get_urgent(
+        &self,
+        request: Request<GetUrgentRequest>,
+    ) -> TonicResult<GetUrgentResponse> {
         let tag_id = TagId::new(request.into_inner().tag_id);
         run_unary(&self.sender, move |state| {
-            let name = tag_id
+            let urgent = tag_id
                 .tag(&state.pinnacle)
-                .map(|tag| tag.name())
+                .map(|tag| tag.urgent())
                 .unwrap_or_default();
 
+            Ok(GetUrgentResponse { urgent })
+        })
+        .await
+    }
+
+    async fn get_name(&self, request: Request<GetNameRequest>) -> TonicResult<GetNameResponse> {
+        let tag_id = TagId::new(request.into_inner().tag_id);
+        run_unary(&self.sender, move |state| {
+            let name = tag_id.tag(&state.pinnacle).map(|tag| tag.name());
+
             Ok(GetNameResponse { name })
         })
         .await
@@ -75,8 +107,7 @@ impl v1::tag_service_server::TagService for super::TagService {
         run_unary(&self.sender, move |state| {
             let output_name = tag_id
                 .tag(&state.pinnacle)
-                .and_then(|tag| Some(tag.output(&state.pinnacle)?.name()))
-                .unwrap_or_default();
+                .and_then(|tag| Some(tag.output(&state.pinnacle)?.name()));
 
             Ok(GetOutputNameResponse { output_name })
         })
@@ -123,6 +154,78 @@ impl v1::tag_service_server::TagService for super::TagService {
         .await
     }
 
+    async fn switch_to_matching(
+        &self,
+        request: Request<SwitchToMatchingRequest>,
+    ) -> TonicResult<SwitchToMatchingResponse> {
+        let request = request.into_inner();
+
+        let output_name = request.output_name.map(OutputName);
+
+        let Some(matcher) = request.tag else {
+            return Err(Status::invalid_argument("no tag index or name specified"));
+        };
+
+        run_unary(&self.sender, move |state| {
+            let output = match output_name {
+                Some(output_name) => output_name.output(&state.pinnacle),
+                None => state.pinnacle.focused_output().cloned(),
+            };
+
+            let Some(output) = output else {
+                return Ok(SwitchToMatchingResponse { tag_id: None });
+            };
+
+            let tag = output.with_state(|op_state| {
+                let mut tags = op_state.tags.iter().filter(|tag| !tag.defunct());
+                match &matcher {
+                    switch_to_matching_request::Tag::Index(index) => {
+                        tags.nth(*index as usize).cloned()
+                    }
+                    switch_to_matching_request::Tag::Name(name) => {
+                        tags.find(|tag| tag.name() == *name).cloned()
+                    }
+                }
+            });
+
+            let Some(tag) = tag else {
+                return Ok(SwitchToMatchingResponse { tag_id: None });
+            };
+
+            crate::api::tag::switch_to(state, &tag);
+
+            Ok(SwitchToMatchingResponse {
+                tag_id: Some(tag.id().to_inner()),
+            })
+        })
+        .await
+    }
+
+    async fn switch_to_previous(
+        &self,
+        request: Request<SwitchToPreviousRequest>,
+    ) -> TonicResult<SwitchToPreviousResponse> {
+        let output_name = request.into_inner().output_name.map(OutputName);
+
+        run_unary(&self.sender, move |state| {
+            let output = match output_name {
+                Some(output_name) => output_name.output(&state.pinnacle),
+                None => state.pinnacle.focused_output().cloned(),
+            };
+
+            let Some(output) = output else {
+                return Ok(SwitchToPreviousResponse::default());
+            };
+
+            let tags = crate::api::tag::switch_to_previous(state, &output);
+
+            Ok(SwitchToPreviousResponse {
+                tag_ids: tags.iter().map(|tag| tag.id().to_inner()).collect(),
+            })
+        })
+        .await
+    }
+
     async fn add(&self, request: Request<AddRequest>) -> TonicResult<AddResponse> {
         let request = request.into_inner();
 
@@ -177,6 +280,19 @@ impl v1::tag_service_server::TagService for super::TagService {
         .await
     }
 
+    async fn move_to_index(&self, request: Request<MoveToIndexRequest>) -> TonicResult<()> {
+        let request = request.into_inner();
+
+        let tag_id = TagId::new(request.tag_id);
+        let index = request.index as usize;
+
+        run_unary_no_response(&self.sender, move |state| {
+            let Some(tag) = tag_id.tag(&state.pinnacle) else { return };
+            crate::api::tag::move_to_index(state, &tag, index);
+        })
+        .await
+    }
+
     async fn remove(&self, request: Request<RemoveRequest>) -> TonicResult<()> {
         let request = request.into_inner();
 
@@ -191,4 +307,80 @@ impl v1::tag_service_server::TagService for super::TagService {
         })
         .await
     }
+
+    async fn peek(&self, request: Request<PeekRequest>) -> TonicResult<()> {
+        let request = request.into_inner();
+
+        let tag_id = TagId::new(request.tag_id);
+        let duration = Duration::from_millis(request.millis.into());
+
+        run_unary_no_response(&self.sender, move |state| {
+            let Some(tag) = tag_id.tag(&state.pinnacle) else { return };
+            state.peek_tag(&tag, duration);
+        })
+        .await
+    }
+
+    async fn add_effect_rule(&self, request: Request<AddEffectRuleRequest>) -> TonicResult<()> {
+        let request = request.into_inner();
+
+        let tag_id = TagId::new(request.tag_id);
+        let target = match request.target() {
+            EffectRuleTarget::Unspecified => {
+                return Err(Status::invalid_argument("unspecified target"));
+            }
+            EffectRuleTarget::WindowsOnTag => TagEffectTarget::WindowsOnTag,
+            EffectRuleTarget::OtherWindows => TagEffectTarget::OtherWindows,
+        };
+        let effect = WindowEffect {
+            opacity: request.opacity.clamp(0.0, 1.0),
+            dim: request.dim.clamp(0.0, 1.0),
+            desaturate: request.desaturate.clamp(0.0, 1.0),
+        };
+
+        run_unary_no_response(&self.sender, move |state| {
+            let Some(tag) = tag_id.tag(&state.pinnacle) else { return };
+
+            state.pinnacle.config.tag_effect_rules.push(TagEffectRule {
+                tag,
+                target,
+                effect,
+            });
+        })
+        .await
+    }
+
+    async fn set_empty_output_policy(
+        &self,
+        request: Request<SetEmptyOutputPolicyRequest>,
+    ) -> TonicResult<()> {
+        let request = request.into_inner();
+
+        let policy = match request.policy() {
+            v1::EmptyOutputPolicy::Unspecified => {
+                return Err(Status::invalid_argument("unspecified policy"));
+            }
+            v1::EmptyOutputPolicy::Blank => EmptyOutputPolicy::Blank,
+            v1::EmptyOutputPolicy::ReactivateLast => EmptyOutputPolicy::ReactivateLast,
+            v1::EmptyOutputPolicy::Fallback => {
+                let Some(name) = request.fallback_tag_name else {
+                    return Err(Status::invalid_argument("no fallback tag name"));
+                };
+                EmptyOutputPolicy::Fallback(name)
+            }
+        };
+
+        run_unary_no_response(&self.sender, move |state| {
+            state.pinnacle.config.empty_output_policy = policy;
+        })
+        .await
+    }
+
+    async fn pager(&self, _request: Request<PagerRequest>) -> TonicResult<Self::PagerStream> {
+        run_server_streaming(&self.sender, move |state, sender| {
+            state.pinnacle.pager_state.subscribe(sender);
+            Ok(())
+        })
+        .await
+    }
 }
@@ -1,5 +1,9 @@
 mod v1;
 
+use pinnacle_api_defs::pinnacle::{
+    util,
+    window::v1::{self as window_v1, ClearableProperty, TagIds, WatchPropertiesResponse},
+};
 use smithay::{
     reexports::wayland_protocols::xdg::{
         decoration::zv1::server::zxdg_toplevel_decoration_v1, shell::server,
@@ -7,17 +11,23 @@ use smithay::{
     utils::{Point, SERIAL_COUNTER, Size},
     wayland::seat::WaylandFocus,
 };
+use tokio::sync::mpsc::UnboundedSender;
+use tonic::Status;
 use tracing::warn;
 
 use crate::{
-    focus::keyboard::KeyboardFocusTarget,
-    state::{State, WithState},
+    focus::{FocusReason, keyboard::KeyboardFocusTarget},
+    state::{Pinnacle, State, WithState},
     tag::Tag,
-    util::transaction::TransactionBuilder,
-    window::WindowElement,
+    util::{rect::Direction, transaction::TransactionBuilder},
+    window::{
+        WindowElement,
+        rules::{RuleProperty, WindowRules},
+        window_state::{LayoutModeKind, SizeConstraints, WindowId, WindowLayer},
+    },
 };
 
-use super::StateFnSender;
+use super::{StateFnSender, changed_property, cleared_property};
 
 pub struct WindowService {
     sender: StateFnSender,
@@ -59,13 +69,56 @@ pub fn set_geometry(
         state.floating_size = window_size;
     });
 
+    if let Some(loc) = window.with_state(|state| state.floating_loc()) {
+        let loc = state.pinnacle.constrain_floating_loc(window, loc);
+        window.with_state_mut(|state| state.set_floating_loc(loc));
+    }
+
     state.pinnacle.update_window_geometry(
         window,
         window.with_state(|state| state.layout_mode.is_tiled()),
     );
 }
 
-// TODO: minimized
+/// Minimizes or unminimizes a window, toggling it if `set` is `None`.
+///
+/// Minimized windows keep their tags but aren't laid out, drawn, or focused.
+pub fn set_minimized(state: &mut State, window: &WindowElement, set: impl Into<Option<bool>>) {
+    if window.is_x11_override_redirect() {
+        return;
+    }
+
+    let was_minimized = window.with_state(|state| state.minimized);
+    let minimized = set.into().unwrap_or(!was_minimized);
+
+    if minimized == was_minimized {
+        return;
+    }
+
+    window.with_state_mut(|state| state.minimized = minimized);
+
+    if let Some(surface) = window.x11_surface()
+        && let Err(err) = surface.set_hidden(minimized)
+    {
+        warn!("Failed to set x11 window hidden state: {err}");
+    }
+
+    state
+        .pinnacle
+        .signal_state
+        .window_minimized
+        .signal((window, minimized));
+
+    state.pinnacle.close_hidden_popups();
+    state.pinnacle.update_xwayland_stacking_order();
+
+    let Some(output) = window.output(&state.pinnacle) else {
+        return;
+    };
+
+    state.pinnacle.request_layout(&output);
+    state.schedule_render(&output);
+}
 
 /// Sets a window to focused or not.
 ///
@@ -100,7 +153,7 @@ pub fn set_focused(state: &mut State, window: &WindowElement, set: impl Into<Opt
         state
             .pinnacle
             .keyboard_focus_stack
-            .set_focus(window.clone());
+            .set_focus(window.clone(), FocusReason::Keybind);
 
         state.pinnacle.on_demand_layer_focus = None;
 
@@ -145,6 +198,68 @@ pub fn set_focused(state: &mut State, window: &WindowElement, set: impl Into<Opt
     }
 }
 
+/// Focuses the window nearest to the focused window in `dir` on the focused output.
+///
+/// Returns the newly focused window, or `None` if no window on the focused output
+/// is focused or there's no window in that direction.
+pub fn focus_in_direction(state: &mut State, dir: Direction) -> Option<WindowElement> {
+    let output = state.pinnacle.focused_output()?.clone();
+    let focused = state.pinnacle.keyboard_focus_stack.current_focus()?.clone();
+
+    if focused.output(&state.pinnacle).as_ref() != Some(&output) {
+        return None;
+    }
+
+    let window = nearest_in_direction(&state.pinnacle, &focused, dir)?;
+
+    state.pinnacle.raise_window(window.clone());
+    set_focused(state, &window, true);
+
+    Some(window)
+}
+
+/// Swaps `window` with the window nearest to it in `dir` on the same output.
+///
+/// Returns the window it was swapped with, or `None` if there's no window in that direction.
+pub fn swap_in_direction(
+    state: &mut State,
+    window: &WindowElement,
+    dir: Direction,
+) -> Option<WindowElement> {
+    let target = nearest_in_direction(&state.pinnacle, window, dir)?;
+    swap(state, window.clone(), target.clone());
+    Some(target)
+}
+
+/// Returns the window that can get focus nearest to `window` in `dir`
+/// on the same output.
+fn nearest_in_direction(
+    pinnacle: &Pinnacle,
+    window: &WindowElement,
+    dir: Direction,
+) -> Option<WindowElement> {
+    let output = window.output(pinnacle)?;
+    let window_geo = pinnacle.space.element_geometry(window)?;
+
+    let candidates = pinnacle
+        .space
+        .elements()
+        .filter(|win| {
+            *win != window
+                && !win.is_x11_override_redirect()
+                && !win.with_state(|state| state.input_disabled)
+                && win.output(pinnacle).as_ref() == Some(&output)
+        })
+        .collect::<Vec<_>>();
+    let rects = candidates
+        .iter()
+        .map(|win| pinnacle.space.element_geometry(win).expect("mapped"))
+        .collect::<Vec<_>>();
+
+    let idx = *crate::util::rect::closest_in_dir(window_geo, &rects, dir).first()?;
+    Some(candidates[idx].clone())
+}
+
 pub fn set_decoration_mode(
     _state: &mut State,
     window: &WindowElement,
@@ -193,6 +308,29 @@ pub fn move_to_tag(state: &mut State, window: &WindowElement, tag: &Tag) {
     state.pinnacle.update_xwayland_stacking_order();
 }
 
+/// Moves a window to a tag, then switches to that tag and focuses the window.
+///
+/// Doing this all at once means no frame is rendered with the window moved
+/// but the tag not switched to yet.
+pub fn move_to_tag_and_follow(state: &mut State, window: &WindowElement, tag: &Tag) {
+    move_to_tag(state, window, tag);
+    crate::api::tag::switch_to(state, tag);
+
+    if window.is_x11_override_redirect() {
+        return;
+    }
+
+    if let Some(output) = tag.output(&state.pinnacle) {
+        state.pinnacle.focus_output(&output);
+    }
+
+    state
+        .pinnacle
+        .keyboard_focus_stack
+        .set_focus(window.clone(), FocusReason::Keybind);
+    state.pinnacle.on_demand_layer_focus = None;
+}
+
 pub fn set_tag(state: &mut State, window: &WindowElement, tag: &Tag, set: impl Into<Option<bool>>) {
     let set = set.into();
 
@@ -242,6 +380,14 @@ pub fn lower(state: &mut State, window: WindowElement) {
     state.pinnacle.lower_window(window);
 }
 
+pub fn set_layer(state: &mut State, window: WindowElement, layer: WindowLayer) {
+    for output in state.pinnacle.space.outputs_for_element(&window) {
+        state.schedule_render(&output);
+    }
+
+    state.pinnacle.set_window_layer(window, layer);
+}
+
 pub fn move_grab(state: &mut State, button: u32) {
     let Some((pointer_focus, _)) = state.pinnacle.pointer_contents.focus_under.as_ref() else {
         return;
@@ -377,6 +523,126 @@ pub fn resize_grab(state: &mut State, button: u32) {
     }
 }
 
+/// Applies the declarative rules matching each mapped window to it.
+///
+/// Rules only apply to windows when they open, so this lets rules added later,
+/// like after a config reload, affect existing windows. Whether to focus a window
+/// is left alone as it only makes sense when a window opens.
+///
+/// Returns the windows that changed along with which of their properties did.
+pub fn apply_rules_to_existing(state: &mut State) -> Vec<(WindowElement, Vec<RuleProperty>)> {
+    let mut report = Vec::new();
+
+    for window in state.pinnacle.windows.clone() {
+        if window.is_x11_override_redirect() {
+            continue;
+        }
+
+        let WindowRules {
+            layout_mode,
+            focused: _,
+            floating_x,
+            floating_y,
+            floating_size,
+            decoration_mode,
+            tags,
+            maximize_request_policy,
+            size_constraints,
+            border_style,
+        } = state
+            .pinnacle
+            .window_rule_state
+            .declarative_rules_for(&window);
+
+        let old_output = window.output(&state.pinnacle);
+        let mut changed = Vec::new();
+
+        if let Some(layout_mode) = layout_mode {
+            let current = window.with_state(|state| state.layout_mode);
+            // Spilled windows are tiled ones that didn't fit, so leave them to the layout
+            let tiled_and_spilled = layout_mode.is_tiled() && current.is_spilled();
+            if current.current() != layout_mode.current() && !tiled_and_spilled {
+                state
+                    .pinnacle
+                    .update_window_layout_mode(&window, |mode| *mode = layout_mode);
+                changed.push(RuleProperty::LayoutMode);
+            }
+        }
+
+        let (current_x, current_y, current_size) =
+            window.with_state(|state| (state.floating_x, state.floating_y, state.floating_size));
+        if floating_x.is_some_and(|x| Some(x) != current_x)
+            || floating_y.is_some_and(|y| Some(y) != current_y)
+            || floating_size.is_some_and(|size| size != current_size)
+        {
+            set_geometry(
+                state,
+                &window,
+                floating_x,
+                floating_y,
+                floating_size.map(|size| size.w as u32),
+                floating_size.map(|size| size.h as u32),
+            );
+            changed.push(RuleProperty::FloatingGeometry);
+        }
+
+        if let Some(mode) = decoration_mode
+            && window.with_state(|state| state.decoration_mode) != Some(mode)
+        {
+            set_decoration_mode(state, &window, mode);
+            changed.push(RuleProperty::DecorationMode);
+        }
+
+        if let Some(tags) = tags
+            && window.with_state(|state| state.tags != tags)
+        {
+            window.with_state_mut(|state| state.tags = tags);
+            changed.push(RuleProperty::Tags);
+        }
+
+        if let Some(policy) = maximize_request_policy
+            && window.with_state(|state| state.maximize_request_policy) != Some(policy)
+        {
+            window.with_state_mut(|state| state.maximize_request_policy = Some(policy));
+            changed.push(RuleProperty::MaximizeRequestPolicy);
+        }
+
+        if size_constraints != SizeConstraints::default()
+            && window.with_state(|state| state.size_constraints) != size_constraints
+        {
+            window.with_state_mut(|state| state.size_constraints = size_constraints);
+            let is_tiled = window.with_state(|state| state.layout_mode.is_tiled());
+            state.pinnacle.update_window_geometry(&window, is_tiled);
+            changed.push(RuleProperty::SizeConstraints);
+        }
+
+        if let Some(style) = border_style
+            && window.with_state(|state| state.border_style) != Some(style)
+        {
+            window.with_state_mut(|state| state.border_style = Some(style));
+            changed.push(RuleProperty::BorderStyle);
+        }
+
+        if changed.is_empty() {
+            continue;
+        }
+
+        let new_output = window
+            .output(&state.pinnacle)
+            .filter(|output| Some(output) != old_output.as_ref());
+        for output in old_output.into_iter().chain(new_output) {
+            state.pinnacle.request_layout(&output);
+            state.schedule_render(&output);
+        }
+
+        report.push((window, changed));
+    }
+
+    state.pinnacle.update_xwayland_stacking_order();
+
+    report
+}
+
 pub fn swap(state: &mut State, window: WindowElement, target: WindowElement) {
     if state.pinnacle.layout_state.pending_swap {
         return;
@@ -481,3 +747,135 @@ pub fn swap(state: &mut State, window: WindowElement, target: WindowElement) {
             builder.into_pending(unmappings, state.pinnacle.layout_state.pending_swap, false),
         );
 }
+
+/// Clients watching window properties.
+#[derive(Debug, Default)]
+pub struct WindowWatchState {
+    watchers: Vec<WindowWatcher>,
+}
+
+#[derive(Debug)]
+struct WindowWatcher {
+    window_id: WindowId,
+    sender: UnboundedSender<Result<WatchPropertiesResponse, Status>>,
+    last_sent: Option<WatchPropertiesResponse>,
+}
+
+impl WindowWatchState {
+    pub fn subscribe(
+        &mut self,
+        window_id: WindowId,
+        sender: UnboundedSender<Result<WatchPropertiesResponse, Status>>,
+    ) {
+        self.watchers.push(WindowWatcher {
+            window_id,
+            sender,
+            last_sent: None,
+        });
+    }
+}
+
+impl Pinnacle {
+    fn window_properties(&self, window: &WindowElement) -> WatchPropertiesResponse {
+        let focused = matches!(
+            self.seat.get_keyboard().and_then(|keyboard| keyboard.current_focus()),
+            Some(KeyboardFocusTarget::Window(win)) if win == *window
+        );
+
+        let (layout_mode, tag_ids) = window.with_state(|state| {
+            let tag_ids = state.tags.iter().map(|tag| tag.id().to_inner()).collect();
+            (state.layout_mode.current(), tag_ids)
+        });
+
+        let layout_mode = match layout_mode {
+            LayoutModeKind::Tiled => window_v1::LayoutMode::Tiled,
+            LayoutModeKind::Floating => window_v1::LayoutMode::Floating,
+            LayoutModeKind::Maximized => window_v1::LayoutMode::Maximized,
+            LayoutModeKind::Fullscreen => window_v1::LayoutMode::Fullscreen,
+            LayoutModeKind::Spilled => window_v1::LayoutMode::Floating,
+        };
+
+        WatchPropertiesResponse {
+            app_id: Some(window.class().unwrap_or_default()),
+            title: Some(window.title().unwrap_or_default()),
+            loc: self
+                .space
+                .element_location(window)
+                .map(|loc| util::v1::Point { x: loc.x, y: loc.y }),
+            size: self
+                .space
+                .element_geometry(window)
+                .map(|geo| util::v1::Size {
+                    width: geo.size.w.try_into().unwrap_or_default(),
+                    height: geo.size.h.try_into().unwrap_or_default(),
+                }),
+            focused: Some(focused),
+            layout_mode: Some(layout_mode.into()),
+            tag_ids: Some(TagIds { tag_ids }),
+            cleared: Vec::new(),
+        }
+    }
+
+    /// Sends changed window properties to watching clients.
+    ///
+    /// Watchers of windows that no longer exist are dropped, which ends their streams.
+    pub fn refresh_window_watchers(&mut self) {
+        let _span = tracy_client::span!("Pinnacle::refresh_window_watchers");
+
+        let mut watchers = std::mem::take(&mut self.window_watch_state.watchers);
+
+        watchers.retain_mut(|watcher| {
+            if watcher.sender.is_closed() {
+                return false;
+            }
+
+            let window = watcher.window_id.window(self).or_else(|| {
+                watcher
+                    .window_id
+                    .unmapped_window(self)
+                    .map(|unmapped| unmapped.window.clone())
+            });
+
+            let Some(window) = window else {
+                return false;
+            };
+
+            let properties = self.window_properties(&window);
+
+            let response = match watcher.last_sent.as_ref() {
+                Some(last_sent) => WatchPropertiesResponse {
+                    app_id: changed_property(&last_sent.app_id, &properties.app_id),
+                    title: changed_property(&last_sent.title, &properties.title),
+                    loc: changed_property(&last_sent.loc, &properties.loc),
+                    size: changed_property(&last_sent.size, &properties.size),
+                    focused: changed_property(&last_sent.focused, &properties.focused),
+                    layout_mode: changed_property(&last_sent.layout_mode, &properties.layout_mode),
+                    tag_ids: changed_property(&last_sent.tag_ids, &properties.tag_ids),
+                    cleared: [
+                        cleared_property(&last_sent.loc, &properties.loc, ClearableProperty::Loc),
+                        cleared_property(
+                            &last_sent.size,
+                            &properties.size,
+                            ClearableProperty::Size,
+                        ),
+                    ]
+                    .into_iter()
+                    .flatten()
+                    .map(i32::from)
+                    .collect(),
+                },
+                None => properties.clone(),
+            };
+
+            if response != WatchPropertiesResponse::default() {
+                let _ = watcher.sender.send(Ok(response));
+            }
+
+            watcher.last_sent = Some(properties);
+
+            true
+        });
+
+        self.window_watch_state.watchers = watchers;
+    }
+}
@@ -1,6 +1,9 @@
-use std::sync::{
-    Arc,
-    atomic::{AtomicU32, Ordering},
+use std::{
+    sync::{
+        Arc,
+        atomic::{AtomicU32, Ordering},
+    },
+    time::Duration,
 };
 
 use indexmap::IndexSet;
@@ -9,18 +12,41 @@ use pinnacle_api_defs::pinnacle::{
     window::{
         self,
         v1::{
-            self, CloseRequest, GetAppIdRequest, GetAppIdResponse, GetFocusedRequest,
-            GetFocusedResponse, GetForeignToplevelListIdentifierRequest,
-            GetForeignToplevelListIdentifierResponse, GetLayoutModeRequest, GetLayoutModeResponse,
-            GetLocRequest, GetLocResponse, GetRequest, GetResponse, GetSizeRequest,
-            GetSizeResponse, GetTagIdsRequest, GetTagIdsResponse, GetTitleRequest,
-            GetTitleResponse, GetWindowsInDirRequest, GetWindowsInDirResponse, LowerRequest,
-            LowerResponse, MoveGrabRequest, MoveToOutputRequest, MoveToOutputResponse,
-            MoveToTagRequest, RaiseRequest, ResizeGrabRequest, ResizeTileRequest,
-            SetDecorationModeRequest, SetFloatingRequest, SetFocusedRequest, SetFullscreenRequest,
-            SetGeometryRequest, SetMaximizedRequest, SetTagRequest, SetTagsRequest,
-            SetTagsResponse, SetVrrDemandRequest, SetVrrDemandResponse, SwapRequest, SwapResponse,
-            WindowRuleRequest, WindowRuleResponse,
+            self, ApplyRulesToExistingRequest, ApplyRulesToExistingResponse, BeginCycleRequest,
+            BeginCycleResponse, CloseRequest, CycleRequest, CycleResponse, EndCycleRequest,
+            EndCycleResponse, ExistsRequest, ExistsResponse, FindMode, FindRequest, FindResponse,
+            FocusInDirectionRequest, FocusInDirectionResponse, GetAppIdRequest, GetAppIdResponse,
+            GetChildrenRequest, GetChildrenResponse, GetDemandsAttentionRequest,
+            GetDemandsAttentionResponse, GetFocusAgeRequest, GetFocusAgeResponse,
+            GetFocusedRequest, GetFocusedResponse, GetForeignToplevelListIdentifierRequest,
+            GetForeignToplevelListIdentifierResponse, GetIconRequest, GetIconResponse,
+            GetLayerRequest, GetLayerResponse, GetLayoutModeRequest, GetLayoutModeResponse,
+            GetLocRequest, GetLocResponse, GetMatchingWindowRulesRequest,
+            GetMatchingWindowRulesResponse, GetMinimizedRequest, GetMinimizedResponse,
+            GetParentRequest, GetParentResponse, GetPreviouslyFocusedRequest,
+            GetPreviouslyFocusedResponse, GetRequest, GetResponse, GetResponsiveRequest,
+            GetResponsiveResponse, GetSizeRequest, GetSizeResponse, GetTagIdsRequest,
+            GetTagIdsResponse, GetTitleRequest, GetTitleResponse, GetWindowsInDirRequest,
+            GetWindowsInDirResponse, LowerRequest, LowerResponse, MoveGrabRequest,
+            MoveToOutputRequest, MoveToOutputResponse, MoveToTagRequest, RaiseRequest,
+            ResizeGrabRequest, ResizeTileRequest, SetAllowOffscreenRequest,
+            SetAllowOffscreenResponse, SetBorderStyleRequest, SetBorderStyleResponse,
+            SetDecorationModeRequest, SetDemandsAttentionRequest, SetDialogPolicyRequest,
+            SetDialogPolicyResponse, SetFloatingRequest, SetFloatingVisibilityRequest,
+            SetFloatingVisibilityResponse, SetFocusedRequest, SetFullscreenRequest,
+            SetGeometryRequest, SetGreyOutUnresponsiveRequest, SetGreyOutUnresponsiveResponse,
+            SetHoldToMoveEnabledRequest, SetHoldToMoveEnabledResponse, SetInputEnabledRequest,
+            SetInputEnabledResponse, SetLayerRequest, SetLayerResponse,
+            SetMaximizeRequestPolicyRequest, SetMaximizeRequestPolicyResponse, SetMaximizedRequest,
+            SetMinimizedRequest, SetPopupPolicyRequest, SetPopupPolicyResponse,
+            SetScaleOverrideRequest, SetScaleOverrideResponse, SetSizeConstraintsRequest,
+            SetSizeConstraintsResponse, SetSnapGridRequest, SetSnapGridResponse,
+            SetTagInheritanceRequest, SetTagInheritanceResponse, SetTagRequest, SetTagsRequest,
+            SetTagsResponse, SetVrrDemandRequest, SetVrrDemandResponse, SetWindowRulesRequest,
+            SetWindowRulesResponse, SnapToRegionRequest, SnapToRegionResponse,
+            SwapInDirectionRequest, SwapInDirectionResponse, SwapRequest, SwapResponse,
+            WatchPropertiesRequest, WatchPropertiesResponse, WindowRuleRequest, WindowRuleResponse,
+            apply_rules_to_existing_response, snap_to_region_request,
         },
     },
 };
@@ -28,29 +54,39 @@ use smithay::{
     reexports::wayland_protocols::xdg::decoration::zv1::server::zxdg_toplevel_decoration_v1,
     utils::Size,
 };
-use tonic::{Request, Status, Streaming};
+use tonic::{Request, Response, Status, Streaming};
 use tracing::warn;
 
 use crate::{
     api::{
-        ResponseStream, TonicResult, run_bidirectional_streaming_mapped, run_unary,
-        run_unary_no_response,
+        ResponseStream, TonicResult, namespace::client_pid, run_bidirectional_streaming_mapped,
+        run_server_streaming, run_unary, run_unary_no_response,
     },
     focus::keyboard::KeyboardFocusTarget,
     layout::tree::ResizeDir,
     output::OutputName,
     state::WithState,
     tag::TagId,
-    util::rect::Direction,
+    util::rect::{Containment, Direction},
     window::{
         UnmappedState,
-        window_state::{LayoutMode, LayoutModeKind, VrrDemand, WindowId},
+        border::BorderStyle,
+        find::Matcher,
+        icon::desktop_entry_icon,
+        popup::PopupPolicy,
+        rules::{DeclarativeRule, RuleProperty, RuleStrategy, WindowRules},
+        snap::{SnapGrid, SnapRegion},
+        window_state::{
+            DialogPolicy, LayoutMode, LayoutModeKind, MaximizeRequestPolicy, SizeConstraints,
+            TagInheritance, VrrDemand, WindowId, WindowLayer,
+        },
     },
 };
 
 #[tonic::async_trait]
 impl v1::window_service_server::WindowService for super::WindowService {
     type WindowRuleStream = ResponseStream<WindowRuleResponse>;
+    type WatchPropertiesStream = ResponseStream<WatchPropertiesResponse>;
 
     async fn get(&self, _request: Request<GetRequest>) -> TonicResult<GetResponse> {
         run_unary(&self.sender, move |state| {
@@ -66,6 +102,18 @@ impl v1::window_service_server::WindowService for super::WindowService {
         .await
     }
 
+    async fn exists(&self, request: Request<ExistsRequest>) -> TonicResult<ExistsResponse> {
+        let window_id = WindowId(request.into_inner().window_id);
+
+        run_unary(&self.sender, move |state| {
+            let exists = window_id.window(&state.pinnacle).is_some()
+                || window_id.unmapped_window(&state.pinnacle).is_some();
+
+            Ok(ExistsResponse { exists })
+        })
+        .await
+    }
+
     async fn get_app_id(&self, request: Request<GetAppIdRequest>) -> TonicResult<GetAppIdResponse> {
         let window_id = WindowId(request.into_inner().window_id);
 
@@ -163,6 +211,114 @@ impl v1::window_service_server::WindowService for super::WindowService {
         .await
     }
 
+    async fn get_focus_age(
+        &self,
+        request: Request<GetFocusAgeRequest>,
+    ) -> TonicResult<GetFocusAgeResponse> {
+        let window_id = WindowId(request.into_inner().window_id);
+
+        run_unary(&self.sender, move |state| {
+            let focus_age = window_id
+                .window(&state.pinnacle)
+                .and_then(|win| state.pinnacle.keyboard_focus_stack.focus_age(&win));
+
+            Ok(GetFocusAgeResponse {
+                focus_age: focus_age.map(|age| age as u32),
+            })
+        })
+        .await
+    }
+
+    async fn get_previously_focused(
+        &self,
+        _request: Request<GetPreviouslyFocusedRequest>,
+    ) -> TonicResult<GetPreviouslyFocusedResponse> {
+        run_unary(&self.sender, move |state| {
+            let window_id = state
+                .pinnacle
+                .keyboard_focus_stack
+                .previous_focus()
+                .map(|win| win.with_state(|state| state.id.0));
+
+            Ok(GetPreviouslyFocusedResponse { window_id })
+        })
+        .await
+    }
+
+    async fn find(&self, request: Request<FindRequest>) -> TonicResult<FindResponse> {
+        let request = request.into_inner();
+
+        let matcher = match request.mode() {
+            FindMode::Unspecified | FindMode::Fuzzy => Matcher::fuzzy(&request.query),
+            FindMode::Regex => match Matcher::regex(&request.query) {
+                Ok(matcher) => matcher,
+                Err(err) => {
+                    return Ok(Response::new(FindResponse {
+                        window_ids: Vec::new(),
+                        error: Some(err.to_string()),
+                    }));
+                }
+            },
+        };
+        let limit = request.limit.map_or(usize::MAX, |limit| limit as usize);
+
+        run_unary(&self.sender, move |state| {
+            let window_ids = crate::window::find::find(&matcher, &state.pinnacle.windows)
+                .into_iter()
+                .take(limit)
+                .map(|win| win.with_state(|state| state.id.0))
+                .collect();
+
+            Ok(FindResponse {
+                window_ids,
+                error: None,
+            })
+        })
+        .await
+    }
+
+    async fn get_responsive(
+        &self,
+        request: Request<GetResponsiveRequest>,
+    ) -> TonicResult<GetResponsiveResponse> {
+        let window_id = WindowId(request.into_inner().window_id);
+
+        run_unary(&self.sender, move |state| {
+            let responsive = window_id
+                .window(&state.pinnacle)
+                .is_none_or(|win| win.is_responsive());
+
+            Ok(GetResponsiveResponse { responsive })
+        })
+        .await
+    }
+
+    async fn get_layer(&self, request: Request<GetLayerRequest>) -> TonicResult<GetLayerResponse> {
+        let window_id = WindowId(request.into_inner().window_id);
+
+        run_unary(&self.sender, move |state| {
+            let layer = window_id
+                .window(&state.pinnacle)
+                .or_else(|| {
+                    window_id
+                        .unmapped_window(&state.pinnacle)
+                        .map(|unmapped| unmapped.window.clone())
+                })
+                .map(|win| win.with_state(|state| state.layer));
+
+            Ok(GetLayerResponse {
+                layer: match layer {
+                    Some(WindowLayer::Below) => window::v1::WindowLayer::Below,
+                    Some(WindowLayer::Normal) => window::v1::WindowLayer::Normal,
+                    Some(WindowLayer::Above) => window::v1::WindowLayer::Above,
+                    None => window::v1::WindowLayer::Unspecified,
+                }
+                .into(),
+            })
+        })
+        .await
+    }
+
     async fn get_layout_mode(
         &self,
         request: Request<GetLayoutModeRequest>,
@@ -177,16 +333,16 @@ impl v1::window_service_server::WindowService for super::WindowService {
                         .unmapped_window(&state.pinnacle)
                         .map(|unmapped| unmapped.window.clone())
                 })
-                .map(|win| win.with_state(|state| state.layout_mode))
-                .unwrap_or(LayoutMode::new_tiled());
+                .map(|win| win.with_state(|state| state.layout_mode));
 
             Ok(GetLayoutModeResponse {
-                layout_mode: match layout_mode.current() {
-                    LayoutModeKind::Tiled => window::v1::LayoutMode::Tiled,
-                    LayoutModeKind::Floating => window::v1::LayoutMode::Floating,
-                    LayoutModeKind::Maximized => window::v1::LayoutMode::Maximized,
-                    LayoutModeKind::Fullscreen => window::v1::LayoutMode::Fullscreen,
-                    LayoutModeKind::Spilled => window::v1::LayoutMode::Floating,
+                layout_mode: match layout_mode.map(|mode| mode.current()) {
+                    Some(LayoutModeKind::Tiled) => window::v1::LayoutMode::Tiled,
+                    Some(LayoutModeKind::Floating) => window::v1::LayoutMode::Floating,
+                    Some(LayoutModeKind::Maximized) => window::v1::LayoutMode::Maximized,
+                    Some(LayoutModeKind::Fullscreen) => window::v1::LayoutMode::Fullscreen,
+                    Some(LayoutModeKind::Spilled) => window::v1::LayoutMode::Floating,
+                    None => window::v1::LayoutMode::Unspecified,
                 }
                 .into(),
             })
@@ -194,6 +350,85 @@ impl v1::window_service_server::WindowService for super::WindowService {
         .await
     }
 
+    async fn get_minimized(
+        &self,
+        request: Request<GetMinimizedRequest>,
+    ) -> TonicResult<GetMinimizedResponse> {
+        let window_id = WindowId(request.into_inner().window_id);
+
+        run_unary(&self.sender, move |state| {
+            let minimized = window_id
+                .window(&state.pinnacle)
+                .is_some_and(|win| win.with_state(|state| state.minimized));
+
+            Ok(GetMinimizedResponse { minimized })
+        })
+        .await
+    }
+
+    async fn get_demands_attention(
+        &self,
+        request: Request<GetDemandsAttentionRequest>,
+    ) -> TonicResult<GetDemandsAttentionResponse> {
+        let window_id = WindowId(request.into_inner().window_id);
+
+        run_unary(&self.sender, move |state| {
+            let demands_attention = window_id
+                .window(&state.pinnacle)
+                .is_some_and(|win| win.with_state(|state| state.urgent));
+
+            Ok(GetDemandsAttentionResponse { demands_attention })
+        })
+        .await
+    }
+
+    async fn get_icon(&self, request: Request<GetIconRequest>) -> TonicResult<GetIconResponse> {
+        let window_id = WindowId(request.into_inner().window_id);
+
+        let (icon, app_id) = run_unary(&self.sender, move |state| {
+            let Some(window) = window_id.window(&state.pinnacle) else {
+                return Ok((None, None));
+            };
+
+            Ok((
+                window.with_state(|state| state.icon.clone()),
+                window.class(),
+            ))
+        })
+        .await?
+        .into_inner();
+
+        if let Some(icon) = icon {
+            let images = icon
+                .images
+                .into_iter()
+                .map(|image| v1::IconImage {
+                    size: image.size as u32,
+                    scale: image.scale as u32,
+                    rgba: image.rgba,
+                })
+                .collect();
+
+            return Ok(Response::new(GetIconResponse {
+                name: icon.name,
+                images,
+            }));
+        }
+
+        // Windows without an icon of their own fall back to their desktop entry's
+        let name = match app_id {
+            Some(app_id) => tokio::task::spawn_blocking(move || desktop_entry_icon(&app_id))
+                .await
+                .map_err(|err| Status::internal(err.to_string()))?,
+            None => None,
+        };
+
+        Ok(Response::new(GetIconResponse {
+            name,
+            images: Vec::new(),
+        }))
+    }
+
     async fn get_tag_ids(
         &self,
         request: Request<GetTagIdsRequest>,
@@ -224,6 +459,55 @@ impl v1::window_service_server::WindowService for super::WindowService {
         .await
     }
 
+    async fn get_parent(
+        &self,
+        request: Request<GetParentRequest>,
+    ) -> TonicResult<GetParentResponse> {
+        let window_id = WindowId(request.into_inner().window_id);
+
+        run_unary(&self.sender, move |state| {
+            let parent_id = window_id
+                .window(&state.pinnacle)
+                .or_else(|| {
+                    window_id
+                        .unmapped_window(&state.pinnacle)
+                        .map(|unmapped| unmapped.window.clone())
+                })
+                .and_then(|win| {
+                    state
+                        .pinnacle
+                        .parent_window_for(&win)
+                        .map(|parent| parent.with_state(|state| state.id.0))
+                });
+
+            Ok(GetParentResponse { parent_id })
+        })
+        .await
+    }
+
+    async fn get_children(
+        &self,
+        request: Request<GetChildrenRequest>,
+    ) -> TonicResult<GetChildrenResponse> {
+        let window_id = WindowId(request.into_inner().window_id);
+
+        run_unary(&self.sender, move |state| {
+            let child_ids = window_id
+                .window(&state.pinnacle)
+                .map(|win| {
+                    state
+                        .pinnacle
+                        .child_windows_for(&win)
+                        .map(|child| child.with_state(|state| state.id.0))
+                        .collect()
+                })
+                .unwrap_or_default();
+
+            Ok(GetChildrenResponse { child_ids })
+        })
+        .await
+    }
+
     async fn get_windows_in_dir(
         &self,
         request: Request<GetWindowsInDirRequest>,
@@ -305,6 +589,28 @@ impl v1::window_service_server::WindowService for super::WindowService {
         .await
     }
 
+    async fn watch_properties(
+        &self,
+        request: Request<WatchPropertiesRequest>,
+    ) -> TonicResult<Self::WatchPropertiesStream> {
+        let window_id = WindowId(request.into_inner().window_id);
+
+        run_server_streaming(&self.sender, move |state, sender| {
+            if window_id.window(&state.pinnacle).is_none()
+                && window_id.unmapped_window(&state.pinnacle).is_none()
+            {
+                return Err(Status::not_found("window doesn't exist"));
+            }
+
+            state
+                .pinnacle
+                .window_watch_state
+                .subscribe(window_id, sender);
+            Ok(())
+        })
+        .await
+    }
+
     async fn close(&self, request: Request<CloseRequest>) -> TonicResult<()> {
         let request = request.into_inner();
 
@@ -533,6 +839,55 @@ impl v1::window_service_server::WindowService for super::WindowService {
         .await
     }
 
+    async fn set_minimized(&self, request: Request<SetMinimizedRequest>) -> TonicResult<()> {
+        let request = request.into_inner();
+
+        let window_id = WindowId(request.window_id);
+
+        let set = match request.set_or_toggle() {
+            SetOrToggle::Unspecified => {
+                return Err(Status::invalid_argument("unspecified set or toggle"));
+            }
+            SetOrToggle::Set => Some(true),
+            SetOrToggle::Unset => Some(false),
+            SetOrToggle::Toggle => None,
+        };
+
+        run_unary_no_response(&self.sender, move |state| {
+            if let Some(window) = window_id.window(&state.pinnacle) {
+                crate::api::window::set_minimized(state, &window, set);
+            }
+        })
+        .await
+    }
+
+    async fn set_demands_attention(
+        &self,
+        request: Request<SetDemandsAttentionRequest>,
+    ) -> TonicResult<()> {
+        let request = request.into_inner();
+
+        let window_id = WindowId(request.window_id);
+        let demands_attention = request.demands_attention;
+
+        run_unary_no_response(&self.sender, move |state| {
+            let Some(window) = window_id.window(&state.pinnacle) else {
+                return;
+            };
+
+            if demands_attention {
+                crate::api::tag::mark_urgent(&mut state.pinnacle, &window);
+            } else {
+                crate::api::tag::clear_urgent(&mut state.pinnacle, &window);
+            }
+
+            if let Some(output) = window.output(&state.pinnacle) {
+                state.schedule_render(&output);
+            }
+        })
+        .await
+    }
+
     async fn set_focused(&self, request: Request<SetFocusedRequest>) -> TonicResult<()> {
         let request = request.into_inner();
 
@@ -569,6 +924,64 @@ impl v1::window_service_server::WindowService for super::WindowService {
         .await
     }
 
+    async fn focus_in_direction(
+        &self,
+        request: Request<FocusInDirectionRequest>,
+    ) -> TonicResult<FocusInDirectionResponse> {
+        let dir = match request.into_inner().dir() {
+            util::v1::Dir::Unspecified => {
+                return Err(Status::invalid_argument("no dir was specified"));
+            }
+            util::v1::Dir::Left => Direction::Left,
+            util::v1::Dir::Right => Direction::Right,
+            util::v1::Dir::Up => Direction::Up,
+            util::v1::Dir::Down => Direction::Down,
+        };
+
+        run_unary(&self.sender, move |state| {
+            let window_id = crate::api::window::focus_in_direction(state, dir)
+                .map(|win| win.with_state(|state| state.id.0));
+
+            Ok(FocusInDirectionResponse { window_id })
+        })
+        .await
+    }
+
+    async fn begin_cycle(
+        &self,
+        _request: Request<BeginCycleRequest>,
+    ) -> TonicResult<BeginCycleResponse> {
+        run_unary(&self.sender, move |state| {
+            state.begin_window_cycle();
+            Ok(BeginCycleResponse {})
+        })
+        .await
+    }
+
+    async fn cycle(&self, request: Request<CycleRequest>) -> TonicResult<CycleResponse> {
+        let forward = !request.into_inner().backward;
+
+        run_unary(&self.sender, move |state| {
+            let window_id = state
+                .cycle_windows(forward)
+                .map(|win| win.with_state(|state| state.id.0));
+
+            Ok(CycleResponse { window_id })
+        })
+        .await
+    }
+
+    async fn end_cycle(&self, _request: Request<EndCycleRequest>) -> TonicResult<EndCycleResponse> {
+        run_unary(&self.sender, move |state| {
+            let window_id = state
+                .end_window_cycle()
+                .map(|win| win.with_state(|state| state.id.0));
+
+            Ok(EndCycleResponse { window_id })
+        })
+        .await
+    }
+
     async fn set_decoration_mode(
         &self,
         request: Request<SetDecorationModeRequest>,
@@ -602,16 +1015,25 @@ impl v1::window_service_server::WindowService for super::WindowService {
 
         let window_id = WindowId(request.window_id);
         let tag_id = TagId::new(request.tag_id);
+        let follow = request.follow;
 
         run_unary_no_response(&self.sender, move |state| {
             let Some(tag) = tag_id.tag(&state.pinnacle) else { return };
 
             if let Some(window) = window_id.window(&state.pinnacle) {
-                crate::api::window::move_to_tag(state, &window, &tag);
+                if follow {
+                    crate::api::window::move_to_tag_and_follow(state, &window, &tag);
+                } else {
+                    crate::api::window::move_to_tag(state, &window, &tag);
+                }
             } else if let Some(unmapped) = window_id.unmapped_window_mut(&mut state.pinnacle)
                 && let UnmappedState::WaitingForRules { rules, .. } = &mut unmapped.state
             {
-                rules.tags = Some([tag].into_iter().collect());
+                rules.tags = Some([tag.clone()].into_iter().collect());
+
+                if follow {
+                    crate::api::tag::switch_to(state, &tag);
+                }
             }
         })
         .await
@@ -791,6 +1213,27 @@ impl v1::window_service_server::WindowService for super::WindowService {
         .await
     }
 
+    async fn set_layer(&self, request: Request<SetLayerRequest>) -> TonicResult<SetLayerResponse> {
+        let request = request.into_inner();
+        let window_id = WindowId(request.window_id);
+
+        let Some(layer) = window_layer_from_api(request.layer()) else {
+            return Err(Status::invalid_argument("window layer was unspecified"));
+        };
+
+        run_unary(&self.sender, move |state| {
+            if let Some(window) = window_id.window(&state.pinnacle) {
+                crate::api::window::set_layer(state, window, layer);
+            } else if let Some(unmapped) = window_id.unmapped_window(&state.pinnacle) {
+                // Takes effect once the window is mapped and raised
+                unmapped.window.with_state_mut(|state| state.layer = layer);
+            }
+
+            Ok(SetLayerResponse {})
+        })
+        .await
+    }
+
     async fn move_grab(&self, request: Request<MoveGrabRequest>) -> TonicResult<()> {
         let request = request.into_inner();
         let button = request.button;
@@ -830,54 +1273,446 @@ impl v1::window_service_server::WindowService for super::WindowService {
         .await
     }
 
-    async fn window_rule(
+    async fn swap_in_direction(
         &self,
-        request: Request<Streaming<WindowRuleRequest>>,
-    ) -> TonicResult<Self::WindowRuleStream> {
-        let in_stream = request.into_inner();
-
-        let id_ctr = Arc::new(AtomicU32::default());
-
-        run_bidirectional_streaming_mapped(
-            self.sender.clone(),
-            in_stream,
-            {
-                let id_ctr = id_ctr.clone();
-                move |state, request| {
-                    let Some(request) = request.request else {
-                        return;
-                    };
+        request: Request<SwapInDirectionRequest>,
+    ) -> TonicResult<SwapInDirectionResponse> {
+        let request = request.into_inner();
+        let window_id = WindowId(request.window_id);
 
-                    match request {
-                        v1::window_rule_request::Request::Finished(finished) => {
-                            let id = finished.request_id;
-                            id_ctr.store(id, Ordering::Release);
+        let dir = match request.dir() {
+            util::v1::Dir::Unspecified => {
+                return Err(Status::invalid_argument("no dir was specified"));
+            }
+            util::v1::Dir::Left => Direction::Left,
+            util::v1::Dir::Right => Direction::Right,
+            util::v1::Dir::Up => Direction::Up,
+            util::v1::Dir::Down => Direction::Down,
+        };
 
-                            for win in state.pinnacle.window_rule_state.finished_windows() {
-                                let Some(unmapped_idx) = state
-                                    .pinnacle
-                                    .unmapped_windows
-                                    .iter_mut()
-                                    .position(|unmapped| unmapped.window == win)
-                                else {
-                                    continue;
-                                };
+        run_unary(&self.sender, move |state| {
+            let Some(window) = window_id.window(&state.pinnacle) else {
+                return Ok(SwapInDirectionResponse::default());
+            };
 
-                                let mut unmapped =
-                                    state.pinnacle.unmapped_windows.swap_remove(unmapped_idx);
+            let target_id = crate::api::window::swap_in_direction(state, &window, dir)
+                .map(|target| target.with_state(|state| state.id.0));
 
-                                state
-                                    .pinnacle
-                                    .apply_window_rules_and_send_initial_configure(&mut unmapped);
+            Ok(SwapInDirectionResponse { target_id })
+        })
+        .await
+    }
 
-                                state.pinnacle.unmapped_windows.push(unmapped);
-                            }
+    async fn set_floating_visibility(
+        &self,
+        request: Request<SetFloatingVisibilityRequest>,
+    ) -> TonicResult<SetFloatingVisibilityResponse> {
+        let policy = request
+            .into_inner()
+            .policy
+            .ok_or_else(|| Status::invalid_argument("no policy specified"))?;
+
+        let containment = match policy {
+            v1::set_floating_visibility_request::Policy::Unrestricted(_) => None,
+            v1::set_floating_visibility_request::Policy::KeepVisible(keep_visible) => Some(
+                Containment::Partial(keep_visible.pixels.try_into().unwrap_or(i32::MAX)),
+            ),
+            v1::set_floating_visibility_request::Policy::ClampToOutputs(_) => {
+                Some(Containment::Full)
+            }
+        };
+
+        run_unary(&self.sender, move |state| {
+            state.pinnacle.config.floating_containment = containment;
+            Ok(SetFloatingVisibilityResponse {})
+        })
+        .await
+    }
+
+    async fn set_allow_offscreen(
+        &self,
+        request: Request<SetAllowOffscreenRequest>,
+    ) -> TonicResult<SetAllowOffscreenResponse> {
+        let request = request.into_inner();
+
+        let window_id = WindowId(request.window_id);
+        let allow_offscreen = request.allow_offscreen;
+
+        run_unary(&self.sender, move |state| {
+            let Some(window) = window_id.window(&state.pinnacle).or_else(|| {
+                window_id
+                    .unmapped_window(&state.pinnacle)
+                    .map(|unmapped| unmapped.window.clone())
+            }) else {
+                return Ok(SetAllowOffscreenResponse {});
+            };
+
+            window.with_state_mut(|state| {
+                state.allow_offscreen = allow_offscreen;
+            });
+
+            Ok(SetAllowOffscreenResponse {})
+        })
+        .await
+    }
+
+    async fn set_input_enabled(
+        &self,
+        request: Request<SetInputEnabledRequest>,
+    ) -> TonicResult<SetInputEnabledResponse> {
+        let request = request.into_inner();
+
+        let window_id = WindowId(request.window_id);
+        let enabled = request.enabled;
+
+        run_unary(&self.sender, move |state| {
+            let Some(window) = window_id.window(&state.pinnacle).or_else(|| {
+                window_id
+                    .unmapped_window(&state.pinnacle)
+                    .map(|unmapped| unmapped.window.clone())
+            }) else {
+                return Ok(SetInputEnabledResponse {});
+            };
+
+            window.with_state_mut(|state| {
+                state.input_disabled = !enabled;
+            });
+
+            // Move the pointer and keyboard focus off of or back onto the window
+            state.update_pointer_focus();
+            state.update_keyboard_focus();
+
+            Ok(SetInputEnabledResponse {})
+        })
+        .await
+    }
+
+    async fn set_hold_to_move_enabled(
+        &self,
+        request: Request<SetHoldToMoveEnabledRequest>,
+    ) -> TonicResult<SetHoldToMoveEnabledResponse> {
+        let request = request.into_inner();
+
+        let window_id = WindowId(request.window_id);
+        let enabled = request.enabled;
+
+        run_unary(&self.sender, move |state| {
+            let Some(window) = window_id.window(&state.pinnacle).or_else(|| {
+                window_id
+                    .unmapped_window(&state.pinnacle)
+                    .map(|unmapped| unmapped.window.clone())
+            }) else {
+                return Ok(SetHoldToMoveEnabledResponse {});
+            };
+
+            window.with_state_mut(|state| state.hold_to_move_enabled = enabled);
+
+            Ok(SetHoldToMoveEnabledResponse {})
+        })
+        .await
+    }
+
+    async fn set_scale_override(
+        &self,
+        request: Request<SetScaleOverrideRequest>,
+    ) -> TonicResult<SetScaleOverrideResponse> {
+        let request = request.into_inner();
+
+        let window_id = WindowId(request.window_id);
+
+        if let Some(scale) = request.scale
+            && !(scale.is_finite() && scale >= 0.25)
+        {
+            return Err(Status::invalid_argument("scale must be at least 0.25"));
+        }
+
+        let scale = request.scale.map(f64::from);
+
+        run_unary(&self.sender, move |state| {
+            let Some(window) = window_id.window(&state.pinnacle).or_else(|| {
+                window_id
+                    .unmapped_window(&state.pinnacle)
+                    .map(|unmapped| unmapped.window.clone())
+            }) else {
+                return Ok(SetScaleOverrideResponse {});
+            };
+
+            window.set_scale_override(&state.pinnacle, scale);
+
+            if let Some(output) = window.output(&state.pinnacle) {
+                state.schedule_render(&output);
+            }
+
+            Ok(SetScaleOverrideResponse {})
+        })
+        .await
+    }
+
+    async fn snap_to_region(
+        &self,
+        request: Request<SnapToRegionRequest>,
+    ) -> TonicResult<SnapToRegionResponse> {
+        let request = request.into_inner();
+
+        let window_id = WindowId(request.window_id);
+
+        let Some(region) = request.region else {
+            return Err(Status::invalid_argument("region was unspecified"));
+        };
+
+        run_unary(&self.sender, move |state| {
+            let region = match region {
+                snap_to_region_request::Region::Preset(preset) => {
+                    let preset =
+                        v1::SnapPreset::try_from(preset).unwrap_or(v1::SnapPreset::Unspecified);
+                    snap_preset_region(preset)
+                        .ok_or_else(|| Status::invalid_argument("snap preset was unspecified"))?
+                }
+                snap_to_region_request::Region::GridCell(cell) => {
+                    let region = SnapRegion {
+                        grid: state.pinnacle.config.snap_grid,
+                        column: cell.column,
+                        row: cell.row,
+                        column_span: cell.column_span,
+                        row_span: cell.row_span,
+                    };
+
+                    if !region.is_valid() {
+                        return Err(Status::invalid_argument(
+                            "grid cell is empty or outside the snap grid",
+                        ));
+                    }
+
+                    region
+                }
+            };
+
+            if let Some(window) = window_id.window(&state.pinnacle) {
+                state.snap_window_to_region(&window, region);
+            }
+
+            Ok(SnapToRegionResponse {})
+        })
+        .await
+    }
+
+    async fn set_snap_grid(
+        &self,
+        request: Request<SetSnapGridRequest>,
+    ) -> TonicResult<SetSnapGridResponse> {
+        let request = request.into_inner();
+
+        if request.columns == 0 || request.rows == 0 {
+            return Err(Status::invalid_argument(
+                "snap grid must have at least one column and row",
+            ));
+        }
+
+        run_unary(&self.sender, move |state| {
+            state.pinnacle.config.snap_grid = SnapGrid {
+                columns: request.columns,
+                rows: request.rows,
+            };
+
+            Ok(SetSnapGridResponse {})
+        })
+        .await
+    }
+
+    async fn set_popup_policy(
+        &self,
+        request: Request<SetPopupPolicyRequest>,
+    ) -> TonicResult<SetPopupPolicyResponse> {
+        let request = request.into_inner();
+
+        run_unary(&self.sender, move |state| {
+            state.pinnacle.config.popup_policy = PopupPolicy {
+                clamp_to_output: request.clamp_to_output,
+                close_on_tag_switch: request.close_on_tag_switch,
+                max_grab_duration: request
+                    .max_grab_duration_ms
+                    .map(|ms| Duration::from_millis(ms.into())),
+            };
+
+            Ok(SetPopupPolicyResponse {})
+        })
+        .await
+    }
+
+    async fn set_grey_out_unresponsive(
+        &self,
+        request: Request<SetGreyOutUnresponsiveRequest>,
+    ) -> TonicResult<SetGreyOutUnresponsiveResponse> {
+        let grey_out = request.into_inner().grey_out;
+
+        run_unary(&self.sender, move |state| {
+            state.pinnacle.config.grey_out_unresponsive = grey_out;
+            Ok(SetGreyOutUnresponsiveResponse {})
+        })
+        .await
+    }
+
+    async fn set_maximize_request_policy(
+        &self,
+        request: Request<SetMaximizeRequestPolicyRequest>,
+    ) -> TonicResult<SetMaximizeRequestPolicyResponse> {
+        let request = request.into_inner();
+
+        let Some(policy) = maximize_request_policy_from_api(request.policy()) else {
+            return Err(Status::invalid_argument(
+                "maximize request policy was unspecified",
+            ));
+        };
+
+        run_unary(&self.sender, move |state| {
+            let Some(window_id) = request.window_id.map(WindowId) else {
+                state.pinnacle.config.maximize_request_policy = policy;
+                return Ok(SetMaximizeRequestPolicyResponse {});
+            };
+
+            if let Some(window) = window_id.window(&state.pinnacle) {
+                window.with_state_mut(|state| state.maximize_request_policy = Some(policy));
+            } else if let Some(unmapped) = window_id.unmapped_window_mut(&mut state.pinnacle)
+                && let UnmappedState::WaitingForRules { rules, .. } = &mut unmapped.state
+            {
+                rules.maximize_request_policy = Some(policy);
+            }
+
+            Ok(SetMaximizeRequestPolicyResponse {})
+        })
+        .await
+    }
+
+    async fn set_tag_inheritance(
+        &self,
+        request: Request<SetTagInheritanceRequest>,
+    ) -> TonicResult<SetTagInheritanceResponse> {
+        let inheritance = match request.into_inner().inheritance() {
+            v1::TagInheritance::Unspecified => {
+                return Err(Status::invalid_argument("tag inheritance was unspecified"));
+            }
+            v1::TagInheritance::ActiveTags => TagInheritance::ActiveTags,
+            v1::TagInheritance::FirstActiveTag => TagInheritance::FirstActiveTag,
+            v1::TagInheritance::Parent => TagInheritance::Parent,
+        };
+
+        run_unary(&self.sender, move |state| {
+            state.pinnacle.config.tag_inheritance = inheritance;
+            Ok(SetTagInheritanceResponse {})
+        })
+        .await
+    }
+
+    async fn set_dialog_policy(
+        &self,
+        request: Request<SetDialogPolicyRequest>,
+    ) -> TonicResult<SetDialogPolicyResponse> {
+        let policy = match request.into_inner().policy() {
+            v1::DialogPolicy::Unspecified => {
+                return Err(Status::invalid_argument("dialog policy was unspecified"));
+            }
+            v1::DialogPolicy::Independent => DialogPolicy::Independent,
+            v1::DialogPolicy::AttachToParent => DialogPolicy::AttachToParent,
+        };
+
+        run_unary(&self.sender, move |state| {
+            state.pinnacle.config.dialog_policy = policy;
+            Ok(SetDialogPolicyResponse {})
+        })
+        .await
+    }
+
+    async fn set_size_constraints(
+        &self,
+        request: Request<SetSizeConstraintsRequest>,
+    ) -> TonicResult<SetSizeConstraintsResponse> {
+        let request = request.into_inner();
+
+        let window_id = WindowId(request.window_id);
+        let constraints = size_constraints_from_api(request.constraints.unwrap_or_default())?;
+
+        run_unary(&self.sender, move |state| {
+            if let Some(window) = window_id.window(&state.pinnacle) {
+                window.with_state_mut(|state| state.size_constraints = constraints);
+
+                let is_tiled = window.with_state(|state| state.layout_mode.is_tiled());
+                state.pinnacle.update_window_geometry(&window, is_tiled);
+            } else if let Some(unmapped) = window_id.unmapped_window_mut(&mut state.pinnacle)
+                && let UnmappedState::WaitingForRules { rules, .. } = &mut unmapped.state
+            {
+                rules.size_constraints = constraints;
+            }
+
+            Ok(SetSizeConstraintsResponse {})
+        })
+        .await
+    }
+
+    async fn set_border_style(
+        &self,
+        request: Request<SetBorderStyleRequest>,
+    ) -> TonicResult<SetBorderStyleResponse> {
+        let request = request.into_inner();
+
+        let style = request.style.map(border_style_from_api);
+
+        run_unary(&self.sender, move |state| {
+            let Some(window_id) = request.window_id.map(WindowId) else {
+                state.pinnacle.config.border_style = style;
+                return Ok(SetBorderStyleResponse {});
+            };
+
+            // An empty style keeps the window from falling back to the global style
+            let style = style.unwrap_or_default();
+
+            if let Some(window) = window_id.window(&state.pinnacle) {
+                window.with_state_mut(|state| state.border_style = Some(style));
+            } else if let Some(unmapped) = window_id.unmapped_window_mut(&mut state.pinnacle)
+                && let UnmappedState::WaitingForRules { rules, .. } = &mut unmapped.state
+            {
+                rules.border_style = Some(style);
+            }
+
+            Ok(SetBorderStyleResponse {})
+        })
+        .await
+    }
+
+    async fn window_rule(
+        &self,
+        request: Request<Streaming<WindowRuleRequest>>,
+    ) -> TonicResult<Self::WindowRuleStream> {
+        let pid = client_pid(&request);
+        let in_stream = request.into_inner();
+
+        let id_ctr = Arc::new(AtomicU32::default());
+
+        run_bidirectional_streaming_mapped(
+            self.sender.clone(),
+            in_stream,
+            {
+                let id_ctr = id_ctr.clone();
+                move |state, request| {
+                    let Some(request) = request.request else {
+                        return;
+                    };
+
+                    match request {
+                        v1::window_rule_request::Request::Finished(finished) => {
+                            let id = finished.request_id;
+                            id_ctr.store(id, Ordering::Release);
+
+                            state.pinnacle.apply_finished_window_rules();
                         }
                     }
                 }
             },
-            |state, sender, _join_handle| {
-                state.pinnacle.window_rule_state.new_sender(sender, id_ctr);
+            move |state, sender, _join_handle| {
+                let namespace = state.pinnacle.namespace_state.namespace_of(pid);
+                state
+                    .pinnacle
+                    .window_rule_state
+                    .new_sender(sender, id_ctr, namespace);
             },
             |request| {
                 Ok(WindowRuleResponse {
@@ -891,4 +1726,253 @@ impl v1::window_service_server::WindowService for super::WindowService {
             },
         )
     }
+
+    async fn set_window_rules(
+        &self,
+        request: Request<SetWindowRulesRequest>,
+    ) -> TonicResult<SetWindowRulesResponse> {
+        let request = request.into_inner();
+
+        let strategy = match request.strategy() {
+            v1::WindowRuleStrategy::Unspecified | v1::WindowRuleStrategy::Merge => {
+                RuleStrategy::Merge
+            }
+            v1::WindowRuleStrategy::LastMatchWins => RuleStrategy::LastMatchWins,
+        };
+
+        let size_constraints = request
+            .rules
+            .iter()
+            .map(|rule| size_constraints_from_api(rule.size_constraints.unwrap_or_default()))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        run_unary(&self.sender, move |state| {
+            let rules = request
+                .rules
+                .into_iter()
+                .zip(size_constraints)
+                .map(|(rule, size_constraints)| {
+                    let layout_mode = match rule.layout_mode() {
+                        v1::LayoutMode::Unspecified => None,
+                        v1::LayoutMode::Tiled => Some(LayoutMode::new_tiled()),
+                        v1::LayoutMode::Floating => Some(LayoutMode::new_floating()),
+                        v1::LayoutMode::Fullscreen => Some(LayoutMode::new_fullscreen()),
+                        v1::LayoutMode::Maximized => Some(LayoutMode::new_maximized()),
+                    };
+
+                    let decoration_mode = match rule.decoration_mode() {
+                        v1::DecorationMode::Unspecified => None,
+                        v1::DecorationMode::ClientSide => {
+                            Some(zxdg_toplevel_decoration_v1::Mode::ClientSide)
+                        }
+                        v1::DecorationMode::ServerSide => {
+                            Some(zxdg_toplevel_decoration_v1::Mode::ServerSide)
+                        }
+                    };
+
+                    let floating_size = rule
+                        .floating_w
+                        .zip(rule.floating_h)
+                        .map(|(w, h)| Size::from((w as i32, h as i32)));
+
+                    let maximize_request_policy =
+                        maximize_request_policy_from_api(rule.maximize_request_policy());
+
+                    let tags = rule
+                        .tag_ids
+                        .into_iter()
+                        .filter_map(|id| TagId::new(id).tag(&state.pinnacle))
+                        .collect::<IndexSet<_>>();
+
+                    DeclarativeRule {
+                        name: rule.name,
+                        priority: rule.priority,
+                        app_id: rule.app_id,
+                        title: rule.title,
+                        rules: WindowRules {
+                            layout_mode,
+                            focused: rule.focused,
+                            floating_x: rule.floating_x,
+                            floating_y: rule.floating_y,
+                            floating_size,
+                            decoration_mode,
+                            tags: (!tags.is_empty()).then_some(tags),
+                            maximize_request_policy,
+                            size_constraints,
+                            border_style: rule.border_style.map(border_style_from_api),
+                        },
+                    }
+                })
+                .collect();
+
+            state
+                .pinnacle
+                .window_rule_state
+                .set_declarative_rules(rules, strategy);
+
+            Ok(SetWindowRulesResponse {})
+        })
+        .await
+    }
+
+    async fn get_matching_window_rules(
+        &self,
+        request: Request<GetMatchingWindowRulesRequest>,
+    ) -> TonicResult<GetMatchingWindowRulesResponse> {
+        let window_id = WindowId(request.into_inner().window_id);
+
+        run_unary(&self.sender, move |state| {
+            let Some(window) = window_id.window(&state.pinnacle).or_else(|| {
+                window_id
+                    .unmapped_window(&state.pinnacle)
+                    .map(|unmapped| unmapped.window.clone())
+            }) else {
+                return Ok(GetMatchingWindowRulesResponse::default());
+            };
+
+            let rule_names = state
+                .pinnacle
+                .window_rule_state
+                .matching_rules(&window)
+                .into_iter()
+                .map(|rule| rule.name.clone())
+                .collect();
+
+            Ok(GetMatchingWindowRulesResponse { rule_names })
+        })
+        .await
+    }
+
+    async fn apply_rules_to_existing(
+        &self,
+        _request: Request<ApplyRulesToExistingRequest>,
+    ) -> TonicResult<ApplyRulesToExistingResponse> {
+        run_unary(&self.sender, move |state| {
+            let windows = crate::api::window::apply_rules_to_existing(state)
+                .into_iter()
+                .map(
+                    |(window, changed)| apply_rules_to_existing_response::WindowChanges {
+                        window_id: window.with_state(|state| state.id.0),
+                        changed: changed
+                            .into_iter()
+                            .map(|property| rule_property_to_api(property).into())
+                            .collect(),
+                    },
+                )
+                .collect();
+
+            Ok(ApplyRulesToExistingResponse { windows })
+        })
+        .await
+    }
+}
+
+fn rule_property_to_api(property: RuleProperty) -> v1::WindowRuleProperty {
+    match property {
+        RuleProperty::LayoutMode => v1::WindowRuleProperty::LayoutMode,
+        RuleProperty::FloatingGeometry => v1::WindowRuleProperty::FloatingGeometry,
+        RuleProperty::DecorationMode => v1::WindowRuleProperty::DecorationMode,
+        RuleProperty::Tags => v1::WindowRuleProperty::Tags,
+        RuleProperty::MaximizeRequestPolicy => v1::WindowRuleProperty::MaximizeRequestPolicy,
+        RuleProperty::SizeConstraints => v1::WindowRuleProperty::SizeConstraints,
+        RuleProperty::BorderStyle => v1::WindowRuleProperty::BorderStyle,
+    }
+}
+
+fn snap_preset_region(preset: v1::SnapPreset) -> Option<SnapRegion> {
+    let (columns, rows, column, row, column_span) = match preset {
+        v1::SnapPreset::Unspecified => return None,
+        v1::SnapPreset::LeftHalf => (2, 1, 0, 0, 1),
+        v1::SnapPreset::RightHalf => (2, 1, 1, 0, 1),
+        v1::SnapPreset::TopHalf => (1, 2, 0, 0, 1),
+        v1::SnapPreset::BottomHalf => (1, 2, 0, 1, 1),
+        v1::SnapPreset::LeftThird => (3, 1, 0, 0, 1),
+        v1::SnapPreset::CenterThird => (3, 1, 1, 0, 1),
+        v1::SnapPreset::RightThird => (3, 1, 2, 0, 1),
+        v1::SnapPreset::LeftTwoThirds => (3, 1, 0, 0, 2),
+        v1::SnapPreset::RightTwoThirds => (3, 1, 1, 0, 2),
+        v1::SnapPreset::TopLeftQuarter => (2, 2, 0, 0, 1),
+        v1::SnapPreset::TopRightQuarter => (2, 2, 1, 0, 1),
+        v1::SnapPreset::BottomLeftQuarter => (2, 2, 0, 1, 1),
+        v1::SnapPreset::BottomRightQuarter => (2, 2, 1, 1, 1),
+    };
+
+    Some(SnapRegion {
+        grid: SnapGrid { columns, rows },
+        column,
+        row,
+        column_span,
+        row_span: 1,
+    })
+}
+
+fn size_constraints_from_api(constraints: v1::SizeConstraints) -> Result<SizeConstraints, Status> {
+    if let Some(aspect_ratio) = constraints.aspect_ratio
+        && !(aspect_ratio.is_finite() && aspect_ratio > 0.0)
+    {
+        return Err(Status::invalid_argument("aspect ratio must be positive"));
+    }
+
+    let clamp = |value: u32| i32::try_from(value).unwrap_or(i32::MAX).max(1);
+
+    let min_size = (constraints.min_w.is_some() || constraints.min_h.is_some()).then(|| {
+        Size::from((
+            constraints.min_w.map_or(1, clamp),
+            constraints.min_h.map_or(1, clamp),
+        ))
+    });
+    let max_size = (constraints.max_w.is_some() || constraints.max_h.is_some()).then(|| {
+        Size::from((
+            constraints.max_w.map_or(i32::MAX, clamp),
+            constraints.max_h.map_or(i32::MAX, clamp),
+        ))
+    });
+
+    Ok(SizeConstraints {
+        min_size,
+        max_size,
+        aspect_ratio: constraints.aspect_ratio,
+    })
+}
+
+fn window_layer_from_api(layer: v1::WindowLayer) -> Option<WindowLayer> {
+    match layer {
+        v1::WindowLayer::Unspecified => None,
+        v1::WindowLayer::Below => Some(WindowLayer::Below),
+        v1::WindowLayer::Normal => Some(WindowLayer::Normal),
+        v1::WindowLayer::Above => Some(WindowLayer::Above),
+    }
+}
+
+fn border_style_from_api(style: v1::BorderStyle) -> BorderStyle {
+    let color = |color: Option<util::v1::Color>| {
+        color.map_or([0.0; 4], |color| {
+            [color.red, color.green, color.blue, color.alpha].map(|channel| channel.clamp(0.0, 1.0))
+        })
+    };
+
+    BorderStyle {
+        width: style.width,
+        focused_color: color(style.focused_color),
+        unfocused_color: color(style.unfocused_color),
+        urgent_color: color(style.urgent_color),
+        corner_radius: style.corner_radius.max(0.0),
+        titlebar_height: style.titlebar_height,
+    }
+}
+
+fn maximize_request_policy_from_api(
+    policy: v1::MaximizeRequestPolicy,
+) -> Option<MaximizeRequestPolicy> {
+    match policy {
+        v1::MaximizeRequestPolicy::Unspecified => None,
+        v1::MaximizeRequestPolicy::Honor => Some(MaximizeRequestPolicy::Honor),
+        v1::MaximizeRequestPolicy::Ignore => Some(MaximizeRequestPolicy::Ignore),
+        v1::MaximizeRequestPolicy::FullscreenWithinTile => {
+            Some(MaximizeRequestPolicy::FullscreenWithinTile)
+        }
+        v1::MaximizeRequestPolicy::FloatAndMaximize => {
+            Some(MaximizeRequestPolicy::FloatAndMaximize)
+        }
+    }
 }
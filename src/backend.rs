@@ -18,7 +18,7 @@ use tracing::{error, warn};
 use wayland_backend::server::GlobalId;
 
 use crate::{
-    output::OutputMode,
+    output::{ColorDepth, OutputMode},
     state::{Pinnacle, State, WithState},
 };
 
@@ -112,6 +112,34 @@ impl Backend {
         }
     }
 
+    pub fn set_output_hdr(&mut self, output: &Output, hdr: bool) -> anyhow::Result<()> {
+        match self {
+            Backend::Winit(_) => anyhow::bail!("HDR is not supported in the nested backend"),
+            Backend::Udev(udev) => udev.set_output_hdr(output, hdr),
+            #[cfg(feature = "testing")]
+            Backend::Dummy(_) => Ok(()),
+        }
+    }
+
+    pub fn set_output_color_depth(
+        &mut self,
+        pinnacle: &mut Pinnacle,
+        output: &Output,
+        color_depth: ColorDepth,
+    ) -> anyhow::Result<()> {
+        match self {
+            Backend::Winit(_) => {
+                anyhow::bail!("changing the color depth is not supported in the nested backend")
+            }
+            Backend::Udev(udev) => udev.set_output_color_depth(pinnacle, output, color_depth),
+            #[cfg(feature = "testing")]
+            Backend::Dummy(_) => {
+                output.with_state_mut(|state| state.color_depth = color_depth);
+                Ok(())
+            }
+        }
+    }
+
     fn set_output_powered(
         &mut self,
         output: &Output,
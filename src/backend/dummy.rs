@@ -123,6 +123,7 @@ impl Pinnacle {
         output.with_state_mut(|state| state.enabled_global_id = Some(global));
 
         self.outputs.push(output.clone());
+        self.pager_state.mark_dirty();
 
         self.space.map_output(&output, loc);
 
@@ -1,8 +1,10 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
 
+mod color_depth;
 mod drm;
 mod frame;
 mod gamma;
+mod hdr;
 
 use assert_matches::assert_matches;
 pub use drm::drm_mode_from_modeinfo;
@@ -18,11 +20,12 @@ use smithay::{
     backend::{
         SwapBuffersError,
         allocator::{
-            Buffer, Fourcc,
-            gbm::{GbmAllocator, GbmBuffer, GbmBufferFlags, GbmDevice},
+            Buffer,
+            gbm::{GbmAllocator, GbmBuffer, GbmDevice},
         },
         drm::{
-            DrmDevice, DrmDeviceFd, DrmEvent, DrmEventMetadata, DrmNode, DrmSurface, NodeType,
+            DrmDevice, DrmDeviceFd, DrmDeviceNotifier, DrmEvent, DrmEventMetadata, DrmNode,
+            DrmSurface, NodeType,
             compositor::{FrameFlags, PrimaryPlaneElement, RenderFrameResult},
             exporter::gbm::GbmFramebufferExporter,
             gbm::GbmFramebuffer,
@@ -81,7 +84,7 @@ use crate::{
     backend::Backend,
     config::ConnectorSavedState,
     input::libinput::DeviceState,
-    output::{BlankingState, OutputMode, OutputName},
+    output::{BlankingState, ColorDepth, OutputMode, OutputName, edid::EdidInfo},
     render::{
         CLEAR_COLOR, CLEAR_COLOR_LOCKED, OutputRenderElement, pointer::pointer_render_elements,
         take_presentation_feedback,
@@ -91,14 +94,6 @@ use crate::{
 
 use super::{BackendData, UninitBackend};
 
-const SUPPORTED_FORMATS: &[Fourcc] = &[
-    Fourcc::Abgr2101010,
-    Fourcc::Argb2101010,
-    Fourcc::Abgr8888,
-    Fourcc::Argb8888,
-];
-const SUPPORTED_FORMATS_8BIT_ONLY: &[Fourcc] = &[Fourcc::Abgr8888, Fourcc::Argb8888];
-
 /// A [`MultiRenderer`] that uses the [`GbmGlesBackend`].
 pub type UdevRenderer<'a> = MultiRenderer<
     'a,
@@ -131,6 +126,11 @@ pub struct Udev {
     dmabuf_global: Option<DmabufGlobal>,
     drm_global: Option<GlobalId>,
 
+    /// Devices with connector changes waiting for hotplug events to settle
+    pending_device_changes: IndexSet<DrmNode>,
+    /// The timer that scans `pending_device_changes` once events settle
+    device_change_timer: Option<RegistrationToken>,
+
     pub(super) upscale_filter: TextureFilter,
     pub(super) downscale_filter: TextureFilter,
 }
@@ -217,7 +217,7 @@ impl Udev {
                 }
                 UdevEvent::Changed { device_id } => {
                     if let Ok(node) = DrmNode::from_dev_id(device_id) {
-                        udev.device_changed(pinnacle, node)
+                        udev.schedule_device_changed(pinnacle, node)
                     }
                 }
                 // GPU disconnected
@@ -239,6 +239,9 @@ impl Udev {
             dmabuf_global: None,
             drm_global: None,
 
+            pending_device_changes: IndexSet::new(),
+            device_change_timer: None,
+
             upscale_filter: TextureFilter::Linear,
             downscale_filter: TextureFilter::Linear,
         };
@@ -525,12 +528,16 @@ impl Udev {
                 warn!("Failed to clear compositor state on crtc {crtc:?}: {err}");
             }
 
-            if let Some(surface) = render_surface_for_output(output, &mut self.devices)
-                && let RenderState::WaitingForEstimatedVblankAndScheduled(token)
+            if let Some(surface) = render_surface_for_output(output, &mut self.devices) {
+                // The last presentation time is stale once the output comes back on
+                surface.frame_clock.reset();
+
+                if let RenderState::WaitingForEstimatedVblankAndScheduled(token)
                 | RenderState::WaitingForEstimatedVblank(token) =
                     mem::take(&mut surface.render_state)
-            {
-                loop_handle.remove(token);
+                {
+                    loop_handle.remove(token);
+                }
             }
         }
     }
@@ -639,19 +646,47 @@ impl BackendData for Udev {
 }
 
 // TODO: document desperately
+type UdevOutputManager = DrmOutputManager<
+    GbmAllocator<DrmDeviceFd>,
+    GbmFramebufferExporter<DrmDeviceFd>,
+    Option<OutputPresentationFeedback>,
+    DrmDeviceFd,
+>;
+
 struct Device {
     surfaces: HashMap<crtc::Handle, RenderSurface>,
-    drm_output_manager: DrmOutputManager<
-        GbmAllocator<DrmDeviceFd>,
-        GbmFramebufferExporter<DrmDeviceFd>,
-        Option<OutputPresentationFeedback>,
-        DrmDeviceFd,
-    >,
+    drm_output_manager: UdevOutputManager,
     drm_scanner: DrmScanner,
     render_node: DrmNode,
+    gbm: GbmDevice<DrmDeviceFd>,
+    /// The color depth outputs on this device are set up with.
+    color_depth: ColorDepth,
     registration_token: RegistrationToken,
 }
 
+fn insert_drm_notifier(
+    loop_handle: &LoopHandle<'static, State>,
+    node: DrmNode,
+    notifier: DrmDeviceNotifier,
+) -> RegistrationToken {
+    loop_handle
+        .insert_source(notifier, move |event, metadata, state| {
+            let metadata = metadata.expect("vblank events must have metadata");
+            match event {
+                DrmEvent::VBlank(crtc) => {
+                    state
+                        .backend
+                        .udev_mut()
+                        .on_vblank(&mut state.pinnacle, node, crtc, metadata);
+                }
+                DrmEvent::Error(error) => {
+                    error!("{:?}", error);
+                }
+            }
+        })
+        .expect("failed to insert drm notifier into event loop")
+}
+
 fn get_surface_dmabuf_feedback(
     primary_gpu: DrmNode,
     render_node: DrmNode,
@@ -772,6 +807,11 @@ struct RenderSurface {
 
     frame_clock: FrameClock,
     frame_callback_sequence: FrameCallbackSequence,
+
+    /// When the frame waiting for vblank was queued.
+    frame_queued_at: Option<Duration>,
+    /// The time of the oldest input event the frame waiting for vblank may be showing.
+    frame_input_time: Option<Duration>,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -826,25 +866,7 @@ impl Udev {
             DrmDevice::new(fd.clone(), true).context("failed to init drm device")?;
         let gbm = GbmDevice::new(fd).context("failed to init gbm device")?;
 
-        let registration_token = pinnacle
-            .loop_handle
-            .insert_source(notifier, move |event, metadata, state| {
-                let metadata = metadata.expect("vblank events must have metadata");
-                match event {
-                    DrmEvent::VBlank(crtc) => {
-                        state.backend.udev_mut().on_vblank(
-                            &mut state.pinnacle,
-                            node,
-                            crtc,
-                            metadata,
-                        );
-                    }
-                    DrmEvent::Error(error) => {
-                        error!("{:?}", error);
-                    }
-                }
-            })
-            .expect("failed to insert drm notifier into event loop");
+        let registration_token = insert_drm_notifier(&pinnacle.loop_handle, node, notifier);
 
         // INFO: Anvil changes this as of c21ff35, figure that out
         // SAFETY: no clue lol just copied this from anvil
@@ -891,31 +913,14 @@ impl Udev {
             }
         }
 
-        let allocator = GbmAllocator::new(
-            gbm.clone(),
-            GbmBufferFlags::RENDERING | GbmBufferFlags::SCANOUT,
-        );
-        let color_formats = if std::env::var("PINNACLE_DISABLE_10BIT").is_ok() {
-            SUPPORTED_FORMATS_8BIT_ONLY
+        let color_depth = if std::env::var("PINNACLE_DISABLE_10BIT").is_ok() {
+            ColorDepth::Eight
         } else {
-            SUPPORTED_FORMATS
+            ColorDepth::Ten
         };
 
-        let mut renderer = self.gpu_manager.single_renderer(&render_node).unwrap();
-        let render_formats = renderer
-            .as_mut()
-            .egl_context()
-            .dmabuf_render_formats()
-            .clone();
-
-        let drm_output_manager = DrmOutputManager::new(
-            drm,
-            allocator,
-            GbmFramebufferExporter::new(gbm.clone(), render_node.into()),
-            Some(gbm),
-            color_formats.iter().copied(),
-            render_formats,
-        );
+        let drm_output_manager =
+            self.create_output_manager(drm, gbm.clone(), render_node, color_depth);
 
         self.devices.insert(
             node,
@@ -924,6 +929,8 @@ impl Udev {
                 drm_output_manager,
                 drm_scanner: DrmScanner::new(),
                 render_node,
+                gbm,
+                color_depth,
                 surfaces: HashMap::new(),
             },
         );
@@ -987,6 +994,11 @@ impl Udev {
 
         let display_info =
             smithay_drm_extras::display_info::for_connector(drm_device, connector.handle());
+        let edid = display_info.as_ref().map(EdidInfo::new);
+        let hdr_capable = edid
+            .and_then(|edid| edid.hdr_metadata)
+            .is_some_and(|hdr| hdr.pq)
+            && hdr::connector_supports_hdr(drm_device, connector.handle());
 
         let (make, model, serial) = display_info
             .map(|info| {
@@ -1011,9 +1023,14 @@ impl Udev {
             },
         );
         let global = output.create_global::<State>(&self.display_handle);
-        output.with_state_mut(|state| state.enabled_global_id = Some(global));
+        output.with_state_mut(|state| {
+            state.enabled_global_id = Some(global);
+            state.edid = edid;
+            state.hdr_capable = hdr_capable;
+        });
 
         pinnacle.outputs.push(output.clone());
+        pinnacle.pager_state.mark_dirty();
         pinnacle.output_focus_stack.add_to_end(output.clone());
 
         output.with_state_mut(|state| {
@@ -1078,6 +1095,11 @@ impl Udev {
             )
         });
 
+        // The compositor falls back to 8 bits if the connector can't do 10
+        let color_depth = drm_output
+            .with_compositor(|compositor| color_depth::color_depth_of(compositor.format()));
+        output.with_state_mut(|state| state.color_depth = color_depth);
+
         let surface = RenderSurface {
             device_id: node,
             render_node: device.render_node,
@@ -1089,6 +1111,8 @@ impl Udev {
             pending_gamma_change: PendingGammaChange::Idle,
             frame_clock: FrameClock::new(Some(refresh_interval(drm_mode))),
             frame_callback_sequence: FrameCallbackSequence::default(),
+            frame_queued_at: None,
+            frame_input_time: None,
         };
 
         device.surfaces.insert(crtc, surface);
@@ -1161,6 +1185,48 @@ impl Udev {
         }
     }
 
+    /// Schedules a connector scan of `node` once hotplug events settle.
+    ///
+    /// Docks and KVMs can fire many connect/disconnect events in quick succession.
+    /// Every new event restarts the settle delay, and all events are coalesced
+    /// into a single scan so outputs aren't repeatedly set up and torn down.
+    fn schedule_device_changed(&mut self, pinnacle: &mut Pinnacle, node: DrmNode) {
+        let settle_delay = pinnacle.config.hotplug_settle_delay;
+
+        if settle_delay.is_zero() {
+            self.device_changed(pinnacle, node);
+            return;
+        }
+
+        self.pending_device_changes.insert(node);
+
+        if let Some(token) = self.device_change_timer.take() {
+            pinnacle.loop_handle.remove(token);
+        }
+
+        let token = pinnacle
+            .loop_handle
+            .insert_source(Timer::from_duration(settle_delay), |_, _, state| {
+                let udev = state.backend.udev_mut();
+                udev.device_change_timer = None;
+
+                // All devices are rescanned when the session is resumed
+                if !udev.session.is_active() {
+                    udev.pending_device_changes.clear();
+                    return TimeoutAction::Drop;
+                }
+
+                for node in mem::take(&mut udev.pending_device_changes) {
+                    udev.device_changed(&mut state.pinnacle, node);
+                }
+
+                TimeoutAction::Drop
+            })
+            .expect("failed to insert hotplug settle timer");
+
+        self.device_change_timer = Some(token);
+    }
+
     fn device_changed(&mut self, pinnacle: &mut Pinnacle, node: DrmNode) {
         debug!(?node, "Udev::device_changed");
 
@@ -1203,6 +1269,8 @@ impl Udev {
     fn device_removed(&mut self, pinnacle: &mut Pinnacle, node: DrmNode) {
         debug!(?node, "Udev::device_removed");
 
+        self.pending_device_changes.shift_remove(&node);
+
         let Some(device) = self.devices.get(&node) else {
             warn!(?node, "Device disappeared");
             return;
@@ -1353,6 +1421,28 @@ impl Udev {
             }
         };
 
+        let presented_at = if presentation_time.is_zero() {
+            pinnacle.clock.now().into()
+        } else {
+            presentation_time
+        };
+
+        if let Some(queued_at) = surface.frame_queued_at.take() {
+            pinnacle
+                .metrics
+                .frame_presented(surface.frame_input_time.take(), presented_at);
+
+            // A frame queued more than a refresh interval before it was presented
+            // missed at least one vblank.
+            if let Some(refresh_interval) = surface.frame_clock.refresh_interval()
+                && !surface.frame_clock.vrr()
+            {
+                let latency = presented_at.saturating_sub(queued_at);
+                pinnacle.metrics.missed_frames +=
+                    (latency.as_nanos() / refresh_interval.as_nanos()) as u64;
+            }
+        }
+
         surface.frame_clock.presented(presentation_time);
 
         let render_needed = match mem::take(&mut surface.render_state) {
@@ -1363,7 +1453,12 @@ impl Udev {
             }
         };
 
-        if render_needed || pinnacle.cursor_state.is_current_cursor_animated() {
+        if render_needed
+            || pinnacle
+                .cursor_state
+                .is_current_cursor_animated(Some(&output))
+            || pinnacle.idle_state.is_screensaver_animated()
+        {
             self.schedule_render(&output);
         } else {
             pinnacle.send_frame_callbacks(&output, Some(surface.frame_callback_sequence));
@@ -1422,9 +1517,10 @@ impl Udev {
             return;
         }
 
-        // TODO: possibly lift this out and make it so that scheduling a render
-        // does nothing on powered off outputs
-        if output.with_state(|state| !state.powered) {
+        // Powered off outputs and outputs blanked for a session lock don't get rendered to.
+        // This also means their surfaces don't get frame callbacks until rendering resumes.
+        if output.with_state(|state| state.is_render_suspended(&pinnacle.lock_state)) {
+            output.with_state_mut(|state| state.render_stats.frames_skipped_suspended += 1);
             make_idle(&mut surface.render_state, &pinnacle.loop_handle);
             return;
         }
@@ -1474,6 +1570,7 @@ impl Udev {
         let (pointer_render_elements, cursor_ids) = pointer_render_elements(
             pointer_location - output_geo.loc.to_f64(),
             scale,
+            Some(output),
             &mut renderer,
             &mut pinnacle.cursor_state,
             &pinnacle.clock,
@@ -1491,6 +1588,13 @@ impl Udev {
                     state.blanking_state = BlankingState::Blanking;
                 }
             });
+        } else if let Some(screensaver) = pinnacle.idle_state.active_screensaver.as_mut() {
+            output_render_elements.extend(screensaver.render_elements(
+                &mut renderer,
+                output,
+                output_geo.size,
+                scale.into(),
+            ));
         } else if pinnacle.lock_state.is_locked() {
             if let Some(lock_surface) = output.with_state(|state| state.lock_surface.clone()) {
                 let elems = render_elements_from_surface_tree(
@@ -1505,6 +1609,15 @@ impl Udev {
                 output_render_elements.extend(elems);
             }
         } else {
+            if let Some(region_select) = pinnacle.region_select.as_ref() {
+                output_render_elements.extend(
+                    region_select
+                        .render_elements(output_geo, scale.into())
+                        .into_iter()
+                        .map(OutputRenderElement::from),
+                );
+            }
+
             output_render_elements.extend(crate::render::output_render_elements(
                 output,
                 &mut renderer,
@@ -1513,6 +1626,40 @@ impl Udev {
             ));
         }
 
+        let clear_color = if pinnacle.lock_state.is_unlocked() {
+            CLEAR_COLOR
+        } else {
+            CLEAR_COLOR_LOCKED
+        };
+
+        crate::render::util::color_filter::apply_color_filter(
+            output,
+            &mut output_render_elements,
+            &mut renderer,
+            clear_color,
+        );
+
+        let hdr = output.with_state(|state| state.hdr);
+
+        // HDR output already maps colors to the monitor's gamut
+        let gamut_matrix = output.with_state(|state| {
+            let primaries = state.edid?.color_primaries?;
+            (state.srgb_emulation && !hdr)
+                .then(|| crate::render::util::gamut::srgb_to_primaries(&primaries))
+        });
+
+        if let Some(gamut_matrix) = gamut_matrix {
+            crate::render::util::gamut::emulate_srgb(
+                &mut output_render_elements,
+                gamut_matrix,
+                &mut renderer,
+            );
+        }
+
+        if hdr {
+            crate::render::util::hdr::encode_for_hdr(&mut output_render_elements, &mut renderer);
+        }
+
         if pinnacle.config.debug.visualize_opaque_regions {
             crate::render::util::render_opaque_regions(
                 &mut output_render_elements,
@@ -1535,10 +1682,13 @@ impl Udev {
                 .collect();
         }
 
-        let clear_color = if pinnacle.lock_state.is_unlocked() {
-            CLEAR_COLOR
+        let clear_color = gamut_matrix.map_or(clear_color, |gamut_matrix| {
+            crate::render::util::gamut::emulate_srgb_color(gamut_matrix, clear_color)
+        });
+        let clear_color = if hdr {
+            crate::render::util::hdr::sdr_to_pq(clear_color)
         } else {
-            CLEAR_COLOR_LOCKED
+            clear_color
         };
 
         // No overlay planes cuz they wonk
@@ -1638,6 +1788,14 @@ impl Udev {
 
                 let rendered = !res.is_empty;
 
+                output.with_state_mut(|state| {
+                    if rendered {
+                        state.render_stats.frames_rendered += 1;
+                    } else {
+                        state.render_stats.frames_skipped_no_damage += 1;
+                    }
+                });
+
                 if rendered {
                     let output_presentation_feedback =
                         take_presentation_feedback(output, &pinnacle.space, &res.states);
@@ -1647,6 +1805,10 @@ impl Udev {
                         .queue_frame(Some(output_presentation_feedback))
                     {
                         Ok(()) => {
+                            let now = pinnacle.clock.now().into();
+                            surface.frame_queued_at = Some(now);
+                            surface.frame_input_time = pinnacle.metrics.take_pending_input(now);
+
                             let new_state = RenderState::WaitingForVblank {
                                 render_needed: false,
                             };
@@ -1780,7 +1942,11 @@ impl Udev {
             }
         }
 
-        if pinnacle.cursor_state.is_current_cursor_animated() {
+        if pinnacle
+            .cursor_state
+            .is_current_cursor_animated(Some(output))
+            || pinnacle.idle_state.is_screensaver_animated()
+        {
             self.schedule_render(output);
         } else {
             pinnacle.send_frame_callbacks(output, Some(surface.frame_callback_sequence));
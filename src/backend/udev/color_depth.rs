@@ -0,0 +1,159 @@
+//! Rendering and scanning out at 8 or 10 bits per color channel.
+//!
+//! The formats a DRM compositor may pick from are set per device by its output manager,
+//! so all outputs on a device share a color depth. Changing it recreates the output
+//! manager and sets the device's outputs up again, as if they were replugged.
+
+use std::collections::HashMap;
+
+use anyhow::{Context, ensure};
+use smithay::{
+    backend::{
+        allocator::{
+            Fourcc,
+            gbm::{GbmAllocator, GbmBufferFlags, GbmDevice},
+        },
+        drm::{
+            DrmDevice, DrmDeviceFd, DrmNode, exporter::gbm::GbmFramebufferExporter,
+            output::DrmOutputManager,
+        },
+        session::Session,
+    },
+    output::Output,
+};
+use smithay_drm_extras::drm_scanner::DrmScanner;
+use tracing::info;
+
+use crate::{output::ColorDepth, state::Pinnacle};
+
+use super::{Device, Udev, UdevOutputData, UdevOutputManager, insert_drm_notifier};
+
+/// 10-bit formats, falling back to 8-bit ones if the connector or GPU can't do 10 bits.
+const FORMATS_10BIT: &[Fourcc] = &[
+    Fourcc::Abgr2101010,
+    Fourcc::Argb2101010,
+    Fourcc::Abgr8888,
+    Fourcc::Argb8888,
+];
+const FORMATS_8BIT: &[Fourcc] = &[Fourcc::Abgr8888, Fourcc::Argb8888];
+
+/// Returns the color depth of a format picked from the ones above.
+pub(super) fn color_depth_of(format: Fourcc) -> ColorDepth {
+    match format {
+        Fourcc::Abgr2101010 | Fourcc::Argb2101010 => ColorDepth::Ten,
+        _ => ColorDepth::Eight,
+    }
+}
+
+impl Udev {
+    /// Creates an output manager for a device whose outputs use `color_depth`.
+    pub(super) fn create_output_manager(
+        &mut self,
+        drm: DrmDevice,
+        gbm: GbmDevice<DrmDeviceFd>,
+        render_node: DrmNode,
+        color_depth: ColorDepth,
+    ) -> UdevOutputManager {
+        let allocator = GbmAllocator::new(
+            gbm.clone(),
+            GbmBufferFlags::RENDERING | GbmBufferFlags::SCANOUT,
+        );
+        let color_formats = match color_depth {
+            ColorDepth::Eight => FORMATS_8BIT,
+            ColorDepth::Ten => FORMATS_10BIT,
+        };
+
+        let mut renderer = self.gpu_manager.single_renderer(&render_node).unwrap();
+        let render_formats = renderer
+            .as_mut()
+            .egl_context()
+            .dmabuf_render_formats()
+            .clone();
+
+        DrmOutputManager::new(
+            drm,
+            allocator,
+            GbmFramebufferExporter::new(gbm.clone(), render_node.into()),
+            Some(gbm),
+            color_formats.iter().copied(),
+            render_formats,
+        )
+    }
+
+    /// Sets the color depth of all outputs on `output`'s device.
+    ///
+    /// The device's outputs are briefly disconnected while they're set up again.
+    pub fn set_output_color_depth(
+        &mut self,
+        pinnacle: &mut Pinnacle,
+        output: &Output,
+        color_depth: ColorDepth,
+    ) -> anyhow::Result<()> {
+        ensure!(self.session.is_active(), "session is inactive");
+
+        let node = output
+            .user_data()
+            .get::<UdevOutputData>()
+            .context("no udev output data for output")?
+            .device_id;
+
+        let device = self
+            .devices
+            .get(&node)
+            .context("no udev backend data for output")?;
+
+        if device.color_depth == color_depth {
+            return Ok(());
+        }
+
+        let crtcs = device
+            .drm_scanner
+            .crtcs()
+            .map(|(_info, crtc)| crtc)
+            .collect::<Vec<_>>();
+
+        for crtc in crtcs {
+            self.connector_disconnected(pinnacle, node, crtc);
+        }
+
+        let Some(device) = self.devices.remove(&node) else {
+            unreachable!()
+        };
+
+        pinnacle.loop_handle.remove(device.registration_token);
+
+        // The old DRM device restores the state it started with when dropped,
+        // so it has to go before the new one takes over
+        let fd = device.drm_output_manager.device().device_fd().clone();
+        drop(device.drm_output_manager);
+
+        let (drm, notifier) = DrmDevice::new(fd, true).context("failed to init drm device")?;
+        let registration_token = insert_drm_notifier(&pinnacle.loop_handle, node, notifier);
+
+        let drm_output_manager =
+            self.create_output_manager(drm, device.gbm.clone(), device.render_node, color_depth);
+
+        self.devices.insert(
+            node,
+            Device {
+                registration_token,
+                drm_output_manager,
+                drm_scanner: DrmScanner::new(),
+                render_node: device.render_node,
+                gbm: device.gbm,
+                color_depth,
+                surfaces: HashMap::new(),
+            },
+        );
+
+        info!(
+            ?node,
+            "Set up outputs with {} bits per channel",
+            color_depth.bits()
+        );
+
+        self.device_changed(pinnacle, node);
+
+        Ok(())
+    }
+}
@@ -42,6 +42,11 @@ impl FrameClock {
         self.vrr
     }
 
+    /// Forgets the last presentation time, for example after the output was powered off.
+    pub fn reset(&mut self) {
+        self.last_presentation_time = None;
+    }
+
     pub fn presented(&mut self, presentation_time: Duration) {
         if presentation_time.is_zero() {
             // Not interested in these
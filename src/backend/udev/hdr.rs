@@ -0,0 +1,193 @@
+//! HDR output through the connector's colorspace and HDR metadata properties.
+//!
+//! Outputs in HDR mode are switched to the BT.2020 colorspace with static metadata
+//! saying their content is encoded with the PQ transfer function. The renderer
+//! is responsible for actually encoding content that way.
+
+use anyhow::{Context, bail};
+use smithay::{
+    backend::drm::DrmDevice,
+    output::Output,
+    reexports::drm::control::{Device, ResourceHandle, connector, property},
+};
+use tracing::info;
+
+use crate::{output::edid::EdidInfo, state::WithState};
+
+use super::{Udev, UdevOutputData};
+
+const COLORSPACE_PROPERTY: &str = "Colorspace";
+const HDR_METADATA_PROPERTY: &str = "HDR_OUTPUT_METADATA";
+
+/// `HDMI_STATIC_METADATA_TYPE1` from the kernel's DRM uapi.
+const STATIC_METADATA_TYPE1: u8 = 0;
+/// `HDMI_EOTF_SMPTE_ST2084` from the kernel's DRM uapi.
+const EOTF_SMPTE_ST2084: u8 = 2;
+
+/// `struct hdr_metadata_infoframe` from the kernel's DRM uapi.
+#[repr(C)]
+struct HdrMetadataInfoframe {
+    eotf: u8,
+    metadata_type: u8,
+    /// Red, green, and blue chromaticities in units of 0.00002.
+    display_primaries: [[u16; 2]; 3],
+    white_point: [u16; 2],
+    /// In units of 1 cd/m².
+    max_display_mastering_luminance: u16,
+    /// In units of 0.0001 cd/m².
+    min_display_mastering_luminance: u16,
+    max_cll: u16,
+    max_fall: u16,
+}
+
+/// `struct hdr_output_metadata` from the kernel's DRM uapi.
+#[repr(C)]
+struct HdrOutputMetadata {
+    metadata_type: u32,
+    hdmi_metadata_type1: HdrMetadataInfoframe,
+}
+
+impl HdrOutputMetadata {
+    /// Creates PQ metadata, using the monitor's advertised luminance and primaries
+    /// when available and BT.2020 otherwise.
+    fn new(edid: Option<&EdidInfo>) -> Self {
+        const BT2020_PRIMARIES: [[f32; 2]; 3] = [[0.708, 0.292], [0.170, 0.797], [0.131, 0.046]];
+        const D65_WHITE_POINT: [f32; 2] = [0.3127, 0.3290];
+
+        let chromaticity = |[x, y]: [f32; 2]| [(x * 50000.0) as u16, (y * 50000.0) as u16];
+
+        let primaries = edid.and_then(|edid| edid.color_primaries);
+        let display_primaries = match primaries {
+            Some(primaries) => [primaries.red, primaries.green, primaries.blue]
+                .map(|primary| chromaticity([primary.x, primary.y])),
+            None => BT2020_PRIMARIES.map(chromaticity),
+        };
+        let white_point = primaries
+            .and_then(|primaries| primaries.white)
+            .map_or(chromaticity(D65_WHITE_POINT), |white| {
+                chromaticity([white.x, white.y])
+            });
+
+        let hdr = edid.and_then(|edid| edid.hdr_metadata);
+        let max_luminance = hdr.and_then(|hdr| hdr.max_luminance).unwrap_or(1000.0);
+        let max_frame_avg_luminance = hdr
+            .and_then(|hdr| hdr.max_frame_avg_luminance)
+            .unwrap_or(max_luminance);
+        let min_luminance = hdr.and_then(|hdr| hdr.min_luminance).unwrap_or(0.0);
+
+        Self {
+            metadata_type: STATIC_METADATA_TYPE1 as u32,
+            hdmi_metadata_type1: HdrMetadataInfoframe {
+                eotf: EOTF_SMPTE_ST2084,
+                metadata_type: STATIC_METADATA_TYPE1,
+                display_primaries,
+                white_point,
+                max_display_mastering_luminance: max_luminance as u16,
+                min_display_mastering_luminance: (min_luminance * 10000.0) as u16,
+                max_cll: max_luminance as u16,
+                max_fall: max_frame_avg_luminance as u16,
+            },
+        }
+    }
+}
+
+/// Returns the handle and info of the property called `name` on a DRM object.
+fn find_property(
+    device: &DrmDevice,
+    handle: impl ResourceHandle,
+    name: &str,
+) -> Option<(property::Handle, property::Info)> {
+    let properties = device.get_properties(handle).ok()?;
+    let (handles, _) = properties.as_props_and_values();
+
+    handles.iter().find_map(|&property| {
+        let info = device.get_property(property).ok()?;
+        (info.name().to_str() == Ok(name)).then_some((property, info))
+    })
+}
+
+/// Returns whether a connector has the properties needed for HDR output.
+pub(super) fn connector_supports_hdr(device: &DrmDevice, connector: connector::Handle) -> bool {
+    find_property(device, connector, COLORSPACE_PROPERTY).is_some()
+        && find_property(device, connector, HDR_METADATA_PROPERTY).is_some()
+}
+
+impl Udev {
+    /// Switches an output's connector in or out of HDR mode.
+    pub fn set_output_hdr(&mut self, output: &Output, hdr: bool) -> anyhow::Result<()> {
+        let UdevOutputData { device_id, crtc } = output
+            .user_data()
+            .get()
+            .context("no udev output data for output")?;
+
+        let device = self
+            .devices
+            .get(device_id)
+            .context("no udev backend data for output")?;
+
+        let connector = device
+            .surfaces
+            .get(crtc)
+            .context("no render surface for output")?
+            .drm_output
+            .with_compositor(|comp| comp.surface().current_connectors())
+            .into_iter()
+            .next()
+            .context("output has no connector")?;
+
+        let drm_device = device.drm_output_manager.device();
+
+        let (colorspace, colorspace_info) =
+            find_property(drm_device, connector, COLORSPACE_PROPERTY)
+                .context("connector has no colorspace property")?;
+        let (metadata, _) = find_property(drm_device, connector, HDR_METADATA_PROPERTY)
+            .context("connector has no HDR metadata property")?;
+
+        let colorspace_name = if hdr { "BT2020_RGB" } else { "Default" };
+        let property::ValueType::Enum(colorspaces) = colorspace_info.value_type() else {
+            bail!("colorspace property is not an enum");
+        };
+        let colorspace_value = colorspaces
+            .values()
+            .1
+            .iter()
+            .find(|value| value.name().to_str() == Ok(colorspace_name))
+            .map(|value| value.value())
+            .with_context(|| {
+                format!("connector doesn't support the {colorspace_name} colorspace")
+            })?;
+
+        let metadata_blob = if hdr {
+            let edid = output.with_state(|state| state.edid);
+            let property::Value::Blob(blob) = drm_device
+                .create_property_blob(&HdrOutputMetadata::new(edid.as_ref()))
+                .context("failed to create HDR metadata blob")?
+            else {
+                unreachable!("created property blobs are blobs");
+            };
+            Some(blob)
+        } else {
+            None
+        };
+
+        let result = drm_device
+            .set_property(connector, colorspace, colorspace_value)
+            .and_then(|()| drm_device.set_property(connector, metadata, metadata_blob.unwrap_or(0)))
+            .context("failed to set connector properties");
+
+        // The connector holds its own reference to the blob
+        if let Some(blob) = metadata_blob {
+            let _ = drm_device.destroy_property_blob(blob);
+        }
+
+        result?;
+
+        info!(
+            "{} HDR on output {}",
+            if hdr { "Enabled" } else { "Disabled" },
+            output.name()
+        );
+
+        Ok(())
+    }
+}
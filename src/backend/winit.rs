@@ -159,6 +159,7 @@ impl Winit {
             pinnacle.focus_output(&output);
 
             pinnacle.outputs.push(output.clone());
+            pinnacle.pager_state.mark_dirty();
 
             pinnacle
                 .shm_state
@@ -224,6 +225,16 @@ impl Winit {
     fn render_winit_window(&mut self, pinnacle: &mut Pinnacle) {
         let _span = tracy_client::span!("Winit::render_winit_window");
 
+        // Keep the last frame on screen while the output is suspended.
+        if self
+            .output
+            .with_state(|state| state.is_render_suspended(&pinnacle.lock_state))
+        {
+            self.output
+                .with_state_mut(|state| state.render_stats.frames_skipped_suspended += 1);
+            return;
+        }
+
         let full_redraw = &mut self.full_redraw;
         *full_redraw = full_redraw.saturating_sub(1);
 
@@ -249,6 +260,7 @@ impl Winit {
             let (pointer_render_elements, _cursor_ids) = pointer_render_elements(
                 pointer_location - output_loc.to_f64(),
                 scale,
+                Some(&self.output),
                 self.backend.renderer(),
                 &mut pinnacle.cursor_state,
                 &pinnacle.clock,
@@ -271,6 +283,16 @@ impl Winit {
                     state.blanking_state = BlankingState::Blanking;
                 }
             });
+        } else if let Some(screensaver) = pinnacle.idle_state.active_screensaver.as_mut()
+            && let Some(output_geo) = pinnacle.space.output_geometry(&self.output)
+        {
+            let scale = self.output.current_scale().fractional_scale();
+            output_render_elements.extend(screensaver.render_elements(
+                self.backend.renderer(),
+                &self.output,
+                output_geo.size,
+                scale.into(),
+            ));
         } else if pinnacle.lock_state.is_locked() {
             if let Some(lock_surface) = self.output.with_state(|state| state.lock_surface.clone()) {
                 let elems = render_elements_from_surface_tree(
@@ -285,6 +307,18 @@ impl Winit {
                 output_render_elements.extend(elems);
             }
         } else {
+            if let Some(region_select) = pinnacle.region_select.as_ref()
+                && let Some(output_geo) = pinnacle.space.output_geometry(&self.output)
+            {
+                let scale = self.output.current_scale().fractional_scale();
+                output_render_elements.extend(
+                    region_select
+                        .render_elements(output_geo, scale.into())
+                        .into_iter()
+                        .map(OutputRenderElement::from),
+                );
+            }
+
             output_render_elements.extend(crate::render::output_render_elements(
                 &self.output,
                 self.backend.renderer(),
@@ -293,6 +327,19 @@ impl Winit {
             ));
         }
 
+        let clear_color = if pinnacle.lock_state.is_unlocked() {
+            CLEAR_COLOR
+        } else {
+            CLEAR_COLOR_LOCKED
+        };
+
+        crate::render::util::color_filter::apply_color_filter(
+            &self.output,
+            &mut output_render_elements,
+            self.backend.renderer(),
+            clear_color,
+        );
+
         if pinnacle.config.debug.visualize_opaque_regions {
             crate::render::util::render_opaque_regions(
                 &mut output_render_elements,
@@ -324,12 +371,6 @@ impl Winit {
         let age = 0;
 
         let render_res = self.backend.bind().and_then(|(renderer, mut framebuffer)| {
-            let clear_color = if pinnacle.lock_state.is_unlocked() {
-                CLEAR_COLOR
-            } else {
-                CLEAR_COLOR_LOCKED
-            };
-
             self.damage_tracker
                 .render_output(
                     renderer,
@@ -348,12 +389,26 @@ impl Winit {
             Ok(render_output_result) => {
                 let has_rendered = render_output_result.damage.is_some();
 
+                self.output.with_state_mut(|state| {
+                    if has_rendered {
+                        state.render_stats.frames_rendered += 1;
+                    } else {
+                        state.render_stats.frames_skipped_no_damage += 1;
+                    }
+                });
+
                 match self
                     .backend
                     .submit(render_output_result.damage.map(|damage| damage.as_slice()))
                 {
                     Ok(()) => {
                         if has_rendered {
+                            // There's no presentation time in a nested window,
+                            // so latency is measured up to buffer submission.
+                            let now = pinnacle.clock.now().into();
+                            let input_time = pinnacle.metrics.take_pending_input(now);
+                            pinnacle.metrics.frame_presented(input_time, now);
+
                             self.output.with_state_mut(|state| {
                                 if matches!(state.blanking_state, BlankingState::Blanking) {
                                     // TODO: this is probably wrong
@@ -410,7 +465,11 @@ impl Winit {
         pinnacle.send_frame_callbacks(&self.output, None);
 
         // At the end cuz borrow checker
-        if pinnacle.cursor_state.is_current_cursor_animated() {
+        if pinnacle
+            .cursor_state
+            .is_current_cursor_animated(Some(&self.output))
+            || pinnacle.idle_state.is_screensaver_animated()
+        {
             self.schedule_render();
         }
     }
@@ -1,13 +1,25 @@
 use crate::{
     api::{
-        debug::DebugService, input::InputService, layout::LayoutService, output::OutputService,
-        pinnacle::PinnacleService, process::ProcessService, render::RenderService,
-        signal::SignalService, tag::TagService, window::WindowService,
+        debug::DebugService, idle::IdleService, input::InputService, layout::LayoutService,
+        nightlight::NightlightService, output::OutputService, pinnacle::PinnacleService,
+        portal::PortalService, power::PowerService, process::ProcessService, render::RenderService,
+        screenshot::ScreenshotService, signal::SignalService, tag::TagService,
+        window::WindowService,
     },
     cli::Cli,
+    metrics::MetricsExport,
     output::OutputName,
+    profile::ProfileState,
+    render::util::effect::{InactiveEffect, TagEffectRule},
     state::Pinnacle,
-    tag::Tag,
+    tag::{EmptyOutputPolicy, Tag},
+    util::rect::Containment,
+    window::{
+        border::BorderStyle,
+        popup::PopupPolicy,
+        snap::SnapGrid,
+        window_state::{DialogPolicy, MaximizeRequestPolicy, TagInheritance},
+    },
 };
 use std::{
     collections::HashMap,
@@ -15,17 +27,23 @@ use std::{
     io::{self, Write},
     path::{Path, PathBuf},
     process::Stdio,
+    time::Duration,
 };
 
 use anyhow::Context;
 use indexmap::IndexSet;
 use pinnacle_api_defs::pinnacle::{
     debug::v1::debug_service_server::DebugServiceServer,
+    idle::v1::idle_service_server::IdleServiceServer,
     input::v1::input_service_server::InputServiceServer,
     layout::v1::layout_service_server::LayoutServiceServer,
+    nightlight::v1::nightlight_service_server::NightlightServiceServer,
     output::v1::output_service_server::OutputServiceServer,
+    portal::v1::portal_service_server::PortalServiceServer,
+    power::v1::power_service_server::PowerServiceServer,
     process::v1::process_service_server::ProcessServiceServer,
     render::v1::render_service_server::RenderServiceServer,
+    screenshot::v1::screenshot_service_server::ScreenshotServiceServer,
     signal::v1::signal_service_server::SignalServiceServer,
     tag::v1::tag_service_server::TagServiceServer,
     v1::pinnacle_service_server::PinnacleServiceServer,
@@ -51,6 +69,8 @@ use crate::{
 
 const DEFAULT_SOCKET_DIR: &str = "/tmp";
 pub const GRPC_SOCKET_ENV: &str = "PINNACLE_GRPC_SOCKET";
+const DEFAULT_HOTPLUG_SETTLE_DELAY: Duration = Duration::from_millis(200);
+const DEFAULT_CLOSE_SNAPSHOT_HOLD: Duration = Duration::from_secs(2);
 
 mod builtin {
     include!("../api/rust/examples/default_config/main.rs");
@@ -232,6 +252,44 @@ pub struct Config {
     pub last_error: Option<String>,
 
     pub process_envs: HashMap<String, String>,
+
+    /// How much of floating windows must remain on an output, if constrained at all.
+    pub floating_containment: Option<Containment>,
+
+    /// How long output hotplug events must settle before connectors are rescanned.
+    pub hotplug_settle_delay: Duration,
+    /// How long the snapshot of a closed tiled window is held in place while waiting for
+    /// the layout that fills its space.
+    pub close_snapshot_hold: Duration,
+
+    /// Dimming and desaturation applied to inactive windows.
+    pub inactive_effect: Option<InactiveEffect>,
+    /// Effects applied to windows while certain tags are active.
+    pub tag_effect_rules: Vec<TagEffectRule>,
+    /// Whether unresponsive windows are greyed out.
+    pub grey_out_unresponsive: bool,
+    /// How client maximize requests are handled unless a window rule says otherwise.
+    pub maximize_request_policy: MaximizeRequestPolicy,
+    /// Which tags new windows are put on.
+    pub tag_inheritance: TagInheritance,
+    /// How dialogs are placed.
+    pub dialog_policy: DialogPolicy,
+    /// What happens when an output is left with no active tags.
+    pub empty_output_policy: EmptyOutputPolicy,
+    /// The border drawn around windows without their own border style.
+    pub border_style: Option<BorderStyle>,
+    /// Whether fullscreen windows that don't fill their output are centered with bars around them.
+    pub letterbox_fullscreen: bool,
+    /// The grid windows are snapped to when snapping to a grid cell.
+    pub snap_grid: SnapGrid,
+    /// How popups are constrained.
+    pub popup_policy: PopupPolicy,
+    /// The socket metrics are being exported on, if any.
+    pub metrics_export: Option<MetricsExport>,
+    /// Whether the session is kept from going idle, regardless of idle inhibitors.
+    pub inhibit_idle: bool,
+    /// Profiles defined by the config and the active one.
+    pub profiles: ProfileState,
 }
 
 #[derive(Debug, Default)]
@@ -240,6 +298,8 @@ pub struct Debug {
     pub visualize_opaque_regions: bool,
     pub disable_cursor_plane_scanout: bool,
     pub disable_process_piping: bool,
+    /// Whether input events are logged along with where they went.
+    pub trace_input: bool,
 }
 
 impl Drop for Config {
@@ -263,6 +323,23 @@ impl Config {
             debug: Default::default(),
             last_error: None,
             process_envs: Default::default(),
+            floating_containment: None,
+            hotplug_settle_delay: DEFAULT_HOTPLUG_SETTLE_DELAY,
+            close_snapshot_hold: DEFAULT_CLOSE_SNAPSHOT_HOLD,
+            inactive_effect: None,
+            tag_effect_rules: Vec::new(),
+            grey_out_unresponsive: false,
+            maximize_request_policy: MaximizeRequestPolicy::default(),
+            tag_inheritance: TagInheritance::default(),
+            dialog_policy: DialogPolicy::default(),
+            empty_output_policy: EmptyOutputPolicy::default(),
+            border_style: None,
+            letterbox_fullscreen: false,
+            snap_grid: SnapGrid::default(),
+            popup_policy: PopupPolicy::default(),
+            metrics_export: None,
+            inhibit_idle: false,
+            profiles: ProfileState::default(),
         }
     }
 
@@ -283,6 +360,28 @@ impl Config {
         std::mem::take(&mut self.debug);
 
         self.process_envs.clear();
+        self.floating_containment = None;
+        self.hotplug_settle_delay = DEFAULT_HOTPLUG_SETTLE_DELAY;
+        self.close_snapshot_hold = DEFAULT_CLOSE_SNAPSHOT_HOLD;
+        self.inactive_effect = None;
+        self.tag_effect_rules.clear();
+        self.grey_out_unresponsive = false;
+        self.maximize_request_policy = MaximizeRequestPolicy::default();
+        self.tag_inheritance = TagInheritance::default();
+        self.dialog_policy = DialogPolicy::default();
+        self.empty_output_policy = EmptyOutputPolicy::default();
+        self.border_style = None;
+        self.letterbox_fullscreen = false;
+        self.snap_grid = SnapGrid::default();
+        self.popup_policy = PopupPolicy::default();
+        self.metrics_export = None;
+        self.inhibit_idle = false;
+        self.profiles = ProfileState::default();
+    }
+
+    /// Returns the directory the gRPC socket is in, once the gRPC server has started.
+    pub fn socket_dir(&self) -> Option<&Path> {
+        self.socket_path.as_deref().and_then(Path::parent)
     }
 }
 
@@ -350,6 +449,25 @@ impl Pinnacle {
 
         self.signal_state.clear();
 
+        self.window_rule_state.clear_declarative_rules();
+
+        self.cursor_state.clear_output_overrides();
+
+        if self.idle_state.clear(&self.loop_handle) {
+            self.loop_handle
+                .insert_idle(|state| state.schedule_render_all());
+        }
+
+        if self.idle_state.idle_timeout().is_some() {
+            self.loop_handle
+                .insert_idle(|state| state.set_idle_timeout(None));
+        }
+
+        if self.nightlight_state.schedule().is_some() {
+            self.loop_handle
+                .insert_idle(|state| state.set_nightlight_schedule(None));
+        }
+
         #[cfg(feature = "snowcap")]
         {
             // FIXME: add some mechanism to detect if the client dies to do cleanup
@@ -359,7 +477,10 @@ impl Pinnacle {
             }
 
             for win in self.windows.iter() {
-                win.with_state_mut(|state| state.decoration_surfaces.clear());
+                win.with_state_mut(|state| {
+                    state.decoration_surfaces.clear();
+                    state.render_plan_cache.invalidate();
+                });
             }
         }
 
@@ -546,7 +667,10 @@ impl Pinnacle {
 
         self.loop_handle
             .insert_source(grpc_receiver, |msg, _, state| match msg {
-                Event::Msg(f) => f(state),
+                Event::Msg(f) => {
+                    f(state);
+                    state.pinnacle.pager_state.mark_dirty();
+                }
                 Event::Closed => error!("grpc receiver was closed"),
             })
             .expect("failed to insert grpc_receiver into loop");
@@ -560,6 +684,11 @@ impl Pinnacle {
         let signal_service = SignalService::new(grpc_sender.clone());
         let layout_service = LayoutService::new(grpc_sender.clone());
         let render_service = RenderService::new(grpc_sender.clone());
+        let screenshot_service = ScreenshotService::new(grpc_sender.clone());
+        let portal_service = PortalService::new(grpc_sender.clone());
+        let idle_service = IdleService::new(grpc_sender.clone());
+        let nightlight_service = NightlightService::new(grpc_sender.clone());
+        let power_service = PowerService::new(grpc_sender.clone());
         let debug_service = DebugService::new(grpc_sender.clone());
 
         let refl_service = tonic_reflection::server::Builder::configure()
@@ -585,6 +714,11 @@ impl Pinnacle {
             .add_service(SignalServiceServer::new(signal_service))
             .add_service(LayoutServiceServer::new(layout_service))
             .add_service(RenderServiceServer::new(render_service))
+            .add_service(ScreenshotServiceServer::new(screenshot_service))
+            .add_service(PortalServiceServer::new(portal_service))
+            .add_service(IdleServiceServer::new(idle_service))
+            .add_service(NightlightServiceServer::new(nightlight_service))
+            .add_service(PowerServiceServer::new(power_service))
             .add_service(DebugServiceServer::new(debug_service));
 
         self.grpc_server_join_handle = Some(tokio::spawn(async move {
@@ -7,6 +7,7 @@ use anyhow::Context;
 use smithay::backend::allocator::Fourcc;
 use smithay::desktop::utils::bbox_from_surface_tree;
 use smithay::input::pointer::CursorImageSurfaceData;
+use smithay::output::Output;
 use smithay::reexports::wayland_server::protocol::wl_surface::WlSurface;
 use smithay::utils::{Buffer, IsAlive, Logical, Monotonic, Point, Rectangle, Time};
 use smithay::wayland::compositor::{self, SurfaceAttributes};
@@ -20,27 +21,49 @@ use xcursor::{
     parser::{Image, parse_xcursor},
 };
 
-use crate::render::pointer::PointerElement;
+use crate::{
+    output::OutputName,
+    render::{
+        pointer::PointerElement,
+        util::accounting::{AllocationKind, GpuAccounting, TrackedAllocation},
+    },
+};
 
 static FALLBACK_CURSOR_DATA: &[u8] = include_bytes!("../resources/cursor.rgba");
 
 pub struct CursorState {
-    current_cursor_image: CursorImageStatus,
-    theme: CursorTheme,
+    /// The cursor image requested by the focused client, either as a surface
+    /// or as a named shape through the cursor shape protocol
+    client_cursor_image: CursorImageStatus,
+    /// A compositor-side cursor icon that takes precedence over the client's, e.g. during resizes
+    cursor_icon_override: Option<CursorImageStatus>,
+    theme: String,
     size: u32,
-    mem_buffer_cache: Vec<(Image, MemoryRenderBuffer)>,
-    /// A map of cursor icons to loaded images
-    loaded_images: HashMap<CursorIcon, Option<Rc<XCursor>>>,
+    /// Per-output overrides for the theme and size
+    output_overrides: HashMap<OutputName, CursorOverride>,
+    /// A map of theme names to loaded themes
+    themes: HashMap<String, CursorTheme>,
+    mem_buffer_cache: Vec<((Image, i32), MemoryRenderBuffer, TrackedAllocation)>,
+    gpu_accounting: Rc<GpuAccounting>,
+    /// A map of theme names and cursor icons to loaded images
+    loaded_images: HashMap<(String, CursorIcon), Option<Rc<XCursor>>>,
     dnd_icon: Option<DndIcon>,
 }
 
+/// An output-specific xcursor theme and/or size.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct CursorOverride {
+    pub theme: Option<String>,
+    pub size: Option<u32>,
+}
+
 pub struct DndIcon {
     pub surface: WlSurface,
     pub offset: Point<i32, Logical>,
 }
 
 impl CursorState {
-    pub fn new() -> Self {
+    pub fn new(gpu_accounting: Rc<GpuAccounting>) -> Self {
         let (theme, size) = load_xcursor_theme_from_env();
 
         // SAFETY: All set_vars occur on the event loop thread
@@ -50,10 +73,14 @@ impl CursorState {
         }
 
         Self {
-            current_cursor_image: CursorImageStatus::default_named(),
-            theme: CursorTheme::load(&theme),
+            client_cursor_image: CursorImageStatus::default_named(),
+            cursor_icon_override: None,
+            themes: HashMap::from([(theme.clone(), CursorTheme::load(&theme))]),
+            theme,
             size,
+            output_overrides: Default::default(),
             mem_buffer_cache: Default::default(),
+            gpu_accounting,
             loaded_images: Default::default(),
             dnd_icon: Default::default(),
         }
@@ -65,7 +92,8 @@ impl CursorState {
             std::env::set_var("XCURSOR_THEME", theme);
         }
 
-        self.theme = CursorTheme::load(theme);
+        self.theme = theme.to_string();
+        self.themes.clear();
         self.mem_buffer_cache.clear();
         self.loaded_images.clear();
     }
@@ -81,25 +109,98 @@ impl CursorState {
         self.loaded_images.clear();
     }
 
+    /// Overrides the theme and/or size of the cursor on the given output.
+    ///
+    /// Unset fields fall back to the global theme and size.
+    pub fn set_output_override(&mut self, output: &Output, cursor_override: CursorOverride) {
+        let name = OutputName(output.name());
+        if cursor_override == CursorOverride::default() {
+            self.output_overrides.remove(&name);
+        } else {
+            self.output_overrides.insert(name, cursor_override);
+        }
+        self.mem_buffer_cache.clear();
+    }
+
+    /// Removes all per-output theme and size overrides.
+    pub fn clear_output_overrides(&mut self) {
+        if !self.output_overrides.is_empty() {
+            self.output_overrides.clear();
+            self.mem_buffer_cache.clear();
+        }
+    }
+
+    /// Returns the theme and size overrides for the given output.
+    pub fn output_override(&self, output: &Output) -> CursorOverride {
+        self.output_overrides
+            .get(&OutputName(output.name()))
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// Returns the xcursor theme name and size used on the given output.
+    fn theme_and_size(&self, output: Option<&Output>) -> (&str, u32) {
+        let cursor_override =
+            output.and_then(|op| self.output_overrides.get(&OutputName(op.name())));
+
+        let theme = cursor_override
+            .and_then(|over| over.theme.as_deref())
+            .unwrap_or(&self.theme);
+        let size = cursor_override
+            .and_then(|over| over.size)
+            .unwrap_or(self.size);
+
+        (theme, size)
+    }
+
     pub fn cursor_size(&self, scale: i32) -> u32 {
         self.size * scale as u32
     }
 
+    /// Sets the cursor image requested by a client.
     pub fn set_cursor_image(&mut self, image: CursorImageStatus) {
-        self.current_cursor_image = image;
+        self.client_cursor_image = image;
     }
 
+    /// Sets or clears a compositor-side cursor icon.
+    ///
+    /// While set, this is shown instead of the client's cursor image, which is
+    /// kept around and restored once the override is cleared.
+    pub fn set_cursor_icon_override(&mut self, icon: Option<CursorIcon>) {
+        self.cursor_icon_override = icon.map(CursorImageStatus::Named);
+    }
+
+    /// Returns the cursor image that should be displayed.
     pub fn cursor_image(&self) -> &CursorImageStatus {
-        &self.current_cursor_image
+        self.cursor_icon_override
+            .as_ref()
+            .unwrap_or(&self.client_cursor_image)
     }
 
-    pub fn get_xcursor_images(&mut self, icon: CursorIcon) -> Option<Rc<XCursor>> {
+    /// Gets the xcursor images for the given icon in the theme used on `output`,
+    /// or the global theme if `output` is `None`.
+    pub fn get_xcursor_images(
+        &mut self,
+        icon: CursorIcon,
+        output: Option<&Output>,
+    ) -> Option<Rc<XCursor>> {
         let _span = tracy_client::span!("CursorState::get_xcursor_images");
 
-        self.loaded_images
-            .entry(icon)
-            .or_insert_with_key(|icon| {
-                let mut images = load_xcursor_images(&self.theme, *icon);
+        let theme_name = self.theme_and_size(output).0.to_string();
+
+        let Self {
+            themes,
+            loaded_images,
+            ..
+        } = self;
+
+        loaded_images
+            .entry((theme_name, icon))
+            .or_insert_with_key(|(theme_name, icon)| {
+                let theme = themes
+                    .entry(theme_name.clone())
+                    .or_insert_with(|| CursorTheme::load(theme_name));
+                let mut images = load_xcursor_images(theme, *icon);
                 if *icon == CursorIcon::Default && images.is_err() {
                     images = Ok(fallback_cursor());
                 }
@@ -113,9 +214,10 @@ impl CursorState {
 
         self.mem_buffer_cache
             .iter()
-            .find_map(|(img, buf)| (*img == image).then(|| buf.clone()))
+            .find_map(|((img, img_scale), buf, _)| {
+                (*img == image && *img_scale == scale).then(|| buf.clone())
+            })
             .unwrap_or_else(|| {
-                // TODO: scale
                 let buffer = MemoryRenderBuffer::from_slice(
                     &image.pixels_rgba,
                     // Don't make Abgr, then the format doesn't match the
@@ -127,25 +229,31 @@ impl CursorState {
                     None,
                 );
 
-                self.mem_buffer_cache.push((image, buffer.clone()));
+                let allocation = self
+                    .gpu_accounting
+                    .track(AllocationKind::Cursor, image.pixels_rgba.len() as u64);
+                self.mem_buffer_cache
+                    .push(((image, scale), buffer.clone(), allocation));
 
                 buffer
             })
     }
 
-    pub fn pointer_element(&mut self) -> PointerElement {
+    /// Returns the pointer element to draw on the given output.
+    pub fn pointer_element(&mut self, output: Option<&Output>) -> PointerElement {
         let _span = tracy_client::span!("CursorState::pointer_element");
 
-        match &self.current_cursor_image {
+        match self.cursor_image() {
             CursorImageStatus::Hidden => PointerElement::Hidden,
             CursorImageStatus::Named(icon) => {
+                let icon = *icon;
                 let cursor = self
-                    .get_xcursor_images(*icon)
-                    .or_else(|| self.get_xcursor_images(CursorIcon::Default))
+                    .get_xcursor_images(icon, output)
+                    .or_else(|| self.get_xcursor_images(CursorIcon::Default, output))
                     .unwrap();
                 PointerElement::Named {
                     cursor,
-                    size: self.size,
+                    size: self.theme_and_size(output).1,
                 }
             }
             CursorImageStatus::Surface(surface) => PointerElement::Surface {
@@ -159,8 +267,9 @@ impl CursorState {
         &mut self,
         time: Time<Monotonic>,
         scale: f64,
+        output: Option<&Output>,
     ) -> Option<Rectangle<i32, Buffer>> {
-        let mut geo = match self.pointer_element() {
+        let mut geo = match self.pointer_element(output) {
             PointerElement::Hidden => None,
             PointerElement::Named { cursor, size } => {
                 let image = cursor.image(time.into(), size * scale.ceil() as u32);
@@ -182,7 +291,7 @@ impl CursorState {
         }
         .unwrap_or_default();
 
-        geo.loc -= self.cursor_hotspot(time, scale).unwrap_or_default();
+        geo.loc -= self.cursor_hotspot(time, scale, output).unwrap_or_default();
 
         let (dnd_geo, offset) = self
             .dnd_icon()
@@ -209,8 +318,9 @@ impl CursorState {
         &mut self,
         time: Time<Monotonic>,
         scale: f64,
+        output: Option<&Output>,
     ) -> Option<Point<i32, Buffer>> {
-        match self.pointer_element() {
+        match self.pointer_element(output) {
             PointerElement::Hidden => None,
             PointerElement::Named { cursor, size } => {
                 let image = cursor.image(time.into(), size * scale.ceil() as u32);
@@ -239,18 +349,20 @@ impl CursorState {
         }
     }
 
-    pub fn is_current_cursor_animated(&mut self) -> bool {
+    pub fn is_current_cursor_animated(&mut self, output: Option<&Output>) -> bool {
         let _span = tracy_client::span!("CursorState::is_current_cursor_animated");
 
-        match &self.current_cursor_image {
+        match self.cursor_image() {
             CursorImageStatus::Hidden => false,
             CursorImageStatus::Named(icon) => {
+                let icon = *icon;
                 let cursor = self
-                    .get_xcursor_images(*icon)
-                    .or_else(|| self.get_xcursor_images(CursorIcon::Default))
+                    .get_xcursor_images(icon, output)
+                    .or_else(|| self.get_xcursor_images(CursorIcon::Default, output))
                     .unwrap();
 
-                let img_count = nearest_size_images(self.size, &cursor.images).count();
+                let size = self.theme_and_size(output).1;
+                let img_count = nearest_size_images(size, &cursor.images).count();
 
                 let is_animated = img_count > 1;
                 is_animated
@@ -286,7 +398,7 @@ impl CursorState {
     /// Returns whether a render should be scheduled in response.
     pub fn handle_commit(&mut self, surface: &WlSurface, root: &WlSurface) -> bool {
         // This is a cursor surface
-        if matches!(self.cursor_image(), CursorImageStatus::Surface(s) if s == root) {
+        if matches!(&self.client_cursor_image, CursorImageStatus::Surface(s) if s == root) {
             if surface == root {
                 // Update the hotspot if the buffer moved
                 compositor::with_states(surface, |states| {
@@ -339,10 +451,10 @@ impl CursorState {
     pub fn cleanup(&mut self) {
         let _span = tracy_client::span!("CursorState::cleanup");
 
-        if let CursorImageStatus::Surface(surface) = &self.current_cursor_image
+        if let CursorImageStatus::Surface(surface) = &self.client_cursor_image
             && !surface.alive()
         {
-            self.current_cursor_image = CursorImageStatus::default_named();
+            self.client_cursor_image = CursorImageStatus::default_named();
         }
     }
 }
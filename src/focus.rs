@@ -3,6 +3,7 @@
 use keyboard::KeyboardFocusTarget;
 use smithay::{
     desktop::layer_map_for_output,
+    input::keyboard::KeyboardHandle,
     output::Output,
     reexports::wayland_server::Resource,
     utils::{IsAlive, SERIAL_COUNTER},
@@ -15,9 +16,10 @@ use smithay::{
 use crate::{
     api::signal::Signal,
     state::{Pinnacle, State, WithState},
-    window::{WindowElement, ZIndexElement},
+    window::{WindowElement, ZIndexElement, window_state::WindowLayer},
 };
 
+pub mod cycle;
 pub mod keyboard;
 pub mod pointer;
 
@@ -38,6 +40,7 @@ impl State {
     ///   but this may change in the future.
     /// - On-demand layer surfaces can only be focused by clicking on them.
     ///   They retain focus unless a window is focused or it is clicked off of.
+    ///   When they close, focus returns to the window that was focused before.
     /// - Only the focused window on the focused output gets focus.
     ///   If the focused output changes, the window may lose focus.
     pub fn update_keyboard_focus(&mut self) {
@@ -47,6 +50,102 @@ impl State {
             return;
         };
 
+        let prev_focus = keyboard.current_focus();
+
+        self.refresh_keyboard_focus(&keyboard);
+
+        let focus = keyboard.current_focus();
+        if prev_focus != focus {
+            self.pinnacle.pager_state.mark_dirty();
+        }
+
+        self.update_focused_layer(prev_focus, focus);
+    }
+
+    /// Focuses and raises `window`, unminimizing it, focusing its output, and switching to one
+    /// of its tags if it isn't on an active one.
+    pub fn activate_window(&mut self, window: WindowElement, reason: FocusReason) {
+        let _span = tracy_client::span!("State::activate_window");
+
+        let Some(output) = window.output(&self.pinnacle) else {
+            return;
+        };
+
+        crate::api::window::set_minimized(self, &window, false);
+
+        self.pinnacle.focus_output(&output);
+        self.pinnacle
+            .keyboard_focus_stack
+            .set_focus(window.clone(), reason);
+        self.pinnacle.raise_window(window.clone());
+
+        if !window.is_on_active_tag() {
+            let new_active_tag = window.with_state(|state| {
+                state
+                    .tags
+                    .iter()
+                    .min_by_key(|tag| tag.id().to_inner())
+                    .cloned()
+            });
+
+            if let Some(tag) = new_active_tag {
+                crate::api::tag::switch_to(self, &tag);
+            }
+        } else {
+            self.schedule_render(&output);
+        }
+    }
+
+    /// Signals layer surfaces gaining and losing keyboard focus.
+    fn update_focused_layer(
+        &mut self,
+        prev_focus: Option<KeyboardFocusTarget>,
+        focus: Option<KeyboardFocusTarget>,
+    ) {
+        let layer = match focus {
+            Some(KeyboardFocusTarget::LayerSurface(layer)) => Some(layer),
+            _ => None,
+        };
+
+        if self.pinnacle.focused_layer.as_ref().map(|(layer, _)| layer) == layer.as_ref() {
+            return;
+        }
+
+        if let Some((old_layer, output)) = self.pinnacle.focused_layer.take() {
+            self.pinnacle.layer_focus_return = None;
+            self.pinnacle
+                .signal_state
+                .layer_unfocused
+                .signal((&output, &old_layer));
+        }
+
+        let Some(layer) = layer else {
+            return;
+        };
+
+        let output = self
+            .pinnacle
+            .space
+            .outputs()
+            .find(|op| layer_map_for_output(op).layers().any(|l| l == &layer))
+            .cloned();
+
+        let Some(output) = output else {
+            return;
+        };
+
+        if let Some(KeyboardFocusTarget::Window(window)) = prev_focus {
+            self.pinnacle.layer_focus_return = Some(window);
+        }
+
+        self.pinnacle
+            .signal_state
+            .layer_focused
+            .signal((&output, &layer));
+        self.pinnacle.focused_layer = Some((layer, output));
+    }
+
+    fn refresh_keyboard_focus(&mut self, keyboard: &KeyboardHandle<State>) {
         if keyboard.current_focus().is_some_and(|focus| !focus.alive()) {
             keyboard.set_focus(self, None, SERIAL_COUNTER.next_serial());
         }
@@ -152,9 +251,10 @@ impl State {
         }
 
         // Handle on-demand layer shell focus
-        self.pinnacle
-            .on_demand_layer_focus
-            .take_if(|layer| !layer.alive());
+        self.pinnacle.on_demand_layer_focus.take_if(|layer| {
+            !layer.alive()
+                || layer.cached_state().keyboard_interactivity == KeyboardInteractivity::None
+        });
 
         if let Some(layer) = self.pinnacle.on_demand_layer_focus.as_ref() {
             let layer_target = KeyboardFocusTarget::LayerSurface(layer.clone());
@@ -179,12 +279,20 @@ impl State {
         let focused_window = self
             .pinnacle
             .focused_output()
-            .and_then(|op| self.pinnacle.focus_stack_for_output(op).last().cloned())
+            .and_then(|op| {
+                self.pinnacle
+                    .focus_stack_for_output(op)
+                    .rev()
+                    .find(|win| win.accepts_input())
+                    .cloned()
+            })
             .filter(|_| self.pinnacle.keyboard_focus_stack.focused);
 
         if keyboard.current_focus().is_some_and(
             |focus| matches!(&focus, KeyboardFocusTarget::Window(w) if Some(w) == focused_window.as_ref()),
         ) {
+            // Refocusing the focused window isn't a focus change
+            self.pinnacle.keyboard_focus_stack.reason = None;
             return;
         }
 
@@ -195,7 +303,11 @@ impl State {
                 toplevel.send_pending_configure();
             }
             if focused {
-                self.pinnacle.signal_state.window_focused.signal(win);
+                let (reason, serial) = self.pinnacle.keyboard_focus_stack.take_focus_change();
+                self.pinnacle
+                    .signal_state
+                    .window_focused
+                    .signal((win, reason, serial));
             }
         }
 
@@ -208,6 +320,51 @@ impl State {
 }
 
 impl Pinnacle {
+    /// Signals the focused window's title and app id when the focused window
+    /// or either of them changes.
+    pub fn process_focused_title_signal(&mut self) {
+        let focused_window = self
+            .seat
+            .get_keyboard()
+            .and_then(|keyboard| keyboard.current_focus())
+            .and_then(|focus| match focus {
+                KeyboardFocusTarget::Window(window) => Some(window),
+                _ => None,
+            });
+
+        self.signal_state
+            .window_focused_title_changed
+            .signal(focused_window.as_ref());
+    }
+
+    /// Releases on-demand keyboard focus from a closing layer surface.
+    ///
+    /// If the layer had focus, focus returns to the window that was focused
+    /// before the layer took it.
+    pub fn release_layer_focus(&mut self, surface: &wlr_layer::LayerSurface) {
+        if self
+            .on_demand_layer_focus
+            .take_if(|layer| layer.layer_surface() == surface)
+            .is_none()
+        {
+            return;
+        }
+
+        let Some(window) = self.layer_focus_return.take() else {
+            return;
+        };
+
+        if !self.windows.contains(&window) {
+            return;
+        }
+
+        if let Some(output) = window.output(self) {
+            self.focus_output(&output);
+        }
+        self.keyboard_focus_stack
+            .set_focus(window, FocusReason::UnmapFallback);
+    }
+
     pub fn fixup_z_layering(&mut self) {
         let _span = tracy_client::span!("Pinnacle::fixup_z_layering");
 
@@ -220,26 +377,48 @@ impl Pinnacle {
         });
     }
 
-    /// Raise a window to the top of the z-index stack.
+    /// Raise a window to the top of its layer in the z-index stack.
     pub fn raise_window(&mut self, window: WindowElement) {
         let _span = tracy_client::span!("Pinnacle::raise_window");
 
-        self.space.raise_element(&window, false);
+        self.pager_state.mark_dirty();
+
+        let layer = window.with_state(|state| state.layer);
 
         self.z_index_stack
             .retain(|win| !matches!(win, ZIndexElement::Window(win) if win == window));
-        self.z_index_stack.push(ZIndexElement::Window(window));
+        let idx = self
+            .z_index_stack
+            .iter()
+            .position(|z| z.layer() > layer)
+            .unwrap_or(self.z_index_stack.len());
+        self.z_index_stack
+            .insert(idx, ZIndexElement::Window(window));
+
+        for win in self.z_index_stack[idx..].iter() {
+            if let ZIndexElement::Window(win) = win {
+                self.space.raise_element(win, false);
+            }
+        }
 
         self.update_xwayland_stacking_order();
     }
 
-    /// Lower a window to the bottom of the z-index stack.
+    /// Lower a window to the bottom of its layer in the z-index stack.
     pub fn lower_window(&mut self, window: WindowElement) {
         let _span = tracy_client::span!("Pinnacle::lower_window");
 
+        let layer = window.with_state(|state| state.layer);
+
         self.z_index_stack
             .retain(|win| !matches!(win, ZIndexElement::Window(win) if win == window));
-        self.z_index_stack.insert(0, ZIndexElement::Window(window));
+        let idx = self
+            .z_index_stack
+            .iter()
+            .position(|z| z.layer() >= layer)
+            .unwrap_or(self.z_index_stack.len());
+        self.z_index_stack
+            .insert(idx, ZIndexElement::Window(window));
 
         for win in self.z_index_stack.iter() {
             if let ZIndexElement::Window(win) = win {
@@ -250,6 +429,12 @@ impl Pinnacle {
         self.update_xwayland_stacking_order();
     }
 
+    /// Moves a window to another stacking layer, placing it at the top of that layer.
+    pub fn set_window_layer(&mut self, window: WindowElement, layer: WindowLayer) {
+        window.with_state_mut(|state| state.layer = layer);
+        self.raise_window(window);
+    }
+
     /// Get the currently focused output, or the first mapped output if there is none, or None.
     pub fn focused_output(&self) -> Option<&Output> {
         let _span = tracy_client::span!("Pinnacle::focused_output");
@@ -282,6 +467,7 @@ impl Pinnacle {
             return;
         }
         self.output_focus_stack.set_focus(output.clone());
+        self.pager_state.mark_dirty();
         self.signal_state.output_focused.signal(output);
     }
 }
@@ -318,22 +504,52 @@ impl OutputFocusStack {
     }
 }
 
+/// Why a window got keyboard focus.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FocusReason {
+    /// The window was clicked.
+    Pointer,
+    /// The window was focused through the API, usually from a keybind.
+    Keybind,
+    /// The window or another client requested that it be activated.
+    Activation,
+    /// The window was focused when it mapped.
+    Map,
+    /// The previously focused window closed or was hidden and focus fell back to this one.
+    UnmapFallback,
+}
+
 /// A stack of windows, with the top one being the one in focus.
 #[derive(Debug, Default)]
 pub struct WindowKeyboardFocusStack {
     stack: Vec<WindowElement>,
     focused: bool,
+    /// Why the top window was last set to be focused, if it hasn't gotten focus yet.
+    reason: Option<FocusReason>,
+    /// The serial of the latest focus change.
+    serial: u64,
 }
 
 impl WindowKeyboardFocusStack {
-    /// Sets `window` to be focused.
+    /// Sets `window` to be focused for `reason`.
     ///
     /// If it's already in the stack, it will be removed then pushed.
     /// If it isn't, it will just be pushed.
-    pub fn set_focus(&mut self, window: WindowElement) {
+    pub fn set_focus(&mut self, window: WindowElement, reason: FocusReason) {
         self.stack.retain(|win| win != window);
         self.stack.push(window);
         self.focused = true;
+        self.reason = Some(reason);
+    }
+
+    /// Returns the reason for and the serial of a window getting keyboard focus.
+    ///
+    /// Serials increase with every focus change. Focus changes not caused by
+    /// [`Self::set_focus`] are [`FocusReason::UnmapFallback`].
+    pub fn take_focus_change(&mut self) -> (FocusReason, u64) {
+        self.serial += 1;
+        let reason = self.reason.take().unwrap_or(FocusReason::UnmapFallback);
+        (reason, self.serial)
     }
 
     /// Adds a window to the focus stack while keeping the currently focused window
@@ -362,6 +578,28 @@ impl WindowKeyboardFocusStack {
         self.stack.iter()
     }
 
+    /// Returns the windows that have been focused, most recently focused first.
+    ///
+    /// OR windows are skipped as they never really have focus.
+    pub fn history(&self) -> impl Iterator<Item = &WindowElement> {
+        self.stack
+            .iter()
+            .rev()
+            .filter(|win| !win.is_x11_override_redirect())
+    }
+
+    /// Returns how many windows were focused more recently than `window`,
+    /// or `None` if it was never focused.
+    pub fn focus_age(&self, window: &WindowElement) -> Option<usize> {
+        self.history().position(|win| win == window)
+    }
+
+    /// Returns the most recently focused window that isn't currently focused.
+    pub fn previous_focus(&self) -> Option<&WindowElement> {
+        let current_focus = self.current_focus();
+        self.history().find(|win| Some(*win) != current_focus)
+    }
+
     /// Gets the currently focused window on this stack.
     ///
     /// This is the topmost window that is on an active tag and not
@@ -374,7 +612,7 @@ impl WindowKeyboardFocusStack {
         self.stack
             .iter()
             .rev()
-            .filter(|win| win.is_on_active_tag())
+            .filter(|win| win.is_shown())
             .find(|win| !win.is_x11_override_redirect())
     }
 }
@@ -0,0 +1,145 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Cycling through windows in most recently focused order, like alt-tab.
+
+use crate::{
+    state::{Pinnacle, State},
+    window::{WindowElement, ZIndexElement},
+};
+
+/// A cycle through windows, most recently focused first.
+///
+/// While cycling, the current candidate is raised but not focused.
+/// Focus moves to it when the cycle ends.
+#[derive(Debug)]
+pub struct WindowCycle {
+    /// The windows to cycle through, most recently focused first.
+    candidates: Vec<WindowElement>,
+    /// The index of the current candidate in `candidates`.
+    current: usize,
+    /// The raised candidate along with where it was in the z-index stack before.
+    raised: Option<(WindowElement, usize)>,
+}
+
+impl WindowCycle {
+    /// Returns the current candidate.
+    pub fn current(&self) -> Option<&WindowElement> {
+        self.candidates.get(self.current)
+    }
+
+    /// Removes a window that's going away from the cycle.
+    pub fn remove(&mut self, window: &WindowElement) {
+        if let Some(idx) = self.candidates.iter().position(|win| win == window) {
+            self.candidates.remove(idx);
+            if idx < self.current {
+                self.current -= 1;
+            }
+            if self.current >= self.candidates.len() {
+                self.current = 0;
+            }
+        }
+
+        if self.raised.as_ref().is_some_and(|(win, _)| win == window) {
+            self.raised = None;
+        }
+    }
+}
+
+impl Pinnacle {
+    /// Puts the raised candidate of the current cycle back where it was
+    /// in the z-index stack.
+    fn lower_cycle_candidate(&mut self) {
+        let Some((window, idx)) = self
+            .window_cycle
+            .as_mut()
+            .and_then(|cycle| cycle.raised.take())
+        else {
+            return;
+        };
+
+        self.z_index_stack
+            .retain(|z| !matches!(z, ZIndexElement::Window(win) if *win == window));
+        let idx = idx.min(self.z_index_stack.len());
+        self.z_index_stack
+            .insert(idx, ZIndexElement::Window(window));
+
+        self.fixup_z_layering();
+        self.update_xwayland_stacking_order();
+    }
+}
+
+impl State {
+    /// Starts cycling through shown windows, starting at the focused one.
+    ///
+    /// This restarts the cycle if one is in progress.
+    pub fn begin_window_cycle(&mut self) {
+        self.pinnacle.lower_cycle_candidate();
+
+        let candidates = self
+            .pinnacle
+            .keyboard_focus_stack
+            .history()
+            .filter(|win| win.is_shown() && win.accepts_input())
+            .cloned()
+            .collect();
+
+        self.pinnacle.window_cycle = Some(WindowCycle {
+            candidates,
+            current: 0,
+            raised: None,
+        });
+    }
+
+    /// Moves the cycle to the next less recently focused window, or the next more
+    /// recently focused one if `forward` is false, and raises it.
+    ///
+    /// Starts a cycle if there isn't one. Returns the new candidate.
+    pub fn cycle_windows(&mut self, forward: bool) -> Option<WindowElement> {
+        if self.pinnacle.window_cycle.is_none() {
+            self.begin_window_cycle();
+        }
+
+        self.pinnacle.lower_cycle_candidate();
+
+        let cycle = self.pinnacle.window_cycle.as_mut()?;
+        let len = cycle.candidates.len();
+        if len == 0 {
+            return None;
+        }
+
+        cycle.current = if forward {
+            (cycle.current + 1) % len
+        } else {
+            (cycle.current + len - 1) % len
+        };
+        let window = cycle.candidates[cycle.current].clone();
+
+        let idx = self
+            .pinnacle
+            .z_index_stack
+            .iter()
+            .position(|z| matches!(z, ZIndexElement::Window(win) if *win == window));
+
+        crate::api::window::raise(self, window.clone());
+
+        if let Some(cycle) = self.pinnacle.window_cycle.as_mut() {
+            cycle.raised = idx.map(|idx| (window.clone(), idx));
+        }
+
+        Some(window)
+    }
+
+    /// Ends the cycle, focusing the current candidate.
+    ///
+    /// Returns the focused window, or `None` if there was no cycle or no candidate.
+    pub fn end_window_cycle(&mut self) -> Option<WindowElement> {
+        let window = self.pinnacle.window_cycle.take()?.current().cloned()?;
+
+        if window.is_shown() {
+            crate::api::window::raise(self, window.clone());
+            crate::api::window::set_focused(self, &window, true);
+        }
+
+        Some(window)
+    }
+}
@@ -22,6 +22,7 @@ use smithay::{
 
 use crate::{
     api::signal::Signal as _,
+    input::edge_action::EdgeAction,
     state::{Pinnacle, State, WithState},
     window::WindowElement,
 };
@@ -578,6 +579,21 @@ pub struct PointerContents {
     pub focus_under: Option<(<State as SeatHandler>::PointerFocus, Point<f64, Logical>)>,
     /// The output under the pointer.
     pub output_under: Option<WeakOutput>,
+    /// The edge or corner of the output the pointer is touching.
+    pub edge: Option<ScreenEdge>,
+}
+
+/// An edge or corner of an output that the pointer can't move past.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ScreenEdge {
+    Top,
+    Bottom,
+    Left,
+    Right,
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    BottomRight,
 }
 
 impl Pinnacle {
@@ -591,11 +607,26 @@ impl Pinnacle {
             .and_then(|op| op.upgrade());
 
         if old_op != new_op {
-            if let Some(old) = old_op {
-                self.signal_state.output_pointer_leave.signal(&old);
+            if let Some(old) = &old_op {
+                self.signal_state.output_pointer_leave.signal(old);
+            }
+            if let Some(new) = &new_op {
+                self.signal_state.output_pointer_enter.signal(new);
             }
-            if let Some(new) = new_op {
-                self.signal_state.output_pointer_enter.signal(&new);
+        }
+
+        // Only signal when the pointer first hits an edge so configs
+        // don't get spammed while it's pushed against it.
+        if let Some(new) = &new_op
+            && let Some(edge) = new_contents.edge
+            && (old_op != new_op || old_contents.edge != Some(edge))
+        {
+            self.signal_state.output_pointer_edge.signal((new, edge));
+
+            if let Some(EdgeAction::Callback { sender, .. }) =
+                self.input_state.edge_actions.action(edge, &new.name())
+            {
+                let _ = sender.send(new.name());
             }
         }
 
@@ -1,7 +1,9 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
 
 pub mod move_grab;
+pub mod region_select_grab;
 pub mod resize_grab;
+pub mod toplevel_drag_grab;
 
 use smithay::{
     input::pointer::{GrabStartData, PointerHandle},
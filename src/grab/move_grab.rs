@@ -6,19 +6,20 @@ use smithay::{
     input::{
         Seat, SeatHandler,
         pointer::{
-            AxisFrame, ButtonEvent, CursorIcon, CursorImageStatus, Focus, GestureHoldBeginEvent,
-            GestureHoldEndEvent, GesturePinchBeginEvent, GesturePinchEndEvent,
-            GesturePinchUpdateEvent, GestureSwipeBeginEvent, GestureSwipeEndEvent,
-            GestureSwipeUpdateEvent, GrabStartData, MotionEvent, PointerGrab, PointerInnerHandle,
-            RelativeMotionEvent,
+            AxisFrame, ButtonEvent, CursorIcon, Focus, GestureHoldBeginEvent, GestureHoldEndEvent,
+            GesturePinchBeginEvent, GesturePinchEndEvent, GesturePinchUpdateEvent,
+            GestureSwipeBeginEvent, GestureSwipeEndEvent, GestureSwipeUpdateEvent, GrabStartData,
+            MotionEvent, PointerGrab, PointerInnerHandle, RelativeMotionEvent,
         },
     },
+    output::Output,
     reexports::wayland_server::protocol::wl_surface::WlSurface,
     utils::{IsAlive, Logical, Point, Rectangle, Serial},
 };
 use tracing::{debug, warn};
 
 use crate::{
+    input::edge_action::{EdgeAction, snap_region_for_edge},
     state::{State, WithState},
     window::{WindowElement, window_state::LayoutModeKind},
 };
@@ -46,18 +47,11 @@ impl PointerGrab<State> for MoveSurfaceGrab {
         handle.motion(state, None, event);
 
         if !self.window.alive() {
-            state
-                .pinnacle
-                .cursor_state
-                .set_cursor_image(CursorImageStatus::default_named());
+            state.pinnacle.cursor_state.set_cursor_icon_override(None);
             handle.unset_grab(self, state, event.serial, event.time, true);
             return;
         }
 
-        state.pinnacle.raise_window(self.window.clone());
-
-        let mut layout_mode = self.window.with_state(|state| state.layout_mode.current());
-
         let output_under_pointer = state
             .pinnacle
             .pointer_contents
@@ -65,103 +59,14 @@ impl PointerGrab<State> for MoveSurfaceGrab {
             .as_ref()
             .and_then(|op| op.upgrade());
 
-        let win_output = self.window.output(&state.pinnacle);
-
-        if matches!(layout_mode, LayoutModeKind::Spilled) && win_output != output_under_pointer {
-            layout_mode = LayoutModeKind::Tiled;
-        }
-
-        match layout_mode {
-            LayoutModeKind::Tiled => {
-                let tag_output = self.window.output(&state.pinnacle);
-                if let Some(output_under_pointer) = output_under_pointer
-                    && Some(&output_under_pointer) != tag_output.as_ref()
-                {
-                    self.window.set_tags_to_output(&output_under_pointer);
-
-                    if self.window.with_state(|state| state.layout_mode.is_tiled()) {
-                        self.window
-                            .with_state_mut(|state| state.set_floating_loc(None));
-                    }
-
-                    state.pinnacle.request_layout(&output_under_pointer);
-
-                    if let Some(tag_output) = tag_output {
-                        state.pinnacle.request_layout(&tag_output);
-                    }
-                }
-
-                // INFO: this is being used instead of space.element_under(event.location) because that
-                // |     uses the bounding box, which is different from the actual geometry
-                let window_under = state
-                    .pinnacle
-                    .space
-                    .elements()
-                    .filter(|win| win.is_on_active_tag())
-                    .rev()
-                    .find(|&win| {
-                        if let Some(loc) = state.pinnacle.space.element_location(win) {
-                            let size = win.geometry().size;
-                            let rect = Rectangle { size, loc };
-                            rect.contains(event.location.to_i32_round())
-                        } else {
-                            false
-                        }
-                    })
-                    .cloned();
-
-                if let Some(window_under) = window_under {
-                    if state.pinnacle.layout_state.pending_swap {
-                        return;
-                    }
-
-                    if window_under == self.window {
-                        return;
-                    }
-
-                    if window_under.with_state(|state| !state.layout_mode.is_tiled()) {
-                        return;
-                    }
-
-                    let output = self.window.output(&state.pinnacle);
-
-                    debug!("Swapping window positions");
-                    state
-                        .pinnacle
-                        .swap_window_positions(&self.window, &window_under);
-
-                    state.pinnacle.layout_state.pending_swap = true;
-
-                    if let Some(output) = output.as_ref() {
-                        state.pinnacle.request_layout(output);
-                    }
-                }
-            }
-            LayoutModeKind::Floating | LayoutModeKind::Spilled => {
-                let delta = event.location - self.start_data.location;
-                let new_loc = self.initial_window_loc.to_f64() + delta;
-
-                state
-                    .pinnacle
-                    .map_window_to(&self.window, new_loc.to_i32_round());
-
-                self.window.with_state_mut(|state| {
-                    state.set_floating_loc(new_loc.to_i32_round());
-                });
-            }
-            LayoutModeKind::Maximized | LayoutModeKind::Fullscreen => {
-                let tag_output = self.window.output(&state.pinnacle);
-                if let Some(output_under_pointer) = output_under_pointer
-                    && Some(&output_under_pointer) != tag_output.as_ref()
-                {
-                    state
-                        .pinnacle
-                        .move_window_to_output(&self.window, output_under_pointer.clone());
-
-                    state.pinnacle.update_window_geometry(&self.window, false);
-                }
-            }
-        }
+        drag_window(
+            state,
+            &self.window,
+            self.initial_window_loc,
+            self.start_data.location,
+            event.location,
+            output_under_pointer,
+        );
     }
 
     fn relative_motion(
@@ -183,9 +88,7 @@ impl PointerGrab<State> for MoveSurfaceGrab {
         handle.button(data, event);
 
         if !handle.current_pressed().contains(&self.start_data.button) {
-            data.pinnacle
-                .cursor_state
-                .set_cursor_image(CursorImageStatus::default_named());
+            data.pinnacle.cursor_state.set_cursor_icon_override(None);
             handle.unset_grab(self, data, event.serial, event.time, true);
         }
     }
@@ -204,6 +107,10 @@ impl PointerGrab<State> for MoveSurfaceGrab {
     }
 
     fn unset(&mut self, state: &mut State) {
+        state.pinnacle.cursor_state.set_cursor_icon_override(None);
+
+        snap_dropped_window(state, &self.window);
+
         // FIXME: granular
         for output in state.pinnacle.space.outputs().cloned().collect::<Vec<_>>() {
             state.schedule_render(&output);
@@ -283,6 +190,150 @@ impl PointerGrab<State> for MoveSurfaceGrab {
     }
 }
 
+/// Snaps a floating window dropped at an edge of an output if that edge is set
+/// to snap windows.
+fn snap_dropped_window(state: &mut State, window: &WindowElement) {
+    if !window.alive() || !window.with_state(|state| state.layout_mode.is_floating()) {
+        return;
+    }
+
+    let contents = &state.pinnacle.pointer_contents;
+    let Some((output, edge)) = contents
+        .output_under
+        .as_ref()
+        .and_then(|op| op.upgrade())
+        .zip(contents.edge)
+    else {
+        return;
+    };
+
+    if !matches!(
+        state
+            .pinnacle
+            .input_state
+            .edge_actions
+            .action(edge, &output.name()),
+        Some(EdgeAction::SnapWindow)
+    ) {
+        return;
+    }
+
+    if window.output(&state.pinnacle).as_ref() != Some(&output) {
+        return;
+    }
+
+    state.snap_window_to_region(window, snap_region_for_edge(edge));
+}
+
+/// Moves a dragged window after the pointer or touch dragging it moved from `start_loc`
+/// to `loc`.
+///
+/// Floating windows follow the drag, tiled windows swap places with the tiled window under
+/// `loc`, and all windows move to `output_under` if it's another output.
+pub fn drag_window(
+    state: &mut State,
+    window: &WindowElement,
+    initial_window_loc: Point<f64, Logical>,
+    start_loc: Point<f64, Logical>,
+    loc: Point<f64, Logical>,
+    output_under: Option<Output>,
+) {
+    state.pinnacle.raise_window(window.clone());
+
+    let mut layout_mode = window.with_state(|state| state.layout_mode.current());
+
+    let win_output = window.output(&state.pinnacle);
+
+    if matches!(layout_mode, LayoutModeKind::Spilled) && win_output != output_under {
+        layout_mode = LayoutModeKind::Tiled;
+    }
+
+    match layout_mode {
+        LayoutModeKind::Tiled => {
+            let tag_output = window.output(&state.pinnacle);
+            if let Some(output_under) = output_under
+                && Some(&output_under) != tag_output.as_ref()
+            {
+                window.set_tags_to_output(&output_under);
+
+                if window.with_state(|state| state.layout_mode.is_tiled()) {
+                    window.with_state_mut(|state| state.set_floating_loc(None));
+                }
+
+                state.pinnacle.request_layout(&output_under);
+
+                if let Some(tag_output) = tag_output {
+                    state.pinnacle.request_layout(&tag_output);
+                }
+            }
+
+            // INFO: this is being used instead of space.element_under(loc) because that
+            // |     uses the bounding box, which is different from the actual geometry
+            let window_under = state
+                .pinnacle
+                .space
+                .elements()
+                .filter(|win| win.is_on_active_tag())
+                .rev()
+                .find(|&win| {
+                    if let Some(win_loc) = state.pinnacle.space.element_location(win) {
+                        let size = win.geometry().size;
+                        let rect = Rectangle { size, loc: win_loc };
+                        rect.contains(loc.to_i32_round())
+                    } else {
+                        false
+                    }
+                })
+                .cloned();
+
+            if let Some(window_under) = window_under {
+                if state.pinnacle.layout_state.pending_swap {
+                    return;
+                }
+
+                if window_under == *window {
+                    return;
+                }
+
+                if window_under.with_state(|state| !state.layout_mode.is_tiled()) {
+                    return;
+                }
+
+                let output = window.output(&state.pinnacle);
+
+                debug!("Swapping window positions");
+                state.pinnacle.swap_window_positions(window, &window_under);
+
+                state.pinnacle.layout_state.pending_swap = true;
+
+                if let Some(output) = output.as_ref() {
+                    state.pinnacle.request_layout(output);
+                }
+            }
+        }
+        LayoutModeKind::Floating | LayoutModeKind::Spilled => {
+            let delta = loc - start_loc;
+            let new_loc = initial_window_loc.to_f64() + delta;
+
+            state
+                .pinnacle
+                .move_floating_window(window, new_loc.to_i32_round());
+        }
+        LayoutModeKind::Maximized | LayoutModeKind::Fullscreen => {
+            let tag_output = window.output(&state.pinnacle);
+            if let Some(output_under) = output_under
+                && Some(&output_under) != tag_output.as_ref()
+            {
+                state
+                    .pinnacle
+                    .move_window_to_output(window, output_under.clone());
+
+                state.pinnacle.update_window_geometry(window, false);
+            }
+        }
+    }
+}
+
 impl State {
     /// The application initiated a move grab e.g. when you drag a titlebar.
     pub fn move_request_client(&mut self, surface: &WlSurface, seat: &Seat<State>, serial: Serial) {
@@ -354,6 +405,6 @@ impl State {
 
         self.pinnacle
             .cursor_state
-            .set_cursor_image(CursorImageStatus::Named(CursorIcon::Grabbing));
+            .set_cursor_icon_override(Some(CursorIcon::Grabbing));
     }
 }
@@ -5,10 +5,10 @@ use smithay::{
     input::{
         Seat, SeatHandler,
         pointer::{
-            AxisFrame, ButtonEvent, CursorIcon, CursorImageStatus, Focus, GestureHoldBeginEvent,
-            GestureHoldEndEvent, GesturePinchBeginEvent, GesturePinchEndEvent,
-            GesturePinchUpdateEvent, GestureSwipeBeginEvent, GestureSwipeEndEvent,
-            GestureSwipeUpdateEvent, GrabStartData, PointerGrab, PointerInnerHandle,
+            AxisFrame, ButtonEvent, CursorIcon, Focus, GestureHoldBeginEvent, GestureHoldEndEvent,
+            GesturePinchBeginEvent, GesturePinchEndEvent, GesturePinchUpdateEvent,
+            GestureSwipeBeginEvent, GestureSwipeEndEvent, GestureSwipeUpdateEvent, GrabStartData,
+            PointerGrab, PointerInnerHandle,
         },
     },
     reexports::{
@@ -132,10 +132,7 @@ impl PointerGrab<State> for ResizeSurfaceGrab {
         let output = self.window.output(&state.pinnacle);
 
         if !self.window.alive() || output.is_none() {
-            state
-                .pinnacle
-                .cursor_state
-                .set_cursor_image(CursorImageStatus::default_named());
+            state.pinnacle.cursor_state.set_cursor_icon_override(None);
             handle.unset_grab(self, state, event.serial, event.time, true);
             return;
         }
@@ -196,10 +193,10 @@ impl PointerGrab<State> for ResizeSurfaceGrab {
         let max_width = if max_size.w != 0 { max_size.w } else { i32::MAX };
         let max_height = if max_size.h != 0 { max_size.h } else { i32::MAX };
 
-        self.last_window_size = Size::from((
+        self.last_window_size = self.window.constrain_size(Size::from((
             new_window_width.clamp(min_width, max_width),
             new_window_height.clamp(min_height, max_height),
-        ));
+        )));
 
         if self.window.with_state(|state| state.layout_mode.is_tiled()) {
             let (resize_x_dir, resize_y_dir) = match self.edges.0 {
@@ -276,9 +273,7 @@ impl PointerGrab<State> for ResizeSurfaceGrab {
         handle.button(data, event);
 
         if !handle.current_pressed().contains(&self.button_used) {
-            data.pinnacle
-                .cursor_state
-                .set_cursor_image(CursorImageStatus::default_named());
+            data.pinnacle.cursor_state.set_cursor_icon_override(None);
             handle.unset_grab(self, data, event.serial, event.time, true);
         }
     }
@@ -296,7 +291,8 @@ impl PointerGrab<State> for ResizeSurfaceGrab {
         &self.start_data
     }
 
-    fn unset(&mut self, _data: &mut State) {
+    fn unset(&mut self, data: &mut State) {
+        data.pinnacle.cursor_state.set_cursor_icon_override(None);
         self.ungrab();
     }
 
@@ -476,7 +472,7 @@ impl State {
 
             self.pinnacle
                 .cursor_state
-                .set_cursor_image(CursorImageStatus::Named(edges.cursor_icon()));
+                .set_cursor_icon_override(Some(edges.cursor_icon()));
         }
     }
 }
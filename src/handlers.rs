@@ -10,11 +10,15 @@ pub mod image_capture_source;
 pub mod image_copy_capture;
 pub mod session_lock;
 pub mod snowcap_decoration;
+pub mod snowcap_thumbnail;
 pub mod xdg_activation;
 mod xdg_shell;
+pub mod xdg_toplevel_drag;
+pub mod xdg_toplevel_icon;
 pub mod xwayland;
 
 use std::{
+    any::Any,
     collections::HashMap,
     os::fd::OwnedFd,
     sync::{Arc, atomic::Ordering},
@@ -26,11 +30,11 @@ use smithay::{
         input::TabletToolDescriptor,
         renderer::utils::{self, with_renderer_surface_state},
     },
-    delegate_compositor, delegate_content_type, delegate_cursor_shape, delegate_data_control,
-    delegate_data_device, delegate_ext_data_control, delegate_fractional_scale,
-    delegate_keyboard_shortcuts_inhibit, delegate_layer_shell, delegate_output,
-    delegate_pointer_constraints, delegate_pointer_gestures, delegate_presentation,
-    delegate_primary_selection, delegate_relative_pointer, delegate_seat,
+    delegate_alpha_modifier, delegate_compositor, delegate_content_type, delegate_cursor_shape,
+    delegate_data_control, delegate_data_device, delegate_ext_data_control,
+    delegate_fractional_scale, delegate_keyboard_shortcuts_inhibit, delegate_layer_shell,
+    delegate_output, delegate_pointer_constraints, delegate_pointer_gestures,
+    delegate_presentation, delegate_primary_selection, delegate_relative_pointer, delegate_seat,
     delegate_security_context, delegate_shm, delegate_single_pixel_buffer, delegate_tablet_manager,
     delegate_viewporter, delegate_xwayland_keyboard_grab, delegate_xwayland_shell,
     desktop::{
@@ -46,7 +50,10 @@ use smithay::{
     output::{Mode, Output, Scale},
     reexports::wayland_server::{
         Client, Resource,
-        protocol::{wl_buffer::WlBuffer, wl_output::WlOutput, wl_surface::WlSurface},
+        protocol::{
+            wl_buffer::WlBuffer, wl_data_source::WlDataSource, wl_output::WlOutput,
+            wl_surface::WlSurface,
+        },
     },
     utils::{Logical, Point, Rectangle},
     wayland::{
@@ -92,6 +99,7 @@ use crate::{
     delegate_gamma_control, delegate_output_management, delegate_output_power_management,
     delegate_screencopy,
     focus::{keyboard::KeyboardFocusTarget, pointer::PointerFocusTarget},
+    grab::toplevel_drag_grab::ToplevelDragGrab,
     hook::add_mapped_toplevel_pre_commit_hook,
     output::OutputMode,
     protocol::{
@@ -103,7 +111,7 @@ use crate::{
         screencopy::{Screencopy, ScreencopyHandler},
     },
     state::{ClientState, Pinnacle, State, WithState},
-    window::UnmappedState,
+    window::{UnmappedState, popup::clamp_popup_geometry},
 };
 
 impl BufferHandler for State {
@@ -141,8 +149,12 @@ impl CompositorHandler for State {
             .root_surface_cache
             .insert(surface.clone(), root.clone());
 
+        self.pinnacle.invalidate_render_plan(&root);
+
         // Root surface commit
         if surface == &root {
+            self.pinnacle.apply_pending_icon(surface);
+
             // Unmapped window commit
             if let Some(idx) = self
                 .pinnacle
@@ -189,7 +201,8 @@ impl CompositorHandler for State {
                         {
                             // FIXME: If there are no tags and the window still commits a buffer,
                             // Pinnacle will crash at `map_new_window`.
-                            unmapped.window.set_tags_to_output(&output);
+                            self.pinnacle
+                                .set_tags_for_new_window(&unmapped.window, &output);
                             self.pinnacle.request_window_rules(&mut unmapped);
                         }
                     }
@@ -222,6 +235,8 @@ impl CompositorHandler for State {
 
                 window.on_commit();
 
+                self.pinnacle.update_letterbox(&window);
+
                 if window.is_wayland() {
                     // Toplevel has become unmapped,
                     // see https://wayland.app/protocols/xdg-shell#xdg_toplevel
@@ -327,6 +342,14 @@ impl CompositorHandler for State {
                 return;
             }
 
+            // A null buffer commit unmaps the layer, so it shouldn't hold onto focus
+            if surface == layer.wl_surface()
+                && with_renderer_surface_state(surface, |state| state.buffer().is_none())
+                    .unwrap_or_default()
+            {
+                self.pinnacle.release_layer_focus(layer.layer_surface());
+            }
+
             let layer_changed = layer_map_for_output(&output).arrange();
             if layer_changed {
                 self.pinnacle.request_layout(&output);
@@ -410,10 +433,15 @@ impl CompositorHandler for State {
                     renderer,
                     output.current_scale().fractional_scale().into(),
                     1.0,
+                    &self.pinnacle.gpu_accounting,
                 );
             });
         }
 
+        if let Some(root_surface) = self.pinnacle.root_surface_cache.get(surface) {
+            self.pinnacle.invalidate_render_plan(root_surface);
+        }
+
         self.pinnacle
             .root_surface_cache
             .retain(|surf, root| surf != surface && root != surface);
@@ -462,12 +490,25 @@ impl WaylandDndGrabHandler for State {
             GrabType::Pointer => {
                 let pointer = seat.get_pointer().unwrap();
                 let start_data = pointer.grab_start_data().unwrap();
-                pointer.set_grab(
-                    self,
-                    DnDGrab::new_pointer(&self.pinnacle.display_handle, start_data, source, seat),
-                    serial,
-                    pointer::Focus::Keep,
-                );
+
+                let is_toplevel_drag = (&source as &dyn Any)
+                    .downcast_ref::<WlDataSource>()
+                    .is_some_and(|source| self.pinnacle.xdg_toplevel_drag_state.start(source));
+
+                let dnd_grab =
+                    DnDGrab::new_pointer(&self.pinnacle.display_handle, start_data, source, seat);
+
+                if is_toplevel_drag {
+                    pointer.set_grab(
+                        self,
+                        ToplevelDragGrab { dnd_grab },
+                        serial,
+                        pointer::Focus::Keep,
+                    );
+                    self.update_toplevel_drag(pointer.current_location());
+                } else {
+                    pointer.set_grab(self, dnd_grab, serial, pointer::Focus::Keep);
+                }
             }
             GrabType::Touch => {
                 let Some(touch) = seat.get_touch() else {
@@ -494,6 +535,7 @@ impl DndGrabHandler for State {
         _location: Point<f64, Logical>,
     ) {
         self.pinnacle.cursor_state.set_dnd_icon(None);
+        self.pinnacle.xdg_toplevel_drag_state.end();
     }
 }
 
@@ -711,9 +753,7 @@ impl WlrLayerShellHandler for State {
     fn layer_destroyed(&mut self, surface: wlr_layer::LayerSurface) {
         let _span = tracy_client::span!("WlrLayerShellHandler::layer_destroyed");
 
-        self.pinnacle
-            .on_demand_layer_focus
-            .take_if(|layer| layer.layer_surface() == &surface);
+        self.pinnacle.release_layer_focus(&surface);
 
         let mut output: Option<Output> = None;
         if let Some((mut map, layer, op)) = self.pinnacle.space.outputs().find_map(|o| {
@@ -898,6 +938,10 @@ impl OutputManagementHandler for State {
     fn apply_configuration(&mut self, config: HashMap<Output, OutputConfiguration>) -> bool {
         let _span = tracy_client::span!("OutputManagementHandler::apply_configuration");
 
+        if !output_configuration_is_valid(&config) {
+            return false;
+        }
+
         for (output, config) in config {
             match config {
                 OutputConfiguration::Disabled => {
@@ -967,11 +1011,53 @@ impl OutputManagementHandler for State {
 
     fn test_configuration(&mut self, config: HashMap<Output, OutputConfiguration>) -> bool {
         debug!(?config);
-        true
+        output_configuration_is_valid(&config)
     }
 }
 delegate_output_management!(State);
 
+/// Checks that an output configuration from `wlr-output-management` can be applied.
+///
+/// Configurations that would disable every output, or that have
+/// an empty mode or a non-positive scale, are rejected.
+fn output_configuration_is_valid(config: &HashMap<Output, OutputConfiguration>) -> bool {
+    let mut any_enabled = false;
+
+    for (output, config) in config {
+        let OutputConfiguration::Enabled { mode, scale, .. } = config else {
+            continue;
+        };
+
+        any_enabled = true;
+
+        if let Some((size, _)) = mode
+            && (size.w <= 0 || size.h <= 0)
+        {
+            warn!(
+                "Rejecting output configuration with an empty mode for {}",
+                output.name()
+            );
+            return false;
+        }
+
+        if let Some(scale) = scale
+            && !(scale.is_finite() && *scale > 0.0)
+        {
+            warn!(
+                "Rejecting output configuration with scale {scale} for {}",
+                output.name()
+            );
+            return false;
+        }
+    }
+
+    if !any_enabled {
+        warn!("Rejecting output configuration that disables all outputs");
+    }
+
+    any_enabled
+}
+
 impl OutputPowerManagementHandler for State {
     fn output_power_management_state(&mut self) -> &mut OutputPowerManagementState {
         &mut self.pinnacle.output_power_management_state
@@ -1026,6 +1112,8 @@ delegate_pointer_gestures!(State);
 
 delegate_single_pixel_buffer!(State);
 
+delegate_alpha_modifier!(State);
+
 delegate_content_type!(State);
 
 impl Pinnacle {
@@ -1042,8 +1130,11 @@ impl Pinnacle {
             unreachable!("popup has a root surface and therefore a parent");
         };
 
+        // Only popups parented directly to a window are affected by its decorations;
+        // nested popups are positioned relative to their parent popup.
         let deco_offset = self
             .window_for_surface(&root)
+            .filter(|_| root == parent)
             .map(|win| win.total_decoration_offset())
             .unwrap_or_default();
 
@@ -1058,11 +1149,15 @@ impl Pinnacle {
                 .element_geometry(win)
                 .context("window was not mapped")?;
 
-            (
-                win_geo.loc,
-                self.focused_output().context("no focused output")?.clone(),
-            )
+            let output = win
+                .output(self)
+                .or_else(|| self.focused_output().cloned())
+                .context("no output for popup")?;
+
+            (win_geo.loc, output)
         } else {
+            // Layer surfaces have no window geometry, so their popups are positioned
+            // relative to the layer's location on its output.
             self.space
                 .outputs()
                 .find_map(|op| {
@@ -1093,6 +1188,10 @@ impl Pinnacle {
 
         let mut popup_geo = positioner.get_unconstrained_geometry(output_geo);
 
+        if self.config.popup_policy.clamp_to_output {
+            popup_geo = clamp_popup_geometry(popup_geo, output_geo);
+        }
+
         // Make the popup location relative to the wl surface
         // by "undoing" the offset above.
         popup_geo.loc -= deco_offset;
@@ -2,6 +2,7 @@ use smithay::reexports::wayland_server::protocol::{wl_output::WlOutput, wl_surfa
 
 use crate::{
     delegate_foreign_toplevel,
+    focus::FocusReason,
     protocol::foreign_toplevel::{ForeignToplevelHandler, ForeignToplevelManagerState},
     state::{State, WithState},
 };
@@ -17,28 +18,8 @@ impl ForeignToplevelHandler for State {
         let Some(window) = self.pinnacle.window_for_surface(&wl_surface).cloned() else {
             return;
         };
-        let Some(output) = window.output(&self.pinnacle) else {
-            return;
-        };
-
-        self.pinnacle.keyboard_focus_stack.set_focus(window.clone());
-        self.pinnacle.raise_window(window.clone());
-
-        if !window.is_on_active_tag() {
-            let new_active_tag = window.with_state(|state| {
-                state
-                    .tags
-                    .iter()
-                    .min_by_key(|tag| tag.id().to_inner())
-                    .cloned()
-            });
 
-            if let Some(tag) = new_active_tag {
-                crate::api::tag::switch_to(self, &tag);
-            }
-        } else {
-            self.schedule_render(&output);
-        }
+        self.activate_window(window, FocusReason::Activation);
     }
 
     fn close(&mut self, wl_surface: WlSurface) {
@@ -103,40 +84,24 @@ impl ForeignToplevelHandler for State {
             });
     }
 
-    // TODO:
     fn set_minimized(&mut self, wl_surface: WlSurface) {
         let _span = tracy_client::span!("ForeignToplevelHandler::set_minimized");
 
-        let Some(window) = self.pinnacle.window_for_surface(&wl_surface) else {
-            return;
-        };
-
-        window.with_state_mut(|state| state.minimized = true);
-
-        let Some(output) = window.output(&self.pinnacle) else {
+        let Some(window) = self.pinnacle.window_for_surface(&wl_surface).cloned() else {
             return;
         };
 
-        self.pinnacle.request_layout(&output);
-        self.schedule_render(&output);
+        crate::api::window::set_minimized(self, &window, true);
     }
 
-    // TODO:
     fn unset_minimized(&mut self, wl_surface: WlSurface) {
         let _span = tracy_client::span!("ForeignToplevelHandler::unset_minimized");
 
-        let Some(window) = self.pinnacle.window_for_surface(&wl_surface) else {
-            return;
-        };
-
-        window.with_state_mut(|state| state.minimized = false);
-
-        let Some(output) = window.output(&self.pinnacle) else {
+        let Some(window) = self.pinnacle.window_for_surface(&wl_surface).cloned() else {
             return;
         };
 
-        self.pinnacle.request_layout(&output);
-        self.schedule_render(&output);
+        crate::api::window::set_minimized(self, &window, false);
     }
 }
 delegate_foreign_toplevel!(State);
@@ -23,6 +23,8 @@ impl IdleInhibitHandler for State {
     fn inhibit(&mut self, surface: WlSurface) {
         self.pinnacle.idle_inhibiting_surfaces.insert(surface);
         self.pinnacle.idle_notifier_state.set_is_inhibited(true);
+        // Don't cover the surface that wants to stay visible
+        self.stop_screensaver();
     }
 
     fn uninhibit(&mut self, surface: WlSurface) {
@@ -38,11 +40,18 @@ impl Pinnacle {
 
         self.idle_inhibiting_surfaces.retain(|s| s.alive());
 
-        let is_inhibited = self.idle_inhibiting_surfaces.iter().any(|surface| {
-            compositor::with_states(surface, |states| {
-                surface_primary_scanout_output(surface, states).is_some()
-            })
-        });
+        let is_inhibited = self.config.inhibit_idle
+            || self.idle_inhibiting_surfaces.iter().any(|surface| {
+                compositor::with_states(surface, |states| {
+                    surface_primary_scanout_output(surface, states).is_some()
+                })
+            });
+
+        // Count the time spent inhibited as activity so the screen doesn't blank
+        // right after a video ends
+        if self.idle_notifier_state.is_inhibited() && !is_inhibited {
+            self.idle_state.reset_activity();
+        }
 
         self.idle_notifier_state.set_is_inhibited(is_inhibited);
     }
@@ -225,6 +225,7 @@ impl State {
                             let (pointer_elements, _) = pointer_render_elements(
                                 pointer_loc,
                                 scale,
+                                Some(&output),
                                 renderer,
                                 &mut self.pinnacle.cursor_state,
                                 &self.pinnacle.clock,
@@ -276,6 +277,8 @@ impl State {
                 };
 
                 let elements = if session.draw_cursor() {
+                    let output = win.output(&self.pinnacle);
+
                     self.backend
                         .with_renderer(|renderer| {
                             let win_loc = self.pinnacle.space.element_location(&win);
@@ -288,6 +291,7 @@ impl State {
                                 let (pointer_elements, _) = pointer_render_elements(
                                     pointer_loc,
                                     scale,
+                                    output.as_ref(),
                                     renderer,
                                     &mut self.pinnacle.cursor_state,
                                     &self.pinnacle.clock,
@@ -378,10 +382,12 @@ impl State {
             return;
         }
 
+        let output = self.pinnacle.output_for_cursor_source(&session.source());
+
         let cursor_offset = self
             .pinnacle
             .cursor_state
-            .cursor_geometry(self.pinnacle.clock.now(), scale)
+            .cursor_geometry(self.pinnacle.clock.now(), scale, output.as_ref())
             .unwrap_or_default()
             .loc;
 
@@ -393,6 +399,7 @@ impl State {
                 let (pointer_elements, _) = pointer_render_elements(
                     (0.0, 0.0).into(),
                     scale,
+                    output.as_ref(),
                     renderer,
                     &mut self.pinnacle.cursor_state,
                     &self.pinnacle.clock,
@@ -443,6 +450,7 @@ impl State {
                         .cursor_geometry(
                             self.pinnacle.clock.now(),
                             output.current_scale().fractional_scale(),
+                            Some(&output),
                         )
                         .unwrap_or_default();
 
@@ -497,10 +505,16 @@ impl State {
                         cursor_loc.to_physical_precise_round(fractional_scale);
                     let cursor_loc: Point<i32, Buffer> = (cursor_loc.x, cursor_loc.y).into();
 
+                    let output = window.output(&self.pinnacle);
+
                     let mut cursor_geo = self
                         .pinnacle
                         .cursor_state
-                        .cursor_geometry(self.pinnacle.clock.now(), fractional_scale)
+                        .cursor_geometry(
+                            self.pinnacle.clock.now(),
+                            fractional_scale,
+                            output.as_ref(),
+                        )
                         .unwrap_or_default();
 
                     cursor_geo.loc += cursor_loc;
@@ -809,12 +823,28 @@ impl Pinnacle {
             }
         };
 
+        let output = self.output_for_cursor_source(source);
+
         let geo = self
             .cursor_state
-            .cursor_geometry(self.clock.now(), scale)
+            .cursor_geometry(self.clock.now(), scale, output.as_ref())
             .unwrap_or(Rectangle::from_size((1, 1).into()));
         Some((geo.size, scale))
     }
+
+    /// Returns the output whose cursor is captured for an [`ImageCaptureSource`].
+    ///
+    /// For toplevels, this is the output the window is on.
+    fn output_for_cursor_source(&self, source: &ImageCaptureSource) -> Option<Output> {
+        match source.user_data().get::<ImageCaptureSourceKind>()? {
+            ImageCaptureSourceKind::Output(output) => output.upgrade(),
+            ImageCaptureSourceKind::Toplevel(foreign_toplevel) => {
+                let foreign_toplevel = foreign_toplevel.upgrade()?;
+                self.window_for_foreign_toplevel_handle(&foreign_toplevel)?
+                    .output(self)
+            }
+        }
+    }
 }
 
 /// Damage trackers for copy-capture sessions.
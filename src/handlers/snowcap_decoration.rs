@@ -73,6 +73,7 @@ impl SnowcapDecorationHandler for State {
 
         window.with_state_mut(|state| {
             state.decoration_surfaces.push(decoration_surface);
+            state.render_plan_cache.invalidate();
         });
     }
 
@@ -84,9 +85,13 @@ impl SnowcapDecorationHandler for State {
                 .map(|unmapped| &unmapped.window),
         ) {
             win.with_state_mut(|state| {
+                let len = state.decoration_surfaces.len();
                 state
                     .decoration_surfaces
                     .retain(|deco| deco.decoration_surface() != &surface);
+                if state.decoration_surfaces.len() != len {
+                    state.render_plan_cache.invalidate();
+                }
             });
         }
     }
@@ -0,0 +1,20 @@
+use smithay::utils::{Logical, Size};
+
+use crate::{
+    delegate_snowcap_thumbnail,
+    protocol::snowcap_thumbnail::{
+        SnowcapThumbnailHandler, SnowcapThumbnailState, ThumbnailSource,
+    },
+    state::State,
+};
+
+impl SnowcapThumbnailHandler for State {
+    fn snowcap_thumbnail_state(&mut self) -> &mut SnowcapThumbnailState {
+        &mut self.pinnacle.snowcap_thumbnail_state
+    }
+
+    fn thumbnail_source_size(&mut self, source: &ThumbnailSource) -> Option<Size<i32, Logical>> {
+        self.pinnacle.thumbnail_source_size(source)
+    }
+}
+delegate_snowcap_thumbnail!(State);
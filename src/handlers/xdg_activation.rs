@@ -10,7 +10,7 @@ use smithay::{
 };
 use tracing::debug;
 
-use crate::state::State;
+use crate::{focus::FocusReason, state::State};
 
 pub const XDG_ACTIVATION_TOKEN_TIMEOUT: Duration = Duration::from_secs(10);
 
@@ -98,13 +98,17 @@ impl XdgActivationHandler for State {
 
                         self.pinnacle.raise_window(window.clone());
 
-                        self.pinnacle.keyboard_focus_stack.set_focus(window);
+                        self.pinnacle
+                            .keyboard_focus_stack
+                            .set_focus(window, FocusReason::Activation);
 
                         self.schedule_render(&output);
+                    } else {
+                        crate::api::tag::mark_urgent(&mut self.pinnacle, &window);
                     }
                 }
                 ActivationContext::UrgentOnly => {
-                    // TODO: add urgent state to windows, use in a focus border/taskbar flash
+                    crate::api::tag::mark_urgent(&mut self.pinnacle, &window);
                 }
             }
         } else if let Some(unmapped) = self.pinnacle.unmapped_window_for_surface_mut(&surface) {
@@ -21,8 +21,9 @@ use crate::{
     focus::keyboard::KeyboardFocusTarget,
     state::{State, WithState},
     window::{
-        Unmapped, UnmappedState, WindowElement, rules::ClientRequests,
-        window_state::FullscreenOrMaximized,
+        Unmapped, UnmappedState, WindowElement,
+        rules::ClientRequests,
+        window_state::{FullscreenOrMaximized, MaximizeRequestPolicy},
     },
 };
 
@@ -84,6 +85,7 @@ impl XdgShellHandler for State {
                     renderer,
                     output.current_scale().fractional_scale().into(),
                     1.0,
+                    &self.pinnacle.gpu_accounting,
                 );
             });
         }
@@ -182,20 +184,24 @@ impl XdgShellHandler for State {
         let seat: Seat<Self> = Seat::from_resource(&seat).expect("couldn't get seat from WlSeat");
         let popup_kind = PopupKind::Xdg(surface);
 
-        let Some(root) = find_popup_root_surface(&popup_kind).ok().and_then(|root| {
-            self.pinnacle
-                .window_for_surface(&root)
-                .cloned()
-                .map(KeyboardFocusTarget::Window)
-                .or_else(|| {
-                    self.pinnacle.space.outputs().find_map(|op| {
-                        layer_map_for_output(op)
-                            .layer_for_surface(&root, WindowSurfaceType::TOPLEVEL)
-                            .cloned()
-                            .map(KeyboardFocusTarget::LayerSurface)
-                    })
+        let Ok(root_surface) = find_popup_root_surface(&popup_kind) else {
+            return;
+        };
+
+        let Some(root) = self
+            .pinnacle
+            .window_for_surface(&root_surface)
+            .cloned()
+            .map(KeyboardFocusTarget::Window)
+            .or_else(|| {
+                self.pinnacle.space.outputs().find_map(|op| {
+                    layer_map_for_output(op)
+                        .layer_for_surface(&root_surface, WindowSurfaceType::TOPLEVEL)
+                        .cloned()
+                        .map(KeyboardFocusTarget::LayerSurface)
                 })
-        }) else {
+            })
+        else {
             return;
         };
 
@@ -239,6 +245,8 @@ impl XdgShellHandler for State {
 
             keyboard.set_focus(self, grab.current_grab(), serial);
             keyboard.set_grab(self, PopupKeyboardGrab::new(&grab), serial);
+
+            self.popup_grab_started(root_surface, serial);
         }
     }
 
@@ -357,14 +365,15 @@ impl XdgShellHandler for State {
     fn maximize_request(&mut self, surface: ToplevelSurface) {
         let _span = tracy_client::span!("XdgShellHandler::maximize_request");
 
+        let default_policy = self.pinnacle.config.maximize_request_policy;
+
         if let Some(window) = self
             .pinnacle
             .window_for_surface(surface.wl_surface())
             .cloned()
         {
             window.with_state_mut(|state| state.need_configure = true);
-            self.pinnacle
-                .update_window_layout_mode(&window, |mode| mode.set_client_maximized(true));
+            self.pinnacle.handle_client_maximize(&window);
         } else if let Some(unmapped) = self
             .pinnacle
             .unmapped_window_for_surface_mut(surface.wl_surface())
@@ -384,8 +393,15 @@ impl XdgShellHandler for State {
                     ..
                 } => {
                     let window = unmapped.window.clone();
-                    window.with_state_mut(|state| state.layout_mode.set_client_maximized(true));
-                    *attempt_float_on_map = false;
+                    let policy = window.maximize_request_policy(default_policy);
+                    window.with_state_mut(|state| {
+                        policy.apply(&mut state.layout_mode);
+                        state.fullscreen_within_tile |=
+                            policy == MaximizeRequestPolicy::FullscreenWithinTile;
+                    });
+                    if policy != MaximizeRequestPolicy::Ignore {
+                        *attempt_float_on_map = false;
+                    }
                     self.pinnacle.configure_window_if_nontiled(&window);
                     window.toplevel().expect("in xdgshell").send_configure();
                 }
@@ -403,8 +419,7 @@ impl XdgShellHandler for State {
         {
             window.with_state_mut(|state| state.need_configure = true);
 
-            self.pinnacle
-                .update_window_layout_mode(&window, |mode| mode.set_client_maximized(false));
+            self.pinnacle.handle_client_unmaximize(&window);
         } else if let Some(unmapped) = self
             .pinnacle
             .unmapped_window_for_surface_mut(surface.wl_surface())
@@ -425,7 +440,10 @@ impl XdgShellHandler for State {
                 }
                 UnmappedState::PostInitialConfigure { .. } => {
                     let window = unmapped.window.clone();
-                    window.with_state_mut(|state| state.layout_mode.set_client_maximized(false));
+                    window.with_state_mut(|state| {
+                        state.layout_mode.set_client_maximized(false);
+                        state.fullscreen_within_tile = false;
+                    });
                     self.pinnacle.configure_window_if_nontiled(&window);
                     window.toplevel().expect("in xdgshell").send_configure();
                 }
@@ -433,11 +451,14 @@ impl XdgShellHandler for State {
         }
     }
 
-    fn minimize_request(&mut self, _surface: ToplevelSurface) {
-        // TODO:
-        // if let Some(window) = self.window_for_surface(surface.wl_surface()) {
-        //     self.space.unmap_elem(&window);
-        // }
+    fn minimize_request(&mut self, surface: ToplevelSurface) {
+        if let Some(window) = self
+            .pinnacle
+            .window_for_surface(surface.wl_surface())
+            .cloned()
+        {
+            crate::api::window::set_minimized(self, &window, true);
+        }
     }
 
     fn app_id_changed(&mut self, surface: ToplevelSurface) {
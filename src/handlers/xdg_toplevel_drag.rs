@@ -0,0 +1,67 @@
+use smithay::{
+    reexports::wayland_protocols::xdg::shell::server::xdg_toplevel::XdgToplevel,
+    utils::{Logical, Point},
+};
+
+use crate::{
+    delegate_xdg_toplevel_drag,
+    protocol::xdg_toplevel_drag::{XdgToplevelDragHandler, XdgToplevelDragState},
+    state::{Pinnacle, State, WithState},
+    window::WindowElement,
+};
+
+impl XdgToplevelDragHandler for State {
+    fn xdg_toplevel_drag_state(&mut self) -> &mut XdgToplevelDragState {
+        &mut self.pinnacle.xdg_toplevel_drag_state
+    }
+
+    fn toplevel_attached(&mut self, _toplevel: &XdgToplevel) {
+        let Some(pointer) = self.pinnacle.seat.get_pointer() else {
+            return;
+        };
+
+        self.update_toplevel_drag(pointer.current_location());
+    }
+}
+delegate_xdg_toplevel_drag!(State);
+
+impl Pinnacle {
+    /// Returns where `window` should be if it's attached to the toplevel drag in progress.
+    pub fn toplevel_drag_loc(
+        &self,
+        window: &WindowElement,
+        pointer_loc: Point<f64, Logical>,
+    ) -> Option<Point<i32, Logical>> {
+        let (toplevel, offset) = self.xdg_toplevel_drag_state.active_toplevel()?;
+
+        let is_attached = window
+            .toplevel()
+            .is_some_and(|surface| *surface.xdg_toplevel() == toplevel);
+
+        is_attached.then(|| pointer_loc.to_i32_round() - offset)
+    }
+}
+
+impl State {
+    /// Moves the window attached to the toplevel drag in progress under the pointer,
+    /// floating it if needed.
+    ///
+    /// Unmapped windows are placed once they map.
+    pub fn update_toplevel_drag(&mut self, pointer_loc: Point<f64, Logical>) {
+        let Some((window, loc)) = self.pinnacle.windows.iter().find_map(|win| {
+            self.pinnacle
+                .toplevel_drag_loc(win, pointer_loc)
+                .map(|loc| (win.clone(), loc))
+        }) else {
+            return;
+        };
+
+        if !window.with_state(|state| state.layout_mode.is_floating()) {
+            window.with_state_mut(|state| state.set_floating_loc(loc));
+            self.pinnacle
+                .update_window_layout_mode(&window, |mode| mode.set_floating(true));
+        }
+
+        self.pinnacle.move_floating_window(&window, loc);
+    }
+}
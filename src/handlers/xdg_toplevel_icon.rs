@@ -0,0 +1,52 @@
+use smithay::reexports::{
+    wayland_protocols::xdg::shell::server::xdg_toplevel::XdgToplevel,
+    wayland_server::protocol::wl_surface::WlSurface,
+};
+
+use crate::{
+    delegate_xdg_toplevel_icon,
+    protocol::xdg_toplevel_icon::{ToplevelIcon, XdgToplevelIconHandler},
+    state::{Pinnacle, State, WithState},
+};
+
+impl XdgToplevelIconHandler for State {
+    fn set_icon(&mut self, toplevel: &XdgToplevel, icon: Option<ToplevelIcon>) {
+        let window = self
+            .pinnacle
+            .windows
+            .iter()
+            .chain(
+                self.pinnacle
+                    .unmapped_windows
+                    .iter()
+                    .map(|unmapped| &unmapped.window),
+            )
+            .find(|win| {
+                win.toplevel()
+                    .is_some_and(|surface| surface.xdg_toplevel() == toplevel)
+            });
+
+        if let Some(window) = window {
+            window.with_state_mut(|state| state.pending_icon = Some(icon));
+        }
+    }
+}
+delegate_xdg_toplevel_icon!(State);
+
+impl Pinnacle {
+    /// Applies the icon change pending on the window with the root `surface`, if any.
+    pub fn apply_pending_icon(&self, surface: &WlSurface) {
+        let window = self.window_for_surface(surface).or_else(|| {
+            self.unmapped_window_for_surface(surface)
+                .map(|unmapped| &unmapped.window)
+        });
+
+        if let Some(window) = window {
+            window.with_state_mut(|state| {
+                if let Some(icon) = state.pending_icon.take() {
+                    state.icon = icon;
+                }
+            });
+        }
+    }
+}
@@ -38,8 +38,9 @@ use crate::{
     focus::keyboard::KeyboardFocusTarget,
     state::{Pinnacle, State, WithState},
     window::{
-        Unmapped, UnmappedState, WindowElement, rules::ClientRequests,
-        window_state::FullscreenOrMaximized,
+        Unmapped, UnmappedState, WindowElement,
+        rules::ClientRequests,
+        window_state::{FullscreenOrMaximized, MaximizeRequestPolicy},
     },
 };
 
@@ -106,7 +107,8 @@ impl XwmHandler for State {
         } else if let Some(output) = self.pinnacle.focused_output()
             && output.with_state(|state| !state.tags.is_empty())
         {
-            unmapped.window.set_tags_to_output(output);
+            self.pinnacle
+                .set_tags_for_new_window(&unmapped.window, output);
             self.pinnacle.request_window_rules(&mut unmapped);
         }
 
@@ -257,11 +259,10 @@ impl XwmHandler for State {
     fn maximize_request(&mut self, _xwm: XwmId, window: X11Surface) {
         trace!(class = window.class(), "XwmHandler::maximize_request");
 
+        let default_policy = self.pinnacle.config.maximize_request_policy;
+
         if let Some(window) = self.pinnacle.window_for_x11_surface(&window).cloned() {
-            self.pinnacle
-                .update_window_layout_mode(&window, |layout_mode| {
-                    layout_mode.set_client_maximized(true);
-                });
+            self.pinnacle.handle_client_maximize(&window);
         } else if let Some(unmapped) = self.pinnacle.unmapped_window_for_x11_surface_mut(&window) {
             match &mut unmapped.state {
                 UnmappedState::WaitingForTags { client_requests } => {
@@ -278,8 +279,15 @@ impl XwmHandler for State {
                     ..
                 } => {
                     let window = unmapped.window.clone();
-                    window.with_state_mut(|state| state.layout_mode.set_client_maximized(true));
-                    *attempt_float_on_map = false;
+                    let policy = window.maximize_request_policy(default_policy);
+                    window.with_state_mut(|state| {
+                        policy.apply(&mut state.layout_mode);
+                        state.fullscreen_within_tile |=
+                            policy == MaximizeRequestPolicy::FullscreenWithinTile;
+                    });
+                    if policy != MaximizeRequestPolicy::Ignore {
+                        *attempt_float_on_map = false;
+                    }
                     self.pinnacle.configure_window_if_nontiled(&window);
                 }
             }
@@ -290,10 +298,7 @@ impl XwmHandler for State {
         trace!(class = window.class(), "XwmHandler::unmaximize_request");
 
         if let Some(window) = self.pinnacle.window_for_x11_surface(&window).cloned() {
-            self.pinnacle
-                .update_window_layout_mode(&window, |layout_mode| {
-                    layout_mode.set_client_maximized(false);
-                });
+            self.pinnacle.handle_client_unmaximize(&window);
         } else if let Some(unmapped) = self.pinnacle.unmapped_window_for_x11_surface_mut(&window) {
             match &mut unmapped.state {
                 UnmappedState::WaitingForTags { client_requests } => {
@@ -311,7 +316,10 @@ impl XwmHandler for State {
                 }
                 UnmappedState::PostInitialConfigure { .. } => {
                     let window = unmapped.window.clone();
-                    window.with_state_mut(|state| state.layout_mode.set_client_maximized(false));
+                    window.with_state_mut(|state| {
+                        state.layout_mode.set_client_maximized(false);
+                        state.fullscreen_within_tile = false;
+                    });
                     self.pinnacle.configure_window_if_nontiled(&window);
                 }
             }
@@ -382,6 +390,22 @@ impl XwmHandler for State {
         }
     }
 
+    fn minimize_request(&mut self, _xwm: XwmId, window: X11Surface) {
+        trace!(class = window.class(), "XwmHandler::minimize_request");
+
+        if let Some(window) = self.pinnacle.window_for_x11_surface(&window).cloned() {
+            crate::api::window::set_minimized(self, &window, true);
+        }
+    }
+
+    fn unminimize_request(&mut self, _xwm: XwmId, window: X11Surface) {
+        trace!(class = window.class(), "XwmHandler::unminimize_request");
+
+        if let Some(window) = self.pinnacle.window_for_x11_surface(&window).cloned() {
+            crate::api::window::set_minimized(self, &window, false);
+        }
+    }
+
     fn resize_request(
         &mut self,
         _xwm: XwmId,
@@ -531,6 +555,18 @@ impl XwmHandler for State {
                     }
                 });
             }
+            WmWindowProperty::Hints => {
+                let urgent = window
+                    .x11_surface()
+                    .and_then(|surface| surface.hints())
+                    .is_some_and(|hints| hints.urgent);
+
+                if urgent {
+                    crate::api::tag::mark_urgent(&mut self.pinnacle, &window);
+                } else {
+                    crate::api::tag::clear_urgent(&mut self.pinnacle, &window);
+                }
+            }
             WmWindowProperty::Class => {
                 let app_id = window.class().unwrap_or_default();
                 window.with_state(|state| {
@@ -564,6 +600,7 @@ impl State {
                     renderer,
                     output.current_scale().fractional_scale().into(),
                     1.0,
+                    &self.pinnacle.gpu_accounting,
                 );
             });
         }
@@ -601,7 +638,7 @@ impl Pinnacle {
             .iter()
             .filter_map(|z| z.window())
             .filter(|win| !win.is_x11_override_redirect())
-            .partition::<Vec<_>, _>(|win| win.is_on_active_tag());
+            .partition::<Vec<_>, _>(|win| win.is_shown());
 
         let active_windows = active_windows.into_iter().flat_map(|win| win.x11_surface());
         let non_active_windows = non_active_windows
@@ -657,21 +694,6 @@ impl Pinnacle {
                             }
                         };
 
-                        let cursor = state
-                            .pinnacle
-                            .cursor_state
-                            .get_xcursor_images(CursorIcon::Default)
-                            .unwrap();
-                        let image = cursor
-                            .image(Duration::ZERO, state.pinnacle.cursor_state.cursor_size(1));
-                        if let Err(err) = wm.set_cursor(
-                            &image.pixels_rgba,
-                            Size::from((image.width as u16, image.height as u16)),
-                            Point::from((image.xhot as u16, image.yhot as u16)),
-                        ) {
-                            warn!("Failed to set xwayland default cursor: {err}");
-                        }
-
                         // SAFETY: All set_vars occur on the event loop thread
                         unsafe {
                             std::env::set_var("DISPLAY", format!(":{display_number}"));
@@ -769,9 +791,36 @@ impl Pinnacle {
 
         xwayland_state.current_scale = Some(new_scale);
 
+        self.update_xwayland_cursor();
         self.update_xwayland_stacking_order();
     }
 
+    /// Sets XWayland's default cursor from the current xcursor theme and size,
+    /// rendered at the current XWayland scale.
+    pub fn update_xwayland_cursor(&mut self) {
+        let Some(xwayland_state) = self.xwayland_state.as_mut() else {
+            return;
+        };
+
+        let scale = xwayland_state.current_scale.unwrap_or(1.0).ceil() as i32;
+
+        let Some(cursor) = self
+            .cursor_state
+            .get_xcursor_images(CursorIcon::Default, None)
+        else {
+            return;
+        };
+        let image = cursor.image(Duration::ZERO, self.cursor_state.cursor_size(scale));
+
+        if let Err(err) = xwayland_state.xwm.set_cursor(
+            &image.pixels_rgba,
+            Size::from((image.width as u16, image.height as u16)),
+            Point::from((image.xhot as u16, image.yhot as u16)),
+        ) {
+            warn!("Failed to set xwayland default cursor: {err}");
+        }
+    }
+
     fn window_for_x11_surface(&self, surface: &X11Surface) -> Option<&WindowElement> {
         self.windows
             .iter()
@@ -197,6 +197,8 @@ pub fn add_mapped_toplevel_pre_commit_hook(toplevel: &ToplevelSurface) -> HookId
                     span.record("serial", format!("{serial:?}"));
                 }
 
+                window.configure_committed(serial);
+
                 let mut already_txned_deco = false;
 
                 if window.with_state(|state| state.pending_transactions.is_empty()) {
@@ -303,6 +305,7 @@ pub fn add_mapped_toplevel_pre_commit_hook(toplevel: &ToplevelSurface) -> HookId
                         renderer,
                         output.current_scale().fractional_scale().into(),
                         1.0,
+                        &state.pinnacle.gpu_accounting,
                     );
                 });
             } else {
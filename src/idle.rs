@@ -0,0 +1,347 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Idle tracking and the screensaver.
+//!
+//! The screensaver starts once there has been no input for its timeout.
+//! Separately, the session becomes idle once there has been no input for the idle
+//! timeout, which notifies the config and can power off outputs.
+//! Idle inhibitors keep both from happening and hide a screensaver that is already
+//! showing; once the last inhibitor goes away, both timeouts start over.
+//! Any input stops the screensaver and resumes the session.
+
+use std::{
+    mem,
+    path::PathBuf,
+    time::{Duration, Instant},
+};
+
+use smithay::{
+    output::{Output, WeakOutput},
+    reexports::calloop::{
+        LoopHandle, RegistrationToken,
+        timer::{TimeoutAction, Timer},
+    },
+};
+
+use crate::{
+    api::signal::Signal,
+    render::screensaver::ActiveScreensaver,
+    state::{State, WithState},
+};
+
+/// What a screensaver shows.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ScreensaverSource {
+    /// A GLSL ES 1.00 fragment shader drawn over each output.
+    Shader(String),
+    /// PNG images shown one after another, each for `interval`.
+    Slideshow {
+        images: Vec<PathBuf>,
+        interval: Duration,
+    },
+}
+
+/// A screensaver shown on all outputs after a period without input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Screensaver {
+    /// How long there must be no input before the screensaver starts.
+    pub timeout: Duration,
+    pub source: ScreensaverSource,
+}
+
+/// What happens when there has been no input for a while.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IdleTimeout {
+    /// How long there must be no input before the session is idle.
+    pub timeout: Duration,
+    /// Whether to power off all outputs while the session is idle.
+    pub power_off_outputs: bool,
+}
+
+/// The screensaver and idle timeouts.
+///
+/// Input goes through [`State::notify_idle_activity`], which also drives the idle notifier.
+/// The notifier only keeps timers for clients' idle notifications, so the timeouts here
+/// are tracked separately but share its activity and inhibition.
+#[derive(Debug)]
+pub struct IdleState {
+    screensaver: Option<Screensaver>,
+    /// When the last input happened.
+    last_activity: Instant,
+    /// The timer that starts the screensaver once the session is idle.
+    timer: Option<RegistrationToken>,
+    /// The screensaver being shown, if any.
+    pub active_screensaver: Option<ActiveScreensaver>,
+    idle_timeout: Option<IdleTimeout>,
+    /// The timer that marks the session idle.
+    idle_timer: Option<RegistrationToken>,
+    is_idle: bool,
+    /// Outputs that were powered off when the session became idle.
+    powered_off_outputs: Vec<WeakOutput>,
+}
+
+impl Default for IdleState {
+    fn default() -> Self {
+        Self {
+            screensaver: None,
+            last_activity: Instant::now(),
+            timer: None,
+            active_screensaver: None,
+            idle_timeout: None,
+            idle_timer: None,
+            is_idle: false,
+            powered_off_outputs: Vec::new(),
+        }
+    }
+}
+
+impl IdleState {
+    /// Returns the configured screensaver.
+    pub fn screensaver(&self) -> Option<&Screensaver> {
+        self.screensaver.as_ref()
+    }
+
+    /// Restarts the screensaver and idle timeouts as if there was input just now.
+    pub fn reset_activity(&mut self) {
+        self.last_activity = Instant::now();
+    }
+
+    /// Returns the configured idle timeout.
+    pub fn idle_timeout(&self) -> Option<IdleTimeout> {
+        self.idle_timeout
+    }
+
+    /// Returns whether the session is idle.
+    pub fn is_idle(&self) -> bool {
+        self.is_idle
+    }
+
+    /// Returns whether the screensaver is showing something that changes every frame.
+    pub fn is_screensaver_animated(&self) -> bool {
+        self.active_screensaver
+            .as_ref()
+            .is_some_and(|active| active.is_animated())
+    }
+
+    /// Removes the screensaver and stops it if it's showing.
+    ///
+    /// Returns whether the screensaver was showing.
+    pub(crate) fn clear(&mut self, loop_handle: &LoopHandle<'static, State>) -> bool {
+        self.screensaver = None;
+
+        if let Some(token) = self.timer.take() {
+            loop_handle.remove(token);
+        }
+
+        match self.active_screensaver.take() {
+            Some(active) => {
+                active.stop(loop_handle);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+impl State {
+    /// Sets the screensaver, or disables it with `None`.
+    ///
+    /// This stops the current screensaver and restarts the idle timeout.
+    pub fn set_screensaver(&mut self, screensaver: Option<Screensaver>) {
+        let _span = tracy_client::span!("State::set_screensaver");
+
+        if self.pinnacle.idle_state.clear(&self.pinnacle.loop_handle) {
+            self.schedule_render_all();
+        }
+
+        let Some(screensaver) = screensaver else {
+            return;
+        };
+
+        let timeout = screensaver.timeout;
+        let idle_state = &mut self.pinnacle.idle_state;
+        idle_state.screensaver = Some(screensaver);
+        idle_state.last_activity = Instant::now();
+
+        let timer = self
+            .pinnacle
+            .loop_handle
+            .insert_source(Timer::from_duration(timeout), |_, _, state| {
+                state.on_idle_timeout()
+            })
+            .expect("failed to insert idle timer");
+
+        self.pinnacle.idle_state.timer = Some(timer);
+    }
+
+    /// Sets the idle timeout, or disables idle tracking with `None`.
+    ///
+    /// This resumes the session if it's idle and restarts the idle timeout.
+    pub fn set_idle_timeout(&mut self, idle_timeout: Option<IdleTimeout>) {
+        let _span = tracy_client::span!("State::set_idle_timeout");
+
+        self.resume_from_idle();
+
+        let idle_state = &mut self.pinnacle.idle_state;
+        idle_state.idle_timeout = idle_timeout;
+
+        if let Some(token) = idle_state.idle_timer.take() {
+            self.pinnacle.loop_handle.remove(token);
+        }
+
+        let Some(idle_timeout) = idle_timeout else {
+            return;
+        };
+
+        self.pinnacle.idle_state.last_activity = Instant::now();
+
+        let timer = self
+            .pinnacle
+            .loop_handle
+            .insert_source(Timer::from_duration(idle_timeout.timeout), |_, _, state| {
+                state.on_session_idle_timeout()
+            })
+            .expect("failed to insert idle timer");
+
+        self.pinnacle.idle_state.idle_timer = Some(timer);
+    }
+
+    /// Records input activity, notifying clients' idle notifications, stopping the screensaver
+    /// if it's showing, and resuming the session if it's idle.
+    pub fn notify_idle_activity(&mut self) {
+        self.pinnacle
+            .idle_notifier_state
+            .notify_activity(&self.pinnacle.seat);
+        self.pinnacle.idle_state.last_activity = Instant::now();
+        self.stop_screensaver();
+        self.resume_from_idle();
+    }
+
+    /// Marks the session idle, powering off outputs if configured to.
+    fn start_idle(&mut self) {
+        let _span = tracy_client::span!("State::start_idle");
+
+        let idle_state = &mut self.pinnacle.idle_state;
+        let Some(idle_timeout) = idle_state.idle_timeout else {
+            return;
+        };
+        if idle_state.is_idle {
+            return;
+        }
+        idle_state.is_idle = true;
+
+        if idle_timeout.power_off_outputs {
+            let outputs = self
+                .pinnacle
+                .outputs
+                .iter()
+                .filter(|output| output.with_state(|state| state.powered))
+                .cloned()
+                .collect::<Vec<_>>();
+
+            for output in outputs.iter() {
+                self.set_output_powered(output, false);
+            }
+
+            self.pinnacle.idle_state.powered_off_outputs =
+                outputs.iter().map(Output::downgrade).collect();
+        }
+
+        self.pinnacle.signal_state.idle_started.signal(());
+    }
+
+    /// Resumes the session if it's idle, powering back on the outputs
+    /// that were powered off.
+    fn resume_from_idle(&mut self) {
+        let idle_state = &mut self.pinnacle.idle_state;
+        if !mem::take(&mut idle_state.is_idle) {
+            return;
+        }
+
+        let _span = tracy_client::span!("State::resume_from_idle");
+
+        let outputs = mem::take(&mut idle_state.powered_off_outputs);
+        for output in outputs.iter().filter_map(WeakOutput::upgrade) {
+            self.set_output_powered(&output, true);
+            self.schedule_render(&output);
+        }
+
+        self.pinnacle.signal_state.idle_resumed.signal(());
+    }
+
+    /// Shows the screensaver on all outputs.
+    ///
+    /// Does nothing if there is no screensaver or it's already showing.
+    pub fn start_screensaver(&mut self) {
+        let _span = tracy_client::span!("State::start_screensaver");
+
+        let idle_state = &self.pinnacle.idle_state;
+        if idle_state.active_screensaver.is_some() {
+            return;
+        }
+
+        let Some(screensaver) = idle_state.screensaver.clone() else {
+            return;
+        };
+
+        let active = ActiveScreensaver::new(screensaver.source, &self.pinnacle.loop_handle);
+        self.pinnacle.idle_state.active_screensaver = Some(active);
+
+        self.schedule_render_all();
+    }
+
+    /// Stops the screensaver if it's showing.
+    pub fn stop_screensaver(&mut self) {
+        let Some(active) = self.pinnacle.idle_state.active_screensaver.take() else {
+            return;
+        };
+
+        active.stop(&self.pinnacle.loop_handle);
+
+        self.schedule_render_all();
+    }
+
+    fn on_idle_timeout(&mut self) -> TimeoutAction {
+        let idle_state = &self.pinnacle.idle_state;
+
+        let Some(timeout) = idle_state.screensaver.as_ref().map(|s| s.timeout) else {
+            self.pinnacle.idle_state.timer = None;
+            return TimeoutAction::Drop;
+        };
+
+        // Input doesn't touch the timer, so check whether it happened since the timer started
+        let idle_for = idle_state.last_activity.elapsed();
+        if idle_for < timeout {
+            return TimeoutAction::ToDuration(timeout - idle_for);
+        }
+
+        if !self.pinnacle.idle_notifier_state.is_inhibited() {
+            self.start_screensaver();
+        }
+
+        TimeoutAction::ToDuration(timeout)
+    }
+
+    fn on_session_idle_timeout(&mut self) -> TimeoutAction {
+        let idle_state = &self.pinnacle.idle_state;
+
+        let Some(timeout) = idle_state
+            .idle_timeout
+            .map(|idle_timeout| idle_timeout.timeout)
+        else {
+            self.pinnacle.idle_state.idle_timer = None;
+            return TimeoutAction::Drop;
+        };
+
+        let idle_for = idle_state.last_activity.elapsed();
+        if idle_for < timeout {
+            return TimeoutAction::ToDuration(timeout - idle_for);
+        }
+
+        if !self.pinnacle.idle_notifier_state.is_inhibited() {
+            self.start_idle();
+        }
+
+        TimeoutAction::ToDuration(timeout)
+    }
+}
@@ -1,18 +1,30 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
 
+pub mod accessibility;
 pub mod bind;
+pub mod edge_action;
+pub mod hold_to_move;
 pub mod libinput;
+pub mod remap;
+pub mod trace;
 
 use std::{any::Any, time::Duration};
 
 use crate::{
     api::signal::Signal as _,
-    focus::pointer::{PointerContents, PointerFocusTarget},
+    focus::{
+        FocusReason,
+        pointer::{PointerContents, PointerFocusTarget, ScreenEdge},
+    },
     state::{Pinnacle, WithState},
     window::WindowElement,
 };
+use accessibility::AccessibilityState;
 use bind::BindState;
+use edge_action::EdgeActionState;
+use hold_to_move::{HoldToMoveConfig, HoldToMoveState};
 use libinput::LibinputState;
+use remap::RemapState;
 use smithay::{
     backend::{
         input::{
@@ -27,7 +39,7 @@ use smithay::{
     },
     desktop::{WindowSurfaceType, layer_map_for_output, space::SpaceElement},
     input::{
-        keyboard::{FilterResult, keysyms},
+        keyboard::{FilterResult, Keycode, keysyms},
         pointer::{
             AxisFrame, ButtonEvent, GestureHoldBeginEvent, GestureHoldEndEvent,
             GesturePinchBeginEvent, GesturePinchEndEvent, GesturePinchUpdateEvent,
@@ -54,14 +66,36 @@ use crate::state::State;
 pub struct InputState {
     pub bind_state: BindState,
     pub libinput_state: LibinputState,
+    pub accessibility: AccessibilityState,
+    pub remap_state: RemapState,
+    pub hold_to_move: HoldToMoveState,
+    pub pointer_crossing: PointerCrossing,
+    pub edge_actions: EdgeActionState,
 }
 
 impl InputState {
     pub fn clear(&mut self) {
         self.bind_state.clear();
+        self.remap_state.clear(None);
+        self.hold_to_move.config = HoldToMoveConfig::default();
+        self.pointer_crossing = PointerCrossing::default();
+        self.edge_actions.clear();
     }
 }
 
+/// Where the pointer ends up when it crosses from one output to another.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PointerCrossing {
+    /// The pointer keeps its position in the global space.
+    #[default]
+    Logical,
+    /// The pointer keeps its relative position along the edge it crossed.
+    ///
+    /// This lines the pointer up between outputs that are physically the same size
+    /// but have different logical sizes, like a 1x output next to a 1.5x one.
+    Physical,
+}
+
 impl InputState {
     pub fn new() -> Self {
         Default::default()
@@ -117,6 +151,7 @@ impl Pinnacle {
             return PointerContents {
                 focus_under: focus,
                 output_under: Some(output.downgrade()),
+                edge: None,
             };
         }
 
@@ -164,31 +199,34 @@ impl Pinnacle {
         let window_under = |windows: &[&WindowElement],
                             surface_type: WindowSurfaceType|
          -> Option<(PointerFocusTarget, Point<f64, Logical>)> {
-            windows.iter().find_map(|win| {
-                let loc = self
-                    .space
-                    .element_location(win)
-                    .expect("called elem loc on unmapped win")
-                    - win.geometry().loc;
-
-                let loc = loc.to_f64();
-
-                win.surface_under(point - loc, surface_type)
-                    .map(|(surf, surf_loc)| {
-                        let target = if let Some(x11_surface) = win.x11_surface()
-                            && Some(&surf) == x11_surface.wl_surface().as_ref()
-                        {
-                            // Our `surface_under` impl currently doesn't differentiate between
-                            // wl surfaces from wayland or xwayland, which is a problem for XDND
-                            // which requires knowledge that the pointer focus is an X11Surface,
-                            // so recover that information here.
-                            PointerFocusTarget::X11Surface(x11_surface.clone())
-                        } else {
-                            PointerFocusTarget::WlSurface(surf)
-                        };
-                        (target, surf_loc.to_f64() + loc)
-                    })
-            })
+            windows
+                .iter()
+                .filter(|win| win.accepts_input())
+                .find_map(|win| {
+                    let loc = self
+                        .space
+                        .element_location(win)
+                        .expect("called elem loc on unmapped win")
+                        - win.geometry().loc;
+
+                    let loc = loc.to_f64();
+
+                    win.surface_under(point - loc, surface_type)
+                        .map(|(surf, surf_loc)| {
+                            let target = if let Some(x11_surface) = win.x11_surface()
+                                && Some(&surf) == x11_surface.wl_surface().as_ref()
+                            {
+                                // Our `surface_under` impl currently doesn't differentiate between
+                                // wl surfaces from wayland or xwayland, which is a problem for XDND
+                                // which requires knowledge that the pointer focus is an X11Surface,
+                                // so recover that information here.
+                                PointerFocusTarget::X11Surface(x11_surface.clone())
+                            } else {
+                                PointerFocusTarget::WlSurface(surf)
+                            };
+                            (target, surf_loc.to_f64() + loc)
+                        })
+                })
         };
 
         // Input and rendering go, from top to bottom,
@@ -220,29 +258,48 @@ impl Pinnacle {
                 &windows[..fullscreen_and_up_split_at],
                 WindowSurfaceType::TOPLEVEL | WindowSurfaceType::SUBSURFACE,
             )
-        })
-        .or_else(|| {
-            layer_under(
-                &[wlr_layer::Layer::Top],
-                WindowSurfaceType::TOPLEVEL | WindowSurfaceType::SUBSURFACE,
-            )
-        })
-        .or_else(|| {
-            window_under(
-                &windows[fullscreen_and_up_split_at..],
-                WindowSurfaceType::TOPLEVEL | WindowSurfaceType::SUBSURFACE,
-            )
-        })
-        .or_else(|| {
-            layer_under(
-                &[wlr_layer::Layer::Bottom, wlr_layer::Layer::Background],
-                WindowSurfaceType::TOPLEVEL | WindowSurfaceType::SUBSURFACE,
-            )
         });
 
+        // Letterbox bars cover everything under the bottom-most fullscreen window
+        let covered_by_letterbox = fullscreen_and_up_split_at
+            .checked_sub(1)
+            .is_some_and(|i| windows[i].with_state(|state| state.letterboxed));
+
+        let focus_under = if covered_by_letterbox {
+            focus_under
+        } else {
+            focus_under
+                .or_else(|| {
+                    layer_under(
+                        &[wlr_layer::Layer::Top],
+                        WindowSurfaceType::TOPLEVEL | WindowSurfaceType::SUBSURFACE,
+                    )
+                })
+                .or_else(|| {
+                    window_under(
+                        &windows[fullscreen_and_up_split_at..],
+                        WindowSurfaceType::TOPLEVEL | WindowSurfaceType::SUBSURFACE,
+                    )
+                })
+                .or_else(|| {
+                    layer_under(
+                        &[wlr_layer::Layer::Bottom, wlr_layer::Layer::Background],
+                        WindowSurfaceType::TOPLEVEL | WindowSurfaceType::SUBSURFACE,
+                    )
+                })
+        };
+
+        let other_output_geos = self
+            .space
+            .outputs()
+            .filter(|op| *op != output)
+            .flat_map(|op| self.space.output_geometry(op))
+            .collect::<Vec<_>>();
+
         PointerContents {
             focus_under,
             output_under: Some(output.downgrade()),
+            edge: screen_edge_at(point, output_geo, &other_output_geos),
         }
     }
 
@@ -283,9 +340,18 @@ impl State {
     {
         let _span = tracy_client::span!("State::process_input_event");
 
-        self.pinnacle
-            .idle_notifier_state
-            .notify_activity(&self.pinnacle.seat);
+        self.notify_idle_activity();
+
+        let now = self.pinnacle.clock.now().into();
+        self.pinnacle.metrics.input_received(now);
+
+        let traced_event = self
+            .pinnacle
+            .config
+            .debug
+            .trace_input
+            .then(|| trace::event_name(&event))
+            .flatten();
 
         match event {
             InputEvent::DeviceAdded { device } => self.on_device_added(device),
@@ -322,6 +388,10 @@ impl State {
             // TODO: rest of input events
             _ => (),
         }
+
+        if let Some(event) = traced_event {
+            self.trace_input_event(event);
+        }
     }
 
     /// Update the pointer focus if it's different from the previous one.
@@ -427,13 +497,28 @@ impl State {
     fn on_keyboard<I: InputBackend>(&mut self, event: I::KeyboardKeyEvent) {
         let _span = tracy_client::span!("State::on_keyboard");
 
+        let device_sysname = event
+            .device()
+            .syspath()
+            .and_then(|path| Some(path.file_name()?.to_string_lossy().into_owned()));
+
+        self.on_remappable_key(
+            event.key_code(),
+            event.state(),
+            event.time_msec(),
+            device_sysname.as_deref(),
+        );
+    }
+
+    /// Sends a key event to the keyboard after accessibility features have processed it.
+    fn on_key(&mut self, keycode: Keycode, press_state: KeyState, time: u32) {
+        let _span = tracy_client::span!("State::on_key");
+
         let Some(keyboard) = self.pinnacle.seat.get_keyboard() else {
             return;
         };
 
         let serial = SERIAL_COUNTER.next_serial();
-        let time = event.time_msec();
-        let press_state = event.state();
 
         let shortcuts_inhibited = keyboard
             .current_focus()
@@ -446,13 +531,23 @@ impl State {
             })
             .is_some_and(|inhibitor| inhibitor.is_active());
 
+        let mut bind_triggered = false;
+
         let action = keyboard.input(
             self,
-            event.key_code(),
+            keycode,
             press_state,
             serial,
             time,
             |state, modifiers, keysym| {
+                if press_state == KeyState::Pressed {
+                    state
+                        .pinnacle
+                        .input_state
+                        .accessibility
+                        .key_pressed(keycode, keysym.modified_sym().is_modifier_key());
+                }
+
                 if press_state == KeyState::Pressed
                     && let mut vt @ keysyms::KEY_XF86Switch_VT_1..=keysyms::KEY_XF86Switch_VT_12 =
                         keysym.modified_sym().raw()
@@ -461,6 +556,14 @@ impl State {
                     return FilterResult::Intercept(KeyAction::SwitchVt(vt as i32));
                 }
 
+                if press_state == KeyState::Pressed
+                    && state.pinnacle.region_select.is_some()
+                    && keysym.modified_sym().raw() == keysyms::KEY_Escape
+                {
+                    state.cancel_region_select();
+                    return FilterResult::Intercept(KeyAction::Suppress);
+                }
+
                 let Some(raw_sym) = keysym.raw_latin_sym_or_raw_current_sym() else {
                     return FilterResult::Forward;
                 };
@@ -479,6 +582,23 @@ impl State {
                     !state.pinnacle.lock_state.is_unlocked(),
                 );
 
+                bind_triggered =
+                    press_state == KeyState::Pressed && bind_action != bind::BindAction::Forward;
+
+                // Config binds take priority over shortcuts bound by apps
+                if bind_action == bind::BindAction::Forward
+                    && state.pinnacle.global_shortcuts_state.key(
+                        raw_sym,
+                        *modifiers,
+                        edge,
+                        time,
+                        shortcuts_inhibited,
+                        !state.pinnacle.lock_state.is_unlocked(),
+                    )
+                {
+                    return FilterResult::Intercept(KeyAction::Suppress);
+                }
+
                 match bind_action {
                     bind::BindAction::Forward => FilterResult::Forward,
                     bind::BindAction::Suppress => FilterResult::Intercept(KeyAction::Suppress),
@@ -490,6 +610,10 @@ impl State {
             },
         );
 
+        if bind_triggered {
+            self.popup_grab_blocked_keybind();
+        }
+
         if let Some(action) = action {
             match action {
                 KeyAction::Quit => {
@@ -569,6 +693,14 @@ impl State {
             }
         }
 
+        match button_state {
+            ButtonState::Pressed if !pointer.is_grabbed() => {
+                self.hold_to_move_button_pressed(button, pointer_loc);
+            }
+            ButtonState::Released => self.hold_to_move_button_released(button),
+            _ => (),
+        }
+
         if button_state == ButtonState::Pressed {
             let output_under = self
                 .pinnacle
@@ -588,7 +720,9 @@ impl State {
                         self.schedule_render(&output);
                     }
                     if !window.is_x11_override_redirect() {
-                        self.pinnacle.keyboard_focus_stack.set_focus(window.clone());
+                        self.pinnacle
+                            .keyboard_focus_stack
+                            .set_focus(window.clone(), FocusReason::Pointer);
                     }
                     self.pinnacle.on_demand_layer_focus = None;
                 } else if let Some(layer) = focus.layer_for(&self.pinnacle) {
@@ -596,6 +730,7 @@ impl State {
                         self.pinnacle.on_demand_layer_focus = Some(layer);
                     } else if let wlr_layer::Layer::Bottom | wlr_layer::Layer::Background =
                         layer.layer()
+                        && focus.popup_for(&self.pinnacle).is_none()
                     {
                         // Only unset focus when clicking on background stuff
                         self.pinnacle.keyboard_focus_stack.unset_focus();
@@ -698,6 +833,8 @@ impl State {
         let pointer_loc = event.position_transformed(output_geo.size) + output_geo.loc.to_f64();
         let serial = SERIAL_COUNTER.next_serial();
 
+        self.hold_to_move_pointer_motion(pointer_loc);
+
         let new_contents = self.pinnacle.pointer_contents_under(pointer_loc);
 
         if let Some(new_output) = new_contents
@@ -795,23 +932,19 @@ impl State {
             }
         }
 
-        let mut new_pointer_loc = pointer_loc + event.delta();
-
-        if self
+        let output_geos = self
             .pinnacle
             .space
-            .output_under(new_pointer_loc.to_i32_round::<i32>().to_f64())
-            .next()
-            .is_none()
-        {
-            // Place the pointer inside the nearest output if it would be outside one
-            let output_locs = self
-                .pinnacle
-                .space
-                .outputs()
-                .flat_map(|op| self.pinnacle.space.output_geometry(op));
-            new_pointer_loc = constrain_point_inside_rects(new_pointer_loc, output_locs);
-        }
+            .outputs()
+            .flat_map(|op| self.pinnacle.space.output_geometry(op))
+            .collect::<Vec<_>>();
+
+        let mut new_pointer_loc = constrain_pointer_motion(
+            self.pinnacle.input_state.pointer_crossing,
+            pointer_loc,
+            pointer_loc + event.delta(),
+            &output_geos,
+        );
 
         if let Some((focus, surf_loc, region)) = &pointer_confined_to {
             let region = region
@@ -862,6 +995,8 @@ impl State {
             new_pointer_loc = constrain_point_inside_rects(new_pointer_loc, region_rects);
         }
 
+        self.hold_to_move_pointer_motion(new_pointer_loc);
+
         let mut new_contents = self.pinnacle.pointer_contents_under(new_pointer_loc);
 
         self.pinnacle
@@ -1040,6 +1175,8 @@ impl State {
             return;
         };
 
+        self.hold_to_move_touch_down(event.slot(), touch_loc);
+
         let focus = self.pinnacle.pointer_contents_under(touch_loc);
 
         touch.down(
@@ -1066,6 +1203,10 @@ impl State {
             return;
         };
 
+        if self.hold_to_move_touch_motion(event.slot(), touch_loc) {
+            return;
+        }
+
         let focus = self.pinnacle.pointer_contents_under(touch_loc);
 
         touch.motion(
@@ -1084,6 +1225,10 @@ impl State {
             return;
         };
 
+        if self.hold_to_move_touch_up(event.slot()) {
+            return;
+        }
+
         touch.up(
             self,
             &touch::UpEvent {
@@ -1107,6 +1252,8 @@ impl State {
             return;
         };
 
+        self.hold_to_move_cancel();
+
         touch.cancel(self);
     }
 
@@ -1326,6 +1473,132 @@ fn constrain_point_inside_rects(
         .unwrap_or(pos)
 }
 
+/// Moves the pointer from `old_pos` to `new_pos`, keeping it inside the output `rects`.
+///
+/// If `new_pos` is outside every output, the pointer slides along the edge of the output
+/// it was on instead of jumping to the nearest point on another one.
+fn constrain_pointer_motion(
+    crossing: PointerCrossing,
+    old_pos: Point<f64, Logical>,
+    new_pos: Point<f64, Logical>,
+    rects: &[Rectangle<i32, Logical>],
+) -> Point<f64, Logical> {
+    let _span = tracy_client::span!("constrain_pointer_motion");
+
+    let is_inside = |rect: &Rectangle<i32, Logical>, pos: Point<f64, Logical>| {
+        rect.contains(pos.to_i32_round())
+    };
+    let is_inside_any = |pos| rects.iter().any(|rect| is_inside(rect, pos));
+
+    let Some(from) = rects.iter().find(|rect| is_inside(rect, old_pos)) else {
+        return constrain_point_inside_rects(new_pos, rects.iter().copied());
+    };
+
+    if is_inside(from, new_pos) {
+        return new_pos;
+    }
+
+    if crossing == PointerCrossing::Physical
+        && let Some(pos) = cross_edge_physically(new_pos, *from, rects)
+    {
+        return pos;
+    }
+
+    if is_inside_any(new_pos) {
+        return new_pos;
+    }
+
+    let clamped = constrain_point_inside_rects(new_pos, [*from]);
+
+    let slide_horizontally = Point::from((new_pos.x, clamped.y));
+    if is_inside_any(slide_horizontally) {
+        return slide_horizontally;
+    }
+
+    let slide_vertically = Point::from((clamped.x, new_pos.y));
+    if is_inside_any(slide_vertically) {
+        return slide_vertically;
+    }
+
+    clamped
+}
+
+/// Moves `pos`, which just left `from`, onto the output sharing the edge it crossed.
+///
+/// The pointer keeps its relative position along that edge. Returns `None` if no output
+/// shares the crossed edge.
+fn cross_edge_physically(
+    pos: Point<f64, Logical>,
+    from: Rectangle<i32, Logical>,
+    rects: &[Rectangle<i32, Logical>],
+) -> Option<Point<f64, Logical>> {
+    let from_geo = from.to_f64();
+    let (left, top) = (from_geo.loc.x, from_geo.loc.y);
+    let (right, bottom) = (left + from_geo.size.w, top + from_geo.size.h);
+
+    rects.iter().filter(|rect| **rect != from).find_map(|to| {
+        let to_geo = to.to_f64();
+        let (to_left, to_top) = (to_geo.loc.x, to_geo.loc.y);
+        let (to_right, to_bottom) = (to_left + to_geo.size.w, to_top + to_geo.size.h);
+
+        let shares_vertical_edge = to_top < bottom
+            && to_bottom > top
+            && ((pos.x >= right && to_left == right) || (pos.x < left && to_right == left));
+        let shares_horizontal_edge = to_left < right
+            && to_right > left
+            && ((pos.y >= bottom && to_top == bottom) || (pos.y < top && to_bottom == top));
+
+        let crossed = if shares_vertical_edge {
+            let y = to_top + (pos.y - top) / from_geo.size.h * to_geo.size.h;
+            Point::from((pos.x, y))
+        } else if shares_horizontal_edge {
+            let x = to_left + (pos.x - left) / from_geo.size.w * to_geo.size.w;
+            Point::from((x, pos.y))
+        } else {
+            return None;
+        };
+
+        Some(constrain_point_inside_rects(crossed, [*to]))
+    })
+}
+
+/// Returns the edge or corner of `output_geo` that `pos` is touching.
+///
+/// Sides that border one of `other_rects` are ignored, as the pointer
+/// moves onto the neighboring output instead of stopping there.
+fn screen_edge_at(
+    pos: Point<f64, Logical>,
+    output_geo: Rectangle<i32, Logical>,
+    other_rects: &[Rectangle<i32, Logical>],
+) -> Option<ScreenEdge> {
+    let geo = output_geo.to_f64();
+    let (left_x, top_y) = (geo.loc.x, geo.loc.y);
+    let (right_x, bottom_y) = (geo.loc.x + geo.size.w, geo.loc.y + geo.size.h);
+
+    let is_outer = |x: f64, y: f64| {
+        !other_rects
+            .iter()
+            .any(|rect| rect.to_f64().contains((x, y)))
+    };
+
+    let top = pos.y < top_y + 1.0 && is_outer(pos.x, top_y - 1.0);
+    let bottom = pos.y >= bottom_y - 1.0 && is_outer(pos.x, bottom_y);
+    let left = pos.x < left_x + 1.0 && is_outer(left_x - 1.0, pos.y);
+    let right = pos.x >= right_x - 1.0 && is_outer(right_x, pos.y);
+
+    match (top, bottom, left, right) {
+        (true, _, true, _) => Some(ScreenEdge::TopLeft),
+        (true, _, _, true) => Some(ScreenEdge::TopRight),
+        (_, true, true, _) => Some(ScreenEdge::BottomLeft),
+        (_, true, _, true) => Some(ScreenEdge::BottomRight),
+        (true, _, _, _) => Some(ScreenEdge::Top),
+        (_, true, _, _) => Some(ScreenEdge::Bottom),
+        (_, _, true, _) => Some(ScreenEdge::Left),
+        (_, _, _, true) => Some(ScreenEdge::Right),
+        _ => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1403,4 +1676,112 @@ mod tests {
             "closer to second rect failed"
         );
     }
+
+    #[test]
+    fn constrain_pointer_motion_slides_along_edge() {
+        // A 1x output next to a shorter 2x one
+        let rects = [rect((0, 0), (1920, 1080)), rect((1920, 0), (1280, 720))];
+        assert_eq!(
+            constrain_pointer_motion(
+                PointerCrossing::Logical,
+                (1915.0, 900.0).into(),
+                (1925.0, 905.0).into(),
+                &rects,
+            ),
+            (1919.0, 905.0).into(),
+            "moving past a shorter output failed"
+        );
+        assert_eq!(
+            constrain_pointer_motion(
+                PointerCrossing::Logical,
+                (1915.0, 1075.0).into(),
+                (1925.0, 1085.0).into(),
+                &rects,
+            ),
+            (1919.0, 1079.0).into(),
+            "moving into the corner failed"
+        );
+        assert_eq!(
+            constrain_pointer_motion(
+                PointerCrossing::Logical,
+                (1915.0, 500.0).into(),
+                (1925.0, 500.0).into(),
+                &rects,
+            ),
+            (1925.0, 500.0).into(),
+            "crossing failed"
+        );
+    }
+
+    #[test]
+    fn constrain_pointer_motion_physical_crossing() {
+        let rects = [rect((0, 0), (1920, 1080)), rect((1920, 0), (1280, 720))];
+        assert_eq!(
+            constrain_pointer_motion(
+                PointerCrossing::Physical,
+                (1915.0, 540.0).into(),
+                (1925.0, 540.0).into(),
+                &rects,
+            ),
+            (1925.0, 360.0).into(),
+            "crossing right failed"
+        );
+        assert_eq!(
+            constrain_pointer_motion(
+                PointerCrossing::Physical,
+                (1925.0, 360.0).into(),
+                (1915.0, 360.0).into(),
+                &rects,
+            ),
+            (1915.0, 540.0).into(),
+            "crossing left failed"
+        );
+        assert_eq!(
+            constrain_pointer_motion(
+                PointerCrossing::Physical,
+                (1000.0, 540.0).into(),
+                (1010.0, 540.0).into(),
+                &rects,
+            ),
+            (1010.0, 540.0).into(),
+            "moving within an output failed"
+        );
+    }
+
+    #[test]
+    fn screen_edge_at_single_output() {
+        let geo = rect((0, 0), (1920, 1080));
+        assert_eq!(screen_edge_at((500.0, 500.0).into(), geo, &[]), None);
+        assert_eq!(
+            screen_edge_at((500.0, 0.0).into(), geo, &[]),
+            Some(ScreenEdge::Top)
+        );
+        assert_eq!(
+            screen_edge_at((500.0, 1079.0).into(), geo, &[]),
+            Some(ScreenEdge::Bottom)
+        );
+        assert_eq!(
+            screen_edge_at((0.0, 0.0).into(), geo, &[]),
+            Some(ScreenEdge::TopLeft)
+        );
+        assert_eq!(
+            screen_edge_at((1919.0, 1079.0).into(), geo, &[]),
+            Some(ScreenEdge::BottomRight)
+        );
+    }
+
+    #[test]
+    fn screen_edge_at_ignores_shared_sides() {
+        let geo = rect((0, 0), (1920, 1080));
+        let others = [rect((1920, 0), (1920, 1080))];
+        assert_eq!(screen_edge_at((1919.0, 500.0).into(), geo, &others), None);
+        assert_eq!(
+            screen_edge_at((1919.0, 0.0).into(), geo, &others),
+            Some(ScreenEdge::Top)
+        );
+        assert_eq!(
+            screen_edge_at((0.0, 500.0).into(), geo, &others),
+            Some(ScreenEdge::Left)
+        );
+    }
 }
@@ -0,0 +1,217 @@
+//! Keyboard accessibility features.
+//!
+//! These are handled in the compositor so they work regardless of client support.
+
+use std::{
+    collections::{HashMap, HashSet},
+    time::Duration,
+};
+
+use smithay::{
+    backend::input::KeyState,
+    input::keyboard::{FilterResult, Keycode},
+    reexports::calloop::{
+        RegistrationToken,
+        timer::{TimeoutAction, Timer},
+    },
+    utils::SERIAL_COUNTER,
+};
+
+use crate::{api::signal::Signal as _, state::State};
+
+#[derive(Debug, Default)]
+pub struct AccessibilityState {
+    /// Whether modifiers stay pressed until the next non-modifier key is released.
+    pub sticky_keys: bool,
+    /// How long keys must be held before they are accepted, if enabled.
+    pub slow_keys: Option<Duration>,
+
+    /// Modifiers that are physically held, along with whether another key was pressed
+    /// while they were.
+    held_modifiers: Vec<(Keycode, bool)>,
+    /// Modifiers whose release is being held back until the next key is released.
+    latched_modifiers: Vec<Keycode>,
+    /// Keys that are pressed but haven't been held long enough yet.
+    slow_keys_pending: HashMap<Keycode, RegistrationToken>,
+    /// Keys that were held long enough and whose release should be forwarded.
+    slow_keys_accepted: HashSet<Keycode>,
+}
+
+impl AccessibilityState {
+    /// Notes that a key was pressed, for sticky keys.
+    pub fn key_pressed(&mut self, keycode: Keycode, is_modifier: bool) {
+        if !self.sticky_keys {
+            return;
+        }
+
+        for (_, used) in self.held_modifiers.iter_mut() {
+            *used = true;
+        }
+
+        if is_modifier && !self.held_modifiers.iter().any(|(code, _)| *code == keycode) {
+            self.held_modifiers.push((keycode, false));
+        }
+    }
+}
+
+impl State {
+    /// Runs a key event through slow keys, then passes it on if it was accepted.
+    pub fn on_accessible_key(&mut self, keycode: Keycode, press_state: KeyState, time: u32) {
+        let accessibility = &mut self.pinnacle.input_state.accessibility;
+
+        if let Some(delay) = accessibility.slow_keys {
+            match press_state {
+                KeyState::Pressed => {
+                    if accessibility.slow_keys_pending.contains_key(&keycode) {
+                        return;
+                    }
+
+                    let token = self
+                        .pinnacle
+                        .loop_handle
+                        .insert_source(Timer::from_duration(delay), move |_, _, state| {
+                            let accessibility = &mut state.pinnacle.input_state.accessibility;
+                            accessibility.slow_keys_pending.remove(&keycode);
+                            accessibility.slow_keys_accepted.insert(keycode);
+
+                            let time =
+                                Duration::from(state.pinnacle.clock.now()).as_millis() as u32;
+                            state.on_sticky_key(keycode, KeyState::Pressed, time);
+
+                            TimeoutAction::Drop
+                        })
+                        .expect("failed to insert slow keys timer");
+
+                    self.pinnacle
+                        .input_state
+                        .accessibility
+                        .slow_keys_pending
+                        .insert(keycode, token);
+                    return;
+                }
+                KeyState::Released => {
+                    if let Some(token) = accessibility.slow_keys_pending.remove(&keycode) {
+                        // Released too early, drop the key entirely
+                        self.pinnacle.loop_handle.remove(token);
+                        return;
+                    }
+                    accessibility.slow_keys_accepted.remove(&keycode);
+                }
+            }
+        }
+
+        self.on_sticky_key(keycode, press_state, time);
+    }
+
+    /// Runs a key event through sticky keys, then passes it on to the keyboard.
+    fn on_sticky_key(&mut self, keycode: Keycode, press_state: KeyState, time: u32) {
+        let accessibility = &mut self.pinnacle.input_state.accessibility;
+
+        if !accessibility.sticky_keys {
+            self.on_key(keycode, press_state, time);
+            return;
+        }
+
+        match press_state {
+            KeyState::Pressed => {
+                // Pressing a latched modifier again unlatches it on release
+                if let Some(idx) = accessibility
+                    .latched_modifiers
+                    .iter()
+                    .position(|code| *code == keycode)
+                {
+                    accessibility.latched_modifiers.remove(idx);
+                    accessibility.held_modifiers.push((keycode, true));
+                    return;
+                }
+
+                self.on_key(keycode, press_state, time);
+            }
+            KeyState::Released => {
+                let held_modifier = accessibility
+                    .held_modifiers
+                    .iter()
+                    .position(|(code, _)| *code == keycode)
+                    .map(|idx| accessibility.held_modifiers.remove(idx));
+
+                match held_modifier {
+                    Some((keycode, false)) => {
+                        // A lone modifier tap; keep it pressed for the next key
+                        accessibility.latched_modifiers.push(keycode);
+                    }
+                    Some(_) => self.on_key(keycode, press_state, time),
+                    None => {
+                        self.on_key(keycode, press_state, time);
+                        self.release_latched_modifiers(time);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Releases all modifiers latched by sticky keys.
+    pub fn release_latched_modifiers(&mut self, time: u32) {
+        let latched =
+            std::mem::take(&mut self.pinnacle.input_state.accessibility.latched_modifiers);
+
+        if latched.is_empty() {
+            return;
+        }
+
+        let Some(keyboard) = self.pinnacle.seat.get_keyboard() else {
+            return;
+        };
+
+        for keycode in latched {
+            keyboard.input(
+                self,
+                keycode,
+                KeyState::Released,
+                SERIAL_COUNTER.next_serial(),
+                time,
+                |_, _, _| FilterResult::<()>::Forward,
+            );
+        }
+    }
+
+    /// Enables or disables sticky keys.
+    pub fn set_sticky_keys(&mut self, enabled: bool) {
+        let accessibility = &mut self.pinnacle.input_state.accessibility;
+        if accessibility.sticky_keys == enabled {
+            return;
+        }
+
+        accessibility.sticky_keys = enabled;
+        accessibility.held_modifiers.clear();
+
+        if !enabled {
+            let time = Duration::from(self.pinnacle.clock.now()).as_millis() as u32;
+            self.release_latched_modifiers(time);
+        }
+
+        self.pinnacle
+            .signal_state
+            .input_accessibility_changed
+            .signal(&self.pinnacle.input_state.accessibility);
+    }
+
+    /// Enables slow keys with the given delay, or disables it.
+    pub fn set_slow_keys(&mut self, delay: Option<Duration>) {
+        let accessibility = &mut self.pinnacle.input_state.accessibility;
+        if accessibility.slow_keys == delay {
+            return;
+        }
+
+        accessibility.slow_keys = delay;
+
+        // Keys that haven't been accepted yet are dropped
+        for (_, token) in accessibility.slow_keys_pending.drain() {
+            self.pinnacle.loop_handle.remove(token);
+        }
+
+        self.pinnacle
+            .signal_state
+            .input_accessibility_changed
+            .signal(&self.pinnacle.input_state.accessibility);
+    }
+}
@@ -17,14 +17,44 @@ pub struct BindState {
     pub layer_stack: Vec<String>,
     pub keybinds: Keybinds,
     pub mousebinds: Mousebinds,
+    /// How conflicting binds are handled on each layer.
+    /// Layers not in here use [`ConflictPolicy::Keep`].
+    pub conflict_policies: HashMap<Option<String>, ConflictPolicy>,
 }
 
 impl BindState {
+    /// Removes the config's binds, keeping those owned by namespaced clients.
     pub fn clear(&mut self) {
-        self.keybinds.id_map.clear();
-        self.keybinds.keysym_map.clear();
-        self.mousebinds.id_map.clear();
-        self.mousebinds.button_map.clear();
+        self.clear_namespace(None);
+        self.conflict_policies.clear();
+    }
+
+    /// Removes all binds owned by `namespace`, or by the config if `None`.
+    pub fn clear_namespace(&mut self, namespace: Option<&str>) {
+        let keep = |bind_data: &BindData| bind_data.namespace.as_deref() != namespace;
+
+        self.keybinds
+            .id_map
+            .retain(|_, keybind| keep(&keybind.borrow().bind_data));
+        self.keybinds.keysym_map.retain(|_, keybinds| {
+            keybinds.retain(|keybind| keybind.strong_count() > 0);
+            !keybinds.is_empty()
+        });
+
+        self.mousebinds
+            .id_map
+            .retain(|_, mousebind| keep(&mousebind.borrow().bind_data));
+        self.mousebinds.button_map.retain(|_, mousebinds| {
+            mousebinds.retain(|mousebind| mousebind.strong_count() > 0);
+            !mousebinds.is_empty()
+        });
+    }
+
+    pub fn conflict_policy(&self, layer: &Option<String>) -> ConflictPolicy {
+        self.conflict_policies
+            .get(layer)
+            .copied()
+            .unwrap_or_default()
     }
 
     pub fn enter_layer(&mut self, layer: Option<String>) {
@@ -128,6 +158,43 @@ impl ModMask {
 
         shift && ctrl && alt && super_ && iso_level3_shift && iso_level5_shift
     }
+
+    /// Returns whether there is a modifier state that both this mask and `other` match.
+    pub fn overlaps(&self, other: &ModMask) -> bool {
+        fn overlap(a: Option<bool>, b: Option<bool>) -> bool {
+            a.zip(b).is_none_or(|(a, b)| a == b)
+        }
+
+        overlap(self.shift, other.shift)
+            && overlap(self.ctrl, other.ctrl)
+            && overlap(self.alt, other.alt)
+            && overlap(self.super_, other.super_)
+            && overlap(self.iso_level3_shift, other.iso_level3_shift)
+            && overlap(self.iso_level5_shift, other.iso_level5_shift)
+    }
+}
+
+/// What to do with existing binds when a conflicting bind is added.
+///
+/// Binds conflict when they are on the same layer, trigger on the same key or button,
+/// and have modifiers that can match at the same time.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub enum ConflictPolicy {
+    /// Keep the existing binds. All conflicting binds will trigger.
+    #[default]
+    Keep,
+    /// Remove the existing binds.
+    Override,
+    /// Disable the existing binds until the new bind is removed.
+    Shadow,
+}
+
+/// An existing bind that conflicted with a newly added one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BindConflict {
+    pub id: u32,
+    pub group: String,
+    pub desc: String,
 }
 
 #[derive(Debug)]
@@ -140,6 +207,26 @@ pub struct BindData {
     pub is_quit_bind: bool,
     pub is_reload_config_bind: bool,
     pub allow_when_locked: bool,
+    /// The namespace of the client that created this bind, or `None` for the config.
+    pub namespace: Option<String>,
+    /// The ids of binds that shadow this bind.
+    ///
+    /// This bind is disabled while any of them still exist.
+    pub shadowed_by: Vec<u32>,
+}
+
+impl BindData {
+    fn conflicts_with(&self, mods: &ModMask, layer: &Option<String>) -> bool {
+        self.layer == *layer && self.mods.overlaps(mods)
+    }
+
+    fn to_conflict(&self) -> BindConflict {
+        BindConflict {
+            id: self.id,
+            group: self.group.clone(),
+            desc: self.desc.clone(),
+        }
+    }
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
@@ -232,6 +319,15 @@ impl Keybinds {
 
             let keybind = keybind.borrow();
 
+            let shadowed = keybind
+                .bind_data
+                .shadowed_by
+                .iter()
+                .any(|id| self.id_map.contains_key(id));
+            if shadowed {
+                return true;
+            }
+
             let same_layer = current_layer == keybind.bind_data.layer;
 
             if let BindAction::Quit | BindAction::ReloadConfig = bind_action {
@@ -297,9 +393,36 @@ impl Keybinds {
         is_quit_bind: bool,
         is_reload_config_bind: bool,
         allow_when_locked: bool,
-    ) -> u32 {
+        conflict_policy: ConflictPolicy,
+        namespace: Option<String>,
+    ) -> (u32, Vec<BindConflict>) {
         let id = BIND_ID_COUNTER.fetch_add(1, Ordering::Relaxed);
 
+        let conflicting = self
+            .keysym_map
+            .get(&key)
+            .into_iter()
+            .flatten()
+            .filter_map(Weak::upgrade)
+            .filter(|keybind| keybind.borrow().bind_data.conflicts_with(&mods, &layer))
+            .collect::<Vec<_>>();
+
+        let conflicts = conflicting
+            .iter()
+            .map(|keybind| keybind.borrow().bind_data.to_conflict())
+            .collect::<Vec<_>>();
+
+        for keybind in conflicting {
+            match conflict_policy {
+                ConflictPolicy::Keep => (),
+                ConflictPolicy::Override => {
+                    let conflicting_id = keybind.borrow().bind_data.id;
+                    self.remove_keybind(conflicting_id);
+                }
+                ConflictPolicy::Shadow => keybind.borrow_mut().bind_data.shadowed_by.push(id),
+            }
+        }
+
         let (sender, recv) = tokio::sync::mpsc::unbounded_channel::<Edge>();
 
         let keybind = Rc::new(RefCell::new(Keybind {
@@ -312,6 +435,8 @@ impl Keybinds {
                 is_quit_bind,
                 is_reload_config_bind,
                 allow_when_locked,
+                namespace,
+                shadowed_by: Vec::new(),
             },
             key,
             sender,
@@ -329,7 +454,7 @@ impl Keybinds {
             .or_default()
             .push(Rc::downgrade(&keybind));
 
-        id
+        (id, conflicts)
     }
 
     pub fn remove_keybind(&mut self, keybind_id: u32) {
@@ -426,6 +551,15 @@ impl Mousebinds {
 
             let mousebind = mousebind.borrow();
 
+            let shadowed = mousebind
+                .bind_data
+                .shadowed_by
+                .iter()
+                .any(|id| self.id_map.contains_key(id));
+            if shadowed {
+                return true;
+            }
+
             let same_layer = current_layer == mousebind.bind_data.layer;
 
             if let BindAction::Quit | BindAction::ReloadConfig = bind_action {
@@ -485,9 +619,36 @@ impl Mousebinds {
         is_quit_bind: bool,
         is_reload_config_bind: bool,
         allow_when_locked: bool,
-    ) -> u32 {
+        conflict_policy: ConflictPolicy,
+        namespace: Option<String>,
+    ) -> (u32, Vec<BindConflict>) {
         let id = BIND_ID_COUNTER.fetch_add(1, Ordering::Relaxed);
 
+        let conflicting = self
+            .button_map
+            .get(&button)
+            .into_iter()
+            .flatten()
+            .filter_map(Weak::upgrade)
+            .filter(|mousebind| mousebind.borrow().bind_data.conflicts_with(&mods, &layer))
+            .collect::<Vec<_>>();
+
+        let conflicts = conflicting
+            .iter()
+            .map(|mousebind| mousebind.borrow().bind_data.to_conflict())
+            .collect::<Vec<_>>();
+
+        for mousebind in conflicting {
+            match conflict_policy {
+                ConflictPolicy::Keep => (),
+                ConflictPolicy::Override => {
+                    let conflicting_id = mousebind.borrow().bind_data.id;
+                    self.remove_mousebind(conflicting_id);
+                }
+                ConflictPolicy::Shadow => mousebind.borrow_mut().bind_data.shadowed_by.push(id),
+            }
+        }
+
         let (sender, recv) = tokio::sync::mpsc::unbounded_channel::<Edge>();
 
         let mousebind = Rc::new(RefCell::new(Mousebind {
@@ -500,6 +661,8 @@ impl Mousebinds {
                 is_quit_bind,
                 is_reload_config_bind,
                 allow_when_locked,
+                namespace,
+                shadowed_by: Vec::new(),
             },
             button,
             sender,
@@ -517,7 +680,7 @@ impl Mousebinds {
             .or_default()
             .push(Rc::downgrade(&mousebind));
 
-        id
+        (id, conflicts)
     }
 
     pub fn remove_mousebind(&mut self, mousebind_id: u32) {
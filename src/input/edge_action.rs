@@ -0,0 +1,173 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Actions run when the pointer hits an edge or corner of an output.
+
+use std::collections::HashMap;
+
+use tokio::sync::mpsc::{UnboundedReceiver, UnboundedSender, unbounded_channel};
+
+use crate::{
+    focus::pointer::ScreenEdge,
+    window::snap::{SnapGrid, SnapRegion},
+};
+
+/// What happens when the pointer hits an edge or corner of an output.
+#[derive(Debug)]
+pub enum EdgeAction {
+    /// Nothing happens, even if there's an action for all outputs.
+    None,
+    /// The config is sent the name of the output through the action's stream.
+    Callback {
+        id: u32,
+        sender: UnboundedSender<String>,
+        recv: Option<UnboundedReceiver<String>>,
+    },
+    /// Floating windows dropped at the edge snap to the region of the output next to it.
+    SnapWindow,
+}
+
+#[derive(Debug, Default)]
+pub struct EdgeActionState {
+    /// Actions by edge and the name of the output they're limited to, if any.
+    actions: HashMap<(ScreenEdge, Option<String>), EdgeAction>,
+    next_id: u32,
+}
+
+impl EdgeActionState {
+    /// Sets a callback action on `edge`, returning the id used to open its stream.
+    pub fn set_callback(&mut self, edge: ScreenEdge, output_name: Option<String>) -> u32 {
+        let id = self.next_id;
+        self.next_id += 1;
+
+        let (sender, recv) = unbounded_channel();
+        self.actions.insert(
+            (edge, output_name),
+            EdgeAction::Callback {
+                id,
+                sender,
+                recv: Some(recv),
+            },
+        );
+
+        id
+    }
+
+    /// Sets `edge` to snap floating windows dropped at it.
+    pub fn set_snap_window(&mut self, edge: ScreenEdge, output_name: Option<String>) {
+        self.actions
+            .insert((edge, output_name), EdgeAction::SnapWindow);
+    }
+
+    /// Sets `edge` to do nothing.
+    ///
+    /// For a single output, this overrides the action for all outputs.
+    pub fn set_none(&mut self, edge: ScreenEdge, output_name: Option<String>) {
+        match output_name {
+            Some(output_name) => {
+                self.actions
+                    .insert((edge, Some(output_name)), EdgeAction::None);
+            }
+            None => {
+                self.actions.remove(&(edge, None));
+            }
+        }
+    }
+
+    /// Returns the action on `edge` of the output named `output_name`.
+    ///
+    /// Actions limited to the output take precedence over ones for all outputs.
+    pub fn action(&self, edge: ScreenEdge, output_name: &str) -> Option<&EdgeAction> {
+        self.actions
+            .get(&(edge, Some(output_name.to_string())))
+            .or_else(|| self.actions.get(&(edge, None)))
+            .filter(|action| !matches!(action, EdgeAction::None))
+    }
+
+    /// Takes the receiving end of the callback action with the given `id`.
+    ///
+    /// Returns `None` if there's no such action or its stream was already set up.
+    pub fn take_callback_recv(&mut self, id: u32) -> Option<UnboundedReceiver<String>> {
+        self.actions.values_mut().find_map(|action| match action {
+            EdgeAction::Callback {
+                id: action_id,
+                recv,
+                ..
+            } if *action_id == id => recv.take(),
+            _ => None,
+        })
+    }
+
+    pub fn clear(&mut self) {
+        self.actions.clear();
+    }
+}
+
+/// Returns the region of an output next to `edge`.
+///
+/// Sides get the half of the output along them and corners get the quarter in them.
+pub fn snap_region_for_edge(edge: ScreenEdge) -> SnapRegion {
+    let (columns, rows, column, row) = match edge {
+        ScreenEdge::Top => (1, 2, 0, 0),
+        ScreenEdge::Bottom => (1, 2, 0, 1),
+        ScreenEdge::Left => (2, 1, 0, 0),
+        ScreenEdge::Right => (2, 1, 1, 0),
+        ScreenEdge::TopLeft => (2, 2, 0, 0),
+        ScreenEdge::TopRight => (2, 2, 1, 0),
+        ScreenEdge::BottomLeft => (2, 2, 0, 1),
+        ScreenEdge::BottomRight => (2, 2, 1, 1),
+    };
+
+    SnapRegion {
+        grid: SnapGrid { columns, rows },
+        column,
+        row,
+        column_span: 1,
+        row_span: 1,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn output_action_overrides_global_action() {
+        let mut state = EdgeActionState::default();
+
+        state.set_snap_window(ScreenEdge::Left, None);
+        state.set_callback(ScreenEdge::Left, Some("DP-1".into()));
+
+        assert!(matches!(
+            state.action(ScreenEdge::Left, "DP-1"),
+            Some(EdgeAction::Callback { .. })
+        ));
+        assert!(matches!(
+            state.action(ScreenEdge::Left, "DP-2"),
+            Some(EdgeAction::SnapWindow)
+        ));
+    }
+
+    #[test]
+    fn none_on_output_disables_global_action() {
+        let mut state = EdgeActionState::default();
+
+        state.set_snap_window(ScreenEdge::Left, None);
+        state.set_none(ScreenEdge::Left, Some("DP-1".into()));
+
+        assert!(state.action(ScreenEdge::Left, "DP-1").is_none());
+        assert!(matches!(
+            state.action(ScreenEdge::Left, "DP-2"),
+            Some(EdgeAction::SnapWindow)
+        ));
+    }
+
+    #[test]
+    fn global_none_removes_global_action() {
+        let mut state = EdgeActionState::default();
+
+        state.set_snap_window(ScreenEdge::Left, None);
+        state.set_none(ScreenEdge::Left, None);
+
+        assert!(state.action(ScreenEdge::Left, "DP-1").is_none());
+    }
+}
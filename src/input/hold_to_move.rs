@@ -0,0 +1,285 @@
+//! Moving windows by holding them.
+//!
+//! Holding a touch or a configured pointer button on a window for a while starts moving the
+//! window without needing a modifier key. Windows that use long presses themselves can opt out.
+
+use std::time::Duration;
+
+use smithay::{
+    backend::input::TouchSlot,
+    reexports::calloop::{
+        RegistrationToken,
+        timer::{TimeoutAction, Timer},
+    },
+    utils::{Logical, Point, SERIAL_COUNTER},
+};
+
+use crate::{
+    grab::move_grab::drag_window,
+    state::{State, WithState},
+    window::WindowElement,
+};
+
+/// How far a hold can drift before it stops counting as a hold.
+const MAX_HOLD_DRIFT: f64 = 8.0;
+
+/// Hold-to-move settings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HoldToMoveConfig {
+    /// Whether holding a touch moves windows.
+    pub touch: bool,
+    /// The pointer button that moves windows when held, if any.
+    pub button: Option<u32>,
+    /// How long a window must be held before it starts moving.
+    pub hold_time: Duration,
+}
+
+impl Default for HoldToMoveConfig {
+    fn default() -> Self {
+        Self {
+            touch: false,
+            button: None,
+            hold_time: Duration::from_millis(500),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum HoldInput {
+    Button(u32),
+    Touch(TouchSlot),
+}
+
+/// A hold that hasn't lasted long enough to start moving yet.
+#[derive(Debug)]
+struct PendingHold {
+    input: HoldInput,
+    window: WindowElement,
+    start_loc: Point<f64, Logical>,
+    timer: RegistrationToken,
+}
+
+/// A window being moved by a touch.
+#[derive(Debug)]
+struct TouchMove {
+    slot: TouchSlot,
+    window: WindowElement,
+    start_loc: Point<f64, Logical>,
+    initial_window_loc: Point<f64, Logical>,
+}
+
+#[derive(Debug, Default)]
+pub struct HoldToMoveState {
+    pub config: HoldToMoveConfig,
+    pending: Option<PendingHold>,
+    touch_move: Option<TouchMove>,
+}
+
+impl State {
+    /// Starts waiting for a pointer button hold if it's the hold-to-move button.
+    pub fn hold_to_move_button_pressed(&mut self, button: u32, loc: Point<f64, Logical>) {
+        if self.pinnacle.input_state.hold_to_move.config.button == Some(button) {
+            self.begin_hold(HoldInput::Button(button), loc);
+        }
+    }
+
+    /// Starts waiting for a touch hold if touches move windows.
+    pub fn hold_to_move_touch_down(&mut self, slot: TouchSlot, loc: Point<f64, Logical>) {
+        if self.pinnacle.input_state.hold_to_move.config.touch {
+            self.begin_hold(HoldInput::Touch(slot), loc);
+        }
+    }
+
+    /// Cancels a pending pointer hold if the pointer drifted too far.
+    pub fn hold_to_move_pointer_motion(&mut self, loc: Point<f64, Logical>) {
+        if let Some(HoldInput::Button(button)) = self.pending_hold_input() {
+            self.hold_motion(HoldInput::Button(button), loc);
+        }
+    }
+
+    /// Cancels a pending pointer hold if its button was released.
+    pub fn hold_to_move_button_released(&mut self, button: u32) {
+        if self.pending_hold_input() == Some(HoldInput::Button(button)) {
+            self.cancel_hold();
+        }
+    }
+
+    /// Moves the window held by a touch, or cancels a pending touch hold if it drifted too far.
+    ///
+    /// Returns whether the motion was used to move a window and shouldn't be sent to clients.
+    pub fn hold_to_move_touch_motion(&mut self, slot: TouchSlot, loc: Point<f64, Logical>) -> bool {
+        let hold_to_move = &self.pinnacle.input_state.hold_to_move;
+
+        let Some(touch_move) = hold_to_move
+            .touch_move
+            .as_ref()
+            .filter(|touch_move| touch_move.slot == slot)
+        else {
+            self.hold_motion(HoldInput::Touch(slot), loc);
+            return false;
+        };
+
+        let window = touch_move.window.clone();
+        let start_loc = touch_move.start_loc;
+        let initial_window_loc = touch_move.initial_window_loc;
+
+        let output_under = self.pinnacle.space.output_under(loc).next().cloned();
+        drag_window(
+            self,
+            &window,
+            initial_window_loc,
+            start_loc,
+            loc,
+            output_under,
+        );
+
+        true
+    }
+
+    /// Stops moving the window held by a touch, or cancels a pending touch hold.
+    ///
+    /// Returns whether the touch was moving a window and its release shouldn't be sent
+    /// to clients.
+    pub fn hold_to_move_touch_up(&mut self, slot: TouchSlot) -> bool {
+        let hold_to_move = &mut self.pinnacle.input_state.hold_to_move;
+
+        if hold_to_move
+            .touch_move
+            .as_ref()
+            .is_some_and(|touch_move| touch_move.slot == slot)
+        {
+            hold_to_move.touch_move.take();
+            return true;
+        }
+
+        if self.pending_hold_input() == Some(HoldInput::Touch(slot)) {
+            self.cancel_hold();
+        }
+
+        false
+    }
+
+    /// Cancels any pending hold and stops moving windows with touches.
+    pub fn hold_to_move_cancel(&mut self) {
+        self.cancel_hold();
+        self.pinnacle.input_state.hold_to_move.touch_move.take();
+    }
+
+    fn pending_hold_input(&self) -> Option<HoldInput> {
+        self.pinnacle
+            .input_state
+            .hold_to_move
+            .pending
+            .as_ref()
+            .map(|pending| pending.input)
+    }
+
+    fn begin_hold(&mut self, input: HoldInput, loc: Point<f64, Logical>) {
+        self.cancel_hold();
+
+        if !self.pinnacle.lock_state.is_unlocked() {
+            return;
+        }
+
+        let Some(window) = self
+            .pinnacle
+            .pointer_contents_under(loc)
+            .focus_under
+            .and_then(|(focus, _)| focus.window_for(&self.pinnacle))
+        else {
+            return;
+        };
+
+        if window.with_state(|state| !state.hold_to_move_enabled || state.input_disabled) {
+            return;
+        }
+
+        let hold_time = self.pinnacle.input_state.hold_to_move.config.hold_time;
+
+        let Ok(timer) = self.pinnacle.loop_handle.insert_source(
+            Timer::from_duration(hold_time),
+            |_, _, state| {
+                state.on_hold_elapsed();
+                TimeoutAction::Drop
+            },
+        ) else {
+            return;
+        };
+
+        self.pinnacle.input_state.hold_to_move.pending = Some(PendingHold {
+            input,
+            window,
+            start_loc: loc,
+            timer,
+        });
+    }
+
+    fn hold_motion(&mut self, input: HoldInput, loc: Point<f64, Logical>) {
+        let drifted = self
+            .pinnacle
+            .input_state
+            .hold_to_move
+            .pending
+            .as_ref()
+            .is_some_and(|pending| {
+                let delta = loc - pending.start_loc;
+                pending.input == input && delta.x.hypot(delta.y) > MAX_HOLD_DRIFT
+            });
+
+        if drifted {
+            self.cancel_hold();
+        }
+    }
+
+    fn cancel_hold(&mut self) {
+        if let Some(pending) = self.pinnacle.input_state.hold_to_move.pending.take() {
+            self.pinnacle.loop_handle.remove(pending.timer);
+        }
+    }
+
+    /// Starts moving the held window.
+    fn on_hold_elapsed(&mut self) {
+        let Some(PendingHold {
+            input,
+            window,
+            start_loc,
+            timer: _,
+        }) = self.pinnacle.input_state.hold_to_move.pending.take()
+        else {
+            return;
+        };
+
+        let Some(initial_window_loc) = self
+            .pinnacle
+            .space
+            .element_location(&window)
+            .map(|loc| loc.to_f64())
+        else {
+            return;
+        };
+
+        match input {
+            HoldInput::Button(button) => {
+                let Some(surface) = window.wl_surface() else {
+                    return;
+                };
+                let seat = self.pinnacle.seat.clone();
+                self.move_request_server(&surface, &seat, SERIAL_COUNTER.next_serial(), button);
+            }
+            HoldInput::Touch(slot) => {
+                // The client already got the touch down, tell it the touch is no longer
+                // its to handle.
+                if let Some(touch) = self.pinnacle.seat.get_touch() {
+                    touch.cancel(self);
+                }
+
+                self.pinnacle.input_state.hold_to_move.touch_move = Some(TouchMove {
+                    slot,
+                    window,
+                    start_loc,
+                    initial_window_loc,
+                });
+            }
+        }
+    }
+}
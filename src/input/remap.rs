@@ -0,0 +1,180 @@
+//! Key remapping.
+//!
+//! Remaps are applied to key events as they come in from devices, before they reach
+//! accessibility features, binds, or clients.
+
+use std::collections::HashMap;
+
+use smithay::{backend::input::KeyState, input::keyboard::Keycode};
+use xkbcommon::xkb::Keysym;
+
+use crate::state::State;
+
+/// What a remapped key turns into.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RemapTarget {
+    /// Another key, which is pressed and released along with the remapped key.
+    Key(Keysym),
+    /// A sequence of keys that are each pressed and released in order when the remapped key
+    /// is pressed.
+    Macro(Vec<Keysym>),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Remap {
+    pub from: Keysym,
+    pub to: RemapTarget,
+    /// The sysname of the device this remap is scoped to, or `None` to apply it to all devices.
+    pub device_sysname: Option<String>,
+}
+
+#[derive(Debug, Default)]
+pub struct RemapState {
+    pub remaps: Vec<Remap>,
+    /// Keys that were remapped when pressed, along with the key they were remapped to.
+    ///
+    /// Keys remapped to macros map to `None` as their release is dropped.
+    pressed: HashMap<Keycode, Option<Keycode>>,
+}
+
+impl RemapState {
+    /// Adds a remap, replacing any existing one for the same key and device.
+    pub fn add(&mut self, remap: Remap) {
+        self.remaps.retain(|existing| {
+            existing.from != remap.from || existing.device_sysname != remap.device_sysname
+        });
+        self.remaps.push(remap);
+    }
+
+    /// Removes all remaps scoped to the given device, or all remaps if `None`.
+    pub fn clear(&mut self, device_sysname: Option<&str>) {
+        match device_sysname {
+            Some(sysname) => self
+                .remaps
+                .retain(|remap| remap.device_sysname.as_deref() != Some(sysname)),
+            None => self.remaps.clear(),
+        }
+    }
+
+    /// Returns the remap for any of `keysyms` from the given device.
+    ///
+    /// Remaps scoped to the device take priority over global ones.
+    fn remap_for(&self, keysyms: &[Keysym], device_sysname: Option<&str>) -> Option<&Remap> {
+        let matches = |remap: &&Remap| keysyms.contains(&remap.from);
+
+        self.remaps
+            .iter()
+            .filter(|remap| {
+                device_sysname.is_some() && remap.device_sysname.as_deref() == device_sysname
+            })
+            .find(matches)
+            .or_else(|| {
+                self.remaps
+                    .iter()
+                    .filter(|remap| remap.device_sysname.is_none())
+                    .find(matches)
+            })
+    }
+}
+
+impl State {
+    /// Runs a key event through the remap table, then passes it on to accessibility features.
+    pub fn on_remappable_key(
+        &mut self,
+        keycode: Keycode,
+        press_state: KeyState,
+        time: u32,
+        device_sysname: Option<&str>,
+    ) {
+        if press_state == KeyState::Released {
+            match self
+                .pinnacle
+                .input_state
+                .remap_state
+                .pressed
+                .remove(&keycode)
+            {
+                Some(Some(remapped)) => self.on_accessible_key(remapped, press_state, time),
+                Some(None) => (),
+                None => self.on_accessible_key(keycode, press_state, time),
+            }
+            return;
+        }
+
+        if self.pinnacle.input_state.remap_state.remaps.is_empty() {
+            self.on_accessible_key(keycode, press_state, time);
+            return;
+        }
+
+        let keysyms = self.keysyms_for_keycode(keycode);
+
+        let Some(target) = self
+            .pinnacle
+            .input_state
+            .remap_state
+            .remap_for(&keysyms, device_sysname)
+            .map(|remap| remap.to.clone())
+        else {
+            self.on_accessible_key(keycode, press_state, time);
+            return;
+        };
+
+        match target {
+            RemapTarget::Key(keysym) => {
+                let Some(remapped) = self.keycode_for_keysym(keysym) else {
+                    self.on_accessible_key(keycode, press_state, time);
+                    return;
+                };
+
+                self.pinnacle
+                    .input_state
+                    .remap_state
+                    .pressed
+                    .insert(keycode, Some(remapped));
+                self.on_accessible_key(remapped, press_state, time);
+            }
+            RemapTarget::Macro(keysyms) => {
+                self.pinnacle
+                    .input_state
+                    .remap_state
+                    .pressed
+                    .insert(keycode, None);
+
+                for keysym in keysyms {
+                    let Some(keycode) = self.keycode_for_keysym(keysym) else {
+                        continue;
+                    };
+                    self.on_key(keycode, KeyState::Pressed, time);
+                    self.on_key(keycode, KeyState::Released, time);
+                }
+            }
+        }
+    }
+
+    /// Returns the keysyms `keycode` produces in the active layout, ignoring modifiers.
+    fn keysyms_for_keycode(&mut self, keycode: Keycode) -> Vec<Keysym> {
+        let Some(keyboard) = self.pinnacle.seat.get_keyboard() else {
+            return Vec::new();
+        };
+
+        keyboard.with_xkb_state(self, |ctx| {
+            let xkb = ctx.xkb().lock().unwrap();
+            xkb.raw_syms_for_key_in_layout(keycode, xkb.active_layout())
+                .to_vec()
+        })
+    }
+
+    /// Returns the first keycode that produces `keysym` in the active layout.
+    fn keycode_for_keysym(&mut self, keysym: Keysym) -> Option<Keycode> {
+        let keyboard = self.pinnacle.seat.get_keyboard()?;
+
+        keyboard.with_xkb_state(self, |ctx| {
+            let xkb = ctx.xkb().lock().unwrap();
+            let layout = xkb.active_layout();
+            (8..=255).map(Keycode::new).find(|keycode| {
+                xkb.raw_syms_for_key_in_layout(*keycode, layout)
+                    .contains(&keysym)
+            })
+        })
+    }
+}
@@ -0,0 +1,186 @@
+//! Input event tracing.
+//!
+//! When enabled, every input event is logged along with where it ended up,
+//! which helps debug input going to the wrong window.
+
+use smithay::{
+    backend::input::{InputBackend, InputEvent},
+    reexports::wayland_server::Resource,
+    wayland::seat::WaylandFocus,
+};
+use tracing::info;
+
+use crate::{
+    focus::{keyboard::KeyboardFocusTarget, pointer::PointerFocusTarget},
+    state::{Pinnacle, State, WithState},
+    window::WindowElement,
+};
+
+/// A focus target at the time an input event was traced.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TracedFocus {
+    /// A human-readable description of the target, like `window 3 (Alacritty)`.
+    pub description: String,
+    /// The id of the window the target belongs to, if any.
+    pub window_id: Option<u32>,
+    /// The pid of the client that owns the target.
+    pub client_pid: Option<i32>,
+}
+
+/// An input event and where it went.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InputTrace {
+    /// The kind of input event, like `PointerButton`.
+    pub event: &'static str,
+    pub pointer_focus: Option<TracedFocus>,
+    pub keyboard_focus: Option<TracedFocus>,
+    pub pointer_grabbed: bool,
+    pub keyboard_grabbed: bool,
+    /// The pid of the client the event was sent to.
+    ///
+    /// This is the keyboard focus's client for keyboard events
+    /// and the pointer focus's client for everything else.
+    pub receiving_client_pid: Option<i32>,
+}
+
+/// Returns the name of an input event that should be traced.
+///
+/// Returns `None` for events that aren't input, like devices being added.
+pub fn event_name<B: InputBackend>(event: &InputEvent<B>) -> Option<&'static str> {
+    let name = match event {
+        InputEvent::Keyboard { .. } => "Keyboard",
+        InputEvent::PointerMotion { .. } => "PointerMotion",
+        InputEvent::PointerMotionAbsolute { .. } => "PointerMotionAbsolute",
+        InputEvent::PointerButton { .. } => "PointerButton",
+        InputEvent::PointerAxis { .. } => "PointerAxis",
+        InputEvent::GestureSwipeBegin { .. } => "GestureSwipeBegin",
+        InputEvent::GestureSwipeUpdate { .. } => "GestureSwipeUpdate",
+        InputEvent::GestureSwipeEnd { .. } => "GestureSwipeEnd",
+        InputEvent::GesturePinchBegin { .. } => "GesturePinchBegin",
+        InputEvent::GesturePinchUpdate { .. } => "GesturePinchUpdate",
+        InputEvent::GesturePinchEnd { .. } => "GesturePinchEnd",
+        InputEvent::GestureHoldBegin { .. } => "GestureHoldBegin",
+        InputEvent::GestureHoldEnd { .. } => "GestureHoldEnd",
+        InputEvent::TouchDown { .. } => "TouchDown",
+        InputEvent::TouchMotion { .. } => "TouchMotion",
+        InputEvent::TouchUp { .. } => "TouchUp",
+        InputEvent::TouchCancel { .. } => "TouchCancel",
+        InputEvent::TouchFrame { .. } => "TouchFrame",
+        InputEvent::TabletToolAxis { .. } => "TabletToolAxis",
+        InputEvent::TabletToolProximity { .. } => "TabletToolProximity",
+        InputEvent::TabletToolTip { .. } => "TabletToolTip",
+        InputEvent::TabletToolButton { .. } => "TabletToolButton",
+        _ => return None,
+    };
+
+    Some(name)
+}
+
+impl State {
+    /// Logs where an input event went and sends it to clients streaming input traces.
+    pub fn trace_input_event(&mut self, event: &'static str) {
+        let _span = tracy_client::span!("State::trace_input_event");
+
+        let pointer = self.pinnacle.seat.get_pointer();
+        let keyboard = self.pinnacle.seat.get_keyboard();
+
+        let pointer_focus = pointer
+            .as_ref()
+            .and_then(|pointer| pointer.current_focus())
+            .map(|focus| self.pinnacle.trace_pointer_focus(&focus));
+        let keyboard_focus = keyboard
+            .as_ref()
+            .and_then(|keyboard| keyboard.current_focus())
+            .map(|focus| self.pinnacle.trace_keyboard_focus(&focus));
+
+        let receiving_focus = if event == "Keyboard" {
+            keyboard_focus.as_ref()
+        } else {
+            pointer_focus.as_ref()
+        };
+
+        let trace = InputTrace {
+            event,
+            receiving_client_pid: receiving_focus.and_then(|focus| focus.client_pid),
+            pointer_grabbed: pointer.is_some_and(|pointer| pointer.is_grabbed()),
+            keyboard_grabbed: keyboard.is_some_and(|keyboard| keyboard.is_grabbed()),
+            pointer_focus,
+            keyboard_focus,
+        };
+
+        let describe = |focus: &Option<TracedFocus>| {
+            focus
+                .as_ref()
+                .map(|focus| focus.description.clone())
+                .unwrap_or_else(|| "none".into())
+        };
+
+        info!(
+            event = trace.event,
+            pointer_focus = %describe(&trace.pointer_focus),
+            keyboard_focus = %describe(&trace.keyboard_focus),
+            pointer_grabbed = trace.pointer_grabbed,
+            keyboard_grabbed = trace.keyboard_grabbed,
+            receiving_client_pid = trace.receiving_client_pid,
+            "Input event"
+        );
+
+        self.pinnacle.input_trace_state.send(&trace);
+    }
+}
+
+impl Pinnacle {
+    fn trace_pointer_focus(&self, focus: &PointerFocusTarget) -> TracedFocus {
+        let window = focus.window_for(self);
+
+        let description = if let Some(window) = window.as_ref() {
+            describe_window(window)
+        } else if let Some(layer) = focus.layer_for(self) {
+            format!("layer surface ({})", layer.namespace())
+        } else if focus.popup_for(self).is_some() {
+            "popup".into()
+        } else {
+            "surface".into()
+        };
+
+        TracedFocus {
+            description,
+            window_id: window.map(|window| window.with_state(|state| state.id.0)),
+            client_pid: self.client_pid(focus),
+        }
+    }
+
+    fn trace_keyboard_focus(&self, focus: &KeyboardFocusTarget) -> TracedFocus {
+        let (description, window_id) = match focus {
+            KeyboardFocusTarget::Window(window) => (
+                describe_window(window),
+                Some(window.with_state(|state| state.id.0)),
+            ),
+            KeyboardFocusTarget::Popup(_) => ("popup".into(), None),
+            KeyboardFocusTarget::LayerSurface(layer) => {
+                (format!("layer surface ({})", layer.namespace()), None)
+            }
+            KeyboardFocusTarget::LockSurface(_) => ("lock surface".into(), None),
+        };
+
+        TracedFocus {
+            description,
+            window_id,
+            client_pid: self.client_pid(focus),
+        }
+    }
+
+    fn client_pid(&self, focus: &impl WaylandFocus) -> Option<i32> {
+        let client = focus.wl_surface()?.client()?;
+        let credentials = client.get_credentials(&self.display_handle).ok()?;
+        Some(credentials.pid)
+    }
+}
+
+fn describe_window(window: &WindowElement) -> String {
+    let id = window.with_state(|state| state.id.0);
+    match window.class() {
+        Some(class) => format!("window {id} ({class})"),
+        None => format!("window {id}"),
+    }
+}
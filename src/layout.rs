@@ -5,22 +5,25 @@ pub mod tree;
 use std::{
     collections::{HashMap, HashSet, hash_map::Entry},
     rc::Rc,
+    time::{Duration, Instant},
 };
 
-use indexmap::IndexSet;
+use indexmap::{IndexMap, IndexSet};
 use smithay::{
     desktop::layer_map_for_output,
     output::{Output, WeakOutput},
+    reexports::calloop::timer::{TimeoutAction, Timer},
     reexports::wayland_protocols::xdg::shell::server::xdg_toplevel,
     utils::{Logical, Rectangle, Size},
 };
 use tokio::sync::mpsc::UnboundedSender;
-use tracing::warn;
+use tracing::{debug, warn};
 use tree::{LayoutNode, LayoutTree, ResizeDir};
 
 use crate::{
     backend::Backend,
     output::OutputName,
+    render::util::accounting::GpuAccounting,
     state::{Pinnacle, State, WithState},
     tag::TagId,
     util::transaction::{Location, PendingTransaction, TransactionBuilder},
@@ -57,7 +60,9 @@ impl Pinnacle {
                 .filter(|win| win.output(self).as_ref() == Some(output))
                 .cloned()
                 .partition::<Vec<_>, _>(|win| {
-                    win.with_state(|state| state.tags.intersection(&focused_tags).next().is_some())
+                    win.with_state(|state| {
+                        !state.minimized && state.tags.intersection(&focused_tags).next().is_some()
+                    })
                 })
         });
 
@@ -171,6 +176,7 @@ impl Pinnacle {
         for win in remaining_wins {
             let to_schedule = self.space.outputs_for_element(&win);
             self.space.unmap_elem(&win);
+            self.pager_state.mark_dirty();
             self.loop_handle.insert_idle(move |state| {
                 for output in to_schedule {
                     state.schedule_render(&output);
@@ -202,6 +208,10 @@ impl LayoutRequestId {
 #[derive(Debug, Default)]
 pub struct LayoutState {
     pub layout_request_sender: Option<UnboundedSender<LayoutInfo>>,
+    /// Senders for external layout generators, most recently registered last.
+    ///
+    /// The newest generator that is still connected takes priority over the config.
+    pub generators: Vec<UnboundedSender<LayoutInfo>>,
     pub pending_swap: bool,
     pub pending_resize: bool,
     current_id: LayoutRequestId,
@@ -212,6 +222,7 @@ pub struct LayoutState {
     pub pending_transactions: PendingTransactions,
     pub pending_unmaps: PendingUnmaps,
     pub pending_window_updates: PendingWindowUpdates,
+    pub queued_updates: QueuedUpdates,
 }
 
 /// Currently pending transactions.
@@ -246,6 +257,11 @@ impl PendingTransactions {
         None
     }
 
+    /// Returns the oldest transaction still pending for `output`.
+    pub fn next_for_output_mut(&mut self, output: &Output) -> Option<&mut PendingTransaction> {
+        self.pending.get_mut(&output.downgrade())?.first_mut()
+    }
+
     #[cfg(feature = "testing")]
     pub fn is_empty(&self) -> bool {
         self.pending.is_empty() || self.pending.iter().all(|(_, v)| v.is_empty())
@@ -259,7 +275,7 @@ impl PendingTransactions {
 /// Once that layout completes, these windows are dropped and no longer rendered.
 #[derive(Debug, Default)]
 pub struct PendingUnmaps {
-    pending: HashMap<WeakOutput, Vec<Vec<Rc<UnmappingWindow>>>>,
+    pending: HashMap<WeakOutput, Vec<(Instant, Vec<Rc<UnmappingWindow>>)>>,
 }
 
 impl PendingUnmaps {
@@ -268,14 +284,56 @@ impl PendingUnmaps {
         self.pending
             .entry(output.downgrade())
             .or_default()
-            .push(pending);
+            .push((Instant::now(), pending));
     }
 
     /// Takes the next set of [`UnmappingWindow`]s.
     pub fn take_next_for_output(&mut self, output: &Output) -> Option<Vec<Rc<UnmappingWindow>>> {
         let entry = self.pending.entry(output.downgrade()).or_default();
 
-        (!entry.is_empty()).then(|| entry.remove(0))
+        (!entry.is_empty()).then(|| entry.remove(0).1)
+    }
+
+    /// Adds [`UnmappingWindow`]s from a cancelled transaction back so they are picked up
+    /// by the next requested layout.
+    ///
+    /// They join the next set if there is one, as that set belongs to the layout
+    /// that will replace them.
+    pub fn carry_over(&mut self, output: &Output, unmapping: Vec<Rc<UnmappingWindow>>) {
+        let entry = self.pending.entry(output.downgrade()).or_default();
+
+        match entry.first_mut() {
+            Some((_, next)) => next.extend(unmapping),
+            None => entry.push((Instant::now(), unmapping)),
+        }
+    }
+
+    /// Drops sets of [`UnmappingWindow`]s that have waited for a layout for longer than `hold`.
+    ///
+    /// This bounds how long their snapshot textures are kept around if no layout ever comes,
+    /// for example when the config isn't running.
+    ///
+    /// Returns the outputs they were displayed on.
+    pub fn evict_stale(&mut self, hold: Duration, accounting: &GpuAccounting) -> Vec<Output> {
+        let mut outputs = Vec::new();
+
+        for (output, pending) in self.pending.iter_mut() {
+            let mut evicted = 0;
+            pending.retain(|(added_at, unmapping)| {
+                let stale = added_at.elapsed() >= hold;
+                if stale {
+                    evicted += unmapping.len();
+                }
+                !stale
+            });
+
+            if evicted > 0 {
+                accounting.record_evicted_snapshots(evicted);
+                outputs.extend(output.upgrade());
+            }
+        }
+
+        outputs
     }
 }
 
@@ -313,6 +371,39 @@ impl PendingWindowUpdates {
     }
 }
 
+/// Window updates and layout requests queued during the current event loop cycle.
+///
+/// These are flushed at the end of the cycle so that several state changes to a window,
+/// like a window rule unfullscreening, retagging, and focusing it at map time, result in
+/// a single configure and a single layout transaction.
+#[derive(Debug, Default)]
+pub struct QueuedUpdates {
+    /// Windows whose geometry needs to be updated, along with whether they need a layout.
+    windows: IndexMap<WindowElement, bool>,
+    /// Outputs that need a layout, along with the windows and target geometries to apply
+    /// with it.
+    layouts: IndexMap<WeakOutput, Vec<(WindowElement, Rectangle<i32, Logical>)>>,
+}
+
+impl QueuedUpdates {
+    /// Queues a geometry update for `window`.
+    pub fn queue_window(&mut self, window: &WindowElement, need_layout: bool) {
+        *self.windows.entry(window.clone()).or_default() |= need_layout;
+    }
+
+    /// Queues a layout for `output`, optionally latching a window's geometry to it.
+    pub fn queue_layout(
+        &mut self,
+        output: &Output,
+        latched: Option<(WindowElement, Rectangle<i32, Logical>)>,
+    ) {
+        self.layouts
+            .entry(output.downgrade())
+            .or_default()
+            .extend(latched);
+    }
+}
+
 impl LayoutState {
     fn next_id(&mut self) -> LayoutRequestId {
         self.current_id.0 += 1;
@@ -347,16 +438,35 @@ pub struct LayoutInfo {
 }
 
 impl State {
+    /// Drops pending [`UnmappingWindow`]s that have waited for a layout for longer than
+    /// the close snapshot hold and rerenders the outputs they were on.
+    pub fn evict_stale_unmaps(&mut self) {
+        let _span = tracy_client::span!("State::evict_stale_unmaps");
+
+        let hold = self.pinnacle.config.close_snapshot_hold;
+        for output in self
+            .pinnacle
+            .layout_state
+            .pending_unmaps
+            .evict_stale(hold, &self.pinnacle.gpu_accounting)
+        {
+            self.schedule_render(&output);
+        }
+    }
+
     /// Updates the layouts of outputs whose transactions have completed.
     pub fn update_layout(&mut self) {
         let _span = tracy_client::span!("State::update_layout");
 
+        self.evict_stale_unmaps();
+
         let mut outputs = HashSet::new();
 
         for output in self.pinnacle.outputs.clone() {
             let mut transactions = Vec::new();
+            let mut carried_unmapping = Vec::new();
 
-            while let Some(tx) = self
+            while let Some(mut tx) = self
                 .pinnacle
                 .layout_state
                 .pending_transactions
@@ -370,6 +480,26 @@ impl State {
                 }
                 if tx.is_completed() {
                     transactions.push(tx);
+                } else {
+                    // Keep showing the closed windows of a cancelled transaction until
+                    // the layout replacing it is applied so their space doesn't flash empty.
+                    carried_unmapping.extend(tx.take_unmapping());
+                }
+            }
+
+            if !carried_unmapping.is_empty() {
+                let layout_state = &mut self.pinnacle.layout_state;
+                match layout_state
+                    .pending_transactions
+                    .next_for_output_mut(&output)
+                {
+                    Some(next) => next.hold_unmapping(carried_unmapping),
+                    None => {
+                        layout_state
+                            .pending_unmaps
+                            .carry_over(&output, carried_unmapping);
+                        self.pinnacle.schedule_unmap_eviction();
+                    }
                 }
             }
 
@@ -417,6 +547,8 @@ impl State {
             }
 
             for (window, loc) in locs {
+                let loc = self.pinnacle.letterboxed_loc(&window, loc);
+
                 if let Some(surface) = window.x11_surface() {
                     let mut configure_loc = loc;
 
@@ -433,6 +565,7 @@ impl State {
                 outputs.extend(self.pinnacle.space.outputs_for_element(&window));
 
                 self.pinnacle.space.map_element(window.clone(), loc, false);
+                self.pinnacle.pager_state.mark_dirty();
                 outputs.extend(self.pinnacle.space.outputs_for_element(&window));
             }
         }
@@ -447,29 +580,78 @@ impl State {
         // Probably a better way to do this
         for win in self.pinnacle.windows.iter() {
             let is_tiled = win.with_state(|state| state.layout_mode.is_tiled());
-            let is_on_active_tag = win.is_on_active_tag();
-            if !is_tiled && is_on_active_tag && !self.pinnacle.space.elements().any(|w| w == win) {
+            let is_shown = win.is_shown();
+            if !is_tiled && is_shown && !self.pinnacle.space.elements().any(|w| w == win) {
                 wins_to_update.push(win.clone());
             }
         }
 
         for win in wins_to_update {
-            self.pinnacle.update_window_geometry(&win, false);
+            self.pinnacle.apply_window_geometry(&win, false);
         }
     }
 }
 
 impl Pinnacle {
+    /// Evicts pending [`UnmappingWindow`]s once they become stale, even if no layout
+    /// or anything else wakes the event loop in the meantime.
+    pub fn schedule_unmap_eviction(&self) {
+        let hold = self.config.close_snapshot_hold;
+        self.loop_handle
+            .insert_source(Timer::from_duration(hold), |_, _, state| {
+                state.evict_stale_unmaps();
+                TimeoutAction::Drop
+            })
+            .expect("failed to insert unmap eviction timer");
+    }
+
+    /// Requests a layout for `output`.
+    ///
+    /// The request is sent at the end of the event loop cycle, so multiple requests for
+    /// the same output in one cycle result in a single layout.
     pub fn request_layout(&mut self, output: &Output) {
+        self.layout_state.queued_updates.queue_layout(output, None);
+    }
+
+    /// Applies all window updates and sends all layout requests queued this event loop cycle.
+    pub fn flush_queued_updates(&mut self) {
+        let _span = tracy_client::span!("Pinnacle::flush_queued_updates");
+
+        let windows = std::mem::take(&mut self.layout_state.queued_updates.windows);
+        for (window, need_layout) in windows {
+            if self.windows.contains(&window) {
+                self.apply_window_geometry(&window, need_layout);
+            }
+        }
+
+        let layouts = std::mem::take(&mut self.layout_state.queued_updates.layouts);
+        for (output, latched) in layouts {
+            let Some(output) = output.upgrade() else {
+                continue;
+            };
+
+            if !latched.is_empty() {
+                self.layout_state
+                    .pending_window_updates
+                    .add_for_output(&output, latched);
+            }
+
+            self.send_layout_request(&output);
+        }
+    }
+
+    fn send_layout_request(&mut self, output: &Output) {
         if output.with_state(|state| state.enabled_global_id.is_none()) {
             return;
         }
 
         let id = self.layout_state.next_id();
-        let Some(sender) = self.layout_state.layout_request_sender.as_ref() else {
+        if self.layout_state.layout_request_sender.is_none()
+            && self.layout_state.generators.is_empty()
+        {
             warn!("Layout requested but no client has connected to the layout service");
             return;
-        };
+        }
 
         let windows_on_foc_tags = output.with_state(|state| {
             let focused_tags = state.focused_tags().cloned().collect::<IndexSet<_>>();
@@ -477,7 +659,9 @@ impl Pinnacle {
                 .iter()
                 .filter(|win| !win.is_x11_override_redirect())
                 .filter(|win| {
-                    win.with_state(|state| state.tags.intersection(&focused_tags).next().is_some())
+                    win.with_state(|state| {
+                        !state.minimized && state.tags.intersection(&focused_tags).next().is_some()
+                    })
                 })
                 .cloned()
                 .collect::<Vec<_>>()
@@ -494,21 +678,86 @@ impl Pinnacle {
 
         let tag_ids = output.with_state(|state| state.focused_tags().map(|tag| tag.id()).collect());
 
-        let _ = sender.send(LayoutInfo {
+        self.metrics.layout_requested(output, id.to_inner());
+
+        let info = LayoutInfo {
             request_id: id,
             output_name: OutputName(output.name()),
             window_count: window_count as u32,
             tag_ids,
-        });
+        };
+
+        let sender = self
+            .layout_state
+            .generators
+            .iter()
+            .rev()
+            .chain(self.layout_state.layout_request_sender.as_ref())
+            .find(|sender| !sender.is_closed());
+
+        match sender {
+            Some(sender) => {
+                let _ = sender.send(info);
+            }
+            // Every layout client has died, keep tiling with the built-in layout
+            // until one comes back.
+            None => {
+                self.loop_handle.insert_idle(move |state| {
+                    if let Err(err) = state.apply_layout_tree(
+                        FALLBACK_TREE_ID,
+                        fallback_layout(info.window_count),
+                        info.request_id.to_inner(),
+                        info.output_name.0,
+                    ) {
+                        debug!("{err}");
+                    }
+                });
+            }
+        }
     }
 }
 
+/// The tree id of the built-in fallback layout.
+const FALLBACK_TREE_ID: u32 = u32::MAX;
+
+/// Builds the built-in master-stack layout used when no layout client is connected.
+fn fallback_layout(window_count: u32) -> LayoutNode {
+    let node = |traversal_index, flex_direction, children| LayoutNode {
+        label: None,
+        traversal_index,
+        traversal_overrides: HashMap::new(),
+        style: taffy::Style {
+            flex_direction,
+            flex_basis: taffy::Dimension::percent(1.0),
+            flex_grow: 1.0,
+            ..Default::default()
+        },
+        children,
+    };
+
+    let children = match window_count {
+        0 => Vec::new(),
+        1 => vec![node(0, taffy::FlexDirection::Row, Vec::new())],
+        _ => {
+            let stack = (0..window_count - 1)
+                .map(|idx| node(idx, taffy::FlexDirection::Row, Vec::new()))
+                .collect();
+            vec![
+                node(0, taffy::FlexDirection::Row, Vec::new()),
+                node(1, taffy::FlexDirection::Column, stack),
+            ]
+        }
+    };
+
+    node(0, taffy::FlexDirection::Row, children)
+}
+
 impl State {
     pub fn apply_layout_tree(
         &mut self,
         tree_id: u32,
         root_node: LayoutNode,
-        _request_id: u32,
+        request_id: u32,
         output_name: String,
     ) -> anyhow::Result<()> {
         let Some(output) = OutputName(output_name).output(&self.pinnacle) else {
@@ -541,6 +790,8 @@ impl State {
         self.pinnacle
             .update_windows_from_tree(&output, &mut self.backend, false);
 
+        self.pinnacle.metrics.layout_applied(&output, request_id);
+
         self.schedule_render(&output);
 
         Ok(())
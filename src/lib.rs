@@ -8,12 +8,21 @@ pub mod focus;
 pub mod grab;
 pub mod handlers;
 pub mod hook;
+pub mod idle;
 pub mod input;
 pub mod layout;
+pub mod logging;
+pub mod metrics;
+pub mod nightlight;
 pub mod output;
+pub mod portal;
+pub mod power;
 pub mod process;
+pub mod profile;
 pub mod protocol;
 pub mod render;
+pub mod restart;
+pub mod screenshot;
 pub mod session;
 pub mod state;
 pub mod tag;
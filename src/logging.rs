@@ -0,0 +1,108 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Runtime control of logging.
+//!
+//! The stdout log filter can be changed while Pinnacle is running, and everything logged to
+//! stdout can be streamed to clients. The file log is unaffected by both.
+
+use std::{
+    io,
+    sync::{
+        Mutex, OnceLock,
+        atomic::{AtomicBool, Ordering},
+    },
+};
+
+use tokio::sync::mpsc::{UnboundedReceiver, UnboundedSender, unbounded_channel};
+use tracing::Subscriber;
+use tracing_subscriber::{
+    EnvFilter, Layer, filter::filter_fn, fmt::MakeWriter, registry::LookupSpan, reload,
+};
+
+type FilterReloader = Box<dyn Fn(EnvFilter) -> Result<(), reload::Error> + Send + Sync>;
+
+static FILTER_RELOADER: OnceLock<FilterReloader> = OnceLock::new();
+
+/// Clients streaming log lines.
+static LOG_SUBSCRIBERS: Mutex<Vec<UnboundedSender<String>>> = Mutex::new(Vec::new());
+/// Whether [`LOG_SUBSCRIBERS`] is non-empty, so lines aren't formatted for nobody.
+static HAS_LOG_SUBSCRIBERS: AtomicBool = AtomicBool::new(false);
+
+/// Creates the stdout logging layer with `filter` as its initial filter.
+///
+/// The layer also sends log lines to clients that called [`subscribe`].
+/// Only the first layer created can have its filter changed with [`set_filter`].
+pub fn stdout_layer<S>(filter: EnvFilter) -> impl Layer<S>
+where
+    S: Subscriber + for<'a> LookupSpan<'a> + Send + Sync + 'static,
+{
+    let (filter, handle) = reload::Layer::new(filter);
+    let _ = FILTER_RELOADER.set(Box::new(move |filter| handle.reload(filter)));
+
+    let stdout_layer = tracing_subscriber::fmt::layer()
+        .compact()
+        .with_writer(io::stdout);
+
+    let stream_layer = tracing_subscriber::fmt::layer()
+        .compact()
+        .with_ansi(false)
+        .with_writer(LogStreamWriter)
+        .with_filter(filter_fn(|_| HAS_LOG_SUBSCRIBERS.load(Ordering::Relaxed)));
+
+    stdout_layer.and_then(stream_layer).with_filter(filter)
+}
+
+/// Replaces the stdout log filter with one parsed from `directives`,
+/// e.g. `"warn,pinnacle::render=trace"`.
+pub fn set_filter(directives: &str) -> anyhow::Result<()> {
+    let filter = EnvFilter::try_new(directives)?;
+
+    let reloader = FILTER_RELOADER
+        .get()
+        .ok_or_else(|| anyhow::anyhow!("the log filter is not reloadable"))?;
+
+    reloader(filter)?;
+
+    Ok(())
+}
+
+/// Returns a receiver of every line logged to stdout from now on.
+pub fn subscribe() -> UnboundedReceiver<String> {
+    let (sender, receiver) = unbounded_channel();
+
+    let mut subscribers = LOG_SUBSCRIBERS.lock().unwrap();
+    subscribers.push(sender);
+    HAS_LOG_SUBSCRIBERS.store(true, Ordering::Relaxed);
+
+    receiver
+}
+
+/// Sends formatted log lines to [`LOG_SUBSCRIBERS`].
+///
+/// The fmt layer writes each event with a single `write`.
+struct LogStreamWriter;
+
+impl<'a> MakeWriter<'a> for LogStreamWriter {
+    type Writer = LogStreamWriter;
+
+    fn make_writer(&'a self) -> Self::Writer {
+        LogStreamWriter
+    }
+}
+
+impl io::Write for LogStreamWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let line = String::from_utf8_lossy(buf);
+        let line = line.trim_end_matches('\n');
+
+        let mut subscribers = LOG_SUBSCRIBERS.lock().unwrap();
+        subscribers.retain(|sender| sender.send(line.to_string()).is_ok());
+        HAS_LOG_SUBSCRIBERS.store(!subscribers.is_empty(), Ordering::Relaxed);
+
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
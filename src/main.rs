@@ -3,6 +3,7 @@
 use std::{
     env,
     io::{BufRead, BufReader},
+    path::Path,
     sync::atomic::Ordering,
     time::Duration,
 };
@@ -15,7 +16,9 @@ use pinnacle::{
         start_lua_repl,
     },
     config::{StartupConfig, get_config_dir, parse_startup_config},
+    logging,
     process::{REMOVE_RUST_BACKTRACE, REMOVE_RUST_LIB_BACKTRACE},
+    restart::{self, RESTORE_SESSION_ENV},
     session::{import_environment, notify_fd},
     state::State,
     util::increase_nofile_rlimit,
@@ -36,6 +39,10 @@ static GLOBAL_ALLOC: tracy_client::ProfiledAllocator<std::alloc::System> =
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
+    // Restarting passes these on as they were, as they're changed below
+    let display_vars =
+        ["WAYLAND_DISPLAY", "WAYLAND_SOCKET", "DISPLAY"].map(|key| (key, env::var_os(key)));
+
     if env::var_os("RUST_BACKTRACE").is_none() {
         // SAFETY: All set_vars occur on the event loop thread
         unsafe {
@@ -77,10 +84,7 @@ async fn main() -> anyhow::Result<()> {
 
     let stdout_env_filter =
         env_filter.unwrap_or_else(|_| EnvFilter::new("warn,pinnacle=info,snowcap=info,sctk=error"));
-    let stdout_layer = tracing_subscriber::fmt::layer()
-        .compact()
-        .with_writer(std::io::stdout)
-        .with_filter(stdout_env_filter);
+    let stdout_layer = logging::stdout_layer(stdout_env_filter);
 
     tracing_subscriber::registry()
         .with(file_log_layer)
@@ -225,6 +229,14 @@ async fn main() -> anyhow::Result<()> {
         env::set_var("WAYLAND_DISPLAY", &state.pinnacle.socket_name);
     }
 
+    if let Some(session_file) = env::var_os(RESTORE_SESSION_ENV) {
+        // SAFETY: All remove_vars occur on the event loop thread
+        unsafe {
+            env::remove_var(RESTORE_SESSION_ENV);
+        }
+        state.pinnacle.restore_session(Path::new(&session_file));
+    }
+
     state
         .pinnacle
         .start_grpc_server(&startup_config.socket_dir.clone())?;
@@ -273,8 +285,11 @@ async fn main() -> anyhow::Result<()> {
 
     if session {
         import_environment();
+        state.pinnacle.start_portal();
     }
 
+    state.pinnacle.start_power_monitor();
+
     if let Err(err) = sd_notify::notify(true, &[sd_notify::NotifyState::Ready]) {
         warn!("Error notifying systemd: {err}");
     }
@@ -293,6 +308,15 @@ async fn main() -> anyhow::Result<()> {
         state.on_event_loop_cycle_completion();
     })?;
 
+    if state.pinnacle.restart_state.requested {
+        // Release the session and devices so the new instance can take them over
+        drop(state);
+        drop(event_loop);
+
+        let err = restart::exec(&base_dirs, &display_vars);
+        error!("Failed to restart: {err:#}");
+    }
+
     Ok(())
 }
 
@@ -0,0 +1,290 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Latency and frame timing metrics.
+//!
+//! Metrics are always collected; they are cheap enough that users debugging stutter don't need
+//! to restart with anything enabled. They can be queried through the API or exported in the
+//! Prometheus text format on a Unix socket.
+
+use std::{
+    collections::HashMap,
+    fmt::Write as _,
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+use smithay::output::{Output, WeakOutput};
+use tokio::io::AsyncWriteExt;
+use tracing::{info, warn};
+
+use crate::{api::StateFnSender, state::State};
+
+/// Upper bounds of histogram buckets, in milliseconds.
+///
+/// Anything slower falls into an implicit `+Inf` bucket.
+pub const BUCKET_BOUNDS_MS: [f64; 11] = [
+    1.0, 2.0, 4.0, 8.0, 16.0, 33.0, 50.0, 100.0, 250.0, 500.0, 1000.0,
+];
+
+/// Inputs older than this when a frame is queued are assumed to not have caused that frame.
+const MAX_INPUT_LATENCY: Duration = Duration::from_secs(1);
+
+/// A histogram of durations.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Histogram {
+    /// The number of observations in each bucket of [`BUCKET_BOUNDS_MS`], followed by the
+    /// number of observations larger than every bound.
+    ///
+    /// These are not cumulative.
+    pub counts: [u64; BUCKET_BOUNDS_MS.len() + 1],
+    /// The sum of all observations.
+    pub sum: Duration,
+    /// The number of observations.
+    pub count: u64,
+}
+
+impl Histogram {
+    /// Records a duration.
+    pub fn observe(&mut self, duration: Duration) {
+        let ms = duration.as_secs_f64() * 1000.0;
+        let bucket = BUCKET_BOUNDS_MS
+            .iter()
+            .position(|bound| ms <= *bound)
+            .unwrap_or(BUCKET_BOUNDS_MS.len());
+
+        self.counts[bucket] += 1;
+        self.sum += duration;
+        self.count += 1;
+    }
+
+    fn write_prometheus(&self, out: &mut String, name: &str, help: &str) {
+        let _ = writeln!(out, "# HELP {name} {help}");
+        let _ = writeln!(out, "# TYPE {name} histogram");
+
+        let mut cumulative = 0;
+        for (bound, count) in BUCKET_BOUNDS_MS.iter().zip(self.counts) {
+            cumulative += count;
+            let _ = writeln!(
+                out,
+                "{name}_bucket{{le=\"{}\"}} {cumulative}",
+                bound / 1000.0
+            );
+        }
+        let _ = writeln!(out, "{name}_bucket{{le=\"+Inf\"}} {}", self.count);
+        let _ = writeln!(out, "{name}_sum {}", self.sum.as_secs_f64());
+        let _ = writeln!(out, "{name}_count {}", self.count);
+    }
+}
+
+/// Collected metrics.
+#[derive(Debug, Default)]
+pub struct Metrics {
+    /// Time from an input event to the presentation of the first frame queued after it.
+    pub input_latency: Histogram,
+    /// Time from sending a layout request to applying the returned layout.
+    pub layout_time: Histogram,
+    /// The number of vblanks queued frames missed.
+    pub missed_frames: u64,
+
+    /// The time of the oldest input event not yet followed by a frame.
+    pending_input: Option<Duration>,
+    /// The latest layout request sent for each output.
+    layout_requests: HashMap<WeakOutput, (u32, Instant)>,
+}
+
+impl Metrics {
+    /// Notes that an input event was received at `time`.
+    pub fn input_received(&mut self, time: Duration) {
+        self.pending_input.get_or_insert(time);
+    }
+
+    /// Takes the time of the oldest input event that a frame queued at `now` could
+    /// have been caused by.
+    pub fn take_pending_input(&mut self, now: Duration) -> Option<Duration> {
+        self.pending_input
+            .take()
+            .filter(|time| now.saturating_sub(*time) <= MAX_INPUT_LATENCY)
+    }
+
+    /// Records the input latency of a frame that was queued with the given input time.
+    pub fn frame_presented(&mut self, input_time: Option<Duration>, presentation_time: Duration) {
+        if let Some(input_time) = input_time
+            && presentation_time >= input_time
+        {
+            self.input_latency.observe(presentation_time - input_time);
+        }
+    }
+
+    /// Notes that a layout request was sent for `output`.
+    pub fn layout_requested(&mut self, output: &Output, request_id: u32) {
+        self.layout_requests
+            .insert(output.downgrade(), (request_id, Instant::now()));
+    }
+
+    /// Records the layout time of a layout for `output`.
+    ///
+    /// Layouts for requests that were superseded are ignored.
+    pub fn layout_applied(&mut self, output: &Output, request_id: u32) {
+        let Some((id, requested_at)) = self.layout_requests.get(&output.downgrade()).copied()
+        else {
+            return;
+        };
+
+        if id == request_id {
+            self.layout_requests.remove(&output.downgrade());
+            self.layout_time.observe(requested_at.elapsed());
+        }
+    }
+
+    /// Formats these metrics in the Prometheus text exposition format.
+    pub fn to_prometheus(&self) -> String {
+        let mut out = String::new();
+
+        self.input_latency.write_prometheus(
+            &mut out,
+            "pinnacle_input_latency_seconds",
+            "Time from an input event to the presentation of the next frame.",
+        );
+        self.layout_time.write_prometheus(
+            &mut out,
+            "pinnacle_layout_time_seconds",
+            "Time from a layout request to the layout being applied.",
+        );
+
+        let _ = writeln!(
+            out,
+            "# HELP pinnacle_missed_frames_total Vblanks missed by queued frames."
+        );
+        let _ = writeln!(out, "# TYPE pinnacle_missed_frames_total counter");
+        let _ = writeln!(out, "pinnacle_missed_frames_total {}", self.missed_frames);
+
+        out
+    }
+}
+
+/// A Unix socket that serves [`Metrics`] in the Prometheus text format.
+///
+/// Every connection receives the current metrics and is then closed.
+/// The socket is removed when this is dropped.
+#[derive(Debug)]
+pub struct MetricsExport {
+    socket_path: PathBuf,
+    join_handle: tokio::task::JoinHandle<()>,
+}
+
+impl MetricsExport {
+    /// Starts serving metrics on a socket in `socket_dir`.
+    pub fn start(socket_dir: &Path, sender: StateFnSender) -> std::io::Result<Self> {
+        let socket_path = socket_dir.join(format!("pinnacle-metrics-{}.sock", std::process::id()));
+
+        if let Ok(true) = socket_path.try_exists() {
+            std::fs::remove_file(&socket_path)?;
+        }
+
+        let listener = tokio::net::UnixListener::bind(&socket_path)?;
+
+        let join_handle = tokio::spawn(async move {
+            loop {
+                let mut stream = match listener.accept().await {
+                    Ok((stream, _)) => stream,
+                    Err(err) => {
+                        warn!("Failed to accept metrics connection: {err}");
+                        continue;
+                    }
+                };
+
+                let (text_sender, text_receiver) = tokio::sync::oneshot::channel::<String>();
+                let sent = sender.send(Box::new(move |state: &mut State| {
+                    let _ = text_sender.send(state.pinnacle.metrics.to_prometheus());
+                }));
+                if sent.is_err() {
+                    break;
+                }
+
+                tokio::spawn(async move {
+                    let Ok(text) = text_receiver.await else {
+                        return;
+                    };
+                    if let Err(err) = stream.write_all(text.as_bytes()).await {
+                        warn!("Failed to write metrics: {err}");
+                    }
+                    let _ = stream.shutdown().await;
+                });
+            }
+        });
+
+        info!("Exporting metrics at {}", socket_path.display());
+
+        Ok(Self {
+            socket_path,
+            join_handle,
+        })
+    }
+
+    /// Returns the path of the socket metrics are served on.
+    pub fn socket_path(&self) -> &Path {
+        &self.socket_path
+    }
+}
+
+impl Drop for MetricsExport {
+    fn drop(&mut self) {
+        self.join_handle.abort();
+        let _ = std::fs::remove_file(&self.socket_path);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn histogram_buckets_observations() {
+        let mut histogram = Histogram::default();
+
+        histogram.observe(Duration::from_micros(500));
+        histogram.observe(Duration::from_millis(16));
+        histogram.observe(Duration::from_millis(17));
+        histogram.observe(Duration::from_secs(2));
+
+        assert_eq!(histogram.counts[0], 1);
+        assert_eq!(histogram.counts[4], 1);
+        assert_eq!(histogram.counts[5], 1);
+        assert_eq!(histogram.counts[BUCKET_BOUNDS_MS.len()], 1);
+        assert_eq!(histogram.count, 4);
+        assert_eq!(histogram.sum, Duration::from_micros(2_033_500));
+    }
+
+    #[test]
+    fn prometheus_buckets_are_cumulative() {
+        let mut metrics = Metrics::default();
+        metrics.layout_time.observe(Duration::from_millis(3));
+        metrics.layout_time.observe(Duration::from_millis(40));
+        metrics.missed_frames = 2;
+
+        let text = metrics.to_prometheus();
+
+        assert!(text.contains("pinnacle_layout_time_seconds_bucket{le=\"0.004\"} 1\n"));
+        assert!(text.contains("pinnacle_layout_time_seconds_bucket{le=\"0.05\"} 2\n"));
+        assert!(text.contains("pinnacle_layout_time_seconds_bucket{le=\"+Inf\"} 2\n"));
+        assert!(text.contains("pinnacle_layout_time_seconds_count 2\n"));
+        assert!(text.contains("pinnacle_input_latency_seconds_count 0\n"));
+        assert!(text.contains("pinnacle_missed_frames_total 2\n"));
+    }
+
+    #[test]
+    fn stale_inputs_are_dropped() {
+        let mut metrics = Metrics::default();
+
+        metrics.input_received(Duration::from_secs(1));
+        metrics.input_received(Duration::from_secs(2));
+        assert_eq!(
+            metrics.take_pending_input(Duration::from_millis(1500)),
+            Some(Duration::from_secs(1))
+        );
+
+        metrics.input_received(Duration::from_secs(1));
+        assert_eq!(metrics.take_pending_input(Duration::from_secs(3)), None);
+        assert_eq!(metrics.take_pending_input(Duration::from_secs(3)), None);
+    }
+}
@@ -0,0 +1,431 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! The night light.
+//!
+//! While the night light is on, output gamma ramps are tinted to a warmer color temperature.
+//! It turns on and off on a schedule of local times or of sunset and sunrise at a location,
+//! fading between temperatures instead of switching at once.
+
+use std::{f64::consts::TAU, time::Duration};
+
+use smithay::{
+    output::Output,
+    reexports::calloop::{
+        LoopHandle, RegistrationToken,
+        timer::{TimeoutAction, Timer},
+    },
+};
+use tracing::warn;
+
+use crate::{backend::Backend, state::State};
+
+/// The color temperature of outputs without the night light, in Kelvin.
+pub const NEUTRAL_TEMPERATURE: u32 = 6500;
+
+const MINUTES_PER_DAY: f64 = 24.0 * 60.0;
+
+/// How often the temperature is updated while fading.
+const TRANSITION_INTERVAL: Duration = Duration::from_secs(1);
+/// How often the schedule is checked otherwise.
+const IDLE_INTERVAL: Duration = Duration::from_secs(30);
+
+/// When the night light turns on or off.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScheduleTime {
+    /// A local time of day, in minutes after midnight.
+    Clock(u32),
+    Sunrise,
+    Sunset,
+}
+
+/// A place on Earth, in degrees.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Location {
+    pub latitude: f64,
+    pub longitude: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NightlightSchedule {
+    pub start: ScheduleTime,
+    pub end: ScheduleTime,
+    /// The color temperature while the night light is on, in Kelvin.
+    pub temperature: u32,
+    /// How long fading between temperatures takes.
+    pub transition: Duration,
+    /// Where sunrise and sunset are calculated for.
+    pub location: Option<Location>,
+}
+
+#[derive(Debug, Default)]
+pub struct NightlightState {
+    schedule: Option<NightlightSchedule>,
+    timer: Option<RegistrationToken>,
+    /// The temperature last applied to outputs.
+    applied_temperature: Option<u32>,
+}
+
+impl NightlightState {
+    pub fn schedule(&self) -> Option<&NightlightSchedule> {
+        self.schedule.as_ref()
+    }
+
+    /// Returns the temperature last applied to outputs.
+    pub fn applied_temperature(&self) -> Option<u32> {
+        self.applied_temperature
+    }
+
+    fn stop_timer(&mut self, loop_handle: &LoopHandle<'static, State>) {
+        if let Some(timer) = self.timer.take() {
+            loop_handle.remove(timer);
+        }
+    }
+}
+
+impl State {
+    /// Sets the night light schedule, or disables the night light with `None`.
+    pub fn set_nightlight_schedule(&mut self, schedule: Option<NightlightSchedule>) {
+        let _span = tracy_client::span!("State::set_nightlight_schedule");
+
+        let nightlight_state = &mut self.pinnacle.nightlight_state;
+        nightlight_state.stop_timer(&self.pinnacle.loop_handle);
+        nightlight_state.schedule = schedule;
+
+        if schedule.is_none() {
+            self.apply_nightlight_temperature(NEUTRAL_TEMPERATURE);
+            return;
+        }
+
+        let interval = self.update_nightlight();
+
+        let timer = self
+            .pinnacle
+            .loop_handle
+            .insert_source(Timer::from_duration(interval), |_, _, state| {
+                if state.pinnacle.nightlight_state.schedule.is_none() {
+                    state.pinnacle.nightlight_state.timer = None;
+                    return TimeoutAction::Drop;
+                }
+                TimeoutAction::ToDuration(state.update_nightlight())
+            })
+            .expect("failed to insert night light timer");
+
+        self.pinnacle.nightlight_state.timer = Some(timer);
+    }
+
+    /// Applies the scheduled temperature for the current time.
+    ///
+    /// Returns how long until the temperature should be updated again.
+    fn update_nightlight(&mut self) -> Duration {
+        let Some(schedule) = self.pinnacle.nightlight_state.schedule else {
+            return IDLE_INTERVAL;
+        };
+
+        let Some(now) = LocalTime::now() else {
+            warn!("Failed to get the local time, the night light will not be updated");
+            return IDLE_INTERVAL;
+        };
+
+        let (temperature, transitioning) = scheduled_temperature(&schedule, &now);
+        self.apply_nightlight_temperature(temperature);
+
+        if transitioning {
+            TRANSITION_INTERVAL
+        } else {
+            IDLE_INTERVAL
+        }
+    }
+
+    /// Sets the gamma ramps of all outputs to the given color temperature.
+    ///
+    /// Outputs with a gamma control client are skipped.
+    fn apply_nightlight_temperature(&mut self, temperature: u32) {
+        let nightlight_state = &mut self.pinnacle.nightlight_state;
+
+        // Reapply while the night light is on to catch new outputs and released gamma controls
+        if temperature == NEUTRAL_TEMPERATURE
+            && nightlight_state
+                .applied_temperature
+                .is_none_or(|applied| applied == NEUTRAL_TEMPERATURE)
+        {
+            nightlight_state.applied_temperature = Some(temperature);
+            return;
+        }
+
+        nightlight_state.applied_temperature = Some(temperature);
+
+        let Backend::Udev(udev) = &mut self.backend else {
+            return;
+        };
+
+        let outputs = self
+            .pinnacle
+            .outputs
+            .iter()
+            .filter(|output| {
+                !self
+                    .pinnacle
+                    .gamma_control_manager_state
+                    .has_gamma_control(output)
+            })
+            .cloned()
+            .collect::<Vec<Output>>();
+
+        for output in outputs {
+            let result = if temperature == NEUTRAL_TEMPERATURE {
+                udev.set_gamma(&output, None)
+            } else {
+                match udev.gamma_size(&output) {
+                    // Setting gamma is not supported
+                    Ok(0) => continue,
+                    Ok(size) => {
+                        let [r, g, b] = gamma_ramps(temperature, size as usize);
+                        udev.set_gamma(&output, Some([&r, &g, &b]))
+                    }
+                    Err(err) => Err(err),
+                }
+            };
+
+            if let Err(err) = result {
+                warn!(
+                    "Failed to set night light gamma for output {}: {err}",
+                    output.name()
+                );
+            }
+        }
+    }
+}
+
+/// The current local date and time.
+#[derive(Debug, Clone, Copy)]
+struct LocalTime {
+    /// Minutes after local midnight.
+    minutes: f64,
+    /// The day of the year, starting at 0.
+    day_of_year: u32,
+    /// The offset from UTC, in minutes.
+    utc_offset: f64,
+}
+
+impl LocalTime {
+    fn now() -> Option<Self> {
+        // SAFETY: `localtime_r` only writes to `tm`, which lives for the whole call.
+        let tm = unsafe {
+            let time = libc::time(std::ptr::null_mut());
+            let mut tm = std::mem::zeroed::<libc::tm>();
+            if libc::localtime_r(&time, &mut tm).is_null() {
+                return None;
+            }
+            tm
+        };
+
+        Some(Self {
+            minutes: (tm.tm_hour * 60 + tm.tm_min) as f64 + tm.tm_sec as f64 / 60.0,
+            day_of_year: tm.tm_yday as u32,
+            utc_offset: tm.tm_gmtoff as f64 / 60.0,
+        })
+    }
+}
+
+/// Returns the temperature the schedule calls for at `now` and whether it's fading.
+fn scheduled_temperature(schedule: &NightlightSchedule, now: &LocalTime) -> (u32, bool) {
+    let start = resolve_time(schedule.start, schedule.location, now);
+    let end = resolve_time(schedule.end, schedule.location, now);
+
+    let since_start = (now.minutes - start).rem_euclid(MINUTES_PER_DAY);
+    let since_end = (now.minutes - end).rem_euclid(MINUTES_PER_DAY);
+    let night_length = (end - start).rem_euclid(MINUTES_PER_DAY);
+
+    let is_night = since_start < night_length;
+
+    let transition = schedule.transition.as_secs_f64() / 60.0;
+    let since_change = if is_night { since_start } else { since_end };
+    let progress = if transition > 0.0 {
+        (since_change / transition).min(1.0)
+    } else {
+        1.0
+    };
+
+    let (from, to) = if is_night {
+        (NEUTRAL_TEMPERATURE, schedule.temperature)
+    } else {
+        (schedule.temperature, NEUTRAL_TEMPERATURE)
+    };
+
+    let temperature = from as f64 + (to as f64 - from as f64) * progress;
+
+    (temperature.round() as u32, progress < 1.0)
+}
+
+/// Returns a schedule time in minutes after local midnight.
+///
+/// Sun events without a location fall back to 6:00 for sunrise and 18:00 for sunset.
+fn resolve_time(time: ScheduleTime, location: Option<Location>, now: &LocalTime) -> f64 {
+    let sunrise = match time {
+        ScheduleTime::Clock(minutes) => return minutes as f64,
+        ScheduleTime::Sunrise => true,
+        ScheduleTime::Sunset => false,
+    };
+
+    let Some(location) = location else {
+        return if sunrise { 6.0 * 60.0 } else { 18.0 * 60.0 };
+    };
+
+    let utc = sun_event_utc(sunrise, location, now.day_of_year);
+    (utc + now.utc_offset).rem_euclid(MINUTES_PER_DAY)
+}
+
+/// Returns when the sun rises or sets at `location`, in minutes after UTC midnight.
+///
+/// This uses NOAA's approximate equations. During polar night the sun "rises" and "sets"
+/// at noon, and during polar day at midnight.
+fn sun_event_utc(sunrise: bool, location: Location, day_of_year: u32) -> f64 {
+    let gamma = TAU / 365.0 * day_of_year as f64;
+
+    let equation_of_time = 229.18
+        * (0.000075 + 0.001868 * gamma.cos()
+            - 0.032077 * gamma.sin()
+            - 0.014615 * (2.0 * gamma).cos()
+            - 0.040849 * (2.0 * gamma).sin());
+
+    let declination = 0.006918 - 0.399912 * gamma.cos() + 0.070257 * gamma.sin()
+        - 0.006758 * (2.0 * gamma).cos()
+        + 0.000907 * (2.0 * gamma).sin()
+        - 0.002697 * (3.0 * gamma).cos()
+        + 0.00148 * (3.0 * gamma).sin();
+
+    let latitude = location.latitude.to_radians();
+
+    let cos_hour_angle = 90.833_f64.to_radians().cos() / (latitude.cos() * declination.cos())
+        - latitude.tan() * declination.tan();
+    let hour_angle = cos_hour_angle.clamp(-1.0, 1.0).acos().to_degrees();
+
+    let hour_angle = if sunrise { hour_angle } else { -hour_angle };
+
+    720.0 - 4.0 * (location.longitude + hour_angle) - equation_of_time
+}
+
+/// Returns the red, green, and blue gamma ramps for a color temperature.
+fn gamma_ramps(temperature: u32, size: usize) -> [Vec<u16>; 3] {
+    let color = temperature_color(temperature);
+    let neutral = temperature_color(NEUTRAL_TEMPERATURE);
+
+    let denom = size.saturating_sub(1).max(1) as f64;
+
+    std::array::from_fn(|channel| {
+        let factor = (color[channel] / neutral[channel]).min(1.0);
+        (0..size)
+            .map(|i| (0xFFFF as f64 * i as f64 / denom * factor) as u16)
+            .collect()
+    })
+}
+
+/// Approximates the RGB color of a black body at a temperature, each channel from 0 to 1.
+fn temperature_color(temperature: u32) -> [f64; 3] {
+    let temp = temperature.clamp(1000, 40000) as f64 / 100.0;
+
+    let red = if temp <= 66.0 {
+        255.0
+    } else {
+        329.698727446 * (temp - 60.0).powf(-0.1332047592)
+    };
+
+    let green = if temp <= 66.0 {
+        99.4708025861 * temp.ln() - 161.1195681661
+    } else {
+        288.1221695283 * (temp - 60.0).powf(-0.0755148492)
+    };
+
+    let blue = if temp >= 66.0 {
+        255.0
+    } else if temp <= 19.0 {
+        0.0
+    } else {
+        138.5177312231 * (temp - 10.0).ln() - 305.0447927307
+    };
+
+    [red, green, blue].map(|channel| channel.clamp(0.0, 255.0) / 255.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(minutes: f64) -> LocalTime {
+        LocalTime {
+            minutes,
+            day_of_year: 0,
+            utc_offset: 0.0,
+        }
+    }
+
+    fn schedule(transition_mins: u64) -> NightlightSchedule {
+        NightlightSchedule {
+            start: ScheduleTime::Clock(20 * 60),
+            end: ScheduleTime::Clock(7 * 60),
+            temperature: 4000,
+            transition: Duration::from_secs(transition_mins * 60),
+            location: None,
+        }
+    }
+
+    #[test]
+    fn schedule_wraps_past_midnight() {
+        let schedule = schedule(0);
+
+        assert_eq!(
+            scheduled_temperature(&schedule, &at(23.0 * 60.0)),
+            (4000, false)
+        );
+        assert_eq!(
+            scheduled_temperature(&schedule, &at(3.0 * 60.0)),
+            (4000, false)
+        );
+        assert_eq!(
+            scheduled_temperature(&schedule, &at(12.0 * 60.0)),
+            (NEUTRAL_TEMPERATURE, false)
+        );
+    }
+
+    #[test]
+    fn schedule_fades_between_temperatures() {
+        let schedule = schedule(60);
+
+        assert_eq!(
+            scheduled_temperature(&schedule, &at(20.5 * 60.0)),
+            (5250, true)
+        );
+        assert_eq!(
+            scheduled_temperature(&schedule, &at(7.5 * 60.0)),
+            (5250, true)
+        );
+        assert_eq!(
+            scheduled_temperature(&schedule, &at(21.0 * 60.0)),
+            (4000, false)
+        );
+    }
+
+    #[test]
+    fn sun_rises_before_it_sets() {
+        let location = Location {
+            latitude: 52.52,
+            longitude: 13.40,
+        };
+
+        let sunrise = sun_event_utc(true, location, 172);
+        let sunset = sun_event_utc(false, location, 172);
+
+        // Around 2:45 and 19:30 UTC in Berlin at midsummer
+        assert!((150.0..180.0).contains(&sunrise));
+        assert!((1155.0..1185.0).contains(&sunset));
+    }
+
+    #[test]
+    fn neutral_temperature_keeps_ramps_linear() {
+        let [r, g, b] = gamma_ramps(NEUTRAL_TEMPERATURE, 3);
+
+        assert_eq!(r, [0, 0x7FFF, 0xFFFF]);
+        assert_eq!(g, r);
+        assert_eq!(b, r);
+    }
+}
@@ -1,26 +1,45 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
 
-use std::cell::RefCell;
+pub mod auto_rotate;
+pub mod edid;
+
+use std::{cell::RefCell, collections::VecDeque, time::Duration};
 
 use indexmap::IndexSet;
 use smithay::{
     backend::renderer::damage::OutputDamageTracker,
     desktop::layer_map_for_output,
     output::{Mode, Output, Scale},
-    reexports::{drm, wayland_server::backend::GlobalId},
+    reexports::{
+        calloop::{
+            RegistrationToken,
+            timer::{TimeoutAction, Timer},
+        },
+        drm,
+        wayland_server::backend::GlobalId,
+    },
     utils::{Logical, Point, Size, Transform},
     wayland::session_lock::LockSurface,
 };
-use tracing::debug;
+use tracing::{debug, info};
 
 use crate::{
     api::signal::Signal,
     backend::BackendData,
     config::ConnectorSavedState,
+    handlers::session_lock::LockState,
     protocol::screencopy::Screencopy,
+    render::{
+        peek::TagPeek,
+        util::{
+            color_filter::ColorFilterPass,
+            effect::{AppliedInactiveEffect, ColorFilter},
+        },
+    },
     state::{Pinnacle, State, WithState},
     tag::Tag,
     util::centered_loc,
+    window::WindowElement,
 };
 
 /// A unique identifier for an output.
@@ -55,6 +74,53 @@ pub enum BlankingState {
     Blanked,
 }
 
+/// Rendering statistics for an output.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct RenderStats {
+    /// The number of frames that were rendered.
+    pub frames_rendered: u64,
+    /// The number of renders skipped because nothing changed.
+    pub frames_skipped_no_damage: u64,
+    /// The number of renders skipped because the output was powered off or blanked while
+    /// the session is locked.
+    pub frames_skipped_suspended: u64,
+}
+
+/// The number of bits per color channel an output is rendered and scanned out with.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub enum ColorDepth {
+    #[default]
+    Eight,
+    Ten,
+}
+
+impl ColorDepth {
+    /// Returns the color depth with `bits` bits per channel, if supported.
+    pub fn from_bits(bits: u32) -> Option<Self> {
+        match bits {
+            8 => Some(Self::Eight),
+            10 => Some(Self::Ten),
+            _ => None,
+        }
+    }
+
+    pub fn bits(self) -> u32 {
+        match self {
+            Self::Eight => 8,
+            Self::Ten => 10,
+        }
+    }
+}
+
+/// A mode change that is reverted unless it's confirmed in time.
+#[derive(Debug)]
+pub struct PendingModeChange {
+    /// The mode to go back to.
+    previous_mode: Mode,
+    /// The timer that reverts the change.
+    timer: RegistrationToken,
+}
+
 /// The state of an output
 #[derive(Debug)]
 pub struct OutputState {
@@ -76,6 +142,31 @@ pub struct OutputState {
     pub debug_damage_tracker: OutputDamageTracker,
     pub is_vrr_on: bool,
     pub is_vrr_on_demand: bool,
+    /// The inactive effect currently applied to windows on this output.
+    pub inactive_effect: Option<AppliedInactiveEffect>,
+    /// The color filter applied to everything on this output.
+    pub color_filter: Option<ColorFilter>,
+    /// The texture this output is rendered into while it has a color filter.
+    pub color_filter_pass: Option<ColorFilterPass>,
+    pub render_stats: RenderStats,
+    /// Snapshots of another tag's windows being shown over this output.
+    pub peek: Option<TagPeek>,
+    /// Whether this output is rotated to match the device's orientation.
+    pub auto_rotate: bool,
+    /// A mode change waiting to be confirmed.
+    pub pending_mode_change: Option<PendingModeChange>,
+    /// Previously active sets of tags, most recent last.
+    pub tag_history: VecDeque<Vec<Tag>>,
+    /// Properties parsed from the monitor's EDID, if it has one.
+    pub edid: Option<edid::EdidInfo>,
+    /// Whether the monitor and its connector support HDR output.
+    pub hdr_capable: bool,
+    /// Whether content is encoded for HDR on this output.
+    pub hdr: bool,
+    /// The color depth this output is scanned out with.
+    pub color_depth: ColorDepth,
+    /// Whether sRGB content is converted to the monitor's advertised primaries.
+    pub srgb_emulation: bool,
 }
 
 impl Default for OutputState {
@@ -95,6 +186,19 @@ impl Default for OutputState {
             ),
             is_vrr_on: false,
             is_vrr_on_demand: false,
+            inactive_effect: None,
+            color_filter: None,
+            color_filter_pass: None,
+            render_stats: RenderStats::default(),
+            peek: None,
+            auto_rotate: false,
+            pending_mode_change: None,
+            tag_history: VecDeque::new(),
+            edid: None,
+            hdr_capable: false,
+            hdr: false,
+            color_depth: ColorDepth::default(),
+            srgb_emulation: false,
         }
     }
 }
@@ -150,6 +254,17 @@ impl OutputState {
 
         self.tags.extend(new_tags);
     }
+
+    /// Returns whether rendering to this output should be skipped entirely.
+    ///
+    /// This is the case when the output is powered off or when it has been blanked
+    /// for a session lock and has no lock surface to show.
+    pub fn is_render_suspended(&self, lock_state: &LockState) -> bool {
+        !self.powered
+            || (lock_state.is_locked()
+                && self.lock_surface.is_none()
+                && matches!(self.blanking_state, BlankingState::Blanked))
+    }
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -355,6 +470,7 @@ impl Pinnacle {
         }
 
         self.outputs.retain(|op| op != output);
+        self.pager_state.mark_dirty();
 
         for layer in layer_map_for_output(output).layers() {
             layer.layer_surface().send_close();
@@ -373,6 +489,12 @@ impl Pinnacle {
 
         self.output_power_management_state.output_removed(output);
 
+        self.snowcap_thumbnail_state.output_removed(output);
+
+        if let Some(pending) = output.with_state_mut(|state| state.pending_mode_change.take()) {
+            self.loop_handle.remove(pending.timer);
+        }
+
         self.output_management_manager_state.remove_head(output);
         self.output_management_manager_state.update::<State>();
 
@@ -390,6 +512,185 @@ impl Pinnacle {
 
         self.layout_state.remove_output(output);
     }
+
+    /// Signals outputs whose topmost window became fullscreen or stopped being so.
+    pub fn process_fullscreen_signal(&mut self) {
+        let _span = tracy_client::span!("Pinnacle::process_fullscreen_signal");
+
+        for output in self.outputs.iter() {
+            let fullscreen_window = self
+                .space
+                .elements_for_output(output)
+                .rev()
+                .find(|win| !win.is_x11_override_redirect())
+                .filter(|win| win.with_state(|state| state.layout_mode.is_fullscreen()));
+
+            self.signal_state
+                .output_fullscreen_changed
+                .signal((output, fullscreen_window));
+        }
+    }
+
+    /// Returns the windows shown on `output`, from bottom to top.
+    pub fn stacking_order(&self, output: &Output) -> Vec<WindowElement> {
+        self.space
+            .elements_for_output(output)
+            .filter(|win| !win.is_x11_override_redirect())
+            .cloned()
+            .collect()
+    }
+
+    /// Signals outputs whose windows were restacked.
+    pub fn process_stacking_signal(&mut self) {
+        let _span = tracy_client::span!("Pinnacle::process_stacking_signal");
+
+        for output in self.outputs.iter() {
+            let window_ids = self
+                .stacking_order(output)
+                .iter()
+                .map(|win| win.with_state(|state| state.id.0))
+                .collect();
+
+            self.signal_state
+                .output_stacking_changed
+                .signal((output, window_ids));
+        }
+    }
+}
+
+impl State {
+    /// Sets `output`'s mode.
+    ///
+    /// If `revert_after` is set, the last confirmed mode is restored after that long
+    /// unless [`State::confirm_mode_change`] is called first. Otherwise, any pending
+    /// mode change is confirmed.
+    pub fn set_output_mode(
+        &mut self,
+        output: &Output,
+        mode: OutputMode,
+        revert_after: Option<Duration>,
+    ) {
+        // Changing the mode again before confirming still reverts to the mode
+        // from before the first change
+        let confirmed_mode = match output.with_state_mut(|state| state.pending_mode_change.take()) {
+            Some(pending) => {
+                self.pinnacle.loop_handle.remove(pending.timer);
+                Some(pending.previous_mode)
+            }
+            None => output.current_mode(),
+        };
+
+        self.apply_output_mode(output, mode);
+
+        let (Some(revert_after), Some(previous_mode)) = (revert_after, confirmed_mode) else {
+            return;
+        };
+
+        let weak_output = output.downgrade();
+        let timer = self
+            .pinnacle
+            .loop_handle
+            .insert_source(Timer::from_duration(revert_after), move |_, _, state| {
+                if let Some(output) = weak_output.upgrade() {
+                    state.revert_mode_change(&output);
+                }
+                TimeoutAction::Drop
+            })
+            .expect("failed to insert mode revert timer");
+
+        output.with_state_mut(|state| {
+            state.pending_mode_change = Some(PendingModeChange {
+                previous_mode,
+                timer,
+            });
+        });
+    }
+
+    /// Keeps `output`'s pending mode change from being reverted.
+    ///
+    /// Returns whether there was a pending mode change.
+    pub fn confirm_mode_change(&mut self, output: &Output) -> bool {
+        let Some(pending) = output.with_state_mut(|state| state.pending_mode_change.take()) else {
+            return false;
+        };
+
+        self.pinnacle.loop_handle.remove(pending.timer);
+        true
+    }
+
+    fn revert_mode_change(&mut self, output: &Output) {
+        let Some(pending) = output.with_state_mut(|state| state.pending_mode_change.take()) else {
+            return;
+        };
+
+        info!(
+            "Mode change for {} wasn't confirmed, reverting",
+            output.name()
+        );
+
+        self.apply_output_mode(output, OutputMode::Smithay(pending.previous_mode));
+    }
+
+    fn apply_output_mode(&mut self, output: &Output, mode: OutputMode) {
+        self.pinnacle
+            .change_output_state(&mut self.backend, output, Some(mode), None, None, None);
+        self.pinnacle.request_layout(output);
+        self.pinnacle
+            .output_management_manager_state
+            .update::<State>();
+    }
+
+    /// Switches `output` in or out of HDR mode.
+    ///
+    /// Returns an error if the output doesn't support HDR or the backend failed to
+    /// switch it.
+    pub fn set_output_hdr(&mut self, output: &Output, hdr: bool) -> Result<(), String> {
+        let (capable, enabled) = output.with_state(|state| (state.hdr_capable, state.hdr));
+
+        if hdr == enabled {
+            return Ok(());
+        }
+
+        if hdr && !capable {
+            return Err(format!("output {} doesn't support HDR", output.name()));
+        }
+
+        self.backend
+            .set_output_hdr(output, hdr)
+            .map_err(|err| format!("{err:#}"))?;
+
+        output.with_state_mut(|state| state.hdr = hdr);
+
+        self.backend.reset_buffers(output);
+        self.schedule_render(output);
+
+        Ok(())
+    }
+
+    /// Sets whether sRGB content on `output` is converted to the monitor's primaries.
+    ///
+    /// Returns an error if the monitor's EDID doesn't advertise its primaries.
+    pub fn set_output_srgb_emulation(
+        &mut self,
+        output: &Output,
+        srgb_emulation: bool,
+    ) -> Result<(), String> {
+        let primaries = output.with_state(|state| state.edid.and_then(|edid| edid.color_primaries));
+
+        if srgb_emulation && primaries.is_none() {
+            return Err(format!(
+                "output {} doesn't advertise its color primaries",
+                output.name()
+            ));
+        }
+
+        output.with_state_mut(|state| state.srgb_emulation = srgb_emulation);
+
+        self.backend.reset_buffers(output);
+        self.schedule_render(output);
+
+        Ok(())
+    }
 }
 
 /// Attempts to retrieve a known mode for the given output with the provided width and height.
@@ -0,0 +1,161 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Automatic output rotation on convertibles.
+//!
+//! The device's orientation is read from iio-sensor-proxy over the system bus.
+//! Outputs with auto-rotate enabled get the transform that keeps them upright.
+
+use smithay::{
+    output::Output,
+    reexports::calloop::{self, channel::Event},
+    utils::Transform,
+};
+use tokio_stream::StreamExt;
+use tracing::{info, warn};
+
+use crate::{
+    api::{StateFnSender, signal::Signal},
+    state::{Pinnacle, State, WithState},
+};
+
+#[zbus::proxy(
+    interface = "net.hadess.SensorProxy",
+    default_service = "net.hadess.SensorProxy",
+    default_path = "/net/hadess/SensorProxy"
+)]
+trait SensorProxy {
+    fn claim_accelerometer(&self) -> zbus::Result<()>;
+
+    #[zbus(property)]
+    fn has_accelerometer(&self) -> zbus::Result<bool>;
+
+    /// One of `normal`, `bottom-up`, `left-up`, `right-up`, or `undefined`.
+    #[zbus(property)]
+    fn accelerometer_orientation(&self) -> zbus::Result<String>;
+}
+
+#[derive(Debug, Default)]
+pub struct AutoRotateState {
+    /// Whether the accelerometer is being read.
+    started: bool,
+    /// The transform that keeps outputs upright in the device's current orientation,
+    /// or `None` if it isn't known.
+    pub orientation: Option<Transform>,
+}
+
+impl Pinnacle {
+    /// Starts reading the accelerometer if it isn't being read already.
+    pub fn start_auto_rotate(&mut self) {
+        if self.auto_rotate_state.started {
+            return;
+        }
+
+        self.auto_rotate_state.started = true;
+
+        let (fn_sender, fn_receiver) =
+            calloop::channel::channel::<Box<dyn FnOnce(&mut State) + Send>>();
+
+        self.loop_handle
+            .insert_source(fn_receiver, |msg, _, state| match msg {
+                Event::Msg(f) => f(state),
+                Event::Closed => (),
+            })
+            .expect("failed to insert auto-rotate receiver into loop");
+
+        tokio::spawn(async move {
+            if let Err(err) = watch_orientation(fn_sender).await {
+                warn!("Failed to read accelerometer orientation: {err}");
+            }
+        });
+    }
+}
+
+impl State {
+    /// Rotates `output` to match the device's orientation, if it's known.
+    pub fn auto_rotate_output(&mut self, output: &Output) {
+        if let Some(transform) = self.pinnacle.auto_rotate_state.orientation {
+            self.rotate_output(output, transform);
+        }
+    }
+
+    /// Updates the device's orientation and rotates all outputs with auto-rotate enabled.
+    fn set_orientation(&mut self, transform: Transform) {
+        if self.pinnacle.auto_rotate_state.orientation == Some(transform) {
+            return;
+        }
+
+        self.pinnacle.auto_rotate_state.orientation = Some(transform);
+
+        let outputs = self
+            .pinnacle
+            .outputs
+            .iter()
+            .filter(|output| output.with_state(|state| state.auto_rotate))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        for output in outputs {
+            self.rotate_output(&output, transform);
+        }
+    }
+
+    fn rotate_output(&mut self, output: &Output, transform: Transform) {
+        if output.current_transform() == transform {
+            return;
+        }
+
+        self.pinnacle.change_output_state(
+            &mut self.backend,
+            output,
+            None,
+            Some(transform),
+            None,
+            None,
+        );
+        self.pinnacle.request_layout(output);
+        self.schedule_render(output);
+        self.pinnacle
+            .output_management_manager_state
+            .update::<State>();
+
+        self.pinnacle
+            .signal_state
+            .output_orientation_changed
+            .signal((output, transform));
+    }
+}
+
+async fn watch_orientation(fn_sender: StateFnSender) -> zbus::Result<()> {
+    let connection = zbus::Connection::system().await?;
+    let proxy = SensorProxyProxy::new(&connection).await?;
+
+    if !proxy.has_accelerometer().await? {
+        info!("No accelerometer found, outputs will not auto-rotate");
+        return Ok(());
+    }
+
+    proxy.claim_accelerometer().await?;
+
+    let mut changes = proxy.receive_accelerometer_orientation_changed().await;
+
+    send_orientation(&fn_sender, &proxy.accelerometer_orientation().await?);
+
+    while let Some(change) = changes.next().await {
+        send_orientation(&fn_sender, &change.get().await?);
+    }
+
+    Ok(())
+}
+
+fn send_orientation(fn_sender: &StateFnSender, orientation: &str) {
+    // The screen is rotated the opposite way of the device to stay upright.
+    let transform = match orientation {
+        "normal" => Transform::Normal,
+        "left-up" => Transform::_90,
+        "bottom-up" => Transform::_180,
+        "right-up" => Transform::_270,
+        _ => return,
+    };
+
+    let _ = fn_sender.send(Box::new(move |state| state.set_orientation(transform)));
+}
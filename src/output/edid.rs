@@ -0,0 +1,93 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Display properties parsed from a monitor's EDID.
+//!
+//! Only the properties that go beyond make, model, and serial are kept here;
+//! those are stored in the output's physical properties.
+
+use libdisplay_info::info::Info;
+
+/// Chromaticity coordinates in the CIE 1931 color space.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Chromaticity {
+    pub x: f32,
+    pub y: f32,
+}
+
+/// The color primaries and white point a monitor advertises.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ColorPrimaries {
+    pub red: Chromaticity,
+    pub green: Chromaticity,
+    pub blue: Chromaticity,
+    pub white: Option<Chromaticity>,
+}
+
+/// HDR static metadata. Luminances are in cd/m², or `None` if not advertised.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct HdrMetadata {
+    pub max_luminance: Option<f32>,
+    pub max_frame_avg_luminance: Option<f32>,
+    pub min_luminance: Option<f32>,
+    /// Whether the SMPTE ST 2084 (PQ) transfer function is supported.
+    pub pq: bool,
+    /// Whether the hybrid log-gamma transfer function is supported.
+    pub hlg: bool,
+}
+
+/// Properties parsed from an EDID.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct EdidInfo {
+    pub manufacture_year: Option<u32>,
+    pub manufacture_week: Option<u32>,
+    pub color_primaries: Option<ColorPrimaries>,
+    pub hdr_metadata: Option<HdrMetadata>,
+}
+
+impl EdidInfo {
+    pub fn new(info: &Info) -> Self {
+        // A year of 0 means the EDID has a model year instead,
+        // and a week of 0 means the week is unspecified
+        let (manufacture_year, manufacture_week) = info
+            .edid()
+            .map(|edid| {
+                let vendor_product = edid.vendor_product();
+                let year = u32::try_from(vendor_product.manufacture_year).ok();
+                let week = u32::try_from(vendor_product.manufacture_week).ok();
+                (year.filter(|year| *year > 0), week.filter(|week| *week > 0))
+            })
+            .unwrap_or_default();
+
+        let primaries = info.default_color_primaries();
+        let chromaticity = |index: usize| Chromaticity {
+            x: primaries.primary[index].x,
+            y: primaries.primary[index].y,
+        };
+        let color_primaries = primaries.has_primaries.then(|| ColorPrimaries {
+            red: chromaticity(0),
+            green: chromaticity(1),
+            blue: chromaticity(2),
+            white: primaries.has_default_white_point.then_some(Chromaticity {
+                x: primaries.default_white.x,
+                y: primaries.default_white.y,
+            }),
+        });
+
+        let metadata = info.hdr_static_metadata();
+        let luminance = |luminance: f32| (luminance > 0.0).then_some(luminance);
+        let hdr_metadata = (metadata.pq || metadata.hlg).then(|| HdrMetadata {
+            max_luminance: luminance(metadata.desired_content_max_luminance),
+            max_frame_avg_luminance: luminance(metadata.desired_content_max_frame_avg_luminance),
+            min_luminance: luminance(metadata.desired_content_min_luminance),
+            pq: metadata.pq,
+            hlg: metadata.hlg,
+        });
+
+        Self {
+            manufacture_year,
+            manufacture_week,
+            color_primaries,
+            hdr_metadata,
+        }
+    }
+}
@@ -0,0 +1,70 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! An xdg-desktop-portal backend.
+//!
+//! Only the GlobalShortcuts portal is implemented.
+//! `resources/pinnacle.portal` tells xdg-desktop-portal about this backend.
+
+pub mod global_shortcuts;
+
+use smithay::reexports::calloop::{self, channel::Event};
+use tokio::sync::mpsc::UnboundedReceiver;
+use tracing::{error, info, warn};
+use zbus::object_server::SignalEmitter;
+
+use crate::{
+    api::StateFnSender,
+    state::{Pinnacle, State},
+};
+
+use self::global_shortcuts::{GlobalShortcutsPortal, ShortcutEvent};
+
+const BUS_NAME: &str = "org.freedesktop.impl.portal.desktop.pinnacle";
+const OBJECT_PATH: &str = "/org/freedesktop/portal/desktop";
+
+impl Pinnacle {
+    /// Starts serving the portal backend on the session bus.
+    pub fn start_portal(&mut self) {
+        let (fn_sender, fn_receiver) =
+            calloop::channel::channel::<Box<dyn FnOnce(&mut State) + Send>>();
+
+        self.loop_handle
+            .insert_source(fn_receiver, |msg, _, state| match msg {
+                Event::Msg(f) => f(state),
+                Event::Closed => warn!("portal receiver was closed"),
+            })
+            .expect("failed to insert portal receiver into loop");
+
+        let (event_sender, events) = tokio::sync::mpsc::unbounded_channel();
+        self.global_shortcuts_state.event_sender = Some(event_sender);
+
+        tokio::spawn(async move {
+            if let Err(err) = serve(fn_sender, events).await {
+                error!("Portal backend error: {err}");
+            }
+        });
+    }
+}
+
+async fn serve(
+    fn_sender: StateFnSender,
+    mut events: UnboundedReceiver<ShortcutEvent>,
+) -> zbus::Result<()> {
+    let connection = zbus::connection::Builder::session()?
+        .name(BUS_NAME)?
+        .serve_at(OBJECT_PATH, GlobalShortcutsPortal::new(fn_sender))?
+        .build()
+        .await?;
+
+    info!("Portal backend started as {BUS_NAME}");
+
+    let emitter = SignalEmitter::new(&connection, OBJECT_PATH)?;
+
+    while let Some(event) = events.recv().await {
+        if let Err(err) = global_shortcuts::emit(&emitter, event).await {
+            warn!("Failed to emit global shortcut signal: {err}");
+        }
+    }
+
+    Ok(())
+}
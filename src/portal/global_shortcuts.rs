@@ -0,0 +1,722 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! The GlobalShortcuts portal, which lets apps bind shortcuts that trigger while they
+//! are unfocused, like push-to-talk.
+//!
+//! Apps need permission from the config before their shortcuts are bound.
+//! Once an app is allowed, it isn't asked again until Pinnacle restarts.
+
+use std::{
+    collections::{HashMap, HashSet},
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use async_channel::Sender;
+use smithay::input::keyboard::ModifiersState;
+use tokio::sync::{mpsc::UnboundedSender, oneshot};
+use tracing::{debug, warn};
+use xkbcommon::xkb::{self, Keysym};
+use zbus::{
+    Connection, fdo,
+    object_server::SignalEmitter,
+    zvariant::{ObjectPath, OwnedObjectPath, OwnedValue, Value},
+};
+
+use crate::{
+    api::StateFnSender,
+    input::bind::{Edge, ModMask},
+    state::State,
+};
+
+/// How long to wait for the config to allow or deny an app before denying it.
+const PERMISSION_TIMEOUT: Duration = Duration::from_secs(120);
+
+const RESPONSE_SUCCESS: u32 = 0;
+const RESPONSE_CANCELLED: u32 = 1;
+
+/// A shortcut an app wants to bind.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShortcutInfo {
+    /// The app-defined id of the shortcut.
+    pub id: String,
+    pub description: String,
+    /// The trigger the app would like, in the format of the XDG shortcuts spec.
+    pub preferred_trigger: Option<String>,
+}
+
+/// A key combination that activates a shortcut.
+#[derive(Debug, Clone, Copy)]
+pub struct Trigger {
+    pub mods: ModMask,
+    pub key: Keysym,
+}
+
+impl Trigger {
+    /// Parses a trigger in the format of the XDG shortcuts spec, like `CTRL+SHIFT+a`.
+    pub fn parse(trigger: &str) -> Option<Self> {
+        let mut parts = trigger.split('+').map(str::trim).collect::<Vec<_>>();
+        let key = parts.pop()?;
+
+        let mut mods = ModMask::new();
+        for modifier in parts {
+            match modifier.to_ascii_uppercase().as_str() {
+                "SHIFT" => mods.shift = Some(true),
+                "CTRL" | "CONTROL" => mods.ctrl = Some(true),
+                "ALT" => mods.alt = Some(true),
+                "LOGO" | "SUPER" => mods.super_ = Some(true),
+                // Num lock shouldn't change whether a shortcut triggers
+                "NUM" => (),
+                _ => return None,
+            }
+        }
+
+        let mut chars = key.chars();
+        let key = match (chars.next(), chars.next()) {
+            // Binds trigger on the unshifted key, so `A` would never trigger
+            (Some(ch), None) => Keysym::from_char(ch.to_ascii_lowercase()),
+            _ => xkb::keysym_from_name(key, xkb::KEYSYM_CASE_INSENSITIVE),
+        };
+
+        (key != Keysym::NoSymbol).then_some(Self { mods, key })
+    }
+
+    /// Returns a human-readable description of this trigger, like `Ctrl+Shift+a`.
+    pub fn describe(&self) -> String {
+        let mods = [
+            (self.mods.super_, "Super"),
+            (self.mods.ctrl, "Ctrl"),
+            (self.mods.alt, "Alt"),
+            (self.mods.shift, "Shift"),
+        ];
+
+        mods.into_iter()
+            .filter(|(held, _)| *held == Some(true))
+            .map(|(_, name)| name.to_string())
+            .chain([xkb::keysym_get_name(self.key)])
+            .collect::<Vec<_>>()
+            .join("+")
+    }
+}
+
+/// A shortcut bound by an app.
+#[derive(Debug, Clone)]
+pub struct BoundShortcut {
+    pub info: ShortcutInfo,
+    /// The trigger of this shortcut, or `None` if the preferred trigger
+    /// was missing or couldn't be parsed.
+    pub trigger: Option<Trigger>,
+}
+
+/// A shortcut being activated or deactivated.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShortcutEvent {
+    pub session_handle: String,
+    pub shortcut_id: String,
+    pub edge: Edge,
+    /// The time of the key event in milliseconds.
+    pub time: u32,
+}
+
+/// A request for the config to allow or deny an app binding shortcuts.
+#[derive(Debug, Clone)]
+pub struct PermissionRequest {
+    pub request_id: u32,
+    pub app_id: String,
+    pub shortcuts: Vec<ShortcutInfo>,
+}
+
+#[derive(Debug, Default)]
+pub struct GlobalShortcutsState {
+    /// Bound shortcuts keyed by the handle of the session that bound them.
+    sessions: HashMap<String, Vec<BoundShortcut>>,
+    /// Apps that were allowed to bind shortcuts.
+    allowed_apps: HashSet<String>,
+    /// The shortcuts activated by a pressed key, to deactivate when it's released.
+    activated: HashMap<Keysym, Vec<(String, String)>>,
+    permission_senders: Vec<Sender<PermissionRequest>>,
+    pending_permissions: HashMap<u32, (String, oneshot::Sender<bool>)>,
+    current_request_id: u32,
+    /// Sends activations to the portal to be emitted over D-Bus.
+    pub event_sender: Option<UnboundedSender<ShortcutEvent>>,
+}
+
+impl GlobalShortcutsState {
+    pub fn new_permission_sender(&mut self, sender: Sender<PermissionRequest>) {
+        self.permission_senders.push(sender);
+    }
+
+    /// Asks the config whether `app_id` may bind `shortcuts`.
+    ///
+    /// `responder` receives the decision. Apps are denied if no config is listening.
+    ///
+    /// Returns the id of the request if it is waiting on the config.
+    pub fn request_permission(
+        &mut self,
+        app_id: String,
+        shortcuts: Vec<ShortcutInfo>,
+        responder: oneshot::Sender<bool>,
+    ) -> Option<u32> {
+        if self.allowed_apps.contains(&app_id) {
+            let _ = responder.send(true);
+            return None;
+        }
+
+        let request_id = self.current_request_id;
+        self.current_request_id += 1;
+
+        let request = PermissionRequest {
+            request_id,
+            app_id: app_id.clone(),
+            shortcuts,
+        };
+
+        self.permission_senders
+            .retain(|sender| sender.send_blocking(request.clone()).is_ok());
+
+        if self.permission_senders.is_empty() {
+            warn!("Denying global shortcuts for `{app_id}`: the config isn't handling requests");
+            let _ = responder.send(false);
+            return None;
+        }
+
+        self.pending_permissions
+            .insert(request_id, (app_id, responder));
+
+        Some(request_id)
+    }
+
+    /// Drops a permission request that the config took too long to answer,
+    /// so a late answer doesn't allow the app.
+    pub fn cancel_request(&mut self, request_id: u32) {
+        if let Some((app_id, _)) = self.pending_permissions.remove(&request_id) {
+            debug!("Global shortcuts permission request {request_id} for `{app_id}` timed out");
+        }
+    }
+
+    /// Responds to a permission request.
+    pub fn respond(&mut self, request_id: u32, allow: bool) {
+        let Some((app_id, responder)) = self.pending_permissions.remove(&request_id) else {
+            debug!("Global shortcuts permission request {request_id} was already answered");
+            return;
+        };
+
+        // Apps that aren't sandboxed have no app id, so they can't be told apart
+        if allow && !app_id.is_empty() {
+            self.allowed_apps.insert(app_id);
+        }
+
+        let _ = responder.send(allow);
+    }
+
+    /// Binds shortcuts for a session, replacing any it bound before.
+    pub fn bind(
+        &mut self,
+        session_handle: String,
+        shortcuts: Vec<ShortcutInfo>,
+    ) -> Vec<BoundShortcut> {
+        let bound = shortcuts
+            .into_iter()
+            .map(|info| BoundShortcut {
+                trigger: info.preferred_trigger.as_deref().and_then(Trigger::parse),
+                info,
+            })
+            .collect::<Vec<_>>();
+
+        self.sessions.insert(session_handle, bound.clone());
+
+        bound
+    }
+
+    /// Returns the shortcuts bound by a session.
+    pub fn shortcuts(&self, session_handle: &str) -> Vec<BoundShortcut> {
+        self.sessions
+            .get(session_handle)
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    pub fn close_session(&mut self, session_handle: &str) {
+        self.sessions.remove(session_handle);
+    }
+
+    /// Activates or deactivates shortcuts triggered by a key.
+    ///
+    /// Returns whether the key should be suppressed (not sent to the client).
+    pub fn key(
+        &mut self,
+        key: Keysym,
+        mods: ModifiersState,
+        edge: Edge,
+        time: u32,
+        shortcuts_inhibited: bool,
+        is_locked: bool,
+    ) -> bool {
+        let shortcuts = match edge {
+            Edge::Press => {
+                if shortcuts_inhibited || is_locked {
+                    return false;
+                }
+
+                let shortcuts = self
+                    .sessions
+                    .iter()
+                    .flat_map(|(session_handle, shortcuts)| {
+                        shortcuts
+                            .iter()
+                            .filter(|shortcut| {
+                                shortcut.trigger.is_some_and(|trigger| {
+                                    trigger.key == key && trigger.mods.matches(mods)
+                                })
+                            })
+                            .map(|shortcut| (session_handle.clone(), shortcut.info.id.clone()))
+                    })
+                    .collect::<Vec<_>>();
+
+                if shortcuts.is_empty() {
+                    return false;
+                }
+
+                self.activated.insert(key, shortcuts.clone());
+                shortcuts
+            }
+            Edge::Release => {
+                let Some(shortcuts) = self.activated.remove(&key) else {
+                    return false;
+                };
+                shortcuts
+            }
+        };
+
+        if let Some(event_sender) = self.event_sender.as_ref() {
+            for (session_handle, shortcut_id) in shortcuts {
+                let _ = event_sender.send(ShortcutEvent {
+                    session_handle,
+                    shortcut_id,
+                    edge,
+                    time,
+                });
+            }
+        }
+
+        true
+    }
+}
+
+/// Runs `f` with the compositor state and returns its result.
+async fn with_state<T: Send + 'static>(
+    fn_sender: &StateFnSender,
+    f: impl FnOnce(&mut State) -> T + Send + 'static,
+) -> fdo::Result<T> {
+    let (sender, receiver) = oneshot::channel();
+
+    fn_sender
+        .send(Box::new(move |state| {
+            let _ = sender.send(f(state));
+        }))
+        .map_err(|_| fdo::Error::Failed("the compositor is shutting down".into()))?;
+
+    receiver
+        .await
+        .map_err(|_| fdo::Error::Failed("the compositor dropped the request".into()))
+}
+
+fn string_option(options: &HashMap<String, OwnedValue>, key: &str) -> Option<String> {
+    options
+        .get(key)
+        .and_then(|value| value.downcast_ref::<String>().ok())
+}
+
+fn shortcuts_results(shortcuts: &[BoundShortcut]) -> fdo::Result<HashMap<String, OwnedValue>> {
+    let shortcuts = shortcuts
+        .iter()
+        .map(|shortcut| {
+            let trigger_description = shortcut
+                .trigger
+                .as_ref()
+                .map(Trigger::describe)
+                .unwrap_or_default();
+
+            let options = HashMap::from([
+                (
+                    "description".to_string(),
+                    Value::from(shortcut.info.description.clone()),
+                ),
+                (
+                    "trigger_description".to_string(),
+                    Value::from(trigger_description),
+                ),
+            ]);
+
+            (shortcut.info.id.clone(), options)
+        })
+        .collect::<Vec<_>>();
+
+    let shortcuts = OwnedValue::try_from(Value::from(shortcuts))
+        .map_err(|err| fdo::Error::Failed(err.to_string()))?;
+
+    Ok(HashMap::from([("shortcuts".to_string(), shortcuts)]))
+}
+
+/// The `org.freedesktop.impl.portal.GlobalShortcuts` interface.
+pub struct GlobalShortcutsPortal {
+    fn_sender: StateFnSender,
+    /// The app ids of open sessions keyed by their handles.
+    sessions: Arc<Mutex<HashMap<OwnedObjectPath, String>>>,
+}
+
+impl GlobalShortcutsPortal {
+    pub fn new(fn_sender: StateFnSender) -> Self {
+        Self {
+            fn_sender,
+            sessions: Default::default(),
+        }
+    }
+}
+
+#[zbus::interface(name = "org.freedesktop.impl.portal.GlobalShortcuts")]
+impl GlobalShortcutsPortal {
+    async fn create_session(
+        &self,
+        _handle: OwnedObjectPath,
+        session_handle: OwnedObjectPath,
+        app_id: String,
+        _options: HashMap<String, OwnedValue>,
+        #[zbus(connection)] connection: &Connection,
+    ) -> fdo::Result<(u32, HashMap<String, OwnedValue>)> {
+        let session = Session {
+            handle: session_handle.clone(),
+            fn_sender: self.fn_sender.clone(),
+            sessions: self.sessions.clone(),
+        };
+
+        connection
+            .object_server()
+            .at(&session_handle, session)
+            .await?;
+
+        self.sessions.lock().unwrap().insert(session_handle, app_id);
+
+        Ok((RESPONSE_SUCCESS, HashMap::new()))
+    }
+
+    async fn bind_shortcuts(
+        &self,
+        _handle: OwnedObjectPath,
+        session_handle: OwnedObjectPath,
+        shortcuts: Vec<(String, HashMap<String, OwnedValue>)>,
+        _parent_window: String,
+        _options: HashMap<String, OwnedValue>,
+    ) -> fdo::Result<(u32, HashMap<String, OwnedValue>)> {
+        let Some(app_id) = self.sessions.lock().unwrap().get(&session_handle).cloned() else {
+            return Err(fdo::Error::InvalidArgs(format!(
+                "no session at {}",
+                session_handle.as_str()
+            )));
+        };
+
+        let shortcuts = shortcuts
+            .into_iter()
+            .map(|(id, options)| ShortcutInfo {
+                id,
+                description: string_option(&options, "description").unwrap_or_default(),
+                preferred_trigger: string_option(&options, "preferred_trigger"),
+            })
+            .collect::<Vec<_>>();
+
+        let (responder, decision) = oneshot::channel();
+        let request_id = with_state(&self.fn_sender, {
+            let shortcuts = shortcuts.clone();
+            move |state| {
+                state
+                    .pinnacle
+                    .global_shortcuts_state
+                    .request_permission(app_id, shortcuts, responder)
+            }
+        })
+        .await?;
+
+        let allowed = match tokio::time::timeout(PERMISSION_TIMEOUT, decision).await {
+            Ok(decision) => decision.unwrap_or(false),
+            Err(_) => {
+                if let Some(request_id) = request_id {
+                    with_state(&self.fn_sender, move |state| {
+                        state
+                            .pinnacle
+                            .global_shortcuts_state
+                            .cancel_request(request_id);
+                    })
+                    .await?;
+                }
+                false
+            }
+        };
+
+        if !allowed {
+            return Ok((RESPONSE_CANCELLED, HashMap::new()));
+        }
+
+        let session_handle = session_handle.as_str().to_string();
+        let bound = with_state(&self.fn_sender, move |state| {
+            state
+                .pinnacle
+                .global_shortcuts_state
+                .bind(session_handle, shortcuts)
+        })
+        .await?;
+
+        Ok((RESPONSE_SUCCESS, shortcuts_results(&bound)?))
+    }
+
+    async fn list_shortcuts(
+        &self,
+        _handle: OwnedObjectPath,
+        session_handle: OwnedObjectPath,
+    ) -> fdo::Result<(u32, HashMap<String, OwnedValue>)> {
+        let shortcuts = with_state(&self.fn_sender, move |state| {
+            state
+                .pinnacle
+                .global_shortcuts_state
+                .shortcuts(session_handle.as_str())
+        })
+        .await?;
+
+        Ok((RESPONSE_SUCCESS, shortcuts_results(&shortcuts)?))
+    }
+
+    #[zbus(signal)]
+    async fn activated(
+        emitter: &SignalEmitter<'_>,
+        session_handle: ObjectPath<'_>,
+        shortcut_id: &str,
+        timestamp: u64,
+        options: HashMap<&str, Value<'_>>,
+    ) -> zbus::Result<()>;
+
+    #[zbus(signal)]
+    async fn deactivated(
+        emitter: &SignalEmitter<'_>,
+        session_handle: ObjectPath<'_>,
+        shortcut_id: &str,
+        timestamp: u64,
+        options: HashMap<&str, Value<'_>>,
+    ) -> zbus::Result<()>;
+
+    #[zbus(property)]
+    fn version(&self) -> u32 {
+        1
+    }
+}
+
+/// Emits the D-Bus signal for a shortcut event.
+pub async fn emit(emitter: &SignalEmitter<'_>, event: ShortcutEvent) -> zbus::Result<()> {
+    let session_handle = ObjectPath::try_from(event.session_handle.as_str())?;
+    let timestamp = u64::from(event.time);
+
+    match event.edge {
+        Edge::Press => {
+            GlobalShortcutsPortal::activated(
+                emitter,
+                session_handle,
+                &event.shortcut_id,
+                timestamp,
+                HashMap::new(),
+            )
+            .await
+        }
+        Edge::Release => {
+            GlobalShortcutsPortal::deactivated(
+                emitter,
+                session_handle,
+                &event.shortcut_id,
+                timestamp,
+                HashMap::new(),
+            )
+            .await
+        }
+    }
+}
+
+/// The `org.freedesktop.impl.portal.Session` interface of a GlobalShortcuts session.
+struct Session {
+    handle: OwnedObjectPath,
+    fn_sender: StateFnSender,
+    sessions: Arc<Mutex<HashMap<OwnedObjectPath, String>>>,
+}
+
+#[zbus::interface(name = "org.freedesktop.impl.portal.Session")]
+impl Session {
+    async fn close(&self, #[zbus(connection)] connection: &Connection) {
+        self.sessions.lock().unwrap().remove(&self.handle);
+
+        let session_handle = self.handle.as_str().to_string();
+        let _ = self.fn_sender.send(Box::new(move |state| {
+            state
+                .pinnacle
+                .global_shortcuts_state
+                .close_session(&session_handle);
+        }));
+
+        // Removing this interface while one of its methods is running deadlocks
+        let connection = connection.clone();
+        let handle = self.handle.clone();
+        tokio::spawn(async move {
+            if let Err(err) = connection.object_server().remove::<Self, _>(&handle).await {
+                let handle = handle.as_str();
+                warn!("Failed to remove global shortcuts session {handle}: {err}");
+            }
+        });
+    }
+
+    #[zbus(property)]
+    fn version(&self) -> u32 {
+        1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn shortcut(id: &str, trigger: &str) -> ShortcutInfo {
+        ShortcutInfo {
+            id: id.to_string(),
+            description: String::new(),
+            preferred_trigger: Some(trigger.to_string()),
+        }
+    }
+
+    #[test]
+    fn trigger_parses_mods_and_key() {
+        let trigger = Trigger::parse("CTRL+SHIFT+a").unwrap();
+
+        assert_eq!(trigger.key, Keysym::a);
+        assert_eq!(trigger.mods.ctrl, Some(true));
+        assert_eq!(trigger.mods.shift, Some(true));
+        assert_eq!(trigger.mods.alt, Some(false));
+        assert_eq!(trigger.describe(), "Ctrl+Shift+a");
+    }
+
+    #[test]
+    fn trigger_lowercases_single_chars() {
+        assert_eq!(Trigger::parse("LOGO+P").unwrap().key, Keysym::p);
+        assert_eq!(Trigger::parse("F12").unwrap().key, Keysym::F12);
+    }
+
+    #[test]
+    fn trigger_rejects_unknown_mods_and_keys() {
+        assert!(Trigger::parse("HYPER+a").is_none());
+        assert!(Trigger::parse("CTRL+not_a_key").is_none());
+        assert!(Trigger::parse("").is_none());
+    }
+
+    #[test]
+    fn key_activates_and_deactivates_shortcuts() {
+        let (event_sender, mut events) = tokio::sync::mpsc::unbounded_channel();
+        let mut state = GlobalShortcutsState {
+            event_sender: Some(event_sender),
+            ..Default::default()
+        };
+
+        state.bind("/session".to_string(), vec![shortcut("talk", "CTRL+t")]);
+
+        let ctrl = ModifiersState {
+            ctrl: true,
+            ..Default::default()
+        };
+
+        assert!(!state.key(
+            Keysym::t,
+            ModifiersState::default(),
+            Edge::Press,
+            0,
+            false,
+            false
+        ));
+        assert!(state.key(Keysym::t, ctrl, Edge::Press, 1, false, false));
+        // Releasing ctrl first still deactivates the shortcut
+        assert!(state.key(
+            Keysym::t,
+            ModifiersState::default(),
+            Edge::Release,
+            2,
+            false,
+            false
+        ));
+
+        let event = |edge, time| ShortcutEvent {
+            session_handle: "/session".to_string(),
+            shortcut_id: "talk".to_string(),
+            edge,
+            time,
+        };
+
+        assert_eq!(events.try_recv(), Ok(event(Edge::Press, 1)));
+        assert_eq!(events.try_recv(), Ok(event(Edge::Release, 2)));
+        assert!(events.try_recv().is_err());
+    }
+
+    #[test]
+    fn key_does_nothing_when_locked() {
+        let mut state = GlobalShortcutsState::default();
+        state.bind("/session".to_string(), vec![shortcut("talk", "t")]);
+
+        assert!(!state.key(
+            Keysym::t,
+            ModifiersState::default(),
+            Edge::Press,
+            0,
+            false,
+            true
+        ));
+    }
+
+    #[test]
+    fn apps_are_denied_without_a_listening_config() {
+        let mut state = GlobalShortcutsState::default();
+        let (responder, mut decision) = oneshot::channel();
+
+        state.request_permission("app".to_string(), Vec::new(), responder);
+
+        assert_eq!(decision.try_recv(), Ok(false));
+    }
+
+    #[test]
+    fn allowed_apps_are_not_asked_again() {
+        let mut state = GlobalShortcutsState::default();
+        let (sender, receiver) = async_channel::unbounded();
+        state.new_permission_sender(sender);
+
+        let (responder, mut decision) = oneshot::channel();
+        state.request_permission("app".to_string(), Vec::new(), responder);
+        let request = receiver.try_recv().unwrap();
+        state.respond(request.request_id, true);
+        assert_eq!(decision.try_recv(), Ok(true));
+
+        let (responder, mut decision) = oneshot::channel();
+        state.request_permission("app".to_string(), Vec::new(), responder);
+        assert!(receiver.try_recv().is_err());
+        assert_eq!(decision.try_recv(), Ok(true));
+    }
+
+    #[test]
+    fn late_answers_to_cancelled_requests_are_ignored() {
+        let mut state = GlobalShortcutsState::default();
+        let (sender, receiver) = async_channel::unbounded();
+        state.new_permission_sender(sender);
+
+        let (responder, _decision) = oneshot::channel();
+        let request_id = state
+            .request_permission("app".to_string(), Vec::new(), responder)
+            .unwrap();
+        state.cancel_request(request_id);
+
+        let request = receiver.try_recv().unwrap();
+        state.respond(request.request_id, true);
+
+        // The app wasn't allowed, so it's asked about again
+        let (responder, _decision) = oneshot::channel();
+        state.request_permission("app".to_string(), Vec::new(), responder);
+        assert!(receiver.try_recv().is_ok());
+    }
+}
@@ -0,0 +1,157 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Battery and power supply status.
+//!
+//! The status is read from UPower over the system bus. Changes are sent to configs
+//! through the power signals.
+
+use smithay::reexports::calloop::{self, channel::Event};
+use tokio_stream::StreamExt;
+use tracing::info;
+
+use crate::{
+    api::{StateFnSender, signal::Signal},
+    state::{Pinnacle, State},
+};
+
+/// UPower's `Charging` device state.
+const DEVICE_STATE_CHARGING: u32 = 1;
+/// UPower's `Low` warning level. `Critical` and `Action` come after it.
+const WARNING_LEVEL_LOW: u32 = 3;
+
+#[zbus::proxy(
+    interface = "org.freedesktop.UPower",
+    default_service = "org.freedesktop.UPower",
+    default_path = "/org/freedesktop/UPower"
+)]
+trait UPower {
+    #[zbus(property)]
+    fn on_battery(&self) -> zbus::Result<bool>;
+}
+
+/// The composite device UPower uses to represent all batteries.
+#[zbus::proxy(
+    interface = "org.freedesktop.UPower.Device",
+    default_service = "org.freedesktop.UPower",
+    default_path = "/org/freedesktop/UPower/devices/DisplayDevice"
+)]
+trait DisplayDevice {
+    #[zbus(property)]
+    fn is_present(&self) -> zbus::Result<bool>;
+
+    #[zbus(property)]
+    fn state(&self) -> zbus::Result<u32>;
+
+    #[zbus(property)]
+    fn percentage(&self) -> zbus::Result<f64>;
+
+    #[zbus(property)]
+    fn warning_level(&self) -> zbus::Result<u32>;
+}
+
+/// The battery and power supply status of the system.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct PowerStatus {
+    /// Whether the system is running on battery power.
+    pub on_battery: bool,
+    /// Whether the battery is charging.
+    pub charging: bool,
+    /// The battery's charge from 0 to 100, or `None` if there is no battery.
+    pub percentage: Option<f64>,
+    /// Whether the battery is low.
+    pub low_battery: bool,
+}
+
+#[derive(Debug, Default)]
+pub struct PowerState {
+    pub status: PowerStatus,
+}
+
+impl Pinnacle {
+    /// Starts watching the power status through UPower.
+    pub fn start_power_monitor(&mut self) {
+        let (fn_sender, fn_receiver) =
+            calloop::channel::channel::<Box<dyn FnOnce(&mut State) + Send>>();
+
+        self.loop_handle
+            .insert_source(fn_receiver, |msg, _, state| match msg {
+                Event::Msg(f) => f(state),
+                Event::Closed => (),
+            })
+            .expect("failed to insert power receiver into loop");
+
+        tokio::spawn(async move {
+            if let Err(err) = watch_power(fn_sender).await {
+                info!("Not watching power status: {err}");
+            }
+        });
+    }
+}
+
+impl State {
+    /// Updates the power status, notifying configs of what changed.
+    pub fn set_power_status(&mut self, status: PowerStatus) {
+        let old = std::mem::replace(&mut self.pinnacle.power_state.status, status);
+        let signal_state = &mut self.pinnacle.signal_state;
+
+        if old.on_battery != status.on_battery {
+            signal_state.power_on_battery.signal(status.on_battery);
+        }
+
+        if old.charging != status.charging {
+            signal_state.power_charging.signal(status.charging);
+        }
+
+        if !old.low_battery && status.low_battery {
+            signal_state
+                .power_low_battery
+                .signal(status.percentage.unwrap_or_default());
+        }
+    }
+}
+
+async fn watch_power(fn_sender: StateFnSender) -> zbus::Result<()> {
+    let connection = zbus::Connection::system().await?;
+    let upower = UPowerProxy::new(&connection).await?;
+    let device = DisplayDeviceProxy::new(&connection).await?;
+
+    let changes = upower
+        .receive_on_battery_changed()
+        .await
+        .map(|_| ())
+        .merge(device.receive_is_present_changed().await.map(|_| ()))
+        .merge(device.receive_state_changed().await.map(|_| ()))
+        .merge(device.receive_percentage_changed().await.map(|_| ()))
+        .merge(device.receive_warning_level_changed().await.map(|_| ()));
+    let mut changes = std::pin::pin!(changes);
+
+    loop {
+        let status = read_status(&upower, &device).await?;
+        let _ = fn_sender.send(Box::new(move |state| state.set_power_status(status)));
+
+        if changes.next().await.is_none() {
+            return Ok(());
+        }
+    }
+}
+
+async fn read_status(
+    upower: &UPowerProxy<'_>,
+    device: &DisplayDeviceProxy<'_>,
+) -> zbus::Result<PowerStatus> {
+    let on_battery = upower.on_battery().await?;
+
+    if !device.is_present().await? {
+        return Ok(PowerStatus {
+            on_battery,
+            ..Default::default()
+        });
+    }
+
+    Ok(PowerStatus {
+        on_battery,
+        charging: device.state().await? == DEVICE_STATE_CHARGING,
+        percentage: Some(device.percentage().await?),
+        low_battery: device.warning_level().await? >= WARNING_LEVEL_LOW,
+    })
+}
@@ -0,0 +1,114 @@
+//! Compositor profiles.
+//!
+//! A profile is a named set of runtime options defined by the config, like a gaming
+//! profile that keeps the session from going idle and turns off the inactive effect.
+//! Setting a profile applies all of its options at once and saves the values they
+//! replaced, which are restored when the profile is unset or another one is set.
+
+use std::collections::HashMap;
+
+use crate::{render::util::effect::InactiveEffect, state::State};
+
+/// The runtime options a profile changes. Options that are `None` are left alone.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Profile {
+    /// Whether the session is kept from going idle.
+    pub inhibit_idle: Option<bool>,
+    /// Whether the inactive effect is turned off.
+    pub disable_inactive_effect: bool,
+    /// Whether unresponsive windows are greyed out.
+    pub grey_out_unresponsive: Option<bool>,
+    /// Whether fullscreen windows that don't fill their output are letterboxed.
+    pub letterbox_fullscreen: Option<bool>,
+}
+
+/// The options a profile replaced when it was set.
+#[derive(Debug, Clone)]
+struct SavedOptions {
+    inhibit_idle: bool,
+    inactive_effect: Option<InactiveEffect>,
+    grey_out_unresponsive: bool,
+    letterbox_fullscreen: bool,
+}
+
+#[derive(Debug, Default)]
+pub struct ProfileState {
+    /// Profiles defined by the config.
+    pub profiles: HashMap<String, Profile>,
+    /// The active profile along with the options it replaced.
+    active: Option<(String, SavedOptions)>,
+}
+
+impl ProfileState {
+    /// Returns the name of the active profile.
+    pub fn active(&self) -> Option<&str> {
+        self.active.as_ref().map(|(name, _)| name.as_str())
+    }
+}
+
+impl State {
+    /// Sets the active profile, restoring the options the previous one replaced.
+    ///
+    /// Returns an error if no profile called `name` was defined.
+    pub fn set_profile(&mut self, name: &str) -> Result<(), String> {
+        let Some(profile) = self.pinnacle.config.profiles.profiles.get(name).cloned() else {
+            return Err(format!("no profile named `{name}`"));
+        };
+
+        self.unset_profile();
+
+        let config = &mut self.pinnacle.config;
+        let saved = SavedOptions {
+            inhibit_idle: config.inhibit_idle,
+            inactive_effect: config.inactive_effect,
+            grey_out_unresponsive: config.grey_out_unresponsive,
+            letterbox_fullscreen: config.letterbox_fullscreen,
+        };
+        config.profiles.active = Some((name.to_string(), saved));
+
+        if let Some(inhibit_idle) = profile.inhibit_idle {
+            config.inhibit_idle = inhibit_idle;
+        }
+        if profile.disable_inactive_effect {
+            config.inactive_effect = None;
+        }
+        if let Some(grey_out_unresponsive) = profile.grey_out_unresponsive {
+            config.grey_out_unresponsive = grey_out_unresponsive;
+        }
+        if let Some(letterbox_fullscreen) = profile.letterbox_fullscreen {
+            config.letterbox_fullscreen = letterbox_fullscreen;
+        }
+
+        self.apply_profile_options();
+
+        Ok(())
+    }
+
+    /// Unsets the active profile, restoring the options it replaced.
+    pub fn unset_profile(&mut self) {
+        let config = &mut self.pinnacle.config;
+
+        let Some((_, saved)) = config.profiles.active.take() else {
+            return;
+        };
+
+        config.inhibit_idle = saved.inhibit_idle;
+        config.inactive_effect = saved.inactive_effect;
+        config.grey_out_unresponsive = saved.grey_out_unresponsive;
+        config.letterbox_fullscreen = saved.letterbox_fullscreen;
+
+        self.apply_profile_options();
+    }
+
+    /// Updates the state that doesn't pick up option changes on its own.
+    fn apply_profile_options(&mut self) {
+        self.pinnacle.refresh_idle_inhibit();
+        if self.pinnacle.config.inhibit_idle {
+            self.stop_screensaver();
+        }
+
+        for window in self.pinnacle.windows.clone() {
+            self.pinnacle.update_letterbox(&window);
+        }
+    }
+}
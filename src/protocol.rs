@@ -6,3 +6,6 @@ pub mod output_management;
 pub mod output_power_management;
 pub mod screencopy;
 pub mod snowcap_decoration;
+pub mod snowcap_thumbnail;
+pub mod xdg_toplevel_drag;
+pub mod xdg_toplevel_icon;
@@ -58,6 +58,8 @@ struct ExtWorkspaceGroupData {
 struct ExtWorkspaceData {
     id: String,
     name: String,
+    /// The position of the tag on its output.
+    index: u32,
     state: ext_workspace_handle_v1::State,
     instances: Vec<ExtWorkspaceHandleV1>,
     output: Output,
@@ -69,17 +71,25 @@ pub struct ExtWorkspaceGlobalData {
 
 fn tags_by_output<'a>(
     outputs: impl Iterator<Item = &'a Output> + 'a,
-) -> impl Iterator<Item = (Output, Tag)> {
+) -> impl Iterator<Item = (Output, u32, Tag)> {
     outputs.flat_map(|output| {
         output.with_state(|outp| {
             outp.tags
                 .clone()
                 .into_iter()
-                .map(|tag| (output.clone(), tag))
+                .enumerate()
+                .map(|(index, tag)| (output.clone(), index as u32, tag))
         })
     })
 }
 
+/// Encodes a workspace's coordinates in its group.
+///
+/// Tags are laid out in one dimension, in the order they were added to their output.
+fn coordinates(index: u32) -> Vec<u8> {
+    index.to_ne_bytes().to_vec()
+}
+
 pub fn on_output_bound(state: &mut State, output: &Output, wl_output: &WlOutput) {
     let Some(client) = wl_output.client() else {
         return;
@@ -117,7 +127,7 @@ pub fn refresh(state: &mut State) {
     let mut changed = false;
 
     let mut seen_tags = HashMap::new();
-    for (output, tag) in tags_by_output(state.pinnacle.outputs.iter()) {
+    for (output, _, tag) in tags_by_output(state.pinnacle.outputs.iter()) {
         seen_tags.insert(tag.id(), output);
     }
 
@@ -158,8 +168,8 @@ pub fn refresh(state: &mut State) {
     });
 
     // Update existing tags and create new ones.
-    for (output, tag) in tags_by_output(state.pinnacle.outputs.iter()) {
-        changed |= refresh_workspace(protocol_state, &output, &tag);
+    for (output, index, tag) in tags_by_output(state.pinnacle.outputs.iter()) {
+        changed |= refresh_workspace(protocol_state, &output, index, &tag);
     }
 
     // Update tag groups and create new ones, sending workspace_enter events as needed.
@@ -247,12 +257,22 @@ fn remove_workspace_instances(
 fn refresh_workspace(
     protocol_state: &mut ExtWorkspaceManagerState,
     output: &Output,
+    index: u32,
     tag: &Tag,
 ) -> bool {
     let mut state = ext_workspace_handle_v1::State::empty();
     if tag.active() {
         state |= ext_workspace_handle_v1::State::Active;
     }
+    if tag.urgent() {
+        state |= ext_workspace_handle_v1::State::Urgent;
+    }
+    // Defunct tags stick around after a config reload until the config adds them back
+    if tag.defunct() {
+        state |= ext_workspace_handle_v1::State::Hidden;
+    }
+
+    let name = tag.name();
 
     match protocol_state.tags.entry(tag.id()) {
         Entry::Occupied(entry) => {
@@ -265,6 +285,18 @@ fn refresh_workspace(
                 state_changed = true;
             }
 
+            let mut name_changed = false;
+            if data.name != name {
+                data.name = name;
+                name_changed = true;
+            }
+
+            let mut index_changed = false;
+            if data.index != index {
+                data.index = index;
+                index_changed = true;
+            }
+
             let mut output_changed = false;
             if &data.output != output {
                 send_workspace_enter_leave(&protocol_state.tag_groups, data, false);
@@ -279,21 +311,26 @@ fn refresh_workspace(
                 send_workspace_enter_leave(&protocol_state.tag_groups, data, true);
             }
 
-            if state_changed {
-                for instance in &data.instances {
-                    instance.id(data.id.clone());
+            for instance in &data.instances {
+                if name_changed {
                     instance.name(data.name.clone());
+                }
+                if index_changed {
+                    instance.coordinates(coordinates(data.index));
+                }
+                if state_changed {
                     instance.state(data.state);
                 }
             }
 
-            output_changed || state_changed
+            output_changed || state_changed || name_changed || index_changed
         }
         Entry::Vacant(entry) => {
             // New workspace, start tracking it.
             let mut data = ExtWorkspaceData {
                 id: tag.id().to_string(),
-                name: tag.name(),
+                name,
+                index,
                 state,
                 instances: Vec::new(),
                 output: output.clone(),
@@ -366,6 +403,7 @@ impl ExtWorkspaceData {
 
         workspace.id(self.id.clone());
         workspace.name(self.name.clone());
+        workspace.coordinates(coordinates(self.index));
         workspace.state(self.state);
         workspace.capabilities(
             ext_workspace_handle_v1::WorkspaceCapabilities::Activate
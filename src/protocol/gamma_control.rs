@@ -68,6 +68,11 @@ impl GammaControlManagerState {
     pub fn output_removed(&mut self, output: &Output) {
         self.gamma_controls.remove(&output.downgrade());
     }
+
+    /// Returns whether a client is controlling the gamma of `output`.
+    pub fn has_gamma_control(&self, output: &Output) -> bool {
+        self.gamma_controls.contains_key(&output.downgrade())
+    }
 }
 
 pub struct GammaControlState {
@@ -83,7 +83,7 @@ pub struct PendingOutputHeadConfiguration {
     pub adaptive_sync: Option<bool>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum OutputConfiguration {
     Disabled,
     Enabled {
@@ -84,7 +84,7 @@ impl DecorationSurfaceAttributes {
     }
 }
 
-#[derive(Clone, Copy, Default, Debug)]
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq)]
 pub struct Bounds {
     pub top: u32,
     pub bottom: u32,
@@ -0,0 +1,322 @@
+//! Implementation of the snowcap-thumbnail protocol.
+//!
+//! This lets clients capture downscaled images of outputs, tags, and windows,
+//! e.g. to show live thumbnails in a pager widget.
+
+use smithay::{
+    output::{Output, WeakOutput},
+    reexports::wayland_server::{
+        self, Client, DataInit, Dispatch, DisplayHandle, GlobalDispatch, backend::ClientId,
+        protocol::wl_buffer::WlBuffer,
+    },
+    utils::{Buffer, Logical, Size},
+};
+use snowcap_protocols::snowcap_thumbnail_v1::server::{
+    snowcap_thumbnail_manager_v1::{self, SnowcapThumbnailManagerV1},
+    snowcap_thumbnail_v1::{self, SnowcapThumbnailV1},
+};
+
+const VERSION: u32 = 2;
+
+pub struct SnowcapThumbnailState {
+    thumbnails: Vec<Thumbnail>,
+}
+
+pub struct SnowcapThumbnailGlobalData {
+    filter: Box<dyn Fn(&Client) -> bool + Send + Sync + 'static>,
+}
+
+/// What a thumbnail captures.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ThumbnailSource {
+    Output(WeakOutput),
+    /// A tag, by its id.
+    Tag(u32),
+    /// A window, by its id.
+    Window(u32),
+}
+
+#[derive(Debug)]
+pub struct Thumbnail {
+    thumbnail: SnowcapThumbnailV1,
+    pub source: ThumbnailSource,
+    max_size: Size<i32, Buffer>,
+    /// The size buffers must be, or `None` if the source no longer exists.
+    buffer_size: Option<Size<i32, Buffer>>,
+    /// The buffer of the capture that was requested but not yet done.
+    pending: Option<WlBuffer>,
+}
+
+pub trait SnowcapThumbnailHandler {
+    fn snowcap_thumbnail_state(&mut self) -> &mut SnowcapThumbnailState;
+    /// Returns the logical size of a thumbnail's source, or `None` if it doesn't exist.
+    fn thumbnail_source_size(&mut self, source: &ThumbnailSource) -> Option<Size<i32, Logical>>;
+}
+
+impl SnowcapThumbnailState {
+    pub fn new<D, F>(display: &DisplayHandle, filter: F) -> Self
+    where
+        D: GlobalDispatch<SnowcapThumbnailManagerV1, SnowcapThumbnailGlobalData> + 'static,
+        F: Fn(&Client) -> bool + Send + Sync + 'static,
+    {
+        let data = SnowcapThumbnailGlobalData {
+            filter: Box::new(filter),
+        };
+
+        display.create_global::<D, SnowcapThumbnailManagerV1, _>(VERSION, data);
+
+        Self {
+            thumbnails: Vec::new(),
+        }
+    }
+
+    pub fn thumbnails_mut(&mut self) -> impl Iterator<Item = &mut Thumbnail> {
+        self.thumbnails.iter_mut()
+    }
+
+    /// Stops all thumbnails of `output`.
+    pub fn output_removed(&mut self, output: &Output) {
+        for thumbnail in self.thumbnails.iter_mut() {
+            if thumbnail.source == ThumbnailSource::Output(output.downgrade()) {
+                thumbnail.stop();
+            }
+        }
+    }
+}
+
+impl Thumbnail {
+    /// Returns the size buffers must be, or `None` if the source no longer exists.
+    pub fn buffer_size(&self) -> Option<Size<i32, Buffer>> {
+        self.buffer_size
+    }
+
+    /// Takes the buffer of the requested capture, if any.
+    pub fn take_pending(&mut self) -> Option<WlBuffer> {
+        self.pending.take()
+    }
+
+    pub fn resource(&self) -> &SnowcapThumbnailV1 {
+        &self.thumbnail
+    }
+
+    /// Updates the buffer size from the logical size of the source,
+    /// notifying the client if it changed.
+    ///
+    /// A size of `None` stops the thumbnail.
+    pub fn update_source_size(&mut self, source_size: Option<Size<i32, Logical>>) {
+        if self.stopped() {
+            return;
+        }
+
+        let Some(source_size) = source_size else {
+            self.stop();
+            return;
+        };
+
+        let buffer_size = fit_size(source_size, self.max_size);
+
+        if self.buffer_size != Some(buffer_size) {
+            self.buffer_size = Some(buffer_size);
+            self.thumbnail
+                .buffer_size(buffer_size.w as u32, buffer_size.h as u32);
+        }
+    }
+
+    fn stopped(&self) -> bool {
+        self.buffer_size.is_none()
+    }
+
+    fn stop(&mut self) {
+        if self.stopped() {
+            return;
+        }
+
+        if self.pending.take().is_some() {
+            self.thumbnail.failed();
+        }
+
+        self.buffer_size = None;
+        self.thumbnail.stopped();
+    }
+}
+
+/// Scales `size` down to fit within `max_size`, keeping its aspect ratio.
+fn fit_size(size: Size<i32, Logical>, max_size: Size<i32, Buffer>) -> Size<i32, Buffer> {
+    if size.is_empty() {
+        return Size::from((1, 1));
+    }
+
+    let zoom = f64::min(
+        max_size.w as f64 / size.w as f64,
+        max_size.h as f64 / size.h as f64,
+    );
+
+    Size::from((
+        ((size.w as f64 * zoom).round() as i32).max(1),
+        ((size.h as f64 * zoom).round() as i32).max(1),
+    ))
+}
+
+impl<D> GlobalDispatch<SnowcapThumbnailManagerV1, SnowcapThumbnailGlobalData, D>
+    for SnowcapThumbnailState
+where
+    D: Dispatch<SnowcapThumbnailManagerV1, ()> + SnowcapThumbnailHandler,
+{
+    fn bind(
+        _state: &mut D,
+        _handle: &DisplayHandle,
+        _client: &Client,
+        resource: wayland_server::New<SnowcapThumbnailManagerV1>,
+        _global_data: &SnowcapThumbnailGlobalData,
+        data_init: &mut DataInit<'_, D>,
+    ) {
+        data_init.init(resource, ());
+    }
+
+    fn can_view(client: Client, global_data: &SnowcapThumbnailGlobalData) -> bool {
+        (global_data.filter)(&client)
+    }
+}
+
+impl<D> Dispatch<SnowcapThumbnailManagerV1, (), D> for SnowcapThumbnailState
+where
+    D: Dispatch<SnowcapThumbnailV1, ()> + SnowcapThumbnailHandler,
+{
+    fn request(
+        state: &mut D,
+        _client: &Client,
+        _resource: &SnowcapThumbnailManagerV1,
+        request: <SnowcapThumbnailManagerV1 as wayland_server::Resource>::Request,
+        _data: &(),
+        _dhandle: &DisplayHandle,
+        data_init: &mut DataInit<'_, D>,
+    ) {
+        let (id, source, max_width, max_height) = match request {
+            snowcap_thumbnail_manager_v1::Request::GetOutputThumbnail {
+                id,
+                output,
+                max_width,
+                max_height,
+            } => {
+                let source = Output::from_resource(&output)
+                    .map(|output| ThumbnailSource::Output(output.downgrade()));
+                (id, source, max_width, max_height)
+            }
+            snowcap_thumbnail_manager_v1::Request::GetTagThumbnail {
+                id,
+                tag_id,
+                max_width,
+                max_height,
+            } => (
+                id,
+                Some(ThumbnailSource::Tag(tag_id)),
+                max_width,
+                max_height,
+            ),
+            snowcap_thumbnail_manager_v1::Request::GetWindowThumbnail {
+                id,
+                window_id,
+                max_width,
+                max_height,
+            } => (
+                id,
+                Some(ThumbnailSource::Window(window_id)),
+                max_width,
+                max_height,
+            ),
+            snowcap_thumbnail_manager_v1::Request::Destroy => return,
+            _ => unreachable!(),
+        };
+
+        let thumbnail = data_init.init(id, ());
+
+        let source_size = source
+            .as_ref()
+            .and_then(|source| state.thumbnail_source_size(source));
+
+        let max_size = Size::from((
+            max_width.clamp(1, i32::MAX as u32) as i32,
+            max_height.clamp(1, i32::MAX as u32) as i32,
+        ));
+
+        let Some((source, source_size)) = source.zip(source_size) else {
+            thumbnail.stopped();
+            return;
+        };
+
+        let buffer_size = fit_size(source_size, max_size);
+        thumbnail.buffer_size(buffer_size.w as u32, buffer_size.h as u32);
+
+        state.snowcap_thumbnail_state().thumbnails.push(Thumbnail {
+            thumbnail,
+            source,
+            max_size,
+            buffer_size: Some(buffer_size),
+            pending: None,
+        });
+    }
+}
+
+impl<D> Dispatch<SnowcapThumbnailV1, (), D> for SnowcapThumbnailState
+where
+    D: Dispatch<SnowcapThumbnailV1, ()> + SnowcapThumbnailHandler,
+{
+    fn request(
+        state: &mut D,
+        _client: &Client,
+        resource: &SnowcapThumbnailV1,
+        request: <SnowcapThumbnailV1 as wayland_server::Resource>::Request,
+        _data: &(),
+        _dhandle: &DisplayHandle,
+        _data_init: &mut DataInit<'_, D>,
+    ) {
+        match request {
+            snowcap_thumbnail_v1::Request::Capture { buffer } => {
+                let Some(thumbnail) = state
+                    .snowcap_thumbnail_state()
+                    .thumbnails
+                    .iter_mut()
+                    .find(|thumbnail| thumbnail.thumbnail == *resource)
+                else {
+                    resource.failed();
+                    return;
+                };
+
+                if thumbnail.stopped() {
+                    resource.failed();
+                    return;
+                }
+
+                if thumbnail.pending.replace(buffer).is_some() {
+                    resource.failed();
+                }
+            }
+            snowcap_thumbnail_v1::Request::Destroy => (),
+            _ => unreachable!(),
+        }
+    }
+
+    fn destroyed(state: &mut D, _client: ClientId, resource: &SnowcapThumbnailV1, _data: &()) {
+        state
+            .snowcap_thumbnail_state()
+            .thumbnails
+            .retain(|thumbnail| thumbnail.thumbnail != *resource);
+    }
+}
+
+#[macro_export]
+macro_rules! delegate_snowcap_thumbnail {
+    ($(@<$( $lt:tt $( : $clt:tt $(+ $dlt:tt )* )? ),+>)? $ty: ty) => {
+        smithay::reexports::wayland_server::delegate_global_dispatch!($(@< $( $lt $( : $clt $(+ $dlt )* )? ),+ >)? $ty: [
+            snowcap_protocols::snowcap_thumbnail_v1::server::snowcap_thumbnail_manager_v1::SnowcapThumbnailManagerV1: $crate::protocol::snowcap_thumbnail::SnowcapThumbnailGlobalData
+        ] => $crate::protocol::snowcap_thumbnail::SnowcapThumbnailState);
+
+        smithay::reexports::wayland_server::delegate_dispatch!($(@< $( $lt $( : $clt $(+ $dlt )* )? ),+ >)? $ty: [
+            snowcap_protocols::snowcap_thumbnail_v1::server::snowcap_thumbnail_manager_v1::SnowcapThumbnailManagerV1: ()
+        ] => $crate::protocol::snowcap_thumbnail::SnowcapThumbnailState);
+
+        smithay::reexports::wayland_server::delegate_dispatch!($(@< $( $lt $( : $clt $(+ $dlt )* )? ),+ >)? $ty: [
+            snowcap_protocols::snowcap_thumbnail_v1::server::snowcap_thumbnail_v1::SnowcapThumbnailV1: ()
+        ] => $crate::protocol::snowcap_thumbnail::SnowcapThumbnailState);
+    };
+}
@@ -0,0 +1,228 @@
+//! Implementation of the xdg-toplevel-drag protocol.
+//!
+//! This lets clients attach a toplevel to a drag-and-drop operation so it follows the pointer,
+//! e.g. when tearing a tab out of a browser.
+
+use smithay::{
+    reexports::{
+        wayland_protocols::xdg::{
+            shell::server::xdg_toplevel::XdgToplevel,
+            toplevel_drag::v1::server::{
+                xdg_toplevel_drag_manager_v1::{self, XdgToplevelDragManagerV1},
+                xdg_toplevel_drag_v1::{self, XdgToplevelDragV1},
+            },
+        },
+        wayland_server::{
+            self, Client, DataInit, Dispatch, DisplayHandle, GlobalDispatch, Resource,
+            backend::ClientId, protocol::wl_data_source::WlDataSource,
+        },
+    },
+    utils::{Logical, Point},
+};
+
+const VERSION: u32 = 1;
+
+#[derive(Debug, Default)]
+pub struct XdgToplevelDragState {
+    drags: Vec<ToplevelDrag>,
+}
+
+#[derive(Debug)]
+struct ToplevelDrag {
+    drag: XdgToplevelDragV1,
+    source: WlDataSource,
+    /// The attached toplevel and where the pointer grabbed it, relative to its geometry.
+    toplevel: Option<(XdgToplevel, Point<i32, Logical>)>,
+    /// Whether the drag-and-drop operation using `source` is in progress.
+    active: bool,
+}
+
+pub trait XdgToplevelDragHandler {
+    fn xdg_toplevel_drag_state(&mut self) -> &mut XdgToplevelDragState;
+    /// A toplevel was attached to a drag, which may already be in progress.
+    fn toplevel_attached(&mut self, toplevel: &XdgToplevel);
+}
+
+impl XdgToplevelDragState {
+    pub fn new<D>(display: &DisplayHandle) -> Self
+    where
+        D: GlobalDispatch<XdgToplevelDragManagerV1, ()> + 'static,
+    {
+        display.create_global::<D, XdgToplevelDragManagerV1, _>(VERSION, ());
+
+        Self::default()
+    }
+
+    /// Marks the toplevel drag using `source` as in progress.
+    ///
+    /// Returns whether `source` has a toplevel drag.
+    pub fn start(&mut self, source: &WlDataSource) -> bool {
+        let Some(drag) = self.drags.iter_mut().find(|drag| drag.source == *source) else {
+            return false;
+        };
+
+        drag.active = true;
+        true
+    }
+
+    /// Marks all toplevel drags as finished.
+    pub fn end(&mut self) {
+        for drag in self.drags.iter_mut() {
+            drag.active = false;
+        }
+    }
+
+    /// Returns the toplevel attached to the drag in progress and its offset from the pointer.
+    pub fn active_toplevel(&self) -> Option<(XdgToplevel, Point<i32, Logical>)> {
+        self.drags
+            .iter()
+            .find(|drag| drag.active && drag.source.is_alive())
+            .and_then(|drag| drag.toplevel.clone())
+            .filter(|(toplevel, _)| toplevel.is_alive())
+    }
+}
+
+impl<D> GlobalDispatch<XdgToplevelDragManagerV1, (), D> for XdgToplevelDragState
+where
+    D: Dispatch<XdgToplevelDragManagerV1, ()> + XdgToplevelDragHandler,
+{
+    fn bind(
+        _state: &mut D,
+        _handle: &DisplayHandle,
+        _client: &Client,
+        resource: wayland_server::New<XdgToplevelDragManagerV1>,
+        _global_data: &(),
+        data_init: &mut DataInit<'_, D>,
+    ) {
+        data_init.init(resource, ());
+    }
+}
+
+impl<D> Dispatch<XdgToplevelDragManagerV1, (), D> for XdgToplevelDragState
+where
+    D: Dispatch<XdgToplevelDragV1, ()> + XdgToplevelDragHandler,
+{
+    fn request(
+        state: &mut D,
+        _client: &Client,
+        resource: &XdgToplevelDragManagerV1,
+        request: <XdgToplevelDragManagerV1 as wayland_server::Resource>::Request,
+        _data: &(),
+        _dhandle: &DisplayHandle,
+        data_init: &mut DataInit<'_, D>,
+    ) {
+        match request {
+            xdg_toplevel_drag_manager_v1::Request::GetXdgToplevelDrag { id, data_source } => {
+                let drags = &mut state.xdg_toplevel_drag_state().drags;
+
+                if drags.iter().any(|drag| drag.source == data_source) {
+                    resource.post_error(
+                        xdg_toplevel_drag_manager_v1::Error::InvalidSource,
+                        "data source already used for a toplevel drag",
+                    );
+                    return;
+                }
+
+                let drag = data_init.init(id, ());
+
+                drags.push(ToplevelDrag {
+                    drag,
+                    source: data_source,
+                    toplevel: None,
+                    active: false,
+                });
+            }
+            xdg_toplevel_drag_manager_v1::Request::Destroy => (),
+            _ => unreachable!(),
+        }
+    }
+}
+
+impl<D> Dispatch<XdgToplevelDragV1, (), D> for XdgToplevelDragState
+where
+    D: Dispatch<XdgToplevelDragV1, ()> + XdgToplevelDragHandler,
+{
+    fn request(
+        state: &mut D,
+        _client: &Client,
+        resource: &XdgToplevelDragV1,
+        request: <XdgToplevelDragV1 as wayland_server::Resource>::Request,
+        _data: &(),
+        _dhandle: &DisplayHandle,
+        _data_init: &mut DataInit<'_, D>,
+    ) {
+        match request {
+            xdg_toplevel_drag_v1::Request::Attach {
+                toplevel,
+                x_offset,
+                y_offset,
+            } => {
+                let Some(drag) = state
+                    .xdg_toplevel_drag_state()
+                    .drags
+                    .iter_mut()
+                    .find(|drag| drag.drag == *resource)
+                else {
+                    return;
+                };
+
+                if drag
+                    .toplevel
+                    .as_ref()
+                    .is_some_and(|(attached, _)| attached.is_alive() && *attached != toplevel)
+                {
+                    resource.post_error(
+                        xdg_toplevel_drag_v1::Error::ToplevelAttached,
+                        "a toplevel is already attached",
+                    );
+                    return;
+                }
+
+                drag.toplevel = Some((toplevel.clone(), (x_offset, y_offset).into()));
+
+                state.toplevel_attached(&toplevel);
+            }
+            xdg_toplevel_drag_v1::Request::Destroy => {
+                let drags = &mut state.xdg_toplevel_drag_state().drags;
+
+                if drags
+                    .iter()
+                    .any(|drag| drag.drag == *resource && drag.active && drag.source.is_alive())
+                {
+                    resource.post_error(
+                        xdg_toplevel_drag_v1::Error::OngoingDrag,
+                        "destroyed a toplevel drag while the drag is ongoing",
+                    );
+                    return;
+                }
+
+                drags.retain(|drag| drag.drag != *resource);
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    fn destroyed(state: &mut D, _client: ClientId, resource: &XdgToplevelDragV1, _data: &()) {
+        state
+            .xdg_toplevel_drag_state()
+            .drags
+            .retain(|drag| drag.drag != *resource);
+    }
+}
+
+#[macro_export]
+macro_rules! delegate_xdg_toplevel_drag {
+    ($(@<$( $lt:tt $( : $clt:tt $(+ $dlt:tt )* )? ),+>)? $ty: ty) => {
+        smithay::reexports::wayland_server::delegate_global_dispatch!($(@< $( $lt $( : $clt $(+ $dlt )* )? ),+ >)? $ty: [
+            smithay::reexports::wayland_protocols::xdg::toplevel_drag::v1::server::xdg_toplevel_drag_manager_v1::XdgToplevelDragManagerV1: ()
+        ] => $crate::protocol::xdg_toplevel_drag::XdgToplevelDragState);
+
+        smithay::reexports::wayland_server::delegate_dispatch!($(@< $( $lt $( : $clt $(+ $dlt )* )? ),+ >)? $ty: [
+            smithay::reexports::wayland_protocols::xdg::toplevel_drag::v1::server::xdg_toplevel_drag_manager_v1::XdgToplevelDragManagerV1: ()
+        ] => $crate::protocol::xdg_toplevel_drag::XdgToplevelDragState);
+
+        smithay::reexports::wayland_server::delegate_dispatch!($(@< $( $lt $( : $clt $(+ $dlt )* )? ),+ >)? $ty: [
+            smithay::reexports::wayland_protocols::xdg::toplevel_drag::v1::server::xdg_toplevel_drag_v1::XdgToplevelDragV1: ()
+        ] => $crate::protocol::xdg_toplevel_drag::XdgToplevelDragState);
+    };
+}
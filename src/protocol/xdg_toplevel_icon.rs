@@ -0,0 +1,284 @@
+//! Implementation of the xdg-toplevel-icon protocol.
+//!
+//! This lets clients set the icon of their toplevels, either by name from the icon theme
+//! or as pixel data, for use in taskbars and window switchers.
+
+use std::sync::Mutex;
+
+use smithay::{
+    reexports::{
+        wayland_protocols::xdg::{
+            shell::server::xdg_toplevel::XdgToplevel,
+            toplevel_icon::v1::server::{
+                xdg_toplevel_icon_manager_v1::{self, XdgToplevelIconManagerV1},
+                xdg_toplevel_icon_v1::{self, XdgToplevelIconV1},
+            },
+        },
+        wayland_server::{
+            self, Client, DataInit, Dispatch, DisplayHandle, GlobalDispatch, Resource,
+            protocol::{wl_buffer::WlBuffer, wl_shm},
+        },
+    },
+    wayland::shm,
+};
+
+const VERSION: u32 = 1;
+
+/// Icon sizes clients are told to provide images for.
+const PREFERRED_SIZES: &[i32] = &[32, 64, 128];
+
+/// An icon set on a toplevel.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ToplevelIcon {
+    /// The name of the icon in the icon theme.
+    pub name: Option<String>,
+    /// Images of the icon, used if there's no name or it doesn't resolve.
+    pub images: Vec<IconImage>,
+}
+
+/// An image of a [`ToplevelIcon`].
+#[derive(Clone, PartialEq, Eq)]
+pub struct IconImage {
+    /// The width and height of the image.
+    pub size: i32,
+    /// The scale the image is meant for.
+    pub scale: i32,
+    /// Non-premultiplied RGBA8 pixels, row by row.
+    pub rgba: Vec<u8>,
+}
+
+impl std::fmt::Debug for IconImage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("IconImage")
+            .field("size", &self.size)
+            .field("scale", &self.scale)
+            .finish_non_exhaustive()
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct XdgToplevelIconState;
+
+/// The state of an `xdg_toplevel_icon_v1` being built by a client.
+#[derive(Debug, Default)]
+pub struct IconData {
+    name: Option<String>,
+    /// Added buffers along with their size and scale.
+    buffers: Vec<(WlBuffer, i32, i32)>,
+    /// Whether the icon was set on a toplevel, after which it can't change.
+    immutable: bool,
+}
+
+pub trait XdgToplevelIconHandler {
+    /// A client set the icon of `toplevel`, or unset it if `icon` is `None`.
+    ///
+    /// Like other toplevel state, this applies on the toplevel's next commit.
+    fn set_icon(&mut self, toplevel: &XdgToplevel, icon: Option<ToplevelIcon>);
+}
+
+impl XdgToplevelIconState {
+    pub fn new<D>(display: &DisplayHandle) -> Self
+    where
+        D: GlobalDispatch<XdgToplevelIconManagerV1, ()> + 'static,
+    {
+        display.create_global::<D, XdgToplevelIconManagerV1, _>(VERSION, ());
+
+        Self
+    }
+}
+
+/// Reads an `xdg_toplevel_icon_v1` into a [`ToplevelIcon`].
+///
+/// Buffers that can no longer be read are skipped.
+fn read_icon(data: &IconData) -> ToplevelIcon {
+    let images = data
+        .buffers
+        .iter()
+        .filter_map(|(buffer, size, scale)| {
+            shm::with_buffer_contents(buffer, |ptr, len, buffer_data| {
+                let size = *size;
+                let offset = buffer_data.offset as usize;
+                let stride = buffer_data.stride as usize;
+
+                if stride < size as usize * 4 || offset + stride * size as usize > len {
+                    return None;
+                }
+
+                // SAFETY: The range was checked to be within the pool above
+                let bytes = unsafe { std::slice::from_raw_parts(ptr.add(offset), len - offset) };
+
+                let mut rgba = Vec::with_capacity((size * size * 4) as usize);
+                for row in bytes.chunks(stride).take(size as usize) {
+                    // Little-endian ARGB8888 is BGRA in memory, with premultiplied alpha
+                    for pixel in row[..size as usize * 4].chunks_exact(4) {
+                        let [b, g, r, a] = [pixel[0], pixel[1], pixel[2], pixel[3]];
+                        let unpremultiply = |channel: u8| {
+                            if a == 0 {
+                                0
+                            } else {
+                                ((channel as u32 * 255 + a as u32 / 2) / a as u32).min(255) as u8
+                            }
+                        };
+                        rgba.extend([unpremultiply(r), unpremultiply(g), unpremultiply(b), a]);
+                    }
+                }
+
+                Some(IconImage {
+                    size,
+                    scale: *scale,
+                    rgba,
+                })
+            })
+            .ok()
+            .flatten()
+        })
+        .collect();
+
+    ToplevelIcon {
+        name: data.name.clone(),
+        images,
+    }
+}
+
+impl<D> GlobalDispatch<XdgToplevelIconManagerV1, (), D> for XdgToplevelIconState
+where
+    D: Dispatch<XdgToplevelIconManagerV1, ()> + XdgToplevelIconHandler,
+{
+    fn bind(
+        _state: &mut D,
+        _handle: &DisplayHandle,
+        _client: &Client,
+        resource: wayland_server::New<XdgToplevelIconManagerV1>,
+        _global_data: &(),
+        data_init: &mut DataInit<'_, D>,
+    ) {
+        let manager = data_init.init(resource, ());
+
+        for size in PREFERRED_SIZES {
+            manager.icon_size(*size);
+        }
+        manager.done();
+    }
+}
+
+impl<D> Dispatch<XdgToplevelIconManagerV1, (), D> for XdgToplevelIconState
+where
+    D: Dispatch<XdgToplevelIconV1, Mutex<IconData>> + XdgToplevelIconHandler,
+{
+    fn request(
+        state: &mut D,
+        _client: &Client,
+        _resource: &XdgToplevelIconManagerV1,
+        request: <XdgToplevelIconManagerV1 as wayland_server::Resource>::Request,
+        _data: &(),
+        _dhandle: &DisplayHandle,
+        data_init: &mut DataInit<'_, D>,
+    ) {
+        match request {
+            xdg_toplevel_icon_manager_v1::Request::CreateIcon { id } => {
+                data_init.init(id, Mutex::new(IconData::default()));
+            }
+            xdg_toplevel_icon_manager_v1::Request::SetIcon { toplevel, icon } => {
+                let icon = icon.map(|icon| {
+                    let mut data = icon
+                        .data::<Mutex<IconData>>()
+                        .expect("icon should have data")
+                        .lock()
+                        .unwrap();
+                    data.immutable = true;
+                    read_icon(&data)
+                });
+
+                // An icon with neither a name nor images is the same as no icon
+                let icon = icon.filter(|icon| icon.name.is_some() || !icon.images.is_empty());
+
+                state.set_icon(&toplevel, icon);
+            }
+            xdg_toplevel_icon_manager_v1::Request::Destroy => (),
+            _ => unreachable!(),
+        }
+    }
+}
+
+impl<D> Dispatch<XdgToplevelIconV1, Mutex<IconData>, D> for XdgToplevelIconState
+where
+    D: Dispatch<XdgToplevelIconV1, Mutex<IconData>> + XdgToplevelIconHandler,
+{
+    fn request(
+        _state: &mut D,
+        _client: &Client,
+        resource: &XdgToplevelIconV1,
+        request: <XdgToplevelIconV1 as wayland_server::Resource>::Request,
+        data: &Mutex<IconData>,
+        _dhandle: &DisplayHandle,
+        _data_init: &mut DataInit<'_, D>,
+    ) {
+        let mut data = data.lock().unwrap();
+
+        let immutable = data.immutable;
+        let post_immutable = || {
+            resource.post_error(
+                xdg_toplevel_icon_v1::Error::Immutable,
+                "the icon was already assigned to a toplevel",
+            );
+        };
+
+        match request {
+            xdg_toplevel_icon_v1::Request::SetName { icon_name } => {
+                if immutable {
+                    post_immutable();
+                    return;
+                }
+
+                data.name = Some(icon_name);
+            }
+            xdg_toplevel_icon_v1::Request::AddBuffer { buffer, scale } => {
+                if immutable {
+                    post_immutable();
+                    return;
+                }
+
+                let size = shm::with_buffer_contents(&buffer, |_, _, buffer_data| {
+                    (buffer_data.format == wl_shm::Format::Argb8888
+                        && buffer_data.width == buffer_data.height)
+                        .then_some(buffer_data.width)
+                })
+                .ok()
+                .flatten();
+
+                let Some(size) = size else {
+                    resource.post_error(
+                        xdg_toplevel_icon_v1::Error::InvalidBuffer,
+                        "icon buffers must be square ARGB8888 shm buffers",
+                    );
+                    return;
+                };
+
+                // A newer buffer replaces one with the same size and scale
+                data.buffers.retain(|(_, existing_size, existing_scale)| {
+                    (*existing_size, *existing_scale) != (size, scale)
+                });
+                data.buffers.push((buffer, size, scale));
+            }
+            xdg_toplevel_icon_v1::Request::Destroy => (),
+            _ => unreachable!(),
+        }
+    }
+}
+
+#[macro_export]
+macro_rules! delegate_xdg_toplevel_icon {
+    ($(@<$( $lt:tt $( : $clt:tt $(+ $dlt:tt )* )? ),+>)? $ty: ty) => {
+        smithay::reexports::wayland_server::delegate_global_dispatch!($(@< $( $lt $( : $clt $(+ $dlt )* )? ),+ >)? $ty: [
+            smithay::reexports::wayland_protocols::xdg::toplevel_icon::v1::server::xdg_toplevel_icon_manager_v1::XdgToplevelIconManagerV1: ()
+        ] => $crate::protocol::xdg_toplevel_icon::XdgToplevelIconState);
+
+        smithay::reexports::wayland_server::delegate_dispatch!($(@< $( $lt $( : $clt $(+ $dlt )* )? ),+ >)? $ty: [
+            smithay::reexports::wayland_protocols::xdg::toplevel_icon::v1::server::xdg_toplevel_icon_manager_v1::XdgToplevelIconManagerV1: ()
+        ] => $crate::protocol::xdg_toplevel_icon::XdgToplevelIconState);
+
+        smithay::reexports::wayland_server::delegate_dispatch!($(@< $( $lt $( : $clt $(+ $dlt )* )? ),+ >)? $ty: [
+            smithay::reexports::wayland_protocols::xdg::toplevel_icon::v1::server::xdg_toplevel_icon_v1::XdgToplevelIconV1: std::sync::Mutex<$crate::protocol::xdg_toplevel_icon::IconData>
+        ] => $crate::protocol::xdg_toplevel_icon::XdgToplevelIconState);
+    };
+}
@@ -1,20 +1,27 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
 
+pub mod border;
+pub mod peek;
 pub mod pointer;
 pub mod render_elements;
+pub mod screensaver;
 pub mod texture;
+pub mod thumbnail;
 pub mod util;
 
+use std::sync::Arc;
+
 use itertools::Itertools;
 use smithay::{
     backend::renderer::{
         ImportAll, ImportMem, Renderer, RendererSuper, Texture,
         element::{
-            self, AsRenderElements, RenderElementStates,
+            self, RenderElementStates,
             solid::SolidColorRenderElement,
             surface::{WaylandSurfaceRenderElement, render_elements_from_surface_tree},
         },
-        gles::GlesRenderer,
+        gles::{GlesRenderer, GlesTexProgram},
+        utils::CommitCounter,
     },
     desktop::{
         PopupManager, Space, WindowSurface, layer_map_for_output,
@@ -25,21 +32,36 @@ use smithay::{
     },
     output::Output,
     reexports::wayland_server::protocol::wl_surface::WlSurface,
-    utils::{Logical, Physical, Point, Scale},
+    utils::{Logical, Physical, Point, Rectangle, Scale},
     wayland::shell::wlr_layer,
 };
-use util::{snapshot::SnapshotRenderElement, surface::WlSurfaceTextureRenderElement};
+use util::{
+    cache::{
+        RenderPlanKey, WindowRenderPlan, planned_surfaces_from_surface_tree,
+        render_elements_from_planned_surfaces,
+    },
+    color_filter::FilteredOutputElement,
+    effect::{
+        AppliedInactiveEffect, EffectRenderElement, InactiveEffectTarget, TagEffectTarget,
+        WindowEffect, color_transform_program, window_color_matrix,
+    },
+    shader::ShaderRenderElement,
+    snapshot::SnapshotRenderElement,
+    surface::WlSurfaceTextureRenderElement,
+};
 
 use crate::{
-    backend::{Backend, udev::UdevRenderer},
+    backend::{Backend, BackendData, udev::UdevRenderer},
     decoration::DecorationSurface,
     pinnacle_render_elements,
     state::{State, WithState},
-    window::{WindowElement, ZIndexElement},
+    window::{WindowElement, ZIndexElement, letterbox::LETTERBOX_COLOR},
 };
 
 use self::{
-    pointer::PointerRenderElement, util::surface::texture_render_elements_from_surface_tree,
+    border::border_render_element, pointer::PointerRenderElement,
+    screensaver::ScreensaverRenderElement,
+    util::surface::texture_render_elements_from_surface_tree,
 };
 
 pub const CLEAR_COLOR: [f32; 4] = [0.6, 0.6, 0.6, 1.0];
@@ -51,7 +73,13 @@ pinnacle_render_elements! {
         Surface = WaylandSurfaceRenderElement<R>,
         Pointer = PointerRenderElement<R>,
         Snapshot = SnapshotRenderElement,
+        Effect = EffectRenderElement<WaylandSurfaceRenderElement<R>>,
+        PointerEffect = EffectRenderElement<PointerRenderElement<R>>,
+        SnapshotEffect = EffectRenderElement<SnapshotRenderElement>,
         SolidColor = SolidColorRenderElement,
+        Screensaver = ScreensaverRenderElement<R>,
+        Border = ShaderRenderElement,
+        ColorFilter = EffectRenderElement<FilteredOutputElement>,
     }
 }
 
@@ -141,6 +169,9 @@ fn popup_render_elements<R: PRenderer>(
 impl WindowElement {
     /// Renders surface and popup elements for this window at the given *logical* location in the space,
     /// output-relative.
+    ///
+    /// The surfaces making up the window are cached until one of them commits or is destroyed,
+    /// or a decoration is added or removed.
     pub fn render_elements<R: PRenderer>(
         &self,
         renderer: &mut R,
@@ -151,6 +182,41 @@ impl WindowElement {
     ) -> SplitRenderElements<WaylandSurfaceRenderElement<R>> {
         let _span = tracy_client::span!("WindowElement::render_elements");
 
+        let key = RenderPlanKey {
+            location,
+            scale,
+            include_decorations,
+            has_ssd: !self.should_not_have_ssd(),
+        };
+
+        let cached = self.with_state(|state| state.render_plan_cache.get(&key));
+
+        let plan = cached.unwrap_or_else(|| {
+            let plan = Arc::new(self.render_plan(location, scale, include_decorations));
+            self.with_state_mut(|state| state.render_plan_cache.insert(key, plan.clone()));
+            plan
+        });
+
+        SplitRenderElements {
+            surface_elements: render_elements_from_planned_surfaces(
+                renderer,
+                &plan.surfaces,
+                alpha,
+            ),
+            popup_elements: render_elements_from_planned_surfaces(renderer, &plan.popups, alpha),
+        }
+    }
+
+    /// Collects the surfaces and popups making up this window at the given *logical* location
+    /// in the space, output-relative.
+    fn render_plan(
+        &self,
+        location: Point<i32, Logical>,
+        scale: Scale<f64>,
+        include_decorations: bool,
+    ) -> WindowRenderPlan {
+        let _span = tracy_client::span!("WindowElement::render_plan");
+
         let total_deco_offset = if include_decorations {
             self.total_decoration_offset()
         } else {
@@ -168,87 +234,89 @@ impl WindowElement {
         // Popups render relative to the actual window, so offset by the decoration offset.
         let surface_location = (location + total_deco_offset).to_physical_precise_round(scale);
 
-        let (deco_elems_under, deco_elems_over) =
-            if self.should_not_have_ssd() || !include_decorations {
-                (Vec::new(), Vec::new())
-            } else {
-                self.with_state(|state| {
-                    let mut surfaces = state.decoration_surfaces.iter().collect::<Vec<_>>();
-                    surfaces.sort_by_key(|deco| deco.z_index());
-                    let mut surfaces = surfaces.into_iter().rev().peekable();
-
-                    let mut deco_to_elems = |deco: &DecorationSurface| {
-                        let deco_location = {
-                            let deco_loc =
-                                location + deco.location() + total_deco_offset - deco.offset();
-                            deco_loc.to_physical_precise_round(scale)
-                        };
-
-                        let surface_elements: Vec<WaylandSurfaceRenderElement<_>> =
-                            render_elements_from_surface_tree(
-                                renderer,
-                                deco.wl_surface(),
-                                deco_location,
-                                scale,
-                                alpha,
-                                element::Kind::Unspecified,
-                            );
-                        surface_elements
+        let (deco_surfaces_under, deco_surfaces_over) = if self.should_not_have_ssd()
+            || !include_decorations
+        {
+            (Vec::new(), Vec::new())
+        } else {
+            self.with_state(|state| {
+                let mut surfaces = state.decoration_surfaces.iter().collect::<Vec<_>>();
+                surfaces.sort_by_key(|deco| deco.z_index());
+                let mut surfaces = surfaces.into_iter().rev().peekable();
+
+                let deco_to_surfaces = |deco: &DecorationSurface| {
+                    let deco_location = {
+                        let deco_loc =
+                            location + deco.location() + total_deco_offset - deco.offset();
+                        deco_loc.to_physical_precise_round(scale)
                     };
 
-                    let deco_elems_over = surfaces
-                        .peeking_take_while(|deco| deco.z_index() >= 0)
-                        .flat_map(&mut deco_to_elems)
-                        .collect::<Vec<_>>();
+                    planned_surfaces_from_surface_tree(deco.wl_surface(), deco_location, scale)
+                };
 
-                    let deco_elems_under = surfaces.flat_map(deco_to_elems).collect::<Vec<_>>();
+                let deco_surfaces_over = surfaces
+                    .peeking_take_while(|deco| deco.z_index() >= 0)
+                    .flat_map(deco_to_surfaces)
+                    .collect::<Vec<_>>();
 
-                    (deco_elems_under, deco_elems_over)
-                })
-            };
+                let deco_surfaces_under = surfaces.flat_map(deco_to_surfaces).collect::<Vec<_>>();
 
-        match self.underlying_surface() {
+                (deco_surfaces_under, deco_surfaces_over)
+            })
+        };
+
+        let (surface, popups) = match self.underlying_surface() {
             WindowSurface::Wayland(toplevel) => {
                 let surface = toplevel.wl_surface();
 
-                let surface_elements = deco_elems_over
-                    .into_iter()
-                    .chain(render_elements_from_surface_tree(
-                        renderer,
-                        surface,
-                        window_location,
-                        scale,
-                        alpha,
-                        element::Kind::Unspecified,
-                    ))
-                    .chain(deco_elems_under)
-                    .collect::<Vec<_>>();
-
-                let popup_elements =
-                    popup_render_elements(surface, renderer, surface_location, scale, alpha);
+                let popups = PopupManager::popups_for_surface(surface)
+                    .flat_map(|(popup, popup_offset)| {
+                        let offset =
+                            (popup_offset - popup.geometry().loc).to_physical_precise_round(scale);
 
-                SplitRenderElements {
-                    surface_elements,
-                    popup_elements,
-                }
-            }
-            WindowSurface::X11(s) => {
-                let surface_elements = deco_elems_over
-                    .into_iter()
-                    .chain(AsRenderElements::render_elements(
-                        s,
-                        renderer,
-                        window_location,
-                        scale,
-                        alpha,
-                    ))
-                    .chain(deco_elems_under)
+                        planned_surfaces_from_surface_tree(
+                            popup.wl_surface(),
+                            surface_location + offset,
+                            scale,
+                        )
+                    })
                     .collect();
-                SplitRenderElements {
-                    surface_elements,
-                    popup_elements: Vec::new(),
-                }
+
+                (Some(surface.clone()), popups)
             }
+            WindowSurface::X11(s) => (s.wl_surface(), Vec::new()),
+        };
+
+        let deco_roots = if self.should_not_have_ssd() || !include_decorations {
+            Vec::new()
+        } else {
+            self.with_state(|state| {
+                state
+                    .decoration_surfaces
+                    .iter()
+                    .map(|deco| deco.wl_surface().clone())
+                    .collect()
+            })
+        };
+
+        let roots = surface.iter().cloned().chain(deco_roots).collect();
+
+        let surfaces = deco_surfaces_over
+            .into_iter()
+            .chain(
+                surface
+                    .map(|surface| {
+                        planned_surfaces_from_surface_tree(&surface, window_location, scale)
+                    })
+                    .unwrap_or_default(),
+            )
+            .chain(deco_surfaces_under)
+            .collect();
+
+        WindowRenderPlan {
+            surfaces,
+            popups,
+            roots,
         }
     }
 
@@ -453,11 +521,15 @@ fn window_render_elements<R: PRenderer + AsGlesRenderer>(
     renderer: &mut R,
     scale: Scale<f64>,
     z_index_stack: &[ZIndexElement],
+    inactive_effect: Option<&AppliedInactiveEffect>,
+    color_transform: Option<&GlesTexProgram>,
 ) -> WindowRenderElements<R> {
     let _span = tracy_client::span!("window_render_elements");
 
     let windows = space.elements_for_output(output);
 
+    let output_size = space.output_geometry(output).unwrap_or_default().size;
+
     let mut last_fullscreen_split_at = 0;
 
     let mut renderables = Vec::new();
@@ -500,14 +572,67 @@ fn window_render_elements<R: PRenderer + AsGlesRenderer>(
                 let loc =
                     space.element_location(win).unwrap_or_default() - output.current_location();
 
+                let (tag_effect, letterbox_id) = win.with_state(|state| {
+                    (
+                        state.tag_effect,
+                        state.letterboxed.then(|| state.letterbox_id.clone()),
+                    )
+                });
+                let alpha = tag_effect.map_or(1.0, |effect| effect.opacity);
+
                 let SplitRenderElements {
                     surface_elements,
                     popup_elements,
-                } = win.render_elements(renderer, loc, scale, 1.0, true);
+                } = win.render_elements(renderer, loc, scale, alpha, true);
+
+                let matrix = window_color_matrix(win, inactive_effect);
+                let effect = color_transform.zip(matrix);
+                let output_name = &output.name();
+                let to_output_elem = move |elem: WaylandSurfaceRenderElement<R>| match effect {
+                    Some((program, matrix)) => {
+                        let id = win.with_state_mut(|state| {
+                            state.effect_ids.id(output_name, matrix, elem.id())
+                        });
+                        OutputRenderElement::from(
+                            EffectRenderElement::new(elem, program.clone(), matrix).with_id(id),
+                        )
+                    }
+                    None => OutputRenderElement::from(elem),
+                };
+
+                popups.extend(popup_elements.into_iter().map(to_output_elem));
+
+                let border = if win.should_not_have_ssd() {
+                    None
+                } else {
+                    let area = Rectangle::new(loc, win.geometry().size);
+                    border_render_element(
+                        renderer.as_gles_renderer(),
+                        win,
+                        output,
+                        area,
+                        scale,
+                        alpha,
+                        matrix,
+                    )
+                    .map(OutputRenderElement::from)
+                };
 
-                popups.extend(popup_elements.into_iter().map(OutputRenderElement::from));
+                let letterbox = letterbox_id.map(|id| {
+                    OutputRenderElement::from(SolidColorRenderElement::new(
+                        id,
+                        Rectangle::from_size(output_size).to_physical_precise_round(scale),
+                        CommitCounter::default(),
+                        LETTERBOX_COLOR,
+                        element::Kind::Unspecified,
+                    ))
+                });
 
-                let iter = surface_elements.into_iter().map(OutputRenderElement::from);
+                let iter = surface_elements
+                    .into_iter()
+                    .map(to_output_elem)
+                    .chain(border)
+                    .chain(letterbox);
                 itertools::Either::Left(iter)
             }
             itertools::Either::Right(snap) => {
@@ -517,8 +642,15 @@ fn window_render_elements<R: PRenderer + AsGlesRenderer>(
                 let space_loc = snap.space_loc;
                 let loc = space_loc - output.current_location();
                 let loc = loc.to_f64().to_physical_precise_round(scale);
+                let effect = color_transform.zip(snap.color_matrix);
                 let snap = snap.snapshot.render_elements(renderer, loc, scale, 1.0);
-                let iter = snap.into_iter().map(OutputRenderElement::from);
+                let iter =
+                    snap.into_iter().map(move |elem| match effect {
+                        Some((program, matrix)) => OutputRenderElement::from(
+                            EffectRenderElement::new(elem, program.clone(), matrix),
+                        ),
+                        None => OutputRenderElement::from(elem),
+                    });
                 itertools::Either::Right(iter)
             }
         })
@@ -544,8 +676,20 @@ pub fn output_render_elements<R: PRenderer + AsGlesRenderer>(
 
     let scale = Scale::from(output.current_scale().fractional_scale());
 
+    let inactive_effect = output.with_state(|state| state.inactive_effect.clone());
+    let color_transform = color_transform_program(renderer.as_gles_renderer());
+
     let mut output_render_elements: Vec<OutputRenderElement<_>> = Vec::new();
 
+    let output_size = space.output_geometry(output).unwrap_or_default().size;
+    let peek_elements = output.with_state(|state| {
+        state
+            .peek
+            .as_ref()
+            .map(|peek| peek.render_elements(renderer, output_size, scale))
+            .unwrap_or_default()
+    });
+
     let LayerRenderElements {
         popup: layer_popups,
         background,
@@ -558,10 +702,19 @@ pub fn output_render_elements<R: PRenderer + AsGlesRenderer>(
         popups: window_popups,
         fullscreen_and_up: fullscreen_and_up_elements,
         rest: rest_of_window_elements,
-    } = window_render_elements::<R>(output, space, renderer, scale, z_index_stack);
+    } = window_render_elements::<R>(
+        output,
+        space,
+        renderer,
+        scale,
+        z_index_stack,
+        inactive_effect.as_ref(),
+        color_transform.as_ref(),
+    );
 
     // Elements render from top to bottom
 
+    output_render_elements.extend(peek_elements);
     output_render_elements.extend(layer_popups.into_iter().map(OutputRenderElement::from));
     output_render_elements.extend(window_popups);
     output_render_elements.extend(overlay.into_iter().map(OutputRenderElement::from));
@@ -626,4 +779,93 @@ impl State {
             Backend::Dummy(_) => (),
         }
     }
+    /// Updates the inactive effect applied to each output, redrawing the ones that changed.
+    pub fn refresh_inactive_effect(&mut self) {
+        let _span = tracy_client::span!("State::refresh_inactive_effect");
+
+        let effect = self.pinnacle.config.inactive_effect;
+        let focused_output = self.pinnacle.focused_output().cloned();
+        let focused_window = self.pinnacle.keyboard_focus_stack.current_focus().cloned();
+
+        for output in self.pinnacle.outputs.clone() {
+            let applied = effect.and_then(|effect| match effect.target {
+                InactiveEffectTarget::UnfocusedOutputs => {
+                    let unfocused = focused_output.as_ref() != Some(&output);
+                    unfocused.then_some(AppliedInactiveEffect {
+                        effect,
+                        except: None,
+                    })
+                }
+                InactiveEffectTarget::UnfocusedWindows => Some(AppliedInactiveEffect {
+                    effect,
+                    except: focused_window.clone(),
+                }),
+            });
+
+            let changed = output.with_state_mut(|state| {
+                let changed = state.inactive_effect != applied;
+                state.inactive_effect = applied;
+                changed
+            });
+
+            // Windows whose effect changed are drawn under new ids, so only they are redrawn
+            if changed {
+                self.schedule_render(&output);
+            }
+        }
+    }
+
+    /// Updates the effect tag effect rules apply to each window, redrawing the outputs
+    /// of windows that changed.
+    pub fn refresh_tag_effects(&mut self) {
+        let _span = tracy_client::span!("State::refresh_tag_effects");
+
+        let rules = self
+            .pinnacle
+            .config
+            .tag_effect_rules
+            .iter()
+            .filter(|rule| rule.tag.active() && !rule.tag.defunct())
+            .map(|rule| (rule, rule.tag.output(&self.pinnacle)))
+            .collect::<Vec<_>>();
+
+        let mut changed_outputs = Vec::new();
+
+        for window in self.pinnacle.windows.iter() {
+            let output = window.output(&self.pinnacle);
+
+            let effect = rules
+                .iter()
+                .filter(|(rule, tag_output)| {
+                    let on_tag = window.with_state(|state| state.tags.contains(&rule.tag));
+                    match rule.target {
+                        TagEffectTarget::WindowsOnTag => on_tag,
+                        TagEffectTarget::OtherWindows => {
+                            !on_tag && output.is_some() && output == *tag_output
+                        }
+                    }
+                })
+                .map(|(rule, _)| rule.effect)
+                .reduce(WindowEffect::stack);
+
+            let changed = window.with_state_mut(|state| {
+                let changed = state.tag_effect != effect;
+                state.tag_effect = effect;
+                changed
+            });
+
+            if changed {
+                for output in self.pinnacle.space.outputs_for_element(window) {
+                    if !changed_outputs.contains(&output) {
+                        changed_outputs.push(output);
+                    }
+                }
+            }
+        }
+
+        for output in changed_outputs {
+            self.backend.reset_buffers(&output);
+            self.schedule_render(&output);
+        }
+    }
 }
@@ -0,0 +1,181 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Rendering for window borders.
+
+use std::{cell::RefCell, collections::HashMap};
+
+use smithay::{
+    backend::renderer::{
+        element,
+        gles::{
+            GlesPixelProgram, GlesRenderer, Uniform, UniformName, UniformType,
+            element::PixelShaderElement,
+        },
+    },
+    output::Output,
+    utils::{Logical, Rectangle, Scale},
+};
+use tracing::warn;
+
+use crate::{state::WithState, window::WindowElement};
+
+use super::util::{effect::ColorMatrix, shader::ShaderRenderElement};
+
+/// Fills a rounded rectangle except for an inner rectangle inset from its edges.
+const BORDER_SHADER: &str = r#"
+precision mediump float;
+
+uniform float alpha;
+uniform vec2 size;
+varying vec2 v_coords;
+
+#if defined(DEBUG_FLAGS)
+uniform float tint;
+#endif
+
+uniform vec4 color;
+// Top, right, bottom, and left insets of the inner rectangle in pixels
+uniform vec4 insets;
+uniform float radius;
+
+void main() {
+    vec2 pos = v_coords * size;
+
+    vec2 half_size = size / 2.0;
+    vec2 corner = abs(pos - half_size) - half_size + radius;
+    float dist = length(max(corner, 0.0)) + min(max(corner.x, corner.y), 0.0) - radius;
+    float coverage = clamp(0.5 - dist, 0.0, 1.0);
+
+    if (pos.x > insets.w && pos.x < size.x - insets.y
+        && pos.y > insets.x && pos.y < size.y - insets.z)
+    {
+        coverage = 0.0;
+    }
+
+    vec4 mix_color = color * coverage * alpha;
+
+#if defined(DEBUG_FLAGS)
+    if (tint == 1.0)
+        mix_color = vec4(0.0, 0.3, 0.0, 0.2) + mix_color * 0.8;
+#endif
+
+    gl_FragColor = mix_color;
+}
+"#;
+
+struct BorderProgram(Option<GlesPixelProgram>);
+
+/// Gets the border shader, compiling it if needed.
+///
+/// Returns `None` if the shader failed to compile.
+fn border_program(renderer: &mut GlesRenderer) -> Option<GlesPixelProgram> {
+    if let Some(program) = renderer.egl_context().user_data().get::<BorderProgram>() {
+        return program.0.clone();
+    }
+
+    let program = renderer
+        .compile_custom_pixel_shader(
+            BORDER_SHADER,
+            &[
+                UniformName::new("color", UniformType::_4f),
+                UniformName::new("insets", UniformType::_4f),
+                UniformName::new("radius", UniformType::_1f),
+            ],
+        )
+        .inspect_err(|err| warn!("Failed to compile border shader: {err}"))
+        .ok();
+
+    renderer
+        .egl_context()
+        .user_data()
+        .insert_if_missing(|| BorderProgram(program.clone()));
+
+    program
+}
+
+/// What a border element was last drawn with.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct BorderParams {
+    area: Rectangle<i32, Logical>,
+    color: [f32; 4],
+    insets: [f32; 4],
+    radius: f32,
+}
+
+impl BorderParams {
+    fn uniforms(&self) -> Vec<Uniform<'static>> {
+        vec![
+            Uniform::new("color", self.color),
+            Uniform::new("insets", self.insets),
+            Uniform::new("radius", self.radius),
+        ]
+    }
+}
+
+/// Border elements of a window by output name, kept around so they keep their ids
+/// and only get damaged when they change.
+#[derive(Default)]
+struct BorderElements(RefCell<HashMap<String, (PixelShaderElement, BorderParams)>>);
+
+/// Renders the border of `window` around `area`, its output-relative geometry.
+///
+/// Returns `None` if the window has no border or the shader failed to compile.
+pub fn border_render_element(
+    renderer: &mut GlesRenderer,
+    window: &WindowElement,
+    output: &Output,
+    area: Rectangle<i32, Logical>,
+    scale: Scale<f64>,
+    alpha: f32,
+    color_matrix: Option<ColorMatrix>,
+) -> Option<ShaderRenderElement> {
+    let (style, color) = window.with_state(|state| state.border.zip(state.border_color))?;
+    let program = border_program(renderer)?;
+
+    let bounds = style.bounds();
+    let scale = scale.x as f32;
+
+    let [red, green, blue, color_alpha] = color;
+    let color_alpha = color_alpha * alpha;
+
+    // The shader works with premultiplied colors
+    let color = [
+        red * color_alpha,
+        green * color_alpha,
+        blue * color_alpha,
+        color_alpha,
+    ];
+    let color = color_matrix.map_or(color, |matrix| matrix.apply(color));
+
+    let params = BorderParams {
+        area,
+        color,
+        insets: [bounds.top, bounds.right, bounds.bottom, bounds.left]
+            .map(|inset| inset as f32 * scale),
+        radius: style.corner_radius * scale,
+    };
+
+    let user_data = window.user_data();
+    user_data.insert_if_missing(BorderElements::default);
+    let mut elements = user_data.get::<BorderElements>()?.0.borrow_mut();
+
+    let (element, last_params) = elements.entry(output.name()).or_insert_with(|| {
+        let element = PixelShaderElement::new(
+            program,
+            area,
+            None,
+            1.0,
+            params.uniforms(),
+            element::Kind::Unspecified,
+        );
+        (element, params)
+    });
+
+    if *last_params != params {
+        element.resize(area, None);
+        element.update_uniforms(params.uniforms());
+        *last_params = params;
+    }
+
+    Some(ShaderRenderElement(element.clone()))
+}
@@ -0,0 +1,207 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Peeking at the windows of a tag without switching to it.
+
+use std::time::Duration;
+
+use smithay::{
+    backend::renderer::{
+        element::{self, Id, solid::SolidColorRenderElement},
+        utils::CommitCounter,
+    },
+    output::Output,
+    reexports::calloop::{
+        RegistrationToken,
+        timer::{TimeoutAction, Timer},
+    },
+    utils::{Logical, Point, Rectangle, Scale, Size},
+};
+
+use crate::{
+    state::{State, WithState},
+    tag::Tag,
+};
+
+use super::{
+    AsGlesRenderer, OutputRenderElement, PRenderer,
+    util::snapshot::{RenderSnapshot, WindowSnapshot},
+};
+
+/// The color drawn over the output behind peeked windows.
+const BACKDROP_COLOR: [f32; 4] = [0.0, 0.0, 0.0, 0.6];
+/// The gap between peeked windows and around the edges of the output, in logical pixels.
+const GAP: i32 = 24;
+
+/// Snapshots of a tag's windows that are being shown over its output.
+#[derive(Debug)]
+pub struct TagPeek {
+    /// The tag being peeked at.
+    pub tag: Tag,
+    /// Snapshots of the tag's windows along with their logical sizes.
+    snapshots: Vec<(WindowSnapshot, Size<i32, Logical>)>,
+    backdrop_id: Id,
+    /// The timer that ends this peek.
+    timer: RegistrationToken,
+}
+
+impl TagPeek {
+    /// Renders the snapshots in a grid over an output of the given size, above a backdrop.
+    pub fn render_elements<R: PRenderer + AsGlesRenderer>(
+        &self,
+        renderer: &mut R,
+        output_size: Size<i32, Logical>,
+        scale: Scale<f64>,
+    ) -> Vec<OutputRenderElement<R>> {
+        let _span = tracy_client::span!("TagPeek::render_elements");
+
+        let mut elements = grid_render_elements(renderer, &self.snapshots, output_size, scale);
+
+        elements.push(OutputRenderElement::from(SolidColorRenderElement::new(
+            self.backdrop_id.clone(),
+            Rectangle::from_size(output_size).to_physical_precise_round(scale),
+            CommitCounter::default(),
+            BACKDROP_COLOR,
+            element::Kind::Unspecified,
+        )));
+
+        elements
+    }
+}
+
+/// Renders window snapshots in a grid filling an area of the given size.
+pub fn grid_render_elements<R: PRenderer + AsGlesRenderer>(
+    renderer: &mut R,
+    snapshots: &[(WindowSnapshot, Size<i32, Logical>)],
+    area_size: Size<i32, Logical>,
+    scale: Scale<f64>,
+) -> Vec<OutputRenderElement<R>> {
+    let mut elements = Vec::new();
+
+    let count = snapshots.len() as i32;
+
+    if count == 0 {
+        return elements;
+    }
+
+    let cols = (count as f64).sqrt().ceil() as i32;
+    let rows = count.div_ceil(cols);
+
+    let cell_size: Size<i32, Logical> = Size::from((
+        (area_size.w - GAP * (cols + 1)) / cols,
+        (area_size.h - GAP * (rows + 1)) / rows,
+    ));
+
+    if cell_size.is_empty() {
+        return elements;
+    }
+
+    for (i, (snapshot, size)) in (0..).zip(snapshots.iter()) {
+        let zoom = f64::min(
+            cell_size.w as f64 / size.w as f64,
+            cell_size.h as f64 / size.h as f64,
+        )
+        .min(1.0);
+
+        let cell_loc: Point<i32, Logical> = Point::from((
+            GAP + (i % cols) * (cell_size.w + GAP),
+            GAP + (i / cols) * (cell_size.h + GAP),
+        ));
+        let centering = (cell_size.to_f64() - size.to_f64().upscale(zoom))
+            .downscale(2.0)
+            .to_point();
+        let loc = (cell_loc.to_f64() + centering).to_physical_precise_round(scale);
+
+        // Snapshots undo the scale they are rendered with,
+        // so dividing it by the zoom shrinks the snapshot by that much.
+        let snapshot_scale = Scale::from((scale.x / zoom, scale.y / zoom));
+
+        elements.extend(
+            snapshot
+                .render_elements(renderer, loc, snapshot_scale, 1.0)
+                .map(OutputRenderElement::from),
+        );
+    }
+
+    elements
+}
+
+impl State {
+    /// Shows snapshots of the given tag's windows over its output for `duration`
+    /// without switching to the tag.
+    ///
+    /// Peeking at a tag while another tag on the same output is being peeked at
+    /// replaces that peek.
+    pub fn peek_tag(&mut self, tag: &Tag, duration: Duration) {
+        let _span = tracy_client::span!("State::peek_tag");
+
+        let Some(output) = tag.output(&self.pinnacle) else {
+            return;
+        };
+
+        let scale = Scale::from(output.current_scale().fractional_scale());
+
+        let snapshots = self.tag_window_snapshots(tag, scale);
+
+        let weak_output = output.downgrade();
+        let timer = self
+            .pinnacle
+            .loop_handle
+            .insert_source(Timer::from_duration(duration), move |_, _, state| {
+                if let Some(output) = weak_output.upgrade() {
+                    output.with_state_mut(|state| state.peek.take());
+                    state.schedule_render(&output);
+                }
+                TimeoutAction::Drop
+            })
+            .expect("failed to insert peek timer");
+
+        let peek = TagPeek {
+            tag: tag.clone(),
+            snapshots,
+            backdrop_id: Id::new(),
+            timer,
+        };
+
+        if let Some(old_peek) = output.with_state_mut(|state| state.peek.replace(peek)) {
+            self.pinnacle.loop_handle.remove(old_peek.timer);
+        }
+
+        self.schedule_render(&output);
+    }
+
+    /// Takes snapshots of the given tag's windows at `scale`, along with their logical sizes.
+    pub fn tag_window_snapshots(
+        &mut self,
+        tag: &Tag,
+        scale: Scale<f64>,
+    ) -> Vec<(WindowSnapshot, Size<i32, Logical>)> {
+        let windows = self
+            .pinnacle
+            .windows
+            .iter()
+            .filter(|win| win.with_state(|state| state.tags.contains(tag)))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        self.backend
+            .with_renderer(|renderer| {
+                windows
+                    .iter()
+                    .filter(|win| !win.geometry().size.is_empty())
+                    .map(|win| {
+                        let elements =
+                            win.texture_render_elements(renderer, (0, 0).into(), scale, 1.0);
+                        (
+                            RenderSnapshot::new(
+                                elements.surface_elements,
+                                scale,
+                                self.pinnacle.gpu_accounting.clone(),
+                            ),
+                            win.geometry().size,
+                        )
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default()
+    }
+}
@@ -11,6 +11,7 @@ use smithay::{
             surface::{WaylandSurfaceRenderElement, render_elements_from_surface_tree},
         },
     },
+    output::Output,
     reexports::wayland_server::protocol::wl_surface::WlSurface,
     render_elements,
     utils::{Clock, Logical, Monotonic, Point},
@@ -37,20 +38,23 @@ render_elements! {
 ///
 /// This will create render elements such that the hotspot of the cursor will be at `location`.
 ///
+/// The cursor's theme and size are those configured for `output`, if any.
+///
 /// Additionally returns the ids of cursor elements for use in screencopy.
 pub fn pointer_render_elements<R: PRenderer>(
     location: Point<f64, Logical>,
     scale: f64,
+    output: Option<&Output>,
     renderer: &mut R,
     cursor_state: &mut CursorState,
     clock: &Clock<Monotonic>,
 ) -> (Vec<PointerRenderElement<R>>, Vec<Id>) {
     let integer_scale = scale.ceil() as i32;
 
-    let pointer_elem = cursor_state.pointer_element();
+    let pointer_elem = cursor_state.pointer_element(output);
 
     let hotspot = cursor_state
-        .cursor_hotspot(clock.now(), scale)
+        .cursor_hotspot(clock.now(), scale, output)
         .unwrap_or_default();
 
     let mut pointer_elements = match &pointer_elem {
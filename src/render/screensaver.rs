@@ -0,0 +1,370 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Rendering for the screensaver.
+
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+    fs::File,
+    io::BufReader,
+    path::{Path, PathBuf},
+    time::Instant,
+};
+
+use anyhow::Context;
+use smithay::{
+    backend::{
+        allocator::Fourcc,
+        renderer::{
+            element::{
+                self, Id,
+                memory::{MemoryRenderBuffer, MemoryRenderBufferRenderElement},
+                solid::SolidColorRenderElement,
+            },
+            gles::{
+                GlesPixelProgram, GlesRenderer, Uniform, UniformName, UniformType,
+                element::PixelShaderElement,
+            },
+            utils::CommitCounter,
+        },
+    },
+    output::Output,
+    reexports::calloop::{
+        LoopHandle, RegistrationToken,
+        channel::{self, Sender},
+        timer::{TimeoutAction, Timer},
+    },
+    utils::{Buffer, Logical, Rectangle, Scale, Size, Transform},
+};
+use tracing::warn;
+
+use crate::{idle::ScreensaverSource, pinnacle_render_elements, state::State};
+
+use super::{AsGlesRenderer, OutputRenderElement, PRenderer, util::shader::ShaderRenderElement};
+
+/// The color drawn behind slideshow images and in place of shaders that failed to compile.
+const BACKDROP_COLOR: [f32; 4] = [0.0, 0.0, 0.0, 1.0];
+
+pinnacle_render_elements! {
+    #[derive(Debug)]
+    pub enum ScreensaverRenderElement<R> {
+        Shader = ShaderRenderElement,
+        Image = MemoryRenderBufferRenderElement<R>,
+    }
+}
+
+/// The most recently compiled screensaver shader.
+struct ScreensaverProgram(RefCell<Option<(String, Result<GlesPixelProgram, String>)>>);
+
+/// Gets the program for a screensaver shader, compiling it if needed.
+///
+/// Shaders get the output's size in pixels as `uniform vec2 size` and the seconds since
+/// the screensaver started as `uniform float time`.
+pub fn screensaver_program(
+    renderer: &mut GlesRenderer,
+    source: &str,
+) -> Result<GlesPixelProgram, String> {
+    if let Some(cached) = renderer
+        .egl_context()
+        .user_data()
+        .get::<ScreensaverProgram>()
+        && let Some((cached_source, program)) = &*cached.0.borrow()
+        && cached_source == source
+    {
+        return program.clone();
+    }
+
+    let program = renderer
+        .compile_custom_pixel_shader(source, &[UniformName::new("time", UniformType::_1f)])
+        .map_err(|err| err.to_string());
+
+    if let Err(err) = &program {
+        warn!("Failed to compile screensaver shader: {err}");
+    }
+
+    let user_data = renderer.egl_context().user_data();
+    user_data.insert_if_missing(|| ScreensaverProgram(RefCell::new(None)));
+    if let Some(cached) = user_data.get::<ScreensaverProgram>() {
+        *cached.0.borrow_mut() = Some((source.to_string(), program.clone()));
+    }
+
+    program
+}
+
+/// An image of a slideshow.
+#[derive(Debug)]
+struct Slide {
+    /// The decoded image, or `None` if it failed to load.
+    buffer: Option<(MemoryRenderBuffer, Size<i32, Buffer>)>,
+}
+
+/// The index of a slide along with its pixels, or `None` if it failed to load.
+type DecodedSlide = (usize, Option<(Vec<u8>, Size<i32, Buffer>)>);
+
+/// A screensaver that is being shown.
+#[derive(Debug)]
+pub struct ActiveScreensaver {
+    source: ScreensaverSource,
+    started: Instant,
+    /// Shader elements by output name, kept around so they keep their ids.
+    shader_elements: HashMap<String, PixelShaderElement>,
+    slide: Option<Slide>,
+    /// The index of the slide that was last requested.
+    slide_index: usize,
+    /// Sends slides decoded on worker threads back to the event loop.
+    slide_sender: Option<Sender<DecodedSlide>>,
+    /// The timer that advances the slideshow.
+    slide_timer: Option<RegistrationToken>,
+    /// The source receiving decoded slides.
+    slide_receiver: Option<RegistrationToken>,
+    backdrop_id: Id,
+}
+
+impl ActiveScreensaver {
+    pub fn new(source: ScreensaverSource, loop_handle: &LoopHandle<'static, State>) -> Self {
+        let mut slide_sender = None;
+        let mut slide_timer = None;
+        let mut slide_receiver = None;
+
+        if let ScreensaverSource::Slideshow { images, interval } = &source {
+            let (sender, receiver) = channel::channel::<DecodedSlide>();
+            let token = loop_handle
+                .insert_source(receiver, |event, _, state| {
+                    let channel::Event::Msg((index, decoded)) = event else {
+                        return;
+                    };
+                    // Drop slides that were superseded while they were being decoded
+                    if let Some(active) = state.pinnacle.idle_state.active_screensaver.as_mut()
+                        && active.slide_index == index
+                    {
+                        active.slide = Some(Slide::new(decoded));
+                        state.schedule_render_all();
+                    }
+                })
+                .expect("failed to insert slideshow receiver");
+            slide_receiver = Some(token);
+
+            decode_slide(images, 0, &sender);
+            slide_sender = Some(sender);
+
+            let interval = *interval;
+            let timer = loop_handle
+                .insert_source(Timer::from_duration(interval), move |_, _, state| {
+                    if let Some(active) = state.pinnacle.idle_state.active_screensaver.as_mut() {
+                        active.advance_slide();
+                        state.schedule_render_all();
+                    }
+                    TimeoutAction::ToDuration(interval)
+                })
+                .expect("failed to insert slideshow timer");
+            slide_timer = Some(timer);
+        }
+
+        Self {
+            source,
+            started: Instant::now(),
+            shader_elements: HashMap::new(),
+            slide: None,
+            slide_index: 0,
+            slide_sender,
+            slide_timer,
+            slide_receiver,
+            backdrop_id: Id::new(),
+        }
+    }
+
+    /// Stops this screensaver's timers.
+    ///
+    /// Slides that are still being decoded are dropped once they finish.
+    pub fn stop(self, loop_handle: &LoopHandle<'static, State>) {
+        for token in [self.slide_timer, self.slide_receiver]
+            .into_iter()
+            .flatten()
+        {
+            loop_handle.remove(token);
+        }
+    }
+
+    /// Returns whether this screensaver changes every frame.
+    pub fn is_animated(&self) -> bool {
+        matches!(self.source, ScreensaverSource::Shader(_))
+    }
+
+    fn advance_slide(&mut self) {
+        let (ScreensaverSource::Slideshow { images, .. }, Some(sender)) =
+            (&self.source, &self.slide_sender)
+        else {
+            return;
+        };
+
+        // The current slide stays up until the next one is decoded
+        self.slide_index = (self.slide_index + 1) % images.len();
+        decode_slide(images, self.slide_index, sender);
+    }
+
+    /// Renders this screensaver over an output of the given size.
+    pub fn render_elements<R: PRenderer + AsGlesRenderer>(
+        &mut self,
+        renderer: &mut R,
+        output: &Output,
+        output_size: Size<i32, Logical>,
+        scale: Scale<f64>,
+    ) -> Vec<OutputRenderElement<R>> {
+        let _span = tracy_client::span!("ActiveScreensaver::render_elements");
+
+        let mut elements = Vec::new();
+
+        match &self.source {
+            ScreensaverSource::Shader(source) => {
+                if let Ok(program) = screensaver_program(renderer.as_gles_renderer(), source) {
+                    let area = Rectangle::from_size(output_size);
+                    let time = self.started.elapsed().as_secs_f32();
+                    let uniforms = vec![Uniform::new("time", time)];
+
+                    let element = self
+                        .shader_elements
+                        .entry(output.name())
+                        .or_insert_with(|| {
+                            PixelShaderElement::new(
+                                program,
+                                area,
+                                None,
+                                1.0,
+                                uniforms.clone(),
+                                element::Kind::Unspecified,
+                            )
+                        });
+                    element.resize(area, None);
+                    element.update_uniforms(uniforms);
+
+                    elements.push(OutputRenderElement::from(ScreensaverRenderElement::from(
+                        ShaderRenderElement(element.clone()),
+                    )));
+                }
+            }
+            ScreensaverSource::Slideshow { .. } => {
+                if let Some((buffer, image_size)) =
+                    self.slide.as_ref().and_then(|slide| slide.buffer.as_ref())
+                {
+                    // Fit the image inside the output, centered
+                    let zoom = f64::min(
+                        output_size.w as f64 / image_size.w as f64,
+                        output_size.h as f64 / image_size.h as f64,
+                    );
+                    let size = image_size
+                        .to_f64()
+                        .to_logical(1.0, Transform::Normal)
+                        .upscale(zoom);
+                    let loc = (output_size.to_f64() - size).downscale(2.0).to_point();
+
+                    let image = MemoryRenderBufferRenderElement::from_buffer(
+                        renderer,
+                        loc.to_physical(scale),
+                        buffer,
+                        None,
+                        None,
+                        Some(size.to_i32_round()),
+                        element::Kind::Unspecified,
+                    );
+
+                    match image {
+                        Ok(image) => elements.push(OutputRenderElement::from(
+                            ScreensaverRenderElement::from(image),
+                        )),
+                        Err(err) => warn!("Failed to render screensaver image: {err}"),
+                    }
+                }
+            }
+        }
+
+        elements.push(OutputRenderElement::from(SolidColorRenderElement::new(
+            self.backdrop_id.clone(),
+            Rectangle::from_size(output_size).to_physical_precise_round(scale),
+            CommitCounter::default(),
+            BACKDROP_COLOR,
+            element::Kind::Unspecified,
+        )));
+
+        elements
+    }
+}
+
+impl Slide {
+    fn new(decoded: Option<(Vec<u8>, Size<i32, Buffer>)>) -> Self {
+        let buffer = decoded.map(|(rgba, size)| {
+            let buffer = MemoryRenderBuffer::from_slice(
+                &rgba,
+                Fourcc::Abgr8888,
+                (size.w, size.h),
+                1,
+                Transform::Normal,
+                None,
+            );
+            (buffer, size)
+        });
+
+        Self { buffer }
+    }
+}
+
+/// Decodes the slide at `index` on a worker thread and sends it to `sender`,
+/// so large images don't stall the event loop.
+fn decode_slide(images: &[PathBuf], index: usize, sender: &Sender<DecodedSlide>) {
+    let path = images[index].clone();
+    let sender = sender.clone();
+
+    std::thread::spawn(move || {
+        let decoded = load_png(&path)
+            .inspect_err(|err| {
+                warn!(
+                    "Failed to load screensaver image {}: {err:?}",
+                    path.display()
+                );
+            })
+            .ok();
+
+        // Fails if the screensaver stopped in the meantime
+        let _ = sender.send((index, decoded));
+    });
+}
+
+/// Decodes a PNG into premultiplied 8-bit RGBA.
+fn load_png(path: &Path) -> anyhow::Result<(Vec<u8>, Size<i32, Buffer>)> {
+    let file = File::open(path).context("failed to open file")?;
+
+    let mut decoder = png::Decoder::new(BufReader::new(file));
+    decoder.set_transformations(png::Transformations::normalize_to_color8());
+
+    let mut reader = decoder.read_info().context("failed to read png header")?;
+    let mut buf = vec![0; reader.output_buffer_size().context("image is too large")?];
+    let info = reader
+        .next_frame(&mut buf)
+        .context("failed to decode png")?;
+    buf.truncate(info.buffer_size());
+
+    let mut rgba: Vec<u8> = match info.color_type {
+        png::ColorType::Rgba => buf,
+        png::ColorType::Rgb => buf
+            .chunks_exact(3)
+            .flat_map(|px| [px[0], px[1], px[2], u8::MAX])
+            .collect(),
+        png::ColorType::GrayscaleAlpha => buf
+            .chunks_exact(2)
+            .flat_map(|px| [px[0], px[0], px[0], px[1]])
+            .collect(),
+        png::ColorType::Grayscale => buf
+            .into_iter()
+            .flat_map(|gray| [gray, gray, gray, u8::MAX])
+            .collect(),
+        png::ColorType::Indexed => anyhow::bail!("indexed png was not expanded"),
+    };
+
+    for px in rgba.chunks_exact_mut(4) {
+        let alpha = px[3] as u16;
+        for channel in &mut px[..3] {
+            *channel = (*channel as u16 * alpha / u8::MAX as u16) as u8;
+        }
+    }
+
+    Ok((rgba, Size::from((info.width as i32, info.height as i32))))
+}
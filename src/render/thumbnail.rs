@@ -0,0 +1,285 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Rendering of output, tag, and window thumbnails for the snowcap-thumbnail protocol.
+
+use smithay::{
+    backend::renderer::{
+        Bind, Color32F, ExportMem, Offscreen, buffer_dimensions,
+        damage::OutputDamageTracker,
+        element::utils::RescaleRenderElement,
+        gles::{GlesRenderbuffer, GlesRenderer},
+    },
+    output::Output,
+    reexports::wayland_server::protocol::wl_buffer::WlBuffer,
+    utils::{Logical, Rectangle, Scale, Size, Transform},
+    wayland::{
+        dmabuf::get_dmabuf,
+        shm::{shm_format_to_fourcc, with_buffer_contents},
+    },
+};
+use tracing::error;
+
+use crate::{
+    protocol::snowcap_thumbnail::ThumbnailSource,
+    state::{Pinnacle, State},
+    tag::TagId,
+    window::window_state::WindowId,
+};
+
+use super::{
+    output_render_elements,
+    peek::grid_render_elements,
+    util::{DynElement, blit},
+};
+
+impl Pinnacle {
+    /// Returns the output a thumbnail's source is on.
+    pub fn thumbnail_source_output(&self, source: &ThumbnailSource) -> Option<Output> {
+        match source {
+            ThumbnailSource::Output(output) => output.upgrade(),
+            ThumbnailSource::Tag(tag_id) => TagId::new(*tag_id).tag(self)?.output(self),
+            ThumbnailSource::Window(window_id) => WindowId(*window_id).window(self)?.output(self),
+        }
+    }
+
+    /// Returns the logical size of a thumbnail's source, or `None` if it doesn't exist.
+    ///
+    /// Outputs and tags have the size of their output. Windows have their own size.
+    pub fn thumbnail_source_size(&self, source: &ThumbnailSource) -> Option<Size<i32, Logical>> {
+        if let ThumbnailSource::Window(window_id) = source {
+            return Some(WindowId(*window_id).window(self)?.geometry().size);
+        }
+
+        let output = self.thumbnail_source_output(source)?;
+        self.space.output_geometry(&output).map(|geo| geo.size)
+    }
+}
+
+impl State {
+    /// Updates the buffer size of all thumbnails and renders requested captures.
+    pub fn process_thumbnails(&mut self) {
+        let _span = tracy_client::span!();
+
+        let sources = self
+            .pinnacle
+            .snowcap_thumbnail_state
+            .thumbnails_mut()
+            .map(|thumbnail| thumbnail.source.clone())
+            .collect::<Vec<_>>();
+
+        let source_sizes = sources
+            .iter()
+            .map(|source| self.pinnacle.thumbnail_source_size(source))
+            .collect::<Vec<_>>();
+
+        let captures = self
+            .pinnacle
+            .snowcap_thumbnail_state
+            .thumbnails_mut()
+            .zip(source_sizes)
+            .filter_map(|(thumbnail, source_size)| {
+                thumbnail.update_source_size(source_size);
+                let buffer = thumbnail.take_pending()?;
+                Some((
+                    thumbnail.resource().clone(),
+                    thumbnail.source.clone(),
+                    buffer,
+                ))
+            })
+            .collect::<Vec<_>>();
+
+        for (thumbnail, source, buffer) in captures {
+            // Thumbnails would show what the session lock is hiding
+            if !self.pinnacle.lock_state.is_unlocked() {
+                thumbnail.failed();
+                continue;
+            }
+
+            if self.capture_thumbnail(&source, &buffer) {
+                thumbnail.ready();
+            } else {
+                thumbnail.failed();
+            }
+        }
+    }
+
+    /// Renders a thumbnail's source into `buffer`, scaled down to its size.
+    ///
+    /// Active tags are drawn as their output. Inactive tags are drawn as a grid
+    /// of their windows, like when peeking at them.
+    ///
+    /// Returns whether the buffer was written to.
+    /// Callers must make sure the session isn't locked.
+    fn capture_thumbnail(&mut self, source: &ThumbnailSource, buffer: &WlBuffer) -> bool {
+        let _span = tracy_client::span!();
+
+        if let ThumbnailSource::Window(window_id) = source {
+            return self.capture_window_thumbnail(WindowId(*window_id), buffer);
+        }
+
+        let Some(output) = self.pinnacle.thumbnail_source_output(source) else {
+            return false;
+        };
+
+        let Some(output_size) = self
+            .pinnacle
+            .space
+            .output_geometry(&output)
+            .map(|geo| geo.size)
+        else {
+            return false;
+        };
+
+        let Some(buffer_size) = buffer_dimensions(buffer) else {
+            return false;
+        };
+
+        if output_size.is_empty() || buffer_size.is_empty() {
+            return false;
+        }
+
+        let scale = buffer_size.w as f64 / output_size.w as f64;
+        let output_scale = output.current_scale().fractional_scale();
+
+        let inactive_tag = match source {
+            ThumbnailSource::Output(_) | ThumbnailSource::Window(_) => None,
+            ThumbnailSource::Tag(tag_id) => TagId::new(*tag_id)
+                .tag(&self.pinnacle)
+                .filter(|tag| !tag.active()),
+        };
+
+        let snapshots = inactive_tag
+            .as_ref()
+            .map(|tag| self.tag_window_snapshots(tag, Scale::from(scale)))
+            .unwrap_or_default();
+
+        let rendered = self.backend.with_renderer(|renderer| {
+            let elements = if inactive_tag.is_some() {
+                grid_render_elements(renderer, &snapshots, output_size, Scale::from(scale))
+                    .into_iter()
+                    .map(DynElement::owned)
+                    .collect::<Vec<_>>()
+            } else {
+                output_render_elements(
+                    &output,
+                    renderer,
+                    &self.pinnacle.space,
+                    &self.pinnacle.z_index_stack,
+                )
+                .into_iter()
+                .map(|elem| {
+                    RescaleRenderElement::from_element(elem, (0, 0).into(), scale / output_scale)
+                })
+                .map(DynElement::owned)
+                .collect::<Vec<_>>()
+            };
+
+            render_to_buffer(renderer, &elements, buffer)
+        });
+
+        match rendered {
+            Some(Ok(())) => true,
+            Some(Err(err)) => {
+                error!("failed to capture thumbnail: {err}");
+                false
+            }
+            None => false,
+        }
+    }
+
+    /// Renders a window into `buffer`, scaled down to its size.
+    ///
+    /// Windows on inactive tags are drawn as they were last shown.
+    ///
+    /// Returns whether the buffer was written to.
+    fn capture_window_thumbnail(&mut self, window_id: WindowId, buffer: &WlBuffer) -> bool {
+        let Some(window) = window_id.window(&self.pinnacle) else {
+            return false;
+        };
+
+        let window_size = window.geometry().size;
+
+        let Some(buffer_size) = buffer_dimensions(buffer) else {
+            return false;
+        };
+
+        if window_size.is_empty() || buffer_size.is_empty() {
+            return false;
+        }
+
+        let scale = Scale::from(buffer_size.w as f64 / window_size.w as f64);
+
+        let rendered = self.backend.with_renderer(|renderer| {
+            let elements = window
+                .texture_render_elements(renderer, (0, 0).into(), scale, 1.0)
+                .surface_elements
+                .into_iter()
+                .map(DynElement::owned)
+                .collect::<Vec<_>>();
+
+            render_to_buffer(renderer, &elements, buffer)
+        });
+
+        match rendered {
+            Some(Ok(())) => true,
+            Some(Err(err)) => {
+                error!("failed to capture window thumbnail: {err}");
+                false
+            }
+            None => false,
+        }
+    }
+}
+
+/// Renders elements into a shm or dmabuf buffer.
+fn render_to_buffer(
+    renderer: &mut GlesRenderer,
+    elements: &[DynElement<'_, GlesRenderer>],
+    buffer: &WlBuffer,
+) -> anyhow::Result<()> {
+    let buffer_size = buffer_dimensions(buffer).expect("buffer size was checked");
+
+    let mut damage_tracker =
+        OutputDamageTracker::new((buffer_size.w, buffer_size.h), 1.0, Transform::Normal);
+
+    let mut dmabuf;
+    let mut renderbuffer: GlesRenderbuffer;
+    let mut shm_format = None;
+
+    let mut framebuffer = if let Ok(dma) = get_dmabuf(buffer).cloned() {
+        dmabuf = dma;
+        renderer.bind(&mut dmabuf)?
+    } else if let Ok(format) = with_buffer_contents(buffer, |_, _, data| data.format) {
+        let Some(fourcc) = shm_format_to_fourcc(format) else {
+            anyhow::bail!("unsupported shm format {format:?}");
+        };
+        shm_format = Some(fourcc);
+        renderbuffer = renderer.create_buffer(fourcc, buffer_size)?;
+        renderer.bind(&mut renderbuffer)?
+    } else {
+        anyhow::bail!("buffer is neither shm nor dmabuf");
+    };
+
+    damage_tracker.render_output(
+        renderer,
+        &mut framebuffer,
+        0,
+        elements,
+        Color32F::TRANSPARENT,
+    )?;
+
+    if let Some(fourcc) = shm_format {
+        let mapping =
+            renderer.copy_framebuffer(&framebuffer, Rectangle::from_size(buffer_size), fourcc)?;
+        let bytes = renderer.map_texture(&mapping)?;
+
+        blit(
+            bytes,
+            buffer_size,
+            Rectangle::from_size(buffer_size),
+            buffer,
+        )?;
+    }
+
+    Ok(())
+}
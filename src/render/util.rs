@@ -1,6 +1,13 @@
 //! Render utilities.
 
+pub mod accounting;
+pub mod cache;
+pub mod color_filter;
 pub mod damage;
+pub mod effect;
+pub mod gamut;
+pub mod hdr;
+pub mod shader;
 pub mod snapshot;
 pub mod surface;
 
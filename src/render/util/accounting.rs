@@ -0,0 +1,145 @@
+//! Accounting for textures and buffers that Pinnacle holds onto.
+//!
+//! This gives some visibility into how much GPU memory is kept around by
+//! snapshots and cached cursor images.
+
+use std::{cell::Cell, rc::Rc};
+
+/// What a [`TrackedAllocation`] is used for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AllocationKind {
+    /// A snapshot texture, e.g. of a window that is unmapping.
+    Snapshot,
+    /// A cached cursor image buffer.
+    Cursor,
+}
+
+#[derive(Debug, Default)]
+struct Counters {
+    count: Cell<u64>,
+    bytes: Cell<u64>,
+}
+
+/// Running totals of the textures and buffers tracked for a compositor instance.
+#[derive(Debug, Default)]
+pub struct GpuAccounting {
+    snapshots: Counters,
+    cursors: Counters,
+    evicted_snapshots: Cell<u64>,
+}
+
+impl GpuAccounting {
+    fn counters(&self, kind: AllocationKind) -> &Counters {
+        match kind {
+            AllocationKind::Snapshot => &self.snapshots,
+            AllocationKind::Cursor => &self.cursors,
+        }
+    }
+
+    /// Starts accounting for an allocation of `bytes` bytes.
+    pub fn track(self: &Rc<Self>, kind: AllocationKind, bytes: u64) -> TrackedAllocation {
+        let counters = self.counters(kind);
+        counters.count.set(counters.count.get() + 1);
+        counters.bytes.set(counters.bytes.get() + bytes);
+
+        TrackedAllocation {
+            accounting: self.clone(),
+            kind,
+            bytes,
+        }
+    }
+
+    /// Records that `count` snapshots were dropped before they were done being used.
+    pub fn record_evicted_snapshots(&self, count: usize) {
+        self.evicted_snapshots
+            .set(self.evicted_snapshots.get() + count as u64);
+    }
+
+    /// Returns the current totals of tracked textures and buffers.
+    pub fn stats(&self) -> GpuStats {
+        GpuStats {
+            snapshot_textures: self.snapshots.count.get(),
+            snapshot_texture_bytes: self.snapshots.bytes.get(),
+            cursor_buffers: self.cursors.count.get(),
+            cursor_buffer_bytes: self.cursors.bytes.get(),
+            evicted_snapshots: self.evicted_snapshots.get(),
+        }
+    }
+}
+
+/// A texture or buffer that is being accounted for.
+///
+/// It stops being accounted for once this is dropped, so this should be stored
+/// alongside what it tracks.
+#[derive(Debug)]
+pub struct TrackedAllocation {
+    accounting: Rc<GpuAccounting>,
+    kind: AllocationKind,
+    bytes: u64,
+}
+
+impl Drop for TrackedAllocation {
+    fn drop(&mut self) {
+        let counters = self.accounting.counters(self.kind);
+        counters.count.set(counters.count.get() - 1);
+        counters.bytes.set(counters.bytes.get() - self.bytes);
+    }
+}
+
+/// Current totals of tracked textures and buffers.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct GpuStats {
+    /// The number of live snapshot textures.
+    pub snapshot_textures: u64,
+    /// The total size of live snapshot textures, in bytes.
+    pub snapshot_texture_bytes: u64,
+    /// The number of cached cursor buffers.
+    pub cursor_buffers: u64,
+    /// The total size of cached cursor buffers, in bytes.
+    pub cursor_buffer_bytes: u64,
+    /// The number of snapshots that have been evicted over the lifetime of the compositor.
+    pub evicted_snapshots: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dropping_allocation_stops_tracking_it() {
+        let accounting = Rc::new(GpuAccounting::default());
+
+        let snapshot = accounting.track(AllocationKind::Snapshot, 16);
+        let cursor = accounting.track(AllocationKind::Cursor, 4);
+
+        assert_eq!(
+            accounting.stats(),
+            GpuStats {
+                snapshot_textures: 1,
+                snapshot_texture_bytes: 16,
+                cursor_buffers: 1,
+                cursor_buffer_bytes: 4,
+                evicted_snapshots: 0,
+            }
+        );
+
+        drop(snapshot);
+        drop(cursor);
+
+        assert_eq!(accounting.stats(), GpuStats::default());
+    }
+
+    #[test]
+    fn instances_are_accounted_separately() {
+        let first = Rc::new(GpuAccounting::default());
+        let second = Rc::new(GpuAccounting::default());
+
+        let _snapshot = first.track(AllocationKind::Snapshot, 16);
+        second.record_evicted_snapshots(2);
+
+        assert_eq!(first.stats().snapshot_textures, 1);
+        assert_eq!(first.stats().evicted_snapshots, 0);
+        assert_eq!(second.stats().snapshot_textures, 0);
+        assert_eq!(second.stats().evicted_snapshots, 2);
+    }
+}
@@ -0,0 +1,252 @@
+//! Caching of the surfaces that make up a window's render elements.
+//!
+//! Render elements borrow the renderer they were created with (e.g. `UdevRenderer<'_>`),
+//! so they can't be kept across frames. Instead, this caches which surfaces a window
+//! consists of and where they are, which is what walking the surface trees every frame
+//! would otherwise compute. The textures backing those surfaces are already kept per
+//! renderer by smithay, so recreating the elements from a cached plan doesn't reimport
+//! any buffers.
+//!
+//! Entries go out of date whenever a surface belonging to the window commits or is destroyed,
+//! and are dropped whenever a decoration is added to or removed from the window. Commits are
+//! counted on the root surfaces themselves, so a commit doesn't need to find its window.
+
+use smithay::{
+    backend::renderer::{
+        element::{self, surface::WaylandSurfaceRenderElement},
+        utils::RendererSurfaceStateUserData,
+    },
+    reexports::wayland_server::{Resource, protocol::wl_surface::WlSurface},
+    utils::{Logical, Physical, Point, Scale},
+    wayland::compositor::{self, TraversalAction},
+};
+use std::{cell::Cell, sync::Arc};
+
+use tracing::warn;
+
+use crate::render::PRenderer;
+
+/// The maximum number of render plans kept per window.
+///
+/// Windows are usually rendered with a single location and scale; the extra entries
+/// are for windows spanning multiple outputs.
+const MAX_ENTRIES: usize = 4;
+
+/// A mapped surface along with the location it should be rendered at.
+#[derive(Debug, Clone)]
+pub struct PlannedSurface {
+    pub surface: WlSurface,
+    pub location: Point<f64, Physical>,
+}
+
+/// The surfaces that make up a window, ordered from top to bottom.
+#[derive(Debug, Clone, Default)]
+pub struct WindowRenderPlan {
+    pub surfaces: Vec<PlannedSurface>,
+    pub popups: Vec<PlannedSurface>,
+    /// The root surfaces whose trees this plan was collected from.
+    pub roots: Vec<WlSurface>,
+}
+
+/// The number of times a root surface's plans were invalidated.
+#[derive(Debug, Default)]
+struct PlanGeneration(Cell<u64>);
+
+fn plan_generation(root: &WlSurface) -> u64 {
+    compositor::with_states(root, |states| {
+        states
+            .data_map
+            .get::<PlanGeneration>()
+            .map_or(0, |generation| generation.0.get())
+    })
+}
+
+/// Marks the render plans collected from `root`'s surface tree as out of date.
+pub fn invalidate_render_plans(root: &WlSurface) {
+    if !root.is_alive() {
+        return;
+    }
+
+    compositor::with_states(root, |states| {
+        let generation = states.data_map.get_or_insert(PlanGeneration::default);
+        generation.0.set(generation.0.get() + 1);
+    });
+}
+
+/// What a [`WindowRenderPlan`] was computed for.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RenderPlanKey {
+    pub location: Point<i32, Logical>,
+    pub scale: Scale<f64>,
+    pub include_decorations: bool,
+    pub has_ssd: bool,
+}
+
+/// A per-window cache of [`WindowRenderPlan`]s.
+#[derive(Debug, Default)]
+pub struct RenderPlanCache {
+    /// Plans along with the generations of their roots when they were cached.
+    entries: Vec<(RenderPlanKey, Arc<WindowRenderPlan>, Vec<u64>)>,
+}
+
+impl RenderPlanCache {
+    /// Returns the cached plan for `key`, if any and it's not out of date.
+    pub fn get(&self, key: &RenderPlanKey) -> Option<Arc<WindowRenderPlan>> {
+        let (_, plan, generations) = self.entries.iter().find(|(k, ..)| k == key)?;
+
+        let up_to_date = plan
+            .roots
+            .iter()
+            .zip(generations)
+            .all(|(root, generation)| root.is_alive() && plan_generation(root) == *generation);
+
+        up_to_date.then(|| plan.clone())
+    }
+
+    /// Caches a plan for `key`.
+    pub fn insert(&mut self, key: RenderPlanKey, plan: Arc<WindowRenderPlan>) {
+        self.entries.retain(|(k, ..)| *k != key);
+
+        if self.entries.len() >= MAX_ENTRIES {
+            self.entries.remove(0);
+        }
+
+        let generations = plan
+            .roots
+            .iter()
+            .map(|root| if root.is_alive() { plan_generation(root) } else { 0 })
+            .collect();
+        self.entries.push((key, plan, generations));
+    }
+
+    /// Drops all cached plans.
+    pub fn invalidate(&mut self) {
+        self.entries.clear();
+    }
+
+    /// Returns whether no plans are cached.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+/// Collects the mapped surfaces in a surface tree along with their locations.
+///
+/// Surfaces are returned in the same order as
+/// [`render_elements_from_surface_tree`][element::surface::render_elements_from_surface_tree]
+/// would create elements for them.
+pub fn planned_surfaces_from_surface_tree(
+    surface: &WlSurface,
+    location: impl Into<Point<i32, Physical>>,
+    scale: impl Into<Scale<f64>>,
+) -> Vec<PlannedSurface> {
+    let location = location.into().to_f64();
+    let scale = scale.into();
+    let mut surfaces = Vec::new();
+
+    let view_offset = |states: &compositor::SurfaceData| {
+        let data = states.data_map.get::<RendererSurfaceStateUserData>()?;
+        let data = data.lock().unwrap();
+        data.view()
+            .map(|view| view.offset.to_f64().to_physical(scale))
+    };
+
+    compositor::with_surface_tree_downward(
+        surface,
+        location,
+        |_, states, location| match view_offset(states) {
+            Some(offset) => TraversalAction::DoChildren(*location + offset),
+            None => TraversalAction::SkipChildren,
+        },
+        |surface, states, location| {
+            if let Some(offset) = view_offset(states) {
+                surfaces.push(PlannedSurface {
+                    surface: surface.clone(),
+                    location: *location + offset,
+                });
+            }
+        },
+        |_, _, _| true,
+    );
+
+    surfaces
+}
+
+/// Creates render elements for the given planned surfaces.
+pub fn render_elements_from_planned_surfaces<R: PRenderer>(
+    renderer: &mut R,
+    surfaces: &[PlannedSurface],
+    alpha: f32,
+) -> Vec<WaylandSurfaceRenderElement<R>> {
+    surfaces
+        .iter()
+        .filter(|planned| planned.surface.is_alive())
+        .filter_map(|PlannedSurface { surface, location }| {
+            compositor::with_states(surface, |states| {
+                WaylandSurfaceRenderElement::from_surface(
+                    renderer,
+                    surface,
+                    states,
+                    *location,
+                    alpha,
+                    element::Kind::Unspecified,
+                )
+            })
+            .inspect_err(|err| warn!("Failed to import surface: {err}"))
+            .ok()
+            .flatten()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(x: i32) -> RenderPlanKey {
+        RenderPlanKey {
+            location: (x, 0).into(),
+            scale: 1.0.into(),
+            include_decorations: true,
+            has_ssd: false,
+        }
+    }
+
+    #[test]
+    fn invalidate_drops_all_plans() {
+        let mut cache = RenderPlanCache::default();
+        cache.insert(key(0), Arc::default());
+        cache.insert(key(1), Arc::default());
+
+        assert!(cache.get(&key(0)).is_some());
+        assert!(cache.get(&key(1)).is_some());
+
+        cache.invalidate();
+
+        assert!(cache.is_empty());
+        assert!(cache.get(&key(0)).is_none());
+        assert!(cache.get(&key(1)).is_none());
+    }
+
+    #[test]
+    fn get_shares_the_cached_plan() {
+        let mut cache = RenderPlanCache::default();
+        let plan = Arc::new(WindowRenderPlan::default());
+        cache.insert(key(0), plan.clone());
+
+        assert!(Arc::ptr_eq(&cache.get(&key(0)).unwrap(), &plan));
+    }
+
+    #[test]
+    fn insert_evicts_oldest_plan() {
+        let mut cache = RenderPlanCache::default();
+        for x in 0..=MAX_ENTRIES as i32 {
+            cache.insert(key(x), Arc::default());
+        }
+
+        assert!(cache.get(&key(0)).is_none());
+        for x in 1..=MAX_ENTRIES as i32 {
+            assert!(cache.get(&key(x)).is_some());
+        }
+    }
+}
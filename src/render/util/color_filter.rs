@@ -0,0 +1,335 @@
+//! Drawing outputs through their color filter.
+//!
+//! Everything on a filtered output is first rendered into a texture, which is then
+//! drawn with the filter's color matrix. Filtering once at the end treats solid colors,
+//! borders, and shaders the same as client surfaces.
+
+#[cfg(feature = "testing")]
+use smithay::backend::renderer::test::DummyRenderer;
+use smithay::{
+    backend::{
+        allocator::Fourcc,
+        renderer::{
+            Bind, Offscreen, RendererSuper,
+            damage::OutputDamageTracker,
+            element::{
+                self, Element, Id, RenderElement,
+                texture::{TextureBuffer, TextureRenderElement},
+            },
+            gles::{GlesRenderer, GlesTexture},
+            utils::{CommitCounter, DamageSet, OpaqueRegions},
+        },
+    },
+    output::Output,
+    utils::{Buffer, Physical, Point, Rectangle, Scale, Size, Transform},
+};
+use tracing::warn;
+
+use crate::{
+    backend::udev::UdevRenderer,
+    render::{AsGlesRenderer, OutputRenderElement, PRenderer, texture::CommonTextureRenderElement},
+    state::WithState,
+};
+
+use super::effect::{EffectRenderElement, color_transform_program};
+
+/// The number of renders whose damage is remembered.
+///
+/// Frames compare against the last few renders at most, older ones are fully redrawn.
+const DAMAGE_HISTORY: usize = 4;
+
+/// The texture an output is rendered into before its color filter is applied.
+#[derive(Debug)]
+pub struct ColorFilterPass {
+    texture: GlesTexture,
+    size: Size<i32, Physical>,
+    scale: Scale<f64>,
+    damage_tracker: OutputDamageTracker,
+    id: Id,
+    commit: CommitCounter,
+    /// Damage of the last few renders along with the commit they made, oldest first.
+    damage: Vec<(CommitCounter, Vec<Rectangle<i32, Physical>>)>,
+    /// Whether the texture holds the last render.
+    rendered: bool,
+}
+
+impl ColorFilterPass {
+    fn new<R>(
+        renderer: &mut R,
+        size: Size<i32, Physical>,
+        scale: Scale<f64>,
+    ) -> anyhow::Result<Self>
+    where
+        R: PRenderer + Offscreen<GlesTexture>,
+    {
+        let buffer_size = size.to_logical(1).to_buffer(1, Transform::Normal);
+        let texture = renderer.create_buffer(Fourcc::Abgr8888, buffer_size)?;
+
+        Ok(Self {
+            texture,
+            size,
+            scale,
+            damage_tracker: OutputDamageTracker::new(size, scale, Transform::Normal),
+            id: Id::new(),
+            commit: CommitCounter::default(),
+            damage: Vec::new(),
+            rendered: false,
+        })
+    }
+
+    /// Renders `elements` into this pass's texture, redrawing only what was damaged.
+    fn render<R>(
+        &mut self,
+        renderer: &mut R,
+        elements: &[OutputRenderElement<R>],
+        clear_color: [f32; 4],
+    ) -> anyhow::Result<()>
+    where
+        R: PRenderer + Bind<GlesTexture>,
+        OutputRenderElement<R>: RenderElement<R>,
+    {
+        let age = usize::from(self.rendered);
+        // Don't reuse contents that a failed render may have left half-drawn
+        self.rendered = false;
+
+        let mut framebuffer = renderer.bind(&mut self.texture)?;
+        let result = self
+            .damage_tracker
+            .render_output(renderer, &mut framebuffer, age, elements, clear_color)
+            .map_err(|err| anyhow::anyhow!("{err:?}"))?;
+
+        if let Some(damage) = result.damage.filter(|damage| !damage.is_empty()) {
+            self.commit.increment();
+            self.damage.push((self.commit, damage.clone()));
+            if self.damage.len() > DAMAGE_HISTORY {
+                self.damage.remove(0);
+            }
+        }
+
+        self.rendered = true;
+
+        Ok(())
+    }
+
+    /// Returns an element that draws the last render.
+    fn render_element(&self, renderer: &mut GlesRenderer) -> FilteredOutputElement {
+        let buffer_rect =
+            Rectangle::from_size(self.size.to_logical(1).to_buffer(1, Transform::Normal));
+        let buffer = TextureBuffer::from_texture(
+            renderer,
+            self.texture.clone(),
+            1,
+            Transform::Normal,
+            Some(vec![buffer_rect]),
+        );
+        let texture = TextureRenderElement::from_texture_buffer(
+            (0.0, 0.0),
+            &buffer,
+            None,
+            None,
+            None,
+            element::Kind::Unspecified,
+        );
+
+        FilteredOutputElement {
+            texture: CommonTextureRenderElement::new(texture),
+            id: self.id.clone(),
+            commit: self.commit,
+            size: self.size,
+            damage: self.damage.clone(),
+        }
+    }
+}
+
+/// Draws all of an output's elements through its color filter, if it has one.
+///
+/// The elements are replaced with a single element that draws them filtered.
+/// If they can't be rendered to a texture, they're left unfiltered.
+pub fn apply_color_filter<R>(
+    output: &Output,
+    elements: &mut Vec<OutputRenderElement<R>>,
+    renderer: &mut R,
+    clear_color: [f32; 4],
+) where
+    R: PRenderer + AsGlesRenderer + Offscreen<GlesTexture> + Bind<GlesTexture>,
+    OutputRenderElement<R>: RenderElement<R>,
+{
+    let _span = tracy_client::span!("apply_color_filter");
+
+    let Some(filter) = output.with_state(|state| state.color_filter) else {
+        output.with_state_mut(|state| state.color_filter_pass = None);
+        return;
+    };
+
+    let Some(mode) = output.current_mode() else {
+        return;
+    };
+
+    let Some(program) = color_transform_program(renderer.as_gles_renderer()) else {
+        return;
+    };
+
+    let size = output.current_transform().transform_size(mode.size);
+    let scale = Scale::from(output.current_scale().fractional_scale());
+
+    // Outputs that changed size or scale get a new texture
+    let pass = output
+        .with_state_mut(|state| state.color_filter_pass.take())
+        .filter(|pass| pass.size == size && pass.scale == scale);
+
+    let mut pass = match pass {
+        Some(pass) => pass,
+        None => match ColorFilterPass::new(renderer, size, scale) {
+            Ok(pass) => pass,
+            Err(err) => {
+                warn!("Failed to create color filter texture: {err}");
+                return;
+            }
+        },
+    };
+
+    if let Err(err) = pass.render(renderer, elements, clear_color) {
+        warn!("Failed to render color filter texture: {err}");
+        return;
+    }
+
+    let element = pass.render_element(renderer.as_gles_renderer());
+    output.with_state_mut(|state| state.color_filter_pass = Some(pass));
+
+    *elements = vec![EffectRenderElement::new(element, program, filter.color_matrix()).into()];
+}
+
+/// An output's elements, drawn from the texture of a [`ColorFilterPass`].
+#[derive(Debug)]
+pub struct FilteredOutputElement {
+    texture: CommonTextureRenderElement,
+    id: Id,
+    commit: CommitCounter,
+    size: Size<i32, Physical>,
+    damage: Vec<(CommitCounter, Vec<Rectangle<i32, Physical>>)>,
+}
+
+impl Element for FilteredOutputElement {
+    fn id(&self) -> &Id {
+        &self.id
+    }
+
+    fn current_commit(&self) -> CommitCounter {
+        self.commit
+    }
+
+    fn src(&self) -> Rectangle<f64, Buffer> {
+        Rectangle::from_size(self.size.to_logical(1).to_buffer(1, Transform::Normal)).to_f64()
+    }
+
+    fn geometry(&self, _scale: Scale<f64>) -> Rectangle<i32, Physical> {
+        Rectangle::from_size(self.size)
+    }
+
+    fn location(&self, _scale: Scale<f64>) -> Point<i32, Physical> {
+        Point::default()
+    }
+
+    fn transform(&self) -> Transform {
+        Transform::Normal
+    }
+
+    fn damage_since(
+        &self,
+        scale: Scale<f64>,
+        commit: Option<CommitCounter>,
+    ) -> DamageSet<i32, Physical> {
+        if commit == Some(self.commit) {
+            return DamageSet::default();
+        }
+
+        let since = commit.and_then(|commit| {
+            self.damage
+                .iter()
+                .position(|(damage_commit, _)| *damage_commit == commit)
+        });
+
+        match since {
+            Some(index) => self.damage[index + 1..]
+                .iter()
+                .flat_map(|(_, damage)| damage.iter().copied())
+                .collect(),
+            None => DamageSet::from_slice(&[self.geometry(scale)]),
+        }
+    }
+
+    fn opaque_regions(&self, scale: Scale<f64>) -> OpaqueRegions<i32, Physical> {
+        OpaqueRegions::from_slice(&[self.geometry(scale)])
+    }
+
+    fn alpha(&self) -> f32 {
+        1.0
+    }
+
+    fn kind(&self) -> element::Kind {
+        element::Kind::Unspecified
+    }
+}
+
+impl RenderElement<GlesRenderer> for FilteredOutputElement {
+    fn draw(
+        &self,
+        frame: &mut <GlesRenderer as RendererSuper>::Frame<'_, '_>,
+        src: Rectangle<f64, Buffer>,
+        dst: Rectangle<i32, Physical>,
+        damage: &[Rectangle<i32, Physical>],
+        opaque_regions: &[Rectangle<i32, Physical>],
+    ) -> Result<(), <GlesRenderer as RendererSuper>::Error> {
+        RenderElement::<GlesRenderer>::draw(&self.texture, frame, src, dst, damage, opaque_regions)
+    }
+
+    fn underlying_storage(
+        &self,
+        renderer: &mut GlesRenderer,
+    ) -> Option<element::UnderlyingStorage<'_>> {
+        let _ = renderer;
+        None
+    }
+}
+
+impl<'a> RenderElement<UdevRenderer<'a>> for FilteredOutputElement {
+    fn draw(
+        &self,
+        frame: &mut <UdevRenderer<'a> as RendererSuper>::Frame<'_, '_>,
+        src: Rectangle<f64, Buffer>,
+        dst: Rectangle<i32, Physical>,
+        damage: &[Rectangle<i32, Physical>],
+        opaque_regions: &[Rectangle<i32, Physical>],
+    ) -> Result<(), <UdevRenderer<'a> as RendererSuper>::Error> {
+        RenderElement::<UdevRenderer<'a>>::draw(
+            &self.texture,
+            frame,
+            src,
+            dst,
+            damage,
+            opaque_regions,
+        )
+    }
+
+    fn underlying_storage(
+        &self,
+        renderer: &mut UdevRenderer<'a>,
+    ) -> Option<element::UnderlyingStorage<'_>> {
+        let _ = renderer;
+        None
+    }
+}
+
+#[cfg(feature = "testing")]
+impl RenderElement<DummyRenderer> for FilteredOutputElement {
+    fn draw(
+        &self,
+        _frame: &mut <DummyRenderer as RendererSuper>::Frame<'static, 'static>,
+        _src: Rectangle<f64, Buffer>,
+        _dst: Rectangle<i32, Physical>,
+        _damage: &[Rectangle<i32, Physical>],
+        _opaque_regions: &[Rectangle<i32, Physical>],
+    ) -> Result<(), <DummyRenderer as RendererSuper>::Error> {
+        Ok(())
+    }
+}
@@ -0,0 +1,592 @@
+//! Color transforms applied while rendering, like inactive window dimming and color filters.
+
+use std::collections::HashMap;
+
+#[cfg(feature = "testing")]
+use smithay::backend::renderer::test::DummyRenderer;
+use smithay::{
+    backend::renderer::{
+        RendererSuper,
+        element::{self, Element, Id, RenderElement},
+        gles::{GlesRenderer, GlesTexProgram, Uniform, UniformName, UniformType, UniformValue},
+        utils::{CommitCounter, DamageSet, OpaqueRegions},
+    },
+    utils::{Buffer, Physical, Point, Rectangle, Scale, Transform},
+};
+use tracing::warn;
+
+use crate::{backend::udev::UdevRenderer, state::WithState, tag::Tag, window::WindowElement};
+
+const COLOR_TRANSFORM_SHADER: &str = r#"
+#version 100
+
+//_DEFINES_
+
+#if defined(EXTERNAL)
+#extension GL_OES_EGL_image_external : require
+#endif
+
+precision mediump float;
+#if defined(EXTERNAL)
+uniform samplerExternalOES tex;
+#else
+uniform sampler2D tex;
+#endif
+
+uniform float alpha;
+varying vec2 v_coords;
+
+#if defined(DEBUG_FLAGS)
+uniform float tint;
+#endif
+
+uniform mat4 color_matrix;
+
+void main() {
+    vec4 color = texture2D(tex, v_coords);
+
+#if defined(NO_ALPHA)
+    color = vec4(color.rgb, 1.0);
+#endif
+
+    // Colors are premultiplied, so transforms that need an offset
+    // (like inversion) can use alpha as the constant term.
+    color = clamp(color_matrix * color, 0.0, 1.0);
+    color.rgb = min(color.rgb, vec3(color.a));
+
+    color = color * alpha;
+
+#if defined(DEBUG_FLAGS)
+    if (tint == 1.0)
+        color = vec4(0.0, 0.2, 0.0, 0.2) + color * 0.8;
+#endif
+
+    gl_FragColor = color;
+}
+"#;
+
+/// Rec. 709 luma coefficients.
+const LUMA: [f32; 3] = [0.2126, 0.7152, 0.0722];
+
+/// A 4x4 matrix applied to premultiplied RGBA colors, row-major.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ColorMatrix(pub [[f32; 4]; 4]);
+
+impl ColorMatrix {
+    pub const IDENTITY: Self = Self([
+        [1.0, 0.0, 0.0, 0.0],
+        [0.0, 1.0, 0.0, 0.0],
+        [0.0, 0.0, 1.0, 0.0],
+        [0.0, 0.0, 0.0, 1.0],
+    ]);
+
+    /// Creates a matrix that transforms RGB with the given 3x3 matrix, leaving alpha alone.
+    pub fn from_rgb(rgb: [[f32; 3]; 3]) -> Self {
+        let mut matrix = Self::IDENTITY;
+        for (row, rgb_row) in matrix.0.iter_mut().zip(rgb) {
+            row[..3].copy_from_slice(&rgb_row);
+        }
+        matrix
+    }
+
+    /// Creates a matrix that darkens colors by `amount`, from 0.0 to 1.0.
+    pub fn dim(amount: f32) -> Self {
+        let factor = 1.0 - amount;
+        Self::from_rgb([[factor, 0.0, 0.0], [0.0, factor, 0.0], [0.0, 0.0, factor]])
+    }
+
+    /// Creates a matrix that desaturates colors by `amount`, from 0.0 to 1.0.
+    pub fn desaturate(amount: f32) -> Self {
+        let mut rgb = [[0.0; 3]; 3];
+        for (i, row) in rgb.iter_mut().enumerate() {
+            for (j, value) in row.iter_mut().enumerate() {
+                *value = identity(i, j) + (LUMA[j] - identity(i, j)) * amount;
+            }
+        }
+        Self::from_rgb(rgb)
+    }
+
+    /// Creates a matrix that inverts colors.
+    pub fn invert() -> Self {
+        Self([
+            [-1.0, 0.0, 0.0, 1.0],
+            [0.0, -1.0, 0.0, 1.0],
+            [0.0, 0.0, -1.0, 1.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+
+    /// Creates a daltonization matrix that shifts colors lost under the given
+    /// color blindness simulation into channels that can still be seen.
+    fn daltonize(simulation: [[f32; 3]; 3]) -> Self {
+        // Error shift from Fidaner et al.
+        const SHIFT: [[f32; 3]; 3] = [[0.0, 0.0, 0.0], [0.7, 1.0, 0.0], [0.7, 0.0, 1.0]];
+
+        // I + SHIFT * (I - simulation)
+        let mut rgb = [[0.0; 3]; 3];
+        for (i, row) in rgb.iter_mut().enumerate() {
+            for (j, value) in row.iter_mut().enumerate() {
+                let lost = (0..3)
+                    .map(|k| SHIFT[i][k] * (identity(k, j) - simulation[k][j]))
+                    .sum::<f32>();
+                *value = identity(i, j) + lost;
+            }
+        }
+        Self::from_rgb(rgb)
+    }
+
+    /// Returns a matrix that applies `self`, then `next`.
+    pub fn then(self, next: Self) -> Self {
+        let mut result = [[0.0; 4]; 4];
+        for (i, row) in result.iter_mut().enumerate() {
+            for (j, value) in row.iter_mut().enumerate() {
+                *value = (0..4).map(|k| next.0[i][k] * self.0[k][j]).sum();
+            }
+        }
+        Self(result)
+    }
+
+    /// Applies this matrix to a premultiplied color.
+    pub fn apply(&self, color: [f32; 4]) -> [f32; 4] {
+        let mut result = [0.0; 4];
+        for (value, row) in result.iter_mut().zip(self.0) {
+            let transformed = row.iter().zip(color).map(|(a, b)| a * b).sum::<f32>();
+            *value = transformed.clamp(0.0, 1.0);
+        }
+        let alpha = result[3];
+        for value in &mut result[..3] {
+            *value = value.min(alpha);
+        }
+        result
+    }
+
+    /// Returns this matrix in the column-major layout GLSL expects.
+    fn to_columns(self) -> [[f32; 4]; 4] {
+        let mut columns = [[0.0; 4]; 4];
+        for (i, row) in self.0.iter().enumerate() {
+            for (j, value) in row.iter().enumerate() {
+                columns[j][i] = *value;
+            }
+        }
+        columns
+    }
+}
+
+fn identity(i: usize, j: usize) -> f32 {
+    if i == j { 1.0 } else { 0.0 }
+}
+
+/// What the inactive effect applies to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InactiveEffectTarget {
+    /// Applies to every window on outputs that don't have focus.
+    UnfocusedOutputs,
+    /// Applies to every window but the keyboard-focused one.
+    UnfocusedWindows,
+}
+
+/// Dimming and desaturation applied to inactive windows.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct InactiveEffect {
+    pub target: InactiveEffectTarget,
+    /// How much to darken windows, from 0.0 to 1.0.
+    pub dim: f32,
+    /// How much to desaturate windows, from 0.0 to 1.0.
+    pub desaturate: f32,
+}
+
+impl InactiveEffect {
+    pub fn color_matrix(&self) -> ColorMatrix {
+        ColorMatrix::desaturate(self.desaturate).then(ColorMatrix::dim(self.dim))
+    }
+}
+
+/// The inactive effect as currently applied to an output.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AppliedInactiveEffect {
+    pub effect: InactiveEffect,
+    /// A window that is exempt from the effect.
+    pub except: Option<WindowElement>,
+}
+
+impl AppliedInactiveEffect {
+    /// Returns whether the effect should be applied to the given window.
+    pub fn applies_to(&self, window: &WindowElement) -> bool {
+        self.except.as_ref() != Some(window)
+    }
+}
+
+/// Returns the combined color matrix of the effects applied to a window,
+/// or `None` if its colors aren't changed.
+pub fn window_color_matrix(
+    window: &WindowElement,
+    inactive_effect: Option<&AppliedInactiveEffect>,
+) -> Option<ColorMatrix> {
+    let (tag_effect, unresponsive_effect) =
+        window.with_state(|state| (state.tag_effect, state.unresponsive_effect));
+
+    [
+        tag_effect.and_then(|effect| effect.color_matrix()),
+        unresponsive_effect.and_then(|effect| effect.color_matrix()),
+        inactive_effect
+            .filter(|effect| effect.applies_to(window))
+            .map(|effect| effect.effect.color_matrix()),
+    ]
+    .into_iter()
+    .flatten()
+    .reduce(ColorMatrix::then)
+}
+
+/// Ids for a window's elements while they're drawn with an effect.
+///
+/// Changing a window's effect doesn't change its elements, so damage tracking
+/// wouldn't redraw it. Drawing the elements under their own ids that are replaced
+/// whenever the effect changes redraws only that window.
+#[derive(Debug, Default)]
+pub struct EffectIds {
+    /// Per output name, the matrix the ids were made for and the ids by element id.
+    by_output: HashMap<String, (ColorMatrix, HashMap<Id, Id>)>,
+}
+
+impl EffectIds {
+    /// Returns the id to draw the element with the id `inner` on `output` with `matrix`.
+    pub fn id(&mut self, output: &str, matrix: ColorMatrix, inner: &Id) -> Id {
+        let (last_matrix, ids) = self
+            .by_output
+            .entry(output.to_string())
+            .or_insert_with(|| (matrix, HashMap::new()));
+
+        if *last_matrix != matrix {
+            *last_matrix = matrix;
+            ids.clear();
+        }
+
+        ids.entry(inner.clone()).or_insert_with(Id::new).clone()
+    }
+}
+
+/// Opacity, dimming, and desaturation applied to a window.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WindowEffect {
+    /// The window's opacity, from 0.0 to 1.0.
+    pub opacity: f32,
+    /// How much to darken the window, from 0.0 to 1.0.
+    pub dim: f32,
+    /// How much to desaturate the window, from 0.0 to 1.0.
+    pub desaturate: f32,
+}
+
+impl Default for WindowEffect {
+    fn default() -> Self {
+        Self {
+            opacity: 1.0,
+            dim: 0.0,
+            desaturate: 0.0,
+        }
+    }
+}
+
+impl WindowEffect {
+    /// Combines this effect with `other` applied on top of it.
+    pub fn stack(self, other: Self) -> Self {
+        Self {
+            opacity: self.opacity * other.opacity,
+            dim: 1.0 - (1.0 - self.dim) * (1.0 - other.dim),
+            desaturate: 1.0 - (1.0 - self.desaturate) * (1.0 - other.desaturate),
+        }
+    }
+
+    /// Returns the color matrix for this effect, or `None` if it doesn't change colors.
+    pub fn color_matrix(&self) -> Option<ColorMatrix> {
+        (self.dim > 0.0 || self.desaturate > 0.0)
+            .then(|| ColorMatrix::desaturate(self.desaturate).then(ColorMatrix::dim(self.dim)))
+    }
+}
+
+/// What a [`TagEffectRule`] applies to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TagEffectTarget {
+    /// Applies to windows on the tag.
+    WindowsOnTag,
+    /// Applies to windows on the tag's output that aren't on the tag.
+    OtherWindows,
+}
+
+/// An effect applied to windows while a tag is active.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TagEffectRule {
+    pub tag: Tag,
+    pub target: TagEffectTarget,
+    pub effect: WindowEffect,
+}
+
+/// A color filter applied to everything on an output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorFilter {
+    /// Inverts colors.
+    Invert,
+    /// Removes all color.
+    Grayscale,
+    /// Corrects colors for deuteranopia.
+    DeuteranopiaCorrection,
+    /// Corrects colors for protanopia.
+    ProtanopiaCorrection,
+}
+
+impl ColorFilter {
+    pub fn color_matrix(&self) -> ColorMatrix {
+        // Simulation matrices from Machado et al. 2009, severity 1.0
+        match self {
+            ColorFilter::Invert => ColorMatrix::invert(),
+            ColorFilter::Grayscale => ColorMatrix::desaturate(1.0),
+            ColorFilter::DeuteranopiaCorrection => ColorMatrix::daltonize([
+                [0.367322, 0.860646, -0.227968],
+                [0.280085, 0.672501, 0.047413],
+                [-0.011820, 0.042940, 0.968881],
+            ]),
+            ColorFilter::ProtanopiaCorrection => ColorMatrix::daltonize([
+                [0.152286, 1.052583, -0.204868],
+                [0.114503, 0.786281, 0.099216],
+                [-0.003882, -0.048116, 1.051998],
+            ]),
+        }
+    }
+}
+
+struct ColorTransformProgram(Option<GlesTexProgram>);
+
+/// Gets the color transform shader, compiling it if needed.
+///
+/// Returns `None` if the shader failed to compile.
+pub fn color_transform_program(renderer: &mut GlesRenderer) -> Option<GlesTexProgram> {
+    if let Some(program) = renderer
+        .egl_context()
+        .user_data()
+        .get::<ColorTransformProgram>()
+    {
+        return program.0.clone();
+    }
+
+    let program = renderer
+        .compile_custom_texture_shader(
+            COLOR_TRANSFORM_SHADER,
+            &[UniformName::new("color_matrix", UniformType::Matrix4x4)],
+        )
+        .inspect_err(|err| warn!("Failed to compile color transform shader: {err}"))
+        .ok();
+
+    renderer
+        .egl_context()
+        .user_data()
+        .insert_if_missing(|| ColorTransformProgram(program.clone()));
+
+    program
+}
+
+/// A render element that draws its inner element with a [`ColorMatrix`] applied.
+#[derive(Debug)]
+pub struct EffectRenderElement<E> {
+    inner: E,
+    program: GlesTexProgram,
+    matrix: ColorMatrix,
+    /// Uniforms the program takes besides `color_matrix`.
+    extra_uniforms: Vec<Uniform<'static>>,
+    /// The id to use instead of the inner element's.
+    id: Option<Id>,
+}
+
+impl<E> EffectRenderElement<E> {
+    pub fn new(inner: E, program: GlesTexProgram, matrix: ColorMatrix) -> Self {
+        Self {
+            inner,
+            program,
+            matrix,
+            extra_uniforms: Vec::new(),
+            id: None,
+        }
+    }
+
+    /// Uses `id` instead of the inner element's id, see [`EffectIds`].
+    pub fn with_id(self, id: Id) -> Self {
+        Self {
+            id: Some(id),
+            ..self
+        }
+    }
+
+    /// Draws this element with another shader that also takes a `color_matrix` uniform.
+    ///
+    /// Uniforms set with [`Self::with_uniforms`] are dropped, as the new shader may not
+    /// take them.
+    pub fn with_program(self, program: GlesTexProgram) -> Self {
+        Self {
+            program,
+            extra_uniforms: Vec::new(),
+            ..self
+        }
+    }
+
+    /// Passes additional uniforms to this element's shader.
+    pub fn with_uniforms(self, extra_uniforms: Vec<Uniform<'static>>) -> Self {
+        Self {
+            extra_uniforms,
+            ..self
+        }
+    }
+
+    fn uniforms(&self) -> Vec<Uniform<'static>> {
+        let mut uniforms = vec![Uniform::new(
+            "color_matrix",
+            UniformValue::Matrix4x4 {
+                matrices: vec![self.matrix.to_columns()],
+                transpose: false,
+            },
+        )];
+        uniforms.extend(self.extra_uniforms.iter().cloned());
+        uniforms
+    }
+}
+
+impl<E: Element> Element for EffectRenderElement<E> {
+    fn id(&self) -> &element::Id {
+        self.id.as_ref().unwrap_or_else(|| self.inner.id())
+    }
+
+    fn current_commit(&self) -> CommitCounter {
+        self.inner.current_commit()
+    }
+
+    fn src(&self) -> Rectangle<f64, Buffer> {
+        self.inner.src()
+    }
+
+    fn geometry(&self, scale: Scale<f64>) -> Rectangle<i32, Physical> {
+        self.inner.geometry(scale)
+    }
+
+    fn location(&self, scale: Scale<f64>) -> Point<i32, Physical> {
+        self.inner.location(scale)
+    }
+
+    fn transform(&self) -> Transform {
+        self.inner.transform()
+    }
+
+    fn damage_since(
+        &self,
+        scale: Scale<f64>,
+        commit: Option<CommitCounter>,
+    ) -> DamageSet<i32, Physical> {
+        self.inner.damage_since(scale, commit)
+    }
+
+    fn opaque_regions(&self, scale: Scale<f64>) -> OpaqueRegions<i32, Physical> {
+        self.inner.opaque_regions(scale)
+    }
+
+    fn alpha(&self) -> f32 {
+        self.inner.alpha()
+    }
+
+    fn kind(&self) -> element::Kind {
+        self.inner.kind()
+    }
+}
+
+impl<E: RenderElement<GlesRenderer>> RenderElement<GlesRenderer> for EffectRenderElement<E> {
+    fn draw(
+        &self,
+        frame: &mut <GlesRenderer as RendererSuper>::Frame<'_, '_>,
+        src: Rectangle<f64, Buffer>,
+        dst: Rectangle<i32, Physical>,
+        damage: &[Rectangle<i32, Physical>],
+        opaque_regions: &[Rectangle<i32, Physical>],
+    ) -> Result<(), <GlesRenderer as RendererSuper>::Error> {
+        frame.override_default_tex_program(self.program.clone(), self.uniforms());
+        let res = self.inner.draw(frame, src, dst, damage, opaque_regions);
+        frame.clear_tex_program_override();
+        res
+    }
+
+    fn underlying_storage(
+        &self,
+        renderer: &mut GlesRenderer,
+    ) -> Option<element::UnderlyingStorage<'_>> {
+        // Returning the underlying storage would allow the element to be scanned out
+        // directly, skipping the shader.
+        let _ = renderer;
+        None
+    }
+}
+
+impl<'a, E: RenderElement<UdevRenderer<'a>>> RenderElement<UdevRenderer<'a>>
+    for EffectRenderElement<E>
+{
+    fn draw(
+        &self,
+        frame: &mut <UdevRenderer<'a> as RendererSuper>::Frame<'_, '_>,
+        src: Rectangle<f64, Buffer>,
+        dst: Rectangle<i32, Physical>,
+        damage: &[Rectangle<i32, Physical>],
+        opaque_regions: &[Rectangle<i32, Physical>],
+    ) -> Result<(), <UdevRenderer<'a> as RendererSuper>::Error> {
+        frame
+            .as_mut()
+            .override_default_tex_program(self.program.clone(), self.uniforms());
+        let res = self.inner.draw(frame, src, dst, damage, opaque_regions);
+        frame.as_mut().clear_tex_program_override();
+        res
+    }
+
+    fn underlying_storage(
+        &self,
+        renderer: &mut UdevRenderer<'a>,
+    ) -> Option<element::UnderlyingStorage<'_>> {
+        let _ = renderer;
+        None
+    }
+}
+
+#[cfg(feature = "testing")]
+impl<E: RenderElement<DummyRenderer>> RenderElement<DummyRenderer> for EffectRenderElement<E> {
+    fn draw(
+        &self,
+        frame: &mut <DummyRenderer as RendererSuper>::Frame<'static, 'static>,
+        src: Rectangle<f64, Buffer>,
+        dst: Rectangle<i32, Physical>,
+        damage: &[Rectangle<i32, Physical>],
+        opaque_regions: &[Rectangle<i32, Physical>],
+    ) -> Result<(), <DummyRenderer as RendererSuper>::Error> {
+        self.inner.draw(frame, src, dst, damage, opaque_regions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn approx_eq(a: [f32; 4], b: [f32; 4]) -> bool {
+        a.iter().zip(b).all(|(a, b)| (a - b).abs() < 1e-4)
+    }
+
+    #[test]
+    fn invert_respects_premultiplied_alpha() {
+        let color = [0.1, 0.2, 0.3, 0.5];
+        let inverted = ColorMatrix::invert().apply(color);
+        assert!(approx_eq(inverted, [0.4, 0.3, 0.2, 0.5]));
+    }
+
+    #[test]
+    fn full_desaturation_is_gray() {
+        let [r, g, b, a] = ColorMatrix::desaturate(1.0).apply([1.0, 0.0, 0.0, 1.0]);
+        assert!(approx_eq([r, g, b, a], [LUMA[0], LUMA[0], LUMA[0], 1.0]));
+    }
+
+    #[test]
+    fn then_applies_in_order() {
+        let color = [0.2, 0.4, 0.6, 1.0];
+        let matrix = ColorMatrix::invert().then(ColorMatrix::dim(0.5));
+        let expected = ColorMatrix::dim(0.5).apply(ColorMatrix::invert().apply(color));
+        assert!(approx_eq(matrix.apply(color), expected));
+    }
+}
@@ -0,0 +1,354 @@
+//! sRGB emulation for wide-gamut outputs.
+//!
+//! Clients draw sRGB content, which looks oversaturated when shown as-is on a monitor
+//! with wider primaries. Emulation converts colors from sRGB to the monitor's primaries
+//! in linear light, keeping white white, so they look as they would on an sRGB monitor.
+//!
+//! The monitor is assumed to use the sRGB transfer function, so converting with a matrix
+//! gives the same result as a full 3D LUT built from the primaries its EDID advertises.
+
+use smithay::backend::renderer::gles::{
+    GlesRenderer, GlesTexProgram, Uniform, UniformName, UniformType, UniformValue,
+};
+use tracing::warn;
+
+use crate::{
+    output::edid::{Chromaticity, ColorPrimaries},
+    render::{AsGlesRenderer, OutputRenderElement, PRenderer},
+};
+
+use super::effect::{ColorMatrix, EffectRenderElement};
+
+/// A 3x3 matrix, row-major.
+pub type Mat3 = [[f32; 3]; 3];
+
+const SRGB_PRIMARIES: ColorPrimaries = ColorPrimaries {
+    red: Chromaticity { x: 0.64, y: 0.33 },
+    green: Chromaticity { x: 0.30, y: 0.60 },
+    blue: Chromaticity { x: 0.15, y: 0.06 },
+    white: Some(D65),
+};
+
+const D65: Chromaticity = Chromaticity {
+    x: 0.3127,
+    y: 0.3290,
+};
+
+/// The Bradford cone response matrix, for chromatic adaptation.
+const BRADFORD: Mat3 = [
+    [0.8951, 0.2664, -0.1614],
+    [-0.7502, 1.7135, 0.0367],
+    [0.0389, -0.0685, 1.0296],
+];
+
+const GAMUT_SHADER: &str = r#"
+#version 100
+
+//_DEFINES_
+
+#if defined(EXTERNAL)
+#extension GL_OES_EGL_image_external : require
+#endif
+
+precision mediump float;
+#if defined(EXTERNAL)
+uniform samplerExternalOES tex;
+#else
+uniform sampler2D tex;
+#endif
+
+uniform float alpha;
+varying vec2 v_coords;
+
+#if defined(DEBUG_FLAGS)
+uniform float tint;
+#endif
+
+uniform mat4 color_matrix;
+uniform mat3 gamut_matrix;
+
+vec3 srgb_to_linear(vec3 color) {
+    vec3 low = color / 12.92;
+    vec3 high = pow((color + 0.055) / 1.055, vec3(2.4));
+    return mix(low, high, step(vec3(0.04045), color));
+}
+
+vec3 linear_to_srgb(vec3 color) {
+    vec3 low = color * 12.92;
+    vec3 high = 1.055 * pow(color, vec3(1.0 / 2.4)) - 0.055;
+    return mix(low, high, step(vec3(0.0031308), color));
+}
+
+void main() {
+    vec4 color = texture2D(tex, v_coords);
+
+#if defined(NO_ALPHA)
+    color = vec4(color.rgb, 1.0);
+#endif
+
+    color = clamp(color_matrix * color, 0.0, 1.0);
+    color.rgb = min(color.rgb, vec3(color.a));
+
+    // Colors are premultiplied, and the transfer functions need straight colors
+    if (color.a > 0.0) {
+        vec3 rgb = srgb_to_linear(color.rgb / color.a);
+        rgb = clamp(gamut_matrix * rgb, 0.0, 1.0);
+        color.rgb = linear_to_srgb(rgb) * color.a;
+    }
+
+    color = color * alpha;
+
+#if defined(DEBUG_FLAGS)
+    if (tint == 1.0)
+        color = vec4(0.0, 0.2, 0.0, 0.2) + color * 0.8;
+#endif
+
+    gl_FragColor = color;
+}
+"#;
+
+/// Returns the matrix converting linear sRGB to linear RGB with the given primaries.
+///
+/// The sRGB white point is adapted to the primaries' white point, which is assumed
+/// to be D65 if not given.
+pub fn srgb_to_primaries(primaries: &ColorPrimaries) -> Mat3 {
+    let white = primaries.white.unwrap_or(D65);
+
+    let srgb_to_xyz = rgb_to_xyz(&SRGB_PRIMARIES);
+    let xyz_to_output = invert(rgb_to_xyz(primaries));
+
+    multiply(xyz_to_output, multiply(adaptation(D65, white), srgb_to_xyz))
+}
+
+fn xyz(chromaticity: Chromaticity) -> [f32; 3] {
+    let Chromaticity { x, y } = chromaticity;
+    [x / y, 1.0, (1.0 - x - y) / y]
+}
+
+fn rgb_to_xyz(primaries: &ColorPrimaries) -> Mat3 {
+    let [r, g, b] = [primaries.red, primaries.green, primaries.blue].map(xyz);
+    let columns = [[r[0], g[0], b[0]], [r[1], g[1], b[1]], [r[2], g[2], b[2]]];
+
+    // Scale the primaries so that full RGB is the white point
+    let white = xyz(primaries.white.unwrap_or(D65));
+    let scale = apply(invert(columns), white);
+
+    columns.map(|row| [row[0] * scale[0], row[1] * scale[1], row[2] * scale[2]])
+}
+
+/// Returns the Bradford matrix adapting XYZ colors from one white point to another.
+fn adaptation(from: Chromaticity, to: Chromaticity) -> Mat3 {
+    let from = apply(BRADFORD, xyz(from));
+    let to = apply(BRADFORD, xyz(to));
+
+    let scale = [
+        [to[0] / from[0], 0.0, 0.0],
+        [0.0, to[1] / from[1], 0.0],
+        [0.0, 0.0, to[2] / from[2]],
+    ];
+
+    multiply(invert(BRADFORD), multiply(scale, BRADFORD))
+}
+
+fn multiply(a: Mat3, b: Mat3) -> Mat3 {
+    let mut result = [[0.0; 3]; 3];
+    for (i, row) in result.iter_mut().enumerate() {
+        for (j, value) in row.iter_mut().enumerate() {
+            *value = (0..3).map(|k| a[i][k] * b[k][j]).sum();
+        }
+    }
+    result
+}
+
+fn apply(matrix: Mat3, vector: [f32; 3]) -> [f32; 3] {
+    matrix.map(|row| row.iter().zip(vector).map(|(a, b)| a * b).sum())
+}
+
+fn invert(m: Mat3) -> Mat3 {
+    let cofactor = |i: usize, j: usize| {
+        let (r0, r1) = ((i + 1) % 3, (i + 2) % 3);
+        let (c0, c1) = ((j + 1) % 3, (j + 2) % 3);
+        m[r0][c0] * m[r1][c1] - m[r0][c1] * m[r1][c0]
+    };
+
+    let det = (0..3).map(|j| m[0][j] * cofactor(0, j)).sum::<f32>();
+
+    let mut result = [[0.0; 3]; 3];
+    for (i, row) in result.iter_mut().enumerate() {
+        for (j, value) in row.iter_mut().enumerate() {
+            *value = cofactor(j, i) / det;
+        }
+    }
+    result
+}
+
+struct GamutProgram(Option<GlesTexProgram>);
+
+/// Gets the sRGB emulation shader, compiling it if needed.
+///
+/// Returns `None` if the shader failed to compile.
+fn gamut_program(renderer: &mut GlesRenderer) -> Option<GlesTexProgram> {
+    if let Some(program) = renderer.egl_context().user_data().get::<GamutProgram>() {
+        return program.0.clone();
+    }
+
+    let program = renderer
+        .compile_custom_texture_shader(
+            GAMUT_SHADER,
+            &[
+                UniformName::new("color_matrix", UniformType::Matrix4x4),
+                UniformName::new("gamut_matrix", UniformType::Matrix3x3),
+            ],
+        )
+        .inspect_err(|err| warn!("Failed to compile sRGB emulation shader: {err}"))
+        .ok();
+
+    renderer
+        .egl_context()
+        .user_data()
+        .insert_if_missing(|| GamutProgram(program.clone()));
+
+    program
+}
+
+/// Converts all elements of an output from sRGB with the given gamut matrix.
+///
+/// This should run after color filters. Solid colors, borders, and the screensaver
+/// are left unchanged.
+pub fn emulate_srgb<R: PRenderer + AsGlesRenderer>(
+    elements: &mut Vec<OutputRenderElement<R>>,
+    gamut_matrix: Mat3,
+    renderer: &mut R,
+) {
+    let _span = tracy_client::span!("emulate_srgb");
+
+    let Some(program) = gamut_program(renderer.as_gles_renderer()) else {
+        return;
+    };
+
+    // GLSL expects column-major matrices
+    let columns = [0, 1, 2].map(|j| gamut_matrix.map(|row| row[j]));
+    let uniforms = vec![Uniform::new(
+        "gamut_matrix",
+        UniformValue::Matrix3x3 {
+            matrices: vec![columns],
+            transpose: false,
+        },
+    )];
+
+    let identity = ColorMatrix::IDENTITY;
+
+    *elements = std::mem::take(elements)
+        .into_iter()
+        .map(|elem| match elem {
+            OutputRenderElement::Surface(elem) => {
+                EffectRenderElement::new(elem, program.clone(), identity)
+                    .with_uniforms(uniforms.clone())
+                    .into()
+            }
+            OutputRenderElement::Pointer(elem) => {
+                EffectRenderElement::new(elem, program.clone(), identity)
+                    .with_uniforms(uniforms.clone())
+                    .into()
+            }
+            OutputRenderElement::Snapshot(elem) => {
+                EffectRenderElement::new(elem, program.clone(), identity)
+                    .with_uniforms(uniforms.clone())
+                    .into()
+            }
+            OutputRenderElement::Effect(elem) => elem
+                .with_program(program.clone())
+                .with_uniforms(uniforms.clone())
+                .into(),
+            OutputRenderElement::PointerEffect(elem) => elem
+                .with_program(program.clone())
+                .with_uniforms(uniforms.clone())
+                .into(),
+            OutputRenderElement::SnapshotEffect(elem) => elem
+                .with_program(program.clone())
+                .with_uniforms(uniforms.clone())
+                .into(),
+            OutputRenderElement::ColorFilter(elem) => elem
+                .with_program(program.clone())
+                .with_uniforms(uniforms.clone())
+                .into(),
+            elem @ (OutputRenderElement::SolidColor(_)
+            | OutputRenderElement::Screensaver(_)
+            | OutputRenderElement::Border(_)) => elem,
+        })
+        .collect();
+}
+
+/// Converts a premultiplied sRGB color the same way [`emulate_srgb`] does.
+pub fn emulate_srgb_color(gamut_matrix: Mat3, color: [f32; 4]) -> [f32; 4] {
+    let [r, g, b, a] = color;
+    if a <= 0.0 {
+        return color;
+    }
+
+    let linear = [r, g, b].map(|value| {
+        let value = value / a;
+        if value < 0.04045 {
+            value / 12.92
+        } else {
+            ((value + 0.055) / 1.055).powf(2.4)
+        }
+    });
+
+    let [r, g, b] = apply(gamut_matrix, linear).map(|value| {
+        let value = value.clamp(0.0, 1.0);
+        let encoded = if value < 0.0031308 {
+            value * 12.92
+        } else {
+            1.055 * value.powf(1.0 / 2.4) - 0.055
+        };
+        encoded * a
+    });
+
+    [r, g, b, a]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn approx_eq(a: [f32; 3], b: [f32; 3]) -> bool {
+        a.iter().zip(b).all(|(a, b)| (a - b).abs() < 1e-3)
+    }
+
+    const DISPLAY_P3: ColorPrimaries = ColorPrimaries {
+        red: Chromaticity { x: 0.680, y: 0.320 },
+        green: Chromaticity { x: 0.265, y: 0.690 },
+        blue: Chromaticity { x: 0.150, y: 0.060 },
+        white: Some(D65),
+    };
+
+    #[test]
+    fn srgb_monitor_is_unchanged() {
+        let matrix = srgb_to_primaries(&SRGB_PRIMARIES);
+
+        assert!(approx_eq(matrix[0], [1.0, 0.0, 0.0]));
+        assert!(approx_eq(matrix[1], [0.0, 1.0, 0.0]));
+        assert!(approx_eq(matrix[2], [0.0, 0.0, 1.0]));
+    }
+
+    #[test]
+    fn wide_gamut_desaturates_primaries() {
+        let matrix = srgb_to_primaries(&DISPLAY_P3);
+
+        assert!(approx_eq(matrix[0], [0.8225, 0.1774, 0.0]));
+        assert!(approx_eq(matrix[1], [0.0332, 0.9669, 0.0]));
+        assert!(approx_eq(matrix[2], [0.0171, 0.0724, 0.9108]));
+    }
+
+    #[test]
+    fn white_stays_white() {
+        let warm_white = ColorPrimaries {
+            white: Some(Chromaticity { x: 0.345, y: 0.352 }),
+            ..DISPLAY_P3
+        };
+        let matrix = srgb_to_primaries(&warm_white);
+
+        assert!(approx_eq(apply(matrix, [1.0, 1.0, 1.0]), [1.0, 1.0, 1.0]));
+    }
+}
@@ -0,0 +1,232 @@
+//! Encoding SDR content for outputs in HDR mode.
+//!
+//! HDR outputs expect BT.2020 colors encoded with the PQ transfer function. Clients
+//! draw sRGB content, so every element is converted while rendering, with SDR white
+//! mapped to the reference white of ITU-R BT.2408.
+
+use smithay::backend::renderer::gles::{GlesRenderer, GlesTexProgram, UniformName, UniformType};
+use tracing::warn;
+
+use crate::render::{AsGlesRenderer, OutputRenderElement, PRenderer};
+
+use super::effect::{ColorMatrix, EffectRenderElement};
+
+/// The luminance SDR white is mapped to, in cd/m².
+const SDR_WHITE_NITS: f32 = 203.0;
+/// The peak luminance of the PQ transfer function, in cd/m².
+const PQ_MAX_NITS: f32 = 10000.0;
+
+/// Converts BT.709 primaries to BT.2020, row-major.
+const BT709_TO_BT2020: [[f32; 3]; 3] = [
+    [0.6274, 0.3293, 0.0433],
+    [0.0691, 0.9195, 0.0114],
+    [0.0164, 0.0880, 0.8956],
+];
+
+const SDR_TO_PQ_SHADER: &str = r#"
+#version 100
+
+//_DEFINES_
+
+#if defined(EXTERNAL)
+#extension GL_OES_EGL_image_external : require
+#endif
+
+#if defined(GL_FRAGMENT_PRECISION_HIGH)
+precision highp float;
+#else
+precision mediump float;
+#endif
+
+#if defined(EXTERNAL)
+uniform samplerExternalOES tex;
+#else
+uniform sampler2D tex;
+#endif
+
+uniform float alpha;
+varying vec2 v_coords;
+
+#if defined(DEBUG_FLAGS)
+uniform float tint;
+#endif
+
+uniform mat4 color_matrix;
+
+const float SDR_WHITE = 203.0 / 10000.0;
+
+// BT.709 to BT.2020, column-major
+const mat3 BT709_TO_BT2020 = mat3(
+    0.6274, 0.0691, 0.0164,
+    0.3293, 0.9195, 0.0880,
+    0.0433, 0.0114, 0.8956
+);
+
+vec3 srgb_to_linear(vec3 color) {
+    vec3 low = color / 12.92;
+    vec3 high = pow((color + 0.055) / 1.055, vec3(2.4));
+    return mix(low, high, step(vec3(0.04045), color));
+}
+
+vec3 linear_to_pq(vec3 color) {
+    const float m1 = 0.1593017578125;
+    const float m2 = 78.84375;
+    const float c1 = 0.8359375;
+    const float c2 = 18.8515625;
+    const float c3 = 18.6875;
+
+    vec3 p = pow(max(color, 0.0), vec3(m1));
+    return pow((c1 + c2 * p) / (1.0 + c3 * p), vec3(m2));
+}
+
+void main() {
+    vec4 color = texture2D(tex, v_coords);
+
+#if defined(NO_ALPHA)
+    color = vec4(color.rgb, 1.0);
+#endif
+
+    color = clamp(color_matrix * color, 0.0, 1.0);
+    color.rgb = min(color.rgb, vec3(color.a));
+
+    // Colors are premultiplied, and the transfer functions need straight colors
+    if (color.a > 0.0) {
+        vec3 rgb = srgb_to_linear(color.rgb / color.a);
+        rgb = linear_to_pq(BT709_TO_BT2020 * rgb * SDR_WHITE);
+        color.rgb = rgb * color.a;
+    }
+
+    color = color * alpha;
+
+#if defined(DEBUG_FLAGS)
+    if (tint == 1.0)
+        color = vec4(0.0, 0.2, 0.0, 0.2) + color * 0.8;
+#endif
+
+    gl_FragColor = color;
+}
+"#;
+
+struct SdrToPqProgram(Option<GlesTexProgram>);
+
+/// Gets the SDR to PQ shader, compiling it if needed.
+///
+/// Returns `None` if the shader failed to compile.
+fn sdr_to_pq_program(renderer: &mut GlesRenderer) -> Option<GlesTexProgram> {
+    if let Some(program) = renderer.egl_context().user_data().get::<SdrToPqProgram>() {
+        return program.0.clone();
+    }
+
+    let program = renderer
+        .compile_custom_texture_shader(
+            SDR_TO_PQ_SHADER,
+            &[UniformName::new("color_matrix", UniformType::Matrix4x4)],
+        )
+        .inspect_err(|err| warn!("Failed to compile SDR to PQ shader: {err}"))
+        .ok();
+
+    renderer
+        .egl_context()
+        .user_data()
+        .insert_if_missing(|| SdrToPqProgram(program.clone()));
+
+    program
+}
+
+/// Encodes all elements of an output for HDR.
+///
+/// This should run after all other color transforms. Solid colors, borders,
+/// and the screensaver are left unchanged.
+pub fn encode_for_hdr<R: PRenderer + AsGlesRenderer>(
+    elements: &mut Vec<OutputRenderElement<R>>,
+    renderer: &mut R,
+) {
+    let _span = tracy_client::span!("encode_for_hdr");
+
+    let Some(program) = sdr_to_pq_program(renderer.as_gles_renderer()) else {
+        return;
+    };
+
+    let identity = ColorMatrix::IDENTITY;
+
+    *elements = std::mem::take(elements)
+        .into_iter()
+        .map(|elem| match elem {
+            OutputRenderElement::Surface(elem) => {
+                EffectRenderElement::new(elem, program.clone(), identity).into()
+            }
+            OutputRenderElement::Pointer(elem) => {
+                EffectRenderElement::new(elem, program.clone(), identity).into()
+            }
+            OutputRenderElement::Snapshot(elem) => {
+                EffectRenderElement::new(elem, program.clone(), identity).into()
+            }
+            OutputRenderElement::Effect(elem) => elem.with_program(program.clone()).into(),
+            OutputRenderElement::PointerEffect(elem) => elem.with_program(program.clone()).into(),
+            OutputRenderElement::SnapshotEffect(elem) => elem.with_program(program.clone()).into(),
+            OutputRenderElement::ColorFilter(elem) => elem.with_program(program.clone()).into(),
+            elem @ (OutputRenderElement::SolidColor(_)
+            | OutputRenderElement::Screensaver(_)
+            | OutputRenderElement::Border(_)) => elem,
+        })
+        .collect();
+}
+
+/// Encodes a premultiplied sRGB color the same way [`encode_for_hdr`] does.
+pub fn sdr_to_pq(color: [f32; 4]) -> [f32; 4] {
+    let [r, g, b, a] = color;
+    if a <= 0.0 {
+        return color;
+    }
+
+    let linear = [r, g, b].map(|value| {
+        let value = value / a;
+        if value < 0.04045 {
+            value / 12.92
+        } else {
+            ((value + 0.055) / 1.055).powf(2.4)
+        }
+    });
+
+    let encoded = BT709_TO_BT2020.map(|row| {
+        let value = row.iter().zip(linear).map(|(a, b)| a * b).sum::<f32>();
+        linear_to_pq(value * SDR_WHITE_NITS / PQ_MAX_NITS) * a
+    });
+
+    [encoded[0], encoded[1], encoded[2], a]
+}
+
+fn linear_to_pq(value: f32) -> f32 {
+    const M1: f32 = 0.1593017578125;
+    const M2: f32 = 78.84375;
+    const C1: f32 = 0.8359375;
+    const C2: f32 = 18.8515625;
+    const C3: f32 = 18.6875;
+
+    let p = value.max(0.0).powf(M1);
+    ((C1 + C2 * p) / (1.0 + C3 * p)).powf(M2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sdr_white_is_reference_white() {
+        let [r, g, b, a] = sdr_to_pq([1.0, 1.0, 1.0, 1.0]);
+
+        // 203 cd/m² is about 58% of the PQ range
+        for value in [r, g, b] {
+            assert!((value - 0.5807).abs() < 1e-3, "{value}");
+        }
+        assert_eq!(a, 1.0);
+    }
+
+    #[test]
+    fn black_stays_black() {
+        let [r, g, b, _] = sdr_to_pq([0.0, 0.0, 0.0, 1.0]);
+        for value in [r, g, b] {
+            assert!(value < 1e-5, "{value}");
+        }
+    }
+}
@@ -0,0 +1,131 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Pixel shader elements.
+
+#[cfg(feature = "testing")]
+use smithay::backend::renderer::test::DummyRenderer;
+use smithay::{
+    backend::renderer::{
+        RendererSuper,
+        element::{self, Element, RenderElement},
+        gles::{GlesRenderer, element::PixelShaderElement},
+        utils::{CommitCounter, DamageSet, OpaqueRegions},
+    },
+    utils::{Buffer, Physical, Point, Rectangle, Scale, Transform},
+};
+
+use crate::backend::udev::UdevRenderer;
+
+/// A [`PixelShaderElement`] that can be drawn with all of Pinnacle's renderers.
+#[derive(Debug)]
+pub struct ShaderRenderElement(pub PixelShaderElement);
+
+impl Element for ShaderRenderElement {
+    fn id(&self) -> &element::Id {
+        self.0.id()
+    }
+
+    fn current_commit(&self) -> CommitCounter {
+        self.0.current_commit()
+    }
+
+    fn src(&self) -> Rectangle<f64, Buffer> {
+        self.0.src()
+    }
+
+    fn geometry(&self, scale: Scale<f64>) -> Rectangle<i32, Physical> {
+        self.0.geometry(scale)
+    }
+
+    fn location(&self, scale: Scale<f64>) -> Point<i32, Physical> {
+        self.0.location(scale)
+    }
+
+    fn transform(&self) -> Transform {
+        self.0.transform()
+    }
+
+    fn damage_since(
+        &self,
+        scale: Scale<f64>,
+        commit: Option<CommitCounter>,
+    ) -> DamageSet<i32, Physical> {
+        self.0.damage_since(scale, commit)
+    }
+
+    fn opaque_regions(&self, scale: Scale<f64>) -> OpaqueRegions<i32, Physical> {
+        self.0.opaque_regions(scale)
+    }
+
+    fn alpha(&self) -> f32 {
+        self.0.alpha()
+    }
+
+    fn kind(&self) -> element::Kind {
+        self.0.kind()
+    }
+}
+
+impl RenderElement<GlesRenderer> for ShaderRenderElement {
+    fn draw(
+        &self,
+        frame: &mut <GlesRenderer as RendererSuper>::Frame<'_, '_>,
+        src: Rectangle<f64, Buffer>,
+        dst: Rectangle<i32, Physical>,
+        damage: &[Rectangle<i32, Physical>],
+        opaque_regions: &[Rectangle<i32, Physical>],
+    ) -> Result<(), <GlesRenderer as RendererSuper>::Error> {
+        RenderElement::<GlesRenderer>::draw(&self.0, frame, src, dst, damage, opaque_regions)
+    }
+
+    fn underlying_storage(
+        &self,
+        renderer: &mut GlesRenderer,
+    ) -> Option<element::UnderlyingStorage<'_>> {
+        let _ = renderer;
+        None
+    }
+}
+
+impl<'a> RenderElement<UdevRenderer<'a>> for ShaderRenderElement {
+    fn draw(
+        &self,
+        frame: &mut <UdevRenderer<'a> as RendererSuper>::Frame<'_, '_>,
+        src: Rectangle<f64, Buffer>,
+        dst: Rectangle<i32, Physical>,
+        damage: &[Rectangle<i32, Physical>],
+        opaque_regions: &[Rectangle<i32, Physical>],
+    ) -> Result<(), <UdevRenderer<'a> as RendererSuper>::Error> {
+        RenderElement::<GlesRenderer>::draw(
+            &self.0,
+            frame.as_mut(),
+            src,
+            dst,
+            damage,
+            opaque_regions,
+        )?;
+        Ok(())
+    }
+
+    fn underlying_storage(
+        &self,
+        renderer: &mut UdevRenderer<'a>,
+    ) -> Option<element::UnderlyingStorage<'_>> {
+        let _ = renderer;
+        None
+    }
+}
+
+#[cfg(feature = "testing")]
+impl RenderElement<DummyRenderer> for ShaderRenderElement {
+    fn draw(
+        &self,
+        _frame: &mut <DummyRenderer as RendererSuper>::Frame<'static, 'static>,
+        _src: Rectangle<f64, Buffer>,
+        _dst: Rectangle<i32, Physical>,
+        _damage: &[Rectangle<i32, Physical>],
+        _opaque_regions: &[Rectangle<i32, Physical>],
+    ) -> Result<(), <DummyRenderer as RendererSuper>::Error> {
+        Ok(())
+    }
+}
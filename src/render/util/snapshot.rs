@@ -9,6 +9,7 @@ use smithay::backend::renderer::element::texture::{TextureBuffer, TextureRenderE
 use smithay::backend::renderer::element::utils::RescaleRenderElement;
 use smithay::{
     backend::renderer::{
+        Texture,
         element::RenderElement,
         gles::{GlesRenderer, GlesTexture},
     },
@@ -21,6 +22,7 @@ use crate::render::{AsGlesRenderer, PRenderer};
 use crate::state::WithState;
 use crate::window::WindowElement;
 
+use super::accounting::{AllocationKind, GpuAccounting, TrackedAllocation};
 use super::surface::WlSurfaceTextureRenderElement;
 use super::{EncompassingTexture, render_to_encompassing_texture};
 
@@ -42,7 +44,9 @@ pub struct RenderSnapshot<E> {
     /// The texture that elements will be rendered into.
     ///
     /// Happens lazily for performance.
-    texture: OnceCell<(GlesTexture, Point<i32, Physical>)>,
+    texture: OnceCell<(GlesTexture, Point<i32, Physical>, Rc<TrackedAllocation>)>,
+    /// Where the texture is accounted for once it's rendered.
+    accounting: Rc<GpuAccounting>,
 }
 
 impl<E> Clone for RenderSnapshot<E> {
@@ -51,17 +55,23 @@ impl<E> Clone for RenderSnapshot<E> {
             elements: self.elements.clone(),
             scale: self.scale,
             texture: self.texture.clone(),
+            accounting: self.accounting.clone(),
         }
     }
 }
 
 impl<E: RenderElement<GlesRenderer>> RenderSnapshot<E> {
     /// Creates a new snapshot from elements.
-    pub fn new(elements: impl IntoIterator<Item = E>, scale: Scale<f64>) -> Self {
+    pub fn new(
+        elements: impl IntoIterator<Item = E>,
+        scale: Scale<f64>,
+        accounting: Rc<GpuAccounting>,
+    ) -> Self {
         Self {
             elements: Rc::new(elements.into_iter().collect()),
             scale,
             texture: OnceCell::new(),
+            accounting,
         }
     }
 
@@ -87,11 +97,17 @@ impl<E: RenderElement<GlesRenderer>> RenderSnapshot<E> {
                     return None;
                 }
             };
-            let Ok(()) = self.texture.set((texture, loc)) else {
+            let size = texture.size();
+            let allocation = self
+                .accounting
+                .track(AllocationKind::Snapshot, size.w as u64 * size.h as u64 * 4);
+            let Ok(()) = self.texture.set((texture, loc, Rc::new(allocation))) else {
                 unreachable!()
             };
         }
-        self.texture.get().cloned()
+        self.texture
+            .get()
+            .map(|(texture, loc, _)| (texture.clone(), *loc))
     }
 
     /// Render elements for this snapshot.
@@ -138,11 +154,16 @@ impl WindowElement {
         renderer: &mut GlesRenderer,
         scale: Scale<f64>,
         alpha: f32,
+        accounting: &Rc<GpuAccounting>,
     ) -> Option<WindowSnapshot> {
         let elements = self.texture_render_elements(renderer, (0, 0).into(), scale, alpha);
         self.with_state_mut(|state| {
             if !elements.surface_elements.is_empty() {
-                state.snapshot = Some(RenderSnapshot::new(elements.surface_elements, scale));
+                state.snapshot = Some(RenderSnapshot::new(
+                    elements.surface_elements,
+                    scale,
+                    accounting.clone(),
+                ));
             }
             state.snapshot.clone()
         })
@@ -3,7 +3,7 @@
 use smithay::{
     backend::renderer::{
         element::{
-            self,
+            self, Element,
             solid::{SolidColorBuffer, SolidColorRenderElement},
             surface::{WaylandSurfaceRenderElement, WaylandSurfaceTexture},
             texture::{TextureBuffer, TextureRenderElement},
@@ -86,7 +86,8 @@ pub fn texture_render_elements_from_surface_tree(
                         element::Kind::Unspecified,
                     ) {
                         Ok(Some(surface)) => {
-                            // Reconstruct the element as a TextureRenderElement
+                            // Reconstruct the element as a TextureRenderElement.
+                            // The element's alpha includes the surface's alpha modifier.
 
                             let data = data.lock().unwrap();
                             let view = data.view().unwrap();
@@ -104,7 +105,7 @@ pub fn texture_render_elements_from_surface_tree(
                                     let texture_elem = TextureRenderElement::from_texture_buffer(
                                         location,
                                         &texture_buffer,
-                                        Some(alpha),
+                                        Some(surface.alpha()),
                                         Some(view.src),
                                         Some(view.dst),
                                         element::Kind::Unspecified,
@@ -120,7 +121,7 @@ pub fn texture_render_elements_from_surface_tree(
                                         &solid_color_buffer,
                                         location.to_i32_round(), // INFO: is this the correct rounding
                                         scale,
-                                        alpha,
+                                        surface.alpha(),
                                         element::Kind::Unspecified,
                                     );
                                     surfaces.push(solid_color_elem.into());
@@ -0,0 +1,377 @@
+//! Restarting Pinnacle in place.
+//!
+//! Restarting saves where every window is, then re-executes Pinnacle with the arguments
+//! it was started with, so an upgraded binary takes over the session. Clients don't
+//! survive the restart, but windows that come back afterwards, like ones the config
+//! spawns again, are put back on their old tags and in their old layout mode.
+//! Windows are matched by app id, preferring ones that also have the same title.
+
+use std::{
+    env,
+    ffi::OsString,
+    fs,
+    os::unix::process::CommandExt,
+    path::{Path, PathBuf},
+    process::Command,
+    sync::atomic::Ordering,
+    time::Duration,
+};
+
+use anyhow::Context;
+use indexmap::IndexSet;
+use serde::{Deserialize, Serialize};
+use smithay::reexports::calloop::{
+    RegistrationToken,
+    timer::{TimeoutAction, Timer},
+};
+use tracing::{info, warn};
+use xdg::BaseDirectories;
+
+use crate::{
+    process::{REMOVE_RUST_BACKTRACE, REMOVE_RUST_LIB_BACKTRACE},
+    state::{Pinnacle, WithState},
+    window::{
+        WindowElement,
+        rules::WindowRules,
+        window_state::{LayoutMode, LayoutModeKind},
+    },
+};
+
+/// Set to the saved session's path when Pinnacle re-executes itself.
+pub const RESTORE_SESSION_ENV: &str = "PINNACLE_RESTORE_SESSION";
+
+/// How long saved windows wait to be matched before they're forgotten.
+const RESTORE_TIMEOUT: Duration = Duration::from_secs(60);
+
+#[derive(Debug, Default)]
+pub struct RestartState {
+    /// Whether Pinnacle should re-execute itself once the event loop stops.
+    pub requested: bool,
+    /// Windows from before a restart that haven't come back yet.
+    restored: SavedSession,
+    restore_timer: Option<RegistrationToken>,
+}
+
+/// The placement of all windows, saved across a restart.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SavedSession {
+    #[serde(default)]
+    pub windows: Vec<SavedWindow>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SavedWindow {
+    pub app_id: String,
+    pub title: Option<String>,
+    pub tags: Vec<SavedTag>,
+    pub layout_mode: SavedLayoutMode,
+    pub floating_x: Option<i32>,
+    pub floating_y: Option<i32>,
+    pub floating_size: Option<(i32, i32)>,
+}
+
+/// A tag, identified by its name and the name of its output.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SavedTag {
+    pub output: String,
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SavedLayoutMode {
+    Tiled,
+    Floating,
+    Maximized,
+    Fullscreen,
+}
+
+impl From<LayoutModeKind> for SavedLayoutMode {
+    fn from(kind: LayoutModeKind) -> Self {
+        match kind {
+            LayoutModeKind::Tiled | LayoutModeKind::Spilled => Self::Tiled,
+            LayoutModeKind::Floating => Self::Floating,
+            LayoutModeKind::Maximized => Self::Maximized,
+            LayoutModeKind::Fullscreen => Self::Fullscreen,
+        }
+    }
+}
+
+impl From<SavedLayoutMode> for LayoutMode {
+    fn from(mode: SavedLayoutMode) -> Self {
+        match mode {
+            SavedLayoutMode::Tiled => LayoutMode::new_tiled(),
+            SavedLayoutMode::Floating => LayoutMode::new_floating(),
+            SavedLayoutMode::Maximized => LayoutMode::new_maximized(),
+            SavedLayoutMode::Fullscreen => LayoutMode::new_fullscreen(),
+        }
+    }
+}
+
+impl SavedSession {
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        let contents = fs::read_to_string(path).context("failed to read saved session")?;
+        toml::from_str(&contents).context("failed to parse saved session")
+    }
+
+    pub fn save(&self, path: &Path) -> anyhow::Result<()> {
+        let contents = toml::to_string(self).context("failed to serialize session")?;
+        fs::write(path, contents).context("failed to write saved session")
+    }
+
+    /// Removes and returns the saved window that a new window with the given
+    /// app id and title replaces.
+    pub fn take_match(&mut self, app_id: &str, title: Option<&str>) -> Option<SavedWindow> {
+        let mut same_app = self
+            .windows
+            .iter()
+            .enumerate()
+            .filter(|(_, window)| window.app_id == app_id);
+
+        let (index, _) = same_app
+            .clone()
+            .find(|(_, window)| title.is_some() && window.title.as_deref() == title)
+            .or_else(|| same_app.next())?;
+
+        Some(self.windows.remove(index))
+    }
+}
+
+/// Returns where the session is saved while restarting.
+pub fn session_file(base_dirs: &BaseDirectories) -> Option<PathBuf> {
+    let runtime_dir = base_dirs.get_runtime_directory()?;
+    Some(runtime_dir.join(format!("pinnacle-restart-{}.toml", std::process::id())))
+}
+
+/// Re-executes Pinnacle with the arguments it was started with.
+///
+/// `display_vars` are the display environment variables Pinnacle was started with, so the
+/// restarted Pinnacle picks the same backend instead of trying to nest inside itself.
+///
+/// This only returns if executing failed.
+pub fn exec(
+    base_dirs: &BaseDirectories,
+    display_vars: &[(&str, Option<OsString>)],
+) -> anyhow::Error {
+    let mut args = env::args_os();
+    let Some(program) = args.next() else {
+        return anyhow::anyhow!("no program name in arguments");
+    };
+
+    let mut command = Command::new(program);
+    command.args(args);
+
+    // Let the new instance decide whether to set these again
+    if REMOVE_RUST_BACKTRACE.load(Ordering::Relaxed) {
+        command.env_remove("RUST_BACKTRACE");
+    }
+    if REMOVE_RUST_LIB_BACKTRACE.load(Ordering::Relaxed) {
+        command.env_remove("RUST_LIB_BACKTRACE");
+    }
+
+    for (key, value) in display_vars {
+        match value {
+            Some(value) => command.env(key, value),
+            None => command.env_remove(key),
+        };
+    }
+
+    if let Some(path) = session_file(base_dirs).filter(|path| path.exists()) {
+        command.env(RESTORE_SESSION_ENV, path);
+    }
+
+    anyhow::Error::new(command.exec()).context("failed to re-execute pinnacle")
+}
+
+impl Pinnacle {
+    /// Saves the placement of all mapped windows.
+    pub fn save_session(&self) -> SavedSession {
+        let windows = self
+            .windows
+            .iter()
+            .filter_map(|window| {
+                let app_id = window.class()?;
+
+                let tags = window.with_state(|state| {
+                    state
+                        .tags
+                        .iter()
+                        .filter_map(|tag| {
+                            Some(SavedTag {
+                                output: tag.output(self)?.name(),
+                                name: tag.name(),
+                            })
+                        })
+                        .collect()
+                });
+
+                Some(window.with_state(|state| {
+                    SavedWindow {
+                        app_id,
+                        title: window.title(),
+                        tags,
+                        layout_mode: state.layout_mode.current().into(),
+                        floating_x: state.floating_x,
+                        floating_y: state.floating_y,
+                        floating_size: (state.floating_size.w > 0 && state.floating_size.h > 0)
+                            .then_some((state.floating_size.w, state.floating_size.h)),
+                    }
+                }))
+            })
+            .collect();
+
+        SavedSession { windows }
+    }
+
+    /// Saves the session and stops Pinnacle so it re-executes itself.
+    pub fn restart(&mut self) {
+        info!("Restarting Pinnacle");
+
+        match session_file(&self.xdg_base_dirs) {
+            Some(path) => {
+                if let Err(err) = self.save_session().save(&path) {
+                    warn!("Failed to save session, windows won't be restored: {err:#}");
+                }
+            }
+            None => warn!("XDG_RUNTIME_DIR is not set, windows won't be restored"),
+        }
+
+        self.restart_state.requested = true;
+        self.shutdown();
+    }
+
+    /// Restores window placement saved before a restart.
+    ///
+    /// Saved windows that haven't come back after a minute are forgotten.
+    pub fn restore_session(&mut self, path: &Path) {
+        let session = SavedSession::load(path);
+        let _ = fs::remove_file(path);
+
+        let session = match session {
+            Ok(session) => session,
+            Err(err) => {
+                warn!("Failed to restore session: {err:#}");
+                return;
+            }
+        };
+
+        info!(
+            "Restoring {} windows from before restart",
+            session.windows.len()
+        );
+
+        self.restart_state.restored = session;
+
+        if let Some(timer) = self.restart_state.restore_timer.take() {
+            self.loop_handle.remove(timer);
+        }
+
+        let timer = self
+            .loop_handle
+            .insert_source(Timer::from_duration(RESTORE_TIMEOUT), |_, _, state| {
+                let restart_state = &mut state.pinnacle.restart_state;
+                restart_state.restored = SavedSession::default();
+                restart_state.restore_timer = None;
+                TimeoutAction::Drop
+            })
+            .expect("failed to insert restore timer");
+
+        self.restart_state.restore_timer = Some(timer);
+    }
+
+    /// Returns rules that put `window` back where it was before a restart,
+    /// if it replaces a saved window.
+    pub fn take_restored_rules(&mut self, window: &WindowElement) -> Option<WindowRules> {
+        if self.restart_state.restored.windows.is_empty() {
+            return None;
+        }
+
+        let saved = self
+            .restart_state
+            .restored
+            .take_match(&window.class()?, window.title().as_deref())?;
+
+        let tags = saved
+            .tags
+            .iter()
+            .filter_map(|saved_tag| {
+                let output = self
+                    .outputs
+                    .iter()
+                    .find(|output| output.name() == saved_tag.output)?;
+                output.with_state(|state| {
+                    state
+                        .tags
+                        .iter()
+                        .find(|tag| tag.name() == saved_tag.name)
+                        .cloned()
+                })
+            })
+            .collect::<IndexSet<_>>();
+
+        Some(WindowRules {
+            layout_mode: Some(saved.layout_mode.into()),
+            floating_x: saved.floating_x,
+            floating_y: saved.floating_y,
+            floating_size: saved.floating_size.map(Into::into),
+            tags: (!tags.is_empty()).then_some(tags),
+            ..Default::default()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn saved_window(app_id: &str, title: &str) -> SavedWindow {
+        SavedWindow {
+            app_id: app_id.to_string(),
+            title: Some(title.to_string()),
+            tags: vec![SavedTag {
+                output: "DP-1".to_string(),
+                name: "2".to_string(),
+            }],
+            layout_mode: SavedLayoutMode::Floating,
+            floating_x: Some(100),
+            floating_y: None,
+            floating_size: Some((800, 600)),
+        }
+    }
+
+    #[test]
+    fn session_round_trips() {
+        let session = SavedSession {
+            windows: vec![
+                saved_window("foot", "~"),
+                saved_window("firefox", "Mozilla"),
+            ],
+        };
+
+        let serialized = toml::to_string(&session).unwrap();
+        assert_eq!(
+            toml::from_str::<SavedSession>(&serialized).unwrap(),
+            session
+        );
+    }
+
+    #[test]
+    fn matching_prefers_same_title() {
+        let mut session = SavedSession {
+            windows: vec![
+                saved_window("foot", "~"),
+                saved_window("firefox", "Mozilla"),
+                saved_window("foot", "htop"),
+            ],
+        };
+
+        let matched = session.take_match("foot", Some("htop")).unwrap();
+        assert_eq!(matched.title.as_deref(), Some("htop"));
+
+        let matched = session.take_match("foot", Some("vim")).unwrap();
+        assert_eq!(matched.title.as_deref(), Some("~"));
+
+        assert_eq!(session.take_match("foot", None), None);
+        assert_eq!(session.windows.len(), 1);
+    }
+}
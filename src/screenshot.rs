@@ -0,0 +1,405 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Interactive region screenshots.
+//!
+//! While a region is being selected, every output is dimmed except for the selection,
+//! and the pointer is grabbed so clients don't receive any clicks.
+
+use std::{io::BufWriter, path::Path, time::Duration};
+
+use anyhow::Context;
+use smithay::{
+    backend::{
+        allocator::Fourcc,
+        renderer::{
+            Bind, ExportMem,
+            element::{
+                self, Id,
+                solid::SolidColorRenderElement,
+                utils::{Relocate, RelocateRenderElement},
+            },
+            gles::GlesRenderer,
+            utils::CommitCounter,
+        },
+    },
+    desktop::Space,
+    input::pointer::{CursorIcon, Focus, GrabStartData},
+    output::Output,
+    utils::{Logical, Physical, Point, Rectangle, SERIAL_COUNTER, Scale, Size, Transform},
+};
+use tokio::sync::oneshot;
+use tracing::{error, warn};
+
+use crate::{
+    grab::region_select_grab::RegionSelectGrab,
+    render::{output_render_elements, util::render_to_texture},
+    state::State,
+    window::{WindowElement, ZIndexElement},
+};
+
+/// The color drawn over everything outside of the selection.
+const DIM_COLOR: [f32; 4] = [0.0, 0.0, 0.0, 0.5];
+/// The color of the selection's border.
+const BORDER_COLOR: [f32; 4] = [1.0, 1.0, 1.0, 0.9];
+/// The thickness of the selection's border, in logical pixels.
+const BORDER_THICKNESS: i32 = 2;
+
+/// A captured region of an output.
+#[derive(Debug, Clone)]
+pub struct Screenshot {
+    /// The name of the output the region was captured from.
+    pub output_name: String,
+    /// The captured region in the global space.
+    pub region: Rectangle<i32, Logical>,
+    /// The size of the captured image.
+    pub size: Size<i32, Physical>,
+    /// The captured pixels as 8-bit RGBA, row by row.
+    pub rgba: Vec<u8>,
+}
+
+impl Screenshot {
+    /// Encodes this screenshot as a PNG and writes it to `path`.
+    pub fn save_png(&self, path: &Path) -> anyhow::Result<()> {
+        let file = std::fs::File::create(path)
+            .with_context(|| format!("failed to create {}", path.display()))?;
+
+        let mut encoder =
+            png::Encoder::new(BufWriter::new(file), self.size.w as u32, self.size.h as u32);
+        encoder.set_color(png::ColorType::Rgba);
+        encoder.set_depth(png::BitDepth::Eight);
+
+        encoder
+            .write_header()
+            .context("failed to write png header")?
+            .write_image_data(&self.rgba)
+            .context("failed to write png data")?;
+
+        Ok(())
+    }
+}
+
+/// An in-progress region selection.
+#[derive(Debug)]
+pub struct RegionSelect {
+    /// Where the selection was started, or `None` if the user hasn't started dragging.
+    anchor: Option<Point<f64, Logical>>,
+    /// The current pointer location.
+    pointer_loc: Point<f64, Logical>,
+    dim_ids: [Id; 4],
+    border_ids: [Id; 4],
+    sender: oneshot::Sender<anyhow::Result<Option<Screenshot>>>,
+}
+
+impl RegionSelect {
+    /// Returns the selected region in the global space.
+    fn selection(&self) -> Option<Rectangle<i32, Logical>> {
+        let anchor = self.anchor?;
+
+        let min = Point::from((
+            f64::min(anchor.x, self.pointer_loc.x),
+            f64::min(anchor.y, self.pointer_loc.y),
+        ));
+        let max = Point::from((
+            f64::max(anchor.x, self.pointer_loc.x),
+            f64::max(anchor.y, self.pointer_loc.y),
+        ));
+
+        Some(Rectangle::from_extremities(
+            min.to_i32_round(),
+            max.to_i32_round(),
+        ))
+    }
+
+    /// Renders the dimming and selection border for an output with the given geometry.
+    pub fn render_elements(
+        &self,
+        output_geo: Rectangle<i32, Logical>,
+        scale: Scale<f64>,
+    ) -> Vec<SolidColorRenderElement> {
+        let _span = tracy_client::span!("RegionSelect::render_elements");
+
+        let output_rect = Rectangle::from_size(output_geo.size);
+
+        let selection = self.selection().map(|mut selection| {
+            selection.loc -= output_geo.loc;
+            selection
+        });
+
+        let dim_rects: Vec<_> = match selection {
+            Some(selection) => output_rect.subtract_rect(selection).into_iter().collect(),
+            None => vec![output_rect],
+        };
+
+        let border_rects = selection
+            .map(|selection| {
+                let Rectangle { loc, size } = selection;
+                let t = BORDER_THICKNESS;
+                vec![
+                    Rectangle::new((loc.x - t, loc.y - t).into(), (size.w + t * 2, t).into()),
+                    Rectangle::new(
+                        (loc.x - t, loc.y + size.h).into(),
+                        (size.w + t * 2, t).into(),
+                    ),
+                    Rectangle::new((loc.x - t, loc.y).into(), (t, size.h).into()),
+                    Rectangle::new((loc.x + size.w, loc.y).into(), (t, size.h).into()),
+                ]
+            })
+            .unwrap_or_default();
+
+        let to_element = |(rect, id): (Rectangle<i32, Logical>, &Id), color| {
+            SolidColorRenderElement::new(
+                id.clone(),
+                rect.to_physical_precise_round(scale),
+                CommitCounter::default(),
+                color,
+                element::Kind::Unspecified,
+            )
+        };
+
+        // Elements render from top to bottom
+        border_rects
+            .into_iter()
+            .zip(&self.border_ids)
+            .map(|rect| to_element(rect, BORDER_COLOR))
+            .chain(
+                dim_rects
+                    .into_iter()
+                    .zip(&self.dim_ids)
+                    .map(|rect| to_element(rect, DIM_COLOR)),
+            )
+            .collect()
+    }
+}
+
+/// Renders the given output-local region of an output and returns its pixels as 8-bit RGBA.
+fn capture_region(
+    renderer: &mut GlesRenderer,
+    output: &Output,
+    space: &Space<WindowElement>,
+    z_index_stack: &[ZIndexElement],
+    region: Rectangle<i32, Physical>,
+) -> anyhow::Result<Vec<u8>> {
+    let _span = tracy_client::span!("capture_region");
+
+    let scale = Scale::from(output.current_scale().fractional_scale());
+
+    let elements = output_render_elements(output, renderer, space, z_index_stack);
+
+    // Elements are drawn from bottom to top
+    let offset: Point<i32, Physical> = Point::from((-region.loc.x, -region.loc.y));
+    let elements = elements
+        .iter()
+        .rev()
+        .map(|elem| RelocateRenderElement::from_element(elem, offset, Relocate::Relative));
+
+    let (mut texture, _) = render_to_texture(
+        renderer,
+        elements,
+        region.size,
+        scale,
+        Transform::Normal,
+        Fourcc::Abgr8888,
+    )?;
+
+    let framebuffer = renderer
+        .bind(&mut texture)
+        .context("failed to bind texture")?;
+
+    let buffer_size = region.size.to_logical(1).to_buffer(1, Transform::Normal);
+    let mapping = renderer
+        .copy_framebuffer(
+            &framebuffer,
+            Rectangle::from_size(buffer_size),
+            Fourcc::Abgr8888,
+        )
+        .context("failed to copy framebuffer")?;
+
+    let rgba = renderer
+        .map_texture(&mapping)
+        .context("failed to map texture")?;
+
+    Ok(rgba.to_vec())
+}
+
+impl State {
+    /// Starts an interactive region selection.
+    ///
+    /// The returned receiver gets the captured region once the user finishes dragging,
+    /// `None` if the selection was cancelled, or an error if the selection couldn't be
+    /// started or captured. Starting a new selection cancels the current one.
+    ///
+    /// Selections are refused while the session is locked.
+    pub fn start_region_select(&mut self) -> oneshot::Receiver<anyhow::Result<Option<Screenshot>>> {
+        let _span = tracy_client::span!("State::start_region_select");
+
+        let (sender, receiver) = oneshot::channel();
+
+        if !self.pinnacle.lock_state.is_unlocked() {
+            let _ = sender.send(Err(anyhow::anyhow!(
+                "cannot select a region while the session is locked"
+            )));
+            return receiver;
+        }
+
+        let Some(pointer) = self.pinnacle.seat.get_pointer() else {
+            let _ = sender.send(Err(anyhow::anyhow!("there is no pointer to select with")));
+            return receiver;
+        };
+
+        self.cancel_region_select();
+
+        self.pinnacle.region_select = Some(RegionSelect {
+            anchor: None,
+            pointer_loc: pointer.current_location(),
+            dim_ids: std::array::from_fn(|_| Id::new()),
+            border_ids: std::array::from_fn(|_| Id::new()),
+            sender,
+        });
+
+        let grab = RegionSelectGrab {
+            start_data: GrabStartData {
+                focus: None,
+                button: 0,
+                location: pointer.current_location(),
+            },
+            anchored: false,
+        };
+
+        pointer.set_grab(self, grab, SERIAL_COUNTER.next_serial(), Focus::Clear);
+
+        self.pinnacle
+            .cursor_state
+            .set_cursor_icon_override(Some(CursorIcon::Crosshair));
+
+        self.schedule_render_all();
+
+        receiver
+    }
+
+    /// Updates the current region selection with a new pointer location.
+    pub fn update_region_select(&mut self, pointer_loc: Point<f64, Logical>) {
+        let Some(region_select) = self.pinnacle.region_select.as_mut() else {
+            return;
+        };
+
+        region_select.pointer_loc = pointer_loc;
+
+        self.schedule_render_all();
+    }
+
+    /// Starts dragging out the selection from the current pointer location.
+    pub fn anchor_region_select(&mut self) {
+        let Some(region_select) = self.pinnacle.region_select.as_mut() else {
+            return;
+        };
+
+        region_select.anchor = Some(region_select.pointer_loc);
+    }
+
+    /// Finishes the current region selection and captures the selected region.
+    ///
+    /// The region is clamped to the output the selection was started on.
+    pub fn finish_region_select(&mut self) {
+        let _span = tracy_client::span!("State::finish_region_select");
+
+        let Some(region_select) = self.pinnacle.region_select.take() else {
+            return;
+        };
+
+        self.pinnacle.cursor_state.set_cursor_icon_override(None);
+        self.schedule_render_all();
+
+        let screenshot = self.capture_selection(&region_select);
+
+        if region_select.sender.send(screenshot).is_err() {
+            warn!("Region selection finished but nothing was waiting on it");
+        }
+    }
+
+    /// Cancels the current region selection, if any.
+    pub fn cancel_region_select(&mut self) {
+        if !self.abort_region_select() {
+            return;
+        }
+
+        if let Some(pointer) = self.pinnacle.seat.get_pointer() {
+            let time = Duration::from(self.pinnacle.clock.now()).as_millis() as u32;
+            pointer.unset_grab(self, SERIAL_COUNTER.next_serial(), time);
+        }
+    }
+
+    /// Cancels the current region selection without releasing the pointer grab.
+    ///
+    /// Returns whether there was a selection to cancel.
+    pub(crate) fn abort_region_select(&mut self) -> bool {
+        let Some(region_select) = self.pinnacle.region_select.take() else {
+            return false;
+        };
+
+        let _ = region_select.sender.send(Ok(None));
+
+        self.pinnacle.cursor_state.set_cursor_icon_override(None);
+        self.schedule_render_all();
+
+        true
+    }
+
+    /// Captures the selected region.
+    ///
+    /// Returns `None` if nothing was selected.
+    fn capture_selection(
+        &mut self,
+        region_select: &RegionSelect,
+    ) -> anyhow::Result<Option<Screenshot>> {
+        let (Some(anchor), Some(selection)) = (region_select.anchor, region_select.selection())
+        else {
+            return Ok(None);
+        };
+
+        let Some(output) = self.pinnacle.space.output_under(anchor).next().cloned() else {
+            return Ok(None);
+        };
+        let Some(output_geo) = self.pinnacle.space.output_geometry(&output) else {
+            return Ok(None);
+        };
+
+        let Some(region) = selection.intersection(output_geo) else {
+            return Ok(None);
+        };
+
+        let scale = output.current_scale().fractional_scale();
+        let local_region = Rectangle::new(region.loc - output_geo.loc, region.size)
+            .to_physical_precise_round(scale);
+
+        if local_region.is_empty() {
+            return Ok(None);
+        }
+
+        let rgba = self
+            .backend
+            .with_renderer(|renderer| {
+                capture_region(
+                    renderer,
+                    &output,
+                    &self.pinnacle.space,
+                    &self.pinnacle.z_index_stack,
+                    local_region,
+                )
+            })
+            .context("no renderer is available to capture with")?
+            .inspect_err(|err| error!("Failed to capture region: {err:?}"))?;
+
+        Ok(Some(Screenshot {
+            output_name: output.name(),
+            region,
+            size: local_region.size,
+            rgba,
+        }))
+    }
+
+    pub(crate) fn schedule_render_all(&mut self) {
+        for output in self.pinnacle.outputs.clone() {
+            self.schedule_render(&output);
+        }
+    }
+}
@@ -1,4 +1,10 @@
-use std::{env, fs::File, io::Write, os::fd::FromRawFd};
+use std::{
+    env,
+    fs::File,
+    io::Write,
+    os::{fd::FromRawFd, unix::fs::PermissionsExt},
+    path::Path,
+};
 
 use tracing::warn;
 
@@ -49,6 +55,69 @@ pub fn import_environment() {
     }
 }
 
+/// Updates `key` in the systemd and D-Bus activation environments
+/// so services started later see the new value.
+///
+/// Unlike [`import_environment`], this doesn't block until the update finishes.
+pub fn update_activation_environment(key: &str, value: Option<&str>) {
+    // Pass the key as an argument so it isn't interpreted by the shell
+    let mut script = match value {
+        Some(_) => "systemctl --user import-environment \"$1\"",
+        None => "systemctl --user unset-environment \"$1\"",
+    }
+    .to_string();
+
+    if has_dbus_update_activation_environment() {
+        script.push_str(match value {
+            Some(_) => "; dbus-update-activation-environment \"$1\"",
+            None => "; dbus-update-activation-environment \"$1=\"",
+        });
+    }
+
+    let mut command = std::process::Command::new("/bin/sh");
+    command.args(["-c", &script, "sh", key]);
+
+    match value {
+        Some(value) => command.env(key, value),
+        None => command.env_remove(key),
+    };
+
+    match command.spawn() {
+        Ok(mut child) => {
+            let key = key.to_string();
+            std::thread::spawn(move || match child.wait() {
+                Ok(status) if !status.success() => {
+                    warn!("Updating activation environment for {key} exited with {status}");
+                }
+                Ok(_) => (),
+                Err(err) => {
+                    warn!("Error waiting for activation environment update of {key}: {err}");
+                }
+            });
+        }
+        Err(err) => {
+            warn!("Error spawning shell to update activation environment: {err}");
+        }
+    }
+}
+
+/// Returns whether `dbus-update-activation-environment` is in `PATH`.
+///
+/// Not every session has it, and its absence shouldn't be reported as a failure.
+fn has_dbus_update_activation_environment() -> bool {
+    let Some(path) = env::var_os("PATH") else {
+        return false;
+    };
+
+    env::split_paths(&path)
+        .any(|dir| is_executable(&dir.join("dbus-update-activation-environment")))
+}
+
+fn is_executable(path: &Path) -> bool {
+    path.metadata()
+        .is_ok_and(|metadata| metadata.is_file() && metadata.permissions().mode() & 0o111 != 0)
+}
+
 pub fn notify_fd() -> anyhow::Result<()> {
     let fd = match env::var("NOTIFY_FD") {
         Ok(notify_fd) => notify_fd.parse()?,
@@ -1,7 +1,10 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
 
 use crate::{
-    api::signal::SignalState,
+    api::{
+        debug::InputTraceState, namespace::NamespaceState, output::OutputWatchState,
+        signal::SignalState, tag::PagerState, window::WindowWatchState,
+    },
     backend::{
         self, Backend,
         udev::{SurfaceDmabufFeedback, Udev},
@@ -10,12 +13,20 @@ use crate::{
     cli::{self, Cli},
     config::Config,
     cursor::CursorState,
-    focus::{OutputFocusStack, WindowKeyboardFocusStack, pointer::PointerContents},
+    focus::{
+        OutputFocusStack, WindowKeyboardFocusStack, cycle::WindowCycle, pointer::PointerContents,
+    },
     handlers::{
         session_lock::LockState, xdg_activation::XDG_ACTIVATION_TOKEN_TIMEOUT,
         xwayland::XwaylandState,
     },
+    idle::IdleState,
     layout::LayoutState,
+    metrics::Metrics,
+    nightlight::NightlightState,
+    output::auto_rotate::AutoRotateState,
+    portal::global_shortcuts::GlobalShortcutsState,
+    power::PowerState,
     process::ProcessState,
     protocol::{
         drm::WlDrmState,
@@ -26,8 +37,17 @@ use crate::{
         output_power_management::OutputPowerManagementState,
         screencopy::ScreencopyManagerState,
         snowcap_decoration::SnowcapDecorationState,
+        snowcap_thumbnail::SnowcapThumbnailState,
+        xdg_toplevel_drag::XdgToplevelDragState,
+        xdg_toplevel_icon::XdgToplevelIconState,
+    },
+    render::util::accounting::GpuAccounting,
+    restart::RestartState,
+    screenshot::RegionSelect,
+    window::{
+        Unmapped, WindowElement, ZIndexElement, popup::PopupGrabState, rules::WindowRuleState,
+        send_preferred_scale, watchdog::WATCHDOG_INTERVAL,
     },
-    window::{Unmapped, WindowElement, ZIndexElement, rules::WindowRuleState},
 };
 use smithay::{
     backend::renderer::element::{
@@ -58,6 +78,7 @@ use smithay::{
     },
     utils::{Clock, HookId, Monotonic},
     wayland::{
+        alpha_modifier::AlphaModifierState,
         compositor::{
             self, CompositorClientState, CompositorHandler, CompositorState, SurfaceData,
             with_surface_tree_downward,
@@ -105,6 +126,7 @@ use std::{
     collections::{HashMap, HashSet},
     ffi::OsString,
     path::PathBuf,
+    rc::Rc,
     sync::Arc,
     time::Duration,
 };
@@ -175,15 +197,19 @@ pub struct Pinnacle {
     pub kde_decoration_state: KdeDecorationState,
     pub pointer_gestures_state: PointerGesturesState,
     pub single_pixel_buffer_state: SinglePixelBufferState,
+    pub alpha_modifier_state: AlphaModifierState,
     pub foreign_toplevel_list_state: ForeignToplevelListState,
     pub ext_workspace_state: ExtWorkspaceManagerState,
     pub snowcap_decoration_state: SnowcapDecorationState,
+    pub snowcap_thumbnail_state: SnowcapThumbnailState,
     pub wl_drm_state: WlDrmState,
     pub image_capture_source_state: ImageCaptureSourceState,
     pub output_capture_source_state: OutputCaptureSourceState,
     pub toplevel_capture_source_state: ToplevelCaptureSourceState,
     pub image_copy_capture_state: ImageCopyCaptureState,
     pub content_type_state: ContentTypeState,
+    pub xdg_toplevel_drag_state: XdgToplevelDragState,
+    pub xdg_toplevel_icon_state: XdgToplevelIconState,
 
     pub lock_state: LockState,
 
@@ -197,13 +223,21 @@ pub struct Pinnacle {
     pub z_index_stack: Vec<ZIndexElement>,
 
     pub popup_manager: PopupManager,
+    /// The popup currently holding the keyboard grab, if any.
+    pub popup_grab_state: Option<PopupGrabState>,
 
     /// The main window vec
     pub windows: Vec<WindowElement>,
     /// Windows with no buffer attached
     pub unmapped_windows: Vec<Unmapped>,
     pub keyboard_focus_stack: WindowKeyboardFocusStack,
+    /// The in-progress cycle through recently focused windows, if any
+    pub window_cycle: Option<WindowCycle>,
     pub on_demand_layer_focus: Option<LayerSurface>,
+    /// The layer surface that currently has keyboard focus along with its output
+    pub focused_layer: Option<(LayerSurface, Output)>,
+    /// The window to return focus to when an on-demand layer surface closes
+    pub layer_focus_return: Option<WindowElement>,
     pub lock_surface_focus: Option<LockSurface>,
 
     pub config: Config,
@@ -218,6 +252,7 @@ pub struct Pinnacle {
     pub xdg_base_dirs: BaseDirectories,
 
     pub signal_state: SignalState,
+    pub namespace_state: NamespaceState,
 
     pub layout_state: LayoutState,
 
@@ -228,6 +263,8 @@ pub struct Pinnacle {
 
     /// WlSurfaces with an attached idle inhibitor.
     pub idle_inhibiting_surfaces: HashSet<WlSurface>,
+    pub idle_state: IdleState,
+    pub nightlight_state: NightlightState,
 
     #[cfg(feature = "snowcap")]
     pub snowcap_handle: Option<snowcap::SnowcapHandle>,
@@ -236,6 +273,8 @@ pub struct Pinnacle {
 
     pub cursor_shape_manager_state: CursorShapeManagerState,
     pub cursor_state: CursorState,
+    /// Totals of the snapshot textures and cursor buffers held onto.
+    pub gpu_accounting: Rc<GpuAccounting>,
 
     pub pointer_contents: PointerContents,
     pub last_pointer_focus: Option<<State as SeatHandler>::PointerFocus>,
@@ -247,6 +286,20 @@ pub struct Pinnacle {
 
     pub capture_sessions: Vec<Session>,
     pub cursor_capture_sessions: Vec<CursorSession>,
+
+    pub pager_state: PagerState,
+    pub input_trace_state: InputTraceState,
+    pub window_watch_state: WindowWatchState,
+    pub output_watch_state: OutputWatchState,
+
+    /// The region selection in progress for an interactive screenshot.
+    pub region_select: Option<RegionSelect>,
+
+    pub global_shortcuts_state: GlobalShortcutsState,
+    pub auto_rotate_state: AutoRotateState,
+    pub power_state: PowerState,
+    pub metrics: Metrics,
+    pub restart_state: RestartState,
 }
 
 #[cfg(feature = "snowcap")]
@@ -263,7 +316,9 @@ impl State {
         let _span = tracy_client::span!("State::on_event_loop_cycle_completion");
 
         self.notify_blocker_cleared();
+        self.pinnacle.flush_queued_updates();
         self.update_layout();
+        self.refresh_empty_outputs();
 
         self.update_keyboard_focus();
         self.pinnacle.fixup_z_layering();
@@ -273,12 +328,26 @@ impl State {
         self.pinnacle.popup_manager.cleanup();
         self.update_pointer_focus();
         self.pinnacle.process_window_focus_signal();
+        self.pinnacle.process_focused_title_signal();
+        self.pinnacle.process_fullscreen_signal();
+        self.pinnacle.process_stacking_signal();
         foreign_toplevel::refresh(self);
         ext_workspace::refresh(self);
         self.pinnacle.refresh_idle_inhibit();
+        self.pinnacle.refresh_pager();
+        self.pinnacle.refresh_window_watchers();
+        self.pinnacle.refresh_output_watchers();
+        self.refresh_inactive_effect();
+        self.refresh_tag_effects();
+        self.refresh_unresponsive_windows();
+        self.refresh_borders();
 
         // TODO: Probably want to do this only after a redraw
         self.process_capture_sessions();
+        self.process_thumbnails();
+
+        // Catch updates queued while refreshing so they don't wait for the next cycle
+        self.pinnacle.flush_queued_updates();
 
         self.backend.render_scheduled_outputs(&mut self.pinnacle);
 
@@ -363,6 +432,7 @@ impl Pinnacle {
                         .dispatch_clients(state)
                         .expect("failed to dispatch clients");
                 }
+                state.pinnacle.pager_state.mark_dirty();
                 Ok(PostAction::Continue)
             },
         )?;
@@ -401,8 +471,18 @@ impl Pinnacle {
                 anyhow::anyhow!("failed to insert xdg activation token cleanup source: {err}")
             })?;
 
+        // Unresponsive windows are found after each event loop cycle,
+        // so make sure one happens even if nothing else is going on.
+        loop_handle
+            .insert_source(Timer::from_duration(WATCHDOG_INTERVAL), |_, _, _| {
+                TimeoutAction::ToDuration(WATCHDOG_INTERVAL)
+            })
+            .map_err(|err| anyhow::anyhow!("failed to insert window watchdog source: {err}"))?;
+
         let (blocker_cleared_tx, blocker_cleared_rx) = std::sync::mpsc::channel();
 
+        let gpu_accounting = Rc::new(GpuAccounting::default());
+
         let pinnacle = Pinnacle {
             loop_signal,
             loop_handle: loop_handle.clone(),
@@ -480,12 +560,17 @@ impl Pinnacle {
             ),
             pointer_gestures_state: PointerGesturesState::new::<State>(&display_handle),
             single_pixel_buffer_state: SinglePixelBufferState::new::<State>(&display_handle),
+            alpha_modifier_state: AlphaModifierState::new::<State>(&display_handle),
             foreign_toplevel_list_state: ForeignToplevelListState::new::<State>(&display_handle),
             ext_workspace_state: ExtWorkspaceManagerState::new::<State, _>(
                 &display_handle,
                 filter_restricted_client,
             ),
             snowcap_decoration_state: SnowcapDecorationState::new::<State>(&display_handle),
+            snowcap_thumbnail_state: SnowcapThumbnailState::new::<State, _>(
+                &display_handle,
+                filter_restricted_client,
+            ),
             wl_drm_state: WlDrmState,
             image_capture_source_state: ImageCaptureSourceState::new(),
             output_capture_source_state: OutputCaptureSourceState::new::<State>(&display_handle),
@@ -494,6 +579,8 @@ impl Pinnacle {
             ),
             image_copy_capture_state: ImageCopyCaptureState::new::<State>(&display_handle),
             content_type_state: ContentTypeState::new::<State>(&display_handle),
+            xdg_toplevel_drag_state: XdgToplevelDragState::new::<State>(&display_handle),
+            xdg_toplevel_icon_state: XdgToplevelIconState::new::<State>(&display_handle),
 
             lock_state: LockState::default(),
 
@@ -507,11 +594,15 @@ impl Pinnacle {
             seat,
 
             popup_manager: PopupManager::default(),
+            popup_grab_state: None,
 
             windows: Vec::new(),
             unmapped_windows: Default::default(),
             keyboard_focus_stack: WindowKeyboardFocusStack::default(),
+            window_cycle: None,
             on_demand_layer_focus: None,
+            focused_layer: None,
+            layer_focus_return: None,
             lock_surface_focus: None,
 
             xwayland_state: None,
@@ -525,6 +616,7 @@ impl Pinnacle {
             xdg_base_dirs: BaseDirectories::with_prefix("pinnacle"),
 
             signal_state: SignalState::default(),
+            namespace_state: NamespaceState::default(),
 
             layout_state: LayoutState::default(),
 
@@ -533,6 +625,8 @@ impl Pinnacle {
             root_surface_cache: HashMap::new(),
 
             idle_inhibiting_surfaces: HashSet::new(),
+            idle_state: IdleState::default(),
+            nightlight_state: NightlightState::default(),
 
             outputs: Default::default(),
 
@@ -542,7 +636,8 @@ impl Pinnacle {
             snowcap_join_handle: None,
 
             cursor_shape_manager_state: CursorShapeManagerState::new::<State>(&display_handle),
-            cursor_state: CursorState::new(),
+            cursor_state: CursorState::new(gpu_accounting.clone()),
+            gpu_accounting,
 
             pointer_contents: Default::default(),
             last_pointer_focus: Default::default(),
@@ -554,6 +649,19 @@ impl Pinnacle {
 
             capture_sessions: Default::default(),
             cursor_capture_sessions: Default::default(),
+
+            pager_state: PagerState::default(),
+            input_trace_state: InputTraceState::default(),
+            window_watch_state: WindowWatchState::default(),
+            output_watch_state: OutputWatchState::default(),
+
+            region_select: None,
+
+            global_shortcuts_state: GlobalShortcutsState::default(),
+            auto_rotate_state: AutoRotateState::default(),
+            power_state: PowerState::default(),
+            metrics: Metrics::default(),
+            restart_state: RestartState::default(),
         };
 
         Ok(pinnacle)
@@ -720,6 +828,8 @@ impl Pinnacle {
         let _span = tracy_client::span!("Pinnacle::update_primary_scanout_output");
 
         for window in self.space.elements() {
+            let scale_override = window.with_state(|state| state.scale_override);
+
             window.with_surfaces(|surface, states| {
                 let primary_scanout_output = update_surface_primary_scanout_output(
                     surface,
@@ -730,10 +840,13 @@ impl Pinnacle {
                 );
 
                 if let Some(output) = primary_scanout_output {
-                    with_fractional_scale(states, |fraction_scale| {
-                        fraction_scale
-                            .set_preferred_scale(output.current_scale().fractional_scale());
-                    });
+                    match scale_override {
+                        Some(scale) => send_preferred_scale(surface, states, &output, scale),
+                        None => with_fractional_scale(states, |fraction_scale| {
+                            fraction_scale
+                                .set_preferred_scale(output.current_scale().fractional_scale());
+                        }),
+                    }
                 }
             });
 
@@ -75,6 +75,8 @@ struct TagInner {
     name: String,
     /// Whether this tag is active or not.
     active: bool,
+    /// Whether a window on this tag requested attention while the tag was inactive.
+    urgent: bool,
     /// This tag is defunct as a result of a config reload
     /// and will be replaced by the next added tag.
     defunct: bool,
@@ -112,6 +114,7 @@ impl Tag {
                 id: TagId::next(),
                 name: name.clone(),
                 active: false,
+                urgent: false,
                 defunct: false,
             })),
         }
@@ -159,6 +162,18 @@ impl Tag {
         std::mem::replace(&mut self.inner.lock().unwrap().active, active) != active
     }
 
+    /// Gets whether a window on this tag requested attention while it was inactive.
+    pub fn urgent(&self) -> bool {
+        self.inner.lock().unwrap().urgent
+    }
+
+    /// Sets this tag's urgency.
+    ///
+    /// Returns whether the new state is different from the old one.
+    pub fn set_urgent(&self, urgent: bool) -> bool {
+        std::mem::replace(&mut self.inner.lock().unwrap().urgent, urgent) != urgent
+    }
+
     /// Gets whether this tag is defunct as a result of a config reload.
     pub fn defunct(&self) -> bool {
         self.inner.lock().unwrap().defunct
@@ -169,3 +184,17 @@ impl Tag {
         self.inner.lock().unwrap().defunct = true;
     }
 }
+
+/// What happens when an output is left with no active tags.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub enum EmptyOutputPolicy {
+    /// Nothing is shown but the background.
+    #[default]
+    Blank,
+    /// The most recently active tags are activated again, or the first tag
+    /// if there are none.
+    ReactivateLast,
+    /// The tag with this name is activated. If the output doesn't have one,
+    /// it is left blank.
+    Fallback(String),
+}
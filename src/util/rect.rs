@@ -8,6 +8,66 @@ pub enum Direction {
     Down,
 }
 
+/// How much of a rectangle must remain inside a set of bounding rectangles.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Containment {
+    /// At least this many pixels along each axis must stay inside.
+    Partial(i32),
+    /// The whole rectangle must stay inside, if it fits.
+    Full,
+}
+
+/// Returns a new location for `rect` such that it satisfies `containment`
+/// with respect to one of `bounds`.
+///
+/// The bound with the largest overlap is used. If `rect` doesn't overlap any bound,
+/// the one with the closest center is used instead.
+pub fn constrain_to_rects(
+    rect: Rectangle<i32, Logical>,
+    bounds: &[Rectangle<i32, Logical>],
+    containment: Containment,
+) -> Point<i32, Logical> {
+    let target = bounds
+        .iter()
+        .filter_map(|bound| {
+            let overlap = rect.intersection(*bound)?;
+            Some((bound, overlap.size.w as i64 * overlap.size.h as i64))
+        })
+        .max_by_key(|(_, area)| *area)
+        .map(|(bound, _)| *bound)
+        .or_else(|| {
+            bounds.iter().copied().min_by(|a, b| {
+                distance(rect.center(), a.center()).total_cmp(&distance(rect.center(), b.center()))
+            })
+        });
+
+    let Some(target) = target else {
+        return rect.loc;
+    };
+
+    let clamp_axis = |loc: i32, len: i32, bound_loc: i32, bound_len: i32| match containment {
+        Containment::Partial(min_visible) => {
+            let min_visible = min_visible.clamp(0, len.min(bound_len));
+            loc.clamp(
+                bound_loc - len + min_visible,
+                bound_loc + bound_len - min_visible,
+            )
+        }
+        Containment::Full => {
+            if len >= bound_len {
+                bound_loc
+            } else {
+                loc.clamp(bound_loc, bound_loc + bound_len - len)
+            }
+        }
+    };
+
+    Point::new(
+        clamp_axis(rect.loc.x, rect.size.w, target.loc.x, target.size.w),
+        clamp_axis(rect.loc.y, rect.size.h, target.loc.y, target.size.h),
+    )
+}
+
 /// Returns the indices of rectangles in `rects` that are closest to `rect`
 /// in the given direction.
 pub fn closest_in_dir(
@@ -294,6 +354,65 @@ mod tests {
         assert_eq!(closest_in_dir(rects[1], &rects, Direction::Down), vec![]);
     }
 
+    #[test]
+    fn constrain_to_rects_keeps_pixels_visible() {
+        let outputs = [rect(0, 0, 1920, 1080), rect(1920, 0, 1920, 1080)];
+
+        // Off the left edge of the left output
+        assert_eq!(
+            constrain_to_rects(
+                rect(-900, 100, 500, 500),
+                &outputs,
+                Containment::Partial(50)
+            ),
+            Point::new(-450, 100)
+        );
+
+        // Off the bottom edge of the right output
+        assert_eq!(
+            constrain_to_rects(
+                rect(2000, 1500, 500, 500),
+                &outputs,
+                Containment::Partial(50)
+            ),
+            Point::new(2000, 1030)
+        );
+
+        // Already sufficiently visible
+        assert_eq!(
+            constrain_to_rects(
+                rect(1700, 100, 500, 500),
+                &outputs,
+                Containment::Partial(50)
+            ),
+            Point::new(1700, 100)
+        );
+    }
+
+    #[test]
+    fn constrain_to_rects_clamps_fully() {
+        let outputs = [rect(0, 0, 1920, 1080)];
+
+        assert_eq!(
+            constrain_to_rects(rect(1700, -50, 500, 500), &outputs, Containment::Full),
+            Point::new(1420, 0)
+        );
+
+        // Too large to fit, pinned to the top-left
+        assert_eq!(
+            constrain_to_rects(rect(100, 100, 2500, 500), &outputs, Containment::Full),
+            Point::new(0, 100)
+        );
+    }
+
+    #[test]
+    fn constrain_to_rects_with_no_bounds_does_nothing() {
+        assert_eq!(
+            constrain_to_rects(rect(-5000, -5000, 10, 10), &[], Containment::Full),
+            Point::new(-5000, -5000)
+        );
+    }
+
     #[allow(dead_code)]
     fn arbitrary_rect() -> impl Strategy<Value = Rectangle<i32, Logical>> {
         (-500i32..500, -500i32..500, 10i32..100, 10i32..100)
@@ -78,7 +78,7 @@ pub struct PendingTransaction {
     pub is_resize: bool,
     /// Held until this transaction drops, at which point the `UnmappingWindow`s in the
     /// z_index_stack are no longer valid
-    _unmapping: Vec<Rc<UnmappingWindow>>,
+    unmapping: Vec<Rc<UnmappingWindow>>,
 }
 
 impl PendingTransaction {
@@ -93,6 +93,19 @@ impl PendingTransaction {
     pub fn is_cancelled(&self) -> bool {
         !self.is_completed() && self.target_locs.keys().any(|win| !win.alive())
     }
+
+    /// Takes the [`UnmappingWindow`]s held by this transaction.
+    ///
+    /// This is used to keep displaying them when this transaction is cancelled
+    /// before the layout replacing them is applied.
+    pub fn take_unmapping(&mut self) -> Vec<Rc<UnmappingWindow>> {
+        std::mem::take(&mut self.unmapping)
+    }
+
+    /// Holds additional [`UnmappingWindow`]s until this transaction drops.
+    pub fn hold_unmapping(&mut self, unmapping: Vec<Rc<UnmappingWindow>>) {
+        self.unmapping.extend(unmapping);
+    }
 }
 
 impl TransactionBuilder {
@@ -154,7 +167,7 @@ impl TransactionBuilder {
             inner: Arc::downgrade(&self.inner),
             is_swap,
             is_resize,
-            _unmapping: unmapping,
+            unmapping,
         }
     }
 }
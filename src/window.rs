@@ -1,7 +1,14 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
 
+pub mod border;
+pub mod find;
+pub mod icon;
 pub mod layout;
+pub mod letterbox;
+pub mod popup;
 pub mod rules;
+pub mod snap;
+pub mod watchdog;
 
 use std::{cell::RefCell, collections::HashMap, ops::Deref, rc::Rc};
 
@@ -10,8 +17,8 @@ use itertools::Itertools;
 use rules::{ClientRequests, WindowRules};
 use smithay::{
     desktop::{
-        PopupManager, Window, WindowSurface, WindowSurfaceType, space::SpaceElement,
-        utils::under_from_surface_tree,
+        PopupManager, Window, WindowSurface, WindowSurfaceType, find_popup_root_surface,
+        space::SpaceElement, utils::under_from_surface_tree,
     },
     output::{Output, WeakOutput},
     reexports::{
@@ -26,8 +33,9 @@ use smithay::{
     },
     utils::{IsAlive, Logical, Point, Rectangle, Serial, Size},
     wayland::{
-        compositor,
+        compositor::{self, SurfaceData},
         foreign_toplevel_list::ForeignToplevelHandle,
+        fractional_scale::with_fractional_scale,
         seat::WaylandFocus,
         shell::xdg::{PositionerState, SurfaceCachedState, XdgToplevelSurfaceData},
         xdg_activation::XdgActivationTokenData,
@@ -39,13 +47,18 @@ use window_state::LayoutModeKind;
 
 use crate::{
     api::signal::Signal,
-    render::util::snapshot::WindowSnapshot,
+    focus::FocusReason,
+    render::util::{
+        cache::invalidate_render_plans,
+        effect::{ColorMatrix, window_color_matrix},
+        snapshot::WindowSnapshot,
+    },
     state::{Pinnacle, State, WithState},
     tag::Tag,
     util::transaction::Transaction,
 };
 
-use self::window_state::WindowElementState;
+use self::window_state::{DialogPolicy, TagInheritance, WindowElementState, WindowLayer};
 
 pub mod window_state;
 
@@ -155,6 +168,37 @@ impl WindowElement {
         self.with_state(|state| state.tags.iter().any(|tag| tag.active()))
     }
 
+    /// Returns whether this window has an active tag and isn't minimized.
+    ///
+    /// RefCell Safety: This calls `with_state` on `self`.
+    pub fn is_shown(&self) -> bool {
+        self.with_state(|state| !state.minimized && state.tags.iter().any(|tag| tag.active()))
+    }
+
+    /// Returns whether this window can get pointer and keyboard focus.
+    ///
+    /// RefCell Safety: This calls `with_state` on `self`.
+    pub fn accepts_input(&self) -> bool {
+        !self.with_state(|state| state.input_disabled)
+    }
+
+    /// Sets the scale this window is told to render at instead of its output's,
+    /// or goes back to its output's scale with `None`.
+    ///
+    /// RefCell Safety: This calls `with_state_mut` on `self`.
+    pub fn set_scale_override(&self, pinnacle: &Pinnacle, scale: Option<f64>) {
+        self.with_state_mut(|state| state.scale_override = scale);
+
+        let Some(output) = self.output(pinnacle) else {
+            return;
+        };
+
+        let scale = scale.unwrap_or_else(|| output.current_scale().fractional_scale());
+        self.with_surfaces(|surface, states| {
+            send_preferred_scale(surface, states, &output, scale);
+        });
+    }
+
     pub fn is_x11_override_redirect(&self) -> bool {
         matches!(self.x11_surface(), Some(surface) if surface.is_override_redirect())
     }
@@ -211,6 +255,15 @@ impl WindowElement {
             }
         };
 
+        // Fullscreen windows always fill their output
+        let size = self.with_state(|state| {
+            if state.layout_mode.is_fullscreen() {
+                size
+            } else {
+                state.size_constraints.clamp(size)
+            }
+        });
+
         match self.underlying_surface() {
             WindowSurface::Wayland(toplevel) => {
                 toplevel.with_pending_state(|state| {
@@ -248,6 +301,23 @@ impl WindowElement {
         geometry
     }
 
+    /// Clamps `size`, which includes decorations, to this window's size constraints.
+    ///
+    /// RefCell Safety: This calls `with_state` on `self`.
+    pub fn constrain_size(&self, size: Size<i32, Logical>) -> Size<i32, Logical> {
+        let outer = self.geometry().size;
+        let inner = self.geometry_without_decorations().size;
+        let (deco_w, deco_h) = (outer.w - inner.w, outer.h - inner.h);
+
+        let constraints = self.with_state(|state| state.size_constraints);
+        let size = constraints.clamp(Size::from((
+            i32::max(1, size.w - deco_w),
+            i32::max(1, size.h - deco_h),
+        )));
+
+        Size::from((size.w + deco_w, size.h + deco_h))
+    }
+
     /// Gets this window's geometry ignoring decoration bounds.
     pub fn geometry_without_decorations(&self) -> Rectangle<i32, Logical> {
         self.0.geometry()
@@ -375,12 +445,19 @@ impl SpaceElement for WindowElement {
         }
 
         let mut bbox = self.0.bbox();
-        self.with_state(|state| {
+        let has_border = self.with_state(|state| {
             for deco in state.decoration_surfaces.iter() {
                 // FIXME: verify this
                 bbox = bbox.merge(deco.bbox());
             }
+            state.border.is_some()
         });
+
+        // Borders are drawn around the whole geometry
+        if has_border {
+            bbox = bbox.merge(self.geometry());
+        }
+
         bbox
     }
 
@@ -502,6 +579,27 @@ impl WithState for WindowElement {
     }
 }
 
+/// Tells a surface to render at `scale` on `output`.
+///
+/// This sends the integer preferred buffer scale along with the fractional scale
+/// so clients without fractional scaling support render at the closest scale up.
+pub fn send_preferred_scale(
+    surface: &WlSurface,
+    states: &SurfaceData,
+    output: &Output,
+    scale: f64,
+) {
+    with_fractional_scale(states, |fractional_scale| {
+        fractional_scale.set_preferred_scale(scale);
+    });
+    compositor::send_surface_state(
+        surface,
+        states,
+        scale.ceil() as i32,
+        output.current_transform(),
+    );
+}
+
 impl Pinnacle {
     /// Returns the [Window] associated with a given [WlSurface].
     pub fn window_for_surface(&self, surface: &WlSurface) -> Option<&WindowElement> {
@@ -512,6 +610,22 @@ impl Pinnacle {
             .find(|&win| win.wl_surface().is_some_and(|surf| &*surf == surface))
     }
 
+    /// Marks the cached render plans the given root surface is part of as out of date.
+    ///
+    /// The surface may be a window's own surface, one of its popups, or one of its
+    /// decorations.
+    pub fn invalidate_render_plan(&self, root: &WlSurface) {
+        let _span = tracy_client::span!("Pinnacle::invalidate_render_plan");
+
+        let root = self
+            .popup_manager
+            .find_popup(root)
+            .and_then(|popup| find_popup_root_surface(&popup).ok())
+            .unwrap_or_else(|| root.clone());
+
+        invalidate_render_plans(&root);
+    }
+
     pub fn unmapped_window_for_surface(&self, surface: &WlSurface) -> Option<&Unmapped> {
         self.unmapped_windows.iter().find(|win| {
             win.window
@@ -553,6 +667,41 @@ impl Pinnacle {
             })
     }
 
+    /// Constrains a floating window's location according to the configured
+    /// floating containment policy.
+    ///
+    /// Windows that allow being offscreen are returned as-is.
+    pub fn constrain_floating_loc(
+        &self,
+        window: &WindowElement,
+        loc: Point<i32, Logical>,
+    ) -> Point<i32, Logical> {
+        let Some(containment) = self.config.floating_containment else {
+            return loc;
+        };
+
+        let (allow_offscreen, floating_size) =
+            window.with_state(|state| (state.allow_offscreen, state.floating_size));
+
+        if allow_offscreen {
+            return loc;
+        }
+
+        let size = if floating_size.is_empty() {
+            window.geometry().size
+        } else {
+            floating_size
+        };
+
+        let output_geos = self
+            .space
+            .outputs()
+            .filter_map(|op| self.space.output_geometry(op))
+            .collect::<Vec<_>>();
+
+        crate::util::rect::constrain_to_rects(Rectangle::new(loc, size), &output_geos, containment)
+    }
+
     /// Removes a window from the main window vec, z_index stack, and focus stacks.
     ///
     /// If `unmap` is true the window has become unmapped and will be pushed to `unmapped_windows`.
@@ -587,21 +736,26 @@ impl Pinnacle {
         let mut should_remove = true;
 
         if let Some(snap) = window.with_state_mut(|state| state.snapshot.take())
+            && !self.config.close_snapshot_hold.is_zero()
             && window.with_state(|state| state.layout_mode.is_tiled())
             && let Some(output) = maybe_output
             && let Some(loc) = self.space.element_location(window)
         {
             // Add an unmapping window to the z_index_stack that will be displayed
             // in place of the removed window until a transaction finishes.
+            let inactive_effect = output.with_state(|state| state.inactive_effect.clone());
             let unmapping = Rc::new(UnmappingWindow {
                 snapshot: snap,
                 fullscreen: window.with_state(|state| state.layout_mode.is_fullscreen()),
                 space_loc: loc,
+                layer: window.with_state(|state| state.layer),
+                color_matrix: window_color_matrix(window, inactive_effect.as_ref()),
             });
             let weak = Rc::downgrade(&unmapping);
             self.layout_state
                 .pending_unmaps
                 .add_for_output(&output, vec![unmapping]);
+            self.schedule_unmap_eviction();
             *z = ZIndexElement::Unmapping(weak);
             should_remove = false;
         }
@@ -629,9 +783,13 @@ impl Pinnacle {
         }
 
         self.keyboard_focus_stack.remove(window);
+        if let Some(cycle) = self.window_cycle.as_mut() {
+            cycle.remove(window);
+        }
 
         let to_schedule = self.space.outputs_for_element(window);
         self.space.unmap_elem(window);
+        self.pager_state.mark_dirty();
         self.loop_handle.insert_idle(move |state| {
             for output in to_schedule {
                 state.schedule_render(&output);
@@ -658,6 +816,45 @@ impl Pinnacle {
         }
     }
 
+    /// Returns the windows whose parent or parent-equivalent is `window`.
+    pub fn child_windows_for<'a>(
+        &'a self,
+        window: &'a WindowElement,
+    ) -> impl Iterator<Item = &'a WindowElement> + 'a {
+        self.windows
+            .iter()
+            .filter(move |win| self.parent_window_for(win) == Some(window))
+    }
+
+    /// Puts a new window on tags following the configured [`TagInheritance`]
+    /// and [`DialogPolicy`].
+    pub fn set_tags_for_new_window(&self, window: &WindowElement, output: &Output) {
+        let parent_tags = || {
+            self.parent_window_for(window)
+                .map(|parent| parent.with_state(|state| state.tags.clone()))
+                .filter(|tags| !tags.is_empty())
+        };
+
+        if self.config.dialog_policy == DialogPolicy::AttachToParent
+            && let Some(tags) = parent_tags()
+        {
+            window.with_state_mut(|state| state.tags = tags);
+            return;
+        }
+
+        match self.config.tag_inheritance {
+            TagInheritance::ActiveTags => window.set_tags_to_output(output),
+            TagInheritance::FirstActiveTag => {
+                window.set_tags_to_output(output);
+                window.with_state_mut(|state| state.tags.truncate(1));
+            }
+            TagInheritance::Parent => match parent_tags() {
+                Some(tags) => window.with_state_mut(|state| state.tags = tags),
+                None => window.set_tags_to_output(output),
+            },
+        }
+    }
+
     /// Updates the tags of windows that have moved to another output.
     ///
     /// A window "moves" to another output when it has more of its area over the new output
@@ -833,6 +1030,18 @@ impl State {
             });
         }
 
+        // Windows torn out with xdg-toplevel-drag start under the pointer
+        if let Some(pointer) = self.pinnacle.seat.get_pointer()
+            && let Some(loc) = self
+                .pinnacle
+                .toplevel_drag_loc(&window, pointer.current_location())
+        {
+            window.with_state_mut(|state| {
+                state.layout_mode.set_floating(true);
+                state.set_floating_loc(loc);
+            });
+        }
+
         if window.output(&self.pinnacle).is_none() {
             return;
         };
@@ -845,7 +1054,9 @@ impl State {
         // TODO: xdg activation
 
         if focus {
-            self.pinnacle.keyboard_focus_stack.set_focus(window);
+            self.pinnacle
+                .keyboard_focus_stack
+                .set_focus(window, FocusReason::Map);
         } else {
             self.pinnacle.keyboard_focus_stack.add_focus(window);
         }
@@ -957,6 +1168,19 @@ impl ZIndexElement {
             ZIndexElement::Unmapping(_) => None,
         }
     }
+
+    /// Returns the stacking layer of this element.
+    ///
+    /// Snapshots that are gone are treated as normal windows.
+    pub fn layer(&self) -> WindowLayer {
+        match self {
+            ZIndexElement::Window(window) => window.with_state(|state| state.layer),
+            ZIndexElement::Unmapping(weak) => weak
+                .upgrade()
+                .map(|unmapping| unmapping.layer)
+                .unwrap_or_default(),
+        }
+    }
 }
 
 /// A window (more correctly its snapshot) in the process of unmapping.
@@ -968,4 +1192,8 @@ pub struct UnmappingWindow {
     pub fullscreen: bool,
     /// The location of the original window in the space.
     pub space_loc: Point<i32, Logical>,
+    /// The stacking layer of the window this is for.
+    pub layer: WindowLayer,
+    /// The color effects that were applied to the window, so the snapshot keeps them.
+    pub color_matrix: Option<ColorMatrix>,
 }
@@ -0,0 +1,113 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Borders and titlebars drawn around windows with server-side decorations.
+//!
+//! Borders take up space like decoration surfaces do, so they go around the window and any
+//! decoration surfaces it has. Windows drawing their own decorations and fullscreen windows
+//! don't get a border.
+
+use crate::{
+    protocol::snowcap_decoration::Bounds,
+    state::{State, WithState},
+};
+
+/// How the border around windows looks.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct BorderStyle {
+    /// The width of the border in logical pixels.
+    pub width: u32,
+    pub focused_color: [f32; 4],
+    pub unfocused_color: [f32; 4],
+    /// The color used while the window wants attention.
+    pub urgent_color: [f32; 4],
+    /// The radius of the border's outer corners in logical pixels.
+    pub corner_radius: f32,
+    /// The height of the bar above the window in logical pixels, or 0 for no titlebar.
+    pub titlebar_height: u32,
+}
+
+impl BorderStyle {
+    /// Returns whether this style draws nothing.
+    pub fn is_empty(&self) -> bool {
+        self.width == 0 && self.titlebar_height == 0
+    }
+
+    /// Returns the border drawn around a window with its own `style`, falling back to `global`.
+    pub fn resolve(style: Option<Self>, global: Option<Self>) -> Option<Self> {
+        style.or(global).filter(|style| !style.is_empty())
+    }
+
+    /// Returns how much space the border takes up on each side of the window.
+    ///
+    /// The titlebar is drawn as part of the top border.
+    pub fn bounds(&self) -> Bounds {
+        Bounds {
+            top: self.width + self.titlebar_height,
+            bottom: self.width,
+            left: self.width,
+            right: self.width,
+        }
+    }
+}
+
+impl State {
+    /// Updates the border style and color of every window, relaying out windows whose border
+    /// changed size and redrawing the outputs of those that changed color.
+    ///
+    /// This also clears the urgency of the focused window.
+    pub fn refresh_borders(&mut self) {
+        let _span = tracy_client::span!("State::refresh_borders");
+
+        let global = self.pinnacle.config.border_style;
+        let focused_window = self.pinnacle.keyboard_focus_stack.current_focus().cloned();
+
+        if let Some(focused_window) = focused_window.as_ref() {
+            crate::api::tag::clear_urgent(&mut self.pinnacle, focused_window);
+        }
+
+        let mut changed_outputs = Vec::new();
+
+        for window in self.pinnacle.windows.clone() {
+            let focused = focused_window.as_ref() == Some(&window);
+
+            let (resized, changed) = window.with_state_mut(|state| {
+                let style = BorderStyle::resolve(state.border_style, global);
+                let color = style.map(|style| {
+                    if state.urgent {
+                        style.urgent_color
+                    } else if focused {
+                        style.focused_color
+                    } else {
+                        style.unfocused_color
+                    }
+                });
+
+                let resized =
+                    style.map(|style| style.bounds()) != state.border.map(|style| style.bounds());
+                let changed = resized || style != state.border || color != state.border_color;
+
+                state.border = style;
+                state.border_color = color;
+
+                (resized, changed)
+            });
+
+            if resized {
+                let is_tiled = window.with_state(|state| state.layout_mode.is_tiled());
+                self.pinnacle.update_window_geometry(&window, is_tiled);
+            }
+
+            if changed {
+                for output in self.pinnacle.space.outputs_for_element(&window) {
+                    if !changed_outputs.contains(&output) {
+                        changed_outputs.push(output);
+                    }
+                }
+            }
+        }
+
+        for output in changed_outputs {
+            self.schedule_render(&output);
+        }
+    }
+}
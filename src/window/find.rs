@@ -0,0 +1,168 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Finding windows by their title and app id.
+//!
+//! A query matches a window if it matches either its title or its app id, and windows
+//! are ranked by the better of the two. Fuzzy queries favor consecutive characters and
+//! characters at the start of words, while regex queries favor earlier matches.
+
+use std::cmp::Reverse;
+
+use regex::Regex;
+
+use super::WindowElement;
+
+/// Bonus for every matched character.
+const MATCH_SCORE: i64 = 16;
+/// Bonus for a matched character right after the previous one.
+const CONSECUTIVE_BONUS: i64 = 16;
+/// Bonus for a matched character at the start of a word.
+const WORD_START_BONUS: i64 = 24;
+/// Penalty for every skipped character between the first and last match.
+const GAP_PENALTY: i64 = 1;
+
+/// How a query is matched against windows.
+#[derive(Debug, Clone)]
+pub enum Matcher {
+    /// The query's characters must appear in order, ignoring case.
+    Fuzzy(Vec<char>),
+    Regex(Regex),
+}
+
+impl Matcher {
+    /// Creates a fuzzy matcher.
+    pub fn fuzzy(query: &str) -> Self {
+        Self::Fuzzy(query.to_lowercase().chars().collect())
+    }
+
+    /// Creates a regex matcher, failing if `query` isn't a valid regex.
+    pub fn regex(query: &str) -> Result<Self, regex::Error> {
+        Regex::new(query).map(Self::Regex)
+    }
+
+    /// Returns how well `haystack` matches, higher being better,
+    /// or `None` if it doesn't match.
+    pub fn score(&self, haystack: &str) -> Option<i64> {
+        match self {
+            Matcher::Fuzzy(query) => fuzzy_score(query, haystack),
+            Matcher::Regex(regex) => regex.find(haystack).map(|found| -(found.start() as i64)),
+        }
+    }
+
+    /// Returns how well `window`'s title or app id matches.
+    pub fn score_window(&self, window: &WindowElement) -> Option<i64> {
+        [window.title(), window.class()]
+            .into_iter()
+            .flatten()
+            .filter_map(|haystack| self.score(&haystack))
+            .max()
+    }
+}
+
+/// Returns the windows that match, best match first.
+///
+/// Windows that match equally well keep their order in `windows`.
+pub fn find<'a>(
+    matcher: &Matcher,
+    windows: impl IntoIterator<Item = &'a WindowElement>,
+) -> Vec<WindowElement> {
+    let mut found = windows
+        .into_iter()
+        .filter_map(|win| Some((matcher.score_window(win)?, win)))
+        .collect::<Vec<_>>();
+
+    found.sort_by_key(|(score, _)| Reverse(*score));
+
+    found.into_iter().map(|(_, win)| win.clone()).collect()
+}
+
+fn fuzzy_score(query: &[char], haystack: &str) -> Option<i64> {
+    let mut query = query.iter().peekable();
+
+    let mut score = 0;
+    let mut matched = 0;
+    let mut first_match = None;
+    let mut last_match = 0;
+    let mut prev_matched = false;
+    let mut prev_char: Option<char> = None;
+
+    for (i, c) in haystack.chars().flat_map(char::to_lowercase).enumerate() {
+        let Some(&&wanted) = query.peek() else {
+            break;
+        };
+
+        if c == wanted {
+            query.next();
+
+            matched += 1;
+            score += MATCH_SCORE;
+            if prev_matched {
+                score += CONSECUTIVE_BONUS;
+            }
+            if prev_char.is_none_or(|prev| !prev.is_alphanumeric()) {
+                score += WORD_START_BONUS;
+            }
+
+            first_match.get_or_insert(i);
+            last_match = i;
+            prev_matched = true;
+        } else {
+            prev_matched = false;
+        }
+
+        prev_char = Some(c);
+    }
+
+    if query.peek().is_some() {
+        return None;
+    }
+
+    let span = first_match.map_or(0, |first| last_match - first + 1) as i64;
+
+    Some(score - (span - matched).max(0) * GAP_PENALTY)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn score(query: &str, haystack: &str) -> Option<i64> {
+        Matcher::fuzzy(query).score(haystack)
+    }
+
+    #[test]
+    fn fuzzy_needs_chars_in_order() {
+        assert!(score("ffx", "Firefox").is_some());
+        assert!(score("xff", "Firefox").is_none());
+        assert!(score("firefoxes", "Firefox").is_none());
+    }
+
+    #[test]
+    fn fuzzy_empty_query_matches_everything() {
+        assert_eq!(score("", "anything"), Some(0));
+    }
+
+    #[test]
+    fn fuzzy_prefers_consecutive_and_word_starts() {
+        let consecutive = score("term", "terminal").unwrap();
+        let spread = score("term", "the great mirror").unwrap();
+        assert!(consecutive > spread);
+
+        let word_start = score("vc", "visual code").unwrap();
+        let mid_word = score("vc", "ovcd").unwrap();
+        assert!(word_start > mid_word);
+    }
+
+    #[test]
+    fn regex_prefers_earlier_matches() {
+        let matcher = Matcher::regex("fox").unwrap();
+
+        assert!(matcher.score("fox") > matcher.score("firefox"));
+        assert_eq!(matcher.score("alacritty"), None);
+    }
+
+    #[test]
+    fn invalid_regex_is_an_error() {
+        assert!(Matcher::regex("(unclosed").is_err());
+    }
+}
@@ -0,0 +1,109 @@
+//! Resolving the icons of windows.
+//!
+//! Windows can set their icon with xdg-toplevel-icon. Those that don't fall back to
+//! the icon named in the desktop entry for their app id.
+
+use xdg::BaseDirectories;
+
+/// Returns the icon named in the desktop entry for `app_id`.
+///
+/// This reads from the filesystem, so avoid calling it on the event loop.
+/// The desktop entry is either the one named after the app id or, failing that,
+/// the one whose `StartupWMClass` is the app id.
+pub fn desktop_entry_icon(app_id: &str) -> Option<String> {
+    if app_id.is_empty() {
+        return None;
+    }
+
+    let base_dirs = BaseDirectories::new();
+
+    let named_entry = [app_id.to_string(), app_id.to_lowercase()]
+        .into_iter()
+        .find_map(|name| base_dirs.find_data_file(format!("applications/{name}.desktop")))
+        .and_then(|path| std::fs::read_to_string(path).ok())
+        .and_then(|contents| parse_desktop_entry(&contents).icon);
+
+    named_entry.or_else(|| {
+        base_dirs
+            .list_data_files("applications")
+            .into_iter()
+            .filter(|path| path.extension().is_some_and(|ext| ext == "desktop"))
+            .filter_map(|path| std::fs::read_to_string(path).ok())
+            .map(|contents| parse_desktop_entry(&contents))
+            .find(|entry| entry.startup_wm_class.as_deref() == Some(app_id))
+            .and_then(|entry| entry.icon)
+    })
+}
+
+#[derive(Debug, Default, PartialEq)]
+struct DesktopEntry {
+    icon: Option<String>,
+    startup_wm_class: Option<String>,
+}
+
+/// Parses the keys needed to find icons out of the `[Desktop Entry]` group of a desktop entry.
+fn parse_desktop_entry(contents: &str) -> DesktopEntry {
+    let mut entry = DesktopEntry::default();
+    let mut in_main_group = false;
+
+    for line in contents.lines().map(str::trim) {
+        if line.starts_with('[') {
+            in_main_group = line == "[Desktop Entry]";
+            continue;
+        }
+
+        if !in_main_group || line.starts_with('#') {
+            continue;
+        }
+
+        let Some((key, value)) = line.split_once('=') else {
+            continue;
+        };
+        let value = value.trim();
+
+        match key.trim() {
+            "Icon" if !value.is_empty() => entry.icon = Some(value.to_string()),
+            "StartupWMClass" if !value.is_empty() => {
+                entry.startup_wm_class = Some(value.to_string())
+            }
+            _ => (),
+        }
+    }
+
+    entry
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_icon_from_main_group() {
+        let entry = parse_desktop_entry(
+            "# A comment\n\
+             [Desktop Entry]\n\
+             Name=Firefox\n\
+             Icon[de]=firefox-de\n\
+             Icon = firefox\n\
+             StartupWMClass=firefox-esr\n\
+             \n\
+             [Desktop Action new-window]\n\
+             Icon=firefox-new-window\n",
+        );
+
+        assert_eq!(
+            entry,
+            DesktopEntry {
+                icon: Some("firefox".into()),
+                startup_wm_class: Some("firefox-esr".into()),
+            }
+        );
+    }
+
+    #[test]
+    fn ignores_keys_outside_main_group() {
+        let entry = parse_desktop_entry("[Desktop Action new-window]\nIcon=firefox\n");
+
+        assert_eq!(entry, DesktopEntry::default());
+    }
+}
@@ -8,9 +8,10 @@ use smithay::{
 
 use crate::{
     backend::Backend,
+    render::util::effect::window_color_matrix,
     state::{Pinnacle, WithState},
     util::transaction::{Location, TransactionBuilder},
-    window::window_state::LayoutMode,
+    window::window_state::{LayoutMode, MaximizeRequestPolicy},
 };
 
 use super::{UnmappingWindow, WindowElement};
@@ -34,17 +35,22 @@ impl Pinnacle {
                 renderer,
                 output.current_scale().fractional_scale().into(),
                 1.0,
+                &self.gpu_accounting,
             );
         });
 
         let snap = window.with_state_mut(|state| state.snapshot.take());
         let loc = self.space.element_location(window);
 
+        let inactive_effect = output.with_state(|state| state.inactive_effect.clone());
+
         let unmapping = snap.zip(loc).map(|(snap, loc)| {
             let unmapping = Rc::new(UnmappingWindow {
                 snapshot: snap,
                 fullscreen: window.with_state(|state| state.layout_mode.is_fullscreen()),
                 space_loc: loc,
+                layer: window.with_state(|state| state.layer),
+                color_matrix: window_color_matrix(window, inactive_effect.as_ref()),
             });
 
             let weak = Rc::downgrade(&unmapping);
@@ -64,6 +70,7 @@ impl Pinnacle {
 
         let to_schedule = self.space.outputs_for_element(window);
         self.space.unmap_elem(window);
+        self.pager_state.mark_dirty();
 
         self.loop_handle.insert_idle(move |state| {
             for output in to_schedule {
@@ -98,6 +105,17 @@ impl Pinnacle {
         }
     }
 
+    /// Moves a floating window to `loc`, constrained by the floating containment policy.
+    pub fn move_floating_window(&mut self, window: &WindowElement, loc: Point<i32, Logical>) {
+        let loc = self.constrain_floating_loc(window, loc);
+
+        self.map_window_to(window, loc);
+
+        window.with_state_mut(|state| {
+            state.set_floating_loc(loc);
+        });
+    }
+
     /// Configure a window state and geometry and add it to the [`TransactionBuilder`]
     pub fn configure_window_and_add_map(
         &self,
@@ -137,12 +155,25 @@ impl Pinnacle {
             .add_for_output(output, builder.into_pending(Vec::new(), false, false));
     }
 
+    /// Queues a geometry update for a window.
+    ///
+    /// The update is applied with [`Pinnacle::apply_window_geometry`] at the end of the event
+    /// loop cycle, so multiple state changes to a window in the same cycle result in a single
+    /// configure and layout.
+    pub fn update_window_geometry(&mut self, window: &WindowElement, need_layout: bool) {
+        self.layout_state
+            .queued_updates
+            .queue_window(window, need_layout);
+    }
+
     /// Compute a new geometry and applies it.
     ///
     /// If need_layout is true a new layout is requested. The computed geometry (if any) will be
     /// applied at the same time the new layout is.
-    pub fn update_window_geometry(&mut self, window: &WindowElement, need_layout: bool) {
-        let _span = tracy_client::span!("Pinnacle::update_window_geometry");
+    ///
+    /// Prefer [`Pinnacle::update_window_geometry`] outside of the end of the event loop cycle.
+    pub fn apply_window_geometry(&mut self, window: &WindowElement, need_layout: bool) {
+        let _span = tracy_client::span!("Pinnacle::apply_window_geometry");
         let output = window.output(self);
 
         let Some(output) = output else {
@@ -162,7 +193,7 @@ impl Pinnacle {
         let non_exclusive_zone = layer_map_for_output(&output).non_exclusive_zone();
         let geo = self.compute_window_geometry(window, output_geo, non_exclusive_zone);
 
-        if !window.is_on_active_tag() {
+        if !window.is_shown() {
             if let Some(geo) = geo {
                 window.set_pending_geo(geo.size, Some(geo.loc));
             }
@@ -171,15 +202,13 @@ impl Pinnacle {
         }
 
         if need_layout {
-            if let Some(geo) = geo
-                && !mode.is_spilled()
-            {
-                self.layout_state
-                    .pending_window_updates
-                    .add_for_output(&output, vec![(window.clone(), geo)]);
-            }
+            let latched = geo
+                .filter(|_| !mode.is_spilled())
+                .map(|geo| (window.clone(), geo));
 
-            self.request_layout(&output);
+            self.layout_state
+                .queued_updates
+                .queue_layout(&output, latched);
         } else if let Some(geo) = geo {
             self.configure_window_and_map(window, &output, geo);
         } else {
@@ -226,6 +255,31 @@ impl Pinnacle {
         }
     }
 
+    /// Handles a client asking for a mapped window to be maximized,
+    /// following the window's [`MaximizeRequestPolicy`].
+    pub fn handle_client_maximize(&mut self, window: &WindowElement) {
+        let policy = window.maximize_request_policy(self.config.maximize_request_policy);
+
+        if policy == MaximizeRequestPolicy::FullscreenWithinTile {
+            window.with_state_mut(|state| state.fullscreen_within_tile = true);
+            window.configure_states();
+        }
+
+        self.update_window_layout_mode(window, |mode| policy.apply(mode));
+    }
+
+    /// Handles a client asking for a mapped window to be unmaximized.
+    pub fn handle_client_unmaximize(&mut self, window: &WindowElement) {
+        let was_within_tile =
+            window.with_state_mut(|state| std::mem::take(&mut state.fullscreen_within_tile));
+
+        if was_within_tile {
+            window.configure_states();
+        }
+
+        self.update_window_layout_mode(window, |mode| mode.set_client_maximized(false));
+    }
+
     /// Compute a floating window location for an output.
     ///
     /// This is used when a window's tags are moved across outputs to prevent the
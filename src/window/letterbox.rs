@@ -0,0 +1,96 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Letterboxing of fullscreen windows.
+//!
+//! A fullscreen window that doesn't fill its output, like a game running at a lower resolution
+//! or with a different aspect ratio, is centered on the output with black bars around it
+//! instead of leaving the desktop visible.
+
+use smithay::utils::{Logical, Point, Rectangle};
+
+use crate::state::{Pinnacle, WithState};
+
+use super::WindowElement;
+
+/// The color of the bars around letterboxed windows.
+pub const LETTERBOX_COLOR: [f32; 4] = [0.0, 0.0, 0.0, 1.0];
+
+impl Pinnacle {
+    /// Returns where `window` should be mapped instead of `loc`, centering it on its output
+    /// if it's a fullscreen window that needs to be letterboxed.
+    ///
+    /// This also updates whether the window is letterboxed.
+    pub fn letterboxed_loc(
+        &self,
+        window: &WindowElement,
+        loc: Point<i32, Logical>,
+    ) -> Point<i32, Logical> {
+        let output_size = window
+            .output(self)
+            .and_then(|output| self.space.output_geometry(&output))
+            .map(|geo| geo.size);
+
+        let size = window.geometry().size;
+
+        let offset = match output_size {
+            Some(output_size)
+                if self.config.letterbox_fullscreen
+                    && window.with_state(|state| state.layout_mode.is_fullscreen())
+                    && (size.w < output_size.w || size.h < output_size.h) =>
+            {
+                Point::from((
+                    (output_size.w - size.w).max(0) / 2,
+                    (output_size.h - size.h).max(0) / 2,
+                ))
+            }
+            _ => Point::default(),
+        };
+
+        window.with_state_mut(|state| state.letterboxed = offset != Point::default());
+
+        loc + offset
+    }
+
+    /// Recenters a mapped fullscreen window after it resized itself or letterboxing was
+    /// toggled.
+    pub fn update_letterbox(&mut self, window: &WindowElement) {
+        let is_fullscreen = window.with_state(|state| state.layout_mode.is_fullscreen());
+        if !is_fullscreen {
+            window.with_state_mut(|state| state.letterboxed = false);
+            return;
+        }
+
+        let Some(current_loc) = self.space.element_location(window) else {
+            return;
+        };
+
+        let Some(output_geo) = window
+            .output(self)
+            .and_then(|output| self.space.output_geometry(&output))
+        else {
+            return;
+        };
+
+        let was_letterboxed = window.with_state(|state| state.letterboxed);
+        let loc = self.letterboxed_loc(window, output_geo.loc);
+        let is_letterboxed = window.with_state(|state| state.letterboxed);
+
+        if loc == current_loc && was_letterboxed == is_letterboxed {
+            return;
+        }
+
+        if let Some(surface) = window.x11_surface() {
+            let _ = surface.configure(Rectangle::new(loc, surface.geometry().size));
+        }
+
+        self.space.map_element(window.clone(), loc, false);
+        self.pager_state.mark_dirty();
+
+        let to_schedule = self.space.outputs_for_element(window);
+        self.loop_handle.insert_idle(move |state| {
+            for output in to_schedule {
+                state.schedule_render(&output);
+            }
+        });
+    }
+}
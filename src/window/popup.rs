@@ -0,0 +1,206 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Policies for xdg-popups, like menus and tooltips.
+
+use std::time::Duration;
+
+use smithay::{
+    desktop::PopupManager,
+    reexports::{
+        calloop::{
+            RegistrationToken,
+            timer::{TimeoutAction, Timer},
+        },
+        wayland_server::protocol::wl_surface::WlSurface,
+    },
+    utils::{Logical, Rectangle, SERIAL_COUNTER, Serial},
+};
+
+use crate::{
+    api::signal::Signal,
+    state::{Pinnacle, State},
+};
+
+/// How popups are constrained.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct PopupPolicy {
+    /// Whether popups are moved fully onto their output, even if their positioner
+    /// doesn't allow it.
+    pub clamp_to_output: bool,
+    /// Whether popups of windows hidden by a tag switch are closed.
+    pub close_on_tag_switch: bool,
+    /// How long a popup can hold a grab before it is closed.
+    pub max_grab_duration: Option<Duration>,
+}
+
+/// The popup currently holding the keyboard grab.
+#[derive(Debug)]
+pub struct PopupGrabState {
+    /// The toplevel surface the popup belongs to.
+    pub root: WlSurface,
+    pub serial: Serial,
+    /// The timer that closes the popup after [`PopupPolicy::max_grab_duration`].
+    timer: Option<RegistrationToken>,
+}
+
+/// Moves `geo` so it lies within `bounds` where possible.
+///
+/// Popups larger than `bounds` are aligned to its top left corner.
+pub fn clamp_popup_geometry(
+    mut geo: Rectangle<i32, Logical>,
+    bounds: Rectangle<i32, Logical>,
+) -> Rectangle<i32, Logical> {
+    let max_x = bounds.loc.x + bounds.size.w - geo.size.w;
+    let max_y = bounds.loc.y + bounds.size.h - geo.size.h;
+
+    geo.loc.x = geo.loc.x.min(max_x).max(bounds.loc.x);
+    geo.loc.y = geo.loc.y.min(max_y).max(bounds.loc.y);
+
+    geo
+}
+
+impl Pinnacle {
+    /// Closes the popups of windows that are hidden because they aren't on an active tag
+    /// or are minimized.
+    pub fn close_hidden_popups(&mut self) {
+        if !self.config.popup_policy.close_on_tag_switch {
+            return;
+        }
+
+        for window in self.windows.iter() {
+            if window.is_shown() {
+                continue;
+            }
+
+            let Some(surface) = window.wl_surface() else {
+                continue;
+            };
+
+            for (popup, _) in PopupManager::popups_for_surface(&surface) {
+                let _ = PopupManager::dismiss_popup(&surface, &popup);
+            }
+        }
+    }
+}
+
+impl State {
+    /// Tracks a new popup grab, starting its timer if grabs have a maximum duration.
+    pub fn popup_grab_started(&mut self, root: WlSurface, serial: Serial) {
+        if let Some(grab) = self.pinnacle.popup_grab_state.take()
+            && let Some(timer) = grab.timer
+        {
+            self.pinnacle.loop_handle.remove(timer);
+        }
+
+        let timer = self
+            .pinnacle
+            .config
+            .popup_policy
+            .max_grab_duration
+            .map(|duration| {
+                self.pinnacle
+                    .loop_handle
+                    .insert_source(Timer::from_duration(duration), move |_, _, state| {
+                        state.end_popup_grab(serial);
+                        TimeoutAction::Drop
+                    })
+                    .expect("failed to insert popup grab timer")
+            });
+
+        self.pinnacle.popup_grab_state = Some(PopupGrabState {
+            root,
+            serial,
+            timer,
+        });
+    }
+
+    /// Closes the grabbing popup and its parents if it still holds the grab started
+    /// with `serial`.
+    fn end_popup_grab(&mut self, serial: Serial) {
+        let Some(grab) = self
+            .pinnacle
+            .popup_grab_state
+            .take_if(|grab| grab.serial == serial)
+        else {
+            return;
+        };
+
+        let Some(keyboard) = self.pinnacle.seat.get_keyboard() else {
+            return;
+        };
+
+        if !keyboard.has_grab(serial) {
+            return;
+        }
+
+        for (popup, _) in PopupManager::popups_for_surface(&grab.root) {
+            let _ = PopupManager::dismiss_popup(&grab.root, &popup);
+        }
+
+        keyboard.unset_grab(self);
+        if let Some(pointer) = self.pinnacle.seat.get_pointer()
+            && pointer.has_grab(serial)
+        {
+            let time = Duration::from(self.pinnacle.clock.now()).as_millis() as u32;
+            pointer.unset_grab(self, SERIAL_COUNTER.next_serial(), time);
+        }
+    }
+
+    /// Signals that a keybind was pressed while a popup held the keyboard grab.
+    ///
+    /// Keybinds still run, but actions that move keyboard focus don't take effect
+    /// until the popup closes.
+    pub fn popup_grab_blocked_keybind(&mut self) {
+        let Some(grab) = self.pinnacle.popup_grab_state.as_ref() else {
+            return;
+        };
+
+        let Some(keyboard) = self.pinnacle.seat.get_keyboard() else {
+            return;
+        };
+
+        if !keyboard.has_grab(grab.serial) {
+            return;
+        }
+
+        if let Some(window) = self.pinnacle.window_for_surface(&grab.root).cloned() {
+            self.pinnacle
+                .signal_state
+                .window_popup_grab_blocked_keybind
+                .signal(&window);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn popups_are_moved_onto_bounds() {
+        let bounds = Rectangle::new((0, 0).into(), (1920, 1080).into());
+
+        let geo = Rectangle::new((1800, 1000).into(), (300, 200).into());
+        assert_eq!(
+            clamp_popup_geometry(geo, bounds),
+            Rectangle::new((1620, 880).into(), (300, 200).into())
+        );
+
+        let geo = Rectangle::new((-50, 100).into(), (300, 200).into());
+        assert_eq!(
+            clamp_popup_geometry(geo, bounds),
+            Rectangle::new((0, 100).into(), (300, 200).into())
+        );
+    }
+
+    #[test]
+    fn oversized_popups_align_to_top_left() {
+        let bounds = Rectangle::new((100, 100).into(), (400, 300).into());
+
+        let geo = Rectangle::new((300, 50).into(), (500, 400).into());
+        assert_eq!(
+            clamp_popup_geometry(geo, bounds),
+            Rectangle::new((100, 100).into(), (500, 400).into())
+        );
+    }
+}
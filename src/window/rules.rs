@@ -13,7 +13,10 @@ use crate::{
 
 use super::{
     Unmapped, UnmappedState, WindowElement,
-    window_state::{FullscreenOrMaximized, LayoutMode, WindowId},
+    border::BorderStyle,
+    window_state::{
+        FullscreenOrMaximized, LayoutMode, MaximizeRequestPolicy, SizeConstraints, WindowId,
+    },
 };
 
 use std::{
@@ -27,8 +30,54 @@ use std::{
 #[derive(Debug, Default)]
 pub struct WindowRuleState {
     pub pending_windows: HashMap<WindowElement, PendingWindowRuleRequest>,
-    pub senders: Vec<(Sender<WindowRuleRequest>, Arc<AtomicU32>)>,
+    /// Window rule senders, along with the namespace of the client they belong to.
+    pub senders: Vec<(Sender<WindowRuleRequest>, Arc<AtomicU32>, Option<String>)>,
     current_request_id: u32,
+    /// Declarative rules, sorted by ascending priority
+    declarative_rules: Vec<DeclarativeRule>,
+    strategy: RuleStrategy,
+}
+
+/// A window rule that the compositor matches and applies itself,
+/// as opposed to rules run by the config.
+#[derive(Debug, Clone, Default)]
+pub struct DeclarativeRule {
+    pub name: String,
+    /// Rules with a higher priority are applied later.
+    pub priority: i32,
+    /// Matches if the window's app id is exactly this.
+    pub app_id: Option<String>,
+    /// Matches if the window's title contains this.
+    pub title: Option<String>,
+    pub rules: WindowRules,
+}
+
+/// How multiple matching [`DeclarativeRule`]s are combined.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum RuleStrategy {
+    /// Every matching rule is applied in priority order, with later
+    /// rules overriding properties set by earlier ones.
+    #[default]
+    Merge,
+    /// Only the matching rule with the highest priority is applied.
+    LastMatchWins,
+}
+
+impl DeclarativeRule {
+    pub fn matches(&self, window: &WindowElement) -> bool {
+        let app_id_matches = self
+            .app_id
+            .as_ref()
+            .is_none_or(|app_id| window.class().as_ref() == Some(app_id));
+
+        let title_matches = self.title.as_ref().is_none_or(|title| {
+            window
+                .title()
+                .is_some_and(|win_title| win_title.contains(title.as_str()))
+        });
+
+        app_id_matches && title_matches
+    }
 }
 
 #[derive(Debug, Clone, Default)]
@@ -40,6 +89,59 @@ pub struct WindowRules {
     pub floating_size: Option<Size<i32, Logical>>,
     pub decoration_mode: Option<zxdg_toplevel_decoration_v1::Mode>,
     pub tags: Option<IndexSet<Tag>>,
+    pub maximize_request_policy: Option<MaximizeRequestPolicy>,
+    pub size_constraints: SizeConstraints,
+    pub border_style: Option<BorderStyle>,
+}
+
+impl WindowRules {
+    /// Overrides properties in `self` with those set in `other`.
+    pub fn merge(&mut self, other: &WindowRules) {
+        let WindowRules {
+            layout_mode,
+            focused,
+            floating_x,
+            floating_y,
+            floating_size,
+            decoration_mode,
+            tags,
+            maximize_request_policy,
+            size_constraints,
+            border_style,
+        } = other;
+
+        self.layout_mode = layout_mode.or(self.layout_mode);
+        self.focused = focused.or(self.focused);
+        self.floating_x = floating_x.or(self.floating_x);
+        self.floating_y = floating_y.or(self.floating_y);
+        self.floating_size = floating_size.or(self.floating_size);
+        self.decoration_mode = decoration_mode.or(self.decoration_mode);
+        self.maximize_request_policy = maximize_request_policy.or(self.maximize_request_policy);
+        self.size_constraints.merge(size_constraints);
+        self.border_style = border_style.or(self.border_style);
+
+        let tags = tags.as_ref().map(|tags| {
+            tags.iter()
+                .filter(|tag| !tag.defunct())
+                .cloned()
+                .collect::<IndexSet<_>>()
+        });
+        if let Some(tags) = tags.filter(|tags| !tags.is_empty()) {
+            self.tags = Some(tags);
+        }
+    }
+}
+
+/// A window property that a window rule can set.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RuleProperty {
+    LayoutMode,
+    FloatingGeometry,
+    DecorationMode,
+    Tags,
+    MaximizeRequestPolicy,
+    SizeConstraints,
+    BorderStyle,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -69,7 +171,7 @@ impl WindowRuleState {
         self.current_request_id += 1;
 
         let mut waiting_on = Vec::new();
-        self.senders.retain(|(sender, id)| {
+        self.senders.retain(|(sender, id, _)| {
             let sent = sender
                 .send_blocking(WindowRuleRequest {
                     request_id,
@@ -98,8 +200,75 @@ impl WindowRuleState {
         true
     }
 
-    pub fn new_sender(&mut self, sender: Sender<WindowRuleRequest>, id_ctr: Arc<AtomicU32>) {
-        self.senders.push((sender, id_ctr));
+    pub fn new_sender(
+        &mut self,
+        sender: Sender<WindowRuleRequest>,
+        id_ctr: Arc<AtomicU32>,
+        namespace: Option<String>,
+    ) {
+        self.senders.push((sender, id_ctr, namespace));
+    }
+
+    /// Removes the senders of the client with the given namespace.
+    ///
+    /// Windows waiting on those senders stop waiting and show up in
+    /// [`Self::finished_windows`] if nothing else is pending.
+    pub fn remove_namespace(&mut self, namespace: &str) {
+        let mut removed = Vec::new();
+        self.senders.retain(|(_, id, sender_namespace)| {
+            let keep = sender_namespace.as_deref() != Some(namespace);
+            if !keep {
+                removed.push(id.clone());
+            }
+            keep
+        });
+
+        for pending_request in self.pending_windows.values_mut() {
+            pending_request
+                .waiting_on
+                .retain(|id| !removed.iter().any(|removed| Arc::ptr_eq(id, removed)));
+        }
+    }
+
+    /// Replaces all declarative rules.
+    pub fn set_declarative_rules(
+        &mut self,
+        mut rules: Vec<DeclarativeRule>,
+        strategy: RuleStrategy,
+    ) {
+        // Stable, so rules with the same priority apply in the order given
+        rules.sort_by_key(|rule| rule.priority);
+        self.declarative_rules = rules;
+        self.strategy = strategy;
+    }
+
+    pub fn clear_declarative_rules(&mut self) {
+        self.declarative_rules.clear();
+        self.strategy = RuleStrategy::default();
+    }
+
+    /// Returns the declarative rules that apply to `window`, in the order they apply.
+    pub fn matching_rules(&self, window: &WindowElement) -> Vec<&DeclarativeRule> {
+        let mut matching = self
+            .declarative_rules
+            .iter()
+            .filter(|rule| rule.matches(window))
+            .collect::<Vec<_>>();
+
+        if self.strategy == RuleStrategy::LastMatchWins && matching.len() > 1 {
+            matching.drain(..matching.len() - 1);
+        }
+
+        matching
+    }
+
+    /// Combines the declarative rules that apply to `window`.
+    pub fn declarative_rules_for(&self, window: &WindowElement) -> WindowRules {
+        let mut rules = WindowRules::default();
+        for rule in self.matching_rules(window) {
+            rules.merge(&rule.rules);
+        }
+        rules
     }
 
     pub fn finished_windows(&mut self) -> Vec<WindowElement> {
@@ -148,7 +317,33 @@ impl PendingWindowRuleRequest {
 }
 
 impl Pinnacle {
-    pub fn apply_window_rules_and_send_initial_configure(&self, unmapped: &mut Unmapped) {
+    /// Applies rules to and configures unmapped windows that are no longer waiting on clients.
+    pub fn apply_finished_window_rules(&mut self) {
+        for win in self.window_rule_state.finished_windows() {
+            let Some(unmapped_idx) = self
+                .unmapped_windows
+                .iter()
+                .position(|unmapped| unmapped.window == win)
+            else {
+                continue;
+            };
+
+            let mut unmapped = self.unmapped_windows.swap_remove(unmapped_idx);
+
+            self.apply_window_rules_and_send_initial_configure(&mut unmapped);
+
+            self.unmapped_windows.push(unmapped);
+        }
+    }
+
+    pub fn apply_window_rules_and_send_initial_configure(&mut self, unmapped: &mut Unmapped) {
+        // Windows coming back after a restart go where they were, overriding other rules
+        if let Some(restored) = self.take_restored_rules(&unmapped.window)
+            && let UnmappedState::WaitingForRules { rules, .. } = &mut unmapped.state
+        {
+            rules.merge(&restored);
+        }
+
         let UnmappedState::WaitingForRules {
             rules,
             client_requests,
@@ -165,6 +360,9 @@ impl Pinnacle {
             floating_size,
             decoration_mode,
             tags,
+            maximize_request_policy,
+            size_constraints,
+            border_style,
         } = rules;
 
         let ClientRequests {
@@ -172,19 +370,42 @@ impl Pinnacle {
             decoration_mode: client_decoration_mode,
         } = client_requests;
 
+        let policy = maximize_request_policy.unwrap_or(self.config.maximize_request_policy);
+
+        let client_layout_mode = client_layout_mode.filter(|mode| {
+            *mode != FullscreenOrMaximized::Maximized || policy != MaximizeRequestPolicy::Ignore
+        });
+        let fullscreen_within_tile = layout_mode.is_none()
+            && client_layout_mode == Some(FullscreenOrMaximized::Maximized)
+            && policy == MaximizeRequestPolicy::FullscreenWithinTile;
+
         let attempt_float_on_map = layout_mode.is_none() && client_layout_mode.is_none();
 
         let layout_mode = layout_mode
             .or_else(|| {
-                client_layout_mode.map(|mode| match mode {
-                    FullscreenOrMaximized::Fullscreen => LayoutMode::new_fullscreen_external(),
-                    FullscreenOrMaximized::Maximized => LayoutMode::new_maximized_external(),
+                client_layout_mode.and_then(|mode| match mode {
+                    FullscreenOrMaximized::Fullscreen => {
+                        Some(LayoutMode::new_fullscreen_external())
+                    }
+                    FullscreenOrMaximized::Maximized => {
+                        let mut layout_mode = LayoutMode::new_tiled();
+                        policy.apply(&mut layout_mode);
+                        (layout_mode != LayoutMode::new_tiled()).then_some(layout_mode)
+                    }
                 })
             })
             .unwrap_or(LayoutMode::new_tiled());
 
+        // Resolve the border now so the window is laid out with it when it maps
+        let border = BorderStyle::resolve(*border_style, self.config.border_style);
+
         unmapped.window.with_state_mut(|state| {
             state.layout_mode = layout_mode;
+            state.maximize_request_policy = *maximize_request_policy;
+            state.size_constraints = *size_constraints;
+            state.border_style = *border_style;
+            state.border = border;
+            state.fullscreen_within_tile = fullscreen_within_tile;
             state.floating_x = *floating_x;
             state.floating_y = *floating_y;
             state.floating_size = floating_size.unwrap_or(state.floating_size);
@@ -232,7 +453,9 @@ impl Pinnacle {
         };
 
         unmapped.state = UnmappedState::WaitingForRules {
-            rules: Default::default(),
+            rules: self
+                .window_rule_state
+                .declarative_rules_for(&unmapped.window),
             client_requests: client_requests.clone(),
         };
 
@@ -0,0 +1,151 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Snapping windows to regions of their output.
+//!
+//! A region is a block of cells in a grid laid over the non-exclusive zone of a window's output.
+//! Snapping a window floats it and gives it the region's geometry.
+
+use smithay::{
+    desktop::layer_map_for_output,
+    utils::{Logical, Rectangle},
+};
+
+use crate::state::{State, WithState};
+
+use super::WindowElement;
+
+/// A grid of equally sized cells.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SnapGrid {
+    pub columns: u32,
+    pub rows: u32,
+}
+
+impl Default for SnapGrid {
+    fn default() -> Self {
+        Self {
+            columns: 3,
+            rows: 2,
+        }
+    }
+}
+
+/// A block of cells in a [`SnapGrid`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SnapRegion {
+    pub grid: SnapGrid,
+    pub column: u32,
+    pub row: u32,
+    pub column_span: u32,
+    pub row_span: u32,
+}
+
+impl SnapRegion {
+    /// Returns whether this region lies within its grid and isn't empty.
+    pub fn is_valid(&self) -> bool {
+        self.column_span > 0
+            && self.row_span > 0
+            && self.column.saturating_add(self.column_span) <= self.grid.columns
+            && self.row.saturating_add(self.row_span) <= self.grid.rows
+    }
+
+    /// Returns the geometry of this region when its grid fills `zone`.
+    ///
+    /// Cell edges are rounded so adjacent regions never overlap or leave gaps.
+    pub fn geometry(&self, zone: Rectangle<i32, Logical>) -> Rectangle<i32, Logical> {
+        let edge = |start: i32, len: i32, index: u32, count: u32| {
+            start + (i64::from(len) * i64::from(index) / i64::from(count.max(1))) as i32
+        };
+
+        let x1 = edge(zone.loc.x, zone.size.w, self.column, self.grid.columns);
+        let x2 = edge(
+            zone.loc.x,
+            zone.size.w,
+            self.column + self.column_span,
+            self.grid.columns,
+        );
+        let y1 = edge(zone.loc.y, zone.size.h, self.row, self.grid.rows);
+        let y2 = edge(
+            zone.loc.y,
+            zone.size.h,
+            self.row + self.row_span,
+            self.grid.rows,
+        );
+
+        Rectangle::new((x1, y1).into(), (x2 - x1, y2 - y1).into())
+    }
+}
+
+impl State {
+    /// Floats `window` and moves and resizes it to fill `region` of its output.
+    pub fn snap_window_to_region(&mut self, window: &WindowElement, region: SnapRegion) {
+        let Some(output) = window.output(&self.pinnacle) else {
+            return;
+        };
+        let Some(output_geo) = self.pinnacle.space.output_geometry(&output) else {
+            return;
+        };
+
+        let mut zone = layer_map_for_output(&output).non_exclusive_zone();
+        zone.loc += output_geo.loc;
+
+        let geo = region.geometry(zone);
+
+        window.with_state_mut(|state| {
+            state.set_floating_loc(geo.loc);
+            state.floating_size = geo.size;
+        });
+
+        if window.with_state(|state| state.layout_mode.is_floating()) {
+            self.pinnacle.update_window_geometry(window, false);
+        } else {
+            self.pinnacle
+                .update_window_layout_mode(window, |layout_mode| layout_mode.set_floating(true));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn region(grid: (u32, u32), cell: (u32, u32), span: (u32, u32)) -> SnapRegion {
+        SnapRegion {
+            grid: SnapGrid {
+                columns: grid.0,
+                rows: grid.1,
+            },
+            column: cell.0,
+            row: cell.1,
+            column_span: span.0,
+            row_span: span.1,
+        }
+    }
+
+    #[test]
+    fn halves_fill_zone() {
+        let zone = Rectangle::new((10, 20).into(), (1001, 600).into());
+
+        let left = region((2, 1), (0, 0), (1, 1)).geometry(zone);
+        let right = region((2, 1), (1, 0), (1, 1)).geometry(zone);
+
+        assert_eq!(left, Rectangle::new((10, 20).into(), (500, 600).into()));
+        assert_eq!(right, Rectangle::new((510, 20).into(), (501, 600).into()));
+    }
+
+    #[test]
+    fn spans_cover_multiple_cells() {
+        let zone = Rectangle::new((0, 0).into(), (1200, 900).into());
+
+        let geo = region((3, 3), (1, 1), (2, 2)).geometry(zone);
+
+        assert_eq!(geo, Rectangle::new((400, 300).into(), (800, 600).into()));
+    }
+
+    #[test]
+    fn regions_outside_grid_are_invalid() {
+        assert!(region((3, 2), (2, 1), (1, 1)).is_valid());
+        assert!(!region((3, 2), (2, 1), (2, 1)).is_valid());
+        assert!(!region((3, 2), (0, 0), (0, 1)).is_valid());
+    }
+}
@@ -0,0 +1,119 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Detection of windows that stopped responding.
+//!
+//! A window is unresponsive once it has gone [`UNRESPONSIVE_TIMEOUT`] without committing
+//! a configure it was sent while visible. It becomes responsive again when it catches up.
+
+use std::time::{Duration, Instant};
+
+use smithay::utils::Serial;
+
+use crate::{
+    api::signal::Signal,
+    render::util::effect::WindowEffect,
+    state::{State, WithState},
+};
+
+use super::WindowElement;
+
+/// How long a window can take to commit a configure before it's unresponsive.
+pub const UNRESPONSIVE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// How often the event loop wakes up to check for unresponsive windows.
+pub const WATCHDOG_INTERVAL: Duration = Duration::from_secs(1);
+
+/// The effect used to grey out unresponsive windows.
+const GREY_OUT: WindowEffect = WindowEffect {
+    opacity: 1.0,
+    dim: 0.4,
+    desaturate: 1.0,
+};
+
+impl WindowElement {
+    /// Starts waiting for this window to commit the configure with the given serial.
+    ///
+    /// If the window is already waiting on a configure, the older one is kept.
+    pub(crate) fn watch_configure(&self, serial: Serial) {
+        self.with_state_mut(|state| {
+            state
+                .uncommitted_configure
+                .get_or_insert_with(|| (serial, Instant::now()));
+        });
+    }
+
+    /// Stops waiting on configures up to and including `serial`.
+    pub(crate) fn configure_committed(&self, serial: Serial) {
+        self.with_state_mut(|state| {
+            if state
+                .uncommitted_configure
+                .is_some_and(|(uncommitted, _)| uncommitted <= serial)
+            {
+                state.uncommitted_configure = None;
+            }
+        });
+    }
+
+    /// Returns whether this window has been responding to configures.
+    pub fn is_responsive(&self) -> bool {
+        !self.with_state(|state| state.unresponsive)
+    }
+}
+
+impl State {
+    /// Updates which windows are unresponsive, signaling windows that just became
+    /// unresponsive and redrawing the outputs of windows that got greyed out or restored.
+    pub fn refresh_unresponsive_windows(&mut self) {
+        let _span = tracy_client::span!("State::refresh_unresponsive_windows");
+
+        let grey_out = self.pinnacle.config.grey_out_unresponsive;
+
+        let mut changed_outputs = Vec::new();
+
+        for window in self.pinnacle.windows.iter() {
+            let visible = window.is_shown();
+
+            let (became_unresponsive, effect_changed) = window.with_state_mut(|state| {
+                let unresponsive = match state.uncommitted_configure.as_mut() {
+                    None => false,
+                    // Hidden windows may not commit until they get frame callbacks,
+                    // so only count the time they're visible.
+                    Some((_, sent)) if !visible => {
+                        *sent = Instant::now();
+                        state.unresponsive
+                    }
+                    Some((_, sent)) => sent.elapsed() >= UNRESPONSIVE_TIMEOUT,
+                };
+
+                let became_unresponsive = unresponsive && !state.unresponsive;
+                state.unresponsive = unresponsive;
+
+                let effect = (unresponsive && grey_out).then_some(GREY_OUT);
+                let effect_changed = state.unresponsive_effect != effect;
+                state.unresponsive_effect = effect;
+
+                (became_unresponsive, effect_changed)
+            });
+
+            if became_unresponsive {
+                self.pinnacle
+                    .signal_state
+                    .window_unresponsive
+                    .signal(window);
+            }
+
+            if effect_changed {
+                for output in self.pinnacle.space.outputs_for_element(window) {
+                    if !changed_outputs.contains(&output) {
+                        changed_outputs.push(output);
+                    }
+                }
+            }
+        }
+
+        for output in changed_outputs {
+            self.backend.reset_buffers(&output);
+            self.schedule_render(&output);
+        }
+    }
+}
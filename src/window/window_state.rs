@@ -1,9 +1,13 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
 
-use std::sync::atomic::{AtomicU32, Ordering};
+use std::{
+    sync::atomic::{AtomicU32, Ordering},
+    time::Instant,
+};
 
 use indexmap::IndexSet;
 use smithay::{
+    backend::renderer::element::Id,
     desktop::{WindowSurface, layer_map_for_output},
     reexports::wayland_protocols::xdg::{
         decoration::zv1::server::zxdg_toplevel_decoration_v1, shell::server::xdg_toplevel,
@@ -15,14 +19,18 @@ use tracing::warn;
 
 use crate::{
     decoration::DecorationSurface,
-    protocol::snowcap_decoration::Bounds,
-    render::util::snapshot::WindowSnapshot,
+    protocol::{snowcap_decoration::Bounds, xdg_toplevel_icon::ToplevelIcon},
+    render::util::{
+        cache::RenderPlanCache,
+        effect::{EffectIds, WindowEffect},
+        snapshot::WindowSnapshot,
+    },
     state::{Pinnacle, WithState},
     tag::Tag,
     util::transaction::Transaction,
 };
 
-use super::{Unmapped, WindowElement};
+use super::{Unmapped, WindowElement, border::BorderStyle};
 
 /// A unique identifier for each window.
 #[derive(Debug, Hash, Clone, Copy, PartialEq, Eq)]
@@ -373,6 +381,139 @@ impl LayoutMode {
     }
 }
 
+/// What to do when a client asks for its window to be maximized.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum MaximizeRequestPolicy {
+    /// Maximize the window.
+    #[default]
+    Honor,
+    /// Leave the window as is.
+    Ignore,
+    /// Tell the window it's fullscreen but leave it in its tile.
+    FullscreenWithinTile,
+    /// Float the window and maximize it, so it's floating once unmaximized.
+    FloatAndMaximize,
+}
+
+impl MaximizeRequestPolicy {
+    /// Updates `layout_mode` for a client's maximize request following this policy.
+    ///
+    /// [`FullscreenWithinTile`][Self::FullscreenWithinTile] doesn't change the layout mode;
+    /// set [`WindowElementState::fullscreen_within_tile`] instead.
+    pub fn apply(self, layout_mode: &mut LayoutMode) {
+        match self {
+            MaximizeRequestPolicy::Honor => layout_mode.set_client_maximized(true),
+            MaximizeRequestPolicy::Ignore | MaximizeRequestPolicy::FullscreenWithinTile => (),
+            MaximizeRequestPolicy::FloatAndMaximize => {
+                layout_mode.set_floating(true);
+                layout_mode.set_client_maximized(true);
+            }
+        }
+    }
+}
+
+/// Which tags a new window is put on before window rules run.
+///
+/// Window rules that set tags always override this.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum TagInheritance {
+    /// All active tags on the focused output.
+    #[default]
+    ActiveTags,
+    /// Only the first active tag on the focused output.
+    FirstActiveTag,
+    /// The tags of the window's parent, or the active tags on the focused output
+    /// if it doesn't have one.
+    Parent,
+}
+
+/// How dialogs, windows with a parent, are placed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum DialogPolicy {
+    /// Dialogs are put on tags like any other window.
+    #[default]
+    Independent,
+    /// Dialogs are put on their parent's tags and centered over it,
+    /// even if another output is focused.
+    AttachToParent,
+}
+
+/// Limits on a window's size set by window rules.
+///
+/// These are enforced whenever the window is configured or resized, on top of the
+/// minimum and maximum sizes the client asks for.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct SizeConstraints {
+    pub min_size: Option<Size<i32, Logical>>,
+    pub max_size: Option<Size<i32, Logical>>,
+    /// The window's width divided by its height.
+    pub aspect_ratio: Option<f64>,
+}
+
+impl SizeConstraints {
+    /// Overrides constraints in `self` with those set in `other`.
+    pub fn merge(&mut self, other: &SizeConstraints) {
+        self.min_size = other.min_size.or(self.min_size);
+        self.max_size = other.max_size.or(self.max_size);
+        self.aspect_ratio = other.aspect_ratio.or(self.aspect_ratio);
+    }
+
+    /// Clamps `size` to these constraints.
+    ///
+    /// The aspect ratio is kept by shrinking the side that's too long, so it wins
+    /// over the minimum size if they conflict. Sides of 0, which let clients pick
+    /// their own size, are left alone.
+    pub fn clamp(&self, mut size: Size<i32, Logical>) -> Size<i32, Logical> {
+        if size.w > 0 {
+            if let Some(min_size) = self.min_size {
+                size.w = size.w.max(min_size.w);
+            }
+            if let Some(max_size) = self.max_size {
+                size.w = size.w.min(max_size.w);
+            }
+        }
+
+        if size.h > 0 {
+            if let Some(min_size) = self.min_size {
+                size.h = size.h.max(min_size.h);
+            }
+            if let Some(max_size) = self.max_size {
+                size.h = size.h.min(max_size.h);
+            }
+        }
+
+        if let Some(aspect_ratio) = self.aspect_ratio
+            && size.w > 0
+            && size.h > 0
+        {
+            let width_for_height = (size.h as f64 * aspect_ratio).round() as i32;
+
+            if width_for_height <= size.w {
+                size.w = width_for_height.max(1);
+            } else {
+                size.h = (size.w as f64 / aspect_ratio).round().max(1.0) as i32;
+            }
+        }
+
+        size
+    }
+}
+
+/// The stacking layer of a window.
+///
+/// Windows are always stacked above every window in a lower layer,
+/// no matter how they're raised or lowered.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum WindowLayer {
+    /// Below all normal windows.
+    Below,
+    /// The layer windows start out in.
+    #[default]
+    Normal,
+    /// Above all normal windows.
+    Above,
+}
+
 /// State of a [`WindowElement`]
 #[derive(Debug)]
 pub struct WindowElementState {
@@ -401,6 +542,51 @@ pub struct WindowElementState {
     pub decoration_surfaces: Vec<DecorationSurface>,
 
     pub vrr_demand: Option<VrrDemand>,
+    /// Whether this window ignores the floating containment policy.
+    pub allow_offscreen: bool,
+    /// Whether this window is click-through and can't get keyboard focus.
+    pub input_disabled: bool,
+    /// Whether holding this window moves it.
+    pub hold_to_move_enabled: bool,
+    /// The scale this window is told to render at instead of its output's.
+    pub scale_override: Option<f64>,
+    /// The surfaces last used to render this window.
+    pub render_plan_cache: RenderPlanCache,
+    /// The combined effect of the tag effect rules that apply to this window.
+    pub tag_effect: Option<WindowEffect>,
+    /// The oldest configure this window hasn't committed yet and when it was sent.
+    pub uncommitted_configure: Option<(Serial, Instant)>,
+    /// Whether this window stopped responding to configures.
+    pub unresponsive: bool,
+    /// The effect applied to this window because it's unresponsive.
+    pub unresponsive_effect: Option<WindowEffect>,
+    /// The ids this window's elements are drawn with while they have an effect.
+    pub effect_ids: EffectIds,
+    /// How this window's maximize requests are handled, overriding the global policy.
+    pub maximize_request_policy: Option<MaximizeRequestPolicy>,
+    /// Limits on this window's size set by window rules.
+    pub size_constraints: SizeConstraints,
+    /// Whether this window is told it's fullscreen while tiled because it asked
+    /// to be maximized with [`MaximizeRequestPolicy::FullscreenWithinTile`].
+    pub fullscreen_within_tile: bool,
+    /// Whether this window is fullscreen and centered on its output with bars around it.
+    pub letterboxed: bool,
+    /// The id of the bars drawn around this window while it's letterboxed.
+    pub letterbox_id: Id,
+    /// The stacking layer of this window.
+    pub layer: WindowLayer,
+    /// The border style set for this window, overriding the global style.
+    pub border_style: Option<BorderStyle>,
+    /// The border drawn around this window, if any.
+    pub border: Option<BorderStyle>,
+    /// The color of [`Self::border`].
+    pub border_color: Option<[f32; 4]>,
+    /// Whether this window wants attention and hasn't been focused since.
+    pub urgent: bool,
+    /// The icon this window set through xdg-toplevel-icon.
+    pub icon: Option<ToplevelIcon>,
+    /// An icon change that applies on this window's next commit.
+    pub pending_icon: Option<Option<ToplevelIcon>>,
 }
 
 impl WindowElement {
@@ -501,7 +687,11 @@ impl WindowElement {
 
         match self.with_state(|state| state.layout_mode.current()) {
             LayoutModeKind::Tiled => {
-                self.set_tiled_states();
+                if self.with_state(|state| state.fullscreen_within_tile) {
+                    self.set_fullscreen_states();
+                } else {
+                    self.set_tiled_states();
+                }
             }
             LayoutModeKind::Floating | LayoutModeKind::Spilled => {
                 self.set_floating_states();
@@ -523,12 +713,25 @@ impl WindowElement {
         let force = self.with_state(|state| state.need_configure);
         self.with_state_mut(|state| state.need_configure = false);
 
-        if force {
+        let serial = if force {
             self.toplevel().map(|toplevel| toplevel.send_configure())
         } else {
             self.toplevel()
                 .and_then(|toplevel| toplevel.send_pending_configure())
+        };
+
+        if let Some(serial) = serial {
+            self.watch_configure(serial);
         }
+
+        serial
+    }
+
+    /// Returns how this window's maximize requests are handled, falling back to `default`
+    /// if it doesn't have its own policy.
+    pub fn maximize_request_policy(&self, default: MaximizeRequestPolicy) -> MaximizeRequestPolicy {
+        self.with_state(|state| state.maximize_request_policy)
+            .unwrap_or(default)
     }
 
     pub fn total_decoration_offset(&self) -> Point<i32, Logical> {
@@ -671,6 +874,28 @@ impl WindowElementState {
             foreign_toplevel_list_handle: None,
             decoration_surfaces: Vec::new(),
             vrr_demand: None,
+            allow_offscreen: false,
+            input_disabled: false,
+            hold_to_move_enabled: true,
+            scale_override: None,
+            render_plan_cache: RenderPlanCache::default(),
+            tag_effect: None,
+            uncommitted_configure: None,
+            unresponsive: false,
+            unresponsive_effect: None,
+            effect_ids: EffectIds::default(),
+            maximize_request_policy: None,
+            size_constraints: SizeConstraints::default(),
+            fullscreen_within_tile: false,
+            letterboxed: false,
+            letterbox_id: Id::new(),
+            layer: WindowLayer::default(),
+            border_style: None,
+            border: None,
+            border_color: None,
+            urgent: false,
+            icon: None,
+            pending_icon: None,
         }
     }
 
@@ -699,6 +924,15 @@ impl WindowElementState {
                 right: max_bounds.right.max(bounds.right),
             };
         }
+
+        // The border goes around decoration surfaces
+        if let Some(border) = self.border.map(|border| border.bounds()) {
+            max_bounds.top += border.top;
+            max_bounds.bottom += border.bottom;
+            max_bounds.left += border.left;
+            max_bounds.right += border.right;
+        }
+
         max_bounds
     }
 
@@ -827,4 +1061,64 @@ mod tests {
             Some(FullscreenOrMaximized::Maximized)
         );
     }
+
+    #[test]
+    fn maximize_request_policies_apply_correctly() {
+        let mut layout_mode = LayoutMode::new_tiled();
+        MaximizeRequestPolicy::Honor.apply(&mut layout_mode);
+        assert!(layout_mode.is_maximized());
+        layout_mode.set_client_maximized(false);
+        assert!(layout_mode.is_tiled());
+
+        let mut layout_mode = LayoutMode::new_tiled();
+        MaximizeRequestPolicy::Ignore.apply(&mut layout_mode);
+        assert!(layout_mode.is_tiled());
+
+        let mut layout_mode = LayoutMode::new_tiled();
+        MaximizeRequestPolicy::FullscreenWithinTile.apply(&mut layout_mode);
+        assert!(layout_mode.is_tiled());
+
+        let mut layout_mode = LayoutMode::new_tiled();
+        MaximizeRequestPolicy::FloatAndMaximize.apply(&mut layout_mode);
+        assert!(layout_mode.is_maximized());
+        layout_mode.set_client_maximized(false);
+        assert!(layout_mode.is_floating());
+    }
+
+    #[test]
+    fn size_constraints_clamp_to_min_and_max() {
+        let constraints = SizeConstraints {
+            min_size: Some((200, 100).into()),
+            max_size: Some((800, 600).into()),
+            aspect_ratio: None,
+        };
+
+        assert_eq!(constraints.clamp((100, 50).into()), (200, 100).into());
+        assert_eq!(constraints.clamp((1000, 1000).into()), (800, 600).into());
+        assert_eq!(constraints.clamp((400, 300).into()), (400, 300).into());
+        assert_eq!(constraints.clamp((0, 0).into()), (0, 0).into());
+    }
+
+    #[test]
+    fn size_constraints_keep_aspect_ratio() {
+        let constraints = SizeConstraints {
+            aspect_ratio: Some(2.0),
+            ..Default::default()
+        };
+
+        assert_eq!(constraints.clamp((1920, 1200).into()), (1920, 960).into());
+        assert_eq!(constraints.clamp((1000, 400).into()), (800, 400).into());
+    }
+
+    #[test]
+    fn size_constraints_fixed_size() {
+        let constraints = SizeConstraints {
+            min_size: Some((640, 360).into()),
+            max_size: Some((640, 360).into()),
+            aspect_ratio: None,
+        };
+
+        assert_eq!(constraints.clamp((1920, 1080).into()), (640, 360).into());
+        assert_eq!(constraints.clamp((10, 10).into()), (640, 360).into());
+    }
 }
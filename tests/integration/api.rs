@@ -1,6 +1,16 @@
+mod debug;
+mod env;
+mod idle;
 mod input;
+mod nightlight;
 mod output;
 mod pinnacle;
+mod portal;
+mod power;
 mod process;
+mod render;
+mod screenshot;
+#[cfg(feature = "snowcap")]
+mod snowcap;
 mod tag;
 mod window;
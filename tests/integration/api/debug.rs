@@ -0,0 +1,155 @@
+use pinnacle::render::util::accounting::AllocationKind;
+use tokio_stream::StreamExt;
+
+use crate::{
+    common::{Lang, fixture::Fixture, for_each_api},
+    spawn_lua_blocking,
+};
+
+fn set_up() -> Fixture {
+    let fixture = Fixture::new();
+
+    fixture
+        .runtime_handle()
+        .block_on(pinnacle_api::connect())
+        .unwrap();
+
+    fixture
+}
+
+#[test_log::test]
+fn debug_trace_input() {
+    for_each_api(|lang| {
+        let mut fixture = set_up();
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::debug::trace_input(true);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                require("pinnacle.debug").trace_input(true)
+            },
+        }
+
+        assert!(fixture.pinnacle().config.debug.trace_input);
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::debug::toggle_input_tracing();
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                require("pinnacle.debug").toggle_input_tracing()
+            },
+        }
+
+        assert!(!fixture.pinnacle().config.debug.trace_input);
+    });
+}
+
+#[test_log::test]
+fn debug_input_trace_stream() {
+    let mut fixture = set_up();
+
+    let mut traces = fixture.spawn_blocking(|| {
+        pinnacle_api::debug::trace_input(true);
+        pinnacle_api::debug::input_trace_stream()
+    });
+
+    fixture.state().trace_input_event("PointerButton");
+
+    let trace = fixture.runtime_handle().block_on(traces.next()).unwrap();
+    assert_eq!(trace.event, "PointerButton");
+    assert_eq!(trace.pointer_focus, None);
+    assert!(!trace.pointer_grabbed);
+    assert_eq!(trace.receiving_client_pid, None);
+}
+
+#[test_log::test]
+fn debug_set_log_filter_rejects_invalid_filter() {
+    for_each_api(|lang| {
+        let mut fixture = set_up();
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                let result = pinnacle_api::debug::set_log_filter("pinnacle=notalevel");
+                assert!(result.is_err());
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                local ok, err = require("pinnacle.debug").set_log_filter("pinnacle=notalevel")
+                assert(not ok)
+                assert(err)
+            },
+        }
+    });
+}
+
+#[test_log::test]
+fn debug_gpu_stats() {
+    for_each_api(|lang| {
+        let mut fixture = set_up();
+
+        let accounting = fixture.pinnacle().gpu_accounting.clone();
+        let base = accounting.stats();
+
+        let _snapshots = [
+            accounting.track(AllocationKind::Snapshot, 1024),
+            accounting.track(AllocationKind::Snapshot, 512),
+        ];
+        let cursor = accounting.track(AllocationKind::Cursor, 64);
+        accounting.record_evicted_snapshots(3);
+
+        let expected = pinnacle_api::debug::GpuStats {
+            snapshot_textures: base.snapshot_textures + 2,
+            snapshot_texture_bytes: base.snapshot_texture_bytes + 1536,
+            cursor_buffers: base.cursor_buffers + 1,
+            cursor_buffer_bytes: base.cursor_buffer_bytes + 64,
+            evicted_snapshots: base.evicted_snapshots + 3,
+        };
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(move || {
+                assert_eq!(pinnacle_api::debug::gpu_stats(), expected);
+            }),
+            Lang::Lua => {
+                let snapshot_textures = expected.snapshot_textures;
+                let snapshot_texture_bytes = expected.snapshot_texture_bytes;
+                let cursor_buffers = expected.cursor_buffers;
+                let cursor_buffer_bytes = expected.cursor_buffer_bytes;
+                let evicted_snapshots = expected.evicted_snapshots;
+                spawn_lua_blocking! {
+                    fixture,
+                    local stats = require("pinnacle.debug").gpu_stats()
+                    assert(stats.snapshot_textures == $snapshot_textures)
+                    assert(stats.snapshot_texture_bytes == $snapshot_texture_bytes)
+                    assert(stats.cursor_buffers == $cursor_buffers)
+                    assert(stats.cursor_buffer_bytes == $cursor_buffer_bytes)
+                    assert(stats.evicted_snapshots == $evicted_snapshots)
+                }
+            }
+        }
+
+        // Dropped allocations are no longer reported
+        drop(cursor);
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(move || {
+                let stats = pinnacle_api::debug::gpu_stats();
+                assert_eq!(stats.cursor_buffers, base.cursor_buffers);
+                assert_eq!(stats.cursor_buffer_bytes, base.cursor_buffer_bytes);
+            }),
+            Lang::Lua => {
+                let cursor_buffers = base.cursor_buffers;
+                let cursor_buffer_bytes = base.cursor_buffer_bytes;
+                spawn_lua_blocking! {
+                    fixture,
+                    local stats = require("pinnacle.debug").gpu_stats()
+                    assert(stats.cursor_buffers == $cursor_buffers)
+                    assert(stats.cursor_buffer_bytes == $cursor_buffer_bytes)
+                }
+            }
+        }
+    });
+}
@@ -0,0 +1,107 @@
+use std::{fs::File, io::Read};
+
+use crate::{
+    common::{Lang, fixture::Fixture, for_each_api},
+    spawn_lua_blocking,
+};
+
+fn set_up() -> Fixture {
+    let fixture = Fixture::new_with_socket();
+
+    fixture
+        .runtime_handle()
+        .block_on(pinnacle_api::connect())
+        .unwrap();
+
+    fixture
+}
+
+// The compositor shares the test process's environment, so each test uses its own variables.
+
+#[test_log::test]
+fn env_set_reaches_new_processes() {
+    for_each_api(|lang| {
+        let mut fixture = set_up();
+        let handle = fixture.runtime_handle();
+        let _guard = handle.enter();
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::env::set("PINNACLE_TEST_ENV_SET_RUST", "silk song");
+
+                let mut child = pinnacle_api::process::Command::with_shell(
+                    ["bash", "-c"],
+                    "printf %s \"$PINNACLE_TEST_ENV_SET_RUST\"",
+                )
+                .pipe_stdout()
+                .spawn()
+                .unwrap();
+                let mut out = String::new();
+                let mut stdout: File = child.stdout.take().unwrap().into_owned_fd().unwrap().into();
+                stdout.read_to_string(&mut out).unwrap();
+                assert_eq!(out, "silk song");
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                require("pinnacle.env").set("PINNACLE_TEST_ENV_SET_LUA", "silk song")
+
+                local child = Process.command({
+                    cmd = "printf %s \"$PINNACLE_TEST_ENV_SET_LUA\"",
+                    shell_cmd = { "bash", "-c" },
+                    pipe_stdout = true,
+                }):spawn()
+                local out = child.stdout:read("*a")
+                assert(out == "silk song")
+            },
+        }
+
+        let key = match lang {
+            Lang::Rust => "PINNACLE_TEST_ENV_SET_RUST",
+            Lang::Lua => "PINNACLE_TEST_ENV_SET_LUA",
+        };
+        assert_eq!(std::env::var(key).as_deref(), Ok("silk song"));
+    });
+}
+
+#[test_log::test]
+fn env_unset_removes_process_env_override() {
+    for_each_api(|lang| {
+        let mut fixture = set_up();
+
+        let key = match lang {
+            Lang::Rust => "PINNACLE_TEST_ENV_UNSET_RUST",
+            Lang::Lua => "PINNACLE_TEST_ENV_UNSET_LUA",
+        };
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(move || {
+                pinnacle_api::env::set(key, "global");
+                pinnacle_api::process::set_env(key, "spawned");
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                require("pinnacle.env").set($key, "global")
+                Process.set_env($key, "spawned")
+            },
+        }
+
+        assert_eq!(std::env::var(key).as_deref(), Ok("global"));
+        assert_eq!(
+            fixture.pinnacle().config.process_envs.get(key),
+            Some(&"spawned".to_string())
+        );
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(move || {
+                pinnacle_api::env::unset(key);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                require("pinnacle.env").unset($key)
+            },
+        }
+
+        assert!(std::env::var(key).is_err());
+        assert!(!fixture.pinnacle().config.process_envs.contains_key(key));
+    });
+}
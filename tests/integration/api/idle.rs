@@ -0,0 +1,230 @@
+use std::time::Duration;
+
+use pinnacle::{state::WithState, tag::Tag};
+use pinnacle_api::{
+    idle::{IdleTimeout, Screensaver, ScreensaverSource},
+    layout::{LayoutGenerator as _, generators::MasterStack},
+};
+use smithay::{utils::Rectangle, wayland::idle_inhibit::IdleInhibitHandler};
+
+use crate::{
+    common::{Lang, fixture::Fixture, for_each_api},
+    spawn_lua_blocking,
+};
+
+const SHADER: &str = "void main() { gl_FragColor = vec4(1.0); }";
+
+fn set_up() -> Fixture {
+    let fixture = Fixture::new();
+
+    fixture
+        .runtime_handle()
+        .block_on(pinnacle_api::connect())
+        .unwrap();
+
+    fixture
+}
+
+#[test_log::test]
+fn idle_set_screensaver() {
+    for_each_api(|lang| {
+        let mut fixture = set_up();
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::idle::set_screensaver(Some(Screensaver {
+                    timeout: Duration::from_millis(10),
+                    source: ScreensaverSource::Shader(SHADER.into()),
+                }));
+            }),
+            Lang::Lua => {
+                spawn_lua_blocking! {
+                    fixture,
+                    require("pinnacle.idle").set_screensaver({
+                        timeout = 10,
+                        shader = $SHADER,
+                    })
+                }
+            }
+        }
+
+        let screensaver = fixture
+            .pinnacle()
+            .idle_state
+            .screensaver()
+            .cloned()
+            .unwrap();
+        assert_eq!(screensaver.timeout, Duration::from_millis(10));
+        assert_eq!(
+            screensaver.source,
+            pinnacle::idle::ScreensaverSource::Shader(SHADER.into())
+        );
+
+        fixture
+            .dispatch_until(|fixture| fixture.pinnacle().idle_state.active_screensaver.is_some());
+
+        fixture.state().notify_idle_activity();
+        assert!(fixture.pinnacle().idle_state.active_screensaver.is_none());
+    });
+}
+
+#[test_log::test]
+fn idle_set_screensaver_none_disables_screensaver() {
+    for_each_api(|lang| {
+        let mut fixture = set_up();
+
+        fixture
+            .state()
+            .set_screensaver(Some(pinnacle::idle::Screensaver {
+                timeout: Duration::from_millis(10),
+                source: pinnacle::idle::ScreensaverSource::Shader(SHADER.into()),
+            }));
+        fixture
+            .dispatch_until(|fixture| fixture.pinnacle().idle_state.active_screensaver.is_some());
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::idle::set_screensaver(None);
+            }),
+            Lang::Lua => {
+                spawn_lua_blocking! {
+                    fixture,
+                    require("pinnacle.idle").set_screensaver(nil)
+                }
+            }
+        }
+
+        assert!(fixture.pinnacle().idle_state.screensaver().is_none());
+        assert!(fixture.pinnacle().idle_state.active_screensaver.is_none());
+    });
+}
+
+#[test_log::test]
+fn idle_set_idle_timeout_powers_off_outputs() {
+    for_each_api(|lang| {
+        let mut fixture = set_up();
+        let output = fixture.add_output(Rectangle::new((0, 0).into(), (1920, 1080).into()));
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::idle::set_idle_timeout(Some(IdleTimeout {
+                    timeout: Duration::from_millis(10),
+                    power_off_outputs: true,
+                }));
+            }),
+            Lang::Lua => {
+                spawn_lua_blocking! {
+                    fixture,
+                    require("pinnacle.idle").set_idle_timeout({
+                        timeout = 10,
+                        power_off_outputs = true,
+                    })
+                }
+            }
+        }
+
+        assert_eq!(
+            fixture.pinnacle().idle_state.idle_timeout(),
+            Some(pinnacle::idle::IdleTimeout {
+                timeout: Duration::from_millis(10),
+                power_off_outputs: true,
+            })
+        );
+
+        fixture.dispatch_until(|fixture| fixture.pinnacle().idle_state.is_idle());
+        assert!(!output.with_state(|state| state.powered));
+
+        fixture.state().notify_idle_activity();
+        assert!(!fixture.pinnacle().idle_state.is_idle());
+        assert!(output.with_state(|state| state.powered));
+    });
+}
+
+#[test_log::test]
+fn idle_set_idle_timeout_none_disables_idle() {
+    for_each_api(|lang| {
+        let mut fixture = set_up();
+
+        fixture
+            .state()
+            .set_idle_timeout(Some(pinnacle::idle::IdleTimeout {
+                timeout: Duration::from_millis(10),
+                power_off_outputs: false,
+            }));
+        fixture.dispatch_until(|fixture| fixture.pinnacle().idle_state.is_idle());
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::idle::set_idle_timeout(None);
+            }),
+            Lang::Lua => {
+                spawn_lua_blocking! {
+                    fixture,
+                    require("pinnacle.idle").set_idle_timeout(nil)
+                }
+            }
+        }
+
+        assert!(fixture.pinnacle().idle_state.idle_timeout().is_none());
+        assert!(!fixture.pinnacle().idle_state.is_idle());
+    });
+}
+
+#[test_log::test]
+fn idle_inhibit_hides_screensaver() {
+    let mut fixture = set_up();
+    let output = fixture.add_output(Rectangle::new((0, 0).into(), (1920, 1080).into()));
+    output.with_state_mut(|state| {
+        let tag = Tag::new("1".to_string());
+        tag.set_active(true);
+        state.add_tags([tag]);
+    });
+
+    fixture.spawn_blocking(|| {
+        pinnacle_api::layout::manage(|args| pinnacle_api::layout::LayoutResponse {
+            root_node: MasterStack::default().layout(args.window_count),
+            tree_id: 0,
+        });
+    });
+
+    let client_id = fixture.add_client();
+    fixture.spawn_windows(1, client_id);
+    let surface = fixture.pinnacle().windows[0]
+        .wl_surface()
+        .unwrap()
+        .into_owned();
+
+    fixture
+        .state()
+        .set_screensaver(Some(pinnacle::idle::Screensaver {
+            timeout: Duration::from_millis(10),
+            source: pinnacle::idle::ScreensaverSource::Shader(SHADER.into()),
+        }));
+    fixture.dispatch_until(|fixture| fixture.pinnacle().idle_state.active_screensaver.is_some());
+
+    IdleInhibitHandler::inhibit(fixture.state(), surface);
+    assert!(fixture.pinnacle().idle_state.active_screensaver.is_none());
+}
+
+#[test_log::test]
+fn idle_timeout_restarts_when_inhibit_ends() {
+    let mut fixture = set_up();
+
+    fixture
+        .state()
+        .set_idle_timeout(Some(pinnacle::idle::IdleTimeout {
+            timeout: Duration::from_millis(200),
+            power_off_outputs: false,
+        }));
+
+    fixture.pinnacle().config.inhibit_idle = true;
+    fixture.dispatch_for(Duration::from_millis(300));
+    assert!(!fixture.pinnacle().idle_state.is_idle());
+
+    // The time spent inhibited doesn't count towards the timeout
+    fixture.pinnacle().config.inhibit_idle = false;
+    fixture.dispatch_for(Duration::from_millis(50));
+    assert!(!fixture.pinnacle().idle_state.is_idle());
+
+    fixture.dispatch_until(|fixture| fixture.pinnacle().idle_state.is_idle());
+}
@@ -1,4 +1,21 @@
-use pinnacle_api::input::Bind as _;
+use std::{
+    sync::{Arc, Mutex, OnceLock},
+    time::Duration,
+};
+
+use mlua::{UserData, UserDataMethods};
+use pinnacle::{
+    focus::pointer::ScreenEdge,
+    input::{
+        PointerCrossing,
+        bind::Edge,
+        edge_action::EdgeAction,
+        hold_to_move::HoldToMoveConfig,
+        remap::{Remap, RemapTarget},
+    },
+};
+use pinnacle_api::{Keysym, input::Bind as _, signal::InputSignal};
+use smithay::input::keyboard::ModifiersState;
 
 use crate::{
     common::{Lang, fixture::Fixture, for_each_api},
@@ -234,3 +251,416 @@ fn input_mousebind() {
         assert!(mousebind.has_on_press)
     });
 }
+
+#[test_log::test]
+fn input_keybind_reports_conflicts() {
+    let mut fixture = set_up();
+
+    let conflicts = fixture.spawn_blocking(|| {
+        pinnacle_api::input::keybind(pinnacle_api::input::Mod::SUPER, 'c')
+            .group("Window")
+            .description("Close");
+        // Ignoring shift still overlaps with the bind above
+        let second = pinnacle_api::input::keybind(
+            pinnacle_api::input::Mod::SUPER | pinnacle_api::input::Mod::IGNORE_SHIFT,
+            'c',
+        );
+        second.conflicts().to_vec()
+    });
+
+    assert_eq!(conflicts.len(), 1);
+    assert_eq!(conflicts[0].group, "Window");
+    assert_eq!(conflicts[0].description, "Close");
+
+    // The default policy keeps both binds
+    assert_eq!(
+        fixture
+            .pinnacle()
+            .input_state
+            .bind_state
+            .keybinds
+            .id_map
+            .len(),
+        2
+    );
+}
+
+#[test_log::test]
+fn input_bind_layer_conflict_policy_override() {
+    for_each_api(|lang| {
+        let mut fixture = set_up();
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                let layer = pinnacle_api::input::BindLayer::get("morb_layer");
+                layer.set_conflict_policy(pinnacle_api::input::ConflictPolicy::Override);
+                layer
+                    .keybind(pinnacle_api::input::Mod::SUPER, 'c')
+                    .description("first");
+                layer
+                    .keybind(pinnacle_api::input::Mod::SUPER, 'c')
+                    .description("second");
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Input.set_bind_layer_conflict_policy("morb_layer", "override")
+                Input.keybind({
+                    mods = { "super" },
+                    key = "c",
+                    bind_layer = "morb_layer",
+                    description = "first",
+                })
+                Input.keybind({
+                    mods = { "super" },
+                    key = "c",
+                    bind_layer = "morb_layer",
+                    description = "second",
+                })
+            },
+        }
+
+        let keybinds = &fixture.pinnacle().input_state.bind_state.keybinds;
+        assert_eq!(keybinds.id_map.len(), 1);
+        assert_eq!(
+            keybinds
+                .id_map
+                .values()
+                .next()
+                .unwrap()
+                .borrow()
+                .bind_data
+                .desc,
+            "second"
+        );
+    });
+}
+
+#[test_log::test]
+fn input_bind_layer_conflict_policy_shadow() {
+    let mut fixture = set_up();
+
+    fixture.spawn_blocking(|| {
+        let layer = pinnacle_api::input::BindLayer::get("morb_layer");
+        layer.keybind(pinnacle_api::input::Mod::SUPER, 'c');
+        layer.set_conflict_policy(pinnacle_api::input::ConflictPolicy::Shadow);
+        layer.keybind(pinnacle_api::input::Mod::SUPER, 'c');
+        // Binds on other layers don't conflict
+        pinnacle_api::input::keybind(pinnacle_api::input::Mod::SUPER, 'c');
+    });
+
+    let keybinds = &mut fixture.pinnacle().input_state.bind_state.keybinds;
+    let ids = keybinds.id_map.keys().copied().collect::<Vec<_>>();
+    let shadowed_by = |keybinds: &pinnacle::input::bind::Keybinds, id| {
+        keybinds.id_map[&id].borrow().bind_data.shadowed_by.clone()
+    };
+
+    assert_eq!(shadowed_by(keybinds, ids[0]), vec![ids[1]]);
+    assert!(shadowed_by(keybinds, ids[1]).is_empty());
+    assert!(shadowed_by(keybinds, ids[2]).is_empty());
+
+    keybinds.set_keybind_has_on_press(ids[0]);
+    keybinds.set_keybind_has_on_press(ids[1]);
+
+    let press = |keybinds: &mut pinnacle::input::bind::Keybinds| {
+        let mods = ModifiersState {
+            logo: true,
+            ..Default::default()
+        };
+        let layer = Some("morb_layer".to_string());
+        keybinds.key(Keysym::c, mods, Edge::Press, layer.clone(), false, false);
+        let triggered = keybinds.last_pressed_triggered_binds[&Keysym::c].clone();
+        keybinds.key(Keysym::c, mods, Edge::Release, layer, false, false);
+        triggered
+    };
+
+    assert_eq!(press(keybinds), vec![ids[1]]);
+
+    // Removing the shadowing bind reenables the shadowed one
+    keybinds.remove_keybind(ids[1]);
+    assert_eq!(press(keybinds), vec![ids[0]]);
+}
+
+#[test_log::test]
+fn input_remap() {
+    for_each_api(|lang| {
+        let mut fixture = set_up();
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::input::remap(Keysym::Caps_Lock, Keysym::Escape);
+                pinnacle_api::input::remap_to_macro(Keysym::F13, ['h', 'i']);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Input.remap(Input.key.Caps_Lock, Input.key.Escape)
+                Input.remap(Input.key.F13, { "h", "i" })
+            },
+        }
+
+        let remaps = &fixture.pinnacle().input_state.remap_state.remaps;
+
+        assert_eq!(
+            remaps,
+            &vec![
+                Remap {
+                    from: Keysym::Caps_Lock,
+                    to: RemapTarget::Key(Keysym::Escape),
+                    device_sysname: None,
+                },
+                Remap {
+                    from: Keysym::F13,
+                    to: RemapTarget::Macro(vec![Keysym::h, Keysym::i]),
+                    device_sysname: None,
+                },
+            ]
+        );
+    });
+}
+
+#[test_log::test]
+fn input_set_hold_to_move() {
+    for_each_api(|lang| {
+        let mut fixture = set_up();
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::input::set_hold_to_move(
+                    true,
+                    Some(pinnacle_api::input::MouseButton::Left),
+                    Some(Duration::from_millis(300)),
+                );
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Input.set_hold_to_move({ touch = true, button = "btn_left", hold_time = 300 })
+            },
+        }
+
+        assert_eq!(
+            fixture.pinnacle().input_state.hold_to_move.config,
+            HoldToMoveConfig {
+                touch: true,
+                button: Some(0x110),
+                hold_time: Duration::from_millis(300),
+            }
+        );
+    });
+}
+
+#[test_log::test]
+fn input_set_pointer_crossing() {
+    for_each_api(|lang| {
+        let mut fixture = set_up();
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::input::set_pointer_crossing(
+                    pinnacle_api::input::PointerCrossing::Physical,
+                );
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Input.set_pointer_crossing("physical")
+            },
+        }
+
+        assert_eq!(
+            fixture.pinnacle().input_state.pointer_crossing,
+            PointerCrossing::Physical
+        );
+    });
+}
+
+#[test_log::test]
+fn input_set_sticky_keys() {
+    for_each_api(|lang| {
+        let mut fixture = set_up();
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::input::set_sticky_keys(true);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Input.set_sticky_keys(true)
+            },
+        }
+
+        assert!(fixture.pinnacle().input_state.accessibility.sticky_keys);
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::input::set_sticky_keys(false);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Input.set_sticky_keys(false)
+            },
+        }
+
+        assert!(!fixture.pinnacle().input_state.accessibility.sticky_keys);
+    });
+}
+
+#[test_log::test]
+fn input_set_slow_keys() {
+    for_each_api(|lang| {
+        let mut fixture = set_up();
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::input::set_slow_keys(Some(Duration::from_millis(300)));
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Input.set_slow_keys(300)
+            },
+        }
+
+        assert_eq!(
+            fixture.pinnacle().input_state.accessibility.slow_keys,
+            Some(Duration::from_millis(300))
+        );
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::input::set_slow_keys(None);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Input.set_slow_keys(nil)
+            },
+        }
+
+        assert_eq!(fixture.pinnacle().input_state.accessibility.slow_keys, None);
+    });
+}
+
+/// Records the arguments of accessibility signals, in order.
+#[derive(Clone, Default)]
+struct AccessibilityLog(Arc<Mutex<Vec<(bool, Option<u64>)>>>);
+
+impl AccessibilityLog {
+    fn log(&self, sticky_keys: bool, slow_keys_millis: Option<u64>) {
+        self.0.lock().unwrap().push((sticky_keys, slow_keys_millis));
+    }
+
+    fn entries(&self) -> Vec<(bool, Option<u64>)> {
+        self.0.lock().unwrap().clone()
+    }
+}
+
+impl UserData for AccessibilityLog {
+    fn add_methods<M: UserDataMethods<Self>>(methods: &mut M) {
+        methods.add_method(
+            "log",
+            |_, this, (sticky_keys, slow_keys_millis): (bool, Option<u64>)| {
+                this.log(sticky_keys, slow_keys_millis);
+                Ok(())
+            },
+        );
+
+        methods.add_method("len", |_, this, ()| Ok(this.entries().len()));
+    }
+}
+
+#[test_log::test]
+fn input_signal_accessibility_changed() {
+    for_each_api(|lang| {
+        let mut fixture = set_up();
+
+        let log = AccessibilityLog::default();
+        let log_clone = log.clone();
+        let signal_handle = Arc::new(OnceLock::new());
+        let signal_handle_clone = signal_handle.clone();
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(move || {
+                let handle = pinnacle_api::input::connect_signal(
+                    InputSignal::AccessibilityChanged(Box::new(move |sticky_keys, slow_keys| {
+                        log.log(sticky_keys, slow_keys.map(|delay| delay.as_millis() as u64));
+                    })),
+                );
+                signal_handle_clone.set(handle).unwrap();
+
+                pinnacle_api::input::set_sticky_keys(true);
+                pinnacle_api::input::set_slow_keys(Some(Duration::from_millis(300)));
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Input.connect_signal({
+                    accessibility_changed = function(sticky_keys, slow_keys_delay)
+                        $log:log(sticky_keys, slow_keys_delay)
+                    end
+                })
+
+                Input.set_sticky_keys(true)
+                Input.set_slow_keys(300)
+
+                local client = require("pinnacle.grpc.client").client
+                while $log:len() < 2 do
+                    client.loop:step();
+                end
+            },
+        }
+
+        fixture.dispatch_until(|_| log_clone.entries().len() >= 2);
+
+        assert_eq!(log_clone.entries(), [(true, None), (true, Some(300))]);
+
+        if lang == Lang::Rust {
+            signal_handle.get().unwrap().disconnect();
+        }
+    });
+}
+
+#[test_log::test]
+fn input_set_edge_action() {
+    for_each_api(|lang| {
+        let mut fixture = set_up();
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::input::set_edge_action(
+                    pinnacle_api::output::ScreenEdge::Left,
+                    pinnacle_api::input::EdgeAction::SnapWindow,
+                );
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Input.set_edge_action("left", "snap_window")
+            },
+        }
+
+        assert!(matches!(
+            fixture
+                .pinnacle()
+                .input_state
+                .edge_actions
+                .action(ScreenEdge::Left, "pinnacle-1"),
+            Some(EdgeAction::SnapWindow)
+        ));
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::input::set_edge_action(
+                    pinnacle_api::output::ScreenEdge::Left,
+                    pinnacle_api::input::EdgeAction::None,
+                );
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Input.set_edge_action("left", "none")
+            },
+        }
+
+        assert!(
+            fixture
+                .pinnacle()
+                .input_state
+                .edge_actions
+                .action(ScreenEdge::Left, "pinnacle-1")
+                .is_none()
+        );
+    });
+}
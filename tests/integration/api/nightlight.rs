@@ -0,0 +1,137 @@
+use std::time::Duration;
+
+use pinnacle::nightlight::{Location, NightlightSchedule, ScheduleTime};
+use pinnacle_api::nightlight::Schedule;
+
+use crate::{
+    common::{Lang, fixture::Fixture, for_each_api},
+    spawn_lua_blocking,
+};
+
+fn set_up() -> Fixture {
+    let fixture = Fixture::new();
+
+    fixture
+        .runtime_handle()
+        .block_on(pinnacle_api::connect())
+        .unwrap();
+
+    fixture
+}
+
+#[test_log::test]
+fn nightlight_schedule() {
+    for_each_api(|lang| {
+        let mut fixture = set_up();
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::nightlight::schedule(
+                    pinnacle_api::nightlight::ScheduleTime::At {
+                        hour: 20,
+                        minute: 0,
+                    },
+                    pinnacle_api::nightlight::ScheduleTime::At {
+                        hour: 7,
+                        minute: 30,
+                    },
+                    4000,
+                );
+            }),
+            Lang::Lua => {
+                spawn_lua_blocking! {
+                    fixture,
+                    require("pinnacle.nightlight").schedule("20:00", "07:30", 4000)
+                }
+            }
+        }
+
+        let schedule = fixture.pinnacle().nightlight_state.schedule().copied();
+        assert_eq!(
+            schedule,
+            Some(NightlightSchedule {
+                start: ScheduleTime::Clock(20 * 60),
+                end: ScheduleTime::Clock(7 * 60 + 30),
+                temperature: 4000,
+                transition: Duration::from_secs(30 * 60),
+                location: None,
+            })
+        );
+    });
+}
+
+#[test_log::test]
+fn nightlight_schedule_at_location() {
+    for_each_api(|lang| {
+        let mut fixture = set_up();
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::nightlight::set_schedule(Some(
+                    Schedule::new(
+                        pinnacle_api::nightlight::ScheduleTime::Sunset,
+                        pinnacle_api::nightlight::ScheduleTime::Sunrise,
+                        3500,
+                    )
+                    .location(52.5, 13.5)
+                    .transition(Duration::from_secs(600)),
+                ));
+            }),
+            Lang::Lua => {
+                spawn_lua_blocking! {
+                    fixture,
+                    require("pinnacle.nightlight").schedule("sunset", "sunrise", 3500, {
+                        transition = 600000,
+                        location = { latitude = 52.5, longitude = 13.5 },
+                    })
+                }
+            }
+        }
+
+        let schedule = fixture.pinnacle().nightlight_state.schedule().copied();
+        assert_eq!(
+            schedule,
+            Some(NightlightSchedule {
+                start: ScheduleTime::Sunset,
+                end: ScheduleTime::Sunrise,
+                temperature: 3500,
+                transition: Duration::from_secs(600),
+                location: Some(Location {
+                    latitude: 52.5,
+                    longitude: 13.5,
+                }),
+            })
+        );
+    });
+}
+
+#[test_log::test]
+fn nightlight_disable() {
+    for_each_api(|lang| {
+        let mut fixture = set_up();
+
+        fixture
+            .state()
+            .set_nightlight_schedule(Some(NightlightSchedule {
+                start: ScheduleTime::Clock(0),
+                end: ScheduleTime::Clock(60),
+                temperature: 4000,
+                transition: Duration::ZERO,
+                location: None,
+            }));
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::nightlight::set_schedule(None);
+            }),
+            Lang::Lua => {
+                spawn_lua_blocking! {
+                    fixture,
+                    require("pinnacle.nightlight").disable()
+                }
+            }
+        }
+
+        assert!(fixture.pinnacle().nightlight_state.schedule().is_none());
+    });
+}
@@ -1,5 +1,34 @@
-use pinnacle::{state::WithState, tag::Tag};
-use smithay::{output::Output, utils::Rectangle};
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::{
+        Arc, Mutex, OnceLock,
+        atomic::{AtomicBool, Ordering},
+    },
+    time::Duration,
+};
+
+use mlua::{UserData, UserDataMethods};
+use pinnacle::{
+    cursor::CursorOverride,
+    handlers::session_lock::LockState,
+    output::{
+        BlankingState, ColorDepth, RenderStats,
+        edid::{Chromaticity, ColorPrimaries, EdidInfo, HdrMetadata},
+    },
+    protocol::output_management::{OutputConfiguration, OutputManagementHandler},
+    render::{pointer::PointerElement, util::effect::ColorFilter},
+    state::{State, WithState},
+    tag::Tag,
+};
+use smithay::{
+    desktop::layer_map_for_output,
+    input::pointer::MotionEvent,
+    output::Output,
+    reexports::wayland_protocols_wlr::layer_shell::v1::client::{
+        zwlr_layer_shell_v1::Layer, zwlr_layer_surface_v1::KeyboardInteractivity,
+    },
+    utils::{Logical, Point, Rectangle, SERIAL_COUNTER},
+};
 
 use crate::{
     common::{Lang, fixture::Fixture, for_each_api},
@@ -273,6 +302,58 @@ fn output_handle_set_custom_mode() {
     });
 }
 
+#[test_log::test]
+fn output_handle_mode_change_reverts_unless_confirmed() {
+    for_each_api(|lang| {
+        let (mut fixture, output, _) = set_up();
+
+        let old_mode = output.current_mode().unwrap();
+        let new_mode = smithay::output::Mode {
+            size: (800, 600).into(),
+            refresh: 75000,
+        };
+
+        let test_mode = |fixture: &mut Fixture| match lang {
+            Lang::Rust => fixture.spawn_blocking(move || {
+                pinnacle_api::output::get_focused()
+                    .unwrap()
+                    .test_custom_mode(800, 600, 75000, Duration::from_millis(100));
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Output.get_focused():set_custom_mode(800, 600, 75000, { revert_after_ms = 100 })
+            },
+        };
+
+        test_mode(&mut fixture);
+        assert_eq!(output.current_mode().unwrap(), new_mode);
+
+        fixture.dispatch_for(Duration::from_millis(200));
+        assert_eq!(output.current_mode().unwrap(), old_mode);
+
+        test_mode(&mut fixture);
+
+        let confirmed = match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::output::get_focused()
+                    .unwrap()
+                    .confirm_mode_change()
+            }),
+            Lang::Lua => {
+                spawn_lua_blocking! {
+                    fixture,
+                    assert(Output.get_focused():confirm_mode_change())
+                }
+                true
+            }
+        };
+        assert!(confirmed);
+
+        fixture.dispatch_for(Duration::from_millis(200));
+        assert_eq!(output.current_mode().unwrap(), new_mode);
+    });
+}
+
 #[test_log::test]
 fn output_handle_set_modeline() {
     for_each_api(|lang| {
@@ -389,6 +470,32 @@ fn output_handle_set_transform() {
     });
 }
 
+#[test_log::test]
+fn output_handle_set_auto_rotate() {
+    for_each_api(|lang| {
+        let (mut fixture, output, _) = set_up();
+
+        fixture.pinnacle().auto_rotate_state.orientation = Some(smithay::utils::Transform::_90);
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(move || {
+                pinnacle_api::output::get_focused()
+                    .unwrap()
+                    .set_auto_rotate(true);
+            }),
+            Lang::Lua => {
+                spawn_lua_blocking! {
+                    fixture,
+                    Output.get_focused():set_auto_rotate(true)
+                }
+            }
+        }
+
+        assert!(output.with_state(|state| state.auto_rotate));
+        assert_eq!(output.current_transform(), smithay::utils::Transform::_90);
+    });
+}
+
 #[test_log::test]
 fn output_handle_set_powered() {
     for_each_api(|lang| {
@@ -563,6 +670,150 @@ fn output_handle_serial() {
     }
 }
 
+#[test_log::test]
+fn output_handle_edid() {
+    let (mut fixture, output, _) = set_up();
+
+    fixture.spawn_blocking(|| {
+        assert_eq!(pinnacle_api::output::get_focused().unwrap().edid(), None);
+    });
+
+    output.with_state_mut(|state| {
+        state.edid = Some(EdidInfo {
+            manufacture_year: Some(2024),
+            manufacture_week: None,
+            color_primaries: None,
+            hdr_metadata: Some(HdrMetadata {
+                max_luminance: Some(600.0),
+                pq: true,
+                ..Default::default()
+            }),
+        });
+    });
+
+    fixture.spawn_blocking(|| {
+        let edid = pinnacle_api::output::get_focused().unwrap().edid().unwrap();
+        assert_eq!(edid.manufacture_year, Some(2024));
+        assert_eq!(edid.manufacture_week, None);
+
+        let hdr = edid.hdr_metadata.unwrap();
+        assert_eq!(hdr.max_luminance, Some(600.0));
+        assert!(hdr.pq);
+        assert!(!hdr.hlg);
+    });
+
+    spawn_lua_blocking! {
+        fixture,
+        local edid = Output.get_focused():edid()
+        assert(edid.manufacture_year == 2024)
+        assert(edid.color_primaries == nil)
+        assert(edid.hdr_metadata.max_luminance == 600)
+        assert(edid.hdr_metadata.pq)
+    }
+}
+
+#[test_log::test]
+fn output_handle_set_hdr() {
+    let (mut fixture, output, _) = set_up();
+
+    fixture.spawn_blocking(|| {
+        let output = pinnacle_api::output::get_focused().unwrap();
+        assert!(!output.hdr_capable());
+        assert!(output.set_hdr(true).is_err());
+        assert!(!output.hdr_enabled());
+    });
+
+    output.with_state_mut(|state| state.hdr_capable = true);
+
+    fixture.spawn_blocking(|| {
+        let output = pinnacle_api::output::get_focused().unwrap();
+        assert!(output.hdr_capable());
+        assert_eq!(output.set_hdr(true), Ok(()));
+        assert!(output.hdr_enabled());
+    });
+
+    assert!(output.with_state(|state| state.hdr));
+
+    spawn_lua_blocking! {
+        fixture,
+        local output = Output.get_focused()
+        assert(output:set_hdr(false) == nil)
+        assert(not output:hdr_enabled())
+    }
+
+    assert!(!output.with_state(|state| state.hdr));
+}
+
+#[test_log::test]
+fn output_handle_set_color_depth() {
+    let (mut fixture, output, _) = set_up();
+
+    fixture.spawn_blocking(|| {
+        let output = pinnacle_api::output::get_focused().unwrap();
+        assert_eq!(output.color_depth(), 8);
+        assert!(output.set_color_depth(12).is_err());
+        assert_eq!(output.set_color_depth(10), Ok(()));
+        assert_eq!(output.color_depth(), 10);
+    });
+
+    assert_eq!(
+        output.with_state(|state| state.color_depth),
+        ColorDepth::Ten
+    );
+
+    spawn_lua_blocking! {
+        fixture,
+        local output = Output.get_focused()
+        assert(output:set_color_depth(8) == nil)
+        assert(output:color_depth() == 8)
+    }
+
+    assert_eq!(
+        output.with_state(|state| state.color_depth),
+        ColorDepth::Eight
+    );
+}
+
+#[test_log::test]
+fn output_handle_set_srgb_emulation() {
+    let (mut fixture, output, _) = set_up();
+
+    fixture.spawn_blocking(|| {
+        let output = pinnacle_api::output::get_focused().unwrap();
+        assert!(output.set_srgb_emulation(true).is_err());
+        assert!(!output.srgb_emulation());
+    });
+
+    output.with_state_mut(|state| {
+        state.edid = Some(EdidInfo {
+            color_primaries: Some(ColorPrimaries {
+                red: Chromaticity { x: 0.68, y: 0.32 },
+                green: Chromaticity { x: 0.265, y: 0.69 },
+                blue: Chromaticity { x: 0.15, y: 0.06 },
+                white: None,
+            }),
+            ..Default::default()
+        });
+    });
+
+    fixture.spawn_blocking(|| {
+        let output = pinnacle_api::output::get_focused().unwrap();
+        assert_eq!(output.set_srgb_emulation(true), Ok(()));
+        assert!(output.srgb_emulation());
+    });
+
+    assert!(output.with_state(|state| state.srgb_emulation));
+
+    spawn_lua_blocking! {
+        fixture,
+        local output = Output.get_focused()
+        assert(output:set_srgb_emulation(false) == nil)
+        assert(not output:srgb_emulation())
+    }
+
+    assert!(!output.with_state(|state| state.srgb_emulation));
+}
+
 #[test_log::test]
 fn output_handle_loc() {
     let (mut fixture, _, output) = set_up();
@@ -1069,6 +1320,112 @@ fn output_handle_powered() {
     }
 }
 
+#[test_log::test]
+fn output_handle_render_stats() {
+    let (mut fixture, output1, output2) = set_up();
+
+    output1.with_state_mut(|state| {
+        state.render_stats = RenderStats {
+            frames_rendered: 10,
+            frames_skipped_no_damage: 4,
+            frames_skipped_suspended: 2,
+        };
+    });
+
+    fixture.spawn_blocking({
+        let output1_name = output1.name();
+        let output2_name = output2.name();
+        move || {
+            let output1_stats = pinnacle_api::output::get_by_name(output1_name)
+                .unwrap()
+                .render_stats();
+            let output2_stats = pinnacle_api::output::get_by_name(output2_name)
+                .unwrap()
+                .render_stats();
+
+            assert_eq!(
+                output1_stats,
+                pinnacle_api::output::RenderStats {
+                    frames_rendered: 10,
+                    frames_skipped_no_damage: 4,
+                    frames_skipped_suspended: 2,
+                }
+            );
+            assert_eq!(output2_stats, Default::default());
+        }
+    });
+
+    let output1_name = output1.name();
+    let output2_name = output2.name();
+    spawn_lua_blocking! {
+        fixture,
+        local output1_stats = Output.get_by_name($output1_name):render_stats()
+        local output2_stats = Output.get_by_name($output2_name):render_stats()
+
+        assert(output1_stats.frames_rendered == 10)
+        assert(output1_stats.frames_skipped_no_damage == 4)
+        assert(output1_stats.frames_skipped_suspended == 2)
+        assert(output2_stats.frames_rendered == 0)
+        assert(output2_stats.frames_skipped_no_damage == 0)
+        assert(output2_stats.frames_skipped_suspended == 0)
+    }
+}
+
+#[test_log::test]
+fn output_render_is_suspended_when_powered_off_or_blanked_by_lock() {
+    for_each_api(|lang| {
+        let (mut fixture, output, _) = set_up();
+
+        let is_suspended = |fixture: &mut Fixture| {
+            let lock_state = &fixture.pinnacle().lock_state;
+            output.with_state(|state| state.is_render_suspended(lock_state))
+        };
+
+        assert!(!is_suspended(&mut fixture));
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(move || {
+                pinnacle_api::output::get_focused()
+                    .unwrap()
+                    .set_powered(false);
+            }),
+            Lang::Lua => {
+                spawn_lua_blocking! {
+                    fixture,
+                    Output.get_focused():set_powered(false)
+                }
+            }
+        }
+
+        assert!(is_suspended(&mut fixture));
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(move || {
+                pinnacle_api::output::get_focused()
+                    .unwrap()
+                    .set_powered(true);
+            }),
+            Lang::Lua => {
+                spawn_lua_blocking! {
+                    fixture,
+                    Output.get_focused():set_powered(true)
+                }
+            }
+        }
+
+        assert!(!is_suspended(&mut fixture));
+
+        // Blanked for a lock but without a lock surface to show
+        output.with_state_mut(|state| state.blanking_state = BlankingState::Blanked);
+        assert!(!is_suspended(&mut fixture));
+        fixture.pinnacle().lock_state = LockState::Locked;
+        assert!(is_suspended(&mut fixture));
+
+        fixture.pinnacle().lock_state = LockState::Unlocked;
+        assert!(!is_suspended(&mut fixture));
+    });
+}
+
 #[test_log::test]
 fn output_handle_focus() {
     for_each_api(|lang| {
@@ -1093,7 +1450,629 @@ fn output_handle_focus() {
     });
 }
 
+#[test_log::test]
+fn output_handle_stacking_order() {
+    for_each_api(|lang| {
+        let (mut fixture, ..) = set_up();
+
+        let client_id = fixture.add_client();
+        fixture.spawn_windows(2, client_id);
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                let windows = pinnacle_api::window::get_all().collect::<Vec<_>>();
+                let output = pinnacle_api::output::get_focused().unwrap();
+
+                assert_eq!(output.stacking_order().collect::<Vec<_>>(), windows);
+
+                windows[0].raise();
+                assert_eq!(
+                    output.stacking_order().collect::<Vec<_>>(),
+                    [windows[1].clone(), windows[0].clone()]
+                );
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                local windows = Window.get_all()
+                local output = Output.get_focused()
+
+                windows[1]:raise()
+                local order = output:stacking_order()
+                assert(#order == 2)
+                assert(order[1].id == windows[2].id)
+                assert(order[2].id == windows[1].id)
+            },
+        }
+    });
+}
+
+#[test_log::test]
+fn output_set_xcursor_size_changes_cursor_on_that_output() {
+    for_each_api(|lang| {
+        let (mut fixture, output1, output2) = set_up();
+        let output_name = output1.name();
+
+        let named_size = |fixture: &mut Fixture, output: &Output| match fixture
+            .pinnacle()
+            .cursor_state
+            .pointer_element(Some(output))
+        {
+            PointerElement::Named { size, .. } => size,
+            _ => panic!("expected a named cursor"),
+        };
+
+        let default_size = named_size(&mut fixture, &output1);
+        assert_ne!(default_size, 48);
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(move || {
+                pinnacle_api::output::get_by_name(output_name)
+                    .unwrap()
+                    .set_xcursor_size(48);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Output.get_by_name($output_name):set_xcursor_size(48)
+            },
+        }
+
+        assert_eq!(named_size(&mut fixture, &output1), 48);
+        assert_eq!(named_size(&mut fixture, &output2), default_size);
+    });
+}
+
+#[test_log::test]
+fn output_xcursor_overrides_are_cleared_after_config_reload() {
+    for_each_api(|lang| {
+        let (mut fixture, output1, _) = set_up();
+        let output_name = output1.name();
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(move || {
+                let output = pinnacle_api::output::get_by_name(output_name).unwrap();
+                output.set_xcursor_theme("Adwaita");
+                output.set_xcursor_size(48);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                local output = Output.get_by_name($output_name)
+                output:set_xcursor_theme("Adwaita")
+                output:set_xcursor_size(48)
+            },
+        }
+
+        assert_eq!(
+            fixture.pinnacle().cursor_state.output_override(&output1),
+            CursorOverride {
+                theme: Some("Adwaita".to_string()),
+                size: Some(48),
+            }
+        );
+
+        fixture.spawn_blocking(pinnacle_api::pinnacle::reload_config);
+
+        assert_eq!(
+            fixture.pinnacle().cursor_state.output_override(&output1),
+            CursorOverride::default()
+        );
+    });
+}
+
+#[test_log::test]
+fn output_management_configuration_is_seen_by_api() {
+    for_each_api(|lang| {
+        let (mut fixture, output1, output2) = set_up();
+
+        let config = HashMap::from([
+            (
+                output1.clone(),
+                OutputConfiguration::Enabled {
+                    mode: None,
+                    position: Some((0, 0).into()),
+                    transform: None,
+                    scale: Some(1.5),
+                    adaptive_sync: None,
+                },
+            ),
+            (output2.clone(), OutputConfiguration::Disabled),
+        ]);
+        assert!(OutputManagementHandler::apply_configuration(
+            fixture.state(),
+            config
+        ));
+
+        let output1_name = output1.name();
+        let output2_name = output2.name();
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(move || {
+                let output1 = pinnacle_api::output::get_by_name(output1_name).unwrap();
+                let output2 = pinnacle_api::output::get_by_name(output2_name).unwrap();
+                assert_eq!(output1.scale(), 1.5);
+                assert!(output1.enabled());
+                assert!(!output2.enabled());
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                local output1 = Output.get_by_name($output1_name)
+                local output2 = Output.get_by_name($output2_name)
+                assert(output1:scale() == 1.5)
+                assert(output1:enabled())
+                assert(not output2:enabled())
+            },
+        }
+    });
+}
+
+#[test_log::test]
+fn output_management_rejects_invalid_configurations() {
+    for_each_api(|lang| {
+        let (mut fixture, output1, output2) = set_up();
+
+        let enabled = |mode, scale| OutputConfiguration::Enabled {
+            mode,
+            position: None,
+            transform: None,
+            scale,
+            adaptive_sync: None,
+        };
+
+        let invalid_configs = [
+            HashMap::from([
+                (output1.clone(), OutputConfiguration::Disabled),
+                (output2.clone(), OutputConfiguration::Disabled),
+            ]),
+            HashMap::from([(output1.clone(), enabled(None, Some(0.0)))]),
+            HashMap::from([(output1.clone(), enabled(None, Some(f64::NAN)))]),
+            HashMap::from([(
+                output1.clone(),
+                enabled(Some(((0, 1080).into(), None)), None),
+            )]),
+        ];
+
+        for config in invalid_configs {
+            assert!(!OutputManagementHandler::test_configuration(
+                fixture.state(),
+                config.clone()
+            ));
+            assert!(!OutputManagementHandler::apply_configuration(
+                fixture.state(),
+                config
+            ));
+        }
+
+        let output1_name = output1.name();
+        let output2_name = output2.name();
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(move || {
+                let output1 = pinnacle_api::output::get_by_name(output1_name).unwrap();
+                let output2 = pinnacle_api::output::get_by_name(output2_name).unwrap();
+                assert_eq!(output1.scale(), 1.0);
+                assert!(output1.enabled());
+                assert!(output2.enabled());
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                local output1 = Output.get_by_name($output1_name)
+                local output2 = Output.get_by_name($output2_name)
+                assert(output1:scale() == 1.0)
+                assert(output1:enabled())
+                assert(output2:enabled())
+            },
+        }
+    });
+}
+
+/// Records the fullscreen window of each fullscreen signal for one output, in order.
+#[derive(Clone)]
+struct FullscreenLog {
+    output_name: String,
+    windows: Arc<Mutex<Vec<Option<u32>>>>,
+}
+
+impl FullscreenLog {
+    fn new(output_name: String) -> Self {
+        Self {
+            output_name,
+            windows: Default::default(),
+        }
+    }
+
+    fn log(&self, output_name: &str, window_id: Option<u32>) {
+        if output_name == self.output_name {
+            self.windows.lock().unwrap().push(window_id);
+        }
+    }
+
+    fn windows(&self) -> Vec<Option<u32>> {
+        self.windows.lock().unwrap().clone()
+    }
+}
+
+impl UserData for FullscreenLog {
+    fn add_methods<M: UserDataMethods<Self>>(methods: &mut M) {
+        methods.add_method(
+            "log",
+            |_, this, (output_name, window_id): (String, Option<u32>)| {
+                this.log(&output_name, window_id);
+                Ok(())
+            },
+        );
+
+        methods.add_method("len", |_, this, ()| Ok(this.windows().len()));
+    }
+}
+
+#[test_log::test]
+fn output_signal_fullscreen_changed() {
+    for_each_api(|lang| {
+        let (mut fixture, output1, _) = set_up();
+
+        let client_id = fixture.add_client();
+        fixture.spawn_windows(1, client_id);
+
+        let window_id = fixture.pinnacle().windows[0].with_state(|state| state.id.0);
+
+        let log = FullscreenLog::new(output1.name());
+        let log_clone = log.clone();
+        let signal_handle = Arc::new(OnceLock::new());
+        let signal_handle_clone = signal_handle.clone();
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(move || {
+                let handle = pinnacle_api::output::connect_signal(
+                    pinnacle_api::signal::OutputSignal::FullscreenChanged(Box::new(
+                        move |output, window| {
+                            log.log(&output.name(), window.map(|window| window.id()));
+                        },
+                    )),
+                );
+                signal_handle_clone.set(handle).unwrap();
+
+                pinnacle_api::window::get_focused()
+                    .unwrap()
+                    .set_fullscreen(true);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Output.connect_signal({
+                    fullscreen_changed = function(output, window)
+                        $log:log(output.name, window and window.id)
+                    end
+                })
+
+                local window = Window.get_focused()
+                window:set_fullscreen(true)
+
+                local client = require("pinnacle.grpc.client").client
+                while $log:len() < 1 do
+                    client.loop:step();
+                end
+
+                window:set_fullscreen(false)
+
+                while $log:len() < 2 do
+                    client.loop:step();
+                end
+            },
+        }
+
+        if lang == Lang::Rust {
+            fixture.dispatch_until(|_| !log_clone.windows().is_empty());
+            fixture.spawn_blocking(|| {
+                pinnacle_api::window::get_focused()
+                    .unwrap()
+                    .set_fullscreen(false);
+            });
+        }
+
+        fixture.dispatch_until(|_| log_clone.windows().len() >= 2);
+
+        assert_eq!(log_clone.windows(), [Some(window_id), None]);
+
+        if lang == Lang::Rust {
+            signal_handle.get().unwrap().disconnect();
+        }
+    });
+}
+
 // TODO: for_each_output
 // TODO: connect_signal
 // TODO: keyboard_focus_stack
 // TODO: keyboard_focus_stack_visible
+
+#[test_log::test]
+fn output_signal_layer_focused_and_unfocused() {
+    let (mut fixture, output1, _) = set_up();
+
+    let client_id = fixture.add_client();
+    let surface = fixture.spawn_layer_with(client_id, Layer::Top, "launcher", |layer| {
+        layer.set_keyboard_interactivity(KeyboardInteractivity::OnDemand);
+    });
+
+    let log = Arc::new(Mutex::new(Vec::<(bool, String, String)>::new()));
+
+    let handles = fixture.spawn_blocking({
+        let log = log.clone();
+        move || {
+            let focused_log = log.clone();
+            let focused = pinnacle_api::output::connect_signal(
+                pinnacle_api::signal::OutputSignal::LayerFocused(Box::new(
+                    move |output, namespace| {
+                        focused_log.lock().unwrap().push((
+                            true,
+                            output.name(),
+                            namespace.to_string(),
+                        ));
+                    },
+                )),
+            );
+            let unfocused = pinnacle_api::output::connect_signal(
+                pinnacle_api::signal::OutputSignal::LayerUnfocused(Box::new(
+                    move |output, namespace| {
+                        log.lock()
+                            .unwrap()
+                            .push((false, output.name(), namespace.to_string()));
+                    },
+                )),
+            );
+            [focused, unfocused]
+        }
+    });
+
+    // Clicking an on-demand layer surface focuses it
+    let layer = layer_map_for_output(&output1)
+        .layers()
+        .next()
+        .cloned()
+        .unwrap();
+    fixture.pinnacle().on_demand_layer_focus = Some(layer);
+    fixture.dispatch_until(|_| !log.lock().unwrap().is_empty());
+
+    fixture.client(client_id).close_layer(&surface);
+    fixture.roundtrip(client_id);
+    fixture.dispatch_until(|_| log.lock().unwrap().len() >= 2);
+
+    assert_eq!(
+        *log.lock().unwrap(),
+        [
+            (true, output1.name(), "launcher".to_string()),
+            (false, output1.name(), "launcher".to_string()),
+        ]
+    );
+
+    for handle in handles {
+        handle.disconnect();
+    }
+}
+
+/// Records the output and edge of each pointer edge signal, in order.
+#[derive(Clone, Default)]
+struct EdgeLog {
+    ready: Arc<AtomicBool>,
+    edges: Arc<Mutex<Vec<(String, String)>>>,
+}
+
+impl EdgeLog {
+    fn log(&self, output_name: String, edge: String) {
+        self.edges.lock().unwrap().push((output_name, edge));
+    }
+
+    fn edges(&self) -> Vec<(String, String)> {
+        self.edges.lock().unwrap().clone()
+    }
+}
+
+impl UserData for EdgeLog {
+    fn add_methods<M: UserDataMethods<Self>>(methods: &mut M) {
+        methods.add_method("log", |_, this, (output_name, edge): (String, String)| {
+            this.log(output_name, edge);
+            Ok(())
+        });
+
+        methods.add_method("ready", |_, this, ()| {
+            this.ready.store(true, Ordering::Relaxed);
+            Ok(())
+        });
+
+        methods.add_method("len", |_, this, ()| Ok(this.edges().len()));
+    }
+}
+
+fn move_pointer(state: &mut State, loc: Point<f64, Logical>) {
+    let pointer = state.pinnacle.seat.get_pointer().unwrap();
+    pointer.motion(
+        state,
+        None,
+        &MotionEvent {
+            location: loc,
+            serial: SERIAL_COUNTER.next_serial(),
+            time: 0,
+        },
+    );
+    pointer.frame(state);
+}
+
+/// Moves the pointer to each of `locs` in turn, waiting for Pinnacle
+/// to update what's under the pointer before making the next move.
+fn move_pointer_through(state: &mut State, mut locs: VecDeque<Point<f64, Logical>>) {
+    let Some(loc) = locs.pop_front() else {
+        return;
+    };
+
+    move_pointer(state, loc);
+
+    state.pinnacle.schedule(
+        move |state| state.pinnacle.pointer_contents == state.pinnacle.pointer_contents_under(loc),
+        move |state| move_pointer_through(state, locs),
+    );
+}
+
+#[test_log::test]
+fn output_signal_pointer_edge() {
+    for_each_api(|lang| {
+        let (mut fixture, output1, output2) = set_up();
+
+        let output2_name = output2.name();
+        fixture.spawn_blocking(move || {
+            pinnacle_api::output::get_by_name(output2_name)
+                .unwrap()
+                .set_loc(1920, 0);
+        });
+        move_pointer(fixture.state(), (960.0, 540.0).into());
+        fixture.dispatch();
+
+        let log = EdgeLog::default();
+        let log_clone = log.clone();
+        let signal_handle = Arc::new(OnceLock::new());
+        let signal_handle_clone = signal_handle.clone();
+
+        // Hit the top of the first output, then the side shared with the second one,
+        // then the second output's bottom-right corner
+        let ready = log.ready.clone();
+        fixture.pinnacle().schedule(
+            move |_| ready.load(Ordering::Relaxed),
+            |state| {
+                move_pointer_through(
+                    state,
+                    VecDeque::from([
+                        (500.0, 0.0).into(),
+                        (1919.0, 500.0).into(),
+                        (3839.0, 1079.0).into(),
+                    ]),
+                )
+            },
+        );
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(move || {
+                let handle = pinnacle_api::output::connect_signal(
+                    pinnacle_api::signal::OutputSignal::PointerEdge(Box::new({
+                        let log = log.clone();
+                        move |output, edge| {
+                            let edge = match edge {
+                                pinnacle_api::output::ScreenEdge::Top => "top",
+                                pinnacle_api::output::ScreenEdge::BottomRight => "bottom_right",
+                                edge => panic!("unexpected edge {edge:?}"),
+                            };
+                            log.log(output.name(), edge.to_string());
+                        }
+                    })),
+                );
+                signal_handle_clone.set(handle).unwrap();
+
+                // Make sure the signal is connected before the pointer moves
+                pinnacle_api::output::get_focused();
+                log.ready.store(true, Ordering::Relaxed);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Output.connect_signal({
+                    pointer_edge = function(output, edge)
+                        $log:log(output.name, edge)
+                    end
+                })
+
+                // Make sure the signal is connected before the pointer moves
+                Output.get_focused()
+                $log:ready()
+
+                local client = require("pinnacle.grpc.client").client
+                while $log:len() < 2 do
+                    client.loop:step();
+                end
+            },
+        }
+
+        fixture.dispatch_until(|_| log_clone.edges().len() >= 2);
+
+        assert_eq!(
+            log_clone.edges(),
+            [
+                (output1.name(), "top".to_string()),
+                (output2.name(), "bottom_right".to_string()),
+            ]
+        );
+
+        if lang == Lang::Rust {
+            signal_handle.get().unwrap().disconnect();
+        }
+    });
+}
+
+#[test_log::test]
+fn output_set_color_filter() {
+    for_each_api(|lang| {
+        let (mut fixture, output1, output2) = set_up();
+        let output_name = output1.name();
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(move || {
+                pinnacle_api::output::get_by_name(output_name)
+                    .unwrap()
+                    .set_color_filter(pinnacle_api::output::ColorFilter::Grayscale);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Output.get_by_name($output_name):set_color_filter("grayscale")
+            },
+        }
+
+        assert_eq!(
+            output1.with_state(|state| state.color_filter),
+            Some(ColorFilter::Grayscale)
+        );
+        assert_eq!(output2.with_state(|state| state.color_filter), None);
+
+        let output_name = output1.name();
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(move || {
+                pinnacle_api::output::get_by_name(output_name)
+                    .unwrap()
+                    .set_color_filter(pinnacle_api::output::ColorFilter::None);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Output.get_by_name($output_name):set_color_filter("none")
+            },
+        }
+
+        assert_eq!(output1.with_state(|state| state.color_filter), None);
+    });
+}
+
+#[test_log::test]
+fn output_set_hotplug_settle_delay() {
+    for_each_api(|lang| {
+        let (mut fixture, ..) = set_up();
+
+        assert_eq!(
+            fixture.pinnacle().config.hotplug_settle_delay,
+            Duration::from_millis(200)
+        );
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::output::set_hotplug_settle_delay(Duration::from_millis(750));
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Output.set_hotplug_settle_delay(750)
+            },
+        }
+
+        assert_eq!(
+            fixture.pinnacle().config.hotplug_settle_delay,
+            Duration::from_millis(750)
+        );
+
+        fixture.spawn_blocking(pinnacle_api::pinnacle::reload_config);
+
+        assert_eq!(
+            fixture.pinnacle().config.hotplug_settle_delay,
+            Duration::from_millis(200)
+        );
+    });
+}
@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use crate::{
     common::{Lang, fixture::Fixture, for_each_api},
     spawn_lua_blocking,
@@ -67,3 +69,268 @@ fn pinnacle_take_last_error() {
         }
     });
 }
+
+#[test_log::test]
+fn pinnacle_set_request_deadline() {
+    for_each_api(|lang| {
+        let mut fixture = set_up();
+
+        let error = "allons-y";
+
+        fixture.pinnacle().config.last_error = Some(error.to_string());
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(move || {
+                pinnacle_api::set_request_deadline(Some(Duration::from_secs(5)));
+                let err = pinnacle_api::pinnacle::take_last_error();
+                pinnacle_api::set_request_deadline(None);
+
+                assert_eq!(err.as_deref(), Some(error));
+            }),
+            Lang::Lua => {
+                spawn_lua_blocking! {
+                    fixture,
+                    Pinnacle.set_request_deadline(5)
+                    assert(Pinnacle.take_last_error() == $error)
+                }
+            }
+        }
+    });
+}
+
+#[test_log::test]
+fn pinnacle_request_deadline_returns_default_on_timeout() {
+    let mut fixture = set_up();
+
+    let error = "exterminate";
+
+    fixture.pinnacle().config.last_error = Some(error.to_string());
+
+    pinnacle_api::set_request_deadline(Some(Duration::from_millis(100)));
+
+    // The event loop isn't dispatched, so Pinnacle never responds
+    let handle = fixture.runtime_handle();
+    let err = handle
+        .block_on(handle.spawn_blocking(pinnacle_api::pinnacle::take_last_error))
+        .unwrap();
+
+    pinnacle_api::set_request_deadline(None);
+
+    assert_eq!(err, None);
+    assert_eq!(fixture.pinnacle().config.last_error.as_deref(), Some(error));
+}
+
+#[test_log::test]
+fn pinnacle_request_deadline_returns_error_from_try_getters() {
+    let fixture = set_up();
+
+    pinnacle_api::set_request_deadline(Some(Duration::from_millis(100)));
+
+    // The event loop isn't dispatched, so Pinnacle never responds
+    let handle = fixture.runtime_handle();
+    let (active, scale) = handle
+        .block_on(handle.spawn_blocking(|| {
+            (
+                pinnacle_api::tag::TagHandle::from_id(0).try_active(),
+                pinnacle_api::output::OutputHandle::from_name("pinnacle-1").try_scale(),
+            )
+        }))
+        .unwrap();
+
+    pinnacle_api::set_request_deadline(None);
+
+    assert!(active.unwrap_err().is_deadline_exceeded());
+    assert!(scale.unwrap_err().is_deadline_exceeded());
+}
+
+#[test_log::test]
+fn pinnacle_settings() {
+    for_each_api(|lang| {
+        let mut fixture = set_up();
+
+        fixture.pinnacle().config.letterbox_fullscreen = true;
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                let settings = pinnacle_api::pinnacle::settings();
+                let letterbox = settings
+                    .iter()
+                    .find(|setting| setting.name == "render.letterbox_fullscreen")
+                    .unwrap();
+                assert_eq!(
+                    letterbox.value,
+                    pinnacle_api::pinnacle::SettingValue::Bool(true)
+                );
+                assert_eq!(
+                    letterbox.setter,
+                    "pinnacle.render.v1.RenderService.SetLetterboxFullscreen"
+                );
+
+                let policy = settings
+                    .iter()
+                    .find(|setting| setting.name == "window.maximize_request_policy")
+                    .unwrap();
+                assert!(
+                    policy
+                        .choices
+                        .contains(&"MAXIMIZE_REQUEST_POLICY_IGNORE".to_string())
+                );
+            }),
+            Lang::Lua => {
+                spawn_lua_blocking! {
+                    fixture,
+                    local letterbox
+                    for _, setting in ipairs(Pinnacle.settings()) do
+                        if setting.name == "render.letterbox_fullscreen" then
+                            letterbox = setting
+                        end
+                    end
+                    assert(letterbox.type == "bool")
+                    assert(letterbox.value == true)
+                }
+            }
+        }
+    });
+}
+
+#[test_log::test]
+fn pinnacle_profile_restores_options_when_unset() {
+    for_each_api(|lang| {
+        let mut fixture = set_up();
+
+        fixture.pinnacle().config.letterbox_fullscreen = true;
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                use pinnacle_api::pinnacle::{self, Profile};
+
+                pinnacle::define_profile(
+                    "gaming",
+                    Profile {
+                        inhibit_idle: Some(true),
+                        letterbox_fullscreen: Some(false),
+                        ..Default::default()
+                    },
+                );
+
+                assert!(pinnacle::set_profile("nonexistent").is_err());
+                pinnacle::set_profile("gaming").unwrap();
+                assert_eq!(pinnacle::profile().as_deref(), Some("gaming"));
+            }),
+            Lang::Lua => {
+                spawn_lua_blocking! {
+                    fixture,
+                    Pinnacle.define_profile("gaming", {
+                        inhibit_idle = true,
+                        letterbox_fullscreen = false,
+                    })
+
+                    assert(Pinnacle.set_profile("nonexistent") ~= nil)
+                    assert(Pinnacle.set_profile("gaming") == nil)
+                    assert(Pinnacle.profile() == "gaming")
+                }
+            }
+        }
+
+        let config = &fixture.pinnacle().config;
+        assert!(config.inhibit_idle);
+        assert!(!config.letterbox_fullscreen);
+        assert!(fixture.pinnacle().idle_notifier_state.is_inhibited());
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::pinnacle::unset_profile();
+                assert_eq!(pinnacle_api::pinnacle::profile(), None);
+            }),
+            Lang::Lua => {
+                spawn_lua_blocking! {
+                    fixture,
+                    Pinnacle.unset_profile()
+                    assert(Pinnacle.profile() == nil)
+                }
+            }
+        }
+
+        let config = &fixture.pinnacle().config;
+        assert!(!config.inhibit_idle);
+        assert!(config.letterbox_fullscreen);
+    });
+}
+
+#[test_log::test]
+fn pinnacle_metrics() {
+    for_each_api(|lang| {
+        let mut fixture = set_up();
+
+        let metrics = &mut fixture.pinnacle().metrics;
+        metrics.missed_frames = 3;
+        metrics.layout_time.observe(Duration::from_millis(5));
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                let metrics = pinnacle_api::pinnacle::metrics();
+                assert_eq!(metrics.missed_frames, 3);
+                assert_eq!(metrics.layout_time.count, 1);
+                assert_eq!(metrics.layout_time.buckets[3].1, 1);
+                assert_eq!(metrics.layout_time.buckets.last().unwrap().0, None);
+                assert_eq!(metrics.input_latency.count, 0);
+            }),
+            Lang::Lua => {
+                spawn_lua_blocking! {
+                    fixture,
+                    local metrics = Pinnacle.metrics()
+                    assert(metrics.missed_frames == 3)
+                    assert(metrics.layout_time.count == 1)
+                    assert(metrics.layout_time.buckets[4].count == 1)
+                    assert(metrics.input_latency.count == 0)
+                }
+            }
+        }
+    });
+}
+
+#[test_log::test]
+fn pinnacle_register_namespace() {
+    let mut fixture = set_up();
+
+    // Need tokio here for the input stuff
+    let handle = fixture.runtime_handle();
+    let _guard = handle.enter();
+
+    fixture.spawn_blocking(|| {
+        pinnacle_api::pinnacle::register_namespace("bar").unwrap();
+        assert!(pinnacle_api::pinnacle::register_namespace("baz").is_err());
+
+        pinnacle_api::input::keybind(pinnacle_api::input::Mod::SUPER, 'b').on_press(|| {});
+    });
+
+    let keybinds = &fixture.pinnacle().input_state.bind_state.keybinds;
+    assert_eq!(keybinds.id_map.len(), 1);
+    let keybind = keybinds.id_map.values().next().unwrap().borrow();
+    assert_eq!(keybind.bind_data.namespace.as_deref(), Some("bar"));
+    drop(keybind);
+
+    // Reloading the config keeps binds from namespaced clients
+    fixture.pinnacle().input_state.clear();
+    assert_eq!(
+        fixture
+            .pinnacle()
+            .input_state
+            .bind_state
+            .keybinds
+            .id_map
+            .len(),
+        1
+    );
+
+    fixture.state().revoke_namespace("bar");
+    assert!(
+        fixture
+            .pinnacle()
+            .input_state
+            .bind_state
+            .keybinds
+            .id_map
+            .is_empty()
+    );
+}
@@ -0,0 +1,145 @@
+use std::sync::{Arc, Mutex};
+
+use pinnacle::{
+    input::bind::Edge,
+    portal::global_shortcuts::{ShortcutEvent, ShortcutInfo},
+};
+use smithay::{backend::input::KeyState, input::keyboard::Keycode};
+use tokio::sync::{mpsc, oneshot};
+
+use crate::common::fixture::Fixture;
+
+fn set_up() -> Fixture {
+    let fixture = Fixture::new();
+
+    fixture
+        .runtime_handle()
+        .block_on(pinnacle_api::connect())
+        .unwrap();
+
+    fixture
+}
+
+fn shortcuts() -> Vec<ShortcutInfo> {
+    vec![ShortcutInfo {
+        id: "record".into(),
+        description: "Start recording".into(),
+        preferred_trigger: Some("CTRL+SHIFT+r".into()),
+    }]
+}
+
+fn request_permission(fixture: &mut Fixture, app_id: &str) -> bool {
+    let (sender, mut receiver) = oneshot::channel();
+    fixture
+        .pinnacle()
+        .global_shortcuts_state
+        .request_permission(app_id.into(), shortcuts(), sender);
+
+    let mut allowed = None;
+    fixture.dispatch_until(|_| {
+        allowed = receiver.try_recv().ok();
+        allowed.is_some()
+    });
+    allowed.unwrap()
+}
+
+#[test_log::test]
+fn portal_global_shortcuts_request_is_denied_without_handler() {
+    let mut fixture = set_up();
+
+    assert!(!request_permission(&mut fixture, "org.example.App"));
+}
+
+#[test_log::test]
+fn portal_global_shortcuts_request_is_answered_by_config() {
+    let mut fixture = set_up();
+
+    let requests = Arc::new(Mutex::new(Vec::new()));
+    let requests_clone = requests.clone();
+
+    fixture.spawn_blocking(move || {
+        pinnacle_api::portal::on_global_shortcuts_request(move |request| {
+            requests_clone
+                .lock()
+                .unwrap()
+                .push((request.app_id().to_string(), request.shortcuts().to_vec()));
+
+            if request.app_id() == "org.example.Allowed" {
+                request.allow();
+            } else {
+                request.deny();
+            }
+        });
+    });
+    // Register the handler
+    fixture.dispatch();
+
+    assert!(request_permission(&mut fixture, "org.example.Allowed"));
+    assert!(!request_permission(&mut fixture, "org.example.Denied"));
+
+    {
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0].0, "org.example.Allowed");
+        assert_eq!(requests[0].1[0].id, "record");
+        assert_eq!(
+            requests[0].1[0].preferred_trigger.as_deref(),
+            Some("CTRL+SHIFT+r")
+        );
+    }
+
+    // Allowed apps aren't asked about again
+    assert!(request_permission(&mut fixture, "org.example.Allowed"));
+    assert_eq!(requests.lock().unwrap().len(), 2);
+}
+
+#[test_log::test]
+fn portal_global_shortcuts_request_is_denied_when_dropped() {
+    let mut fixture = set_up();
+
+    fixture.spawn_blocking(|| {
+        pinnacle_api::portal::on_global_shortcuts_request(drop);
+    });
+    fixture.dispatch();
+
+    assert!(!request_permission(&mut fixture, "org.example.App"));
+}
+
+#[test_log::test]
+fn portal_global_shortcuts_bound_shortcut_is_activated_by_keys() {
+    let mut fixture = set_up();
+
+    let (event_sender, mut events) = mpsc::unbounded_channel();
+    let state = &mut fixture.pinnacle().global_shortcuts_state;
+    state.event_sender = Some(event_sender);
+    state.bind("/session".into(), shortcuts());
+
+    // xkb keycodes of left ctrl, left shift, and r
+    let ctrl = Keycode::new(37);
+    let shift = Keycode::new(50);
+    let r = Keycode::new(27);
+
+    let state = fixture.state();
+    state.on_remappable_key(ctrl, KeyState::Pressed, 1, None);
+    state.on_remappable_key(shift, KeyState::Pressed, 2, None);
+    state.on_remappable_key(r, KeyState::Pressed, 3, None);
+    state.on_remappable_key(r, KeyState::Released, 4, None);
+    state.on_remappable_key(shift, KeyState::Released, 5, None);
+    state.on_remappable_key(ctrl, KeyState::Released, 6, None);
+
+    let event = |edge, time| ShortcutEvent {
+        session_handle: "/session".into(),
+        shortcut_id: "record".into(),
+        edge,
+        time,
+    };
+
+    assert_eq!(events.try_recv(), Ok(event(Edge::Press, 3)));
+    assert_eq!(events.try_recv(), Ok(event(Edge::Release, 4)));
+    assert!(events.try_recv().is_err());
+
+    // Without the shortcut's modifiers, the key goes to the client
+    state.on_remappable_key(r, KeyState::Pressed, 7, None);
+    state.on_remappable_key(r, KeyState::Released, 8, None);
+    assert!(events.try_recv().is_err());
+}
@@ -0,0 +1,67 @@
+use pinnacle::power::PowerStatus;
+
+use crate::{common::fixture::Fixture, spawn_lua_blocking};
+
+fn set_up() -> Fixture {
+    let mut fixture = Fixture::new();
+
+    fixture
+        .runtime_handle()
+        .block_on(pinnacle_api::connect())
+        .unwrap();
+
+    fixture.state().set_power_status(PowerStatus {
+        on_battery: true,
+        charging: false,
+        percentage: Some(12.5),
+        low_battery: true,
+    });
+
+    fixture
+}
+
+#[test_log::test]
+fn power_status() {
+    let mut fixture = set_up();
+
+    fixture.spawn_blocking(|| {
+        let status = pinnacle_api::power::status();
+        assert_eq!(
+            status,
+            pinnacle_api::power::PowerStatus {
+                on_battery: true,
+                charging: false,
+                percentage: Some(12.5),
+                low_battery: true,
+            }
+        );
+    });
+
+    spawn_lua_blocking! {
+        fixture,
+        local status = require("pinnacle.power").status()
+        assert(status.on_battery == true)
+        assert(status.charging == false)
+        assert(status.percentage == 12.5)
+        assert(status.low_battery == true)
+    }
+}
+
+#[test_log::test]
+fn power_status_without_battery() {
+    let mut fixture = set_up();
+    fixture.state().set_power_status(PowerStatus::default());
+
+    fixture.spawn_blocking(|| {
+        let status = pinnacle_api::power::status();
+        assert!(!status.on_battery);
+        assert_eq!(status.percentage, None);
+    });
+
+    spawn_lua_blocking! {
+        fixture,
+        local status = require("pinnacle.power").status()
+        assert(status.on_battery == false)
+        assert(status.percentage == nil)
+    }
+}
@@ -360,3 +360,43 @@ fn process_set_env() {
         );
     });
 }
+
+#[test_log::test]
+fn process_run_or_raise_switches_to_window() {
+    for_each_api(|lang| {
+        let (mut fixture, output) = set_up();
+
+        let client_id = fixture.add_client();
+        fixture.spawn_window_with(client_id, |win| {
+            win.set_app_id("mango");
+        });
+
+        let tag_1 = output.with_state(|state| state.tags[0].clone());
+        let tag_2 = Tag::new("2".to_string());
+        output.with_state_mut(|state| state.add_tags([tag_2.clone()]));
+        tag_1.set_active(false);
+        tag_2.set_active(true);
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                use pinnacle_api::process::{self, RunOrRaise};
+
+                let result = process::run_or_raise("^mango$", ["false"]).unwrap();
+                assert!(matches!(result, RunOrRaise::Raised(_)));
+
+                assert!(process::run_or_raise("(unclosed", ["false"]).is_err());
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                local window, child, err = Process.run_or_raise("^mango$", "false")
+                assert(window and not child and not err)
+
+                local _, _, err = Process.run_or_raise("(unclosed", "false")
+                assert(err)
+            },
+        }
+
+        assert!(tag_1.active());
+        assert!(!tag_2.active());
+    });
+}
@@ -0,0 +1,164 @@
+use pinnacle::{
+    render::util::effect::{InactiveEffect, InactiveEffectTarget},
+    state::WithState,
+    tag::Tag,
+};
+use pinnacle_api::layout::{LayoutGenerator as _, generators::MasterStack};
+use smithay::{output::Output, utils::Rectangle};
+
+use crate::{
+    common::{Lang, fixture::Fixture, for_each_api},
+    spawn_lua_blocking,
+};
+
+fn set_up() -> (Fixture, Output, Output) {
+    let mut fixture = Fixture::new();
+
+    let output1 = fixture.add_output(Rectangle::new((0, 0).into(), (1920, 1080).into()));
+    let output2 = fixture.add_output(Rectangle::new((1920, 0).into(), (1920, 1080).into()));
+    for output in [&output1, &output2] {
+        output.with_state_mut(|state| {
+            let tag = Tag::new("1".to_string());
+            tag.set_active(true);
+            state.add_tags([tag]);
+        });
+    }
+    fixture.pinnacle().focus_output(&output1);
+
+    fixture
+        .runtime_handle()
+        .block_on(pinnacle_api::connect())
+        .unwrap();
+
+    (fixture, output1, output2)
+}
+
+#[test_log::test]
+fn render_set_inactive_effect_applies_to_unfocused_outputs() {
+    for_each_api(|lang| {
+        let (mut fixture, output1, output2) = set_up();
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::render::set_inactive_effect(
+                    pinnacle_api::render::InactiveEffectTarget::UnfocusedOutputs,
+                    0.2,
+                    1.0,
+                );
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                require("pinnacle.render").set_inactive_effect({
+                    target = "unfocused_outputs",
+                    dim = 0.2,
+                    desaturate = 1.0,
+                })
+            },
+        }
+
+        let effect = InactiveEffect {
+            target: InactiveEffectTarget::UnfocusedOutputs,
+            dim: 0.2,
+            desaturate: 1.0,
+        };
+        assert_eq!(fixture.pinnacle().config.inactive_effect, Some(effect));
+
+        fixture.dispatch();
+        assert_eq!(
+            output1.with_state(|state| state.inactive_effect.clone()),
+            None
+        );
+        assert_eq!(
+            output2.with_state(|state| state.inactive_effect.clone().map(|applied| applied.effect)),
+            Some(effect)
+        );
+
+        fixture.pinnacle().focus_output(&output2);
+        fixture.dispatch();
+        assert!(output1.with_state(|state| state.inactive_effect.is_some()));
+        assert!(output2.with_state(|state| state.inactive_effect.is_none()));
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(pinnacle_api::render::disable_inactive_effect),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                require("pinnacle.render").disable_inactive_effect()
+            },
+        }
+
+        fixture.dispatch();
+        assert_eq!(fixture.pinnacle().config.inactive_effect, None);
+        assert!(output1.with_state(|state| state.inactive_effect.is_none()));
+    });
+}
+
+#[test_log::test]
+fn render_set_inactive_effect_exempts_focused_window() {
+    for_each_api(|lang| {
+        let (mut fixture, output1, _) = set_up();
+
+        fixture.spawn_blocking(|| {
+            pinnacle_api::layout::manage(|args| pinnacle_api::layout::LayoutResponse {
+                root_node: MasterStack::default().layout(args.window_count),
+                tree_id: 0,
+            });
+        });
+
+        let client_id = fixture.add_client();
+        fixture.spawn_windows(2, client_id);
+        let focused = fixture
+            .pinnacle()
+            .keyboard_focus_stack
+            .current_focus()
+            .cloned()
+            .unwrap();
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::render::set_inactive_effect(
+                    pinnacle_api::render::InactiveEffectTarget::UnfocusedWindows,
+                    0.5,
+                    0.0,
+                );
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                require("pinnacle.render").set_inactive_effect({
+                    target = "unfocused_windows",
+                    dim = 0.5,
+                })
+            },
+        }
+
+        fixture.dispatch();
+        let applied = output1.with_state(|state| state.inactive_effect.clone().unwrap());
+        assert!(applied.except.is_some());
+        assert!(!applied.applies_to(&focused));
+        for window in fixture.pinnacle().windows.clone() {
+            assert_eq!(applied.applies_to(&window), window != focused);
+        }
+    });
+}
+
+#[test_log::test]
+fn render_inactive_effect_is_cleared_after_config_reload() {
+    let (mut fixture, output1, output2) = set_up();
+
+    fixture.spawn_blocking(|| {
+        pinnacle_api::render::set_inactive_effect(
+            pinnacle_api::render::InactiveEffectTarget::UnfocusedOutputs,
+            0.2,
+            1.0,
+        );
+    });
+
+    fixture.dispatch();
+    assert!(output2.with_state(|state| state.inactive_effect.is_some()));
+
+    fixture.spawn_blocking(pinnacle_api::pinnacle::reload_config);
+
+    fixture.dispatch();
+    assert_eq!(fixture.pinnacle().config.inactive_effect, None);
+    assert!(output1.with_state(|state| state.inactive_effect.is_none()));
+    assert!(output2.with_state(|state| state.inactive_effect.is_none()));
+}
@@ -0,0 +1,208 @@
+//! The test backend has no renderer, so selections that reach capturing fail
+//! instead of returning pixels.
+
+use std::time::Duration;
+
+use pinnacle::{
+    handlers::session_lock::LockState,
+    state::{State, WithState},
+    tag::Tag,
+};
+use smithay::{
+    backend::input::ButtonState,
+    input::pointer::{ButtonEvent, MotionEvent},
+    utils::{Logical, Point, Rectangle, SERIAL_COUNTER},
+};
+
+use crate::{
+    common::{Lang, fixture::Fixture, for_each_api},
+    spawn_lua_blocking,
+};
+
+const BUTTON_LEFT: u32 = 0x110;
+
+fn set_up() -> Fixture {
+    let mut fixture = Fixture::new();
+
+    let output = fixture.add_output(Rectangle::new((0, 0).into(), (1920, 1080).into()));
+    output.with_state_mut(|state| {
+        let tag = Tag::new("1".to_string());
+        tag.set_active(true);
+        state.add_tags([tag]);
+    });
+    fixture.pinnacle().focus_output(&output);
+
+    fixture
+        .runtime_handle()
+        .block_on(pinnacle_api::connect())
+        .unwrap();
+
+    fixture
+}
+
+/// Runs `run` once a region selection has started.
+fn on_region_select(fixture: &mut Fixture, run: impl FnOnce(&mut State) + 'static) {
+    fixture
+        .pinnacle()
+        .schedule(|state| state.pinnacle.region_select.is_some(), run);
+}
+
+fn move_pointer(state: &mut State, loc: Point<f64, Logical>) {
+    let pointer = state.pinnacle.seat.get_pointer().unwrap();
+    pointer.motion(
+        state,
+        None,
+        &MotionEvent {
+            location: loc,
+            serial: SERIAL_COUNTER.next_serial(),
+            time: 0,
+        },
+    );
+    pointer.frame(state);
+}
+
+fn left_button(state: &mut State, button_state: ButtonState) {
+    let pointer = state.pinnacle.seat.get_pointer().unwrap();
+    pointer.button(
+        state,
+        &ButtonEvent {
+            serial: SERIAL_COUNTER.next_serial(),
+            time: 0,
+            button: BUTTON_LEFT,
+            state: button_state,
+        },
+    );
+    pointer.frame(state);
+}
+
+#[test_log::test]
+fn screenshot_select_region_returns_none_when_cancelled() {
+    for_each_api(|lang| {
+        let mut fixture = set_up();
+
+        on_region_select(&mut fixture, |state| state.cancel_region_select());
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                let screenshot = pinnacle_api::screenshot::select_region(None::<&str>);
+                assert_eq!(screenshot, Ok(None));
+            }),
+            Lang::Lua => {
+                spawn_lua_blocking! {
+                    fixture,
+                    local shot, err = require("pinnacle.screenshot").select_region()
+                    assert(shot == nil)
+                    assert(err == nil)
+                }
+            }
+        }
+
+        assert!(fixture.pinnacle().region_select.is_none());
+    });
+}
+
+#[test_log::test]
+fn screenshot_select_region_replaces_previous_selection() {
+    let mut fixture = set_up();
+
+    let handle = fixture.runtime_handle();
+    let _guard = handle.enter();
+    let first = handle.spawn_blocking(|| pinnacle_api::screenshot::select_region(None::<&str>));
+
+    fixture.dispatch_until(|fixture| fixture.pinnacle().region_select.is_some());
+
+    let second = handle.spawn_blocking(|| pinnacle_api::screenshot::select_region(None::<&str>));
+
+    fixture.dispatch_until(|_| first.is_finished());
+    assert_eq!(handle.block_on(first).unwrap(), Ok(None));
+    assert!(fixture.pinnacle().region_select.is_some());
+
+    fixture.state().cancel_region_select();
+    fixture.dispatch_until(|_| second.is_finished());
+    assert_eq!(handle.block_on(second).unwrap(), Ok(None));
+}
+
+#[test_log::test]
+fn screenshot_select_region_is_not_subject_to_request_deadline() {
+    let mut fixture = set_up();
+
+    pinnacle_api::set_request_deadline(Some(Duration::from_millis(50)));
+
+    let handle = fixture.runtime_handle();
+    let _guard = handle.enter();
+    let join = handle.spawn_blocking(|| pinnacle_api::screenshot::select_region(None::<&str>));
+
+    fixture.dispatch_until(|fixture| fixture.pinnacle().region_select.is_some());
+    fixture.dispatch_for(Duration::from_millis(200));
+    assert!(!join.is_finished());
+
+    fixture.state().cancel_region_select();
+    fixture.dispatch_until(|_| join.is_finished());
+
+    pinnacle_api::set_request_deadline(None);
+
+    assert_eq!(handle.block_on(join).unwrap(), Ok(None));
+}
+
+#[test_log::test]
+fn screenshot_select_region_fails_while_locked() {
+    for_each_api(|lang| {
+        let mut fixture = set_up();
+
+        fixture.pinnacle().lock_state = LockState::Locked;
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                let err = pinnacle_api::screenshot::select_region(None::<&str>).unwrap_err();
+                assert!(err.message().contains("locked"), "{err}");
+            }),
+            Lang::Lua => {
+                spawn_lua_blocking! {
+                    fixture,
+                    local shot, err = require("pinnacle.screenshot").select_region()
+                    assert(shot == nil)
+                    assert(err:find("locked"))
+                }
+            }
+        }
+
+        assert!(fixture.pinnacle().region_select.is_none());
+        assert!(!fixture.pinnacle().seat.get_pointer().unwrap().is_grabbed());
+
+        fixture.pinnacle().lock_state = LockState::Unlocked;
+    });
+}
+
+#[test_log::test]
+fn screenshot_select_region_captures_dragged_region() {
+    for_each_api(|lang| {
+        let mut fixture = set_up();
+
+        on_region_select(&mut fixture, |state| {
+            move_pointer(state, (100.0, 100.0).into());
+            left_button(state, ButtonState::Pressed);
+            move_pointer(state, (400.0, 300.0).into());
+            left_button(state, ButtonState::Released);
+        });
+
+        // Finishing the drag reaches capturing, which fails without a renderer.
+        // A cancelled selection would come back empty instead.
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                let err = pinnacle_api::screenshot::select_region(None::<&str>).unwrap_err();
+                assert!(err.message().contains("no renderer"), "{err}");
+            }),
+            Lang::Lua => {
+                spawn_lua_blocking! {
+                    fixture,
+                    local shot, err = require("pinnacle.screenshot").select_region()
+                    assert(shot == nil)
+                    assert(err:find("no renderer"))
+                }
+            }
+        }
+
+        assert!(fixture.pinnacle().region_select.is_none());
+        assert!(!fixture.pinnacle().seat.get_pointer().unwrap().is_grabbed());
+    });
+}
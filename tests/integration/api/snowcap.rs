@@ -0,0 +1,513 @@
+use std::{
+    collections::VecDeque,
+    num::NonZeroU32,
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicBool, Ordering},
+    },
+    time::Duration,
+};
+
+use mlua::{UserData, UserDataMethods};
+use pinnacle::{
+    power::PowerStatus,
+    state::{State, WithState},
+    tag::Tag,
+};
+use pinnacle_api::{
+    layout::{LayoutGenerator as _, generators::MasterStack},
+    output::ScreenEdge,
+    snowcap::binding::{self, BindTarget},
+};
+use smithay::{
+    desktop::layer_map_for_output,
+    input::pointer::MotionEvent,
+    output::Output,
+    utils::{Logical, Point, Rectangle, SERIAL_COUNTER},
+    wayland::shell::wlr_layer::{self, LayerSurfaceCachedState},
+};
+use snowcap::SnowcapHandle;
+use snowcap_api::{
+    layer::{ExclusiveZone, KeyboardInteractivity, Margin, ZLayer},
+    widget::{Program, WidgetDef, row::Row},
+};
+use tokio::sync::mpsc::{UnboundedReceiver, UnboundedSender, unbounded_channel};
+
+use crate::{
+    common::{Lang, fixture::Fixture, for_each_api},
+    spawn_lua_blocking,
+};
+
+/// A bind target that forwards messages to the test.
+struct Target<Msg>(UnboundedSender<Msg>);
+
+impl<Msg> Clone for Target<Msg> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<Msg: Send + 'static> BindTarget<Msg> for Target<Msg> {
+    fn send(&self, message: Msg) {
+        let _ = self.0.send(message);
+    }
+}
+
+fn target<Msg>() -> (Target<Msg>, UnboundedReceiver<Msg>) {
+    let (send, recv) = unbounded_channel();
+    (Target(send), recv)
+}
+
+/// Dispatches until the binding sends a message matching `until`, skipping others.
+fn recv_until<Msg>(
+    fixture: &mut Fixture,
+    recv: &mut UnboundedReceiver<Msg>,
+    mut until: impl FnMut(&Msg) -> bool,
+) -> Msg {
+    let mut message = None;
+    fixture.dispatch_until(|_| {
+        message = recv.try_recv().ok().filter(&mut until);
+        message.is_some()
+    });
+    message.unwrap()
+}
+
+fn recv<Msg>(fixture: &mut Fixture, recv: &mut UnboundedReceiver<Msg>) -> Msg {
+    recv_until(fixture, recv, |_| true)
+}
+
+fn set_up() -> Fixture {
+    let mut fixture = Fixture::new();
+
+    let output = fixture.add_output(Rectangle::new((0, 0).into(), (1920, 1080).into()));
+    output.with_state_mut(|state| {
+        let tag = Tag::new("1".to_string());
+        tag.set_active(true);
+        state.add_tags([tag, Tag::new("2".to_string())]);
+    });
+    fixture.pinnacle().focus_output(&output);
+
+    fixture
+        .runtime_handle()
+        .block_on(pinnacle_api::connect())
+        .unwrap();
+
+    fixture
+}
+
+#[test_log::test]
+fn bind_tag_pager_sends_tags_of_output() {
+    let mut fixture = set_up();
+    let (target, mut messages) = target();
+
+    let binding = fixture.spawn_blocking(move || {
+        let output = pinnacle_api::output::get_focused().unwrap();
+        binding::bind_tag_pager(&target, &output, |tags| tags)
+    });
+
+    let tags = recv(&mut fixture, &mut messages);
+    let names = tags.iter().map(|tag| tag.name.as_str()).collect::<Vec<_>>();
+    assert_eq!(names, ["1", "2"]);
+    assert!(tags[0].active);
+    assert!(!tags[1].active);
+
+    fixture.spawn_blocking(|| {
+        pinnacle_api::tag::get("2").unwrap().set_active(true);
+    });
+
+    let tags = recv(&mut fixture, &mut messages);
+    assert!(tags[1].active);
+
+    binding.unbind();
+}
+
+#[test_log::test]
+fn bind_focused_title_sends_only_title_changes() {
+    let mut fixture = set_up();
+
+    fixture.spawn_blocking(|| {
+        pinnacle_api::layout::manage(|args| pinnacle_api::layout::LayoutResponse {
+            root_node: MasterStack::default().layout(args.window_count),
+            tree_id: 0,
+        });
+    });
+
+    let client_id = fixture.add_client();
+    let surface = fixture.spawn_windows(1, client_id).remove(0);
+    fixture
+        .client(client_id)
+        .window_for_surface(&surface)
+        .set_title("first");
+    fixture.roundtrip(client_id);
+    fixture.dispatch();
+
+    let (target, mut messages) = target();
+    let binding =
+        fixture.spawn_blocking(move || binding::bind_focused_title(&target, |title| title));
+
+    assert_eq!(recv(&mut fixture, &mut messages).as_deref(), Some("first"));
+
+    // Pager changes that keep the title don't resend it
+    fixture.spawn_blocking(|| {
+        pinnacle_api::tag::get("2").unwrap().set_active(true);
+    });
+    fixture.dispatch_for(Duration::from_millis(100));
+    assert!(messages.try_recv().is_err());
+
+    fixture
+        .client(client_id)
+        .window_for_surface(&surface)
+        .set_title("renamed");
+    fixture.roundtrip(client_id);
+    fixture.dispatch();
+
+    assert_eq!(
+        recv(&mut fixture, &mut messages).as_deref(),
+        Some("renamed")
+    );
+
+    binding.unbind();
+}
+
+#[test_log::test]
+fn bind_power_sends_status_changes() {
+    let mut fixture = set_up();
+    fixture.state().set_power_status(PowerStatus {
+        on_battery: true,
+        charging: false,
+        percentage: Some(50.0),
+        low_battery: false,
+    });
+
+    let (target, mut messages) = target();
+    let binding = fixture.spawn_blocking(move || binding::bind_power(&target, |status| status));
+
+    let status = recv(&mut fixture, &mut messages);
+    assert!(status.on_battery);
+    assert_eq!(status.percentage, Some(50.0));
+
+    // The charging signal sends the new status without waiting for the next poll
+    fixture.state().set_power_status(PowerStatus {
+        on_battery: false,
+        charging: true,
+        percentage: Some(50.0),
+        low_battery: false,
+    });
+
+    let status = recv_until(&mut fixture, &mut messages, |status| status.charging);
+    assert!(!status.on_battery);
+
+    binding.unbind();
+}
+
+#[test_log::test]
+fn bind_clock_sends_formatted_time() {
+    let mut fixture = set_up();
+
+    let (target, mut messages) = target();
+    let binding =
+        fixture.spawn_blocking(move || binding::bind_clock(&target, "clock", |time| time));
+
+    assert_eq!(recv(&mut fixture, &mut messages), "clock");
+
+    binding.unbind();
+}
+
+/// Records the messages a binding sends, from either API.
+#[derive(Clone, Default)]
+struct MessageLog {
+    ready: Arc<AtomicBool>,
+    messages: Arc<Mutex<Vec<String>>>,
+}
+
+impl MessageLog {
+    fn messages(&self) -> Vec<String> {
+        self.messages.lock().unwrap().clone()
+    }
+}
+
+impl BindTarget<String> for MessageLog {
+    fn send(&self, message: String) {
+        self.messages.lock().unwrap().push(message);
+    }
+}
+
+impl UserData for MessageLog {
+    fn add_methods<M: UserDataMethods<Self>>(methods: &mut M) {
+        methods.add_method("send_message", |_, this, message: String| {
+            this.send(message);
+            Ok(())
+        });
+
+        methods.add_method("ready", |_, this, ()| {
+            this.ready.store(true, Ordering::Relaxed);
+            Ok(())
+        });
+
+        methods.add_method("len", |_, this, ()| Ok(this.messages().len()));
+    }
+}
+
+fn move_pointer(state: &mut State, loc: Point<f64, Logical>) {
+    let pointer = state.pinnacle.seat.get_pointer().unwrap();
+    pointer.motion(
+        state,
+        None,
+        &MotionEvent {
+            location: loc,
+            serial: SERIAL_COUNTER.next_serial(),
+            time: 0,
+        },
+    );
+    pointer.frame(state);
+}
+
+/// Moves the pointer to each of `locs` in turn, waiting for Pinnacle
+/// to update what's under the pointer before making the next move.
+fn move_pointer_through(state: &mut State, mut locs: VecDeque<Point<f64, Logical>>) {
+    let Some(loc) = locs.pop_front() else {
+        return;
+    };
+
+    move_pointer(state, loc);
+
+    state.pinnacle.schedule(
+        move |state| state.pinnacle.pointer_contents == state.pinnacle.pointer_contents_under(loc),
+        move |state| move_pointer_through(state, locs),
+    );
+}
+
+/// An auto-hiding bar at the top of the output is revealed through this binding.
+#[test_log::test]
+fn bind_screen_edge_sends_only_bound_edges() {
+    for_each_api(|lang| {
+        let mut fixture = set_up();
+
+        move_pointer(fixture.state(), (960.0, 540.0).into());
+        fixture.dispatch();
+
+        let log = MessageLog::default();
+
+        // Hit the bottom edge, which isn't bound, before the top one
+        let ready = log.ready.clone();
+        fixture.pinnacle().schedule(
+            move |_| ready.load(Ordering::Relaxed),
+            |state| {
+                move_pointer_through(
+                    state,
+                    VecDeque::from([(500.0, 1079.0).into(), (500.0, 0.0).into()]),
+                )
+            },
+        );
+
+        let binding = match lang {
+            Lang::Rust => Some(fixture.spawn_blocking({
+                let log = log.clone();
+                move || {
+                    let output = pinnacle_api::output::get_focused().unwrap();
+                    let binding = binding::bind_screen_edge(
+                        &log,
+                        &output,
+                        [ScreenEdge::Top, ScreenEdge::TopLeft, ScreenEdge::TopRight],
+                        |edge| format!("{edge:?}").to_lowercase(),
+                    );
+                    log.ready.store(true, Ordering::Relaxed);
+                    binding
+                }
+            })),
+            Lang::Lua => {
+                let log = log.clone();
+                spawn_lua_blocking! {
+                    fixture,
+                    // Bindings don't talk to Snowcap, so it doesn't need to be running
+                    package.loaded.snowcap = { init = function() end }
+                    package.loaded["pinnacle.snowcap"] = nil
+                    local integration = require("pinnacle.snowcap").integration
+
+                    local binding = integration.bind_screen_edge(
+                        $log,
+                        Output.get_focused(),
+                        { "top", "top_left", "top_right" },
+                        function(edge)
+                            return edge
+                        end
+                    )
+
+                    // Make sure the signal is connected before the pointer moves
+                    Output.get_focused()
+                    $log:ready()
+
+                    local client = require("pinnacle.grpc.client").client
+                    while $log:len() < 1 do
+                        client.loop:step();
+                    end
+
+                    binding:unbind()
+                };
+                None
+            }
+        };
+
+        fixture.dispatch_until(|_| !log.messages().is_empty());
+        assert_eq!(log.messages(), ["top"]);
+
+        if let Some(binding) = binding {
+            binding.unbind();
+        }
+    });
+}
+
+/// Snowcap running as a Wayland client of a fixture.
+struct Snowcap {
+    handle: SnowcapHandle,
+    join: tokio::task::JoinHandle<()>,
+}
+
+impl Snowcap {
+    fn start(fixture: &mut Fixture) -> Self {
+        let (sender, mut recv) = tokio::sync::oneshot::channel();
+        let join = fixture
+            .runtime_handle()
+            .spawn_blocking(move || snowcap::start(Some(sender)));
+
+        // Snowcap roundtrips with the compositor while starting up
+        let mut handle = None;
+        fixture.dispatch_until(|_| {
+            handle = recv.try_recv().ok();
+            handle.is_some()
+        });
+
+        Self {
+            handle: handle.unwrap(),
+            join,
+        }
+    }
+
+    fn stop(self, fixture: &mut Fixture) {
+        self.handle.stop();
+        fixture.dispatch_until(|_| self.join.is_finished());
+    }
+}
+
+fn set_up_with_snowcap() -> (Fixture, Output, Snowcap) {
+    let mut fixture = Fixture::new_with_socket();
+
+    let output = fixture.add_output(Rectangle::new((0, 0).into(), (1920, 1080).into()));
+    output.with_state_mut(|state| {
+        let tag = Tag::new("1".to_string());
+        tag.set_active(true);
+        state.add_tags([tag]);
+    });
+    fixture.pinnacle().focus_output(&output);
+
+    let snowcap = Snowcap::start(&mut fixture);
+
+    fixture
+        .runtime_handle()
+        .block_on(pinnacle_api::connect())
+        .unwrap();
+
+    (fixture, output, snowcap)
+}
+
+/// A layer widget with nothing in it.
+struct Empty;
+
+impl Program for Empty {
+    type Message = ();
+
+    fn update(&mut self, _msg: ()) {}
+
+    fn view(&self) -> Option<WidgetDef<()>> {
+        Some(Row::new().into())
+    }
+}
+
+/// Dispatches until the state Snowcap last committed for its layer surface
+/// on `output` matches `until`.
+fn wait_for_layer(
+    output: &Output,
+    fixture: &mut Fixture,
+    until: impl Fn(&LayerSurfaceCachedState) -> bool,
+) {
+    fixture.dispatch_until(|_| {
+        layer_map_for_output(output)
+            .layers()
+            .any(|layer| until(&layer.cached_state()))
+    });
+}
+
+#[test_log::test]
+fn layer_widget_sets_layer_exclusive_zone_and_margin() {
+    let (mut fixture, output, snowcap) = set_up_with_snowcap();
+
+    let layer = fixture.spawn_blocking(|| {
+        snowcap_api::layer::new_widget(
+            Empty,
+            Some(snowcap_api::layer::Anchor::Top),
+            KeyboardInteractivity::None,
+            ExclusiveZone::Exclusive(NonZeroU32::new(24).unwrap()),
+            ZLayer::Overlay,
+        )
+        .unwrap()
+    });
+
+    wait_for_layer(&output, &mut fixture, |state| {
+        state.anchor.contains(wlr_layer::Anchor::TOP)
+    });
+
+    let state = layer_map_for_output(&output)
+        .layers()
+        .next()
+        .unwrap()
+        .cached_state();
+    assert_eq!(state.layer, wlr_layer::Layer::Overlay);
+    assert_eq!(
+        state.exclusive_zone,
+        wlr_layer::ExclusiveZone::Exclusive(24)
+    );
+    assert_eq!(state.margin, wlr_layer::Margins::default());
+
+    let margin = Margin {
+        top: 8,
+        right: 0,
+        bottom: 0,
+        left: 16,
+    };
+    fixture.spawn_blocking({
+        let layer = layer.clone();
+        move || layer.set_margin(margin).unwrap()
+    });
+    wait_for_layer(&output, &mut fixture, |state| {
+        state.margin
+            == wlr_layer::Margins {
+                top: 8,
+                right: 0,
+                bottom: 0,
+                left: 16,
+            }
+    });
+
+    fixture.spawn_blocking({
+        let layer = layer.clone();
+        move || {
+            layer.set_layer(ZLayer::Bottom).unwrap();
+            layer.set_exclusive_zone(ExclusiveZone::Ignore).unwrap();
+        }
+    });
+    wait_for_layer(&output, &mut fixture, |state| {
+        state.layer == wlr_layer::Layer::Bottom
+            && state.exclusive_zone == wlr_layer::ExclusiveZone::DontCare
+    });
+
+    // Updating one option leaves the others alone
+    let state = layer_map_for_output(&output)
+        .layers()
+        .next()
+        .unwrap()
+        .cached_state();
+    assert_eq!(state.margin.left, 16);
+
+    fixture.spawn_blocking(move || layer.close());
+    snowcap.stop(&mut fixture);
+}
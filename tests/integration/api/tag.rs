@@ -1,12 +1,18 @@
 use std::{
     collections::{HashMap, HashSet},
     sync::{Arc, Mutex, OnceLock},
+    time::Duration,
 };
 
 use indexmap::IndexSet;
 use mlua::{UserData, UserDataMethods};
-use pinnacle::{state::WithState, tag::Tag};
-use pinnacle_api::{layout::LayoutNode, output::OutputHandle, signal::TagSignal, tag::TagHandle};
+use pinnacle::{render::util::effect, state::WithState, tag::Tag};
+use pinnacle_api::{
+    layout::{LayoutGenerator as _, LayoutNode, generators::MasterStack},
+    output::OutputHandle,
+    signal::TagSignal,
+    tag::{EffectTarget, TagHandle, WindowEffect},
+};
 use proptest::prelude::*;
 use smithay::{output::Output, utils::Rectangle};
 
@@ -300,11 +306,12 @@ proptest! {
                             }
 
                             for tag in target_tags {
-                                assert_eq!(tag.output(), target_output, "tag not on target output");
+                                assert_eq!(tag.output(), Some(target_output.clone()), "tag not on target output");
                             }
                         }
                         Err(err) => match err {
                             pinnacle_api::tag::MoveToOutputError::OutputDoesNotExist => unreachable!(),
+                            pinnacle_api::tag::MoveToOutputError::RequestFailed(_) => unreachable!(),
                             pinnacle_api::tag::MoveToOutputError::SameWindowOnTwoOutputs(window_handles) => {
                                 assert_eq!(window_handles.len(), 1, "win not on two ops");
                                 let win = &window_handles[0];
@@ -370,6 +377,38 @@ fn tag_handle_remove() {
     });
 }
 
+#[test_log::test]
+fn tag_handle_exists_after_remove() {
+    for_each_api(|lang| {
+        let (mut fixture, ..) = set_up();
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(move || {
+                let tag = pinnacle_api::output::get_focused()
+                    .unwrap()
+                    .tags()
+                    .next()
+                    .unwrap();
+
+                assert!(tag.exists());
+                tag.remove();
+                assert!(!tag.exists());
+                assert_eq!(tag.output(), None);
+                assert_eq!(tag.name(), None);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                local tag = Output.get_focused():tags()[1]
+                assert(tag:exists())
+                tag:remove()
+                assert(not tag:exists())
+                assert(tag:output() == nil)
+                assert(tag:name() == nil)
+            },
+        }
+    });
+}
+
 #[test_log::test]
 fn tag_handle_switch_to() {
     for_each_api(|lang| {
@@ -399,6 +438,251 @@ fn tag_handle_switch_to() {
     });
 }
 
+#[test_log::test]
+fn tag_switch_to_index() {
+    for_each_api(|lang| {
+        let (mut fixture, output1, output2, ..) = set_up();
+        let output2_name = output2.name();
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(move || {
+                let tag = pinnacle_api::tag::switch_to_index(None, 2);
+                assert_eq!(tag, pinnacle_api::tag::get("3"));
+
+                let output2 = pinnacle_api::output::get_by_name(output2_name).unwrap();
+                let tag = pinnacle_api::tag::switch_to_index(Some(&output2), 1);
+                assert_eq!(tag.and_then(|tag| tag.name()).as_deref(), Some("5"));
+
+                assert_eq!(pinnacle_api::tag::switch_to_index(None, 3), None);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                local tag = Tag.switch_to_index(3)
+                assert(tag.id == Tag.get("3").id)
+
+                local tag = Tag.switch_to_index(2, Output.get_by_name($output2_name))
+                assert(tag:name() == "5")
+
+                assert(Tag.switch_to_index(4) == nil)
+            },
+        }
+
+        output1.with_state(|state| {
+            assert!(!state.tags[0].active());
+            assert!(!state.tags[1].active());
+            assert!(state.tags[2].active());
+        });
+        output2.with_state(|state| {
+            assert!(!state.tags[0].active());
+            assert!(state.tags[1].active());
+            assert!(!state.tags[2].active());
+        });
+    });
+}
+
+#[test_log::test]
+fn tag_switch_to_name() {
+    for_each_api(|lang| {
+        let (mut fixture, output, ..) = set_up();
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(move || {
+                let tag = pinnacle_api::tag::switch_to_name(None, "2");
+                assert_eq!(tag, pinnacle_api::tag::get("2"));
+
+                assert_eq!(pinnacle_api::tag::switch_to_name(None, "nope"), None);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                local tag = Tag.switch_to_name("2")
+                assert(tag.id == Tag.get("2").id)
+
+                assert(Tag.switch_to_name("nope") == nil)
+            },
+        }
+
+        output.with_state(|state| {
+            assert!(!state.tags[0].active());
+            assert!(state.tags[1].active());
+            assert!(!state.tags[2].active());
+        });
+    });
+}
+
+#[test_log::test]
+fn tag_switch_to_previous_flips_between_tag_sets() {
+    for_each_api(|lang| {
+        let (mut fixture, output, ..) = set_up();
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(move || {
+                assert!(pinnacle_api::tag::switch_to_previous(None).is_empty());
+
+                pinnacle_api::tag::get("2").unwrap().switch_to();
+                pinnacle_api::tag::get("3").unwrap().set_active(true);
+
+                let previous = pinnacle_api::tag::switch_to_previous(None);
+                assert_eq!(previous, vec![pinnacle_api::tag::get("2").unwrap()]);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                assert(#Tag.switch_to_previous() == 0)
+
+                Tag.get("2"):switch_to()
+                Tag.get("3"):set_active(true)
+
+                local previous = Tag.switch_to_previous()
+                assert(#previous == 1)
+                assert(previous[1].id == Tag.get("2").id)
+            },
+        }
+
+        output.with_state(|state| {
+            assert!(!state.tags[0].active());
+            assert!(state.tags[1].active());
+            assert!(!state.tags[2].active());
+        });
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(move || {
+                pinnacle_api::tag::switch_to_previous(None);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Tag.switch_to_previous()
+            },
+        }
+
+        output.with_state(|state| {
+            assert!(!state.tags[0].active());
+            assert!(state.tags[1].active());
+            assert!(state.tags[2].active());
+        });
+    });
+}
+
+#[test_log::test]
+fn tag_urgency_is_cleared_when_activated() {
+    for_each_api(|lang| {
+        let (mut fixture, output, ..) = set_up();
+
+        output.with_state(|state| {
+            state.tags[1].set_urgent(true);
+        });
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(move || {
+                let tag = pinnacle_api::tag::get("2").unwrap();
+                assert!(tag.urgent());
+                assert!(!pinnacle_api::tag::get("1").unwrap().urgent());
+
+                tag.switch_to();
+                assert!(!tag.urgent());
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                local tag = Tag.get("2")
+                assert(tag:urgent())
+                assert(not Tag.get("1"):urgent())
+
+                tag:switch_to()
+                assert(not tag:urgent())
+            },
+        }
+
+        output.with_state(|state| {
+            assert!(!state.tags[1].urgent());
+        });
+    });
+}
+
+#[test_log::test]
+fn tag_empty_output_policy_fallback() {
+    for_each_api(|lang| {
+        let (mut fixture, output, ..) = set_up();
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(move || {
+                pinnacle_api::tag::set_empty_output_policy(
+                    pinnacle_api::tag::EmptyOutputPolicy::Fallback("3".into()),
+                );
+                pinnacle_api::tag::get("1").unwrap().set_active(false);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Tag.set_empty_output_policy("fallback", "3")
+                Tag.get("1"):set_active(false)
+            },
+        }
+
+        fixture.dispatch();
+
+        output.with_state(|state| {
+            assert!(!state.tags[0].active());
+            assert!(!state.tags[1].active());
+            assert!(state.tags[2].active());
+        });
+    });
+}
+
+#[test_log::test]
+fn tag_empty_output_policy_reactivate_last() {
+    for_each_api(|lang| {
+        let (mut fixture, output, ..) = set_up();
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(move || {
+                pinnacle_api::tag::set_empty_output_policy(
+                    pinnacle_api::tag::EmptyOutputPolicy::ReactivateLast,
+                );
+                pinnacle_api::tag::get("2").unwrap().switch_to();
+                pinnacle_api::tag::get("2").unwrap().set_active(false);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Tag.set_empty_output_policy("reactivate_last")
+                Tag.get("2"):switch_to()
+                Tag.get("2"):set_active(false)
+            },
+        }
+
+        fixture.dispatch();
+
+        output.with_state(|state| {
+            assert!(!state.tags[0].active());
+            assert!(state.tags[1].active());
+            assert!(!state.tags[2].active());
+        });
+    });
+}
+
+#[test_log::test]
+fn tag_handle_peek() {
+    for_each_api(|lang| {
+        let (mut fixture, output, _, tags, _) = set_up();
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(move || {
+                pinnacle_api::tag::get("2")
+                    .unwrap()
+                    .peek(std::time::Duration::from_millis(200));
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Tag.get("2"):peek(200)
+            },
+        }
+
+        output.with_state(|state| {
+            assert_eq!(state.peek.as_ref().map(|peek| &peek.tag), Some(&tags[1]));
+            assert!(state.tags[0].active());
+            assert!(!state.tags[1].active());
+        });
+
+        fixture.dispatch_until(|_| output.with_state(|state| state.peek.is_none()));
+    });
+}
+
 #[test_log::test]
 fn tag_handle_set_active() {
     for_each_api(|lang| {
@@ -478,9 +762,18 @@ fn tag_handle_name() {
     let (mut fixture, ..) = set_up();
 
     fixture.spawn_blocking(move || {
-        assert_eq!(pinnacle_api::tag::get("1").unwrap().name(), "1");
-        assert_eq!(pinnacle_api::tag::get("2").unwrap().name(), "2");
-        assert_eq!(pinnacle_api::tag::get("3").unwrap().name(), "3");
+        assert_eq!(
+            pinnacle_api::tag::get("1").unwrap().name().as_deref(),
+            Some("1")
+        );
+        assert_eq!(
+            pinnacle_api::tag::get("2").unwrap().name().as_deref(),
+            Some("2")
+        );
+        assert_eq!(
+            pinnacle_api::tag::get("3").unwrap().name().as_deref(),
+            Some("3")
+        );
     });
 
     spawn_lua_blocking! {
@@ -500,14 +793,14 @@ fn tag_handle_output() {
         let output2_name = output2.name();
         move || {
             let tag = pinnacle_api::tag::get("1").unwrap();
-            assert_eq!(tag.output().name(), output1_name);
+            assert_eq!(tag.output().unwrap().name(), output1_name);
 
             let tag = pinnacle_api::tag::get_on_output(
                 "4",
                 &pinnacle_api::output::get_by_name(&output2_name).unwrap(),
             )
             .unwrap();
-            assert_eq!(tag.output().name(), output2_name);
+            assert_eq!(tag.output().unwrap().name(), output2_name);
         }
     });
 
@@ -555,6 +848,87 @@ fn tag_handle_windows() {
     }
 }
 
+#[test_log::test]
+fn tag_add_effect_rule() {
+    for_each_api(|lang| {
+        let (mut fixture, _, _, tags, _) = set_up();
+
+        fixture.spawn_blocking(|| {
+            pinnacle_api::layout::manage(|_| pinnacle_api::layout::LayoutResponse {
+                root_node: LayoutNode::new(),
+                tree_id: 0,
+            })
+        });
+
+        let id = fixture.add_client();
+        fixture.spawn_windows(1, id);
+        let window = fixture.pinnacle().windows[0].clone();
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::tag::add_effect_rule(
+                    &pinnacle_api::tag::get("2").unwrap(),
+                    EffectTarget::OtherWindows,
+                    WindowEffect {
+                        opacity: 0.5,
+                        dim: 0.25,
+                        ..Default::default()
+                    },
+                );
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Tag.add_effect_rule({
+                    tag = Tag.get("2"),
+                    target = "other_windows",
+                    opacity = 0.5,
+                    dim = 0.25,
+                })
+            },
+        }
+
+        fixture.dispatch();
+        assert_eq!(window.with_state(|state| state.tag_effect), None);
+
+        tags[1].set_active(true);
+        fixture.dispatch();
+        assert_eq!(
+            window.with_state(|state| state.tag_effect),
+            Some(effect::WindowEffect {
+                opacity: 0.5,
+                dim: 0.25,
+                desaturate: 0.0,
+            })
+        );
+
+        tags[1].set_active(false);
+        fixture.dispatch();
+        assert_eq!(window.with_state(|state| state.tag_effect), None);
+    });
+}
+
+#[test_log::test]
+fn tag_effect_rules_are_cleared_after_config_reload() {
+    let (mut fixture, ..) = set_up();
+
+    fixture.spawn_blocking(|| {
+        pinnacle_api::tag::add_effect_rule(
+            &pinnacle_api::tag::get("1").unwrap(),
+            EffectTarget::WindowsOnTag,
+            WindowEffect {
+                opacity: 0.5,
+                ..Default::default()
+            },
+        );
+    });
+
+    assert_eq!(fixture.pinnacle().config.tag_effect_rules.len(), 1);
+
+    fixture.spawn_blocking(pinnacle_api::pinnacle::reload_config);
+
+    assert!(fixture.pinnacle().config.tag_effect_rules.is_empty());
+}
+
 #[test_log::test]
 fn tag_get_all_does_not_return_tags_cleared_after_config_reload() {
     for_each_api(|lang| {
@@ -746,6 +1120,148 @@ fn tag_signal_active() {
     })
 }
 
+#[test_log::test]
+fn tag_signal_callback_panic_is_isolated() {
+    let (mut fixture, _o1, _o2, tags, ..) = set_up();
+
+    let tag_name = tags[1].name();
+
+    let activations = Arc::new(Mutex::new(Vec::new()));
+    let panics = Arc::new(Mutex::new(Vec::new()));
+    let handles = Arc::new(OnceLock::new());
+
+    let activations_clone = activations.clone();
+    let panics_clone = panics.clone();
+    let handles_clone = handles.clone();
+
+    fixture.spawn_blocking(move || {
+        let panicked = pinnacle_api::pinnacle::connect_signal(
+            pinnacle_api::signal::ConfigSignal::CallbackPanicked(Box::new(
+                move |signal, message| {
+                    panics_clone
+                        .lock()
+                        .unwrap()
+                        .push((signal.to_string(), message.to_string()));
+                },
+            )),
+        );
+        let panicking =
+            pinnacle_api::tag::connect_signal(TagSignal::Active(Box::new(|_, _| panic!("oh no"))));
+        let active =
+            pinnacle_api::tag::connect_signal(TagSignal::Active(Box::new(move |tag, active| {
+                activations_clone
+                    .lock()
+                    .unwrap()
+                    .push((tag.clone(), active));
+            })));
+        handles_clone.set([panicked, panicking, active]).unwrap();
+
+        pinnacle_api::tag::get(tag_name).unwrap().switch_to();
+    });
+
+    fixture.dispatch_until(|_| activations.lock().unwrap().len() == 2);
+    fixture.dispatch_until(|_| panics.lock().unwrap().len() == 2);
+
+    assert!(
+        activations
+            .lock()
+            .unwrap()
+            .contains(&(TagHandle::from_id(tags[1].id().to_inner()), true))
+    );
+    assert!(
+        panics
+            .lock()
+            .unwrap()
+            .iter()
+            .all(|(signal, message)| signal == "TagActive" && message == "oh no")
+    );
+
+    for handle in handles.get().unwrap() {
+        handle.disconnect();
+    }
+}
+
+#[test_log::test]
+fn callback_panicked_callback_can_connect_signals() {
+    let (mut fixture, _o1, _o2, tags, ..) = set_up();
+
+    let tag_name = tags[1].name();
+
+    let nested_handles = Arc::new(Mutex::new(Vec::new()));
+    let handles = Arc::new(OnceLock::new());
+
+    let nested_handles_clone = nested_handles.clone();
+    let handles_clone = handles.clone();
+
+    fixture.spawn_blocking(move || {
+        let panicked = pinnacle_api::pinnacle::connect_signal(
+            pinnacle_api::signal::ConfigSignal::CallbackPanicked(Box::new(move |_, _| {
+                // Connecting to the signal being reported used to deadlock
+                let handle = pinnacle_api::pinnacle::connect_signal(
+                    pinnacle_api::signal::ConfigSignal::CallbackPanicked(Box::new(|_, _| ())),
+                );
+                nested_handles_clone.lock().unwrap().push(handle);
+            })),
+        );
+        let panicking =
+            pinnacle_api::tag::connect_signal(TagSignal::Active(Box::new(|_, _| panic!("oh no"))));
+        handles_clone.set([panicked, panicking]).unwrap();
+
+        pinnacle_api::tag::get(tag_name).unwrap().switch_to();
+    });
+
+    fixture.dispatch_until(|_| nested_handles.lock().unwrap().len() == 2);
+
+    for handle in handles.get().unwrap() {
+        handle.disconnect();
+    }
+    for handle in nested_handles.lock().unwrap().iter() {
+        handle.disconnect();
+    }
+}
+
+#[test_log::test]
+fn tag_signal_callback_panic_does_not_end_config() {
+    static PANICS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+    static ACTIVATIONS: Mutex<Vec<bool>> = Mutex::new(Vec::new());
+
+    async fn config() {
+        pinnacle_api::pinnacle::connect_signal(
+            pinnacle_api::signal::ConfigSignal::CallbackPanicked(Box::new(|_, message| {
+                PANICS.lock().unwrap().push(message.to_string());
+            })),
+        );
+        pinnacle_api::tag::connect_signal(TagSignal::Active(Box::new(|_, _| panic!("oh no"))));
+        pinnacle_api::tag::connect_signal(TagSignal::Active(Box::new(|_, active| {
+            ACTIVATIONS.lock().unwrap().push(active);
+        })));
+
+        pinnacle_api::tag::get("2").unwrap().switch_to();
+    }
+
+    let (mut fixture, ..) = set_up();
+
+    // `config!` installs the panic hook that ends the config on panics it isn't told to ignore
+    let config = fixture
+        .runtime_handle()
+        .spawn(async { pinnacle_api::config!(config) });
+
+    fixture.dispatch_until(|_| ACTIVATIONS.lock().unwrap().len() == 2);
+    fixture.dispatch_until(|_| PANICS.lock().unwrap().len() == 2);
+    fixture.dispatch_for(Duration::from_millis(100));
+
+    assert!(!config.is_finished());
+    assert!(
+        PANICS
+            .lock()
+            .unwrap()
+            .iter()
+            .all(|message| message == "oh no")
+    );
+
+    config.abort();
+}
+
 #[test_log::test]
 fn tag_signal_created() {
     for_each_api(|lang| {
@@ -874,3 +1390,174 @@ fn tag_signal_removed() {
         }
     });
 }
+
+#[test_log::test]
+fn tag_handle_move_to_index() {
+    for_each_api(|lang| {
+        let (mut fixture, output, _, tags, _) = set_up();
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(move || {
+                pinnacle_api::tag::get("3").unwrap().move_to_index(0);
+                pinnacle_api::tag::get("1").unwrap().move_to_index(10);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Tag.get("3"):move_to_index(1)
+                Tag.get("1"):move_to_index(11)
+            },
+        }
+
+        output.with_state(|state| {
+            let order = state.tags.iter().cloned().collect::<Vec<_>>();
+            assert_eq!(order, [tags[2].clone(), tags[1].clone(), tags[0].clone()]);
+        });
+    });
+}
+
+#[test_log::test]
+fn workspaces_switch_and_cycle() {
+    let (mut fixture, output, ..) = set_up();
+
+    fixture.spawn_blocking(|| {
+        use pinnacle_api::workspaces;
+
+        assert_eq!(workspaces::current(), Some(1));
+
+        workspaces::switch_to(3);
+        assert_eq!(workspaces::current(), Some(3));
+
+        workspaces::next();
+        assert_eq!(workspaces::current(), Some(1));
+
+        workspaces::prev();
+        assert_eq!(workspaces::current(), Some(3));
+
+        // Switching to the shown workspace goes back to the last one
+        workspaces::switch_to_or_back(3);
+        assert_eq!(workspaces::current(), Some(1));
+    });
+
+    output.with_state(|state| {
+        assert!(state.tags[0].active());
+        assert!(!state.tags[1].active());
+        assert!(!state.tags[2].active());
+    });
+}
+
+#[test_log::test]
+fn tag_pager_stream_updates_after_relevant_changes() {
+    use tokio_stream::StreamExt;
+
+    let (mut fixture, _, _, tags1, _) = set_up();
+
+    fixture.spawn_blocking(|| {
+        pinnacle_api::layout::manage(|args| pinnacle_api::layout::LayoutResponse {
+            root_node: MasterStack::default().layout(args.window_count),
+            tree_id: 0,
+        });
+    });
+
+    let client_id = fixture.add_client();
+    let surface = fixture.spawn_windows(1, client_id).remove(0);
+
+    let mut pager = fixture.spawn_blocking(pinnacle_api::pager::stream);
+    let handle = fixture.runtime_handle();
+
+    let tag_active = |pager: &pinnacle_api::pager::Pager, name: &str| {
+        pager.outputs[0]
+            .tags
+            .iter()
+            .find(|tag| tag.name == name)
+            .unwrap()
+            .active
+    };
+
+    fixture.dispatch();
+    let state = handle.block_on(pager.next()).unwrap();
+    assert!(!tag_active(&state, "2"));
+    assert_eq!(state.outputs[0].tags[0].windows.len(), 1);
+
+    // Changes the pager isn't told about aren't picked up
+    tags1[1].set_active(true);
+    fixture.dispatch();
+    let next = handle
+        .block_on(async { tokio::time::timeout(Duration::from_millis(100), pager.next()).await });
+    assert!(next.is_err());
+
+    fixture.pinnacle().pager_state.mark_dirty();
+    fixture.dispatch();
+    let state = handle.block_on(pager.next()).unwrap();
+    assert!(tag_active(&state, "2"));
+
+    fixture
+        .client(client_id)
+        .window_for_surface(&surface)
+        .set_title("renamed");
+    fixture.roundtrip(client_id);
+    fixture.dispatch();
+    let state = handle.block_on(pager.next()).unwrap();
+    assert_eq!(state.outputs[0].tags[0].windows[0].title, "renamed");
+
+    fixture.client(client_id).close_window(&surface);
+    fixture.roundtrip(client_id);
+    fixture.dispatch();
+    let state = handle.block_on(pager.next()).unwrap();
+    assert!(state.outputs[0].tags[0].windows.is_empty());
+    assert!(!state.outputs[0].tags[0].occupied);
+}
+
+#[test_log::test]
+fn tag_pager_reports_window_geometry_relative_to_output() {
+    use tokio_stream::StreamExt;
+
+    let (mut fixture, _, output2, _, tags2) = set_up();
+
+    fixture.spawn_blocking(|| {
+        pinnacle_api::layout::manage(|args| pinnacle_api::layout::LayoutResponse {
+            root_node: MasterStack::default().layout(args.window_count),
+            tree_id: 0,
+        });
+    });
+
+    fixture.pinnacle().focus_output(&output2);
+    let client_id = fixture.add_client();
+    fixture.spawn_windows(1, client_id);
+
+    let window = fixture.pinnacle().windows[0].clone();
+    let geo = fixture.pinnacle().space.element_geometry(&window).unwrap();
+    let expected = pinnacle_api::util::Rect {
+        loc: pinnacle_api::util::Point {
+            x: geo.loc.x - 1920,
+            y: geo.loc.y,
+        },
+        size: pinnacle_api::util::Size {
+            w: geo.size.w as u32,
+            h: geo.size.h as u32,
+        },
+    };
+
+    let mut pager = fixture.spawn_blocking(pinnacle_api::pager::stream);
+    let handle = fixture.runtime_handle();
+
+    fixture.dispatch();
+    let state = handle.block_on(pager.next()).unwrap();
+    let output = &state.outputs[1];
+    assert_eq!(output.size, pinnacle_api::util::Size { w: 1920, h: 1080 });
+    assert_eq!(output.tags[0].windows[0].geometry, Some(expected));
+
+    // Windows on inactive tags are shown where they last were
+    fixture.spawn_blocking(|| {
+        pinnacle_api::tag::get("5").unwrap().switch_to();
+    });
+    let state = loop {
+        fixture.dispatch();
+        let state = handle.block_on(pager.next()).unwrap();
+        if !state.outputs[1].tags[0].active {
+            break state;
+        }
+    };
+    assert_eq!(state.outputs[1].tags[0].windows[0].geometry, Some(expected));
+
+    assert!(tags2[1].active());
+}
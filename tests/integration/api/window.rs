@@ -1,17 +1,38 @@
+use std::{
+    sync::{Arc, Mutex, OnceLock},
+    time::Duration,
+};
+
 use crate::{
-    common::{Lang, fixture::Fixture, for_each_api},
+    common::{Lang, client::ClientId, fixture::Fixture, for_each_api},
     spawn_lua_blocking,
 };
-use pinnacle::{focus::keyboard::KeyboardFocusTarget, state::WithState, tag::Tag};
+use mlua::{UserData, UserDataMethods};
+use pinnacle::{
+    focus::keyboard::KeyboardFocusTarget,
+    protocol::xdg_toplevel_icon::{IconImage, ToplevelIcon},
+    render::util::cache::{RenderPlanKey, WindowRenderPlan},
+    state::WithState,
+    tag::Tag,
+    window::window_state::MaximizeRequestPolicy,
+};
 use pinnacle_api::{
     layout::{LayoutGenerator as _, generators::MasterStack},
     output::OutputHandle,
+    signal::WindowSignal,
+    window::{FocusReason, WindowPropertiesChange},
 };
 use smithay::{
+    backend::input::ButtonState,
+    input::pointer::{ButtonEvent, CursorIcon, CursorImageStatus},
     output::Output,
-    reexports::wayland_protocols::xdg::decoration::zv1::server::zxdg_toplevel_decoration_v1,
-    utils::Rectangle,
+    reexports::{
+        wayland_protocols::xdg::decoration::zv1::server::zxdg_toplevel_decoration_v1,
+        wayland_server::Resource,
+    },
+    utils::{Rectangle, SERIAL_COUNTER},
 };
+use wayland_client::{Proxy, protocol::wl_surface::WlSurface};
 
 fn set_up() -> (Fixture, Output) {
     let mut fixture = Fixture::new();
@@ -93,6 +114,37 @@ fn window_get_focused() {
     }
 }
 
+#[test_log::test]
+fn window_get_previously_focused_and_focus_age() {
+    let (mut fixture, _) = set_up();
+
+    fixture.spawn_blocking(|| {
+        assert!(pinnacle_api::window::get_previously_focused().is_none());
+    });
+
+    let client_id = fixture.add_client();
+
+    fixture.spawn_windows(2, client_id);
+
+    fixture.spawn_blocking(|| {
+        let windows = pinnacle_api::window::get_all().collect::<Vec<_>>();
+        assert_eq!(
+            pinnacle_api::window::get_previously_focused(),
+            Some(windows[0].clone())
+        );
+        assert_eq!(windows[0].focus_age(), Some(1));
+        assert_eq!(windows[1].focus_age(), Some(0));
+    });
+
+    spawn_lua_blocking! {
+        fixture,
+        local windows = Window.get_all()
+        assert(Window.get_previously_focused().id == windows[1].id)
+        assert(windows[1]:focus_age() == 1)
+        assert(windows[2]:focus_age() == 0)
+    }
+}
+
 #[test_log::test]
 fn window_handle_close() {
     let (mut fixture, _) = set_up();
@@ -124,6 +176,36 @@ fn window_handle_close() {
     });
 }
 
+#[test_log::test]
+fn window_handle_exists_after_close() {
+    let (mut fixture, _) = set_up();
+
+    let client_id = fixture.add_client();
+
+    for_each_api(|lang| {
+        let surface = fixture.spawn_windows(1, client_id).remove(0);
+        let window_id = fixture.pinnacle().windows[0].with_state(|state| state.id.0);
+
+        fixture.client(client_id).close_window(&surface);
+        fixture.roundtrip(client_id);
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(move || {
+                let window = pinnacle_api::window::WindowHandle::from_id(window_id);
+                assert!(!window.exists());
+                assert_eq!(window.layout_mode(), None);
+                assert_eq!(window.layer(), None);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                local window = Window.handle.new($window_id)
+                assert(not window:exists())
+                assert(window:layer() == nil)
+            },
+        }
+    });
+}
+
 #[test_log::test]
 fn window_handle_set_geometry_floating() {
     for_each_api(|lang| {
@@ -155,6 +237,45 @@ fn window_handle_set_geometry_floating() {
     });
 }
 
+#[test_log::test]
+fn window_handle_set_size_constraints() {
+    for_each_api(|lang| {
+        let (mut fixture, _) = set_up();
+
+        let client_id = fixture.add_client();
+
+        let _surface = fixture.spawn_floating_window_with(client_id, (500, 500), |_| ());
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                let window = pinnacle_api::window::get_focused().unwrap();
+                window.set_size_constraints(pinnacle_api::window::SizeConstraints {
+                    max_size: Some(pinnacle_api::util::Size { w: 800, h: 800 }),
+                    ..pinnacle_api::window::SizeConstraints::keep_aspect_ratio(16, 9)
+                });
+                window.set_geometry(200, 300, 1000, 1000);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                local window = Window.get_focused()
+                window:set_size_constraints({
+                    max_size = { width = 800, height = 800 },
+                    aspect_ratio = 16 / 9,
+                })
+                window:set_geometry({ x = 200, y = 300, width = 1000, height = 1000 })
+            },
+        }
+
+        fixture.wait_client_configure(client_id);
+        fixture.flush();
+
+        let window = fixture.pinnacle().windows[0].clone();
+        let geo = fixture.pinnacle().space.element_geometry(&window).unwrap();
+
+        assert_eq!(geo, Rectangle::new((200, 300).into(), (800, 450).into()));
+    });
+}
+
 #[test_log::test]
 fn window_handle_set_geometry_tiled_does_not_change_geometry() {
     for_each_api(|lang| {
@@ -432,6 +553,272 @@ fn window_handle_toggle_maximized() {
     });
 }
 
+fn request_maximized(
+    fixture: &mut Fixture,
+    client_id: ClientId,
+    surface: &WlSurface,
+    maximized: bool,
+) {
+    let window = fixture.client(client_id).window_for_surface(surface);
+    if maximized {
+        window.set_maximized();
+    } else {
+        window.unset_maximized();
+    }
+    fixture.roundtrip(client_id);
+    fixture.wait_client_configure(client_id);
+    fixture.flush();
+}
+
+#[test_log::test]
+fn window_set_maximize_request_policy() {
+    for_each_api(|lang| {
+        let (mut fixture, _) = set_up();
+
+        let client_id = fixture.add_client();
+        let surface = fixture.spawn_windows(1, client_id).remove(0);
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::window::set_maximize_request_policy(
+                    pinnacle_api::window::MaximizeRequestPolicy::Ignore,
+                );
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Window.set_maximize_request_policy("ignore")
+            },
+        }
+
+        assert_eq!(
+            fixture.pinnacle().config.maximize_request_policy,
+            MaximizeRequestPolicy::Ignore
+        );
+
+        request_maximized(&mut fixture, client_id, &surface, true);
+
+        let window = fixture.pinnacle().windows[0].clone();
+        assert!(window.with_state(|state| state.layout_mode.is_tiled()));
+        assert!(
+            !fixture
+                .client(client_id)
+                .window_for_surface(&surface)
+                .maximized
+        );
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::window::set_maximize_request_policy(
+                    pinnacle_api::window::MaximizeRequestPolicy::FullscreenWithinTile,
+                );
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Window.set_maximize_request_policy("fullscreen_within_tile")
+            },
+        }
+
+        request_maximized(&mut fixture, client_id, &surface, true);
+
+        assert!(window.with_state(|state| state.layout_mode.is_tiled()));
+        assert!(window.with_state(|state| state.fullscreen_within_tile));
+    });
+}
+
+#[test_log::test]
+fn window_handle_set_maximize_request_policy() {
+    for_each_api(|lang| {
+        let (mut fixture, _) = set_up();
+
+        let client_id = fixture.add_client();
+        let surfaces = fixture.spawn_windows(2, client_id);
+
+        fixture.spawn_blocking(|| {
+            pinnacle_api::window::set_maximize_request_policy(
+                pinnacle_api::window::MaximizeRequestPolicy::Ignore,
+            );
+        });
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::window::get_focused()
+                    .unwrap()
+                    .set_maximize_request_policy(
+                        pinnacle_api::window::MaximizeRequestPolicy::FloatAndMaximize,
+                    );
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Window.get_focused():set_maximize_request_policy("float_and_maximize")
+            },
+        }
+
+        let focused = fixture
+            .pinnacle()
+            .keyboard_focus_stack
+            .current_focus()
+            .cloned()
+            .unwrap();
+        assert_eq!(
+            focused.with_state(|state| state.maximize_request_policy),
+            Some(MaximizeRequestPolicy::FloatAndMaximize)
+        );
+
+        for surface in &surfaces {
+            request_maximized(&mut fixture, client_id, surface, true);
+        }
+
+        // Only the focused window overrides the global policy
+        for window in fixture.pinnacle().windows.clone() {
+            let overridden = window == focused;
+            assert_eq!(
+                window.with_state(|state| state.layout_mode.is_maximized()),
+                overridden
+            );
+            assert_eq!(
+                window.with_state(|state| state.layout_mode.is_tiled()),
+                !overridden
+            );
+        }
+
+        for surface in &surfaces {
+            request_maximized(&mut fixture, client_id, surface, false);
+        }
+
+        assert!(focused.with_state(|state| state.layout_mode.is_floating()));
+    });
+}
+
+#[test_log::test]
+fn window_rule_maximize_request_policy() {
+    for_each_api(|lang| {
+        let (mut fixture, _) = set_up();
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                use pinnacle_api::window::{MaximizeRequestPolicy, WindowRule, WindowRuleStrategy};
+
+                pinnacle_api::window::set_window_rules(
+                    [WindowRule {
+                        name: "keep tiled".into(),
+                        app_id: Some("tiled".into()),
+                        maximize_request_policy: Some(MaximizeRequestPolicy::Ignore),
+                        ..Default::default()
+                    }],
+                    WindowRuleStrategy::Merge,
+                );
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Window.set_window_rules({
+                    { name = "keep tiled", app_id = "tiled", maximize_request_policy = "ignore" },
+                })
+            },
+        }
+
+        let client_id = fixture.add_client();
+        let tiled = fixture.spawn_window_with(client_id, |window| {
+            window.set_app_id("tiled");
+            window.set_maximized();
+        });
+        let other = fixture.spawn_window_with(client_id, |window| window.set_app_id("other"));
+
+        let windows = fixture.pinnacle().windows.clone();
+        let window_with = |app_id: &str| {
+            windows
+                .iter()
+                .find(|window| window.class().as_deref() == Some(app_id))
+                .unwrap()
+                .clone()
+        };
+
+        // The rule applies to maximize requests made before the window is mapped
+        let window = window_with("tiled");
+        assert_eq!(
+            window.with_state(|state| state.maximize_request_policy),
+            Some(MaximizeRequestPolicy::Ignore)
+        );
+        assert!(window.with_state(|state| state.layout_mode.is_tiled()));
+
+        request_maximized(&mut fixture, client_id, &tiled, true);
+        request_maximized(&mut fixture, client_id, &other, true);
+
+        assert!(window.with_state(|state| state.layout_mode.is_tiled()));
+        assert!(window_with("other").with_state(|state| state.layout_mode.is_maximized()));
+    });
+}
+
+#[test_log::test]
+fn window_handle_set_minimized() {
+    for_each_api(|lang| {
+        let (mut fixture, _) = set_up();
+
+        let client_id = fixture.add_client();
+
+        fixture.spawn_windows(2, client_id);
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::window::get_focused().unwrap().minimize();
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Window.get_focused():minimize()
+            },
+        }
+
+        fixture.dispatch();
+
+        let minimized = fixture
+            .pinnacle()
+            .windows
+            .iter()
+            .find(|win| win.with_state(|state| state.minimized))
+            .cloned()
+            .unwrap();
+
+        assert!(minimized.with_state(|state| !state.tags.is_empty()));
+        assert!(
+            !fixture
+                .pinnacle()
+                .space
+                .elements()
+                .any(|win| *win == minimized)
+        );
+
+        let minimized_id = minimized.with_state(|state| state.id.0);
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(move || {
+                let win = pinnacle_api::window::get_all()
+                    .find(|win| win.id() == minimized_id)
+                    .unwrap();
+                assert!(win.minimized());
+                win.set_minimized(false);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                for _, win in ipairs(Window.get_all()) do
+                    if win:minimized() then
+                        win:set_minimized(false)
+                    end
+                end
+            },
+        }
+
+        fixture.dispatch();
+
+        assert!(minimized.with_state(|state| !state.minimized));
+        assert!(
+            fixture
+                .pinnacle()
+                .space
+                .elements()
+                .any(|win| *win == minimized)
+        );
+    });
+}
+
 #[test_log::test]
 fn window_handle_set_floating() {
     for_each_api(|lang| {
@@ -601,51 +988,707 @@ fn window_handle_set_focused() {
 }
 
 #[test_log::test]
-fn window_handle_toggle_focused() {
-    for_each_api(|lang| {
-        let (mut fixture, _) = set_up();
+fn window_signal_focus_changed() {
+    let (mut fixture, _) = set_up();
 
-        let client_id = fixture.add_client();
+    let client_id = fixture.add_client();
+    let _surfaces = fixture.spawn_windows(2, client_id);
 
-        let _surfaces = fixture.spawn_windows(2, client_id);
+    let changes = Arc::new(Mutex::new(Vec::new()));
+    let handle = Arc::new(OnceLock::new());
 
-        let keyboard = fixture.pinnacle().seat.get_keyboard().unwrap();
+    let changes_clone = changes.clone();
+    let handle_clone = handle.clone();
 
-        match lang {
-            Lang::Rust => fixture.spawn_blocking(|| {
-                pinnacle_api::window::get_focused()
-                    .unwrap()
-                    .toggle_focused();
+    fixture.spawn_blocking(move || {
+        let signal_handle = pinnacle_api::window::connect_signal(WindowSignal::FocusChanged(
+            Box::new(move |window, change| {
+                changes_clone.lock().unwrap().push((window.clone(), change));
             }),
-            Lang::Lua => spawn_lua_blocking! {
-                fixture,
-                Window.get_focused():toggle_focused()
-            },
-        }
+        ));
+        handle_clone.set(signal_handle).unwrap();
 
-        fixture.wait_client_configure(client_id);
-        fixture.flush();
+        pinnacle_api::window::get_all()
+            .next()
+            .unwrap()
+            .set_focused(true);
+    });
 
-        assert_eq!(keyboard.current_focus(), None);
+    fixture.dispatch_until(|_| changes.lock().unwrap().len() == 1);
 
-        match lang {
-            Lang::Rust => fixture.spawn_blocking(|| {
-                pinnacle_api::window::get_all()
-                    .next()
-                    .unwrap()
-                    .toggle_focused();
-            }),
-            Lang::Lua => spawn_lua_blocking! {
-                fixture,
-                Window.get_all()[1]:toggle_focused()
-            },
-        }
+    fixture.spawn_blocking(|| {
+        pinnacle_api::window::get_all()
+            .nth(1)
+            .unwrap()
+            .set_focused(true);
+    });
 
-        fixture.wait_client_configure(client_id);
-        fixture.flush();
+    fixture.dispatch_until(|_| changes.lock().unwrap().len() == 2);
 
-        let first_window = fixture.pinnacle().windows[0].clone();
-        assert_eq!(
+    let changes = changes.lock().unwrap();
+    assert!(
+        changes
+            .iter()
+            .all(|(_, change)| change.reason == FocusReason::Keybind)
+    );
+    assert!(changes[0].1.serial < changes[1].1.serial);
+    assert_ne!(changes[0].0, changes[1].0);
+
+    handle.get().unwrap().disconnect();
+}
+
+#[test_log::test]
+fn window_signal_urgency_changed() {
+    let (mut fixture, _) = set_up();
+
+    let client_id = fixture.add_client();
+    let _surfaces = fixture.spawn_windows(2, client_id);
+
+    let changes = Arc::new(Mutex::new(Vec::new()));
+    let handle = Arc::new(OnceLock::new());
+
+    let changes_clone = changes.clone();
+    let handle_clone = handle.clone();
+
+    fixture.spawn_blocking(move || {
+        let signal_handle = pinnacle_api::window::connect_signal(WindowSignal::UrgencyChanged(
+            Box::new(move |window, demands_attention| {
+                changes_clone
+                    .lock()
+                    .unwrap()
+                    .push((window.clone(), demands_attention));
+            }),
+        ));
+        handle_clone.set(signal_handle).unwrap();
+
+        let unfocused = pinnacle_api::window::get_all()
+            .find(|win| !win.focused())
+            .unwrap();
+        unfocused.set_demands_attention(true);
+        assert!(unfocused.demands_attention());
+    });
+
+    fixture.dispatch_until(|_| changes.lock().unwrap().len() == 1);
+
+    let urgent = changes.lock().unwrap()[0].0.clone();
+    assert!(changes.lock().unwrap()[0].1);
+
+    fixture.spawn_blocking(move || {
+        urgent.set_focused(true);
+    });
+
+    fixture.dispatch_until(|_| changes.lock().unwrap().len() == 2);
+
+    let changes = changes.lock().unwrap();
+    assert_eq!(changes[0].0, changes[1].0);
+    assert!(!changes[1].1);
+    assert!(
+        fixture
+            .pinnacle()
+            .windows
+            .iter()
+            .all(|win| win.with_state(|state| !state.urgent))
+    );
+
+    handle.get().unwrap().disconnect();
+}
+
+#[test_log::test]
+fn window_signal_focused_title_changed() {
+    let (mut fixture, _) = set_up();
+
+    let client_id = fixture.add_client();
+    let first = fixture.spawn_window_with(client_id, |window| window.set_title("one"));
+    let second = fixture.spawn_window_with(client_id, |window| window.set_title("two"));
+
+    let changes = Arc::new(Mutex::new(Vec::new()));
+    let handle = Arc::new(OnceLock::new());
+
+    let changes_clone = changes.clone();
+    let handle_clone = handle.clone();
+
+    fixture.spawn_blocking(move || {
+        let signal_handle = pinnacle_api::window::connect_signal(
+            WindowSignal::FocusedTitleChanged(Box::new(move |window, title, _app_id| {
+                changes_clone
+                    .lock()
+                    .unwrap()
+                    .push((window.cloned(), title.to_string()));
+            })),
+        );
+        handle_clone.set(signal_handle).unwrap();
+
+        pinnacle_api::window::get_all()
+            .next()
+            .unwrap()
+            .set_focused(true);
+    });
+
+    fixture.dispatch_until(|_| changes.lock().unwrap().len() == 1);
+
+    // Only the focused window's title is sent
+    let window = fixture.client(client_id).window_for_surface(&first);
+    window.set_title("uno");
+    window.commit();
+    let window = fixture.client(client_id).window_for_surface(&second);
+    window.set_title("dos");
+    window.commit();
+    fixture.roundtrip(client_id);
+
+    fixture.dispatch_until(|_| changes.lock().unwrap().len() == 2);
+
+    // Refocusing the focused window doesn't change anything
+    fixture.spawn_blocking(|| {
+        pinnacle_api::window::get_all()
+            .next()
+            .unwrap()
+            .set_focused(true);
+        pinnacle_api::window::get_all()
+            .nth(1)
+            .unwrap()
+            .set_focused(true);
+    });
+
+    fixture.dispatch_until(|_| changes.lock().unwrap().len() == 3);
+    fixture.dispatch_for(Duration::from_millis(100));
+
+    let changes = changes.lock().unwrap();
+    let titles = changes
+        .iter()
+        .map(|(_, title)| title.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(titles, ["one", "uno", "dos"]);
+    assert_eq!(changes[0].0, changes[1].0);
+    assert_ne!(changes[1].0, changes[2].0);
+
+    handle.get().unwrap().disconnect();
+}
+
+#[test_log::test]
+fn window_focus_in_direction() {
+    for_each_api(|lang| {
+        let (mut fixture, _) = set_up();
+
+        let client_id = fixture.add_client();
+        fixture.spawn_windows(2, client_id);
+
+        let focused = fixture
+            .pinnacle()
+            .keyboard_focus_stack
+            .current_focus()
+            .cloned()
+            .unwrap();
+        let other = fixture
+            .pinnacle()
+            .windows
+            .iter()
+            .find(|win| **win != focused)
+            .cloned()
+            .unwrap();
+
+        let focused_x = fixture
+            .pinnacle()
+            .space
+            .element_geometry(&focused)
+            .unwrap()
+            .loc
+            .x;
+        let other_x = fixture
+            .pinnacle()
+            .space
+            .element_geometry(&other)
+            .unwrap()
+            .loc
+            .x;
+        let towards_other = if other_x < focused_x { "left" } else { "right" };
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(move || {
+                use pinnacle_api::util::Direction;
+
+                assert!(pinnacle_api::window::focus_in_direction(Direction::Up).is_none());
+
+                let dir = match towards_other {
+                    "left" => Direction::Left,
+                    _ => Direction::Right,
+                };
+                assert!(pinnacle_api::window::focus_in_direction(dir).is_some());
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                assert(Window.focus_in_direction("up") == nil)
+                assert(Window.focus_in_direction($towards_other) ~= nil)
+            },
+        }
+
+        assert_eq!(
+            fixture.pinnacle().keyboard_focus_stack.current_focus(),
+            Some(&other)
+        );
+    });
+}
+
+#[test_log::test]
+fn window_cycle() {
+    for_each_api(|lang| {
+        let (mut fixture, _) = set_up();
+
+        let client_id = fixture.add_client();
+        fixture.spawn_windows(3, client_id);
+
+        let focused = fixture
+            .pinnacle()
+            .keyboard_focus_stack
+            .current_focus()
+            .cloned()
+            .unwrap();
+        let previous = fixture
+            .pinnacle()
+            .keyboard_focus_stack
+            .previous_focus()
+            .cloned()
+            .unwrap();
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::window::begin_cycle();
+                assert!(pinnacle_api::window::cycle_next().is_some());
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Window.begin_cycle()
+                assert(Window.cycle_next() ~= nil)
+            },
+        }
+
+        assert_eq!(
+            fixture.pinnacle().keyboard_focus_stack.current_focus(),
+            Some(&focused)
+        );
+        assert_eq!(
+            fixture.pinnacle().window_cycle.as_ref().unwrap().current(),
+            Some(&previous)
+        );
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                assert!(pinnacle_api::window::end_cycle().is_some());
+                assert!(pinnacle_api::window::end_cycle().is_none());
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                assert(Window.end_cycle() ~= nil)
+                assert(Window.end_cycle() == nil)
+            },
+        }
+
+        assert_eq!(
+            fixture.pinnacle().keyboard_focus_stack.current_focus(),
+            Some(&previous)
+        );
+        assert!(fixture.pinnacle().window_cycle.is_none());
+    });
+}
+
+#[test_log::test]
+fn window_set_window_rules_orders_by_priority() {
+    for_each_api(|lang| {
+        let (mut fixture, _) = set_up();
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                use pinnacle_api::window::{LayoutMode, WindowRule, WindowRuleStrategy};
+
+                pinnacle_api::window::set_window_rules(
+                    [
+                        WindowRule {
+                            name: "tile".into(),
+                            priority: 10,
+                            layout_mode: Some(LayoutMode::Tiled),
+                            ..Default::default()
+                        },
+                        WindowRule {
+                            name: "firefox".into(),
+                            app_id: Some("firefox".into()),
+                            ..Default::default()
+                        },
+                        WindowRule {
+                            name: "float".into(),
+                            layout_mode: Some(LayoutMode::Floating),
+                            ..Default::default()
+                        },
+                    ],
+                    WindowRuleStrategy::Merge,
+                );
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Window.set_window_rules({
+                    { name = "tile", priority = 10, layout_mode = "tiled" },
+                    { name = "firefox", app_id = "firefox" },
+                    { name = "float", layout_mode = "floating" },
+                })
+            },
+        }
+
+        let client_id = fixture.add_client();
+        fixture.spawn_windows(1, client_id);
+
+        let window = fixture.pinnacle().windows[0].clone();
+        assert!(window.with_state(|state| state.layout_mode.is_tiled()));
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                use pinnacle_api::window::{LayoutMode, WindowRule, WindowRuleStrategy};
+
+                let window = pinnacle_api::window::get_all().next().unwrap();
+                assert_eq!(window.matching_window_rules(), ["float", "tile"]);
+
+                pinnacle_api::window::set_window_rules(
+                    [
+                        WindowRule {
+                            name: "tile".into(),
+                            priority: 10,
+                            layout_mode: Some(LayoutMode::Tiled),
+                            ..Default::default()
+                        },
+                        WindowRule {
+                            name: "float".into(),
+                            layout_mode: Some(LayoutMode::Floating),
+                            ..Default::default()
+                        },
+                    ],
+                    WindowRuleStrategy::LastMatchWins,
+                );
+                assert_eq!(window.matching_window_rules(), ["tile"]);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                local window = Window.get_all()[1]
+                local names = window:matching_window_rules()
+                assert(#names == 2)
+                assert(names[1] == "float")
+                assert(names[2] == "tile")
+
+                Window.set_window_rules({
+                    { name = "tile", priority = 10, layout_mode = "tiled" },
+                    { name = "float", layout_mode = "floating" },
+                }, "last_match_wins")
+
+                local names = window:matching_window_rules()
+                assert(#names == 1)
+                assert(names[1] == "tile")
+            },
+        }
+    });
+}
+
+#[test_log::test]
+fn window_apply_rules_to_existing() {
+    for_each_api(|lang| {
+        let (mut fixture, _) = set_up();
+
+        let client_id = fixture.add_client();
+        fixture.spawn_windows(1, client_id);
+
+        let window = fixture.pinnacle().windows[0].clone();
+        assert!(window.with_state(|state| state.layout_mode.is_tiled()));
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                use pinnacle_api::window::{
+                    LayoutMode, WindowRule, WindowRuleProperty, WindowRuleStrategy,
+                };
+
+                pinnacle_api::window::set_window_rules(
+                    [WindowRule {
+                        name: "float everything".into(),
+                        layout_mode: Some(LayoutMode::Floating),
+                        ..Default::default()
+                    }],
+                    WindowRuleStrategy::Merge,
+                );
+
+                let changes = pinnacle_api::window::apply_rules_to_existing();
+                assert_eq!(changes.len(), 1);
+                assert_eq!(changes[0].changed, [WindowRuleProperty::LayoutMode]);
+
+                assert!(pinnacle_api::window::apply_rules_to_existing().is_empty());
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Window.set_window_rules({
+                    { name = "float everything", layout_mode = "floating" },
+                })
+
+                local changes = Window.apply_rules_to_existing()
+                assert(#changes == 1)
+                assert(#changes[1].changed == 1)
+                assert(changes[1].changed[1] == "layout_mode")
+
+                assert(#Window.apply_rules_to_existing() == 0)
+            },
+        }
+
+        assert!(window.with_state(|state| state.layout_mode.is_floating()));
+    });
+}
+
+#[test_log::test]
+fn window_handle_set_input_enabled() {
+    for_each_api(|lang| {
+        let (mut fixture, _) = set_up();
+
+        let client_id = fixture.add_client();
+
+        let _surfaces = fixture.spawn_windows(2, client_id);
+
+        let keyboard = fixture.pinnacle().seat.get_keyboard().unwrap();
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::window::get_focused()
+                    .unwrap()
+                    .set_input_enabled(false);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Window.get_focused():set_input_enabled(false)
+            },
+        }
+
+        fixture.wait_client_configure(client_id);
+        fixture.flush();
+
+        let first_window = fixture.pinnacle().windows[0].clone();
+        let second_window = fixture.pinnacle().windows[1].clone();
+
+        assert!(!second_window.accepts_input());
+
+        // Keyboard focus falls through to the next window
+        assert_eq!(
+            keyboard.current_focus(),
+            Some(KeyboardFocusTarget::Window(first_window))
+        );
+    });
+}
+
+#[test_log::test]
+fn window_handle_set_hold_to_move_enabled() {
+    for_each_api(|lang| {
+        let (mut fixture, _) = set_up();
+
+        let client_id = fixture.add_client();
+        fixture.spawn_windows(1, client_id);
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::window::get_focused()
+                    .unwrap()
+                    .set_hold_to_move_enabled(false);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Window.get_focused():set_hold_to_move_enabled(false)
+            },
+        }
+
+        let window = fixture.pinnacle().windows[0].clone();
+        assert!(!window.with_state(|state| state.hold_to_move_enabled));
+    });
+}
+
+#[test_log::test]
+fn window_handle_snap_to_region() {
+    for_each_api(|lang| {
+        let (mut fixture, _) = set_up();
+
+        let client_id = fixture.add_client();
+        fixture.spawn_windows(1, client_id);
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::window::get_focused()
+                    .unwrap()
+                    .snap_to_region(pinnacle_api::window::Region::RightHalf);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Window.get_focused():snap_to_region("right_half")
+            },
+        }
+
+        let window = fixture.pinnacle().windows[0].clone();
+        assert!(window.with_state(|state| state.layout_mode.is_floating()));
+        assert_eq!(
+            window.with_state(|state| (state.floating_loc(), state.floating_size)),
+            (Some((960, 0).into()), (960, 1080).into())
+        );
+    });
+}
+
+#[test_log::test]
+fn window_handle_snap_to_grid_cell() {
+    for_each_api(|lang| {
+        let (mut fixture, _) = set_up();
+
+        let client_id = fixture.add_client();
+        fixture.spawn_windows(1, client_id);
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::window::set_snap_grid(4, 2);
+                pinnacle_api::window::get_focused().unwrap().snap_to_region(
+                    pinnacle_api::window::Region::GridCell {
+                        column: 1,
+                        row: 1,
+                        column_span: 2,
+                        row_span: 1,
+                    },
+                );
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Window.set_snap_grid(4, 2)
+                Window.get_focused():snap_to_region({ column = 1, row = 1, column_span = 2 })
+            },
+        }
+
+        let window = fixture.pinnacle().windows[0].clone();
+        assert_eq!(
+            window.with_state(|state| (state.floating_loc(), state.floating_size)),
+            (Some((480, 540).into()), (960, 540).into())
+        );
+    });
+}
+
+#[test_log::test]
+fn window_set_popup_policy() {
+    for_each_api(|lang| {
+        let (mut fixture, _) = set_up();
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::window::set_popup_policy(pinnacle_api::window::PopupPolicy {
+                    close_on_tag_switch: true,
+                    max_grab_duration: Some(std::time::Duration::from_secs(5)),
+                    ..Default::default()
+                });
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Window.set_popup_policy({ close_on_tag_switch = true, max_grab_duration = 5000 })
+            },
+        }
+
+        let policy = fixture.pinnacle().config.popup_policy;
+        assert!(!policy.clamp_to_output);
+        assert!(policy.close_on_tag_switch);
+        assert_eq!(
+            policy.max_grab_duration,
+            Some(std::time::Duration::from_secs(5))
+        );
+    });
+}
+
+#[test_log::test]
+fn window_handle_set_scale_override() {
+    for_each_api(|lang| {
+        let (mut fixture, _) = set_up();
+
+        let client_id = fixture.add_client();
+        let surface = fixture.spawn_windows(1, client_id).remove(0);
+
+        // Fractional scales are sent in 120ths
+        let preferred_scale = |fixture: &mut Fixture| {
+            fixture.roundtrip(client_id);
+            fixture
+                .client(client_id)
+                .window_for_surface(&surface)
+                .preferred_fractional_scale
+        };
+
+        assert_eq!(preferred_scale(&mut fixture), Some(120));
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::window::get_focused()
+                    .unwrap()
+                    .set_scale_override(1.5);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Window.get_focused():set_scale_override(1.5)
+            },
+        }
+
+        let window = fixture.pinnacle().windows[0].clone();
+        assert_eq!(window.with_state(|state| state.scale_override), Some(1.5));
+        assert_eq!(preferred_scale(&mut fixture), Some(180));
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::window::get_focused()
+                    .unwrap()
+                    .set_scale_override(None);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Window.get_focused():set_scale_override(nil)
+            },
+        }
+
+        assert_eq!(window.with_state(|state| state.scale_override), None);
+        assert_eq!(preferred_scale(&mut fixture), Some(120));
+    });
+}
+
+#[test_log::test]
+fn window_handle_toggle_focused() {
+    for_each_api(|lang| {
+        let (mut fixture, _) = set_up();
+
+        let client_id = fixture.add_client();
+
+        let _surfaces = fixture.spawn_windows(2, client_id);
+
+        let keyboard = fixture.pinnacle().seat.get_keyboard().unwrap();
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::window::get_focused()
+                    .unwrap()
+                    .toggle_focused();
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Window.get_focused():toggle_focused()
+            },
+        }
+
+        fixture.wait_client_configure(client_id);
+        fixture.flush();
+
+        assert_eq!(keyboard.current_focus(), None);
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::window::get_all()
+                    .next()
+                    .unwrap()
+                    .toggle_focused();
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Window.get_all()[1]:toggle_focused()
+            },
+        }
+
+        fixture.wait_client_configure(client_id);
+        fixture.flush();
+
+        let first_window = fixture.pinnacle().windows[0].clone();
+        assert_eq!(
             keyboard.current_focus(),
             Some(KeyboardFocusTarget::Window(first_window))
         );
@@ -821,6 +1864,55 @@ fn window_handle_move_to_tag() {
     });
 }
 
+#[test_log::test]
+fn window_handle_move_to_tag_and_follow() {
+    for_each_api(|lang| {
+        let (mut fixture, output) = set_up();
+        output.with_state_mut(|state| {
+            let tag2 = Tag::new("2".to_string());
+            let tag3 = Tag::new("3".to_string());
+            state.add_tags([tag2, tag3]);
+        });
+
+        let client_id = fixture.add_client();
+
+        fixture.spawn_windows(1, client_id);
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::window::get_focused()
+                    .unwrap()
+                    .move_to_tag_and_follow(&pinnacle_api::tag::get("2").unwrap());
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Window.get_focused():move_to_tag_and_follow(Tag.get('2'))
+            },
+        }
+
+        let window = fixture.pinnacle().windows[0].clone();
+        let tags = window.with_state(|state| state.tags.clone());
+
+        assert_eq!(tags.len(), 1);
+        assert_eq!(tags[0].name(), "2");
+        assert!(window.is_on_active_tag());
+
+        let active_tags = output.with_state(|state| {
+            state
+                .tags
+                .iter()
+                .filter(|tag| tag.active())
+                .map(|tag| tag.name())
+                .collect::<Vec<_>>()
+        });
+        assert_eq!(active_tags, ["2"]);
+        assert_eq!(
+            fixture.pinnacle().keyboard_focus_stack.current_focus(),
+            Some(&window)
+        );
+    });
+}
+
 #[test_log::test]
 fn window_handle_set_tag() {
     for_each_api(|lang| {
@@ -1022,45 +2114,281 @@ fn window_handle_raise() {
 }
 
 #[test_log::test]
-fn window_handle_lower() {
+fn window_handle_lower() {
+    for_each_api(|lang| {
+        let (mut fixture, _) = set_up();
+
+        let client_id = fixture.add_client();
+
+        fixture.spawn_windows(2, client_id);
+
+        let top = fixture
+            .pinnacle()
+            .z_index_stack
+            .last()
+            .unwrap()
+            .window()
+            .unwrap()
+            .clone();
+        let second = fixture.pinnacle().windows[1].clone();
+        assert_eq!(top, second);
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::window::get_all().nth(1).unwrap().lower();
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Window.get_all()[2]:lower()
+            },
+        }
+
+        let top = fixture
+            .pinnacle()
+            .z_index_stack
+            .last()
+            .unwrap()
+            .window()
+            .unwrap()
+            .clone();
+        let first = fixture.pinnacle().windows[0].clone();
+        assert_eq!(top, first);
+    });
+}
+
+#[test_log::test]
+fn window_handle_set_layer() {
+    for_each_api(|lang| {
+        let (mut fixture, _) = set_up();
+
+        let client_id = fixture.add_client();
+
+        fixture.spawn_windows(2, client_id);
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                let mut windows = pinnacle_api::window::get_all();
+                let first = windows.next().unwrap();
+                let second = windows.next().unwrap();
+                first.set_layer(pinnacle_api::window::WindowLayer::Above);
+                second.raise();
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Window.get_all()[1]:set_layer("above")
+                Window.get_all()[2]:raise()
+            },
+        }
+
+        let first = fixture.pinnacle().windows[0].clone();
+        let top = fixture
+            .pinnacle()
+            .z_index_stack
+            .last()
+            .unwrap()
+            .window()
+            .unwrap()
+            .clone();
+        assert_eq!(top, first);
+        assert_eq!(
+            first.with_state(|state| state.layer),
+            pinnacle::window::window_state::WindowLayer::Above
+        );
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                let first = pinnacle_api::window::get_all().next().unwrap();
+                assert_eq!(
+                    first.layer(),
+                    Some(pinnacle_api::window::WindowLayer::Above)
+                );
+                first.set_layer(pinnacle_api::window::WindowLayer::Below);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                assert(Window.get_all()[1]:layer() == "above")
+                Window.get_all()[1]:set_layer("below")
+            },
+        }
+
+        let bottom = fixture
+            .pinnacle()
+            .z_index_stack
+            .first()
+            .unwrap()
+            .window()
+            .unwrap()
+            .clone();
+        assert_eq!(bottom, first);
+    });
+}
+
+#[test_log::test]
+fn window_set_tag_inheritance() {
+    for_each_api(|lang| {
+        let (mut fixture, output) = set_up();
+
+        output.with_state_mut(|state| {
+            let tag = Tag::new("2".to_string());
+            tag.set_active(true);
+            state.add_tags([tag]);
+        });
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::window::set_tag_inheritance(
+                    pinnacle_api::window::TagInheritance::FirstActiveTag,
+                );
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Window.set_tag_inheritance("first_active_tag")
+            },
+        }
+
+        let client_id = fixture.add_client();
+        fixture.spawn_windows(1, client_id);
+
+        let tags = fixture.pinnacle().windows[0].with_state(|state| state.tags.clone());
+        assert_eq!(tags.len(), 1);
+        assert_eq!(tags[0].name(), "1");
+    });
+}
+
+#[test_log::test]
+fn window_set_dialog_policy_attach_to_parent() {
+    for_each_api(|lang| {
+        let (mut fixture, output) = set_up();
+
+        let client_id = fixture.add_client();
+        let parent_surface = fixture.spawn_windows(1, client_id).remove(0);
+        let parent_toplevel = fixture
+            .client(client_id)
+            .window_for_surface(&parent_surface)
+            .toplevel();
+
+        output.with_state_mut(|state| {
+            let tag = Tag::new("2".to_string());
+            tag.set_active(true);
+            state.add_tags([tag]);
+        });
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::window::set_dialog_policy(
+                    pinnacle_api::window::DialogPolicy::AttachToParent,
+                );
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Window.set_dialog_policy("attach_to_parent")
+            },
+        }
+
+        fixture.spawn_floating_window_with(client_id, (300, 200), |window| {
+            window.set_parent(Some(&parent_toplevel));
+        });
+
+        let parent = fixture.pinnacle().windows[0].clone();
+        let dialog = fixture.pinnacle().windows[1].clone();
+        assert_eq!(
+            dialog.with_state(|state| state.tags.clone()),
+            parent.with_state(|state| state.tags.clone())
+        );
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                let mut windows = pinnacle_api::window::get_all();
+                let parent = windows.next().unwrap();
+                let dialog = windows.next().unwrap();
+                assert_eq!(dialog.parent(), Some(parent.clone()));
+                assert_eq!(parent.parent(), None);
+                assert_eq!(parent.children().collect::<Vec<_>>(), vec![dialog]);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                local windows = Window.get_all()
+                assert(windows[2]:parent().id == windows[1].id)
+                assert(windows[1]:parent() == nil)
+                local children = windows[1]:children()
+                assert(#children == 1 and children[1].id == windows[2].id)
+            },
+        }
+    });
+}
+
+#[test_log::test]
+fn window_set_border_style() {
+    for_each_api(|lang| {
+        let (mut fixture, _) = set_up();
+
+        let client_id = fixture.add_client();
+        fixture.spawn_windows(2, client_id);
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::window::set_border_style(pinnacle_api::window::BorderStyle {
+                    width: 4,
+                    focused_color: pinnacle_api::util::Color::rgb(1.0, 0.0, 0.0),
+                    unfocused_color: pinnacle_api::util::Color::rgb(0.0, 0.0, 1.0),
+                    titlebar_height: 20,
+                    ..Default::default()
+                });
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Window.set_border_style({
+                    width = 4,
+                    focused_color = { red = 1.0, green = 0.0, blue = 0.0 },
+                    unfocused_color = { red = 0.0, green = 0.0, blue = 1.0 },
+                    titlebar_height = 20,
+                })
+            },
+        }
+
+        fixture.dispatch();
+
+        let focused = fixture
+            .pinnacle()
+            .keyboard_focus_stack
+            .current_focus()
+            .cloned()
+            .unwrap();
+
+        for window in fixture.pinnacle().windows.clone() {
+            let color = if window == focused {
+                [1.0, 0.0, 0.0, 1.0]
+            } else {
+                [0.0, 0.0, 1.0, 1.0]
+            };
+            assert_eq!(window.with_state(|state| state.border_color), Some(color));
+
+            let outer = window.geometry().size;
+            let inner = window.geometry_without_decorations().size;
+            assert_eq!((outer.w - inner.w, outer.h - inner.h), (8, 28));
+        }
+    });
+}
+
+#[test_log::test]
+fn render_set_close_snapshot_hold() {
     for_each_api(|lang| {
         let (mut fixture, _) = set_up();
 
-        let client_id = fixture.add_client();
-
-        fixture.spawn_windows(2, client_id);
-
-        let top = fixture
-            .pinnacle()
-            .z_index_stack
-            .last()
-            .unwrap()
-            .window()
-            .unwrap()
-            .clone();
-        let second = fixture.pinnacle().windows[1].clone();
-        assert_eq!(top, second);
-
         match lang {
             Lang::Rust => fixture.spawn_blocking(|| {
-                pinnacle_api::window::get_all().nth(1).unwrap().lower();
+                pinnacle_api::render::set_close_snapshot_hold(Duration::from_millis(500));
             }),
             Lang::Lua => spawn_lua_blocking! {
                 fixture,
-                Window.get_all()[2]:lower()
+                require("pinnacle.render").set_close_snapshot_hold(500)
             },
         }
 
-        let top = fixture
-            .pinnacle()
-            .z_index_stack
-            .last()
-            .unwrap()
-            .window()
-            .unwrap()
-            .clone();
-        let first = fixture.pinnacle().windows[0].clone();
-        assert_eq!(top, first);
+        assert_eq!(
+            fixture.pinnacle().config.close_snapshot_hold,
+            Duration::from_millis(500)
+        );
     });
 }
 
@@ -1223,6 +2551,50 @@ fn window_handle_title() {
     };
 }
 
+#[test_log::test]
+fn window_find() {
+    let (mut fixture, _) = set_up();
+
+    let client_id = fixture.add_client();
+
+    fixture.spawn_window_with(client_id, |win| {
+        win.set_title("the great mirror");
+    });
+    fixture.spawn_window_with(client_id, |win| {
+        win.set_app_id("terminal");
+    });
+
+    fixture.spawn_blocking(|| {
+        use pinnacle_api::window::{self, FindMode};
+
+        let found = window::find("term", FindMode::Fuzzy, None).unwrap();
+        assert_eq!(found.len(), 2);
+        assert_eq!(found[0].app_id(), "terminal");
+
+        let found = window::find("term", FindMode::Fuzzy, Some(1)).unwrap();
+        assert_eq!(found.len(), 1);
+
+        let found = window::find("^the", FindMode::Regex, None).unwrap();
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].title(), "the great mirror");
+
+        assert!(window::find("(unclosed", FindMode::Regex, None).is_err());
+    });
+
+    spawn_lua_blocking! {
+        fixture,
+        local found = Window.find("term")
+        assert(#found == 2)
+        assert(found[1]:app_id() == "terminal")
+
+        found = Window.find("^the", { mode = "regex" })
+        assert(#found == 1)
+
+        local _, err = Window.find("(unclosed", { mode = "regex" })
+        assert(err)
+    };
+}
+
 #[test_log::test]
 fn window_handle_focused() {
     let (mut fixture, _) = set_up();
@@ -1284,7 +2656,7 @@ fn window_handle_tiled() {
     fixture.spawn_blocking(move || {
         assert_eq!(
             pinnacle_api::window::get_focused().unwrap().layout_mode(),
-            pinnacle_api::window::LayoutMode::Tiled
+            Some(pinnacle_api::window::LayoutMode::Tiled)
         );
     });
     spawn_lua_blocking! {
@@ -1308,7 +2680,7 @@ fn window_handle_floating() {
     fixture.spawn_blocking(move || {
         assert_eq!(
             pinnacle_api::window::get_focused().unwrap().layout_mode(),
-            pinnacle_api::window::LayoutMode::Floating
+            Some(pinnacle_api::window::LayoutMode::Floating)
         );
     });
     spawn_lua_blocking! {
@@ -1338,7 +2710,7 @@ fn window_handle_fullscreen() {
     fixture.spawn_blocking(move || {
         assert_eq!(
             pinnacle_api::window::get_focused().unwrap().layout_mode(),
-            pinnacle_api::window::LayoutMode::Fullscreen
+            Some(pinnacle_api::window::LayoutMode::Fullscreen)
         );
     });
     spawn_lua_blocking! {
@@ -1368,7 +2740,7 @@ fn window_handle_maximized() {
     fixture.spawn_blocking(move || {
         assert_eq!(
             pinnacle_api::window::get_focused().unwrap().layout_mode(),
-            pinnacle_api::window::LayoutMode::Maximized
+            Some(pinnacle_api::window::LayoutMode::Maximized)
         );
     });
     spawn_lua_blocking! {
@@ -1542,6 +2914,70 @@ fn window_handle_swap_simple() {
     })
 }
 
+#[test_log::test]
+fn window_handle_swap_in_direction() {
+    for_each_api(|lang| {
+        let (mut fixture, _output1) = set_up();
+
+        let client_id = fixture.add_client();
+
+        let _surfaces = fixture.spawn_windows(2, client_id);
+
+        let window = fixture.pinnacle().windows[0].clone();
+        let target = fixture.pinnacle().windows[1].clone();
+
+        let window_id = window.with_state(|s| s.id.0);
+        let target_id = target.with_state(|s| s.id.0);
+
+        let window_geo = fixture.pinnacle().space.element_geometry(&window);
+        let target_geo = fixture.pinnacle().space.element_geometry(&target);
+
+        let towards_target = if target_geo.unwrap().loc.x < window_geo.unwrap().loc.x {
+            "left"
+        } else {
+            "right"
+        };
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(move || {
+                use pinnacle_api::util::Direction;
+
+                let window_handle = pinnacle_api::window::WindowHandle::from_id(window_id);
+
+                assert!(window_handle.swap_in_direction(Direction::Up).is_none());
+
+                let dir = match towards_target {
+                    "left" => Direction::Left,
+                    _ => Direction::Right,
+                };
+                assert_eq!(
+                    window_handle.swap_in_direction(dir).map(|win| win.id()),
+                    Some(target_id)
+                );
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                local window_handle = Window.handle.new($window_id)
+
+                assert(window_handle:swap_in_direction("up") == nil)
+                assert(window_handle:swap_in_direction($towards_target).id == $target_id)
+            },
+        };
+
+        fixture.wait_client_configure(client_id);
+        fixture.flush();
+
+        assert_eq!(
+            window_geo,
+            fixture.pinnacle().space.element_geometry(&target)
+        );
+        assert_eq!(
+            target_geo,
+            fixture.pinnacle().space.element_geometry(&window)
+        );
+    })
+}
+
 #[test_log::test]
 fn window_handle_swap_multi_output() {
     for_each_api(|lang| {
@@ -1948,7 +3384,402 @@ fn window_handle_swap_swap_inactive_tag_multi_output() {
     })
 }
 
-// TODO: window_begin_move
+/// A summary of a [`WindowPropertiesChange`], recorded in order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct WatchedChange {
+    title_set: bool,
+    tags_set: bool,
+    focused: Option<bool>,
+    fullscreen: bool,
+}
+
+#[derive(Clone, Default)]
+struct WatchLog(Arc<Mutex<Vec<WatchedChange>>>);
+
+impl WatchLog {
+    fn log(&self, change: WatchedChange) {
+        self.0.lock().unwrap().push(change);
+    }
+
+    fn changes(&self) -> Vec<WatchedChange> {
+        self.0.lock().unwrap().clone()
+    }
+}
+
+impl UserData for WatchLog {
+    fn add_methods<M: UserDataMethods<Self>>(methods: &mut M) {
+        methods.add_method(
+            "log",
+            |_, this, (title_set, tags_set, focused, fullscreen): (bool, bool, Option<bool>, bool)| {
+                this.log(WatchedChange {
+                    title_set,
+                    tags_set,
+                    focused,
+                    fullscreen,
+                });
+                Ok(())
+            },
+        );
+
+        methods.add_method("len", |_, this, ()| Ok(this.changes().len()));
+        methods.add_method("saw_fullscreen", |_, this, ()| {
+            Ok(this.changes().iter().any(|change| change.fullscreen))
+        });
+    }
+}
+
+#[test_log::test]
+fn window_handle_watch_sends_changed_properties() {
+    for_each_api(|lang| {
+        let (mut fixture, _) = set_up();
+
+        let client_id = fixture.add_client();
+        fixture.spawn_windows(1, client_id);
+
+        let log = WatchLog::default();
+        let log_clone = log.clone();
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(move || {
+                let window = pinnacle_api::window::get_focused().unwrap();
+
+                let watch_log = log.clone();
+                window.watch(move |change: WindowPropertiesChange| {
+                    watch_log.log(WatchedChange {
+                        title_set: change.title.is_some(),
+                        tags_set: change.tags.is_some(),
+                        focused: change.focused,
+                        fullscreen: change.layout_mode
+                            == Some(pinnacle_api::window::LayoutMode::Fullscreen),
+                    });
+                });
+
+                while log.changes().is_empty() {
+                    std::thread::sleep(Duration::from_millis(1));
+                }
+
+                window.set_fullscreen(true);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                local window = Window.get_focused()
+
+                window:watch(function(change)
+                    $log:log(
+                        change.title ~= nil,
+                        change.tags ~= nil,
+                        change.focused,
+                        change.layout_mode == "fullscreen"
+                    )
+                end)
+
+                local client = require("pinnacle.grpc.client").client
+                while $log:len() < 1 do
+                    client.loop:step();
+                end
+
+                window:set_fullscreen(true)
+
+                while not $log:saw_fullscreen() do
+                    client.loop:step();
+                end
+            },
+        }
+
+        fixture.dispatch_until(|_| log_clone.changes().iter().any(|change| change.fullscreen));
+
+        let changes = log_clone.changes();
+
+        assert_eq!(
+            changes[0],
+            WatchedChange {
+                title_set: true,
+                tags_set: true,
+                focused: Some(true),
+                fullscreen: false,
+            }
+        );
+
+        assert!(
+            changes[1..]
+                .iter()
+                .all(|change| !change.title_set && !change.tags_set)
+        );
+    });
+}
+
+/// Whether `loc` and `size` were cleared in each [`WindowPropertiesChange`], in order.
+#[derive(Clone, Default)]
+struct ClearLog(Arc<Mutex<Vec<(bool, bool)>>>);
+
+impl ClearLog {
+    fn log(&self, loc_cleared: bool, size_cleared: bool) {
+        self.0.lock().unwrap().push((loc_cleared, size_cleared));
+    }
+
+    fn changes(&self) -> Vec<(bool, bool)> {
+        self.0.lock().unwrap().clone()
+    }
+}
+
+impl UserData for ClearLog {
+    fn add_methods<M: UserDataMethods<Self>>(methods: &mut M) {
+        methods.add_method("log", |_, this, (loc, size): (bool, bool)| {
+            this.log(loc, size);
+            Ok(())
+        });
+
+        methods.add_method("len", |_, this, ()| Ok(this.changes().len()));
+        methods.add_method("saw_cleared", |_, this, ()| {
+            Ok(this.changes().contains(&(true, true)))
+        });
+    }
+}
+
+#[test_log::test]
+fn window_handle_watch_sends_cleared_geometry_on_unmap() {
+    for_each_api(|lang| {
+        let (mut fixture, _) = set_up();
+
+        let client_id = fixture.add_client();
+        fixture.spawn_windows(1, client_id);
+
+        let log = ClearLog::default();
+        let log_clone = log.clone();
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(move || {
+                let window = pinnacle_api::window::get_focused().unwrap();
+
+                let watch_log = log.clone();
+                window.watch(move |change: WindowPropertiesChange| {
+                    watch_log.log(change.loc == Some(None), change.size == Some(None));
+                });
+
+                while log.changes().is_empty() {
+                    std::thread::sleep(Duration::from_millis(1));
+                }
+
+                pinnacle_api::tag::get("1").unwrap().set_active(false);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                local window = Window.get_focused()
+
+                window:watch(function(change)
+                    $log:log(change.cleared.loc == true, change.cleared.size == true)
+                end)
+
+                local client = require("pinnacle.grpc.client").client
+                while $log:len() < 1 do
+                    client.loop:step();
+                end
+
+                Tag.get("1"):set_active(false)
+
+                while not $log:saw_cleared() do
+                    client.loop:step();
+                end
+            },
+        }
+
+        fixture.dispatch_until(|_| log_clone.changes().contains(&(true, true)));
+
+        assert_eq!(log_clone.changes()[0], (false, false));
+    });
+}
+
+fn left_button(fixture: &mut Fixture, button_state: ButtonState) {
+    const BUTTON_LEFT: u32 = 0x110;
+
+    let pointer = fixture.pinnacle().seat.get_pointer().unwrap();
+    let state = fixture.state();
+    pointer.button(
+        state,
+        &ButtonEvent {
+            serial: SERIAL_COUNTER.next_serial(),
+            time: 0,
+            button: BUTTON_LEFT,
+            state: button_state,
+        },
+    );
+    pointer.frame(state);
+}
+
+#[test_log::test]
+fn window_begin_move_restores_client_cursor() {
+    for_each_api(|lang| {
+        let (mut fixture, _) = set_up();
+
+        let client_id = fixture.add_client();
+        fixture.spawn_windows(1, client_id);
+
+        let window = fixture.pinnacle().windows[0].clone();
+        let geo = fixture.pinnacle().space.element_geometry(&window).unwrap();
+        let center = geo.loc.to_f64() + geo.size.to_f64().downscale(2.0).to_point();
+        fixture.state().warp_cursor_to_global_loc(center);
+        fixture.dispatch();
+
+        // As if the client set a cursor shape
+        fixture
+            .pinnacle()
+            .cursor_state
+            .set_cursor_image(CursorImageStatus::Named(CursorIcon::Text));
+
+        left_button(&mut fixture, ButtonState::Pressed);
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::window::begin_move(pinnacle_api::input::MouseButton::Left);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Window.begin_move("btn_left")
+            },
+        }
+
+        assert!(fixture.pinnacle().seat.get_pointer().unwrap().is_grabbed());
+        assert!(matches!(
+            fixture.pinnacle().cursor_state.cursor_image(),
+            CursorImageStatus::Named(CursorIcon::Grabbing)
+        ));
+
+        left_button(&mut fixture, ButtonState::Released);
+
+        assert!(!fixture.pinnacle().seat.get_pointer().unwrap().is_grabbed());
+        assert!(matches!(
+            fixture.pinnacle().cursor_state.cursor_image(),
+            CursorImageStatus::Named(CursorIcon::Text)
+        ));
+    });
+}
+
 // TODO: window_begin_resize
 // TODO: window_connect_signal
 // TODO: window_add_window_rule
+
+#[test_log::test]
+fn window_handle_icon() {
+    for_each_api(|lang| {
+        let (mut fixture, _output1) = set_up();
+
+        let client_id = fixture.add_client();
+
+        let _surfaces = fixture.spawn_windows(2, client_id);
+
+        let with_icon = fixture.pinnacle().windows[0].clone();
+        let without_icon = fixture.pinnacle().windows[1].clone();
+
+        with_icon.with_state_mut(|state| {
+            state.icon = Some(ToplevelIcon {
+                name: Some("firefox".into()),
+                images: vec![IconImage {
+                    size: 1,
+                    scale: 2,
+                    rgba: vec![1, 2, 3, 4],
+                }],
+            });
+        });
+
+        let with_icon_id = with_icon.with_state(|s| s.id.0);
+        let without_icon_id = without_icon.with_state(|s| s.id.0);
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(move || {
+                let icon = pinnacle_api::window::WindowHandle::from_id(with_icon_id)
+                    .icon()
+                    .unwrap();
+                assert_eq!(icon.name.as_deref(), Some("firefox"));
+                assert_eq!(
+                    icon.images,
+                    vec![pinnacle_api::window::IconImage {
+                        size: 1,
+                        scale: 2,
+                        rgba: vec![1, 2, 3, 4],
+                    }]
+                );
+
+                assert!(
+                    pinnacle_api::window::WindowHandle::from_id(without_icon_id)
+                        .icon()
+                        .is_none()
+                );
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                local icon = Window.handle.new($with_icon_id):icon()
+                assert(icon.name == "firefox")
+                assert(#icon.images == 1)
+                assert(icon.images[1].size == 1)
+                assert(icon.images[1].scale == 2)
+                assert(icon.images[1].rgba == string.char(1, 2, 3, 4))
+
+                assert(Window.handle.new($without_icon_id):icon() == nil)
+            },
+        };
+    })
+}
+
+#[test_log::test]
+fn window_render_plan_is_reused_until_its_surface_commits() {
+    let (mut fixture, _) = set_up();
+
+    let client_id = fixture.add_client();
+    let surfaces = fixture.spawn_windows(2, client_id);
+
+    let windows = surfaces
+        .iter()
+        .map(|surface| {
+            fixture
+                .pinnacle()
+                .windows
+                .iter()
+                .find(|win| {
+                    win.wl_surface()
+                        .is_some_and(|surf| surf.id().protocol_id() == surface.id().protocol_id())
+                })
+                .cloned()
+                .unwrap()
+        })
+        .collect::<Vec<_>>();
+
+    let key = RenderPlanKey {
+        location: (0, 0).into(),
+        scale: 1.0.into(),
+        include_decorations: true,
+        has_ssd: false,
+    };
+
+    let plans = windows
+        .iter()
+        .map(|window| {
+            let plan = Arc::new(WindowRenderPlan {
+                roots: vec![window.wl_surface().unwrap().into_owned()],
+                ..Default::default()
+            });
+            window.with_state_mut(|state| state.render_plan_cache.insert(key, plan.clone()));
+            plan
+        })
+        .collect::<Vec<_>>();
+
+    fixture.dispatch();
+
+    for (window, plan) in windows.iter().zip(&plans) {
+        let cached = window.with_state(|state| state.render_plan_cache.get(&key));
+        assert!(Arc::ptr_eq(&cached.unwrap(), plan));
+    }
+
+    fixture
+        .client(client_id)
+        .window_for_surface(&surfaces[0])
+        .commit();
+    fixture.roundtrip(client_id);
+
+    assert!(
+        windows[0]
+            .with_state(|state| state.render_plan_cache.get(&key))
+            .is_none()
+    );
+    let cached = windows[1].with_state(|state| state.render_plan_cache.get(&key));
+    assert!(Arc::ptr_eq(&cached.unwrap(), &plans[1]));
+}
@@ -16,28 +16,67 @@ use calloop_wayland_source::WaylandSource;
 use smithay::reexports::{
     calloop::EventLoop,
     wayland_protocols::{
+        ext::workspace::v1::client::{
+            ext_workspace_group_handle_v1::ExtWorkspaceGroupHandleV1,
+            ext_workspace_handle_v1::{self, ExtWorkspaceHandleV1},
+            ext_workspace_manager_v1::{self, ExtWorkspaceManagerV1},
+        },
         wp::{
+            alpha_modifier::v1::client::{
+                wp_alpha_modifier_surface_v1::WpAlphaModifierSurfaceV1,
+                wp_alpha_modifier_v1::WpAlphaModifierV1,
+            },
+            fractional_scale::v1::client::{
+                wp_fractional_scale_manager_v1::WpFractionalScaleManagerV1,
+                wp_fractional_scale_v1::{self, WpFractionalScaleV1},
+            },
+            pointer_gestures::zv1::client::{
+                zwp_pointer_gesture_pinch_v1::{self, ZwpPointerGesturePinchV1},
+                zwp_pointer_gestures_v1::ZwpPointerGesturesV1,
+            },
             single_pixel_buffer::v1::client::wp_single_pixel_buffer_manager_v1::WpSinglePixelBufferManagerV1,
             viewporter::client::{wp_viewport::WpViewport, wp_viewporter::WpViewporter},
         },
-        xdg::shell::client::{
-            xdg_surface::{self, XdgSurface},
-            xdg_toplevel::{self, XdgToplevel},
-            xdg_wm_base::{self, XdgWmBase},
+        xdg::{
+            shell::client::{
+                xdg_popup::{self, XdgPopup},
+                xdg_positioner::XdgPositioner,
+                xdg_surface::{self, XdgSurface},
+                xdg_toplevel::{self, XdgToplevel},
+                xdg_wm_base::{self, XdgWmBase},
+            },
+            toplevel_drag::v1::client::{
+                xdg_toplevel_drag_manager_v1::XdgToplevelDragManagerV1,
+                xdg_toplevel_drag_v1::XdgToplevelDragV1,
+            },
         },
     },
+    wayland_protocols_wlr::layer_shell::v1::client::{
+        zwlr_layer_shell_v1::{self, ZwlrLayerShellV1},
+        zwlr_layer_surface_v1::{self, ZwlrLayerSurfaceV1},
+    },
+};
+use snowcap_protocols::snowcap_thumbnail_v1::client::{
+    snowcap_thumbnail_manager_v1::SnowcapThumbnailManagerV1,
+    snowcap_thumbnail_v1::{self, SnowcapThumbnailV1},
 };
 use tracing::debug;
 use wayland_client::{
-    Connection, Dispatch, Proxy, QueueHandle, delegate_noop,
+    Connection, Dispatch, Proxy, QueueHandle, delegate_noop, event_created_child,
     globals::GlobalListContents,
     protocol::{
         wl_buffer::WlBuffer,
         wl_callback::{self, WlCallback},
         wl_compositor::WlCompositor,
+        wl_data_device::{self, WlDataDevice},
+        wl_data_device_manager::WlDataDeviceManager,
+        wl_data_offer::WlDataOffer,
+        wl_data_source::WlDataSource,
         wl_display::WlDisplay,
         wl_output::WlOutput,
+        wl_pointer::{self, WlPointer},
         wl_registry::{self, WlRegistry},
+        wl_seat::WlSeat,
         wl_surface::WlSurface,
     },
 };
@@ -67,8 +106,24 @@ struct State {
     xdg_wm_base: Option<XdgWmBase>,
     single_pixel_buffer: Option<WpSinglePixelBufferManagerV1>,
     viewporter: Option<WpViewporter>,
+    fractional_scale_manager: Option<WpFractionalScaleManagerV1>,
+    thumbnail_manager: Option<SnowcapThumbnailManagerV1>,
+    seat: Option<WlSeat>,
+    pointer: Option<WlPointer>,
+    pointer_gestures: Option<ZwpPointerGesturesV1>,
+    data_device_manager: Option<WlDataDeviceManager>,
+    toplevel_drag_manager: Option<XdgToplevelDragManagerV1>,
+    alpha_modifier: Option<WpAlphaModifierV1>,
+    layer_shell: Option<ZwlrLayerShellV1>,
+    /// The serial of the last pointer button press.
+    button_serial: Option<u32>,
     windows: Vec<Window>,
+    popups: Vec<Popup>,
+    layers: Vec<Layer>,
     outputs: Vec<WlOutput>,
+    thumbnails: Vec<Thumbnail>,
+    pinch_events: Vec<PinchEvent>,
+    workspaces: Vec<Workspace>,
 }
 
 pub struct Window {
@@ -77,6 +132,7 @@ pub struct Window {
     xdg_surface: XdgSurface,
     toplevel: XdgToplevel,
     viewport: WpViewport,
+    fractional_scale: WpFractionalScaleV1,
     single_pixel_buffer: WpSinglePixelBufferManagerV1,
 
     current_configure_serial: Option<u32>,
@@ -85,6 +141,8 @@ pub struct Window {
     pub close_requested: bool,
     pub fullscreen: bool,
     pub maximized: bool,
+    /// The last fractional scale the compositor asked this window to render at, in 120ths.
+    pub preferred_fractional_scale: Option<u32>,
 }
 
 impl Drop for Window {
@@ -92,10 +150,74 @@ impl Drop for Window {
         self.toplevel.destroy();
         self.xdg_surface.destroy();
         self.viewport.destroy();
+        self.fractional_scale.destroy();
+        self.wl_surface.destroy();
+    }
+}
+
+/// An xdg-popup and the geometry it was last configured with.
+pub struct Popup {
+    wl_surface: WlSurface,
+    xdg_surface: XdgSurface,
+    popup: XdgPopup,
+    /// The popup's location relative to its parent and its size.
+    pub geometry: Option<((i32, i32), (i32, i32))>,
+}
+
+impl Drop for Popup {
+    fn drop(&mut self) {
+        self.popup.destroy();
+        self.xdg_surface.destroy();
+        self.wl_surface.destroy();
+    }
+}
+
+/// A wlr-layer-shell surface.
+pub struct Layer {
+    qh: QueueHandle<State>,
+    wl_surface: WlSurface,
+    layer_surface: ZwlrLayerSurfaceV1,
+    viewport: WpViewport,
+    single_pixel_buffer: WpSinglePixelBufferManagerV1,
+
+    current_configure_serial: Option<u32>,
+}
+
+impl Drop for Layer {
+    fn drop(&mut self) {
+        self.layer_surface.destroy();
+        self.viewport.destroy();
         self.wl_surface.destroy();
     }
 }
 
+/// A snowcap-thumbnail and the events it received.
+pub struct Thumbnail {
+    thumbnail: SnowcapThumbnailV1,
+    pub buffer_size: Option<(u32, u32)>,
+    pub ready: u32,
+    pub failed: u32,
+    pub stopped: bool,
+}
+
+/// An ext-workspace handle and the state it was last sent.
+pub struct Workspace {
+    handle: ExtWorkspaceHandleV1,
+    pub id: Option<String>,
+    pub name: Option<String>,
+    pub coordinates: Vec<u8>,
+    pub state: ext_workspace_handle_v1::State,
+    pub removed: bool,
+}
+
+/// A pinch gesture event received by a client.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PinchEvent {
+    Begin { fingers: u32 },
+    Update { scale: f64 },
+    End { cancelled: bool },
+}
+
 #[derive(Default, Debug)]
 struct PendingConfigure {
     size: Option<(i32, i32)>,
@@ -129,8 +251,23 @@ impl Client {
             xdg_wm_base: None,
             single_pixel_buffer: None,
             viewporter: None,
+            fractional_scale_manager: None,
+            thumbnail_manager: None,
+            seat: None,
+            pointer: None,
+            pointer_gestures: None,
+            data_device_manager: None,
+            toplevel_drag_manager: None,
+            alpha_modifier: None,
+            layer_shell: None,
+            button_serial: None,
             windows: Vec::new(),
+            popups: Vec::new(),
+            layers: Vec::new(),
             outputs: Vec::new(),
+            thumbnails: Vec::new(),
+            pinch_events: Vec::new(),
+            workspaces: Vec::new(),
         };
 
         Self {
@@ -196,9 +333,208 @@ impl Client {
         self.state.windows.retain(|win| &win.surface() != surface);
     }
 
+    /// Creates a popup for the window with the surface `parent` and commits it.
+    ///
+    /// `positioner` is called to set up where the popup should go.
+    pub fn create_popup(
+        &mut self,
+        parent: &WlSurface,
+        positioner: impl FnOnce(&XdgPositioner),
+    ) -> WlSurface {
+        self.state.create_popup(parent, positioner)
+    }
+
+    pub fn popup_for_surface(&self, surface: &WlSurface) -> &Popup {
+        self.state
+            .popups
+            .iter()
+            .find(|popup| &popup.wl_surface == surface)
+            .unwrap()
+    }
+
+    /// Creates a 100x100 layer surface on the compositor's choice of output.
+    ///
+    /// It is mapped once its initial configure is acked with [`Layer::ack_and_map`].
+    pub fn create_layer(
+        &mut self,
+        layer: zwlr_layer_shell_v1::Layer,
+        namespace: &str,
+    ) -> &mut Layer {
+        self.state.create_layer(layer, namespace)
+    }
+
+    pub fn layer_for_surface(&mut self, surface: &WlSurface) -> &mut Layer {
+        self.state
+            .layers
+            .iter_mut()
+            .find(|layer| &layer.wl_surface == surface)
+            .unwrap()
+    }
+
+    pub fn close_layer(&mut self, surface: &WlSurface) {
+        self.state
+            .layers
+            .retain(|layer| &layer.wl_surface != surface);
+    }
+
     pub fn wl_outputs(&self) -> &Vec<WlOutput> {
         &self.state.outputs
     }
+
+    pub fn create_output_thumbnail(
+        &mut self,
+        output: &WlOutput,
+        max_size: (u32, u32),
+    ) -> SnowcapThumbnailV1 {
+        let thumbnail = self.thumbnail_manager().get_output_thumbnail(
+            output,
+            max_size.0,
+            max_size.1,
+            &self.state.qh,
+            (),
+        );
+        self.state.push_thumbnail(thumbnail)
+    }
+
+    pub fn create_tag_thumbnail(
+        &mut self,
+        tag_id: u32,
+        max_size: (u32, u32),
+    ) -> SnowcapThumbnailV1 {
+        let thumbnail = self.thumbnail_manager().get_tag_thumbnail(
+            tag_id,
+            max_size.0,
+            max_size.1,
+            &self.state.qh,
+            (),
+        );
+        self.state.push_thumbnail(thumbnail)
+    }
+
+    pub fn create_window_thumbnail(
+        &mut self,
+        window_id: u32,
+        max_size: (u32, u32),
+    ) -> SnowcapThumbnailV1 {
+        let thumbnail = self.thumbnail_manager().get_window_thumbnail(
+            window_id,
+            max_size.0,
+            max_size.1,
+            &self.state.qh,
+            (),
+        );
+        self.state.push_thumbnail(thumbnail)
+    }
+
+    pub fn thumbnail(&self, thumbnail: &SnowcapThumbnailV1) -> &Thumbnail {
+        self.state
+            .thumbnails
+            .iter()
+            .find(|thumb| &thumb.thumbnail == thumbnail)
+            .unwrap()
+    }
+
+    /// Requests a capture of `thumbnail` into a new buffer.
+    pub fn capture_thumbnail(&self, thumbnail: &SnowcapThumbnailV1) {
+        let buffer = self
+            .state
+            .single_pixel_buffer
+            .as_ref()
+            .unwrap()
+            .create_u32_rgba_buffer(0, 0, 0, u32::MAX, &self.state.qh, ());
+        thumbnail.capture(&buffer);
+    }
+
+    pub fn destroy_thumbnail(&mut self, thumbnail: &SnowcapThumbnailV1) {
+        thumbnail.destroy();
+        self.state
+            .thumbnails
+            .retain(|thumb| &thumb.thumbnail != thumbnail);
+    }
+
+    /// Starts receiving pointer events.
+    pub fn create_pointer(&mut self) {
+        let pointer = self
+            .state
+            .seat
+            .as_ref()
+            .unwrap()
+            .get_pointer(&self.state.qh, ());
+        self.state.pointer = Some(pointer);
+    }
+
+    /// Starts receiving pinch gestures through this client's pointer.
+    pub fn create_pinch_gesture(&mut self) {
+        if self.state.pointer.is_none() {
+            self.create_pointer();
+        }
+
+        self.state
+            .pointer_gestures
+            .as_ref()
+            .unwrap()
+            .get_pinch_gesture(self.state.pointer.as_ref().unwrap(), &self.state.qh, ());
+    }
+
+    /// Starts a drag from `origin` with `toplevel` attached to it, like tearing out a tab.
+    ///
+    /// The drag uses the implicit grab of the last pointer button press.
+    pub fn start_toplevel_drag(
+        &mut self,
+        origin: &WlSurface,
+        toplevel: &XdgToplevel,
+        offset: (i32, i32),
+    ) -> XdgToplevelDragV1 {
+        let qh = &self.state.qh;
+        let data_device_manager = self.state.data_device_manager.as_ref().unwrap();
+
+        let source = data_device_manager.create_data_source(qh, ());
+        source.offer("text/plain".to_string());
+
+        let drag = self
+            .state
+            .toplevel_drag_manager
+            .as_ref()
+            .unwrap()
+            .get_xdg_toplevel_drag(&source, qh, ());
+        drag.attach(toplevel, offset.0, offset.1);
+
+        let data_device =
+            data_device_manager.get_data_device(self.state.seat.as_ref().unwrap(), qh, ());
+        data_device.start_drag(
+            Some(&source),
+            origin,
+            None,
+            self.state.button_serial.unwrap(),
+        );
+
+        drag
+    }
+
+    /// Gets the alpha modifier of `surface`, which can only be created once per surface.
+    pub fn create_alpha_modifier(&self, surface: &WlSurface) -> WpAlphaModifierSurfaceV1 {
+        self.state
+            .alpha_modifier
+            .as_ref()
+            .unwrap()
+            .get_surface(surface, &self.state.qh, ())
+    }
+
+    pub fn pinch_events(&self) -> &[PinchEvent] {
+        &self.state.pinch_events
+    }
+
+    /// Returns the ext-workspace workspaces this client was told about that weren't removed.
+    pub fn workspaces(&self) -> impl Iterator<Item = &Workspace> {
+        self.state
+            .workspaces
+            .iter()
+            .filter(|workspace| !workspace.removed)
+    }
+
+    fn thumbnail_manager(&self) -> &SnowcapThumbnailManagerV1 {
+        self.state.thumbnail_manager.as_ref().unwrap()
+    }
 }
 
 impl State {
@@ -219,6 +555,11 @@ impl State {
             .as_ref()
             .unwrap()
             .get_viewport(&wl_surface, &self.qh, ());
+        let fractional_scale = self
+            .fractional_scale_manager
+            .as_ref()
+            .unwrap()
+            .get_fractional_scale(&wl_surface, &self.qh, ());
 
         let window = Window {
             qh: self.qh.clone(),
@@ -227,18 +568,99 @@ impl State {
             xdg_surface,
             toplevel,
             viewport,
+            fractional_scale,
             current_configure_serial: None,
             pending_configure: Default::default(),
             size_configure_hook: None,
             close_requested: false,
             fullscreen: false,
             maximized: false,
+            preferred_fractional_scale: None,
         };
 
         self.windows.push(window);
         self.windows.last_mut().unwrap()
     }
 
+    fn create_popup(
+        &mut self,
+        parent: &WlSurface,
+        positioner: impl FnOnce(&XdgPositioner),
+    ) -> WlSurface {
+        let xdg_wm_base = self.xdg_wm_base.as_ref().unwrap();
+
+        let xdg_positioner = xdg_wm_base.create_positioner(&self.qh, ());
+        positioner(&xdg_positioner);
+
+        let wl_surface = self
+            .compositor
+            .as_ref()
+            .unwrap()
+            .create_surface(&self.qh, ());
+        let xdg_surface = xdg_wm_base.get_xdg_surface(&wl_surface, &self.qh, ());
+        let parent = self
+            .windows
+            .iter()
+            .find(|win| &win.wl_surface == parent)
+            .map(|win| &win.xdg_surface);
+        let popup = xdg_surface.get_popup(parent, &xdg_positioner, &self.qh, ());
+        xdg_positioner.destroy();
+
+        wl_surface.commit();
+
+        self.popups.push(Popup {
+            wl_surface: wl_surface.clone(),
+            xdg_surface,
+            popup,
+            geometry: None,
+        });
+
+        wl_surface
+    }
+
+    fn create_layer(&mut self, layer: zwlr_layer_shell_v1::Layer, namespace: &str) -> &mut Layer {
+        let wl_surface = self
+            .compositor
+            .as_ref()
+            .unwrap()
+            .create_surface(&self.qh, ());
+        let layer_surface = self.layer_shell.as_ref().unwrap().get_layer_surface(
+            &wl_surface,
+            None,
+            layer,
+            namespace.to_string(),
+            &self.qh,
+            (),
+        );
+        layer_surface.set_size(100, 100);
+        let viewport = self
+            .viewporter
+            .as_ref()
+            .unwrap()
+            .get_viewport(&wl_surface, &self.qh, ());
+
+        self.layers.push(Layer {
+            qh: self.qh.clone(),
+            single_pixel_buffer: self.single_pixel_buffer.clone().unwrap(),
+            wl_surface,
+            layer_surface,
+            viewport,
+            current_configure_serial: None,
+        });
+        self.layers.last_mut().unwrap()
+    }
+
+    fn push_thumbnail(&mut self, thumbnail: SnowcapThumbnailV1) -> SnowcapThumbnailV1 {
+        self.thumbnails.push(Thumbnail {
+            thumbnail: thumbnail.clone(),
+            buffer_size: None,
+            ready: 0,
+            failed: 0,
+            stopped: false,
+        });
+        thumbnail
+    }
+
     fn send_sync(&self) -> Arc<AtomicBool> {
         let wait = Arc::new(AtomicBool::new(false));
         self.display.sync(&self.qh, wait.clone());
@@ -277,6 +699,14 @@ impl Window {
         self.wl_surface.attach(Some(&buffer), 0, 0);
     }
 
+    pub fn toplevel(&self) -> XdgToplevel {
+        self.toplevel.clone()
+    }
+
+    pub fn set_parent(&self, parent: Option<&XdgToplevel>) {
+        self.toplevel.set_parent(parent);
+    }
+
     pub fn set_app_id(&self, app_id: &str) {
         self.toplevel.set_app_id(app_id.to_string());
     }
@@ -328,6 +758,43 @@ impl Window {
     }
 }
 
+impl Layer {
+    pub fn surface(&self) -> WlSurface {
+        self.wl_surface.clone()
+    }
+
+    pub fn layer_surface(&self) -> ZwlrLayerSurfaceV1 {
+        self.layer_surface.clone()
+    }
+
+    pub fn current_serial(&self) -> Option<u32> {
+        self.current_configure_serial
+    }
+
+    pub fn commit(&self) {
+        self.wl_surface.commit();
+    }
+
+    /// Acks the last configure and commits a buffer.
+    pub fn ack_and_map(&mut self) {
+        if let Some(serial) = self.current_configure_serial.take() {
+            self.layer_surface.ack_configure(serial);
+        }
+
+        let buffer =
+            self.single_pixel_buffer
+                .create_u32_rgba_buffer(0, 0, 0, u32::MAX, &self.qh, ());
+        self.wl_surface.attach(Some(&buffer), 0, 0);
+        self.commit();
+    }
+
+    /// Commits a null buffer, which unmaps the layer surface.
+    pub fn unmap(&self) {
+        self.wl_surface.attach(None, 0, 0);
+        self.commit();
+    }
+}
+
 impl Dispatch<WlRegistry, ()> for State {
     fn event(
         state: &mut Self,
@@ -356,6 +823,36 @@ impl Dispatch<WlRegistry, ()> for State {
                 } else if interface == WpViewporter::interface().name {
                     let version = u32::min(version, WpViewporter::interface().version);
                     state.viewporter = Some(registry.bind(name, version, qhandle, ()));
+                } else if interface == WpFractionalScaleManagerV1::interface().name {
+                    let version =
+                        u32::min(version, WpFractionalScaleManagerV1::interface().version);
+                    state.fractional_scale_manager =
+                        Some(registry.bind(name, version, qhandle, ()));
+                } else if interface == SnowcapThumbnailManagerV1::interface().name {
+                    let version = u32::min(version, SnowcapThumbnailManagerV1::interface().version);
+                    state.thumbnail_manager = Some(registry.bind(name, version, qhandle, ()));
+                } else if interface == WlSeat::interface().name {
+                    let version = u32::min(version, WlSeat::interface().version);
+                    state.seat = Some(registry.bind(name, version, qhandle, ()));
+                } else if interface == WlDataDeviceManager::interface().name {
+                    let version = u32::min(version, WlDataDeviceManager::interface().version);
+                    state.data_device_manager = Some(registry.bind(name, version, qhandle, ()));
+                } else if interface == XdgToplevelDragManagerV1::interface().name {
+                    let version = u32::min(version, XdgToplevelDragManagerV1::interface().version);
+                    state.toplevel_drag_manager = Some(registry.bind(name, version, qhandle, ()));
+                } else if interface == ExtWorkspaceManagerV1::interface().name {
+                    let version = u32::min(version, ExtWorkspaceManagerV1::interface().version);
+                    // Workspaces are announced on bind, no requests are needed
+                    let _: ExtWorkspaceManagerV1 = registry.bind(name, version, qhandle, ());
+                } else if interface == WpAlphaModifierV1::interface().name {
+                    let version = u32::min(version, WpAlphaModifierV1::interface().version);
+                    state.alpha_modifier = Some(registry.bind(name, version, qhandle, ()));
+                } else if interface == ZwlrLayerShellV1::interface().name {
+                    let version = u32::min(version, ZwlrLayerShellV1::interface().version);
+                    state.layer_shell = Some(registry.bind(name, version, qhandle, ()));
+                } else if interface == ZwpPointerGesturesV1::interface().name {
+                    let version = u32::min(version, ZwpPointerGesturesV1::interface().version);
+                    state.pointer_gestures = Some(registry.bind(name, version, qhandle, ()));
                 } else if interface == WlOutput::interface().name {
                     let version = u32::min(version, WlOutput::interface().version);
                     state
@@ -426,6 +923,32 @@ impl Dispatch<WlSurface, ()> for State {
     }
 }
 
+impl Dispatch<WpFractionalScaleV1, ()> for State {
+    fn event(
+        state: &mut Self,
+        proxy: &WpFractionalScaleV1,
+        event: <WpFractionalScaleV1 as wayland_client::Proxy>::Event,
+        _data: &(),
+        _conn: &Connection,
+        _qhandle: &QueueHandle<Self>,
+    ) {
+        let Some(window) = state
+            .windows
+            .iter_mut()
+            .find(|win| &win.fractional_scale == proxy)
+        else {
+            return;
+        };
+
+        match event {
+            wp_fractional_scale_v1::Event::PreferredScale { scale } => {
+                window.preferred_fractional_scale = Some(scale);
+            }
+            _ => panic!(),
+        }
+    }
+}
+
 impl Dispatch<XdgSurface, ()> for State {
     fn event(
         state: &mut Self,
@@ -437,6 +960,11 @@ impl Dispatch<XdgSurface, ()> for State {
     ) {
         match event {
             xdg_surface::Event::Configure { serial } => {
+                if state.popups.iter().any(|popup| &popup.xdg_surface == proxy) {
+                    proxy.ack_configure(serial);
+                    return;
+                }
+
                 let window = state
                     .windows
                     .iter_mut()
@@ -525,9 +1053,227 @@ impl Dispatch<XdgToplevel, ()> for State {
     }
 }
 
+impl Dispatch<XdgPopup, ()> for State {
+    fn event(
+        state: &mut Self,
+        proxy: &XdgPopup,
+        event: <XdgPopup as wayland_client::Proxy>::Event,
+        _data: &(),
+        _conn: &Connection,
+        _qhandle: &QueueHandle<Self>,
+    ) {
+        let Some(popup) = state.popups.iter_mut().find(|popup| &popup.popup == proxy) else {
+            return;
+        };
+
+        match event {
+            xdg_popup::Event::Configure {
+                x,
+                y,
+                width,
+                height,
+            } => popup.geometry = Some(((x, y), (width, height))),
+            xdg_popup::Event::PopupDone | xdg_popup::Event::Repositioned { .. } => (),
+            _ => panic!(),
+        }
+    }
+}
+
+impl Dispatch<ZwlrLayerSurfaceV1, ()> for State {
+    fn event(
+        state: &mut Self,
+        proxy: &ZwlrLayerSurfaceV1,
+        event: <ZwlrLayerSurfaceV1 as wayland_client::Proxy>::Event,
+        _data: &(),
+        _conn: &Connection,
+        _qhandle: &QueueHandle<Self>,
+    ) {
+        let Some(layer) = state
+            .layers
+            .iter_mut()
+            .find(|layer| &layer.layer_surface == proxy)
+        else {
+            return;
+        };
+
+        match event {
+            zwlr_layer_surface_v1::Event::Configure {
+                serial,
+                width,
+                height,
+            } => {
+                layer.current_configure_serial = Some(serial);
+                if width > 0 && height > 0 {
+                    layer.viewport.set_destination(width as i32, height as i32);
+                }
+            }
+            zwlr_layer_surface_v1::Event::Closed => (),
+            _ => panic!(),
+        }
+    }
+}
+
+impl Dispatch<SnowcapThumbnailV1, ()> for State {
+    fn event(
+        state: &mut Self,
+        proxy: &SnowcapThumbnailV1,
+        event: <SnowcapThumbnailV1 as wayland_client::Proxy>::Event,
+        _data: &(),
+        _conn: &Connection,
+        _qhandle: &QueueHandle<Self>,
+    ) {
+        let Some(thumbnail) = state
+            .thumbnails
+            .iter_mut()
+            .find(|thumb| &thumb.thumbnail == proxy)
+        else {
+            return;
+        };
+
+        match event {
+            snowcap_thumbnail_v1::Event::BufferSize { width, height } => {
+                thumbnail.buffer_size = Some((width, height));
+            }
+            snowcap_thumbnail_v1::Event::Ready => thumbnail.ready += 1,
+            snowcap_thumbnail_v1::Event::Failed => thumbnail.failed += 1,
+            snowcap_thumbnail_v1::Event::Stopped => thumbnail.stopped = true,
+            _ => panic!(),
+        }
+    }
+}
+
+impl Dispatch<WlPointer, ()> for State {
+    fn event(
+        state: &mut Self,
+        _proxy: &WlPointer,
+        event: <WlPointer as wayland_client::Proxy>::Event,
+        _data: &(),
+        _conn: &Connection,
+        _qhandle: &QueueHandle<Self>,
+    ) {
+        if let wl_pointer::Event::Button { serial, .. } = event {
+            state.button_serial = Some(serial);
+        }
+    }
+}
+
+impl Dispatch<WlDataDevice, ()> for State {
+    fn event(
+        _state: &mut Self,
+        _proxy: &WlDataDevice,
+        _event: <WlDataDevice as wayland_client::Proxy>::Event,
+        _data: &(),
+        _conn: &Connection,
+        _qhandle: &QueueHandle<Self>,
+    ) {
+    }
+
+    event_created_child!(State, WlDataDevice, [
+        wl_data_device::EVT_DATA_OFFER_OPCODE => (WlDataOffer, ()),
+    ]);
+}
+
+impl Dispatch<ZwpPointerGesturePinchV1, ()> for State {
+    fn event(
+        state: &mut Self,
+        _proxy: &ZwpPointerGesturePinchV1,
+        event: <ZwpPointerGesturePinchV1 as wayland_client::Proxy>::Event,
+        _data: &(),
+        _conn: &Connection,
+        _qhandle: &QueueHandle<Self>,
+    ) {
+        let event = match event {
+            zwp_pointer_gesture_pinch_v1::Event::Begin { fingers, .. } => {
+                PinchEvent::Begin { fingers }
+            }
+            zwp_pointer_gesture_pinch_v1::Event::Update { scale, .. } => {
+                PinchEvent::Update { scale }
+            }
+            zwp_pointer_gesture_pinch_v1::Event::End { cancelled, .. } => PinchEvent::End {
+                cancelled: cancelled != 0,
+            },
+            _ => panic!(),
+        };
+        state.pinch_events.push(event);
+    }
+}
+
+impl Dispatch<ExtWorkspaceManagerV1, ()> for State {
+    fn event(
+        state: &mut Self,
+        _proxy: &ExtWorkspaceManagerV1,
+        event: <ExtWorkspaceManagerV1 as wayland_client::Proxy>::Event,
+        _data: &(),
+        _conn: &Connection,
+        _qhandle: &QueueHandle<Self>,
+    ) {
+        if let ext_workspace_manager_v1::Event::Workspace { workspace } = event {
+            state.workspaces.push(Workspace {
+                handle: workspace,
+                id: None,
+                name: None,
+                coordinates: Vec::new(),
+                state: ext_workspace_handle_v1::State::empty(),
+                removed: false,
+            });
+        }
+    }
+
+    event_created_child!(State, ExtWorkspaceManagerV1, [
+        ext_workspace_manager_v1::EVT_WORKSPACE_GROUP_OPCODE => (ExtWorkspaceGroupHandleV1, ()),
+        ext_workspace_manager_v1::EVT_WORKSPACE_OPCODE => (ExtWorkspaceHandleV1, ()),
+    ]);
+}
+
+impl Dispatch<ExtWorkspaceHandleV1, ()> for State {
+    fn event(
+        state: &mut Self,
+        proxy: &ExtWorkspaceHandleV1,
+        event: <ExtWorkspaceHandleV1 as wayland_client::Proxy>::Event,
+        _data: &(),
+        _conn: &Connection,
+        _qhandle: &QueueHandle<Self>,
+    ) {
+        let Some(workspace) = state
+            .workspaces
+            .iter_mut()
+            .find(|workspace| &workspace.handle == proxy)
+        else {
+            return;
+        };
+
+        match event {
+            ext_workspace_handle_v1::Event::Id { id } => workspace.id = Some(id),
+            ext_workspace_handle_v1::Event::Name { name } => workspace.name = Some(name),
+            ext_workspace_handle_v1::Event::Coordinates { coordinates } => {
+                workspace.coordinates = coordinates;
+            }
+            ext_workspace_handle_v1::Event::State { state } => {
+                workspace.state = state.into_result().unwrap();
+            }
+            ext_workspace_handle_v1::Event::Removed => workspace.removed = true,
+            _ => (),
+        }
+    }
+}
+
 delegate_noop!(State: WlCompositor);
+delegate_noop!(State: SnowcapThumbnailManagerV1);
 delegate_noop!(State: WpSinglePixelBufferManagerV1);
 delegate_noop!(State: WpViewporter);
+delegate_noop!(State: WpFractionalScaleManagerV1);
 delegate_noop!(State: WpViewport);
 delegate_noop!(State: ignore WlBuffer);
 delegate_noop!(State: ignore WlOutput);
+delegate_noop!(State: ignore WlSeat);
+delegate_noop!(State: ZwpPointerGesturesV1);
+delegate_noop!(State: WlDataDeviceManager);
+delegate_noop!(State: ignore WlDataSource);
+delegate_noop!(State: ignore WlDataOffer);
+delegate_noop!(State: XdgToplevelDragManagerV1);
+delegate_noop!(State: XdgToplevelDragV1);
+delegate_noop!(State: ignore ExtWorkspaceGroupHandleV1);
+delegate_noop!(State: WpAlphaModifierV1);
+delegate_noop!(State: ZwlrLayerShellV1);
+delegate_noop!(State: XdgPositioner);
+delegate_noop!(State: WpAlphaModifierSurfaceV1);
@@ -10,7 +10,12 @@ use std::{
 use pinnacle::state::{ClientState, Pinnacle};
 use smithay::{
     output::Output,
-    reexports::calloop::{EventLoop, Interest, Mode, PostAction, generic::Generic},
+    reexports::{
+        calloop::{EventLoop, Interest, Mode, PostAction, generic::Generic},
+        wayland_protocols_wlr::layer_shell::v1::client::{
+            zwlr_layer_shell_v1, zwlr_layer_surface_v1::ZwlrLayerSurfaceV1,
+        },
+    },
     utils::{Logical, Rectangle, Transform},
 };
 use tracing::debug;
@@ -289,6 +294,32 @@ impl Fixture {
         surface
     }
 
+    /// Spawns and maps a layer surface on the focused output.
+    pub fn spawn_layer_with<F>(
+        &mut self,
+        id: ClientId,
+        layer: zwlr_layer_shell_v1::Layer,
+        namespace: &str,
+        pre_initial_commit: F,
+    ) -> WlSurface
+    where
+        F: FnOnce(&ZwlrLayerSurfaceV1),
+    {
+        let layer = self.client(id).create_layer(layer, namespace);
+        pre_initial_commit(&layer.layer_surface());
+        layer.commit();
+        let surface = layer.surface();
+        self.roundtrip(id);
+
+        // Commit a buffer
+        let layer = self.client(id).layer_for_surface(&surface);
+        assert!(layer.current_serial().is_some());
+        layer.ack_and_map();
+        self.roundtrip(id);
+
+        surface
+    }
+
     pub fn spawn_windows(&mut self, amount: u8, id: ClientId) -> Vec<WlSurface> {
         let surfaces = (0..amount)
             .map(|_| self.spawn_window_with(id, |_| ()))
@@ -1,8 +1,16 @@
-use crate::common::fixture::Fixture;
+use crate::common::{client::ClientId, fixture::Fixture};
 use pinnacle::{focus::keyboard::KeyboardFocusTarget, state::WithState, tag::Tag};
 use pinnacle_api::layout::{LayoutGenerator, generators::MasterStack};
-use smithay::{output::Output, utils::Rectangle};
+use smithay::{
+    desktop::layer_map_for_output,
+    output::Output,
+    reexports::wayland_protocols_wlr::layer_shell::v1::client::{
+        zwlr_layer_shell_v1::Layer, zwlr_layer_surface_v1::KeyboardInteractivity,
+    },
+    utils::Rectangle,
+};
 use test_log::test;
+use wayland_client::protocol::wl_surface::WlSurface;
 
 fn set_up() -> (Fixture, Output, Output) {
     let mut fixture = Fixture::new();
@@ -92,3 +100,114 @@ fn keyboard_focus() {
         ))
     );
 }
+
+fn current_focus(fixture: &mut Fixture) -> Option<KeyboardFocusTarget> {
+    fixture
+        .pinnacle()
+        .seat
+        .get_keyboard()
+        .unwrap()
+        .current_focus()
+}
+
+/// Spawns a window on `window_output` and an on-demand layer surface on `layer_output`,
+/// then gives the layer surface focus like clicking on it would.
+fn focus_layer_over_window(
+    fixture: &mut Fixture,
+    window_output: &Output,
+    layer_output: &Output,
+) -> (ClientId, WlSurface) {
+    fixture.spawn_blocking(|| {
+        pinnacle_api::layout::manage(|args| pinnacle_api::layout::LayoutResponse {
+            root_node: MasterStack::default().layout(args.window_count),
+            tree_id: 0,
+        });
+    });
+
+    let client_id = fixture.add_client();
+
+    fixture.pinnacle().focus_output(layer_output);
+    let surface = fixture.spawn_layer_with(client_id, Layer::Top, "launcher", |layer| {
+        layer.set_keyboard_interactivity(KeyboardInteractivity::OnDemand);
+    });
+
+    fixture.pinnacle().focus_output(window_output);
+    fixture.spawn_windows(1, client_id);
+
+    let layer = layer_map_for_output(layer_output)
+        .layers()
+        .next()
+        .cloned()
+        .unwrap();
+    fixture.pinnacle().focus_output(layer_output);
+    fixture.pinnacle().on_demand_layer_focus = Some(layer.clone());
+    fixture.dispatch();
+
+    assert_eq!(
+        current_focus(fixture),
+        Some(KeyboardFocusTarget::LayerSurface(layer))
+    );
+
+    (client_id, surface)
+}
+
+#[test]
+fn closing_layer_returns_focus_to_previous_window() {
+    let (mut fixture, op1, op2) = set_up();
+
+    let (client_id, layer) = focus_layer_over_window(&mut fixture, &op1, &op2);
+
+    fixture.client(client_id).close_layer(&layer);
+    fixture.roundtrip(client_id);
+    fixture.dispatch();
+
+    let window = fixture.pinnacle().windows[0].clone();
+    assert_eq!(fixture.pinnacle().on_demand_layer_focus, None);
+    assert_eq!(fixture.pinnacle().focused_output(), Some(&op1));
+    assert_eq!(
+        current_focus(&mut fixture),
+        Some(KeyboardFocusTarget::Window(window))
+    );
+}
+
+#[test]
+fn unmapping_layer_returns_focus_to_previous_window() {
+    let (mut fixture, op1, op2) = set_up();
+
+    let (client_id, layer) = focus_layer_over_window(&mut fixture, &op1, &op2);
+
+    fixture.client(client_id).layer_for_surface(&layer).unmap();
+    fixture.roundtrip(client_id);
+    fixture.dispatch();
+
+    let window = fixture.pinnacle().windows[0].clone();
+    assert_eq!(fixture.pinnacle().on_demand_layer_focus, None);
+    assert_eq!(fixture.pinnacle().focused_output(), Some(&op1));
+    assert_eq!(
+        current_focus(&mut fixture),
+        Some(KeyboardFocusTarget::Window(window))
+    );
+}
+
+#[test]
+fn layer_without_keyboard_interactivity_loses_focus() {
+    let (mut fixture, op1, _) = set_up();
+
+    let (client_id, layer) = focus_layer_over_window(&mut fixture, &op1, &op1);
+
+    let client = fixture.client(client_id);
+    let layer = client.layer_for_surface(&layer);
+    layer
+        .layer_surface()
+        .set_keyboard_interactivity(KeyboardInteractivity::None);
+    layer.commit();
+    fixture.roundtrip(client_id);
+    fixture.dispatch();
+
+    let window = fixture.pinnacle().windows[0].clone();
+    assert_eq!(fixture.pinnacle().on_demand_layer_focus, None);
+    assert_eq!(
+        current_focus(&mut fixture),
+        Some(KeyboardFocusTarget::Window(window))
+    );
+}
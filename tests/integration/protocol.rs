@@ -1 +1,6 @@
+mod alpha_modifier;
+mod ext_workspace;
+mod pointer_gestures;
+mod snowcap_thumbnail;
 mod xdg_shell;
+mod xdg_toplevel_drag;
@@ -0,0 +1,109 @@
+//! alpha-modifier test suite
+
+use pinnacle::{state::WithState, tag::Tag};
+use pinnacle_api::layout::{LayoutGenerator as _, generators::MasterStack};
+use smithay::{
+    reexports::wayland_server::protocol::wl_surface::WlSurface,
+    utils::Rectangle,
+    wayland::{alpha_modifier::AlphaModifierSurfaceCachedState, compositor},
+};
+
+use crate::common::fixture::Fixture;
+
+fn set_up() -> Fixture {
+    let mut fixture = Fixture::new();
+
+    let output = fixture.add_output(Rectangle::new((0, 0).into(), (1920, 1080).into()));
+    output.with_state_mut(|state| {
+        let tag = Tag::new("1".to_string());
+        tag.set_active(true);
+        state.add_tags([tag]);
+    });
+    fixture.pinnacle().focus_output(&output);
+
+    fixture
+        .runtime_handle()
+        .block_on(pinnacle_api::connect())
+        .unwrap();
+
+    fixture.spawn_blocking(|| {
+        pinnacle_api::layout::manage(|args| pinnacle_api::layout::LayoutResponse {
+            root_node: MasterStack::default().layout(args.window_count),
+            tree_id: 0,
+        });
+    });
+
+    fixture
+}
+
+fn multiplier(surface: &WlSurface) -> Option<u32> {
+    compositor::with_states(surface, |states| {
+        states
+            .cached_state
+            .get::<AlphaModifierSurfaceCachedState>()
+            .current()
+            .multiplier()
+    })
+}
+
+#[test_log::test]
+fn multiplier_is_applied_on_commit() {
+    let mut fixture = set_up();
+
+    let client_id = fixture.add_client();
+    let surfaces = fixture.spawn_windows(1, client_id);
+    let surface = fixture.pinnacle().windows[0]
+        .wl_surface()
+        .unwrap()
+        .into_owned();
+    assert_eq!(multiplier(&surface), None);
+
+    let modifier = fixture
+        .client(client_id)
+        .create_alpha_modifier(&surfaces[0]);
+    modifier.set_multiplier(u32::MAX / 2);
+    fixture.roundtrip(client_id);
+
+    // The multiplier is double-buffered
+    assert_eq!(multiplier(&surface), None);
+
+    fixture
+        .client(client_id)
+        .window_for_surface(&surfaces[0])
+        .commit();
+    fixture.roundtrip(client_id);
+
+    assert_eq!(multiplier(&surface), Some(u32::MAX / 2));
+}
+
+#[test_log::test]
+fn destroying_the_modifier_resets_the_multiplier() {
+    let mut fixture = set_up();
+
+    let client_id = fixture.add_client();
+    let surfaces = fixture.spawn_windows(1, client_id);
+    let surface = fixture.pinnacle().windows[0]
+        .wl_surface()
+        .unwrap()
+        .into_owned();
+
+    let modifier = fixture
+        .client(client_id)
+        .create_alpha_modifier(&surfaces[0]);
+    modifier.set_multiplier(0);
+    fixture
+        .client(client_id)
+        .window_for_surface(&surfaces[0])
+        .commit();
+    fixture.roundtrip(client_id);
+    assert_eq!(multiplier(&surface), Some(0));
+
+    modifier.destroy();
+    fixture
+        .client(client_id)
+        .window_for_surface(&surfaces[0])
+        .commit();
+    fixture.roundtrip(client_id);
+
+    assert_eq!(multiplier(&surface), None);
+}
@@ -0,0 +1,113 @@
+//! ext-workspace test suite
+
+use pinnacle::{state::WithState, tag::Tag};
+use smithay::{
+    output::Output,
+    reexports::wayland_protocols::ext::workspace::v1::client::ext_workspace_handle_v1::State,
+    utils::Rectangle,
+};
+
+use crate::common::{
+    client::{ClientId, Workspace},
+    fixture::Fixture,
+};
+
+fn set_up() -> (Fixture, Output, ClientId) {
+    let mut fixture = Fixture::new();
+
+    let output = fixture.add_output(Rectangle::new((0, 0).into(), (1920, 1080).into()));
+    output.with_state_mut(|state| {
+        let tag = Tag::new("1".to_string());
+        tag.set_active(true);
+        state.add_tags([tag, Tag::new("2".to_string()), Tag::new("3".to_string())]);
+    });
+    fixture.pinnacle().focus_output(&output);
+
+    let client_id = fixture.add_client();
+    fixture.roundtrip(client_id);
+
+    (fixture, output, client_id)
+}
+
+/// Returns the name and position of each workspace, ordered by position.
+fn workspaces(fixture: &mut Fixture, client_id: ClientId) -> Vec<(String, u32, State)> {
+    let mut workspaces = fixture
+        .client(client_id)
+        .workspaces()
+        .map(|workspace: &Workspace| {
+            let index = u32::from_ne_bytes(workspace.coordinates.as_slice().try_into().unwrap());
+            (workspace.name.clone().unwrap(), index, workspace.state)
+        })
+        .collect::<Vec<_>>();
+    workspaces.sort_by_key(|(_, index, _)| *index);
+    workspaces
+}
+
+#[test_log::test]
+fn workspaces_are_reported_in_tag_order() {
+    let (mut fixture, output, client_id) = set_up();
+
+    assert_eq!(
+        workspaces(&mut fixture, client_id),
+        [
+            ("1".to_string(), 0, State::Active),
+            ("2".to_string(), 1, State::empty()),
+            ("3".to_string(), 2, State::empty()),
+        ]
+    );
+
+    let tag_ids = output.with_state(|state| {
+        state
+            .tags
+            .iter()
+            .map(|tag| tag.id().to_string())
+            .collect::<Vec<_>>()
+    });
+    for workspace in fixture.client(client_id).workspaces() {
+        assert!(tag_ids.contains(workspace.id.as_ref().unwrap()));
+    }
+}
+
+#[test_log::test]
+fn urgent_tag_is_reported() {
+    let (mut fixture, output, client_id) = set_up();
+
+    output.with_state(|state| state.tags[1].set_urgent(true));
+    fixture.roundtrip(client_id);
+
+    assert_eq!(workspaces(&mut fixture, client_id)[1].2, State::Urgent);
+
+    output.with_state(|state| state.tags[1].set_urgent(false));
+    fixture.roundtrip(client_id);
+
+    assert_eq!(workspaces(&mut fixture, client_id)[1].2, State::empty());
+}
+
+#[test_log::test]
+fn tags_replaced_after_reload_keep_their_position() {
+    let (mut fixture, output, client_id) = set_up();
+
+    // Reloading the config makes all tags defunct until the config adds them back
+    output.with_state(|state| {
+        for tag in state.tags.iter() {
+            tag.make_defunct();
+        }
+    });
+    fixture.roundtrip(client_id);
+
+    assert!(
+        workspaces(&mut fixture, client_id)
+            .iter()
+            .all(|(_, _, state)| state.contains(State::Hidden))
+    );
+
+    output.with_state_mut(|state| state.add_tags([Tag::new("renamed".to_string())]));
+    fixture.roundtrip(client_id);
+
+    let workspaces = workspaces(&mut fixture, client_id);
+    assert_eq!(workspaces.len(), 3);
+    assert_eq!(workspaces[0].0, "renamed");
+    assert_eq!(workspaces[0].1, 0);
+    assert!(!workspaces[0].2.contains(State::Hidden));
+    assert!(workspaces[1].2.contains(State::Hidden));
+}
@@ -0,0 +1,107 @@
+//! pointer-gestures test suite
+
+use pinnacle::{state::WithState, tag::Tag};
+use pinnacle_api::layout::{LayoutGenerator as _, generators::MasterStack};
+use smithay::{
+    input::pointer::{
+        GesturePinchBeginEvent, GesturePinchEndEvent, GesturePinchUpdateEvent, MotionEvent,
+    },
+    utils::{Rectangle, SERIAL_COUNTER},
+};
+
+use crate::common::{client::PinchEvent, fixture::Fixture};
+
+fn set_up() -> Fixture {
+    let mut fixture = Fixture::new();
+
+    let output = fixture.add_output(Rectangle::new((0, 0).into(), (1920, 1080).into()));
+    output.with_state_mut(|state| {
+        let tag = Tag::new("1".to_string());
+        tag.set_active(true);
+        state.add_tags([tag]);
+    });
+    fixture.pinnacle().focus_output(&output);
+
+    fixture
+        .runtime_handle()
+        .block_on(pinnacle_api::connect())
+        .unwrap();
+
+    fixture.spawn_blocking(|| {
+        pinnacle_api::layout::manage(|args| pinnacle_api::layout::LayoutResponse {
+            root_node: MasterStack::default().layout(args.window_count),
+            tree_id: 0,
+        });
+    });
+
+    fixture
+}
+
+#[test_log::test]
+fn pinch_gesture_is_forwarded_to_window_under_pointer() {
+    let mut fixture = set_up();
+
+    let client_id = fixture.add_client();
+    fixture.spawn_windows(1, client_id);
+
+    fixture.client(client_id).create_pinch_gesture();
+    fixture.roundtrip(client_id);
+
+    let window = fixture.pinnacle().windows[0].clone();
+    let geo = fixture.pinnacle().space.element_geometry(&window).unwrap();
+    let center = geo.loc.to_f64() + geo.size.to_f64().downscale(2.0).to_point();
+
+    let pointer = fixture.pinnacle().seat.get_pointer().unwrap();
+    let state = fixture.state();
+    pointer.motion(
+        state,
+        None,
+        &MotionEvent {
+            location: center,
+            serial: SERIAL_COUNTER.next_serial(),
+            time: 0,
+        },
+    );
+    pointer.frame(state);
+
+    // Gives the window pointer focus
+    fixture.dispatch();
+
+    let state = fixture.state();
+    pointer.gesture_pinch_begin(
+        state,
+        &GesturePinchBeginEvent {
+            serial: SERIAL_COUNTER.next_serial(),
+            time: 0,
+            fingers: 2,
+        },
+    );
+    pointer.gesture_pinch_update(
+        state,
+        &GesturePinchUpdateEvent {
+            time: 1,
+            delta: (0.0, 0.0).into(),
+            scale: 1.5,
+            rotation: 0.0,
+        },
+    );
+    pointer.gesture_pinch_end(
+        state,
+        &GesturePinchEndEvent {
+            serial: SERIAL_COUNTER.next_serial(),
+            time: 2,
+            cancelled: false,
+        },
+    );
+
+    fixture.roundtrip(client_id);
+
+    assert_eq!(
+        fixture.client(client_id).pinch_events(),
+        [
+            PinchEvent::Begin { fingers: 2 },
+            PinchEvent::Update { scale: 1.5 },
+            PinchEvent::End { cancelled: false },
+        ]
+    );
+}
@@ -0,0 +1,228 @@
+//! snowcap-thumbnail test suite
+//!
+//! The test backend has no renderer, so captures that reach rendering fail.
+//! These tests check that every capture is answered rather than what gets drawn.
+
+use crate::common::fixture::Fixture;
+use pinnacle::{handlers::session_lock::LockState, state::WithState, tag::Tag};
+use pinnacle_api::layout::{LayoutGenerator as _, generators::MasterStack};
+
+use smithay::{output::Output, utils::Rectangle};
+
+fn set_up() -> (Fixture, Output) {
+    let mut fixture = Fixture::new();
+
+    let output = fixture.add_output(Rectangle::new((0, 0).into(), (1920, 1080).into()));
+    output.with_state_mut(|state| {
+        let tag = Tag::new("1".to_string());
+        tag.set_active(true);
+        state.add_tags([tag, Tag::new("2".to_string())]);
+    });
+
+    fixture.pinnacle().focus_output(&output);
+
+    fixture
+        .runtime_handle()
+        .block_on(pinnacle_api::connect())
+        .unwrap();
+
+    fixture.spawn_blocking(|| {
+        pinnacle_api::layout::manage(|args| pinnacle_api::layout::LayoutResponse {
+            root_node: MasterStack::default().layout(args.window_count),
+            tree_id: 0,
+        });
+    });
+
+    (fixture, output)
+}
+
+#[test_log::test]
+fn output_thumbnail_fits_max_size() {
+    let (mut fixture, _) = set_up();
+
+    let client_id = fixture.add_client();
+
+    let wl_output = fixture.client(client_id).wl_outputs()[0].clone();
+    let thumbnail = fixture
+        .client(client_id)
+        .create_output_thumbnail(&wl_output, (192, 192));
+    fixture.roundtrip(client_id);
+
+    let thumb = fixture.client(client_id).thumbnail(&thumbnail);
+    assert_eq!(thumb.buffer_size, Some((192, 108)));
+    assert!(!thumb.stopped);
+}
+
+#[test_log::test]
+fn tag_thumbnail_has_output_size() {
+    let (mut fixture, output) = set_up();
+
+    let client_id = fixture.add_client();
+
+    let tag_id = output.with_state(|state| state.tags[1].id().to_inner());
+    let thumbnail = fixture
+        .client(client_id)
+        .create_tag_thumbnail(tag_id, (960, 960));
+    fixture.roundtrip(client_id);
+
+    let thumb = fixture.client(client_id).thumbnail(&thumbnail);
+    assert_eq!(thumb.buffer_size, Some((960, 540)));
+    assert!(!thumb.stopped);
+}
+
+#[test_log::test]
+fn thumbnail_of_missing_source_is_stopped() {
+    let (mut fixture, _) = set_up();
+
+    let client_id = fixture.add_client();
+
+    let thumbnail = fixture
+        .client(client_id)
+        .create_tag_thumbnail(u32::MAX, (192, 192));
+    fixture.roundtrip(client_id);
+
+    let thumb = fixture.client(client_id).thumbnail(&thumbnail);
+    assert_eq!(thumb.buffer_size, None);
+    assert!(thumb.stopped);
+
+    fixture.client(client_id).capture_thumbnail(&thumbnail);
+    fixture.roundtrip(client_id);
+
+    assert_eq!(fixture.client(client_id).thumbnail(&thumbnail).failed, 1);
+}
+
+#[test_log::test]
+fn window_thumbnail_fits_window() {
+    let (mut fixture, _) = set_up();
+
+    let client_id = fixture.add_client();
+    fixture.spawn_windows(1, client_id);
+
+    let window = fixture.pinnacle().windows[0].clone();
+    let window_id = window.with_state(|state| state.id.0);
+    let size = window.geometry().size;
+
+    let thumbnail = fixture
+        .client(client_id)
+        .create_window_thumbnail(window_id, (100, 100));
+    fixture.roundtrip(client_id);
+
+    let zoom = f64::min(100.0 / size.w as f64, 100.0 / size.h as f64);
+    let expected = (
+        (size.w as f64 * zoom).round() as u32,
+        (size.h as f64 * zoom).round() as u32,
+    );
+
+    assert_eq!(
+        fixture.client(client_id).thumbnail(&thumbnail).buffer_size,
+        Some(expected)
+    );
+}
+
+#[test_log::test]
+fn thumbnail_capture_is_answered() {
+    let (mut fixture, _) = set_up();
+
+    let client_id = fixture.add_client();
+
+    let wl_output = fixture.client(client_id).wl_outputs()[0].clone();
+    let thumbnail = fixture
+        .client(client_id)
+        .create_output_thumbnail(&wl_output, (192, 192));
+    fixture.roundtrip(client_id);
+
+    fixture.client(client_id).capture_thumbnail(&thumbnail);
+    fixture.dispatch_until(|fixture| fixture.client(client_id).thumbnail(&thumbnail).failed > 0);
+
+    let thumb = fixture.client(client_id).thumbnail(&thumbnail);
+    assert_eq!(thumb.failed, 1);
+    assert_eq!(thumb.ready, 0);
+    assert!(!thumb.stopped);
+}
+
+#[test_log::test]
+fn window_thumbnail_stops_when_window_closes() {
+    let (mut fixture, _) = set_up();
+
+    let client_id = fixture.add_client();
+    let surface = fixture.spawn_windows(1, client_id).remove(0);
+
+    let window_id = fixture.pinnacle().windows[0].with_state(|state| state.id.0);
+
+    let thumbnail = fixture
+        .client(client_id)
+        .create_window_thumbnail(window_id, (100, 100));
+    fixture.roundtrip(client_id);
+
+    assert!(!fixture.client(client_id).thumbnail(&thumbnail).stopped);
+
+    fixture.client(client_id).close_window(&surface);
+    fixture.roundtrip(client_id);
+    fixture.dispatch_until(|fixture| fixture.client(client_id).thumbnail(&thumbnail).stopped);
+
+    fixture.client(client_id).capture_thumbnail(&thumbnail);
+    fixture.roundtrip(client_id);
+
+    assert_eq!(fixture.client(client_id).thumbnail(&thumbnail).failed, 1);
+}
+
+#[test_log::test]
+fn destroyed_thumbnail_is_removed() {
+    let (mut fixture, _) = set_up();
+
+    let client_id = fixture.add_client();
+
+    let wl_output = fixture.client(client_id).wl_outputs()[0].clone();
+    let thumbnail = fixture
+        .client(client_id)
+        .create_output_thumbnail(&wl_output, (192, 192));
+    fixture.roundtrip(client_id);
+
+    assert_eq!(
+        fixture
+            .pinnacle()
+            .snowcap_thumbnail_state
+            .thumbnails_mut()
+            .count(),
+        1
+    );
+
+    fixture.client(client_id).destroy_thumbnail(&thumbnail);
+    fixture.roundtrip(client_id);
+
+    assert_eq!(
+        fixture
+            .pinnacle()
+            .snowcap_thumbnail_state
+            .thumbnails_mut()
+            .count(),
+        0
+    );
+}
+
+#[test_log::test]
+fn thumbnail_capture_fails_while_locked() {
+    let (mut fixture, _) = set_up();
+
+    let client_id = fixture.add_client();
+
+    let wl_output = fixture.client(client_id).wl_outputs()[0].clone();
+    let thumbnail = fixture
+        .client(client_id)
+        .create_output_thumbnail(&wl_output, (192, 192));
+    fixture.roundtrip(client_id);
+
+    fixture.pinnacle().lock_state = LockState::Locked;
+
+    fixture.client(client_id).capture_thumbnail(&thumbnail);
+    fixture.dispatch_until(|fixture| fixture.client(client_id).thumbnail(&thumbnail).failed > 0);
+
+    let thumb = fixture.client(client_id).thumbnail(&thumbnail);
+    assert_eq!(thumb.failed, 1);
+    assert_eq!(thumb.ready, 0);
+    // Locking doesn't end the thumbnail, so it can capture again after unlocking
+    assert!(!thumb.stopped);
+    assert_eq!(thumb.buffer_size, Some((192, 108)));
+
+    fixture.pinnacle().lock_state = LockState::Unlocked;
+}
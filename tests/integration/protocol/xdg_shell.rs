@@ -25,7 +25,13 @@ use crate::common::fixture::Fixture;
 use pinnacle::{state::WithState, tag::Tag};
 use pinnacle_api::layout::{LayoutGenerator as _, generators::MasterStack};
 
-use smithay::{output::Output, utils::Rectangle};
+use smithay::{
+    output::Output,
+    reexports::wayland_protocols::xdg::shell::client::xdg_positioner::{
+        Anchor, ConstraintAdjustment, Gravity,
+    },
+    utils::Rectangle,
+};
 
 fn set_up() -> (Fixture, Output, Output) {
     let mut fixture = Fixture::new();
@@ -443,3 +449,49 @@ fn mapped_set_maximized_after_fullscreen() {
             .maximized
     );
 }
+
+#[test_log::test]
+fn popup_is_constrained_to_its_window_output() {
+    let (mut fixture, output, output2) = set_up();
+
+    let client_id = fixture.add_client();
+
+    fixture.pinnacle().focus_output(&output2);
+    let surfaces = fixture.spawn_windows(1, client_id);
+    fixture.pinnacle().focus_output(&output);
+
+    // A popup at the window's top left corner that would extend past the output's edges
+    let popup = fixture
+        .client(client_id)
+        .create_popup(&surfaces[0], |positioner| {
+            positioner.set_size(100, 100);
+            positioner.set_anchor_rect(0, 0, 1, 1);
+            positioner.set_anchor(Anchor::TopLeft);
+            positioner.set_gravity(Gravity::TopLeft);
+            positioner.set_constraint_adjustment(
+                ConstraintAdjustment::SlideX | ConstraintAdjustment::SlideY,
+            );
+        });
+    fixture.roundtrip(client_id);
+
+    let ((x, y), (width, height)) = fixture
+        .client(client_id)
+        .popup_for_surface(&popup)
+        .geometry
+        .unwrap();
+
+    let window = fixture.pinnacle().windows[0].clone();
+    let window_loc = fixture
+        .pinnacle()
+        .space
+        .element_geometry(&window)
+        .unwrap()
+        .loc;
+    let popup_geo = Rectangle::new(
+        (window_loc.x + x, window_loc.y + y).into(),
+        (width, height).into(),
+    );
+
+    let output2_geo = fixture.pinnacle().space.output_geometry(&output2).unwrap();
+    assert_eq!(output2_geo.intersection(popup_geo), Some(popup_geo));
+}
@@ -0,0 +1,134 @@
+//! xdg-toplevel-drag test suite
+
+use pinnacle::{state::WithState, tag::Tag};
+use pinnacle_api::layout::{LayoutGenerator as _, generators::MasterStack};
+use smithay::{
+    backend::input::ButtonState,
+    input::pointer::{ButtonEvent, MotionEvent},
+    reexports::wayland_server::Resource,
+    utils::{Logical, Point, Rectangle, SERIAL_COUNTER},
+};
+use wayland_client::Proxy;
+
+use crate::common::fixture::Fixture;
+
+const BUTTON_LEFT: u32 = 0x110;
+
+fn set_up() -> Fixture {
+    let mut fixture = Fixture::new();
+
+    let output = fixture.add_output(Rectangle::new((0, 0).into(), (1920, 1080).into()));
+    output.with_state_mut(|state| {
+        let tag = Tag::new("1".to_string());
+        tag.set_active(true);
+        state.add_tags([tag]);
+    });
+    fixture.pinnacle().focus_output(&output);
+
+    fixture
+        .runtime_handle()
+        .block_on(pinnacle_api::connect())
+        .unwrap();
+
+    fixture.spawn_blocking(|| {
+        pinnacle_api::layout::manage(|args| pinnacle_api::layout::LayoutResponse {
+            root_node: MasterStack::default().layout(args.window_count),
+            tree_id: 0,
+        });
+    });
+
+    fixture
+}
+
+fn move_pointer(fixture: &mut Fixture, loc: Point<f64, Logical>) {
+    let pointer = fixture.pinnacle().seat.get_pointer().unwrap();
+    let state = fixture.state();
+    pointer.motion(
+        state,
+        None,
+        &MotionEvent {
+            location: loc,
+            serial: SERIAL_COUNTER.next_serial(),
+            time: 0,
+        },
+    );
+    pointer.frame(state);
+}
+
+fn press_left_button(fixture: &mut Fixture) {
+    let pointer = fixture.pinnacle().seat.get_pointer().unwrap();
+    let state = fixture.state();
+    pointer.button(
+        state,
+        &ButtonEvent {
+            serial: SERIAL_COUNTER.next_serial(),
+            time: 0,
+            button: BUTTON_LEFT,
+            state: ButtonState::Pressed,
+        },
+    );
+    pointer.frame(state);
+}
+
+#[test_log::test]
+fn attached_toplevel_follows_pointer() {
+    let mut fixture = set_up();
+
+    let client_id = fixture.add_client();
+    let surfaces = fixture.spawn_windows(2, client_id);
+
+    fixture.client(client_id).create_pointer();
+    fixture.roundtrip(client_id);
+
+    let windows = surfaces
+        .iter()
+        .map(|surface| {
+            fixture
+                .pinnacle()
+                .windows
+                .iter()
+                .find(|win| {
+                    win.wl_surface()
+                        .is_some_and(|surf| surf.id().protocol_id() == surface.id().protocol_id())
+                })
+                .cloned()
+                .unwrap()
+        })
+        .collect::<Vec<_>>();
+
+    let origin = &windows[0];
+    let origin_geo = fixture.pinnacle().space.element_geometry(origin).unwrap();
+    move_pointer(
+        &mut fixture,
+        origin_geo.loc.to_f64() + origin_geo.size.to_f64().downscale(2.0).to_point(),
+    );
+
+    // Gives the origin window pointer focus
+    fixture.dispatch();
+    press_left_button(&mut fixture);
+    fixture.roundtrip(client_id);
+
+    let toplevel = fixture
+        .client(client_id)
+        .window_for_surface(&surfaces[1])
+        .toplevel();
+    let _drag = fixture
+        .client(client_id)
+        .start_toplevel_drag(&surfaces[0], &toplevel, (10, 20));
+    fixture.roundtrip(client_id);
+
+    let dragged = &windows[1];
+    assert!(dragged.with_state(|state| state.layout_mode.is_floating()));
+
+    move_pointer(&mut fixture, (500.0, 400.0).into());
+    assert_eq!(
+        dragged.with_state(|state| state.floating_loc()),
+        Some((490, 380).into())
+    );
+
+    move_pointer(&mut fixture, (700.0, 300.0).into());
+    assert_eq!(
+        dragged.with_state(|state| state.floating_loc()),
+        Some((690, 280).into())
+    );
+}
@@ -1,4 +1,11 @@
-use pinnacle::{state::WithState, tag::Tag};
+use std::{cell::RefCell, rc::Rc};
+
+use pinnacle::{
+    restart::{SavedLayoutMode, SavedSession, SavedTag, SavedWindow},
+    state::WithState,
+    tag::Tag,
+    window::watchdog::UNRESPONSIVE_TIMEOUT,
+};
 use pinnacle_api::{
     layout::{LayoutGenerator as _, generators::MasterStack},
     output::OutputHandle,
@@ -229,3 +236,173 @@ fn window_floating_pick_size() {
     let size = fixture.pinnacle().windows[0].geometry().size;
     assert_eq!(size, (500, 500).into());
 }
+
+#[test_log::test]
+fn window_unresponsive_until_configure_committed() {
+    let (mut fixture, _) = set_up();
+
+    let client_id = fixture.add_client();
+    fixture.spawn_windows(1, client_id);
+
+    let window = fixture.pinnacle().windows[0].clone();
+    assert!(window.is_responsive());
+
+    fixture.spawn_blocking(|| {
+        pinnacle_api::window::get_focused()
+            .unwrap()
+            .set_maximized(true);
+    });
+    fixture.wait_client_configure(client_id);
+
+    // Pretend the configure was sent a while ago
+    window.with_state_mut(|state| {
+        state.uncommitted_configure.as_mut().unwrap().1 -= UNRESPONSIVE_TIMEOUT;
+    });
+    fixture.dispatch();
+
+    assert!(!window.is_responsive());
+
+    fixture.client(client_id).ack_all_window();
+    fixture.roundtrip(client_id);
+    fixture.dispatch();
+
+    assert!(window.is_responsive());
+}
+
+#[test_log::test]
+fn fullscreen_window_smaller_than_output_is_letterboxed() {
+    let (mut fixture, _) = set_up();
+
+    let client_id = fixture.add_client();
+    let surfaces = fixture.spawn_windows(1, client_id);
+
+    fixture.spawn_blocking(|| {
+        pinnacle_api::render::set_letterbox_fullscreen(true);
+        pinnacle_api::window::get_focused()
+            .unwrap()
+            .set_fullscreen(true);
+    });
+    fixture.wait_client_configure(client_id);
+
+    fixture
+        .client(client_id)
+        .window_for_surface(&surfaces[0])
+        .set_size(1280, 720);
+    fixture.client(client_id).ack_all_window();
+    fixture.roundtrip(client_id);
+    fixture.dispatch();
+
+    let window = fixture.pinnacle().windows[0].clone();
+    assert!(window.with_state(|state| state.letterboxed));
+    assert_eq!(
+        fixture.pinnacle().space.element_location(&window),
+        Some((320, 180).into())
+    );
+}
+
+#[test_log::test]
+fn external_layout_generator_takes_priority_over_config() {
+    let (mut fixture, output) = set_up();
+
+    fixture.spawn_blocking(|| {
+        pinnacle_api::layout::register_generator(|args| pinnacle_api::layout::LayoutResponse {
+            root_node: MasterStack::default().layout(args.window_count),
+            tree_id: 7,
+        });
+    });
+
+    let client_id = fixture.add_client();
+    fixture.spawn_windows(1, client_id);
+
+    assert_eq!(
+        fixture
+            .pinnacle()
+            .layout_state
+            .current_layout_tree_ids
+            .get(&output.downgrade()),
+        Some(&7)
+    );
+}
+
+#[test_log::test]
+fn window_state_changes_in_one_cycle_send_one_configure() {
+    let (mut fixture, _) = set_up();
+
+    let client_id = fixture.add_client();
+    let surfaces = fixture.spawn_windows(1, client_id);
+
+    let sizes = Rc::new(RefCell::new(Vec::new()));
+    let sizes_clone = sizes.clone();
+    fixture
+        .client(client_id)
+        .window_for_surface(&surfaces[0])
+        .size_configure_hook(move |size| {
+            sizes_clone.borrow_mut().push(size);
+            true
+        });
+
+    let window = fixture.pinnacle().windows[0].clone();
+    fixture
+        .pinnacle()
+        .update_window_layout_mode(&window, |mode| mode.set_floating(true));
+    fixture
+        .pinnacle()
+        .update_window_layout_mode(&window, |mode| mode.set_maximized(true));
+
+    fixture.wait_client_configure(client_id);
+    fixture.client(client_id).ack_all_window();
+    fixture.roundtrip(client_id);
+    fixture.dispatch();
+
+    assert_eq!(*sizes.borrow(), vec![(1920, 1080)]);
+}
+
+#[test_log::test]
+fn window_from_before_restart_goes_back_to_its_tags() {
+    let (mut fixture, output) = set_up();
+
+    let tag2 = Tag::new("2".to_string());
+    tag2.set_active(true);
+    output.with_state_mut(|state| state.add_tags([tag2.clone()]));
+
+    let session = SavedSession {
+        windows: vec![SavedWindow {
+            app_id: "mango".to_string(),
+            title: None,
+            tags: vec![SavedTag {
+                output: output.name(),
+                name: "2".to_string(),
+            }],
+            layout_mode: SavedLayoutMode::Tiled,
+            floating_x: None,
+            floating_y: None,
+            floating_size: None,
+        }],
+    };
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("session.toml");
+    session.save(&path).unwrap();
+
+    fixture.pinnacle().restore_session(&path);
+    assert!(!path.exists());
+
+    let client_id = fixture.add_client();
+    fixture.spawn_window_with(client_id, |win| {
+        win.set_app_id("mango");
+    });
+    fixture.spawn_window_with(client_id, |win| {
+        win.set_app_id("mango");
+    });
+
+    let tags = fixture
+        .pinnacle()
+        .windows
+        .iter()
+        .map(|window| window.with_state(|state| state.tags.len()))
+        .collect::<Vec<_>>();
+
+    // Only the first window replaces the saved one
+    assert_eq!(tags, vec![1, 2]);
+    assert!(fixture.pinnacle().windows[0].with_state(|state| state.tags.contains(&tag2)));
+}